@@ -37,6 +37,7 @@ mod mac {
 	use super::*;
 
 	static INPUT_SIZES: [usize; 4] = [512, 1024, 2048, 4098];
+	static SMALL_INPUT_SIZES: [usize; 3] = [16, 64, 128];
 
 	pub fn bench_poly1305(c: &mut Criterion) {
 		let mut group = c.benchmark_group("Poly1305");
@@ -72,12 +73,29 @@ mod mac {
 		}
 	}
 
+	pub fn bench_hmac_small(c: &mut Criterion) {
+		let mut group = c.benchmark_group("HMAC-SHA512");
+		let key = hmac::SecretKey::generate();
+
+		for size in SMALL_INPUT_SIZES.iter() {
+			let input = vec![0u8; *size];
+
+			group.throughput(Throughput::Bytes(*size as u64));
+			group.bench_with_input(
+				BenchmarkId::new("compute mac", *size),
+				&input,
+				|b, input_message| b.iter(|| hmac::hmac(&key, &input_message).unwrap()),
+			);
+		}
+	}
+
 	criterion_group! {
 		name = mac_benches;
 		config = Criterion::default();
 		targets =
 		bench_poly1305,
 		bench_hmac,
+		bench_hmac_small,
 	}
 }
 
@@ -85,6 +103,7 @@ mod aead {
 	use super::*;
 
 	static INPUT_SIZES: [usize; 4] = [512, 1024, 2048, 4098];
+	static SMALL_INPUT_SIZES: [usize; 3] = [16, 64, 128];
 
 	pub fn bench_chacha20poly1305(c: &mut Criterion) {
 		let mut group = c.benchmark_group("ChaCha20-Poly1305");
@@ -132,11 +151,35 @@ mod aead {
 		}
 	}
 
+	pub fn bench_chacha20poly1305_small(c: &mut Criterion) {
+		let mut group = c.benchmark_group("ChaCha20-Poly1305");
+		let key = chacha20poly1305::SecretKey::generate();
+		let nonce = chacha20poly1305::Nonce::from_slice(&[0u8; 12]).unwrap();
+
+		for size in SMALL_INPUT_SIZES.iter() {
+			let input = vec![0u8; *size];
+			let mut out = vec![0u8; input.len() + 16];
+
+			group.throughput(Throughput::Bytes(*size as u64));
+			group.bench_with_input(
+				BenchmarkId::new("encrypt", *size),
+				&input,
+				|b, input_message| {
+					b.iter(|| {
+						chacha20poly1305::seal(&key, &nonce, &input_message, None, &mut out)
+							.unwrap()
+					})
+				},
+			);
+		}
+	}
+
 	criterion_group! {
 		name = aead_benches;
 		config = Criterion::default();
 		targets =
 		bench_chacha20poly1305,
+		bench_chacha20poly1305_small,
 		bench_xchacha20poly1305,
 	}
 }
@@ -145,6 +188,7 @@ mod hash {
 	use super::*;
 
 	static INPUT_SIZES: [usize; 4] = [512, 1024, 2048, 4098];
+	static SMALL_INPUT_SIZES: [usize; 3] = [16, 64, 128];
 
 	pub fn bench_sha512(c: &mut Criterion) {
 		let mut group = c.benchmark_group("SHA512");
@@ -161,6 +205,21 @@ mod hash {
 		}
 	}
 
+	pub fn bench_sha512_small(c: &mut Criterion) {
+		let mut group = c.benchmark_group("SHA512");
+
+		for size in SMALL_INPUT_SIZES.iter() {
+			let input = vec![0u8; *size];
+
+			group.throughput(Throughput::Bytes(*size as u64));
+			group.bench_with_input(
+				BenchmarkId::new("compute hash", *size),
+				&input,
+				|b, input_message| b.iter(|| sha512::digest(&input_message).unwrap()),
+			);
+		}
+	}
+
 	pub fn bench_blake2b_256(c: &mut Criterion) {
 		let mut group = c.benchmark_group("BLAKE2b-256");
 
@@ -276,6 +335,7 @@ mod hash {
 		config = Criterion::default();
 		targets =
 		bench_sha512,
+		bench_sha512_small,
 		bench_blake2b_256,
 		bench_blake2b_384,
 		bench_blake2b_512,
@@ -289,6 +349,7 @@ mod stream {
 	use super::*;
 
 	static INPUT_SIZES: [usize; 4] = [512, 1024, 2048, 4098];
+	static LARGE_INPUT_SIZES: [usize; 3] = [16384, 65536, 1048576];
 
 	pub fn bench_chacha20(c: &mut Criterion) {
 		let mut group = c.benchmark_group("ChaCha20");
@@ -310,6 +371,36 @@ mod stream {
 		}
 	}
 
+	pub fn bench_chacha20_large(c: &mut Criterion) {
+		let mut group = c.benchmark_group("ChaCha20");
+		let key = chacha20poly1305::SecretKey::generate();
+		let nonce = chacha20poly1305::Nonce::from_slice(&[0u8; 12]).unwrap();
+
+		for size in LARGE_INPUT_SIZES.iter() {
+			let input = vec![0u8; *size];
+			let mut out = vec![0u8; input.len()];
+
+			group.throughput(Throughput::Bytes(*size as u64));
+			group.bench_with_input(
+				BenchmarkId::new("xor-stream-large", *size),
+				&input,
+				|b, input_message| {
+					b.iter(|| chacha20::encrypt(&key, &nonce, 0, &input_message, &mut out).unwrap())
+				},
+			);
+			// A length that is not a multiple of the block size.
+			let input = vec![0u8; *size - 1];
+			group.throughput(Throughput::Bytes((*size - 1) as u64));
+			group.bench_with_input(
+				BenchmarkId::new("xor-stream-large", *size - 1),
+				&input,
+				|b, input_message| {
+					b.iter(|| chacha20::encrypt(&key, &nonce, 0, &input_message, &mut out).unwrap())
+				},
+			);
+		}
+	}
+
 	pub fn bench_xchacha20(c: &mut Criterion) {
 		let mut group = c.benchmark_group("XChaCha20");
 		let key = xchacha20::SecretKey::generate();
@@ -337,6 +428,7 @@ mod stream {
 		config = Criterion::default();
 		targets =
 		bench_chacha20,
+		bench_chacha20_large,
 		bench_xchacha20,
 	}
 }
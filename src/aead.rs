@@ -31,31 +31,119 @@
 //!
 //! # About:
 //! - The nonce is automatically generated.
-//! - Returns a vector where the first 24 bytes are the nonce and the rest is
-//!   the authenticated
-//! ciphertext with the last 16 bytes being the corresponding Poly1305 tag.
-//! - Uses XChaCha20Poly1305 with no additional data.
+//! - Returns a vector that starts with a 26 byte header, followed by the
+//!   authenticated ciphertext with the last 16 bytes being the corresponding
+//!   Poly1305 tag. The header is a format version, an algorithm identifier
+//!   and the 24 byte nonce. [`open`] checks the version and algorithm
+//!   identifier before decrypting, and both are authenticated as additional
+//!   data. This lets orion change the algorithm in the future without
+//!   breaking stored messages.
+//! - Uses XChaCha20Poly1305.
 //! - When using [`seal`] and [`open`] then the separation of tags, nonces and
 //!   ciphertext are automatically handled.
+//! - A [`SealingSession`] and [`OpeningSession`] can be used instead, to
+//!   protect an ordered sequence of messages under one key. Nonces are not
+//!   included in their ciphertexts, but derived from a random 16 byte session
+//!   header and a message counter. The header must be sent to the receiver
+//!   once, before any messages.
+//! - Sessions started with [`SealingSession::new_with_counter`] do not need a
+//!   random generator. Their header is derived from the `secret_key` and a
+//!   `session_counter`, using [`derive_xnonce`].
+//! - [`seal_into`], [`open_into`] and the `_into` methods of the sessions
+//!   write to a caller-supplied `dst_out` instead of allocating. A sealed
+//!   message is [`SEAL_OVERHEAD`] bytes longer than its plaintext, or
+//!   [`SESSION_SEAL_OVERHEAD`] bytes when sealed by a session.
+//! - [`seal_detached`] returns the Poly1305 tag separately from the nonce and
+//!   ciphertext, and [`open_detached`] takes it separately. Otherwise, they
+//!   are the same as [`seal`] and [`open`].
+//! - [`seal_padded`], [`open_padded`] and the `_padded` methods of the
+//!   sessions pad the plaintext with Padmé before encrypting it, and remove
+//!   the padding after decrypting it. A ciphertext then only reveals the
+//!   length of its plaintext rounded up, with at most 12% overhead. Padded
+//!   plaintexts may be empty.
+//! - [`seal_with_nonce`] and [`open_with_nonce`] take the nonce from the
+//!   caller, for protocols that derive nonces themselves, such as from a
+//!   sequence number. The nonce is not included in the sealed message, which
+//!   is [`POLY1305_OUTSIZE`] bytes longer than its plaintext.
+//! - [`ratchet_key`] derives the next key from a key, which it consumes, with
+//!   keyed BLAKE2b-256. `rekey()` on a [`SealingSession`] or
+//!   [`OpeningSession`] does the same to the session's key. Once the old key
+//!   is wiped, messages sealed under it cannot be opened with the new key,
+//!   which gives forward secrecy within a long-lived session. The message
+//!   counter is not reset.
+//! - A [`MultiKey`] holds a current key and older keys, for key rotation. It
+//!   seals with the current key, and opens with each key in turn, returning
+//!   the index of the key that succeeded. Data opened with an older key can
+//!   then be sealed again with the current key.
+//! - Messages sealed by earlier versions of orion have no header, only the
+//!   nonce. [`open_legacy`] opens them, and [`upgrade_legacy`] opens such a
+//!   message and seals it again with [`seal`]. A message in the legacy format
+//!   is [`LEGACY_SEAL_OVERHEAD`] bytes longer than its plaintext.
+//! - With the `serde` feature, [`seal_value`] serializes a value with a
+//!   canonical binary encoding and seals it with [`seal`].
+//!   [`open_value`] opens and deserializes it. The plaintext starts with a
+//!   version byte of the encoding, so it is authenticated along with the
+//!   value. Integers are encoded as fixed-width little-endian, and strings,
+//!   sequences and maps are prefixed by their length. Maps are encoded in
+//!   iteration order, so a `BTreeMap` should be used where the same value
+//!   must always give the same plaintext.
 //!
 //! # Parameters:
 //! - `plaintext`:  The data to be encrypted.
 //! - `secret_key`: The secret key used to encrypt the `plaintext`.
-//! - `ciphertext_with_tag_and_nonce`:  The data to be decrypted with the first
-//!   24 bytes being the nonce and the last
-//! 16 bytes being the corresponding Poly1305 tag.
+//! - `ciphertext_with_tag_and_header`: The data to be decrypted with the first
+//!   26 bytes being the header and the last 16 bytes being the corresponding
+//!   Poly1305 tag.
+//! - `ciphertext_with_tag_and_nonce`: A message in the legacy format, with the
+//!   first 24 bytes being the nonce and the last 16 bytes being the
+//!   corresponding Poly1305 tag.
+//! - `rng`: The random generator used to generate the nonce in
+//!   [`seal_with_rng`], or the session header in
+//!   [`SealingSession::new_with_rng`]. [`seal`] and [`SealingSession::new`]
+//!   use [`OsRng`].
+//! - `header`: The session header of a [`SealingSession`].
+//! - `session_counter`: A counter that is unique for each session started
+//!   under `secret_key`.
+//! - `ciphertext_with_tag`: A message sealed by a [`SealingSession`], with the
+//!   last 16 bytes being the corresponding Poly1305 tag.
+//! - `dst_out`: Destination buffer for the sealed or opened message.
+//! - `ciphertext_with_header`: A message sealed with [`seal_detached`], with
+//!   the first 26 bytes being the header.
+//! - `tag`: The Poly1305 tag returned by [`seal_detached`].
+//! - `nonce`: The nonce used to seal or open a message with
+//!   [`seal_with_nonce`] or [`open_with_nonce`].
+//! - `keys`: The keys of a [`MultiKey`], the current key first.
+//! - `value`: The value to serialize and seal with [`seal_value`].
 //!
 //! # Errors:
 //! An error will be returned if:
 //! - `secret_key` is not 32 bytes.
 //! - `plaintext` is empty.
+//! - `ciphertext_with_tag_and_header` is less than 43 bytes
+//!   ([`SEAL_OVERHEAD`] + 1).
+//! - The header of `ciphertext_with_tag_and_header` names an unknown format
+//!   version or algorithm.
 //! - `ciphertext_with_tag_and_nonce` is less than 41 bytes
-//!   ([`XCHACHA_NONCESIZE`] + [`POLY1305_OUTSIZE`] + 1).
-//! - The received tag does not match the calculated tag when calling [`open`].
+//!   ([`LEGACY_SEAL_OVERHEAD`] + 1).
+//! - The received tag does not match the calculated tag when calling [`open`]
+//!   or [`open_legacy`].
+//! - `ciphertext_with_tag` is less than 17 bytes ([`POLY1305_OUTSIZE`] + 1).
+//! - The received tag does not match the calculated tag when calling
+//!   [`open_with_nonce`] or [`open_detached`].
+//! - `ciphertext_with_header` is less than 27 bytes ([`SEAL_HEADER_SIZE`] + 1).
+//! - A message opened by an [`OpeningSession`] was not the next message sealed
+//!   by the corresponding [`SealingSession`]. This includes replayed, reordered
+//!   and dropped messages.
+//! - A session has sealed or opened 2^64-1 messages.
+//! - `dst_out` is shorter than the sealed or opened message.
+//! - A message opened with [`open_padded`] or `open_padded()` was not sealed
+//!   with padding.
+//! - `keys` is empty.
+//! - None of the keys of a [`MultiKey`] can open a message.
+//! - More than 2^32-1 * 64 bytes of data are processed.
 //!
 //! # Panics:
 //! A panic will occur if:
-//! - More than 2^32-1 * 64 bytes of data are processed.
 //! - Failure to generate random bytes securely.
 //!
 //! # Security:
@@ -64,6 +152,25 @@
 //! the security of all data that has been encrypted with that given key is
 //! compromised.
 //! - To securely generate a strong key, use [`SecretKey::default()`].
+//! - A session does not implement `Clone`, and each [`SealingSession`] has a
+//!   random header, so nonces cannot be reused within or between sessions.
+//! - When using [`SealingSession::new_with_counter`], a `session_counter` must
+//!   never be used twice with the same `secret_key`. Doing so reuses nonces.
+//! - An [`OpeningSession`] that has failed to open a message can still open
+//!   the message it expected. It is up to the caller to decide whether an
+//!   error should end the session.
+//! - When using [`seal_with_nonce`], a nonce must never be used twice with the
+//!   same `secret_key`. [`derive_xnonce`] can derive unique nonces from a
+//!   counter.
+//! - Padding reduces what the length of a ciphertext reveals, but does not
+//!   hide it. Lengths that differ by more than the padding can still be told
+//!   apart.
+//! - The time [`MultiKey`] takes to open a message reveals which key opened
+//!   it.
+//! - A [`SealingSession`] and its [`OpeningSession`] must call `rekey()`
+//!   after the same message. Otherwise, no further messages can be opened.
+//!   The old key is wiped from memory, but not from copies the caller made
+//!   of it.
 //!
 //! # Example:
 //! ```rust
@@ -72,64 +179,245 @@
 //! let secret_key = aead::SecretKey::default();
 //! let ciphertext = aead::seal(&secret_key, "Secret message".as_bytes())?;
 //! let decrypted_data = aead::open(&secret_key, &ciphertext)?;
+//!
+//! // Sealing several messages in a session.
+//! let mut sealer = aead::SealingSession::new(&secret_key)?;
+//! let mut opener = aead::OpeningSession::new(&secret_key, &sealer.header())?;
+//! let first = sealer.seal("First message".as_bytes())?;
+//! let second = sealer.seal("Second message".as_bytes())?;
+//!
+//! assert_eq!(opener.open(&first)?, "First message".as_bytes());
+//! // A replayed message is rejected.
+//! assert!(opener.open(&first).is_err());
+//! assert_eq!(opener.open(&second)?, "Second message".as_bytes());
+//!
+//! // Ratcheting the session key forward.
+//! sealer.rekey()?;
+//! opener.rekey()?;
+//! let third = sealer.seal("Third message".as_bytes())?;
+//! assert_eq!(opener.open(&third)?, "Third message".as_bytes());
+//!
+//! // Sealing and opening without allocating.
+//! let mut sealed = [0u8; 14 + aead::SEAL_OVERHEAD];
+//! let mut opened = [0u8; 14];
+//! aead::seal_into(&secret_key, "Secret message".as_bytes(), &mut sealed)?;
+//! let opened_len = aead::open_into(&secret_key, &sealed, &mut opened)?;
+//! assert_eq!(&opened[..opened_len], "Secret message".as_bytes());
+//!
+//! // Hiding the exact length of the plaintext.
+//! let padded = aead::seal_padded(&secret_key, "Secret message".as_bytes())?;
+//! assert_eq!(aead::open_padded(&secret_key, &padded)?, "Secret message".as_bytes());
+//!
+//! // Rotating keys.
+//! let new_key = aead::SecretKey::default();
+//! let keys = aead::MultiKey::new(vec![new_key, secret_key])?;
+//! let (decrypted_data, index) = keys.open(&ciphertext)?;
+//! assert_eq!(index, 1);
+//! // Opened with an old key, so seal it again with the current key.
+//! let ciphertext = keys.seal(&decrypted_data)?;
+//! assert_eq!(keys.open(&ciphertext)?.1, 0);
 //! # Ok::<(), orion::errors::UnknownCryptoError>(())
 //! ```
 //! [`seal`]: https://docs.rs/orion/latest/orion/aead/fn.seal.html
 //! [`open`]: https://docs.rs/orion/latest/orion/aead/fn.open.html
+//! [`seal_with_rng`]: https://docs.rs/orion/latest/orion/aead/fn.seal_with_rng.html
+//! [`seal_into`]: https://docs.rs/orion/latest/orion/aead/fn.seal_into.html
+//! [`open_into`]: https://docs.rs/orion/latest/orion/aead/fn.open_into.html
+//! [`seal_detached`]: https://docs.rs/orion/latest/orion/aead/fn.seal_detached.html
+//! [`open_detached`]: https://docs.rs/orion/latest/orion/aead/fn.open_detached.html
+//! [`seal_with_nonce`]: https://docs.rs/orion/latest/orion/aead/fn.seal_with_nonce.html
+//! [`open_with_nonce`]: https://docs.rs/orion/latest/orion/aead/fn.open_with_nonce.html
+//! [`seal_padded`]: https://docs.rs/orion/latest/orion/aead/fn.seal_padded.html
+//! [`open_padded`]: https://docs.rs/orion/latest/orion/aead/fn.open_padded.html
+//! [`SEAL_OVERHEAD`]: https://docs.rs/orion/latest/orion/aead/constant.SEAL_OVERHEAD.html
+//! [`SESSION_SEAL_OVERHEAD`]: https://docs.rs/orion/latest/orion/aead/constant.SESSION_SEAL_OVERHEAD.html
+//! [`OsRng`]: https://docs.rs/orion/latest/orion/util/struct.OsRng.html
+//! [`open_legacy`]: https://docs.rs/orion/latest/orion/aead/fn.open_legacy.html
+//! [`upgrade_legacy`]: https://docs.rs/orion/latest/orion/aead/fn.upgrade_legacy.html
+//! [`SEAL_HEADER_SIZE`]: https://docs.rs/orion/latest/orion/aead/constant.SEAL_HEADER_SIZE.html
+//! [`LEGACY_SEAL_OVERHEAD`]: https://docs.rs/orion/latest/orion/aead/constant.LEGACY_SEAL_OVERHEAD.html
+//! [`seal_value`]: https://docs.rs/orion/latest/orion/aead/fn.seal_value.html
+//! [`open_value`]: https://docs.rs/orion/latest/orion/aead/fn.open_value.html
+//! [`ratchet_key`]: https://docs.rs/orion/latest/orion/aead/fn.ratchet_key.html
+//! [`MultiKey`]: https://docs.rs/orion/latest/orion/aead/struct.MultiKey.html
+//! [`SealingSession`]: https://docs.rs/orion/latest/orion/aead/struct.SealingSession.html
+//! [`OpeningSession`]: https://docs.rs/orion/latest/orion/aead/struct.OpeningSession.html
+//! [`SealingSession::new`]: https://docs.rs/orion/latest/orion/aead/struct.SealingSession.html#method.new
+//! [`SealingSession::new_with_rng`]: https://docs.rs/orion/latest/orion/aead/struct.SealingSession.html#method.new_with_rng
+//! [`SealingSession::new_with_counter`]: https://docs.rs/orion/latest/orion/aead/struct.SealingSession.html#method.new_with_counter
+//! [`derive_xnonce`]: https://docs.rs/orion/latest/orion/hazardous/aead/nonce_sequence/fn.derive_xnonce.html
 //! [`POLY1305_OUTSIZE`]: https://docs.rs/orion/latest/orion/hazardous/mac/poly1305/constant.POLY1305_OUTSIZE.html
-//! [`XCHACHA_NONCESIZE`]: https://docs.rs/orion/latest/orion/hazardous/stream/xchacha20/constant.XCHACHA_NONCESIZE.html
 //! [`SecretKey::default()`]: https://docs.rs/orion/latest/orion/aead/struct.SecretKey.html
 
+pub use crate::hazardous::mac::poly1305::Tag;
+pub use crate::hazardous::stream::xchacha20::Nonce;
 pub use crate::hltypes::SecretKey;
 use crate::{
 	errors::UnknownCryptoError,
 	hazardous::{
-		aead,
+		aead::{self, nonce_sequence::derive_xnonce},
+		hash::blake2b,
 		mac::poly1305::POLY1305_OUTSIZE,
-		stream::{
-			chacha20,
-			xchacha20::{Nonce, XCHACHA_NONCESIZE},
-		},
+		stream::{chacha20, xchacha20::XCHACHA_NONCESIZE},
 	},
+	util::SecureRandom,
 };
+use alloc::{vec, vec::Vec};
+use zeroize::Zeroize;
 
+#[cfg(feature = "safe_api")]
+use crate::util::OsRng;
+
+/// The version of the format of `seal()`.
+const FORMAT_VERSION: u8 = 1;
+/// The identifier of XChaCha20Poly1305 in the format of `seal()`.
+const ALGORITHM_XCHACHA20POLY1305: u8 = 1;
+
+/// The size of the header of a sealed message: the format version, the
+/// algorithm identifier and the 24 byte nonce.
+pub const SEAL_HEADER_SIZE: usize = 2 + XCHACHA_NONCESIZE;
+
+/// The number of bytes a sealed message is longer than its plaintext: the
+/// header and the 16 byte Poly1305 tag.
+pub const SEAL_OVERHEAD: usize = SEAL_HEADER_SIZE + POLY1305_OUTSIZE;
+
+/// The number of bytes a message in the legacy format, without a version and
+/// algorithm identifier, is longer than its plaintext: the 24 byte nonce and
+/// the 16 byte Poly1305 tag.
+pub const LEGACY_SEAL_OVERHEAD: usize = XCHACHA_NONCESIZE + POLY1305_OUTSIZE;
+
+#[cfg(feature = "safe_api")]
 #[must_use]
 /// Authenticated encryption using XChaCha20Poly1305.
 pub fn seal(secret_key: &SecretKey, plaintext: &[u8]) -> Result<Vec<u8>, UnknownCryptoError> {
+	seal_with_rng(secret_key, plaintext, &mut OsRng)
+}
+
+#[must_use]
+/// Authenticated encryption using XChaCha20Poly1305, with the nonce generated
+/// by `rng`.
+pub fn seal_with_rng<R: SecureRandom + ?Sized>(
+	secret_key: &SecretKey,
+	plaintext: &[u8],
+	rng: &mut R,
+) -> Result<Vec<u8>, UnknownCryptoError> {
 	if plaintext.is_empty() {
-		return Err(UnknownCryptoError);
+		return Err(UnknownCryptoError::InvalidLength);
 	}
 
-	let nonce = Nonce::generate();
+	let mut dst_out = vec![0u8; plaintext.len() + SEAL_OVERHEAD];
+	seal_into_with_rng(secret_key, plaintext, &mut dst_out, rng)?;
+
+	Ok(dst_out)
+}
+
+#[cfg(feature = "safe_api")]
+#[must_use]
+/// Authenticated encryption using XChaCha20Poly1305, writing to `dst_out`.
+/// Returns the number of bytes written.
+pub fn seal_into(
+	secret_key: &SecretKey,
+	plaintext: &[u8],
+	dst_out: &mut [u8],
+) -> Result<usize, UnknownCryptoError> {
+	seal_into_with_rng(secret_key, plaintext, dst_out, &mut OsRng)
+}
+
+#[must_use]
+/// Authenticated encryption using XChaCha20Poly1305, with the nonce generated
+/// by `rng`, writing to `dst_out`. Returns the number of bytes written.
+pub fn seal_into_with_rng<R: SecureRandom + ?Sized>(
+	secret_key: &SecretKey,
+	plaintext: &[u8],
+	dst_out: &mut [u8],
+	rng: &mut R,
+) -> Result<usize, UnknownCryptoError> {
+	if plaintext.is_empty() {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+	if dst_out.len() < plaintext.len() + SEAL_OVERHEAD {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
 
-	let mut dst_out = vec![0u8; plaintext.len() + (XCHACHA_NONCESIZE + POLY1305_OUTSIZE)];
-	dst_out[..XCHACHA_NONCESIZE].copy_from_slice(nonce.as_ref());
+	let (header, ciphertext_with_tag) = dst_out.split_at_mut(SEAL_HEADER_SIZE);
+	header[0] = FORMAT_VERSION;
+	header[1] = ALGORITHM_XCHACHA20POLY1305;
+	rng.fill_bytes(&mut header[2..])?;
 
 	aead::xchacha20poly1305::seal(
 		&chacha20::SecretKey::from_slice(secret_key.unprotected_as_bytes())?,
-		&nonce,
+		&Nonce::from_slice(&header[2..])?,
 		plaintext,
-		None,
-		&mut dst_out[XCHACHA_NONCESIZE..],
+		Some(&header[..2]),
+		&mut ciphertext_with_tag[..plaintext.len() + POLY1305_OUTSIZE],
 	)?;
 
-	Ok(dst_out)
+	Ok(plaintext.len() + SEAL_OVERHEAD)
 }
 
 #[must_use]
-/// Authenticated decryption using XChaCha20Poly1305.
+/// Authenticated decryption using XChaCha20Poly1305, of a message sealed
+/// with `seal()`.
 pub fn open(
 	secret_key: &SecretKey,
-	ciphertext_with_tag_and_nonce: &[u8],
+	ciphertext_with_tag_and_header: &[u8],
 ) -> Result<Vec<u8>, UnknownCryptoError> {
 	// `+ 1` to avoid empty ciphertexts
-	if ciphertext_with_tag_and_nonce.len() < (XCHACHA_NONCESIZE + POLY1305_OUTSIZE + 1) {
-		return Err(UnknownCryptoError);
+	if ciphertext_with_tag_and_header.len() < (SEAL_OVERHEAD + 1) {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let mut dst_out = vec![0u8; ciphertext_with_tag_and_header.len() - SEAL_OVERHEAD];
+	open_into(secret_key, ciphertext_with_tag_and_header, &mut dst_out)?;
+
+	Ok(dst_out)
+}
+
+#[must_use]
+/// Authenticated decryption using XChaCha20Poly1305, of a message sealed
+/// with `seal()`, writing to `dst_out`. Returns the number of bytes written.
+pub fn open_into(
+	secret_key: &SecretKey,
+	ciphertext_with_tag_and_header: &[u8],
+	dst_out: &mut [u8],
+) -> Result<usize, UnknownCryptoError> {
+	// `+ 1` to avoid empty ciphertexts
+	if ciphertext_with_tag_and_header.len() < (SEAL_OVERHEAD + 1) {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+	let plaintext_len = ciphertext_with_tag_and_header.len() - SEAL_OVERHEAD;
+	if dst_out.len() < plaintext_len {
+		return Err(UnknownCryptoError::InvalidLength);
 	}
+	let (header, ciphertext_with_tag) = ciphertext_with_tag_and_header.split_at(SEAL_HEADER_SIZE);
+	if header[0] != FORMAT_VERSION || header[1] != ALGORITHM_XCHACHA20POLY1305 {
+		return Err(UnknownCryptoError::InvalidEncoding);
+	}
+
+	aead::xchacha20poly1305::open(
+		&chacha20::SecretKey::from_slice(secret_key.unprotected_as_bytes())?,
+		&Nonce::from_slice(&header[2..])?,
+		ciphertext_with_tag,
+		Some(&header[..2]),
+		&mut dst_out[..plaintext_len],
+	)?;
+
+	Ok(plaintext_len)
+}
 
-	let mut dst_out =
-		vec![0u8; ciphertext_with_tag_and_nonce.len() - (XCHACHA_NONCESIZE + POLY1305_OUTSIZE)];
+#[must_use]
+/// Authenticated decryption using XChaCha20Poly1305, of a message in the
+/// legacy format without a header, where the first 24 bytes are the nonce.
+pub fn open_legacy(
+	secret_key: &SecretKey,
+	ciphertext_with_tag_and_nonce: &[u8],
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	// `+ 1` to avoid empty ciphertexts
+	if ciphertext_with_tag_and_nonce.len() < (LEGACY_SEAL_OVERHEAD + 1) {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
 
+	let mut dst_out = vec![0u8; ciphertext_with_tag_and_nonce.len() - LEGACY_SEAL_OVERHEAD];
 	aead::xchacha20poly1305::open(
 		&chacha20::SecretKey::from_slice(secret_key.unprotected_as_bytes())?,
 		&Nonce::from_slice(&ciphertext_with_tag_and_nonce[..XCHACHA_NONCESIZE])?,
@@ -141,94 +429,1465 @@ pub fn open(
 	Ok(dst_out)
 }
 
-// Testing public functions in the module.
-#[cfg(test)]
-mod public {
-	use super::*;
+#[cfg(feature = "safe_api")]
+#[must_use]
+/// Open a message in the legacy format with `open_legacy()`, and seal it
+/// again with `seal()`.
+pub fn upgrade_legacy(
+	secret_key: &SecretKey,
+	ciphertext_with_tag_and_nonce: &[u8],
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	upgrade_legacy_with_rng(secret_key, ciphertext_with_tag_and_nonce, &mut OsRng)
+}
 
-	mod test_seal_open {
-		use super::*;
-		#[test]
-		fn test_auth_enc_encryption_decryption() {
-			let key = SecretKey::default();
-			let plaintext = "Secret message".as_bytes().to_vec();
+#[must_use]
+/// Open a message in the legacy format with `open_legacy()`, and seal it
+/// again with `seal_with_rng()` and `rng`.
+pub fn upgrade_legacy_with_rng<R: SecureRandom + ?Sized>(
+	secret_key: &SecretKey,
+	ciphertext_with_tag_and_nonce: &[u8],
+	rng: &mut R,
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	let mut plaintext = open_legacy(secret_key, ciphertext_with_tag_and_nonce)?;
+	let sealed = seal_with_rng(secret_key, &plaintext, rng);
+	plaintext.zeroize();
 
-			let dst_ciphertext = seal(&key, &plaintext).unwrap();
-			assert!(dst_ciphertext.len() == plaintext.len() + (24 + 16));
-			let dst_plaintext = open(&key, &dst_ciphertext).unwrap();
-			assert!(dst_plaintext.len() == plaintext.len());
-			assert_eq!(plaintext, dst_plaintext);
-		}
+	sealed
+}
 
-		#[test]
-		fn test_auth_enc_plaintext_empty_err() {
-			let key = SecretKey::default();
-			let plaintext = "".as_bytes().to_vec();
+#[cfg(feature = "safe_api")]
+#[must_use]
+/// Authenticated encryption using XChaCha20Poly1305, returning the header and
+/// ciphertext, and the tag separately.
+pub fn seal_detached(
+	secret_key: &SecretKey,
+	plaintext: &[u8],
+) -> Result<(Vec<u8>, Tag), UnknownCryptoError> {
+	let mut sealed = seal(secret_key, plaintext)?;
+	let tag = Tag::from_slice(&sealed[sealed.len() - POLY1305_OUTSIZE..])?;
+	sealed.truncate(sealed.len() - POLY1305_OUTSIZE);
 
-			assert!(seal(&key, &plaintext).is_err());
-		}
+	Ok((sealed, tag))
+}
 
-		#[test]
-		fn test_auth_enc_ciphertext_less_than_41_err() {
-			let key = SecretKey::default();
-			let ciphertext = [0u8; 40];
+#[must_use]
+/// Authenticated decryption using XChaCha20Poly1305, of a message sealed with
+/// `seal_detached()`.
+pub fn open_detached(
+	secret_key: &SecretKey,
+	ciphertext_with_header: &[u8],
+	tag: &Tag,
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	// `+ 1` to avoid empty ciphertexts
+	if ciphertext_with_header.len() < (SEAL_HEADER_SIZE + 1) {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
 
-			assert!(open(&key, &ciphertext).is_err());
-		}
+	let mut sealed = Vec::with_capacity(ciphertext_with_header.len() + POLY1305_OUTSIZE);
+	sealed.extend_from_slice(ciphertext_with_header);
+	sealed.extend_from_slice(tag.unprotected_as_bytes());
 
-		#[test]
-		fn test_modified_nonce_err() {
-			let key = SecretKey::default();
-			let plaintext = "Secret message".as_bytes().to_vec();
+	open(secret_key, &sealed)
+}
 
-			let mut dst_ciphertext = seal(&key, &plaintext).unwrap();
-			// Modify nonce
-			dst_ciphertext[10] ^= 1;
-			assert!(open(&key, &dst_ciphertext).is_err());
+#[must_use]
+/// Authenticated encryption using XChaCha20Poly1305, with a nonce supplied by
+/// the caller. The nonce is not included in the output.
+pub fn seal_with_nonce(
+	secret_key: &SecretKey,
+	nonce: &Nonce,
+	plaintext: &[u8],
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	if plaintext.is_empty() {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let mut dst_out = vec![0u8; plaintext.len() + POLY1305_OUTSIZE];
+	aead::xchacha20poly1305::seal(
+		&chacha20::SecretKey::from_slice(secret_key.unprotected_as_bytes())?,
+		nonce,
+		plaintext,
+		None,
+		&mut dst_out,
+	)?;
+
+	Ok(dst_out)
+}
+
+#[must_use]
+/// Authenticated decryption using XChaCha20Poly1305, of a message sealed with
+/// `seal_with_nonce()` and `nonce`.
+pub fn open_with_nonce(
+	secret_key: &SecretKey,
+	nonce: &Nonce,
+	ciphertext_with_tag: &[u8],
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	// `+ 1` to avoid empty ciphertexts
+	if ciphertext_with_tag.len() < (POLY1305_OUTSIZE + 1) {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let mut dst_out = vec![0u8; ciphertext_with_tag.len() - POLY1305_OUTSIZE];
+	aead::xchacha20poly1305::open(
+		&chacha20::SecretKey::from_slice(secret_key.unprotected_as_bytes())?,
+		nonce,
+		ciphertext_with_tag,
+		None,
+		&mut dst_out,
+	)?;
+
+	Ok(dst_out)
+}
+
+/// The length that Padmé pads a plaintext of `length` bytes, and the byte
+/// that marks its end, to. The last `E - S` bits of the padded length are
+/// zero, where `E` is the exponent of the length and `S` the number of bits
+/// needed to represent `E`.
+fn padme_length(length: usize) -> usize {
+	let length = length + 1;
+	let exponent = (usize::BITS - 1 - length.leading_zeros()) as usize;
+	let exponent_bits = (usize::BITS - exponent.leading_zeros()) as usize;
+	let mask = (1usize << (exponent - exponent_bits)) - 1;
+
+	(length + mask) & !mask
+}
+
+/// Pad `plaintext` with Padmé, marking its end with a 0x80 byte.
+fn padme_pad(plaintext: &[u8]) -> Vec<u8> {
+	let mut padded = vec![0u8; padme_length(plaintext.len())];
+	padded[..plaintext.len()].copy_from_slice(plaintext);
+	padded[plaintext.len()] = 0x80;
+
+	padded
+}
+
+/// Remove the padding added by `padme_pad()`.
+fn padme_unpad(mut padded: Vec<u8>) -> Result<Vec<u8>, UnknownCryptoError> {
+	match crate::util::unpad(&padded, padded.len()) {
+		Ok(length) => {
+			padded.truncate(length);
+			Ok(padded)
+		}
+		Err(err) => {
+			padded.zeroize();
+			Err(err)
 		}
+	}
+}
 
-		#[test]
-		fn test_modified_ciphertext_err() {
-			let key = SecretKey::default();
-			let plaintext = "Secret message".as_bytes().to_vec();
+#[cfg(feature = "safe_api")]
+#[must_use]
+/// Authenticated encryption using XChaCha20Poly1305, with the plaintext
+/// padded using Padmé.
+pub fn seal_padded(
+	secret_key: &SecretKey,
+	plaintext: &[u8],
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	seal_padded_with_rng(secret_key, plaintext, &mut OsRng)
+}
 
-			let mut dst_ciphertext = seal(&key, &plaintext).unwrap();
-			// Modify ciphertext
-			dst_ciphertext[25] ^= 1;
-			assert!(open(&key, &dst_ciphertext).is_err());
+#[must_use]
+/// Authenticated encryption using XChaCha20Poly1305, with the plaintext
+/// padded using Padmé and the nonce generated by `rng`.
+pub fn seal_padded_with_rng<R: SecureRandom + ?Sized>(
+	secret_key: &SecretKey,
+	plaintext: &[u8],
+	rng: &mut R,
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	let mut padded = padme_pad(plaintext);
+	let sealed = seal_with_rng(secret_key, &padded, rng);
+	padded.zeroize();
+
+	sealed
+}
+
+#[must_use]
+/// Authenticated decryption using XChaCha20Poly1305, removing the padding
+/// added by `seal_padded()`.
+pub fn open_padded(
+	secret_key: &SecretKey,
+	ciphertext_with_tag_and_header: &[u8],
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	padme_unpad(open(secret_key, ciphertext_with_tag_and_header)?)
+}
+
+/// The version of the encoding of values sealed with `seal_value()`. It is
+/// the first byte of their plaintext.
+#[cfg(feature = "serde")]
+const VALUE_FORMAT_VERSION: u8 = 1;
+
+#[cfg(all(feature = "serde", feature = "safe_api"))]
+#[must_use]
+/// Serialize `value` with a canonical encoding, and seal it with `seal()`.
+pub fn seal_value<T: serde::Serialize + ?Sized>(
+	secret_key: &SecretKey,
+	value: &T,
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	seal_value_with_rng(secret_key, value, &mut OsRng)
+}
+
+#[cfg(feature = "serde")]
+#[must_use]
+/// Serialize `value` with a canonical encoding, and seal it with
+/// `seal_with_rng()` and `rng`.
+pub fn seal_value_with_rng<T: serde::Serialize + ?Sized, R: SecureRandom + ?Sized>(
+	secret_key: &SecretKey,
+	value: &T,
+	rng: &mut R,
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	let mut encoded = vec![VALUE_FORMAT_VERSION];
+	let sealed = match crate::canonical::to_vec(value, &mut encoded) {
+		Ok(()) => seal_with_rng(secret_key, &encoded, rng),
+		Err(err) => Err(err),
+	};
+	encoded.zeroize();
+
+	sealed
+}
+
+#[cfg(feature = "serde")]
+#[must_use]
+/// Open a message sealed with `seal_value()`, and deserialize the value.
+pub fn open_value<T: serde::de::DeserializeOwned>(
+	secret_key: &SecretKey,
+	ciphertext_with_tag_and_header: &[u8],
+) -> Result<T, UnknownCryptoError> {
+	let mut encoded = open(secret_key, ciphertext_with_tag_and_header)?;
+	let value = match encoded.split_first() {
+		Some((&VALUE_FORMAT_VERSION, rest)) => crate::canonical::from_slice(rest),
+		_ => Err(UnknownCryptoError::InvalidEncoding),
+	};
+	encoded.zeroize();
+
+	value
+}
+
+/// A set of keys for key rotation: a current key that seals, and older keys
+/// that are still tried when opening.
+#[derive(Debug)]
+pub struct MultiKey {
+	keys: Vec<SecretKey>,
+}
+
+impl MultiKey {
+	#[must_use]
+	/// Create a set from `keys`, the current key first, followed by the older
+	/// keys in the order they should be tried.
+	pub fn new(keys: Vec<SecretKey>) -> Result<Self, UnknownCryptoError> {
+		if keys.is_empty() {
+			return Err(UnknownCryptoError::InvalidLength);
 		}
 
-		#[test]
-		fn test_modified_tag_err() {
-			let key = SecretKey::default();
-			let plaintext = "Secret message".as_bytes().to_vec();
+		Ok(Self { keys })
+	}
 
-			let mut dst_ciphertext = seal(&key, &plaintext).unwrap();
-			let dst_ciphertext_len = dst_ciphertext.len();
-			// Modify tag
-			dst_ciphertext[dst_ciphertext_len - 6] ^= 1;
-			assert!(open(&key, &dst_ciphertext).is_err());
+	/// The current key, which seals.
+	pub fn current(&self) -> &SecretKey {
+		&self.keys[0]
+	}
+
+	#[cfg(feature = "safe_api")]
+	#[must_use]
+	/// Authenticated encryption using XChaCha20Poly1305, with the current key.
+	pub fn seal(&self, plaintext: &[u8]) -> Result<Vec<u8>, UnknownCryptoError> {
+		seal(self.current(), plaintext)
+	}
+
+	#[must_use]
+	/// Authenticated encryption using XChaCha20Poly1305, with the current key
+	/// and the nonce generated by `rng`.
+	pub fn seal_with_rng<R: SecureRandom + ?Sized>(
+		&self,
+		plaintext: &[u8],
+		rng: &mut R,
+	) -> Result<Vec<u8>, UnknownCryptoError> {
+		seal_with_rng(self.current(), plaintext, rng)
+	}
+
+	#[must_use]
+	/// Authenticated decryption using XChaCha20Poly1305, with each key in
+	/// turn. Returns the plaintext and the index of the key that opened it,
+	/// which is `0` for the current key.
+	pub fn open(
+		&self,
+		ciphertext_with_tag_and_header: &[u8],
+	) -> Result<(Vec<u8>, usize), UnknownCryptoError> {
+		// `+ 1` to avoid empty ciphertexts
+		if ciphertext_with_tag_and_header.len() < (SEAL_OVERHEAD + 1) {
+			return Err(UnknownCryptoError::InvalidLength);
 		}
 
-		#[test]
-		fn test_diff_secret_key_err() {
-			let key = SecretKey::default();
-			let plaintext = "Secret message".as_bytes().to_vec();
+		let mut dst_out = vec![0u8; ciphertext_with_tag_and_header.len() - SEAL_OVERHEAD];
+		for (index, key) in self.keys.iter().enumerate() {
+			if open_into(key, ciphertext_with_tag_and_header, &mut dst_out).is_ok() {
+				return Ok((dst_out, index));
+			}
+		}
 
-			let dst_ciphertext = seal(&key, &plaintext).unwrap();
-			let bad_key = SecretKey::default();
-			assert!(open(&bad_key, &dst_ciphertext).is_err());
+		Err(UnknownCryptoError::InvalidTag)
+	}
+}
+
+/// The size of the header that starts a session.
+pub const SESSION_HEADER_SIZE: usize = 16;
+
+/// The number of bytes a message sealed by a `SealingSession` is longer than
+/// its plaintext: the 16 byte Poly1305 tag.
+pub const SESSION_SEAL_OVERHEAD: usize = POLY1305_OUTSIZE;
+
+/// Context used to derive the nonce key of counter-based sessions.
+const SESSION_NONCE_KEY_CONTEXT: &[u8] = b"orion aead session nonce key";
+
+/// Derive the header of the session numbered `session_counter` under
+/// `secret_key`.
+fn derive_session_header(
+	secret_key: &SecretKey,
+	session_counter: u64,
+) -> Result<[u8; SESSION_HEADER_SIZE], UnknownCryptoError> {
+	let mut state = blake2b::init(
+		Some(&blake2b::SecretKey::from_slice(
+			secret_key.unprotected_as_bytes(),
+		)?),
+		32,
+	)?;
+	state.update(SESSION_NONCE_KEY_CONTEXT)?;
+	let nonce_key = blake2b::SecretKey::from_slice(state.finalize()?.as_ref())?;
+
+	let mut header = [0u8; SESSION_HEADER_SIZE];
+	header.copy_from_slice(
+		&derive_xnonce(&nonce_key, session_counter)?.as_ref()[..SESSION_HEADER_SIZE],
+	);
+
+	Ok(header)
+}
+
+/// Context used to derive the next key in a key ratchet.
+const REKEY_CONTEXT: &[u8] = b"orion aead rekey";
+
+/// Derive the key that follows `secret_key` in a key ratchet.
+fn next_key(secret_key: &[u8]) -> Result<[u8; 32], UnknownCryptoError> {
+	let mut state = blake2b::init(Some(&blake2b::SecretKey::from_slice(secret_key)?), 32)?;
+	state.update(REKEY_CONTEXT)?;
+	let mut key = [0u8; 32];
+	key.copy_from_slice(state.finalize()?.as_ref());
+
+	Ok(key)
+}
+
+/// Derive the session key that follows `secret_key`.
+fn next_session_key(
+	secret_key: &chacha20::SecretKey,
+) -> Result<chacha20::SecretKey, UnknownCryptoError> {
+	let mut key = next_key(secret_key.unprotected_as_bytes())?;
+	let next = chacha20::SecretKey::from(key);
+	key.zeroize();
+
+	Ok(next)
+}
+
+#[must_use]
+/// Ratchet `secret_key` forward, returning the next key. `secret_key` is
+/// wiped when it is dropped.
+pub fn ratchet_key(secret_key: SecretKey) -> Result<SecretKey, UnknownCryptoError> {
+	if secret_key.get_length() != 32 {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let mut key = next_key(secret_key.unprotected_as_bytes())?;
+	let next = SecretKey::from_slice(&key);
+	key.zeroize();
+
+	next
+}
+
+/// Build the XChaCha20 nonce of message number `counter` in a session.
+fn session_nonce(header: &[u8; SESSION_HEADER_SIZE], counter: u64) -> Nonce {
+	let mut nonce = [0u8; XCHACHA_NONCESIZE];
+	nonce[..SESSION_HEADER_SIZE].copy_from_slice(header);
+	nonce[SESSION_HEADER_SIZE..].copy_from_slice(&counter.to_be_bytes());
+
+	Nonce::from(nonce)
+}
+
+/// A session that seals an ordered sequence of messages under one key.
+pub struct SealingSession {
+	secret_key: chacha20::SecretKey,
+	header: [u8; SESSION_HEADER_SIZE],
+	counter: u64,
+}
+
+impl core::fmt::Debug for SealingSession {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(
+			f,
+			"SealingSession {{ secret_key: ***OMITTED***, header: {:?}, counter: {:?} }}",
+			self.header, self.counter
+		)
+	}
+}
+
+impl SealingSession {
+	#[cfg(feature = "safe_api")]
+	#[must_use]
+	/// Start a new session with a random header.
+	pub fn new(secret_key: &SecretKey) -> Result<Self, UnknownCryptoError> {
+		Self::new_with_rng(secret_key, &mut OsRng)
+	}
+
+	#[must_use]
+	/// Start a new session, with the header generated by `rng`.
+	pub fn new_with_rng<R: SecureRandom + ?Sized>(
+		secret_key: &SecretKey,
+		rng: &mut R,
+	) -> Result<Self, UnknownCryptoError> {
+		let mut header = [0u8; SESSION_HEADER_SIZE];
+		rng.fill_bytes(&mut header)?;
+
+		Ok(Self {
+			secret_key: chacha20::SecretKey::from_slice(secret_key.unprotected_as_bytes())?,
+			header,
+			counter: 0,
+		})
+	}
+
+	#[must_use]
+	/// Start a new session, with the header derived from `secret_key` and
+	/// `session_counter`.
+	pub fn new_with_counter(
+		secret_key: &SecretKey,
+		session_counter: u64,
+	) -> Result<Self, UnknownCryptoError> {
+		Ok(Self {
+			secret_key: chacha20::SecretKey::from_slice(secret_key.unprotected_as_bytes())?,
+			header: derive_session_header(secret_key, session_counter)?,
+			counter: 0,
+		})
+	}
+
+	#[must_use]
+	/// The header that an `OpeningSession` needs to open messages from this
+	/// session.
+	pub fn header(&self) -> [u8; SESSION_HEADER_SIZE] {
+		self.header
+	}
+
+	#[must_use]
+	/// Authenticated encryption of the next message in the session.
+	pub fn seal(&mut self, plaintext: &[u8]) -> Result<Vec<u8>, UnknownCryptoError> {
+		if plaintext.is_empty() {
+			return Err(UnknownCryptoError::InvalidLength);
 		}
 
-		#[test]
-		fn test_secret_length_err() {
-			let key = SecretKey::generate(31).unwrap();
+		let mut dst_out = vec![0u8; plaintext.len() + SESSION_SEAL_OVERHEAD];
+		self.seal_into(plaintext, &mut dst_out)?;
+
+		Ok(dst_out)
+	}
+
+	#[must_use]
+	/// Authenticated encryption of the next message in the session, writing to
+	/// `dst_out`. Returns the number of bytes written.
+	pub fn seal_into(
+		&mut self,
+		plaintext: &[u8],
+		dst_out: &mut [u8],
+	) -> Result<usize, UnknownCryptoError> {
+		if plaintext.is_empty() {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+		if self.counter == u64::max_value() {
+			return Err(UnknownCryptoError::NonceCounterOverflow);
+		}
+		if dst_out.len() < plaintext.len() + SESSION_SEAL_OVERHEAD {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+
+		aead::xchacha20poly1305::seal(
+			&self.secret_key,
+			&session_nonce(&self.header, self.counter),
+			plaintext,
+			None,
+			dst_out,
+		)?;
+		self.counter += 1;
+
+		Ok(plaintext.len() + SESSION_SEAL_OVERHEAD)
+	}
+
+	#[must_use]
+	/// Ratchet the key of the session forward and wipe the old key. The
+	/// `OpeningSession` must call `rekey()` after the same message.
+	pub fn rekey(&mut self) -> Result<(), UnknownCryptoError> {
+		self.secret_key = next_session_key(&self.secret_key)?;
+
+		Ok(())
+	}
+
+	#[must_use]
+	/// Authenticated encryption of the next message in the session, with the
+	/// plaintext padded using Padmé.
+	pub fn seal_padded(&mut self, plaintext: &[u8]) -> Result<Vec<u8>, UnknownCryptoError> {
+		let mut padded = padme_pad(plaintext);
+		let sealed = self.seal(&padded);
+		padded.zeroize();
+
+		sealed
+	}
+}
+
+/// A session that opens the messages of a `SealingSession`, in the order they
+/// were sealed.
+pub struct OpeningSession {
+	secret_key: chacha20::SecretKey,
+	header: [u8; SESSION_HEADER_SIZE],
+	counter: u64,
+}
+
+impl core::fmt::Debug for OpeningSession {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(
+			f,
+			"OpeningSession {{ secret_key: ***OMITTED***, header: {:?}, counter: {:?} }}",
+			self.header, self.counter
+		)
+	}
+}
+
+impl OpeningSession {
+	#[must_use]
+	/// Start opening the messages of the session that produced `header`.
+	pub fn new(secret_key: &SecretKey, header: &[u8]) -> Result<Self, UnknownCryptoError> {
+		if header.len() != SESSION_HEADER_SIZE {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+
+		let mut session = Self {
+			secret_key: chacha20::SecretKey::from_slice(secret_key.unprotected_as_bytes())?,
+			header: [0u8; SESSION_HEADER_SIZE],
+			counter: 0,
+		};
+		session.header.copy_from_slice(header);
+
+		Ok(session)
+	}
+
+	#[must_use]
+	/// Start opening the messages of the session that was started with
+	/// `SealingSession::new_with_counter()` and `session_counter`.
+	pub fn new_with_counter(
+		secret_key: &SecretKey,
+		session_counter: u64,
+	) -> Result<Self, UnknownCryptoError> {
+		Self::new(
+			secret_key,
+			&derive_session_header(secret_key, session_counter)?,
+		)
+	}
+
+	#[must_use]
+	/// Authenticated decryption of the next message in the session.
+	pub fn open(&mut self, ciphertext_with_tag: &[u8]) -> Result<Vec<u8>, UnknownCryptoError> {
+		// `+ 1` to avoid empty ciphertexts
+		if ciphertext_with_tag.len() < (SESSION_SEAL_OVERHEAD + 1) {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+
+		let mut dst_out = vec![0u8; ciphertext_with_tag.len() - SESSION_SEAL_OVERHEAD];
+		self.open_into(ciphertext_with_tag, &mut dst_out)?;
+
+		Ok(dst_out)
+	}
+
+	#[must_use]
+	/// Authenticated decryption of the next message in the session, writing to
+	/// `dst_out`. Returns the number of bytes written.
+	pub fn open_into(
+		&mut self,
+		ciphertext_with_tag: &[u8],
+		dst_out: &mut [u8],
+	) -> Result<usize, UnknownCryptoError> {
+		// `+ 1` to avoid empty ciphertexts
+		if ciphertext_with_tag.len() < (SESSION_SEAL_OVERHEAD + 1) {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+		if self.counter == u64::max_value() {
+			return Err(UnknownCryptoError::NonceCounterOverflow);
+		}
+		if dst_out.len() < ciphertext_with_tag.len() - SESSION_SEAL_OVERHEAD {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+
+		aead::xchacha20poly1305::open(
+			&self.secret_key,
+			&session_nonce(&self.header, self.counter),
+			ciphertext_with_tag,
+			None,
+			dst_out,
+		)?;
+		self.counter += 1;
+
+		Ok(ciphertext_with_tag.len() - SESSION_SEAL_OVERHEAD)
+	}
+
+	#[must_use]
+	/// Ratchet the key of the session forward and wipe the old key. The
+	/// `SealingSession` must call `rekey()` after the same message.
+	pub fn rekey(&mut self) -> Result<(), UnknownCryptoError> {
+		self.secret_key = next_session_key(&self.secret_key)?;
+
+		Ok(())
+	}
+
+	#[must_use]
+	/// Authenticated decryption of the next message in the session, removing
+	/// the padding added by `SealingSession::seal_padded()`.
+	pub fn open_padded(
+		&mut self,
+		ciphertext_with_tag: &[u8],
+	) -> Result<Vec<u8>, UnknownCryptoError> {
+		padme_unpad(self.open(ciphertext_with_tag)?)
+	}
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+#[cfg(feature = "safe_api")]
+mod public {
+	use super::*;
+
+	mod test_seal_open {
+		use super::*;
+
+		#[test]
+		fn test_seal_with_rng_deterministic() {
+			use crate::util::HmacDrbg;
+
+			let key = SecretKey::from_slice(&[0x42u8; 32]).unwrap();
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			let first = seal_with_rng(&key, b"Secret message", &mut rng).unwrap();
+			let second = seal_with_rng(&key, b"Secret message", &mut rng).unwrap();
+			assert_ne!(first, second);
+
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			assert_eq!(
+				seal_with_rng(&key, b"Secret message", &mut rng).unwrap(),
+				first
+			);
+			assert_eq!(open(&key, &first).unwrap(), b"Secret message");
+		}
+
+		#[test]
+		fn test_auth_enc_encryption_decryption() {
+			let key = SecretKey::default();
+			let plaintext = "Secret message".as_bytes().to_vec();
+
+			let dst_ciphertext = seal(&key, &plaintext).unwrap();
+			assert!(dst_ciphertext.len() == plaintext.len() + (26 + 16));
+			let dst_plaintext = open(&key, &dst_ciphertext).unwrap();
+			assert!(dst_plaintext.len() == plaintext.len());
+			assert_eq!(plaintext, dst_plaintext);
+		}
+
+		#[test]
+		fn test_auth_enc_plaintext_empty_err() {
+			let key = SecretKey::default();
+			let plaintext = "".as_bytes().to_vec();
+
+			assert_eq!(
+				seal(&key, &plaintext),
+				Err(UnknownCryptoError::InvalidLength)
+			);
+		}
+
+		#[test]
+		fn test_auth_enc_ciphertext_less_than_43_err() {
+			let key = SecretKey::default();
+			let ciphertext = [0u8; 42];
+
+			assert_eq!(
+				open(&key, &ciphertext),
+				Err(UnknownCryptoError::InvalidLength)
+			);
+		}
+
+		#[test]
+		fn test_modified_nonce_err() {
+			let key = SecretKey::default();
+			let plaintext = "Secret message".as_bytes().to_vec();
+
+			let mut dst_ciphertext = seal(&key, &plaintext).unwrap();
+			// Modify nonce
+			dst_ciphertext[10] ^= 1;
+			assert_eq!(
+				open(&key, &dst_ciphertext),
+				Err(UnknownCryptoError::InvalidTag)
+			);
+		}
+
+		#[test]
+		fn test_modified_ciphertext_err() {
+			let key = SecretKey::default();
+			let plaintext = "Secret message".as_bytes().to_vec();
+
+			let mut dst_ciphertext = seal(&key, &plaintext).unwrap();
+			// Modify ciphertext
+			dst_ciphertext[27] ^= 1;
+			assert_eq!(
+				open(&key, &dst_ciphertext),
+				Err(UnknownCryptoError::InvalidTag)
+			);
+		}
+
+		#[test]
+		fn test_modified_tag_err() {
+			let key = SecretKey::default();
+			let plaintext = "Secret message".as_bytes().to_vec();
+
+			let mut dst_ciphertext = seal(&key, &plaintext).unwrap();
+			let dst_ciphertext_len = dst_ciphertext.len();
+			// Modify tag
+			dst_ciphertext[dst_ciphertext_len - 6] ^= 1;
+			assert_eq!(
+				open(&key, &dst_ciphertext),
+				Err(UnknownCryptoError::InvalidTag)
+			);
+		}
+
+		#[test]
+		fn test_diff_secret_key_err() {
+			let key = SecretKey::default();
+			let plaintext = "Secret message".as_bytes().to_vec();
+
+			let dst_ciphertext = seal(&key, &plaintext).unwrap();
+			let bad_key = SecretKey::default();
+			assert_eq!(
+				open(&bad_key, &dst_ciphertext),
+				Err(UnknownCryptoError::InvalidTag)
+			);
+		}
+
+		#[test]
+		fn test_secret_length_err() {
+			let key = SecretKey::generate(31).unwrap();
 			let plaintext = "Secret message Secret message Secret message Secret message "
 				.as_bytes()
 				.to_vec();
 
-			assert!(seal(&key, &plaintext).is_err());
-			assert!(open(&key, &plaintext).is_err());
+			assert_eq!(
+				seal(&key, &plaintext),
+				Err(UnknownCryptoError::InvalidLength)
+			);
+			assert_eq!(
+				open(&key, &plaintext),
+				Err(UnknownCryptoError::InvalidLength)
+			);
+		}
+	}
+
+	mod test_seal_open_into {
+		use super::*;
+
+		#[test]
+		fn test_seal_open_into_same_as_allocating() {
+			use crate::util::HmacDrbg;
+
+			let key = SecretKey::from_slice(&[0x42u8; 32]).unwrap();
+			let plaintext = b"Secret message";
+
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			let expected = seal_with_rng(&key, plaintext, &mut rng).unwrap();
+
+			// A destination buffer longer than needed is fine.
+			let mut sealed = [0u8; 64];
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			let sealed_len = seal_into_with_rng(&key, plaintext, &mut sealed, &mut rng).unwrap();
+			assert_eq!(sealed_len, plaintext.len() + SEAL_OVERHEAD);
+			assert_eq!(&sealed[..sealed_len], &expected[..]);
+
+			let mut opened = [0u8; 64];
+			let opened_len = open_into(&key, &sealed[..sealed_len], &mut opened).unwrap();
+			assert_eq!(&opened[..opened_len], &plaintext[..]);
+			assert_eq!(open(&key, &sealed[..sealed_len]).unwrap(), &plaintext[..]);
+		}
+
+		#[test]
+		fn test_dst_out_length() {
+			let key = SecretKey::default();
+			let plaintext = [1u8; 10];
+
+			let mut sealed = [0u8; 10 + SEAL_OVERHEAD];
+			assert!(seal_into(&key, &plaintext, &mut sealed[..10 + SEAL_OVERHEAD - 1]).is_err());
+			assert!(seal_into(&key, &plaintext, &mut sealed).is_ok());
+			assert!(seal_into(&key, &[], &mut sealed).is_err());
+
+			let mut opened = [0u8; 10];
+			assert!(open_into(&key, &sealed, &mut opened[..9]).is_err());
+			assert_eq!(open_into(&key, &sealed, &mut opened).unwrap(), 10);
+			assert!(open_into(&key, &sealed[..SEAL_OVERHEAD], &mut opened).is_err());
+		}
+
+		#[test]
+		fn test_open_into_modified_err() {
+			let key = SecretKey::default();
+			let mut sealed = [0u8; 10 + SEAL_OVERHEAD];
+			seal_into(&key, &[1u8; 10], &mut sealed).unwrap();
+			sealed[SEAL_HEADER_SIZE] ^= 1;
+
+			let mut opened = [0u8; 10];
+			assert!(open_into(&key, &sealed, &mut opened).is_err());
+		}
+	}
+
+	mod test_detached {
+		use super::*;
+
+		#[test]
+		fn test_roundtrip() {
+			let key = SecretKey::default();
+			let (ciphertext, tag) = seal_detached(&key, b"Secret message").unwrap();
+			assert_eq!(ciphertext.len(), SEAL_HEADER_SIZE + 14);
+			assert_eq!(
+				open_detached(&key, &ciphertext, &tag).unwrap(),
+				b"Secret message"
+			);
+
+			// The same as `open()` of the ciphertext followed by the tag.
+			let mut combined = ciphertext.clone();
+			combined.extend_from_slice(tag.unprotected_as_bytes());
+			assert_eq!(open(&key, &combined).unwrap(), b"Secret message");
+		}
+
+		#[test]
+		fn test_modified_err() {
+			let key = SecretKey::default();
+			let (ciphertext, tag) = seal_detached(&key, b"Secret message").unwrap();
+
+			for idx in 0..ciphertext.len() {
+				let mut modified = ciphertext.clone();
+				modified[idx] ^= 1;
+				// The format version and algorithm identifier are checked
+				// before the tag.
+				let expected = if idx < 2 {
+					UnknownCryptoError::InvalidEncoding
+				} else {
+					UnknownCryptoError::InvalidTag
+				};
+				assert_eq!(open_detached(&key, &modified, &tag), Err(expected));
+			}
+
+			let mut modified_tag = [0u8; POLY1305_OUTSIZE];
+			modified_tag.copy_from_slice(tag.unprotected_as_bytes());
+			modified_tag[0] ^= 1;
+			assert_eq!(
+				open_detached(&key, &ciphertext, &Tag::from(modified_tag)),
+				Err(UnknownCryptoError::InvalidTag)
+			);
+		}
+
+		#[test]
+		fn test_lengths_err() {
+			let key = SecretKey::default();
+			assert!(seal_detached(&key, b"").is_err());
+			let tag = Tag::from([0u8; POLY1305_OUTSIZE]);
+			assert_eq!(
+				open_detached(&key, &[0u8; SEAL_HEADER_SIZE], &tag),
+				Err(UnknownCryptoError::InvalidLength)
+			);
+		}
+	}
+
+	mod test_with_nonce {
+		use super::*;
+
+		#[test]
+		fn test_roundtrip() {
+			let key = SecretKey::default();
+			let nonce = Nonce::from([0x01u8; XCHACHA_NONCESIZE]);
+			let sealed = seal_with_nonce(&key, &nonce, b"Secret message").unwrap();
+			assert_eq!(sealed.len(), 14 + POLY1305_OUTSIZE);
+			assert_eq!(
+				open_with_nonce(&key, &nonce, &sealed).unwrap(),
+				b"Secret message"
+			);
+
+			// The same as the legacy format, without the nonce.
+			let mut legacy = nonce.as_ref().to_vec();
+			legacy.extend_from_slice(&sealed);
+			assert_eq!(open_legacy(&key, &legacy).unwrap(), b"Secret message");
+		}
+
+		#[test]
+		fn test_wrong_nonce_err() {
+			let key = SecretKey::default();
+			let sealed = seal_with_nonce(
+				&key,
+				&Nonce::from([0x01u8; XCHACHA_NONCESIZE]),
+				b"Secret message",
+			)
+			.unwrap();
+			assert_eq!(
+				open_with_nonce(&key, &Nonce::from([0x02u8; XCHACHA_NONCESIZE]), &sealed),
+				Err(UnknownCryptoError::InvalidTag)
+			);
+		}
+
+		#[test]
+		fn test_lengths_err() {
+			let key = SecretKey::default();
+			let nonce = Nonce::from([0x01u8; XCHACHA_NONCESIZE]);
+			assert!(seal_with_nonce(&key, &nonce, b"").is_err());
+			assert!(open_with_nonce(&key, &nonce, &[0u8; POLY1305_OUTSIZE]).is_err());
+		}
+	}
+
+	mod test_padded {
+		use super::*;
+
+		#[test]
+		fn test_padme_length() {
+			// The padded length includes the byte that marks the end.
+			let lengths = [
+				(0, 1),
+				(1, 2),
+				(2, 3),
+				(8, 10),
+				(9, 10),
+				(99, 104),
+				(999, 1024),
+				(1000, 1024),
+				(100_000, 100_352),
+			];
+			for (length, padded_length) in lengths.iter() {
+				assert_eq!(padme_length(*length), *padded_length);
+			}
+			// The overhead is at most 12%.
+			for length in 0..5000 {
+				assert!(padme_length(length) <= (length + 1) + (length + 1) * 12 / 100 + 1);
+			}
+		}
+
+		#[test]
+		fn test_roundtrip() {
+			let key = SecretKey::default();
+			for length in 0..300 {
+				let plaintext = vec![0x80u8; length];
+				let sealed = seal_padded(&key, &plaintext).unwrap();
+				assert_eq!(sealed.len(), padme_length(length) + SEAL_OVERHEAD);
+				assert_eq!(open_padded(&key, &sealed).unwrap(), plaintext);
+			}
+		}
+
+		#[test]
+		fn test_hides_length() {
+			let key = SecretKey::default();
+			assert_eq!(
+				seal_padded(&key, &[0u8; 993]).unwrap().len(),
+				seal_padded(&key, &[0u8; 1000]).unwrap().len()
+			);
+		}
+
+		#[test]
+		fn test_unpadded_err() {
+			let key = SecretKey::default();
+			let sealed = seal(&key, b"Secret message").unwrap();
+			assert!(open_padded(&key, &sealed).is_err());
+
+			let sealed = seal(&key, b"Secret message\x80").unwrap();
+			assert_eq!(open_padded(&key, &sealed).unwrap(), b"Secret message");
+		}
+
+		#[test]
+		fn test_modified_err() {
+			let key = SecretKey::default();
+			let mut sealed = seal_padded(&key, b"Secret message").unwrap();
+			sealed[30] ^= 1;
+			assert!(open_padded(&key, &sealed).is_err());
+		}
+
+		#[test]
+		fn test_sessions() {
+			let key = SecretKey::default();
+			let mut sealer = SealingSession::new(&key).unwrap();
+			let mut opener = OpeningSession::new(&key, &sealer.header()).unwrap();
+
+			let first = sealer.seal_padded(b"").unwrap();
+			let second = sealer.seal_padded(b"Second message").unwrap();
+			let third = sealer.seal(b"Third message").unwrap();
+			assert_eq!(first.len(), 1 + SESSION_SEAL_OVERHEAD);
+			assert_eq!(second.len(), 16 + SESSION_SEAL_OVERHEAD);
+
+			assert_eq!(opener.open_padded(&first).unwrap(), b"");
+			assert!(opener.open_padded(&first).is_err());
+			assert_eq!(opener.open_padded(&second).unwrap(), b"Second message");
+			assert!(opener.open_padded(&third).is_err());
+		}
+	}
+
+	mod test_header {
+		use super::*;
+		use crate::util::HmacDrbg;
+
+		#[test]
+		fn test_header() {
+			let key = SecretKey::default();
+			let sealed = seal(&key, b"Secret message").unwrap();
+			assert_eq!(&sealed[..2], &[FORMAT_VERSION, ALGORITHM_XCHACHA20POLY1305]);
+		}
+
+		#[test]
+		fn test_same_as_seal_with_ad() {
+			let key = SecretKey::from_slice(&[0x42u8; 32]).unwrap();
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			let sealed = seal_with_rng(&key, b"Secret message", &mut rng).unwrap();
+
+			let mut expected = [0u8; 14 + POLY1305_OUTSIZE];
+			aead::xchacha20poly1305::seal(
+				&chacha20::SecretKey::from_slice(&[0x42u8; 32]).unwrap(),
+				&Nonce::from_slice(&sealed[2..SEAL_HEADER_SIZE]).unwrap(),
+				b"Secret message",
+				Some(&[1u8, 1u8]),
+				&mut expected,
+			)
+			.unwrap();
+			assert_eq!(&sealed[SEAL_HEADER_SIZE..], &expected[..]);
+		}
+
+		#[test]
+		fn test_header_err() {
+			let key = SecretKey::default();
+			let sealed = seal(&key, b"Secret message").unwrap();
+
+			for index in 0..SEAL_HEADER_SIZE {
+				let mut modified = sealed.clone();
+				modified[index] ^= 1;
+				assert!(open(&key, &modified).is_err());
+			}
+			let mut modified = sealed.clone();
+			modified[0] = 2;
+			assert_eq!(
+				open(&key, &modified).unwrap_err(),
+				UnknownCryptoError::InvalidEncoding
+			);
+			let mut modified = sealed;
+			modified[1] = 2;
+			assert_eq!(
+				open(&key, &modified).unwrap_err(),
+				UnknownCryptoError::InvalidEncoding
+			);
+		}
+	}
+
+	mod test_legacy {
+		use super::*;
+
+		/// Seal `plaintext` in the legacy format, without a header.
+		fn seal_legacy(key: &SecretKey, plaintext: &[u8]) -> Vec<u8> {
+			let nonce = Nonce::from([0x01u8; XCHACHA_NONCESIZE]);
+			let mut sealed = nonce.as_ref().to_vec();
+			sealed.extend_from_slice(&seal_with_nonce(key, &nonce, plaintext).unwrap());
+
+			sealed
+		}
+
+		#[test]
+		fn test_open_legacy() {
+			let key = SecretKey::default();
+			let legacy = seal_legacy(&key, b"Secret message");
+			assert_eq!(legacy.len(), 14 + LEGACY_SEAL_OVERHEAD);
+			assert_eq!(open_legacy(&key, &legacy).unwrap(), b"Secret message");
+
+			assert!(open_legacy(&SecretKey::default(), &legacy).is_err());
+			assert!(open_legacy(&key, &legacy[..LEGACY_SEAL_OVERHEAD]).is_err());
+		}
+
+		#[test]
+		fn test_not_interchangeable() {
+			let key = SecretKey::default();
+			let legacy = seal_legacy(&key, b"Secret message");
+			let sealed = seal(&key, b"Secret message").unwrap();
+
+			assert!(open(&key, &legacy).is_err());
+			assert!(open_legacy(&key, &sealed).is_err());
+		}
+
+		#[test]
+		fn test_upgrade() {
+			let key = SecretKey::default();
+			let legacy = seal_legacy(&key, b"Secret message");
+			let upgraded = upgrade_legacy(&key, &legacy).unwrap();
+
+			assert_eq!(open(&key, &upgraded).unwrap(), b"Secret message");
+			assert!(upgrade_legacy(&key, &upgraded).is_err());
+			assert!(upgrade_legacy(&SecretKey::default(), &legacy).is_err());
+		}
+	}
+
+	#[cfg(feature = "serde")]
+	mod test_value {
+		use super::*;
+		use std::collections::BTreeMap;
+
+		#[test]
+		fn test_roundtrip() {
+			let key = SecretKey::default();
+			let mut value = BTreeMap::new();
+			value.insert("name".to_string(), (42u32, Some(vec![1.5f64])));
+			value.insert("other".to_string(), (0u32, None));
+
+			let sealed = seal_value(&key, &value).unwrap();
+			let opened: BTreeMap<String, (u32, Option<Vec<f64>>)> =
+				open_value(&key, &sealed).unwrap();
+			assert_eq!(opened, value);
+
+			// A unit value has an empty encoding, but is still sealed.
+			let sealed = seal_value(&key, &()).unwrap();
+			open_value::<()>(&key, &sealed).unwrap();
+		}
+
+		#[test]
+		fn test_format_version() {
+			let key = SecretKey::default();
+			let sealed = seal_value(&key, &7u8).unwrap();
+			assert_eq!(open(&key, &sealed).unwrap(), [VALUE_FORMAT_VERSION, 7]);
+
+			let sealed = seal(&key, &[2u8, 7]).unwrap();
+			assert!(open_value::<u8>(&key, &sealed).is_err());
+		}
+
+		#[test]
+		fn test_wrong_type_err() {
+			let key = SecretKey::default();
+			let sealed = seal_value(&key, &7u8).unwrap();
+
+			assert!(open_value::<u16>(&key, &sealed).is_err());
+			assert!(open_value::<bool>(&key, &sealed).is_err());
+			assert!(open_value::<(u8, u8)>(&key, &sealed).is_err());
+			assert_eq!(open_value::<u8>(&key, &sealed).unwrap(), 7);
+		}
+
+		#[test]
+		fn test_modified_err() {
+			let key = SecretKey::default();
+			let mut sealed = seal_value(&key, "value").unwrap();
+			let last = sealed.len() - 1;
+			sealed[last] ^= 1;
+
+			assert!(open_value::<String>(&key, &sealed).is_err());
+			assert!(open_value::<String>(&SecretKey::default(), &sealed).is_err());
+		}
+	}
+
+	mod test_multi_key {
+		use super::*;
+
+		#[test]
+		fn test_new_empty_err() {
+			assert!(MultiKey::new(vec![]).is_err());
+		}
+
+		#[test]
+		fn test_open_reports_index() {
+			let old = SecretKey::from_slice(&[1u8; 32]).unwrap();
+			let older = SecretKey::from_slice(&[2u8; 32]).unwrap();
+			let sealed_old = seal(&old, b"old").unwrap();
+			let sealed_older = seal(&older, b"older").unwrap();
+
+			let keys = MultiKey::new(vec![
+				SecretKey::from_slice(&[0u8; 32]).unwrap(),
+				SecretKey::from_slice(&[1u8; 32]).unwrap(),
+				SecretKey::from_slice(&[2u8; 32]).unwrap(),
+			])
+			.unwrap();
+			let sealed_current = keys.seal(b"current").unwrap();
+
+			assert_eq!(
+				keys.open(&sealed_current).unwrap(),
+				(b"current".to_vec(), 0)
+			);
+			assert_eq!(keys.open(&sealed_old).unwrap(), (b"old".to_vec(), 1));
+			assert_eq!(keys.open(&sealed_older).unwrap(), (b"older".to_vec(), 2));
+			assert_eq!(open(keys.current(), &sealed_current).unwrap(), b"current");
+		}
+
+		#[test]
+		fn test_open_unknown_key_err() {
+			let keys = MultiKey::new(vec![SecretKey::default(), SecretKey::default()]).unwrap();
+			let sealed = seal(&SecretKey::default(), b"message").unwrap();
+
+			assert!(keys.open(&sealed).is_err());
+			assert!(keys.open(&sealed[..SEAL_OVERHEAD]).is_err());
+		}
+
+		#[test]
+		fn test_open_modified_err() {
+			let keys = MultiKey::new(vec![SecretKey::default()]).unwrap();
+			let mut sealed = keys.seal(b"message").unwrap();
+			sealed[SEAL_HEADER_SIZE] ^= 1;
+
+			assert!(keys.open(&sealed).is_err());
+		}
+
+		#[test]
+		#[cfg(not(feature = "unprotected_debug"))]
+		fn test_debug_omits_keys() {
+			let keys = MultiKey::new(vec![SecretKey::from_slice(&[0x42u8; 32]).unwrap()]).unwrap();
+			assert!(format!("{:?}", keys).contains("***OMITTED***"));
+		}
+	}
+
+	mod test_rekey {
+		use super::*;
+
+		#[test]
+		fn test_ratchet_key() {
+			let key = SecretKey::from_slice(&[0x42u8; 32]).unwrap();
+			let next = ratchet_key(key).unwrap();
+			assert!(next != SecretKey::from_slice(&[0x42u8; 32]).unwrap());
+			// Deterministic.
+			let again = ratchet_key(SecretKey::from_slice(&[0x42u8; 32]).unwrap()).unwrap();
+			assert!(next == again);
+
+			let sealed = seal(&next, b"Secret message").unwrap();
+			let after = ratchet_key(next).unwrap();
+			assert!(open(&after, &sealed).is_err());
+		}
+
+		#[test]
+		fn test_ratchet_key_length_err() {
+			let key = SecretKey::generate(64).unwrap();
+			assert_eq!(
+				ratchet_key(key).unwrap_err(),
+				UnknownCryptoError::InvalidLength
+			);
+		}
+
+		#[test]
+		fn test_session_rekey() {
+			let key = SecretKey::default();
+			let mut sealer = SealingSession::new(&key).unwrap();
+			let mut opener = OpeningSession::new(&key, &sealer.header()).unwrap();
+
+			for i in 1..5u8 {
+				let ciphertext = sealer.seal(&[i]).unwrap();
+				assert_eq!(opener.open(&ciphertext).unwrap(), [i]);
+				sealer.rekey().unwrap();
+				opener.rekey().unwrap();
+			}
+		}
+
+		#[test]
+		fn test_session_rekey_mismatch_err() {
+			let key = SecretKey::default();
+			let mut sealer = SealingSession::new(&key).unwrap();
+			let mut opener = OpeningSession::new(&key, &sealer.header()).unwrap();
+			let before = sealer.seal(b"Before").unwrap();
+			sealer.rekey().unwrap();
+			let after = sealer.seal(b"After").unwrap();
+
+			assert_eq!(opener.open(&before).unwrap(), b"Before");
+			// The opener has not ratcheted yet.
+			assert!(opener.open(&after).is_err());
+			opener.rekey().unwrap();
+			assert_eq!(opener.open(&after).unwrap(), b"After");
+		}
+
+		#[test]
+		fn test_session_rekey_differs_from_new_session() {
+			let key = SecretKey::default();
+			let mut rekeyed = SealingSession::new_with_counter(&key, 0).unwrap();
+			rekeyed.rekey().unwrap();
+			let mut fresh = SealingSession::new_with_counter(&key, 0).unwrap();
+			assert_ne!(rekeyed.seal(b"Same").unwrap(), fresh.seal(b"Same").unwrap());
+		}
+	}
+
+	mod test_sessions {
+		use super::*;
+
+		#[test]
+		fn test_session_seal_open() {
+			let key = SecretKey::default();
+			let mut sealer = SealingSession::new(&key).unwrap();
+			let mut opener = OpeningSession::new(&key, &sealer.header()).unwrap();
+
+			for i in 1..10 {
+				let plaintext = vec![i as u8; i * 7];
+				let ciphertext = sealer.seal(&plaintext).unwrap();
+				assert_eq!(ciphertext.len(), plaintext.len() + POLY1305_OUTSIZE);
+				assert_eq!(opener.open(&ciphertext).unwrap(), plaintext);
+			}
+		}
+
+		#[test]
+		fn test_session_unique_nonces() {
+			let key = SecretKey::default();
+			let mut sealer = SealingSession::new(&key).unwrap();
+			let first = sealer.seal(b"Same message").unwrap();
+			let second = sealer.seal(b"Same message").unwrap();
+			assert_ne!(first, second);
+
+			// Two sessions under the same key have different headers.
+			let mut other = SealingSession::new(&key).unwrap();
+			assert_ne!(other.header(), sealer.header());
+			assert_ne!(other.seal(b"Same message").unwrap(), first);
+		}
+
+		#[test]
+		fn test_session_new_with_rng_deterministic() {
+			use crate::util::HmacDrbg;
+
+			let key = SecretKey::from_slice(&[0x42u8; 32]).unwrap();
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			let mut first = SealingSession::new_with_rng(&key, &mut rng).unwrap();
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			let mut second = SealingSession::new_with_rng(&key, &mut rng).unwrap();
+
+			assert_eq!(first.header(), second.header());
+			assert_eq!(
+				first.seal(b"Message").unwrap(),
+				second.seal(b"Message").unwrap()
+			);
+		}
+
+		#[test]
+		fn test_session_new_with_counter() {
+			let key = SecretKey::default();
+			let mut sealer = SealingSession::new_with_counter(&key, 7).unwrap();
+			assert_eq!(
+				sealer.header(),
+				SealingSession::new_with_counter(&key, 7).unwrap().header()
+			);
+			assert_ne!(
+				sealer.header(),
+				SealingSession::new_with_counter(&key, 8).unwrap().header()
+			);
+			assert_ne!(
+				sealer.header(),
+				SealingSession::new_with_counter(&SecretKey::default(), 7)
+					.unwrap()
+					.header()
+			);
+
+			let ciphertext = sealer.seal(b"Message").unwrap();
+			let mut opener = OpeningSession::new_with_counter(&key, 7).unwrap();
+			assert_eq!(opener.open(&ciphertext).unwrap(), b"Message");
+			let mut opener = OpeningSession::new_with_counter(&key, 8).unwrap();
+			assert!(opener.open(&ciphertext).is_err());
+		}
+
+		#[test]
+		fn test_session_replay_reorder_drop_err() {
+			let key = SecretKey::default();
+			let mut sealer = SealingSession::new(&key).unwrap();
+			let mut opener = OpeningSession::new(&key, &sealer.header()).unwrap();
+			let first = sealer.seal(b"First").unwrap();
+			let second = sealer.seal(b"Second").unwrap();
+			let third = sealer.seal(b"Third").unwrap();
+
+			// Reordered.
+			assert!(opener.open(&second).is_err());
+			assert_eq!(opener.open(&first).unwrap(), b"First");
+			// Replayed.
+			assert!(opener.open(&first).is_err());
+			assert_eq!(opener.open(&second).unwrap(), b"Second");
+			assert_eq!(opener.open(&third).unwrap(), b"Third");
+
+			// Dropped.
+			let _ = sealer.seal(b"Fourth").unwrap();
+			let fifth = sealer.seal(b"Fifth").unwrap();
+			assert_eq!(opener.open(&fifth), Err(UnknownCryptoError::InvalidTag));
+		}
+
+		#[test]
+		fn test_session_wrong_header_or_key_err() {
+			let key = SecretKey::default();
+			let mut sealer = SealingSession::new(&key).unwrap();
+			let ciphertext = sealer.seal(b"Message").unwrap();
+
+			let mut header = sealer.header();
+			header[0] ^= 1;
+			let mut opener = OpeningSession::new(&key, &header).unwrap();
+			assert!(opener.open(&ciphertext).is_err());
+
+			let mut opener = OpeningSession::new(&SecretKey::default(), &sealer.header()).unwrap();
+			assert!(opener.open(&ciphertext).is_err());
+		}
+
+		#[test]
+		fn test_session_length_err() {
+			let key = SecretKey::default();
+			assert!(OpeningSession::new(&key, &[0u8; 15]).is_err());
+			assert!(OpeningSession::new(&key, &[0u8; 17]).is_err());
+			assert!(SealingSession::new(&SecretKey::from_slice(&[0u8; 16]).unwrap()).is_err());
+
+			let mut sealer = SealingSession::new(&key).unwrap();
+			let mut opener = OpeningSession::new(&key, &sealer.header()).unwrap();
+			assert!(sealer.seal(b"").is_err());
+			assert!(opener.open(&[0u8; 16]).is_err());
+		}
+
+		#[test]
+		fn test_session_counter_exhausted_err() {
+			let key = SecretKey::default();
+			let mut sealer = SealingSession::new(&key).unwrap();
+			let mut opener = OpeningSession::new(&key, &sealer.header()).unwrap();
+			sealer.counter = u64::max_value() - 1;
+			opener.counter = u64::max_value() - 1;
+
+			let last = sealer.seal(b"Last").unwrap();
+			assert_eq!(
+				sealer.seal(b"One too many"),
+				Err(UnknownCryptoError::NonceCounterOverflow)
+			);
+			assert_eq!(opener.open(&last).unwrap(), b"Last");
+			assert_eq!(
+				opener.open(&last),
+				Err(UnknownCryptoError::NonceCounterOverflow)
+			);
+		}
+
+		#[test]
+		fn test_session_seal_open_into() {
+			let key = SecretKey::default();
+			let mut sealer = SealingSession::new(&key).unwrap();
+			let mut sealer_alloc = SealingSession::new_with_counter(&key, 7).unwrap();
+			let mut opener = OpeningSession::new(&key, &sealer.header()).unwrap();
+			let mut opener_alloc = OpeningSession::new_with_counter(&key, 7).unwrap();
+
+			let mut sealed = [0u8; 9 + SESSION_SEAL_OVERHEAD];
+			let mut opened = [0u8; 9];
+			for i in 1..10 {
+				let plaintext = [i as u8; 9];
+
+				assert!(sealer
+					.seal_into(&plaintext, &mut sealed[..9 + SESSION_SEAL_OVERHEAD - 1])
+					.is_err());
+				let sealed_len = sealer.seal_into(&plaintext[..i], &mut sealed).unwrap();
+				assert_eq!(sealed_len, i + SESSION_SEAL_OVERHEAD);
+
+				assert!(opener
+					.open_into(&sealed[..sealed_len], &mut opened[..i - 1])
+					.is_err());
+				let opened_len = opener
+					.open_into(&sealed[..sealed_len], &mut opened)
+					.unwrap();
+				assert_eq!(&opened[..opened_len], &plaintext[..i]);
+
+				// Both kinds of methods move the session on in the same way.
+				let ciphertext = sealer_alloc.seal(&plaintext[..i]).unwrap();
+				let opened_len = opener_alloc.open_into(&ciphertext, &mut opened).unwrap();
+				assert_eq!(&opened[..opened_len], &plaintext[..i]);
+			}
+		}
+
+		#[test]
+		fn test_session_omitted_debug() {
+			let key = SecretKey::from_slice(&[0xabu8; 32]).unwrap();
+			let sealer = SealingSession::new(&key).unwrap();
+			let opener = OpeningSession::new(&key, &sealer.header()).unwrap();
+
+			assert!(format!("{:?}", sealer).contains("***OMITTED***"));
+			assert!(format!("{:?}", opener).contains("***OMITTED***"));
 		}
 	}
 
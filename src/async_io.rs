@@ -0,0 +1,805 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Asynchronous streaming encryption and hashing.
+//!
+//! # Use case:
+//! `orion::async_io` can be used to encrypt, decrypt and hash data that flows
+//! through asynchronous I/O, without moving it to a blocking thread.
+//!
+//! An example of this could be encrypting an upload as it is being received
+//! over a network connection.
+//!
+//! # About:
+//! - This module is only available with the `async` feature.
+//! - The wrappers implement the [`AsyncRead`] and [`AsyncWrite`] traits of
+//!   the `futures-io` crate. Tokio users can adapt them with `tokio-util`'s
+//!   `compat` module.
+//! - [`EncryptWriter`] and [`DecryptReader`] produce and consume the same
+//!   format as [`orion::file`], so data encrypted with one can be decrypted
+//!   with the other.
+//! - [`HashReader`] and [`HashWriter`] compute the same BLAKE2b-256 digest as
+//!   [`orion::hash::digest()`] over all data passing through them.
+//!
+//! # Parameters:
+//! - `secret_key`: The secret key used to encrypt and decrypt.
+//! - `inner`: The wrapped reader or writer.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `secret_key` is not 32 bytes.
+//! - [`EncryptWriter`] is closed without any data having been written.
+//! - Writing to an [`EncryptWriter`] after it has been closed.
+//! - The data read by [`DecryptReader`] is malformed, fails to authenticate or
+//!   has been truncated. The error is of kind [`InvalidData`].
+//! - The wrapped reader or writer returns an error.
+//!
+//! # Panics:
+//! A panic will occur if:
+//! - Failure to generate random bytes securely.
+//!
+//! # Security:
+//! - [`EncryptWriter`] must be closed, with `poll_close()`, to write the final
+//!   chunk. Flushing only writes complete chunks. Data that has not been closed
+//!   cannot be decrypted.
+//! - [`DecryptReader`] returns each chunk's plaintext once it has been
+//!   authenticated. If an error is returned, all data already read must be
+//!   discarded, as it may be incomplete.
+//!
+//! # Example:
+//! ```rust
+//! use futures::{executor::block_on, io::{AsyncReadExt, AsyncWriteExt}};
+//! use orion::async_io::{DecryptReader, EncryptWriter, SecretKey};
+//!
+//! let secret_key = SecretKey::default();
+//!
+//! let mut writer = EncryptWriter::new(&secret_key, Vec::new())?;
+//! block_on(writer.write_all(b"Secret message")).unwrap();
+//! block_on(writer.close()).unwrap();
+//! let encrypted = writer.into_inner();
+//!
+//! let mut reader = DecryptReader::new(&secret_key, &encrypted[..])?;
+//! let mut decrypted = Vec::new();
+//! block_on(reader.read_to_end(&mut decrypted)).unwrap();
+//!
+//! assert_eq!(decrypted, b"Secret message");
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`AsyncRead`]: https://docs.rs/futures-io/0.3/futures_io/trait.AsyncRead.html
+//! [`AsyncWrite`]: https://docs.rs/futures-io/0.3/futures_io/trait.AsyncWrite.html
+//! [`InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+//! [`EncryptWriter`]: https://docs.rs/orion/latest/orion/async_io/struct.EncryptWriter.html
+//! [`DecryptReader`]: https://docs.rs/orion/latest/orion/async_io/struct.DecryptReader.html
+//! [`HashReader`]: https://docs.rs/orion/latest/orion/async_io/struct.HashReader.html
+//! [`HashWriter`]: https://docs.rs/orion/latest/orion/async_io/struct.HashWriter.html
+//! [`orion::file`]: https://docs.rs/orion/latest/orion/file/index.html
+//! [`orion::hash::digest()`]: https://docs.rs/orion/latest/orion/hash/fn.digest.html
+
+pub use crate::hltypes::SecretKey;
+use crate::{
+	errors::UnknownCryptoError,
+	file::{chunk_nonce, new_header, parse_header, CHUNK_SIZE, HEADER_SIZE},
+	hash::Digest,
+	hazardous::{
+		aead::xchacha20poly1305,
+		hash::blake2b::{self, Blake2b},
+		mac::poly1305::POLY1305_OUTSIZE,
+		stream::chacha20,
+	},
+	util::OsRng,
+};
+use core::{
+	pin::Pin,
+	task::{Context, Poll},
+};
+use futures_io::{AsyncRead, AsyncWrite};
+use std::io;
+use zeroize::Zeroize;
+
+/// The error returned when data fails to decrypt.
+fn invalid_data() -> io::Error {
+	io::Error::new(io::ErrorKind::InvalidData, "UnknownCryptoError")
+}
+
+/// The error returned when a writer is misused.
+fn invalid_input() -> io::Error {
+	io::Error::new(io::ErrorKind::InvalidInput, "UnknownCryptoError")
+}
+
+/// Write all of `buf[*pos..]` to `inner`, advancing `pos`.
+fn poll_write_pending<W: AsyncWrite + Unpin>(
+	inner: &mut W,
+	cx: &mut Context<'_>,
+	buf: &[u8],
+	pos: &mut usize,
+) -> Poll<io::Result<()>> {
+	while *pos < buf.len() {
+		match Pin::new(&mut *inner).poll_write(cx, &buf[*pos..]) {
+			Poll::Ready(Ok(0)) => return Poll::Ready(Err(io::ErrorKind::WriteZero.into())),
+			Poll::Ready(Ok(n)) => *pos += n,
+			Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+			Poll::Pending => return Poll::Pending,
+		}
+	}
+
+	Poll::Ready(Ok(()))
+}
+
+/// A buffer for plaintext, which is wiped on drop.
+struct PlaintextBuffer(Vec<u8>);
+
+impl Drop for PlaintextBuffer {
+	fn drop(&mut self) {
+		self.0.zeroize();
+	}
+}
+
+/// An [`AsyncWrite`] that encrypts everything written to it, in the format of
+/// `orion::file`, and writes the result to the inner writer.
+///
+/// [`AsyncWrite`]: https://docs.rs/futures-io/0.3/futures_io/trait.AsyncWrite.html
+pub struct EncryptWriter<W> {
+	inner: W,
+	secret_key: chacha20::SecretKey,
+	header: [u8; HEADER_SIZE],
+	plaintext: PlaintextBuffer,
+	/// Encrypted data waiting to be written to `inner`.
+	pending: Vec<u8>,
+	pending_pos: usize,
+	counter: u64,
+	finished: bool,
+}
+
+impl<W> core::fmt::Debug for EncryptWriter<W> {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "EncryptWriter {{ ***OMITTED*** }}")
+	}
+}
+
+impl<W: AsyncWrite + Unpin> EncryptWriter<W> {
+	#[must_use]
+	/// Wrap `inner`. The header is written along with the first chunk.
+	pub fn new(secret_key: &SecretKey, inner: W) -> Result<Self, UnknownCryptoError> {
+		let header = new_header(&mut OsRng)?;
+
+		Ok(Self {
+			inner,
+			secret_key: chacha20::SecretKey::from_slice(secret_key.unprotected_as_bytes())?,
+			header,
+			plaintext: PlaintextBuffer(Vec::with_capacity(CHUNK_SIZE)),
+			pending: header.to_vec(),
+			pending_pos: 0,
+			counter: 0,
+			finished: false,
+		})
+	}
+
+	/// Return the inner writer. Data written is only complete once this
+	/// writer has been closed.
+	pub fn into_inner(self) -> W {
+		self.inner
+	}
+
+	/// Encrypt the buffered plaintext as the next chunk.
+	fn seal_chunk(&mut self, last: bool) -> io::Result<()> {
+		let plaintext = &self.plaintext.0;
+		self.pending.clear();
+		self.pending.resize(plaintext.len() + POLY1305_OUTSIZE, 0u8);
+		self.pending_pos = 0;
+
+		xchacha20poly1305::seal(
+			&self.secret_key,
+			&chunk_nonce(&self.header, self.counter, last).map_err(|_| invalid_input())?,
+			plaintext,
+			Some(&self.header),
+			&mut self.pending,
+		)
+		.map_err(|_| invalid_input())?;
+
+		self.plaintext.0.zeroize();
+		self.plaintext.0.clear();
+		self.counter = self.counter.checked_add(1).ok_or_else(invalid_input)?;
+
+		Ok(())
+	}
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWrite for EncryptWriter<W> {
+	fn poll_write(
+		self: Pin<&mut Self>,
+		cx: &mut Context<'_>,
+		buf: &[u8],
+	) -> Poll<io::Result<usize>> {
+		let this = self.get_mut();
+		if this.finished {
+			return Poll::Ready(Err(invalid_input()));
+		}
+		if buf.is_empty() {
+			return Poll::Ready(Ok(0));
+		}
+
+		loop {
+			match poll_write_pending(&mut this.inner, cx, &this.pending, &mut this.pending_pos) {
+				Poll::Ready(Ok(())) => (),
+				other => return other.map(|res| res.map(|_| 0)),
+			}
+
+			// A full chunk is only sealed once more data arrives, as it is
+			// otherwise the final chunk.
+			if this.plaintext.0.len() == CHUNK_SIZE {
+				this.seal_chunk(false)?;
+				continue;
+			}
+
+			let n = core::cmp::min(buf.len(), CHUNK_SIZE - this.plaintext.0.len());
+			this.plaintext.0.extend_from_slice(&buf[..n]);
+
+			return Poll::Ready(Ok(n));
+		}
+	}
+
+	fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+		let this = self.get_mut();
+		match poll_write_pending(&mut this.inner, cx, &this.pending, &mut this.pending_pos) {
+			Poll::Ready(Ok(())) => Pin::new(&mut this.inner).poll_flush(cx),
+			other => other,
+		}
+	}
+
+	fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+		let this = self.get_mut();
+		if !this.finished {
+			match poll_write_pending(&mut this.inner, cx, &this.pending, &mut this.pending_pos) {
+				Poll::Ready(Ok(())) => (),
+				other => return other,
+			}
+			if this.plaintext.0.is_empty() {
+				return Poll::Ready(Err(invalid_input()));
+			}
+			this.seal_chunk(true)?;
+			this.finished = true;
+		}
+
+		match poll_write_pending(&mut this.inner, cx, &this.pending, &mut this.pending_pos) {
+			Poll::Ready(Ok(())) => Pin::new(&mut this.inner).poll_close(cx),
+			other => other,
+		}
+	}
+}
+
+/// An [`AsyncRead`] that decrypts data in the format of `orion::file` from the
+/// inner reader.
+///
+/// [`AsyncRead`]: https://docs.rs/futures-io/0.3/futures_io/trait.AsyncRead.html
+pub struct DecryptReader<R> {
+	inner: R,
+	secret_key: chacha20::SecretKey,
+	header: [u8; HEADER_SIZE],
+	header_len: usize,
+	chunk_size: usize,
+	/// Ciphertext read from `inner`, with room for one byte of the next chunk.
+	ciphertext: Vec<u8>,
+	ciphertext_len: usize,
+	eof: bool,
+	plaintext: PlaintextBuffer,
+	plaintext_pos: usize,
+	counter: u64,
+	done: bool,
+	failed: bool,
+}
+
+impl<R> core::fmt::Debug for DecryptReader<R> {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "DecryptReader {{ ***OMITTED*** }}")
+	}
+}
+
+impl<R: AsyncRead + Unpin> DecryptReader<R> {
+	#[must_use]
+	/// Wrap `inner`.
+	pub fn new(secret_key: &SecretKey, inner: R) -> Result<Self, UnknownCryptoError> {
+		Ok(Self {
+			inner,
+			secret_key: chacha20::SecretKey::from_slice(secret_key.unprotected_as_bytes())?,
+			header: [0u8; HEADER_SIZE],
+			header_len: 0,
+			chunk_size: 0,
+			ciphertext: Vec::new(),
+			ciphertext_len: 0,
+			eof: false,
+			plaintext: PlaintextBuffer(Vec::new()),
+			plaintext_pos: 0,
+			counter: 0,
+			done: false,
+			failed: false,
+		})
+	}
+
+	/// Return the inner reader.
+	pub fn into_inner(self) -> R {
+		self.inner
+	}
+
+	/// Read from `inner` until `buf[*len..]` is full or the end is reached.
+	fn poll_fill(
+		inner: &mut R,
+		cx: &mut Context<'_>,
+		buf: &mut [u8],
+		len: &mut usize,
+	) -> Poll<io::Result<bool>> {
+		while *len < buf.len() {
+			match Pin::new(&mut *inner).poll_read(cx, &mut buf[*len..]) {
+				Poll::Ready(Ok(0)) => return Poll::Ready(Ok(true)),
+				Poll::Ready(Ok(n)) => *len += n,
+				Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+				Poll::Pending => return Poll::Pending,
+			}
+		}
+
+		Poll::Ready(Ok(false))
+	}
+
+	/// Decrypt the first `len` bytes of the ciphertext buffer as the next chunk.
+	fn open_chunk(&mut self, len: usize, last: bool) -> io::Result<()> {
+		if len <= POLY1305_OUTSIZE {
+			return Err(invalid_data());
+		}
+
+		self.plaintext.0.zeroize();
+		self.plaintext.0.clear();
+		self.plaintext.0.resize(len - POLY1305_OUTSIZE, 0u8);
+		self.plaintext_pos = 0;
+
+		xchacha20poly1305::open(
+			&self.secret_key,
+			&chunk_nonce(&self.header, self.counter, last).map_err(|_| invalid_data())?,
+			&self.ciphertext[..len],
+			Some(&self.header),
+			&mut self.plaintext.0,
+		)
+		.map_err(|_| invalid_data())?;
+		self.counter = self.counter.checked_add(1).ok_or_else(invalid_data)?;
+
+		Ok(())
+	}
+
+	/// Make the next chunk of plaintext available, or mark the end of it.
+	fn poll_next_chunk(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+		if self.header_len < HEADER_SIZE {
+			match Self::poll_fill(&mut self.inner, cx, &mut self.header, &mut self.header_len) {
+				Poll::Ready(Ok(false)) => (),
+				Poll::Ready(Ok(true)) => return Poll::Ready(Err(invalid_data())),
+				other => return other.map(|res| res.map(|_| ())),
+			}
+			self.chunk_size = parse_header(&self.header).map_err(|_| invalid_data())?;
+			self.ciphertext = vec![0u8; self.chunk_size + POLY1305_OUTSIZE + 1];
+		}
+
+		if !self.eof {
+			match Self::poll_fill(
+				&mut self.inner,
+				cx,
+				&mut self.ciphertext,
+				&mut self.ciphertext_len,
+			) {
+				Poll::Ready(Ok(eof)) => self.eof = eof,
+				other => return other.map(|res| res.map(|_| ())),
+			}
+		}
+
+		let full_chunk = self.chunk_size + POLY1305_OUTSIZE;
+		if self.ciphertext_len > full_chunk {
+			// More data follows, so this is not the final chunk.
+			self.open_chunk(full_chunk, false)?;
+			self.ciphertext[0] = self.ciphertext[full_chunk];
+			self.ciphertext_len = 1;
+		} else {
+			self.open_chunk(self.ciphertext_len, true)?;
+			self.ciphertext_len = 0;
+			self.done = true;
+		}
+
+		Poll::Ready(Ok(()))
+	}
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for DecryptReader<R> {
+	fn poll_read(
+		self: Pin<&mut Self>,
+		cx: &mut Context<'_>,
+		buf: &mut [u8],
+	) -> Poll<io::Result<usize>> {
+		let this = self.get_mut();
+
+		loop {
+			if this.failed {
+				return Poll::Ready(Err(invalid_data()));
+			}
+
+			let available = &this.plaintext.0[this.plaintext_pos..];
+			if !available.is_empty() {
+				let n = core::cmp::min(buf.len(), available.len());
+				buf[..n].copy_from_slice(&available[..n]);
+				this.plaintext_pos += n;
+
+				return Poll::Ready(Ok(n));
+			}
+			if this.done || buf.is_empty() {
+				return Poll::Ready(Ok(0));
+			}
+
+			match this.poll_next_chunk(cx) {
+				Poll::Ready(Ok(())) => (),
+				Poll::Ready(Err(e)) => {
+					this.failed = true;
+					return Poll::Ready(Err(e));
+				}
+				Poll::Pending => return Poll::Pending,
+			}
+		}
+	}
+}
+
+/// An [`AsyncRead`] that hashes all data read through it with BLAKE2b-256.
+///
+/// [`AsyncRead`]: https://docs.rs/futures-io/0.3/futures_io/trait.AsyncRead.html
+pub struct HashReader<R> {
+	inner: R,
+	state: Blake2b,
+}
+
+impl<R> core::fmt::Debug for HashReader<R> {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "HashReader {{ ***OMITTED*** }}")
+	}
+}
+
+impl<R: AsyncRead + Unpin> HashReader<R> {
+	#[must_use]
+	/// Wrap `inner`.
+	pub fn new(inner: R) -> Result<Self, UnknownCryptoError> {
+		Ok(Self {
+			inner,
+			state: blake2b::Hasher::Blake2b256.init()?,
+		})
+	}
+
+	#[must_use]
+	/// The digest of all data read so far, and the inner reader.
+	pub fn finalize(mut self) -> Result<(Digest, R), UnknownCryptoError> {
+		Ok((self.state.finalize()?, self.inner))
+	}
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for HashReader<R> {
+	fn poll_read(
+		self: Pin<&mut Self>,
+		cx: &mut Context<'_>,
+		buf: &mut [u8],
+	) -> Poll<io::Result<usize>> {
+		let this = self.get_mut();
+		match Pin::new(&mut this.inner).poll_read(cx, buf) {
+			Poll::Ready(Ok(n)) => {
+				this.state.update(&buf[..n]).map_err(|_| invalid_input())?;
+				Poll::Ready(Ok(n))
+			}
+			other => other,
+		}
+	}
+}
+
+/// An [`AsyncWrite`] that hashes all data written through it with
+/// BLAKE2b-256.
+///
+/// [`AsyncWrite`]: https://docs.rs/futures-io/0.3/futures_io/trait.AsyncWrite.html
+pub struct HashWriter<W> {
+	inner: W,
+	state: Blake2b,
+}
+
+impl<W> core::fmt::Debug for HashWriter<W> {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "HashWriter {{ ***OMITTED*** }}")
+	}
+}
+
+impl<W: AsyncWrite + Unpin> HashWriter<W> {
+	#[must_use]
+	/// Wrap `inner`.
+	pub fn new(inner: W) -> Result<Self, UnknownCryptoError> {
+		Ok(Self {
+			inner,
+			state: blake2b::Hasher::Blake2b256.init()?,
+		})
+	}
+
+	#[must_use]
+	/// The digest of all data written so far, and the inner writer.
+	pub fn finalize(mut self) -> Result<(Digest, W), UnknownCryptoError> {
+		Ok((self.state.finalize()?, self.inner))
+	}
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWrite for HashWriter<W> {
+	fn poll_write(
+		self: Pin<&mut Self>,
+		cx: &mut Context<'_>,
+		buf: &[u8],
+	) -> Poll<io::Result<usize>> {
+		let this = self.get_mut();
+		match Pin::new(&mut this.inner).poll_write(cx, buf) {
+			Poll::Ready(Ok(n)) => {
+				this.state.update(&buf[..n]).map_err(|_| invalid_input())?;
+				Poll::Ready(Ok(n))
+			}
+			other => other,
+		}
+	}
+
+	fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+		Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+	}
+
+	fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+		Pin::new(&mut self.get_mut().inner).poll_close(cx)
+	}
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+	use futures::{
+		executor::block_on,
+		io::{AsyncReadExt, AsyncWriteExt},
+	};
+
+	/// Wraps a reader or writer, alternating between returning `Pending` and
+	/// processing at most 7 bytes at a time.
+	struct Trickle<T> {
+		inner: T,
+		pending: bool,
+	}
+
+	impl<T> Trickle<T> {
+		fn new(inner: T) -> Self {
+			Self {
+				inner,
+				pending: true,
+			}
+		}
+
+		fn poll_pending(&mut self, cx: &mut Context<'_>) -> bool {
+			self.pending = !self.pending;
+			if !self.pending {
+				cx.waker().wake_by_ref();
+			}
+
+			!self.pending
+		}
+	}
+
+	impl<T: AsyncRead + Unpin> AsyncRead for Trickle<T> {
+		fn poll_read(
+			self: Pin<&mut Self>,
+			cx: &mut Context<'_>,
+			buf: &mut [u8],
+		) -> Poll<io::Result<usize>> {
+			let this = self.get_mut();
+			if this.poll_pending(cx) {
+				return Poll::Pending;
+			}
+			let len = core::cmp::min(buf.len(), 7);
+			Pin::new(&mut this.inner).poll_read(cx, &mut buf[..len])
+		}
+	}
+
+	impl<T: AsyncWrite + Unpin> AsyncWrite for Trickle<T> {
+		fn poll_write(
+			self: Pin<&mut Self>,
+			cx: &mut Context<'_>,
+			buf: &[u8],
+		) -> Poll<io::Result<usize>> {
+			let this = self.get_mut();
+			if this.poll_pending(cx) {
+				return Poll::Pending;
+			}
+			let len = core::cmp::min(buf.len(), 7);
+			Pin::new(&mut this.inner).poll_write(cx, &buf[..len])
+		}
+
+		fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+			Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+		}
+
+		fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+			Pin::new(&mut self.get_mut().inner).poll_close(cx)
+		}
+	}
+
+	fn encrypt(secret_key: &SecretKey, data: &[u8]) -> io::Result<Vec<u8>> {
+		block_on(async {
+			let mut writer = EncryptWriter::new(secret_key, Vec::new()).unwrap();
+			writer.write_all(data).await?;
+			writer.close().await?;
+			Ok(writer.into_inner())
+		})
+	}
+
+	fn decrypt(secret_key: &SecretKey, data: &[u8]) -> io::Result<Vec<u8>> {
+		block_on(async {
+			let mut reader = DecryptReader::new(secret_key, data).unwrap();
+			let mut decrypted = Vec::new();
+			reader.read_to_end(&mut decrypted).await?;
+			Ok(decrypted)
+		})
+	}
+
+	mod test_encrypt_decrypt {
+		use super::*;
+
+		const LENGTHS: [usize; 6] = [
+			1,
+			CHUNK_SIZE - 1,
+			CHUNK_SIZE,
+			CHUNK_SIZE + 1,
+			2 * CHUNK_SIZE,
+			3 * CHUNK_SIZE + 17,
+		];
+
+		#[test]
+		fn test_roundtrip_and_file_compatibility() {
+			let key = SecretKey::default();
+			for len in LENGTHS.iter() {
+				let data: Vec<u8> = (0..*len).map(|i| i as u8).collect();
+
+				let encrypted = encrypt(&key, &data).unwrap();
+				assert_eq!(decrypt(&key, &encrypted).unwrap(), data);
+
+				let mut decrypted = Vec::new();
+				crate::file::decrypt(&key, &encrypted[..], &mut decrypted, None).unwrap();
+				assert_eq!(decrypted, data);
+
+				let mut encrypted = Vec::new();
+				crate::file::encrypt(&key, &data[..], &mut encrypted, None).unwrap();
+				assert_eq!(encrypted.len(), encrypt(&key, &data).unwrap().len());
+				assert_eq!(decrypt(&key, &encrypted).unwrap(), data);
+			}
+		}
+
+		#[test]
+		fn test_roundtrip_trickle() {
+			let key = SecretKey::default();
+			let data = vec![0x42u8; CHUNK_SIZE + 100];
+
+			let encrypted = block_on(async {
+				let mut writer = EncryptWriter::new(&key, Trickle::new(Vec::new())).unwrap();
+				for part in data.chunks(1000) {
+					writer.write_all(part).await.unwrap();
+					writer.flush().await.unwrap();
+				}
+				writer.close().await.unwrap();
+				writer.into_inner().inner
+			});
+
+			let decrypted = block_on(async {
+				let mut reader = DecryptReader::new(&key, Trickle::new(&encrypted[..])).unwrap();
+				let mut decrypted = Vec::new();
+				reader.read_to_end(&mut decrypted).await.unwrap();
+				decrypted
+			});
+
+			assert_eq!(decrypted, data);
+		}
+
+		#[test]
+		fn test_close_empty_err() {
+			assert!(encrypt(&SecretKey::default(), &[]).is_err());
+		}
+
+		#[test]
+		fn test_write_after_close_err() {
+			block_on(async {
+				let mut writer = EncryptWriter::new(&SecretKey::default(), Vec::new()).unwrap();
+				writer.write_all(b"data").await.unwrap();
+				writer.close().await.unwrap();
+				assert!(writer.write_all(b"more").await.is_err());
+			});
+		}
+
+		#[test]
+		fn test_invalid_key_size_err() {
+			let key = SecretKey::from_slice(&[0u8; 31]).unwrap();
+			assert!(EncryptWriter::new(&key, Vec::new()).is_err());
+			assert!(DecryptReader::new(&key, &[0u8; 0][..]).is_err());
+		}
+
+		#[test]
+		fn test_wrong_key_err() {
+			let encrypted = encrypt(&SecretKey::default(), b"data").unwrap();
+			let err = decrypt(&SecretKey::default(), &encrypted).unwrap_err();
+			assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+		}
+
+		#[test]
+		fn test_truncated_err() {
+			let key = SecretKey::default();
+			let encrypted = encrypt(&key, &vec![0u8; 2 * CHUNK_SIZE + 10]).unwrap();
+			let chunk = CHUNK_SIZE + POLY1305_OUTSIZE;
+
+			for len in [
+				0,
+				HEADER_SIZE - 1,
+				HEADER_SIZE,
+				HEADER_SIZE + chunk,
+				HEADER_SIZE + 2 * chunk,
+				encrypted.len() - 1,
+			]
+			.iter()
+			{
+				assert!(decrypt(&key, &encrypted[..*len]).is_err());
+			}
+		}
+
+		#[test]
+		fn test_error_is_sticky() {
+			let key = SecretKey::default();
+			let mut encrypted = encrypt(&key, &vec![0u8; 2 * CHUNK_SIZE]).unwrap();
+			let last = encrypted.len() - 1;
+			encrypted[last] ^= 1;
+
+			block_on(async {
+				let mut reader = DecryptReader::new(&key, &encrypted[..]).unwrap();
+				let mut decrypted = Vec::new();
+				assert!(reader.read_to_end(&mut decrypted).await.is_err());
+				assert!(reader.read(&mut [0u8; 1]).await.is_err());
+			});
+		}
+	}
+
+	mod test_hash {
+		use super::*;
+
+		#[test]
+		fn test_hash_reader_and_writer() {
+			let data = vec![0x42u8; 1000];
+			let expected = crate::hash::digest(&data).unwrap();
+
+			let (digest, _) = block_on(async {
+				let mut reader = HashReader::new(Trickle::new(&data[..])).unwrap();
+				let mut read = Vec::new();
+				reader.read_to_end(&mut read).await.unwrap();
+				reader.finalize().unwrap()
+			});
+			assert_eq!(digest, expected);
+
+			let (digest, writer) = block_on(async {
+				let mut writer = HashWriter::new(Trickle::new(Vec::new())).unwrap();
+				writer.write_all(&data).await.unwrap();
+				writer.close().await.unwrap();
+				writer.finalize().unwrap()
+			});
+			assert_eq!(digest, expected);
+			assert_eq!(writer.inner, data);
+		}
+	}
+}
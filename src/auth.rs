@@ -32,6 +32,7 @@
 //!
 //! # About:
 //! - Uses HMAC-SHA512.
+//! - Neither function allocates. The [`Tag`] is a fixed-size type of 64 bytes.
 //!
 //! # Parameters:
 //! - `secret_key`: Secret key used to authenticate `data`.
@@ -47,6 +48,8 @@
 //!   [`SecretKey::default()`] can be used for
 //! this, it will generate a [`SecretKey`] of 32 bytes.
 //! - The recommended minimum length for a [`SecretKey`] is 32.
+//!   [`SecretKey::generate_len()`] can be used to generate a longer key, such as
+//! one matching the 128-byte block size of HMAC-SHA512. It refuses lengths below 32.
 //!
 //! # Example:
 //! ```rust
@@ -61,6 +64,7 @@
 //! ```
 //! [`SecretKey`]: https://docs.rs/orion/latest/orion/auth/struct.SecretKey.html
 //! [`SecretKey::default()`]: https://docs.rs/orion/latest/orion/auth/struct.SecretKey.html
+//! [`SecretKey::generate_len()`]: https://docs.rs/orion/latest/orion/auth/struct.SecretKey.html#method.generate_len
 //! [`Tag`]: https://docs.rs/orion/latest/orion/hazardous/mac/hmac/struct.Tag.html
 
 use crate::{errors::UnknownCryptoError, hazardous::mac::hmac};
@@ -89,6 +93,7 @@ pub fn authenticate_verify(
 
 // Testing public functions in the module.
 #[cfg(test)]
+#[cfg(feature = "safe_api")]
 mod public {
 	use super::*;
 
@@ -122,6 +127,19 @@ mod public {
 			);
 			assert!(authenticate_verify(&hmac_bob, &sec_key, b"bad msg").is_err());
 		}
+
+		#[test]
+		fn test_authenticate_verify_generate_len() {
+			let msg = "what do ya want for nothing?".as_bytes();
+
+			for len in [32, 64, 128, 129, 256].iter() {
+				let sec_key = SecretKey::generate_len(*len).unwrap();
+				let tag = authenticate(&sec_key, msg).unwrap();
+				assert!(authenticate_verify(&tag, &sec_key, msg).unwrap());
+			}
+
+			assert!(SecretKey::generate_len(31).is_err());
+		}
 	}
 
 	// Proptests. Only exectued when NOT testing no_std.
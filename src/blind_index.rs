@@ -0,0 +1,271 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Blind indexes for equality lookups over encrypted data.
+//!
+//! # Use case:
+//! `orion::blind_index` can be used to look up rows of a database by the
+//! value of an encrypted column, without decrypting the column and without
+//! the database learning the values.
+//!
+//! An example of this could be finding a user by email address, where the
+//! email address is stored encrypted with [`orion::aead`], next to its blind
+//! index.
+//!
+//! # About:
+//! - An [`IndexKey`] is derived for each field from a master key with
+//!   HKDF-HMAC-SHA512, using the field name as info. Indexes of different
+//!   fields can therefore not be compared with each other.
+//! - The [`BlindIndex`] of a value is its BLAKE2b hash, keyed with the
+//!   [`IndexKey`]. Its size is chosen by the caller, between
+//!   [`MIN_INDEX_SIZE`] and [`MAX_INDEX_SIZE`] bytes. BLAKE2b takes the
+//!   output size as a parameter, so an index is not a prefix of a longer
+//!   index of the same value.
+//! - To look up a value, compute its blind index and query the column of
+//!   indexes for it. Because of truncation, the query can return rows with
+//!   other values, which must be filtered out after decrypting them.
+//!
+//! # Parameters:
+//! - `master_key`: The secret key that index keys are derived from.
+//! - `field`: The name of the field, such as `"users.email"`.
+//! - `index_key`: The key of the field that `value` belongs to.
+//! - `value`: The plaintext value of the field, in a canonical encoding.
+//! - `size`: The size of the blind index in bytes.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `master_key` is shorter than 32 bytes.
+//! - `field` is empty.
+//! - `size` is less than [`MIN_INDEX_SIZE`] or greater than
+//!   [`MAX_INDEX_SIZE`].
+//!
+//! # Security:
+//! - A blind index reveals which rows have equal values, and how often each
+//!   value occurs. For fields with few possible values, such as a boolean or
+//!   a country, this can reveal the values themselves. Only index fields with
+//!   many possible values.
+//! - Shorter indexes reveal less, because unequal values collide more often,
+//!   but make lookups return more rows. An index of `n` bytes makes a false
+//!   match between two values happen with probability 2^(-8n). Choose `size`
+//!   so that a lookup returns a few false matches among all rows.
+//! - Values must be encoded the same way every time, for example with the
+//!   same case and Unicode normalization, or equal values will not match.
+//! - Use a master key that is not used for anything else, such as encrypting
+//!   the column itself.
+//!
+//! # Example:
+//! ```rust
+//! use orion::blind_index::{self, IndexKey, SecretKey};
+//!
+//! let master_key = SecretKey::default();
+//! let email_key = IndexKey::derive(&master_key, "users.email")?;
+//!
+//! // Stored next to the encrypted email address.
+//! let stored = blind_index::compute(&email_key, b"alice@example.com", 4)?;
+//!
+//! // Computed to look up the row.
+//! let lookup = blind_index::compute(&email_key, b"alice@example.com", 4)?;
+//! assert_eq!(stored, lookup);
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`IndexKey`]: https://docs.rs/orion/latest/orion/blind_index/struct.IndexKey.html
+//! [`BlindIndex`]: https://docs.rs/orion/latest/orion/blind_index/struct.BlindIndex.html
+//! [`MIN_INDEX_SIZE`]: https://docs.rs/orion/latest/orion/blind_index/constant.MIN_INDEX_SIZE.html
+//! [`MAX_INDEX_SIZE`]: https://docs.rs/orion/latest/orion/blind_index/constant.MAX_INDEX_SIZE.html
+//! [`orion::aead`]: https://docs.rs/orion/latest/orion/aead/index.html
+
+pub use crate::hltypes::SecretKey;
+use crate::{
+	errors::UnknownCryptoError,
+	hazardous::{hash::blake2b, kdf::hkdf},
+};
+use zeroize::Zeroize;
+
+/// The smallest size of a blind index.
+pub const MIN_INDEX_SIZE: usize = 1;
+/// The largest size of a blind index.
+pub const MAX_INDEX_SIZE: usize = 32;
+
+/// The size of an index key, and the smallest size of a master key.
+const KEY_SIZE: usize = 32;
+/// The HKDF salt used to derive index keys.
+const CONTEXT: &[u8] = b"orion::blind_index";
+
+construct_secret_key! {
+	/// A type to represent the key that the blind indexes of one field are
+	/// computed with.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 32 bytes.
+	///
+	/// # Panics:
+	/// A panic will occur if:
+	/// - Failure to generate random bytes securely.
+	(IndexKey, test_index_key, KEY_SIZE, KEY_SIZE, KEY_SIZE)
+}
+
+impl_from_trait!(IndexKey, KEY_SIZE);
+
+construct_public! {
+	/// A type to represent a blind index.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is empty.
+	/// - `slice` is greater than 32 bytes.
+	(BlindIndex, test_blind_index, MIN_INDEX_SIZE, MAX_INDEX_SIZE)
+}
+
+impl IndexKey {
+	#[must_use]
+	/// Derive the index key of `field` from `master_key`.
+	pub fn derive(master_key: &SecretKey, field: &str) -> Result<Self, UnknownCryptoError> {
+		if master_key.get_length() < KEY_SIZE || field.is_empty() {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+
+		let mut key = [0u8; KEY_SIZE];
+		hkdf::derive_key(
+			CONTEXT,
+			master_key.unprotected_as_bytes(),
+			Some(field.as_bytes()),
+			&mut key,
+		)?;
+		let index_key = Self::from(key);
+		key.zeroize();
+
+		Ok(index_key)
+	}
+}
+
+#[must_use]
+/// Compute the blind index of `value` with `index_key`, of `size` bytes.
+pub fn compute(
+	index_key: &IndexKey,
+	value: &[u8],
+	size: usize,
+) -> Result<BlindIndex, UnknownCryptoError> {
+	if size < MIN_INDEX_SIZE || size > MAX_INDEX_SIZE {
+		return Err(UnknownCryptoError::ParameterOutOfRange);
+	}
+
+	let mut state = blake2b::init(
+		Some(&blake2b::SecretKey::from_slice(
+			index_key.unprotected_as_bytes(),
+		)?),
+		size,
+	)?;
+	state.update(value)?;
+
+	BlindIndex::from_slice(state.finalize()?.as_ref())
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	mod test_derive {
+		use super::*;
+
+		#[test]
+		fn test_field_separation() {
+			let master_key = SecretKey::default();
+			let email = IndexKey::derive(&master_key, "users.email").unwrap();
+			let name = IndexKey::derive(&master_key, "users.name").unwrap();
+			assert!(email != name);
+			assert!(email == IndexKey::derive(&master_key, "users.email").unwrap());
+			assert!(email != IndexKey::derive(&SecretKey::default(), "users.email").unwrap());
+
+			assert_ne!(
+				compute(&email, b"alice", 16).unwrap(),
+				compute(&name, b"alice", 16).unwrap()
+			);
+		}
+
+		#[test]
+		fn test_invalid_parameters_err() {
+			assert!(IndexKey::derive(&SecretKey::default(), "").is_err());
+			let short = SecretKey::from_slice(&[0u8; 31]).unwrap();
+			assert!(IndexKey::derive(&short, "users.email").is_err());
+			let long = SecretKey::from_slice(&[0u8; 64]).unwrap();
+			assert!(IndexKey::derive(&long, "users.email").is_ok());
+		}
+	}
+
+	mod test_compute {
+		use super::*;
+
+		#[test]
+		fn test_known() {
+			// Computed with Python's hashlib.blake2b.
+			let index_key = IndexKey::from([0x42u8; 32]);
+			assert_eq!(
+				compute(&index_key, b"alice@example.com", 4).unwrap(),
+				BlindIndex::from_slice(&[0x67, 0xfe, 0x02, 0x82]).unwrap()
+			);
+			assert_eq!(
+				compute(&index_key, b"alice@example.com", 32).unwrap(),
+				BlindIndex::from_slice(&[
+					0xde, 0x45, 0x9c, 0x12, 0x64, 0x91, 0xbe, 0xbc, 0xc1, 0x67, 0xe0, 0xcf, 0x42,
+					0x29, 0x86, 0x2b, 0x47, 0x3c, 0x63, 0xeb, 0xe2, 0x15, 0x39, 0x49, 0xf4, 0x2b,
+					0x0b, 0x14, 0x0f, 0xf5, 0xdc, 0x88
+				])
+				.unwrap()
+			);
+		}
+
+		#[test]
+		fn test_sizes() {
+			let index_key = IndexKey::generate();
+			for size in MIN_INDEX_SIZE..=MAX_INDEX_SIZE {
+				let index = compute(&index_key, b"alice@example.com", size).unwrap();
+				assert_eq!(index.get_length(), size);
+			}
+			// A shorter index is not a prefix of a longer one.
+			let short = compute(&index_key, b"alice@example.com", 8).unwrap();
+			let long = compute(&index_key, b"alice@example.com", 16).unwrap();
+			assert_ne!(short.as_ref(), &long.as_ref()[..8]);
+		}
+
+		#[test]
+		fn test_values() {
+			let index_key = IndexKey::generate();
+			assert_eq!(
+				compute(&index_key, b"", 16).unwrap(),
+				compute(&index_key, b"", 16).unwrap()
+			);
+			assert_ne!(
+				compute(&index_key, b"alice", 16).unwrap(),
+				compute(&index_key, b"bob", 16).unwrap()
+			);
+		}
+
+		#[test]
+		fn test_invalid_size_err() {
+			let index_key = IndexKey::generate();
+			assert!(compute(&index_key, b"alice", 0).is_err());
+			assert!(compute(&index_key, b"alice", MAX_INDEX_SIZE + 1).is_err());
+		}
+	}
+}
@@ -0,0 +1,665 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! A canonical binary encoding of `serde` values, used by
+//! `aead::seal_value()`.
+//!
+//! - Integers, floats and `char`s are encoded as fixed-width little-endian.
+//! - `bool`s and the tag of `Option`s are one byte, `0` or `1`.
+//! - Strings, byte arrays, sequences and maps are prefixed by their length
+//!   as a little-endian `u64`. Maps are encoded in the order they are
+//!   iterated, so only ordered maps, such as `BTreeMap`, encode canonically.
+//! - Enum variants are prefixed by their index as a little-endian `u32`.
+//! - Structs and tuples are encoded as their fields in order, without names
+//!   or lengths.
+//!
+//! The encoding is not self-describing. Decoding rejects any byte that is not
+//! part of the value.
+
+use crate::errors::UnknownCryptoError;
+use alloc::{string::String, vec::Vec};
+use core::{convert::TryFrom, fmt};
+use serde::{de, ser};
+
+#[derive(Debug)]
+/// An error while encoding or decoding. It carries no details, as they are
+/// not reported.
+pub(crate) struct Error;
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "invalid canonical encoding")
+	}
+}
+
+impl ser::StdError for Error {}
+
+impl ser::Error for Error {
+	fn custom<T: fmt::Display>(_msg: T) -> Self {
+		Error
+	}
+}
+
+impl de::Error for Error {
+	fn custom<T: fmt::Display>(_msg: T) -> Self {
+		Error
+	}
+}
+
+#[must_use]
+/// Encode `value`, appending it to `dst_out`.
+pub(crate) fn to_vec<T: ser::Serialize + ?Sized>(
+	value: &T,
+	dst_out: &mut Vec<u8>,
+) -> Result<(), UnknownCryptoError> {
+	value
+		.serialize(&mut Encoder { out: dst_out })
+		.map_err(|_| UnknownCryptoError::InvalidEncoding)
+}
+
+#[must_use]
+/// Decode a value from all of `src`.
+pub(crate) fn from_slice<T: de::DeserializeOwned>(src: &[u8]) -> Result<T, UnknownCryptoError> {
+	let mut decoder = Decoder { src };
+	let value = T::deserialize(&mut decoder).map_err(|_| UnknownCryptoError::InvalidEncoding)?;
+	if !decoder.src.is_empty() {
+		return Err(UnknownCryptoError::InvalidEncoding);
+	}
+
+	Ok(value)
+}
+
+struct Encoder<'a> {
+	out: &'a mut Vec<u8>,
+}
+
+impl<'a> Encoder<'a> {
+	fn length(&mut self, len: usize) {
+		self.out.extend_from_slice(&(len as u64).to_le_bytes());
+	}
+
+	fn variant(&mut self, index: u32) {
+		self.out.extend_from_slice(&index.to_le_bytes());
+	}
+}
+
+/// Implement a `serialize_*` method that writes `v` as little-endian.
+macro_rules! serialize_le (($method:ident, $ty:ty) => (
+	fn $method(self, v: $ty) -> Result<(), Error> {
+		self.out.extend_from_slice(&v.to_le_bytes());
+		Ok(())
+	}
+));
+
+impl<'a, 'b> ser::Serializer for &'b mut Encoder<'a> {
+	type Ok = ();
+	type Error = Error;
+	type SerializeSeq = Self;
+	type SerializeTuple = Self;
+	type SerializeTupleStruct = Self;
+	type SerializeTupleVariant = Self;
+	type SerializeMap = Self;
+	type SerializeStruct = Self;
+	type SerializeStructVariant = Self;
+
+	fn serialize_bool(self, v: bool) -> Result<(), Error> {
+		self.out.push(u8::from(v));
+		Ok(())
+	}
+
+	serialize_le!(serialize_i8, i8);
+	serialize_le!(serialize_i16, i16);
+	serialize_le!(serialize_i32, i32);
+	serialize_le!(serialize_i64, i64);
+	serialize_le!(serialize_i128, i128);
+	serialize_le!(serialize_u8, u8);
+	serialize_le!(serialize_u16, u16);
+	serialize_le!(serialize_u32, u32);
+	serialize_le!(serialize_u64, u64);
+	serialize_le!(serialize_u128, u128);
+
+	fn serialize_f32(self, v: f32) -> Result<(), Error> {
+		self.serialize_u32(v.to_bits())
+	}
+
+	fn serialize_f64(self, v: f64) -> Result<(), Error> {
+		self.serialize_u64(v.to_bits())
+	}
+
+	fn serialize_char(self, v: char) -> Result<(), Error> {
+		self.serialize_u32(u32::from(v))
+	}
+
+	fn serialize_str(self, v: &str) -> Result<(), Error> {
+		self.serialize_bytes(v.as_bytes())
+	}
+
+	fn serialize_bytes(self, v: &[u8]) -> Result<(), Error> {
+		self.length(v.len());
+		self.out.extend_from_slice(v);
+		Ok(())
+	}
+
+	fn serialize_none(self) -> Result<(), Error> {
+		self.out.push(0);
+		Ok(())
+	}
+
+	fn serialize_some<T: ser::Serialize + ?Sized>(self, value: &T) -> Result<(), Error> {
+		self.out.push(1);
+		value.serialize(self)
+	}
+
+	fn serialize_unit(self) -> Result<(), Error> {
+		Ok(())
+	}
+
+	fn serialize_unit_struct(self, _name: &'static str) -> Result<(), Error> {
+		Ok(())
+	}
+
+	fn serialize_unit_variant(
+		self,
+		_name: &'static str,
+		variant_index: u32,
+		_variant: &'static str,
+	) -> Result<(), Error> {
+		self.variant(variant_index);
+		Ok(())
+	}
+
+	fn serialize_newtype_struct<T: ser::Serialize + ?Sized>(
+		self,
+		_name: &'static str,
+		value: &T,
+	) -> Result<(), Error> {
+		value.serialize(self)
+	}
+
+	fn serialize_newtype_variant<T: ser::Serialize + ?Sized>(
+		self,
+		_name: &'static str,
+		variant_index: u32,
+		_variant: &'static str,
+		value: &T,
+	) -> Result<(), Error> {
+		self.variant(variant_index);
+		value.serialize(self)
+	}
+
+	fn serialize_seq(self, len: Option<usize>) -> Result<Self, Error> {
+		// The length must be known up front to be written before the elements.
+		self.length(len.ok_or(Error)?);
+		Ok(self)
+	}
+
+	fn serialize_tuple(self, _len: usize) -> Result<Self, Error> {
+		Ok(self)
+	}
+
+	fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self, Error> {
+		Ok(self)
+	}
+
+	fn serialize_tuple_variant(
+		self,
+		_name: &'static str,
+		variant_index: u32,
+		_variant: &'static str,
+		_len: usize,
+	) -> Result<Self, Error> {
+		self.variant(variant_index);
+		Ok(self)
+	}
+
+	fn serialize_map(self, len: Option<usize>) -> Result<Self, Error> {
+		self.length(len.ok_or(Error)?);
+		Ok(self)
+	}
+
+	fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self, Error> {
+		Ok(self)
+	}
+
+	fn serialize_struct_variant(
+		self,
+		_name: &'static str,
+		variant_index: u32,
+		_variant: &'static str,
+		_len: usize,
+	) -> Result<Self, Error> {
+		self.variant(variant_index);
+		Ok(self)
+	}
+
+	fn collect_str<T: fmt::Display + ?Sized>(self, value: &T) -> Result<(), Error> {
+		let mut string = String::new();
+		fmt::write(&mut string, format_args!("{}", value)).map_err(|_| Error)?;
+		self.serialize_str(&string)
+	}
+
+	fn is_human_readable(&self) -> bool {
+		false
+	}
+}
+
+/// Implement a compound `serde::ser` trait whose elements are encoded in
+/// order, with nothing between them.
+macro_rules! impl_compound (($trait:ident, $method:ident $(, $key:ty)*) => (
+	impl<'a, 'b> ser::$trait for &'b mut Encoder<'a> {
+		type Ok = ();
+		type Error = Error;
+
+		fn $method<T: ser::Serialize + ?Sized>(
+			&mut self,
+			$(_key: $key,)*
+			value: &T,
+		) -> Result<(), Error> {
+			value.serialize(&mut **self)
+		}
+
+		fn end(self) -> Result<(), Error> {
+			Ok(())
+		}
+	}
+));
+
+impl_compound!(SerializeSeq, serialize_element);
+impl_compound!(SerializeTuple, serialize_element);
+impl_compound!(SerializeTupleStruct, serialize_field);
+impl_compound!(SerializeTupleVariant, serialize_field);
+impl_compound!(SerializeStruct, serialize_field, &'static str);
+impl_compound!(SerializeStructVariant, serialize_field, &'static str);
+
+impl<'a, 'b> ser::SerializeMap for &'b mut Encoder<'a> {
+	type Ok = ();
+	type Error = Error;
+
+	fn serialize_key<T: ser::Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+		key.serialize(&mut **self)
+	}
+
+	fn serialize_value<T: ser::Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+		value.serialize(&mut **self)
+	}
+
+	fn end(self) -> Result<(), Error> {
+		Ok(())
+	}
+}
+
+struct Decoder<'de> {
+	src: &'de [u8],
+}
+
+impl<'de> Decoder<'de> {
+	fn take(&mut self, len: usize) -> Result<&'de [u8], Error> {
+		if len > self.src.len() {
+			return Err(Error);
+		}
+		let (taken, rest) = self.src.split_at(len);
+		self.src = rest;
+
+		Ok(taken)
+	}
+
+	fn length(&mut self) -> Result<usize, Error> {
+		let mut len = [0u8; 8];
+		len.copy_from_slice(self.take(8)?);
+
+		usize::try_from(u64::from_le_bytes(len)).map_err(|_| Error)
+	}
+}
+
+/// Read a `$ty` encoded as little-endian from `$decoder`.
+macro_rules! read_le (($decoder:expr, $ty:ty) => ({
+	let mut bytes = [0u8; core::mem::size_of::<$ty>()];
+	bytes.copy_from_slice($decoder.take(core::mem::size_of::<$ty>())?);
+	<$ty>::from_le_bytes(bytes)
+}));
+
+/// Implement a `deserialize_*` method that reads a little-endian `$ty`.
+macro_rules! deserialize_le (($method:ident, $visit:ident, $ty:ty) => (
+	fn $method<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+		visitor.$visit(read_le!(self, $ty))
+	}
+));
+
+impl<'de, 'a> de::Deserializer<'de> for &'a mut Decoder<'de> {
+	type Error = Error;
+
+	fn deserialize_any<V: de::Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Error> {
+		// The encoding is not self-describing.
+		Err(Error)
+	}
+
+	fn deserialize_bool<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+		match self.take(1)?[0] {
+			0 => visitor.visit_bool(false),
+			1 => visitor.visit_bool(true),
+			_ => Err(Error),
+		}
+	}
+
+	deserialize_le!(deserialize_i8, visit_i8, i8);
+	deserialize_le!(deserialize_i16, visit_i16, i16);
+	deserialize_le!(deserialize_i32, visit_i32, i32);
+	deserialize_le!(deserialize_i64, visit_i64, i64);
+	deserialize_le!(deserialize_i128, visit_i128, i128);
+	deserialize_le!(deserialize_u8, visit_u8, u8);
+	deserialize_le!(deserialize_u16, visit_u16, u16);
+	deserialize_le!(deserialize_u32, visit_u32, u32);
+	deserialize_le!(deserialize_u64, visit_u64, u64);
+	deserialize_le!(deserialize_u128, visit_u128, u128);
+
+	fn deserialize_f32<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+		visitor.visit_f32(f32::from_bits(read_le!(self, u32)))
+	}
+
+	fn deserialize_f64<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+		visitor.visit_f64(f64::from_bits(read_le!(self, u64)))
+	}
+
+	fn deserialize_char<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+		visitor.visit_char(core::char::from_u32(read_le!(self, u32)).ok_or(Error)?)
+	}
+
+	fn deserialize_str<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+		let len = self.length()?;
+		visitor.visit_borrowed_str(core::str::from_utf8(self.take(len)?).map_err(|_| Error)?)
+	}
+
+	fn deserialize_string<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+		self.deserialize_str(visitor)
+	}
+
+	fn deserialize_bytes<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+		let len = self.length()?;
+		visitor.visit_borrowed_bytes(self.take(len)?)
+	}
+
+	fn deserialize_byte_buf<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+		self.deserialize_bytes(visitor)
+	}
+
+	fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+		match self.take(1)?[0] {
+			0 => visitor.visit_none(),
+			1 => visitor.visit_some(self),
+			_ => Err(Error),
+		}
+	}
+
+	fn deserialize_unit<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+		visitor.visit_unit()
+	}
+
+	fn deserialize_unit_struct<V: de::Visitor<'de>>(
+		self,
+		_name: &'static str,
+		visitor: V,
+	) -> Result<V::Value, Error> {
+		visitor.visit_unit()
+	}
+
+	fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+		self,
+		_name: &'static str,
+		visitor: V,
+	) -> Result<V::Value, Error> {
+		visitor.visit_newtype_struct(self)
+	}
+
+	fn deserialize_seq<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+		let remaining = self.length()?;
+		visitor.visit_seq(Elements {
+			decoder: self,
+			remaining,
+		})
+	}
+
+	fn deserialize_tuple<V: de::Visitor<'de>>(
+		self,
+		len: usize,
+		visitor: V,
+	) -> Result<V::Value, Error> {
+		visitor.visit_seq(Elements {
+			decoder: self,
+			remaining: len,
+		})
+	}
+
+	fn deserialize_tuple_struct<V: de::Visitor<'de>>(
+		self,
+		_name: &'static str,
+		len: usize,
+		visitor: V,
+	) -> Result<V::Value, Error> {
+		self.deserialize_tuple(len, visitor)
+	}
+
+	fn deserialize_map<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+		let remaining = self.length()?;
+		visitor.visit_map(Elements {
+			decoder: self,
+			remaining,
+		})
+	}
+
+	fn deserialize_struct<V: de::Visitor<'de>>(
+		self,
+		_name: &'static str,
+		fields: &'static [&'static str],
+		visitor: V,
+	) -> Result<V::Value, Error> {
+		self.deserialize_tuple(fields.len(), visitor)
+	}
+
+	fn deserialize_enum<V: de::Visitor<'de>>(
+		self,
+		_name: &'static str,
+		_variants: &'static [&'static str],
+		visitor: V,
+	) -> Result<V::Value, Error> {
+		visitor.visit_enum(self)
+	}
+
+	fn deserialize_identifier<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+		// Only enum variants are identified, by their index.
+		self.deserialize_u32(visitor)
+	}
+
+	fn deserialize_ignored_any<V: de::Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Error> {
+		Err(Error)
+	}
+
+	fn is_human_readable(&self) -> bool {
+		false
+	}
+}
+
+/// The elements of a sequence, tuple, struct or map.
+struct Elements<'a, 'de> {
+	decoder: &'a mut Decoder<'de>,
+	remaining: usize,
+}
+
+impl<'a, 'de> de::SeqAccess<'de> for Elements<'a, 'de> {
+	type Error = Error;
+
+	fn next_element_seed<T: de::DeserializeSeed<'de>>(
+		&mut self,
+		seed: T,
+	) -> Result<Option<T::Value>, Error> {
+		if self.remaining == 0 {
+			return Ok(None);
+		}
+		self.remaining -= 1;
+
+		seed.deserialize(&mut *self.decoder).map(Some)
+	}
+
+	fn size_hint(&self) -> Option<usize> {
+		// The length is not trusted before the elements have been read.
+		Some(self.remaining.min(self.decoder.src.len()))
+	}
+}
+
+impl<'a, 'de> de::MapAccess<'de> for Elements<'a, 'de> {
+	type Error = Error;
+
+	fn next_key_seed<K: de::DeserializeSeed<'de>>(
+		&mut self,
+		seed: K,
+	) -> Result<Option<K::Value>, Error> {
+		if self.remaining == 0 {
+			return Ok(None);
+		}
+		self.remaining -= 1;
+
+		seed.deserialize(&mut *self.decoder).map(Some)
+	}
+
+	fn next_value_seed<V: de::DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+		seed.deserialize(&mut *self.decoder)
+	}
+
+	fn size_hint(&self) -> Option<usize> {
+		Some(self.remaining.min(self.decoder.src.len()))
+	}
+}
+
+impl<'a, 'de> de::EnumAccess<'de> for &'a mut Decoder<'de> {
+	type Error = Error;
+	type Variant = Self;
+
+	fn variant_seed<V: de::DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self), Error> {
+		let variant = seed.deserialize(&mut *self)?;
+		Ok((variant, self))
+	}
+}
+
+impl<'a, 'de> de::VariantAccess<'de> for &'a mut Decoder<'de> {
+	type Error = Error;
+
+	fn unit_variant(self) -> Result<(), Error> {
+		Ok(())
+	}
+
+	fn newtype_variant_seed<T: de::DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+		seed.deserialize(self)
+	}
+
+	fn tuple_variant<V: de::Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, Error> {
+		de::Deserializer::deserialize_tuple(self, len, visitor)
+	}
+
+	fn struct_variant<V: de::Visitor<'de>>(
+		self,
+		fields: &'static [&'static str],
+		visitor: V,
+	) -> Result<V::Value, Error> {
+		de::Deserializer::deserialize_tuple(self, fields.len(), visitor)
+	}
+}
+
+// Testing private functions in the module.
+#[cfg(test)]
+mod private {
+	use super::*;
+	use alloc::collections::BTreeMap;
+	use alloc::string::ToString;
+	use alloc::vec;
+
+	fn roundtrip<T>(value: &T) -> T
+	where
+		T: ser::Serialize + de::DeserializeOwned,
+	{
+		let mut encoded = Vec::new();
+		to_vec(value, &mut encoded).unwrap();
+		from_slice(&encoded).unwrap()
+	}
+
+	#[test]
+	fn test_known_encoding() {
+		let mut encoded = Vec::new();
+		to_vec(
+			&(true, 0x0102u16, "ab", Some(-1i8), None::<u8>),
+			&mut encoded,
+		)
+		.unwrap();
+		assert_eq!(
+			encoded,
+			[1, 0x02, 0x01, 2, 0, 0, 0, 0, 0, 0, 0, b'a', b'b', 1, 0xff, 0]
+		);
+
+		let mut encoded = Vec::new();
+		to_vec(&Err::<u8, u16>(3), &mut encoded).unwrap();
+		assert_eq!(encoded, [1, 0, 0, 0, 3, 0]);
+	}
+
+	#[test]
+	fn test_roundtrip() {
+		let mut map = BTreeMap::new();
+		map.insert("one".to_string(), vec![1u64]);
+		map.insert("two".to_string(), vec![1u64, 2u64]);
+		assert_eq!(roundtrip(&map), map);
+
+		let value = (
+			u128::max_value(),
+			i64::min_value(),
+			1.5f64,
+			'ö',
+			Some(()),
+			Ok::<bool, String>(false),
+			Err::<bool, String>("error".to_string()),
+		);
+		assert_eq!(roundtrip(&value), value);
+		assert_eq!(roundtrip(&Vec::<u8>::new()), Vec::<u8>::new());
+	}
+
+	#[test]
+	fn test_trailing_bytes_err() {
+		assert!(from_slice::<u8>(&[1, 0]).is_err());
+		assert!(from_slice::<u8>(&[1]).is_ok());
+	}
+
+	#[test]
+	fn test_truncated_err() {
+		let mut encoded = Vec::new();
+		to_vec(&("string", 1u32), &mut encoded).unwrap();
+		for len in 0..encoded.len() {
+			assert!(from_slice::<(String, u32)>(&encoded[..len]).is_err());
+		}
+	}
+
+	#[test]
+	fn test_invalid_values_err() {
+		assert!(from_slice::<bool>(&[2]).is_err());
+		assert!(from_slice::<Option<u8>>(&[2, 0]).is_err());
+		assert!(from_slice::<char>(&0xd800u32.to_le_bytes()).is_err());
+		assert!(from_slice::<String>(&[1, 0, 0, 0, 0, 0, 0, 0, 0xff]).is_err());
+		assert!(from_slice::<Result<u8, u8>>(&[2, 0, 0, 0, 0]).is_err());
+		// A length longer than the input.
+		assert!(from_slice::<Vec<u8>>(&[0xff; 8]).is_err());
+	}
+}
@@ -0,0 +1,661 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Secure channels: an authenticated handshake followed by encrypted transport.
+//!
+//! # Use case:
+//! `orion::channel` can be used to protect a connection between two parties
+//! that know each other's long-term public keys, such as a client and a
+//! server, or two peers in a network.
+//!
+//! An example of this could be a TCP connection between services, where each
+//! service has been configured with the public keys of the others.
+//!
+//! # About:
+//! - The handshake takes two messages. The [`Initiator`] sends its
+//!   ephemeral X448 public key. The responding party answers with
+//!   [`respond()`], which sends its own ephemeral public key and a key
+//!   confirmation tag. The [`Initiator`] verifies the tag with
+//!   [`Initiator::finish()`].
+//! - Four X448 key agreements are made: between the ephemeral keys, between
+//!   each ephemeral key and the other party's long-term key, and between the
+//!   long-term keys. Their results are combined with HKDF-HMAC-SHA512, salted
+//!   with a BLAKE2b-512 hash of the transcript: both long-term public keys and
+//!   both ephemeral public keys.
+//! - The key confirmation tag is an HMAC-SHA512 of the transcript hash.
+//! - The handshake yields a [`Transport`] with one key for each direction.
+//!   Messages are sealed with [`orion::aead`]'s sessions, so each is numbered
+//!   and must be opened in the order it was sealed.
+//! - A sealed message is a frame: the length of the rest of the frame as 4
+//!   big-endian bytes, then the ciphertext and tag. A frame is
+//!   [`FRAME_OVERHEAD`] bytes longer than its plaintext.
+//!   [`Transport::send()`] and [`Transport::receive()`] write and read frames
+//!   on a stream.
+//!
+//! # Parameters:
+//! - `private_key`: This party's long-term private key.
+//! - `remote_public_key`: The other party's long-term public key.
+//! - `message`: The first handshake message, from [`Initiator::message()`].
+//! - `response`: The second handshake message, from [`respond()`].
+//! - `plaintext`: The data to be sent.
+//! - `frame`: A frame sealed by the other party's [`Transport`].
+//! - `rng`: The random generator used to generate the ephemeral private key.
+//!   Functions without `_with_rng` use [`OsRng`].
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `message` or `response` has the wrong length.
+//! - A public key is a low-order point.
+//! - The other party does not have the private key of `remote_public_key`,
+//!   or did not expect this party's public key.
+//! - `plaintext` is empty or longer than [`MAX_MESSAGE_SIZE`].
+//! - `frame` is malformed, has been tampered with, or is not the next frame
+//!   sealed by the other party.
+//! - Reading from or writing to the stream fails.
+//!
+//! # Panics:
+//! A panic will occur if:
+//! - Failure to generate random bytes securely.
+//!
+//! # Security:
+//! - The responding party only knows that the [`Initiator`] is authentic once
+//!   it has opened a frame from it. It should not send anything before that
+//!   which the [`Initiator`] must not learn without being authenticated.
+//! - The long-term public keys must be authentic. This module does not
+//!   distribute or verify them.
+//! - The handshake messages are not secret, and reveal the ephemeral public
+//!   keys only. The lengths of frames are not hidden.
+//! - After an error when opening a frame, the connection should be closed.
+//!   The [`Transport`] can only open the frame it expected next.
+//!
+//! # Example:
+//! ```rust
+//! use orion::channel::{self, Initiator, PrivateKey, PublicKey};
+//! use core::convert::TryFrom;
+//!
+//! let client_private_key = PrivateKey::generate();
+//! let client_public_key = PublicKey::try_from(&client_private_key)?;
+//! let server_private_key = PrivateKey::generate();
+//! let server_public_key = PublicKey::try_from(&server_private_key)?;
+//!
+//! // The client starts the handshake and sends its message to the server.
+//! let client = Initiator::new(&client_private_key, &server_public_key)?;
+//! let message = client.message();
+//! // The server responds.
+//! let (response, mut server) = channel::respond(&server_private_key, &client_public_key, &message)?;
+//! // The client finishes the handshake.
+//! let mut client = client.finish(&response)?;
+//!
+//! let frame = client.seal(b"Hello server")?;
+//! assert_eq!(server.open(&frame)?, b"Hello server");
+//! let frame = server.seal(b"Hello client")?;
+//! assert_eq!(client.open(&frame)?, b"Hello client");
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`Initiator`]: https://docs.rs/orion/latest/orion/channel/struct.Initiator.html
+//! [`Initiator::finish()`]: https://docs.rs/orion/latest/orion/channel/struct.Initiator.html#method.finish
+//! [`Initiator::message()`]: https://docs.rs/orion/latest/orion/channel/struct.Initiator.html#method.message
+//! [`respond()`]: https://docs.rs/orion/latest/orion/channel/fn.respond.html
+//! [`Transport`]: https://docs.rs/orion/latest/orion/channel/struct.Transport.html
+//! [`Transport::send()`]: https://docs.rs/orion/latest/orion/channel/struct.Transport.html#method.send
+//! [`Transport::receive()`]: https://docs.rs/orion/latest/orion/channel/struct.Transport.html#method.receive
+//! [`FRAME_OVERHEAD`]: https://docs.rs/orion/latest/orion/channel/constant.FRAME_OVERHEAD.html
+//! [`MAX_MESSAGE_SIZE`]: https://docs.rs/orion/latest/orion/channel/constant.MAX_MESSAGE_SIZE.html
+//! [`orion::aead`]: https://docs.rs/orion/latest/orion/aead/index.html
+//! [`OsRng`]: https://docs.rs/orion/latest/orion/util/struct.OsRng.html
+
+pub use crate::hazardous::ecc::x448::{PrivateKey, PublicKey};
+use crate::{
+	aead::{self, OpeningSession, SealingSession, SESSION_SEAL_OVERHEAD},
+	errors::UnknownCryptoError,
+	hazardous::{
+		ecc::x448::{self, PRIVATE_KEY_SIZE, PUBLIC_KEY_SIZE},
+		hash::blake2b,
+		kdf::hkdf,
+		mac::hmac,
+	},
+	util::{OsRng, SecureRandom},
+};
+use core::convert::TryFrom;
+use std::io::{Read, Write};
+use zeroize::Zeroize;
+
+/// The size of the first handshake message.
+pub const MESSAGE_SIZE: usize = PUBLIC_KEY_SIZE;
+/// The size of the second handshake message.
+pub const RESPONSE_SIZE: usize = PUBLIC_KEY_SIZE + CONFIRMATION_SIZE;
+/// The largest plaintext that can be sealed in a frame.
+pub const MAX_MESSAGE_SIZE: usize = 16 * 1024 * 1024;
+/// The amount of bytes a frame is longer than its plaintext.
+pub const FRAME_OVERHEAD: usize = LENGTH_SIZE + SESSION_SEAL_OVERHEAD;
+
+/// The size of the key confirmation tag.
+const CONFIRMATION_SIZE: usize = 64;
+/// The size of the length prefix of a frame.
+const LENGTH_SIZE: usize = 4;
+/// The size of each derived key.
+const KEY_SIZE: usize = 32;
+/// The context that the transcript hash and derived keys are bound to.
+const CONTEXT: &[u8] = b"orion channel v1";
+
+/// The keys derived by the handshake.
+struct HandshakeKeys {
+	initiator_to_responder: aead::SecretKey,
+	responder_to_initiator: aead::SecretKey,
+	confirmation: hmac::SecretKey,
+	transcript: [u8; 64],
+}
+
+/// Hash the public keys of the handshake and derive its keys from the
+/// results of the four key agreements, `ee || es || se || ss`.
+fn derive_keys(
+	shared_keys: &[u8],
+	initiator_public_key: &PublicKey,
+	responder_public_key: &PublicKey,
+	initiator_ephemeral_public_key: &PublicKey,
+	responder_ephemeral_public_key: &PublicKey,
+) -> Result<HandshakeKeys, UnknownCryptoError> {
+	let mut state = blake2b::init(None, 64)?;
+	state.update(CONTEXT)?;
+	state.update(initiator_public_key.as_ref())?;
+	state.update(responder_public_key.as_ref())?;
+	state.update(initiator_ephemeral_public_key.as_ref())?;
+	state.update(responder_ephemeral_public_key.as_ref())?;
+	let mut transcript = [0u8; 64];
+	transcript.copy_from_slice(state.finalize()?.as_ref());
+
+	let mut okm = [0u8; KEY_SIZE * 3];
+	hkdf::derive_key(&transcript, shared_keys, Some(CONTEXT), &mut okm)?;
+	let keys = HandshakeKeys {
+		initiator_to_responder: aead::SecretKey::from_slice(&okm[..KEY_SIZE])?,
+		responder_to_initiator: aead::SecretKey::from_slice(&okm[KEY_SIZE..KEY_SIZE * 2])?,
+		confirmation: hmac::SecretKey::from_slice(&okm[KEY_SIZE * 2..])?,
+		transcript,
+	};
+	okm.zeroize();
+
+	Ok(keys)
+}
+
+/// Compute the four key agreements, in the order `ee || es || se || ss`
+/// from the point of view of the initiator, into one buffer.
+fn agree(pairs: [(&PrivateKey, &PublicKey); 4]) -> Result<Vec<u8>, UnknownCryptoError> {
+	let mut shared_keys = Vec::with_capacity(PUBLIC_KEY_SIZE * 4);
+	for (private_key, public_key) in pairs.iter() {
+		match x448::key_agreement(private_key, public_key) {
+			Ok(shared_key) => shared_keys.extend_from_slice(shared_key.unprotected_as_bytes()),
+			Err(err) => {
+				shared_keys.zeroize();
+				return Err(err);
+			}
+		}
+	}
+
+	Ok(shared_keys)
+}
+
+/// Generate an ephemeral key pair with `rng`.
+fn generate_ephemeral<R: SecureRandom + ?Sized>(
+	rng: &mut R,
+) -> Result<(PrivateKey, PublicKey), UnknownCryptoError> {
+	let mut ephemeral = [0u8; PRIVATE_KEY_SIZE];
+	rng.fill_bytes(&mut ephemeral)?;
+	let private_key = PrivateKey::from(ephemeral);
+	ephemeral.zeroize();
+	let public_key = PublicKey::try_from(&private_key)?;
+
+	Ok((private_key, public_key))
+}
+
+/// The initiating party of a handshake, waiting for the response.
+pub struct Initiator<'a> {
+	private_key: &'a PrivateKey,
+	remote_public_key: &'a PublicKey,
+	ephemeral_private_key: PrivateKey,
+	ephemeral_public_key: PublicKey,
+}
+
+impl core::fmt::Debug for Initiator<'_> {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(
+			f,
+			"Initiator {{ remote_public_key: {:?}, ephemeral_public_key: {:?}, ***OMITTED*** }}",
+			self.remote_public_key, self.ephemeral_public_key
+		)
+	}
+}
+
+impl<'a> Initiator<'a> {
+	#[must_use]
+	/// Start a handshake with the party of `remote_public_key`.
+	pub fn new(
+		private_key: &'a PrivateKey,
+		remote_public_key: &'a PublicKey,
+	) -> Result<Self, UnknownCryptoError> {
+		Self::new_with_rng(private_key, remote_public_key, &mut OsRng)
+	}
+
+	#[must_use]
+	/// Start a handshake with the party of `remote_public_key`, with the
+	/// ephemeral private key generated by `rng`.
+	pub fn new_with_rng<R: SecureRandom + ?Sized>(
+		private_key: &'a PrivateKey,
+		remote_public_key: &'a PublicKey,
+		rng: &mut R,
+	) -> Result<Self, UnknownCryptoError> {
+		let (ephemeral_private_key, ephemeral_public_key) = generate_ephemeral(rng)?;
+
+		Ok(Self {
+			private_key,
+			remote_public_key,
+			ephemeral_private_key,
+			ephemeral_public_key,
+		})
+	}
+
+	/// The first handshake message, to be sent to the other party.
+	pub fn message(&self) -> [u8; MESSAGE_SIZE] {
+		let mut message = [0u8; MESSAGE_SIZE];
+		message.copy_from_slice(self.ephemeral_public_key.as_ref());
+
+		message
+	}
+
+	#[must_use]
+	/// Verify the `response` of the other party and finish the handshake.
+	pub fn finish(self, response: &[u8]) -> Result<Transport, UnknownCryptoError> {
+		if response.len() != RESPONSE_SIZE {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+		let remote_ephemeral_public_key = PublicKey::from_slice(&response[..PUBLIC_KEY_SIZE])?;
+		let confirmation = hmac::Tag::from_slice(&response[PUBLIC_KEY_SIZE..])?;
+
+		let mut shared_keys = agree([
+			(&self.ephemeral_private_key, &remote_ephemeral_public_key),
+			(&self.ephemeral_private_key, self.remote_public_key),
+			(self.private_key, &remote_ephemeral_public_key),
+			(self.private_key, self.remote_public_key),
+		])?;
+		let keys = derive_keys(
+			&shared_keys,
+			&PublicKey::try_from(self.private_key)?,
+			self.remote_public_key,
+			&self.ephemeral_public_key,
+			&remote_ephemeral_public_key,
+		);
+		shared_keys.zeroize();
+		let keys = keys?;
+		hmac::verify(&confirmation, &keys.confirmation, &keys.transcript)?;
+
+		Transport::new(&keys.initiator_to_responder, &keys.responder_to_initiator)
+	}
+}
+
+#[must_use]
+/// Answer the first handshake `message` from the party of
+/// `remote_public_key`, returning the response to send to it and the
+/// finished [`Transport`].
+///
+/// [`Transport`]: struct.Transport.html
+pub fn respond(
+	private_key: &PrivateKey,
+	remote_public_key: &PublicKey,
+	message: &[u8],
+) -> Result<([u8; RESPONSE_SIZE], Transport), UnknownCryptoError> {
+	respond_with_rng(private_key, remote_public_key, message, &mut OsRng)
+}
+
+#[must_use]
+/// Answer the first handshake `message` from the party of
+/// `remote_public_key`, with the ephemeral private key generated by `rng`.
+pub fn respond_with_rng<R: SecureRandom + ?Sized>(
+	private_key: &PrivateKey,
+	remote_public_key: &PublicKey,
+	message: &[u8],
+	rng: &mut R,
+) -> Result<([u8; RESPONSE_SIZE], Transport), UnknownCryptoError> {
+	if message.len() != MESSAGE_SIZE {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+	let remote_ephemeral_public_key = PublicKey::from_slice(message)?;
+	let (ephemeral_private_key, ephemeral_public_key) = generate_ephemeral(rng)?;
+
+	let mut shared_keys = agree([
+		(&ephemeral_private_key, &remote_ephemeral_public_key),
+		(private_key, &remote_ephemeral_public_key),
+		(&ephemeral_private_key, remote_public_key),
+		(private_key, remote_public_key),
+	])?;
+	let keys = derive_keys(
+		&shared_keys,
+		remote_public_key,
+		&PublicKey::try_from(private_key)?,
+		&remote_ephemeral_public_key,
+		&ephemeral_public_key,
+	);
+	shared_keys.zeroize();
+	let keys = keys?;
+
+	let mut response = [0u8; RESPONSE_SIZE];
+	response[..PUBLIC_KEY_SIZE].copy_from_slice(ephemeral_public_key.as_ref());
+	response[PUBLIC_KEY_SIZE..]
+		.copy_from_slice(hmac::hmac(&keys.confirmation, &keys.transcript)?.unprotected_as_bytes());
+
+	Ok((
+		response,
+		Transport::new(&keys.responder_to_initiator, &keys.initiator_to_responder)?,
+	))
+}
+
+#[derive(Debug)]
+/// The encrypted transport of a finished handshake, sealing frames for the
+/// other party and opening frames from it.
+pub struct Transport {
+	sending: SealingSession,
+	receiving: OpeningSession,
+}
+
+impl Transport {
+	fn new(
+		sending_key: &aead::SecretKey,
+		receiving_key: &aead::SecretKey,
+	) -> Result<Self, UnknownCryptoError> {
+		// Both keys are unique to this handshake, so the sessions can be
+		// numbered 0.
+		Ok(Self {
+			sending: SealingSession::new_with_counter(sending_key, 0)?,
+			receiving: OpeningSession::new_with_counter(receiving_key, 0)?,
+		})
+	}
+
+	#[must_use]
+	/// Seal `plaintext` in the next frame to the other party.
+	pub fn seal(&mut self, plaintext: &[u8]) -> Result<Vec<u8>, UnknownCryptoError> {
+		if plaintext.is_empty() {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+		if plaintext.len() > MAX_MESSAGE_SIZE {
+			return Err(UnknownCryptoError::SizeLimitExceeded);
+		}
+
+		let mut frame = vec![0u8; plaintext.len() + FRAME_OVERHEAD];
+		let written = self
+			.sending
+			.seal_into(plaintext, &mut frame[LENGTH_SIZE..])?;
+		frame[..LENGTH_SIZE].copy_from_slice(&(written as u32).to_be_bytes());
+
+		Ok(frame)
+	}
+
+	#[must_use]
+	/// Open the next `frame` from the other party.
+	pub fn open(&mut self, frame: &[u8]) -> Result<Vec<u8>, UnknownCryptoError> {
+		if frame.len() <= FRAME_OVERHEAD || frame.len() > MAX_MESSAGE_SIZE + FRAME_OVERHEAD {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+		let mut length = [0u8; LENGTH_SIZE];
+		length.copy_from_slice(&frame[..LENGTH_SIZE]);
+		if u32::from_be_bytes(length) as usize != frame.len() - LENGTH_SIZE {
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+
+		self.receiving.open(&frame[LENGTH_SIZE..])
+	}
+
+	#[must_use]
+	/// Seal `plaintext` in the next frame to the other party and write it to
+	/// `writer`.
+	pub fn send<W: Write>(
+		&mut self,
+		writer: &mut W,
+		plaintext: &[u8],
+	) -> Result<(), UnknownCryptoError> {
+		let frame = self.seal(plaintext)?;
+		writer.write_all(&frame).map_err(|_| UnknownCryptoError::Io)
+	}
+
+	#[must_use]
+	/// Read the next frame from the other party from `reader` and open it.
+	pub fn receive<R: Read>(&mut self, reader: &mut R) -> Result<Vec<u8>, UnknownCryptoError> {
+		let mut length = [0u8; LENGTH_SIZE];
+		reader
+			.read_exact(&mut length)
+			.map_err(|_| UnknownCryptoError::Io)?;
+		let length = u32::from_be_bytes(length) as usize;
+		if length <= SESSION_SEAL_OVERHEAD || length > MAX_MESSAGE_SIZE + SESSION_SEAL_OVERHEAD {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+
+		let mut sealed = vec![0u8; length];
+		reader
+			.read_exact(&mut sealed)
+			.map_err(|_| UnknownCryptoError::Io)?;
+
+		self.receiving.open(&sealed)
+	}
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	struct Party {
+		private_key: PrivateKey,
+		public_key: PublicKey,
+	}
+
+	impl Party {
+		fn new() -> Self {
+			let private_key = PrivateKey::generate();
+			Self {
+				public_key: PublicKey::try_from(&private_key).unwrap(),
+				private_key,
+			}
+		}
+	}
+
+	fn handshake(client: &Party, server: &Party) -> (Transport, Transport) {
+		let initiator = Initiator::new(&client.private_key, &server.public_key).unwrap();
+		let (response, server_transport) = respond(
+			&server.private_key,
+			&client.public_key,
+			&initiator.message(),
+		)
+		.unwrap();
+
+		(initiator.finish(&response).unwrap(), server_transport)
+	}
+
+	mod test_handshake {
+		use super::*;
+
+		#[test]
+		fn test_transports_match() {
+			let (client, server) = (Party::new(), Party::new());
+			let (mut client_transport, mut server_transport) = handshake(&client, &server);
+			for idx in 0..3u8 {
+				let frame = client_transport.seal(&[idx]).unwrap();
+				assert_eq!(server_transport.open(&frame).unwrap(), [idx]);
+				let frame = server_transport.seal(&[idx, idx]).unwrap();
+				assert_eq!(client_transport.open(&frame).unwrap(), [idx, idx]);
+			}
+		}
+
+		#[test]
+		fn test_wrong_responder_err() {
+			let (client, server, other) = (Party::new(), Party::new(), Party::new());
+			let initiator = Initiator::new(&client.private_key, &server.public_key).unwrap();
+			// A party without the server's private key cannot respond.
+			let (response, _) =
+				respond(&other.private_key, &client.public_key, &initiator.message()).unwrap();
+			assert!(initiator.finish(&response).is_err());
+		}
+
+		#[test]
+		fn test_wrong_initiator_err() {
+			let (client, server, other) = (Party::new(), Party::new(), Party::new());
+			// The server expects another client.
+			let initiator = Initiator::new(&client.private_key, &server.public_key).unwrap();
+			let (response, _) =
+				respond(&server.private_key, &other.public_key, &initiator.message()).unwrap();
+			assert!(initiator.finish(&response).is_err());
+		}
+
+		#[test]
+		fn test_modified_response_err() {
+			let (client, server) = (Party::new(), Party::new());
+			let initiator = Initiator::new(&client.private_key, &server.public_key).unwrap();
+			let (response, _) = respond(
+				&server.private_key,
+				&client.public_key,
+				&initiator.message(),
+			)
+			.unwrap();
+			for idx in [0, PUBLIC_KEY_SIZE, RESPONSE_SIZE - 1].iter() {
+				let initiator = Initiator::new(&client.private_key, &server.public_key).unwrap();
+				let mut modified = response;
+				modified[*idx] ^= 1;
+				assert!(initiator.finish(&modified).is_err());
+			}
+		}
+
+		#[test]
+		fn test_invalid_length_err() {
+			let (client, server) = (Party::new(), Party::new());
+			let initiator = Initiator::new(&client.private_key, &server.public_key).unwrap();
+			let message = initiator.message();
+			assert!(respond(&server.private_key, &client.public_key, &message[1..]).is_err());
+			let (response, _) = respond(&server.private_key, &client.public_key, &message).unwrap();
+			assert!(initiator.finish(&response[1..]).is_err());
+		}
+
+		#[test]
+		fn test_with_rng_deterministic() {
+			use crate::util::HmacDrbg;
+
+			let (client, server) = (Party::new(), Party::new());
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			let first =
+				Initiator::new_with_rng(&client.private_key, &server.public_key, &mut rng).unwrap();
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			let second =
+				Initiator::new_with_rng(&client.private_key, &server.public_key, &mut rng).unwrap();
+			assert_eq!(first.message(), second.message());
+		}
+	}
+
+	mod test_transport {
+		use super::*;
+
+		#[test]
+		fn test_frame_layout() {
+			let (client, server) = (Party::new(), Party::new());
+			let (mut client_transport, _) = handshake(&client, &server);
+			let frame = client_transport.seal(b"Hello").unwrap();
+			assert_eq!(frame.len(), 5 + FRAME_OVERHEAD);
+			assert_eq!(
+				&frame[..LENGTH_SIZE],
+				&((frame.len() - LENGTH_SIZE) as u32).to_be_bytes()[..]
+			);
+		}
+
+		#[test]
+		fn test_out_of_order_err() {
+			let (client, server) = (Party::new(), Party::new());
+			let (mut client_transport, mut server_transport) = handshake(&client, &server);
+			let first = client_transport.seal(b"first").unwrap();
+			let second = client_transport.seal(b"second").unwrap();
+			assert!(server_transport.open(&second).is_err());
+			assert_eq!(server_transport.open(&first).unwrap(), b"first");
+			assert!(server_transport.open(&first).is_err());
+			assert_eq!(server_transport.open(&second).unwrap(), b"second");
+		}
+
+		#[test]
+		fn test_own_frame_err() {
+			let (client, server) = (Party::new(), Party::new());
+			let (mut client_transport, _) = handshake(&client, &server);
+			let frame = client_transport.seal(b"Hello").unwrap();
+			assert!(client_transport.open(&frame).is_err());
+		}
+
+		#[test]
+		fn test_modified_err() {
+			let (client, server) = (Party::new(), Party::new());
+			let (mut client_transport, mut server_transport) = handshake(&client, &server);
+			let frame = client_transport.seal(b"Hello").unwrap();
+			for idx in 0..frame.len() {
+				let mut modified = frame.clone();
+				modified[idx] ^= 1;
+				assert!(server_transport.open(&modified).is_err());
+			}
+			assert!(server_transport.open(&frame[..frame.len() - 1]).is_err());
+			assert_eq!(server_transport.open(&frame).unwrap(), b"Hello");
+		}
+
+		#[test]
+		fn test_invalid_length_err() {
+			let (client, server) = (Party::new(), Party::new());
+			let (mut client_transport, _) = handshake(&client, &server);
+			assert!(client_transport.seal(b"").is_err());
+			assert_eq!(
+				client_transport
+					.seal(&vec![0u8; MAX_MESSAGE_SIZE + 1])
+					.unwrap_err(),
+				UnknownCryptoError::SizeLimitExceeded
+			);
+		}
+
+		#[test]
+		fn test_send_receive() {
+			use std::io::Cursor;
+
+			let (client, server) = (Party::new(), Party::new());
+			let (mut client_transport, mut server_transport) = handshake(&client, &server);
+			let mut stream = Vec::new();
+			client_transport.send(&mut stream, b"first").unwrap();
+			client_transport.send(&mut stream, b"second").unwrap();
+
+			let mut reader = Cursor::new(stream);
+			assert_eq!(server_transport.receive(&mut reader).unwrap(), b"first");
+			assert_eq!(server_transport.receive(&mut reader).unwrap(), b"second");
+			assert_eq!(
+				server_transport.receive(&mut reader).unwrap_err(),
+				UnknownCryptoError::Io
+			);
+		}
+
+		#[test]
+		fn test_receive_length_err() {
+			use std::io::Cursor;
+
+			let (client, server) = (Party::new(), Party::new());
+			let (_, mut server_transport) = handshake(&client, &server);
+			let mut reader = Cursor::new(vec![0xffu8; 64]);
+			assert_eq!(
+				server_transport.receive(&mut reader).unwrap_err(),
+				UnknownCryptoError::InvalidLength
+			);
+		}
+	}
+}
@@ -0,0 +1,228 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Commitments.
+//!
+//! # Use case:
+//! `orion::commitment` can be used to commit to a value without revealing
+//! it, and reveal it later in a way that proves it was not changed.
+//!
+//! An example of this could be a sealed-bid auction, where each bidder
+//! publishes a commitment to their bid, and reveals the bid once all
+//! commitments have been published.
+//!
+//! # About:
+//! - The [`Commitment`] is BLAKE2b-256 in keyed mode, with the [`Opening`]
+//!   as key, of a context string followed by the value.
+//! - The [`Opening`] is 32 random bytes. It hides the value, so that the
+//!   commitment reveals nothing about it, even if the value is one of few
+//!   possible values.
+//! - To reveal the value, the committer sends the value and the [`Opening`].
+//!
+//! # Parameters:
+//! - `value`: The value committed to.
+//! - `commitment`: The commitment returned by [`commit()`].
+//! - `opening`: The opening returned by [`commit()`].
+//! - `rng`: The random generator used to generate the opening in
+//!   [`commit_with_rng()`]. [`commit()`] uses [`OsRng`].
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `commitment` was not made to `value` with `opening`.
+//!
+//! # Panics:
+//! A panic will occur if:
+//! - Failure to generate random bytes securely.
+//!
+//! # Security:
+//! - The [`Opening`] must be kept secret until the value is revealed. Anyone
+//!   who knows it can test guesses of the value against the commitment.
+//! - An [`Opening`] must never be reused for a different commitment.
+//! - The commitment is compared in constant time.
+//! - A commitment does not identify the committer. If that matters, it must
+//!   be authenticated, such as by signing it.
+//!
+//! # Example:
+//! ```rust
+//! use orion::commitment;
+//!
+//! let (commitment, opening) = commitment::commit(b"bid: 100")?;
+//! // Publish `commitment`, and later reveal the bid and `opening`.
+//! assert!(commitment::verify(&commitment, b"bid: 100", &opening)?);
+//! assert!(commitment::verify(&commitment, b"bid: 200", &opening).is_err());
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`Commitment`]: https://docs.rs/orion/latest/orion/commitment/struct.Commitment.html
+//! [`Opening`]: https://docs.rs/orion/latest/orion/commitment/struct.Opening.html
+//! [`commit()`]: https://docs.rs/orion/latest/orion/commitment/fn.commit.html
+//! [`commit_with_rng()`]: https://docs.rs/orion/latest/orion/commitment/fn.commit_with_rng.html
+//! [`OsRng`]: https://docs.rs/orion/latest/orion/util/struct.OsRng.html
+
+use crate::{
+	errors::UnknownCryptoError,
+	hazardous::hash::blake2b,
+	util::{OsRng, SecureRandom},
+};
+
+/// The context string hashed before each value.
+const CONTEXT: &[u8] = b"orion::commitment";
+/// The size of a commitment and an opening.
+const SIZE: usize = 32;
+
+construct_public! {
+	/// A type to represent a commitment to a value.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 32 bytes.
+	(Commitment, test_commitment, SIZE, SIZE)
+}
+
+construct_secret_key! {
+	/// A type to represent the opening of a commitment, which must be kept
+	/// secret until the value is revealed.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 32 bytes.
+	///
+	/// # Panics:
+	/// A panic will occur if:
+	/// - Failure to generate random bytes securely.
+	(Opening, test_opening, SIZE, SIZE, SIZE)
+}
+
+/// The commitment to `value` with `opening`.
+fn compute(value: &[u8], opening: &Opening) -> Result<Commitment, UnknownCryptoError> {
+	let mut state = blake2b::init(
+		Some(&blake2b::SecretKey::from_slice(
+			opening.unprotected_as_bytes(),
+		)?),
+		SIZE,
+	)?;
+	state.update(CONTEXT)?;
+	state.update(value)?;
+
+	Commitment::from_slice(state.finalize()?.as_ref())
+}
+
+#[must_use]
+/// Commit to `value`, with a random opening.
+pub fn commit(value: &[u8]) -> Result<(Commitment, Opening), UnknownCryptoError> {
+	commit_with_rng(value, &mut OsRng)
+}
+
+#[must_use]
+/// Commit to `value`, with the opening generated by `rng`.
+pub fn commit_with_rng<R: SecureRandom + ?Sized>(
+	value: &[u8],
+	rng: &mut R,
+) -> Result<(Commitment, Opening), UnknownCryptoError> {
+	let opening = Opening::generate_with_rng(rng)?;
+
+	Ok((compute(value, &opening)?, opening))
+}
+
+#[must_use]
+/// Verify that `commitment` was made to `value` with `opening`, in constant
+/// time.
+pub fn verify(
+	commitment: &Commitment,
+	value: &[u8],
+	opening: &Opening,
+) -> Result<bool, UnknownCryptoError> {
+	if commitment == &compute(value, opening)? {
+		Ok(true)
+	} else {
+		Err(UnknownCryptoError::InvalidTag)
+	}
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	mod test_commit_verify {
+		use super::*;
+
+		#[test]
+		fn test_known() {
+			// Computed with Python's hashlib.blake2b.
+			let opening = Opening::from_slice(&[0x42u8; 32]).unwrap();
+			let expected = Commitment::from_slice(&[
+				0x48, 0x2a, 0x9b, 0xe4, 0xc7, 0x91, 0xd3, 0x10, 0x78, 0x3b, 0x41, 0x35, 0xd7, 0xff,
+				0x14, 0x7d, 0x09, 0xe9, 0x4c, 0x7e, 0x4b, 0x42, 0x20, 0xd9, 0xd5, 0x4f, 0xaf, 0x3c,
+				0xb0, 0xd5, 0x61, 0x7f,
+			])
+			.unwrap();
+
+			assert_eq!(compute(b"bid: 100", &opening).unwrap(), expected);
+			assert!(verify(&expected, b"bid: 100", &opening).unwrap());
+		}
+
+		#[test]
+		fn test_roundtrip() {
+			let (commitment, opening) = commit(b"value").unwrap();
+			assert!(verify(&commitment, b"value", &opening).unwrap());
+
+			let (commitment, opening) = commit(b"").unwrap();
+			assert!(verify(&commitment, b"", &opening).unwrap());
+		}
+
+		#[test]
+		fn test_hiding() {
+			let (first, _) = commit(b"value").unwrap();
+			let (second, _) = commit(b"value").unwrap();
+			assert_ne!(first, second);
+		}
+
+		#[test]
+		fn test_commit_with_rng_deterministic() {
+			use crate::util::HmacDrbg;
+
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			let (first, first_opening) = commit_with_rng(b"value", &mut rng).unwrap();
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			let (second, second_opening) = commit_with_rng(b"value", &mut rng).unwrap();
+
+			assert_eq!(first, second);
+			assert!(first_opening == second_opening);
+		}
+
+		#[test]
+		fn test_verify_err() {
+			let (commitment, opening) = commit(b"value").unwrap();
+			let (_, other_opening) = commit(b"value").unwrap();
+
+			assert!(verify(&commitment, b"Value", &opening).is_err());
+			assert!(verify(&commitment, b"value", &other_opening).is_err());
+			assert!(verify(&commitment, b"value ", &opening).is_err());
+
+			let mut modified = [0u8; 32];
+			modified.copy_from_slice(commitment.as_ref());
+			modified[0] ^= 1;
+			let modified = Commitment::from_slice(&modified).unwrap();
+			assert!(verify(&modified, b"value", &opening).is_err());
+		}
+	}
+}
@@ -0,0 +1,340 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Deterministic (convergent) encryption for deduplicating storage.
+//!
+//! # Use case:
+//! `orion::convergent` can be used to encrypt data such that equal
+//! plaintexts give equal ciphertexts, so that storage can deduplicate
+//! encrypted data without being able to read it.
+//!
+//! An example of this could be a backup service that stores each encrypted
+//! file or chunk only once, no matter how many of its users upload it.
+//!
+//! # About:
+//! - The [`ContentKey`] of a plaintext is derived from the plaintext itself,
+//!   with BLAKE2b-256 keyed with a [`ConvergenceSecret`]. Only parties that
+//!   share the [`ConvergenceSecret`] derive the same keys, and so
+//!   deduplicate with each other.
+//! - The plaintext is encrypted with XChaCha20Poly1305 under its
+//!   [`ContentKey`]. A key is only ever used for one plaintext, so the nonce
+//!   is all zeroes. The ciphertext is [`SEAL_OVERHEAD`] bytes longer than the
+//!   plaintext.
+//! - The [`ContentId`] of a ciphertext is its BLAKE2b-256 hash, which storage
+//!   can index ciphertexts by and check them against.
+//! - To read the data later, the [`ContentKey`] must be kept, for example in
+//!   a manifest that is itself encrypted with [`orion::aead`].
+//!
+//! # Parameters:
+//! - `convergence_secret`: The secret shared by the parties that deduplicate
+//!   with each other.
+//! - `plaintext`: The data to be encrypted.
+//! - `content_key`: The key returned by [`seal_deterministic()`].
+//! - `ciphertext`: The ciphertext returned by [`seal_deterministic()`].
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `plaintext` is empty.
+//! - `ciphertext` is less than 17 bytes ([`SEAL_OVERHEAD`] + 1).
+//! - `ciphertext` was not sealed under `content_key`, or has been tampered
+//!   with.
+//!
+//! # Panics:
+//! A panic will occur if:
+//! - Failure to generate random bytes securely.
+//!
+//! # Security:
+//! - This is **not** semantically secure. Anyone can tell whether two
+//!   ciphertexts hold the same plaintext, and anyone knowing the
+//!   [`ConvergenceSecret`] can tell whether a ciphertext holds a plaintext
+//!   they guess. For plaintexts with little entropy, such as a form with a
+//!   few fields filled in, this reveals the plaintext. Use [`orion::aead`]
+//!   for anything that does not need deduplication.
+//! - Never use a fixed nonce with [`orion::aead`] or the AEADs in
+//!   [`orion::hazardous`] to get deterministic encryption. That reuses the
+//!   nonce with different plaintexts under the same key.
+//! - The [`ConvergenceSecret`] decides who can deduplicate, and who can
+//!   confirm guesses. Use a different one for each group of users that should
+//!   not learn about each other's data.
+//! - The length of the plaintext is not hidden.
+//! - Storage should check that a ciphertext matches the [`ContentId`] it is
+//!   stored under, so that a ciphertext cannot be replaced by another.
+//!
+//! # Example:
+//! ```rust
+//! use orion::convergent::{self, ConvergenceSecret};
+//!
+//! let convergence_secret = ConvergenceSecret::generate();
+//!
+//! let (content_key, ciphertext) = convergent::seal_deterministic(&convergence_secret, b"Secret file")?;
+//! let (_, duplicate) = convergent::seal_deterministic(&convergence_secret, b"Secret file")?;
+//! assert_eq!(ciphertext, duplicate);
+//! assert_eq!(convergent::content_id(&ciphertext)?, convergent::content_id(&duplicate)?);
+//!
+//! assert_eq!(convergent::open_deterministic(&content_key, &ciphertext)?, b"Secret file");
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`ContentKey`]: https://docs.rs/orion/latest/orion/convergent/struct.ContentKey.html
+//! [`ContentId`]: https://docs.rs/orion/latest/orion/convergent/struct.ContentId.html
+//! [`ConvergenceSecret`]: https://docs.rs/orion/latest/orion/convergent/struct.ConvergenceSecret.html
+//! [`SEAL_OVERHEAD`]: https://docs.rs/orion/latest/orion/convergent/constant.SEAL_OVERHEAD.html
+//! [`seal_deterministic()`]: https://docs.rs/orion/latest/orion/convergent/fn.seal_deterministic.html
+//! [`orion::aead`]: https://docs.rs/orion/latest/orion/aead/index.html
+//! [`orion::hazardous`]: https://docs.rs/orion/latest/orion/hazardous/index.html
+
+use crate::{
+	errors::UnknownCryptoError,
+	hazardous::{
+		aead::xchacha20poly1305,
+		hash::blake2b,
+		mac::poly1305::POLY1305_OUTSIZE,
+		stream::{
+			chacha20,
+			xchacha20::{Nonce, XCHACHA_NONCESIZE},
+		},
+	},
+};
+
+/// The amount of bytes a ciphertext is longer than its plaintext.
+pub const SEAL_OVERHEAD: usize = POLY1305_OUTSIZE;
+
+/// The context string hashed before each plaintext to derive its key.
+const KEY_CONTEXT: &[u8] = b"orion::convergent key";
+/// The additional data of each ciphertext.
+const CONTEXT: &[u8] = b"orion::convergent";
+/// The size of keys, secrets and content identifiers.
+const SIZE: usize = 32;
+
+construct_secret_key! {
+	/// A type to represent the secret shared by the parties that deduplicate
+	/// with each other. Anyone knowing it can confirm guesses of plaintexts.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 32 bytes.
+	///
+	/// # Panics:
+	/// A panic will occur if:
+	/// - Failure to generate random bytes securely.
+	(ConvergenceSecret, test_convergence_secret, SIZE, SIZE, SIZE)
+}
+
+construct_secret_key! {
+	/// A type to represent the key of a plaintext, derived from the plaintext
+	/// itself. Equal plaintexts have equal keys.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 32 bytes.
+	///
+	/// # Panics:
+	/// A panic will occur if:
+	/// - Failure to generate random bytes securely.
+	(ContentKey, test_content_key, SIZE, SIZE, SIZE)
+}
+
+construct_public! {
+	/// A type to represent the identifier of a ciphertext, its BLAKE2b-256
+	/// hash.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 32 bytes.
+	(ContentId, test_content_id, SIZE, SIZE)
+}
+
+/// The all-zero nonce. Each content key only encrypts one plaintext.
+fn nonce() -> Nonce {
+	Nonce::from([0u8; XCHACHA_NONCESIZE])
+}
+
+#[must_use]
+/// Derive the key of `plaintext` with `convergence_secret`.
+pub fn content_key(
+	convergence_secret: &ConvergenceSecret,
+	plaintext: &[u8],
+) -> Result<ContentKey, UnknownCryptoError> {
+	let mut state = blake2b::init(
+		Some(&blake2b::SecretKey::from_slice(
+			convergence_secret.unprotected_as_bytes(),
+		)?),
+		SIZE,
+	)?;
+	state.update(KEY_CONTEXT)?;
+	state.update(plaintext)?;
+
+	ContentKey::from_slice(state.finalize()?.as_ref())
+}
+
+#[must_use]
+/// The identifier of `ciphertext`.
+pub fn content_id(ciphertext: &[u8]) -> Result<ContentId, UnknownCryptoError> {
+	let mut state = blake2b::init(None, SIZE)?;
+	state.update(ciphertext)?;
+
+	ContentId::from_slice(state.finalize()?.as_ref())
+}
+
+#[must_use]
+/// Deterministic authenticated encryption of `plaintext`, under a key derived
+/// from it with `convergence_secret`. Returns the key and the ciphertext.
+pub fn seal_deterministic(
+	convergence_secret: &ConvergenceSecret,
+	plaintext: &[u8],
+) -> Result<(ContentKey, Vec<u8>), UnknownCryptoError> {
+	if plaintext.is_empty() {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let content_key = content_key(convergence_secret, plaintext)?;
+	let mut ciphertext = vec![0u8; plaintext.len() + SEAL_OVERHEAD];
+	xchacha20poly1305::seal(
+		&chacha20::SecretKey::from_slice(content_key.unprotected_as_bytes())?,
+		&nonce(),
+		plaintext,
+		Some(CONTEXT),
+		&mut ciphertext,
+	)?;
+
+	Ok((content_key, ciphertext))
+}
+
+#[must_use]
+/// Authenticated decryption of a `ciphertext` sealed with
+/// `seal_deterministic()`.
+pub fn open_deterministic(
+	content_key: &ContentKey,
+	ciphertext: &[u8],
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	if ciphertext.len() <= SEAL_OVERHEAD {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let mut plaintext = vec![0u8; ciphertext.len() - SEAL_OVERHEAD];
+	xchacha20poly1305::open(
+		&chacha20::SecretKey::from_slice(content_key.unprotected_as_bytes())?,
+		&nonce(),
+		ciphertext,
+		Some(CONTEXT),
+		&mut plaintext,
+	)?;
+
+	Ok(plaintext)
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	mod test_seal_open {
+		use super::*;
+
+		#[test]
+		fn test_roundtrip() {
+			let secret = ConvergenceSecret::generate();
+			for len in [1, 64, 1000].iter() {
+				let plaintext = vec![0xa5u8; *len];
+				let (key, ciphertext) = seal_deterministic(&secret, &plaintext).unwrap();
+				assert_eq!(ciphertext.len(), plaintext.len() + SEAL_OVERHEAD);
+				assert_eq!(open_deterministic(&key, &ciphertext).unwrap(), plaintext);
+			}
+		}
+
+		#[test]
+		fn test_deterministic() {
+			let secret = ConvergenceSecret::generate();
+			let (first_key, first) = seal_deterministic(&secret, b"Secret file").unwrap();
+			let (second_key, second) = seal_deterministic(&secret, b"Secret file").unwrap();
+			assert_eq!(first, second);
+			assert!(first_key == second_key);
+			assert!(first_key == content_key(&secret, b"Secret file").unwrap());
+
+			let (other_key, other) = seal_deterministic(&secret, b"Other file").unwrap();
+			assert_ne!(first, other);
+			assert!(first_key != other_key);
+		}
+
+		#[test]
+		fn test_secret_separates() {
+			let (_, first) =
+				seal_deterministic(&ConvergenceSecret::generate(), b"Secret file").unwrap();
+			let (_, second) =
+				seal_deterministic(&ConvergenceSecret::generate(), b"Secret file").unwrap();
+			assert_ne!(first, second);
+		}
+
+		#[test]
+		fn test_known() {
+			// Computed with Python's hashlib.blake2b.
+			let secret = ConvergenceSecret::from_slice(&[0u8; 32]).unwrap();
+			let key = content_key(&secret, b"abc").unwrap();
+			let expected = ContentKey::from_slice(&[
+				0xed, 0x27, 0x59, 0xb9, 0x14, 0x1d, 0x9f, 0x5b, 0xc0, 0x49, 0x11, 0xf4, 0x0e, 0xc6,
+				0xcc, 0x07, 0x46, 0x33, 0xe0, 0x54, 0xcb, 0xd5, 0xe1, 0x81, 0x47, 0x09, 0x78, 0xf9,
+				0xaa, 0xe1, 0x1c, 0x33,
+			])
+			.unwrap();
+			assert!(key == expected);
+		}
+
+		#[test]
+		fn test_invalid_length_err() {
+			let secret = ConvergenceSecret::generate();
+			assert!(seal_deterministic(&secret, b"").is_err());
+			let (key, ciphertext) = seal_deterministic(&secret, b"Secret file").unwrap();
+			assert!(open_deterministic(&key, &ciphertext[..SEAL_OVERHEAD]).is_err());
+		}
+
+		#[test]
+		fn test_wrong_key_err() {
+			let secret = ConvergenceSecret::generate();
+			let (_, ciphertext) = seal_deterministic(&secret, b"Secret file").unwrap();
+			let (other_key, _) = seal_deterministic(&secret, b"Other file").unwrap();
+			assert!(open_deterministic(&other_key, &ciphertext).is_err());
+		}
+
+		#[test]
+		fn test_modified_err() {
+			let secret = ConvergenceSecret::generate();
+			let (key, ciphertext) = seal_deterministic(&secret, b"Secret file").unwrap();
+			for idx in 0..ciphertext.len() {
+				let mut modified = ciphertext.clone();
+				modified[idx] ^= 1;
+				assert!(open_deterministic(&key, &modified).is_err());
+			}
+		}
+	}
+
+	mod test_content_id {
+		use super::*;
+
+		#[test]
+		fn test_content_id() {
+			let secret = ConvergenceSecret::generate();
+			let (_, first) = seal_deterministic(&secret, b"Secret file").unwrap();
+			let (_, other) = seal_deterministic(&secret, b"Other file").unwrap();
+			assert_eq!(content_id(&first).unwrap(), content_id(&first).unwrap());
+			assert_ne!(content_id(&first).unwrap(), content_id(&other).unwrap());
+		}
+	}
+}
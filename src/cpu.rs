@@ -0,0 +1,155 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// Set once the CPU features have been detected.
+const DETECTED: usize = 1;
+const AVX2: usize = 1 << 1;
+const SSE41: usize = 1 << 2;
+const NEON: usize = 1 << 3;
+
+/// The detected CPU features, or `0` if detection has not run yet.
+static FEATURES: AtomicUsize = AtomicUsize::new(0);
+/// Whether all primitives must use their portable implementation.
+static FORCE_PORTABLE: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The CPU features that accelerated implementations may depend on.
+pub(crate) struct Features(usize);
+
+impl Features {
+	/// AVX2 is available.
+	pub(crate) fn avx2(self) -> bool {
+		self.0 & AVX2 != 0
+	}
+
+	/// SSE4.1 is available.
+	pub(crate) fn sse41(self) -> bool {
+		self.0 & SSE41 != 0
+	}
+
+	/// NEON is available.
+	pub(crate) fn neon(self) -> bool {
+		self.0 & NEON != 0
+	}
+
+	/// 128-bit or wider vector registers are available. Implementations that
+	/// process several blocks in parallel rely on them.
+	pub(crate) fn vector(self) -> bool {
+		self.avx2() || self.sse41() || self.neon()
+	}
+}
+
+#[cfg(all(feature = "safe_api", any(target_arch = "x86", target_arch = "x86_64")))]
+/// Detect CPU features at runtime.
+fn detect() -> usize {
+	let mut features = 0;
+	if is_x86_feature_detected!("avx2") {
+		features |= AVX2;
+	}
+	if is_x86_feature_detected!("sse4.1") {
+		features |= SSE41;
+	}
+
+	features
+}
+
+#[cfg(all(feature = "safe_api", target_arch = "aarch64"))]
+/// Detect CPU features at runtime.
+fn detect() -> usize {
+	let mut features = 0;
+	if std::arch::is_aarch64_feature_detected!("neon") {
+		features |= NEON;
+	}
+
+	features
+}
+
+#[cfg(not(all(
+	feature = "safe_api",
+	any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")
+)))]
+/// Without `std`, only the features enabled at compile time can be used.
+fn detect() -> usize {
+	let mut features = 0;
+	if cfg!(target_feature = "avx2") {
+		features |= AVX2;
+	}
+	if cfg!(target_feature = "sse4.1") {
+		features |= SSE41;
+	}
+	if cfg!(target_feature = "neon") {
+		features |= NEON;
+	}
+
+	features
+}
+
+/// The features of the current CPU. Detection only runs on first use.
+pub(crate) fn features() -> Features {
+	let mut features = FEATURES.load(Ordering::Relaxed);
+	if features & DETECTED == 0 {
+		features = detect() | DETECTED;
+		FEATURES.store(features, Ordering::Relaxed);
+	}
+
+	Features(features)
+}
+
+/// Whether accelerated implementations may be used. Each accelerated
+/// primitive must check this, and then `features()`, before choosing an
+/// implementation.
+pub(crate) fn use_accelerated() -> bool {
+	!FORCE_PORTABLE.load(Ordering::Relaxed)
+}
+
+/// Force all primitives to use their portable implementation, or allow them to
+/// use accelerated implementations again.
+pub(crate) fn force_portable(force: bool) {
+	FORCE_PORTABLE.store(force, Ordering::Relaxed);
+}
+
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	#[test]
+	fn test_features_detected_once() {
+		let first = features();
+		assert!(first.0 & DETECTED != 0);
+		assert_eq!(features(), first);
+	}
+
+	#[test]
+	#[cfg(target_arch = "aarch64")]
+	fn test_neon_on_aarch64() {
+		assert!(features().neon());
+	}
+
+	#[test]
+	#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+	fn test_no_x86_features() {
+		assert!(!features().avx2());
+		assert!(!features().sse41());
+	}
+}
@@ -78,12 +78,16 @@ impl_load!(u32, u32, from_le_bytes, load_u32_le);
 #[cfg(test)]
 impl_load_into!(u32, u32, from_le_bytes, load_u32_into_le);
 
+impl_load_into!(u32, u32, from_be_bytes, load_u32_into_be);
+
 impl_load_into!(u64, u64, from_le_bytes, load_u64_into_le);
 
 impl_load_into!(u64, u64, from_be_bytes, load_u64_into_be);
 
 impl_store_into!(u32, to_le_bytes, store_u32_into_le);
 
+impl_store_into!(u32, to_be_bytes, store_u32_into_be);
+
 impl_store_into!(u64, to_le_bytes, store_u64_into_le);
 
 impl_store_into!(u64, to_be_bytes, store_u64_into_be);
@@ -126,35 +130,43 @@ mod public {
 	}
 
 	test_empty_src_panic! {test_panic_empty_load_u32_le, &[0u8; 0], [0u32; 4], load_u32_into_le}
+	test_empty_src_panic! {test_panic_empty_load_u32_be, &[0u8; 0], [0u32; 4], load_u32_into_be}
 	test_empty_src_panic! {test_panic_empty_load_u64_le, &[0u8; 0], [0u64; 4], load_u64_into_le}
 	test_empty_src_panic! {test_panic_empty_load_u64_be, &[0u8; 0], [0u64; 4], load_u64_into_be}
 
 	test_empty_src_panic! {test_panic_empty_store_u32_le, &[0u32; 0], [0u8; 24], store_u32_into_le}
+	test_empty_src_panic! {test_panic_empty_store_u32_be, &[0u32; 0], [0u8; 24], store_u32_into_be}
 	test_empty_src_panic! {test_panic_empty_store_u64_le, &[0u64; 0], [0u8; 24], store_u64_into_le}
 	test_empty_src_panic! {test_panic_empty_store_u64_be, &[0u64; 0], [0u8; 24], store_u64_into_be}
 
 	// -1 too low
 	test_dst_length_panic! {test_dst_length_load_u32_le_low, &[0u8; 64], [0u32; 15], load_u32_into_le}
+	test_dst_length_panic! {test_dst_length_load_u32_be_low, &[0u8; 64], [0u32; 15], load_u32_into_be}
 	test_dst_length_panic! {test_dst_length_load_u64_le_low, &[0u8; 64], [0u64; 7], load_u64_into_le}
 	test_dst_length_panic! {test_dst_length_load_u64_be_low, &[0u8; 64], [0u64; 7], load_u64_into_be}
 
 	test_dst_length_panic! {test_dst_length_store_u32_le_low, &[0u32; 15], [0u8; 64], store_u32_into_le}
+	test_dst_length_panic! {test_dst_length_store_u32_be_low, &[0u32; 15], [0u8; 64], store_u32_into_be}
 	test_dst_length_panic! {test_dst_length_store_u64_le_low, &[0u64; 7], [0u8; 64], store_u64_into_le}
 	test_dst_length_panic! {test_dst_length_store_u64_be_low, &[0u64; 7], [0u8; 64], store_u64_into_be}
 	// +1 too high
 	test_dst_length_panic! {test_dst_length_load_u32_le_high, &[0u8; 64], [0u32; 17], load_u32_into_le}
+	test_dst_length_panic! {test_dst_length_load_u32_be_high, &[0u8; 64], [0u32; 17], load_u32_into_be}
 	test_dst_length_panic! {test_dst_length_load_u64_le_high, &[0u8; 64], [0u64; 9], load_u64_into_le}
 	test_dst_length_panic! {test_dst_length_load_u64_be_high, &[0u8; 64], [0u64; 9], load_u64_into_be}
 
 	test_dst_length_panic! {test_dst_length_store_u32_le_high, &[0u32; 17], [0u8; 64], store_u32_into_le}
+	test_dst_length_panic! {test_dst_length_store_u32_be_high, &[0u32; 17], [0u8; 64], store_u32_into_be}
 	test_dst_length_panic! {test_dst_length_store_u64_le_high, &[0u64; 9], [0u8; 64], store_u64_into_le}
 	test_dst_length_panic! {test_dst_length_store_u64_be_high, &[0u64; 9], [0u8; 64], store_u64_into_be}
 	// Ok
 	test_dst_length_ok! {test_dst_length_load_u32_le_ok, &[0u8; 64], [0u32; 16], load_u32_into_le}
+	test_dst_length_ok! {test_dst_length_load_u32_be_ok, &[0u8; 64], [0u32; 16], load_u32_into_be}
 	test_dst_length_ok! {test_dst_length_load_u64_le_ok, &[0u8; 64], [0u64; 8], load_u64_into_le}
 	test_dst_length_ok! {test_dst_length_load_u64_be_ok, &[0u8; 64], [0u64; 8], load_u64_into_be}
 
 	test_dst_length_ok! {test_dst_length_store_u32_le_ok, &[0u32; 16], [0u8; 64], store_u32_into_le}
+	test_dst_length_ok! {test_dst_length_store_u32_be_ok, &[0u32; 16], [0u8; 64], store_u32_into_be}
 	test_dst_length_ok! {test_dst_length_store_u64_le_ok, &[0u64; 8], [0u8; 64], store_u64_into_le}
 	test_dst_length_ok! {test_dst_length_store_u64_be_ok, &[0u64; 8], [0u8; 64], store_u64_into_be}
 
@@ -387,6 +399,22 @@ mod public {
 		assert_eq!(actual_nums_3, input_3);
 	}
 
+	#[test]
+	fn test_results_store_and_load_u32_into_be() {
+		let input: [u32; 4] = [0x0102_0304, 0xa0b0_c0d0, 0, 0xffff_ffff];
+		let expected: [u8; 16] = [
+			1, 2, 3, 4, 0xa0, 0xb0, 0xc0, 0xd0, 0, 0, 0, 0, 0xff, 0xff, 0xff, 0xff,
+		];
+
+		let mut actual_bytes = [0u8; 16];
+		store_u32_into_be(&input, &mut actual_bytes);
+		assert_eq!(actual_bytes, expected);
+
+		let mut actual_nums = [0u32; 4];
+		load_u32_into_be(&actual_bytes, &mut actual_nums);
+		assert_eq!(actual_nums, input);
+	}
+
 	#[test]
 	fn test_results_load_u32() {
 		let input_0: [u8; 4] = [203, 12, 195, 63];
@@ -24,19 +24,88 @@ extern crate core;
 
 use self::core::fmt;
 
-/// Opaque error.
-#[derive(PartialEq)]
-pub struct UnknownCryptoError;
+#[non_exhaustive]
+#[derive(Clone, Copy, PartialEq, Eq)]
+/// The kind of error that occurred during a cryptographic operation.
+///
+/// # Security:
+/// - The kind only tells which check failed, never anything about secret data.
+///   Secret data is always compared in constant time, and a failed comparison
+///   is reported as [`InvalidTag`] only once the whole comparison is done.
+/// - The kind returned for a given input may become more precise in future
+///   versions. Matching on it is meant for error reporting and for telling
+///   misuse apart from tampering, not for control flow that must be stable.
+///
+/// [`InvalidTag`]: enum.UnknownCryptoError.html#variant.InvalidTag
+pub enum UnknownCryptoError {
+	/// An input, output or key has an invalid length, or is empty where that
+	/// is not allowed.
+	InvalidLength,
+	/// Verification failed: an authentication tag, MAC, signature, password
+	/// hash or key confirmation did not match. For AEADs this means the
+	/// ciphertext, the additional data, the nonce or the key is wrong.
+	InvalidTag,
+	/// Data to be decoded or parsed is malformed, such as invalid hex, an
+	/// unsupported header version or a point that is not on the curve.
+	InvalidEncoding,
+	/// A key is not valid for the operation, such as a low-order public key
+	/// or a scalar outside of the valid range.
+	InvalidKey,
+	/// A nonce, a block counter or a message counter would overflow.
+	NonceCounterOverflow,
+	/// The maximum amount of data that can be processed has been reached.
+	SizeLimitExceeded,
+	/// A streaming state or protocol state was used out of order, such as
+	/// updating a finalized hash state.
+	FinalizedState,
+	/// A parameter is outside of its allowed range, such as an iteration count
+	/// of 0.
+	ParameterOutOfRange,
+	/// The operating system's random number generator failed.
+	Rng,
+	/// Reading or writing failed.
+	Io,
+}
+
+impl UnknownCryptoError {
+	/// A short, lowercase description of the error kind.
+	fn as_str(&self) -> &'static str {
+		match *self {
+			UnknownCryptoError::InvalidLength => "invalid length",
+			UnknownCryptoError::InvalidTag => "verification failed",
+			UnknownCryptoError::InvalidEncoding => "invalid encoding",
+			UnknownCryptoError::InvalidKey => "invalid key",
+			UnknownCryptoError::NonceCounterOverflow => "nonce or counter overflow",
+			UnknownCryptoError::SizeLimitExceeded => "size limit exceeded",
+			UnknownCryptoError::FinalizedState => "invalid state",
+			UnknownCryptoError::ParameterOutOfRange => "parameter out of range",
+			UnknownCryptoError::Rng => "random number generator failure",
+			UnknownCryptoError::Io => "I/O failure",
+		}
+	}
+}
 
 impl fmt::Display for UnknownCryptoError {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		write!(f, "UnknownCryptoError")
+		write!(f, "UnknownCryptoError: {}", self.as_str())
 	}
 }
 
 impl fmt::Debug for UnknownCryptoError {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		write!(f, "UnknownCryptoError")
+		let kind = match *self {
+			UnknownCryptoError::InvalidLength => "InvalidLength",
+			UnknownCryptoError::InvalidTag => "InvalidTag",
+			UnknownCryptoError::InvalidEncoding => "InvalidEncoding",
+			UnknownCryptoError::InvalidKey => "InvalidKey",
+			UnknownCryptoError::NonceCounterOverflow => "NonceCounterOverflow",
+			UnknownCryptoError::SizeLimitExceeded => "SizeLimitExceeded",
+			UnknownCryptoError::FinalizedState => "FinalizedState",
+			UnknownCryptoError::ParameterOutOfRange => "ParameterOutOfRange",
+			UnknownCryptoError::Rng => "Rng",
+			UnknownCryptoError::Io => "Io",
+		};
+		write!(f, "UnknownCryptoError::{}", kind)
 	}
 }
 
@@ -44,7 +113,105 @@ impl fmt::Debug for UnknownCryptoError {
 // Required for rand's generators
 impl From<getrandom::Error> for UnknownCryptoError {
 	fn from(_: getrandom::Error) -> Self {
-		UnknownCryptoError
+		UnknownCryptoError::Rng
+	}
+}
+
+impl core::error::Error for UnknownCryptoError {}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+/// An [`UnknownCryptoError`] with context attached, such as the name of the
+/// parameter that failed validation.
+///
+/// # About:
+/// - Context is attached with [`ErrorContext::in_context()`].
+/// - The context is a `&'static str`, so that only fixed, non-sensitive
+///   strings can be attached, never runtime data such as key material.
+/// - [`source()`] returns the underlying [`UnknownCryptoError`], so the kind
+///   is kept when the error is converted to `anyhow::Error` or wrapped by a
+///   `thiserror` type.
+/// - A `ContextError` converts back into an [`UnknownCryptoError`] with `?`,
+///   dropping the context.
+///
+/// # Example:
+/// ```rust
+/// use orion::errors::{ErrorContext, UnknownCryptoError};
+/// use orion::hazardous::stream::chacha20::SecretKey;
+///
+/// let err = SecretKey::from_slice(&[0u8; 16])
+/// 	.in_context("secret_key")
+/// 	.unwrap_err();
+///
+/// assert_eq!(err.kind(), UnknownCryptoError::InvalidLength);
+/// assert_eq!(err.context(), "secret_key");
+/// assert_eq!(
+/// 	err.to_string(),
+/// 	"secret_key: UnknownCryptoError: invalid length"
+/// );
+/// ```
+/// [`ErrorContext::in_context()`]: trait.ErrorContext.html#tymethod.in_context
+/// [`source()`]: https://doc.rust-lang.org/core/error/trait.Error.html#method.source
+pub struct ContextError {
+	kind: UnknownCryptoError,
+	context: &'static str,
+}
+
+impl ContextError {
+	#[must_use]
+	/// Attach `context` to `kind`.
+	pub fn new(kind: UnknownCryptoError, context: &'static str) -> Self {
+		Self { kind, context }
+	}
+
+	/// The kind of error that occurred.
+	pub fn kind(&self) -> UnknownCryptoError {
+		self.kind
+	}
+
+	/// The context attached to the error.
+	pub fn context(&self) -> &'static str {
+		self.context
+	}
+}
+
+impl fmt::Display for ContextError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}: {}", self.context, self.kind)
+	}
+}
+
+impl fmt::Debug for ContextError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"ContextError {{ kind: {:?}, context: {:?} }}",
+			self.kind, self.context
+		)
+	}
+}
+
+impl core::error::Error for ContextError {
+	fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+		Some(&self.kind)
+	}
+}
+
+impl From<ContextError> for UnknownCryptoError {
+	fn from(err: ContextError) -> Self {
+		err.kind
+	}
+}
+
+/// Attach context to the error of a `Result`.
+pub trait ErrorContext<T> {
+	/// Attach `context`, such as the name of the parameter that failed
+	/// validation, to the error.
+	fn in_context(self, context: &'static str) -> Result<T, ContextError>;
+}
+
+impl<T> ErrorContext<T> for Result<T, UnknownCryptoError> {
+	fn in_context(self, context: &'static str) -> Result<T, ContextError> {
+		self.map_err(|kind| ContextError::new(kind, context))
 	}
 }
 
@@ -53,9 +220,60 @@ impl From<getrandom::Error> for UnknownCryptoError {
 // format! is only available with std
 fn test_unknown_crypto_error_debug_display() {
 	// Tests Debug impl though "{:?}"
-	let err = format!("{:?}", UnknownCryptoError);
-	assert_eq!(err, "UnknownCryptoError");
+	let err = format!("{:?}", UnknownCryptoError::InvalidTag);
+	assert_eq!(err, "UnknownCryptoError::InvalidTag");
 	// Tests Display impl though "{:?}"
-	let err = format!("{}", UnknownCryptoError);
-	assert_eq!(err, "UnknownCryptoError");
+	let err = format!("{}", UnknownCryptoError::InvalidTag);
+	assert_eq!(err, "UnknownCryptoError: verification failed");
+}
+
+#[test]
+#[cfg(feature = "safe_api")]
+fn test_from_getrandom_error() {
+	let os_err = getrandom::Error::from(core::num::NonZeroU32::new(1).unwrap());
+	let err: UnknownCryptoError = os_err.into();
+	assert_eq!(err, UnknownCryptoError::Rng);
+}
+
+#[test]
+#[cfg(feature = "safe_api")]
+fn test_error_trait() {
+	use core::error::Error;
+
+	let err: Box<dyn Error> = Box::new(UnknownCryptoError::InvalidTag);
+	assert!(err.source().is_none());
+	assert_eq!(err.to_string(), "UnknownCryptoError: verification failed");
+}
+
+#[test]
+fn test_error_context() {
+	use core::error::Error;
+
+	let res: Result<(), UnknownCryptoError> = Err(UnknownCryptoError::InvalidLength);
+	let err = res.in_context("dst_out").unwrap_err();
+	assert_eq!(err.kind(), UnknownCryptoError::InvalidLength);
+	assert_eq!(err.context(), "dst_out");
+	assert!(err.source().is_some());
+	assert_eq!(
+		UnknownCryptoError::from(err),
+		UnknownCryptoError::InvalidLength
+	);
+
+	let res: Result<u8, UnknownCryptoError> = Ok(1);
+	assert_eq!(res.in_context("dst_out"), Ok(1));
+}
+
+#[test]
+#[cfg(feature = "safe_api")]
+// format! is only available with std
+fn test_error_context_debug_display() {
+	let err = ContextError::new(UnknownCryptoError::ParameterOutOfRange, "iterations");
+	assert_eq!(
+		format!("{}", err),
+		"iterations: UnknownCryptoError: parameter out of range"
+	);
+	assert_eq!(
+		format!("{:?}", err),
+		"ContextError { kind: UnknownCryptoError::ParameterOutOfRange, context: \"iterations\" }"
+	);
 }
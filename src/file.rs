@@ -0,0 +1,717 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! File and stream encryption.
+//!
+//! # Use case:
+//! `orion::file` can be used to encrypt files, or any other data too large to
+//! comfortably keep in memory, with a secret key.
+//!
+//! An example of this could be encrypting backups before uploading them to
+//! a storage provider.
+//!
+//! # About:
+//! - The data is split into chunks of 64 KiB, which are each encrypted with
+//!   XChaCha20Poly1305. Memory usage is bounded by the chunk size, regardless
+//!   of the size of the data.
+//! - Each chunk's nonce is a random prefix, followed by the chunk counter and
+//!   a flag marking the final chunk. This is the STREAM construction of
+//!   [Hoang et al.](https://eprint.iacr.org/2015/189), which prevents chunks
+//!   from being reordered, dropped or truncated without detection.
+//! - The header is authenticated as additional data of every chunk.
+//! - The output is laid out as follows:
+//!
+//! | Field        | Size                                |
+//! |--------------|-------------------------------------|
+//! | Magic        | 8 bytes: `ORIONSTR`                 |
+//! | Version      | 1 byte: `1`                         |
+//! | Chunk size   | 4 bytes, big-endian                 |
+//! | Nonce prefix | 15 bytes                            |
+//! | Chunks       | Each chunk size + 16 bytes, the last may be shorter |
+//!
+//! # Parameters:
+//! - `secret_key`: The secret key used to encrypt and decrypt.
+//! - `reader`/`src`: The data to encrypt or decrypt.
+//! - `writer`/`dst`: Where the encrypted or decrypted data is written.
+//! - `progress`: An optional callback, called with the total amount of
+//!   plaintext bytes processed so far after each chunk.
+//! - `rng`: The random generator used to generate the nonce prefix in
+//!   [`encrypt_with_rng()`]. All other functions use [`OsRng`].
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `secret_key` is not 32 bytes.
+//! - The data to encrypt is empty.
+//! - The header is malformed, has an unsupported version, or a chunk size
+//!   greater than 16 MiB.
+//! - Any chunk fails to authenticate, or the data has been truncated.
+//! - Reading or writing fails.
+//!
+//! # Panics:
+//! A panic will occur if:
+//! - Failure to generate random bytes securely.
+//!
+//! # Security:
+//! - [`decrypt()`] writes each chunk once it has been authenticated. If an
+//!   error is returned, the data already written must be discarded, as it may
+//!   be incomplete. [`decrypt_file()`] removes `dst` if decryption fails.
+//! - To securely generate a strong key, use [`SecretKey::default()`].
+//!
+//! # Example:
+//! ```rust
+//! use orion::file;
+//!
+//! let secret_key = file::SecretKey::default();
+//! let data = vec![0x42u8; 200_000];
+//!
+//! let mut encrypted = Vec::new();
+//! file::encrypt(&secret_key, &data[..], &mut encrypted, None)?;
+//!
+//! let mut progress = |processed: u64| println!("Decrypted {} bytes", processed);
+//! let mut decrypted = Vec::new();
+//! file::decrypt(&secret_key, &encrypted[..], &mut decrypted, Some(&mut progress))?;
+//!
+//! assert_eq!(data, decrypted);
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`decrypt()`]: https://docs.rs/orion/latest/orion/file/fn.decrypt.html
+//! [`encrypt_with_rng()`]: https://docs.rs/orion/latest/orion/file/fn.encrypt_with_rng.html
+//! [`OsRng`]: https://docs.rs/orion/latest/orion/util/struct.OsRng.html
+//! [`decrypt_file()`]: https://docs.rs/orion/latest/orion/file/fn.decrypt_file.html
+//! [`SecretKey::default()`]: https://docs.rs/orion/latest/orion/file/struct.SecretKey.html
+
+pub use crate::hltypes::SecretKey;
+use crate::{
+	errors::UnknownCryptoError,
+	hazardous::{
+		aead::xchacha20poly1305,
+		mac::poly1305::POLY1305_OUTSIZE,
+		stream::{
+			chacha20,
+			xchacha20::{Nonce, XCHACHA_NONCESIZE},
+		},
+	},
+	util::{OsRng, SecureRandom},
+};
+use std::{
+	fs::{self, File},
+	io::{self, BufReader, BufWriter, Read, Write},
+	path::Path,
+};
+use zeroize::Zeroize;
+
+/// The magic bytes at the start of the output.
+const MAGIC: &[u8; 8] = b"ORIONSTR";
+/// The current format version.
+const VERSION: u8 = 1;
+/// The size of the random nonce prefix.
+const NONCE_PREFIX_SIZE: usize = XCHACHA_NONCESIZE - 8 - 1;
+/// The size of the header.
+pub(crate) const HEADER_SIZE: usize = MAGIC.len() + 1 + 4 + NONCE_PREFIX_SIZE;
+/// The chunk size used when encrypting.
+pub const CHUNK_SIZE: usize = 64 * 1024;
+/// The largest chunk size accepted when decrypting.
+const MAX_CHUNK_SIZE: usize = 16 * 1024 * 1024;
+
+/// Read until `buf` is full or the end of `reader` is reached, returning the
+/// amount of bytes read.
+fn read_full<R: Read>(reader: &mut R, buf: &mut [u8]) -> Result<usize, UnknownCryptoError> {
+	let mut filled = 0;
+	while filled < buf.len() {
+		match reader.read(&mut buf[filled..]) {
+			Ok(0) => break,
+			Ok(n) => filled += n,
+			Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+			Err(_) => return Err(UnknownCryptoError::Io),
+		}
+	}
+
+	Ok(filled)
+}
+
+/// A new header with a nonce prefix generated by `rng`.
+pub(crate) fn new_header<G: SecureRandom + ?Sized>(
+	rng: &mut G,
+) -> Result<[u8; HEADER_SIZE], UnknownCryptoError> {
+	let mut header = [0u8; HEADER_SIZE];
+	header[..MAGIC.len()].copy_from_slice(MAGIC);
+	header[8] = VERSION;
+	header[9..13].copy_from_slice(&(CHUNK_SIZE as u32).to_be_bytes());
+	rng.fill_bytes(&mut header[13..])?;
+
+	Ok(header)
+}
+
+/// Validate a header, returning its chunk size.
+pub(crate) fn parse_header(header: &[u8; HEADER_SIZE]) -> Result<usize, UnknownCryptoError> {
+	if &header[..MAGIC.len()] != MAGIC || header[8] != VERSION {
+		return Err(UnknownCryptoError::InvalidEncoding);
+	}
+	let mut chunk_size = [0u8; 4];
+	chunk_size.copy_from_slice(&header[9..13]);
+	let chunk_size = u32::from_be_bytes(chunk_size) as usize;
+	if chunk_size == 0 || chunk_size > MAX_CHUNK_SIZE {
+		return Err(UnknownCryptoError::InvalidEncoding);
+	}
+
+	Ok(chunk_size)
+}
+
+/// The nonce of a chunk: `prefix || counter || last`, with the prefix taken
+/// from `header`.
+pub(crate) fn chunk_nonce(
+	header: &[u8; HEADER_SIZE],
+	counter: u64,
+	last: bool,
+) -> Result<Nonce, UnknownCryptoError> {
+	let mut nonce = [0u8; XCHACHA_NONCESIZE];
+	nonce[..NONCE_PREFIX_SIZE].copy_from_slice(&header[HEADER_SIZE - NONCE_PREFIX_SIZE..]);
+	nonce[NONCE_PREFIX_SIZE..XCHACHA_NONCESIZE - 1].copy_from_slice(&counter.to_be_bytes());
+	nonce[XCHACHA_NONCESIZE - 1] = last as u8;
+
+	Nonce::from_slice(&nonce)
+}
+
+/// Process `reader` in chunks of `chunk_size`, calling `process` with each
+/// chunk, its counter and whether it is the final one. Returns an error if
+/// `reader` is empty.
+fn for_each_chunk<R: Read>(
+	reader: &mut R,
+	chunk_size: usize,
+	mut process: impl FnMut(&[u8], u64, bool) -> Result<(), UnknownCryptoError>,
+) -> Result<(), UnknownCryptoError> {
+	let mut current = vec![0u8; chunk_size];
+	let mut next = vec![0u8; chunk_size];
+	let mut current_len = read_full(reader, &mut current)?;
+	let mut counter = 0u64;
+
+	let result = loop {
+		if current_len == 0 {
+			break Err(UnknownCryptoError::InvalidLength);
+		}
+		let next_len = match read_full(reader, &mut next) {
+			Ok(len) => len,
+			Err(e) => break Err(e),
+		};
+		let last = next_len == 0;
+
+		if let Err(e) = process(&current[..current_len], counter, last) {
+			break Err(e);
+		}
+		if last {
+			break Ok(());
+		}
+
+		core::mem::swap(&mut current, &mut next);
+		current_len = next_len;
+		counter = match counter.checked_add(1) {
+			Some(counter) => counter,
+			None => break Err(UnknownCryptoError::NonceCounterOverflow),
+		};
+	};
+
+	current.zeroize();
+	next.zeroize();
+
+	result
+}
+
+#[must_use]
+/// Encrypt everything in `reader` and write it to `writer`.
+pub fn encrypt<R: Read, W: Write>(
+	secret_key: &SecretKey,
+	reader: R,
+	writer: W,
+	progress: Option<&mut dyn FnMut(u64)>,
+) -> Result<(), UnknownCryptoError> {
+	encrypt_with_rng(secret_key, reader, writer, progress, &mut OsRng)
+}
+
+#[must_use]
+/// Encrypt everything in `reader` and write it to `writer`, with the nonce
+/// prefix generated by `rng`.
+pub fn encrypt_with_rng<R: Read, W: Write, G: SecureRandom + ?Sized>(
+	secret_key: &SecretKey,
+	mut reader: R,
+	mut writer: W,
+	mut progress: Option<&mut dyn FnMut(u64)>,
+	rng: &mut G,
+) -> Result<(), UnknownCryptoError> {
+	let key = chacha20::SecretKey::from_slice(secret_key.unprotected_as_bytes())?;
+
+	let header = new_header(rng)?;
+	writer
+		.write_all(&header)
+		.map_err(|_| UnknownCryptoError::Io)?;
+
+	let mut dst_out = vec![0u8; CHUNK_SIZE + POLY1305_OUTSIZE];
+	let mut processed = 0u64;
+	for_each_chunk(&mut reader, CHUNK_SIZE, |chunk, counter, last| {
+		let ciphertext = &mut dst_out[..chunk.len() + POLY1305_OUTSIZE];
+		xchacha20poly1305::seal(
+			&key,
+			&chunk_nonce(&header, counter, last)?,
+			chunk,
+			Some(&header),
+			ciphertext,
+		)?;
+		writer
+			.write_all(ciphertext)
+			.map_err(|_| UnknownCryptoError::Io)?;
+
+		processed += chunk.len() as u64;
+		if let Some(ref mut progress) = progress {
+			progress(processed);
+		}
+
+		Ok(())
+	})?;
+
+	writer.flush().map_err(|_| UnknownCryptoError::Io)
+}
+
+#[must_use]
+/// Decrypt everything in `reader` and write it to `writer`.
+pub fn decrypt<R: Read, W: Write>(
+	secret_key: &SecretKey,
+	mut reader: R,
+	mut writer: W,
+	mut progress: Option<&mut dyn FnMut(u64)>,
+) -> Result<(), UnknownCryptoError> {
+	let key = chacha20::SecretKey::from_slice(secret_key.unprotected_as_bytes())?;
+
+	let mut header = [0u8; HEADER_SIZE];
+	if read_full(&mut reader, &mut header)? != HEADER_SIZE {
+		return Err(UnknownCryptoError::InvalidEncoding);
+	}
+	let chunk_size = parse_header(&header)?;
+
+	let mut dst_out = vec![0u8; chunk_size];
+	let mut processed = 0u64;
+	let result = for_each_chunk(
+		&mut reader,
+		chunk_size + POLY1305_OUTSIZE,
+		|chunk, counter, last| {
+			if chunk.len() <= POLY1305_OUTSIZE {
+				return Err(UnknownCryptoError::InvalidEncoding);
+			}
+			let plaintext = &mut dst_out[..chunk.len() - POLY1305_OUTSIZE];
+			xchacha20poly1305::open(
+				&key,
+				&chunk_nonce(&header, counter, last)?,
+				chunk,
+				Some(&header),
+				plaintext,
+			)?;
+			writer
+				.write_all(plaintext)
+				.map_err(|_| UnknownCryptoError::Io)?;
+
+			processed += plaintext.len() as u64;
+			if let Some(ref mut progress) = progress {
+				progress(processed);
+			}
+
+			Ok(())
+		},
+	);
+	dst_out.zeroize();
+	result?;
+
+	writer.flush().map_err(|_| UnknownCryptoError::Io)
+}
+
+#[must_use]
+/// Encrypt the file at `src` and write it to a new file at `dst`.
+pub fn encrypt_file(
+	secret_key: &SecretKey,
+	src: &Path,
+	dst: &Path,
+	progress: Option<&mut dyn FnMut(u64)>,
+) -> Result<(), UnknownCryptoError> {
+	let reader = BufReader::new(File::open(src).map_err(|_| UnknownCryptoError::Io)?);
+	let writer = BufWriter::new(File::create(dst).map_err(|_| UnknownCryptoError::Io)?);
+
+	encrypt(secret_key, reader, writer, progress)
+}
+
+#[must_use]
+/// Decrypt the file at `src` and write it to a new file at `dst`. `dst` is
+/// removed if decryption fails.
+pub fn decrypt_file(
+	secret_key: &SecretKey,
+	src: &Path,
+	dst: &Path,
+	progress: Option<&mut dyn FnMut(u64)>,
+) -> Result<(), UnknownCryptoError> {
+	let reader = BufReader::new(File::open(src).map_err(|_| UnknownCryptoError::Io)?);
+	let writer = BufWriter::new(File::create(dst).map_err(|_| UnknownCryptoError::Io)?);
+
+	decrypt(secret_key, reader, writer, progress).map_err(|e| {
+		let _ = fs::remove_file(dst);
+		e
+	})
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	fn roundtrip(len: usize) {
+		let key = SecretKey::default();
+		let data: Vec<u8> = (0..len).map(|i| i as u8).collect();
+
+		let mut encrypted = Vec::new();
+		encrypt(&key, &data[..], &mut encrypted, None).unwrap();
+		let chunks = (len + CHUNK_SIZE - 1) / CHUNK_SIZE;
+		assert_eq!(
+			encrypted.len(),
+			HEADER_SIZE + len + chunks * POLY1305_OUTSIZE
+		);
+
+		let mut decrypted = Vec::new();
+		decrypt(&key, &encrypted[..], &mut decrypted, None).unwrap();
+		assert_eq!(data, decrypted);
+	}
+
+	fn encrypt_default(key: &SecretKey, len: usize) -> Vec<u8> {
+		let mut encrypted = Vec::new();
+		encrypt(key, &vec![0u8; len][..], &mut encrypted, None).unwrap();
+
+		encrypted
+	}
+
+	mod test_encrypt_decrypt {
+		use super::*;
+
+		#[test]
+		fn test_encrypt_with_rng_deterministic() {
+			use crate::util::HmacDrbg;
+
+			let key = SecretKey::from_slice(&[0x42u8; 32]).unwrap();
+			let mut first = Vec::new();
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			encrypt_with_rng(&key, &b"data"[..], &mut first, None, &mut rng).unwrap();
+
+			let mut second = Vec::new();
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			encrypt_with_rng(&key, &b"data"[..], &mut second, None, &mut rng).unwrap();
+			assert_eq!(first, second);
+
+			let mut decrypted = Vec::new();
+			decrypt(&key, &first[..], &mut decrypted, None).unwrap();
+			assert_eq!(decrypted, b"data");
+		}
+
+		#[test]
+		fn test_roundtrip_chunk_boundaries() {
+			for len in [
+				1,
+				CHUNK_SIZE - 1,
+				CHUNK_SIZE,
+				CHUNK_SIZE + 1,
+				2 * CHUNK_SIZE,
+				3 * CHUNK_SIZE + 17,
+			]
+			.iter()
+			{
+				roundtrip(*len);
+			}
+		}
+
+		#[test]
+		fn test_empty_err() {
+			let mut encrypted = Vec::new();
+			assert!(encrypt(&SecretKey::default(), &[][..], &mut encrypted, None).is_err());
+		}
+
+		#[test]
+		fn test_invalid_key_size_err() {
+			let key = SecretKey::from_slice(&[0u8; 31]).unwrap();
+			let mut encrypted = Vec::new();
+			assert!(encrypt(&key, &[0u8; 16][..], &mut encrypted, None).is_err());
+		}
+
+		#[test]
+		fn test_wrong_key_err() {
+			let encrypted = encrypt_default(&SecretKey::default(), 100);
+			let mut decrypted = Vec::new();
+			assert!(decrypt(&SecretKey::default(), &encrypted[..], &mut decrypted, None).is_err());
+		}
+
+		#[test]
+		fn test_progress() {
+			let key = SecretKey::default();
+			let len = 2 * CHUNK_SIZE + 10;
+			let mut reported = Vec::new();
+			let mut encrypted = Vec::new();
+			encrypt(
+				&key,
+				&vec![0u8; len][..],
+				&mut encrypted,
+				Some(&mut |processed| reported.push(processed)),
+			)
+			.unwrap();
+			assert_eq!(
+				reported,
+				[CHUNK_SIZE as u64, 2 * CHUNK_SIZE as u64, len as u64]
+			);
+
+			reported.clear();
+			let mut decrypted = Vec::new();
+			decrypt(
+				&key,
+				&encrypted[..],
+				&mut decrypted,
+				Some(&mut |processed| reported.push(processed)),
+			)
+			.unwrap();
+			assert_eq!(
+				reported,
+				[CHUNK_SIZE as u64, 2 * CHUNK_SIZE as u64, len as u64]
+			);
+		}
+
+		#[test]
+		fn test_modified_header_err() {
+			let key = SecretKey::default();
+			let encrypted = encrypt_default(&key, 100);
+
+			for idx in 0..HEADER_SIZE {
+				let mut modified = encrypted.clone();
+				modified[idx] ^= 1;
+				let mut decrypted = Vec::new();
+				assert!(decrypt(&key, &modified[..], &mut decrypted, None).is_err());
+			}
+		}
+
+		#[test]
+		fn test_truncated_err() {
+			let key = SecretKey::default();
+			let encrypted = encrypt_default(&key, 2 * CHUNK_SIZE + 10);
+			let chunk = CHUNK_SIZE + POLY1305_OUTSIZE;
+
+			// Dropping the final chunk leaves a valid, but non-final, chunk last.
+			for len in [
+				0,
+				HEADER_SIZE,
+				HEADER_SIZE + chunk,
+				HEADER_SIZE + 2 * chunk,
+				encrypted.len() - 1,
+			]
+			.iter()
+			{
+				let mut decrypted = Vec::new();
+				assert!(decrypt(&key, &encrypted[..*len], &mut decrypted, None).is_err());
+			}
+		}
+
+		#[test]
+		fn test_reordered_chunks_err() {
+			let key = SecretKey::default();
+			let encrypted = encrypt_default(&key, 3 * CHUNK_SIZE);
+			let chunk = CHUNK_SIZE + POLY1305_OUTSIZE;
+
+			let mut reordered = encrypted[..HEADER_SIZE].to_vec();
+			reordered.extend_from_slice(&encrypted[HEADER_SIZE + chunk..HEADER_SIZE + 2 * chunk]);
+			reordered.extend_from_slice(&encrypted[HEADER_SIZE..HEADER_SIZE + chunk]);
+			reordered.extend_from_slice(&encrypted[HEADER_SIZE + 2 * chunk..]);
+
+			let mut decrypted = Vec::new();
+			assert!(decrypt(&key, &reordered[..], &mut decrypted, None).is_err());
+		}
+
+		#[test]
+		fn test_appended_data_err() {
+			let key = SecretKey::default();
+			let mut encrypted = encrypt_default(&key, 100);
+			encrypted.push(0);
+
+			let mut decrypted = Vec::new();
+			assert!(decrypt(&key, &encrypted[..], &mut decrypted, None).is_err());
+		}
+	}
+
+	// The chunks of the file format, sealed and opened one at a time. The
+	// opener finds out whether a chunk is the final one by trying both nonces.
+	mod test_streaming_aead_interface {
+		use super::*;
+		use crate::test_framework::streaming_aead_interface::*;
+
+		struct ChunkSealer {
+			key: chacha20::SecretKey,
+			header: [u8; HEADER_SIZE],
+			counter: u64,
+			finalized: bool,
+		}
+
+		struct ChunkOpener {
+			key: chacha20::SecretKey,
+			header: [u8; HEADER_SIZE],
+			counter: u64,
+			finalized: bool,
+		}
+
+		impl TestableStreamSealer for ChunkSealer {
+			fn seal_chunk(
+				&mut self,
+				plaintext: &[u8],
+				last: bool,
+			) -> Result<Vec<u8>, UnknownCryptoError> {
+				if self.finalized {
+					return Err(UnknownCryptoError::FinalizedState);
+				}
+
+				let mut sealed = vec![0u8; plaintext.len() + POLY1305_OUTSIZE];
+				xchacha20poly1305::seal(
+					&self.key,
+					&chunk_nonce(&self.header, self.counter, last)?,
+					plaintext,
+					Some(&self.header),
+					&mut sealed,
+				)?;
+				self.counter += 1;
+				self.finalized = last;
+
+				Ok(sealed)
+			}
+		}
+
+		impl TestableStreamOpener for ChunkOpener {
+			fn open_chunk(&mut self, sealed: &[u8]) -> Result<(Vec<u8>, bool), UnknownCryptoError> {
+				if self.finalized {
+					return Err(UnknownCryptoError::FinalizedState);
+				}
+				if sealed.len() <= POLY1305_OUTSIZE {
+					return Err(UnknownCryptoError::InvalidLength);
+				}
+
+				let mut plaintext = vec![0u8; sealed.len() - POLY1305_OUTSIZE];
+				for last in [false, true].iter() {
+					if xchacha20poly1305::open(
+						&self.key,
+						&chunk_nonce(&self.header, self.counter, *last)?,
+						sealed,
+						Some(&self.header),
+						&mut plaintext,
+					)
+					.is_ok()
+					{
+						self.counter += 1;
+						self.finalized = *last;
+						return Ok((plaintext, *last));
+					}
+				}
+
+				Err(UnknownCryptoError::InvalidTag)
+			}
+		}
+
+		fn new_stream() -> (ChunkSealer, ChunkOpener) {
+			let key = chacha20::SecretKey::generate();
+			let header = new_header(&mut OsRng).unwrap();
+			let sealer = ChunkSealer {
+				key: chacha20::SecretKey::from_slice(key.unprotected_as_bytes()).unwrap(),
+				header,
+				counter: 0,
+				finalized: false,
+			};
+			let opener = ChunkOpener {
+				key,
+				header,
+				counter: 0,
+				finalized: false,
+			};
+
+			(sealer, opener)
+		}
+
+		#[test]
+		fn test_streaming_aead_interface() {
+			StreamingAeadTester::new(new_stream, 64).run_all_tests();
+		}
+
+		// Seals every chunk with the nonce of the first one.
+		struct NonceReusingSealer(ChunkSealer);
+
+		impl TestableStreamSealer for NonceReusingSealer {
+			fn seal_chunk(
+				&mut self,
+				plaintext: &[u8],
+				last: bool,
+			) -> Result<Vec<u8>, UnknownCryptoError> {
+				let sealed = self.0.seal_chunk(plaintext, last)?;
+				self.0.counter = 0;
+
+				Ok(sealed)
+			}
+		}
+
+		#[test]
+		#[should_panic]
+		fn test_streaming_aead_tester_catches_nonce_reuse() {
+			StreamingAeadTester::new(
+				|| {
+					let (sealer, opener) = new_stream();
+					(NonceReusingSealer(sealer), opener)
+				},
+				64,
+			)
+			.run_all_tests();
+		}
+
+		quickcheck! {
+			fn prop_streaming_aead_interface(input: Vec<u8>) -> bool {
+				StreamingAeadTester::new(new_stream, 64).run_all_tests_property(&input);
+				true
+			}
+		}
+	}
+
+	mod test_files {
+		use super::*;
+
+		#[test]
+		fn test_file_roundtrip_and_cleanup() {
+			let dir = std::env::temp_dir();
+			let id = crate::util::secure_rand_u64().unwrap();
+			let src = dir.join(format!("orion-file-src-{:x}", id));
+			let enc = dir.join(format!("orion-file-enc-{:x}", id));
+			let dec = dir.join(format!("orion-file-dec-{:x}", id));
+			let data = vec![0x42u8; CHUNK_SIZE + 1];
+			fs::write(&src, &data).unwrap();
+
+			let key = SecretKey::default();
+			encrypt_file(&key, &src, &enc, None).unwrap();
+			decrypt_file(&key, &enc, &dec, None).unwrap();
+			assert_eq!(fs::read(&dec).unwrap(), data);
+
+			fs::remove_file(&dec).unwrap();
+			assert!(decrypt_file(&SecretKey::default(), &enc, &dec, None).is_err());
+			assert!(!dec.exists());
+
+			fs::remove_file(&src).unwrap();
+			fs::remove_file(&enc).unwrap();
+		}
+	}
+}
@@ -0,0 +1,337 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Public key fingerprints.
+//!
+//! # Use case:
+//! `orion::fingerprint` can be used to display public keys in a short form
+//! that users can compare, and to pin public keys.
+//!
+//! An example of this could be showing the fingerprint of a server's public
+//! key on first connection, and storing it to check the key on later
+//! connections.
+//!
+//! # About:
+//! - A fingerprint is the SHA256 or BLAKE2b-256 hash of an encoded public key.
+//!   [`Fingerprint::new()`] hashes the encoding it is given, such as
+//!   `PublicKey::as_ref()` of [`x448`] or [`ecdsa_p256`] public keys.
+//! - [`Fingerprint::from_ssh_public_key()`] hashes the OpenSSH encoding of an
+//!   ECDSA P-256 public key. With SHA256, it is the fingerprint that
+//!   `ssh-keygen -l` shows.
+//! - A fingerprint can be formatted as hex, Base58, or in the style of
+//!   OpenSSH: the name of the hash, a colon and unpadded Base64. The last
+//!   format is also what `Display` shows, and it can be parsed back with
+//!   [`Fingerprint::from_ssh_string()`].
+//!
+//! # Parameters:
+//! - `algorithm`: The hash function to use.
+//! - `public_key`: The public key to fingerprint.
+//! - `src`: A fingerprint formatted in the style of OpenSSH.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `public_key` is empty.
+//! - `src` does not name a supported hash or does not hold 32 bytes of
+//!   unpadded Base64.
+//!
+//! # Security:
+//! - Fingerprints of different encodings of the same key differ. Pinned
+//!   fingerprints must be checked against a fingerprint made the same way.
+//! - Comparing two `Fingerprint`s is done in constant time. Comparing their
+//!   formatted strings is not.
+//!
+//! # Example:
+//! ```rust
+//! use orion::fingerprint::{Algorithm, Fingerprint};
+//! use orion::hazardous::ecc::ecdsa_p256::{PublicKey, SecretKey};
+//! use std::convert::TryFrom;
+//!
+//! let public_key = PublicKey::try_from(&SecretKey::generate())?;
+//! let fingerprint = Fingerprint::from_ssh_public_key(Algorithm::Sha256, &public_key)?;
+//! let pinned = fingerprint.to_ssh_string();
+//! assert!(pinned.starts_with("SHA256:"));
+//!
+//! assert_eq!(Fingerprint::from_ssh_string(&pinned)?, fingerprint);
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`Fingerprint::new()`]: https://docs.rs/orion/latest/orion/fingerprint/struct.Fingerprint.html#method.new
+//! [`Fingerprint::from_ssh_public_key()`]: https://docs.rs/orion/latest/orion/fingerprint/struct.Fingerprint.html#method.from_ssh_public_key
+//! [`Fingerprint::from_ssh_string()`]: https://docs.rs/orion/latest/orion/fingerprint/struct.Fingerprint.html#method.from_ssh_string
+//! [`x448`]: https://docs.rs/orion/latest/orion/hazardous/ecc/x448/index.html
+//! [`ecdsa_p256`]: https://docs.rs/orion/latest/orion/hazardous/ecc/ecdsa_p256/index.html
+
+use crate::{
+	errors::UnknownCryptoError,
+	hazardous::{
+		ecc::ecdsa_p256::PublicKey,
+		hash::{blake2b, sha256},
+	},
+	util::{
+		base58,
+		base64::{self, Variant},
+		hex, secure_cmp,
+	},
+};
+use core::fmt;
+
+/// The size of a fingerprint.
+const FINGERPRINT_SIZE: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The hash function of a fingerprint.
+pub enum Algorithm {
+	/// SHA256.
+	Sha256,
+	/// BLAKE2b with a 32-byte output.
+	Blake2b256,
+}
+
+impl Algorithm {
+	/// The name of the hash function in formatted fingerprints.
+	fn name(self) -> &'static str {
+		match self {
+			Algorithm::Sha256 => "SHA256",
+			Algorithm::Blake2b256 => "BLAKE2B-256",
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+/// A public key fingerprint.
+pub struct Fingerprint {
+	algorithm: Algorithm,
+	value: [u8; FINGERPRINT_SIZE],
+}
+
+impl PartialEq for Fingerprint {
+	fn eq(&self, other: &Self) -> bool {
+		self.algorithm == other.algorithm && secure_cmp(&self.value, &other.value).is_ok()
+	}
+}
+
+impl Eq for Fingerprint {}
+
+impl Fingerprint {
+	#[must_use]
+	/// Fingerprint the encoded `public_key` with `algorithm`.
+	pub fn new(algorithm: Algorithm, public_key: &[u8]) -> Result<Self, UnknownCryptoError> {
+		if public_key.is_empty() {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+
+		let mut value = [0u8; FINGERPRINT_SIZE];
+		match algorithm {
+			Algorithm::Sha256 => value.copy_from_slice(sha256::digest(public_key)?.as_ref()),
+			Algorithm::Blake2b256 => {
+				value.copy_from_slice(blake2b::Hasher::Blake2b256.digest(public_key)?.as_ref())
+			}
+		}
+
+		Ok(Self { algorithm, value })
+	}
+
+	#[must_use]
+	/// Fingerprint the OpenSSH encoding of `public_key` with `algorithm`.
+	pub fn from_ssh_public_key(
+		algorithm: Algorithm,
+		public_key: &PublicKey,
+	) -> Result<Self, UnknownCryptoError> {
+		Self::new(algorithm, &crate::ssh::public_key_blob(public_key))
+	}
+
+	#[must_use]
+	/// Parse a fingerprint formatted in the style of OpenSSH, such as
+	/// `SHA256:bS8oTA6hNH8G3HQdJojNniyC971QyV7eW7LFik+qmv4`.
+	pub fn from_ssh_string(src: &str) -> Result<Self, UnknownCryptoError> {
+		let separator = src.find(':').ok_or(UnknownCryptoError::InvalidEncoding)?;
+		let algorithm = match &src[..separator] {
+			"SHA256" => Algorithm::Sha256,
+			"BLAKE2B-256" => Algorithm::Blake2b256,
+			_ => return Err(UnknownCryptoError::InvalidEncoding),
+		};
+
+		let mut value = [0u8; FINGERPRINT_SIZE];
+		base64::decode_to_slice(
+			&src[separator + 1..],
+			&mut value,
+			Variant::StandardNoPadding,
+		)?;
+
+		Ok(Self { algorithm, value })
+	}
+
+	/// The hash function of the fingerprint.
+	pub fn algorithm(&self) -> Algorithm {
+		self.algorithm
+	}
+
+	/// The fingerprint as bytes.
+	pub fn as_bytes(&self) -> &[u8] {
+		&self.value
+	}
+
+	/// The fingerprint as lowercase hex.
+	pub fn to_hex(&self) -> String {
+		hex::encode(&self.value)
+	}
+
+	/// The fingerprint as Base58.
+	pub fn to_base58(&self) -> String {
+		base58::encode(&self.value)
+	}
+
+	/// The fingerprint in the style of OpenSSH.
+	pub fn to_ssh_string(&self) -> String {
+		format!(
+			"{}:{}",
+			self.algorithm.name(),
+			base64::encode(&self.value, Variant::StandardNoPadding)
+		)
+	}
+}
+
+impl fmt::Display for Fingerprint {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.to_ssh_string())
+	}
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	/// The public key of RFC 7515, appendix A.3.
+	fn public_key() -> PublicKey {
+		PublicKey::from_slice(
+			&hex::decode(
+				"047fcdce2770f6c45d4183cbee6fdb4b7b580733357be9ef13bacf6e3c7bd15445\
+				 c7f144cd1bbd9b7e872cdfedb9eeb9f4b3695d6ea90b24ad8a4623288588e5ad",
+			)
+			.unwrap(),
+		)
+		.unwrap()
+	}
+
+	mod test_new {
+		use super::*;
+
+		#[test]
+		fn test_formats() {
+			let fingerprint = Fingerprint::new(Algorithm::Sha256, public_key().as_ref()).unwrap();
+			assert_eq!(fingerprint.algorithm(), Algorithm::Sha256);
+			assert_eq!(
+				fingerprint.to_hex(),
+				"dcd2446ca98830c843c493a672364ba971d674fbef5ce87d697165b31e90300a"
+			);
+			assert_eq!(
+				fingerprint.as_bytes(),
+				&hex::decode(&fingerprint.to_hex()).unwrap()[..]
+			);
+			assert_eq!(
+				fingerprint.to_base58(),
+				"FrzezHGcW6FwGVsrt7ETCfEUx7iT6757NttzZgHU1A2V"
+			);
+
+			let fingerprint =
+				Fingerprint::new(Algorithm::Blake2b256, public_key().as_ref()).unwrap();
+			assert_eq!(
+				fingerprint.to_hex(),
+				"a5ff473a8bb3ccba1829937636b9268380e115259ec5a8924e850f17c10ed5c2"
+			);
+		}
+
+		#[test]
+		fn test_empty_err() {
+			assert!(Fingerprint::new(Algorithm::Sha256, b"").is_err());
+			assert!(Fingerprint::new(Algorithm::Blake2b256, b"").is_err());
+		}
+	}
+
+	mod test_ssh {
+		use super::*;
+
+		#[test]
+		fn test_ssh_keygen() {
+			// Output of `ssh-keygen -l` for this key.
+			let fingerprint =
+				Fingerprint::from_ssh_public_key(Algorithm::Sha256, &public_key()).unwrap();
+			assert_eq!(
+				fingerprint.to_ssh_string(),
+				"SHA256:bS8oTA6hNH8G3HQdJojNniyC971QyV7eW7LFik+qmv4"
+			);
+			assert_eq!(fingerprint.to_string(), fingerprint.to_ssh_string());
+
+			let fingerprint =
+				Fingerprint::from_ssh_public_key(Algorithm::Blake2b256, &public_key()).unwrap();
+			assert_eq!(
+				fingerprint.to_ssh_string(),
+				"BLAKE2B-256:Q4bCSu3aZRrLhqsq3cKYBBOw0N0wkUlGkes5h5N0HQU"
+			);
+		}
+
+		#[test]
+		fn test_parse_roundtrip() {
+			for algorithm in [Algorithm::Sha256, Algorithm::Blake2b256].iter() {
+				let fingerprint =
+					Fingerprint::from_ssh_public_key(*algorithm, &public_key()).unwrap();
+				assert_eq!(
+					Fingerprint::from_ssh_string(&fingerprint.to_ssh_string()).unwrap(),
+					fingerprint
+				);
+			}
+		}
+
+		#[test]
+		fn test_parse_err() {
+			let valid = "SHA256:bS8oTA6hNH8G3HQdJojNniyC971QyV7eW7LFik+qmv4";
+			assert!(Fingerprint::from_ssh_string(valid).is_ok());
+			assert!(Fingerprint::from_ssh_string(&valid[1..]).is_err());
+			assert!(Fingerprint::from_ssh_string(&valid[..valid.len() - 1]).is_err());
+			assert!(Fingerprint::from_ssh_string(&valid.replace(':', "")).is_err());
+			assert!(Fingerprint::from_ssh_string(&valid.replace("SHA256", "MD5")).is_err());
+			assert!(Fingerprint::from_ssh_string(&format!("{}=", valid)).is_err());
+			assert!(Fingerprint::from_ssh_string(&format!("{}AA", valid)).is_err());
+			assert!(Fingerprint::from_ssh_string("").is_err());
+		}
+	}
+
+	#[test]
+	fn test_eq() {
+		let sha256 = Fingerprint::new(Algorithm::Sha256, b"key").unwrap();
+		let blake2b = Fingerprint::new(Algorithm::Blake2b256, b"key").unwrap();
+		assert_eq!(sha256, Fingerprint::new(Algorithm::Sha256, b"key").unwrap());
+		assert_ne!(
+			sha256,
+			Fingerprint::new(Algorithm::Sha256, b"other key").unwrap()
+		);
+		assert_ne!(sha256, blake2b);
+
+		let mut value = [0u8; 32];
+		value.copy_from_slice(blake2b.as_bytes());
+		let relabeled = Fingerprint {
+			algorithm: Algorithm::Sha256,
+			value,
+		};
+		assert_ne!(relabeled, blake2b);
+	}
+}
@@ -0,0 +1,309 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Structured fuzz entry points.
+//!
+//! # Use case:
+//! `orion::fuzz` holds entry points for fuzz targets, such as those of
+//! `cargo fuzz`, that exercise the public API with structured input.
+//!
+//! # About:
+//! - Each entry point takes the raw input of the fuzzer and splits it into the
+//!   keys, nonces, parameters and messages it needs. Missing bytes are taken
+//!   to be zero, so every input is usable.
+//! - Round-trip entry points panic if data that was sealed or authenticated
+//!   is not recovered or verified. Parse-then-verify entry points panic if
+//!   untrusted input is accepted when it should not be.
+//! - Randomness is drawn from an [`HmacDrbg`] seeded with the input, so that
+//!   every crash can be reproduced.
+//! - Iteration counts are kept small, so that each run stays fast.
+//! - This module requires the `fuzz` feature, and is not meant to be used
+//!   outside of fuzz targets.
+//! - The `fuzz` feature also implements `arbitrary::Arbitrary` for orion's
+//!   keys, nonces, salts, tags and other input types, so that fuzz targets
+//!   can take them as structured input. Their length is drawn from the
+//!   lengths the type accepts, and input the type rejects is reported as
+//!   `arbitrary::Error::IncorrectFormat`.
+//!
+//! # Example:
+//! ```rust
+//! // fuzz/fuzz_targets/aead_seal_open.rs
+//! // #![no_main]
+//! // libfuzzer_sys::fuzz_target!(|data: &[u8]| orion::fuzz::aead_seal_open(data));
+//! orion::fuzz::aead_seal_open(b"fuzzer input");
+//! ```
+//! [`HmacDrbg`]: https://docs.rs/orion/latest/orion/util/struct.HmacDrbg.html
+
+use crate::{aead, auth, file, keyfile, pwhash, util::HmacDrbg};
+
+/// The largest iteration count used for PBKDF2.
+const MAX_ITERATIONS: u32 = 4;
+
+/// Splits the input of the fuzzer into fields.
+struct Input<'a> {
+	data: &'a [u8],
+}
+
+impl<'a> Input<'a> {
+	fn new(data: &'a [u8]) -> Self {
+		Self { data }
+	}
+
+	/// The next `len` bytes, with missing bytes taken to be zero.
+	fn bytes(&mut self, len: usize) -> Vec<u8> {
+		let mut out = vec![0u8; len];
+		let available = core::cmp::min(len, self.data.len());
+		out[..available].copy_from_slice(&self.data[..available]);
+		self.data = &self.data[available..];
+
+		out
+	}
+
+	fn u8(&mut self) -> u8 {
+		self.bytes(1)[0]
+	}
+
+	/// The next field, prefixed by its length as a single byte.
+	fn field(&mut self) -> &'a [u8] {
+		let len = core::cmp::min(self.u8() as usize, self.data.len());
+		let (field, rest) = self.data.split_at(len);
+		self.data = rest;
+
+		field
+	}
+
+	/// All remaining bytes.
+	fn rest(self) -> &'a [u8] {
+		self.data
+	}
+
+	/// A PBKDF2 iteration count in `1..=MAX_ITERATIONS`.
+	fn iterations(&mut self) -> u32 {
+		1 + (self.u8() as u32 % MAX_ITERATIONS)
+	}
+}
+
+/// A DRBG seeded with the whole input.
+fn rng(data: &[u8]) -> HmacDrbg {
+	HmacDrbg::new(&[0u8; 32], data, b"orion fuzz").unwrap()
+}
+
+/// Seal and open a message with `orion::aead`, and check that it is recovered
+/// and that a modified message is rejected.
+pub fn aead_seal_open(data: &[u8]) {
+	let mut rng = rng(data);
+	let mut input = Input::new(data);
+	let key = aead::SecretKey::from_slice(&input.bytes(32)).unwrap();
+	let flip = input.u8() as usize;
+	let plaintext = input.rest();
+
+	let sealed = match aead::seal_with_rng(&key, plaintext, &mut rng) {
+		Ok(sealed) => sealed,
+		Err(_) => {
+			assert!(plaintext.is_empty());
+			return;
+		}
+	};
+	assert_eq!(aead::open(&key, &sealed).unwrap(), plaintext);
+
+	let mut modified = sealed;
+	let idx = flip % modified.len();
+	modified[idx] ^= 1;
+	assert!(aead::open(&key, &modified).is_err());
+}
+
+/// Open untrusted data with `orion::aead`, both as a message and as a session.
+pub fn aead_open(data: &[u8]) {
+	let mut input = Input::new(data);
+	let key = aead::SecretKey::from_slice(&input.bytes(32)).unwrap();
+	let header = input.bytes(aead::SESSION_HEADER_SIZE);
+	let ciphertext = input.rest();
+
+	if let Ok(plaintext) = aead::open(&key, ciphertext) {
+		assert_eq!(plaintext.len(), ciphertext.len() - aead::SEAL_OVERHEAD);
+	}
+	let mut session = aead::OpeningSession::new(&key, &header).unwrap();
+	let _ = session.open(ciphertext);
+}
+
+/// Verify an untrusted tag with `orion::auth`, and check that it is accepted
+/// only if it is the tag of the message.
+pub fn auth_verify(data: &[u8]) {
+	let mut input = Input::new(data);
+	let key = auth::SecretKey::from_slice(&input.bytes(32)).unwrap();
+	let untrusted = auth::Tag::from_slice(&input.bytes(64)).unwrap();
+	let msg = input.rest();
+
+	let tag = auth::authenticate(&key, msg).unwrap();
+	assert!(auth::authenticate_verify(&tag, &key, msg).unwrap());
+	assert_eq!(
+		auth::authenticate_verify(&untrusted, &key, msg).is_ok(),
+		untrusted == tag
+	);
+}
+
+/// Verify a password against an untrusted password hash with `orion::pwhash`,
+/// and against one made from it.
+pub fn pwhash_verify(data: &[u8]) {
+	let mut rng = rng(data);
+	let mut input = Input::new(data);
+	let iterations = input.iterations() as usize;
+	let untrusted = pwhash::PasswordHash::from_slice(&input.bytes(128)).unwrap();
+	let password = match pwhash::Password::from_slice(input.rest()) {
+		Ok(password) => password,
+		Err(_) => return,
+	};
+
+	let _ = pwhash::hash_password_verify(&untrusted, &password, iterations);
+
+	let hash = pwhash::hash_password_with_rng(&password, iterations, &mut rng).unwrap();
+	assert!(pwhash::hash_password_verify(&hash, &password, iterations).unwrap());
+}
+
+/// Export a secret with `orion::keyfile` and import it again, then import the
+/// untrusted input as a key file.
+pub fn keyfile_import(data: &[u8]) {
+	let mut rng = rng(data);
+	let mut input = Input::new(data);
+	let iterations = input.iterations();
+	let password = match keyfile::Password::from_slice(input.field()) {
+		Ok(password) => password,
+		Err(_) => return,
+	};
+	let label = core::str::from_utf8(input.field()).unwrap_or("");
+	let secret = input.field();
+	let untrusted = input.rest();
+
+	if let Ok(exported) = keyfile::export_with_rng(&password, label, secret, iterations, &mut rng) {
+		assert_eq!(keyfile::label(&exported).unwrap(), label);
+		let imported = keyfile::import(&password, label, &exported, iterations).unwrap();
+		assert_eq!(imported.unprotected_as_bytes(), secret);
+	}
+
+	// `iterations` caps the iteration count read from the key file, to keep
+	// the run fast.
+	if let Ok(untrusted_label) = keyfile::label(untrusted) {
+		let _ = keyfile::import(&password, untrusted_label, untrusted, iterations);
+	} else {
+		assert!(keyfile::import(&password, label, untrusted, iterations).is_err());
+	}
+}
+
+/// Encrypt and decrypt data with `orion::file`, then decrypt the untrusted
+/// input.
+pub fn file_encrypt_decrypt(data: &[u8]) {
+	let mut rng = rng(data);
+	let mut input = Input::new(data);
+	let key = file::SecretKey::from_slice(&input.bytes(32)).unwrap();
+	let plaintext = input.rest();
+
+	let mut encrypted = Vec::new();
+	if file::encrypt_with_rng(&key, plaintext, &mut encrypted, None, &mut rng).is_ok() {
+		let mut decrypted = Vec::new();
+		file::decrypt(&key, &encrypted[..], &mut decrypted, None).unwrap();
+		assert_eq!(decrypted, plaintext);
+	} else {
+		assert!(plaintext.is_empty());
+	}
+
+	let mut decrypted = Vec::new();
+	let _ = file::decrypt(&key, plaintext, &mut decrypted, None);
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	const ENTRY_POINTS: [fn(&[u8]); 6] = [
+		aead_seal_open,
+		aead_open,
+		auth_verify,
+		pwhash_verify,
+		keyfile_import,
+		file_encrypt_decrypt,
+	];
+
+	#[test]
+	fn test_input_missing_bytes_are_zero() {
+		let mut input = Input::new(&[1, 2, 3]);
+		assert_eq!(input.bytes(2), [1, 2]);
+		assert_eq!(input.bytes(2), [3, 0]);
+		assert_eq!(input.u8(), 0);
+		assert!(input.rest().is_empty());
+
+		let mut input = Input::new(&[5, 1, 2]);
+		assert_eq!(input.field(), [1, 2]);
+		assert!(input.field().is_empty());
+	}
+
+	#[test]
+	fn test_entry_points_short_input() {
+		for len in 0..200 {
+			let data: Vec<u8> = (0..len).map(|i| i as u8).collect();
+			for entry_point in ENTRY_POINTS.iter() {
+				entry_point(&data);
+			}
+		}
+	}
+
+	#[test]
+	fn test_keyfile_import_valid_untrusted() {
+		let password = keyfile::Password::from_slice(b"password").unwrap();
+		let exported = keyfile::export(&password, "label", &[1u8; 32], 1).unwrap();
+
+		let mut data = vec![0u8, 8];
+		data.extend_from_slice(b"password");
+		data.extend_from_slice(&[0, 0]);
+		data.extend_from_slice(&exported);
+		keyfile_import(&data);
+	}
+
+	#[test]
+	#[cfg(feature = "fuzz")]
+	fn test_arbitrary_input_types() {
+		use crate::hazardous::stream::xchacha20::Nonce;
+		use arbitrary::{Arbitrary, Unstructured};
+
+		let data = [0x42u8; 256];
+		let mut unstructured = Unstructured::new(&data);
+		let key = aead::SecretKey::arbitrary(&mut unstructured).unwrap();
+		assert!(key.get_length() >= 1);
+
+		let mut unstructured = Unstructured::new(&data);
+		let nonce = Nonce::arbitrary(&mut unstructured).unwrap();
+		assert!(nonce == Nonce::from_slice(&[0x42u8; 24]).unwrap());
+
+		let mut unstructured = Unstructured::new(&[]);
+		assert!(Nonce::arbitrary(&mut unstructured).is_err());
+	}
+
+	quickcheck! {
+		fn prop_entry_points(input: Vec<u8>) -> bool {
+			for entry_point in ENTRY_POINTS.iter() {
+				entry_point(&input);
+			}
+
+			true
+		}
+	}
+}
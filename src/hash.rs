@@ -31,13 +31,31 @@
 //!
 //! # About:
 //! - Uses BLAKE2b with an output size of 32 bytes (i.e BLAKE2b-256).
+//! - [`digest_many`] hashes many independent messages at once. The hashing
+//!   state is reused between messages, and large batches are spread over all
+//!   available CPU cores.
+//! - [`digest_reader`] and [`digest_file`] hash data as it is read, 64 KiB at
+//!   a time, so that it does not have to fit in memory.
+//! - [`digest_with_context`] separates the digests of different uses of the
+//!   same data. The `context` is hashed with BLAKE2b-512, personalized with
+//!   `orion::hash::ctx`, and the result is used as the key of BLAKE2b-256 to
+//!   hash the data. The digests are unrelated to those of [`digest`] and to
+//!   those of any other `context`.
 //!
 //! # Parameters:
 //! - `data`:  The data to be hashed.
+//! - `context`: A string naming the use of the digest, for
+//!   [`digest_with_context`].
+//! - `messages`: The independent messages to be hashed by [`digest_many`].
+//! - `reader`: The reader whose data is hashed by [`digest_reader`], until
+//!   its end.
+//! - `path`: The path of the file hashed by [`digest_file`].
 //!
-//! # Panics:
-//! A panic will occur if:
+//! # Errors:
+//! An error will be returned if:
 //! - More than 2*(2^64-1) bytes of data are hashed.
+//! - `context` is empty.
+//! - Reading from `reader`, or opening or reading the file at `path`, fails.
 //!
 //! # Security:
 //! - This interface does not support supplying BLAKE2b with a secret key, and
@@ -45,18 +63,41 @@
 //! from using `orion::hash` are therefore not suitable as MACs.
 //! - BLAKE2b is not suitable for password hashing. See [`orion::pwhash`]
 //!   instead.
+//! - `context` should be hardcoded and unique to the application and the use,
+//!   such as `"myapp 2026-01-01 file deduplication"`. It is not secret, and
+//!   must not contain secret or variable data.
 //!
 //! # Example:
 //! ```rust
 //! use orion::hash::{digest, Digest};
 //!
 //! let hash: Digest = digest(b"Some data")?;
+//!
+//! let records: [&[u8]; 3] = [b"First record", b"Second record", b"Third record"];
+//! let hashes: Vec<Digest> = orion::hash::digest_many(&records)?;
+//! assert_eq!(hashes[1], digest(b"Second record")?);
+//!
+//! let streamed: Digest = orion::hash::digest_reader(&b"Some data"[..])?;
+//! assert_eq!(streamed, hash);
+//!
+//! let cache_key = orion::hash::digest_with_context("myapp 2026-01-01 cache keys", b"Some data")?;
+//! assert_ne!(cache_key, hash);
 //! # Ok::<(), orion::errors::UnknownCryptoError>(())
 //! ```
 //! [`orion::pwhash`]: https://docs.rs/orion/latest/orion/pwhash/index.html
+//! [`digest_many`]: https://docs.rs/orion/latest/orion/hash/fn.digest_many.html
+//! [`digest_reader`]: https://docs.rs/orion/latest/orion/hash/fn.digest_reader.html
+//! [`digest_file`]: https://docs.rs/orion/latest/orion/hash/fn.digest_file.html
+//! [`digest_with_context`]: https://docs.rs/orion/latest/orion/hash/fn.digest_with_context.html
+//! [`digest`]: https://docs.rs/orion/latest/orion/hash/fn.digest.html
 
 pub use crate::hazardous::hash::blake2b::Digest;
-use crate::{errors::UnknownCryptoError, hazardous::hash::blake2b};
+use crate::{endianness::load_u64_into_le, errors::UnknownCryptoError, hazardous::hash::blake2b};
+use std::{
+	fs::File,
+	io::{self, Read},
+	path::Path,
+};
 
 #[must_use]
 /// Hashing using BLAKE2b-256.
@@ -64,11 +105,251 @@ pub fn digest(data: &[u8]) -> Result<Digest, UnknownCryptoError> {
 	blake2b::Hasher::Blake2b256.digest(data)
 }
 
+/// The BLAKE2b personalization used to hash the `context` of
+/// `digest_with_context()`.
+const CONTEXT_PERSONAL: &[u8; 16] = b"orion::hash::ctx";
+
+#[must_use]
+#[allow(clippy::unreadable_literal)]
+/// Hashing using BLAKE2b-256, keyed by `context`, so that different uses of
+/// the same data get unrelated digests.
+pub fn digest_with_context(context: &str, data: &[u8]) -> Result<Digest, UnknownCryptoError> {
+	if context.is_empty() {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let mut parameters = [0x01010000, 0, 0, 0, 0, 0, 0, 0];
+	load_u64_into_le(CONTEXT_PERSONAL, &mut parameters[6..]);
+	let mut context_state = blake2b::init_with_parameters(None, 64, &parameters)?;
+	context_state.update(context.as_bytes())?;
+	let context_key = blake2b::SecretKey::from_slice(context_state.finalize()?.as_ref())?;
+
+	let mut state = blake2b::init(Some(&context_key), 32)?;
+	state.update(data)?;
+	state.finalize()
+}
+
+/// Below this number of messages, `digest_many()` does all hashing on the
+/// calling thread.
+const PARALLEL_THRESHOLD: usize = 256;
+
+#[must_use]
+/// Hash each message using BLAKE2b-256, reusing one hashing state per thread.
+fn digest_sequential(messages: &[&[u8]]) -> Result<Vec<Digest>, UnknownCryptoError> {
+	let mut state = blake2b::init(None, 32)?;
+	let mut digests = Vec::with_capacity(messages.len());
+	for message in messages.iter() {
+		state.reset(None)?;
+		state.update(message)?;
+		digests.push(state.finalize()?);
+	}
+
+	Ok(digests)
+}
+
+#[must_use]
+/// Hashing of many independent messages using BLAKE2b-256. The digests are
+/// returned in the same order as `messages`.
+pub fn digest_many(messages: &[&[u8]]) -> Result<Vec<Digest>, UnknownCryptoError> {
+	let threads = std::thread::available_parallelism()
+		.map(|n| n.get())
+		.unwrap_or(1);
+	if messages.len() < PARALLEL_THRESHOLD || threads == 1 {
+		return digest_sequential(messages);
+	}
+
+	let chunk_size = (messages.len() + threads - 1) / threads;
+	std::thread::scope(|scope| {
+		let workers: Vec<_> = messages
+			.chunks(chunk_size)
+			.map(|chunk| scope.spawn(move || digest_sequential(chunk)))
+			.collect();
+
+		let mut digests = Vec::with_capacity(messages.len());
+		for worker in workers {
+			match worker.join() {
+				Ok(result) => digests.extend(result?),
+				Err(panic) => std::panic::resume_unwind(panic),
+			}
+		}
+
+		Ok(digests)
+	})
+}
+
+/// The size of the buffer `digest_reader()` reads into.
+const READ_BUFFER_SIZE: usize = 64 * 1024;
+
+#[must_use]
+/// Hashing of all data read from `reader` using BLAKE2b-256.
+pub fn digest_reader<R: Read>(mut reader: R) -> Result<Digest, UnknownCryptoError> {
+	let mut state = blake2b::init(None, 32)?;
+	let mut buf = vec![0u8; READ_BUFFER_SIZE];
+	loop {
+		match reader.read(&mut buf) {
+			Ok(0) => break,
+			Ok(n) => state.update(&buf[..n])?,
+			Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+			Err(_) => return Err(UnknownCryptoError::Io),
+		}
+	}
+
+	state.finalize()
+}
+
+#[must_use]
+/// Hashing of the file at `path` using BLAKE2b-256.
+pub fn digest_file(path: &Path) -> Result<Digest, UnknownCryptoError> {
+	digest_reader(File::open(path).map_err(|_| UnknownCryptoError::Io)?)
+}
+
 // Testing public functions in the module.
 #[cfg(test)]
 mod public {
 	use super::*;
 
+	mod test_digest_many {
+		use super::*;
+
+		#[test]
+		fn test_empty() {
+			assert!(digest_many(&[]).unwrap().is_empty());
+			assert_eq!(digest_many(&[b""]).unwrap(), vec![digest(b"").unwrap()]);
+		}
+
+		#[test]
+		fn test_same_as_digest() {
+			// Enough messages to be hashed on several threads.
+			let owned: Vec<Vec<u8>> = (0..(PARALLEL_THRESHOLD * 3) + 7)
+				.map(|i| vec![i as u8; i % 300])
+				.collect();
+			let messages: Vec<&[u8]> = owned.iter().map(|m| m.as_slice()).collect();
+
+			for count in [1, PARALLEL_THRESHOLD - 1, messages.len()].iter() {
+				let digests = digest_many(&messages[..*count]).unwrap();
+				assert_eq!(digests.len(), *count);
+				for (message, digest_many_result) in messages.iter().zip(digests.iter()) {
+					assert_eq!(&digest(message).unwrap(), digest_many_result);
+				}
+			}
+		}
+	}
+
+	mod test_digest_with_context {
+		use super::*;
+
+		#[test]
+		fn test_known() {
+			// Computed with Python's hashlib.blake2b.
+			let expected: [u8; 32] = [
+				0xe3, 0x75, 0xfe, 0x63, 0x24, 0x4d, 0xd4, 0xc0, 0xc7, 0xf7, 0x1a, 0x88, 0xf6, 0x5d,
+				0x56, 0x64, 0xf4, 0xd6, 0x1f, 0x67, 0x67, 0x5b, 0x4d, 0x68, 0x1d, 0x89, 0xaa, 0x36,
+				0x80, 0xe9, 0x19, 0x27,
+			];
+			assert_eq!(
+				digest_with_context("orion 2026-10-16 file integrity", b"Some data")
+					.unwrap()
+					.as_ref(),
+				&expected[..]
+			);
+
+			let expected: [u8; 32] = [
+				0xe3, 0x78, 0xf9, 0x40, 0xe4, 0x04, 0x50, 0x6e, 0x39, 0x9e, 0xa9, 0x1c, 0x66, 0xfe,
+				0x04, 0x64, 0x62, 0x53, 0xa7, 0xa0, 0xcd, 0xb5, 0xbf, 0x62, 0x2c, 0xc0, 0xfb, 0x60,
+				0xe0, 0xeb, 0x53, 0x45,
+			];
+			assert_eq!(
+				digest_with_context("orion 2026-10-16 cache keys", b"Some data")
+					.unwrap()
+					.as_ref(),
+				&expected[..]
+			);
+		}
+
+		#[test]
+		fn test_unrelated_to_digest() {
+			let data = b"Some data";
+			let with_context = digest_with_context("Context", data).unwrap();
+			assert_ne!(with_context, digest(data).unwrap());
+			assert_ne!(with_context, digest_with_context("Context ", data).unwrap());
+			assert_eq!(with_context, digest_with_context("Context", data).unwrap());
+		}
+
+		#[test]
+		fn test_empty_context_err() {
+			assert!(digest_with_context("", b"Some data").is_err());
+			assert!(digest_with_context("Context", b"").is_ok());
+		}
+	}
+
+	mod test_digest_reader {
+		use super::*;
+
+		/// A reader that returns at most 7 bytes per read, and is interrupted
+		/// before every read.
+		struct SlowReader<'a> {
+			data: &'a [u8],
+			interrupt: bool,
+		}
+
+		impl<'a> Read for SlowReader<'a> {
+			fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+				self.interrupt = !self.interrupt;
+				if self.interrupt {
+					return Err(io::ErrorKind::Interrupted.into());
+				}
+				let n = buf.len().min(self.data.len()).min(7);
+				buf[..n].copy_from_slice(&self.data[..n]);
+				self.data = &self.data[n..];
+
+				Ok(n)
+			}
+		}
+
+		struct FailingReader;
+
+		impl Read for FailingReader {
+			fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+				Err(io::ErrorKind::Other.into())
+			}
+		}
+
+		#[test]
+		fn test_same_as_digest() {
+			let data = vec![0x42u8; READ_BUFFER_SIZE * 2 + 1];
+			assert_eq!(digest_reader(&data[..]).unwrap(), digest(&data).unwrap());
+			assert_eq!(digest_reader(&b""[..]).unwrap(), digest(b"").unwrap());
+
+			let reader = SlowReader {
+				data: &data[..100],
+				interrupt: false,
+			};
+			assert_eq!(
+				digest_reader(reader).unwrap(),
+				digest(&data[..100]).unwrap()
+			);
+		}
+
+		#[test]
+		fn test_read_err() {
+			assert!(digest_reader(FailingReader).is_err());
+		}
+
+		#[test]
+		fn test_digest_file() {
+			let path = std::env::temp_dir().join(format!(
+				"orion-hash-{:x}",
+				crate::util::secure_rand_u64().unwrap()
+			));
+			let data = vec![0x42u8; READ_BUFFER_SIZE + 1];
+			std::fs::write(&path, &data).unwrap();
+
+			assert_eq!(digest_file(&path).unwrap(), digest(&data).unwrap());
+			std::fs::remove_file(&path).unwrap();
+			assert!(digest_file(&path).is_err());
+		}
+	}
+
 	mod test_digest {
 		use super::*;
 		#[test]
@@ -32,6 +32,10 @@
 //! - `dst_out`: Destination array that will hold the
 //!   `ciphertext_with_tag`/`plaintext` after encryption/decryption.
 //!
+//! With the `safe_api` feature, [`seal_to_vec()`] and [`open_to_vec()`] take
+//! no `dst_out` and instead return a `Vec` of the right length, which is
+//! `plaintext + 16` or `ciphertext_with_tag - 16`.
+//!
 //! `ad`: "A typical use for these data is to authenticate version numbers,
 //! timestamps or monotonically increasing counters in order to discard previous
 //! messages and prevent replay attacks." See [libsodium docs](https://download.libsodium.org/doc/secret-key_cryptography/aead#additional-data) for more information.
@@ -51,9 +55,6 @@
 //! - The length of `ciphertext_with_tag` is not greater than `16`.
 //! - `plaintext` or `ciphertext_with_tag` are empty.
 //! - The received tag does not match the calculated tag when decrypting.
-//!
-//! # Panics:
-//! A panic will occur if:
 //! - More than 2^32-1 * 64 bytes of data are processed.
 //!
 //! # Security:
@@ -91,8 +92,16 @@
 //! aead::chacha20poly1305::open(&secret_key, &nonce, &dst_out_ct, Some(&ad), &mut dst_out_pt)?;
 //!
 //! assert_eq!(dst_out_pt.as_ref(), message.as_ref());
+//!
+//! // The same, but with the output allocated to the right length.
+//! let ciphertext_with_tag = aead::chacha20poly1305::seal_to_vec(&secret_key, &nonce, message, Some(&ad))?;
+//! let plaintext = aead::chacha20poly1305::open_to_vec(&secret_key, &nonce, &ciphertext_with_tag, Some(&ad))?;
+//!
+//! assert_eq!(plaintext, message);
 //! # Ok::<(), orion::errors::UnknownCryptoError>(())
 //! ```
+//! [`seal_to_vec()`]: https://docs.rs/orion/latest/orion/hazardous/aead/chacha20poly1305/fn.seal_to_vec.html
+//! [`open_to_vec()`]: https://docs.rs/orion/latest/orion/hazardous/aead/chacha20poly1305/fn.open_to_vec.html
 //! [`SecretKey::generate()`]: https://docs.rs/orion/latest/orion/hazardous/stream/chacha20/struct.SecretKey.html
 //! [XChaCha20Poly1305]: https://docs.rs/orion/latest/orion/hazardous/aead/xchacha20poly1305/index.html
 pub use crate::hazardous::stream::chacha20::{Nonce, SecretKey};
@@ -144,9 +153,9 @@ fn process_authentication(
 ) -> Result<(), UnknownCryptoError> {
 	// If buf_in_len is 0, then NO ciphertext gets authenticated.
 	// Because of this, buf may never be empty either.
-	debug_assert!(!buf.is_empty());
-	debug_assert!(buf_in_len <= buf.len());
-	assert!(buf_in_len > 0);
+	if buf_in_len == 0 || buf_in_len > buf.len() {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
 
 	let mut padding_max = [0u8; 16];
 
@@ -179,10 +188,10 @@ pub fn seal(
 	dst_out: &mut [u8],
 ) -> Result<(), UnknownCryptoError> {
 	if dst_out.len() < plaintext.len() + POLY1305_OUTSIZE {
-		return Err(UnknownCryptoError);
+		return Err(UnknownCryptoError::InvalidLength);
 	}
 	if plaintext.is_empty() {
-		return Err(UnknownCryptoError);
+		return Err(UnknownCryptoError::InvalidLength);
 	}
 
 	let optional_ad = match ad {
@@ -218,10 +227,10 @@ pub fn open(
 	dst_out: &mut [u8],
 ) -> Result<(), UnknownCryptoError> {
 	if ciphertext_with_tag.len() <= POLY1305_OUTSIZE {
-		return Err(UnknownCryptoError);
+		return Err(UnknownCryptoError::InvalidLength);
 	}
 	if dst_out.len() < ciphertext_with_tag.len() - POLY1305_OUTSIZE {
-		return Err(UnknownCryptoError);
+		return Err(UnknownCryptoError::InvalidLength);
 	}
 
 	let optional_ad = match ad {
@@ -254,6 +263,44 @@ pub fn open(
 	)
 }
 
+#[cfg(feature = "safe_api")]
+#[must_use]
+/// AEAD ChaCha20Poly1305 encryption, returning a newly allocated `ciphertext_with_tag`.
+pub fn seal_to_vec(
+	secret_key: &SecretKey,
+	nonce: &Nonce,
+	plaintext: &[u8],
+	ad: Option<&[u8]>,
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	if plaintext.is_empty() {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let mut dst_out = vec![0u8; plaintext.len() + POLY1305_OUTSIZE];
+	seal(secret_key, nonce, plaintext, ad, &mut dst_out)?;
+
+	Ok(dst_out)
+}
+
+#[cfg(feature = "safe_api")]
+#[must_use]
+/// AEAD ChaCha20Poly1305 decryption, returning a newly allocated `plaintext`.
+pub fn open_to_vec(
+	secret_key: &SecretKey,
+	nonce: &Nonce,
+	ciphertext_with_tag: &[u8],
+	ad: Option<&[u8]>,
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	if ciphertext_with_tag.len() <= POLY1305_OUTSIZE {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let mut dst_out = vec![0u8; ciphertext_with_tag.len() - POLY1305_OUTSIZE];
+	open(secret_key, nonce, ciphertext_with_tag, ad, &mut dst_out)?;
+
+	Ok(dst_out)
+}
+
 // Testing public functions in the module.
 #[cfg(test)]
 mod public {
@@ -454,6 +501,107 @@ mod public {
 		}
 	}
 
+	#[cfg(feature = "safe_api")]
+	mod test_to_vec {
+		use super::*;
+
+		#[test]
+		fn test_roundtrip() {
+			let sk = SecretKey::from_slice(&[0u8; 32]).unwrap();
+			let n = Nonce::from_slice(&[0u8; 12]).unwrap();
+			for len in 1..130 {
+				let plaintext = vec![1u8; len];
+				let ct = seal_to_vec(&sk, &n, &plaintext, Some(b"ad")).unwrap();
+				assert_eq!(ct.len(), len + POLY1305_OUTSIZE);
+				assert_eq!(open_to_vec(&sk, &n, &ct, Some(b"ad")).unwrap(), plaintext);
+			}
+		}
+
+		#[test]
+		fn test_same_as_seal() {
+			let sk = SecretKey::from_slice(&[0u8; 32]).unwrap();
+			let n = Nonce::from_slice(&[0u8; 12]).unwrap();
+			let mut dst_out_ct = [0u8; 64 + POLY1305_OUTSIZE];
+			seal(&sk, &n, &[0u8; 64], None, &mut dst_out_ct).unwrap();
+
+			assert_eq!(
+				seal_to_vec(&sk, &n, &[0u8; 64], None).unwrap(),
+				&dst_out_ct[..]
+			);
+		}
+
+		#[test]
+		fn test_length_err() {
+			let sk = SecretKey::from_slice(&[0u8; 32]).unwrap();
+			let n = Nonce::from_slice(&[0u8; 12]).unwrap();
+
+			assert!(seal_to_vec(&sk, &n, &[0u8; 0], None).is_err());
+			assert!(open_to_vec(&sk, &n, &[0u8; 0], None).is_err());
+			assert!(open_to_vec(&sk, &n, &[0u8; POLY1305_OUTSIZE], None).is_err());
+		}
+
+		#[test]
+		fn test_modified_err() {
+			let sk = SecretKey::from_slice(&[0u8; 32]).unwrap();
+			let n = Nonce::from_slice(&[0u8; 12]).unwrap();
+			let mut ct = seal_to_vec(&sk, &n, &[0u8; 64], Some(b"ad")).unwrap();
+
+			assert!(open_to_vec(&sk, &n, &ct, None).is_err());
+			ct[0] ^= 1;
+			assert!(open_to_vec(&sk, &n, &ct, Some(b"ad")).is_err());
+		}
+	}
+
+	#[cfg(feature = "safe_api")]
+	mod test_aead_interface {
+		use super::*;
+		use crate::test_framework::aead_interface::AeadTestRunner;
+
+		#[test]
+		fn test_aead_interface() {
+			let key = SecretKey::from_slice(&[0u8; 32]).unwrap();
+			let wrong_key = SecretKey::from_slice(&[1u8; 32]).unwrap();
+			let nonce = Nonce::from_slice(&[0u8; 12]).unwrap();
+
+			for input in &[&[0u8; 0][..], &[0u8; 1], &[0u8; 64], &[0u8; 257]] {
+				for aad in &[&[0u8; 0][..], &[0u8; 1], &[0u8; 17]] {
+					AeadTestRunner(
+						seal,
+						open,
+						&key,
+						&wrong_key,
+						&nonce,
+						input,
+						None,
+						POLY1305_OUTSIZE,
+						aad,
+					);
+				}
+			}
+		}
+
+		quickcheck! {
+			fn prop_aead_interface(input: Vec<u8>, ad: Vec<u8>) -> bool {
+				let key = SecretKey::from_slice(&[0u8; 32]).unwrap();
+				let wrong_key = SecretKey::from_slice(&[1u8; 32]).unwrap();
+				let nonce = Nonce::from_slice(&[0u8; 12]).unwrap();
+				AeadTestRunner(
+					seal,
+					open,
+					&key,
+					&wrong_key,
+					&nonce,
+					&input,
+					None,
+					POLY1305_OUTSIZE,
+					&ad,
+				);
+
+				true
+			}
+		}
+	}
+
 	// Proptests. Only exectued when NOT testing no_std.
 	#[cfg(feature = "safe_api")]
 	mod proptest {
@@ -708,39 +856,38 @@ mod private {
 		use super::*;
 
 		#[test]
-		#[should_panic]
-		fn test_panic_index_0() {
+		fn test_err_index_0() {
 			let sk = SecretKey::from_slice(&[0u8; 32]).unwrap();
 			let n = Nonce::from_slice(&[0u8; 12]).unwrap();
 
 			let poly1305_key = poly1305_key_gen(&sk, &n).unwrap();
 			let mut poly1305_state = poly1305::init(&poly1305_key);
 
-			process_authentication(&mut poly1305_state, &[0u8; 0], &[0u8; 64], 0).unwrap();
+			assert!(process_authentication(&mut poly1305_state, &[0u8; 0], &[0u8; 64], 0).is_err());
 		}
 
 		#[test]
-		#[should_panic]
-		fn test_panic_empty_buf() {
+		fn test_err_empty_buf() {
 			let sk = SecretKey::from_slice(&[0u8; 32]).unwrap();
 			let n = Nonce::from_slice(&[0u8; 12]).unwrap();
 
 			let poly1305_key = poly1305_key_gen(&sk, &n).unwrap();
 			let mut poly1305_state = poly1305::init(&poly1305_key);
 
-			process_authentication(&mut poly1305_state, &[0u8; 0], &[0u8; 0], 64).unwrap();
+			assert!(process_authentication(&mut poly1305_state, &[0u8; 0], &[0u8; 0], 64).is_err());
 		}
 
 		#[test]
-		#[should_panic]
-		fn test_panic_above_length_index() {
+		fn test_err_above_length_index() {
 			let sk = SecretKey::from_slice(&[0u8; 32]).unwrap();
 			let n = Nonce::from_slice(&[0u8; 12]).unwrap();
 
 			let poly1305_key = poly1305_key_gen(&sk, &n).unwrap();
 			let mut poly1305_state = poly1305::init(&poly1305_key);
 
-			process_authentication(&mut poly1305_state, &[0u8; 0], &[0u8; 64], 65).unwrap();
+			assert!(
+				process_authentication(&mut poly1305_state, &[0u8; 0], &[0u8; 64], 65).is_err()
+			);
 		}
 
 		#[test]
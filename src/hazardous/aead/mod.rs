@@ -25,3 +25,9 @@ pub mod chacha20poly1305;
 
 /// AEAD XChaCha20Poly1305 as specified in the [draft RFC](https://github.com/bikeshedders/xchacha-rfc).
 pub mod xchacha20poly1305;
+
+/// Counter-based nonces for ChaCha20Poly1305 and XChaCha20Poly1305.
+pub mod nonce_sequence;
+
+/// Tracking key usage against the limits of an AEAD.
+pub mod usage_limits;
@@ -0,0 +1,340 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # About:
+//! A [`NonceSequence`] hands out strictly increasing nonces for use with
+//! [ChaCha20Poly1305] under a single key. Each nonce is a fixed 4-byte prefix
+//! followed by a 64-bit big-endian counter. The sequence refuses to hand out
+//! a nonce once the counter is exhausted, instead of wrapping.
+//!
+//! The state of a sequence can be exported with [`to_bytes()`] and restored
+//! with [`from_bytes()`], so that it can be persisted across restarts. When the
+//! `serde` feature is enabled, `NonceSequence` also implements `Serialize` and
+//! `Deserialize` using the same encoding.
+//!
+//! [`derive_xnonce()`] instead derives a nonce for [XChaCha20Poly1305] as
+//! BLAKE2b(`nonce_key`, `counter`). This lets systems that have a reliable
+//! counter, but no reliable RNG, avoid random nonces altogether without
+//! revealing the counter in the nonce.
+//!
+//! # Parameters:
+//! - `prefix`: A fixed value that is part of every nonce in the sequence.
+//! - `state`: The exported state of a sequence.
+//! - `nonce_key`: The key used to derive nonces. This must not be the key used
+//!   for encryption.
+//! - `counter`: The message counter to derive a nonce for.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - The length of `prefix` is not 4 bytes.
+//! - The length of `state` is not 12 bytes.
+//! - [`next_nonce()`] is called after 2^64-1 nonces have been handed out.
+//!
+//! # Security:
+//! - A `NonceSequence` must only be used with a single key. Two sequences using
+//!   the same key must have different prefixes, such as one per sender.
+//! - The sequence can only prevent nonce reuse while it is the only source of
+//!   nonces for its key. Restoring an older state, or restoring the same state
+//!   twice, will hand out nonces that have already been used.
+//! - When persisting the state, it should be written to storage after calling
+//!   [`next_nonce()`] and before the returned nonce is used.
+//! - `NonceSequence` intentionally does not implement `Clone`.
+//! - [`derive_xnonce()`] only produces unique nonces if each `counter` is used
+//!   at most once for a given `nonce_key` and encryption key.
+//!
+//! # Example:
+//! ```rust
+//! use orion::hazardous::aead::{chacha20poly1305, nonce_sequence::NonceSequence};
+//!
+//! let secret_key = chacha20poly1305::SecretKey::generate();
+//! let mut sequence = NonceSequence::new(&[0u8; 4])?;
+//!
+//! let message = "Data to protect".as_bytes();
+//! let mut dst_out_ct = [0u8; 15 + 16];
+//! let nonce = sequence.next_nonce()?;
+//! chacha20poly1305::seal(&secret_key, &nonce, message, None, &mut dst_out_ct)?;
+//!
+//! // Persist the state and restore it later.
+//! let state = sequence.to_bytes();
+//! let mut restored = NonceSequence::from_bytes(&state)?;
+//! assert_ne!(restored.next_nonce()?, nonce);
+//!
+//! // Deriving nonces from a counter.
+//! use orion::hazardous::aead::{nonce_sequence::derive_xnonce, xchacha20poly1305};
+//! use orion::hazardous::hash::blake2b;
+//!
+//! let nonce_key = blake2b::SecretKey::generate();
+//! let nonce = derive_xnonce(&nonce_key, 0)?;
+//! let mut dst_out_ct = [0u8; 15 + 16];
+//! xchacha20poly1305::seal(&secret_key, &nonce, message, None, &mut dst_out_ct)?;
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`NonceSequence`]: struct.NonceSequence.html
+//! [`to_bytes()`]: struct.NonceSequence.html#method.to_bytes
+//! [`from_bytes()`]: struct.NonceSequence.html#method.from_bytes
+//! [`next_nonce()`]: struct.NonceSequence.html#method.next_nonce
+//! [`derive_xnonce()`]: fn.derive_xnonce.html
+//! [ChaCha20Poly1305]: https://docs.rs/orion/latest/orion/hazardous/aead/chacha20poly1305/index.html
+//! [XChaCha20Poly1305]: https://docs.rs/orion/latest/orion/hazardous/aead/xchacha20poly1305/index.html
+
+use crate::{
+	errors::UnknownCryptoError,
+	hazardous::{
+		hash::blake2b,
+		stream::{
+			chacha20::{Nonce, IETF_CHACHA_NONCESIZE},
+			xchacha20::{self, XCHACHA_NONCESIZE},
+		},
+	},
+};
+
+/// The size of the fixed prefix of each nonce.
+pub const NONCE_PREFIX_SIZE: usize = 4;
+/// The size of the exported state of a `NonceSequence`.
+pub const NONCE_SEQUENCE_STATESIZE: usize = IETF_CHACHA_NONCESIZE;
+
+#[derive(Debug, PartialEq)]
+/// A sequence of unique nonces for a single key.
+pub struct NonceSequence {
+	prefix: [u8; NONCE_PREFIX_SIZE],
+	counter: u64,
+}
+
+impl NonceSequence {
+	#[must_use]
+	/// Start a new sequence with `prefix`, with the counter at zero.
+	pub fn new(prefix: &[u8]) -> Result<Self, UnknownCryptoError> {
+		if prefix.len() != NONCE_PREFIX_SIZE {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+
+		let mut sequence = Self {
+			prefix: [0u8; NONCE_PREFIX_SIZE],
+			counter: 0,
+		};
+		sequence.prefix.copy_from_slice(prefix);
+
+		Ok(sequence)
+	}
+
+	#[must_use]
+	/// Restore a sequence from `state`, which was exported with `to_bytes()`.
+	pub fn from_bytes(state: &[u8]) -> Result<Self, UnknownCryptoError> {
+		if state.len() != NONCE_SEQUENCE_STATESIZE {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+
+		let mut sequence = Self::new(&state[..NONCE_PREFIX_SIZE])?;
+		let mut counter = [0u8; 8];
+		counter.copy_from_slice(&state[NONCE_PREFIX_SIZE..]);
+		sequence.counter = u64::from_be_bytes(counter);
+
+		Ok(sequence)
+	}
+
+	#[must_use]
+	/// Export the state of the sequence. The state holds the next nonce that
+	/// will be handed out.
+	pub fn to_bytes(&self) -> [u8; NONCE_SEQUENCE_STATESIZE] {
+		let mut state = [0u8; NONCE_SEQUENCE_STATESIZE];
+		state[..NONCE_PREFIX_SIZE].copy_from_slice(&self.prefix);
+		state[NONCE_PREFIX_SIZE..].copy_from_slice(&self.counter.to_be_bytes());
+
+		state
+	}
+
+	#[must_use]
+	/// Hand out the next nonce. Returns an error if the sequence is exhausted.
+	pub fn next_nonce(&mut self) -> Result<Nonce, UnknownCryptoError> {
+		if self.counter == u64::max_value() {
+			return Err(UnknownCryptoError::NonceCounterOverflow);
+		}
+
+		let nonce = Nonce::from_slice(&self.to_bytes())?;
+		self.counter += 1;
+
+		Ok(nonce)
+	}
+
+	#[must_use]
+	/// The number of nonces that can still be handed out.
+	pub fn remaining(&self) -> u64 {
+		u64::max_value() - self.counter
+	}
+}
+
+#[must_use]
+/// Derive the XChaCha20 nonce for `counter` as BLAKE2b(`nonce_key`, `counter`).
+pub fn derive_xnonce(
+	nonce_key: &blake2b::SecretKey,
+	counter: u64,
+) -> Result<xchacha20::Nonce, UnknownCryptoError> {
+	let mut state = blake2b::init(Some(nonce_key), XCHACHA_NONCESIZE)?;
+	state.update(&counter.to_be_bytes())?;
+
+	xchacha20::Nonce::from_slice(state.finalize()?.as_ref())
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for NonceSequence {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_bytes(&self.to_bytes())
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for NonceSequence {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		use crate::serde_secret::BytesVisitor;
+
+		deserializer.deserialize_bytes(BytesVisitor::new(
+			"NonceSequence",
+			NONCE_SEQUENCE_STATESIZE,
+			NonceSequence::from_bytes,
+		))
+	}
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	#[test]
+	fn test_prefix_length() {
+		assert!(NonceSequence::new(&[0u8; 0]).is_err());
+		assert!(NonceSequence::new(&[0u8; 3]).is_err());
+		assert!(NonceSequence::new(&[0u8; 4]).is_ok());
+		assert!(NonceSequence::new(&[0u8; 5]).is_err());
+	}
+
+	#[test]
+	fn test_state_length() {
+		assert!(NonceSequence::from_bytes(&[0u8; 0]).is_err());
+		assert!(NonceSequence::from_bytes(&[0u8; 11]).is_err());
+		assert!(NonceSequence::from_bytes(&[0u8; 12]).is_ok());
+		assert!(NonceSequence::from_bytes(&[0u8; 13]).is_err());
+	}
+
+	#[test]
+	fn test_nonce_layout() {
+		let mut sequence = NonceSequence::new(&[0xaa, 0xbb, 0xcc, 0xdd]).unwrap();
+		let expected_first = [0xaa, 0xbb, 0xcc, 0xdd, 0, 0, 0, 0, 0, 0, 0, 0];
+		let expected_second = [0xaa, 0xbb, 0xcc, 0xdd, 0, 0, 0, 0, 0, 0, 0, 1];
+
+		assert_eq!(sequence.next_nonce().unwrap().as_ref(), &expected_first);
+		assert_eq!(sequence.next_nonce().unwrap().as_ref(), &expected_second);
+		assert_eq!(sequence.remaining(), u64::max_value() - 2);
+	}
+
+	#[test]
+	fn test_strictly_increasing() {
+		let mut sequence = NonceSequence::new(&[0u8; 4]).unwrap();
+		let mut previous = sequence.next_nonce().unwrap();
+
+		for _ in 0..1000 {
+			let next = sequence.next_nonce().unwrap();
+			assert!(next.as_ref() > previous.as_ref());
+			previous = next;
+		}
+	}
+
+	#[test]
+	fn test_no_wrap() {
+		let mut state = [0xffu8; NONCE_SEQUENCE_STATESIZE];
+		state[NONCE_SEQUENCE_STATESIZE - 1] = 0xfe;
+		let mut sequence = NonceSequence::from_bytes(&state).unwrap();
+
+		assert_eq!(sequence.remaining(), 1);
+		assert_eq!(sequence.next_nonce().unwrap().as_ref(), &state);
+		assert_eq!(sequence.remaining(), 0);
+		assert!(sequence.next_nonce().is_err());
+		// The sequence stays exhausted.
+		assert!(sequence.next_nonce().is_err());
+		assert_eq!(sequence.to_bytes(), [0xffu8; NONCE_SEQUENCE_STATESIZE]);
+	}
+
+	#[test]
+	fn test_state_roundtrip() {
+		let mut sequence = NonceSequence::new(&[1u8; 4]).unwrap();
+		let _ = sequence.next_nonce().unwrap();
+		let _ = sequence.next_nonce().unwrap();
+
+		let mut restored = NonceSequence::from_bytes(&sequence.to_bytes()).unwrap();
+		assert_eq!(restored, sequence);
+		assert_eq!(
+			restored.next_nonce().unwrap(),
+			sequence.next_nonce().unwrap()
+		);
+	}
+
+	#[test]
+	fn test_derive_xnonce() {
+		let nonce_key = blake2b::SecretKey::from_slice(&[0u8; 32]).unwrap();
+		let first = derive_xnonce(&nonce_key, 0).unwrap();
+		assert_eq!(derive_xnonce(&nonce_key, 0).unwrap(), first);
+		assert_ne!(derive_xnonce(&nonce_key, 1).unwrap(), first);
+
+		let other_key = blake2b::SecretKey::from_slice(&[1u8; 32]).unwrap();
+		assert_ne!(derive_xnonce(&other_key, 0).unwrap(), first);
+	}
+
+	#[test]
+	#[cfg(all(feature = "serde", feature = "safe_api"))]
+	fn test_serde_roundtrip() {
+		let mut sequence = NonceSequence::new(&[1u8; 4]).unwrap();
+		let _ = sequence.next_nonce().unwrap();
+
+		let json = serde_json::to_string(&sequence).unwrap();
+		let restored: NonceSequence = serde_json::from_str(&json).unwrap();
+		assert_eq!(restored, sequence);
+
+		assert!(serde_json::from_str::<NonceSequence>("[1,2,3]").is_err());
+	}
+}
+
+// Testing any test vectors that aren't put into library's /tests folder.
+#[cfg(test)]
+mod test_vectors {
+	use super::*;
+
+	#[test]
+	fn test_derive_xnonce_vectors() {
+		let nonce_key = blake2b::SecretKey::from_slice(&[0u8; 32]).unwrap();
+		let expected = [
+			0xb8, 0x62, 0xc0, 0xca, 0xf7, 0xda, 0x04, 0xd7, 0x11, 0xd2, 0xe9, 0x6f, 0x00, 0xee,
+			0x7e, 0xad, 0x40, 0x2a, 0x3d, 0xb4, 0xab, 0x51, 0x3a, 0xa6,
+		];
+		assert_eq!(derive_xnonce(&nonce_key, 0).unwrap().as_ref(), &expected);
+
+		let mut key = [0u8; 32];
+		for (i, byte) in key.iter_mut().enumerate() {
+			*byte = i as u8;
+		}
+		let nonce_key = blake2b::SecretKey::from_slice(&key).unwrap();
+		let expected = [
+			0x4d, 0x44, 0x1c, 0x27, 0x3a, 0xa9, 0x45, 0x38, 0x07, 0xcc, 0x10, 0xf7, 0x66, 0x2c,
+			0xd7, 0x47, 0xb7, 0x42, 0x98, 0xcc, 0x27, 0x2c, 0x4c, 0x30,
+		];
+		assert_eq!(derive_xnonce(&nonce_key, 1).unwrap().as_ref(), &expected);
+	}
+}
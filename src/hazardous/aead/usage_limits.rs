@@ -0,0 +1,339 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # About:
+//! A [`KeyUsage`] tracks how much a single AEAD key has been used, and refuses
+//! further use once the [`UsageLimits`] for that key would be exceeded.
+//! Callers record each message they seal with [`record_seal()`] and each
+//! message that fails to open with [`record_failed_open()`], before the
+//! message is sealed or after it has been rejected, respectively.
+//!
+//! The predefined limits are:
+//! - [`UsageLimits::CHACHA20POLY1305`]: ChaCha20Poly1305 with nonces that are
+//!   never repeated, such as from a [`NonceSequence`].
+//! - [`UsageLimits::CHACHA20POLY1305_RANDOM_NONCE`]: ChaCha20Poly1305 with
+//!   randomly generated nonces. The number of messages is limited to 2^32, as
+//!   recommended in [NIST SP 800-38D] for 96-bit random nonces.
+//! - [`UsageLimits::XCHACHA20POLY1305`]: XChaCha20Poly1305 with random or
+//!   counter-based nonces.
+//!
+//! All of these limit a single message to 2^38-64 bytes and the number of
+//! failed attempts at opening a message to 2^36, following the integrity limit
+//! for ChaCha20Poly1305 in the [CFRG draft on AEAD usage limits].
+//!
+//! # Parameters:
+//! - `limits`: The limits to enforce for the key.
+//! - `messages`: The maximum number of messages that may be sealed.
+//! - `bytes`: The maximum total number of plaintext bytes that may be sealed.
+//! - `message_bytes`: The maximum number of plaintext bytes in one message.
+//! - `failed_opens`: The maximum number of messages that may fail to open.
+//! - `plaintext_length`: The length of the plaintext that is about to be
+//!   sealed.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - Any of the limits given to [`UsageLimits::new()`] are `0`.
+//! - [`record_seal()`] would exceed the `messages`, `bytes` or `message_bytes`
+//!   limit.
+//! - [`record_failed_open()`] would exceed the `failed_opens` limit.
+//!
+//! # Security:
+//! - A `KeyUsage` must only track a single key, and must be the only tracker
+//!   for that key. `KeyUsage` intentionally does not implement `Clone`.
+//! - Once an error is returned, the key should no longer be used and a new key
+//!   should be established. [`needs_rekey()`] can be used to rekey earlier,
+//!   before any limit is reached.
+//!
+//! # Example:
+//! ```rust
+//! use orion::hazardous::aead::{
+//! 	chacha20poly1305,
+//! 	nonce_sequence::NonceSequence,
+//! 	usage_limits::{KeyUsage, UsageLimits},
+//! };
+//!
+//! let secret_key = chacha20poly1305::SecretKey::generate();
+//! let mut sequence = NonceSequence::new(&[0u8; 4])?;
+//! let mut usage = KeyUsage::new(UsageLimits::CHACHA20POLY1305);
+//!
+//! let message = "Data to protect".as_bytes();
+//! let mut dst_out_ct = [0u8; 15 + 16];
+//! usage.record_seal(message.len())?;
+//! chacha20poly1305::seal(&secret_key, &sequence.next_nonce()?, message, None, &mut dst_out_ct)?;
+//!
+//! assert!(!usage.needs_rekey());
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`KeyUsage`]: struct.KeyUsage.html
+//! [`UsageLimits`]: struct.UsageLimits.html
+//! [`UsageLimits::new()`]: struct.UsageLimits.html#method.new
+//! [`UsageLimits::CHACHA20POLY1305`]: struct.UsageLimits.html#associatedconstant.CHACHA20POLY1305
+//! [`UsageLimits::CHACHA20POLY1305_RANDOM_NONCE`]: struct.UsageLimits.html#associatedconstant.CHACHA20POLY1305_RANDOM_NONCE
+//! [`UsageLimits::XCHACHA20POLY1305`]: struct.UsageLimits.html#associatedconstant.XCHACHA20POLY1305
+//! [`record_seal()`]: struct.KeyUsage.html#method.record_seal
+//! [`record_failed_open()`]: struct.KeyUsage.html#method.record_failed_open
+//! [`needs_rekey()`]: struct.KeyUsage.html#method.needs_rekey
+//! [`NonceSequence`]: ../nonce_sequence/struct.NonceSequence.html
+//! [NIST SP 800-38D]: https://nvlpubs.nist.gov/nistpubs/Legacy/SP/nistspecialpublication800-38d.pdf
+//! [CFRG draft on AEAD usage limits]: https://datatracker.ietf.org/doc/draft-irtf-cfrg-aead-limits/
+
+use crate::errors::UnknownCryptoError;
+
+/// The maximum plaintext length of a single ChaCha20Poly1305 message, which is
+/// limited by the 32-bit block counter.
+const CHACHA_MAX_MESSAGE_BYTES: u64 = (1u64 << 38) - 64;
+/// The maximum number of failed openings, from the ChaCha20Poly1305 integrity
+/// limit.
+const CHACHA_MAX_FAILED_OPENS: u64 = 1u64 << 36;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The limits on how much a single key may be used.
+pub struct UsageLimits {
+	messages: u64,
+	bytes: u64,
+	message_bytes: u64,
+	failed_opens: u64,
+}
+
+impl UsageLimits {
+	/// Limits for ChaCha20Poly1305 with nonces that are never repeated.
+	pub const CHACHA20POLY1305: UsageLimits = UsageLimits {
+		messages: u64::max_value(),
+		bytes: u64::max_value(),
+		message_bytes: CHACHA_MAX_MESSAGE_BYTES,
+		failed_opens: CHACHA_MAX_FAILED_OPENS,
+	};
+
+	/// Limits for ChaCha20Poly1305 with randomly generated nonces.
+	pub const CHACHA20POLY1305_RANDOM_NONCE: UsageLimits = UsageLimits {
+		messages: 1u64 << 32,
+		bytes: u64::max_value(),
+		message_bytes: CHACHA_MAX_MESSAGE_BYTES,
+		failed_opens: CHACHA_MAX_FAILED_OPENS,
+	};
+
+	/// Limits for XChaCha20Poly1305.
+	pub const XCHACHA20POLY1305: UsageLimits = UsageLimits {
+		messages: u64::max_value(),
+		bytes: u64::max_value(),
+		message_bytes: CHACHA_MAX_MESSAGE_BYTES,
+		failed_opens: CHACHA_MAX_FAILED_OPENS,
+	};
+
+	#[must_use]
+	/// Create custom limits.
+	pub fn new(
+		messages: u64,
+		bytes: u64,
+		message_bytes: u64,
+		failed_opens: u64,
+	) -> Result<Self, UnknownCryptoError> {
+		if messages == 0 || bytes == 0 || message_bytes == 0 || failed_opens == 0 {
+			return Err(UnknownCryptoError::ParameterOutOfRange);
+		}
+
+		Ok(Self {
+			messages,
+			bytes,
+			message_bytes,
+			failed_opens,
+		})
+	}
+}
+
+#[derive(Debug, PartialEq)]
+/// Tracks the usage of a single key against its `UsageLimits`.
+pub struct KeyUsage {
+	limits: UsageLimits,
+	messages: u64,
+	bytes: u64,
+	failed_opens: u64,
+}
+
+impl KeyUsage {
+	#[must_use]
+	/// Start tracking a key that has not been used yet.
+	pub fn new(limits: UsageLimits) -> Self {
+		Self {
+			limits,
+			messages: 0,
+			bytes: 0,
+			failed_opens: 0,
+		}
+	}
+
+	#[must_use]
+	/// Record that a message with `plaintext_length` bytes is about to be
+	/// sealed. Nothing is recorded if an error is returned.
+	pub fn record_seal(&mut self, plaintext_length: usize) -> Result<(), UnknownCryptoError> {
+		let length = plaintext_length as u64;
+		if length > self.limits.message_bytes
+			|| self.messages >= self.limits.messages
+			|| length > self.limits.bytes - self.bytes
+		{
+			return Err(UnknownCryptoError::SizeLimitExceeded);
+		}
+
+		self.messages += 1;
+		self.bytes += length;
+
+		Ok(())
+	}
+
+	#[must_use]
+	/// Record that a message failed to open. Returns an error once the key
+	/// should no longer be used to open messages.
+	pub fn record_failed_open(&mut self) -> Result<(), UnknownCryptoError> {
+		if self.failed_opens >= self.limits.failed_opens {
+			return Err(UnknownCryptoError::SizeLimitExceeded);
+		}
+
+		self.failed_opens += 1;
+
+		Ok(())
+	}
+
+	#[must_use]
+	/// Returns `true` if any usage has reached three quarters of its limit.
+	pub fn needs_rekey(&self) -> bool {
+		let approached = |used: u64, limit: u64| used >= limit - (limit / 4);
+
+		approached(self.messages, self.limits.messages)
+			|| approached(self.bytes, self.limits.bytes)
+			|| approached(self.failed_opens, self.limits.failed_opens)
+	}
+
+	#[must_use]
+	/// The number of messages recorded as sealed.
+	pub fn messages(&self) -> u64 {
+		self.messages
+	}
+
+	#[must_use]
+	/// The total number of plaintext bytes recorded as sealed.
+	pub fn bytes(&self) -> u64 {
+		self.bytes
+	}
+
+	#[must_use]
+	/// The number of messages recorded as failing to open.
+	pub fn failed_opens(&self) -> u64 {
+		self.failed_opens
+	}
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	#[test]
+	fn test_limits_new_zero_err() {
+		assert!(UsageLimits::new(0, 1, 1, 1).is_err());
+		assert!(UsageLimits::new(1, 0, 1, 1).is_err());
+		assert!(UsageLimits::new(1, 1, 0, 1).is_err());
+		assert!(UsageLimits::new(1, 1, 1, 0).is_err());
+		assert!(UsageLimits::new(1, 1, 1, 1).is_ok());
+	}
+
+	#[test]
+	fn test_message_limit() {
+		let mut usage = KeyUsage::new(UsageLimits::new(4, 100, 100, 1).unwrap());
+
+		for _ in 0..4 {
+			assert!(usage.record_seal(1).is_ok());
+		}
+		assert!(usage.record_seal(1).is_err());
+		assert_eq!(usage.messages(), 4);
+		assert_eq!(usage.bytes(), 4);
+	}
+
+	#[test]
+	fn test_byte_limits() {
+		let mut usage = KeyUsage::new(UsageLimits::new(100, 10, 6, 1).unwrap());
+
+		assert!(usage.record_seal(7).is_err());
+		assert!(usage.record_seal(6).is_ok());
+		assert!(usage.record_seal(5).is_err());
+		assert!(usage.record_seal(4).is_ok());
+		assert!(usage.record_seal(1).is_err());
+		// Empty messages are still counted.
+		assert!(usage.record_seal(0).is_ok());
+		assert_eq!(usage.messages(), 3);
+		assert_eq!(usage.bytes(), 10);
+	}
+
+	#[test]
+	fn test_failed_open_limit() {
+		let mut usage = KeyUsage::new(UsageLimits::new(1, 1, 1, 2).unwrap());
+
+		assert!(usage.record_failed_open().is_ok());
+		assert!(usage.record_failed_open().is_ok());
+		assert!(usage.record_failed_open().is_err());
+		assert_eq!(usage.failed_opens(), 2);
+	}
+
+	#[test]
+	fn test_needs_rekey() {
+		let mut usage = KeyUsage::new(UsageLimits::new(4, 100, 100, 8).unwrap());
+		assert!(!usage.needs_rekey());
+
+		for _ in 0..2 {
+			assert!(usage.record_seal(1).is_ok());
+		}
+		assert!(!usage.needs_rekey());
+		assert!(usage.record_seal(1).is_ok());
+		assert!(usage.needs_rekey());
+
+		let mut usage = KeyUsage::new(UsageLimits::new(100, 100, 100, 8).unwrap());
+		for _ in 0..5 {
+			assert!(usage.record_failed_open().is_ok());
+		}
+		assert!(!usage.needs_rekey());
+		assert!(usage.record_failed_open().is_ok());
+		assert!(usage.needs_rekey());
+	}
+
+	#[test]
+	#[cfg(target_pointer_width = "64")]
+	fn test_message_bytes_limit() {
+		let mut usage = KeyUsage::new(UsageLimits::CHACHA20POLY1305);
+		assert!(usage.record_seal(CHACHA_MAX_MESSAGE_BYTES as usize).is_ok());
+		assert!(usage
+			.record_seal(CHACHA_MAX_MESSAGE_BYTES as usize + 1)
+			.is_err());
+		assert!(!usage.needs_rekey());
+	}
+
+	#[test]
+	fn test_predefined_limits() {
+		let mut usage = KeyUsage::new(UsageLimits::CHACHA20POLY1305_RANDOM_NONCE);
+		usage.messages = (1u64 << 32) - 1;
+		assert!(usage.needs_rekey());
+		assert!(usage.record_seal(1).is_ok());
+		assert!(usage.record_seal(1).is_err());
+
+		let mut usage = KeyUsage::new(UsageLimits::XCHACHA20POLY1305);
+		usage.failed_opens = CHACHA_MAX_FAILED_OPENS;
+		assert!(usage.record_failed_open().is_err());
+	}
+}
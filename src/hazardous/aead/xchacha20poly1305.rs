@@ -32,6 +32,10 @@
 //! - `dst_out`: Destination array that will hold the
 //!   `ciphertext_with_tag`/`plaintext` after encryption/decryption.
 //!
+//! With the `safe_api` feature, [`seal_to_vec()`] and [`open_to_vec()`] take
+//! no `dst_out` and instead return a `Vec` of the right length, which is
+//! `plaintext + 16` or `ciphertext_with_tag - 16`.
+//!
 //! `ad`: "A typical use for these data is to authenticate version numbers,
 //! timestamps or monotonically increasing counters in order to discard previous
 //! messages and prevent replay attacks." See [libsodium docs](https://download.libsodium.org/doc/secret-key_cryptography/aead#additional-data) for more information.
@@ -44,9 +48,6 @@
 //! - The length of `ciphertext_with_tag` is not greater than `16`.
 //! - `plaintext` or `ciphertext_with_tag` are empty.
 //! - The received tag does not match the calculated tag when decrypting.
-//!
-//! # Panics:
-//! A panic will occur if:
 //! - More than 2^32-1 * 64 bytes of data are processed.
 //!
 //! # Security:
@@ -82,8 +83,16 @@
 //! aead::xchacha20poly1305::open(&secret_key, &nonce, &dst_out_ct, Some(&ad), &mut dst_out_pt)?;
 //!
 //! assert_eq!(dst_out_pt.as_ref(), message.as_ref());
+//!
+//! // The same, but with the output allocated to the right length.
+//! let ciphertext_with_tag = aead::xchacha20poly1305::seal_to_vec(&secret_key, &nonce, message, Some(&ad))?;
+//! let plaintext = aead::xchacha20poly1305::open_to_vec(&secret_key, &nonce, &ciphertext_with_tag, Some(&ad))?;
+//!
+//! assert_eq!(plaintext, message);
 //! # Ok::<(), orion::errors::UnknownCryptoError>(())
 //! ```
+//! [`seal_to_vec()`]: https://docs.rs/orion/latest/orion/hazardous/aead/xchacha20poly1305/fn.seal_to_vec.html
+//! [`open_to_vec()`]: https://docs.rs/orion/latest/orion/hazardous/aead/xchacha20poly1305/fn.open_to_vec.html
 //! [`SecretKey::generate()`]: https://docs.rs/orion/latest/orion/hazardous/stream/chacha20/struct.SecretKey.html
 //! [`Nonce::generate()`]: https://docs.rs/orion/latest/orion/hazardous/stream/xchacha20/struct.Nonce.html
 pub use crate::hazardous::stream::{chacha20::SecretKey, xchacha20::Nonce};
@@ -141,6 +150,48 @@ pub fn open(
 	)
 }
 
+#[cfg(feature = "safe_api")]
+#[must_use]
+/// AEAD XChaCha20Poly1305 encryption, returning a newly allocated `ciphertext_with_tag`.
+pub fn seal_to_vec(
+	secret_key: &SecretKey,
+	nonce: &Nonce,
+	plaintext: &[u8],
+	ad: Option<&[u8]>,
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	use crate::hazardous::mac::poly1305::POLY1305_OUTSIZE;
+
+	if plaintext.is_empty() {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let mut dst_out = vec![0u8; plaintext.len() + POLY1305_OUTSIZE];
+	seal(secret_key, nonce, plaintext, ad, &mut dst_out)?;
+
+	Ok(dst_out)
+}
+
+#[cfg(feature = "safe_api")]
+#[must_use]
+/// AEAD XChaCha20Poly1305 decryption, returning a newly allocated `plaintext`.
+pub fn open_to_vec(
+	secret_key: &SecretKey,
+	nonce: &Nonce,
+	ciphertext_with_tag: &[u8],
+	ad: Option<&[u8]>,
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	use crate::hazardous::mac::poly1305::POLY1305_OUTSIZE;
+
+	if ciphertext_with_tag.len() <= POLY1305_OUTSIZE {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let mut dst_out = vec![0u8; ciphertext_with_tag.len() - POLY1305_OUTSIZE];
+	open(secret_key, nonce, ciphertext_with_tag, ad, &mut dst_out)?;
+
+	Ok(dst_out)
+}
+
 //
 // The tests below are the same tests as the ones in `chacha20poly1305`
 // but with a bigger nonce. It's debatable whether this is needed, but right
@@ -348,6 +399,107 @@ mod public {
 		}
 	}
 
+	#[cfg(feature = "safe_api")]
+	mod test_to_vec {
+		use super::*;
+
+		#[test]
+		fn test_roundtrip() {
+			let sk = SecretKey::from_slice(&[0u8; 32]).unwrap();
+			let n = Nonce::from_slice(&[0u8; 24]).unwrap();
+			for len in 1..130 {
+				let plaintext = vec![1u8; len];
+				let ct = seal_to_vec(&sk, &n, &plaintext, Some(b"ad")).unwrap();
+				assert_eq!(ct.len(), len + POLY1305_OUTSIZE);
+				assert_eq!(open_to_vec(&sk, &n, &ct, Some(b"ad")).unwrap(), plaintext);
+			}
+		}
+
+		#[test]
+		fn test_same_as_seal() {
+			let sk = SecretKey::from_slice(&[0u8; 32]).unwrap();
+			let n = Nonce::from_slice(&[0u8; 24]).unwrap();
+			let mut dst_out_ct = [0u8; 64 + POLY1305_OUTSIZE];
+			seal(&sk, &n, &[0u8; 64], None, &mut dst_out_ct).unwrap();
+
+			assert_eq!(
+				seal_to_vec(&sk, &n, &[0u8; 64], None).unwrap(),
+				&dst_out_ct[..]
+			);
+		}
+
+		#[test]
+		fn test_length_err() {
+			let sk = SecretKey::from_slice(&[0u8; 32]).unwrap();
+			let n = Nonce::from_slice(&[0u8; 24]).unwrap();
+
+			assert!(seal_to_vec(&sk, &n, &[0u8; 0], None).is_err());
+			assert!(open_to_vec(&sk, &n, &[0u8; 0], None).is_err());
+			assert!(open_to_vec(&sk, &n, &[0u8; POLY1305_OUTSIZE], None).is_err());
+		}
+
+		#[test]
+		fn test_modified_err() {
+			let sk = SecretKey::from_slice(&[0u8; 32]).unwrap();
+			let n = Nonce::from_slice(&[0u8; 24]).unwrap();
+			let mut ct = seal_to_vec(&sk, &n, &[0u8; 64], Some(b"ad")).unwrap();
+
+			assert!(open_to_vec(&sk, &n, &ct, None).is_err());
+			ct[0] ^= 1;
+			assert!(open_to_vec(&sk, &n, &ct, Some(b"ad")).is_err());
+		}
+	}
+
+	#[cfg(feature = "safe_api")]
+	mod test_aead_interface {
+		use super::*;
+		use crate::test_framework::aead_interface::AeadTestRunner;
+
+		#[test]
+		fn test_aead_interface() {
+			let key = SecretKey::from_slice(&[0u8; 32]).unwrap();
+			let wrong_key = SecretKey::from_slice(&[1u8; 32]).unwrap();
+			let nonce = Nonce::from_slice(&[0u8; 24]).unwrap();
+
+			for input in &[&[0u8; 0][..], &[0u8; 1], &[0u8; 64], &[0u8; 257]] {
+				for aad in &[&[0u8; 0][..], &[0u8; 1], &[0u8; 17]] {
+					AeadTestRunner(
+						seal,
+						open,
+						&key,
+						&wrong_key,
+						&nonce,
+						input,
+						None,
+						POLY1305_OUTSIZE,
+						aad,
+					);
+				}
+			}
+		}
+
+		quickcheck! {
+			fn prop_aead_interface(input: Vec<u8>, ad: Vec<u8>) -> bool {
+				let key = SecretKey::from_slice(&[0u8; 32]).unwrap();
+				let wrong_key = SecretKey::from_slice(&[1u8; 32]).unwrap();
+				let nonce = Nonce::from_slice(&[0u8; 24]).unwrap();
+				AeadTestRunner(
+					seal,
+					open,
+					&key,
+					&wrong_key,
+					&nonce,
+					&input,
+					None,
+					POLY1305_OUTSIZE,
+					&ad,
+				);
+
+				true
+			}
+		}
+	}
+
 	// Proptests. Only exectued when NOT testing no_std.
 	#[cfg(feature = "safe_api")]
 	mod proptest {
@@ -0,0 +1,239 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! A strict reader for the subset of DER used by PKCS#8 and SPKI encoded
+//! keys, shared by the key types in this module.
+//!
+//! Encoding is not handled here: the encoded keys have a fixed size, so they
+//! are written from fixed prefixes.
+
+use crate::errors::UnknownCryptoError;
+
+/// The tag of an INTEGER.
+pub(crate) const TAG_INTEGER: u8 = 0x02;
+/// The tag of a BIT STRING.
+pub(crate) const TAG_BIT_STRING: u8 = 0x03;
+/// The tag of an OCTET STRING.
+pub(crate) const TAG_OCTET_STRING: u8 = 0x04;
+/// The tag of an OBJECT IDENTIFIER.
+pub(crate) const TAG_OID: u8 = 0x06;
+/// The tag of a SEQUENCE.
+pub(crate) const TAG_SEQUENCE: u8 = 0x30;
+
+/// The tag of a constructed, context-specific, field with number `n`.
+pub(crate) const fn tag_constructed(n: u8) -> u8 {
+	0xa0 | n
+}
+
+/// The tag of a primitive, context-specific, field with number `n`.
+pub(crate) const fn tag_primitive(n: u8) -> u8 {
+	0x80 | n
+}
+
+/// Reads DER encoded values from a slice, one after another.
+pub(crate) struct Reader<'a> {
+	data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+	pub(crate) fn new(data: &'a [u8]) -> Self {
+		Self { data }
+	}
+
+	/// Whether there is an encoded value with `tag` left to be read.
+	pub(crate) fn peek(&self, tag: u8) -> bool {
+		self.data.first() == Some(&tag)
+	}
+
+	/// Read a value with `tag` and return its contents.
+	///
+	/// Only the minimal, definite, length encodings that DER allows, and
+	/// lengths below 2^16, are accepted.
+	pub(crate) fn read(&mut self, tag: u8) -> Result<&'a [u8], UnknownCryptoError> {
+		if !self.peek(tag) || self.data.len() < 2 {
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+
+		let (len, header) = match self.data[1] {
+			len @ 0x00..=0x7f => (len as usize, 2),
+			0x81 if self.data.len() >= 3 && self.data[2] >= 0x80 => (self.data[2] as usize, 3),
+			0x82 if self.data.len() >= 4 && self.data[2] != 0x00 => {
+				(u16::from_be_bytes([self.data[2], self.data[3]]) as usize, 4)
+			}
+			_ => return Err(UnknownCryptoError::InvalidEncoding),
+		};
+		if self.data.len() - header < len {
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+
+		let contents = &self.data[header..header + len];
+		self.data = &self.data[header + len..];
+
+		Ok(contents)
+	}
+
+	/// Read a value with `tag`, if it is the next one.
+	pub(crate) fn read_optional(
+		&mut self,
+		tag: u8,
+	) -> Result<Option<&'a [u8]>, UnknownCryptoError> {
+		if self.peek(tag) {
+			self.read(tag).map(Some)
+		} else {
+			Ok(None)
+		}
+	}
+
+	/// Read a small, non-negative, INTEGER.
+	pub(crate) fn read_small_uint(&mut self) -> Result<u8, UnknownCryptoError> {
+		match self.read(TAG_INTEGER)? {
+			[value] if *value < 0x80 => Ok(*value),
+			_ => Err(UnknownCryptoError::InvalidEncoding),
+		}
+	}
+
+	/// Read a BIT STRING without unused bits and return its bytes.
+	pub(crate) fn read_bit_string(&mut self) -> Result<&'a [u8], UnknownCryptoError> {
+		Self::bit_string_bytes(self.read(TAG_BIT_STRING)?)
+	}
+
+	/// The bytes of a BIT STRING without unused bits, from its `contents`.
+	pub(crate) fn bit_string_bytes(contents: &'a [u8]) -> Result<&'a [u8], UnknownCryptoError> {
+		match contents.split_first() {
+			Some((0, bytes)) => Ok(bytes),
+			_ => Err(UnknownCryptoError::InvalidEncoding),
+		}
+	}
+
+	/// Read a SEQUENCE and return a `Reader` over its contents.
+	pub(crate) fn read_sequence(&mut self) -> Result<Reader<'a>, UnknownCryptoError> {
+		self.read(TAG_SEQUENCE).map(Reader::new)
+	}
+
+	/// Check that all values have been read.
+	pub(crate) fn finish(self) -> Result<(), UnknownCryptoError> {
+		if self.data.is_empty() {
+			Ok(())
+		} else {
+			Err(UnknownCryptoError::InvalidEncoding)
+		}
+	}
+}
+
+/// Read a DER encoded value that must be a single SEQUENCE, and return a
+/// `Reader` over its contents.
+pub(crate) fn outer_sequence(der: &[u8]) -> Result<Reader<'_>, UnknownCryptoError> {
+	let mut reader = Reader::new(der);
+	let sequence = reader.read_sequence()?;
+	reader.finish()?;
+
+	Ok(sequence)
+}
+
+// Testing private functions in the module.
+#[cfg(test)]
+mod private {
+	use super::*;
+
+	#[test]
+	fn test_read_lengths() {
+		assert_eq!(Reader::new(&[0x04, 0x00]).read(0x04).unwrap(), [0u8; 0]);
+		assert_eq!(Reader::new(&[0x04, 0x01, 0xaa]).read(0x04).unwrap(), [0xaa]);
+
+		let mut long = [0xaau8; 3 + 0x80];
+		long[..3].copy_from_slice(&[0x04, 0x81, 0x80]);
+		assert_eq!(Reader::new(&long).read(0x04).unwrap(), &long[3..]);
+
+		let mut longer = [0xaau8; 4 + 0x100];
+		longer[..4].copy_from_slice(&[0x04, 0x82, 0x01, 0x00]);
+		assert_eq!(Reader::new(&longer).read(0x04).unwrap(), &longer[4..]);
+	}
+
+	#[test]
+	fn test_read_non_minimal_length_err() {
+		assert!(Reader::new(&[0x04, 0x81, 0x01, 0xaa]).read(0x04).is_err());
+		let mut longer = [0xaau8; 4 + 0x80];
+		longer[..4].copy_from_slice(&[0x04, 0x82, 0x00, 0x80]);
+		assert!(Reader::new(&longer).read(0x04).is_err());
+	}
+
+	#[test]
+	fn test_read_bad_header_err() {
+		// Indefinite length.
+		assert!(Reader::new(&[0x04, 0x80, 0x00, 0x00]).read(0x04).is_err());
+		// Lengths of more than two bytes.
+		assert!(Reader::new(&[0x04, 0x83, 0x01, 0x00, 0x00])
+			.read(0x04)
+			.is_err());
+		// Wrong tag.
+		assert!(Reader::new(&[0x03, 0x00]).read(0x04).is_err());
+		// Truncated.
+		assert!(Reader::new(&[]).read(0x04).is_err());
+		assert!(Reader::new(&[0x04]).read(0x04).is_err());
+		assert!(Reader::new(&[0x04, 0x02, 0xaa]).read(0x04).is_err());
+		assert!(Reader::new(&[0x04, 0x81]).read(0x04).is_err());
+	}
+
+	#[test]
+	fn test_read_optional() {
+		let mut reader = Reader::new(&[0x04, 0x01, 0xaa]);
+		assert_eq!(reader.read_optional(0x03).unwrap(), None);
+		assert_eq!(reader.read_optional(0x04).unwrap(), Some(&[0xaa][..]));
+		assert!(reader.finish().is_ok());
+	}
+
+	#[test]
+	fn test_read_small_uint() {
+		assert_eq!(
+			Reader::new(&[0x02, 0x01, 0x01]).read_small_uint().unwrap(),
+			1
+		);
+		assert!(Reader::new(&[0x02, 0x01, 0x80]).read_small_uint().is_err());
+		assert!(Reader::new(&[0x02, 0x02, 0x00, 0x01])
+			.read_small_uint()
+			.is_err());
+		assert!(Reader::new(&[0x02, 0x00]).read_small_uint().is_err());
+	}
+
+	#[test]
+	fn test_read_bit_string() {
+		assert_eq!(
+			Reader::new(&[0x03, 0x02, 0x00, 0xaa])
+				.read_bit_string()
+				.unwrap(),
+			[0xaa]
+		);
+		assert!(Reader::new(&[0x03, 0x02, 0x01, 0xaa])
+			.read_bit_string()
+			.is_err());
+		assert!(Reader::new(&[0x03, 0x00]).read_bit_string().is_err());
+	}
+
+	#[test]
+	fn test_outer_sequence_trailing_data_err() {
+		assert!(outer_sequence(&[0x30, 0x00]).unwrap().finish().is_ok());
+		assert!(outer_sequence(&[0x30, 0x00, 0x00]).is_err());
+
+		let mut sequence = outer_sequence(&[0x30, 0x03, 0x04, 0x00, 0x00]).unwrap();
+		sequence.read(0x04).unwrap();
+		assert!(sequence.finish().is_err());
+	}
+}
@@ -0,0 +1,882 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Parameters:
+//! - `secret_key`: The secret key used to sign `message`.
+//! - `public_key`: The public key used to verify `signature`.
+//! - `message`: The message to be signed or verified.
+//! - `signature`: The signature that needs to be verified.
+//! - `seed`: The secret seed from which a `secret_key` is derived.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `secret_key` is zero or not less than the group order.
+//! - `public_key` is not a valid, uncompressed point on P-256.
+//! - The `signature` does not match the `message` when verifying.
+//! - A PKCS#8 or SPKI encoded key is not valid DER, or not a P-256 key.
+//! - A public key in a PKCS#8 encoded key does not belong to its secret key.
+//!
+//! # Security:
+//! - Signatures are deterministic. The per-signature nonce is derived from
+//!   `secret_key` and `message` as specified in RFC 6979, so no randomness is
+//!   needed when signing.
+//! - All arithmetic involving `secret_key` and the nonce is done in constant
+//!   time.
+//! - To securely generate a strong key, use [`SecretKey::generate()`].
+//!   It only returns scalars in `[1, n - 1]`, where `n` is the group order.
+//! - [`SecretKey::from_seed()`] always derives the same secret key from the
+//!   same seed. The seed must therefore be kept as secret as the secret key,
+//!   and should come from a CSPRNG or a KDF.
+//! - [`SecretKey::to_pkcs8_der()`] writes the secret key, unprotected, to
+//!   `dst`. It is up to the caller to zeroize `dst` after use.
+//! - Signatures are encoded as the fixed-size concatenation `r || s`. DER
+//!   encoded signatures must be converted before they can be verified.
+//! - ECDSA signatures are malleable: if `(r, s)` is valid, so is `(r, -s)`.
+//!
+//! # Recommendation:
+//! - ECDSA over P-256 should only be used for interoperability with existing
+//!   protocols, such as WebAuthn (`ES256`).
+//!
+//! # Example:
+//! ```rust
+//! use orion::hazardous::ecc::ecdsa_p256::{self, PublicKey, SecretKey};
+//! use core::convert::TryFrom;
+//!
+//! let secret_key = SecretKey::generate();
+//! let public_key = PublicKey::try_from(&secret_key)?;
+//!
+//! let signature = ecdsa_p256::sign(&secret_key, b"Some message")?;
+//!
+//! assert!(ecdsa_p256::verify(&signature, &public_key, b"Some message")?);
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`SecretKey::generate()`]: https://docs.rs/orion/latest/orion/hazardous/ecc/ecdsa_p256/struct.SecretKey.html
+//! [`SecretKey::from_seed()`]: https://docs.rs/orion/latest/orion/hazardous/ecc/ecdsa_p256/struct.SecretKey.html#method.from_seed
+//! [`SecretKey::to_pkcs8_der()`]: https://docs.rs/orion/latest/orion/hazardous/ecc/ecdsa_p256/struct.SecretKey.html#method.to_pkcs8_der
+
+use super::der;
+use super::p256::{
+	from_mont, is_zero, limbs_from_bytes, limbs_to_bytes, mod_add, mont_invert, mont_mul,
+	reduce_once, scalar_from_bytes, to_mont, Limbs, ProjectivePoint, ELEMENT_SIZE, N,
+};
+use crate::{
+	errors::UnknownCryptoError,
+	hazardous::{
+		hash::sha256::{self, hmac_sha256, SHA256_OUTSIZE},
+		kdf::hkdf,
+	},
+};
+use core::convert::TryFrom;
+use zeroize::Zeroize;
+
+/// The size of a secret key (a scalar) used in ECDSA P-256.
+pub const SECRET_KEY_SIZE: usize = 32;
+/// The size of an uncompressed, SEC1 encoded, public key used in ECDSA P-256.
+pub const PUBLIC_KEY_SIZE: usize = 65;
+/// The size of a signature (`r || s`) produced by ECDSA P-256.
+pub const SIGNATURE_SIZE: usize = 64;
+/// The size of a seed that a secret key can be derived from.
+pub const SEED_SIZE: usize = 32;
+/// The size of a secret key and its public key encoded as PKCS#8 DER.
+pub const PKCS8_SIZE: usize = 138;
+/// The size of a public key encoded as SPKI DER.
+pub const SPKI_SIZE: usize = 91;
+/// The HKDF salt used when deriving a secret key from a seed.
+const SEED_DOMAIN: &[u8] = b"orion-ecdsa-p256-secret-key";
+/// The contents of the AlgorithmIdentifier of a P-256 key, the OID
+/// id-ecPublicKey (1.2.840.10045.2.1) with the named curve prime256v1
+/// (1.2.840.10045.3.1.7) as parameters, as specified in the RFC 5480.
+const ALGORITHM_ID: [u8; 19] = [
+	0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d,
+	0x03, 0x01, 0x07,
+];
+/// The contents of the OID of prime256v1.
+const CURVE_OID: [u8; 8] = [0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07];
+/// A PKCS#8 encoded secret key, up to the secret key itself.
+const PKCS8_PREFIX: [u8; 36] = [
+	0x30, 0x81, 0x87, 0x02, 0x01, 0x00, 0x30, 0x13, 0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02,
+	0x01, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07, 0x04, 0x6d, 0x30, 0x6b, 0x02,
+	0x01, 0x01, 0x04, 0x20,
+];
+/// A PKCS#8 encoded secret key, between the secret and the public key.
+const PKCS8_PUBLIC_KEY_PREFIX: [u8; 5] = [0xa1, 0x44, 0x03, 0x42, 0x00];
+/// An SPKI encoded public key, up to the public key itself.
+const SPKI_PREFIX: [u8; 26] = [
+	0x30, 0x59, 0x30, 0x13, 0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x08, 0x2a,
+	0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07, 0x03, 0x42, 0x00,
+];
+
+construct_secret_key! {
+	/// A type to represent the `SecretKey` that ECDSA P-256 uses.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 32 bytes.
+	///
+	/// # Panics:
+	/// A panic will occur if:
+	/// - Failure to generate random bytes securely.
+	(SecretKey, test_secret_key, SECRET_KEY_SIZE, SECRET_KEY_SIZE, SECRET_KEY_SIZE),
+	custom_generate
+}
+
+impl_from_trait!(SecretKey, SECRET_KEY_SIZE);
+
+impl SecretKey {
+	#[must_use]
+	#[cfg(feature = "safe_api")]
+	/// Randomly generate using a CSPRNG. Not available in `no_std` context.
+	///
+	/// Random bytes are drawn until they are a scalar in `[1, n - 1]`. A
+	/// retry happens with probability below 2^-32.
+	pub fn generate() -> SecretKey {
+		// This will not panic, unless the OS CSPRNG fails.
+		Self::generate_with_rng(&mut crate::util::OsRng).unwrap()
+	}
+
+	#[must_use]
+	/// Randomly generate using `rng`. An error is returned if `rng` fails.
+	///
+	/// Random bytes are drawn until they are a scalar in `[1, n - 1]`. A
+	/// retry happens with probability below 2^-32.
+	pub fn generate_with_rng<R: crate::util::SecureRandom + ?Sized>(
+		rng: &mut R,
+	) -> Result<SecretKey, UnknownCryptoError> {
+		let mut sk = [0u8; SECRET_KEY_SIZE];
+		loop {
+			if let Err(err) = rng.fill_bytes(&mut sk) {
+				sk.zeroize();
+				return Err(err);
+			}
+			if scalar_from_bytes(&sk).is_ok() {
+				let secret_key = SecretKey::from(sk);
+				sk.zeroize();
+				return Ok(secret_key);
+			}
+		}
+	}
+
+	#[must_use]
+	/// Deterministically derive a `SecretKey` from `seed` using HKDF-HMAC-SHA512.
+	///
+	/// A candidate is derived with a one-byte counter as HKDF info, starting
+	/// at 0, until one is a valid scalar. A retry happens with probability
+	/// below 2^-32.
+	pub fn from_seed(seed: &Seed) -> Result<Self, UnknownCryptoError> {
+		let mut sk = [0u8; SECRET_KEY_SIZE];
+		for counter in 0..=u8::max_value() {
+			hkdf::derive_key(
+				SEED_DOMAIN,
+				seed.unprotected_as_bytes(),
+				Some(&[counter]),
+				&mut sk,
+			)?;
+			if scalar_from_bytes(&sk).is_ok() {
+				let secret_key = SecretKey::from(sk);
+				sk.zeroize();
+				return Ok(secret_key);
+			}
+		}
+
+		sk.zeroize();
+		Err(UnknownCryptoError::InvalidKey)
+	}
+
+	/// Encode the secret key and its public key as PKCS#8 DER (RFC 5208,
+	/// RFC 5915) into `dst`, which must be `PKCS8_SIZE` bytes. This is the
+	/// encoding that OpenSSL uses.
+	pub fn to_pkcs8_der(&self, dst: &mut [u8]) -> Result<(), UnknownCryptoError> {
+		if dst.len() != PKCS8_SIZE {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+
+		let public_key = PublicKey::try_from(self)?;
+		let (prefix, rest) = dst.split_at_mut(PKCS8_PREFIX.len());
+		let (secret_key, rest) = rest.split_at_mut(SECRET_KEY_SIZE);
+		let (public_key_prefix, public_key_dst) = rest.split_at_mut(PKCS8_PUBLIC_KEY_PREFIX.len());
+		prefix.copy_from_slice(&PKCS8_PREFIX);
+		secret_key.copy_from_slice(self.unprotected_as_bytes());
+		public_key_prefix.copy_from_slice(&PKCS8_PUBLIC_KEY_PREFIX);
+		public_key_dst.copy_from_slice(public_key.as_ref());
+
+		Ok(())
+	}
+
+	#[must_use]
+	/// Decode a secret key encoded as PKCS#8 v1 or v2 DER. The curve
+	/// parameters and public keys that the encoding may contain must match
+	/// the secret key.
+	pub fn from_pkcs8_der(der: &[u8]) -> Result<Self, UnknownCryptoError> {
+		let mut key_info = der::outer_sequence(der)?;
+		let version = key_info.read_small_uint()?;
+		if version > 1 {
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+		read_algorithm_id(&mut key_info)?;
+
+		// The ECPrivateKey structure of the RFC 5915, wrapped in an OCTET STRING.
+		let mut ec_private_key = der::outer_sequence(key_info.read(der::TAG_OCTET_STRING)?)?;
+		if ec_private_key.read_small_uint()? != 1 {
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+		let secret_key_bytes = ec_private_key.read(der::TAG_OCTET_STRING)?;
+		if secret_key_bytes.len() != SECRET_KEY_SIZE {
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+		if let Some(parameters) = ec_private_key.read_optional(der::tag_constructed(0))? {
+			let mut parameters = der::Reader::new(parameters);
+			if parameters.read(der::TAG_OID)? != CURVE_OID {
+				return Err(UnknownCryptoError::InvalidEncoding);
+			}
+			parameters.finish()?;
+		}
+		let inner_public_key = match ec_private_key.read_optional(der::tag_constructed(1))? {
+			Some(public_key) => {
+				let mut public_key = der::Reader::new(public_key);
+				let public_key_bytes = public_key.read_bit_string()?;
+				public_key.finish()?;
+				Some(public_key_bytes)
+			}
+			None => None,
+		};
+		ec_private_key.finish()?;
+
+		// Attributes are not used.
+		key_info.read_optional(der::tag_constructed(0))?;
+		let outer_public_key = match key_info.read_optional(der::tag_primitive(1))? {
+			Some(_) if version != 1 => return Err(UnknownCryptoError::InvalidEncoding),
+			Some(public_key) => Some(der::Reader::bit_string_bytes(public_key)?),
+			None => None,
+		};
+		key_info.finish()?;
+
+		let secret_key = SecretKey::from_slice(secret_key_bytes)?;
+		// This also checks that the secret key is a valid scalar.
+		let public_key = PublicKey::try_from(&secret_key)?;
+		for embedded in [inner_public_key, outer_public_key].iter().flatten() {
+			if public_key != *embedded {
+				return Err(UnknownCryptoError::InvalidKey);
+			}
+		}
+
+		Ok(secret_key)
+	}
+}
+
+construct_secret_key! {
+	/// A type to represent the `Seed` that a `SecretKey` can be derived from.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 32 bytes.
+	///
+	/// # Panics:
+	/// A panic will occur if:
+	/// - Failure to generate random bytes securely.
+	(Seed, test_seed, SEED_SIZE, SEED_SIZE, SEED_SIZE)
+}
+
+impl_from_trait!(Seed, SEED_SIZE);
+
+construct_public! {
+	/// A type to represent the uncompressed `PublicKey` (`0x04 || x || y`) that ECDSA P-256 uses.
+	///
+	/// Whether the public key is a valid point is checked when it is used.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 65 bytes.
+	(PublicKey, test_public_key, PUBLIC_KEY_SIZE, PUBLIC_KEY_SIZE)
+}
+
+impl_from_trait!(PublicKey, PUBLIC_KEY_SIZE);
+
+impl PublicKey {
+	/// Encode the public key as SPKI DER (RFC 5280, RFC 5480).
+	pub fn to_spki_der(&self) -> [u8; SPKI_SIZE] {
+		let mut der = [0u8; SPKI_SIZE];
+		der[..SPKI_PREFIX.len()].copy_from_slice(&SPKI_PREFIX);
+		der[SPKI_PREFIX.len()..].copy_from_slice(self.as_ref());
+
+		der
+	}
+
+	#[must_use]
+	/// Decode an uncompressed public key encoded as SPKI DER. As with
+	/// `from_slice()`, whether it is a valid point is checked when it is used.
+	pub fn from_spki_der(der: &[u8]) -> Result<Self, UnknownCryptoError> {
+		let mut spki = der::outer_sequence(der)?;
+		read_algorithm_id(&mut spki)?;
+		let public_key = spki.read_bit_string()?;
+		spki.finish()?;
+		if public_key.len() != PUBLIC_KEY_SIZE {
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+
+		PublicKey::from_slice(public_key)
+	}
+}
+
+/// Read the AlgorithmIdentifier of a PKCS#8 or SPKI encoded key, which must be
+/// that of a P-256 key.
+fn read_algorithm_id(reader: &mut der::Reader) -> Result<(), UnknownCryptoError> {
+	if reader.read(der::TAG_SEQUENCE)? != ALGORITHM_ID {
+		return Err(UnknownCryptoError::InvalidEncoding);
+	}
+
+	Ok(())
+}
+
+impl TryFrom<&SecretKey> for PublicKey {
+	type Error = UnknownCryptoError;
+
+	/// Compute the public key belonging to `secret_key`.
+	fn try_from(secret_key: &SecretKey) -> Result<Self, Self::Error> {
+		let mut d = scalar_from_bytes(secret_key.unprotected_as_bytes())?;
+		let point = ProjectivePoint::generator().mul(&d);
+		d.zeroize();
+
+		Ok(PublicKey::from(point.to_uncompressed()?))
+	}
+}
+
+construct_public! {
+	/// A type to represent the `Signature` (`r || s`) that ECDSA P-256 produces.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 64 bytes.
+	(Signature, test_signature, SIGNATURE_SIZE, SIGNATURE_SIZE)
+}
+
+impl_from_trait!(Signature, SIGNATURE_SIZE);
+
+/// Generate the deterministic nonce `k` as specified in RFC 6979 section 3.2.
+/// `secret` is the secret scalar and `h1` is the reduced message digest, both
+/// as 32 big-endian bytes.
+fn rfc6979_nonce(
+	secret: &[u8; ELEMENT_SIZE],
+	h1: &[u8; ELEMENT_SIZE],
+) -> Result<Limbs, UnknownCryptoError> {
+	let mut v = [0x01u8; SHA256_OUTSIZE];
+	let mut k = [0x00u8; SHA256_OUTSIZE];
+
+	k = hmac_sha256(&k, &[&v, &[0x00], secret, h1])?;
+	v = hmac_sha256(&k, &[&v])?;
+	k = hmac_sha256(&k, &[&v, &[0x01], secret, h1])?;
+	v = hmac_sha256(&k, &[&v])?;
+
+	loop {
+		// qlen == hlen, so a single HMAC output is enough.
+		v = hmac_sha256(&k, &[&v])?;
+		let candidate = limbs_from_bytes(&v);
+		if scalar_from_bytes(&v).is_ok() {
+			k.zeroize();
+			v.zeroize();
+			return Ok(candidate);
+		}
+
+		k = hmac_sha256(&k, &[&v, &[0x00]])?;
+		v = hmac_sha256(&k, &[&v])?;
+	}
+}
+
+/// Hash `message` and reduce the digest modulo n.
+fn hash_to_scalar(message: &[u8]) -> Result<Limbs, UnknownCryptoError> {
+	let digest = sha256::digest(message)?;
+	// The digest is 256 bits, so bits2int is the identity and the result is
+	// less than 2n.
+	Ok(reduce_once(&limbs_from_bytes(digest.as_ref()), 0, &N))
+}
+
+/// Sign the reduced message digest `e` with the secret scalar `d` and the
+/// nonce `k`. The secret intermediate values are zeroized before returning.
+fn sign_with_nonce(d: &Limbs, k: &Limbs, e: &Limbs) -> Result<Signature, UnknownCryptoError> {
+	let (r_x, _) = ProjectivePoint::generator().mul(k).to_affine()?;
+	// r_x < p < 2n
+	let r = reduce_once(&r_x, 0, &N);
+	if is_zero(&r) == 1 {
+		return Err(UnknownCryptoError::InvalidKey);
+	}
+
+	// s = k^-1 * (e + r * d) mod n, computed in the Montgomery domain.
+	let mut k_mont = to_mont(k, &N);
+	let mut k_inv = mont_invert(&k_mont, &N);
+	let mut d_mont = to_mont(d, &N);
+	let mut rd = mont_mul(&to_mont(&r, &N), &d_mont, &N);
+	let mut sum = mod_add(&to_mont(e, &N), &rd, &N);
+	let s = from_mont(&mont_mul(&k_inv, &sum, &N), &N);
+	k_mont.zeroize();
+	k_inv.zeroize();
+	d_mont.zeroize();
+	rd.zeroize();
+	sum.zeroize();
+
+	if is_zero(&s) == 1 {
+		return Err(UnknownCryptoError::InvalidKey);
+	}
+
+	let mut signature = [0u8; SIGNATURE_SIZE];
+	signature[..ELEMENT_SIZE].copy_from_slice(&limbs_to_bytes(&r));
+	signature[ELEMENT_SIZE..].copy_from_slice(&limbs_to_bytes(&s));
+
+	Ok(Signature::from(signature))
+}
+
+#[must_use]
+/// Sign `message` using ECDSA P-256 with SHA256 and RFC 6979 nonces.
+pub fn sign(secret_key: &SecretKey, message: &[u8]) -> Result<Signature, UnknownCryptoError> {
+	let e = hash_to_scalar(message)?;
+	let mut d = scalar_from_bytes(secret_key.unprotected_as_bytes())?;
+
+	let mut secret = [0u8; ELEMENT_SIZE];
+	secret.copy_from_slice(secret_key.unprotected_as_bytes());
+	let nonce = rfc6979_nonce(&secret, &limbs_to_bytes(&e));
+	secret.zeroize();
+
+	let signature = match nonce {
+		Ok(mut k) => {
+			let signature = sign_with_nonce(&d, &k, &e);
+			k.zeroize();
+			signature
+		}
+		Err(err) => Err(err),
+	};
+	d.zeroize();
+
+	signature
+}
+
+#[must_use]
+/// Verify an ECDSA P-256 `signature` of `message`.
+pub fn verify(
+	signature: &Signature,
+	public_key: &PublicKey,
+	message: &[u8],
+) -> Result<bool, UnknownCryptoError> {
+	let q = ProjectivePoint::from_uncompressed(public_key.as_ref())?;
+	let r = scalar_from_bytes(&signature.as_ref()[..ELEMENT_SIZE])?;
+	let s = scalar_from_bytes(&signature.as_ref()[ELEMENT_SIZE..])?;
+	let e = hash_to_scalar(message)?;
+
+	let w = mont_invert(&to_mont(&s, &N), &N);
+	let u1 = from_mont(&mont_mul(&to_mont(&e, &N), &w, &N), &N);
+	let u2 = from_mont(&mont_mul(&to_mont(&r, &N), &w, &N), &N);
+
+	let point = ProjectivePoint::generator().mul(&u1).add(&q.mul(&u2));
+	let (x, _) = point.to_affine()?;
+	let v = reduce_once(&x, 0, &N);
+
+	if crate::util::secure_cmp(&limbs_to_bytes(&v), &limbs_to_bytes(&r)).is_ok() {
+		Ok(true)
+	} else {
+		Err(UnknownCryptoError::InvalidTag)
+	}
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	mod test_generate {
+		use super::*;
+		use crate::util::SecureRandom;
+
+		/// Returns the given candidates in order, then errors.
+		struct Candidates<'a>(&'a [[u8; SECRET_KEY_SIZE]]);
+
+		impl SecureRandom for Candidates<'_> {
+			fn fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), UnknownCryptoError> {
+				let (first, rest) = self
+					.0
+					.split_first()
+					.ok_or(UnknownCryptoError::InvalidLength)?;
+				dst.copy_from_slice(first);
+				self.0 = rest;
+				Ok(())
+			}
+		}
+
+		#[test]
+		fn test_generate_rejects_out_of_range() {
+			let mut valid = [0u8; SECRET_KEY_SIZE];
+			valid[SECRET_KEY_SIZE - 1] = 1;
+			let order = limbs_to_bytes(&N.m);
+
+			let mut rng = Candidates(&[
+				[0u8; SECRET_KEY_SIZE],
+				[0xffu8; SECRET_KEY_SIZE],
+				order,
+				valid,
+			]);
+			let sk = SecretKey::generate_with_rng(&mut rng).unwrap();
+			assert!(sk == valid.as_ref());
+			assert!(rng.0.is_empty());
+		}
+
+		#[test]
+		fn test_generate_rng_err() {
+			let mut rng = Candidates(&[[0u8; SECRET_KEY_SIZE]]);
+			assert!(SecretKey::generate_with_rng(&mut rng).is_err());
+		}
+
+		#[test]
+		#[cfg(feature = "safe_api")]
+		fn test_generate_is_scalar() {
+			for _ in 0..32 {
+				let sk = SecretKey::generate();
+				assert!(scalar_from_bytes(sk.unprotected_as_bytes()).is_ok());
+			}
+		}
+	}
+
+	mod test_from_seed {
+		use super::*;
+
+		#[test]
+		fn test_from_seed_deterministic() {
+			let seed = Seed::from([1u8; SEED_SIZE]);
+			let sk = SecretKey::from_seed(&seed).unwrap();
+			assert!(sk == SecretKey::from_seed(&seed).unwrap());
+			assert!(scalar_from_bytes(sk.unprotected_as_bytes()).is_ok());
+		}
+
+		#[test]
+		fn test_from_seed_is_hkdf() {
+			let seed = Seed::from([2u8; SEED_SIZE]);
+			let mut expected = [0u8; SECRET_KEY_SIZE];
+			hkdf::derive_key(SEED_DOMAIN, &[2u8; SEED_SIZE], Some(&[0u8]), &mut expected).unwrap();
+			// The first candidate is valid for all but a negligible fraction of seeds.
+			assert!(SecretKey::from_seed(&seed).unwrap() == expected.as_ref());
+		}
+
+		#[test]
+		fn test_from_seed_diff_seed() {
+			let sk = SecretKey::from_seed(&Seed::from([0u8; SEED_SIZE])).unwrap();
+			let mut other = [0u8; SEED_SIZE];
+			other[SEED_SIZE - 1] = 1;
+			assert!(sk != SecretKey::from_seed(&Seed::from(other)).unwrap());
+		}
+
+		#[test]
+		fn test_from_seed_sign_verify() {
+			let sk = SecretKey::from_seed(&Seed::from([3u8; SEED_SIZE])).unwrap();
+			let pk = PublicKey::try_from(&sk).unwrap();
+			let sig = sign(&sk, b"Some message").unwrap();
+
+			assert!(verify(&sig, &pk, b"Some message").unwrap());
+		}
+	}
+
+	mod test_der {
+		use super::*;
+		use crate::util::hex;
+
+		// Encoded with OpenSSL from the secret key 0x01, 0x02, .., 0x20.
+		const PKCS8: &str = "308187020100301306072a8648ce3d020106082a8648ce3d030107046d306b02010104200102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20a14403420004515c3d6eb9e396b904d3feca7f54fdcd0cc1e997bf375dca515ad0a6c3b4035f4536be3a50f318fbf9a5475902a221502bef0d57e08c53b2cc0a56f17d9f9354";
+		const SPKI: &str = "3059301306072a8648ce3d020106082a8648ce3d03010703420004515c3d6eb9e396b904d3feca7f54fdcd0cc1e997bf375dca515ad0a6c3b4035f4536be3a50f318fbf9a5475902a221502bef0d57e08c53b2cc0a56f17d9f9354";
+		// Accepted by OpenSSL: with the curve parameters in the ECPrivateKey,
+		// and without the public key.
+		const PKCS8_PARAMETERS: &str = "308193020100301306072a8648ce3d020106082a8648ce3d0301070479307702010104200102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20a00a06082a8648ce3d030107a14403420004515c3d6eb9e396b904d3feca7f54fdcd0cc1e997bf375dca515ad0a6c3b4035f4536be3a50f318fbf9a5475902a221502bef0d57e08c53b2cc0a56f17d9f9354";
+		const PKCS8_NO_PUBLIC_KEY: &str = "3041020100301306072a8648ce3d020106082a8648ce3d0301070427302502010104200102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20";
+
+		fn secret_key() -> SecretKey {
+			let mut sk = [0u8; SECRET_KEY_SIZE];
+			for (idx, byte) in sk.iter_mut().enumerate() {
+				*byte = idx as u8 + 1;
+			}
+
+			SecretKey::from(sk)
+		}
+
+		fn decode<'a>(src: &str, dst: &'a mut [u8]) -> &'a [u8] {
+			let len = src.len() / 2;
+			hex::decode_to_slice(src, &mut dst[..len]).unwrap();
+			&dst[..len]
+		}
+
+		#[test]
+		fn test_pkcs8_openssl() {
+			let mut expected = [0u8; PKCS8_SIZE];
+			decode(PKCS8, &mut expected);
+			let mut der = [0u8; PKCS8_SIZE];
+			secret_key().to_pkcs8_der(&mut der).unwrap();
+			assert_eq!(der[..], expected[..]);
+			assert!(SecretKey::from_pkcs8_der(&der).unwrap() == secret_key());
+		}
+
+		#[test]
+		fn test_pkcs8_optional_fields() {
+			let mut buf = [0u8; 256];
+			let der = decode(PKCS8_PARAMETERS, &mut buf);
+			assert!(SecretKey::from_pkcs8_der(der).unwrap() == secret_key());
+			let der = decode(PKCS8_NO_PUBLIC_KEY, &mut buf);
+			assert!(SecretKey::from_pkcs8_der(der).unwrap() == secret_key());
+		}
+
+		#[test]
+		fn test_spki_openssl() {
+			let mut expected = [0u8; SPKI_SIZE];
+			decode(SPKI, &mut expected);
+			let pk = PublicKey::try_from(&secret_key()).unwrap();
+			assert_eq!(pk.to_spki_der()[..], expected[..]);
+			assert_eq!(PublicKey::from_spki_der(&expected).unwrap(), pk);
+		}
+
+		#[test]
+		fn test_pkcs8_bad_dst_len_err() {
+			let sk = secret_key();
+			assert!(sk.to_pkcs8_der(&mut [0u8; PKCS8_SIZE - 1]).is_err());
+			assert!(sk.to_pkcs8_der(&mut [0u8; PKCS8_SIZE + 1]).is_err());
+		}
+
+		#[test]
+		fn test_pkcs8_invalid_secret_key_err() {
+			let sk = SecretKey::from_slice(&[0u8; SECRET_KEY_SIZE]).unwrap();
+			assert!(sk.to_pkcs8_der(&mut [0u8; PKCS8_SIZE]).is_err());
+
+			let mut buf = [0u8; 256];
+			let len = PKCS8_NO_PUBLIC_KEY.len() / 2;
+			decode(PKCS8_NO_PUBLIC_KEY, &mut buf);
+			for byte in buf[len - SECRET_KEY_SIZE..len].iter_mut() {
+				*byte = 0xff;
+			}
+			assert!(SecretKey::from_pkcs8_der(&buf[..len]).is_err());
+		}
+
+		#[test]
+		fn test_pkcs8_wrong_public_key_err() {
+			let mut der = [0u8; PKCS8_SIZE];
+			decode(PKCS8, &mut der);
+			der[PKCS8_SIZE - 1] ^= 1;
+			assert_eq!(
+				SecretKey::from_pkcs8_der(&der).unwrap_err(),
+				UnknownCryptoError::InvalidKey
+			);
+		}
+
+		#[test]
+		fn test_pkcs8_wrong_curve_err() {
+			// The OID of prime192v1, 1.2.840.10045.3.1.1, in the ECPrivateKey
+			// parameters.
+			let mut buf = [0u8; 256];
+			let len = PKCS8_PARAMETERS.len() / 2;
+			decode(PKCS8_PARAMETERS, &mut buf);
+			buf[79] = 0x01;
+			assert!(SecretKey::from_pkcs8_der(&buf[..len]).is_err());
+		}
+
+		#[test]
+		fn test_wrong_algorithm_err() {
+			// 1.2.840.10045.2.2 instead of id-ecPublicKey, and prime256v1
+			// replaced by 1.2.840.10045.3.1.8.
+			let mut der = [0u8; PKCS8_SIZE];
+			decode(PKCS8, &mut der);
+			der[16] = 0x02;
+			assert!(SecretKey::from_pkcs8_der(&der).is_err());
+
+			let mut der = [0u8; SPKI_SIZE];
+			decode(SPKI, &mut der);
+			der[22] = 0x08;
+			assert!(PublicKey::from_spki_der(&der).is_err());
+		}
+
+		#[test]
+		fn test_pkcs8_bad_version_err() {
+			let mut der = [0u8; PKCS8_SIZE];
+			decode(PKCS8, &mut der);
+			der[5] = 0x02;
+			assert!(SecretKey::from_pkcs8_der(&der).is_err());
+
+			// The version of the ECPrivateKey.
+			decode(PKCS8, &mut der);
+			der[33] = 0x00;
+			assert!(SecretKey::from_pkcs8_der(&der).is_err());
+		}
+
+		#[test]
+		fn test_truncated_or_trailing_err() {
+			let mut der = [0u8; PKCS8_SIZE + 1];
+			decode(PKCS8, &mut der);
+			assert!(SecretKey::from_pkcs8_der(&der).is_err());
+			for len in 0..PKCS8_SIZE {
+				assert!(SecretKey::from_pkcs8_der(&der[..len]).is_err());
+			}
+
+			let mut der = [0u8; SPKI_SIZE + 1];
+			decode(SPKI, &mut der);
+			assert!(PublicKey::from_spki_der(&der).is_err());
+			for len in 0..SPKI_SIZE {
+				assert!(PublicKey::from_spki_der(&der[..len]).is_err());
+			}
+		}
+
+		#[test]
+		fn test_compressed_spki_err() {
+			let mut der = [0u8; SPKI_SIZE];
+			decode(SPKI, &mut der);
+			let mut compressed = [0u8; SPKI_SIZE - 32];
+			compressed.copy_from_slice(&der[..SPKI_SIZE - 32]);
+			compressed[1] -= 32;
+			compressed[24] -= 32;
+			compressed[26] = 0x02;
+			assert!(PublicKey::from_spki_der(&compressed).is_err());
+		}
+
+		#[test]
+		fn test_der_sign_verify() {
+			let mut der = [0u8; PKCS8_SIZE];
+			secret_key().to_pkcs8_der(&mut der).unwrap();
+			let sk = SecretKey::from_pkcs8_der(&der).unwrap();
+			let pk = PublicKey::try_from(&secret_key()).unwrap();
+			let pk = PublicKey::from_spki_der(&pk.to_spki_der()).unwrap();
+
+			let signature = sign(&sk, b"message").unwrap();
+			assert!(verify(&signature, &pk, b"message").unwrap());
+		}
+	}
+
+	mod test_sign_verify {
+		use super::*;
+
+		#[test]
+		fn test_sign_verify_ok() {
+			let sk = SecretKey::from_slice(&[0x42u8; 32]).unwrap();
+			let pk = PublicKey::try_from(&sk).unwrap();
+			let sig = sign(&sk, b"Some message").unwrap();
+
+			assert!(verify(&sig, &pk, b"Some message").unwrap());
+		}
+
+		#[test]
+		fn test_sign_deterministic() {
+			let sk = SecretKey::from_slice(&[0x42u8; 32]).unwrap();
+
+			assert_eq!(
+				sign(&sk, b"Some message").unwrap(),
+				sign(&sk, b"Some message").unwrap()
+			);
+			assert_ne!(
+				sign(&sk, b"Some message").unwrap(),
+				sign(&sk, b"Other message").unwrap()
+			);
+		}
+
+		#[test]
+		fn test_verify_modified_message_err() {
+			let sk = SecretKey::from_slice(&[0x42u8; 32]).unwrap();
+			let pk = PublicKey::try_from(&sk).unwrap();
+			let sig = sign(&sk, b"Some message").unwrap();
+
+			assert!(verify(&sig, &pk, b"Some messagf").is_err());
+		}
+
+		#[test]
+		fn test_verify_modified_signature_err() {
+			let sk = SecretKey::from_slice(&[0x42u8; 32]).unwrap();
+			let pk = PublicKey::try_from(&sk).unwrap();
+			let sig = sign(&sk, b"Some message").unwrap();
+
+			let mut modified = [0u8; SIGNATURE_SIZE];
+			modified.copy_from_slice(sig.as_ref());
+			modified[0] ^= 1;
+			assert!(verify(&Signature::from(modified), &pk, b"Some message").is_err());
+
+			modified.copy_from_slice(sig.as_ref());
+			modified[63] ^= 1;
+			assert!(verify(&Signature::from(modified), &pk, b"Some message").is_err());
+		}
+
+		#[test]
+		fn test_verify_diff_key_err() {
+			let sk = SecretKey::from_slice(&[0x42u8; 32]).unwrap();
+			let bad_sk = SecretKey::from_slice(&[0x43u8; 32]).unwrap();
+			let bad_pk = PublicKey::try_from(&bad_sk).unwrap();
+			let sig = sign(&sk, b"Some message").unwrap();
+
+			assert!(verify(&sig, &bad_pk, b"Some message").is_err());
+		}
+
+		#[test]
+		fn test_zero_signature_err() {
+			let sk = SecretKey::from_slice(&[0x42u8; 32]).unwrap();
+			let pk = PublicKey::try_from(&sk).unwrap();
+
+			let sig = Signature::from([0u8; SIGNATURE_SIZE]);
+			assert!(verify(&sig, &pk, b"Some message").is_err());
+		}
+
+		#[test]
+		fn test_invalid_secret_key_err() {
+			// Zero
+			let sk = SecretKey::from_slice(&[0u8; 32]).unwrap();
+			assert!(sign(&sk, b"Some message").is_err());
+			assert!(PublicKey::try_from(&sk).is_err());
+			// Larger than the group order
+			let sk = SecretKey::from_slice(&[0xffu8; 32]).unwrap();
+			assert!(sign(&sk, b"Some message").is_err());
+			assert!(PublicKey::try_from(&sk).is_err());
+		}
+
+		#[test]
+		fn test_invalid_public_key_err() {
+			let sk = SecretKey::from_slice(&[0x42u8; 32]).unwrap();
+			let pk = PublicKey::try_from(&sk).unwrap();
+			let sig = sign(&sk, b"Some message").unwrap();
+
+			// Wrong prefix
+			let mut modified = [0u8; PUBLIC_KEY_SIZE];
+			modified.copy_from_slice(pk.as_ref());
+			modified[0] = 0x02;
+			assert!(verify(&sig, &PublicKey::from(modified), b"Some message").is_err());
+			// Not on the curve
+			modified.copy_from_slice(pk.as_ref());
+			modified[64] ^= 1;
+			assert!(verify(&sig, &PublicKey::from(modified), b"Some message").is_err());
+			// Point at infinity cannot be encoded
+			assert!(verify(
+				&sig,
+				&PublicKey::from([0u8; PUBLIC_KEY_SIZE]),
+				b"Some message"
+			)
+			.is_err());
+		}
+
+		// Proptests. Only exectued when NOT testing no_std.
+		#[cfg(feature = "safe_api")]
+		mod proptest {
+			use super::*;
+
+			quickcheck! {
+				/// Signing and verifying with the same key pair should always succeed.
+				fn prop_sign_verify_same_key(input: Vec<u8>) -> bool {
+					let sk = SecretKey::from_slice(&[0x01u8; 32]).unwrap();
+					let pk = PublicKey::try_from(&sk).unwrap();
+					let sig = sign(&sk, &input[..]).unwrap();
+
+					verify(&sig, &pk, &input[..]).unwrap()
+				}
+			}
+		}
+	}
+}
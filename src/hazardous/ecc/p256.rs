@@ -0,0 +1,549 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Arithmetic over the NIST P-256 curve, shared by the P-256 based
+//! primitives in this crate.
+
+use crate::errors::UnknownCryptoError;
+
+/// The size of a field element or scalar when serialized.
+pub(crate) const ELEMENT_SIZE: usize = 32;
+/// The size of an uncompressed, SEC1 encoded, point.
+pub(crate) const UNCOMPRESSED_POINT_SIZE: usize = 65;
+/// Four 64-bit limbs, least significant first.
+pub(crate) type Limbs = [u64; 4];
+
+/// A 256-bit odd modulus together with its Montgomery constants.
+pub(crate) struct Modulus {
+	/// The modulus itself.
+	pub(crate) m: Limbs,
+	/// R^2 mod m, where R = 2^256.
+	pub(crate) r2: Limbs,
+	/// -m^-1 mod 2^64.
+	pub(crate) m_inv: u64,
+}
+
+#[allow(clippy::unreadable_literal)]
+/// The field prime p = 2^256 - 2^224 + 2^192 + 2^96 - 1.
+pub(crate) const P: Modulus = Modulus {
+	m: [
+		0xffffffffffffffff,
+		0x00000000ffffffff,
+		0x0000000000000000,
+		0xffffffff00000001,
+	],
+	r2: [
+		0x0000000000000003,
+		0xfffffffbffffffff,
+		0xfffffffffffffffe,
+		0x00000004fffffffd,
+	],
+	m_inv: 1,
+};
+
+#[allow(clippy::unreadable_literal)]
+/// The order n of the base point.
+pub(crate) const N: Modulus = Modulus {
+	m: [
+		0xf3b9cac2fc632551,
+		0xbce6faada7179e84,
+		0xffffffffffffffff,
+		0xffffffff00000000,
+	],
+	r2: [
+		0x83244c95be79eea2,
+		0x4699799c49bd6fa6,
+		0x2845b2392b6bec59,
+		0x66e12d94f3d95620,
+	],
+	m_inv: 0xccd1c8aaee00bc4f,
+};
+
+#[allow(clippy::unreadable_literal)]
+/// The curve constant b.
+pub(crate) const CURVE_B: Limbs = [
+	0x3bce3c3e27d2604b,
+	0x651d06b0cc53b0f6,
+	0xb3ebbd55769886bc,
+	0x5ac635d8aa3a93e7,
+];
+
+#[allow(clippy::unreadable_literal)]
+/// The x-coordinate of the base point.
+pub(crate) const GENERATOR_X: Limbs = [
+	0xf4a13945d898c296,
+	0x77037d812deb33a0,
+	0xf8bce6e563a440f2,
+	0x6b17d1f2e12c4247,
+];
+
+#[allow(clippy::unreadable_literal)]
+/// The y-coordinate of the base point.
+pub(crate) const GENERATOR_Y: Limbs = [
+	0xcbb6406837bf51f5,
+	0x2bce33576b315ece,
+	0x8ee7eb4a7c0f9e16,
+	0x4fe342e2fe1a7f9b,
+];
+
+#[inline]
+/// Add with carry.
+pub(crate) fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+	let ret = u128::from(a) + u128::from(b) + u128::from(carry);
+	(ret as u64, (ret >> 64) as u64)
+}
+
+#[inline]
+/// Subtract with borrow. The returned borrow is either 0 or 1.
+pub(crate) fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+	let ret = u128::from(a)
+		.wrapping_sub(u128::from(b))
+		.wrapping_sub(u128::from(borrow));
+	(ret as u64, (ret >> 127) as u64)
+}
+
+#[inline]
+/// Return `a` if `choice` is 0 and `b` if `choice` is 1, in constant time.
+pub(crate) fn select(a: &Limbs, b: &Limbs, choice: u64) -> Limbs {
+	let mask = 0u64.wrapping_sub(choice);
+	[
+		a[0] ^ (mask & (a[0] ^ b[0])),
+		a[1] ^ (mask & (a[1] ^ b[1])),
+		a[2] ^ (mask & (a[2] ^ b[2])),
+		a[3] ^ (mask & (a[3] ^ b[3])),
+	]
+}
+
+#[inline]
+/// Return 1 if `a` is zero and 0 otherwise, in constant time.
+pub(crate) fn is_zero(a: &Limbs) -> u64 {
+	let acc = a[0] | a[1] | a[2] | a[3];
+	// The top bit of `acc | -acc` is set if and only if `acc` is not zero.
+	1 ^ ((acc | acc.wrapping_neg()) >> 63)
+}
+
+#[inline]
+/// Subtract `m` from the 257-bit value `carry * 2^256 + a` if the value is
+/// not less than `m`. The value must be less than `2 * m`.
+pub(crate) fn reduce_once(a: &Limbs, carry: u64, md: &Modulus) -> Limbs {
+	let (r0, borrow) = sbb(a[0], md.m[0], 0);
+	let (r1, borrow) = sbb(a[1], md.m[1], borrow);
+	let (r2, borrow) = sbb(a[2], md.m[2], borrow);
+	let (r3, borrow) = sbb(a[3], md.m[3], borrow);
+	// Keep `a` only if there was no carry into bit 256 and the subtraction
+	// borrowed, meaning `a` < `m`.
+	let keep_a = (carry ^ 1) & borrow;
+
+	select(&[r0, r1, r2, r3], a, keep_a)
+}
+
+/// Addition modulo `md`. Both operands must be reduced.
+pub(crate) fn mod_add(a: &Limbs, b: &Limbs, md: &Modulus) -> Limbs {
+	let (s0, carry) = adc(a[0], b[0], 0);
+	let (s1, carry) = adc(a[1], b[1], carry);
+	let (s2, carry) = adc(a[2], b[2], carry);
+	let (s3, carry) = adc(a[3], b[3], carry);
+
+	reduce_once(&[s0, s1, s2, s3], carry, md)
+}
+
+/// Subtraction modulo `md`. Both operands must be reduced.
+pub(crate) fn mod_sub(a: &Limbs, b: &Limbs, md: &Modulus) -> Limbs {
+	let (d0, borrow) = sbb(a[0], b[0], 0);
+	let (d1, borrow) = sbb(a[1], b[1], borrow);
+	let (d2, borrow) = sbb(a[2], b[2], borrow);
+	let (d3, borrow) = sbb(a[3], b[3], borrow);
+	// Add `md` back if the subtraction borrowed.
+	let mask = 0u64.wrapping_sub(borrow);
+	let (r0, carry) = adc(d0, md.m[0] & mask, 0);
+	let (r1, carry) = adc(d1, md.m[1] & mask, carry);
+	let (r2, carry) = adc(d2, md.m[2] & mask, carry);
+	let (r3, _) = adc(d3, md.m[3] & mask, carry);
+
+	[r0, r1, r2, r3]
+}
+
+/// Montgomery multiplication (CIOS) returning `a * b * R^-1 mod md`.
+pub(crate) fn mont_mul(a: &Limbs, b: &Limbs, md: &Modulus) -> Limbs {
+	let mut t = [0u64; 6];
+
+	for b_i in b.iter() {
+		let mut carry = 0u64;
+		for j in 0..4 {
+			let uv = u128::from(t[j]) + u128::from(a[j]) * u128::from(*b_i) + u128::from(carry);
+			t[j] = uv as u64;
+			carry = (uv >> 64) as u64;
+		}
+		let (t4, t5) = adc(t[4], carry, 0);
+		t[4] = t4;
+		t[5] = t5;
+
+		let m = t[0].wrapping_mul(md.m_inv);
+		let uv = u128::from(t[0]) + u128::from(m) * u128::from(md.m[0]);
+		carry = (uv >> 64) as u64;
+		for j in 1..4 {
+			let uv = u128::from(t[j]) + u128::from(m) * u128::from(md.m[j]) + u128::from(carry);
+			t[j - 1] = uv as u64;
+			carry = (uv >> 64) as u64;
+		}
+		let (t3, c) = adc(t[4], carry, 0);
+		t[3] = t3;
+		t[4] = t[5] + c;
+	}
+
+	reduce_once(&[t[0], t[1], t[2], t[3]], t[4], md)
+}
+
+#[inline]
+/// Convert into the Montgomery domain.
+pub(crate) fn to_mont(a: &Limbs, md: &Modulus) -> Limbs {
+	mont_mul(a, &md.r2, md)
+}
+
+#[inline]
+/// Convert out of the Montgomery domain.
+pub(crate) fn from_mont(a: &Limbs, md: &Modulus) -> Limbs {
+	mont_mul(a, &[1, 0, 0, 0], md)
+}
+
+/// Compute the inverse of `a` (in the Montgomery domain) using Fermat's little
+/// theorem. The exponent `md - 2` is public, so this runs in constant time
+/// with regards to `a`.
+pub(crate) fn mont_invert(a: &Limbs, md: &Modulus) -> Limbs {
+	let (e0, borrow) = sbb(md.m[0], 2, 0);
+	let (e1, borrow) = sbb(md.m[1], 0, borrow);
+	let (e2, borrow) = sbb(md.m[2], 0, borrow);
+	let (e3, _) = sbb(md.m[3], 0, borrow);
+	let exponent = [e0, e1, e2, e3];
+
+	let mut result = to_mont(&[1, 0, 0, 0], md);
+	for bit in (0..256).rev() {
+		result = mont_mul(&result, &result, md);
+		if (exponent[bit / 64] >> (bit % 64)) & 1 == 1 {
+			result = mont_mul(&result, a, md);
+		}
+	}
+
+	result
+}
+
+/// Load 32 big-endian bytes into limbs, without any reduction.
+pub(crate) fn limbs_from_bytes(bytes: &[u8]) -> Limbs {
+	debug_assert_eq!(bytes.len(), ELEMENT_SIZE);
+	let mut limbs = [0u64; 4];
+	for (idx, limb) in limbs.iter_mut().enumerate() {
+		let mut word = [0u8; 8];
+		word.copy_from_slice(&bytes[ELEMENT_SIZE - 8 * (idx + 1)..ELEMENT_SIZE - 8 * idx]);
+		*limb = u64::from_be_bytes(word);
+	}
+
+	limbs
+}
+
+/// Store limbs as 32 big-endian bytes.
+pub(crate) fn limbs_to_bytes(limbs: &Limbs) -> [u8; ELEMENT_SIZE] {
+	let mut bytes = [0u8; ELEMENT_SIZE];
+	for (idx, limb) in limbs.iter().enumerate() {
+		bytes[ELEMENT_SIZE - 8 * (idx + 1)..ELEMENT_SIZE - 8 * idx]
+			.copy_from_slice(&limb.to_be_bytes());
+	}
+
+	bytes
+}
+
+/// Return 1 if `a` < `md` and 0 otherwise, in constant time.
+pub(crate) fn is_reduced(a: &Limbs, md: &Modulus) -> u64 {
+	let (_, borrow) = sbb(a[0], md.m[0], 0);
+	let (_, borrow) = sbb(a[1], md.m[1], borrow);
+	let (_, borrow) = sbb(a[2], md.m[2], borrow);
+	let (_, borrow) = sbb(a[3], md.m[3], borrow);
+
+	borrow
+}
+
+/// Load a scalar in the range `[1, n - 1]`.
+pub(crate) fn scalar_from_bytes(bytes: &[u8]) -> Result<Limbs, UnknownCryptoError> {
+	let scalar = limbs_from_bytes(bytes);
+	if (is_reduced(&scalar, &N) & (is_zero(&scalar) ^ 1)) != 1 {
+		return Err(UnknownCryptoError::InvalidKey);
+	}
+
+	Ok(scalar)
+}
+
+#[derive(Clone, Copy)]
+/// A point in projective coordinates `(X : Y : Z)`, with all coordinates in
+/// the Montgomery domain. The point at infinity is `(0 : 1 : 0)`.
+pub(crate) struct ProjectivePoint {
+	x: Limbs,
+	y: Limbs,
+	z: Limbs,
+}
+
+impl ProjectivePoint {
+	/// The point at infinity.
+	pub(crate) fn identity() -> Self {
+		Self {
+			x: [0u64; 4],
+			y: to_mont(&[1, 0, 0, 0], &P),
+			z: [0u64; 4],
+		}
+	}
+
+	/// The base point.
+	pub(crate) fn generator() -> Self {
+		Self::from_affine(&GENERATOR_X, &GENERATOR_Y)
+	}
+
+	/// A point from affine coordinates that are known to be on the curve.
+	pub(crate) fn from_affine(x: &Limbs, y: &Limbs) -> Self {
+		Self {
+			x: to_mont(x, &P),
+			y: to_mont(y, &P),
+			z: to_mont(&[1, 0, 0, 0], &P),
+		}
+	}
+
+	/// Decode and validate an uncompressed SEC1 point.
+	pub(crate) fn from_uncompressed(bytes: &[u8]) -> Result<Self, UnknownCryptoError> {
+		if bytes.len() != UNCOMPRESSED_POINT_SIZE || bytes[0] != 0x04 {
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+
+		let x = limbs_from_bytes(&bytes[1..33]);
+		let y = limbs_from_bytes(&bytes[33..65]);
+		if (is_reduced(&x, &P) & is_reduced(&y, &P)) != 1 {
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+
+		let x = to_mont(&x, &P);
+		let y = to_mont(&y, &P);
+		// y^2 = x^3 - 3x + b
+		let lhs = mont_mul(&y, &y, &P);
+		let x3 = mont_mul(&mont_mul(&x, &x, &P), &x, &P);
+		let three_x = mod_add(&mod_add(&x, &x, &P), &x, &P);
+		let rhs = mod_add(&mod_sub(&x3, &three_x, &P), &to_mont(&CURVE_B, &P), &P);
+		if is_zero(&mod_sub(&lhs, &rhs, &P)) != 1 {
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+
+		Ok(Self {
+			x,
+			y,
+			z: to_mont(&[1, 0, 0, 0], &P),
+		})
+	}
+
+	/// Return the affine coordinates, out of the Montgomery domain. Returns an
+	/// error if this is the point at infinity.
+	pub(crate) fn to_affine(&self) -> Result<(Limbs, Limbs), UnknownCryptoError> {
+		if is_zero(&self.z) == 1 {
+			return Err(UnknownCryptoError::InvalidKey);
+		}
+
+		let z_inv = mont_invert(&self.z, &P);
+		let x = from_mont(&mont_mul(&self.x, &z_inv, &P), &P);
+		let y = from_mont(&mont_mul(&self.y, &z_inv, &P), &P);
+
+		Ok((x, y))
+	}
+
+	/// Encode as an uncompressed SEC1 point.
+	pub(crate) fn to_uncompressed(
+		&self,
+	) -> Result<[u8; UNCOMPRESSED_POINT_SIZE], UnknownCryptoError> {
+		let (x, y) = self.to_affine()?;
+		let mut bytes = [0u8; UNCOMPRESSED_POINT_SIZE];
+		bytes[0] = 0x04;
+		bytes[1..33].copy_from_slice(&limbs_to_bytes(&x));
+		bytes[33..65].copy_from_slice(&limbs_to_bytes(&y));
+
+		Ok(bytes)
+	}
+
+	#[allow(clippy::many_single_char_names)]
+	/// Complete addition formula for prime order curves with a = -3
+	/// (Algorithm 4 of [Renes, Costello and Batina](https://eprint.iacr.org/2015/1060)).
+	/// Handles doubling and the point at infinity without branching.
+	pub(crate) fn add(&self, other: &Self) -> Self {
+		let (x1, y1, z1) = (&self.x, &self.y, &self.z);
+		let (x2, y2, z2) = (&other.x, &other.y, &other.z);
+		let b = to_mont(&CURVE_B, &P);
+		let mul = |a: &Limbs, b: &Limbs| mont_mul(a, b, &P);
+		let add = |a: &Limbs, b: &Limbs| mod_add(a, b, &P);
+		let sub = |a: &Limbs, b: &Limbs| mod_sub(a, b, &P);
+
+		let mut t0 = mul(x1, x2);
+		let mut t1 = mul(y1, y2);
+		let mut t2 = mul(z1, z2);
+		let mut t3 = add(x1, y1);
+		let mut t4 = add(x2, y2);
+		t3 = mul(&t3, &t4);
+		t4 = add(&t0, &t1);
+		t3 = sub(&t3, &t4);
+		t4 = add(y1, z1);
+		let mut x3 = add(y2, z2);
+		t4 = mul(&t4, &x3);
+		x3 = add(&t1, &t2);
+		t4 = sub(&t4, &x3);
+		x3 = add(x1, z1);
+		let mut y3 = add(x2, z2);
+		x3 = mul(&x3, &y3);
+		y3 = add(&t0, &t2);
+		y3 = sub(&x3, &y3);
+		let mut z3 = mul(&b, &t2);
+		x3 = sub(&y3, &z3);
+		z3 = add(&x3, &x3);
+		x3 = add(&x3, &z3);
+		z3 = sub(&t1, &x3);
+		x3 = add(&t1, &x3);
+		y3 = mul(&b, &y3);
+		t1 = add(&t2, &t2);
+		t2 = add(&t1, &t2);
+		y3 = sub(&y3, &t2);
+		y3 = sub(&y3, &t0);
+		t1 = add(&y3, &y3);
+		y3 = add(&t1, &y3);
+		t1 = add(&t0, &t0);
+		t0 = add(&t1, &t0);
+		t0 = sub(&t0, &t2);
+		t1 = mul(&t4, &y3);
+		t2 = mul(&t0, &y3);
+		y3 = mul(&x3, &z3);
+		y3 = add(&y3, &t2);
+		x3 = mul(&t3, &x3);
+		x3 = sub(&x3, &t1);
+		z3 = mul(&t4, &z3);
+		t1 = mul(&t3, &t0);
+		z3 = add(&z3, &t1);
+
+		Self {
+			x: x3,
+			y: y3,
+			z: z3,
+		}
+	}
+
+	/// The negation of this point.
+	pub(crate) fn neg(&self) -> Self {
+		Self {
+			x: self.x,
+			y: mod_sub(&[0u64; 4], &self.y, &P),
+			z: self.z,
+		}
+	}
+
+	/// Return `a` if `choice` is 0 and `b` if `choice` is 1, in constant time.
+	pub(crate) fn select(a: &Self, b: &Self, choice: u64) -> Self {
+		Self {
+			x: select(&a.x, &b.x, choice),
+			y: select(&a.y, &b.y, choice),
+			z: select(&a.z, &b.z, choice),
+		}
+	}
+
+	/// Scalar multiplication using a constant-time double-and-add ladder.
+	/// `scalar` is not in the Montgomery domain.
+	pub(crate) fn mul(&self, scalar: &Limbs) -> Self {
+		let mut acc = Self::identity();
+		for bit in (0..256).rev() {
+			acc = acc.add(&acc);
+			let sum = acc.add(self);
+			acc = Self::select(&acc, &sum, (scalar[bit / 64] >> (bit % 64)) & 1);
+		}
+
+		acc
+	}
+}
+
+#[cfg(test)]
+mod private {
+	use super::*;
+
+	mod test_arithmetic {
+		use super::*;
+
+		#[test]
+		fn test_mont_roundtrip() {
+			let a = [1, 2, 3, 4];
+			assert_eq!(from_mont(&to_mont(&a, &P), &P), a);
+			assert_eq!(from_mont(&to_mont(&a, &N), &N), a);
+		}
+
+		#[test]
+		fn test_mont_invert() {
+			let a = to_mont(&[5, 6, 7, 8], &N);
+			let one = from_mont(&mont_mul(&a, &mont_invert(&a, &N), &N), &N);
+			assert_eq!(one, [1, 0, 0, 0]);
+		}
+
+		#[test]
+		fn test_mod_sub_wraps() {
+			let zero = [0u64; 4];
+			let one = [1, 0, 0, 0];
+			let minus_one = mod_sub(&zero, &one, &P);
+			assert_eq!(mod_add(&minus_one, &one, &P), zero);
+		}
+
+		#[test]
+		fn test_generator_order() {
+			let mut n_minus_one = N.m;
+			n_minus_one[0] -= 1;
+			let g = ProjectivePoint::generator();
+			// (n - 1) * G + G is the point at infinity.
+			let sum = g.mul(&n_minus_one).add(&g);
+			assert!(sum.to_affine().is_err());
+			// (n - 1) * G is -G.
+			let (x, _) = g.mul(&n_minus_one).to_affine().unwrap();
+			assert_eq!(x, GENERATOR_X);
+		}
+
+		#[test]
+		fn test_neg() {
+			let g = ProjectivePoint::generator();
+			assert!(g.add(&g.neg()).to_affine().is_err());
+			assert!(ProjectivePoint::identity().neg().to_affine().is_err());
+		}
+
+		#[test]
+		fn test_doubling_matches_addition() {
+			let g = ProjectivePoint::generator();
+			let two_g = g.add(&g).to_affine().unwrap();
+			let also_two_g = g.mul(&[2, 0, 0, 0]).to_affine().unwrap();
+			assert_eq!(two_g, also_two_g);
+		}
+	}
+
+	mod test_scalar_from_bytes {
+		use super::*;
+
+		#[test]
+		fn test_bounds() {
+			assert!(scalar_from_bytes(&[0u8; 32]).is_err());
+			assert!(scalar_from_bytes(&limbs_to_bytes(&N.m)).is_err());
+			let mut n_minus_one = N.m;
+			n_minus_one[0] -= 1;
+			assert!(scalar_from_bytes(&limbs_to_bytes(&n_minus_one)).is_ok());
+			assert!(scalar_from_bytes(&limbs_to_bytes(&[1, 0, 0, 0])).is_ok());
+		}
+	}
+}
@@ -0,0 +1,958 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Parameters:
+//! - `private_key`: The private key used in key agreement.
+//! - `public_key`: The public key used in key agreement.
+//! - `seed`: The secret seed from which a `private_key` is derived.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - The `key_agreement()` operation results in an all-zero output.
+//! - A PKCS#8 or SPKI encoded key is not valid DER, or not an X448 key.
+//! - The public key in a PKCS#8 v2 encoded key does not belong to its
+//!   private key.
+//!
+//! # Security:
+//! - Multiple different `private_key`/`public_key` pairs can produce the same
+//!   shared key. Therefore, using the resulting `SharedKey`, directly from
+//!   `key_agreement()`, is not recommended. It should be passed to a KDF, such
+//!   as [HKDF], together with the public keys of both parties, first.
+//! - To securely generate a strong key, use [`PrivateKey::generate()`].
+//! - [`PrivateKey::from_seed()`] always derives the same private key from the
+//!   same seed. The seed must therefore be kept as secret as the private key,
+//!   and should come from a CSPRNG or a KDF.
+//! - [`PrivateKey::to_pkcs8_der()`] writes the private key, unprotected, to
+//!   `dst`. It is up to the caller to zeroize `dst` after use.
+//!
+//! # Recommendation:
+//! - It is recommended to use X448 only when a security level above 128 bits
+//!   is required.
+//!
+//! # Example:
+//! ```rust
+//! use orion::hazardous::ecc::x448::{self, PrivateKey, PublicKey};
+//! use core::convert::TryFrom;
+//!
+//! // Alice generates a private key and computes the corresponding public key
+//! let alice_sk = PrivateKey::generate();
+//! let alice_pk = PublicKey::try_from(&alice_sk)?;
+//!
+//! // Bob does the same
+//! let bob_sk = PrivateKey::generate();
+//! let bob_pk = PublicKey::try_from(&bob_sk)?;
+//!
+//! // They both compute a shared key using the others public key
+//! let alice_shared = x448::key_agreement(&alice_sk, &bob_pk)?;
+//! let bob_shared = x448::key_agreement(&bob_sk, &alice_pk)?;
+//!
+//! assert_eq!(alice_shared, bob_shared);
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [HKDF]: https://docs.rs/orion/latest/orion/hazardous/kdf/hkdf/index.html
+//! [`PrivateKey::generate()`]: https://docs.rs/orion/latest/orion/hazardous/ecc/x448/struct.PrivateKey.html
+//! [`PrivateKey::from_seed()`]: https://docs.rs/orion/latest/orion/hazardous/ecc/x448/struct.PrivateKey.html#method.from_seed
+//! [`PrivateKey::to_pkcs8_der()`]: https://docs.rs/orion/latest/orion/hazardous/ecc/x448/struct.PrivateKey.html#method.to_pkcs8_der
+
+use super::der;
+use crate::{errors::UnknownCryptoError, hazardous::kdf::hkdf};
+use core::convert::TryFrom;
+use zeroize::Zeroize;
+
+/// The size of a private key used in X448.
+pub const PRIVATE_KEY_SIZE: usize = 56;
+/// The size of a public key used in X448.
+pub const PUBLIC_KEY_SIZE: usize = 56;
+/// The size of the shared key that X448 produces.
+pub const SHARED_KEY_SIZE: usize = 56;
+/// The size of a seed that a private key can be derived from.
+pub const SEED_SIZE: usize = 32;
+/// The size of a private key encoded as PKCS#8 v1 DER.
+pub const PKCS8_V1_SIZE: usize = 72;
+/// The size of a private key and its public key encoded as PKCS#8 v2 DER.
+pub const PKCS8_V2_SIZE: usize = 132;
+/// The size of a public key encoded as SPKI DER.
+pub const SPKI_SIZE: usize = 68;
+/// The HKDF salt used when deriving a private key from a seed.
+const SEED_DOMAIN: &[u8] = b"orion-x448-private-key";
+/// The contents of the AlgorithmIdentifier of X448, the OID 1.3.101.111 without
+/// parameters, as specified in the RFC 8410.
+const ALGORITHM_ID: [u8; 5] = [0x06, 0x03, 0x2b, 0x65, 0x6f];
+/// A PKCS#8 v1 encoded private key, up to the private key itself.
+const PKCS8_V1_PREFIX: [u8; 16] = [
+	0x30, 0x46, 0x02, 0x01, 0x00, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x6f, 0x04, 0x3a, 0x04, 0x38,
+];
+/// A PKCS#8 v2 encoded private key, up to the private key itself.
+const PKCS8_V2_PREFIX: [u8; 17] = [
+	0x30, 0x81, 0x81, 0x02, 0x01, 0x01, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x6f, 0x04, 0x3a, 0x04,
+	0x38,
+];
+/// A PKCS#8 v2 encoded private key, between the private and the public key.
+const PKCS8_V2_PUBLIC_KEY_PREFIX: [u8; 3] = [0x81, 0x39, 0x00];
+/// An SPKI encoded public key, up to the public key itself.
+const SPKI_PREFIX: [u8; 12] = [
+	0x30, 0x42, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x6f, 0x03, 0x39, 0x00,
+];
+/// The amount of bits in a scalar.
+const SCALAR_BITS: usize = 448;
+/// The u-coordinate of the base point.
+const BASEPOINT: [u8; PUBLIC_KEY_SIZE] = [
+	5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+/// The constant (A - 2) / 4 for Curve448.
+const A24: u64 = 39081;
+/// Mask of a single 56-bit limb.
+const LIMB_MASK: u64 = (1 << 56) - 1;
+
+construct_secret_key! {
+	/// A type to represent the `PrivateKey` that X448 uses.
+	///
+	/// The private key is stored unclamped. Clamping is done when the key is used.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 56 bytes.
+	///
+	/// # Panics:
+	/// A panic will occur if:
+	/// - Failure to generate random bytes securely.
+	(PrivateKey, test_private_key, PRIVATE_KEY_SIZE, PRIVATE_KEY_SIZE, PRIVATE_KEY_SIZE)
+}
+
+impl_from_trait!(PrivateKey, PRIVATE_KEY_SIZE);
+
+impl PrivateKey {
+	#[must_use]
+	/// Deterministically derive a `PrivateKey` from `seed` using HKDF-HMAC-SHA512.
+	pub fn from_seed(seed: &Seed) -> Result<Self, UnknownCryptoError> {
+		let mut sk = [0u8; PRIVATE_KEY_SIZE];
+		hkdf::derive_key(SEED_DOMAIN, seed.unprotected_as_bytes(), None, &mut sk)?;
+		let private_key = PrivateKey::from(sk);
+		sk.zeroize();
+
+		Ok(private_key)
+	}
+
+	/// Encode the private key as PKCS#8 v1 DER (RFC 5208, RFC 8410) into
+	/// `dst`, which must be `PKCS8_V1_SIZE` bytes.
+	pub fn to_pkcs8_der(&self, dst: &mut [u8]) -> Result<(), UnknownCryptoError> {
+		if dst.len() != PKCS8_V1_SIZE {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+
+		let (prefix, private_key) = dst.split_at_mut(PKCS8_V1_PREFIX.len());
+		prefix.copy_from_slice(&PKCS8_V1_PREFIX);
+		private_key.copy_from_slice(self.unprotected_as_bytes());
+
+		Ok(())
+	}
+
+	/// Encode the private key and its public key as PKCS#8 v2 DER (RFC 5958,
+	/// RFC 8410) into `dst`, which must be `PKCS8_V2_SIZE` bytes.
+	pub fn to_pkcs8_v2_der(&self, dst: &mut [u8]) -> Result<(), UnknownCryptoError> {
+		if dst.len() != PKCS8_V2_SIZE {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+
+		let public_key = PublicKey::try_from(self)?;
+		let (prefix, rest) = dst.split_at_mut(PKCS8_V2_PREFIX.len());
+		let (private_key, rest) = rest.split_at_mut(PRIVATE_KEY_SIZE);
+		let (public_key_prefix, public_key_dst) =
+			rest.split_at_mut(PKCS8_V2_PUBLIC_KEY_PREFIX.len());
+		prefix.copy_from_slice(&PKCS8_V2_PREFIX);
+		private_key.copy_from_slice(self.unprotected_as_bytes());
+		public_key_prefix.copy_from_slice(&PKCS8_V2_PUBLIC_KEY_PREFIX);
+		public_key_dst.copy_from_slice(public_key.as_ref());
+
+		Ok(())
+	}
+
+	#[must_use]
+	/// Decode a private key encoded as PKCS#8 v1 or v2 DER. If a v2 encoded key
+	/// contains a public key, it must belong to the private key.
+	pub fn from_pkcs8_der(der: &[u8]) -> Result<Self, UnknownCryptoError> {
+		let mut key_info = der::outer_sequence(der)?;
+		let version = key_info.read_small_uint()?;
+		if version > 1 {
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+		read_algorithm_id(&mut key_info)?;
+
+		// The private key is an OCTET STRING, wrapped in another.
+		let mut private_key = der::Reader::new(key_info.read(der::TAG_OCTET_STRING)?);
+		let private_key_bytes = private_key.read(der::TAG_OCTET_STRING)?;
+		private_key.finish()?;
+		if private_key_bytes.len() != PRIVATE_KEY_SIZE {
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+		let private_key = PrivateKey::from_slice(private_key_bytes)?;
+
+		// Attributes are not used.
+		key_info.read_optional(der::tag_constructed(0))?;
+		if let Some(public_key) = key_info.read_optional(der::tag_primitive(1))? {
+			let public_key = der::Reader::bit_string_bytes(public_key)?;
+			if version != 1 || public_key.len() != PUBLIC_KEY_SIZE {
+				return Err(UnknownCryptoError::InvalidEncoding);
+			}
+			if PublicKey::try_from(&private_key)? != public_key {
+				return Err(UnknownCryptoError::InvalidKey);
+			}
+		}
+		key_info.finish()?;
+
+		Ok(private_key)
+	}
+}
+
+construct_secret_key! {
+	/// A type to represent the `Seed` that a `PrivateKey` can be derived from.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 32 bytes.
+	///
+	/// # Panics:
+	/// A panic will occur if:
+	/// - Failure to generate random bytes securely.
+	(Seed, test_seed, SEED_SIZE, SEED_SIZE, SEED_SIZE)
+}
+
+impl_from_trait!(Seed, SEED_SIZE);
+
+construct_public! {
+	/// A type to represent the `PublicKey` that X448 uses.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 56 bytes.
+	(PublicKey, test_public_key, PUBLIC_KEY_SIZE, PUBLIC_KEY_SIZE)
+}
+
+impl_from_trait!(PublicKey, PUBLIC_KEY_SIZE);
+
+impl PublicKey {
+	/// Encode the public key as SPKI DER (RFC 5280, RFC 8410).
+	pub fn to_spki_der(&self) -> [u8; SPKI_SIZE] {
+		let mut der = [0u8; SPKI_SIZE];
+		der[..SPKI_PREFIX.len()].copy_from_slice(&SPKI_PREFIX);
+		der[SPKI_PREFIX.len()..].copy_from_slice(self.as_ref());
+
+		der
+	}
+
+	#[must_use]
+	/// Decode a public key encoded as SPKI DER.
+	pub fn from_spki_der(der: &[u8]) -> Result<Self, UnknownCryptoError> {
+		let mut spki = der::outer_sequence(der)?;
+		read_algorithm_id(&mut spki)?;
+		let public_key = spki.read_bit_string()?;
+		spki.finish()?;
+		if public_key.len() != PUBLIC_KEY_SIZE {
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+
+		PublicKey::from_slice(public_key)
+	}
+}
+
+/// Read the AlgorithmIdentifier of a PKCS#8 or SPKI encoded key, which must be
+/// that of X448.
+fn read_algorithm_id(reader: &mut der::Reader) -> Result<(), UnknownCryptoError> {
+	if reader.read(der::TAG_SEQUENCE)? != ALGORITHM_ID {
+		return Err(UnknownCryptoError::InvalidEncoding);
+	}
+
+	Ok(())
+}
+
+impl TryFrom<&PrivateKey> for PublicKey {
+	type Error = UnknownCryptoError;
+
+	/// Compute the public key belonging to `private_key`.
+	fn try_from(private_key: &PrivateKey) -> Result<Self, Self::Error> {
+		let mut k = clamp_scalar(private_key.unprotected_as_bytes());
+		let public_key = PublicKey::from(mont_ladder(&k, &BASEPOINT));
+		k.zeroize();
+
+		Ok(public_key)
+	}
+}
+
+construct_secret_key! {
+	/// A type to represent the `SharedKey` that X448 produces.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 56 bytes.
+	///
+	/// # Panics:
+	/// A panic will occur if:
+	/// - Failure to generate random bytes securely.
+	(SharedKey, test_shared_key, SHARED_KEY_SIZE, SHARED_KEY_SIZE, SHARED_KEY_SIZE)
+}
+
+impl_from_trait!(SharedKey, SHARED_KEY_SIZE);
+
+#[derive(Clone, Copy)]
+/// An element of GF(2^448 - 2^224 - 1) represented by eight 56-bit limbs.
+struct FieldElement([u64; 8]);
+
+impl Zeroize for FieldElement {
+	fn zeroize(&mut self) {
+		self.0.zeroize();
+	}
+}
+
+impl FieldElement {
+	#[inline]
+	/// The additive identity.
+	const fn zero() -> Self {
+		Self([0u64; 8])
+	}
+
+	#[inline]
+	/// The multiplicative identity.
+	const fn one() -> Self {
+		Self([1, 0, 0, 0, 0, 0, 0, 0])
+	}
+
+	/// Load a field element from 56 little-endian bytes. Non-canonical values
+	/// are accepted and reduced by the arithmetic, as required by RFC 7748.
+	fn from_bytes(bytes: &[u8; 56]) -> Self {
+		let mut limbs = [0u64; 8];
+		for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(7)) {
+			for (idx, byte) in chunk.iter().enumerate() {
+				*limb |= u64::from(*byte) << (8 * idx);
+			}
+		}
+
+		Self(limbs)
+	}
+
+	/// Store the fully reduced field element as 56 little-endian bytes.
+	fn to_bytes(&self) -> [u8; 56] {
+		let mut limbs = self.carry().carry().carry().0;
+
+		// `limbs` now represents a value below 2^448 < 2p, so at most
+		// one subtraction of p is required. This is done in constant time.
+		let p: [u64; 8] = [
+			LIMB_MASK,
+			LIMB_MASK,
+			LIMB_MASK,
+			LIMB_MASK,
+			LIMB_MASK - 1,
+			LIMB_MASK,
+			LIMB_MASK,
+			LIMB_MASK,
+		];
+		let mut reduced = [0u64; 8];
+		let mut borrow = 0u64;
+		for idx in 0..8 {
+			let diff = limbs[idx].wrapping_sub(p[idx]).wrapping_sub(borrow);
+			reduced[idx] = diff & LIMB_MASK;
+			borrow = diff >> 63;
+		}
+		// If there was no borrow, limbs >= p and the reduced value is selected.
+		let select_reduced = borrow.wrapping_sub(1);
+		for idx in 0..8 {
+			limbs[idx] = (reduced[idx] & select_reduced) | (limbs[idx] & !select_reduced);
+		}
+
+		let mut bytes = [0u8; 56];
+		for (chunk, limb) in bytes.chunks_exact_mut(7).zip(limbs.iter()) {
+			for (idx, byte) in chunk.iter_mut().enumerate() {
+				*byte = (limb >> (8 * idx)) as u8;
+			}
+		}
+
+		bytes
+	}
+
+	#[inline]
+	/// Propagate carries so that all limbs fit in 56 bits (with limbs 0 and 4
+	/// possibly holding a small excess from the final fold).
+	fn carry(&self) -> Self {
+		let mut limbs = self.0;
+		for idx in 0..7 {
+			limbs[idx + 1] += limbs[idx] >> 56;
+			limbs[idx] &= LIMB_MASK;
+		}
+		// 2^448 = 2^224 + 1 (mod p)
+		let top = limbs[7] >> 56;
+		limbs[7] &= LIMB_MASK;
+		limbs[0] += top;
+		limbs[4] += top;
+
+		Self(limbs)
+	}
+
+	#[inline]
+	fn add(&self, other: &Self) -> Self {
+		let mut limbs = [0u64; 8];
+		for (idx, limb) in limbs.iter_mut().enumerate() {
+			*limb = self.0[idx] + other.0[idx];
+		}
+
+		Self(limbs).carry()
+	}
+
+	#[inline]
+	fn sub(&self, other: &Self) -> Self {
+		// Add 2p before subtracting so that no limb underflows.
+		let two_p: [u64; 8] = [
+			2 * LIMB_MASK,
+			2 * LIMB_MASK,
+			2 * LIMB_MASK,
+			2 * LIMB_MASK,
+			2 * LIMB_MASK - 2,
+			2 * LIMB_MASK,
+			2 * LIMB_MASK,
+			2 * LIMB_MASK,
+		];
+		let other = other.carry();
+		let mut limbs = [0u64; 8];
+		for (idx, limb) in limbs.iter_mut().enumerate() {
+			*limb = (self.0[idx] + two_p[idx]) - other.0[idx];
+		}
+
+		Self(limbs).carry()
+	}
+
+	fn mul(&self, other: &Self) -> Self {
+		let mut z = [0u128; 15];
+		for i in 0..8 {
+			for j in 0..8 {
+				z[i + j] += u128::from(self.0[i]) * u128::from(other.0[j]);
+			}
+		}
+		// Fold the upper half using 2^448 = 2^224 + 1 (mod p). Going from
+		// the top, so that limbs folded into the upper half are folded again.
+		for idx in (8..15).rev() {
+			z[idx - 4] += z[idx];
+			z[idx - 8] += z[idx];
+		}
+
+		let mut carry = 0u128;
+		for limb in z.iter_mut().take(8) {
+			*limb += carry;
+			carry = *limb >> 56;
+			*limb &= u128::from(LIMB_MASK);
+		}
+		z[0] += carry;
+		z[4] += carry;
+
+		carry = 0;
+		let mut limbs = [0u64; 8];
+		for idx in 0..8 {
+			z[idx] += carry;
+			carry = z[idx] >> 56;
+			limbs[idx] = (z[idx] as u64) & LIMB_MASK;
+		}
+		limbs[0] += carry as u64;
+		limbs[4] += carry as u64;
+
+		Self(limbs)
+	}
+
+	#[inline]
+	fn square(&self) -> Self {
+		self.mul(self)
+	}
+
+	#[inline]
+	fn mul_small(&self, small: u64) -> Self {
+		self.mul(&Self([small, 0, 0, 0, 0, 0, 0, 0]))
+	}
+
+	/// Compute the multiplicative inverse using Fermat's little theorem. The
+	/// exponent p - 2 is public, so this runs in constant time.
+	fn invert(&self) -> Self {
+		// p - 2 = 2^448 - 2^224 - 3, as little-endian bits.
+		let mut result = Self::one();
+		for bit in (0..SCALAR_BITS).rev() {
+			result = result.square();
+			let is_set = match bit {
+				0 => true,
+				1 => false,
+				224 => false,
+				_ => true,
+			};
+			if is_set {
+				result = result.mul(self);
+			}
+		}
+
+		result
+	}
+
+	#[inline]
+	/// Swap `a` and `b` if `swap` is 1, in constant time.
+	fn conditional_swap(a: &mut Self, b: &mut Self, swap: u64) {
+		let mask = 0u64.wrapping_sub(swap);
+		for idx in 0..8 {
+			let tmp = mask & (a.0[idx] ^ b.0[idx]);
+			a.0[idx] ^= tmp;
+			b.0[idx] ^= tmp;
+		}
+	}
+}
+
+/// Clamp a scalar as specified in RFC 7748.
+fn clamp_scalar(scalar: &[u8]) -> [u8; PRIVATE_KEY_SIZE] {
+	debug_assert_eq!(scalar.len(), PRIVATE_KEY_SIZE);
+	let mut k = [0u8; PRIVATE_KEY_SIZE];
+	k.copy_from_slice(scalar);
+	k[0] &= 252;
+	k[55] |= 128;
+
+	k
+}
+
+/// The Montgomery ladder computing the u-coordinate of `k * u`, as specified
+/// in RFC 7748. `k` must already be clamped.
+fn mont_ladder(k: &[u8; PRIVATE_KEY_SIZE], u: &[u8; PUBLIC_KEY_SIZE]) -> [u8; SHARED_KEY_SIZE] {
+	let x_1 = FieldElement::from_bytes(u);
+	let mut x_2 = FieldElement::one();
+	let mut z_2 = FieldElement::zero();
+	let mut x_3 = x_1;
+	let mut z_3 = FieldElement::one();
+	let mut swap = 0u64;
+
+	for t in (0..SCALAR_BITS).rev() {
+		let k_t = u64::from((k[t >> 3] >> (t & 7)) & 1);
+		swap ^= k_t;
+		FieldElement::conditional_swap(&mut x_2, &mut x_3, swap);
+		FieldElement::conditional_swap(&mut z_2, &mut z_3, swap);
+		swap = k_t;
+
+		let a = x_2.add(&z_2);
+		let aa = a.square();
+		let b = x_2.sub(&z_2);
+		let bb = b.square();
+		let e = aa.sub(&bb);
+		let c = x_3.add(&z_3);
+		let d = x_3.sub(&z_3);
+		let da = d.mul(&a);
+		let cb = c.mul(&b);
+		x_3 = da.add(&cb).square();
+		z_3 = x_1.mul(&da.sub(&cb).square());
+		x_2 = aa.mul(&bb);
+		z_2 = e.mul(&aa.add(&e.mul_small(A24)));
+	}
+
+	FieldElement::conditional_swap(&mut x_2, &mut x_3, swap);
+	FieldElement::conditional_swap(&mut z_2, &mut z_3, swap);
+
+	let result = x_2.mul(&z_2.invert()).to_bytes();
+
+	x_2.zeroize();
+	z_2.zeroize();
+	x_3.zeroize();
+	z_3.zeroize();
+
+	result
+}
+
+#[must_use]
+/// X448 (Diffie-Hellman with Montgomery form of Curve448) key agreement.
+pub fn key_agreement(
+	private_key: &PrivateKey,
+	public_key: &PublicKey,
+) -> Result<SharedKey, UnknownCryptoError> {
+	let mut u = [0u8; PUBLIC_KEY_SIZE];
+	u.copy_from_slice(public_key.as_ref());
+	let mut k = clamp_scalar(private_key.unprotected_as_bytes());
+	let shared_key = SharedKey::from(mont_ladder(&k, &u));
+	k.zeroize();
+
+	// A low-order public key results in an all-zero shared key.
+	if shared_key == [0u8; SHARED_KEY_SIZE].as_ref() {
+		return Err(UnknownCryptoError::InvalidKey);
+	}
+
+	Ok(shared_key)
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	mod test_from_seed {
+		use super::*;
+
+		#[test]
+		fn test_from_seed_deterministic() {
+			let seed = Seed::from([1u8; SEED_SIZE]);
+			let sk = PrivateKey::from_seed(&seed).unwrap();
+			assert!(sk == PrivateKey::from_seed(&seed).unwrap());
+			assert!(
+				PublicKey::try_from(&sk).unwrap()
+					== PublicKey::try_from(&PrivateKey::from_seed(&seed).unwrap()).unwrap()
+			);
+		}
+
+		#[test]
+		fn test_from_seed_is_hkdf() {
+			let seed = Seed::from([2u8; SEED_SIZE]);
+			let mut expected = [0u8; PRIVATE_KEY_SIZE];
+			hkdf::derive_key(SEED_DOMAIN, &[2u8; SEED_SIZE], None, &mut expected).unwrap();
+			assert!(PrivateKey::from_seed(&seed).unwrap() == expected.as_ref());
+		}
+
+		#[test]
+		fn test_from_seed_diff_seed() {
+			let sk = PrivateKey::from_seed(&Seed::from([0u8; SEED_SIZE])).unwrap();
+			let mut other = [0u8; SEED_SIZE];
+			other[SEED_SIZE - 1] = 1;
+			assert!(sk != PrivateKey::from_seed(&Seed::from(other)).unwrap());
+		}
+
+		#[test]
+		fn test_from_seed_key_agreement() {
+			let alice_sk = PrivateKey::from_seed(&Seed::from([3u8; SEED_SIZE])).unwrap();
+			let bob_sk = PrivateKey::from_seed(&Seed::from([4u8; SEED_SIZE])).unwrap();
+			let alice_pk = PublicKey::try_from(&alice_sk).unwrap();
+			let bob_pk = PublicKey::try_from(&bob_sk).unwrap();
+
+			assert!(
+				key_agreement(&alice_sk, &bob_pk).unwrap()
+					== key_agreement(&bob_sk, &alice_pk).unwrap()
+			);
+		}
+	}
+
+	mod test_der {
+		use super::*;
+		use crate::util::hex;
+
+		// Encoded with OpenSSL from the private key 0x00, 0x01, .., 0x37.
+		const PKCS8_V1: &str = "3046020100300506032b656f043a0438000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f3031323334353637";
+		const SPKI: &str = "3042300506032b656f0339003c6fd1d02960e0d9e93308fc65736141c30db307977f81b7b10996e51e53f573e5c86621205ff491209d3b7cd7933428177ba4defae14dc1";
+		// Accepted by OpenSSL.
+		const PKCS8_V2: &str = "308181020101300506032b656f043a0438000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f30313233343536378139003c6fd1d02960e0d9e93308fc65736141c30db307977f81b7b10996e51e53f573e5c86621205ff491209d3b7cd7933428177ba4defae14dc1";
+
+		fn private_key() -> PrivateKey {
+			let mut sk = [0u8; PRIVATE_KEY_SIZE];
+			for (idx, byte) in sk.iter_mut().enumerate() {
+				*byte = idx as u8;
+			}
+
+			PrivateKey::from(sk)
+		}
+
+		fn decode<'a>(src: &str, dst: &'a mut [u8]) -> &'a [u8] {
+			let len = src.len() / 2;
+			hex::decode_to_slice(src, &mut dst[..len]).unwrap();
+			&dst[..len]
+		}
+
+		#[test]
+		fn test_pkcs8_v1_openssl() {
+			let mut expected = [0u8; PKCS8_V1_SIZE];
+			decode(PKCS8_V1, &mut expected);
+			let mut der = [0u8; PKCS8_V1_SIZE];
+			private_key().to_pkcs8_der(&mut der).unwrap();
+			assert_eq!(der, expected);
+			assert!(PrivateKey::from_pkcs8_der(&der).unwrap() == private_key());
+		}
+
+		#[test]
+		fn test_pkcs8_v2_openssl() {
+			let mut expected = [0u8; PKCS8_V2_SIZE];
+			decode(PKCS8_V2, &mut expected);
+			let mut der = [0u8; PKCS8_V2_SIZE];
+			private_key().to_pkcs8_v2_der(&mut der).unwrap();
+			assert_eq!(der[..], expected[..]);
+			assert!(PrivateKey::from_pkcs8_der(&der).unwrap() == private_key());
+		}
+
+		#[test]
+		fn test_spki_openssl() {
+			let mut expected = [0u8; SPKI_SIZE];
+			decode(SPKI, &mut expected);
+			let pk = PublicKey::try_from(&private_key()).unwrap();
+			assert_eq!(pk.to_spki_der()[..], expected[..]);
+			assert_eq!(PublicKey::from_spki_der(&expected).unwrap(), pk);
+		}
+
+		#[test]
+		fn test_pkcs8_bad_dst_len_err() {
+			let sk = private_key();
+			assert!(sk.to_pkcs8_der(&mut [0u8; PKCS8_V1_SIZE - 1]).is_err());
+			assert!(sk.to_pkcs8_der(&mut [0u8; PKCS8_V1_SIZE + 1]).is_err());
+			assert!(sk.to_pkcs8_v2_der(&mut [0u8; PKCS8_V1_SIZE]).is_err());
+			assert!(sk.to_pkcs8_v2_der(&mut [0u8; PKCS8_V2_SIZE + 1]).is_err());
+		}
+
+		#[test]
+		fn test_pkcs8_v2_wrong_public_key_err() {
+			let mut der = [0u8; PKCS8_V2_SIZE];
+			decode(PKCS8_V2, &mut der);
+			der[PKCS8_V2_SIZE - 1] ^= 1;
+			assert_eq!(
+				PrivateKey::from_pkcs8_der(&der).unwrap_err(),
+				UnknownCryptoError::InvalidKey
+			);
+		}
+
+		#[test]
+		fn test_pkcs8_v1_with_public_key_err() {
+			let mut der = [0u8; PKCS8_V2_SIZE];
+			decode(PKCS8_V2, &mut der);
+			der[5] = 0x00;
+			assert_eq!(
+				PrivateKey::from_pkcs8_der(&der).unwrap_err(),
+				UnknownCryptoError::InvalidEncoding
+			);
+		}
+
+		#[test]
+		fn test_pkcs8_attributes_ignored() {
+			let mut der = [0u8; PKCS8_V1_SIZE + 2];
+			decode(PKCS8_V1, &mut der);
+			der[1] += 2;
+			der[PKCS8_V1_SIZE] = 0xa0;
+			assert!(PrivateKey::from_pkcs8_der(&der).unwrap() == private_key());
+		}
+
+		#[test]
+		fn test_pkcs8_bad_version_err() {
+			let mut der = [0u8; PKCS8_V1_SIZE];
+			decode(PKCS8_V1, &mut der);
+			der[4] = 0x02;
+			assert!(PrivateKey::from_pkcs8_der(&der).is_err());
+		}
+
+		#[test]
+		fn test_wrong_algorithm_err() {
+			// The OID of X25519, 1.3.101.110.
+			let mut der = [0u8; PKCS8_V1_SIZE];
+			decode(PKCS8_V1, &mut der);
+			der[11] = 0x6e;
+			assert!(PrivateKey::from_pkcs8_der(&der).is_err());
+
+			let mut der = [0u8; SPKI_SIZE];
+			decode(SPKI, &mut der);
+			der[8] = 0x6e;
+			assert!(PublicKey::from_spki_der(&der).is_err());
+		}
+
+		#[test]
+		fn test_truncated_or_trailing_err() {
+			let mut der = [0u8; PKCS8_V2_SIZE + 1];
+			decode(PKCS8_V2, &mut der);
+			assert!(PrivateKey::from_pkcs8_der(&der).is_err());
+			for len in 0..PKCS8_V2_SIZE {
+				assert!(PrivateKey::from_pkcs8_der(&der[..len]).is_err());
+			}
+
+			let mut der = [0u8; SPKI_SIZE + 1];
+			decode(SPKI, &mut der);
+			assert!(PublicKey::from_spki_der(&der).is_err());
+			for len in 0..SPKI_SIZE {
+				assert!(PublicKey::from_spki_der(&der[..len]).is_err());
+			}
+		}
+
+		#[test]
+		fn test_pkcs8_is_not_spki() {
+			let mut der = [0u8; PKCS8_V1_SIZE];
+			decode(PKCS8_V1, &mut der);
+			assert!(PublicKey::from_spki_der(&der).is_err());
+
+			let mut der = [0u8; SPKI_SIZE];
+			decode(SPKI, &mut der);
+			assert!(PrivateKey::from_pkcs8_der(&der).is_err());
+		}
+
+		// Proptests. Only exectued when NOT testing no_std.
+		#[cfg(feature = "safe_api")]
+		mod proptest {
+			use super::*;
+
+			quickcheck! {
+				/// Decoding an encoded key should always give the same key.
+				fn prop_der_roundtrip(seed: Vec<u8>) -> bool {
+					let mut sk = [0u8; PRIVATE_KEY_SIZE];
+					for (dst, src) in sk.iter_mut().zip(seed.iter()) {
+						*dst = *src;
+					}
+					let sk = PrivateKey::from(sk);
+					let pk = PublicKey::try_from(&sk).unwrap();
+
+					let mut v1 = [0u8; PKCS8_V1_SIZE];
+					sk.to_pkcs8_der(&mut v1).unwrap();
+					let mut v2 = [0u8; PKCS8_V2_SIZE];
+					sk.to_pkcs8_v2_der(&mut v2).unwrap();
+
+					PrivateKey::from_pkcs8_der(&v1).unwrap() == sk
+						&& PrivateKey::from_pkcs8_der(&v2).unwrap() == sk
+						&& PublicKey::from_spki_der(&pk.to_spki_der()).unwrap() == pk
+				}
+			}
+		}
+	}
+
+	mod test_key_agreement {
+		use super::*;
+
+		#[test]
+		fn test_zero_private_key_ok() {
+			// The all-zero private key is clamped to 2^447.
+			let sk = PrivateKey::from_slice(&[0u8; PRIVATE_KEY_SIZE]).unwrap();
+			let pk = PublicKey::try_from(&sk).unwrap();
+			assert!(pk != [0u8; PUBLIC_KEY_SIZE].as_ref());
+		}
+
+		#[test]
+		fn test_low_order_point_err() {
+			let sk = PrivateKey::from_slice(&[1u8; PRIVATE_KEY_SIZE]).unwrap();
+			// u = 0
+			let pk = PublicKey::from_slice(&[0u8; PUBLIC_KEY_SIZE]).unwrap();
+			assert!(key_agreement(&sk, &pk).is_err());
+			// u = 1
+			let mut one = [0u8; PUBLIC_KEY_SIZE];
+			one[0] = 1;
+			let pk = PublicKey::from(one);
+			assert!(key_agreement(&sk, &pk).is_err());
+		}
+
+		#[test]
+		fn test_non_canonical_public_key() {
+			// p + 5 should be treated the same as 5, the base point.
+			let mut p_plus_five = [0xffu8; PUBLIC_KEY_SIZE];
+			p_plus_five[0] = 0x04;
+			for byte in p_plus_five[1..28].iter_mut() {
+				*byte = 0x00;
+			}
+			let sk = PrivateKey::from_slice(&[7u8; PRIVATE_KEY_SIZE]).unwrap();
+			let expected = PublicKey::try_from(&sk).unwrap();
+			let actual = key_agreement(&sk, &PublicKey::from(p_plus_five)).unwrap();
+			assert!(actual == expected.as_ref());
+		}
+
+		#[test]
+		#[cfg(feature = "safe_api")]
+		fn test_key_agreement_both_parties() {
+			let alice_sk = PrivateKey::generate();
+			let alice_pk = PublicKey::try_from(&alice_sk).unwrap();
+			let bob_sk = PrivateKey::generate();
+			let bob_pk = PublicKey::try_from(&bob_sk).unwrap();
+
+			assert_eq!(
+				key_agreement(&alice_sk, &bob_pk).unwrap(),
+				key_agreement(&bob_sk, &alice_pk).unwrap()
+			);
+		}
+
+		// Proptests. Only exectued when NOT testing no_std.
+		#[cfg(feature = "safe_api")]
+		mod proptest {
+			use super::*;
+
+			quickcheck! {
+				/// Two parties should always arrive at the same shared key.
+				fn prop_key_agreement_same_shared(seed_a: Vec<u8>, seed_b: Vec<u8>) -> bool {
+					let mut a = [1u8; PRIVATE_KEY_SIZE];
+					let mut b = [2u8; PRIVATE_KEY_SIZE];
+					for (dst, src) in a.iter_mut().zip(seed_a.iter()) {
+						*dst ^= *src;
+					}
+					for (dst, src) in b.iter_mut().zip(seed_b.iter()) {
+						*dst ^= *src;
+					}
+
+					let sk_a = PrivateKey::from(a);
+					let sk_b = PrivateKey::from(b);
+					let pk_a = PublicKey::try_from(&sk_a).unwrap();
+					let pk_b = PublicKey::try_from(&sk_b).unwrap();
+
+					key_agreement(&sk_a, &pk_b).unwrap() == key_agreement(&sk_b, &pk_a).unwrap()
+				}
+			}
+		}
+	}
+}
+
+// Testing private functions in the module.
+#[cfg(test)]
+mod private {
+	use super::*;
+
+	mod test_field_element {
+		use super::*;
+
+		#[test]
+		fn test_bytes_roundtrip() {
+			let mut bytes = [0u8; 56];
+			for (idx, byte) in bytes.iter_mut().enumerate() {
+				*byte = idx as u8;
+			}
+			assert_eq!(FieldElement::from_bytes(&bytes).to_bytes()[..], bytes[..]);
+		}
+
+		#[test]
+		fn test_p_reduces_to_zero() {
+			let mut p = [0xffu8; 56];
+			p[28] = 0xfe;
+			assert_eq!(FieldElement::from_bytes(&p).to_bytes()[..], [0u8; 56][..]);
+		}
+
+		#[test]
+		fn test_invert() {
+			let mut bytes = [0u8; 56];
+			bytes[0] = 9;
+			bytes[30] = 0xab;
+			let fe = FieldElement::from_bytes(&bytes);
+			let mut one = [0u8; 56];
+			one[0] = 1;
+			assert_eq!(fe.mul(&fe.invert()).to_bytes()[..], one[..]);
+		}
+
+		#[test]
+		fn test_sub_add_inverse() {
+			let mut a_bytes = [0u8; 56];
+			a_bytes[3] = 7;
+			let mut b_bytes = [0xffu8; 56];
+			b_bytes[28] = 0x00;
+			let a = FieldElement::from_bytes(&a_bytes);
+			let b = FieldElement::from_bytes(&b_bytes);
+			assert_eq!(a.sub(&b).add(&b).to_bytes()[..], a.to_bytes()[..]);
+		}
+	}
+
+	mod test_clamp_scalar {
+		use super::*;
+
+		#[test]
+		fn test_clamp() {
+			let k = clamp_scalar(&[0xffu8; PRIVATE_KEY_SIZE]);
+			assert_eq!(k[0], 252);
+			assert_eq!(k[55], 0xff);
+			let k = clamp_scalar(&[0u8; PRIVATE_KEY_SIZE]);
+			assert_eq!(k[0], 0);
+			assert_eq!(k[55], 128);
+		}
+	}
+}
@@ -37,9 +37,6 @@
 //!   initialized with `None`.
 //! - [`reset()`] is called with `None` as `secret_key` but the struct was
 //!   initialized with `Some(secret_key)`.
-//!
-//! # Panics:
-//! A panic will occur if:
 //! - More than 2*(2^64-1) bytes of data are hashed.
 //!
 //! # Security:
@@ -218,15 +215,20 @@ impl core::fmt::Debug for Blake2b {
 }
 
 impl Blake2b {
+	#[must_use]
 	#[inline(always)]
-	/// Increment the internal states offset value `t`.
-	fn increment_offset(&mut self, value: u64) {
+	/// Increment the internal states offset value `t`. An error is returned if
+	/// the size limit is reached.
+	fn increment_offset(&mut self, value: u64) -> Result<(), UnknownCryptoError> {
 		let (res, was_overflow) = self.t[0].overflowing_add(value);
-		self.t[0] = res;
 		if was_overflow {
-			// If this panics size limit is reached.
-			self.t[1] = self.t[1].checked_add(1).unwrap();
+			self.t[1] = self.t[1]
+				.checked_add(1)
+				.ok_or(UnknownCryptoError::SizeLimitExceeded)?;
 		}
+		self.t[0] = res;
+
+		Ok(())
 	}
 
 	#[inline(always)]
@@ -318,11 +320,11 @@ impl Blake2b {
 	/// Reset to `init()` state.
 	pub fn reset(&mut self, secret_key: Option<&SecretKey>) -> Result<(), UnknownCryptoError> {
 		if secret_key.is_some() && (!self.is_keyed) {
-			return Err(UnknownCryptoError);
+			return Err(UnknownCryptoError::ParameterOutOfRange);
 		}
 
 		if secret_key.is_none() && self.is_keyed {
-			return Err(UnknownCryptoError);
+			return Err(UnknownCryptoError::ParameterOutOfRange);
 		}
 
 		self.internal_state.copy_from_slice(&self.init_state);
@@ -348,7 +350,7 @@ impl Blake2b {
 	/// Update state with a `data`. This can be called multiple times.
 	pub fn update(&mut self, data: &[u8]) -> Result<(), UnknownCryptoError> {
 		if self.is_finalized {
-			return Err(UnknownCryptoError);
+			return Err(UnknownCryptoError::FinalizedState);
 		}
 		if data.is_empty() {
 			return Ok(());
@@ -369,7 +371,7 @@ impl Blake2b {
 
 			self.buffer[self.leftover..(self.leftover + fill)].copy_from_slice(&bytes[..fill]);
 			// Process data
-			self.increment_offset(BLAKE2B_BLOCKSIZE as u64);
+			self.increment_offset(BLAKE2B_BLOCKSIZE as u64)?;
 			self.compress_f(None);
 			self.leftover = 0;
 			// Reduce by slice
@@ -378,7 +380,7 @@ impl Blake2b {
 
 		while bytes.len() > BLAKE2B_BLOCKSIZE {
 			// Process data
-			self.increment_offset(BLAKE2B_BLOCKSIZE as u64);
+			self.increment_offset(BLAKE2B_BLOCKSIZE as u64)?;
 			self.compress_f(Some(bytes[..BLAKE2B_BLOCKSIZE].as_ref()));
 			// Reduce by slice
 			bytes = &bytes[BLAKE2B_BLOCKSIZE..];
@@ -398,13 +400,13 @@ impl Blake2b {
 	/// Return a BLAKE2b digest.
 	pub fn finalize(&mut self) -> Result<Digest, UnknownCryptoError> {
 		if self.is_finalized {
-			return Err(UnknownCryptoError);
+			return Err(UnknownCryptoError::FinalizedState);
 		}
 
 		self.is_finalized = true;
 
 		let in_buffer_len = self.leftover;
-		self.increment_offset(in_buffer_len as u64);
+		self.increment_offset(in_buffer_len as u64)?;
 		// Mark that it is the last block of data to be processed
 		self.f[0] = !0;
 
@@ -418,15 +420,47 @@ impl Blake2b {
 
 		Digest::from_slice(&digest[..self.size])
 	}
+
+	#[must_use]
+	/// Return a BLAKE2b digest and reset to `init()` state, so that the same
+	/// state can be used for the next message without cloning or re-initializing it.
+	/// `secret_key` is used to reset the state, as with `reset()`.
+	pub fn finalize_reset(
+		&mut self,
+		secret_key: Option<&SecretKey>,
+	) -> Result<Digest, UnknownCryptoError> {
+		// Check this before finalizing, so that the digest is not lost on error.
+		if secret_key.is_some() != self.is_keyed {
+			return Err(UnknownCryptoError::ParameterOutOfRange);
+		}
+
+		let digest = self.finalize()?;
+		self.reset(secret_key)?;
+
+		Ok(digest)
+	}
 }
 
 #[must_use]
 #[allow(clippy::unreadable_literal)]
 /// Initialize a `Blake2b` struct with a given size and an optional key.
 pub fn init(secret_key: Option<&SecretKey>, size: usize) -> Result<Blake2b, UnknownCryptoError> {
+	init_with_parameters(secret_key, size, &[0x01010000, 0, 0, 0, 0, 0, 0, 0])
+}
+
+#[must_use]
+/// Initialize a `Blake2b` struct with a given size, an optional key and the
+/// parameter block `parameters`. The digest length and key length of
+/// `parameters` must be zero, as they are set from `size` and `secret_key`.
+pub(crate) fn init_with_parameters(
+	secret_key: Option<&SecretKey>,
+	size: usize,
+	parameters: &[u64; 8],
+) -> Result<Blake2b, UnknownCryptoError> {
 	if size < 1 || size > BLAKE2B_OUTSIZE {
-		return Err(UnknownCryptoError);
+		return Err(UnknownCryptoError::InvalidLength);
 	}
+	debug_assert!(parameters[0] & 0xffff == 0);
 
 	let mut context = Blake2b {
 		init_state: [0u64; 8],
@@ -439,12 +473,15 @@ pub fn init(secret_key: Option<&SecretKey>, size: usize) -> Result<Blake2b, Unkn
 		is_keyed: false,
 		size,
 	};
+	for (word, parameter) in context.internal_state.iter_mut().zip(parameters.iter()) {
+		*word ^= parameter;
+	}
 
 	match secret_key {
 		Some(sk) => {
 			context.is_keyed = true;
 			let klen = sk.get_length();
-			context.internal_state[0] ^= 0x01010000 ^ ((klen as u64) << 8) ^ (size as u64);
+			context.internal_state[0] ^= ((klen as u64) << 8) ^ (size as u64);
 			context.init_state.copy_from_slice(&context.internal_state);
 			context.update(sk.unprotected_as_bytes())?;
 			// The state needs updating with the secret key padded to blocksize length
@@ -453,7 +490,7 @@ pub fn init(secret_key: Option<&SecretKey>, size: usize) -> Result<Blake2b, Unkn
 			context.update(pad[..rem].as_ref())?;
 		}
 		None => {
-			context.internal_state[0] ^= 0x01010000 ^ (size as u64);
+			context.internal_state[0] ^= size as u64;
 			context.init_state.copy_from_slice(&context.internal_state);
 		}
 	}
@@ -475,7 +512,7 @@ pub fn verify(
 	if expected == &state.finalize()? {
 		Ok(true)
 	} else {
-		Err(UnknownCryptoError)
+		Err(UnknownCryptoError::InvalidTag)
 	}
 }
 
@@ -813,6 +850,43 @@ mod public {
 			state.reset(None).unwrap();
 			let _ = state.finalize().unwrap();
 		}
+
+		#[test]
+		fn test_finalize_reset_same_as_finalize_then_reset() {
+			let secret_key = SecretKey::from_slice(b"Testing").unwrap();
+
+			let mut state = init(Some(&secret_key), 64).unwrap();
+			state.update(b"Test").unwrap();
+			let one = state.finalize_reset(Some(&secret_key)).unwrap();
+			state.update(b"Test").unwrap();
+			let two = state.finalize_reset(Some(&secret_key)).unwrap();
+			assert_eq!(one, two);
+
+			compare_blake2b_states(&state, &init(Some(&secret_key), 64).unwrap());
+		}
+
+		#[test]
+		fn test_finalize_reset_switching_keyed_modes_fails() {
+			let secret_key = SecretKey::from_slice(b"Testing").unwrap();
+
+			let mut state = init(Some(&secret_key), 64).unwrap();
+			state.update(b"Test").unwrap();
+			assert!(state.finalize_reset(None).is_err());
+			// The state was not finalized by the failed call.
+			assert!(state.finalize_reset(Some(&secret_key)).is_ok());
+
+			let mut state_second = init(None, 64).unwrap();
+			state_second.update(b"Test").unwrap();
+			assert!(state_second.finalize_reset(Some(&secret_key)).is_err());
+		}
+
+		#[test]
+		fn test_finalize_reset_after_finalize_fail() {
+			let mut state = init(None, 64).unwrap();
+			state.update(b"Test").unwrap();
+			let _ = state.finalize().unwrap();
+			assert!(state.finalize_reset(None).is_err());
+		}
 	}
 
 	mod test_streaming_interface {
@@ -1037,20 +1111,19 @@ mod private {
 				size: 1,
 			};
 
-			context.increment_offset(1);
+			context.increment_offset(1).unwrap();
 			assert!(context.t == [1u64, 0u64]);
-			context.increment_offset(17);
+			context.increment_offset(17).unwrap();
 			assert!(context.t == [18u64, 0u64]);
-			context.increment_offset(12);
+			context.increment_offset(12).unwrap();
 			assert!(context.t == [30u64, 0u64]);
 			// Overflow
-			context.increment_offset(u64::max_value());
+			context.increment_offset(u64::max_value()).unwrap();
 			assert!(context.t == [29u64, 1u64]);
 		}
 
 		#[test]
-		#[should_panic]
-		fn test_panic_on_second_overflow() {
+		fn test_err_on_second_overflow() {
 			let mut context = Blake2b {
 				init_state: [0u64; 8],
 				internal_state: IV,
@@ -1063,7 +1136,9 @@ mod private {
 				size: 1,
 			};
 
-			context.increment_offset(u64::max_value());
+			assert!(context.increment_offset(u64::max_value()).is_err());
+			// The state is left unchanged.
+			assert!(context.t == [1u64, u64::max_value()]);
 		}
 	}
 }
@@ -0,0 +1,262 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! # Parameters:
+//! - `secret_key`: An optional secret key.
+//! - `size`: The desired output length for the digest.
+//! - `data`: The data to be hashed.
+//! - `dst_out`: Destination buffer for the digest. The length of the digest
+//!   is implied by the length of `dst_out`.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `size` is 0.
+//! - `size` is greater than 2^32 - 2.
+//! - The length of `dst_out` is not `size`.
+//! - [`finalize()`] is called twice without a [`reset()`] in between.
+//! - [`update()`] is called after [`finalize()`] without a [`reset()`] in
+//!   between.
+//! - [`reset()`] is called with `Some(secret_key)` but the struct was
+//!   initialized with `None`.
+//! - [`reset()`] is called with `None` as `secret_key` but the struct was
+//!   initialized with `Some(secret_key)`.
+//! - More than 2*(2^64-1) bytes of data are hashed.
+//!
+//! # About:
+//! - BLAKE2Xb produces digests of any length up to 2^32 - 2 bytes from
+//!   BLAKE2b. The data is hashed once with BLAKE2b, and the digest is
+//!   expanded in blocks of 64 bytes, each one a BLAKE2b hash of the first
+//!   digest with its own parameters.
+//! - The length of the digest is part of the parameters, so a shorter digest
+//!   is not a prefix of a longer digest of the same data.
+//!
+//! # Security:
+//! - The secret key should always be generated using a CSPRNG.
+//!   [`SecretKey::generate()`] can be used for this. It generates a secret key
+//!   of 64 bytes.
+//! - The minimum recommended size for a secret key is 32 bytes.
+//! - Digests longer than 64 bytes do not provide more than 256 bits of
+//!   security.
+//!
+//! # Example:
+//! ```rust
+//! use orion::hazardous::hash::{blake2b::SecretKey, blake2xb};
+//!
+//! // Using the streaming interface with a key.
+//! let secret_key = SecretKey::generate();
+//! let mut state = blake2xb::init(Some(&secret_key), 128)?;
+//! state.update(b"Some data")?;
+//! let mut key_material = [0u8; 128];
+//! state.finalize(&mut key_material)?;
+//!
+//! // Using the one-shot function without a key.
+//! let mut digest = [0u8; 100];
+//! blake2xb::digest(b"Some data", &mut digest)?;
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`update()`]: https://docs.rs/orion/latest/orion/hazardous/hash/blake2xb/struct.Blake2xb.html
+//! [`reset()`]: https://docs.rs/orion/latest/orion/hazardous/hash/blake2xb/struct.Blake2xb.html
+//! [`finalize()`]: https://docs.rs/orion/latest/orion/hazardous/hash/blake2xb/struct.Blake2xb.html
+//! [`SecretKey::generate()`]: https://docs.rs/orion/latest/orion/hazardous/hash/blake2b/struct.SecretKey.html
+
+use crate::{
+	errors::UnknownCryptoError,
+	hazardous::hash::blake2b::{self, Blake2b, SecretKey},
+};
+
+/// The output size of BLAKE2b, and the size of each block of a BLAKE2Xb digest.
+const BLAKE2B_OUTSIZE: usize = 64;
+/// The maximum output size for BLAKE2Xb. 2^32 - 1 is reserved for digests of
+/// unknown length.
+const BLAKE2XB_MAX_OUTSIZE: u64 = 0xffff_fffe;
+
+#[must_use]
+#[derive(Clone, Debug)]
+/// BLAKE2Xb streaming state.
+pub struct Blake2xb {
+	root: Blake2b,
+	size: usize,
+}
+
+impl Blake2xb {
+	#[must_use]
+	/// Reset to `init()` state.
+	pub fn reset(&mut self, secret_key: Option<&SecretKey>) -> Result<(), UnknownCryptoError> {
+		self.root.reset(secret_key)
+	}
+
+	#[must_use]
+	/// Update state with a `data`. This can be called multiple times.
+	pub fn update(&mut self, data: &[u8]) -> Result<(), UnknownCryptoError> {
+		self.root.update(data)
+	}
+
+	#[must_use]
+	/// Write a BLAKE2Xb digest to `dst_out`.
+	pub fn finalize(&mut self, dst_out: &mut [u8]) -> Result<(), UnknownCryptoError> {
+		// Check this before finalizing, so that the state is not lost on error.
+		if dst_out.len() != self.size {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+
+		let root_digest = self.root.finalize()?;
+		for (idx, block) in dst_out.chunks_mut(BLAKE2B_OUTSIZE).enumerate() {
+			let mut node = blake2b::init_with_parameters(
+				None,
+				block.len(),
+				&node_parameters(idx as u64, self.size),
+			)?;
+			node.update(root_digest.as_ref())?;
+			block.copy_from_slice(node.finalize()?.as_ref());
+		}
+
+		Ok(())
+	}
+}
+
+/// The BLAKE2b parameter block of the output block `node_offset`, for a
+/// digest of `size` bytes.
+fn node_parameters(node_offset: u64, size: usize) -> [u64; 8] {
+	[
+		// Leaf maximal byte length of 64, fanout and depth of 0.
+		(BLAKE2B_OUTSIZE as u64) << 32,
+		node_offset ^ ((size as u64) << 32),
+		// Inner hash byte length of 64.
+		(BLAKE2B_OUTSIZE as u64) << 8,
+		0,
+		0,
+		0,
+		0,
+		0,
+	]
+}
+
+#[must_use]
+#[allow(clippy::unreadable_literal)]
+/// Initialize a `Blake2xb` struct with a given size and an optional key.
+pub fn init(secret_key: Option<&SecretKey>, size: usize) -> Result<Blake2xb, UnknownCryptoError> {
+	if size < 1 || size as u64 > BLAKE2XB_MAX_OUTSIZE {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let root = blake2b::init_with_parameters(
+		secret_key,
+		BLAKE2B_OUTSIZE,
+		&[0x01010000, (size as u64) << 32, 0, 0, 0, 0, 0, 0],
+	)?;
+
+	Ok(Blake2xb { root, size })
+}
+
+#[must_use]
+/// Calculate a BLAKE2Xb digest of some `data`, writing it to `dst_out`.
+pub fn digest(data: &[u8], dst_out: &mut [u8]) -> Result<(), UnknownCryptoError> {
+	let mut state = init(None, dst_out.len())?;
+	state.update(data)?;
+	state.finalize(dst_out)
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	mod test_init {
+		use super::*;
+
+		#[test]
+		fn test_size() {
+			assert!(init(None, 0).is_err());
+			assert!(init(None, 1).is_ok());
+			assert!(init(None, 0xffff_fffe).is_ok());
+			assert!(init(None, 0xffff_ffff).is_err());
+		}
+	}
+
+	mod test_finalize {
+		use super::*;
+
+		#[test]
+		fn test_wrong_dst_len_err() {
+			let mut state = init(None, 100).unwrap();
+			state.update(b"Test").unwrap();
+			assert!(state.finalize(&mut [0u8; 99]).is_err());
+			assert!(state.finalize(&mut [0u8; 101]).is_err());
+			// The state is not finalized on error.
+			assert!(state.finalize(&mut [0u8; 100]).is_ok());
+		}
+
+		#[test]
+		fn test_double_finalize_err() {
+			let mut state = init(None, 100).unwrap();
+			state.update(b"Test").unwrap();
+			state.finalize(&mut [0u8; 100]).unwrap();
+			assert!(state.finalize(&mut [0u8; 100]).is_err());
+			assert!(state.update(b"Test").is_err());
+		}
+
+		#[test]
+		fn test_finalize_with_reset_ok() {
+			let secret_key = SecretKey::from_slice(&[0u8; 32]).unwrap();
+			let mut state = init(Some(&secret_key), 100).unwrap();
+			state.update(b"Wrong data").unwrap();
+			let mut one = [0u8; 100];
+			state.finalize(&mut one).unwrap();
+			assert!(state.reset(None).is_err());
+			state.reset(Some(&secret_key)).unwrap();
+			state.update(b"Test").unwrap();
+			state.finalize(&mut one).unwrap();
+
+			let mut two = [0u8; 100];
+			let mut other = init(Some(&secret_key), 100).unwrap();
+			other.update(b"Test").unwrap();
+			other.finalize(&mut two).unwrap();
+			assert_eq!(one[..], two[..]);
+		}
+	}
+
+	mod test_digest {
+		use super::*;
+
+		#[test]
+		fn test_size_is_not_prefix() {
+			let mut short = [0u8; 64];
+			let mut long = [0u8; 65];
+			digest(b"Test", &mut short).unwrap();
+			digest(b"Test", &mut long).unwrap();
+			assert_ne!(short[..], long[..64]);
+
+			let mut blake2b_digest = [0u8; 64];
+			blake2b_digest.copy_from_slice(
+				blake2b::Hasher::Blake2b512
+					.digest(b"Test")
+					.unwrap()
+					.as_ref(),
+			);
+			assert_ne!(short[..], blake2b_digest[..]);
+		}
+
+		#[test]
+		fn test_empty_dst_err() {
+			assert!(digest(b"Test", &mut [0u8; 0]).is_err());
+		}
+	}
+}
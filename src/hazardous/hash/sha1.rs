@@ -0,0 +1,233 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! SHA-1 is not collision resistant, so it is not part of the public API. It
+//! is only used for HMAC-SHA1 in [`orion::otp`], because HOTP and TOTP are
+//! specified with it and most authenticator apps accept nothing else. HMAC
+//! does not rely on the collision resistance of its hash function.
+//!
+//! [`orion::otp`]: https://docs.rs/orion/latest/orion/otp/index.html
+
+use crate::endianness::{load_u32_into_be, store_u32_into_be};
+use zeroize::Zeroize;
+
+/// The blocksize for the hash function SHA1.
+const SHA1_BLOCKSIZE: usize = 64;
+/// The output size for the hash function SHA1.
+pub(crate) const SHA1_OUTSIZE: usize = 20;
+
+/// The initial hash value.
+const H0: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+
+/// SHA1 streaming state.
+struct Sha1 {
+	working_state: [u32; 5],
+	buffer: [u8; SHA1_BLOCKSIZE],
+	leftover: usize,
+	/// The amount of bytes hashed. SHA-1 is only used on short inputs, so
+	/// this cannot overflow.
+	message_len: u64,
+}
+
+impl Drop for Sha1 {
+	fn drop(&mut self) {
+		self.working_state.zeroize();
+		self.buffer.zeroize();
+	}
+}
+
+impl Sha1 {
+	fn new() -> Self {
+		Self {
+			working_state: H0,
+			buffer: [0u8; SHA1_BLOCKSIZE],
+			leftover: 0,
+			message_len: 0,
+		}
+	}
+
+	#[allow(clippy::many_single_char_names)]
+	/// Process the block in `self.buffer`.
+	fn process(&mut self) {
+		let mut w = [0u32; 80];
+		load_u32_into_be(&self.buffer, &mut w[..16]);
+		for t in 16..80 {
+			w[t] = (w[t - 3] ^ w[t - 8] ^ w[t - 14] ^ w[t - 16]).rotate_left(1);
+		}
+
+		let mut a = self.working_state[0];
+		let mut b = self.working_state[1];
+		let mut c = self.working_state[2];
+		let mut d = self.working_state[3];
+		let mut e = self.working_state[4];
+
+		for (t, word) in w.iter().enumerate() {
+			let (f, k) = match t {
+				0..=19 => ((b & c) | (!b & d), 0x5a827999),
+				20..=39 => (b ^ c ^ d, 0x6ed9eba1),
+				40..=59 => ((b & c) | (b & d) | (c & d), 0x8f1bbcdc),
+				_ => (b ^ c ^ d, 0xca62c1d6),
+			};
+			let temp = a
+				.rotate_left(5)
+				.wrapping_add(f)
+				.wrapping_add(e)
+				.wrapping_add(k)
+				.wrapping_add(*word);
+			e = d;
+			d = c;
+			c = b.rotate_left(30);
+			b = a;
+			a = temp;
+		}
+
+		self.working_state[0] = self.working_state[0].wrapping_add(a);
+		self.working_state[1] = self.working_state[1].wrapping_add(b);
+		self.working_state[2] = self.working_state[2].wrapping_add(c);
+		self.working_state[3] = self.working_state[3].wrapping_add(d);
+		self.working_state[4] = self.working_state[4].wrapping_add(e);
+		w.zeroize();
+	}
+
+	fn update(&mut self, data: &[u8]) {
+		for byte in data.iter() {
+			self.buffer[self.leftover] = *byte;
+			self.leftover += 1;
+			if self.leftover == SHA1_BLOCKSIZE {
+				self.process();
+				self.leftover = 0;
+			}
+		}
+		self.message_len += data.len() as u64;
+	}
+
+	fn finalize(mut self) -> [u8; SHA1_OUTSIZE] {
+		let message_len_bits = self.message_len * 8;
+		self.update(&[0x80]);
+		while self.leftover != SHA1_BLOCKSIZE - 8 {
+			self.update(&[0x00]);
+		}
+		self.update(&message_len_bits.to_be_bytes());
+		debug_assert!(self.leftover == 0);
+
+		let mut digest = [0u8; SHA1_OUTSIZE];
+		store_u32_into_be(&self.working_state, &mut digest);
+
+		digest
+	}
+}
+
+/// HMAC-SHA1 over the concatenation of `data`. Keys longer than the
+/// blocksize are hashed first.
+pub(crate) fn hmac_sha1(key: &[u8], data: &[&[u8]]) -> [u8; SHA1_OUTSIZE] {
+	let mut hashed_key = [0u8; SHA1_OUTSIZE];
+	let key = if key.len() > SHA1_BLOCKSIZE {
+		let mut state = Sha1::new();
+		state.update(key);
+		hashed_key = state.finalize();
+		&hashed_key[..]
+	} else {
+		key
+	};
+
+	let mut ipad = [0x36u8; SHA1_BLOCKSIZE];
+	let mut opad = [0x5cu8; SHA1_BLOCKSIZE];
+	for (idx, itm) in key.iter().enumerate() {
+		ipad[idx] ^= itm;
+		opad[idx] ^= itm;
+	}
+	hashed_key.zeroize();
+
+	let mut inner = Sha1::new();
+	inner.update(&ipad);
+	for part in data.iter() {
+		inner.update(part);
+	}
+	let mut inner_digest = inner.finalize();
+
+	let mut outer = Sha1::new();
+	outer.update(&opad);
+	outer.update(&inner_digest);
+	let tag = outer.finalize();
+	inner_digest.zeroize();
+	ipad.zeroize();
+	opad.zeroize();
+
+	tag
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	fn digest(data: &[u8]) -> [u8; SHA1_OUTSIZE] {
+		let mut state = Sha1::new();
+		state.update(data);
+		state.finalize()
+	}
+
+	mod test_sha1 {
+		use super::*;
+
+		#[test]
+		fn test_fips_180_examples() {
+			assert_eq!(
+				hex::encode(digest(b"")),
+				"da39a3ee5e6b4b0d3255bfef95601890afd80709"
+			);
+			assert_eq!(
+				hex::encode(digest(b"abc")),
+				"a9993e364706816aba3e25717850c26c9cd0d89d"
+			);
+			assert_eq!(
+				hex::encode(digest(
+					b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"
+				)),
+				"84983e441c3bd26ebaae4aa1f95129e5e54670f1"
+			);
+			assert_eq!(
+				hex::encode(digest(&vec![b'a'; 1_000_000])),
+				"34aa973cd4c4daa4f61eeb2bdbad27316534016f"
+			);
+		}
+	}
+
+	mod test_hmac_sha1 {
+		use super::*;
+
+		#[test]
+		fn test_rfc2202_case_2() {
+			let tag = hmac_sha1(b"Jefe", &[b"what do ya want ", b"for nothing?"]);
+			assert_eq!(hex::encode(tag), "effcdf6ae5eb2fa2d27416d5f184df9c259a7c79");
+		}
+
+		#[test]
+		fn test_rfc2202_case_6() {
+			let tag = hmac_sha1(
+				&[0xaau8; 80],
+				&[b"Test Using Larger Than Block-Size Key - Hash Key First"],
+			);
+			assert_eq!(hex::encode(tag), "aa4ae5e15272d00e95705637ce8a3b55ed402112");
+		}
+	}
+}
@@ -0,0 +1,824 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Parameters:
+//! - `data`: The data to be hashed.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - [`finalize()`] is called twice without a [`reset()`] in between.
+//! - [`update()`] is called after [`finalize()`] without a [`reset()`] in
+//!   between.
+//! - More than 2^64-1 __bits__ of data are hashed.
+//!
+//! # Security:
+//! - SHA256 is vulnerable to length extension attacks.
+//!
+//! # Recommendation:
+//! - It is recommended to use [BLAKE2b] when possible. SHA256 should
+//!   mostly be used for interoperability with existing protocols.
+//!
+//! # Example:
+//! ```rust
+//! use orion::hazardous::hash::sha256;
+//!
+//! // Using the streaming interface
+//! let mut state = sha256::init();
+//! state.update(b"Hello world")?;
+//! let hash = state.finalize()?;
+//!
+//! // Using the one-shot function
+//! let hash_one_shot = sha256::digest(b"Hello world")?;
+//!
+//! assert_eq!(hash, hash_one_shot);
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`update()`]: https://docs.rs/orion/latest/orion/hazardous/hash/sha256/struct.Sha256.html
+//! [`reset()`]: https://docs.rs/orion/latest/orion/hazardous/hash/sha256/struct.Sha256.html
+//! [`finalize()`]: https://docs.rs/orion/latest/orion/hazardous/hash/sha256/struct.Sha256.html
+//! [BLAKE2b]: https://docs.rs/orion/latest/orion/hazardous/hash/blake2b/index.html
+
+use crate::{
+	endianness::{load_u32_into_be, store_u32_into_be},
+	errors::UnknownCryptoError,
+};
+use zeroize::Zeroize;
+
+/// The blocksize for the hash function SHA256.
+pub const SHA256_BLOCKSIZE: usize = 64;
+/// The output size for the hash function SHA256.
+pub const SHA256_OUTSIZE: usize = 32;
+
+construct_public! {
+	/// A type to represent the `Digest` that SHA256 returns.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 32 bytes.
+	(Digest, test_digest, SHA256_OUTSIZE, SHA256_OUTSIZE)
+}
+
+impl_from_trait!(Digest, SHA256_OUTSIZE);
+
+#[rustfmt::skip]
+#[allow(clippy::unreadable_literal)]
+/// The SHA256 constants as defined in the FIPS 180-4.
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+#[rustfmt::skip]
+#[allow(clippy::unreadable_literal)]
+/// The SHA256 initial hash value H(0) as defined in the FIPS 180-4.
+const H0: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+#[derive(Clone)]
+/// SHA256 streaming state.
+pub struct Sha256 {
+	working_state: [u32; 8],
+	buffer: [u8; SHA256_BLOCKSIZE],
+	leftover: usize,
+	message_len: u64,
+	is_finalized: bool,
+}
+
+impl Drop for Sha256 {
+	fn drop(&mut self) {
+		use zeroize::Zeroize;
+		self.working_state.zeroize();
+		self.buffer.zeroize();
+		self.message_len.zeroize();
+	}
+}
+
+impl core::fmt::Debug for Sha256 {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(
+			f,
+			"Sha256 {{ working_state: [***OMITTED***], buffer: [***OMITTED***], leftover: {:?}, \
+			 message_len: {:?}, is_finalized: {:?} }}",
+			self.leftover, self.message_len, self.is_finalized
+		)
+	}
+}
+
+impl Sha256 {
+	#[inline]
+	/// The Ch function as specified in FIPS 180-4 section 4.1.3.
+	fn ch(x: u32, y: u32, z: u32) -> u32 {
+		z ^ (x & (y ^ z))
+	}
+
+	#[inline]
+	/// The Maj function as specified in FIPS 180-4 section 4.1.3.
+	fn maj(x: u32, y: u32, z: u32) -> u32 {
+		(x & y) | (z & (x | y))
+	}
+
+	#[inline]
+	/// The Big Sigma 0 function as specified in FIPS 180-4 section 4.1.3.
+	fn big_sigma_0(x: u32) -> u32 {
+		(x.rotate_right(2)) ^ x.rotate_right(13) ^ x.rotate_right(22)
+	}
+
+	#[inline]
+	/// The Big Sigma 1 function as specified in FIPS 180-4 section 4.1.3.
+	fn big_sigma_1(x: u32) -> u32 {
+		(x.rotate_right(6)) ^ x.rotate_right(11) ^ x.rotate_right(25)
+	}
+
+	#[inline]
+	/// The Small Sigma 0 function as specified in FIPS 180-4 section 4.1.3.
+	fn small_sigma_0(x: u32) -> u32 {
+		(x.rotate_right(7)) ^ x.rotate_right(18) ^ (x >> 3)
+	}
+
+	#[inline]
+	/// The Small Sigma 1 function as specified in FIPS 180-4 section 4.1.3.
+	fn small_sigma_1(x: u32) -> u32 {
+		(x.rotate_right(17)) ^ x.rotate_right(19) ^ (x >> 10)
+	}
+
+	#[inline]
+	#[allow(clippy::many_single_char_names)]
+	#[allow(clippy::too_many_arguments)]
+	/// Message compression adopted from [mbed TLS](https://tls.mbed.org/sha-256-source-code).
+	fn compress(
+		a: u32,
+		b: u32,
+		c: u32,
+		d: &mut u32,
+		e: u32,
+		f: u32,
+		g: u32,
+		h: &mut u32,
+		x: u32,
+		ki: u32,
+	) {
+		let temp1 = h
+			.wrapping_add(Self::big_sigma_1(e))
+			.wrapping_add(Self::ch(e, f, g))
+			.wrapping_add(ki)
+			.wrapping_add(x);
+
+		let temp2 = Self::big_sigma_0(a).wrapping_add(Self::maj(a, b, c));
+
+		*d = d.wrapping_add(temp1);
+		*h = temp1.wrapping_add(temp2);
+	}
+
+	#[inline]
+	#[rustfmt::skip]
+	#[allow(clippy::many_single_char_names)]
+	/// Process data in `self.buffer`.
+	fn process(&mut self, data: Option<&[u8]>) {
+		let mut w = [0u32; 64];
+		match data {
+			Some(bytes) => {
+				debug_assert!(bytes.len() == SHA256_BLOCKSIZE);
+				load_u32_into_be(bytes, &mut w[..16]);
+			}
+			None => load_u32_into_be(&self.buffer, &mut w[..16]),
+		}
+
+		for t in 16..64 {
+			w[t] = Self::small_sigma_1(w[t - 2])
+				.wrapping_add(w[t - 7])
+				.wrapping_add(Self::small_sigma_0(w[t - 15]))
+				.wrapping_add(w[t - 16]);
+		}
+
+		// Initialize working variables
+		let mut a = self.working_state[0];
+		let mut b = self.working_state[1];
+		let mut c = self.working_state[2];
+		let mut d = self.working_state[3];
+		let mut e = self.working_state[4];
+		let mut f = self.working_state[5];
+		let mut g = self.working_state[6];
+		let mut h = self.working_state[7];
+
+		let mut t = 0;
+		while t < 64 {
+			Self::compress(a, b, c, &mut d, e, f, g, &mut h, w[t], K[t]); t += 1;
+			Self::compress(h, a, b, &mut c, d, e, f, &mut g, w[t], K[t]); t += 1;
+			Self::compress(g, h, a, &mut b, c, d, e, &mut f, w[t], K[t]); t += 1;
+			Self::compress(f, g, h, &mut a, b, c, d, &mut e, w[t], K[t]); t += 1;
+			Self::compress(e, f, g, &mut h, a, b, c, &mut d, w[t], K[t]); t += 1;
+			Self::compress(d, e, f, &mut g, h, a, b, &mut c, w[t], K[t]); t += 1;
+			Self::compress(c, d, e, &mut f, g, h, a, &mut b, w[t], K[t]); t += 1;
+			Self::compress(b, c, d, &mut e, f, g, h, &mut a, w[t], K[t]); t += 1;
+		}
+
+		self.working_state[0] = self.working_state[0].wrapping_add(a);
+		self.working_state[1] = self.working_state[1].wrapping_add(b);
+		self.working_state[2] = self.working_state[2].wrapping_add(c);
+		self.working_state[3] = self.working_state[3].wrapping_add(d);
+		self.working_state[4] = self.working_state[4].wrapping_add(e);
+		self.working_state[5] = self.working_state[5].wrapping_add(f);
+		self.working_state[6] = self.working_state[6].wrapping_add(g);
+		self.working_state[7] = self.working_state[7].wrapping_add(h);
+	}
+
+	/// Reset to `init()` state.
+	pub fn reset(&mut self) {
+		self.working_state = H0;
+		self.buffer = [0u8; SHA256_BLOCKSIZE];
+		self.leftover = 0;
+		self.message_len = 0;
+		self.is_finalized = false;
+	}
+
+	#[must_use]
+	#[inline]
+	/// Increment the message length during processing of data. An error is
+	/// returned if the size limit is reached.
+	fn increment_mlen(&mut self, length: u64) -> Result<(), UnknownCryptoError> {
+		// Bit-sized representation of length.
+		let len = length
+			.checked_mul(8)
+			.ok_or(UnknownCryptoError::SizeLimitExceeded)?;
+		self.message_len = self
+			.message_len
+			.checked_add(len)
+			.ok_or(UnknownCryptoError::SizeLimitExceeded)?;
+
+		Ok(())
+	}
+
+	#[must_use]
+	/// Update state with `data`. This can be called multiple times.
+	pub fn update(&mut self, data: &[u8]) -> Result<(), UnknownCryptoError> {
+		if self.is_finalized {
+			return Err(UnknownCryptoError::FinalizedState);
+		}
+		if data.is_empty() {
+			return Ok(());
+		}
+
+		let mut bytes = data;
+
+		if self.leftover != 0 {
+			debug_assert!(self.leftover <= SHA256_BLOCKSIZE);
+
+			let mut want = SHA256_BLOCKSIZE - self.leftover;
+			if want > bytes.len() {
+				want = bytes.len();
+			}
+
+			for (idx, itm) in bytes.iter().enumerate().take(want) {
+				self.buffer[self.leftover + idx] = *itm;
+			}
+
+			// Reduce by slice
+			bytes = &bytes[want..];
+			self.leftover += want;
+			self.increment_mlen(want as u64)?;
+
+			if self.leftover < SHA256_BLOCKSIZE {
+				return Ok(());
+			}
+
+			self.process(None);
+			self.leftover = 0;
+		}
+
+		while bytes.len() >= SHA256_BLOCKSIZE {
+			// Process data
+			self.process(Some(bytes[..SHA256_BLOCKSIZE].as_ref()));
+			self.increment_mlen(SHA256_BLOCKSIZE as u64)?;
+			// Reduce by slice
+			bytes = &bytes[SHA256_BLOCKSIZE..];
+		}
+
+		if !bytes.is_empty() {
+			debug_assert!(self.leftover == 0);
+
+			self.buffer[..bytes.len()].copy_from_slice(bytes);
+			self.leftover = bytes.len();
+			self.increment_mlen(bytes.len() as u64)?;
+		}
+
+		Ok(())
+	}
+
+	#[must_use]
+	/// Return a SHA256 digest.
+	pub fn finalize(&mut self) -> Result<Digest, UnknownCryptoError> {
+		if self.is_finalized {
+			return Err(UnknownCryptoError::FinalizedState);
+		}
+
+		self.is_finalized = true;
+
+		// self.leftover should not be greater than SHA2_BLCOKSIZE
+		// as that would have been processed in the update call
+		debug_assert!(self.leftover < SHA256_BLOCKSIZE);
+		self.buffer[self.leftover] = 0x80;
+		self.leftover += 1;
+
+		for itm in self.buffer.iter_mut().skip(self.leftover) {
+			*itm = 0;
+		}
+
+		// Check for available space for length padding
+		if (SHA256_BLOCKSIZE - self.leftover) < 8 {
+			self.process(None);
+			for itm in self.buffer.iter_mut().take(self.leftover) {
+				*itm = 0;
+			}
+		}
+
+		// Pad with length
+		self.buffer[SHA256_BLOCKSIZE - 8..SHA256_BLOCKSIZE]
+			.copy_from_slice(&self.message_len.to_be_bytes());
+
+		self.process(None);
+
+		let mut digest = [0u8; SHA256_OUTSIZE];
+		store_u32_into_be(&self.working_state, &mut digest);
+
+		Ok(Digest::from(digest))
+	}
+
+	#[must_use]
+	/// Return a SHA256 digest and reset to `init()` state, so that the same
+	/// state can be used for the next message without cloning or re-initializing it.
+	pub fn finalize_reset(&mut self) -> Result<Digest, UnknownCryptoError> {
+		let digest = self.finalize()?;
+		self.reset();
+
+		Ok(digest)
+	}
+}
+
+#[must_use]
+/// Initialize a `Sha256` struct.
+pub fn init() -> Sha256 {
+	Sha256 {
+		working_state: H0,
+		buffer: [0u8; SHA256_BLOCKSIZE],
+		leftover: 0,
+		message_len: 0,
+		is_finalized: false,
+	}
+}
+
+#[must_use]
+/// Calculate a SHA256 digest of some `data`.
+pub fn digest(data: &[u8]) -> Result<Digest, UnknownCryptoError> {
+	let mut state = init();
+	state.update(data)?;
+	state.finalize()
+}
+
+#[must_use]
+/// HMAC-SHA256 over the concatenation of `data`. This is used internally by
+/// primitives that are specified with HMAC-SHA256. Keys longer than the
+/// blocksize are hashed first.
+pub(crate) fn hmac_sha256(
+	key: &[u8],
+	data: &[&[u8]],
+) -> Result<[u8; SHA256_OUTSIZE], UnknownCryptoError> {
+	let mut hashed_key = [0u8; SHA256_OUTSIZE];
+	let key = if key.len() > SHA256_BLOCKSIZE {
+		hashed_key.copy_from_slice(digest(key)?.as_ref());
+		&hashed_key[..]
+	} else {
+		key
+	};
+
+	let mut ipad = [0x36u8; SHA256_BLOCKSIZE];
+	let mut opad = [0x5cu8; SHA256_BLOCKSIZE];
+	for (idx, itm) in key.iter().enumerate() {
+		ipad[idx] ^= itm;
+		opad[idx] ^= itm;
+	}
+	hashed_key.zeroize();
+
+	let mut inner = init();
+	inner.update(&ipad)?;
+	for part in data.iter() {
+		inner.update(part)?;
+	}
+	let inner_digest = inner.finalize()?;
+
+	let mut outer = init();
+	outer.update(&opad)?;
+	outer.update(inner_digest.as_ref())?;
+
+	let mut tag = [0u8; SHA256_OUTSIZE];
+	tag.copy_from_slice(outer.finalize()?.as_ref());
+	ipad.zeroize();
+	opad.zeroize();
+
+	Ok(tag)
+}
+
+#[cfg(test)]
+/// Compare two Sha256 state objects to check if their fields
+/// are the same.
+pub fn compare_sha256_states(state_1: &Sha256, state_2: &Sha256) {
+	assert_eq!(state_1.working_state, state_2.working_state);
+	assert_eq!(state_1.buffer[..], state_2.buffer[..]);
+	assert_eq!(state_1.leftover, state_2.leftover);
+	assert_eq!(state_1.message_len, state_2.message_len);
+	assert_eq!(state_1.is_finalized, state_2.is_finalized);
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	// One function tested per submodule.
+
+	mod test_hmac_sha256 {
+		use super::*;
+
+		#[test]
+		fn test_rfc4231_case_2() {
+			let tag = hmac_sha256(b"Jefe", &[b"what do ya want ", b"for nothing?"]).unwrap();
+			let expected = [
+				0x5b, 0xdc, 0xc1, 0x46, 0xbf, 0x60, 0x75, 0x4e, 0x6a, 0x04, 0x24, 0x26, 0x08, 0x95,
+				0x75, 0xc7, 0x5a, 0x00, 0x3f, 0x08, 0x9d, 0x27, 0x39, 0x83, 0x9d, 0xec, 0x58, 0xb9,
+				0x64, 0xec, 0x38, 0x43,
+			];
+
+			assert_eq!(tag, expected);
+		}
+
+		#[test]
+		fn test_rfc4231_case_6() {
+			let tag = hmac_sha256(
+				&[0xaau8; 131],
+				&[b"Test Using Larger Than Block-Size Key - Hash Key First"],
+			)
+			.unwrap();
+			let expected = [
+				0x60, 0xe4, 0x31, 0x59, 0x1e, 0xe0, 0xb6, 0x7f, 0x0d, 0x8a, 0x26, 0xaa, 0xcb, 0xf5,
+				0xb7, 0x7f, 0x8e, 0x0b, 0xc6, 0x21, 0x37, 0x28, 0xc5, 0x14, 0x05, 0x46, 0x04, 0x0f,
+				0x0e, 0xe3, 0x7f, 0x54,
+			];
+
+			assert_eq!(tag, expected);
+		}
+	}
+	mod test_reset {
+		use super::*;
+
+		#[test]
+		fn test_double_reset_ok() {
+			let data = "what do ya want for nothing?".as_bytes();
+
+			let mut state = init();
+			state.update(data).unwrap();
+			let _ = state.finalize().unwrap();
+			state.reset();
+			state.reset();
+		}
+	}
+
+	mod test_update {
+		use super::*;
+
+		#[test]
+		fn test_update_after_finalize_with_reset_ok() {
+			let data = "what do ya want for nothing?".as_bytes();
+
+			let mut state = init();
+			state.update(data).unwrap();
+			let _ = state.finalize().unwrap();
+			state.reset();
+			state.update(data).unwrap();
+		}
+
+		#[test]
+		/// Related bug: https://github.com/brycx/orion/issues/28
+		fn test_update_after_finalize_err() {
+			let data = "what do ya want for nothing?".as_bytes();
+
+			let mut state = init();
+			state.update(data).unwrap();
+			let _ = state.finalize().unwrap();
+			assert!(state.update(data).is_err());
+		}
+	}
+
+	mod test_finalize {
+		use super::*;
+
+		#[test]
+		fn test_double_finalize_with_reset_no_update_ok() {
+			let data = "what do ya want for nothing?".as_bytes();
+
+			let mut state = init();
+			state.update(data).unwrap();
+			let _ = state.finalize().unwrap();
+			state.reset();
+			let _ = state.finalize().unwrap();
+		}
+
+		#[test]
+		fn test_double_finalize_with_reset_ok() {
+			let data = "what do ya want for nothing?".as_bytes();
+
+			let mut state = init();
+			state.update(data).unwrap();
+			let one = state.finalize().unwrap();
+			state.reset();
+			state.update(data).unwrap();
+			let two = state.finalize().unwrap();
+			assert_eq!(one.as_ref(), two.as_ref());
+		}
+
+		#[test]
+		fn test_double_finalize_err() {
+			let data = "what do ya want for nothing?".as_bytes();
+
+			let mut state = init();
+			state.update(data).unwrap();
+			let _ = state.finalize().unwrap();
+			assert_eq!(
+				state.finalize().unwrap_err(),
+				UnknownCryptoError::FinalizedState
+			);
+		}
+
+		#[test]
+		fn test_finalize_reset_same_as_finalize_then_reset() {
+			let data = "what do ya want for nothing?".as_bytes();
+
+			let mut state = init();
+			state.update(data).unwrap();
+			let one = state.finalize_reset().unwrap();
+			state.update(data).unwrap();
+			let two = state.finalize_reset().unwrap();
+			assert_eq!(one.as_ref(), two.as_ref());
+			assert_eq!(one.as_ref(), digest(data).unwrap().as_ref());
+
+			// The state is usable right away, without an explicit reset().
+			compare_sha256_states(&state, &init());
+		}
+
+		#[test]
+		fn test_finalize_reset_after_finalize_err() {
+			let mut state = init();
+			state.update(b"Test").unwrap();
+			let _ = state.finalize().unwrap();
+			assert!(state.finalize_reset().is_err());
+		}
+	}
+
+	mod test_streaming_interface {
+		use super::*;
+
+		/// Related bug: https://github.com/brycx/orion/issues/46
+		/// Testing different usage combinations of init(), update(),
+		/// finalize() and reset() produce the same Digest.
+		fn produces_same_hash(data: &[u8]) {
+			// init(), update(), finalize()
+			let mut state_1 = init();
+			state_1.update(data).unwrap();
+			let res_1 = state_1.finalize().unwrap();
+
+			// init(), reset(), update(), finalize()
+			let mut state_2 = init();
+			state_2.reset();
+			state_2.update(data).unwrap();
+			let res_2 = state_2.finalize().unwrap();
+
+			// init(), update(), reset(), update(), finalize()
+			let mut state_3 = init();
+			state_3.update(data).unwrap();
+			state_3.reset();
+			state_3.update(data).unwrap();
+			let res_3 = state_3.finalize().unwrap();
+
+			// init(), update(), finalize(), reset(), update(), finalize()
+			let mut state_4 = init();
+			state_4.update(data).unwrap();
+			let _ = state_4.finalize().unwrap();
+			state_4.reset();
+			state_4.update(data).unwrap();
+			let res_4 = state_4.finalize().unwrap();
+
+			assert_eq!(res_1, res_2);
+			assert_eq!(res_2, res_3);
+			assert_eq!(res_3, res_4);
+
+			// Tests for the assumption that returning Ok() on empty update() calls
+			// with streaming API's, gives the correct result. This is done by testing
+			// the reasoning that if update() is empty, returns Ok(), it is the same as
+			// calling init() -> finalize(). i.e not calling update() at all.
+			if data.is_empty() {
+				// init(), finalize()
+				let mut state_5 = init();
+				let res_5 = state_5.finalize().unwrap();
+
+				// init(), reset(), finalize()
+				let mut state_6 = init();
+				state_6.reset();
+				let res_6 = state_6.finalize().unwrap();
+
+				// init(), update(), reset(), finalize()
+				let mut state_7 = init();
+				state_7.update(b"Wrong data").unwrap();
+				state_7.reset();
+				let res_7 = state_7.finalize().unwrap();
+
+				assert_eq!(res_4, res_5);
+				assert_eq!(res_5, res_6);
+				assert_eq!(res_6, res_7);
+			}
+		}
+
+		/// Related bug: https://github.com/brycx/orion/issues/46
+		/// Testing different usage combinations of init(), update(),
+		/// finalize() and reset() produce the same Digest.
+		fn produces_same_state(data: &[u8]) {
+			// init()
+			let state_1 = init();
+
+			// init(), reset()
+			let mut state_2 = init();
+			state_2.reset();
+
+			// init(), update(), reset()
+			let mut state_3 = init();
+			state_3.update(data).unwrap();
+			state_3.reset();
+
+			// init(), update(), finalize(), reset()
+			let mut state_4 = init();
+			state_4.update(data).unwrap();
+			let _ = state_4.finalize().unwrap();
+			state_4.reset();
+
+			compare_sha256_states(&state_1, &state_2);
+			compare_sha256_states(&state_2, &state_3);
+			compare_sha256_states(&state_3, &state_4);
+		}
+
+		#[test]
+		/// Related bug: https://github.com/brycx/orion/issues/46
+		fn test_produce_same_state() {
+			produces_same_state(b"Tests");
+		}
+
+		#[test]
+		/// Related bug: https://github.com/brycx/orion/issues/46
+		fn test_produce_same_hash() {
+			produces_same_hash(b"Tests");
+			produces_same_hash(b"");
+		}
+
+		#[test]
+		#[cfg(feature = "safe_api")]
+		// Test for issues when incrementally processing data
+		// with leftover
+		fn test_streaming_consistency() {
+			for len in 0..SHA256_BLOCKSIZE * 4 {
+				let data = vec![0u8; len];
+				let mut state = init();
+				let mut other_data: Vec<u8> = Vec::new();
+
+				other_data.extend_from_slice(&data);
+				state.update(&data).unwrap();
+
+				if data.len() > SHA256_BLOCKSIZE {
+					other_data.extend_from_slice(b"");
+					state.update(b"").unwrap();
+				}
+				if data.len() > SHA256_BLOCKSIZE * 2 {
+					other_data.extend_from_slice(b"Extra");
+					state.update(b"Extra").unwrap();
+				}
+				if data.len() > SHA256_BLOCKSIZE * 3 {
+					other_data.extend_from_slice(&[0u8; 256]);
+					state.update(&[0u8; 256]).unwrap();
+				}
+
+				let digest_one_shot = digest(&other_data).unwrap();
+
+				assert!(state.finalize().unwrap().as_ref() == digest_one_shot.as_ref());
+			}
+		}
+		// Proptests. Only exectued when NOT testing no_std.
+		#[cfg(feature = "safe_api")]
+		mod proptest {
+			use super::*;
+
+			quickcheck! {
+				/// Related bug: https://github.com/brycx/orion/issues/46
+				/// Test different streaming state usage patterns.
+				fn prop_same_hash_different_usage(data: Vec<u8>) -> bool {
+					// Will panic on incorrect results.
+					produces_same_hash(&data[..]);
+
+					true
+				}
+			}
+
+			quickcheck! {
+				/// Related bug: https://github.com/brycx/orion/issues/46
+				/// Test different streaming state usage patterns.
+				fn prop_same_state_different_usage(data: Vec<u8>) -> bool {
+					// Will panic on incorrect results.
+					produces_same_state(&data[..]);
+
+					true
+				}
+			}
+
+			quickcheck! {
+				/// Using the one-shot function should always produce the
+				/// same result as when using the streaming interface.
+				fn prop_digest_same_as_streaming(data: Vec<u8>) -> bool {
+					let mut state = init();
+					state.update(&data[..]).unwrap();
+					let stream = state.finalize().unwrap();
+					let one_shot = digest(&data[..]).unwrap();
+
+					(one_shot == stream)
+				}
+			}
+		}
+	}
+}
+
+// Testing private functions in the module.
+#[cfg(test)]
+mod private {
+	use super::*;
+	// One function tested per submodule.
+
+	mod test_increment_mlen {
+		use super::*;
+
+		#[test]
+		fn test_mlen_increase_values() {
+			let mut context = Sha256 {
+				working_state: H0,
+				buffer: [0u8; SHA256_BLOCKSIZE],
+				leftover: 0,
+				message_len: 0,
+				is_finalized: false,
+			};
+
+			context.increment_mlen(1).unwrap();
+			assert!(context.message_len == 8u64);
+			context.increment_mlen(17).unwrap();
+			assert!(context.message_len == 144u64);
+			context.increment_mlen(12).unwrap();
+			assert!(context.message_len == 240u64);
+		}
+
+		#[test]
+		fn test_err_on_overflow() {
+			let mut context = Sha256 {
+				working_state: H0,
+				buffer: [0u8; SHA256_BLOCKSIZE],
+				leftover: 0,
+				message_len: u64::max_value() - 7,
+				is_finalized: false,
+			};
+			// u64::max_value() - 7, to leave so that the length represented
+			// in bites should overflow by exactly one.
+
+			assert_eq!(
+				context.increment_mlen(1),
+				Err(UnknownCryptoError::SizeLimitExceeded)
+			);
+			assert!(context.increment_mlen(u64::max_value()).is_err());
+		}
+	}
+}
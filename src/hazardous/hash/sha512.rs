@@ -28,9 +28,6 @@
 //! - [`finalize()`] is called twice without a [`reset()`] in between.
 //! - [`update()`] is called after [`finalize()`] without a [`reset()`] in
 //!   between.
-//!
-//! # Panics:
-//! A panic will occur if:
 //! - More than 2*(2^64-1) __bits__ of data are hashed.
 //!
 //! # Security:
@@ -271,31 +268,44 @@ impl Sha512 {
 		self.is_finalized = false;
 	}
 
+	/// Reset to the state of `other`, which must only have processed whole
+	/// blocks. This lets HMAC restore its padded-key states in place.
+	pub(crate) fn reset_to(&mut self, other: &Self) {
+		debug_assert!(other.leftover == 0);
+		debug_assert!(!other.is_finalized);
+
+		self.working_state = other.working_state;
+		self.buffer = [0u8; SHA512_BLOCKSIZE];
+		self.leftover = 0;
+		self.message_len = other.message_len;
+		self.is_finalized = false;
+	}
+
+	#[must_use]
 	#[inline]
-	/// Increment the message length during processing of data.
-	fn increment_mlen(&mut self, length: u64) {
-		// The checked shift checks that the right-hand side is a legal shift.
-		// The result can still overflow if length > u64::max_value() / 8.
-		// Should be impossible for a user to trigger.
-		debug_assert!(length <= u64::max_value() / 8);
-
-		// left-shift to get bit-sized representation of length
-		// using .unwrap() because it should not panic in practice
-		let len = length.checked_shl(3).unwrap();
+	/// Increment the message length during processing of data. An error is
+	/// returned if the size limit is reached.
+	fn increment_mlen(&mut self, length: u64) -> Result<(), UnknownCryptoError> {
+		// Bit-sized representation of length.
+		let len = length
+			.checked_mul(8)
+			.ok_or(UnknownCryptoError::SizeLimitExceeded)?;
 		let (res, was_overflow) = self.message_len[1].overflowing_add(len);
-		self.message_len[1] = res;
-
 		if was_overflow {
-			// If this panics size limit is reached.
-			self.message_len[0] = self.message_len[0].checked_add(1).unwrap();
+			self.message_len[0] = self.message_len[0]
+				.checked_add(1)
+				.ok_or(UnknownCryptoError::SizeLimitExceeded)?;
 		}
+		self.message_len[1] = res;
+
+		Ok(())
 	}
 
 	#[must_use]
 	/// Update state with `data`. This can be called multiple times.
 	pub fn update(&mut self, data: &[u8]) -> Result<(), UnknownCryptoError> {
 		if self.is_finalized {
-			return Err(UnknownCryptoError);
+			return Err(UnknownCryptoError::FinalizedState);
 		}
 		if data.is_empty() {
 			return Ok(());
@@ -318,7 +328,7 @@ impl Sha512 {
 			// Reduce by slice
 			bytes = &bytes[want..];
 			self.leftover += want;
-			self.increment_mlen(want as u64);
+			self.increment_mlen(want as u64)?;
 
 			if self.leftover < SHA512_BLOCKSIZE {
 				return Ok(());
@@ -331,7 +341,7 @@ impl Sha512 {
 		while bytes.len() >= SHA512_BLOCKSIZE {
 			// Process data
 			self.process(Some(bytes[..SHA512_BLOCKSIZE].as_ref()));
-			self.increment_mlen(SHA512_BLOCKSIZE as u64);
+			self.increment_mlen(SHA512_BLOCKSIZE as u64)?;
 			// Reduce by slice
 			bytes = &bytes[SHA512_BLOCKSIZE..];
 		}
@@ -341,7 +351,7 @@ impl Sha512 {
 
 			self.buffer[..bytes.len()].copy_from_slice(bytes);
 			self.leftover = bytes.len();
-			self.increment_mlen(bytes.len() as u64);
+			self.increment_mlen(bytes.len() as u64)?;
 		}
 
 		Ok(())
@@ -351,7 +361,7 @@ impl Sha512 {
 	/// Return a SHA512 digest.
 	pub fn finalize(&mut self) -> Result<Digest, UnknownCryptoError> {
 		if self.is_finalized {
-			return Err(UnknownCryptoError);
+			return Err(UnknownCryptoError::FinalizedState);
 		}
 
 		self.is_finalized = true;
@@ -387,6 +397,16 @@ impl Sha512 {
 
 		Ok(Digest::from(digest))
 	}
+
+	#[must_use]
+	/// Return a SHA512 digest and reset to `init()` state, so that the same
+	/// state can be used for the next message without cloning or re-initializing it.
+	pub fn finalize_reset(&mut self) -> Result<Digest, UnknownCryptoError> {
+		let digest = self.finalize()?;
+		self.reset();
+
+		Ok(digest)
+	}
 }
 
 #[must_use]
@@ -504,6 +524,29 @@ mod public {
 			assert!(state.finalize().is_err());
 		}
 
+		#[test]
+		fn test_finalize_reset_same_as_finalize_then_reset() {
+			let data = "what do ya want for nothing?".as_bytes();
+
+			let mut state = init();
+			state.update(data).unwrap();
+			let one = state.finalize_reset().unwrap();
+			state.update(data).unwrap();
+			let two = state.finalize_reset().unwrap();
+			assert_eq!(one.as_ref(), two.as_ref());
+			assert_eq!(one.as_ref(), digest(data).unwrap().as_ref());
+
+			// The state is usable right away, without an explicit reset().
+			compare_sha512_states(&state, &init());
+		}
+
+		#[test]
+		fn test_finalize_reset_after_finalize_err() {
+			let mut state = init();
+			state.update(b"Test").unwrap();
+			let _ = state.finalize().unwrap();
+			assert!(state.finalize_reset().is_err());
+		}
 	}
 
 	mod test_streaming_interface {
@@ -702,20 +745,19 @@ mod private {
 				is_finalized: false,
 			};
 
-			context.increment_mlen(1);
+			context.increment_mlen(1).unwrap();
 			assert!(context.message_len == [0u64, 8u64]);
-			context.increment_mlen(17);
+			context.increment_mlen(17).unwrap();
 			assert!(context.message_len == [0u64, 144u64]);
-			context.increment_mlen(12);
+			context.increment_mlen(12).unwrap();
 			assert!(context.message_len == [0u64, 240u64]);
 			// Overflow
-			context.increment_mlen(u64::max_value() / 8);
+			context.increment_mlen(u64::max_value() / 8).unwrap();
 			assert!(context.message_len == [1u64, 232u64]);
 		}
 
 		#[test]
-		#[should_panic]
-		fn test_panic_on_second_overflow() {
+		fn test_err_on_second_overflow() {
 			let mut context = Sha512 {
 				working_state: H0,
 				buffer: [0u8; SHA512_BLOCKSIZE],
@@ -726,7 +768,10 @@ mod private {
 			// u64::max_value() - 7, to leave so that the length represented
 			// in bites should overflow by exactly one.
 
-			context.increment_mlen(1);
+			assert!(context.increment_mlen(1).is_err());
+			// The state is left unchanged.
+			assert!(context.message_len == [u64::max_value(), u64::max_value() - 7]);
+			assert!(context.increment_mlen(u64::max_value()).is_err());
 		}
 	}
 }
@@ -0,0 +1,615 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! # Parameters:
+//! - `function_name`: The function-name string `N` of cSHAKE256.
+//! - `customization`: The customization string `S` of cSHAKE256.
+//! - `data`: The data to be hashed.
+//! - `dst_out`: Destination buffer for the output. The amount of output is
+//!   implied by the length of `dst_out`.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - The length of `dst_out` is less than 1.
+//! - [`update()`] is called after [`squeeze()`] without a [`reset()`] in
+//!   between.
+//!
+//! # About:
+//! - SHAKE256 is the extendable-output function (XOF) of SHA-3, which can
+//!   produce output of any length. [`squeeze()`] can be called multiple times,
+//!   each call continuing the output where the previous one stopped.
+//! - cSHAKE256 is SHAKE256 customized with a function-name and a customization
+//!   string, so that different uses of the function produce unrelated output.
+//!   When both strings are empty, cSHAKE256 is SHAKE256.
+//! - Output of 64 bytes or more provides 256 bits of security.
+//!
+//! # Security:
+//! - SHAKE256 is not a MAC. To derive keys from a secret, use
+//!   [`kdf::kmac`].
+//! - A shorter output is a prefix of a longer output for the same input, so
+//!   the output length does not separate uses of the function. Use a
+//!   customization string for this.
+//!
+//! # Example:
+//! ```rust
+//! use orion::hazardous::hash::shake256;
+//!
+//! // Using the streaming interface
+//! let mut state = shake256::init();
+//! state.update(b"Hello world")?;
+//! let mut output = [0u8; 128];
+//! state.squeeze(&mut output)?;
+//!
+//! // Using the one-shot function
+//! let mut output_one_shot = [0u8; 128];
+//! shake256::digest(b"Hello world", &mut output_one_shot)?;
+//!
+//! assert_eq!(output[..], output_one_shot[..]);
+//!
+//! // Using cSHAKE256
+//! let mut state = shake256::init_customized(b"", b"Email Signature");
+//! state.update(b"Hello world")?;
+//! state.squeeze(&mut output)?;
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`update()`]: https://docs.rs/orion/latest/orion/hazardous/hash/shake256/struct.Shake256.html
+//! [`reset()`]: https://docs.rs/orion/latest/orion/hazardous/hash/shake256/struct.Shake256.html
+//! [`squeeze()`]: https://docs.rs/orion/latest/orion/hazardous/hash/shake256/struct.Shake256.html
+//! [`kdf::kmac`]: https://docs.rs/orion/latest/orion/hazardous/kdf/kmac/index.html
+
+use crate::{
+	endianness::{load_u64_into_le, store_u64_into_le},
+	errors::UnknownCryptoError,
+};
+
+/// The rate, in bytes, of SHAKE256.
+pub const SHAKE256_RATE: usize = 136;
+
+/// The domain separation and first padding bit of SHAKE256.
+const SHAKE_PAD: u8 = 0x1f;
+/// The domain separation and first padding bit of cSHAKE256.
+const CSHAKE_PAD: u8 = 0x04;
+
+#[rustfmt::skip]
+#[allow(clippy::unreadable_literal)]
+/// The round constants of Keccak-f[1600] as defined in the FIPS 202.
+const RC: [u64; 24] = [
+    0x0000000000000001, 0x0000000000008082, 0x800000000000808a, 0x8000000080008000,
+    0x000000000000808b, 0x0000000080000001, 0x8000000080008081, 0x8000000000008009,
+    0x000000000000008a, 0x0000000000000088, 0x0000000080008009, 0x000000008000000a,
+    0x000000008000808b, 0x800000000000008b, 0x8000000000008089, 0x8000000000008003,
+    0x8000000000008002, 0x8000000000000080, 0x000000000000800a, 0x800000008000000a,
+    0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008,
+];
+
+/// The rotation offsets of the rho step, in the order of the lanes visited by
+/// the pi step.
+const RHO: [u32; 24] = [
+	1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+/// The lanes visited by the pi step, starting from lane 1.
+const PI: [usize; 24] = [
+	10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+/// The Keccak-f[1600] permutation as specified in the FIPS 202 section 3.3.
+fn keccak_f(lanes: &mut [u64; 25]) {
+	for rc in RC.iter() {
+		// Theta
+		let mut column = [0u64; 5];
+		for (x, parity) in column.iter_mut().enumerate() {
+			*parity = lanes[x] ^ lanes[x + 5] ^ lanes[x + 10] ^ lanes[x + 15] ^ lanes[x + 20];
+		}
+		for (idx, lane) in lanes.iter_mut().enumerate() {
+			let x = idx % 5;
+			*lane ^= column[(x + 4) % 5] ^ column[(x + 1) % 5].rotate_left(1);
+		}
+
+		// Rho and Pi
+		let mut last = lanes[1];
+		for (pi, rho) in PI.iter().zip(RHO.iter()) {
+			let tmp = lanes[*pi];
+			lanes[*pi] = last.rotate_left(*rho);
+			last = tmp;
+		}
+
+		// Chi
+		for row in lanes.chunks_mut(5) {
+			let mut tmp = [0u64; 5];
+			tmp.copy_from_slice(row);
+			for (x, lane) in row.iter_mut().enumerate() {
+				*lane = tmp[x] ^ (!tmp[(x + 1) % 5] & tmp[(x + 2) % 5]);
+			}
+		}
+
+		// Iota
+		lanes[0] ^= rc;
+	}
+}
+
+/// The `left_encode()` of the NIST SP 800-185 section 2.3.1, written to
+/// `dst` and returning the amount of bytes written.
+fn left_encode(value: u128, dst: &mut [u8; 17]) -> usize {
+	let bytes = value.to_be_bytes();
+	let skip = bytes.iter().take(15).take_while(|byte| **byte == 0).count();
+	let n = 16 - skip;
+	dst[0] = n as u8;
+	dst[1..=n].copy_from_slice(&bytes[skip..]);
+
+	n + 1
+}
+
+/// The `right_encode()` of the NIST SP 800-185 section 2.3.1, written to
+/// `dst` and returning the amount of bytes written.
+pub(crate) fn right_encode(value: u128, dst: &mut [u8; 17]) -> usize {
+	let bytes = value.to_be_bytes();
+	let skip = bytes.iter().take(15).take_while(|byte| **byte == 0).count();
+	let n = 16 - skip;
+	dst[..n].copy_from_slice(&bytes[skip..]);
+	dst[n] = n as u8;
+
+	n + 1
+}
+
+#[derive(Clone)]
+/// SHAKE256 and cSHAKE256 streaming state.
+pub struct Shake256 {
+	working_state: [u64; 25],
+	initial_state: [u64; 25],
+	buffer: [u8; SHAKE256_RATE],
+	leftover: usize,
+	pad: u8,
+	is_squeezing: bool,
+}
+
+impl Drop for Shake256 {
+	fn drop(&mut self) {
+		use zeroize::Zeroize;
+		self.working_state.zeroize();
+		self.initial_state.zeroize();
+		self.buffer.zeroize();
+	}
+}
+
+impl core::fmt::Debug for Shake256 {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(
+			f,
+			"Shake256 {{ working_state: [***OMITTED***], initial_state: [***OMITTED***], \
+			 buffer: [***OMITTED***], leftover: {:?}, pad: {:?}, is_squeezing: {:?} }}",
+			self.leftover, self.pad, self.is_squeezing
+		)
+	}
+}
+
+impl Shake256 {
+	/// Absorb a full block of `data` into the state.
+	fn absorb_block(&mut self, data: &[u8]) {
+		debug_assert!(data.len() == SHAKE256_RATE);
+		let mut block = [0u64; SHAKE256_RATE / 8];
+		load_u64_into_le(data, &mut block);
+		for (lane, word) in self.working_state.iter_mut().zip(block.iter()) {
+			*lane ^= word;
+		}
+		keccak_f(&mut self.working_state);
+	}
+
+	/// Absorb `data` into the state.
+	fn absorb(&mut self, data: &[u8]) {
+		let mut bytes = data;
+
+		if self.leftover != 0 {
+			debug_assert!(self.leftover <= SHAKE256_RATE);
+
+			let mut want = SHAKE256_RATE - self.leftover;
+			if want > bytes.len() {
+				want = bytes.len();
+			}
+
+			self.buffer[self.leftover..self.leftover + want].copy_from_slice(&bytes[..want]);
+
+			// Reduce by slice
+			bytes = &bytes[want..];
+			self.leftover += want;
+
+			if self.leftover < SHAKE256_RATE {
+				return;
+			}
+
+			let block = self.buffer;
+			self.absorb_block(&block);
+			self.leftover = 0;
+		}
+
+		while bytes.len() >= SHAKE256_RATE {
+			self.absorb_block(&bytes[..SHAKE256_RATE]);
+			// Reduce by slice
+			bytes = &bytes[SHAKE256_RATE..];
+		}
+
+		if !bytes.is_empty() {
+			debug_assert!(self.leftover == 0);
+
+			self.buffer[..bytes.len()].copy_from_slice(bytes);
+			self.leftover = bytes.len();
+		}
+	}
+
+	/// Absorb `bytepad(encode_string(strings[0]) || .. , 136)` as specified in
+	/// the NIST SP 800-185 section 2.3.
+	pub(crate) fn absorb_bytepad(&mut self, strings: &[&[u8]]) {
+		debug_assert!(!self.is_squeezing);
+		debug_assert!(self.leftover == 0);
+
+		let mut encoded = [0u8; 17];
+		let n = left_encode(SHAKE256_RATE as u128, &mut encoded);
+		self.absorb(&encoded[..n]);
+		for string in strings.iter() {
+			let n = left_encode(string.len() as u128 * 8, &mut encoded);
+			self.absorb(&encoded[..n]);
+			self.absorb(string);
+		}
+		if self.leftover != 0 {
+			let zeros = [0u8; SHAKE256_RATE];
+			self.absorb(&zeros[..SHAKE256_RATE - self.leftover]);
+		}
+	}
+
+	/// Pad the buffered data and absorb it, then start squeezing.
+	fn pad_and_permute(&mut self) {
+		debug_assert!(self.leftover < SHAKE256_RATE);
+		for itm in self.buffer.iter_mut().skip(self.leftover) {
+			*itm = 0;
+		}
+		self.buffer[self.leftover] ^= self.pad;
+		self.buffer[SHAKE256_RATE - 1] ^= 0x80;
+
+		let block = self.buffer;
+		self.absorb_block(&block);
+		store_u64_into_le(&self.working_state[..SHAKE256_RATE / 8], &mut self.buffer);
+		self.leftover = 0;
+		self.is_squeezing = true;
+	}
+
+	/// Reset to `init()` or `init_customized()` state.
+	pub fn reset(&mut self) {
+		self.working_state = self.initial_state;
+		self.buffer = [0u8; SHAKE256_RATE];
+		self.leftover = 0;
+		self.is_squeezing = false;
+	}
+
+	#[must_use]
+	/// Update state with `data`. This can be called multiple times.
+	pub fn update(&mut self, data: &[u8]) -> Result<(), UnknownCryptoError> {
+		if self.is_squeezing {
+			return Err(UnknownCryptoError::FinalizedState);
+		}
+		if data.is_empty() {
+			return Ok(());
+		}
+
+		self.absorb(data);
+
+		Ok(())
+	}
+
+	#[must_use]
+	/// Write the next `dst_out.len()` bytes of output to `dst_out`. This can
+	/// be called multiple times.
+	pub fn squeeze(&mut self, dst_out: &mut [u8]) -> Result<(), UnknownCryptoError> {
+		if dst_out.is_empty() {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+		if !self.is_squeezing {
+			self.pad_and_permute();
+		}
+
+		let mut written = 0;
+		while written < dst_out.len() {
+			if self.leftover == SHAKE256_RATE {
+				keccak_f(&mut self.working_state);
+				store_u64_into_le(&self.working_state[..SHAKE256_RATE / 8], &mut self.buffer);
+				self.leftover = 0;
+			}
+
+			let want = core::cmp::min(dst_out.len() - written, SHAKE256_RATE - self.leftover);
+			dst_out[written..written + want]
+				.copy_from_slice(&self.buffer[self.leftover..self.leftover + want]);
+			self.leftover += want;
+			written += want;
+		}
+
+		Ok(())
+	}
+}
+
+#[must_use]
+/// Initialize a `Shake256` struct for SHAKE256.
+pub fn init() -> Shake256 {
+	Shake256 {
+		working_state: [0u64; 25],
+		initial_state: [0u64; 25],
+		buffer: [0u8; SHAKE256_RATE],
+		leftover: 0,
+		pad: SHAKE_PAD,
+		is_squeezing: false,
+	}
+}
+
+#[must_use]
+/// Initialize a `Shake256` struct for cSHAKE256, with the function-name
+/// `function_name` and the customization string `customization`.
+pub fn init_customized(function_name: &[u8], customization: &[u8]) -> Shake256 {
+	let mut state = init();
+	if function_name.is_empty() && customization.is_empty() {
+		return state;
+	}
+
+	state.pad = CSHAKE_PAD;
+	state.absorb_bytepad(&[function_name, customization]);
+	state.initial_state = state.working_state;
+
+	state
+}
+
+#[must_use]
+/// Calculate the SHAKE256 output of some `data`, writing it to `dst_out`.
+pub fn digest(data: &[u8], dst_out: &mut [u8]) -> Result<(), UnknownCryptoError> {
+	let mut state = init();
+	state.update(data)?;
+	state.squeeze(dst_out)
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+#[cfg(feature = "safe_api")]
+mod public {
+	use super::*;
+
+	fn squeezed(state: &mut Shake256, len: usize) -> Vec<u8> {
+		let mut out = vec![0u8; len];
+		state.squeeze(&mut out).unwrap();
+		out
+	}
+
+	mod test_digest {
+		use super::*;
+
+		#[test]
+		fn test_known() {
+			// Computed with Python's hashlib.shake_256.
+			let mut out = [0u8; 64];
+			digest(b"", &mut out).unwrap();
+			assert_eq!(
+				out[..],
+				::hex::decode(
+					"46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762f\
+					 d75dc4ddd8c0f200cb05019d67b592f6fc821c49479ab48640292eacb3b7c4be"
+				)
+				.unwrap()[..]
+			);
+
+			// More than one block of output.
+			let mut out = [0u8; 200];
+			digest(b"abc", &mut out).unwrap();
+			assert_eq!(
+				out[..],
+				::hex::decode(
+					"483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739\
+					 d5a15bef186a5386c75744c0527e1faa9f8726e462a12a4feb06bd8801e751e4\
+					 1385141204f329979fd3047a13c5657724ada64d2470157b3cdc288620944d78\
+					 dbcddbd912993f0913f164fb2ce95131a2d09a3e6d51cbfc622720d7a75c6334\
+					 e8a2d7ec71a7cc29cf0ea610eeff1a588290a53000faa79932becec0bd3cd0b3\
+					 3a7e5d397fed1ada9442b99903f4dcfd8559ed3950faf40fe6f3b5d710ed3b67\
+					 7513771af6bfe119"
+				)
+				.unwrap()[..]
+			);
+		}
+
+		#[test]
+		fn test_empty_dst_err() {
+			assert!(digest(b"", &mut [0u8; 0]).is_err());
+		}
+	}
+
+	mod test_init_customized {
+		use super::*;
+
+		#[test]
+		fn test_nist_samples() {
+			// cSHAKE256 samples #3 and #4 from NIST.
+			let mut state = init_customized(b"", b"Email Signature");
+			state.update(&[0x00, 0x01, 0x02, 0x03]).unwrap();
+			assert_eq!(
+				squeezed(&mut state, 64),
+				::hex::decode(
+					"d008828e2b80ac9d2218ffee1d070c48b8e4c87bff32c9699d5b6896eee0edd1\
+					 64020e2be0560858d9c00c037e34a96937c561a74c412bb4c746469527281c8c"
+				)
+				.unwrap()
+			);
+
+			let data: Vec<u8> = (0u8..200).collect();
+			let mut state = init_customized(b"", b"Email Signature");
+			state.update(&data).unwrap();
+			assert_eq!(
+				squeezed(&mut state, 64),
+				::hex::decode(
+					"07dc27b11e51fbac75bc7b3c1d983e8b4b85fb1defaf218912ac864302730917\
+					 27f42b17ed1df63e8ec118f04b23633c1dfb1574c8fb55cb45da8e25afb092bb"
+				)
+				.unwrap()
+			);
+		}
+
+		#[test]
+		fn test_empty_strings_is_shake() {
+			let mut state = init_customized(b"", b"");
+			state.update(b"abc").unwrap();
+			let mut out = [0u8; 64];
+			digest(b"abc", &mut out).unwrap();
+			assert_eq!(squeezed(&mut state, 64), &out[..]);
+		}
+
+		#[test]
+		fn test_different_strings_differ() {
+			let mut state_1 = init_customized(b"a", b"");
+			let mut state_2 = init_customized(b"", b"a");
+			let mut state_3 = init_customized(b"", b"b");
+			let mut state_4 = init();
+
+			let out_1 = squeezed(&mut state_1, 64);
+			let out_2 = squeezed(&mut state_2, 64);
+			let out_3 = squeezed(&mut state_3, 64);
+			let out_4 = squeezed(&mut state_4, 64);
+			assert_ne!(out_1, out_2);
+			assert_ne!(out_2, out_3);
+			assert_ne!(out_1, out_4);
+			assert_ne!(out_2, out_4);
+		}
+	}
+
+	mod test_update {
+		use super::*;
+
+		#[test]
+		fn test_update_after_squeeze_err() {
+			let mut state = init();
+			state.update(b"Test").unwrap();
+			let _ = squeezed(&mut state, 32);
+			assert!(state.update(b"Test").is_err());
+		}
+
+		#[test]
+		fn test_update_after_squeeze_with_reset_ok() {
+			let mut state = init();
+			state.update(b"Test").unwrap();
+			let _ = squeezed(&mut state, 32);
+			state.reset();
+			state.update(b"Test").unwrap();
+		}
+	}
+
+	mod test_squeeze {
+		use super::*;
+
+		#[test]
+		fn test_empty_dst_err() {
+			let mut state = init();
+			assert!(state.squeeze(&mut [0u8; 0]).is_err());
+		}
+
+		#[test]
+		fn test_squeeze_continues_output() {
+			for split in 1..SHAKE256_RATE * 3 {
+				let mut state = init_customized(b"", b"Test");
+				state.update(b"Test").unwrap();
+				let mut output = squeezed(&mut state, split);
+				output.extend_from_slice(&squeezed(&mut state, 1));
+				output.extend_from_slice(&squeezed(&mut state, SHAKE256_RATE * 3 - split));
+
+				let mut state = init_customized(b"", b"Test");
+				state.update(b"Test").unwrap();
+				assert_eq!(output, squeezed(&mut state, SHAKE256_RATE * 3 + 1));
+			}
+		}
+	}
+
+	mod test_reset {
+		use super::*;
+
+		#[test]
+		fn test_reset_keeps_customization() {
+			let mut state = init_customized(b"", b"Test");
+			state.update(b"Wrong data").unwrap();
+			let _ = squeezed(&mut state, 32);
+			state.reset();
+			state.update(b"Test").unwrap();
+
+			let mut other = init_customized(b"", b"Test");
+			other.update(b"Test").unwrap();
+			assert_eq!(squeezed(&mut state, 32), squeezed(&mut other, 32));
+		}
+	}
+
+	mod test_streaming_interface {
+		use super::*;
+
+		#[test]
+		// Test for issues when incrementally processing data
+		// with leftover
+		fn test_streaming_consistency() {
+			for len in 0..SHAKE256_RATE * 4 {
+				let data = vec![0u8; len];
+				let mut state = init();
+				let mut other_data: Vec<u8> = Vec::new();
+
+				other_data.extend_from_slice(&data);
+				state.update(&data).unwrap();
+
+				if data.len() > SHAKE256_RATE {
+					other_data.extend_from_slice(b"");
+					state.update(b"").unwrap();
+				}
+				if data.len() > SHAKE256_RATE * 2 {
+					other_data.extend_from_slice(b"Extra");
+					state.update(b"Extra").unwrap();
+				}
+				if data.len() > SHAKE256_RATE * 3 {
+					other_data.extend_from_slice(&[0u8; 256]);
+					state.update(&[0u8; 256]).unwrap();
+				}
+
+				let mut one_shot = [0u8; 64];
+				digest(&other_data, &mut one_shot).unwrap();
+
+				assert_eq!(squeezed(&mut state, 64), &one_shot[..]);
+			}
+		}
+	}
+}
+
+// Testing private functions in the module.
+#[cfg(test)]
+mod private {
+	use super::*;
+
+	#[test]
+	fn test_left_right_encode() {
+		let mut encoded = [0u8; 17];
+		assert_eq!(left_encode(0, &mut encoded), 2);
+		assert_eq!(encoded[..2], [0x01, 0x00]);
+		assert_eq!(left_encode(136, &mut encoded), 2);
+		assert_eq!(encoded[..2], [0x01, 0x88]);
+		assert_eq!(left_encode(256, &mut encoded), 3);
+		assert_eq!(encoded[..3], [0x02, 0x01, 0x00]);
+
+		assert_eq!(right_encode(0, &mut encoded), 2);
+		assert_eq!(encoded[..2], [0x00, 0x01]);
+		assert_eq!(right_encode(512, &mut encoded), 3);
+		assert_eq!(encoded[..3], [0x02, 0x00, 0x02]);
+		assert_eq!(right_encode(u128::max_value(), &mut encoded), 17);
+		assert_eq!(encoded[16], 16);
+	}
+}
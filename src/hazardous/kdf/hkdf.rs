@@ -91,10 +91,10 @@ pub fn expand(
 	dst_out: &mut [u8],
 ) -> Result<(), UnknownCryptoError> {
 	if dst_out.len() > 16320 {
-		return Err(UnknownCryptoError);
+		return Err(UnknownCryptoError::InvalidLength);
 	}
 	if dst_out.is_empty() {
-		return Err(UnknownCryptoError);
+		return Err(UnknownCryptoError::InvalidLength);
 	}
 
 	let optional_info = match info {
@@ -217,6 +217,39 @@ mod public {
 		}
 	}
 
+	#[cfg(feature = "safe_api")]
+	mod test_kdf_interface {
+		use super::*;
+		use crate::test_framework::kdf_interface::KdfTestRunner;
+
+		fn derive(
+			ikm: &[u8],
+			salt: &[u8],
+			info: &[u8],
+			dst_out: &mut [u8],
+		) -> Result<(), UnknownCryptoError> {
+			derive_key(salt, ikm, Some(info), dst_out)
+		}
+
+		#[test]
+		fn test_kdf_interface() {
+			KdfTestRunner(derive, b"ikm", b"salt", Some(b"info"), 1, Some(16320));
+			KdfTestRunner(derive, b"ikm", b"salt", Some(b""), 1, Some(16320));
+		}
+
+		quickcheck! {
+			fn prop_kdf_interface(ikm: Vec<u8>, salt: Vec<u8>, info: Vec<u8>) -> bool {
+				if ikm.is_empty() || salt.is_empty() {
+					return true;
+				}
+				// Will panic on incorrect results.
+				KdfTestRunner(derive, &ikm, &salt, Some(&info), 1, Some(16320));
+
+				true
+			}
+		}
+	}
+
 	mod test_verify {
 		use super::*;
 
@@ -0,0 +1,224 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! # Parameters:
+//! - `secret_key`: The secret key to derive from.
+//! - `label`: A string naming the purpose of the derived key.
+//! - `context`: Context and application-specific information, such as the
+//!   identities of the parties.
+//! - `dst_out`: Destination buffer for the derived key. The length of the
+//!   derived key is implied by the length of `dst_out`.
+//! - `expected`: The expected derived key.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - The length of `secret_key` is less than 1.
+//! - The length of `dst_out` is less than 1.
+//! - The derived key does not match the expected when verifying.
+//!
+//! # About:
+//! - This is the KDF in KMAC mode of the NIST SP 800-108r1, using KMAC256:
+//!   `KMAC256(secret_key, context, dst_out.len() * 8, label)`. It can be used
+//!   in place of [HKDF] where a protocol specifies a KDF built on SHA-3.
+//! - `label` is used as the customization string of KMAC256, so keys derived
+//!   with different labels are unrelated.
+//! - The length of the derived key is part of the input, so a shorter key
+//!   is not a prefix of a longer key derived from the same input.
+//! - There is no upper limit on the length of the derived key.
+//!
+//! # Security:
+//! - `secret_key` must be uniformly random, such as the output of a key
+//!   exchange passed through a hash function, or a key generated with a
+//!   CSPRNG. It should be at least 32 bytes.
+//! - This KDF is not suitable for password storage.
+//!
+//! # Example:
+//! ```rust
+//! use orion::{hazardous::kdf::kmac, util};
+//!
+//! let mut secret_key = [0u8; 32];
+//! util::secure_rand_bytes(&mut secret_key)?;
+//!
+//! let mut encryption_key = [0u8; 32];
+//! kmac::derive_key(&secret_key, b"encryption", b"Alice to Bob", &mut encryption_key)?;
+//! let mut authentication_key = [0u8; 64];
+//! kmac::derive_key(&secret_key, b"authentication", b"Alice to Bob", &mut authentication_key)?;
+//!
+//! let expected = encryption_key;
+//! assert!(kmac::verify(
+//! 	&expected,
+//! 	&secret_key,
+//! 	b"encryption",
+//! 	b"Alice to Bob",
+//! 	&mut encryption_key
+//! )?);
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [HKDF]: https://docs.rs/orion/latest/orion/hazardous/kdf/hkdf/index.html
+
+use crate::{
+	errors::UnknownCryptoError,
+	hazardous::hash::shake256::{self, right_encode},
+	util,
+};
+
+/// The function-name of KMAC, as specified in the NIST SP 800-185.
+const KMAC_FUNCTION_NAME: &[u8] = b"KMAC";
+
+#[must_use]
+/// Derive a key from `secret_key`, `label` and `context`.
+pub fn derive_key(
+	secret_key: &[u8],
+	label: &[u8],
+	context: &[u8],
+	dst_out: &mut [u8],
+) -> Result<(), UnknownCryptoError> {
+	if secret_key.is_empty() || dst_out.is_empty() {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let mut state = shake256::init_customized(KMAC_FUNCTION_NAME, label);
+	state.absorb_bytepad(&[secret_key]);
+	state.update(context)?;
+	let mut encoded = [0u8; 17];
+	let n = right_encode(dst_out.len() as u128 * 8, &mut encoded);
+	state.update(&encoded[..n])?;
+
+	state.squeeze(dst_out)
+}
+
+#[must_use]
+/// Verify a derived key in constant time.
+pub fn verify(
+	expected: &[u8],
+	secret_key: &[u8],
+	label: &[u8],
+	context: &[u8],
+	dst_out: &mut [u8],
+) -> Result<bool, UnknownCryptoError> {
+	derive_key(secret_key, label, context, dst_out)?;
+	util::secure_cmp(dst_out, expected)
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	const SECRET_KEY: [u8; 32] = [
+		0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e,
+		0x4f, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5a, 0x5b, 0x5c, 0x5d,
+		0x5e, 0x5f,
+	];
+
+	mod test_derive_key {
+		use super::*;
+
+		#[test]
+		#[cfg(feature = "safe_api")]
+		fn test_nist_samples() {
+			// KMAC256 samples #4, #5 and #6 from NIST.
+			let mut out = [0u8; 64];
+			derive_key(
+				&SECRET_KEY,
+				b"My Tagged Application",
+				&[0x00, 0x01, 0x02, 0x03],
+				&mut out,
+			)
+			.unwrap();
+			assert_eq!(
+				out[..],
+				::hex::decode(
+					"20c570c31346f703c9ac36c61c03cb64c3970d0cfc787e9b79599d273a68d2f7\
+					 f69d4cc3de9d104a351689f27cf6f5951f0103f33f4f24871024d9c27773a8dd"
+				)
+				.unwrap()[..]
+			);
+
+			let context: Vec<u8> = (0u8..200).collect();
+			derive_key(&SECRET_KEY, b"", &context, &mut out).unwrap();
+			assert_eq!(
+				out[..],
+				::hex::decode(
+					"75358cf39e41494e949707927cee0af20a3ff553904c86b08f21cc414bcfd691\
+					 589d27cf5e15369cbbff8b9a4c2eb17800855d0235ff635da82533ec6b759b69"
+				)
+				.unwrap()[..]
+			);
+
+			derive_key(&SECRET_KEY, b"My Tagged Application", &context, &mut out).unwrap();
+			assert_eq!(
+				out[..],
+				::hex::decode(
+					"b58618f71f92e1d56c1b8c55ddd7cd188b97b4ca4d99831eb2699a837da2e4d9\
+					 70fbacfde50033aea585f1a2708510c32d07880801bd182898fe476876fc8965"
+				)
+				.unwrap()[..]
+			);
+		}
+
+		#[test]
+		fn test_empty_err() {
+			let mut out = [0u8; 32];
+			assert!(derive_key(&[], b"Label", b"Context", &mut out).is_err());
+			assert!(derive_key(&SECRET_KEY, b"Label", b"Context", &mut [0u8; 0]).is_err());
+			assert!(derive_key(&SECRET_KEY, b"", b"", &mut out).is_ok());
+		}
+
+		#[test]
+		fn test_length_is_not_prefix() {
+			let mut short = [0u8; 32];
+			let mut long = [0u8; 64];
+			derive_key(&SECRET_KEY, b"Label", b"Context", &mut short).unwrap();
+			derive_key(&SECRET_KEY, b"Label", b"Context", &mut long).unwrap();
+			assert_ne!(short[..], long[..32]);
+		}
+
+		#[test]
+		fn test_label_and_context_separate() {
+			let mut out_1 = [0u8; 32];
+			let mut out_2 = [0u8; 32];
+			let mut out_3 = [0u8; 32];
+			derive_key(&SECRET_KEY, b"Label", b"Context", &mut out_1).unwrap();
+			derive_key(&SECRET_KEY, b"Context", b"Label", &mut out_2).unwrap();
+			derive_key(&SECRET_KEY, b"LabelC", b"ontext", &mut out_3).unwrap();
+			assert_ne!(out_1, out_2);
+			assert_ne!(out_1, out_3);
+			assert_ne!(out_2, out_3);
+		}
+	}
+
+	mod test_verify {
+		use super::*;
+
+		#[test]
+		fn test_verify() {
+			let mut out = [0u8; 32];
+			derive_key(&SECRET_KEY, b"Label", b"Context", &mut out).unwrap();
+			let expected = out;
+
+			assert!(verify(&expected, &SECRET_KEY, b"Label", b"Context", &mut out).unwrap());
+			assert!(verify(&expected, &SECRET_KEY, b"Label", b"Other", &mut out).is_err());
+			assert!(verify(&expected, &SECRET_KEY[..31], b"Label", b"Context", &mut out).is_err());
+			assert!(verify(&expected, &SECRET_KEY, b"Label", b"Context", &mut [0u8; 16]).is_err());
+		}
+	}
+}
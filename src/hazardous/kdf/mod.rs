@@ -20,8 +20,17 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+/// bcrypt_pbkdf, the password-based key derivation function used by OpenSSH, as specified in [OpenBSD](https://github.com/openbsd/src/blob/master/lib/libutil/bcrypt_pbkdf.c).
+pub mod bcrypt_pbkdf;
+
 /// HKDF-HMAC-SHA512 (HMAC-based Extract-and-Expand Key Derivation Function) as specified in the [RFC 5869](https://tools.ietf.org/html/rfc5869).
 pub mod hkdf;
 
+/// KDF in KMAC mode, using KMAC256, as specified in the [NIST SP 800-108r1](https://nvlpubs.nist.gov/nistpubs/SpecialPublications/NIST.SP.800-108r1.pdf).
+pub mod kmac;
+
 /// PBKDF2-HMAC-SHA512 (Password-Based Key Derivation Function 2) as specified in the [RFC 8018](https://tools.ietf.org/html/rfc8018).
 pub mod pbkdf2;
+
+/// SLIP-10 (BIP32 hierarchical deterministic key derivation) as specified in [SLIP-0010](https://github.com/satoshilabs/slips/blob/master/slip-0010.md).
+pub mod slip10;
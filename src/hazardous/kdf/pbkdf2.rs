@@ -33,9 +33,6 @@
 //! - The length of `dst_out` is less than 1.
 //! - The specified iteration count is less than 1.
 //! - The hashed password does not match the expected when verifying.
-//!
-//! # Panics:
-//! A panic will occur if:
 //! - The length of `dst_out` is greater than (2^32 - 1) * 64.
 //!
 //! # Security:
@@ -71,6 +68,8 @@
 //! [`Password::generate()`]: https://docs.rs/orion/latest/orion/hazardous/kdf/pbkdf2/struct.Password.html
 //! [`util::secure_rand_bytes()`]: https://docs.rs/orion/latest/orion/util/fn.secure_rand_bytes.html
 
+use core::convert::TryFrom;
+
 use crate::{
 	errors::UnknownCryptoError,
 	hazardous::{
@@ -139,10 +138,10 @@ pub fn derive_key(
 	dst_out: &mut [u8],
 ) -> Result<(), UnknownCryptoError> {
 	if iterations < 1 {
-		return Err(UnknownCryptoError);
+		return Err(UnknownCryptoError::ParameterOutOfRange);
 	}
 	if dst_out.is_empty() {
-		return Err(UnknownCryptoError);
+		return Err(UnknownCryptoError::InvalidLength);
 	}
 
 	let mut hmac = hmac::init(&hmac::SecretKey::from_slice(
@@ -150,8 +149,11 @@ pub fn derive_key(
 	)?);
 
 	for (idx, dk_block) in dst_out.chunks_mut(SHA512_OUTSIZE).enumerate() {
-		// If this panics, then the size limit for PBKDF2 is reached.
-		let block_idx = (1u32).checked_add(idx as u32).unwrap();
+		// The size limit for PBKDF2 is reached.
+		let block_idx = u32::try_from(idx)
+			.ok()
+			.and_then(|idx| idx.checked_add(1))
+			.ok_or(UnknownCryptoError::SizeLimitExceeded)?;
 
 		function_f(
 			salt,
@@ -262,6 +264,38 @@ mod public {
 		}
 	}
 
+	#[cfg(feature = "safe_api")]
+	mod test_kdf_interface {
+		use super::*;
+		use crate::test_framework::kdf_interface::KdfTestRunner;
+
+		fn derive(
+			ikm: &[u8],
+			salt: &[u8],
+			_info: &[u8],
+			dst_out: &mut [u8],
+		) -> Result<(), UnknownCryptoError> {
+			derive_key(&Password::from_slice(ikm)?, salt, 10, dst_out)
+		}
+
+		#[test]
+		fn test_kdf_interface() {
+			KdfTestRunner(derive, b"password", b"salt", None, 1, None);
+		}
+
+		quickcheck! {
+			fn prop_kdf_interface(password: Vec<u8>, salt: Vec<u8>) -> bool {
+				if password.is_empty() || salt.is_empty() {
+					return true;
+				}
+				// Will panic on incorrect results.
+				KdfTestRunner(derive, &password, &salt, None, 1, None);
+
+				true
+			}
+		}
+	}
+
 	mod test_derive_key {
 		use super::*;
 
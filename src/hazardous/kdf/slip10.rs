@@ -0,0 +1,451 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Parameters:
+//! - `curve`: The curve that the derived keys are used with.
+//! - `seed`: The master seed, such as the output of a BIP39 mnemonic.
+//! - `index`: The child index. Indices starting at [`HARDENED_OFFSET`] are
+//!   hardened.
+//! - `path`: A derivation path such as `m/44'/0'/0'`. Hardened indices are
+//!   marked with either `'` or `h`.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - The length of `seed` is less than 16 or greater than 64 bytes.
+//! - A non-hardened index is used with [`Curve::Ed25519`].
+//! - `path` does not start with `m`, has empty components or contains an index
+//!   that is not less than [`HARDENED_OFFSET`].
+//!
+//! # Security:
+//! - The `seed` and every [`ExtendedPrivateKey`] must be kept secret. This
+//!   includes the chain code, which together with a non-hardened child
+//!   private key reveals the parent private key.
+//! - Prefer hardened derivation whenever child private keys may be exposed.
+//!
+//! # About:
+//! This implements the private key derivation of [SLIP-10], which generalizes
+//! BIP32 to other curves:
+//! - [`Curve::Ed25519`] only supports hardened derivation. The derived private
+//!   key is an Ed25519 seed.
+//! - [`Curve::NistP256`] supports both hardened and non-hardened derivation.
+//!   The derived private key can be used with [`ecdsa_p256::SecretKey`].
+//!
+//! secp256k1 (BIP32) is not supported.
+//!
+//! # Example:
+//! ```rust
+//! use orion::hazardous::kdf::slip10::{Curve, DerivationPath, ExtendedPrivateKey};
+//!
+//! let seed = [0x42; 64];
+//! let master = ExtendedPrivateKey::from_seed(Curve::Ed25519, &seed)?;
+//! let path = DerivationPath::parse("m/44'/0'/0'")?;
+//! let account = master.derive_path(&path)?;
+//!
+//! assert!(account.private_key() != master.private_key());
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`HARDENED_OFFSET`]: https://docs.rs/orion/latest/orion/hazardous/kdf/slip10/constant.HARDENED_OFFSET.html
+//! [`Curve::Ed25519`]: https://docs.rs/orion/latest/orion/hazardous/kdf/slip10/enum.Curve.html
+//! [`Curve::NistP256`]: https://docs.rs/orion/latest/orion/hazardous/kdf/slip10/enum.Curve.html
+//! [`ExtendedPrivateKey`]: https://docs.rs/orion/latest/orion/hazardous/kdf/slip10/struct.ExtendedPrivateKey.html
+//! [`ecdsa_p256::SecretKey`]: https://docs.rs/orion/latest/orion/hazardous/ecc/ecdsa_p256/struct.SecretKey.html
+//! [SLIP-10]: https://github.com/satoshilabs/slips/blob/master/slip-0010.md
+
+use crate::{
+	errors::UnknownCryptoError,
+	hazardous::{
+		ecc::p256::{self, ProjectivePoint, N},
+		hash::sha512::SHA512_OUTSIZE,
+		mac::hmac,
+	},
+};
+use zeroize::Zeroize;
+
+/// The first hardened child index.
+pub const HARDENED_OFFSET: u32 = 1 << 31;
+/// The size of a derived private key.
+pub const PRIVATE_KEY_SIZE: usize = 32;
+/// The size of a chain code.
+pub const CHAIN_CODE_SIZE: usize = 32;
+/// The minimum size of a master seed.
+const MIN_SEED_SIZE: usize = 16;
+/// The maximum size of a master seed.
+const MAX_SEED_SIZE: usize = 64;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// The curves that keys can be derived for.
+pub enum Curve {
+	/// Ed25519, hardened derivation only.
+	Ed25519,
+	/// NIST P-256 (secp256r1).
+	NistP256,
+}
+
+impl Curve {
+	/// The HMAC key used to derive the master key from a seed.
+	fn seed_key(self) -> &'static [u8] {
+		match self {
+			Curve::Ed25519 => b"ed25519 seed",
+			Curve::NistP256 => b"Nist256p1 seed",
+		}
+	}
+}
+
+construct_secret_key! {
+	/// A type to represent a derived `PrivateKey`.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 32 bytes.
+	///
+	/// # Panics:
+	/// A panic will occur if:
+	/// - Failure to generate random bytes securely.
+	(PrivateKey, test_private_key, PRIVATE_KEY_SIZE, PRIVATE_KEY_SIZE, PRIVATE_KEY_SIZE)
+}
+
+impl_from_trait!(PrivateKey, PRIVATE_KEY_SIZE);
+
+construct_secret_key! {
+	/// A type to represent the `ChainCode` of an extended private key.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 32 bytes.
+	///
+	/// # Panics:
+	/// A panic will occur if:
+	/// - Failure to generate random bytes securely.
+	(ChainCode, test_chain_code, CHAIN_CODE_SIZE, CHAIN_CODE_SIZE, CHAIN_CODE_SIZE)
+}
+
+impl_from_trait!(ChainCode, CHAIN_CODE_SIZE);
+
+/// A derivation path, such as `m/44'/0'/0'`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DerivationPath<'a> {
+	path: &'a str,
+}
+
+impl<'a> DerivationPath<'a> {
+	#[must_use]
+	/// Parse and validate a derivation path.
+	pub fn parse(path: &'a str) -> Result<Self, UnknownCryptoError> {
+		let mut components = path.split('/');
+		if components.next() != Some("m") {
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+		for component in components {
+			parse_index(component)?;
+		}
+
+		Ok(Self { path })
+	}
+
+	/// The child indices of this path, in order of derivation.
+	pub fn indices(&self) -> impl Iterator<Item = u32> + 'a {
+		// The path has been validated by parse().
+		self.path
+			.split('/')
+			.skip(1)
+			.filter_map(|component| parse_index(component).ok())
+	}
+}
+
+/// Parse a single path component, such as `44'`.
+fn parse_index(component: &str) -> Result<u32, UnknownCryptoError> {
+	let (digits, offset) = match component.as_bytes().last() {
+		Some(b'\'') | Some(b'h') => (&component[..component.len() - 1], HARDENED_OFFSET),
+		_ => (component, 0),
+	};
+	// Reject signs, which u32::from_str accepts, and leading zeros.
+	if digits.is_empty()
+		|| !digits.bytes().all(|byte| byte.is_ascii_digit())
+		|| (digits.len() > 1 && digits.starts_with('0'))
+	{
+		return Err(UnknownCryptoError::InvalidEncoding);
+	}
+
+	match digits.parse::<u32>() {
+		Ok(index) if index < HARDENED_OFFSET => Ok(index + offset),
+		_ => Err(UnknownCryptoError::InvalidEncoding),
+	}
+}
+
+/// HMAC-SHA512 over the concatenation of `data`.
+fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> Result<[u8; SHA512_OUTSIZE], UnknownCryptoError> {
+	let mut state = hmac::init(&hmac::SecretKey::from_slice(key)?);
+	for part in data.iter() {
+		state.update(part)?;
+	}
+
+	let mut out = [0u8; SHA512_OUTSIZE];
+	out.copy_from_slice(state.finalize()?.unprotected_as_bytes());
+
+	Ok(out)
+}
+
+/// The compressed SEC1 encoding of the public key for a P-256 private key.
+fn p256_compressed_public_key(private_key: &[u8]) -> Result<[u8; 33], UnknownCryptoError> {
+	let mut scalar = p256::scalar_from_bytes(private_key)?;
+	let point = ProjectivePoint::generator().mul(&scalar);
+	scalar.zeroize();
+	let (x, y) = point.to_affine()?;
+
+	let mut encoded = [0u8; 33];
+	encoded[0] = 0x02 | (y[0] & 1) as u8;
+	encoded[1..].copy_from_slice(&p256::limbs_to_bytes(&x));
+
+	Ok(encoded)
+}
+
+/// An extended private key: a private key and its chain code.
+pub struct ExtendedPrivateKey {
+	curve: Curve,
+	private_key: PrivateKey,
+	chain_code: ChainCode,
+}
+
+impl core::fmt::Debug for ExtendedPrivateKey {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(
+			f,
+			"ExtendedPrivateKey {{ curve: {:?}, private_key: ***OMITTED***, chain_code: ***OMITTED*** }}",
+			self.curve
+		)
+	}
+}
+
+impl ExtendedPrivateKey {
+	/// Split the HMAC output into a private key and chain code.
+	fn from_hmac_output(
+		curve: Curve,
+		output: &[u8; SHA512_OUTSIZE],
+	) -> Result<Self, UnknownCryptoError> {
+		Ok(Self {
+			curve,
+			private_key: PrivateKey::from_slice(&output[..PRIVATE_KEY_SIZE])?,
+			chain_code: ChainCode::from_slice(&output[PRIVATE_KEY_SIZE..])?,
+		})
+	}
+
+	#[must_use]
+	/// Derive the master key from a seed.
+	pub fn from_seed(curve: Curve, seed: &[u8]) -> Result<Self, UnknownCryptoError> {
+		if seed.len() < MIN_SEED_SIZE || seed.len() > MAX_SEED_SIZE {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+
+		let mut output = hmac_sha512(curve.seed_key(), &[seed])?;
+		if curve == Curve::NistP256 {
+			// Retry until the key is in [1, n - 1].
+			while p256::scalar_from_bytes(&output[..PRIVATE_KEY_SIZE]).is_err() {
+				let retry = hmac_sha512(curve.seed_key(), &[&output])?;
+				output.zeroize();
+				output = retry;
+			}
+		}
+
+		let master = Self::from_hmac_output(curve, &output);
+		output.zeroize();
+
+		master
+	}
+
+	#[must_use]
+	/// Derive the child key at `index`.
+	pub fn derive_child(&self, index: u32) -> Result<Self, UnknownCryptoError> {
+		let index_bytes = index.to_be_bytes();
+		let chain_code = self.chain_code.unprotected_as_bytes();
+		let parent_key = self.private_key.unprotected_as_bytes();
+
+		let mut output = if index >= HARDENED_OFFSET {
+			hmac_sha512(chain_code, &[&[0u8], parent_key, &index_bytes])?
+		} else if self.curve == Curve::NistP256 {
+			let public_key = p256_compressed_public_key(parent_key)?;
+			hmac_sha512(chain_code, &[&public_key, &index_bytes])?
+		} else {
+			return Err(UnknownCryptoError::ParameterOutOfRange);
+		};
+
+		if self.curve == Curve::NistP256 {
+			// The child key is (IL + k_par) mod n. Retry if IL is not less
+			// than n or the child key is zero.
+			let mut parent = p256::limbs_from_bytes(parent_key);
+			loop {
+				let tweak = p256::limbs_from_bytes(&output[..PRIVATE_KEY_SIZE]);
+				let mut child = p256::mod_add(&tweak, &parent, &N);
+				let valid = p256::is_reduced(&tweak, &N) & (p256::is_zero(&child) ^ 1);
+				if valid == 1 {
+					output[..PRIVATE_KEY_SIZE].copy_from_slice(&p256::limbs_to_bytes(&child));
+					child.zeroize();
+					break;
+				}
+				child.zeroize();
+
+				let retry = hmac_sha512(
+					chain_code,
+					&[&[1u8], &output[PRIVATE_KEY_SIZE..], &index_bytes],
+				)?;
+				output.zeroize();
+				output = retry;
+			}
+			parent.zeroize();
+		}
+
+		let child = Self::from_hmac_output(self.curve, &output);
+		output.zeroize();
+
+		child
+	}
+
+	#[must_use]
+	/// Derive the key at `path`, relative to this key. `m` refers to this key.
+	pub fn derive_path(&self, path: &DerivationPath) -> Result<Self, UnknownCryptoError> {
+		let mut key = Self {
+			curve: self.curve,
+			private_key: PrivateKey::from_slice(self.private_key.unprotected_as_bytes())?,
+			chain_code: ChainCode::from_slice(self.chain_code.unprotected_as_bytes())?,
+		};
+		for index in path.indices() {
+			key = key.derive_child(index)?;
+		}
+
+		Ok(key)
+	}
+
+	/// The curve of this key.
+	pub fn curve(&self) -> Curve {
+		self.curve
+	}
+
+	/// The private key.
+	pub fn private_key(&self) -> &PrivateKey {
+		&self.private_key
+	}
+
+	/// The chain code.
+	pub fn chain_code(&self) -> &ChainCode {
+		&self.chain_code
+	}
+}
+
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	mod test_path {
+		use super::*;
+
+		#[test]
+		fn test_valid_paths() {
+			assert_eq!(DerivationPath::parse("m").unwrap().indices().count(), 0);
+
+			let path = DerivationPath::parse("m/44'/0h/2147483647'/0/2147483647").unwrap();
+			let mut indices = path.indices();
+			assert_eq!(indices.next(), Some(HARDENED_OFFSET + 44));
+			assert_eq!(indices.next(), Some(HARDENED_OFFSET));
+			assert_eq!(indices.next(), Some(u32::max_value()));
+			assert_eq!(indices.next(), Some(0));
+			assert_eq!(indices.next(), Some(HARDENED_OFFSET - 1));
+			assert_eq!(indices.next(), None);
+		}
+
+		#[test]
+		fn test_invalid_paths() {
+			for path in [
+				"",
+				"M",
+				"m/",
+				"/0",
+				"0/1",
+				"m//0",
+				"m/0/",
+				"m/'",
+				"m/h",
+				"m/+1",
+				"m/-1",
+				"m/01",
+				"m/1''",
+				"m/1H",
+				"m/0x1",
+				"m/2147483648",
+				"m/4294967296'",
+			]
+			.iter()
+			{
+				assert!(DerivationPath::parse(path).is_err(), "{}", path);
+			}
+		}
+	}
+
+	mod test_derivation {
+		use super::*;
+
+		#[test]
+		fn test_seed_length() {
+			for curve in [Curve::Ed25519, Curve::NistP256].iter() {
+				assert!(ExtendedPrivateKey::from_seed(*curve, &[0u8; 15]).is_err());
+				assert!(ExtendedPrivateKey::from_seed(*curve, &[0u8; 16]).is_ok());
+				assert!(ExtendedPrivateKey::from_seed(*curve, &[0u8; 64]).is_ok());
+				assert!(ExtendedPrivateKey::from_seed(*curve, &[0u8; 65]).is_err());
+			}
+		}
+
+		#[test]
+		fn test_ed25519_non_hardened_err() {
+			let master = ExtendedPrivateKey::from_seed(Curve::Ed25519, &[0u8; 32]).unwrap();
+			assert!(master.derive_child(0).is_err());
+			assert!(master.derive_child(HARDENED_OFFSET - 1).is_err());
+			assert!(master.derive_child(HARDENED_OFFSET).is_ok());
+			assert!(master
+				.derive_path(&DerivationPath::parse("m/0'/1").unwrap())
+				.is_err());
+		}
+
+		#[test]
+		fn test_path_matches_children() {
+			let master = ExtendedPrivateKey::from_seed(Curve::NistP256, &[0u8; 32]).unwrap();
+			let expected = master
+				.derive_child(HARDENED_OFFSET + 1)
+				.unwrap()
+				.derive_child(2)
+				.unwrap();
+			let actual = master
+				.derive_path(&DerivationPath::parse("m/1'/2").unwrap())
+				.unwrap();
+
+			assert!(actual.private_key() == expected.private_key());
+			assert!(actual.chain_code() == expected.chain_code());
+			assert_eq!(actual.curve(), Curve::NistP256);
+		}
+
+		#[test]
+		fn test_curves_differ() {
+			let ed25519 = ExtendedPrivateKey::from_seed(Curve::Ed25519, &[0u8; 32]).unwrap();
+			let p256 = ExtendedPrivateKey::from_seed(Curve::NistP256, &[0u8; 32]).unwrap();
+
+			assert!(ed25519.private_key() != p256.private_key());
+			assert!(ed25519.chain_code() != p256.chain_code());
+		}
+	}
+}
@@ -133,14 +133,14 @@ impl Hmac {
 		// .unwrap() here should not be able to panic
 		self.ipad_hasher.update(ipad.as_ref()).unwrap();
 		self.opad_hasher.update(opad.as_ref()).unwrap();
-		self.working_hasher = self.ipad_hasher.clone();
+		self.working_hasher.reset_to(&self.ipad_hasher);
 		ipad.zeroize();
 		opad.zeroize();
 	}
 
 	/// Reset to `init()` state.
 	pub fn reset(&mut self) {
-		self.working_hasher = self.ipad_hasher.clone();
+		self.working_hasher.reset_to(&self.ipad_hasher);
 		self.is_finalized = false;
 	}
 
@@ -148,7 +148,7 @@ impl Hmac {
 	/// Update state with a `data`. This can be called multiple times.
 	pub fn update(&mut self, data: &[u8]) -> Result<(), UnknownCryptoError> {
 		if self.is_finalized {
-			Err(UnknownCryptoError)
+			Err(UnknownCryptoError::FinalizedState)
 		} else {
 			self.working_hasher.update(data)
 		}
@@ -158,13 +158,26 @@ impl Hmac {
 	/// Return a `Tag`.
 	pub fn finalize(&mut self) -> Result<Tag, UnknownCryptoError> {
 		if self.is_finalized {
-			return Err(UnknownCryptoError);
+			return Err(UnknownCryptoError::FinalizedState);
 		}
 
 		self.is_finalized = true;
-		let mut outer_hasher = self.opad_hasher.clone();
-		outer_hasher.update(self.working_hasher.finalize()?.as_ref())?;
-		let tag = Tag::from_slice(outer_hasher.finalize()?.as_ref())?;
+		// The working hasher is reused as the outer hasher, as it is restored
+		// from `ipad_hasher` on reset() anyway.
+		let inner_digest = self.working_hasher.finalize()?;
+		self.working_hasher.reset_to(&self.opad_hasher);
+		self.working_hasher.update(inner_digest.as_ref())?;
+		let tag = Tag::from_slice(self.working_hasher.finalize()?.as_ref())?;
+
+		Ok(tag)
+	}
+
+	#[must_use]
+	/// Return a `Tag` and reset to `init()` state, so that the same state can
+	/// be used for the next message without cloning or re-initializing it.
+	pub fn finalize_reset(&mut self) -> Result<Tag, UnknownCryptoError> {
+		let tag = self.finalize()?;
+		self.reset();
 
 		Ok(tag)
 	}
@@ -187,9 +200,31 @@ pub fn init(secret_key: &SecretKey) -> Hmac {
 #[must_use]
 /// One-shot function for generating an HMAC-SHA512 tag of `data`.
 pub fn hmac(secret_key: &SecretKey, data: &[u8]) -> Result<Tag, UnknownCryptoError> {
-	let mut hmac_state = init(secret_key);
-	hmac_state.update(data)?;
-	hmac_state.finalize()
+	// The streaming state keeps copies of the padded-key hashers, so that it
+	// can be reset. That is not needed here, so the inner and outer hashers
+	// are used directly.
+	let mut ipad = [0x36; SHA512_BLOCKSIZE];
+	let mut opad = [0x5C; SHA512_BLOCKSIZE];
+	// `key` has already been padded with zeroes to a length of SHA512_BLOCKSIZE
+	// in SecretKey::from_slice
+	for (idx, itm) in secret_key.unprotected_as_bytes().iter().enumerate() {
+		opad[idx] ^= itm;
+		ipad[idx] ^= itm;
+	}
+
+	// The size of input to update() is known to be acceptable size,
+	// so .unwrap() here should not be able to panic
+	let mut inner_hasher = sha512::init();
+	let mut outer_hasher = sha512::init();
+	inner_hasher.update(ipad.as_ref()).unwrap();
+	outer_hasher.update(opad.as_ref()).unwrap();
+	ipad.zeroize();
+	opad.zeroize();
+
+	inner_hasher.update(data)?;
+	outer_hasher.update(inner_hasher.finalize()?.as_ref())?;
+
+	Tag::from_slice(outer_hasher.finalize()?.as_ref())
 }
 
 #[must_use]
@@ -205,7 +240,7 @@ pub fn verify(
 	if expected == &hmac_state.finalize()? {
 		Ok(true)
 	} else {
-		Err(UnknownCryptoError)
+		Err(UnknownCryptoError::InvalidTag)
 	}
 }
 
@@ -228,6 +263,83 @@ mod public {
 		assert_eq!(state_1.is_finalized, state_2.is_finalized);
 	}
 
+	#[cfg(feature = "safe_api")]
+	mod test_mac_interface {
+		use super::*;
+		use crate::test_framework::incremental_interface::TestableStreamingContext;
+		use crate::test_framework::mac_interface::{MacTester, TestableMac};
+
+		impl TestableStreamingContext<Tag> for Hmac {
+			fn reset(&mut self) -> Result<(), UnknownCryptoError> {
+				Hmac::reset(self);
+				Ok(())
+			}
+
+			fn update(&mut self, input: &[u8]) -> Result<(), UnknownCryptoError> {
+				Hmac::update(self, input)
+			}
+
+			fn finalize(&mut self) -> Result<Tag, UnknownCryptoError> {
+				Hmac::finalize(self)
+			}
+
+			fn compare_states(state_1: &Hmac, state_2: &Hmac) {
+				compare_hmac_states(state_1, state_2)
+			}
+		}
+
+		impl TestableMac for Hmac {
+			type Key = SecretKey;
+			type Tag = Tag;
+
+			fn init(key: &SecretKey) -> Self {
+				init(key)
+			}
+
+			fn mac(key: &SecretKey, input: &[u8]) -> Result<Tag, UnknownCryptoError> {
+				hmac(key, input)
+			}
+
+			fn verify(
+				expected: &Tag,
+				key: &SecretKey,
+				input: &[u8],
+			) -> Result<(), UnknownCryptoError> {
+				verify(expected, key, input).map(|_| ())
+			}
+
+			fn tag_from_slice(slice: &[u8]) -> Result<Tag, UnknownCryptoError> {
+				Tag::from_slice(slice)
+			}
+
+			fn tag_as_bytes(tag: &Tag) -> &[u8] {
+				tag.unprotected_as_bytes()
+			}
+		}
+
+		fn tester() -> MacTester<Hmac> {
+			MacTester::new(
+				SecretKey::from_slice(&[1u8; 32]).unwrap(),
+				SecretKey::from_slice(&[2u8; 32]).unwrap(),
+				SHA512_BLOCKSIZE,
+			)
+		}
+
+		#[test]
+		fn test_mac_interface() {
+			tester().run_all_tests();
+		}
+
+		quickcheck! {
+			fn prop_mac_interface(data: Vec<u8>) -> bool {
+				// Will panic on incorrect results.
+				tester().run_all_tests_property(&data);
+
+				true
+			}
+		}
+	}
+
 	mod test_verify {
 		use super::*;
 
@@ -376,6 +488,31 @@ mod public {
 			assert!(state.finalize().is_err());
 		}
 
+		#[test]
+		fn test_finalize_reset_same_as_finalize_then_reset() {
+			let sk = SecretKey::from_slice("Jefe".as_bytes()).unwrap();
+			let data = "what do ya want for nothing?".as_bytes();
+
+			let mut state = init(&sk);
+			state.update(data).unwrap();
+			let one = state.finalize_reset().unwrap();
+			state.update(data).unwrap();
+			let two = state.finalize_reset().unwrap();
+			assert_eq!(one, two);
+			assert_eq!(one, hmac(&sk, data).unwrap());
+
+			compare_hmac_states(&state, &init(&sk));
+		}
+
+		#[test]
+		fn test_finalize_reset_after_finalize_err() {
+			let sk = SecretKey::from_slice("Jefe".as_bytes()).unwrap();
+
+			let mut state = init(&sk);
+			state.update(b"Test").unwrap();
+			let _ = state.finalize().unwrap();
+			assert!(state.finalize_reset().is_err());
+		}
 	}
 
 	mod test_streaming_interface {
@@ -167,7 +167,7 @@ impl Poly1305 {
     /// Process a datablock of `POLY1305_BLOCKSIZE` length.
     fn process_block(&mut self, data: &[u8]) -> Result<(), UnknownCryptoError> {
         if data.len() != POLY1305_BLOCKSIZE {
-            return Err(UnknownCryptoError);
+            return Err(UnknownCryptoError::InvalidLength);
         }
 
         let hibit: u32 = if self.is_finalized {
@@ -323,7 +323,7 @@ impl Poly1305 {
 	/// Update state with a `data`. This can be called multiple times.
 	pub fn update(&mut self, data: &[u8]) -> Result<(), UnknownCryptoError> {
 		if self.is_finalized {
-			return Err(UnknownCryptoError);
+			return Err(UnknownCryptoError::FinalizedState);
 		}
 		if data.is_empty() {
 			return Ok(());
@@ -371,7 +371,7 @@ impl Poly1305 {
 	/// Return a Poly1305 tag.
 	pub fn finalize(&mut self) -> Result<Tag, UnknownCryptoError> {
 		if self.is_finalized {
-			return Err(UnknownCryptoError);
+			return Err(UnknownCryptoError::FinalizedState);
 		}
 
 		self.is_finalized = true;
@@ -434,7 +434,7 @@ pub fn verify(
 	if &poly1305(one_time_key, data)? == expected {
 		Ok(true)
 	} else {
-		Err(UnknownCryptoError)
+		Err(UnknownCryptoError::InvalidTag)
 	}
 }
 
@@ -456,6 +456,83 @@ mod public {
 		assert_eq!(state_1.is_finalized, state_2.is_finalized);
 	}
 
+	#[cfg(feature = "safe_api")]
+	mod test_mac_interface {
+		use super::*;
+		use crate::test_framework::incremental_interface::TestableStreamingContext;
+		use crate::test_framework::mac_interface::{MacTester, TestableMac};
+
+		impl TestableStreamingContext<Tag> for Poly1305 {
+			fn reset(&mut self) -> Result<(), UnknownCryptoError> {
+				Poly1305::reset(self);
+				Ok(())
+			}
+
+			fn update(&mut self, input: &[u8]) -> Result<(), UnknownCryptoError> {
+				Poly1305::update(self, input)
+			}
+
+			fn finalize(&mut self) -> Result<Tag, UnknownCryptoError> {
+				Poly1305::finalize(self)
+			}
+
+			fn compare_states(state_1: &Poly1305, state_2: &Poly1305) {
+				compare_poly1305_states(state_1, state_2)
+			}
+		}
+
+		impl TestableMac for Poly1305 {
+			type Key = OneTimeKey;
+			type Tag = Tag;
+
+			fn init(key: &OneTimeKey) -> Self {
+				init(key)
+			}
+
+			fn mac(key: &OneTimeKey, input: &[u8]) -> Result<Tag, UnknownCryptoError> {
+				poly1305(key, input)
+			}
+
+			fn verify(
+				expected: &Tag,
+				key: &OneTimeKey,
+				input: &[u8],
+			) -> Result<(), UnknownCryptoError> {
+				verify(expected, key, input).map(|_| ())
+			}
+
+			fn tag_from_slice(slice: &[u8]) -> Result<Tag, UnknownCryptoError> {
+				Tag::from_slice(slice)
+			}
+
+			fn tag_as_bytes(tag: &Tag) -> &[u8] {
+				tag.unprotected_as_bytes()
+			}
+		}
+
+		fn tester() -> MacTester<Poly1305> {
+			MacTester::new(
+				OneTimeKey::from_slice(&[1u8; 32]).unwrap(),
+				OneTimeKey::from_slice(&[2u8; 32]).unwrap(),
+				POLY1305_BLOCKSIZE,
+			)
+		}
+
+		#[test]
+		fn test_mac_interface() {
+			tester().run_all_tests();
+		}
+
+		quickcheck! {
+			fn prop_mac_interface(data: Vec<u8>) -> bool {
+				// Will panic on incorrect results.
+				tester().run_all_tests_property(&data);
+
+				true
+			}
+		}
+	}
+
 	mod test_verify {
 		use super::*;
 
@@ -29,6 +29,9 @@
 /// AEADs (Authenticated Encryption with Associated Data).
 pub mod aead;
 
+/// Elliptic-Curve Cryptography.
+pub mod ecc;
+
 /// Cryptographic hash functions.
 pub mod hash;
 
@@ -39,5 +42,11 @@ pub mod mac;
 /// Function).
 pub mod kdf;
 
+/// Noise Protocol Framework handshakes.
+pub mod noise;
+
+/// PAKEs (Password-Authenticated Key Exchange).
+pub mod pake;
+
 /// Stream ciphers.
 pub mod stream;
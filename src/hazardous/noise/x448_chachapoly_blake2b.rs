@@ -0,0 +1,1241 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Parameters:
+//! - `pattern`: The handshake pattern to use.
+//! - `is_initiator`: Whether this party sends the first handshake message.
+//! - `prologue`: Data both parties must agree on, which is authenticated by
+//!   the handshake (can be empty).
+//! - `local_static`: This party's long-term private key, if the pattern needs
+//!   one.
+//! - `ephemeral`: A fresh private key, used for this handshake only.
+//! - `remote_static`: The remote party's long-term public key, if the pattern
+//!   requires it to be known before the handshake.
+//! - `payload`: The data to send along with a handshake or transport message.
+//! - `message`: A received handshake message.
+//! - `dst_out`: Destination buffer for the written message or the decrypted
+//!   payload.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `local_static` or `remote_static` are given when the pattern does not use
+//!   them, or are missing when the pattern needs them.
+//! - A message is written or read out of turn, or after the handshake has
+//!   finished.
+//! - `dst_out` is too small to hold the written message or decrypted payload.
+//! - A written message would be longer than 65535 bytes.
+//! - A received message is malformed or fails authentication.
+//! - A Diffie-Hellman operation results in an all-zero shared key.
+//! - [`into_transport_mode()`] is called before the handshake has finished.
+//! - A `CipherState` has used all of its nonces.
+//!
+//! # Security:
+//! - The `ephemeral` key must be generated with [`PrivateKey::generate()`]
+//!   for every handshake and must never be reused. It is taken by value to
+//!   discourage this.
+//! - If any method on a `HandshakeState` returns an error, the handshake must
+//!   be aborted and the `HandshakeState` discarded.
+//! - The payload of the first message in `NN`, `NK` and `XX`, and of the
+//!   second message in `NN`, is not authenticated against any static key. See
+//!   the payload security properties in the Noise specification.
+//! - Transport messages must be decrypted in the order they were encrypted.
+//!   A `CipherState` is not meant for lossy or reordering transports.
+//!
+//! # Recommendation:
+//! - `XX` should be used when neither party knows the other's static key in
+//!   advance. The remote static key learned during the handshake, available
+//!   through [`get_remote_static()`], must then be checked by the caller.
+//!
+//! # Example:
+//! ```rust
+//! use orion::hazardous::ecc::x448::PrivateKey;
+//! use orion::hazardous::noise::x448_chachapoly_blake2b::{HandshakePattern, HandshakeState};
+//!
+//! let initiator_static = PrivateKey::generate();
+//! let responder_static = PrivateKey::generate();
+//!
+//! let mut initiator = HandshakeState::new(
+//! 	HandshakePattern::XX,
+//! 	true,
+//! 	b"Prologue",
+//! 	Some(&initiator_static),
+//! 	PrivateKey::generate(),
+//! 	None,
+//! )?;
+//! let mut responder = HandshakeState::new(
+//! 	HandshakePattern::XX,
+//! 	false,
+//! 	b"Prologue",
+//! 	Some(&responder_static),
+//! 	PrivateKey::generate(),
+//! 	None,
+//! )?;
+//!
+//! let mut message = [0u8; 256];
+//! let mut payload = [0u8; 256];
+//!
+//! // -> e
+//! let len = initiator.write_message(b"", &mut message)?;
+//! responder.read_message(&message[..len], &mut payload)?;
+//! // <- e, ee, s, es
+//! let len = responder.write_message(b"", &mut message)?;
+//! initiator.read_message(&message[..len], &mut payload)?;
+//! // -> s, se
+//! let len = initiator.write_message(b"", &mut message)?;
+//! responder.read_message(&message[..len], &mut payload)?;
+//!
+//! let (mut initiator_send, _) = initiator.into_transport_mode()?;
+//! let (_, mut responder_recv) = responder.into_transport_mode()?;
+//!
+//! let len = initiator_send.encrypt_with_ad(b"", b"Hello", &mut message)?;
+//! let len = responder_recv.decrypt_with_ad(b"", &message[..len], &mut payload)?;
+//! assert_eq!(&payload[..len], b"Hello");
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`into_transport_mode()`]: https://docs.rs/orion/latest/orion/hazardous/noise/x448_chachapoly_blake2b/struct.HandshakeState.html
+//! [`get_remote_static()`]: https://docs.rs/orion/latest/orion/hazardous/noise/x448_chachapoly_blake2b/struct.HandshakeState.html
+//! [`PrivateKey::generate()`]: https://docs.rs/orion/latest/orion/hazardous/ecc/x448/struct.PrivateKey.html
+
+use crate::{
+	errors::UnknownCryptoError,
+	hazardous::{
+		aead::chacha20poly1305::{self, Nonce, SecretKey},
+		ecc::x448::{self, PrivateKey, PublicKey, PUBLIC_KEY_SIZE},
+		hash::blake2b,
+		mac::poly1305::{self, OneTimeKey, POLY1305_KEYSIZE, POLY1305_OUTSIZE},
+		stream::chacha20::{self, CHACHA_KEYSIZE, IETF_CHACHA_NONCESIZE},
+	},
+	util,
+};
+use core::convert::TryFrom;
+use zeroize::Zeroize;
+
+/// The output size of BLAKE2b when used in Noise.
+const HASHLEN: usize = 64;
+/// The blocksize of BLAKE2b.
+const BLOCKLEN: usize = 128;
+/// The maximum size of any Noise message.
+const MAX_MESSAGE_LEN: usize = 65535;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// The supported handshake patterns.
+pub enum HandshakePattern {
+	/// No static keys are used by either party.
+	NN,
+	/// The initiator knows the static key of the responder in advance.
+	NK,
+	/// Both parties transmit their static keys during the handshake.
+	XX,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+/// The tokens that make up the message patterns.
+enum Token {
+	E,
+	S,
+	EE,
+	ES,
+	SE,
+}
+
+impl HandshakePattern {
+	/// The full Noise protocol name.
+	fn protocol_name(self) -> &'static [u8] {
+		match self {
+			HandshakePattern::NN => b"Noise_NN_448_ChaChaPoly_BLAKE2b",
+			HandshakePattern::NK => b"Noise_NK_448_ChaChaPoly_BLAKE2b",
+			HandshakePattern::XX => b"Noise_XX_448_ChaChaPoly_BLAKE2b",
+		}
+	}
+
+	/// The message patterns, in the order they are sent. Even indices are sent
+	/// by the initiator.
+	fn message_patterns(self) -> &'static [&'static [Token]] {
+		match self {
+			HandshakePattern::NN => &[&[Token::E], &[Token::E, Token::EE]],
+			HandshakePattern::NK => &[&[Token::E, Token::ES], &[Token::E, Token::EE]],
+			HandshakePattern::XX => &[
+				&[Token::E],
+				&[Token::E, Token::EE, Token::S, Token::ES],
+				&[Token::S, Token::SE],
+			],
+		}
+	}
+
+	/// Whether the given party must provide a local static key.
+	fn needs_local_static(self, is_initiator: bool) -> bool {
+		match self {
+			HandshakePattern::NN => false,
+			HandshakePattern::NK => !is_initiator,
+			HandshakePattern::XX => true,
+		}
+	}
+
+	/// Whether the given party must know the remote static key in advance,
+	/// through a pre-message.
+	fn needs_remote_static(self, is_initiator: bool) -> bool {
+		match self {
+			HandshakePattern::NN | HandshakePattern::XX => false,
+			HandshakePattern::NK => is_initiator,
+		}
+	}
+}
+
+/// HMAC-BLAKE2b over the concatenation of `data`. `key` is always `HASHLEN`
+/// bytes, so it never needs to be hashed.
+fn hmac_blake2b(key: &[u8; HASHLEN], data: &[&[u8]]) -> Result<[u8; HASHLEN], UnknownCryptoError> {
+	let mut ipad = [0x36u8; BLOCKLEN];
+	let mut opad = [0x5cu8; BLOCKLEN];
+	for (idx, itm) in key.iter().enumerate() {
+		ipad[idx] ^= itm;
+		opad[idx] ^= itm;
+	}
+
+	let mut inner = blake2b::init(None, HASHLEN)?;
+	inner.update(&ipad)?;
+	for part in data.iter() {
+		inner.update(part)?;
+	}
+	let inner_digest = inner.finalize()?;
+
+	let mut outer = blake2b::init(None, HASHLEN)?;
+	outer.update(&opad)?;
+	outer.update(inner_digest.as_ref())?;
+
+	let mut tag = [0u8; HASHLEN];
+	tag.copy_from_slice(outer.finalize()?.as_ref());
+	ipad.zeroize();
+	opad.zeroize();
+
+	Ok(tag)
+}
+
+/// The Noise HKDF function, returning two outputs.
+fn hkdf(
+	chaining_key: &[u8; HASHLEN],
+	ikm: &[u8],
+) -> Result<([u8; HASHLEN], [u8; HASHLEN]), UnknownCryptoError> {
+	let mut temp_key = hmac_blake2b(chaining_key, &[ikm])?;
+	let output1 = hmac_blake2b(&temp_key, &[&[0x01]])?;
+	let output2 = hmac_blake2b(&temp_key, &[&output1, &[0x02]])?;
+	temp_key.zeroize();
+
+	Ok((output1, output2))
+}
+
+/// Compute the ChaCha20Poly1305 tag of an empty plaintext. This is needed
+/// because `chacha20poly1305::seal()` does not accept empty plaintexts, which
+/// Noise uses for empty payloads.
+fn empty_plaintext_tag(
+	secret_key: &SecretKey,
+	nonce: &Nonce,
+	ad: &[u8],
+) -> Result<poly1305::Tag, UnknownCryptoError> {
+	let one_time_key = OneTimeKey::from_slice(
+		&chacha20::keystream_block(secret_key, nonce, 0)?[..POLY1305_KEYSIZE],
+	)?;
+	let mut poly1305_state = poly1305::init(&one_time_key);
+
+	if !ad.is_empty() {
+		let padding = [0u8; 16];
+		poly1305_state.update(ad)?;
+		if ad.len() % 16 != 0 {
+			poly1305_state.update(&padding[..16 - (ad.len() % 16)])?;
+		}
+	}
+	// The ciphertext is empty, so its length is zero.
+	let mut lengths = [0u8; 16];
+	lengths[..8].copy_from_slice(&(ad.len() as u64).to_le_bytes());
+	poly1305_state.update(&lengths)?;
+
+	poly1305_state.finalize()
+}
+
+/// A Noise `CipherState` used to encrypt and decrypt messages with
+/// ChaCha20Poly1305 and an incrementing nonce.
+pub struct CipherState {
+	k: Option<SecretKey>,
+	n: u64,
+}
+
+impl core::fmt::Debug for CipherState {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "CipherState {{ k: ***OMITTED***, n: {:?} }}", self.n)
+	}
+}
+
+impl CipherState {
+	/// A `CipherState` without a key, which passes data through unchanged.
+	fn empty() -> Self {
+		Self { k: None, n: 0 }
+	}
+
+	/// A `CipherState` using the first 32 bytes of `key`.
+	fn from_key(key: &[u8]) -> Result<Self, UnknownCryptoError> {
+		Ok(Self {
+			k: Some(SecretKey::from_slice(&key[..CHACHA_KEYSIZE])?),
+			n: 0,
+		})
+	}
+
+	/// Whether a key has been set.
+	fn has_key(&self) -> bool {
+		self.k.is_some()
+	}
+
+	/// Encode a nonce value as 32 bits of zeros followed by the little-endian
+	/// counter.
+	fn encode_nonce(n: u64) -> Result<Nonce, UnknownCryptoError> {
+		let mut nonce = [0u8; IETF_CHACHA_NONCESIZE];
+		nonce[4..].copy_from_slice(&n.to_le_bytes());
+
+		Nonce::from_slice(&nonce)
+	}
+
+	/// Get the nonce for the next message. The maximum nonce value is
+	/// reserved for `rekey()`.
+	fn next_nonce(&self) -> Result<Nonce, UnknownCryptoError> {
+		if self.n == u64::max_value() {
+			return Err(UnknownCryptoError::NonceCounterOverflow);
+		}
+
+		Self::encode_nonce(self.n)
+	}
+
+	#[must_use]
+	/// Encrypt `plaintext` with `ad` as additional data, writing the
+	/// ciphertext and tag to `dst_out`. Returns the number of bytes written.
+	pub fn encrypt_with_ad(
+		&mut self,
+		ad: &[u8],
+		plaintext: &[u8],
+		dst_out: &mut [u8],
+	) -> Result<usize, UnknownCryptoError> {
+		let secret_key = match self.k {
+			Some(ref k) => k,
+			None => {
+				if dst_out.len() < plaintext.len() {
+					return Err(UnknownCryptoError::InvalidLength);
+				}
+				dst_out[..plaintext.len()].copy_from_slice(plaintext);
+				return Ok(plaintext.len());
+			}
+		};
+
+		let out_len = plaintext.len() + POLY1305_OUTSIZE;
+		if dst_out.len() < out_len {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+
+		let nonce = self.next_nonce()?;
+		if plaintext.is_empty() {
+			dst_out[..POLY1305_OUTSIZE].copy_from_slice(
+				empty_plaintext_tag(secret_key, &nonce, ad)?.unprotected_as_bytes(),
+			);
+		} else {
+			chacha20poly1305::seal(
+				secret_key,
+				&nonce,
+				plaintext,
+				Some(ad),
+				&mut dst_out[..out_len],
+			)?;
+		}
+		self.n += 1;
+
+		Ok(out_len)
+	}
+
+	#[must_use]
+	/// Authenticate and decrypt `ciphertext` with `ad` as additional data,
+	/// writing the plaintext to `dst_out`. Returns the number of bytes written.
+	pub fn decrypt_with_ad(
+		&mut self,
+		ad: &[u8],
+		ciphertext: &[u8],
+		dst_out: &mut [u8],
+	) -> Result<usize, UnknownCryptoError> {
+		let secret_key = match self.k {
+			Some(ref k) => k,
+			None => {
+				if dst_out.len() < ciphertext.len() {
+					return Err(UnknownCryptoError::InvalidLength);
+				}
+				dst_out[..ciphertext.len()].copy_from_slice(ciphertext);
+				return Ok(ciphertext.len());
+			}
+		};
+
+		if ciphertext.len() < POLY1305_OUTSIZE {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+		let out_len = ciphertext.len() - POLY1305_OUTSIZE;
+		if dst_out.len() < out_len {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+
+		let nonce = self.next_nonce()?;
+		if out_len == 0 {
+			util::secure_cmp(
+				empty_plaintext_tag(secret_key, &nonce, ad)?.unprotected_as_bytes(),
+				ciphertext,
+			)?;
+		} else {
+			chacha20poly1305::open(
+				secret_key,
+				&nonce,
+				ciphertext,
+				Some(ad),
+				&mut dst_out[..out_len],
+			)?;
+		}
+		self.n += 1;
+
+		Ok(out_len)
+	}
+
+	#[must_use]
+	/// Replace the key with a new one derived from the current key, as
+	/// specified in section 4.2 of the Noise specification. The nonce counter
+	/// is not reset.
+	pub fn rekey(&mut self) -> Result<(), UnknownCryptoError> {
+		let new_key = match self.k {
+			Some(ref k) => {
+				let zeros = [0u8; CHACHA_KEYSIZE];
+				let mut out = [0u8; CHACHA_KEYSIZE + POLY1305_OUTSIZE];
+				chacha20poly1305::seal(
+					k,
+					&Self::encode_nonce(u64::max_value())?,
+					&zeros,
+					None,
+					&mut out,
+				)?;
+				let new_key = SecretKey::from_slice(&out[..CHACHA_KEYSIZE])?;
+				out.zeroize();
+
+				new_key
+			}
+			None => return Err(UnknownCryptoError::FinalizedState),
+		};
+		self.k = Some(new_key);
+
+		Ok(())
+	}
+}
+
+/// A Noise `SymmetricState`.
+struct SymmetricState {
+	cipher_state: CipherState,
+	ck: [u8; HASHLEN],
+	h: [u8; HASHLEN],
+}
+
+impl Drop for SymmetricState {
+	fn drop(&mut self) {
+		self.ck.zeroize();
+		self.h.zeroize();
+	}
+}
+
+impl SymmetricState {
+	/// Initialize with the protocol name. Noise protocol names used here are
+	/// always shorter than `HASHLEN`.
+	fn new(protocol_name: &[u8]) -> Self {
+		debug_assert!(protocol_name.len() <= HASHLEN);
+		let mut h = [0u8; HASHLEN];
+		h[..protocol_name.len()].copy_from_slice(protocol_name);
+
+		Self {
+			cipher_state: CipherState::empty(),
+			ck: h,
+			h,
+		}
+	}
+
+	fn mix_key(&mut self, ikm: &[u8]) -> Result<(), UnknownCryptoError> {
+		let (ck, mut temp_k) = hkdf(&self.ck, ikm)?;
+		self.ck = ck;
+		self.cipher_state = CipherState::from_key(&temp_k)?;
+		temp_k.zeroize();
+
+		Ok(())
+	}
+
+	fn mix_hash(&mut self, data: &[u8]) -> Result<(), UnknownCryptoError> {
+		let mut state = blake2b::init(None, HASHLEN)?;
+		state.update(&self.h)?;
+		state.update(data)?;
+		self.h.copy_from_slice(state.finalize()?.as_ref());
+
+		Ok(())
+	}
+
+	fn encrypt_and_hash(
+		&mut self,
+		plaintext: &[u8],
+		dst_out: &mut [u8],
+	) -> Result<usize, UnknownCryptoError> {
+		let len = self
+			.cipher_state
+			.encrypt_with_ad(&self.h, plaintext, dst_out)?;
+		self.mix_hash(&dst_out[..len])?;
+
+		Ok(len)
+	}
+
+	fn decrypt_and_hash(
+		&mut self,
+		ciphertext: &[u8],
+		dst_out: &mut [u8],
+	) -> Result<usize, UnknownCryptoError> {
+		let len = self
+			.cipher_state
+			.decrypt_with_ad(&self.h, ciphertext, dst_out)?;
+		self.mix_hash(ciphertext)?;
+
+		Ok(len)
+	}
+
+	fn split(&self) -> Result<(CipherState, CipherState), UnknownCryptoError> {
+		let (mut temp_k1, mut temp_k2) = hkdf(&self.ck, &[])?;
+		let ret = (
+			CipherState::from_key(&temp_k1)?,
+			CipherState::from_key(&temp_k2)?,
+		);
+		temp_k1.zeroize();
+		temp_k2.zeroize();
+
+		Ok(ret)
+	}
+}
+
+/// A Noise `HandshakeState`, driving one party through a handshake pattern.
+pub struct HandshakeState {
+	symmetric_state: SymmetricState,
+	pattern: HandshakePattern,
+	is_initiator: bool,
+	s: Option<PrivateKey>,
+	e: PrivateKey,
+	rs: Option<PublicKey>,
+	re: Option<PublicKey>,
+	message_index: usize,
+}
+
+impl core::fmt::Debug for HandshakeState {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(
+			f,
+			"HandshakeState {{ pattern: {:?}, is_initiator: {:?}, message_index: {:?}, ***OMITTED*** }}",
+			self.pattern, self.is_initiator, self.message_index
+		)
+	}
+}
+
+impl HandshakeState {
+	#[must_use]
+	/// Initialize a handshake for `pattern`.
+	pub fn new(
+		pattern: HandshakePattern,
+		is_initiator: bool,
+		prologue: &[u8],
+		local_static: Option<&PrivateKey>,
+		ephemeral: PrivateKey,
+		remote_static: Option<&PublicKey>,
+	) -> Result<Self, UnknownCryptoError> {
+		if local_static.is_some() != pattern.needs_local_static(is_initiator) {
+			return Err(UnknownCryptoError::ParameterOutOfRange);
+		}
+		if remote_static.is_some() != pattern.needs_remote_static(is_initiator) {
+			return Err(UnknownCryptoError::ParameterOutOfRange);
+		}
+
+		let mut symmetric_state = SymmetricState::new(pattern.protocol_name());
+		symmetric_state.mix_hash(prologue)?;
+
+		let s = match local_static {
+			Some(sk) => Some(PrivateKey::from_slice(sk.unprotected_as_bytes())?),
+			None => None,
+		};
+		let rs = remote_static.copied();
+
+		// The only pre-message among the supported patterns is the responder's
+		// static key in NK.
+		if pattern == HandshakePattern::NK {
+			let responder_static = match (&rs, &s) {
+				(Some(remote), _) if is_initiator => *remote,
+				(_, Some(local)) => PublicKey::try_from(local)?,
+				_ => return Err(UnknownCryptoError::ParameterOutOfRange),
+			};
+			symmetric_state.mix_hash(responder_static.as_ref())?;
+		}
+
+		Ok(Self {
+			symmetric_state,
+			pattern,
+			is_initiator,
+			s,
+			e: ephemeral,
+			rs,
+			re: None,
+			message_index: 0,
+		})
+	}
+
+	/// Whether all handshake messages have been sent and received.
+	pub fn is_handshake_finished(&self) -> bool {
+		self.message_index == self.pattern.message_patterns().len()
+	}
+
+	/// Whether the next handshake message is to be written by this party.
+	fn is_our_turn(&self) -> bool {
+		(self.message_index % 2 == 0) == self.is_initiator
+	}
+
+	/// The handshake hash. This uniquely identifies the handshake once it has
+	/// finished, and can be used for channel binding.
+	pub fn get_handshake_hash(&self) -> &[u8] {
+		&self.symmetric_state.h
+	}
+
+	/// The remote party's static public key, if it is known.
+	pub fn get_remote_static(&self) -> Option<&PublicKey> {
+		self.rs.as_ref()
+	}
+
+	/// Perform the Diffie-Hellman operation for `token` and mix the result
+	/// into the chaining key.
+	fn mix_dh(&mut self, token: Token) -> Result<(), UnknownCryptoError> {
+		let (private_key, public_key) = match (token, self.is_initiator) {
+			(Token::EE, _) => (Some(&self.e), self.re.as_ref()),
+			(Token::ES, true) | (Token::SE, false) => (Some(&self.e), self.rs.as_ref()),
+			(Token::ES, false) | (Token::SE, true) => (self.s.as_ref(), self.re.as_ref()),
+			(Token::E, _) | (Token::S, _) => return Err(UnknownCryptoError::FinalizedState),
+		};
+
+		match (private_key, public_key) {
+			(Some(sk), Some(pk)) => {
+				let shared_key = x448::key_agreement(sk, pk)?;
+				self.symmetric_state
+					.mix_key(shared_key.unprotected_as_bytes())
+			}
+			_ => Err(UnknownCryptoError::FinalizedState),
+		}
+	}
+
+	#[must_use]
+	/// Write the next handshake message, with `payload`, to `dst_out`.
+	/// Returns the number of bytes written.
+	pub fn write_message(
+		&mut self,
+		payload: &[u8],
+		dst_out: &mut [u8],
+	) -> Result<usize, UnknownCryptoError> {
+		if self.is_handshake_finished() || !self.is_our_turn() {
+			return Err(UnknownCryptoError::FinalizedState);
+		}
+
+		let max_len = core::cmp::min(dst_out.len(), MAX_MESSAGE_LEN);
+		let dst_out = &mut dst_out[..max_len];
+		let mut written = 0;
+
+		for token in self.pattern.message_patterns()[self.message_index].iter() {
+			match *token {
+				Token::E => {
+					if dst_out.len() - written < PUBLIC_KEY_SIZE {
+						return Err(UnknownCryptoError::InvalidLength);
+					}
+					let e_pub = PublicKey::try_from(&self.e)?;
+					dst_out[written..written + PUBLIC_KEY_SIZE].copy_from_slice(e_pub.as_ref());
+					self.symmetric_state.mix_hash(e_pub.as_ref())?;
+					written += PUBLIC_KEY_SIZE;
+				}
+				Token::S => {
+					let s_pub = match self.s {
+						Some(ref sk) => PublicKey::try_from(sk)?,
+						None => return Err(UnknownCryptoError::FinalizedState),
+					};
+					written += self
+						.symmetric_state
+						.encrypt_and_hash(s_pub.as_ref(), &mut dst_out[written..])?;
+				}
+				dh_token => self.mix_dh(dh_token)?,
+			}
+		}
+
+		written += self
+			.symmetric_state
+			.encrypt_and_hash(payload, &mut dst_out[written..])?;
+		self.message_index += 1;
+
+		Ok(written)
+	}
+
+	#[must_use]
+	/// Read the next handshake message, writing its decrypted payload to
+	/// `dst_out`. Returns the length of the payload.
+	pub fn read_message(
+		&mut self,
+		message: &[u8],
+		dst_out: &mut [u8],
+	) -> Result<usize, UnknownCryptoError> {
+		if self.is_handshake_finished() || self.is_our_turn() {
+			return Err(UnknownCryptoError::FinalizedState);
+		}
+		if message.len() > MAX_MESSAGE_LEN {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+
+		let mut read = 0;
+
+		for token in self.pattern.message_patterns()[self.message_index].iter() {
+			match *token {
+				Token::E => {
+					if message.len() - read < PUBLIC_KEY_SIZE {
+						return Err(UnknownCryptoError::InvalidLength);
+					}
+					let re = PublicKey::from_slice(&message[read..read + PUBLIC_KEY_SIZE])?;
+					self.symmetric_state.mix_hash(re.as_ref())?;
+					self.re = Some(re);
+					read += PUBLIC_KEY_SIZE;
+				}
+				Token::S => {
+					let len = if self.symmetric_state.cipher_state.has_key() {
+						PUBLIC_KEY_SIZE + POLY1305_OUTSIZE
+					} else {
+						PUBLIC_KEY_SIZE
+					};
+					if message.len() - read < len {
+						return Err(UnknownCryptoError::InvalidLength);
+					}
+					let mut rs = [0u8; PUBLIC_KEY_SIZE];
+					self.symmetric_state
+						.decrypt_and_hash(&message[read..read + len], &mut rs)?;
+					self.rs = Some(PublicKey::from(rs));
+					read += len;
+				}
+				dh_token => self.mix_dh(dh_token)?,
+			}
+		}
+
+		let payload_len = self
+			.symmetric_state
+			.decrypt_and_hash(&message[read..], dst_out)?;
+		self.message_index += 1;
+
+		Ok(payload_len)
+	}
+
+	#[must_use]
+	/// Finish the handshake, returning a `CipherState` for sending and one for
+	/// receiving transport messages, in that order.
+	pub fn into_transport_mode(self) -> Result<(CipherState, CipherState), UnknownCryptoError> {
+		if !self.is_handshake_finished() {
+			return Err(UnknownCryptoError::FinalizedState);
+		}
+
+		let (initiator_to_responder, responder_to_initiator) = self.symmetric_state.split()?;
+		if self.is_initiator {
+			Ok((initiator_to_responder, responder_to_initiator))
+		} else {
+			Ok((responder_to_initiator, initiator_to_responder))
+		}
+	}
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	#[cfg(feature = "safe_api")]
+	/// Run a full handshake for `pattern` with empty payloads, returning both
+	/// finished `HandshakeState`s.
+	fn handshake(pattern: HandshakePattern) -> (HandshakeState, HandshakeState) {
+		let init_static = PrivateKey::generate();
+		let resp_static = PrivateKey::generate();
+		let resp_public = PublicKey::try_from(&resp_static).unwrap();
+
+		let (init_s, init_rs, resp_s) = match pattern {
+			HandshakePattern::NN => (None, None, None),
+			HandshakePattern::NK => (None, Some(&resp_public), Some(&resp_static)),
+			HandshakePattern::XX => (Some(&init_static), None, Some(&resp_static)),
+		};
+
+		let mut initiator =
+			HandshakeState::new(pattern, true, b"", init_s, PrivateKey::generate(), init_rs)
+				.unwrap();
+		let mut responder =
+			HandshakeState::new(pattern, false, b"", resp_s, PrivateKey::generate(), None).unwrap();
+
+		let mut message = [0u8; 256];
+		let mut payload = [0u8; 256];
+		let mut idx = 0;
+		while !initiator.is_handshake_finished() {
+			let (sender, receiver) = if idx % 2 == 0 {
+				(&mut initiator, &mut responder)
+			} else {
+				(&mut responder, &mut initiator)
+			};
+			let len = sender.write_message(&[], &mut message).unwrap();
+			assert_eq!(
+				receiver
+					.read_message(&message[..len], &mut payload)
+					.unwrap(),
+				0
+			);
+			idx += 1;
+		}
+
+		(initiator, responder)
+	}
+
+	#[cfg(feature = "safe_api")]
+	mod test_handshake_state {
+		use super::*;
+
+		#[test]
+		fn test_all_patterns_agree() {
+			for pattern in [
+				HandshakePattern::NN,
+				HandshakePattern::NK,
+				HandshakePattern::XX,
+			]
+			.iter()
+			{
+				let (initiator, responder) = handshake(*pattern);
+				assert!(responder.is_handshake_finished());
+				assert_eq!(
+					initiator.get_handshake_hash(),
+					responder.get_handshake_hash()
+				);
+
+				let (mut init_send, mut init_recv) = initiator.into_transport_mode().unwrap();
+				let (mut resp_send, mut resp_recv) = responder.into_transport_mode().unwrap();
+
+				let mut ct = [0u8; 32];
+				let mut pt = [0u8; 32];
+				let len = init_send.encrypt_with_ad(&[], b"Ping", &mut ct).unwrap();
+				let len = resp_recv.decrypt_with_ad(&[], &ct[..len], &mut pt).unwrap();
+				assert_eq!(&pt[..len], b"Ping");
+				let len = resp_send.encrypt_with_ad(&[], b"Pong", &mut ct).unwrap();
+				let len = init_recv.decrypt_with_ad(&[], &ct[..len], &mut pt).unwrap();
+				assert_eq!(&pt[..len], b"Pong");
+			}
+		}
+
+		#[test]
+		fn test_xx_learns_remote_static() {
+			let init_static = PrivateKey::generate();
+			let resp_static = PrivateKey::generate();
+			let mut initiator = HandshakeState::new(
+				HandshakePattern::XX,
+				true,
+				b"",
+				Some(&init_static),
+				PrivateKey::generate(),
+				None,
+			)
+			.unwrap();
+			let mut responder = HandshakeState::new(
+				HandshakePattern::XX,
+				false,
+				b"",
+				Some(&resp_static),
+				PrivateKey::generate(),
+				None,
+			)
+			.unwrap();
+			assert!(initiator.get_remote_static().is_none());
+
+			let mut message = [0u8; 256];
+			let mut payload = [0u8; 256];
+			let len = initiator.write_message(&[], &mut message).unwrap();
+			responder
+				.read_message(&message[..len], &mut payload)
+				.unwrap();
+			let len = responder.write_message(&[], &mut message).unwrap();
+			initiator
+				.read_message(&message[..len], &mut payload)
+				.unwrap();
+			let len = initiator.write_message(&[], &mut message).unwrap();
+			responder
+				.read_message(&message[..len], &mut payload)
+				.unwrap();
+
+			assert_eq!(
+				initiator.get_remote_static().unwrap(),
+				&PublicKey::try_from(&resp_static).unwrap()
+			);
+			assert_eq!(
+				responder.get_remote_static().unwrap(),
+				&PublicKey::try_from(&init_static).unwrap()
+			);
+		}
+
+		#[test]
+		fn test_wrong_static_keys_err() {
+			let sk = PrivateKey::generate();
+			let pk = PublicKey::try_from(&sk).unwrap();
+			let e = PrivateKey::generate;
+
+			// NN uses no static keys
+			assert!(
+				HandshakeState::new(HandshakePattern::NN, true, b"", Some(&sk), e(), None).is_err()
+			);
+			assert!(
+				HandshakeState::new(HandshakePattern::NN, true, b"", None, e(), Some(&pk)).is_err()
+			);
+			// NK initiator needs the remote static key, responder its own
+			assert!(HandshakeState::new(HandshakePattern::NK, true, b"", None, e(), None).is_err());
+			assert!(
+				HandshakeState::new(HandshakePattern::NK, false, b"", None, e(), None).is_err()
+			);
+			assert!(HandshakeState::new(
+				HandshakePattern::NK,
+				false,
+				b"",
+				Some(&sk),
+				e(),
+				Some(&pk)
+			)
+			.is_err());
+			// XX needs local static keys only
+			assert!(HandshakeState::new(HandshakePattern::XX, true, b"", None, e(), None).is_err());
+			assert!(HandshakeState::new(
+				HandshakePattern::XX,
+				false,
+				b"",
+				Some(&sk),
+				e(),
+				Some(&pk)
+			)
+			.is_err());
+		}
+
+		#[test]
+		fn test_out_of_turn_err() {
+			let mut initiator = HandshakeState::new(
+				HandshakePattern::NN,
+				true,
+				b"",
+				None,
+				PrivateKey::generate(),
+				None,
+			)
+			.unwrap();
+			let mut responder = HandshakeState::new(
+				HandshakePattern::NN,
+				false,
+				b"",
+				None,
+				PrivateKey::generate(),
+				None,
+			)
+			.unwrap();
+			let mut message = [0u8; 256];
+			let mut payload = [0u8; 256];
+
+			assert!(responder.write_message(&[], &mut message).is_err());
+			assert!(initiator.read_message(&[0u8; 56], &mut payload).is_err());
+			assert!(initiator.into_transport_mode().is_err());
+		}
+
+		#[test]
+		fn test_after_finished_err() {
+			let (mut initiator, mut responder) = handshake(HandshakePattern::NN);
+			let mut message = [0u8; 256];
+			let mut payload = [0u8; 256];
+
+			assert!(initiator.write_message(&[], &mut message).is_err());
+			assert!(responder.write_message(&[], &mut message).is_err());
+			assert!(initiator.read_message(&message, &mut payload).is_err());
+			assert!(responder.read_message(&message, &mut payload).is_err());
+		}
+
+		#[test]
+		fn test_different_prologue_err() {
+			let mut initiator = HandshakeState::new(
+				HandshakePattern::NN,
+				true,
+				b"Prologue A",
+				None,
+				PrivateKey::generate(),
+				None,
+			)
+			.unwrap();
+			let mut responder = HandshakeState::new(
+				HandshakePattern::NN,
+				false,
+				b"Prologue B",
+				None,
+				PrivateKey::generate(),
+				None,
+			)
+			.unwrap();
+			let mut message = [0u8; 256];
+			let mut payload = [0u8; 256];
+
+			let len = initiator.write_message(&[], &mut message).unwrap();
+			responder
+				.read_message(&message[..len], &mut payload)
+				.unwrap();
+			let len = responder.write_message(&[], &mut message).unwrap();
+			assert!(initiator
+				.read_message(&message[..len], &mut payload)
+				.is_err());
+		}
+
+		#[test]
+		fn test_modified_message_err() {
+			let mut initiator = HandshakeState::new(
+				HandshakePattern::NN,
+				true,
+				b"",
+				None,
+				PrivateKey::generate(),
+				None,
+			)
+			.unwrap();
+			let mut responder = HandshakeState::new(
+				HandshakePattern::NN,
+				false,
+				b"",
+				None,
+				PrivateKey::generate(),
+				None,
+			)
+			.unwrap();
+			let mut message = [0u8; 256];
+			let mut payload = [0u8; 256];
+
+			let len = initiator.write_message(&[], &mut message).unwrap();
+			responder
+				.read_message(&message[..len], &mut payload)
+				.unwrap();
+			let len = responder.write_message(b"Payload", &mut message).unwrap();
+			message[len - 1] ^= 1;
+			assert!(initiator
+				.read_message(&message[..len], &mut payload)
+				.is_err());
+		}
+
+		#[test]
+		fn test_short_buffers_err() {
+			let mut initiator = HandshakeState::new(
+				HandshakePattern::NN,
+				true,
+				b"",
+				None,
+				PrivateKey::generate(),
+				None,
+			)
+			.unwrap();
+			let mut responder = HandshakeState::new(
+				HandshakePattern::NN,
+				false,
+				b"",
+				None,
+				PrivateKey::generate(),
+				None,
+			)
+			.unwrap();
+			let mut message = [0u8; 256];
+			let mut payload = [0u8; 256];
+
+			assert!(initiator.write_message(&[], &mut message[..55]).is_err());
+			assert!(responder
+				.read_message(&message[..55], &mut payload)
+				.is_err());
+		}
+	}
+
+	#[cfg(feature = "safe_api")]
+	mod test_cipher_state {
+		use super::*;
+
+		#[test]
+		fn test_empty_payload() {
+			let (initiator, responder) = handshake(HandshakePattern::NN);
+			let (mut send, _) = initiator.into_transport_mode().unwrap();
+			let (_, mut recv) = responder.into_transport_mode().unwrap();
+
+			let mut ct = [0u8; POLY1305_OUTSIZE];
+			assert_eq!(send.encrypt_with_ad(b"ad", &[], &mut ct).unwrap(), 16);
+			assert_eq!(recv.decrypt_with_ad(b"ad", &ct, &mut []).unwrap(), 0);
+		}
+
+		#[test]
+		fn test_modified_ciphertext_or_ad_err() {
+			let (initiator, responder) = handshake(HandshakePattern::NN);
+			let (mut send, _) = initiator.into_transport_mode().unwrap();
+			let (_, mut recv) = responder.into_transport_mode().unwrap();
+
+			let mut ct = [0u8; 20];
+			let mut pt = [0u8; 4];
+			send.encrypt_with_ad(b"ad", b"Data", &mut ct).unwrap();
+			assert!(recv.decrypt_with_ad(b"da", &ct, &mut pt).is_err());
+			ct[0] ^= 1;
+			assert!(recv.decrypt_with_ad(b"ad", &ct, &mut pt).is_err());
+			ct[0] ^= 1;
+			// Failed decryption does not advance the nonce.
+			assert!(recv.decrypt_with_ad(b"ad", &ct, &mut pt).is_ok());
+		}
+
+		#[test]
+		fn test_reordered_err() {
+			let (initiator, responder) = handshake(HandshakePattern::NN);
+			let (mut send, _) = initiator.into_transport_mode().unwrap();
+			let (_, mut recv) = responder.into_transport_mode().unwrap();
+
+			let mut ct1 = [0u8; 20];
+			let mut ct2 = [0u8; 20];
+			let mut pt = [0u8; 4];
+			send.encrypt_with_ad(&[], b"Data", &mut ct1).unwrap();
+			send.encrypt_with_ad(&[], b"Data", &mut ct2).unwrap();
+			assert!(recv.decrypt_with_ad(&[], &ct2, &mut pt).is_err());
+		}
+
+		#[test]
+		fn test_short_buffers_err() {
+			let (initiator, responder) = handshake(HandshakePattern::NN);
+			let (mut send, _) = initiator.into_transport_mode().unwrap();
+			let (_, mut recv) = responder.into_transport_mode().unwrap();
+
+			let mut ct = [0u8; 20];
+			let mut pt = [0u8; 4];
+			assert!(send.encrypt_with_ad(&[], b"Data", &mut ct[..19]).is_err());
+			send.encrypt_with_ad(&[], b"Data", &mut ct).unwrap();
+			assert!(recv.decrypt_with_ad(&[], &ct, &mut pt[..3]).is_err());
+			assert!(recv.decrypt_with_ad(&[], &ct[..15], &mut pt).is_err());
+		}
+
+		#[test]
+		fn test_rekey() {
+			let (initiator, responder) = handshake(HandshakePattern::NN);
+			let (mut send, _) = initiator.into_transport_mode().unwrap();
+			let (_, mut recv) = responder.into_transport_mode().unwrap();
+
+			let mut ct = [0u8; 20];
+			let mut pt = [0u8; 4];
+			send.rekey().unwrap();
+			send.encrypt_with_ad(&[], b"Data", &mut ct).unwrap();
+			assert!(recv.decrypt_with_ad(&[], &ct, &mut pt).is_err());
+			recv.rekey().unwrap();
+			assert!(recv.decrypt_with_ad(&[], &ct, &mut pt).is_ok());
+			assert_eq!(&pt, b"Data");
+		}
+
+		#[test]
+		fn test_nonce_exhaustion_err() {
+			let (initiator, _) = handshake(HandshakePattern::NN);
+			let (mut send, _) = initiator.into_transport_mode().unwrap();
+			let mut ct = [0u8; 20];
+
+			send.n = u64::max_value() - 1;
+			assert!(send.encrypt_with_ad(&[], b"Data", &mut ct).is_ok());
+			assert!(send.encrypt_with_ad(&[], b"Data", &mut ct).is_err());
+		}
+	}
+
+	// Proptests. Only exectued when NOT testing no_std.
+	#[cfg(feature = "safe_api")]
+	mod proptest {
+		use super::*;
+
+		quickcheck! {
+			/// Transport messages should always decrypt to the original input.
+			fn prop_transport_roundtrip(input: Vec<u8>, ad: Vec<u8>) -> bool {
+				let (initiator, responder) = handshake(HandshakePattern::NN);
+				let (mut send, _) = initiator.into_transport_mode().unwrap();
+				let (_, mut recv) = responder.into_transport_mode().unwrap();
+
+				let mut ct = vec![0u8; input.len() + POLY1305_OUTSIZE];
+				let mut pt = vec![0u8; input.len()];
+				send.encrypt_with_ad(&ad, &input, &mut ct).unwrap();
+				recv.decrypt_with_ad(&ad, &ct, &mut pt).unwrap();
+
+				pt == input
+			}
+		}
+	}
+}
+
+// Testing private functions in the module.
+#[cfg(test)]
+mod private {
+	use super::*;
+
+	mod test_empty_plaintext_tag {
+		use super::*;
+
+		#[test]
+		fn test_matches_chacha20poly1305() {
+			let mut key = [0u8; 32];
+			for (idx, itm) in key.iter_mut().enumerate() {
+				*itm = idx as u8;
+			}
+			let secret_key = SecretKey::from_slice(&key).unwrap();
+			let nonce = CipherState::encode_nonce(5).unwrap();
+
+			assert_eq!(
+				empty_plaintext_tag(&secret_key, &nonce, b"ad data of 17 byt")
+					.unwrap()
+					.unprotected_as_bytes(),
+				&[
+					0x36, 0x10, 0x31, 0x3c, 0xa2, 0x06, 0xc0, 0x45, 0xaa, 0xae, 0xd7, 0xc0, 0xf8,
+					0xad, 0xfe, 0xc4
+				][..]
+			);
+			assert_eq!(
+				empty_plaintext_tag(&secret_key, &nonce, &[])
+					.unwrap()
+					.unprotected_as_bytes(),
+				&[
+					0x3e, 0x2f, 0xdc, 0xfe, 0x11, 0x41, 0x17, 0xa9, 0x6d, 0x0d, 0x40, 0xc0, 0x77,
+					0x7d, 0x9d, 0x17
+				][..]
+			);
+		}
+	}
+
+	mod test_cipher_state {
+		use super::*;
+
+		#[test]
+		fn test_empty_passes_through() {
+			let mut cs = CipherState::empty();
+			let mut out = [0u8; 4];
+			assert_eq!(cs.encrypt_with_ad(b"ad", b"Data", &mut out).unwrap(), 4);
+			assert_eq!(&out, b"Data");
+			assert_eq!(cs.decrypt_with_ad(b"ad", b"Data", &mut out).unwrap(), 4);
+			assert!(cs.rekey().is_err());
+		}
+
+		#[test]
+		fn test_nonce_encoding() {
+			assert_eq!(
+				CipherState::encode_nonce(1).unwrap().as_ref(),
+				&[0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0]
+			);
+		}
+	}
+}
@@ -0,0 +1,578 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Parameters:
+//! - `role`: Whether this party is `A` (the initiator) or `B` (the responder).
+//! - `hashed_password`: The password after being processed by a memory-hard
+//!   function, `w` in the RFC. Both parties must use the same value.
+//! - `identity_a`/`identity_b`: The identities of party `A` and `B` (can be
+//!   empty).
+//! - `aad`: Additional data to bind the confirmation keys to (can be empty).
+//! - `ephemeral_key`: A fresh secret scalar, used for this exchange only.
+//! - `peer_share`: The public share received from the other party.
+//! - `peer_confirmation`: The confirmation message received from the other
+//!   party.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `ephemeral_key` is zero or not less than the group order.
+//! - `peer_share` is not a valid, uncompressed point on P-256, or the shared
+//!   point computed from it is the point at infinity.
+//! - `peer_confirmation` does not match the expected confirmation message.
+//!
+//! # Security:
+//! - The password must be processed by a memory-hard function, with a salt
+//!   agreed upon by both parties, before being used as `hashed_password`. The
+//!   output is reduced modulo the group order.
+//! - The `ephemeral_key` must be generated with [`EphemeralKey::generate()`]
+//!   for every exchange and must never be reused. It is taken by value to
+//!   discourage this.
+//! - The [`SharedKey`] is only returned once the peer's confirmation message has
+//!   been verified. A party must not send anything protected by the shared key
+//!   before this.
+//! - Each party must only make a single attempt per [`EphemeralKey`]. An
+//!   attacker gets one online password guess per exchange, so failed exchanges
+//!   should be rate-limited.
+//!
+//! # Example:
+//! ```rust
+//! use orion::hazardous::pake::spake2_p256::{EphemeralKey, HashedPassword, Role, Spake2};
+//!
+//! // In practice, derived from the password with a memory-hard function.
+//! let hashed_password = HashedPassword::from_slice(&[0x42; 32])?;
+//!
+//! let a = Spake2::new(Role::A, &hashed_password, b"client", b"server", b"", EphemeralKey::generate())?;
+//! let b = Spake2::new(Role::B, &hashed_password, b"client", b"server", b"", EphemeralKey::generate())?;
+//!
+//! // Exchange public shares.
+//! let share_a = *a.public_share();
+//! let share_b = *b.public_share();
+//! let a = a.finish(&share_b)?;
+//! let b = b.finish(&share_a)?;
+//!
+//! // Exchange confirmation messages.
+//! let confirmation_a = *a.confirmation();
+//! let confirmation_b = *b.confirmation();
+//! let shared_key_a = a.verify(&confirmation_b)?;
+//! let shared_key_b = b.verify(&confirmation_a)?;
+//!
+//! assert!(shared_key_a == shared_key_b);
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`EphemeralKey::generate()`]: https://docs.rs/orion/latest/orion/hazardous/pake/spake2_p256/struct.EphemeralKey.html
+//! [`EphemeralKey`]: https://docs.rs/orion/latest/orion/hazardous/pake/spake2_p256/struct.EphemeralKey.html
+//! [`SharedKey`]: https://docs.rs/orion/latest/orion/hazardous/pake/spake2_p256/struct.SharedKey.html
+
+use crate::{
+	errors::UnknownCryptoError,
+	hazardous::{
+		ecc::p256::{
+			limbs_from_bytes, limbs_to_bytes, reduce_once, scalar_from_bytes, Limbs,
+			ProjectivePoint, ELEMENT_SIZE, N, UNCOMPRESSED_POINT_SIZE,
+		},
+		hash::sha256::{self, hmac_sha256, SHA256_OUTSIZE},
+	},
+};
+use zeroize::Zeroize;
+
+/// The size of a hashed password.
+pub const HASHED_PASSWORD_SIZE: usize = 32;
+/// The size of an ephemeral key.
+pub const EPHEMERAL_KEY_SIZE: usize = 32;
+/// The size of a public share (an uncompressed SEC1 point).
+pub const PUBLIC_SHARE_SIZE: usize = UNCOMPRESSED_POINT_SIZE;
+/// The size of the shared key, `Ke` in the RFC.
+pub const SHARED_KEY_SIZE: usize = 16;
+/// The size of a confirmation message.
+pub const CONFIRMATION_SIZE: usize = SHA256_OUTSIZE;
+/// The size of each confirmation key, `KcA` and `KcB` in the RFC.
+const CONFIRMATION_KEY_SIZE: usize = 16;
+
+#[allow(clippy::unreadable_literal)]
+/// The x-coordinate of the point M for P-256.
+const M_X: Limbs = [
+	0xafd497333d8fa12f,
+	0x3b64e16ef3dcab95,
+	0xba9dd7242579f299,
+	0x886e2f97ace46e55,
+];
+
+#[allow(clippy::unreadable_literal)]
+/// The y-coordinate of the point M for P-256.
+const M_Y: Limbs = [
+	0xca547d55a12e2d20,
+	0x5c7be09419c785e0,
+	0x4e0b0e65ff02ac8e,
+	0x5ff355163e43ce22,
+];
+
+#[allow(clippy::unreadable_literal)]
+/// The x-coordinate of the point N for P-256.
+const N_X: Limbs = [
+	0x4b4f98baa1292b49,
+	0x19c629d7014d49a2,
+	0xb04d997f38c37707,
+	0xd8bbd6c639c62937,
+];
+
+#[allow(clippy::unreadable_literal)]
+/// The y-coordinate of the point N for P-256.
+const N_Y: Limbs = [
+	0x64490b1e656edbe7,
+	0x4d9bd36034808cd5,
+	0x08a636337f5168c6,
+	0x07d60aa6bfade450,
+];
+
+construct_secret_key! {
+	/// A type to represent the `HashedPassword` (`w`) that SPAKE2 uses.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 32 bytes.
+	///
+	/// # Panics:
+	/// A panic will occur if:
+	/// - Failure to generate random bytes securely.
+	(HashedPassword, test_hashed_password, HASHED_PASSWORD_SIZE, HASHED_PASSWORD_SIZE, HASHED_PASSWORD_SIZE)
+}
+
+impl_from_trait!(HashedPassword, HASHED_PASSWORD_SIZE);
+
+construct_secret_key! {
+	/// A type to represent the `EphemeralKey` (`x` or `y`) that SPAKE2 uses.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 32 bytes.
+	///
+	/// # Panics:
+	/// A panic will occur if:
+	/// - Failure to generate random bytes securely.
+	(EphemeralKey, test_ephemeral_key, EPHEMERAL_KEY_SIZE, EPHEMERAL_KEY_SIZE, EPHEMERAL_KEY_SIZE)
+}
+
+impl_from_trait!(EphemeralKey, EPHEMERAL_KEY_SIZE);
+
+construct_public! {
+	/// A type to represent the `PublicShare` (`pA` or `pB`) that SPAKE2 exchanges.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 65 bytes.
+	(PublicShare, test_public_share, PUBLIC_SHARE_SIZE, PUBLIC_SHARE_SIZE)
+}
+
+impl_from_trait!(PublicShare, PUBLIC_SHARE_SIZE);
+
+construct_secret_key! {
+	/// A type to represent the `SharedKey` (`Ke`) that SPAKE2 produces.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 16 bytes.
+	///
+	/// # Panics:
+	/// A panic will occur if:
+	/// - Failure to generate random bytes securely.
+	(SharedKey, test_shared_key, SHARED_KEY_SIZE, SHARED_KEY_SIZE, SHARED_KEY_SIZE)
+}
+
+impl_from_trait!(SharedKey, SHARED_KEY_SIZE);
+
+construct_tag! {
+	/// A type to represent the `Confirmation` message (`cA` or `cB`) that SPAKE2 exchanges.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 32 bytes.
+	(Confirmation, test_confirmation, CONFIRMATION_SIZE, CONFIRMATION_SIZE)
+}
+
+impl_from_trait!(Confirmation, CONFIRMATION_SIZE);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// The role of a party in SPAKE2.
+pub enum Role {
+	/// The party that computes its share using `M`.
+	A,
+	/// The party that computes its share using `N`.
+	B,
+}
+
+/// The point M.
+fn point_m() -> ProjectivePoint {
+	ProjectivePoint::from_affine(&M_X, &M_Y)
+}
+
+/// The point N.
+fn point_n() -> ProjectivePoint {
+	ProjectivePoint::from_affine(&N_X, &N_Y)
+}
+
+/// SPAKE2 state before the public shares have been exchanged.
+pub struct Spake2<'a> {
+	role: Role,
+	w: Limbs,
+	ephemeral: Limbs,
+	public_share: PublicShare,
+	identity_a: &'a [u8],
+	identity_b: &'a [u8],
+	aad: &'a [u8],
+}
+
+impl Drop for Spake2<'_> {
+	fn drop(&mut self) {
+		self.w.zeroize();
+		self.ephemeral.zeroize();
+	}
+}
+
+impl core::fmt::Debug for Spake2<'_> {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(
+			f,
+			"Spake2 {{ role: {:?}, public_share: {:?}, ***OMITTED*** }}",
+			self.role, self.public_share
+		)
+	}
+}
+
+impl<'a> Spake2<'a> {
+	#[must_use]
+	/// Initialize SPAKE2 and compute this party's public share.
+	pub fn new(
+		role: Role,
+		hashed_password: &HashedPassword,
+		identity_a: &'a [u8],
+		identity_b: &'a [u8],
+		aad: &'a [u8],
+		ephemeral_key: EphemeralKey,
+	) -> Result<Self, UnknownCryptoError> {
+		let ephemeral = scalar_from_bytes(ephemeral_key.unprotected_as_bytes())?;
+		// A 256-bit value is less than 2n, so a single reduction is enough.
+		let w = reduce_once(
+			&limbs_from_bytes(hashed_password.unprotected_as_bytes()),
+			0,
+			&N,
+		);
+
+		let blinding_point = match role {
+			Role::A => point_m(),
+			Role::B => point_n(),
+		};
+		let share = ProjectivePoint::generator()
+			.mul(&ephemeral)
+			.add(&blinding_point.mul(&w));
+
+		Ok(Self {
+			role,
+			w,
+			ephemeral,
+			public_share: PublicShare::from(share.to_uncompressed()?),
+			identity_a,
+			identity_b,
+			aad,
+		})
+	}
+
+	/// The public share to send to the other party.
+	pub fn public_share(&self) -> &PublicShare {
+		&self.public_share
+	}
+
+	#[must_use]
+	/// Compute the shared secret from the other party's public share, and
+	/// derive the confirmation messages.
+	pub fn finish(self, peer_share: &PublicShare) -> Result<KeyConfirmation, UnknownCryptoError> {
+		let peer_point = ProjectivePoint::from_uncompressed(peer_share.as_ref())?;
+		let peer_blinding_point = match self.role {
+			Role::A => point_n(),
+			Role::B => point_m(),
+		};
+		let mut shared_point = peer_point
+			.add(&peer_blinding_point.mul(&self.w).neg())
+			.mul(&self.ephemeral)
+			.to_uncompressed()?;
+
+		let (share_a, share_b) = match self.role {
+			Role::A => (self.public_share.as_ref(), peer_share.as_ref()),
+			Role::B => (peer_share.as_ref(), self.public_share.as_ref()),
+		};
+		let mut w = limbs_to_bytes(&self.w);
+
+		let identity_a_len = (self.identity_a.len() as u64).to_le_bytes();
+		let identity_b_len = (self.identity_b.len() as u64).to_le_bytes();
+		let share_len = (PUBLIC_SHARE_SIZE as u64).to_le_bytes();
+		let w_len = (ELEMENT_SIZE as u64).to_le_bytes();
+		let transcript: [&[u8]; 12] = [
+			&identity_a_len,
+			self.identity_a,
+			&identity_b_len,
+			self.identity_b,
+			&share_len,
+			share_a,
+			&share_len,
+			share_b,
+			&share_len,
+			&shared_point,
+			&w_len,
+			&w,
+		];
+
+		let mut hasher = sha256::init();
+		for part in transcript.iter() {
+			hasher.update(part)?;
+		}
+		let transcript_hash = hasher.finalize()?;
+		let (ke, ka) = transcript_hash.as_ref().split_at(SHARED_KEY_SIZE);
+
+		// HKDF-SHA256 with an empty salt. A single block of output is needed.
+		let mut prk = hmac_sha256(&[], &[ka])?;
+		let mut confirmation_keys = hmac_sha256(&prk, &[b"ConfirmationKeys", self.aad, &[0x01]])?;
+		let (kc_a, kc_b) = confirmation_keys.split_at(CONFIRMATION_KEY_SIZE);
+
+		let confirmation_a = Confirmation::from(hmac_sha256(kc_a, &transcript)?);
+		let confirmation_b = Confirmation::from(hmac_sha256(kc_b, &transcript)?);
+		let shared_key = SharedKey::from_slice(ke)?;
+
+		shared_point.zeroize();
+		w.zeroize();
+		prk.zeroize();
+		confirmation_keys.zeroize();
+
+		let (confirmation, expected_peer_confirmation) = match self.role {
+			Role::A => (confirmation_a, confirmation_b),
+			Role::B => (confirmation_b, confirmation_a),
+		};
+
+		Ok(KeyConfirmation {
+			shared_key,
+			confirmation,
+			expected_peer_confirmation,
+		})
+	}
+}
+
+/// SPAKE2 state after the public shares have been exchanged, waiting for the
+/// other party's confirmation message.
+pub struct KeyConfirmation {
+	shared_key: SharedKey,
+	confirmation: Confirmation,
+	expected_peer_confirmation: Confirmation,
+}
+
+impl core::fmt::Debug for KeyConfirmation {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "KeyConfirmation {{***OMITTED***}}")
+	}
+}
+
+impl KeyConfirmation {
+	/// The confirmation message to send to the other party.
+	pub fn confirmation(&self) -> &Confirmation {
+		&self.confirmation
+	}
+
+	#[must_use]
+	/// Verify the other party's confirmation message in constant time,
+	/// returning the shared key if it is valid.
+	pub fn verify(self, peer_confirmation: &Confirmation) -> Result<SharedKey, UnknownCryptoError> {
+		if &self.expected_peer_confirmation == peer_confirmation {
+			Ok(self.shared_key)
+		} else {
+			Err(UnknownCryptoError::InvalidTag)
+		}
+	}
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	fn exchange(
+		password_a: &HashedPassword,
+		password_b: &HashedPassword,
+		aad_a: &[u8],
+		aad_b: &[u8],
+	) -> (KeyConfirmation, KeyConfirmation) {
+		let a = Spake2::new(
+			Role::A,
+			password_a,
+			b"client",
+			b"server",
+			aad_a,
+			EphemeralKey::from([0x11u8; 32]),
+		)
+		.unwrap();
+		let b = Spake2::new(
+			Role::B,
+			password_b,
+			b"client",
+			b"server",
+			aad_b,
+			EphemeralKey::from([0x22u8; 32]),
+		)
+		.unwrap();
+		let share_a = *a.public_share();
+		let share_b = *b.public_share();
+
+		(a.finish(&share_b).unwrap(), b.finish(&share_a).unwrap())
+	}
+
+	mod test_spake2 {
+		use super::*;
+
+		#[test]
+		fn test_same_password_ok() {
+			let password = HashedPassword::from_slice(&[0x42u8; 32]).unwrap();
+			let (a, b) = exchange(&password, &password, b"", b"");
+			let confirmation_a = *a.confirmation();
+			let confirmation_b = *b.confirmation();
+
+			assert!(confirmation_a != confirmation_b);
+			assert!(a.verify(&confirmation_b).unwrap() == b.verify(&confirmation_a).unwrap());
+		}
+
+		#[test]
+		fn test_different_password_err() {
+			let password_a = HashedPassword::from_slice(&[0x42u8; 32]).unwrap();
+			let password_b = HashedPassword::from_slice(&[0x43u8; 32]).unwrap();
+			let (a, b) = exchange(&password_a, &password_b, b"", b"");
+			let confirmation_a = *a.confirmation();
+			let confirmation_b = *b.confirmation();
+
+			assert!(a.verify(&confirmation_b).is_err());
+			assert!(b.verify(&confirmation_a).is_err());
+		}
+
+		#[test]
+		fn test_different_aad_err() {
+			let password = HashedPassword::from_slice(&[0x42u8; 32]).unwrap();
+			let (a, b) = exchange(&password, &password, b"aad", b"daa");
+			let confirmation_a = *a.confirmation();
+			let confirmation_b = *b.confirmation();
+
+			assert!(a.verify(&confirmation_b).is_err());
+			assert!(b.verify(&confirmation_a).is_err());
+		}
+
+		#[test]
+		fn test_reflected_confirmation_err() {
+			let password = HashedPassword::from_slice(&[0x42u8; 32]).unwrap();
+			let (a, _) = exchange(&password, &password, b"", b"");
+			let confirmation_a = *a.confirmation();
+
+			assert!(a.verify(&confirmation_a).is_err());
+		}
+
+		#[test]
+		fn test_invalid_ephemeral_key_err() {
+			let password = HashedPassword::from_slice(&[0x42u8; 32]).unwrap();
+			assert!(Spake2::new(
+				Role::A,
+				&password,
+				b"",
+				b"",
+				b"",
+				EphemeralKey::from([0u8; 32])
+			)
+			.is_err());
+			assert!(Spake2::new(
+				Role::A,
+				&password,
+				b"",
+				b"",
+				b"",
+				EphemeralKey::from([0xffu8; 32])
+			)
+			.is_err());
+		}
+
+		#[test]
+		fn test_invalid_peer_share_err() {
+			let password = HashedPassword::from_slice(&[0x42u8; 32]).unwrap();
+			let a = || {
+				Spake2::new(
+					Role::A,
+					&password,
+					b"",
+					b"",
+					b"",
+					EphemeralKey::from([0x11u8; 32]),
+				)
+				.unwrap()
+			};
+
+			let mut share = [0u8; PUBLIC_SHARE_SIZE];
+			share.copy_from_slice(a().public_share().as_ref());
+			share[64] ^= 1;
+			assert!(a().finish(&PublicShare::from(share)).is_err());
+			assert!(a()
+				.finish(&PublicShare::from([0u8; PUBLIC_SHARE_SIZE]))
+				.is_err());
+		}
+
+		#[test]
+		fn test_peer_share_cancelling_to_infinity_err() {
+			// A peer share of w*N makes the shared point the point at infinity.
+			let password = HashedPassword::from_slice(&[0x42u8; 32]).unwrap();
+			let w = reduce_once(&limbs_from_bytes(&[0x42u8; 32]), 0, &N);
+			let share = PublicShare::from(point_n().mul(&w).to_uncompressed().unwrap());
+
+			let a = Spake2::new(
+				Role::A,
+				&password,
+				b"",
+				b"",
+				b"",
+				EphemeralKey::from([0x11u8; 32]),
+			)
+			.unwrap();
+			assert!(a.finish(&share).is_err());
+		}
+	}
+
+	// Proptests. Only exectued when NOT testing no_std.
+	#[cfg(feature = "safe_api")]
+	mod proptest {
+		use super::*;
+
+		quickcheck! {
+			/// Two parties with the same password and identities should always agree.
+			fn prop_same_password_agrees(identity_a: Vec<u8>, identity_b: Vec<u8>) -> bool {
+				let password = HashedPassword::generate();
+				let a = Spake2::new(Role::A, &password, &identity_a, &identity_b, b"", EphemeralKey::generate()).unwrap();
+				let b = Spake2::new(Role::B, &password, &identity_a, &identity_b, b"", EphemeralKey::generate()).unwrap();
+				let share_a = *a.public_share();
+				let share_b = *b.public_share();
+				let a = a.finish(&share_b).unwrap();
+				let b = b.finish(&share_a).unwrap();
+				let confirmation_a = *a.confirmation();
+				let confirmation_b = *b.confirmation();
+
+				a.verify(&confirmation_b).unwrap() == b.verify(&confirmation_a).unwrap()
+			}
+		}
+	}
+}
@@ -0,0 +1,1026 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Parameters:
+//! - `group`: The RFC 5054 group to use. Both parties must use the same group.
+//! - `identity`: The username, `I` in the RFC.
+//! - `password`: The password, `P` in the RFC.
+//! - `salt`: The salt stored alongside the verifier, `s` in the RFC.
+//! - `verifier`: The password verifier stored by the server, `v` in the RFC.
+//! - `ephemeral_key`: A fresh secret exponent, used for this session only (`a`
+//!   for the client, `b` for the server).
+//! - `client_public`/`server_public`: The public values `A` and `B`.
+//! - `client_proof`/`server_proof`: The proofs `M1` and `M2`.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `salt` or `identity` are empty.
+//! - `verifier`, `client_public` or `server_public` are not the length of the
+//!   group's modulus.
+//! - `client_public` or `server_public` are zero, or not less than the modulus.
+//! - `ephemeral_key` is zero.
+//! - The scrambling parameter `u` is zero.
+//! - `client_proof` or `server_proof` do not match the expected proof.
+//!
+//! # Security:
+//! - The `ephemeral_key` must be generated with [`EphemeralKey::generate()`]
+//!   for every session and must never be reused.
+//! - `x` is derived from the password with a single SHA512 invocation, as the
+//!   RFC specifies. The verifier is therefore only as hard to brute-force as
+//!   the password itself. Stretching the password with a memory-hard function
+//!   before passing it as `password` is recommended when interoperability
+//!   allows it.
+//! - The verifier must be kept secret. An attacker who learns it can run an
+//!   offline dictionary attack, and can impersonate the server.
+//! - The [`SessionKey`] is only returned once the peer's proof has been
+//!   verified. The server must not reveal its proof before having verified
+//!   the client's.
+//!
+//! # Recommendation:
+//! - SRP-6a should only be used for interoperability with existing
+//!   deployments. New protocols should use a modern PAKE, such as [SPAKE2].
+//!
+//! # About:
+//! SHA512 is used as the hash function `H` and values are encoded as follows:
+//! - `k = H(N | PAD(g))`
+//! - `x = H(s | H(I | ":" | P))`
+//! - `u = H(PAD(A) | PAD(B))`
+//! - `K = H(PAD(S))`
+//! - `M1 = H(H(N) XOR H(g) | H(I) | s | PAD(A) | PAD(B) | K)`
+//! - `M2 = H(PAD(A) | M1 | K)`
+//!
+//! where `PAD()` left-pads a value with zeros to the length of `N` and `g` is
+//! encoded as a single byte when hashed on its own.
+//!
+//! # Example:
+//! ```rust
+//! use orion::hazardous::pake::srp6a::{self, EphemeralKey, Group, SrpClient, SrpServer};
+//!
+//! // Registration: the client sends the salt and verifier to the server.
+//! let salt = [0x42; 16];
+//! let verifier = srp6a::create_verifier(Group::Group3072, b"alice", b"password", &salt)?;
+//!
+//! // Login: the server looks up the salt and verifier for "alice".
+//! let client = SrpClient::new(Group::Group3072, EphemeralKey::generate())?;
+//! let server = SrpServer::new(Group::Group3072, &verifier, EphemeralKey::generate())?;
+//! let client_public = *client.public_value();
+//! let server_public = *server.public_value();
+//!
+//! let client = client.process_challenge(b"alice", b"password", &salt, &server_public)?;
+//! let (server_proof, server_key) =
+//! 	server.verify_client(b"alice", &salt, &client_public, client.proof())?;
+//! let client_key = client.verify_server(&server_proof)?;
+//!
+//! assert!(client_key == server_key);
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`EphemeralKey::generate()`]: https://docs.rs/orion/latest/orion/hazardous/pake/srp6a/struct.EphemeralKey.html
+//! [`SessionKey`]: https://docs.rs/orion/latest/orion/hazardous/pake/srp6a/struct.SessionKey.html
+//! [SPAKE2]: https://docs.rs/orion/latest/orion/hazardous/pake/spake2_p256/index.html
+
+use crate::{
+	errors::UnknownCryptoError,
+	hazardous::hash::sha512::{self, SHA512_OUTSIZE},
+};
+use zeroize::Zeroize;
+
+/// The maximum size of a group's modulus.
+const MAX_MODULUS_SIZE: usize = 512;
+/// The maximum amount of 64-bit limbs needed to represent a group element.
+const MAX_LIMBS: usize = MAX_MODULUS_SIZE / 8;
+/// The size of an ephemeral key.
+pub const EPHEMERAL_KEY_SIZE: usize = 32;
+/// The generator of both supported groups.
+const GENERATOR: u8 = 5;
+
+/// The 3072-bit modulus from RFC 5054, Appendix A.
+const MODULUS_3072: [u8; 384] = [
+	0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xc9, 0x0f, 0xda, 0xa2, 0x21, 0x68, 0xc2, 0x34,
+	0xc4, 0xc6, 0x62, 0x8b, 0x80, 0xdc, 0x1c, 0xd1, 0x29, 0x02, 0x4e, 0x08, 0x8a, 0x67, 0xcc, 0x74,
+	0x02, 0x0b, 0xbe, 0xa6, 0x3b, 0x13, 0x9b, 0x22, 0x51, 0x4a, 0x08, 0x79, 0x8e, 0x34, 0x04, 0xdd,
+	0xef, 0x95, 0x19, 0xb3, 0xcd, 0x3a, 0x43, 0x1b, 0x30, 0x2b, 0x0a, 0x6d, 0xf2, 0x5f, 0x14, 0x37,
+	0x4f, 0xe1, 0x35, 0x6d, 0x6d, 0x51, 0xc2, 0x45, 0xe4, 0x85, 0xb5, 0x76, 0x62, 0x5e, 0x7e, 0xc6,
+	0xf4, 0x4c, 0x42, 0xe9, 0xa6, 0x37, 0xed, 0x6b, 0x0b, 0xff, 0x5c, 0xb6, 0xf4, 0x06, 0xb7, 0xed,
+	0xee, 0x38, 0x6b, 0xfb, 0x5a, 0x89, 0x9f, 0xa5, 0xae, 0x9f, 0x24, 0x11, 0x7c, 0x4b, 0x1f, 0xe6,
+	0x49, 0x28, 0x66, 0x51, 0xec, 0xe4, 0x5b, 0x3d, 0xc2, 0x00, 0x7c, 0xb8, 0xa1, 0x63, 0xbf, 0x05,
+	0x98, 0xda, 0x48, 0x36, 0x1c, 0x55, 0xd3, 0x9a, 0x69, 0x16, 0x3f, 0xa8, 0xfd, 0x24, 0xcf, 0x5f,
+	0x83, 0x65, 0x5d, 0x23, 0xdc, 0xa3, 0xad, 0x96, 0x1c, 0x62, 0xf3, 0x56, 0x20, 0x85, 0x52, 0xbb,
+	0x9e, 0xd5, 0x29, 0x07, 0x70, 0x96, 0x96, 0x6d, 0x67, 0x0c, 0x35, 0x4e, 0x4a, 0xbc, 0x98, 0x04,
+	0xf1, 0x74, 0x6c, 0x08, 0xca, 0x18, 0x21, 0x7c, 0x32, 0x90, 0x5e, 0x46, 0x2e, 0x36, 0xce, 0x3b,
+	0xe3, 0x9e, 0x77, 0x2c, 0x18, 0x0e, 0x86, 0x03, 0x9b, 0x27, 0x83, 0xa2, 0xec, 0x07, 0xa2, 0x8f,
+	0xb5, 0xc5, 0x5d, 0xf0, 0x6f, 0x4c, 0x52, 0xc9, 0xde, 0x2b, 0xcb, 0xf6, 0x95, 0x58, 0x17, 0x18,
+	0x39, 0x95, 0x49, 0x7c, 0xea, 0x95, 0x6a, 0xe5, 0x15, 0xd2, 0x26, 0x18, 0x98, 0xfa, 0x05, 0x10,
+	0x15, 0x72, 0x8e, 0x5a, 0x8a, 0xaa, 0xc4, 0x2d, 0xad, 0x33, 0x17, 0x0d, 0x04, 0x50, 0x7a, 0x33,
+	0xa8, 0x55, 0x21, 0xab, 0xdf, 0x1c, 0xba, 0x64, 0xec, 0xfb, 0x85, 0x04, 0x58, 0xdb, 0xef, 0x0a,
+	0x8a, 0xea, 0x71, 0x57, 0x5d, 0x06, 0x0c, 0x7d, 0xb3, 0x97, 0x0f, 0x85, 0xa6, 0xe1, 0xe4, 0xc7,
+	0xab, 0xf5, 0xae, 0x8c, 0xdb, 0x09, 0x33, 0xd7, 0x1e, 0x8c, 0x94, 0xe0, 0x4a, 0x25, 0x61, 0x9d,
+	0xce, 0xe3, 0xd2, 0x26, 0x1a, 0xd2, 0xee, 0x6b, 0xf1, 0x2f, 0xfa, 0x06, 0xd9, 0x8a, 0x08, 0x64,
+	0xd8, 0x76, 0x02, 0x73, 0x3e, 0xc8, 0x6a, 0x64, 0x52, 0x1f, 0x2b, 0x18, 0x17, 0x7b, 0x20, 0x0c,
+	0xbb, 0xe1, 0x17, 0x57, 0x7a, 0x61, 0x5d, 0x6c, 0x77, 0x09, 0x88, 0xc0, 0xba, 0xd9, 0x46, 0xe2,
+	0x08, 0xe2, 0x4f, 0xa0, 0x74, 0xe5, 0xab, 0x31, 0x43, 0xdb, 0x5b, 0xfc, 0xe0, 0xfd, 0x10, 0x8e,
+	0x4b, 0x82, 0xd1, 0x20, 0xa9, 0x3a, 0xd2, 0xca, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+];
+
+/// The 4096-bit modulus from RFC 5054, Appendix A.
+const MODULUS_4096: [u8; 512] = [
+	0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xc9, 0x0f, 0xda, 0xa2, 0x21, 0x68, 0xc2, 0x34,
+	0xc4, 0xc6, 0x62, 0x8b, 0x80, 0xdc, 0x1c, 0xd1, 0x29, 0x02, 0x4e, 0x08, 0x8a, 0x67, 0xcc, 0x74,
+	0x02, 0x0b, 0xbe, 0xa6, 0x3b, 0x13, 0x9b, 0x22, 0x51, 0x4a, 0x08, 0x79, 0x8e, 0x34, 0x04, 0xdd,
+	0xef, 0x95, 0x19, 0xb3, 0xcd, 0x3a, 0x43, 0x1b, 0x30, 0x2b, 0x0a, 0x6d, 0xf2, 0x5f, 0x14, 0x37,
+	0x4f, 0xe1, 0x35, 0x6d, 0x6d, 0x51, 0xc2, 0x45, 0xe4, 0x85, 0xb5, 0x76, 0x62, 0x5e, 0x7e, 0xc6,
+	0xf4, 0x4c, 0x42, 0xe9, 0xa6, 0x37, 0xed, 0x6b, 0x0b, 0xff, 0x5c, 0xb6, 0xf4, 0x06, 0xb7, 0xed,
+	0xee, 0x38, 0x6b, 0xfb, 0x5a, 0x89, 0x9f, 0xa5, 0xae, 0x9f, 0x24, 0x11, 0x7c, 0x4b, 0x1f, 0xe6,
+	0x49, 0x28, 0x66, 0x51, 0xec, 0xe4, 0x5b, 0x3d, 0xc2, 0x00, 0x7c, 0xb8, 0xa1, 0x63, 0xbf, 0x05,
+	0x98, 0xda, 0x48, 0x36, 0x1c, 0x55, 0xd3, 0x9a, 0x69, 0x16, 0x3f, 0xa8, 0xfd, 0x24, 0xcf, 0x5f,
+	0x83, 0x65, 0x5d, 0x23, 0xdc, 0xa3, 0xad, 0x96, 0x1c, 0x62, 0xf3, 0x56, 0x20, 0x85, 0x52, 0xbb,
+	0x9e, 0xd5, 0x29, 0x07, 0x70, 0x96, 0x96, 0x6d, 0x67, 0x0c, 0x35, 0x4e, 0x4a, 0xbc, 0x98, 0x04,
+	0xf1, 0x74, 0x6c, 0x08, 0xca, 0x18, 0x21, 0x7c, 0x32, 0x90, 0x5e, 0x46, 0x2e, 0x36, 0xce, 0x3b,
+	0xe3, 0x9e, 0x77, 0x2c, 0x18, 0x0e, 0x86, 0x03, 0x9b, 0x27, 0x83, 0xa2, 0xec, 0x07, 0xa2, 0x8f,
+	0xb5, 0xc5, 0x5d, 0xf0, 0x6f, 0x4c, 0x52, 0xc9, 0xde, 0x2b, 0xcb, 0xf6, 0x95, 0x58, 0x17, 0x18,
+	0x39, 0x95, 0x49, 0x7c, 0xea, 0x95, 0x6a, 0xe5, 0x15, 0xd2, 0x26, 0x18, 0x98, 0xfa, 0x05, 0x10,
+	0x15, 0x72, 0x8e, 0x5a, 0x8a, 0xaa, 0xc4, 0x2d, 0xad, 0x33, 0x17, 0x0d, 0x04, 0x50, 0x7a, 0x33,
+	0xa8, 0x55, 0x21, 0xab, 0xdf, 0x1c, 0xba, 0x64, 0xec, 0xfb, 0x85, 0x04, 0x58, 0xdb, 0xef, 0x0a,
+	0x8a, 0xea, 0x71, 0x57, 0x5d, 0x06, 0x0c, 0x7d, 0xb3, 0x97, 0x0f, 0x85, 0xa6, 0xe1, 0xe4, 0xc7,
+	0xab, 0xf5, 0xae, 0x8c, 0xdb, 0x09, 0x33, 0xd7, 0x1e, 0x8c, 0x94, 0xe0, 0x4a, 0x25, 0x61, 0x9d,
+	0xce, 0xe3, 0xd2, 0x26, 0x1a, 0xd2, 0xee, 0x6b, 0xf1, 0x2f, 0xfa, 0x06, 0xd9, 0x8a, 0x08, 0x64,
+	0xd8, 0x76, 0x02, 0x73, 0x3e, 0xc8, 0x6a, 0x64, 0x52, 0x1f, 0x2b, 0x18, 0x17, 0x7b, 0x20, 0x0c,
+	0xbb, 0xe1, 0x17, 0x57, 0x7a, 0x61, 0x5d, 0x6c, 0x77, 0x09, 0x88, 0xc0, 0xba, 0xd9, 0x46, 0xe2,
+	0x08, 0xe2, 0x4f, 0xa0, 0x74, 0xe5, 0xab, 0x31, 0x43, 0xdb, 0x5b, 0xfc, 0xe0, 0xfd, 0x10, 0x8e,
+	0x4b, 0x82, 0xd1, 0x20, 0xa9, 0x21, 0x08, 0x01, 0x1a, 0x72, 0x3c, 0x12, 0xa7, 0x87, 0xe6, 0xd7,
+	0x88, 0x71, 0x9a, 0x10, 0xbd, 0xba, 0x5b, 0x26, 0x99, 0xc3, 0x27, 0x18, 0x6a, 0xf4, 0xe2, 0x3c,
+	0x1a, 0x94, 0x68, 0x34, 0xb6, 0x15, 0x0b, 0xda, 0x25, 0x83, 0xe9, 0xca, 0x2a, 0xd4, 0x4c, 0xe8,
+	0xdb, 0xbb, 0xc2, 0xdb, 0x04, 0xde, 0x8e, 0xf9, 0x2e, 0x8e, 0xfc, 0x14, 0x1f, 0xbe, 0xca, 0xa6,
+	0x28, 0x7c, 0x59, 0x47, 0x4e, 0x6b, 0xc0, 0x5d, 0x99, 0xb2, 0x96, 0x4f, 0xa0, 0x90, 0xc3, 0xa2,
+	0x23, 0x3b, 0xa1, 0x86, 0x51, 0x5b, 0xe7, 0xed, 0x1f, 0x61, 0x29, 0x70, 0xce, 0xe2, 0xd7, 0xaf,
+	0xb8, 0x1b, 0xdd, 0x76, 0x21, 0x70, 0x48, 0x1c, 0xd0, 0x06, 0x91, 0x27, 0xd5, 0xb0, 0x5a, 0xa9,
+	0x93, 0xb4, 0xea, 0x98, 0x8d, 0x8f, 0xdd, 0xc1, 0x86, 0xff, 0xb7, 0xdc, 0x90, 0xa6, 0xc0, 0x8f,
+	0x4d, 0xf4, 0x35, 0xc9, 0x34, 0x06, 0x31, 0x99, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// The supported groups from RFC 5054. Both use the generator `g = 5`.
+pub enum Group {
+	/// The 3072-bit group.
+	Group3072,
+	/// The 4096-bit group.
+	Group4096,
+}
+
+impl Group {
+	/// The modulus `N` of the group, big-endian.
+	fn modulus(self) -> &'static [u8] {
+		match self {
+			Group::Group3072 => &MODULUS_3072,
+			Group::Group4096 => &MODULUS_4096,
+		}
+	}
+
+	/// The size of the modulus in bytes.
+	fn size(self) -> usize {
+		self.modulus().len()
+	}
+}
+
+construct_public! {
+	/// A type to represent the password `Verifier` (`v`) that SRP-6a uses.
+	///
+	/// Whether the length matches the group is checked when it is used.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is less than 384 bytes.
+	/// - `slice` is greater than 512 bytes.
+	(Verifier, test_verifier, 384, MAX_MODULUS_SIZE)
+}
+
+construct_public! {
+	/// A type to represent the `PublicValue` (`A` or `B`) that SRP-6a exchanges.
+	///
+	/// Whether the length matches the group is checked when it is used.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is less than 384 bytes.
+	/// - `slice` is greater than 512 bytes.
+	(PublicValue, test_public_value, 384, MAX_MODULUS_SIZE)
+}
+
+construct_secret_key! {
+	/// A type to represent the `EphemeralKey` (`a` or `b`) that SRP-6a uses.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 32 bytes.
+	///
+	/// # Panics:
+	/// A panic will occur if:
+	/// - Failure to generate random bytes securely.
+	(EphemeralKey, test_ephemeral_key, EPHEMERAL_KEY_SIZE, EPHEMERAL_KEY_SIZE, EPHEMERAL_KEY_SIZE)
+}
+
+impl_from_trait!(EphemeralKey, EPHEMERAL_KEY_SIZE);
+
+construct_secret_key! {
+	/// A type to represent the `SessionKey` (`K`) that SRP-6a produces.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 64 bytes.
+	///
+	/// # Panics:
+	/// A panic will occur if:
+	/// - Failure to generate random bytes securely.
+	(SessionKey, test_session_key, SHA512_OUTSIZE, SHA512_OUTSIZE, SHA512_OUTSIZE)
+}
+
+impl_from_trait!(SessionKey, SHA512_OUTSIZE);
+
+construct_tag! {
+	/// A type to represent a `Proof` (`M1` or `M2`) that SRP-6a exchanges.
+	///
+	/// # Errors:
+	/// An error will be returned if:
+	/// - `slice` is not 64 bytes.
+	(Proof, test_proof, SHA512_OUTSIZE, SHA512_OUTSIZE)
+}
+
+impl_from_trait!(Proof, SHA512_OUTSIZE);
+
+/// Up to 4096 bits, as 64-bit limbs, least significant first.
+type BigLimbs = [u64; MAX_LIMBS];
+
+#[inline]
+/// Return `a` if `choice` is 0 and `b` if `choice` is 1, in constant time.
+fn select(a: &BigLimbs, b: &BigLimbs, choice: u64) -> BigLimbs {
+	let mask = 0u64.wrapping_sub(choice);
+	let mut ret = [0u64; MAX_LIMBS];
+	for (idx, limb) in ret.iter_mut().enumerate() {
+		*limb = a[idx] ^ (mask & (a[idx] ^ b[idx]));
+	}
+
+	ret
+}
+
+/// Load big-endian `bytes` into limbs. `bytes` must not be longer than
+/// `MAX_MODULUS_SIZE`.
+fn limbs_from_bytes(bytes: &[u8]) -> BigLimbs {
+	debug_assert!(bytes.len() <= MAX_MODULUS_SIZE);
+	let mut limbs = [0u64; MAX_LIMBS];
+	for (idx, byte) in bytes.iter().rev().enumerate() {
+		limbs[idx / 8] |= u64::from(*byte) << (8 * (idx % 8));
+	}
+
+	limbs
+}
+
+/// Store limbs as big-endian bytes, filling all of `dst_out`.
+fn limbs_to_bytes(limbs: &BigLimbs, dst_out: &mut [u8]) {
+	debug_assert!(dst_out.len() <= MAX_MODULUS_SIZE);
+	for (idx, byte) in dst_out.iter_mut().rev().enumerate() {
+		*byte = (limbs[idx / 8] >> (8 * (idx % 8))) as u8;
+	}
+}
+
+/// Return 1 if `a` is zero and 0 otherwise, in constant time.
+fn is_zero(a: &BigLimbs) -> u64 {
+	let acc = a.iter().fold(0u64, |acc, limb| acc | limb);
+	1 ^ ((acc | acc.wrapping_neg()) >> 63)
+}
+
+/// A group modulus and its Montgomery constants.
+struct Modulus {
+	m: BigLimbs,
+	r2: BigLimbs,
+	m_inv: u64,
+	len: usize,
+}
+
+impl Modulus {
+	/// Setup the Montgomery constants for the modulus of `group`.
+	fn new(group: Group) -> Self {
+		let m = limbs_from_bytes(group.modulus());
+		let len = group.size() / 8;
+
+		// Newton's iteration for m^-1 mod 2^64, doubling the correct bits
+		// every round.
+		let mut inv = 1u64;
+		for _ in 0..6 {
+			inv = inv.wrapping_mul(2u64.wrapping_sub(m[0].wrapping_mul(inv)));
+		}
+
+		let mut modulus = Self {
+			m,
+			r2: [0u64; MAX_LIMBS],
+			m_inv: inv.wrapping_neg(),
+			len,
+		};
+
+		// R^2 = 2^(2 * 64 * len) mod m, by repeated doubling of 1.
+		let mut r2 = [0u64; MAX_LIMBS];
+		r2[0] = 1;
+		for _ in 0..(128 * len) {
+			r2 = modulus.add(&r2, &r2);
+		}
+		modulus.r2 = r2;
+
+		modulus
+	}
+
+	/// Return 1 if `a` < `m` and 0 otherwise, in constant time.
+	fn is_reduced(&self, a: &BigLimbs) -> u64 {
+		let mut borrow = 0u64;
+		for idx in 0..self.len {
+			let diff = u128::from(a[idx])
+				.wrapping_sub(u128::from(self.m[idx]))
+				.wrapping_sub(u128::from(borrow));
+			borrow = (diff >> 127) as u64;
+		}
+
+		borrow
+	}
+
+	/// Subtract `m` from the value `carry * 2^(64 * len) + a` if the value is
+	/// not less than `m`. The value must be less than `2 * m`.
+	fn reduce_once(&self, a: &BigLimbs, carry: u64) -> BigLimbs {
+		let mut diff = [0u64; MAX_LIMBS];
+		let mut borrow = 0u64;
+		for idx in 0..self.len {
+			let ret = u128::from(a[idx])
+				.wrapping_sub(u128::from(self.m[idx]))
+				.wrapping_sub(u128::from(borrow));
+			diff[idx] = ret as u64;
+			borrow = (ret >> 127) as u64;
+		}
+
+		select(&diff, a, (carry ^ 1) & borrow)
+	}
+
+	/// Addition modulo `m`. Both operands must be reduced.
+	fn add(&self, a: &BigLimbs, b: &BigLimbs) -> BigLimbs {
+		let mut sum = [0u64; MAX_LIMBS];
+		let mut carry = 0u64;
+		for idx in 0..self.len {
+			let ret = u128::from(a[idx]) + u128::from(b[idx]) + u128::from(carry);
+			sum[idx] = ret as u64;
+			carry = (ret >> 64) as u64;
+		}
+
+		self.reduce_once(&sum, carry)
+	}
+
+	/// Subtraction modulo `m`. Both operands must be reduced.
+	fn sub(&self, a: &BigLimbs, b: &BigLimbs) -> BigLimbs {
+		let mut diff = [0u64; MAX_LIMBS];
+		let mut borrow = 0u64;
+		for idx in 0..self.len {
+			let ret = u128::from(a[idx])
+				.wrapping_sub(u128::from(b[idx]))
+				.wrapping_sub(u128::from(borrow));
+			diff[idx] = ret as u64;
+			borrow = (ret >> 127) as u64;
+		}
+
+		// Add `m` back if the subtraction borrowed.
+		let mask = 0u64.wrapping_sub(borrow);
+		let mut carry = 0u64;
+		for idx in 0..self.len {
+			let ret = u128::from(diff[idx]) + u128::from(self.m[idx] & mask) + u128::from(carry);
+			diff[idx] = ret as u64;
+			carry = (ret >> 64) as u64;
+		}
+
+		diff
+	}
+
+	/// Montgomery multiplication (CIOS) returning `a * b * R^-1 mod m`.
+	fn mont_mul(&self, a: &BigLimbs, b: &BigLimbs) -> BigLimbs {
+		let len = self.len;
+		let mut t = [0u64; MAX_LIMBS + 2];
+
+		for b_i in b.iter().take(len) {
+			let mut carry = 0u64;
+			for j in 0..len {
+				let uv = u128::from(t[j]) + u128::from(a[j]) * u128::from(*b_i) + u128::from(carry);
+				t[j] = uv as u64;
+				carry = (uv >> 64) as u64;
+			}
+			let uv = u128::from(t[len]) + u128::from(carry);
+			t[len] = uv as u64;
+			t[len + 1] = (uv >> 64) as u64;
+
+			let m = t[0].wrapping_mul(self.m_inv);
+			let uv = u128::from(t[0]) + u128::from(m) * u128::from(self.m[0]);
+			carry = (uv >> 64) as u64;
+			for j in 1..len {
+				let uv =
+					u128::from(t[j]) + u128::from(m) * u128::from(self.m[j]) + u128::from(carry);
+				t[j - 1] = uv as u64;
+				carry = (uv >> 64) as u64;
+			}
+			let uv = u128::from(t[len]) + u128::from(carry);
+			t[len - 1] = uv as u64;
+			t[len] = t[len + 1] + ((uv >> 64) as u64);
+		}
+
+		let mut ret = [0u64; MAX_LIMBS];
+		ret[..len].copy_from_slice(&t[..len]);
+		let carry = t[len];
+		t.zeroize();
+
+		self.reduce_once(&ret, carry)
+	}
+
+	/// Convert a reduced value into the Montgomery domain.
+	fn to_mont(&self, a: &BigLimbs) -> BigLimbs {
+		self.mont_mul(a, &self.r2)
+	}
+
+	/// Convert out of the Montgomery domain.
+	fn from_mont(&self, a: &BigLimbs) -> BigLimbs {
+		let mut one = [0u64; MAX_LIMBS];
+		one[0] = 1;
+		self.mont_mul(a, &one)
+	}
+
+	/// Compute `base^exponent`, with `base` in the Montgomery domain and
+	/// `exponent` as big-endian bytes. This runs in constant time with
+	/// regards to the value of `exponent`, but not its length.
+	fn pow(&self, base: &BigLimbs, exponent: &[u8]) -> BigLimbs {
+		let mut one = [0u64; MAX_LIMBS];
+		one[0] = 1;
+		let mut result = self.to_mont(&one);
+
+		for byte in exponent.iter() {
+			for bit in (0..8).rev() {
+				result = self.mont_mul(&result, &result);
+				let product = self.mont_mul(&result, base);
+				result = select(&result, &product, u64::from((byte >> bit) & 1));
+			}
+		}
+
+		result
+	}
+
+	/// The generator in the Montgomery domain.
+	fn generator(&self) -> BigLimbs {
+		self.to_mont(&limbs_from_bytes(&[GENERATOR]))
+	}
+
+	/// Load a public value that must be in the range `[1, m - 1]`, returning
+	/// it in the Montgomery domain.
+	fn load_public(&self, bytes: &[u8]) -> Result<BigLimbs, UnknownCryptoError> {
+		if bytes.len() != self.len * 8 {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+		let value = limbs_from_bytes(bytes);
+		if (self.is_reduced(&value) & (is_zero(&value) ^ 1)) != 1 {
+			return Err(UnknownCryptoError::InvalidKey);
+		}
+
+		Ok(self.to_mont(&value))
+	}
+
+	/// Store a value in the Montgomery domain as `PAD(value)`.
+	fn store(&self, a: &BigLimbs) -> [u8; MAX_MODULUS_SIZE] {
+		let mut bytes = [0u8; MAX_MODULUS_SIZE];
+		limbs_to_bytes(&self.from_mont(a), &mut bytes[..self.len * 8]);
+
+		bytes
+	}
+}
+
+/// SHA512 over the concatenation of `data`.
+fn hash(data: &[&[u8]]) -> Result<[u8; SHA512_OUTSIZE], UnknownCryptoError> {
+	let mut state = sha512::init();
+	for part in data.iter() {
+		state.update(part)?;
+	}
+
+	let mut digest = [0u8; SHA512_OUTSIZE];
+	digest.copy_from_slice(state.finalize()?.as_ref());
+
+	Ok(digest)
+}
+
+/// The multiplier parameter `k = H(N | PAD(g))`, in the Montgomery domain.
+fn multiplier(group: Group, modulus: &Modulus) -> Result<BigLimbs, UnknownCryptoError> {
+	let mut padded_generator = [0u8; MAX_MODULUS_SIZE];
+	padded_generator[group.size() - 1] = GENERATOR;
+	let k = hash(&[group.modulus(), &padded_generator[..group.size()]])?;
+
+	Ok(modulus.to_mont(&limbs_from_bytes(&k)))
+}
+
+/// The private key `x = H(s | H(I | ":" | P))`.
+fn private_key(
+	identity: &[u8],
+	password: &[u8],
+	salt: &[u8],
+) -> Result<[u8; SHA512_OUTSIZE], UnknownCryptoError> {
+	if identity.is_empty() || salt.is_empty() {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let mut inner = hash(&[identity, b":", password])?;
+	let x = hash(&[salt, &inner]);
+	inner.zeroize();
+
+	x
+}
+
+/// The scrambling parameter `u = H(PAD(A) | PAD(B))`.
+fn scrambler(
+	client_public: &[u8],
+	server_public: &[u8],
+) -> Result<[u8; SHA512_OUTSIZE], UnknownCryptoError> {
+	let u = hash(&[client_public, server_public])?;
+	if u.iter().all(|byte| *byte == 0) {
+		return Err(UnknownCryptoError::InvalidKey);
+	}
+
+	Ok(u)
+}
+
+/// The proofs `M1` and `M2`, together with the session key `K`.
+fn session_proofs(
+	group: Group,
+	identity: &[u8],
+	salt: &[u8],
+	client_public: &[u8],
+	server_public: &[u8],
+	premaster_secret: &[u8],
+) -> Result<(Proof, Proof, SessionKey), UnknownCryptoError> {
+	let mut key = hash(&[premaster_secret])?;
+
+	let mut group_hash = hash(&[group.modulus()])?;
+	let generator_hash = hash(&[&[GENERATOR]])?;
+	for (idx, itm) in group_hash.iter_mut().enumerate() {
+		*itm ^= generator_hash[idx];
+	}
+	let identity_hash = hash(&[identity])?;
+
+	let client_proof = hash(&[
+		&group_hash,
+		&identity_hash,
+		salt,
+		client_public,
+		server_public,
+		&key,
+	])?;
+	let server_proof = hash(&[client_public, &client_proof, &key])?;
+	let session_key = SessionKey::from_slice(&key)?;
+	key.zeroize();
+
+	Ok((
+		Proof::from(client_proof),
+		Proof::from(server_proof),
+		session_key,
+	))
+}
+
+/// Load an ephemeral key, which must not be zero.
+fn load_ephemeral_key(ephemeral_key: &EphemeralKey) -> Result<(), UnknownCryptoError> {
+	if is_zero(&limbs_from_bytes(ephemeral_key.unprotected_as_bytes())) == 1 {
+		return Err(UnknownCryptoError::InvalidKey);
+	}
+
+	Ok(())
+}
+
+#[must_use]
+/// Create the password verifier `v = g^x`, to be stored by the server along
+/// with `salt`.
+pub fn create_verifier(
+	group: Group,
+	identity: &[u8],
+	password: &[u8],
+	salt: &[u8],
+) -> Result<Verifier, UnknownCryptoError> {
+	let modulus = Modulus::new(group);
+	let mut x = private_key(identity, password, salt)?;
+	let verifier = modulus.store(&modulus.pow(&modulus.generator(), &x));
+	x.zeroize();
+
+	Verifier::from_slice(&verifier[..group.size()])
+}
+
+/// The client side of SRP-6a, before the server's public value is known.
+pub struct SrpClient {
+	group: Group,
+	ephemeral_key: EphemeralKey,
+	public_value: PublicValue,
+}
+
+impl core::fmt::Debug for SrpClient {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(
+			f,
+			"SrpClient {{ group: {:?}, public_value: {:?}, ephemeral_key: ***OMITTED*** }}",
+			self.group, self.public_value
+		)
+	}
+}
+
+impl SrpClient {
+	#[must_use]
+	/// Initialize the client and compute its public value `A = g^a`.
+	pub fn new(group: Group, ephemeral_key: EphemeralKey) -> Result<Self, UnknownCryptoError> {
+		load_ephemeral_key(&ephemeral_key)?;
+		let modulus = Modulus::new(group);
+		let public_value =
+			modulus.store(&modulus.pow(&modulus.generator(), ephemeral_key.unprotected_as_bytes()));
+
+		Ok(Self {
+			group,
+			ephemeral_key,
+			public_value: PublicValue::from_slice(&public_value[..group.size()])?,
+		})
+	}
+
+	/// The public value `A` to send to the server.
+	pub fn public_value(&self) -> &PublicValue {
+		&self.public_value
+	}
+
+	#[must_use]
+	/// Process the server's public value and compute the client's proof `M1`.
+	pub fn process_challenge(
+		self,
+		identity: &[u8],
+		password: &[u8],
+		salt: &[u8],
+		server_public: &PublicValue,
+	) -> Result<ClientVerification, UnknownCryptoError> {
+		let modulus = Modulus::new(self.group);
+		let b = modulus.load_public(server_public.as_ref())?;
+		let u = scrambler(self.public_value.as_ref(), server_public.as_ref())?;
+		let mut x = private_key(identity, password, salt)?;
+		let k = multiplier(self.group, &modulus)?;
+
+		// S = (B - k * g^x)^(a + u * x) = base^a * (base^u)^x
+		let mut base = modulus.sub(
+			&b,
+			&modulus.mont_mul(&k, &modulus.pow(&modulus.generator(), &x)),
+		);
+		let mut premaster_secret = modulus.store(&modulus.mont_mul(
+			&modulus.pow(&base, self.ephemeral_key.unprotected_as_bytes()),
+			&modulus.pow(&modulus.pow(&base, &u), &x),
+		));
+		x.zeroize();
+		base.zeroize();
+
+		let proofs = session_proofs(
+			self.group,
+			identity,
+			salt,
+			self.public_value.as_ref(),
+			server_public.as_ref(),
+			&premaster_secret[..self.group.size()],
+		);
+		premaster_secret.zeroize();
+		let (client_proof, expected_server_proof, session_key) = proofs?;
+
+		Ok(ClientVerification {
+			client_proof,
+			expected_server_proof,
+			session_key,
+		})
+	}
+}
+
+/// The client side of SRP-6a, waiting for the server's proof.
+pub struct ClientVerification {
+	client_proof: Proof,
+	expected_server_proof: Proof,
+	session_key: SessionKey,
+}
+
+impl core::fmt::Debug for ClientVerification {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "ClientVerification {{***OMITTED***}}")
+	}
+}
+
+impl ClientVerification {
+	/// The proof `M1` to send to the server.
+	pub fn proof(&self) -> &Proof {
+		&self.client_proof
+	}
+
+	#[must_use]
+	/// Verify the server's proof `M2` in constant time, returning the session
+	/// key if it is valid.
+	pub fn verify_server(self, server_proof: &Proof) -> Result<SessionKey, UnknownCryptoError> {
+		if &self.expected_server_proof == server_proof {
+			Ok(self.session_key)
+		} else {
+			Err(UnknownCryptoError::InvalidTag)
+		}
+	}
+}
+
+/// The server side of SRP-6a.
+pub struct SrpServer {
+	group: Group,
+	ephemeral_key: EphemeralKey,
+	verifier: BigLimbs,
+	public_value: PublicValue,
+}
+
+impl Drop for SrpServer {
+	fn drop(&mut self) {
+		self.verifier.zeroize();
+	}
+}
+
+impl core::fmt::Debug for SrpServer {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(
+			f,
+			"SrpServer {{ group: {:?}, public_value: {:?}, ***OMITTED*** }}",
+			self.group, self.public_value
+		)
+	}
+}
+
+impl SrpServer {
+	#[must_use]
+	/// Initialize the server with the stored verifier and compute its public
+	/// value `B = k * v + g^b`.
+	pub fn new(
+		group: Group,
+		verifier: &Verifier,
+		ephemeral_key: EphemeralKey,
+	) -> Result<Self, UnknownCryptoError> {
+		load_ephemeral_key(&ephemeral_key)?;
+		let modulus = Modulus::new(group);
+		let v = modulus.load_public(verifier.as_ref())?;
+		let k = multiplier(group, &modulus)?;
+
+		let public_value = modulus.store(&modulus.add(
+			&modulus.mont_mul(&k, &v),
+			&modulus.pow(&modulus.generator(), ephemeral_key.unprotected_as_bytes()),
+		));
+
+		Ok(Self {
+			group,
+			ephemeral_key,
+			verifier: v,
+			public_value: PublicValue::from_slice(&public_value[..group.size()])?,
+		})
+	}
+
+	/// The public value `B` to send to the client, along with the salt.
+	pub fn public_value(&self) -> &PublicValue {
+		&self.public_value
+	}
+
+	#[must_use]
+	/// Verify the client's proof `M1`. If it is valid, return the server's
+	/// proof `M2` to send to the client, and the session key.
+	pub fn verify_client(
+		self,
+		identity: &[u8],
+		salt: &[u8],
+		client_public: &PublicValue,
+		client_proof: &Proof,
+	) -> Result<(Proof, SessionKey), UnknownCryptoError> {
+		if identity.is_empty() || salt.is_empty() {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+
+		let modulus = Modulus::new(self.group);
+		let a = modulus.load_public(client_public.as_ref())?;
+		let u = scrambler(client_public.as_ref(), self.public_value.as_ref())?;
+
+		// S = (A * v^u)^b
+		let mut base = modulus.mont_mul(&a, &modulus.pow(&self.verifier, &u));
+		let mut premaster_secret =
+			modulus.store(&modulus.pow(&base, self.ephemeral_key.unprotected_as_bytes()));
+		base.zeroize();
+
+		let proofs = session_proofs(
+			self.group,
+			identity,
+			salt,
+			client_public.as_ref(),
+			self.public_value.as_ref(),
+			&premaster_secret[..self.group.size()],
+		);
+		premaster_secret.zeroize();
+		let (expected_client_proof, server_proof, session_key) = proofs?;
+
+		if &expected_client_proof == client_proof {
+			Ok((server_proof, session_key))
+		} else {
+			Err(UnknownCryptoError::InvalidTag)
+		}
+	}
+}
+
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	const SALT: [u8; 16] = [0x42u8; 16];
+
+	fn login(
+		group: Group,
+		verifier: &Verifier,
+		password: &[u8],
+	) -> Result<(SessionKey, SessionKey), UnknownCryptoError> {
+		let client = SrpClient::new(group, EphemeralKey::from([0x11u8; 32]))?;
+		let server = SrpServer::new(group, verifier, EphemeralKey::from([0x22u8; 32]))?;
+		let client_public = *client.public_value();
+		let server_public = *server.public_value();
+
+		let client = client.process_challenge(b"alice", password, &SALT, &server_public)?;
+		let (server_proof, server_key) =
+			server.verify_client(b"alice", &SALT, &client_public, client.proof())?;
+
+		Ok((client.verify_server(&server_proof)?, server_key))
+	}
+
+	mod test_srp6a {
+		use super::*;
+
+		#[test]
+		fn test_same_password_ok() {
+			for group in [Group::Group3072, Group::Group4096].iter() {
+				let verifier = create_verifier(*group, b"alice", b"password", &SALT).unwrap();
+				let (client_key, server_key) = login(*group, &verifier, b"password").unwrap();
+				assert!(client_key == server_key);
+			}
+		}
+
+		#[test]
+		fn test_different_password_err() {
+			let verifier = create_verifier(Group::Group3072, b"alice", b"password", &SALT).unwrap();
+			assert!(login(Group::Group3072, &verifier, b"Password").is_err());
+		}
+
+		#[test]
+		fn test_different_identity_err() {
+			let verifier = create_verifier(Group::Group3072, b"bob", b"password", &SALT).unwrap();
+			assert!(login(Group::Group3072, &verifier, b"password").is_err());
+		}
+
+		#[test]
+		fn test_verifier_group_mismatch_err() {
+			let verifier = create_verifier(Group::Group3072, b"alice", b"password", &SALT).unwrap();
+			assert!(SrpServer::new(
+				Group::Group4096,
+				&verifier,
+				EphemeralKey::from([0x22u8; 32])
+			)
+			.is_err());
+		}
+
+		#[test]
+		fn test_empty_identity_or_salt_err() {
+			assert!(create_verifier(Group::Group3072, b"", b"password", &SALT).is_err());
+			assert!(create_verifier(Group::Group3072, b"alice", b"password", b"").is_err());
+			// An empty password is allowed.
+			assert!(create_verifier(Group::Group3072, b"alice", b"", &SALT).is_ok());
+		}
+
+		#[test]
+		fn test_zero_ephemeral_key_err() {
+			let verifier = create_verifier(Group::Group3072, b"alice", b"password", &SALT).unwrap();
+			assert!(SrpClient::new(Group::Group3072, EphemeralKey::from([0u8; 32])).is_err());
+			assert!(
+				SrpServer::new(Group::Group3072, &verifier, EphemeralKey::from([0u8; 32])).is_err()
+			);
+		}
+
+		#[test]
+		fn test_invalid_public_value_err() {
+			let verifier = create_verifier(Group::Group3072, b"alice", b"password", &SALT).unwrap();
+			let zero = PublicValue::from_slice(&[0u8; 384]).unwrap();
+			let modulus = PublicValue::from_slice(&MODULUS_3072).unwrap();
+			let too_long = PublicValue::from_slice(&[0x01u8; 512]).unwrap();
+
+			for public in [zero, modulus, too_long].iter() {
+				let client =
+					SrpClient::new(Group::Group3072, EphemeralKey::from([0x11u8; 32])).unwrap();
+				assert!(client
+					.process_challenge(b"alice", b"password", &SALT, public)
+					.is_err());
+
+				let server = SrpServer::new(
+					Group::Group3072,
+					&verifier,
+					EphemeralKey::from([0x22u8; 32]),
+				)
+				.unwrap();
+				assert!(server
+					.verify_client(b"alice", &SALT, public, &Proof::from([0u8; 64]))
+					.is_err());
+			}
+		}
+
+		#[test]
+		fn test_reflected_proof_err() {
+			let verifier = create_verifier(Group::Group3072, b"alice", b"password", &SALT).unwrap();
+			let client =
+				SrpClient::new(Group::Group3072, EphemeralKey::from([0x11u8; 32])).unwrap();
+			let server = SrpServer::new(
+				Group::Group3072,
+				&verifier,
+				EphemeralKey::from([0x22u8; 32]),
+			)
+			.unwrap();
+			let server_public = *server.public_value();
+
+			let client = client
+				.process_challenge(b"alice", b"password", &SALT, &server_public)
+				.unwrap();
+			let client_proof = *client.proof();
+			assert!(client.verify_server(&client_proof).is_err());
+		}
+	}
+
+	// Proptests. Only exectued when NOT testing no_std.
+	#[cfg(feature = "safe_api")]
+	mod proptest {
+		use super::*;
+
+		quickcheck! {
+			/// A client with the password used to create the verifier should always
+			/// agree with the server.
+			fn prop_same_password_agrees(password: Vec<u8>) -> bool {
+				let verifier = create_verifier(Group::Group3072, b"alice", &password, &SALT).unwrap();
+				let (client_key, server_key) = login(Group::Group3072, &verifier, &password).unwrap();
+
+				client_key == server_key
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod private {
+	use super::*;
+
+	#[test]
+	fn test_limbs_bytes_roundtrip() {
+		let limbs = limbs_from_bytes(&MODULUS_3072);
+		let mut bytes = [0u8; 384];
+		limbs_to_bytes(&limbs, &mut bytes);
+		assert_eq!(&bytes[..], &MODULUS_3072[..]);
+	}
+
+	#[test]
+	fn test_mont_roundtrip_and_mul() {
+		let modulus = Modulus::new(Group::Group4096);
+		let a = limbs_from_bytes(&[0xffu8; 100]);
+		let b = limbs_from_bytes(&[3u8]);
+		assert_eq!(modulus.from_mont(&modulus.to_mont(&a)), a);
+
+		// a * 3 == a + a + a
+		let product =
+			modulus.from_mont(&modulus.mont_mul(&modulus.to_mont(&a), &modulus.to_mont(&b)));
+		assert_eq!(product, modulus.add(&modulus.add(&a, &a), &a));
+	}
+
+	#[test]
+	fn test_sub_wraps() {
+		let modulus = Modulus::new(Group::Group3072);
+		let one = limbs_from_bytes(&[1u8]);
+		let two = limbs_from_bytes(&[2u8]);
+		// 1 - 2 == N - 1
+		let mut expected = limbs_from_bytes(&MODULUS_3072);
+		expected[0] -= 1;
+		assert_eq!(modulus.sub(&one, &two), expected);
+		assert_eq!(modulus.add(&expected, &two), one);
+	}
+
+	#[test]
+	fn test_pow_fermat() {
+		// g^(N - 1) == 1 mod N, as N is prime.
+		let modulus = Modulus::new(Group::Group3072);
+		let mut exponent = MODULUS_3072;
+		exponent[383] -= 1;
+		let result = modulus.from_mont(&modulus.pow(&modulus.generator(), &exponent));
+		assert_eq!(result, limbs_from_bytes(&[1u8]));
+	}
+}
@@ -41,16 +41,13 @@
 //! - The length of `dst_out` is less than `plaintext` or `ciphertext`.
 //! - `plaintext` or `ciphertext` are empty.
 //! - The `initial_counter` is high enough to cause a potential overflow.
+//! - More than 2^32-1 keystream blocks are processed or more than 2^32-1 * 64
+//!   bytes of data are processed.
 //!
 //! Even though `dst_out` is allowed to be of greater length than `plaintext`,
 //! the `ciphertext` produced by `chacha20`/`xchacha20` will always be of the
 //! same length as the `plaintext`.
 //!
-//! # Panics:
-//! A panic will occur if:
-//! - More than 2^32-1 keystream blocks are processed or more than 2^32-1 * 64
-//! bytes of data are processed.
-//!
 //! ### Note:
 //! [`keystream_block`] is for use-cases where more control over the keystream
 //! used for encryption/decryption is desired. It does not encrypt anything.
@@ -137,7 +134,15 @@ construct_public! {
 	/// # Errors:
 	/// An error will be returned if:
 	/// - `slice` is not 12 bytes.
-	(Nonce, test_nonce, IETF_CHACHA_NONCESIZE, IETF_CHACHA_NONCESIZE)
+	///
+	/// # Panics:
+	/// A panic will occur if:
+	/// - Failure to generate random bytes securely.
+	///
+	/// # Security:
+	/// A 96-bit nonce is too short to be safely generated at random for many
+	/// messages under the same key. Prefer XChaCha20 if nonces are random.
+	(Nonce, test_nonce, IETF_CHACHA_NONCESIZE, IETF_CHACHA_NONCESIZE, IETF_CHACHA_NONCESIZE)
 }
 
 impl_from_trait!(Nonce, IETF_CHACHA_NONCESIZE);
@@ -234,6 +239,68 @@ fn double_round(r0: &mut U32x4, r1: &mut U32x4, r2: &mut U32x4, r3: &mut U32x4)
 	*r3 = r3.shl_1();
 }
 
+macro_rules! xor_slices {
+	($destination:expr, $other:expr) => {
+		for (inplace, _other) in $destination.iter_mut().zip($other.iter()) {
+			*inplace ^= _other;
+		}
+	};
+}
+
+/// The number of blocks that are processed in parallel by
+/// `InternalState::xor_blocks_wide()`.
+const WIDE_BLOCKS: usize = 4;
+
+/// The number of keystream bytes produced by
+/// `InternalState::xor_blocks_wide()`.
+const WIDE_BLOCKSIZE: usize = CHACHA_BLOCKSIZE * WIDE_BLOCKS;
+
+/// The same word of `WIDE_BLOCKS` independent ChaCha states. The lanes have no
+/// dependencies on each other, so the CPU can work on all of them at once.
+/// This is the portable replacement for SIMD intrinsics, which would require
+/// `unsafe` code.
+type Lanes = [u32; WIDE_BLOCKS];
+
+#[inline(always)]
+fn lanes_add(a: Lanes, b: Lanes) -> Lanes {
+	[
+		a[0].wrapping_add(b[0]),
+		a[1].wrapping_add(b[1]),
+		a[2].wrapping_add(b[2]),
+		a[3].wrapping_add(b[3]),
+	]
+}
+
+#[inline(always)]
+fn lanes_xor_rotate(a: Lanes, b: Lanes, n: u32) -> Lanes {
+	[
+		(a[0] ^ b[0]).rotate_left(n),
+		(a[1] ^ b[1]).rotate_left(n),
+		(a[2] ^ b[2]).rotate_left(n),
+		(a[3] ^ b[3]).rotate_left(n),
+	]
+}
+
+#[inline(always)]
+/// ChaCha quarter round on the words `a`, `b`, `c` and `d` of all lanes.
+fn wide_round(x: &mut [Lanes; 16], a: usize, b: usize, c: usize, d: usize) {
+	let (mut va, mut vb, mut vc, mut vd) = (x[a], x[b], x[c], x[d]);
+
+	va = lanes_add(va, vb);
+	vd = lanes_xor_rotate(vd, va, 16);
+	vc = lanes_add(vc, vd);
+	vb = lanes_xor_rotate(vb, vc, 12);
+	va = lanes_add(va, vb);
+	vd = lanes_xor_rotate(vd, va, 8);
+	vc = lanes_add(vc, vd);
+	vb = lanes_xor_rotate(vb, vc, 7);
+
+	x[a] = va;
+	x[b] = vb;
+	x[c] = vc;
+	x[d] = vd;
+}
+
 struct InternalState {
 	state: [U32x4; 4],
 	internal_counter: u32,
@@ -260,10 +327,10 @@ impl InternalState {
 	fn new(sk: &[u8], n: &[u8], is_ietf: bool) -> Result<Self, UnknownCryptoError> {
 		debug_assert!(sk.len() == CHACHA_KEYSIZE);
 		if (n.len() != IETF_CHACHA_NONCESIZE) && is_ietf {
-			return Err(UnknownCryptoError);
+			return Err(UnknownCryptoError::InvalidLength);
 		}
 		if (n.len() != HCHACHA_NONCESIZE) && !is_ietf {
-			return Err(UnknownCryptoError);
+			return Err(UnknownCryptoError::InvalidLength);
 		}
 
 		// Row 0 with constants.
@@ -317,16 +384,18 @@ impl InternalState {
 		if self.is_ietf {
 			match block_counter {
 				Some(counter) => self.state[3].0 = counter,
-				None => return Err(UnknownCryptoError),
+				None => return Err(UnknownCryptoError::ParameterOutOfRange),
 			};
 		}
 		if !self.is_ietf && block_counter.is_some() {
-			return Err(UnknownCryptoError);
+			return Err(UnknownCryptoError::ParameterOutOfRange);
 		}
 
-		// If this panics, max amount of keystream blocks
-		// have been retrieved.
-		self.internal_counter = self.internal_counter.checked_add(1).unwrap();
+		// The max amount of keystream blocks have been retrieved.
+		self.internal_counter = self
+			.internal_counter
+			.checked_add(1)
+			.ok_or(UnknownCryptoError::NonceCounterOverflow)?;
 
 		let mut wr0 = self.state[0];
 		let mut wr1 = self.state[1];
@@ -353,14 +422,80 @@ impl InternalState {
 
 		Ok([wr0, wr1, wr2, wr3])
 	}
-}
 
-macro_rules! xor_slices {
-	($destination:expr, $other:expr) => {
-		for (inplace, _other) in $destination.iter_mut().zip($other.iter()) {
-			*inplace ^= _other;
+	#[must_use]
+	#[inline(always)]
+	/// Process `WIDE_BLOCKS` consecutive ChaCha20 blocks, starting at
+	/// `block_counter`, in parallel and write the keystream XORed with `src`
+	/// to `dst`.
+	fn xor_blocks_wide(
+		&mut self,
+		block_counter: u32,
+		src: &[u8],
+		dst: &mut [u8],
+	) -> Result<(), UnknownCryptoError> {
+		debug_assert!(src.len() == WIDE_BLOCKSIZE);
+		debug_assert!(dst.len() == WIDE_BLOCKSIZE);
+		if !self.is_ietf {
+			return Err(UnknownCryptoError::ParameterOutOfRange);
+		}
+		if block_counter
+			.checked_add((WIDE_BLOCKS - 1) as u32)
+			.is_none()
+		{
+			return Err(UnknownCryptoError::NonceCounterOverflow);
+		}
+
+		// The max amount of keystream blocks have been retrieved.
+		self.internal_counter = self
+			.internal_counter
+			.checked_add(WIDE_BLOCKS as u32)
+			.ok_or(UnknownCryptoError::NonceCounterOverflow)?;
+
+		let mut initial = [[0u32; WIDE_BLOCKS]; 16];
+		for (idx, row) in self.state.iter().enumerate() {
+			initial[idx * 4] = [row.0; WIDE_BLOCKS];
+			initial[idx * 4 + 1] = [row.1; WIDE_BLOCKS];
+			initial[idx * 4 + 2] = [row.2; WIDE_BLOCKS];
+			initial[idx * 4 + 3] = [row.3; WIDE_BLOCKS];
+		}
+		for (lane, counter) in initial[12].iter_mut().enumerate() {
+			*counter = block_counter + lane as u32;
+		}
+
+		let mut x = initial;
+		for _ in 0..10 {
+			wide_round(&mut x, 0, 4, 8, 12);
+			wide_round(&mut x, 1, 5, 9, 13);
+			wide_round(&mut x, 2, 6, 10, 14);
+			wide_round(&mut x, 3, 7, 11, 15);
+
+			wide_round(&mut x, 0, 5, 10, 15);
+			wide_round(&mut x, 1, 6, 11, 12);
+			wide_round(&mut x, 2, 7, 8, 13);
+			wide_round(&mut x, 3, 4, 9, 14);
+		}
+
+		let blocks = src
+			.chunks_exact(CHACHA_BLOCKSIZE)
+			.zip(dst.chunks_exact_mut(CHACHA_BLOCKSIZE));
+		for (lane, (src_block, dst_block)) in blocks.enumerate() {
+			let words = src_block.chunks_exact(4).zip(dst_block.chunks_exact_mut(4));
+			for (word, (src_bytes, dst_bytes)) in words.enumerate() {
+				let keystream = x[word][lane].wrapping_add(initial[word][lane]);
+				dst_bytes.copy_from_slice(&(load_u32_le(src_bytes) ^ keystream).to_le_bytes());
 			}
-	};
+		}
+
+		// `initial` holds the key, and `x` the keystream.
+		for lanes in x.iter_mut().chain(initial.iter_mut()) {
+			for word in lanes.iter_mut() {
+				word.zeroize();
+			}
+		}
+
+		Ok(())
+	}
 }
 
 /// Read a ChaCha state matrix row as bytes and XOR with 16-byte block.
@@ -373,6 +508,29 @@ fn xor_row_into(row: &U32x4, slice_in: &mut [u8]) {
 	xor_slices!(slice_in[12..16], row.3.to_le_bytes().as_ref());
 }
 
+/// Write the keystream block `ks` XORed with `src` to `dst`. `src` may be
+/// shorter than a full block, in which case the rest of `ks` is not used.
+fn xor_keystream_into(ks: &[U32x4; 4], src: &[u8], dst: &mut [u8]) {
+	debug_assert!(src.len() <= CHACHA_BLOCKSIZE);
+	debug_assert!(src.len() == dst.len());
+
+	for (row, (src_row, dst_row)) in ks.iter().zip(src.chunks(16).zip(dst.chunks_mut(16))) {
+		let words = [row.0, row.1, row.2, row.3];
+		for (word, (src_word, dst_word)) in words
+			.iter()
+			.zip(src_row.chunks(4).zip(dst_row.chunks_mut(4)))
+		{
+			for ((dst_byte, src_byte), ks_byte) in dst_word
+				.iter_mut()
+				.zip(src_word.iter())
+				.zip(word.to_le_bytes().iter())
+			{
+				*dst_byte = src_byte ^ ks_byte;
+			}
+		}
+	}
+}
+
 enum Serialize {
 	IetfChaCha,
 	HChaCha,
@@ -400,38 +558,71 @@ impl Serialize {
 }
 
 #[must_use]
-/// In-place IETF ChaCha20 encryption as specified in the [RFC 8439](https://tools.ietf.org/html/rfc8439).
-fn encrypt_in_place(
+/// Write `src` XORed with the IETF ChaCha20 keystream to `dst`.
+fn xor_keystream(
 	secret_key: &SecretKey,
 	nonce: &Nonce,
 	initial_counter: u32,
-	bytes: &mut [u8],
+	src: &[u8],
+	dst: &mut [u8],
 ) -> Result<(), UnknownCryptoError> {
-	if bytes.is_empty() {
-		return Err(UnknownCryptoError);
+	debug_assert!(src.len() == dst.len());
+	if src.is_empty() {
+		return Err(UnknownCryptoError::InvalidLength);
 	}
 
 	let mut state = InternalState::new(secret_key.unprotected_as_bytes(), nonce.as_ref(), true)?;
 
-	for (counter, bytes_block) in bytes.chunks_mut(CHACHA_BLOCKSIZE).enumerate() {
-		let block_counter = initial_counter.checked_add(counter as u32);
+	// Process as many blocks in parallel as possible. Whatever is left, and any
+	// blocks where the block counter would overflow, are processed one at a time.
+	// The lanes of the parallel path are only worth it when the compiler can
+	// keep them in vector registers.
+	let use_wide = crate::cpu::use_accelerated() && crate::cpu::features().vector();
+	let mut blocks_processed = 0u32;
+	let (mut src, mut dst) = (src, dst);
+	while use_wide && src.len() >= WIDE_BLOCKSIZE {
+		let block_counter = match initial_counter
+			.checked_add(blocks_processed)
+			.filter(|counter| counter.checked_add((WIDE_BLOCKS - 1) as u32).is_some())
+		{
+			Some(block_counter) => block_counter,
+			None => break,
+		};
 
-		if block_counter.is_some() {
-			let keystream_state = state.process_block(block_counter)?;
+		let (src_chunk, src_rest) = src.split_at(WIDE_BLOCKSIZE);
+		let (dst_chunk, dst_rest) = dst.split_at_mut(WIDE_BLOCKSIZE);
+		state.xor_blocks_wide(block_counter, src_chunk, dst_chunk)?;
+		blocks_processed += WIDE_BLOCKS as u32;
+		src = src_rest;
+		dst = dst_rest;
+	}
 
-			if bytes_block.len() == CHACHA_BLOCKSIZE {
-				Serialize::IetfChaCha.xor_in_place(&keystream_state, bytes_block);
-			} else {
-				let block_len = bytes_block.len();
-				let mut keystream_block = [0u8; CHACHA_BLOCKSIZE];
+	xor_keystream_blocks(&mut state, initial_counter, blocks_processed, src, dst)
+}
 
-				keystream_block[..block_len].copy_from_slice(bytes_block);
-				Serialize::IetfChaCha.xor_in_place(&keystream_state, &mut keystream_block);
-				bytes_block.copy_from_slice(keystream_block[..block_len].as_ref());
-				keystream_block.zeroize();
-			}
+#[must_use]
+/// Process `src` one ChaCha20 block at a time, where the first block has
+/// the block counter `initial_counter + blocks_processed`.
+fn xor_keystream_blocks(
+	state: &mut InternalState,
+	initial_counter: u32,
+	blocks_processed: u32,
+	src: &[u8],
+	dst: &mut [u8],
+) -> Result<(), UnknownCryptoError> {
+	let blocks = src
+		.chunks(CHACHA_BLOCKSIZE)
+		.zip(dst.chunks_mut(CHACHA_BLOCKSIZE));
+	for (counter, (src_block, dst_block)) in blocks.enumerate() {
+		let block_counter = initial_counter
+			.checked_add(blocks_processed)
+			.and_then(|c| c.checked_add(counter as u32));
+
+		if block_counter.is_some() {
+			let keystream_state = state.process_block(block_counter)?;
+			xor_keystream_into(&keystream_state, src_block, dst_block);
 		} else {
-			return Err(UnknownCryptoError);
+			return Err(UnknownCryptoError::ParameterOutOfRange);
 		}
 	}
 
@@ -448,17 +639,17 @@ pub fn encrypt(
 	dst_out: &mut [u8],
 ) -> Result<(), UnknownCryptoError> {
 	if dst_out.len() < plaintext.len() {
-		return Err(UnknownCryptoError);
+		return Err(UnknownCryptoError::InvalidLength);
 	}
 	if plaintext.is_empty() {
-		return Err(UnknownCryptoError);
+		return Err(UnknownCryptoError::InvalidLength);
 	}
 
-	dst_out[..plaintext.len()].copy_from_slice(plaintext);
-	encrypt_in_place(
+	xor_keystream(
 		secret_key,
 		nonce,
 		initial_counter,
+		plaintext,
 		&mut dst_out[..plaintext.len()],
 	)
 }
@@ -513,6 +704,25 @@ mod public {
 	use super::*;
 	// One function tested per submodule.
 
+	mod test_from_bytes {
+		use super::*;
+
+		const KEY: SecretKey = SecretKey::from_bytes([0x2au8; SecretKey::SIZE]);
+		const NONCE: Nonce = Nonce::from_bytes([0x07u8; Nonce::SIZE]);
+
+		#[test]
+		fn test_const_same_as_from_slice() {
+			assert_eq!(SecretKey::SIZE, CHACHA_KEYSIZE);
+			assert_eq!(Nonce::SIZE, IETF_CHACHA_NONCESIZE);
+			assert!(KEY == SecretKey::from_slice(&[0x2au8; CHACHA_KEYSIZE]).unwrap());
+			assert!(KEY == SecretKey::from([0x2au8; CHACHA_KEYSIZE]));
+			assert_eq!(
+				NONCE,
+				Nonce::from_slice(&[0x07u8; IETF_CHACHA_NONCESIZE]).unwrap()
+			);
+		}
+	}
+
 	// encrypt()/decrypt() are tested together here
 	// since decrypt() is just a wrapper around encrypt()
 	// and so only the decrypt() function is called
@@ -935,6 +1145,107 @@ mod private {
 	use super::*;
 	// One function tested per submodule.
 
+	mod test_xor_blocks_wide {
+		use super::*;
+
+		/// Encrypt `bytes` using only `keystream_block()`.
+		fn encrypt_reference(initial_counter: u32, bytes: &mut [u8]) {
+			let sk = SecretKey::from_slice(&[0x2au8; 32]).unwrap();
+			let nonce = Nonce::from_slice(&[0x07u8; 12]).unwrap();
+
+			for (counter, block) in bytes.chunks_mut(CHACHA_BLOCKSIZE).enumerate() {
+				let ks = keystream_block(&sk, &nonce, initial_counter + counter as u32).unwrap();
+				xor_slices!(block, ks.as_ref());
+			}
+		}
+
+		#[test]
+		fn test_same_as_single_blocks() {
+			let sk = SecretKey::from_slice(&[0x2au8; 32]).unwrap();
+			let nonce = Nonce::from_slice(&[0x07u8; 12]).unwrap();
+
+			let mut input = [0u8; 4098];
+			for (i, byte) in input.iter_mut().enumerate() {
+				*byte = i as u8;
+			}
+
+			for len in [1, 63, 64, 255, 256, 257, 511, 512, 1000, 4098].iter() {
+				let mut expected = [0u8; 4098];
+				expected[..*len].copy_from_slice(&input[..*len]);
+				encrypt_reference(1, &mut expected[..*len]);
+
+				let mut actual = [0u8; 4098];
+				encrypt(&sk, &nonce, 1, &input[..*len], &mut actual[..*len]).unwrap();
+				assert_eq!(actual.as_ref(), expected.as_ref());
+			}
+		}
+
+		#[test]
+		fn test_same_as_portable() {
+			let sk = SecretKey::from_slice(&[0x2au8; 32]).unwrap();
+			let nonce = Nonce::from_slice(&[0x07u8; 12]).unwrap();
+			let input = [0x11u8; 1000];
+			let mut accelerated = [0u8; 1000];
+			let mut portable = [0u8; 1000];
+
+			encrypt(&sk, &nonce, 0, &input, &mut accelerated).unwrap();
+			crate::cpu::force_portable(true);
+			let result = encrypt(&sk, &nonce, 0, &input, &mut portable);
+			crate::cpu::force_portable(false);
+			result.unwrap();
+
+			assert_eq!(accelerated.as_ref(), portable.as_ref());
+		}
+
+		#[test]
+		fn test_wide_counter_overflow() {
+			let mut state = InternalState::new(&[0u8; 32], &[0u8; 12], true).unwrap();
+			let src = [0u8; WIDE_BLOCKSIZE];
+			let mut dst = [0u8; WIDE_BLOCKSIZE];
+			assert!(state
+				.xor_blocks_wide(u32::max_value() - 3, &src, &mut dst)
+				.is_ok());
+			assert!(state
+				.xor_blocks_wide(u32::max_value() - 2, &src, &mut dst)
+				.is_err());
+
+			let mut state = InternalState::new(&[0u8; 32], &[0u8; 16], false).unwrap();
+			assert!(state.xor_blocks_wide(0, &src, &mut dst).is_err());
+		}
+
+		#[test]
+		fn test_encrypt_near_counter_overflow() {
+			let sk = SecretKey::from_slice(&[0x2au8; 32]).unwrap();
+			let nonce = Nonce::from_slice(&[0x07u8; 12]).unwrap();
+			let input = [0u8; 6 * CHACHA_BLOCKSIZE];
+
+			// The last two blocks must be processed one at a time.
+			let mut expected = input;
+			encrypt_reference(u32::max_value() - 5, &mut expected);
+			let mut actual = [0u8; 6 * CHACHA_BLOCKSIZE];
+			encrypt(&sk, &nonce, u32::max_value() - 5, &input, &mut actual).unwrap();
+			assert_eq!(actual.as_ref(), expected.as_ref());
+
+			assert!(encrypt(&sk, &nonce, u32::max_value() - 4, &input, &mut actual).is_err());
+			assert!(encrypt(
+				&sk,
+				&nonce,
+				u32::max_value() - 2,
+				&input[..256],
+				&mut actual
+			)
+			.is_err());
+			assert!(encrypt(
+				&sk,
+				&nonce,
+				u32::max_value() - 2,
+				&input[..192],
+				&mut actual
+			)
+			.is_ok());
+		}
+	}
+
 	mod test_init_state {
 		use super::*;
 
@@ -1023,8 +1334,7 @@ mod private {
 		}
 
 		#[test]
-		#[should_panic]
-		fn test_process_block_panic_on_too_much_keystream_data_ietf() {
+		fn test_process_block_err_on_too_much_keystream_data_ietf() {
 			let mut chacha_state_ietf = InternalState {
 				state: [
 					U32x4(0, 0, 0, 0),
@@ -1036,14 +1346,17 @@ mod private {
 				is_ietf: true,
 			};
 
-			for amount in 0..(128 + 1) {
-				let _keystream_block = chacha_state_ietf.process_block(Some(amount as u32));
+			for amount in 0..128 {
+				assert!(chacha_state_ietf.process_block(Some(amount as u32)).is_ok());
 			}
+			assert!(
+				chacha_state_ietf.process_block(Some(128)).err()
+					== Some(UnknownCryptoError::NonceCounterOverflow)
+			);
 		}
 
 		#[test]
-		#[should_panic]
-		fn test_process_block_panic_on_too_much_keystream_data_hchacha() {
+		fn test_process_block_err_on_too_much_keystream_data_hchacha() {
 			let mut chacha_state_ietf = InternalState {
 				state: [
 					U32x4(0, 0, 0, 0),
@@ -1055,9 +1368,10 @@ mod private {
 				is_ietf: false,
 			};
 
-			for _ in 0..(128 + 1) {
-				let _keystream_block = chacha_state_ietf.process_block(None);
+			for _ in 0..128 {
+				assert!(chacha_state_ietf.process_block(None).is_ok());
 			}
+			assert!(chacha_state_ietf.process_block(None).is_err());
 		}
 	}
 }
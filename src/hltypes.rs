@@ -27,13 +27,15 @@ use crate::errors::UnknownCryptoError;
 construct_secret_key_variable_size! {
 	/// A type to represent a secret key.
 	///
-	/// As default it will randomly generate a `SecretKey` of 32 bytes.
+	/// As default it will randomly generate a `SecretKey` of 32 bytes. Use `generate_len()`
+	/// to generate a longer one, which refuses lengths below `SecretKey::MIN_GENERATE_LENGTH`.
 	///
 	/// # Errors:
 	/// An error will be returned if:
 	/// - `slice` is empty.
 	/// - `length` is 0.
 	/// - `length` is not less than `u32::max_value()`.
+	/// - `length` is less than `SecretKey::MIN_GENERATE_LENGTH` when using `generate_len()`.
 	///
 	/// # Panics:
 	/// A panic will occur if:
@@ -41,6 +43,18 @@ construct_secret_key_variable_size! {
 	(SecretKey, test_secret_key, 32)
 }
 
+impl SecretKey {
+	func_generate_len!(SecretKey, 32);
+}
+
+#[cfg(test)]
+#[cfg(feature = "safe_api")]
+mod test_secret_key_generate_len {
+	use super::*;
+
+	test_generate_len!(SecretKey, 32);
+}
+
 construct_salt_variable_size! {
 	/// A type to represent the `Salt` that PBKDF2 uses during key derivation.
 	///
@@ -58,6 +72,7 @@ construct_salt_variable_size! {
 	(Salt, test_salt, 64)
 }
 
+#[cfg(feature = "safe_api")]
 construct_tag! {
 	/// A type to represent the `PasswordHash` that PBKDF2 returns when used for password hashing.
 	///
@@ -70,6 +85,7 @@ construct_tag! {
 	(PasswordHash, test_password_hash, 128, 128)
 }
 
+#[cfg(feature = "safe_api")]
 impl_from_trait!(PasswordHash, 128);
 
 construct_secret_key_variable_size! {
@@ -88,3 +104,23 @@ construct_secret_key_variable_size! {
 	/// - Failure to generate random bytes securely.
 	(Password, test_password, 32)
 }
+
+#[cfg(test)]
+#[cfg(all(feature = "proptest", feature = "safe_api"))]
+mod test_proptest_strategies {
+	use super::*;
+	use proptest::prelude::*;
+
+	proptest! {
+		#[test]
+		fn prop_any_is_valid(
+			secret_key in any::<SecretKey>(),
+			salt in any::<Salt>(),
+			password_hash in any::<PasswordHash>(),
+		) {
+			prop_assert!(secret_key.get_length() >= 1 && secret_key.get_length() <= 1024);
+			prop_assert!(salt.get_length() >= 1 && salt.get_length() <= 1024);
+			prop_assert!(password_hash.get_length() == 128);
+		}
+	}
+}
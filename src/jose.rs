@@ -0,0 +1,432 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Compact JSON Web Signatures and JSON Web Tokens.
+//!
+//! # Use case:
+//! `orion::jose` can be used to sign and verify JWS in the compact
+//! serialization ([RFC 7515]), such as the JSON Web Tokens ([RFC 7519]) used
+//! by OAuth and OpenID Connect.
+//!
+//! An example of this could be a service that verifies the access tokens
+//! issued by an authorization server.
+//!
+//! # About:
+//! - The supported algorithms are `HS256`, `HS512` and `ES256`. orion does
+//!   not implement Ed25519, so `EdDSA` is not supported.
+//! - The algorithm is pinned by the key: the `alg` header of a token must
+//!   match the algorithm of the [`VerificationKey`] exactly. Tokens with the
+//!   `none` algorithm, or any other than that of the key, are rejected.
+//! - Tokens with a `crit` header are rejected, as no extensions are
+//!   supported. Other headers, such as `kid` and `typ`, are ignored.
+//! - Tokens are signed with the header `{"alg":"<alg>","typ":"JWT"}`.
+//! - The payload is returned as bytes once the signature has been verified.
+//!   Claims such as `exp`, `nbf`, `iss` and `aud` are not checked, and must be
+//!   validated by the caller.
+//!
+//! # Parameters:
+//! - `key`: The key used to sign or verify, which determines the algorithm.
+//! - `payload`: The payload to sign, such as the JSON claims of a JWT.
+//! - `token`: The JWS to verify.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - An `HS256` key is less than 32 bytes, or an `HS512` key less than 64
+//!   bytes.
+//! - `token` is not a JWS in the compact serialization.
+//! - The `alg` header of `token` is not that of `key`, or `token` has a
+//!   `crit` header.
+//! - The signature of `token` does not match.
+//!
+//! # Security:
+//! - A verified payload only shows that the holder of the key signed it. The
+//!   claims of a JWT must still be checked, in particular its expiry.
+//! - `ES256` signatures are malleable: a different valid signature can be
+//!   made from a valid one without the secret key. Tokens should therefore
+//!   not be identified by their signature.
+//! - Symmetric keys should be generated using a CSPRNG, with
+//!   `SecretKey::generate_len()`.
+//!
+//! # Example:
+//! ```rust
+//! use orion::jose::{self, SecretKey, SigningKey, VerificationKey};
+//!
+//! let key = SecretKey::default();
+//! let token = jose::sign(&SigningKey::Hs256(&key), br#"{"sub":"1234"}"#)?;
+//!
+//! let payload = jose::verify(&VerificationKey::Hs256(&key), &token)?;
+//! assert_eq!(payload, br#"{"sub":"1234"}"#);
+//! assert!(jose::verify(&VerificationKey::Hs512(&key), &token).is_err());
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [RFC 7515]: https://tools.ietf.org/html/rfc7515
+//! [RFC 7519]: https://tools.ietf.org/html/rfc7519
+//! [`VerificationKey`]: https://docs.rs/orion/latest/orion/jose/enum.VerificationKey.html
+
+pub use crate::hltypes::SecretKey;
+use crate::{
+	errors::UnknownCryptoError,
+	hazardous::{
+		ecc::ecdsa_p256,
+		hash::sha256::{self, SHA256_OUTSIZE},
+		hash::sha512::SHA512_OUTSIZE,
+		mac::hmac,
+	},
+	json,
+	util::{
+		base64::{self, Variant},
+		secure_cmp,
+	},
+};
+
+/// A key used to sign a JWS, which determines its algorithm.
+pub enum SigningKey<'a> {
+	/// HMAC-SHA256 (`HS256`), with a key of at least 32 bytes.
+	Hs256(&'a SecretKey),
+	/// HMAC-SHA512 (`HS512`), with a key of at least 64 bytes.
+	Hs512(&'a SecretKey),
+	/// ECDSA P-256 with SHA256 (`ES256`).
+	Es256(&'a ecdsa_p256::SecretKey),
+}
+
+/// A key used to verify a JWS, which determines the only algorithm accepted.
+pub enum VerificationKey<'a> {
+	/// HMAC-SHA256 (`HS256`), with a key of at least 32 bytes.
+	Hs256(&'a SecretKey),
+	/// HMAC-SHA512 (`HS512`), with a key of at least 64 bytes.
+	Hs512(&'a SecretKey),
+	/// ECDSA P-256 with SHA256 (`ES256`).
+	Es256(&'a ecdsa_p256::PublicKey),
+}
+
+impl<'a> SigningKey<'a> {
+	fn alg(&self) -> &'static str {
+		match self {
+			SigningKey::Hs256(_) => "HS256",
+			SigningKey::Hs512(_) => "HS512",
+			SigningKey::Es256(_) => "ES256",
+		}
+	}
+}
+
+impl<'a> VerificationKey<'a> {
+	fn alg(&self) -> &'static str {
+		match self {
+			VerificationKey::Hs256(_) => "HS256",
+			VerificationKey::Hs512(_) => "HS512",
+			VerificationKey::Es256(_) => "ES256",
+		}
+	}
+}
+
+/// Check that `key` is long enough for an HMAC with an output of `outsize`
+/// bytes, as required by RFC 7518.
+fn check_hmac_key(key: &SecretKey, outsize: usize) -> Result<(), UnknownCryptoError> {
+	if key.get_length() < outsize {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	Ok(())
+}
+
+fn hs256(
+	key: &SecretKey,
+	signing_input: &[u8],
+) -> Result<[u8; SHA256_OUTSIZE], UnknownCryptoError> {
+	check_hmac_key(key, SHA256_OUTSIZE)?;
+	sha256::hmac_sha256(key.unprotected_as_bytes(), &[signing_input])
+}
+
+fn hs512(key: &SecretKey, signing_input: &[u8]) -> Result<hmac::Tag, UnknownCryptoError> {
+	check_hmac_key(key, SHA512_OUTSIZE)?;
+	hmac::hmac(
+		&hmac::SecretKey::from_slice(key.unprotected_as_bytes())?,
+		signing_input,
+	)
+}
+
+fn encode(bytes: &[u8]) -> String {
+	base64::encode(bytes, Variant::UrlSafeNoPadding)
+}
+
+#[must_use]
+/// Sign `payload` with `key`, returning a JWS in the compact serialization.
+pub fn sign(key: &SigningKey, payload: &[u8]) -> Result<String, UnknownCryptoError> {
+	let mut token = encode(format!("{{\"alg\":\"{}\",\"typ\":\"JWT\"}}", key.alg()).as_bytes());
+	token.push('.');
+	token.push_str(&encode(payload));
+
+	let signature = match key {
+		SigningKey::Hs256(secret_key) => encode(&hs256(secret_key, token.as_bytes())?),
+		SigningKey::Hs512(secret_key) => {
+			encode(hs512(secret_key, token.as_bytes())?.unprotected_as_bytes())
+		}
+		SigningKey::Es256(secret_key) => {
+			encode(ecdsa_p256::sign(secret_key, token.as_bytes())?.as_ref())
+		}
+	};
+	token.push('.');
+	token.push_str(&signature);
+
+	Ok(token)
+}
+
+/// Check the JOSE header of a JWS that is to be verified with an algorithm of
+/// `alg`.
+fn check_header(header: &[u8], alg: &str) -> Result<(), UnknownCryptoError> {
+	let header = core::str::from_utf8(header).map_err(|_| UnknownCryptoError::InvalidEncoding)?;
+	let mut alg_matches = false;
+	json::parse_object(header, |name, value| match name {
+		"alg" => {
+			alg_matches = value.string()? == alg;
+			Ok(())
+		}
+		"crit" => Err(UnknownCryptoError::InvalidEncoding),
+		_ => value.skip_value(),
+	})?;
+
+	if alg_matches {
+		Ok(())
+	} else {
+		Err(UnknownCryptoError::InvalidKey)
+	}
+}
+
+#[must_use]
+/// Verify the JWS `token` with `key`, returning its payload.
+pub fn verify(key: &VerificationKey, token: &str) -> Result<Vec<u8>, UnknownCryptoError> {
+	let mut parts = token.split('.');
+	let (header, payload, signature) =
+		match (parts.next(), parts.next(), parts.next(), parts.next()) {
+			(Some(header), Some(payload), Some(signature), None) => (header, payload, signature),
+			_ => return Err(UnknownCryptoError::InvalidEncoding),
+		};
+	check_header(
+		&base64::decode(header, Variant::UrlSafeNoPadding)?,
+		key.alg(),
+	)?;
+	let signature = base64::decode(signature, Variant::UrlSafeNoPadding)?;
+	// The signing input is the encoded header and payload, as in the token.
+	let signing_input = token[..header.len() + 1 + payload.len()].as_bytes();
+
+	match key {
+		VerificationKey::Hs256(secret_key) => {
+			secure_cmp(&hs256(secret_key, signing_input)?, &signature)?;
+		}
+		VerificationKey::Hs512(secret_key) => {
+			check_hmac_key(secret_key, SHA512_OUTSIZE)?;
+			hmac::verify(
+				&hmac::Tag::from_slice(&signature)?,
+				&hmac::SecretKey::from_slice(secret_key.unprotected_as_bytes())?,
+				signing_input,
+			)?;
+		}
+		VerificationKey::Es256(public_key) => {
+			ecdsa_p256::verify(
+				&ecdsa_p256::Signature::from_slice(&signature)?,
+				public_key,
+				signing_input,
+			)?;
+		}
+	}
+
+	base64::decode(payload, Variant::UrlSafeNoPadding)
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+	use core::convert::TryFrom;
+
+	/// The payload of the examples in RFC 7515, appendix A.
+	const RFC_PAYLOAD: &[u8] =
+		b"{\"iss\":\"joe\",\r\n \"exp\":1300819380,\r\n \"http://example.com/is_root\":true}";
+
+	fn decode(src: &str) -> Vec<u8> {
+		base64::decode(src, Variant::UrlSafeNoPadding).unwrap()
+	}
+
+	mod test_vectors {
+		use super::*;
+
+		#[test]
+		fn test_rfc7515_hs256() {
+			// RFC 7515, appendix A.1.
+			let key = SecretKey::from_slice(&decode(
+				"AyM1SysPpbyDfgZld3umj1qzKObwVMkoqQ-EstJQLr_T-1qS0gZH75aKtMN3Yj0iPS4hcgUuTwjAzZr1Z9CAow",
+			))
+			.unwrap();
+			let token = "eyJ0eXAiOiJKV1QiLA0KICJhbGciOiJIUzI1NiJ9.\
+			             eyJpc3MiOiJqb2UiLA0KICJleHAiOjEzMDA4MTkzODAsDQogImh0dHA6Ly9leGFtcGxlLmNvbS9pc19yb290Ijp0cnVlfQ.\
+			             dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+
+			assert_eq!(
+				verify(&VerificationKey::Hs256(&key), token).unwrap(),
+				RFC_PAYLOAD
+			);
+			assert!(verify(&VerificationKey::Hs512(&key), token).is_err());
+		}
+
+		#[test]
+		fn test_rfc7515_es256() {
+			// RFC 7515, appendix A.3.
+			let mut point = vec![0x04];
+			point.extend_from_slice(&decode("f83OJ3D2xF1Bg8vub9tLe1gHMzV76e8Tus9uPHvRVEU"));
+			point.extend_from_slice(&decode("x_FEzRu9m36HLN_tue659LNpXW6pCyStikYjKIWI5a0"));
+			let public_key = ecdsa_p256::PublicKey::from_slice(&point).unwrap();
+			let token = "eyJhbGciOiJFUzI1NiJ9.\
+			             eyJpc3MiOiJqb2UiLA0KICJleHAiOjEzMDA4MTkzODAsDQogImh0dHA6Ly9leGFtcGxlLmNvbS9pc19yb290Ijp0cnVlfQ.\
+			             DtEhU3ljbEg8L38VWAfUAqOyKAM6-Xx-F4GawxaepmXFCgfTjDxw5djxLa8ISlSApmWQxfKTUJqPP3-Kg6NU1Q";
+
+			assert_eq!(
+				verify(&VerificationKey::Es256(&public_key), token).unwrap(),
+				RFC_PAYLOAD
+			);
+		}
+
+		#[test]
+		fn test_hs512() {
+			// Generated with Python's hmac and base64 modules.
+			let key = SecretKey::from_slice(&(0u8..64).collect::<Vec<u8>>()).unwrap();
+			let expected = "eyJhbGciOiJIUzUxMiIsInR5cCI6IkpXVCJ9.eyJzdWIiOiIxIn0.\
+			                Iqvpmlo5_7PXZuZvCSCmH7-5_Z2_0LuFuQpp7Us201HVwWPpU8Csvhj56RCVLYml4Te12OEy098obj3YIVGOfw";
+
+			assert_eq!(
+				sign(&SigningKey::Hs512(&key), br#"{"sub":"1"}"#).unwrap(),
+				expected
+			);
+			assert_eq!(
+				verify(&VerificationKey::Hs512(&key), expected).unwrap(),
+				br#"{"sub":"1"}"#
+			);
+		}
+	}
+
+	mod test_sign_verify {
+		use super::*;
+
+		#[test]
+		fn test_roundtrip() {
+			let key = SecretKey::generate_len(64).unwrap();
+			let secret_key = ecdsa_p256::SecretKey::generate();
+			let public_key = ecdsa_p256::PublicKey::try_from(&secret_key).unwrap();
+
+			let token = sign(&SigningKey::Hs256(&key), b"payload").unwrap();
+			assert_eq!(
+				verify(&VerificationKey::Hs256(&key), &token).unwrap(),
+				b"payload"
+			);
+			let token = sign(&SigningKey::Hs512(&key), b"payload").unwrap();
+			assert_eq!(
+				verify(&VerificationKey::Hs512(&key), &token).unwrap(),
+				b"payload"
+			);
+			let token = sign(&SigningKey::Es256(&secret_key), b"").unwrap();
+			assert_eq!(
+				verify(&VerificationKey::Es256(&public_key), &token).unwrap(),
+				b""
+			);
+		}
+
+		#[test]
+		fn test_short_key_err() {
+			let key = SecretKey::from_slice(&[0u8; 32]).unwrap();
+			let short = SecretKey::from_slice(&[0u8; 31]).unwrap();
+
+			assert!(sign(&SigningKey::Hs256(&short), b"payload").is_err());
+			assert!(sign(&SigningKey::Hs512(&key), b"payload").is_err());
+			let token = sign(&SigningKey::Hs256(&key), b"payload").unwrap();
+			assert!(verify(&VerificationKey::Hs512(&key), &token).is_err());
+		}
+
+		#[test]
+		fn test_algorithm_pinned() {
+			let key = SecretKey::generate_len(64).unwrap();
+			let secret_key = ecdsa_p256::SecretKey::generate();
+			let public_key = ecdsa_p256::PublicKey::try_from(&secret_key).unwrap();
+
+			let token = sign(&SigningKey::Es256(&secret_key), b"payload").unwrap();
+			assert!(verify(&VerificationKey::Hs256(&key), &token).is_err());
+			let token = sign(&SigningKey::Hs256(&key), b"payload").unwrap();
+			assert!(verify(&VerificationKey::Hs512(&key), &token).is_err());
+			assert!(verify(&VerificationKey::Es256(&public_key), &token).is_err());
+		}
+
+		#[test]
+		fn test_header_checks() {
+			let key = SecretKey::generate_len(32).unwrap();
+			let token = sign(&SigningKey::Hs256(&key), b"payload").unwrap();
+			let rest = &token[token.find('.').unwrap()..];
+			let with_header = |header: &str| format!("{}{}", encode(header.as_bytes()), rest);
+
+			// The signature covers the header, so each of these also fails
+			// verification, but the header is checked first.
+			for header in [
+				r#"{"alg":"none"}"#,
+				r#"{"typ":"JWT"}"#,
+				r#"{"alg":"HS256","crit":["exp"],"exp":1}"#,
+				r#"{"alg":"HS256","alg":"HS256"}"#,
+				r#"{"alg":["HS256"]}"#,
+			]
+			.iter()
+			{
+				assert!(check_header(header.as_bytes(), "HS256").is_err());
+				assert!(verify(&VerificationKey::Hs256(&key), &with_header(header)).is_err());
+			}
+			assert!(check_header(br#"{"alg":"HS256","kid":"1"}"#, "HS256").is_ok());
+		}
+
+		#[test]
+		fn test_unsigned_token_err() {
+			let key = SecretKey::generate_len(32).unwrap();
+			let token = format!("{}.{}.", encode(br#"{"alg":"none"}"#), encode(b"payload"));
+
+			assert!(verify(&VerificationKey::Hs256(&key), &token).is_err());
+		}
+
+		#[test]
+		fn test_modified_token_err() {
+			let key = SecretKey::generate_len(32).unwrap();
+			let token = sign(&SigningKey::Hs256(&key), b"payload").unwrap();
+
+			for idx in 0..token.len() {
+				let mut modified = token.clone().into_bytes();
+				modified[idx] ^= 1;
+				if let Ok(modified) = String::from_utf8(modified) {
+					assert!(verify(&VerificationKey::Hs256(&key), &modified).is_err());
+				}
+			}
+		}
+
+		#[test]
+		fn test_malformed_token_err() {
+			let key = SecretKey::generate_len(32).unwrap();
+			let token = sign(&SigningKey::Hs256(&key), b"payload").unwrap();
+
+			assert!(verify(&VerificationKey::Hs256(&key), "").is_err());
+			assert!(verify(&VerificationKey::Hs256(&key), &format!("{}.", token)).is_err());
+			assert!(verify(&VerificationKey::Hs256(&key), &token[..token.len() - 1]).is_err());
+			assert!(verify(&VerificationKey::Hs256(&key), &token.replacen('.', "..", 1)).is_err());
+		}
+	}
+}
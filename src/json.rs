@@ -0,0 +1,371 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! A minimal, strict JSON parser for the objects in JSON Web Keys and JOSE
+//! headers.
+
+use crate::errors::UnknownCryptoError;
+use zeroize::Zeroize;
+
+/// The nesting depth of skipped values that is accepted.
+const MAX_DEPTH: usize = 16;
+
+#[must_use]
+/// Parse the JSON object `src`, calling `member` with the name of each member
+/// and the parser positioned at its value. `member` must read the value with
+/// `Parser::string()` or skip it with `Parser::skip_value()`. Duplicate
+/// members are rejected.
+pub(crate) fn parse_object<F>(src: &str, mut member: F) -> Result<(), UnknownCryptoError>
+where
+	F: FnMut(&str, &mut Parser) -> Result<(), UnknownCryptoError>,
+{
+	let mut seen: Vec<String> = Vec::new();
+	let mut parser = Parser::new(src);
+
+	parser.expect(b'{')?;
+	if !parser.consume(b'}') {
+		loop {
+			let name = parser.string()?;
+			if seen.contains(&name) {
+				return Err(UnknownCryptoError::InvalidEncoding);
+			}
+			parser.expect(b':')?;
+			member(&name, &mut parser)?;
+			seen.push(name);
+
+			if parser.consume(b'}') {
+				break;
+			}
+			parser.expect(b',')?;
+		}
+	}
+
+	parser.finish()
+}
+
+/// A strict parser for JSON (RFC 8259) values.
+pub(crate) struct Parser<'a> {
+	src: &'a [u8],
+	pos: usize,
+}
+
+impl<'a> Parser<'a> {
+	fn new(src: &'a str) -> Self {
+		Self {
+			src: src.as_bytes(),
+			pos: 0,
+		}
+	}
+
+	fn skip_whitespace(&mut self) {
+		while let Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r') = self.src.get(self.pos) {
+			self.pos += 1;
+		}
+	}
+
+	fn next(&mut self) -> Result<u8, UnknownCryptoError> {
+		let byte = *self
+			.src
+			.get(self.pos)
+			.ok_or(UnknownCryptoError::InvalidEncoding)?;
+		self.pos += 1;
+
+		Ok(byte)
+	}
+
+	fn peek(&mut self) -> Option<u8> {
+		self.skip_whitespace();
+		self.src.get(self.pos).copied()
+	}
+
+	/// Consume `byte` if it is the next token.
+	fn consume(&mut self, byte: u8) -> bool {
+		if self.peek() == Some(byte) {
+			self.pos += 1;
+			true
+		} else {
+			false
+		}
+	}
+
+	fn expect(&mut self, byte: u8) -> Result<(), UnknownCryptoError> {
+		if self.consume(byte) {
+			Ok(())
+		} else {
+			Err(UnknownCryptoError::InvalidEncoding)
+		}
+	}
+
+	fn finish(mut self) -> Result<(), UnknownCryptoError> {
+		match self.peek() {
+			None => Ok(()),
+			Some(_) => Err(UnknownCryptoError::InvalidEncoding),
+		}
+	}
+
+	/// Read the four hexadecimal digits of a `\u` escape.
+	fn hex4(&mut self) -> Result<u32, UnknownCryptoError> {
+		let mut value = 0u32;
+		for _ in 0..4 {
+			let digit = (self.next()? as char)
+				.to_digit(16)
+				.ok_or(UnknownCryptoError::InvalidEncoding)?;
+			value = (value << 4) | digit;
+		}
+
+		Ok(value)
+	}
+
+	/// Read a string value.
+	pub(crate) fn string(&mut self) -> Result<String, UnknownCryptoError> {
+		self.expect(b'"')?;
+		let mut value = String::new();
+		loop {
+			let start = self.pos;
+			while let Some(byte) = self.src.get(self.pos) {
+				if *byte == b'"' || *byte == b'\\' || *byte < 0x20 {
+					break;
+				}
+				self.pos += 1;
+			}
+			// The bytes between ASCII characters of a `str` are valid UTF-8.
+			value.push_str(core::str::from_utf8(&self.src[start..self.pos]).unwrap());
+
+			let c = match self.next() {
+				Ok(b'"') => return Ok(value),
+				Ok(b'\\') => match self.next() {
+					Ok(b'"') => Some('"'),
+					Ok(b'\\') => Some('\\'),
+					Ok(b'/') => Some('/'),
+					Ok(b'b') => Some('\u{8}'),
+					Ok(b'f') => Some('\u{c}'),
+					Ok(b'n') => Some('\n'),
+					Ok(b'r') => Some('\r'),
+					Ok(b't') => Some('\t'),
+					Ok(b'u') => self.escaped_char(),
+					_ => None,
+				}
+				.ok_or(UnknownCryptoError::InvalidEncoding),
+				_ => Err(UnknownCryptoError::InvalidEncoding),
+			};
+			match c {
+				Ok(c) => value.push(c),
+				Err(err) => {
+					value.zeroize();
+					return Err(err);
+				}
+			}
+		}
+	}
+
+	/// Read the character of a `\u` escape, which may be a surrogate pair.
+	fn escaped_char(&mut self) -> Option<char> {
+		let high = self.hex4().ok()?;
+		if (0xdc00..0xe000).contains(&high) {
+			return None;
+		}
+		if !(0xd800..0xdc00).contains(&high) {
+			return core::char::from_u32(high);
+		}
+		if self.next().ok()? != b'\\' || self.next().ok()? != b'u' {
+			return None;
+		}
+		let low = self.hex4().ok()?;
+		if !(0xdc00..0xe000).contains(&low) {
+			return None;
+		}
+
+		core::char::from_u32(0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00))
+	}
+
+	fn digits(&mut self) -> Result<(), UnknownCryptoError> {
+		let start = self.pos;
+		while let Some(b'0'..=b'9') = self.src.get(self.pos) {
+			self.pos += 1;
+		}
+		if self.pos == start {
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+
+		Ok(())
+	}
+
+	fn number(&mut self) -> Result<(), UnknownCryptoError> {
+		if self.src.get(self.pos) == Some(&b'-') {
+			self.pos += 1;
+		}
+		if self.src.get(self.pos) == Some(&b'0') {
+			self.pos += 1;
+		} else {
+			self.digits()?;
+		}
+		if self.src.get(self.pos) == Some(&b'.') {
+			self.pos += 1;
+			self.digits()?;
+		}
+		if let Some(b'e') | Some(b'E') = self.src.get(self.pos) {
+			self.pos += 1;
+			if let Some(b'+') | Some(b'-') = self.src.get(self.pos) {
+				self.pos += 1;
+			}
+			self.digits()?;
+		}
+
+		Ok(())
+	}
+
+	fn literal(&mut self, literal: &[u8]) -> Result<(), UnknownCryptoError> {
+		if self.src[self.pos..].starts_with(literal) {
+			self.pos += literal.len();
+			Ok(())
+		} else {
+			Err(UnknownCryptoError::InvalidEncoding)
+		}
+	}
+
+	/// Skip a value of any type, such as that of a member that is not used.
+	pub(crate) fn skip_value(&mut self) -> Result<(), UnknownCryptoError> {
+		self.skip_nested(0)
+	}
+
+	fn skip_nested(&mut self, depth: usize) -> Result<(), UnknownCryptoError> {
+		if depth > MAX_DEPTH {
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+
+		match self.peek() {
+			Some(b'"') => self.string().map(|_| ()),
+			Some(b'{') => {
+				self.pos += 1;
+				if self.consume(b'}') {
+					return Ok(());
+				}
+				loop {
+					self.string()?;
+					self.expect(b':')?;
+					self.skip_nested(depth + 1)?;
+					if self.consume(b'}') {
+						return Ok(());
+					}
+					self.expect(b',')?;
+				}
+			}
+			Some(b'[') => {
+				self.pos += 1;
+				if self.consume(b']') {
+					return Ok(());
+				}
+				loop {
+					self.skip_nested(depth + 1)?;
+					if self.consume(b']') {
+						return Ok(());
+					}
+					self.expect(b',')?;
+				}
+			}
+			Some(b't') => self.literal(b"true"),
+			Some(b'f') => self.literal(b"false"),
+			Some(b'n') => self.literal(b"null"),
+			Some(b'-') | Some(b'0'..=b'9') => self.number(),
+			_ => Err(UnknownCryptoError::InvalidEncoding),
+		}
+	}
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	/// Parse `src`, returning its string members and skipping the others.
+	fn strings(src: &str) -> Result<Vec<(String, String)>, UnknownCryptoError> {
+		let mut members = Vec::new();
+		parse_object(src, |name, value| {
+			if value.peek() == Some(b'"') {
+				members.push((name.to_string(), value.string()?));
+				Ok(())
+			} else {
+				value.skip_value()
+			}
+		})?;
+
+		Ok(members)
+	}
+
+	#[test]
+	fn test_strings_and_escapes() {
+		let parsed = strings(r#" { "a" : "b\"\\\/\b\f\n\r\t" , "c":"\u00e9\ud83d\ude00é" } "#).unwrap();
+		assert_eq!(
+			parsed[0],
+			("a".to_string(), "b\"\\/\u{8}\u{c}\n\r\t".to_string())
+		);
+		assert_eq!(parsed[1], ("c".to_string(), "é😀é".to_string()));
+		assert!(strings("{}").unwrap().is_empty());
+	}
+
+	#[test]
+	fn test_skip_values() {
+		let src = r#"{"a":[],"b":{},"c":[1,-0.5,2e10,3E-2,true,false,null],"d":{"e":{"f":[{}]}}}"#;
+		assert!(strings(src).unwrap().is_empty());
+	}
+
+	#[test]
+	fn test_invalid_strings_err() {
+		assert!(strings(r#"{"a":"\x"}"#).is_err());
+		assert!(strings(r#"{"a":"\u12"}"#).is_err());
+		assert!(strings(r#"{"a":"\udc00"}"#).is_err());
+		assert!(strings(r#"{"a":"\ud800"}"#).is_err());
+		assert!(strings(r#"{"a":"\ud800A"}"#).is_err());
+		assert!(strings("{\"a\":\"\n\"}").is_err());
+		assert!(strings(r#"{"a":"b}"#).is_err());
+		assert!(strings(r#"{a:"b"}"#).is_err());
+	}
+
+	#[test]
+	fn test_invalid_values_err() {
+		for value in [
+			"01", "-", "1.", "1e", "+1", "tru", "nul", "[1,]", "{\"a\"}", "'a'",
+		]
+		.iter()
+		{
+			assert!(strings(&format!("{{\"a\":{}}}", value)).is_err());
+		}
+	}
+
+	#[test]
+	fn test_invalid_objects_err() {
+		assert!(strings("").is_err());
+		assert!(strings("[]").is_err());
+		assert!(strings(r#"{"a":"b",}"#).is_err());
+		assert!(strings(r#"{"a":"b" "c":"d"}"#).is_err());
+		assert!(strings(r#"{"a":"b"}{}"#).is_err());
+		assert!(strings(r#"{"a":"b","a":"c"}"#).is_err());
+		assert!(strings(r#"{"a":1,"a":2}"#).is_err());
+	}
+
+	#[test]
+	fn test_max_depth() {
+		let nested = |depth: usize| format!("{{\"a\":{}{}}}", "[".repeat(depth), "]".repeat(depth));
+		assert!(strings(&nested(MAX_DEPTH + 1)).is_ok());
+		assert!(strings(&nested(MAX_DEPTH + 2)).is_err());
+	}
+}
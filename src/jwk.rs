@@ -0,0 +1,547 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! JSON Web Keys.
+//!
+//! # Use case:
+//! `orion::jwk` can be used to convert orion's keys to and from JSON Web Keys
+//! ([RFC 7517]), and to compute their thumbprints ([RFC 7638]).
+//!
+//! An example of this could be publishing a public key in a JWK Set, or using
+//! the thumbprint of a key as its key ID.
+//!
+//! # About:
+//! - The supported keys are X448 keys (`"kty":"OKP"`, `"crv":"X448"`, [RFC
+//!   8037]), ECDSA P-256 keys (`"kty":"EC"`, `"crv":"P-256"`, [RFC 7518]) and
+//!   symmetric keys (`"kty":"oct"`). orion does not implement Ed25519 or
+//!   X25519, so JWKs of those curves cannot be imported.
+//! - Private keys are exported with both their private and public members.
+//!   When importing, the public members must belong to the private key.
+//! - Keys are exported with only the members required for their type, in
+//!   lexicographic order and without whitespace. Other members, such as
+//!   `kid` or `use`, are ignored when importing.
+//! - Duplicate members, and members that hold key material of another key
+//!   type, are rejected.
+//! - The thumbprint is the SHA256 hash of the required public members,
+//!   encoded as Base64 without padding using the URL safe alphabet. The
+//!   thumbprint of a private key is that of its public key.
+//!
+//! # Parameters:
+//! - `src`: The JWK to import.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `src` is not a JSON object, or contains duplicate members.
+//! - `src` is of an unsupported key type or curve.
+//! - A member of `src` is missing, has the wrong length, or is not valid
+//!   Base64.
+//! - The public members of `src` do not belong to its private key.
+//! - An ECDSA P-256 secret key is zero or not less than the group order.
+//!
+//! # Security:
+//! - The JWK of a private or symmetric key contains the key in plaintext. It
+//!   is up to the caller to zeroize it after use.
+//! - An imported public key is not checked to be a valid point until it is
+//!   used.
+//!
+//! # Example:
+//! ```rust
+//! use orion::hazardous::ecc::x448::{PrivateKey, PublicKey};
+//! use orion::jwk::Jwk;
+//! use core::convert::TryFrom;
+//!
+//! let private_key = PrivateKey::generate();
+//! let public_key = PublicKey::try_from(&private_key)?;
+//!
+//! let jwk = Jwk::X448Public(public_key).to_json()?;
+//! assert!(jwk.starts_with(r#"{"crv":"X448","kty":"OKP","x":"#));
+//!
+//! let private_jwk = Jwk::X448Private(private_key).to_json()?;
+//! let imported = Jwk::from_json(&private_jwk)?;
+//! assert_eq!(imported.thumbprint()?, Jwk::from_json(&jwk)?.thumbprint()?);
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [RFC 7517]: https://tools.ietf.org/html/rfc7517
+//! [RFC 7518]: https://tools.ietf.org/html/rfc7518
+//! [RFC 7638]: https://tools.ietf.org/html/rfc7638
+//! [RFC 8037]: https://tools.ietf.org/html/rfc8037
+
+pub use crate::hltypes::SecretKey;
+use crate::{
+	errors::UnknownCryptoError,
+	hazardous::{
+		ecc::{ecdsa_p256, x448},
+		hash::sha256,
+	},
+	json,
+	util::base64::{self, Variant},
+};
+use core::convert::TryFrom;
+use zeroize::Zeroize;
+
+/// The size of an affine coordinate of P-256.
+const P256_COORDINATE_SIZE: usize = 32;
+
+#[derive(Debug, PartialEq)]
+/// A key that can be converted to and from a JSON Web Key.
+pub enum Jwk {
+	/// An X448 public key.
+	X448Public(x448::PublicKey),
+	/// An X448 private key.
+	X448Private(x448::PrivateKey),
+	/// An ECDSA P-256 public key.
+	P256Public(ecdsa_p256::PublicKey),
+	/// An ECDSA P-256 secret key.
+	P256Private(ecdsa_p256::SecretKey),
+	/// A symmetric key.
+	Symmetric(SecretKey),
+}
+
+/// Encode `bytes` as a JWK member value.
+fn encode(bytes: &[u8]) -> String {
+	base64::encode(bytes, Variant::UrlSafeNoPadding)
+}
+
+/// Append the member `name` with the string `value` to `dst`.
+fn put_member(dst: &mut String, name: &str, value: &str) {
+	dst.push(if dst.is_empty() { '{' } else { ',' });
+	dst.push('"');
+	dst.push_str(name);
+	dst.push_str("\":\"");
+	dst.push_str(value);
+	dst.push('"');
+}
+
+/// The members of an X448 key, in lexicographic order.
+fn okp_json(public_key: &x448::PublicKey, private_key: Option<&str>) -> String {
+	let mut json = String::new();
+	put_member(&mut json, "crv", "X448");
+	if let Some(d) = private_key {
+		put_member(&mut json, "d", d);
+	}
+	put_member(&mut json, "kty", "OKP");
+	put_member(&mut json, "x", &encode(public_key.as_ref()));
+	json.push('}');
+
+	json
+}
+
+/// The members of an ECDSA P-256 key, in lexicographic order.
+fn ec_json(public_key: &ecdsa_p256::PublicKey, private_key: Option<&str>) -> String {
+	// The public key is 0x04 || x || y.
+	let (x, y) = public_key.as_ref()[1..].split_at(P256_COORDINATE_SIZE);
+	let mut json = String::new();
+	put_member(&mut json, "crv", "P-256");
+	if let Some(d) = private_key {
+		put_member(&mut json, "d", d);
+	}
+	put_member(&mut json, "kty", "EC");
+	put_member(&mut json, "x", &encode(x));
+	put_member(&mut json, "y", &encode(y));
+	json.push('}');
+
+	json
+}
+
+impl Jwk {
+	#[must_use]
+	/// Export the key as a JWK.
+	pub fn to_json(&self) -> Result<String, UnknownCryptoError> {
+		Ok(match self {
+			Jwk::X448Public(public_key) => okp_json(public_key, None),
+			Jwk::X448Private(private_key) => {
+				let public_key = x448::PublicKey::try_from(private_key)?;
+				let mut d = encode(private_key.unprotected_as_bytes());
+				let json = okp_json(&public_key, Some(d.as_str()));
+				d.zeroize();
+				json
+			}
+			Jwk::P256Public(public_key) => ec_json(public_key, None),
+			Jwk::P256Private(secret_key) => {
+				let public_key = ecdsa_p256::PublicKey::try_from(secret_key)?;
+				let mut d = encode(secret_key.unprotected_as_bytes());
+				let json = ec_json(&public_key, Some(d.as_str()));
+				d.zeroize();
+				json
+			}
+			Jwk::Symmetric(secret_key) => {
+				let mut k = encode(secret_key.unprotected_as_bytes());
+				let mut json = String::new();
+				put_member(&mut json, "k", &k);
+				put_member(&mut json, "kty", "oct");
+				json.push('}');
+				k.zeroize();
+				json
+			}
+		})
+	}
+
+	#[must_use]
+	/// Import a key from a JWK.
+	pub fn from_json(src: &str) -> Result<Self, UnknownCryptoError> {
+		Members::parse(src)?.to_key()
+	}
+
+	#[must_use]
+	/// Compute the thumbprint of the key, as specified in RFC 7638.
+	pub fn thumbprint(&self) -> Result<String, UnknownCryptoError> {
+		let mut json = match self {
+			Jwk::X448Private(private_key) => {
+				okp_json(&x448::PublicKey::try_from(private_key)?, None)
+			}
+			Jwk::P256Private(secret_key) => {
+				ec_json(&ecdsa_p256::PublicKey::try_from(secret_key)?, None)
+			}
+			_ => self.to_json()?,
+		};
+		let digest = sha256::digest(json.as_bytes());
+		json.zeroize();
+
+		Ok(encode(digest?.as_ref()))
+	}
+}
+
+/// The members of a JWK that orion uses.
+#[derive(Default)]
+struct Members {
+	kty: Option<String>,
+	crv: Option<String>,
+	x: Option<String>,
+	y: Option<String>,
+	d: Option<String>,
+	k: Option<String>,
+}
+
+impl Drop for Members {
+	fn drop(&mut self) {
+		if let Some(d) = self.d.as_mut() {
+			d.zeroize();
+		}
+		if let Some(k) = self.k.as_mut() {
+			k.zeroize();
+		}
+	}
+}
+
+/// Decode the member `value` into a key of exactly `len` bytes.
+fn decode_member<T, F>(
+	value: &Option<String>,
+	len: usize,
+	from_slice: F,
+) -> Result<T, UnknownCryptoError>
+where
+	F: FnOnce(&[u8]) -> Result<T, UnknownCryptoError>,
+{
+	let value = value.as_ref().ok_or(UnknownCryptoError::InvalidEncoding)?;
+	base64::decode_into(value, Variant::UrlSafeNoPadding, |bytes| {
+		if bytes.len() != len {
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+		from_slice(bytes)
+	})
+}
+
+impl Members {
+	fn parse(src: &str) -> Result<Self, UnknownCryptoError> {
+		let mut members = Self::default();
+		json::parse_object(src, |name, value| {
+			let slot = match name {
+				"kty" => &mut members.kty,
+				"crv" => &mut members.crv,
+				"x" => &mut members.x,
+				"y" => &mut members.y,
+				"d" => &mut members.d,
+				"k" => &mut members.k,
+				_ => return value.skip_value(),
+			};
+			*slot = Some(value.string()?);
+
+			Ok(())
+		})?;
+
+		Ok(members)
+	}
+
+	/// Check that only the members in `allowed` hold key material.
+	fn check_allowed(&self, allowed: &[&str]) -> Result<(), UnknownCryptoError> {
+		let present = [
+			("crv", self.crv.is_some()),
+			("x", self.x.is_some()),
+			("y", self.y.is_some()),
+			("d", self.d.is_some()),
+			("k", self.k.is_some()),
+		];
+		if present
+			.iter()
+			.any(|(name, is_some)| *is_some && !allowed.contains(name))
+		{
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+
+		Ok(())
+	}
+
+	fn to_key(&self) -> Result<Jwk, UnknownCryptoError> {
+		match self.kty.as_ref().map(|kty| kty.as_str()) {
+			Some("OKP") => {
+				self.check_allowed(&["crv", "x", "d"])?;
+				if self.crv.as_ref().map(|crv| crv.as_str()) != Some("X448") {
+					return Err(UnknownCryptoError::InvalidEncoding);
+				}
+				let public_key =
+					decode_member(&self.x, x448::PUBLIC_KEY_SIZE, x448::PublicKey::from_slice)?;
+				if self.d.is_none() {
+					return Ok(Jwk::X448Public(public_key));
+				}
+
+				let private_key = decode_member(
+					&self.d,
+					x448::PRIVATE_KEY_SIZE,
+					x448::PrivateKey::from_slice,
+				)?;
+				if x448::PublicKey::try_from(&private_key)? != public_key {
+					return Err(UnknownCryptoError::InvalidKey);
+				}
+
+				Ok(Jwk::X448Private(private_key))
+			}
+			Some("EC") => {
+				self.check_allowed(&["crv", "x", "y", "d"])?;
+				if self.crv.as_ref().map(|crv| crv.as_str()) != Some("P-256") {
+					return Err(UnknownCryptoError::InvalidEncoding);
+				}
+				let mut point = [0u8; ecdsa_p256::PUBLIC_KEY_SIZE];
+				point[0] = 0x04;
+				let (x, y) = point[1..].split_at_mut(P256_COORDINATE_SIZE);
+				decode_member(&self.x, P256_COORDINATE_SIZE, |bytes| {
+					x.copy_from_slice(bytes);
+					Ok(())
+				})?;
+				decode_member(&self.y, P256_COORDINATE_SIZE, |bytes| {
+					y.copy_from_slice(bytes);
+					Ok(())
+				})?;
+				let public_key = ecdsa_p256::PublicKey::from(point);
+				if self.d.is_none() {
+					return Ok(Jwk::P256Public(public_key));
+				}
+
+				let secret_key = decode_member(
+					&self.d,
+					ecdsa_p256::SECRET_KEY_SIZE,
+					ecdsa_p256::SecretKey::from_slice,
+				)?;
+				// This also checks that the secret key is a valid scalar.
+				if ecdsa_p256::PublicKey::try_from(&secret_key)? != public_key {
+					return Err(UnknownCryptoError::InvalidKey);
+				}
+
+				Ok(Jwk::P256Private(secret_key))
+			}
+			Some("oct") => {
+				self.check_allowed(&["k"])?;
+				let k = self.k.as_ref().ok_or(UnknownCryptoError::InvalidEncoding)?;
+
+				Ok(Jwk::Symmetric(base64::decode_into(
+					k,
+					Variant::UrlSafeNoPadding,
+					SecretKey::from_slice,
+				)?))
+			}
+			_ => Err(UnknownCryptoError::InvalidEncoding),
+		}
+	}
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	mod test_roundtrip {
+		use super::*;
+
+		#[test]
+		fn test_x448() {
+			let private_key = x448::PrivateKey::generate();
+			let public_key = x448::PublicKey::try_from(&private_key).unwrap();
+
+			let json = Jwk::X448Public(public_key).to_json().unwrap();
+			assert_eq!(Jwk::from_json(&json).unwrap(), Jwk::X448Public(public_key));
+
+			let json = Jwk::X448Private(private_key).to_json().unwrap();
+			assert!(json.starts_with(r#"{"crv":"X448","d":""#));
+			match Jwk::from_json(&json).unwrap() {
+				Jwk::X448Private(imported) => {
+					assert_eq!(x448::PublicKey::try_from(&imported).unwrap(), public_key)
+				}
+				_ => panic!("Wrong key type"),
+			}
+		}
+
+		#[test]
+		fn test_p256() {
+			let secret_key = ecdsa_p256::SecretKey::generate();
+			let public_key = ecdsa_p256::PublicKey::try_from(&secret_key).unwrap();
+
+			let json = Jwk::P256Public(public_key).to_json().unwrap();
+			assert!(json.starts_with(r#"{"crv":"P-256","kty":"EC","x":""#));
+			assert_eq!(Jwk::from_json(&json).unwrap(), Jwk::P256Public(public_key));
+
+			let json = Jwk::P256Private(secret_key).to_json().unwrap();
+			assert!(Jwk::from_json(&json).is_ok());
+		}
+
+		#[test]
+		fn test_symmetric() {
+			let secret_key = SecretKey::from_slice(&[0x42u8; 20]).unwrap();
+			let json = Jwk::Symmetric(secret_key).to_json().unwrap();
+			assert_eq!(json, r#"{"k":"QkJCQkJCQkJCQkJCQkJCQkJCQkI","kty":"oct"}"#);
+
+			match Jwk::from_json(&json).unwrap() {
+				Jwk::Symmetric(imported) => assert!(imported == &[0x42u8; 20][..]),
+				_ => panic!("Wrong key type"),
+			}
+		}
+	}
+
+	mod test_thumbprint {
+		use super::*;
+
+		#[test]
+		fn test_definition() {
+			// The hash of the required members, in lexicographic order and
+			// without whitespace.
+			let json = r#"{"k":"GawgguFyGrWKav7AX4VKUg","kty":"oct"}"#;
+			let expected = base64::encode(
+				sha256::digest(json.as_bytes()).unwrap().as_ref(),
+				Variant::UrlSafeNoPadding,
+			);
+			let reordered = r#"{ "kty": "oct", "k": "GawgguFyGrWKav7AX4VKUg" }"#;
+			assert_eq!(
+				Jwk::from_json(reordered).unwrap().thumbprint().unwrap(),
+				expected
+			);
+		}
+
+		#[test]
+		fn test_private_matches_public() {
+			let secret_key = ecdsa_p256::SecretKey::generate();
+			let public_key = ecdsa_p256::PublicKey::try_from(&secret_key).unwrap();
+
+			assert_eq!(
+				Jwk::P256Private(secret_key).thumbprint().unwrap(),
+				Jwk::P256Public(public_key).thumbprint().unwrap()
+			);
+		}
+
+		#[test]
+		fn test_ignores_other_members() {
+			let secret_key = SecretKey::from_slice(&[1u8; 32]).unwrap();
+			let json = Jwk::Symmetric(secret_key).to_json().unwrap();
+			let with_kid = json.replace("{", r#"{"kid":"a", "use":"enc","#);
+
+			assert_eq!(
+				Jwk::from_json(&json).unwrap().thumbprint().unwrap(),
+				Jwk::from_json(&with_kid).unwrap().thumbprint().unwrap()
+			);
+		}
+	}
+
+	mod test_import {
+		use super::*;
+
+		fn p256_public() -> String {
+			let secret_key = ecdsa_p256::SecretKey::generate();
+			let public_key = ecdsa_p256::PublicKey::try_from(&secret_key).unwrap();
+			Jwk::P256Public(public_key).to_json().unwrap()
+		}
+
+		#[test]
+		fn test_whitespace_and_ignored_members() {
+			let json = p256_public().replace(",", " ,\n\t").replace(":", " : ");
+			let json = json.replace(
+				"{",
+				r#"{ "key_ops": ["verify", {"a": [1, -2.5e+3, true, false, null]}], "kid": "é😀\"", "#,
+			);
+			assert!(Jwk::from_json(&json).is_ok());
+		}
+
+		#[test]
+		fn test_unsupported_key_types_err() {
+			assert!(Jwk::from_json(
+				r#"{"crv":"Ed25519","kty":"OKP","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#
+			)
+			.is_err());
+			assert!(Jwk::from_json(&p256_public().replace("P-256", "P-384")).is_err());
+			assert!(Jwk::from_json(r#"{"e":"AQAB","kty":"RSA","n":"AQAB"}"#).is_err());
+			assert!(Jwk::from_json(r#"{"k":"AQAB"}"#).is_err());
+		}
+
+		#[test]
+		fn test_other_key_material_err() {
+			assert!(Jwk::from_json(r#"{"k":"AQAB","kty":"oct","x":"AQAB"}"#).is_err());
+			assert!(Jwk::from_json(&p256_public().replace("{", r#"{"k":"AQAB","#)).is_err());
+		}
+
+		#[test]
+		fn test_wrong_lengths_err() {
+			assert!(Jwk::from_json(r#"{"k":"","kty":"oct"}"#).is_err());
+			let json = p256_public();
+			let x_start = json.find(r#""x":""#).unwrap() + 5;
+			let mut short = json.clone();
+			short.remove(x_start);
+			assert!(Jwk::from_json(&short).is_err());
+		}
+
+		#[test]
+		fn test_mismatched_private_key_err() {
+			let secret_key = ecdsa_p256::SecretKey::generate();
+			let json = Jwk::P256Private(secret_key).to_json().unwrap();
+			let other = p256_public();
+			let x = |json: &str| {
+				let start = json.find(r#""x":"#).unwrap();
+				json[start..json.len() - 1].to_string()
+			};
+
+			let mismatched = json.replace(&x(&json), &x(&other));
+			assert!(Jwk::from_json(&mismatched).is_err());
+		}
+
+		#[test]
+		fn test_malformed_json_err() {
+			let json = p256_public();
+
+			assert!(Jwk::from_json(&json[..json.len() - 1]).is_err());
+			assert!(Jwk::from_json(&format!("{}{}", json, "{}")).is_err());
+			assert!(Jwk::from_json(&json.replace("{", r#"{"kty":"EC","#)).is_err());
+			assert!(Jwk::from_json(&json.replace("}", ",}")).is_err());
+			assert!(Jwk::from_json(&json.replace("{", r#"{"a":01,"#)).is_err());
+			assert!(Jwk::from_json(&json.replace("{", r#"{"a":"\x","#)).is_err());
+			assert!(Jwk::from_json(&json.replace("{", r#"{"a":"\udc00","#)).is_err());
+			assert!(Jwk::from_json(&json.replace("{", "{\"a\":\"\n\",")).is_err());
+			assert!(Jwk::from_json(&json.replace("{", r#"{"a":tru,"#)).is_err());
+			assert!(Jwk::from_json("").is_err());
+			assert!(Jwk::from_json("[]").is_err());
+		}
+	}
+}
@@ -35,6 +35,10 @@
 //!
 //! # About:
 //! - Uses PBKDF2-HMAC-SHA512.
+//! - [`kdf::verify_batch`] verifies many derived keys at once. The work is
+//!   spread over a fixed number of worker threads, each verifying one key at a
+//!   time, so memory use depends on the number of workers and not on the size
+//!   of the batch.
 //!
 //! # Parameters:
 //! - `password`: The low-entropy input key to be used in key derivation.
@@ -43,13 +47,19 @@
 //! - `iterations`: The number of iterations performed by PBKDF2, i.e. the cost
 //!   parameter.
 //! - `length`: The desired length of the derived key.
+//! - `candidates`: The expected derived keys, with the password and salt they
+//!   should be derived from, to be verified by [`kdf::verify_batch`].
+//! - `max_workers`: The largest number of threads [`kdf::verify_batch`] uses.
+//!   Fewer are used if the CPU has fewer cores, or the batch fewer keys.
 //!
 //! # Errors:
 //! An error will be returned if:
 //! - `iterations` is 0.
 //! - `length` is 0.
 //! - `length` is not less than `u32::max_value()`.
-//! - The `expected` does not match the derived key.
+//! - The `expected` does not match the derived key. For [`kdf::verify_batch`],
+//!   a key that does not match is reported as `false` instead.
+//! - `max_workers` is 0.
 //!
 //!
 //! # Security:
@@ -78,9 +88,11 @@
 //! ```
 //! [`Salt`]: https://docs.rs/orion/latest/orion/kdf/struct.Salt.html
 //! [`Salt::default()`]: https://docs.rs/orion/latest/orion/kdf/struct.Salt.html
+//! [`kdf::verify_batch`]: https://docs.rs/orion/latest/orion/kdf/fn.verify_batch.html
 
 pub use crate::hltypes::{Password, Salt, SecretKey};
 use crate::{errors::UnknownCryptoError, hazardous::kdf::pbkdf2};
+use alloc::vec;
 use zeroize::Zeroize;
 
 #[must_use]
@@ -92,7 +104,7 @@ pub fn derive_key(
 	length: usize,
 ) -> Result<SecretKey, UnknownCryptoError> {
 	if length < 1 || length >= (u32::max_value() as usize) {
-		return Err(UnknownCryptoError);
+		return Err(UnknownCryptoError::InvalidLength);
 	}
 
 	let mut buffer = vec![0u8; length];
@@ -133,8 +145,32 @@ pub fn derive_key_verify(
 	Ok(is_good)
 }
 
+#[cfg(feature = "safe_api")]
+#[must_use]
+/// Verify many derived keys using PBKDF2-HMAC-SHA512, with at most
+/// `max_workers` threads. The results are returned in the same order as
+/// `candidates`.
+pub fn verify_batch(
+	candidates: &[(&SecretKey, &Password, &Salt)],
+	iterations: usize,
+	max_workers: usize,
+) -> Result<Vec<bool>, UnknownCryptoError> {
+	if iterations < 1 || max_workers < 1 {
+		return Err(UnknownCryptoError::ParameterOutOfRange);
+	}
+
+	Ok(crate::util::verify_with_workers(
+		candidates,
+		max_workers,
+		|(expected, password, salt)| {
+			derive_key_verify(expected, password, salt, iterations).is_ok()
+		},
+	))
+}
+
 // Testing public functions in the module.
 #[cfg(test)]
+#[cfg(feature = "safe_api")]
 mod public {
 	use super::*;
 
@@ -171,6 +207,52 @@ mod public {
 		}
 	}
 
+	mod test_verify_batch {
+		use super::*;
+
+		#[test]
+		fn test_verify_batch_same_as_verify() {
+			let password = Password::from_slice(&[0u8; 64]).unwrap();
+			let bad_password = Password::from_slice(&[1u8; 64]).unwrap();
+			let salt = Salt::from_slice(&[0u8; 64]).unwrap();
+			let keys: Vec<SecretKey> = (1..8)
+				.map(|length| derive_key(&password, &salt, 100, length * 8).unwrap())
+				.collect();
+
+			// Every other key is checked against the wrong password.
+			let candidates: Vec<(&SecretKey, &Password, &Salt)> = keys
+				.iter()
+				.enumerate()
+				.map(|(idx, key)| {
+					if idx % 2 == 0 {
+						(key, &password, &salt)
+					} else {
+						(key, &bad_password, &salt)
+					}
+				})
+				.collect();
+
+			for max_workers in [1, 2, 16].iter() {
+				let results = verify_batch(&candidates, 100, *max_workers).unwrap();
+				assert_eq!(results.len(), candidates.len());
+				for (idx, is_good) in results.iter().enumerate() {
+					assert_eq!(*is_good, idx % 2 == 0);
+				}
+			}
+		}
+
+		#[test]
+		fn test_verify_batch_bad_params() {
+			let password = Password::from_slice(&[0u8; 64]).unwrap();
+			let salt = Salt::from_slice(&[0u8; 64]).unwrap();
+			let dk = derive_key(&password, &salt, 100, 64).unwrap();
+
+			assert!(verify_batch(&[(&dk, &password, &salt)], 0, 4).is_err());
+			assert!(verify_batch(&[(&dk, &password, &salt)], 100, 0).is_err());
+			assert!(verify_batch(&[], 100, 4).unwrap().is_empty());
+		}
+	}
+
 	// Proptests. Only exectued when NOT testing no_std.
 	#[cfg(feature = "safe_api")]
 	mod proptest {
@@ -0,0 +1,279 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Authenticated key exchange.
+//!
+//! # Use case:
+//! `orion::kex` can be used to establish a pair of secret keys between a
+//! client and a server that each have a long-term key pair, where each party
+//! knows the other's public key in advance.
+//!
+//! An example of this could be two services on a network that need to encrypt
+//! traffic between them using [`orion::aead`].
+//!
+//! # About:
+//! - Uses X448 for the key agreement.
+//! - The shared key is never used directly. Session keys are derived with
+//!   HKDF-HMAC-SHA512, using the client's and the server's public keys as
+//!   salt, so that both parties bind the keys to the same transcript.
+//! - Two directional [`SecretKey`]s are returned: one for data sent by the
+//!   client to the server, and one for data sent by the server to the client.
+//!
+//! # Parameters:
+//! - `client_private_key`/`server_private_key`: The private key of the party
+//!   calling the function.
+//! - `server_public_key`/`client_public_key`: The public key of the remote
+//!   party.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - The remote public key is a low-order point, resulting in an all-zero
+//!   shared key.
+//!
+//! # Security:
+//! - The private key should always be generated using a CSPRNG.
+//!   [`PrivateKey::generate()`] can be used for this.
+//! - [`PrivateKey::from_seed()`] can be used instead to derive a long-term
+//!   private key from a backup or KDF-derived [`Seed`]. Anyone who knows the
+//!   seed knows the private key.
+//! - The remote public key must be obtained over an authenticated channel. The
+//!   key exchange only authenticates the remote party if its public key is
+//!   known to be genuine.
+//! - Session keys are the same every time for a given pair of key pairs. They
+//!   must therefore only be used with [`orion::aead`], which generates a random
+//!   nonce for every message.
+//!
+//! # Example:
+//! ```rust
+//! use orion::{aead, kex};
+//! use core::convert::TryFrom;
+//!
+//! let client_private_key = kex::PrivateKey::generate();
+//! let server_private_key = kex::PrivateKey::generate();
+//! let client_public_key = kex::PublicKey::try_from(&client_private_key)?;
+//! let server_public_key = kex::PublicKey::try_from(&server_private_key)?;
+//!
+//! let client_keys = kex::client_session_keys(&client_private_key, &server_public_key)?;
+//! let server_keys = kex::server_session_keys(&server_private_key, &client_public_key)?;
+//!
+//! let ciphertext = aead::seal(client_keys.get_transmitting(), b"Hello server")?;
+//! let plaintext = aead::open(server_keys.get_receiving(), &ciphertext)?;
+//! assert_eq!(plaintext, b"Hello server");
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`orion::aead`]: https://docs.rs/orion/latest/orion/aead/index.html
+//! [`SecretKey`]: https://docs.rs/orion/latest/orion/aead/struct.SecretKey.html
+//! [`PrivateKey::generate()`]: https://docs.rs/orion/latest/orion/hazardous/ecc/x448/struct.PrivateKey.html
+//! [`PrivateKey::from_seed()`]: https://docs.rs/orion/latest/orion/hazardous/ecc/x448/struct.PrivateKey.html#method.from_seed
+//! [`Seed`]: https://docs.rs/orion/latest/orion/hazardous/ecc/x448/struct.Seed.html
+
+pub use crate::hazardous::ecc::x448::{PrivateKey, PublicKey, Seed};
+use crate::{
+	errors::UnknownCryptoError,
+	hazardous::{
+		ecc::x448::{self, PUBLIC_KEY_SIZE},
+		kdf::hkdf,
+	},
+	hltypes::SecretKey,
+};
+use core::convert::TryFrom;
+use zeroize::Zeroize;
+
+/// The size of each derived session key.
+const SESSION_KEY_SIZE: usize = 32;
+
+#[derive(Debug)]
+/// The directional session keys resulting from a key exchange.
+pub struct SessionKeys {
+	receiving: SecretKey,
+	transmitting: SecretKey,
+}
+
+impl SessionKeys {
+	/// The key used to decrypt data sent by the remote party.
+	pub fn get_receiving(&self) -> &SecretKey {
+		&self.receiving
+	}
+
+	/// The key used to encrypt data sent to the remote party.
+	pub fn get_transmitting(&self) -> &SecretKey {
+		&self.transmitting
+	}
+}
+
+#[must_use]
+/// Perform the key agreement and derive the client-to-server and
+/// server-to-client keys, in that order.
+fn derive_session_keys(
+	private_key: &PrivateKey,
+	remote_public_key: &PublicKey,
+	client_public_key: &PublicKey,
+	server_public_key: &PublicKey,
+) -> Result<(SecretKey, SecretKey), UnknownCryptoError> {
+	let shared_key = x448::key_agreement(private_key, remote_public_key)?;
+
+	let mut transcript = [0u8; PUBLIC_KEY_SIZE * 2];
+	transcript[..PUBLIC_KEY_SIZE].copy_from_slice(client_public_key.as_ref());
+	transcript[PUBLIC_KEY_SIZE..].copy_from_slice(server_public_key.as_ref());
+
+	let mut keys = [0u8; SESSION_KEY_SIZE * 2];
+	hkdf::derive_key(
+		&transcript,
+		shared_key.unprotected_as_bytes(),
+		None,
+		&mut keys,
+	)?;
+	let client_to_server = SecretKey::from_slice(&keys[..SESSION_KEY_SIZE])?;
+	let server_to_client = SecretKey::from_slice(&keys[SESSION_KEY_SIZE..])?;
+	keys.zeroize();
+
+	Ok((client_to_server, server_to_client))
+}
+
+#[must_use]
+/// Derive the session keys of a client, given the public key of the server.
+pub fn client_session_keys(
+	client_private_key: &PrivateKey,
+	server_public_key: &PublicKey,
+) -> Result<SessionKeys, UnknownCryptoError> {
+	let client_public_key = PublicKey::try_from(client_private_key)?;
+	let (client_to_server, server_to_client) = derive_session_keys(
+		client_private_key,
+		server_public_key,
+		&client_public_key,
+		server_public_key,
+	)?;
+
+	Ok(SessionKeys {
+		receiving: server_to_client,
+		transmitting: client_to_server,
+	})
+}
+
+#[must_use]
+/// Derive the session keys of a server, given the public key of the client.
+pub fn server_session_keys(
+	server_private_key: &PrivateKey,
+	client_public_key: &PublicKey,
+) -> Result<SessionKeys, UnknownCryptoError> {
+	let server_public_key = PublicKey::try_from(server_private_key)?;
+	let (client_to_server, server_to_client) = derive_session_keys(
+		server_private_key,
+		client_public_key,
+		client_public_key,
+		&server_public_key,
+	)?;
+
+	Ok(SessionKeys {
+		receiving: client_to_server,
+		transmitting: server_to_client,
+	})
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	mod test_session_keys {
+		use super::*;
+
+		#[test]
+		fn test_client_server_agree() {
+			let client_sk = PrivateKey::generate();
+			let server_sk = PrivateKey::generate();
+			let client_pk = PublicKey::try_from(&client_sk).unwrap();
+			let server_pk = PublicKey::try_from(&server_sk).unwrap();
+
+			let client = client_session_keys(&client_sk, &server_pk).unwrap();
+			let server = server_session_keys(&server_sk, &client_pk).unwrap();
+
+			assert_eq!(client.get_transmitting(), server.get_receiving());
+			assert_eq!(client.get_receiving(), server.get_transmitting());
+			assert_ne!(client.get_transmitting(), client.get_receiving());
+		}
+
+		#[test]
+		fn test_wrong_remote_key_differs() {
+			let client_sk = PrivateKey::generate();
+			let server_sk = PrivateKey::generate();
+			let server_pk = PublicKey::try_from(&server_sk).unwrap();
+			let other_pk = PublicKey::try_from(&PrivateKey::generate()).unwrap();
+
+			let client = client_session_keys(&client_sk, &server_pk).unwrap();
+			let server = server_session_keys(&server_sk, &other_pk).unwrap();
+
+			assert_ne!(client.get_transmitting(), server.get_receiving());
+			assert_ne!(client.get_receiving(), server.get_transmitting());
+		}
+
+		#[test]
+		fn test_roles_are_not_symmetric() {
+			// Both parties acting as clients must not end up with matching keys.
+			let a_sk = PrivateKey::generate();
+			let b_sk = PrivateKey::generate();
+			let a_pk = PublicKey::try_from(&a_sk).unwrap();
+			let b_pk = PublicKey::try_from(&b_sk).unwrap();
+
+			let a = client_session_keys(&a_sk, &b_pk).unwrap();
+			let b = client_session_keys(&b_sk, &a_pk).unwrap();
+
+			assert_ne!(a.get_transmitting(), b.get_receiving());
+		}
+
+		#[test]
+		fn test_low_order_public_key_err() {
+			let client_sk = PrivateKey::generate();
+			let zero_pk = PublicKey::from([0u8; PUBLIC_KEY_SIZE]);
+
+			assert!(client_session_keys(&client_sk, &zero_pk).is_err());
+			assert!(server_session_keys(&client_sk, &zero_pk).is_err());
+		}
+	}
+
+	// Proptests. Only exectued when NOT testing no_std.
+	#[cfg(feature = "safe_api")]
+	mod proptest {
+		use super::*;
+		use crate::aead;
+
+		quickcheck! {
+			/// Data encrypted by one party should always be decryptable by the other.
+			fn prop_session_keys_aead(input: Vec<u8>) -> bool {
+				if input.is_empty() {
+					return true;
+				}
+
+				let client_sk = PrivateKey::generate();
+				let server_sk = PrivateKey::generate();
+				let client_pk = PublicKey::try_from(&client_sk).unwrap();
+				let server_pk = PublicKey::try_from(&server_sk).unwrap();
+
+				let client = client_session_keys(&client_sk, &server_pk).unwrap();
+				let server = server_session_keys(&server_sk, &client_pk).unwrap();
+
+				let ct = aead::seal(client.get_transmitting(), &input).unwrap();
+				aead::open(server.get_receiving(), &ct).unwrap() == input
+			}
+		}
+	}
+}
@@ -0,0 +1,430 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Password-protected key files.
+//!
+//! # Use case:
+//! `orion::keyfile` can be used to store a secret key on disk, encrypted with
+//! a key derived from a password.
+//!
+//! An example of this could be a service that keeps its signing key in a file,
+//! which is unlocked with a passphrase at startup.
+//!
+//! # About:
+//! - The key is derived from the password using PBKDF2-HMAC-SHA512, with a
+//!   random 32-byte salt.
+//! - The secret is encrypted with XChaCha20Poly1305, with a random nonce.
+//! - The whole header is authenticated as additional data.
+//! - A key file is laid out as follows:
+//!
+//! | Field        | Size                 |
+//! |--------------|----------------------|
+//! | Magic        | 8 bytes: `ORIONKEY`  |
+//! | Version      | 1 byte: `1`          |
+//! | KDF          | 1 byte: `1` (PBKDF2) |
+//! | Iterations   | 4 bytes, big-endian  |
+//! | Salt         | 32 bytes             |
+//! | Nonce        | 24 bytes             |
+//! | Label length | 1 byte               |
+//! | Label        | 1 to 255 bytes       |
+//! | Ciphertext   | Length of the secret |
+//! | Tag          | 16 bytes             |
+//!
+//! # Parameters:
+//! - `password`: The password used to protect the key file.
+//! - `label`: Describes what kind of key is stored, such as
+//!   `"aead::SecretKey"`. It is stored in plaintext but authenticated.
+//! - `secret`: The secret key to store.
+//! - `iterations`: The number of iterations performed by PBKDF2.
+//! - `max_iterations`: The largest iteration count accepted from `keyfile`.
+//! - `expected_label`: The label the key file must have to be imported.
+//! - `keyfile`: A key file created with [`export()`].
+//! - `rng`: The random generator used to generate the salt and nonce in
+//!   [`export_with_rng()`]. [`export()`] uses [`OsRng`].
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `label` is empty or longer than 255 bytes.
+//! - `secret` is empty.
+//! - `iterations` is 0.
+//! - `keyfile` is malformed, or has an unsupported version or KDF.
+//! - The label of `keyfile` does not match `expected_label`.
+//! - The iteration count of `keyfile` is greater than `max_iterations`.
+//! - The password is wrong, or `keyfile` has been tampered with.
+//!
+//! # Panics:
+//! A panic will occur if:
+//! - Failure to generate random bytes securely.
+//!
+//! # Security:
+//! - The iteration count should be set as high as feasible. The recommended
+//!   minimum is 100000.
+//! - The label is not encrypted. It must not contain sensitive information.
+//! - The iteration count is read from `keyfile` before it is authenticated, so
+//!   a tampered key file could make [`import()`] run PBKDF2 for a very long
+//!   time. `max_iterations` should be set to the highest count used with
+//!   [`export()`].
+//!
+//! # Example:
+//! ```rust
+//! use orion::{aead, keyfile};
+//!
+//! let secret_key = aead::SecretKey::default();
+//! let password = keyfile::Password::from_slice(b"Secret password")?;
+//!
+//! let stored = keyfile::export(
+//! 	&password,
+//! 	"aead::SecretKey",
+//! 	secret_key.unprotected_as_bytes(),
+//! 	100000,
+//! )?;
+//!
+//! assert_eq!(keyfile::label(&stored)?, "aead::SecretKey");
+//! let imported = keyfile::import(&password, "aead::SecretKey", &stored, 100000)?;
+//! let secret_key = aead::SecretKey::from_slice(imported.unprotected_as_bytes())?;
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`import()`]: https://docs.rs/orion/latest/orion/keyfile/fn.import.html
+//! [`export()`]: https://docs.rs/orion/latest/orion/keyfile/fn.export.html
+//! [`export_with_rng()`]: https://docs.rs/orion/latest/orion/keyfile/fn.export_with_rng.html
+//! [`OsRng`]: https://docs.rs/orion/latest/orion/util/struct.OsRng.html
+
+pub use crate::hltypes::{Password, SecretKey};
+use crate::{
+	errors::UnknownCryptoError,
+	hazardous::{
+		aead::xchacha20poly1305,
+		kdf::pbkdf2,
+		mac::poly1305::POLY1305_OUTSIZE,
+		stream::{
+			chacha20,
+			xchacha20::{Nonce, XCHACHA_NONCESIZE},
+		},
+	},
+	util::{OsRng, SecureRandom},
+};
+use zeroize::Zeroize;
+
+/// The magic bytes at the start of a key file.
+const MAGIC: &[u8; 8] = b"ORIONKEY";
+/// The current key file format version.
+const VERSION: u8 = 1;
+/// The identifier for PBKDF2-HMAC-SHA512.
+const KDF_PBKDF2_SHA512: u8 = 1;
+/// The size of the salt.
+const SALT_SIZE: usize = 32;
+/// The size of the header before the label.
+const FIXED_HEADER_SIZE: usize = MAGIC.len() + 1 + 1 + 4 + SALT_SIZE + XCHACHA_NONCESIZE + 1;
+
+/// The parsed header of a key file.
+struct Header<'a> {
+	iterations: u32,
+	salt: &'a [u8],
+	nonce: &'a [u8],
+	label: &'a str,
+	/// The complete header, authenticated as additional data.
+	raw: &'a [u8],
+	ciphertext_with_tag: &'a [u8],
+}
+
+impl<'a> Header<'a> {
+	/// Parse a key file.
+	fn parse(keyfile: &'a [u8]) -> Result<Self, UnknownCryptoError> {
+		if keyfile.len() < FIXED_HEADER_SIZE
+			|| &keyfile[..MAGIC.len()] != MAGIC
+			|| keyfile[8] != VERSION
+			|| keyfile[9] != KDF_PBKDF2_SHA512
+		{
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+
+		let mut iterations = [0u8; 4];
+		iterations.copy_from_slice(&keyfile[10..14]);
+		let salt = &keyfile[14..14 + SALT_SIZE];
+		let nonce = &keyfile[14 + SALT_SIZE..14 + SALT_SIZE + XCHACHA_NONCESIZE];
+
+		let label_len = keyfile[FIXED_HEADER_SIZE - 1] as usize;
+		let header_len = FIXED_HEADER_SIZE + label_len;
+		// `+ 1` to avoid empty secrets
+		if label_len == 0 || keyfile.len() < header_len + POLY1305_OUTSIZE + 1 {
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+		let label = core::str::from_utf8(&keyfile[FIXED_HEADER_SIZE..header_len])
+			.map_err(|_| UnknownCryptoError::InvalidEncoding)?;
+
+		Ok(Self {
+			iterations: u32::from_be_bytes(iterations),
+			salt,
+			nonce,
+			label,
+			raw: &keyfile[..header_len],
+			ciphertext_with_tag: &keyfile[header_len..],
+		})
+	}
+}
+
+/// Derive the encryption key from the password.
+fn derive_key(
+	password: &Password,
+	salt: &[u8],
+	iterations: u32,
+) -> Result<chacha20::SecretKey, UnknownCryptoError> {
+	let mut dk = [0u8; 32];
+	pbkdf2::derive_key(
+		&pbkdf2::Password::from_slice(password.unprotected_as_bytes())?,
+		salt,
+		iterations as usize,
+		&mut dk,
+	)?;
+	let key = chacha20::SecretKey::from_slice(&dk);
+	dk.zeroize();
+
+	key
+}
+
+#[must_use]
+/// Encrypt `secret` into a key file protected by `password`.
+pub fn export(
+	password: &Password,
+	label: &str,
+	secret: &[u8],
+	iterations: u32,
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	export_with_rng(password, label, secret, iterations, &mut OsRng)
+}
+
+#[must_use]
+/// Encrypt `secret` into a key file protected by `password`, with the salt and
+/// nonce generated by `rng`.
+pub fn export_with_rng<R: SecureRandom + ?Sized>(
+	password: &Password,
+	label: &str,
+	secret: &[u8],
+	iterations: u32,
+	rng: &mut R,
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	if label.is_empty() || label.len() > 255 || secret.is_empty() {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+	if iterations == 0 {
+		return Err(UnknownCryptoError::ParameterOutOfRange);
+	}
+
+	let mut salt = [0u8; SALT_SIZE];
+	rng.fill_bytes(&mut salt)?;
+	let mut nonce = [0u8; XCHACHA_NONCESIZE];
+	rng.fill_bytes(&mut nonce)?;
+	let nonce = Nonce::from(nonce);
+
+	let header_len = FIXED_HEADER_SIZE + label.len();
+	let mut keyfile = Vec::with_capacity(header_len + secret.len() + POLY1305_OUTSIZE);
+	keyfile.extend_from_slice(MAGIC);
+	keyfile.push(VERSION);
+	keyfile.push(KDF_PBKDF2_SHA512);
+	keyfile.extend_from_slice(&iterations.to_be_bytes());
+	keyfile.extend_from_slice(&salt);
+	keyfile.extend_from_slice(nonce.as_ref());
+	keyfile.push(label.len() as u8);
+	keyfile.extend_from_slice(label.as_bytes());
+	keyfile.resize(header_len + secret.len() + POLY1305_OUTSIZE, 0u8);
+
+	let (header, dst_out) = keyfile.split_at_mut(header_len);
+	xchacha20poly1305::seal(
+		&derive_key(password, &salt, iterations)?,
+		&nonce,
+		secret,
+		Some(header),
+		dst_out,
+	)?;
+
+	Ok(keyfile)
+}
+
+#[must_use]
+/// Decrypt the secret key in `keyfile`, if its label is `expected_label` and
+/// its iteration count is at most `max_iterations`.
+pub fn import(
+	password: &Password,
+	expected_label: &str,
+	keyfile: &[u8],
+	max_iterations: u32,
+) -> Result<SecretKey, UnknownCryptoError> {
+	let header = Header::parse(keyfile)?;
+	if header.label != expected_label || header.iterations > max_iterations {
+		return Err(UnknownCryptoError::ParameterOutOfRange);
+	}
+
+	let mut secret = vec![0u8; header.ciphertext_with_tag.len() - POLY1305_OUTSIZE];
+	let opened = xchacha20poly1305::open(
+		&derive_key(password, header.salt, header.iterations)?,
+		&Nonce::from_slice(header.nonce)?,
+		header.ciphertext_with_tag,
+		Some(header.raw),
+		&mut secret,
+	)
+	.and_then(|_| SecretKey::from_slice(&secret));
+	secret.zeroize();
+
+	opened
+}
+
+#[must_use]
+/// Read the label of `keyfile`, without decrypting it. The label is not
+/// authenticated until the key file has been imported.
+pub fn label(keyfile: &[u8]) -> Result<&str, UnknownCryptoError> {
+	Ok(Header::parse(keyfile)?.label)
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	const LABEL: &str = "aead::SecretKey";
+
+	fn export_default(secret: &[u8]) -> Vec<u8> {
+		let password = Password::from_slice(b"password").unwrap();
+		export(&password, LABEL, secret, 10).unwrap()
+	}
+
+	mod test_export_import {
+		use super::*;
+
+		#[test]
+		fn test_export_with_rng_deterministic() {
+			use crate::util::HmacDrbg;
+
+			let password = Password::from_slice(b"password").unwrap();
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			let first = export_with_rng(&password, LABEL, &[0x42u8; 32], 10, &mut rng).unwrap();
+
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			let second = export_with_rng(&password, LABEL, &[0x42u8; 32], 10, &mut rng).unwrap();
+			assert_eq!(first, second);
+			assert!(import(&password, LABEL, &first, 10).unwrap() == &[0x42u8; 32][..]);
+		}
+
+		#[test]
+		fn test_roundtrip() {
+			let password = Password::from_slice(b"password").unwrap();
+			let keyfile = export_default(&[0x42u8; 32]);
+
+			assert_eq!(keyfile.len(), FIXED_HEADER_SIZE + LABEL.len() + 32 + 16);
+			assert_eq!(label(&keyfile).unwrap(), LABEL);
+			assert!(import(&password, LABEL, &keyfile, 10).unwrap() == &[0x42u8; 32][..]);
+		}
+
+		#[test]
+		fn test_max_iterations() {
+			let password = Password::from_slice(b"password").unwrap();
+			let keyfile = export_default(&[0x42u8; 32]);
+
+			assert!(import(&password, LABEL, &keyfile, 11).is_ok());
+			assert!(import(&password, LABEL, &keyfile, 10).is_ok());
+			assert!(import(&password, LABEL, &keyfile, 9).is_err());
+
+			// A tampered iteration count is rejected before PBKDF2 is run.
+			let mut modified = keyfile.clone();
+			modified[10..14].copy_from_slice(&u32::max_value().to_be_bytes());
+			assert_eq!(
+				import(&password, LABEL, &modified, 10).unwrap_err(),
+				UnknownCryptoError::ParameterOutOfRange
+			);
+		}
+
+		#[test]
+		fn test_randomized() {
+			assert_ne!(export_default(&[0x42u8; 32]), export_default(&[0x42u8; 32]));
+		}
+
+		#[test]
+		fn test_export_invalid_params_err() {
+			let password = Password::from_slice(b"password").unwrap();
+			let long_label = "a".repeat(256);
+
+			assert!(export(&password, "", &[1u8; 32], 10).is_err());
+			assert!(export(&password, &long_label, &[1u8; 32], 10).is_err());
+			assert!(export(&password, &long_label[..255], &[1u8; 32], 10).is_ok());
+			assert!(export(&password, LABEL, &[], 10).is_err());
+			assert!(export(&password, LABEL, &[1u8; 32], 0).is_err());
+		}
+
+		#[test]
+		fn test_wrong_password_err() {
+			let password = Password::from_slice(b"Password").unwrap();
+			let keyfile = export_default(&[0x42u8; 32]);
+
+			assert!(import(&password, LABEL, &keyfile, 10).is_err());
+		}
+
+		#[test]
+		fn test_wrong_label_err() {
+			let password = Password::from_slice(b"password").unwrap();
+			let keyfile = export_default(&[0x42u8; 32]);
+
+			assert!(import(&password, "hmac::SecretKey", &keyfile, 10).is_err());
+		}
+
+		#[test]
+		fn test_modified_keyfile_err() {
+			let password = Password::from_slice(b"password").unwrap();
+			let keyfile = export_default(&[0x42u8; 32]);
+
+			for idx in 0..keyfile.len() {
+				let mut modified = keyfile.clone();
+				modified[idx] ^= 1;
+				assert!(import(&password, LABEL, &modified, 10).is_err());
+			}
+		}
+
+		#[test]
+		fn test_truncated_keyfile_err() {
+			let password = Password::from_slice(b"password").unwrap();
+			let keyfile = export_default(&[0x42u8; 1]);
+
+			for len in 0..keyfile.len() {
+				assert!(import(&password, LABEL, &keyfile[..len], 10).is_err());
+				assert!(label(&keyfile[..len]).is_err());
+			}
+		}
+	}
+
+	// Proptests. Only exectued when NOT testing no_std.
+	#[cfg(feature = "safe_api")]
+	mod proptest {
+		use super::*;
+
+		quickcheck! {
+			/// Exporting and importing a secret should always return the secret.
+			fn prop_export_import(secret: Vec<u8>) -> bool {
+				if secret.is_empty() {
+					return true;
+				}
+
+				let password = Password::from_slice(b"password").unwrap();
+				let keyfile = export(&password, LABEL, &secret, 1).unwrap();
+
+				import(&password, LABEL, &keyfile, 1).unwrap() == &secret[..]
+			}
+		}
+	}
+}
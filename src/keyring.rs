@@ -0,0 +1,898 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Named keys, with rotation and encrypted storage.
+//!
+//! # Use case:
+//! `orion::keyring` can be used to manage the secret keys of an application
+//! by name, encrypt and authenticate data with a named key, and store all
+//! keys encrypted under a password or a master key.
+//!
+//! An example of this could be a service that encrypts data at rest and
+//! rotates its key regularly, while still decrypting data encrypted with
+//! older keys.
+//!
+//! # About:
+//! - Each key has an id, the Unix time it was added to the keyring, and
+//!   whether it is retired. Retired keys can still open and verify, but not
+//!   seal or become the primary key.
+//! - The first key added becomes the primary key. [`Keyring::rotate()`]
+//!   generates a new primary key and retires the previous one.
+//! - [`Keyring::seal()`] encrypts with the primary key, using
+//!   XChaCha20Poly1305, and includes the key id in the sealed message.
+//!   [`Keyring::open()`] looks the key up by that id. The key id is
+//!   authenticated as additional data.
+//! - [`key_id()`] reads the key id of a sealed message, so that data sealed
+//!   with a key other than the primary key can be sealed again.
+//! - [`Keyring::authenticate()`] uses HMAC-SHA512, as [`orion::auth`] does.
+//! - [`Keyring::export()`] stores the keyring with [`orion::keyfile`],
+//!   protected by a password. [`Keyring::export_with_key()`] stores it with
+//!   [`orion::aead`], protected by a master key.
+//! - A sealed message is laid out as follows:
+//!
+//! | Field         | Size                    |
+//! |---------------|-------------------------|
+//! | Key id length | 1 byte                  |
+//! | Key id        | 1 to 255 bytes          |
+//! | Nonce         | 24 bytes                |
+//! | Ciphertext    | Length of the plaintext |
+//! | Tag           | 16 bytes                |
+//!
+//! # Parameters:
+//! - `key_id`: The id of a key. It must be 1 to 255 bytes.
+//! - `secret_key`: The key to add to the keyring.
+//! - `plaintext`: The data to be encrypted.
+//! - `sealed`: A message sealed with [`Keyring::seal()`] or
+//!   [`Keyring::seal_with()`].
+//! - `data`: The data to be authenticated.
+//! - `expected`: The expected authentication tag.
+//! - `password`: The password protecting an exported keyring.
+//! - `iterations`: The number of iterations performed by PBKDF2 when
+//!   exporting.
+//! - `max_iterations`: The largest iteration count accepted when importing.
+//! - `master_key`: The key protecting a keyring exported with
+//!   [`Keyring::export_with_key()`].
+//! - `exported`: A keyring exported with [`Keyring::export()`] or
+//!   [`Keyring::export_with_key()`].
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `key_id` is empty or longer than 255 bytes.
+//! - A key with `key_id` already exists when adding a key, or does not exist
+//!   when using one.
+//! - The keyring already holds [`MAX_KEYS`] keys.
+//! - A retired key is used to seal, or made the primary key.
+//! - The primary key is retired or removed.
+//! - The keyring has no primary key when calling [`Keyring::seal()`].
+//! - A key used to seal or open is not 32 bytes.
+//! - `plaintext` is empty.
+//! - `sealed` is malformed or has been tampered with.
+//! - The calculated tag does not match `expected`.
+//! - `exported` is malformed, the password or master key is wrong, or it has
+//!   been tampered with.
+//! - The iteration count of `exported` is greater than `max_iterations`.
+//! - The system time is before the Unix epoch.
+//!
+//! # Panics:
+//! A panic will occur if:
+//! - Failure to generate random bytes securely.
+//!
+//! # Security:
+//! - Key ids are stored and sent in plaintext. They must not contain
+//!   sensitive information.
+//! - Retiring a key does not remove it. Data sealed with it can be opened
+//!   until it is removed with [`Keyring::remove()`].
+//! - The iteration count should be set as high as feasible. The recommended
+//!   minimum is 100000. `max_iterations` should be set to the highest count
+//!   used when exporting, as the count is read before `exported` is
+//!   authenticated.
+//!
+//! # Example:
+//! ```rust
+//! use orion::keyring::{self, Keyring, Password};
+//!
+//! let mut keys = Keyring::new();
+//! keys.generate("2019-01")?;
+//! let sealed = keys.seal(b"Secret message")?;
+//!
+//! // Rotate the key, and seal the data again with the new primary key.
+//! keys.rotate("2019-02")?;
+//! assert_eq!(keyring::key_id(&sealed)?, "2019-01");
+//! let sealed = keys.seal(&keys.open(&sealed)?)?;
+//! assert_eq!(keyring::key_id(&sealed)?, "2019-02");
+//!
+//! // Store the keyring, protected by a password.
+//! let password = Password::from_slice(b"Secret password")?;
+//! let exported = keys.export(&password, 100000)?;
+//! let keys = Keyring::import(&password, &exported, 100000)?;
+//! assert_eq!(keys.open(&sealed)?, b"Secret message");
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`Keyring::rotate()`]: https://docs.rs/orion/latest/orion/keyring/struct.Keyring.html#method.rotate
+//! [`Keyring::seal()`]: https://docs.rs/orion/latest/orion/keyring/struct.Keyring.html#method.seal
+//! [`Keyring::seal_with()`]: https://docs.rs/orion/latest/orion/keyring/struct.Keyring.html#method.seal_with
+//! [`Keyring::open()`]: https://docs.rs/orion/latest/orion/keyring/struct.Keyring.html#method.open
+//! [`Keyring::authenticate()`]: https://docs.rs/orion/latest/orion/keyring/struct.Keyring.html#method.authenticate
+//! [`Keyring::export()`]: https://docs.rs/orion/latest/orion/keyring/struct.Keyring.html#method.export
+//! [`Keyring::export_with_key()`]: https://docs.rs/orion/latest/orion/keyring/struct.Keyring.html#method.export_with_key
+//! [`Keyring::remove()`]: https://docs.rs/orion/latest/orion/keyring/struct.Keyring.html#method.remove
+//! [`key_id()`]: https://docs.rs/orion/latest/orion/keyring/fn.key_id.html
+//! [`MAX_KEYS`]: https://docs.rs/orion/latest/orion/keyring/constant.MAX_KEYS.html
+//! [`orion::auth`]: https://docs.rs/orion/latest/orion/auth/index.html
+//! [`orion::aead`]: https://docs.rs/orion/latest/orion/aead/index.html
+//! [`orion::keyfile`]: https://docs.rs/orion/latest/orion/keyfile/index.html
+
+pub use crate::hazardous::mac::hmac::Tag;
+pub use crate::hltypes::{Password, SecretKey};
+use crate::{
+	aead, auth,
+	errors::UnknownCryptoError,
+	hazardous::{
+		aead::xchacha20poly1305,
+		mac::poly1305::POLY1305_OUTSIZE,
+		stream::{
+			chacha20,
+			xchacha20::{Nonce, XCHACHA_NONCESIZE},
+		},
+	},
+	keyfile,
+	util::{OsRng, SecureRandom},
+};
+use zeroize::Zeroize;
+
+/// The largest number of keys a keyring can hold.
+pub const MAX_KEYS: usize = 1024;
+
+/// The label of exported keyrings in `keyfile`.
+const KEYFILE_LABEL: &str = "orion::keyring";
+/// The current version of the encoding of a keyring.
+const VERSION: u8 = 1;
+/// The flag of the primary key.
+const FLAG_PRIMARY: u8 = 0b01;
+/// The flag of a retired key.
+const FLAG_RETIRED: u8 = 0b10;
+
+#[derive(Debug)]
+/// A key in a [`Keyring`], with its id and rotation metadata.
+///
+/// [`Keyring`]: struct.Keyring.html
+pub struct KeyEntry {
+	id: String,
+	secret_key: SecretKey,
+	created: u64,
+	retired: bool,
+}
+
+impl KeyEntry {
+	/// The id of the key.
+	pub fn id(&self) -> &str {
+		&self.id
+	}
+
+	/// The key.
+	pub fn secret_key(&self) -> &SecretKey {
+		&self.secret_key
+	}
+
+	/// The Unix time, in seconds, at which the key was added.
+	pub fn created(&self) -> u64 {
+		self.created
+	}
+
+	/// Whether the key is retired, and can only open and verify.
+	pub fn is_retired(&self) -> bool {
+		self.retired
+	}
+}
+
+#[derive(Debug, Default)]
+/// A set of named keys, one of which is the primary key.
+pub struct Keyring {
+	entries: Vec<KeyEntry>,
+	primary: Option<usize>,
+}
+
+impl Keyring {
+	/// Create an empty keyring.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// The number of keys in the keyring.
+	pub fn len(&self) -> usize {
+		self.entries.len()
+	}
+
+	/// Whether the keyring holds no keys.
+	pub fn is_empty(&self) -> bool {
+		self.entries.is_empty()
+	}
+
+	/// The keys in the keyring, in the order they were added.
+	pub fn iter(&self) -> impl Iterator<Item = &KeyEntry> {
+		self.entries.iter()
+	}
+
+	/// The key with id `key_id`.
+	pub fn get(&self, key_id: &str) -> Option<&KeyEntry> {
+		self.entries.iter().find(|entry| entry.id == key_id)
+	}
+
+	/// The primary key, which seals.
+	pub fn primary(&self) -> Option<&KeyEntry> {
+		self.primary.map(|index| &self.entries[index])
+	}
+
+	fn position(&self, key_id: &str) -> Result<usize, UnknownCryptoError> {
+		self.entries
+			.iter()
+			.position(|entry| entry.id == key_id)
+			.ok_or(UnknownCryptoError::ParameterOutOfRange)
+	}
+
+	fn push(&mut self, entry: KeyEntry) -> Result<(), UnknownCryptoError> {
+		if entry.id.is_empty() || entry.id.len() > 255 {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+		if self.entries.len() == MAX_KEYS || self.get(&entry.id).is_some() {
+			return Err(UnknownCryptoError::ParameterOutOfRange);
+		}
+
+		self.entries.push(entry);
+		Ok(())
+	}
+
+	#[must_use]
+	/// Add `secret_key` with id `key_id`. The first key added becomes the
+	/// primary key.
+	pub fn insert(
+		&mut self,
+		key_id: &str,
+		secret_key: SecretKey,
+	) -> Result<(), UnknownCryptoError> {
+		self.push(KeyEntry {
+			id: key_id.to_string(),
+			secret_key,
+			created: now()?,
+			retired: false,
+		})?;
+		if self.primary.is_none() {
+			self.primary = Some(self.entries.len() - 1);
+		}
+
+		Ok(())
+	}
+
+	#[must_use]
+	/// Add a random 32 byte key with id `key_id`. The first key added becomes
+	/// the primary key.
+	pub fn generate(&mut self, key_id: &str) -> Result<(), UnknownCryptoError> {
+		self.insert(key_id, SecretKey::default())
+	}
+
+	#[must_use]
+	/// Make the key with id `key_id` the primary key.
+	pub fn set_primary(&mut self, key_id: &str) -> Result<(), UnknownCryptoError> {
+		let index = self.position(key_id)?;
+		if self.entries[index].retired {
+			return Err(UnknownCryptoError::ParameterOutOfRange);
+		}
+		self.primary = Some(index);
+
+		Ok(())
+	}
+
+	#[must_use]
+	/// Retire the key with id `key_id`, so that it can no longer seal.
+	pub fn retire(&mut self, key_id: &str) -> Result<(), UnknownCryptoError> {
+		let index = self.position(key_id)?;
+		if self.primary == Some(index) {
+			return Err(UnknownCryptoError::ParameterOutOfRange);
+		}
+		self.entries[index].retired = true;
+
+		Ok(())
+	}
+
+	#[must_use]
+	/// Generate a new primary key with id `key_id`, and retire the previous
+	/// primary key.
+	pub fn rotate(&mut self, key_id: &str) -> Result<(), UnknownCryptoError> {
+		let previous = self.primary;
+		self.generate(key_id)?;
+		self.primary = Some(self.entries.len() - 1);
+		if let Some(index) = previous {
+			self.entries[index].retired = true;
+		}
+
+		Ok(())
+	}
+
+	#[must_use]
+	/// Remove the key with id `key_id`. Data sealed with it can no longer be
+	/// opened.
+	pub fn remove(&mut self, key_id: &str) -> Result<(), UnknownCryptoError> {
+		let index = self.position(key_id)?;
+		match self.primary {
+			Some(primary) if primary == index => {
+				return Err(UnknownCryptoError::ParameterOutOfRange)
+			}
+			Some(primary) if primary > index => self.primary = Some(primary - 1),
+			_ => (),
+		}
+		self.entries.remove(index);
+
+		Ok(())
+	}
+
+	#[must_use]
+	/// Authenticated encryption using XChaCha20Poly1305, with the primary key.
+	pub fn seal(&self, plaintext: &[u8]) -> Result<Vec<u8>, UnknownCryptoError> {
+		match self.primary() {
+			Some(entry) => seal_with_entry(entry, plaintext, &mut OsRng),
+			None => Err(UnknownCryptoError::ParameterOutOfRange),
+		}
+	}
+
+	#[must_use]
+	/// Authenticated encryption using XChaCha20Poly1305, with the key with id
+	/// `key_id`.
+	pub fn seal_with(&self, key_id: &str, plaintext: &[u8]) -> Result<Vec<u8>, UnknownCryptoError> {
+		let entry = &self.entries[self.position(key_id)?];
+		if entry.retired {
+			return Err(UnknownCryptoError::ParameterOutOfRange);
+		}
+
+		seal_with_entry(entry, plaintext, &mut OsRng)
+	}
+
+	#[must_use]
+	/// Authenticated decryption using XChaCha20Poly1305, with the key named in
+	/// `sealed`.
+	pub fn open(&self, sealed: &[u8]) -> Result<Vec<u8>, UnknownCryptoError> {
+		let (header, nonce, ciphertext_with_tag) = parse_sealed(sealed)?;
+		let entry = &self.entries[self.position(key_id(sealed)?)?];
+
+		let mut dst_out = vec![0u8; ciphertext_with_tag.len() - POLY1305_OUTSIZE];
+		xchacha20poly1305::open(
+			&chacha20::SecretKey::from_slice(entry.secret_key.unprotected_as_bytes())?,
+			&Nonce::from_slice(nonce)?,
+			ciphertext_with_tag,
+			Some(header),
+			&mut dst_out,
+		)?;
+
+		Ok(dst_out)
+	}
+
+	#[must_use]
+	/// Authenticate `data` using HMAC-SHA512, with the key with id `key_id`.
+	pub fn authenticate(&self, key_id: &str, data: &[u8]) -> Result<Tag, UnknownCryptoError> {
+		let entry = &self.entries[self.position(key_id)?];
+		if entry.retired {
+			return Err(UnknownCryptoError::ParameterOutOfRange);
+		}
+
+		auth::authenticate(&entry.secret_key, data)
+	}
+
+	#[must_use]
+	/// Authenticate and verify `data` using HMAC-SHA512, with the key with id
+	/// `key_id`.
+	pub fn authenticate_verify(
+		&self,
+		expected: &Tag,
+		key_id: &str,
+		data: &[u8],
+	) -> Result<bool, UnknownCryptoError> {
+		let entry = &self.entries[self.position(key_id)?];
+		auth::authenticate_verify(expected, &entry.secret_key, data)
+	}
+
+	#[must_use]
+	/// Export the keyring, protected by `password`.
+	pub fn export(
+		&self,
+		password: &Password,
+		iterations: u32,
+	) -> Result<Vec<u8>, UnknownCryptoError> {
+		let mut encoded = self.encode();
+		let exported = keyfile::export(password, KEYFILE_LABEL, &encoded, iterations);
+		encoded.zeroize();
+
+		exported
+	}
+
+	#[must_use]
+	/// Import a keyring exported with `export()`, with an iteration count of at
+	/// most `max_iterations`.
+	pub fn import(
+		password: &Password,
+		exported: &[u8],
+		max_iterations: u32,
+	) -> Result<Self, UnknownCryptoError> {
+		let encoded = keyfile::import(password, KEYFILE_LABEL, exported, max_iterations)?;
+		Self::decode(encoded.unprotected_as_bytes())
+	}
+
+	#[must_use]
+	/// Export the keyring, protected by `master_key`.
+	pub fn export_with_key(&self, master_key: &SecretKey) -> Result<Vec<u8>, UnknownCryptoError> {
+		let mut encoded = self.encode();
+		let exported = aead::seal(master_key, &encoded);
+		encoded.zeroize();
+
+		exported
+	}
+
+	#[must_use]
+	/// Import a keyring exported with `export_with_key()`.
+	pub fn import_with_key(
+		master_key: &SecretKey,
+		exported: &[u8],
+	) -> Result<Self, UnknownCryptoError> {
+		let mut encoded = aead::open(master_key, exported)?;
+		let keyring = Self::decode(&encoded);
+		encoded.zeroize();
+
+		keyring
+	}
+
+	/// Encode the keyring as its version, the number of keys as a big-endian
+	/// `u16`, and for each key its flags, creation time as a big-endian `u64`,
+	/// id length, id, key length as a big-endian `u16` and key.
+	fn encode(&self) -> Vec<u8> {
+		let mut encoded = vec![VERSION];
+		encoded.extend_from_slice(&(self.entries.len() as u16).to_be_bytes());
+		for (index, entry) in self.entries.iter().enumerate() {
+			let mut flags = 0u8;
+			if self.primary == Some(index) {
+				flags |= FLAG_PRIMARY;
+			}
+			if entry.retired {
+				flags |= FLAG_RETIRED;
+			}
+			let key = entry.secret_key.unprotected_as_bytes();
+
+			encoded.push(flags);
+			encoded.extend_from_slice(&entry.created.to_be_bytes());
+			encoded.push(entry.id.len() as u8);
+			encoded.extend_from_slice(entry.id.as_bytes());
+			encoded.extend_from_slice(&(key.len() as u16).to_be_bytes());
+			encoded.extend_from_slice(key);
+		}
+
+		encoded
+	}
+
+	/// Decode a keyring encoded with `encode()`.
+	fn decode(src: &[u8]) -> Result<Self, UnknownCryptoError> {
+		let mut reader = Reader { src };
+		if reader.take(1)? != [VERSION] {
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+		let count = reader.u16()?;
+
+		let mut keyring = Self::new();
+		for _ in 0..count {
+			let flags = reader.take(1)?[0];
+			let mut created = [0u8; 8];
+			created.copy_from_slice(reader.take(8)?);
+			let id_len = reader.take(1)?[0] as usize;
+			let id = core::str::from_utf8(reader.take(id_len)?)
+				.map_err(|_| UnknownCryptoError::InvalidEncoding)?;
+			let key_len = reader.u16()? as usize;
+
+			let is_primary = flags & FLAG_PRIMARY != 0;
+			let retired = flags & FLAG_RETIRED != 0;
+			if flags & !(FLAG_PRIMARY | FLAG_RETIRED) != 0
+				|| (is_primary && (retired || keyring.primary.is_some()))
+			{
+				return Err(UnknownCryptoError::InvalidEncoding);
+			}
+			if is_primary {
+				keyring.primary = Some(keyring.entries.len());
+			}
+			keyring
+				.push(KeyEntry {
+					id: id.to_string(),
+					secret_key: SecretKey::from_slice(reader.take(key_len)?)
+						.map_err(|_| UnknownCryptoError::InvalidEncoding)?,
+					created: u64::from_be_bytes(created),
+					retired,
+				})
+				.map_err(|_| UnknownCryptoError::InvalidEncoding)?;
+		}
+		if !reader.src.is_empty() || (keyring.primary.is_none() && count != 0) {
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+
+		Ok(keyring)
+	}
+}
+
+/// A reader of an encoded keyring.
+struct Reader<'a> {
+	src: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+	fn take(&mut self, len: usize) -> Result<&'a [u8], UnknownCryptoError> {
+		if len > self.src.len() {
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+		let (taken, rest) = self.src.split_at(len);
+		self.src = rest;
+
+		Ok(taken)
+	}
+
+	fn u16(&mut self) -> Result<u16, UnknownCryptoError> {
+		let mut value = [0u8; 2];
+		value.copy_from_slice(self.take(2)?);
+
+		Ok(u16::from_be_bytes(value))
+	}
+}
+
+/// Seconds since the Unix epoch.
+fn now() -> Result<u64, UnknownCryptoError> {
+	std::time::SystemTime::now()
+		.duration_since(std::time::UNIX_EPOCH)
+		.map(|duration| duration.as_secs())
+		.map_err(|_| UnknownCryptoError::ParameterOutOfRange)
+}
+
+/// Seal `plaintext` with `entry`, with the nonce generated by `rng`.
+fn seal_with_entry<R: SecureRandom + ?Sized>(
+	entry: &KeyEntry,
+	plaintext: &[u8],
+	rng: &mut R,
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	if plaintext.is_empty() {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let header_len = 1 + entry.id.len();
+	let mut sealed = vec![0u8; header_len + XCHACHA_NONCESIZE + plaintext.len() + POLY1305_OUTSIZE];
+	sealed[0] = entry.id.len() as u8;
+	sealed[1..header_len].copy_from_slice(entry.id.as_bytes());
+	let (header, rest) = sealed.split_at_mut(header_len);
+	let (nonce, dst_out) = rest.split_at_mut(XCHACHA_NONCESIZE);
+	rng.fill_bytes(nonce)?;
+
+	xchacha20poly1305::seal(
+		&chacha20::SecretKey::from_slice(entry.secret_key.unprotected_as_bytes())?,
+		&Nonce::from_slice(nonce)?,
+		plaintext,
+		Some(header),
+		dst_out,
+	)?;
+
+	Ok(sealed)
+}
+
+/// Split `sealed` into its header, nonce and ciphertext with tag.
+fn parse_sealed(sealed: &[u8]) -> Result<(&[u8], &[u8], &[u8]), UnknownCryptoError> {
+	let header_len = match sealed.first() {
+		Some(&id_len) if id_len != 0 => 1 + id_len as usize,
+		_ => return Err(UnknownCryptoError::InvalidEncoding),
+	};
+	// `+ 1` to avoid empty ciphertexts
+	if sealed.len() < header_len + XCHACHA_NONCESIZE + POLY1305_OUTSIZE + 1 {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+	let (header, rest) = sealed.split_at(header_len);
+	let (nonce, ciphertext_with_tag) = rest.split_at(XCHACHA_NONCESIZE);
+
+	Ok((header, nonce, ciphertext_with_tag))
+}
+
+#[must_use]
+/// Read the id of the key that sealed `sealed`. The id is not authenticated
+/// until `sealed` has been opened.
+pub fn key_id(sealed: &[u8]) -> Result<&str, UnknownCryptoError> {
+	let (header, _, _) = parse_sealed(sealed)?;
+	core::str::from_utf8(&header[1..]).map_err(|_| UnknownCryptoError::InvalidEncoding)
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	fn keyring() -> Keyring {
+		let mut keys = Keyring::new();
+		keys.insert("first", SecretKey::from_slice(&[1u8; 32]).unwrap())
+			.unwrap();
+		keys.insert("second", SecretKey::from_slice(&[2u8; 32]).unwrap())
+			.unwrap();
+		keys
+	}
+
+	mod test_keys {
+		use super::*;
+
+		#[test]
+		fn test_first_is_primary() {
+			let keys = keyring();
+			assert_eq!(keys.len(), 2);
+			assert_eq!(keys.primary().unwrap().id(), "first");
+			assert!(*keys.get("second").unwrap().secret_key() == &[2u8; 32][..]);
+			assert!(keys.get("third").is_none());
+			assert_eq!(
+				keys.iter().map(|entry| entry.id()).collect::<Vec<_>>(),
+				["first", "second"]
+			);
+			assert!(Keyring::new().primary().is_none());
+			assert!(Keyring::new().is_empty());
+		}
+
+		#[test]
+		fn test_insert_err() {
+			let mut keys = keyring();
+			assert!(keys.generate("first").is_err());
+			assert!(keys.generate("").is_err());
+			assert!(keys.generate(&"a".repeat(256)).is_err());
+			assert!(keys.generate(&"a".repeat(255)).is_ok());
+		}
+
+		#[test]
+		fn test_max_keys() {
+			let mut keys = Keyring::new();
+			for index in 0..MAX_KEYS {
+				keys.generate(&index.to_string()).unwrap();
+			}
+			assert!(keys.generate("one too many").is_err());
+		}
+
+		#[test]
+		fn test_rotate() {
+			let mut keys = keyring();
+			keys.rotate("third").unwrap();
+
+			assert_eq!(keys.primary().unwrap().id(), "third");
+			assert!(keys.get("first").unwrap().is_retired());
+			assert!(!keys.get("second").unwrap().is_retired());
+			assert!(keys.rotate("third").is_err());
+			assert_eq!(keys.primary().unwrap().id(), "third");
+
+			let mut keys = Keyring::new();
+			keys.rotate("first").unwrap();
+			assert_eq!(keys.primary().unwrap().id(), "first");
+		}
+
+		#[test]
+		fn test_set_primary_retire() {
+			let mut keys = keyring();
+			assert!(keys.retire("first").is_err());
+			keys.retire("second").unwrap();
+			assert!(keys.set_primary("second").is_err());
+			assert!(keys.set_primary("third").is_err());
+
+			keys.generate("third").unwrap();
+			keys.set_primary("third").unwrap();
+			keys.retire("first").unwrap();
+			assert_eq!(keys.primary().unwrap().id(), "third");
+		}
+
+		#[test]
+		fn test_remove() {
+			let mut keys = keyring();
+			keys.generate("third").unwrap();
+			keys.set_primary("third").unwrap();
+
+			assert!(keys.remove("third").is_err());
+			assert!(keys.remove("fourth").is_err());
+			keys.remove("first").unwrap();
+			assert_eq!(keys.primary().unwrap().id(), "third");
+			assert!(keys.get("first").is_none());
+			assert_eq!(keys.len(), 2);
+		}
+	}
+
+	mod test_seal_open {
+		use super::*;
+
+		#[test]
+		fn test_roundtrip() {
+			let keys = keyring();
+			let sealed = keys.seal(b"Secret message").unwrap();
+			assert_eq!(key_id(&sealed).unwrap(), "first");
+			assert_eq!(sealed.len(), 1 + 5 + 24 + 14 + 16);
+			assert_eq!(keys.open(&sealed).unwrap(), b"Secret message");
+
+			let sealed = keys.seal_with("second", b"Secret message").unwrap();
+			assert_eq!(key_id(&sealed).unwrap(), "second");
+			assert_eq!(keys.open(&sealed).unwrap(), b"Secret message");
+		}
+
+		#[test]
+		fn test_retired_key_opens() {
+			let mut keys = keyring();
+			let sealed = keys.seal(b"Secret message").unwrap();
+			keys.rotate("third").unwrap();
+
+			assert!(keys.seal_with("first", b"Secret message").is_err());
+			assert_eq!(keys.open(&sealed).unwrap(), b"Secret message");
+			assert_eq!(
+				key_id(&keys.seal(b"Secret message").unwrap()).unwrap(),
+				"third"
+			);
+		}
+
+		#[test]
+		fn test_same_as_aead_with_header() {
+			use crate::util::HmacDrbg;
+
+			let keys = keyring();
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			let sealed = seal_with_entry(keys.primary().unwrap(), b"message", &mut rng).unwrap();
+
+			let mut expected = [0u8; 7 + POLY1305_OUTSIZE];
+			xchacha20poly1305::seal(
+				&chacha20::SecretKey::from_slice(&[1u8; 32]).unwrap(),
+				&Nonce::from_slice(&sealed[6..30]).unwrap(),
+				b"message",
+				Some(b"\x05first"),
+				&mut expected,
+			)
+			.unwrap();
+			assert_eq!(&sealed[30..], &expected[..]);
+		}
+
+		#[test]
+		fn test_seal_err() {
+			let keys = keyring();
+			assert!(keys.seal(b"").is_err());
+			assert!(keys.seal_with("third", b"message").is_err());
+			assert!(Keyring::new().seal(b"message").is_err());
+
+			let mut keys = Keyring::new();
+			keys.insert("short", SecretKey::from_slice(&[1u8; 16]).unwrap())
+				.unwrap();
+			assert!(keys.seal(b"message").is_err());
+		}
+
+		#[test]
+		fn test_open_err() {
+			let keys = keyring();
+			let sealed = keys.seal(b"Secret message").unwrap();
+
+			for index in 0..sealed.len() {
+				let mut modified = sealed.clone();
+				modified[index] ^= 1;
+				assert!(keys.open(&modified).is_err());
+			}
+			for len in 0..sealed.len() {
+				assert!(keys.open(&sealed[..len]).is_err());
+			}
+			// A key id that names another key.
+			let mut modified = sealed.clone();
+			modified[1..6].copy_from_slice(b"secon");
+			assert!(keys.open(&modified).is_err());
+			assert!(Keyring::new().open(&sealed).is_err());
+		}
+
+		#[test]
+		fn test_authenticate() {
+			let mut keys = keyring();
+			let tag = keys.authenticate("second", b"data").unwrap();
+
+			assert!(keys.authenticate_verify(&tag, "second", b"data").unwrap());
+			assert!(keys.authenticate_verify(&tag, "first", b"data").is_err());
+			assert!(keys.authenticate_verify(&tag, "third", b"data").is_err());
+			assert!(
+				tag == auth::authenticate(keys.get("second").unwrap().secret_key(), b"data")
+					.unwrap()
+			);
+
+			keys.retire("second").unwrap();
+			assert!(keys.authenticate("second", b"data").is_err());
+			assert!(keys.authenticate_verify(&tag, "second", b"data").unwrap());
+		}
+	}
+
+	mod test_export_import {
+		use super::*;
+
+		fn assert_same(keys: &Keyring, imported: &Keyring) {
+			assert_eq!(keys.len(), imported.len());
+			assert_eq!(
+				keys.primary().map(|entry| entry.id()),
+				imported.primary().map(|entry| entry.id())
+			);
+			for (entry, imported) in keys.iter().zip(imported.iter()) {
+				assert_eq!(entry.id(), imported.id());
+				assert!(entry.secret_key() == imported.secret_key());
+				assert_eq!(entry.created(), imported.created());
+				assert_eq!(entry.is_retired(), imported.is_retired());
+			}
+		}
+
+		#[test]
+		fn test_password_roundtrip() {
+			let mut keys = keyring();
+			keys.rotate("third").unwrap();
+			let password = Password::from_slice(b"password").unwrap();
+			let exported = keys.export(&password, 10).unwrap();
+
+			assert_same(&keys, &Keyring::import(&password, &exported, 10).unwrap());
+			let wrong = Password::from_slice(b"Password").unwrap();
+			assert!(Keyring::import(&wrong, &exported, 10).is_err());
+			assert!(Keyring::import(&password, &exported, 9).is_err());
+		}
+
+		#[test]
+		fn test_master_key_roundtrip() {
+			let keys = keyring();
+			let master_key = SecretKey::default();
+			let exported = keys.export_with_key(&master_key).unwrap();
+
+			assert_same(
+				&keys,
+				&Keyring::import_with_key(&master_key, &exported).unwrap(),
+			);
+			assert!(Keyring::import_with_key(&SecretKey::default(), &exported).is_err());
+			assert_same(
+				&Keyring::new(),
+				&Keyring::import_with_key(
+					&master_key,
+					&Keyring::new().export_with_key(&master_key).unwrap(),
+				)
+				.unwrap(),
+			);
+		}
+
+		#[test]
+		fn test_decode_err() {
+			let encoded = keyring().encode();
+			assert!(Keyring::decode(&encoded).is_ok());
+
+			for len in 0..encoded.len() {
+				assert!(Keyring::decode(&encoded[..len]).is_err());
+			}
+			let mut trailing = encoded.clone();
+			trailing.push(0);
+			assert!(Keyring::decode(&trailing).is_err());
+
+			// Unknown version, unknown flags, no primary key, two primary keys,
+			// a retired primary key and duplicate ids.
+			let second_flags = 3 + 1 + 8 + 1 + 5 + 2 + 32;
+			let mut modified = encoded.clone();
+			modified[0] = 2;
+			assert!(Keyring::decode(&modified).is_err());
+			for (index, flags) in
+				[(3, 0b100), (3, 0), (second_flags, FLAG_PRIMARY), (3, 0b11)].iter()
+			{
+				let mut modified = encoded.clone();
+				modified[*index] = *flags;
+				assert!(Keyring::decode(&modified).is_err());
+			}
+			let mut modified = encoded.clone();
+			modified[second_flags + 10..second_flags + 15].copy_from_slice(b"first");
+			modified[second_flags + 9] = 5;
+			modified.remove(second_flags + 15);
+			assert!(Keyring::decode(&modified).is_err());
+		}
+	}
+}
@@ -38,9 +38,124 @@
 //! ## Hashing
 //! [`orion::hash`] offers hashing using BLAKE2b.
 //!
+//! ## Blind indexes
+//! [`orion::blind_index`] offers blind indexes using BLAKE2b and HKDF, for
+//! equality lookups over encrypted database columns.
+//!
+//! ## Key exchange
+//! [`orion::kex`] offers authenticated key exchange using X448 and HKDF.
+//!
+//! ## Secure channels
+//! [`orion::channel`] offers an authenticated handshake using X448, HKDF and
+//! BLAKE2b, followed by framed, ordered transport encryption using
+//! XChaCha20Poly1305.
+//!
+//! ## One-time passwords
+//! [`orion::otp`] offers HOTP and TOTP using HMAC.
+//!
+//! ## Expiring tokens
+//! [`orion::token`] offers authenticated tokens that carry a payload and
+//! expire, using HMAC.
+//!
+//! ## Commitments
+//! [`orion::commitment`] offers commitments to values using BLAKE2b, which
+//! hide the value until it is revealed.
+//!
+//! ## File encryption
+//! [`orion::file`] offers chunked authenticated encryption of files and streams
+//! using XChaCha20Poly1305.
+//!
+//! ## Multi-recipient encryption
+//! [`orion::multi_recipient`] offers authenticated encryption of a message
+//! for several recipients, using secret keys or X448 public keys.
+//!
+//! ## Deterministic encryption
+//! [`orion::convergent`] offers convergent encryption using BLAKE2b and
+//! XChaCha20Poly1305, which gives equal ciphertexts for equal plaintexts so
+//! that encrypted storage can be deduplicated.
+//!
+//! ## Onion encryption
+//! [`orion::onion`] wraps a payload in layers of XChaCha20Poly1305, one for
+//! each hop of a route, and peels them one at a time.
+//!
+//! ## Signcryption
+//! [`orion::signcryption`] signs a message with ECDSA P-256 and encrypts it to
+//! a recipient's X448 public key, using XChaCha20Poly1305.
+//!
+//! ## Double Ratchet
+//! [`orion::ratchet`] offers end-to-end encrypted sessions using the Double
+//! Ratchet algorithm with X448, HKDF and XChaCha20Poly1305.
+//!
+//! ## Asynchronous I/O
+//! [`orion::async_io`] offers asynchronous adapters for [`orion::file`]'s
+//! encryption format and for hashing. It requires the `async` feature.
+//!
+//! ## Key files
+//! [`orion::keyfile`] offers password-protected storage of secret keys using
+//! PBKDF2 and XChaCha20Poly1305.
+//!
+//! ## Keyrings
+//! [`orion::keyring`] manages named keys with rotation, encrypts and
+//! authenticates with them by name, and stores them protected by a password
+//! or a master key.
+//!
+//! ## OpenSSH keys and signatures
+//! [`orion::ssh`] reads and writes ECDSA P-256 keys in the private and public
+//! key formats of OpenSSH, and creates and verifies SSH signatures.
+//!
+//! ## Public key fingerprints
+//! [`orion::fingerprint`] offers fingerprints of public keys using SHA256 or
+//! BLAKE2b, formatted as hex, Base58 or in the style of OpenSSH.
+//!
+//! ## JSON Web Keys and Signatures
+//! [`orion::jwk`] converts X448, ECDSA P-256 and symmetric keys to and from
+//! JSON Web Keys, and computes their thumbprints. [`orion::jose`] signs and
+//! verifies compact JSON Web Signatures, such as JSON Web Tokens, using
+//! `HS256`, `HS512` or `ES256`.
+//!
+//! ## libsodium compatibility
+//! With the `sodium_compat` feature, [`orion::sodium_compat`] offers functions
+//! named after those of libsodium, with the same output, for the primitives
+//! both libraries implement.
+//!
+//! ## Serialization
+//! With the `serde` feature, non-secret types implement `Serialize` and
+//! `Deserialize`. Secret types must be wrapped in
+//! [`orion::serde_secret::ExposeSecret`] to be serialized. `aead::seal_value()`
+//! and `aead::open_value()` encrypt any serializable value.
+//!
+//! ## Testing
+//! With the `testing` feature, [`orion::test_framework`] offers the test
+//! runners used by orion's own tests, so that crates wrapping orion's
+//! primitives can test their wrappers with them.
+//!
+//! With the `proptest` feature, keys, nonces, salts, tags and other input
+//! types implement `proptest::arbitrary::Arbitrary`, so that
+//! `proptest::prelude::any::<SecretKey>()` generates valid values of every
+//! length the type accepts, up to 1024 bytes for types that have no upper
+//! bound.
+//!
+//! ## Fuzzing
+//! With the `fuzz` feature, [`orion::fuzz`] offers structured entry points for
+//! fuzz targets, and keys, nonces, salts, tags and other input types implement
+//! `arbitrary::Arbitrary`.
+//!
 //! ### A note on `no_std`:
 //! When orion is used in a `no_std` context, access to nearly all functionality
-//! outside of [`orion::hazardous`], is not available.
+//! outside of [`orion::hazardous`], is not available. With the `alloc` feature,
+//! [`orion::aead`], [`orion::auth`] and [`orion::kdf`] are available as well.
+//! Functions that need the system's random generator are not, but those that
+//! take a random generator from the caller, such as `aead::seal_with_rng()`,
+//! are.
+//!
+//! ### A note on panics:
+//! No public function or method in orion panics because of its input. Invalid
+//! input, such as malformed encodings, wrong lengths or exceeded size limits,
+//! results in an [`UnknownCryptoError`] instead. The only panics that remain
+//! are failure to generate random bytes securely and failure to allocate
+//! memory, which are documented under `# Panics:` where they apply. This makes
+//! orion suitable for use behind an FFI boundary, where unwinding is undefined
+//! behavior.
 //!
 //!
 //! More information about orion is available in the [wiki](https://github.com/brycx/orion/wiki).
@@ -50,7 +165,31 @@
 //! [`orion::kdf`]: https://docs.rs/orion/latest/orion/kdf/index.html
 //! [`orion::auth`]: https://docs.rs/orion/latest/orion/auth/index.html
 //! [`orion::hash`]: https://docs.rs/orion/latest/orion/hash/index.html
+//! [`orion::blind_index`]: https://docs.rs/orion/latest/orion/blind_index/index.html
+//! [`orion::kex`]: https://docs.rs/orion/latest/orion/kex/index.html
+//! [`orion::channel`]: https://docs.rs/orion/latest/orion/channel/index.html
+//! [`orion::otp`]: https://docs.rs/orion/latest/orion/otp/index.html
+//! [`orion::token`]: https://docs.rs/orion/latest/orion/token/index.html
+//! [`orion::commitment`]: https://docs.rs/orion/latest/orion/commitment/index.html
+//! [`orion::keyfile`]: https://docs.rs/orion/latest/orion/keyfile/index.html
+//! [`orion::keyring`]: https://docs.rs/orion/latest/orion/keyring/index.html
+//! [`orion::file`]: https://docs.rs/orion/latest/orion/file/index.html
+//! [`orion::multi_recipient`]: https://docs.rs/orion/latest/orion/multi_recipient/index.html
+//! [`orion::signcryption`]: https://docs.rs/orion/latest/orion/signcryption/index.html
+//! [`orion::convergent`]: https://docs.rs/orion/latest/orion/convergent/index.html
+//! [`orion::onion`]: https://docs.rs/orion/latest/orion/onion/index.html
+//! [`orion::ratchet`]: https://docs.rs/orion/latest/orion/ratchet/index.html
+//! [`orion::ssh`]: https://docs.rs/orion/latest/orion/ssh/index.html
+//! [`orion::fingerprint`]: https://docs.rs/orion/latest/orion/fingerprint/index.html
+//! [`orion::jwk`]: https://docs.rs/orion/latest/orion/jwk/index.html
+//! [`orion::jose`]: https://docs.rs/orion/latest/orion/jose/index.html
+//! [`orion::sodium_compat`]: https://docs.rs/orion/latest/orion/sodium_compat/index.html
+//! [`orion::async_io`]: https://docs.rs/orion/latest/orion/async_io/index.html
+//! [`orion::serde_secret::ExposeSecret`]: https://docs.rs/orion/latest/orion/serde_secret/struct.ExposeSecret.html
+//! [`orion::test_framework`]: https://docs.rs/orion/latest/orion/test_framework/index.html
+//! [`orion::fuzz`]: https://docs.rs/orion/latest/orion/fuzz/index.html
 //! [`orion::hazardous`]: https://docs.rs/orion/latest/orion/hazardous/index.html
+//! [`UnknownCryptoError`]: https://docs.rs/orion/latest/orion/errors/enum.UnknownCryptoError.html
 
 #![cfg_attr(not(feature = "safe_api"), no_std)]
 #![forbid(unsafe_code)]
@@ -59,11 +198,22 @@
 #![deny(warnings)]
 #![doc(html_root_url = "https://docs.rs/orion/0.14.4")]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "async")]
+extern crate futures_io;
 #[cfg(feature = "safe_api")]
 extern crate getrandom;
+#[cfg(feature = "serde")]
+extern crate serde;
 extern crate subtle;
 extern crate zeroize;
 
+#[cfg(feature = "fuzz")]
+extern crate arbitrary;
+#[cfg(feature = "proptest")]
+extern crate proptest;
+
 #[cfg(test)]
 #[cfg(feature = "safe_api")]
 #[macro_use]
@@ -72,9 +222,24 @@ extern crate quickcheck;
 #[macro_use]
 mod typedefs;
 
+/// The exported `secret_newtype!` macro and `Debug` formatting of secret types.
+mod newtype;
+
+#[doc(hidden)]
+/// Re-exports used by exported macros. Not part of the public API.
+pub mod __private {
+	pub extern crate subtle;
+	pub extern crate zeroize;
+
+	pub use crate::newtype::fmt_secret_debug;
+}
+
 /// Endianness conversion functions.
 mod endianness;
 
+/// CPU feature detection and selection of accelerated implementations.
+mod cpu;
+
 /// Utilities such as constant-time comparison.
 pub mod util;
 
@@ -87,17 +252,94 @@ pub mod hazardous;
 #[cfg(feature = "safe_api")]
 pub mod hash;
 
-#[cfg(feature = "safe_api")]
+#[cfg(feature = "alloc")]
 pub mod aead;
 
-#[cfg(feature = "safe_api")]
+#[cfg(feature = "alloc")]
 pub mod auth;
 
 #[cfg(feature = "safe_api")]
 pub mod pwhash;
 
-#[cfg(feature = "safe_api")]
+#[cfg(feature = "alloc")]
 pub mod kdf;
 
 #[cfg(feature = "safe_api")]
+pub mod blind_index;
+
+#[cfg(feature = "safe_api")]
+pub mod kex;
+
+#[cfg(feature = "safe_api")]
+pub mod channel;
+
+#[cfg(feature = "safe_api")]
+pub mod otp;
+
+#[cfg(feature = "safe_api")]
+pub mod token;
+
+#[cfg(feature = "safe_api")]
+pub mod commitment;
+
+#[cfg(feature = "safe_api")]
+pub mod keyfile;
+
+#[cfg(feature = "safe_api")]
+pub mod keyring;
+
+#[cfg(feature = "safe_api")]
+pub mod file;
+
+#[cfg(feature = "safe_api")]
+pub mod multi_recipient;
+
+#[cfg(feature = "safe_api")]
+pub mod signcryption;
+
+#[cfg(feature = "safe_api")]
+pub mod convergent;
+
+#[cfg(feature = "safe_api")]
+pub mod onion;
+
+#[cfg(feature = "safe_api")]
+pub mod ratchet;
+
+#[cfg(feature = "safe_api")]
+pub mod ssh;
+
+#[cfg(feature = "safe_api")]
+pub mod fingerprint;
+
+#[cfg(feature = "safe_api")]
+pub mod jwk;
+
+#[cfg(feature = "safe_api")]
+pub mod jose;
+
+#[cfg(feature = "safe_api")]
+mod json;
+
+#[cfg(all(feature = "serde", feature = "alloc"))]
+mod canonical;
+
+#[cfg(feature = "sodium_compat")]
+pub mod sodium_compat;
+
+#[cfg(feature = "async")]
+pub mod async_io;
+
+#[cfg(feature = "serde")]
+pub mod serde_secret;
+
+#[cfg(feature = "alloc")]
 mod hltypes;
+
+#[cfg(any(test, feature = "fuzz"))]
+#[cfg(feature = "safe_api")]
+pub mod fuzz;
+
+#[cfg(any(test, feature = "testing"))]
+#[cfg(feature = "safe_api")]
+pub mod test_framework;
@@ -0,0 +1,525 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Encryption to multiple recipients.
+//!
+//! # Use case:
+//! `orion::multi_recipient` can be used to encrypt data once for several
+//! recipients, each of which can decrypt it with their own key.
+//!
+//! An example of this could be a message sent to a group, or a file shared
+//! with several users.
+//!
+//! # About:
+//! - The data is encrypted with XChaCha20Poly1305 under a random content key.
+//!   The content key is only used once, so the nonce is all zeroes.
+//! - The content key is wrapped for each recipient with [`orion::aead`]:
+//!   - For a [`Recipient::SecretKey`], under a key derived from the secret
+//!     key with HKDF-HMAC-SHA512.
+//!   - For a [`Recipient::PublicKey`], under a key derived with
+//!     HKDF-HMAC-SHA512 from an X448 key agreement between a new ephemeral
+//!     private key and the recipient's public key. Both public keys are used
+//!     as salt.
+//! - The header, which holds the wrapped keys, is authenticated as additional
+//!   data of the payload. Changing, adding or removing a wrapped key makes
+//!   decryption fail for every recipient.
+//! - A sealed message is laid out as follows:
+//!
+//! | Field            | Size                             |
+//! |------------------|----------------------------------|
+//! | Recipient count  | 2 bytes, big-endian              |
+//! | Wrapped keys     | 75 or 131 bytes per recipient    |
+//! | Ciphertext       | Length of the plaintext          |
+//! | Tag              | 16 bytes                         |
+//!
+//! - A wrapped key is a byte with its kind, `1` for a secret key and `2` for
+//!   an X448 public key, the ephemeral public key for the latter, and the
+//!   sealed content key.
+//!
+//! # Parameters:
+//! - `recipients`: The recipients that can open the sealed message.
+//! - `plaintext`: The data to be encrypted.
+//! - `identity`: The key of the recipient opening the sealed message.
+//! - `sealed`: A message sealed with [`seal()`].
+//! - `rng`: The random generator used to generate the content key, ephemeral
+//!   private keys and nonces in [`seal_with_rng()`]. [`seal()`] uses
+//!   [`OsRng`].
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `recipients` is empty or has more than [`MAX_RECIPIENTS`] recipients.
+//! - `plaintext` is empty.
+//! - A recipient's public key is a low-order point.
+//! - `sealed` is malformed.
+//! - `identity` is not one of the recipients of `sealed`.
+//! - `sealed` has been tampered with.
+//!
+//! # Panics:
+//! A panic will occur if:
+//! - Failure to generate random bytes securely.
+//!
+//! # Security:
+//! - The recipients are not hidden from each other, and the number of
+//!   recipients and their kinds are visible to anyone. Public keys are not
+//!   included.
+//! - Every recipient knows the content key, so any recipient can make a
+//!   different message that the other recipients will accept as coming from
+//!   the sender. This does not authenticate the sender.
+//! - A recipient learns which wrapped key is theirs by the time [`open()`]
+//!   takes.
+//!
+//! # Example:
+//! ```rust
+//! use orion::multi_recipient::{self, Identity, PrivateKey, PublicKey, Recipient, SecretKey};
+//! use core::convert::TryFrom;
+//!
+//! let shared_key = SecretKey::default();
+//! let private_key = PrivateKey::generate();
+//! let public_key = PublicKey::try_from(&private_key)?;
+//!
+//! let sealed = multi_recipient::seal(
+//! 	&[Recipient::SecretKey(&shared_key), Recipient::PublicKey(&public_key)],
+//! 	b"Secret message",
+//! )?;
+//!
+//! assert_eq!(
+//! 	multi_recipient::open(&Identity::SecretKey(&shared_key), &sealed)?,
+//! 	b"Secret message"
+//! );
+//! assert_eq!(
+//! 	multi_recipient::open(&Identity::PrivateKey(&private_key), &sealed)?,
+//! 	b"Secret message"
+//! );
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`orion::aead`]: https://docs.rs/orion/latest/orion/aead/index.html
+//! [`Recipient::SecretKey`]: https://docs.rs/orion/latest/orion/multi_recipient/enum.Recipient.html#variant.SecretKey
+//! [`Recipient::PublicKey`]: https://docs.rs/orion/latest/orion/multi_recipient/enum.Recipient.html#variant.PublicKey
+//! [`seal()`]: https://docs.rs/orion/latest/orion/multi_recipient/fn.seal.html
+//! [`seal_with_rng()`]: https://docs.rs/orion/latest/orion/multi_recipient/fn.seal_with_rng.html
+//! [`open()`]: https://docs.rs/orion/latest/orion/multi_recipient/fn.open.html
+//! [`MAX_RECIPIENTS`]: https://docs.rs/orion/latest/orion/multi_recipient/constant.MAX_RECIPIENTS.html
+//! [`OsRng`]: https://docs.rs/orion/latest/orion/util/struct.OsRng.html
+
+pub use crate::hazardous::ecc::x448::{PrivateKey, PublicKey};
+pub use crate::hltypes::SecretKey;
+use crate::{
+	aead,
+	errors::UnknownCryptoError,
+	hazardous::{
+		aead::xchacha20poly1305,
+		ecc::x448::{self, PRIVATE_KEY_SIZE, PUBLIC_KEY_SIZE},
+		kdf::hkdf,
+		mac::poly1305::POLY1305_OUTSIZE,
+		stream::{
+			chacha20,
+			xchacha20::{Nonce, XCHACHA_NONCESIZE},
+		},
+	},
+	util::{OsRng, SecureRandom},
+};
+use core::convert::TryFrom;
+use zeroize::Zeroize;
+
+/// The largest number of recipients of a sealed message.
+pub const MAX_RECIPIENTS: usize = 1024;
+
+/// The size of the content key.
+const CONTENT_KEY_SIZE: usize = 32;
+/// The size of a content key sealed with `aead`.
+const WRAPPED_KEY_SIZE: usize = CONTENT_KEY_SIZE + aead::SEAL_OVERHEAD;
+/// The kind of a key wrapped for a secret key.
+const KIND_SECRET_KEY: u8 = 1;
+/// The kind of a key wrapped for an X448 public key.
+const KIND_PUBLIC_KEY: u8 = 2;
+/// The HKDF salt used to derive wrapping keys from secret keys.
+const SECRET_KEY_CONTEXT: &[u8] = b"orion multi-recipient secret key";
+/// The HKDF info used to derive wrapping keys from X448 shared keys.
+const PUBLIC_KEY_CONTEXT: &[u8] = b"orion multi-recipient x448";
+
+#[derive(Debug, Clone, Copy)]
+/// A recipient of a sealed message.
+pub enum Recipient<'a> {
+	/// A recipient that knows a secret key.
+	SecretKey(&'a SecretKey),
+	/// A recipient with an X448 key pair.
+	PublicKey(&'a PublicKey),
+}
+
+#[derive(Debug, Clone, Copy)]
+/// The key a recipient opens a sealed message with.
+pub enum Identity<'a> {
+	/// The secret key of a [`Recipient::SecretKey`].
+	SecretKey(&'a SecretKey),
+	/// The private key of a [`Recipient::PublicKey`].
+	PrivateKey(&'a PrivateKey),
+}
+
+/// Derive the key that wraps content keys for `secret_key`.
+fn secret_key_wrapping_key(secret_key: &SecretKey) -> Result<SecretKey, UnknownCryptoError> {
+	let mut key = [0u8; CONTENT_KEY_SIZE];
+	hkdf::derive_key(
+		SECRET_KEY_CONTEXT,
+		secret_key.unprotected_as_bytes(),
+		None,
+		&mut key,
+	)?;
+	let wrapping_key = SecretKey::from_slice(&key);
+	key.zeroize();
+
+	wrapping_key
+}
+
+/// Derive the key that wraps content keys for `recipient_public_key`, from
+/// the key agreement of `private_key` and `public_key`.
+fn public_key_wrapping_key(
+	private_key: &PrivateKey,
+	public_key: &PublicKey,
+	ephemeral_public_key: &PublicKey,
+	recipient_public_key: &PublicKey,
+) -> Result<SecretKey, UnknownCryptoError> {
+	let shared_key = x448::key_agreement(private_key, public_key)?;
+
+	let mut salt = [0u8; PUBLIC_KEY_SIZE * 2];
+	salt[..PUBLIC_KEY_SIZE].copy_from_slice(ephemeral_public_key.as_ref());
+	salt[PUBLIC_KEY_SIZE..].copy_from_slice(recipient_public_key.as_ref());
+
+	let mut key = [0u8; CONTENT_KEY_SIZE];
+	hkdf::derive_key(
+		&salt,
+		shared_key.unprotected_as_bytes(),
+		Some(PUBLIC_KEY_CONTEXT),
+		&mut key,
+	)?;
+	let wrapping_key = SecretKey::from_slice(&key);
+	key.zeroize();
+
+	wrapping_key
+}
+
+/// Append the header with `content_key` wrapped for each of `recipients` to
+/// `dst`.
+fn write_header<R: SecureRandom + ?Sized>(
+	recipients: &[Recipient],
+	content_key: &[u8],
+	rng: &mut R,
+	dst: &mut Vec<u8>,
+) -> Result<(), UnknownCryptoError> {
+	dst.extend_from_slice(&(recipients.len() as u16).to_be_bytes());
+	for recipient in recipients.iter() {
+		match recipient {
+			Recipient::SecretKey(secret_key) => {
+				let wrapping_key = secret_key_wrapping_key(secret_key)?;
+				dst.push(KIND_SECRET_KEY);
+				dst.extend_from_slice(&aead::seal_with_rng(&wrapping_key, content_key, rng)?);
+			}
+			Recipient::PublicKey(public_key) => {
+				let mut ephemeral = [0u8; PRIVATE_KEY_SIZE];
+				rng.fill_bytes(&mut ephemeral)?;
+				let ephemeral_private_key = PrivateKey::from(ephemeral);
+				ephemeral.zeroize();
+				let ephemeral_public_key = PublicKey::try_from(&ephemeral_private_key)?;
+
+				let wrapping_key = public_key_wrapping_key(
+					&ephemeral_private_key,
+					public_key,
+					&ephemeral_public_key,
+					public_key,
+				)?;
+				dst.push(KIND_PUBLIC_KEY);
+				dst.extend_from_slice(ephemeral_public_key.as_ref());
+				dst.extend_from_slice(&aead::seal_with_rng(&wrapping_key, content_key, rng)?);
+			}
+		}
+	}
+
+	Ok(())
+}
+
+/// Split `sealed` into its header, the wrapped keys in it, and the payload.
+fn parse(sealed: &[u8]) -> Result<(&[u8], Vec<(u8, &[u8])>, &[u8]), UnknownCryptoError> {
+	if sealed.len() < 2 {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+	let count = usize::from(u16::from_be_bytes([sealed[0], sealed[1]]));
+	if count == 0 || count > MAX_RECIPIENTS {
+		return Err(UnknownCryptoError::InvalidEncoding);
+	}
+
+	let mut wrapped_keys = Vec::with_capacity(count);
+	let mut pos = 2;
+	for _ in 0..count {
+		let kind = *sealed.get(pos).ok_or(UnknownCryptoError::InvalidLength)?;
+		let length = match kind {
+			KIND_SECRET_KEY => WRAPPED_KEY_SIZE,
+			KIND_PUBLIC_KEY => PUBLIC_KEY_SIZE + WRAPPED_KEY_SIZE,
+			_ => return Err(UnknownCryptoError::InvalidEncoding),
+		};
+		let wrapped_key = sealed
+			.get(pos + 1..pos + 1 + length)
+			.ok_or(UnknownCryptoError::InvalidLength)?;
+		wrapped_keys.push((kind, wrapped_key));
+		pos += 1 + length;
+	}
+
+	let (header, payload) = sealed.split_at(pos);
+	// `+ 1` to avoid empty ciphertexts
+	if payload.len() < POLY1305_OUTSIZE + 1 {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	Ok((header, wrapped_keys, payload))
+}
+
+/// Unwrap the content key in `wrapped_key`, of `kind`, with `identity`.
+fn unwrap_content_key(
+	identity: &Identity,
+	kind: u8,
+	wrapped_key: &[u8],
+) -> Result<chacha20::SecretKey, UnknownCryptoError> {
+	let mut content_key = match (identity, kind) {
+		(Identity::SecretKey(secret_key), KIND_SECRET_KEY) => {
+			aead::open(&secret_key_wrapping_key(secret_key)?, wrapped_key)?
+		}
+		(Identity::PrivateKey(private_key), KIND_PUBLIC_KEY) => {
+			let (ephemeral_public_key, wrapped_key) = wrapped_key.split_at(PUBLIC_KEY_SIZE);
+			let ephemeral_public_key = PublicKey::from_slice(ephemeral_public_key)?;
+			let wrapping_key = public_key_wrapping_key(
+				private_key,
+				&ephemeral_public_key,
+				&ephemeral_public_key,
+				&PublicKey::try_from(*private_key)?,
+			)?;
+			aead::open(&wrapping_key, wrapped_key)?
+		}
+		_ => return Err(UnknownCryptoError::InvalidKey),
+	};
+	let key = chacha20::SecretKey::from_slice(&content_key);
+	content_key.zeroize();
+
+	key
+}
+
+#[must_use]
+/// Authenticated encryption of `plaintext` for each of `recipients`.
+pub fn seal(recipients: &[Recipient], plaintext: &[u8]) -> Result<Vec<u8>, UnknownCryptoError> {
+	seal_with_rng(recipients, plaintext, &mut OsRng)
+}
+
+#[must_use]
+/// Authenticated encryption of `plaintext` for each of `recipients`, with
+/// the random values generated by `rng`.
+pub fn seal_with_rng<R: SecureRandom + ?Sized>(
+	recipients: &[Recipient],
+	plaintext: &[u8],
+	rng: &mut R,
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	if recipients.is_empty() || recipients.len() > MAX_RECIPIENTS || plaintext.is_empty() {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let mut content_key = [0u8; CONTENT_KEY_SIZE];
+	rng.fill_bytes(&mut content_key)?;
+	let mut sealed = Vec::new();
+	let header = write_header(recipients, &content_key, rng, &mut sealed);
+	let secret_key = chacha20::SecretKey::from(content_key);
+	content_key.zeroize();
+	header?;
+
+	let header_length = sealed.len();
+	sealed.resize(header_length + plaintext.len() + POLY1305_OUTSIZE, 0u8);
+	let (header, payload) = sealed.split_at_mut(header_length);
+	xchacha20poly1305::seal(
+		&secret_key,
+		&Nonce::from([0u8; XCHACHA_NONCESIZE]),
+		plaintext,
+		Some(header),
+		payload,
+	)?;
+
+	Ok(sealed)
+}
+
+#[must_use]
+/// Authenticated decryption of `sealed` by the recipient with `identity`.
+pub fn open(identity: &Identity, sealed: &[u8]) -> Result<Vec<u8>, UnknownCryptoError> {
+	let (header, wrapped_keys, payload) = parse(sealed)?;
+	let secret_key = wrapped_keys
+		.iter()
+		.find_map(|(kind, wrapped_key)| unwrap_content_key(identity, *kind, wrapped_key).ok())
+		.ok_or(UnknownCryptoError::InvalidKey)?;
+
+	let mut plaintext = vec![0u8; payload.len() - POLY1305_OUTSIZE];
+	xchacha20poly1305::open(
+		&secret_key,
+		&Nonce::from([0u8; XCHACHA_NONCESIZE]),
+		payload,
+		Some(header),
+		&mut plaintext,
+	)?;
+
+	Ok(plaintext)
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	struct Keys {
+		secret_keys: Vec<SecretKey>,
+		private_keys: Vec<PrivateKey>,
+		public_keys: Vec<PublicKey>,
+	}
+
+	impl Keys {
+		fn new() -> Self {
+			let private_keys: Vec<PrivateKey> = (0..2).map(|_| PrivateKey::generate()).collect();
+			Self {
+				secret_keys: (0..2).map(|_| SecretKey::default()).collect(),
+				public_keys: private_keys
+					.iter()
+					.map(|key| PublicKey::try_from(key).unwrap())
+					.collect(),
+				private_keys,
+			}
+		}
+
+		fn recipients(&self) -> Vec<Recipient<'_>> {
+			vec![
+				Recipient::SecretKey(&self.secret_keys[0]),
+				Recipient::PublicKey(&self.public_keys[0]),
+				Recipient::SecretKey(&self.secret_keys[1]),
+				Recipient::PublicKey(&self.public_keys[1]),
+			]
+		}
+
+		fn identities(&self) -> Vec<Identity<'_>> {
+			vec![
+				Identity::SecretKey(&self.secret_keys[0]),
+				Identity::PrivateKey(&self.private_keys[0]),
+				Identity::SecretKey(&self.secret_keys[1]),
+				Identity::PrivateKey(&self.private_keys[1]),
+			]
+		}
+	}
+
+	mod test_seal_open {
+		use super::*;
+
+		#[test]
+		fn test_all_recipients() {
+			let keys = Keys::new();
+			let sealed = seal(&keys.recipients(), b"Secret message").unwrap();
+			assert_eq!(
+				sealed.len(),
+				2 + 2 * (1 + WRAPPED_KEY_SIZE)
+					+ 2 * (1 + PUBLIC_KEY_SIZE + WRAPPED_KEY_SIZE)
+					+ 14 + POLY1305_OUTSIZE
+			);
+
+			for identity in keys.identities().iter() {
+				assert_eq!(open(identity, &sealed).unwrap(), b"Secret message");
+			}
+		}
+
+		#[test]
+		fn test_not_a_recipient_err() {
+			let keys = Keys::new();
+			let sealed = seal(&keys.recipients()[..2], b"Secret message").unwrap();
+
+			let identities = keys.identities();
+			assert!(open(&identities[0], &sealed).is_ok());
+			assert!(open(&identities[1], &sealed).is_ok());
+			assert!(open(&identities[2], &sealed).is_err());
+			assert!(open(&identities[3], &sealed).is_err());
+		}
+
+		#[test]
+		fn test_seal_with_rng_deterministic() {
+			use crate::util::HmacDrbg;
+
+			let keys = Keys::new();
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			let first = seal_with_rng(&keys.recipients(), b"Secret message", &mut rng).unwrap();
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			let second = seal_with_rng(&keys.recipients(), b"Secret message", &mut rng).unwrap();
+			assert_eq!(first, second);
+			assert_ne!(first, seal(&keys.recipients(), b"Secret message").unwrap());
+		}
+
+		#[test]
+		fn test_invalid_parameters_err() {
+			let keys = Keys::new();
+			assert!(seal(&[], b"Secret message").is_err());
+			assert!(seal(&keys.recipients(), b"").is_err());
+
+			let recipients = vec![Recipient::SecretKey(&keys.secret_keys[0]); MAX_RECIPIENTS + 1];
+			assert!(seal(&recipients, b"Secret message").is_err());
+			assert!(seal(&recipients[..MAX_RECIPIENTS], b"Secret message").is_ok());
+
+			let low_order = PublicKey::from([0u8; PUBLIC_KEY_SIZE]);
+			assert!(seal(&[Recipient::PublicKey(&low_order)], b"Secret message").is_err());
+		}
+	}
+
+	mod test_tampering {
+		use super::*;
+
+		#[test]
+		fn test_modified_err() {
+			let keys = Keys::new();
+			let recipients = keys.recipients();
+			let sealed = seal(&recipients[..2], b"Secret message").unwrap();
+
+			for idx in 0..sealed.len() {
+				let mut modified = sealed.clone();
+				modified[idx] ^= 1;
+				for identity in keys.identities()[..2].iter() {
+					assert!(open(identity, &modified).is_err());
+				}
+			}
+		}
+
+		#[test]
+		fn test_truncated_err() {
+			let keys = Keys::new();
+			let sealed = seal(&keys.recipients(), b"Secret message").unwrap();
+
+			for len in 0..sealed.len() {
+				assert!(open(&keys.identities()[3], &sealed[..len]).is_err());
+			}
+		}
+
+		#[test]
+		fn test_removed_recipient_err() {
+			let keys = Keys::new();
+			let recipients = keys.recipients();
+			let sealed = seal(&recipients[..2], b"Secret message").unwrap();
+
+			// Remove the first wrapped key, which is for a secret key.
+			let mut removed = vec![0, 1];
+			removed.extend_from_slice(&sealed[2 + 1 + WRAPPED_KEY_SIZE..]);
+			assert!(open(&keys.identities()[1], &removed).is_err());
+		}
+	}
+}
@@ -0,0 +1,356 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+#[macro_export]
+/// Define a newtype for secret data with the same protections as orion's own
+/// secret types.
+///
+/// # About:
+/// The newtype stores its value in a fixed-size array and:
+/// - Omits its value when formatted with `Debug`, unless orion is built with
+///   the `unprotected_debug` feature. Only its length is shown.
+/// - Compares in constant time, both with itself and with `&[u8]`.
+/// - Zeroes out its value when dropped.
+/// - Only exposes its value through `unprotected_as_bytes()`.
+///
+/// `struct Name(SIZE)` defines a newtype of exactly `SIZE` bytes.
+/// `struct Name(MIN, MAX)` defines one that accepts `MIN` to `MAX` bytes.
+///
+/// The following are implemented on the newtype:
+/// - `from_slice()`, which returns an error if the slice length is out of bounds,
+///   and `TryFrom<&[u8]>`, which does the same.
+/// - `unprotected_as_bytes()` and `get_length()`.
+/// - `generate_with_rng()`, which fills the maximum length using a
+///   [`SecureRandom`].
+/// - `generate()`, which does the same using the OS CSPRNG. Only available when
+///   orion is built with the `safe_api` feature.
+///
+/// # Security:
+/// - __**Avoid using**__ `unprotected_as_bytes()` whenever possible, as it
+///   breaks all protections that the type implements.
+/// - Copies made with `unprotected_as_bytes()` are not zeroed out.
+///
+/// # Example:
+/// ```rust
+/// orion::secret_newtype! {
+///     /// An API key.
+///     pub struct ApiKey(32);
+/// }
+///
+/// orion::secret_newtype! {
+///     /// A recovery code of 10 to 16 bytes.
+///     pub struct RecoveryCode(10, 16);
+/// }
+///
+/// let key = ApiKey::from_slice(&[1u8; 32])?;
+/// assert!(key == &[1u8; 32][..]);
+/// # #[cfg(not(feature = "unprotected_debug"))]
+/// assert_eq!(format!("{:?}", key), "ApiKey { value: ***OMITTED***, length: 32 }");
+///
+/// assert!(RecoveryCode::from_slice(&[0u8; 9]).is_err());
+/// assert_eq!(RecoveryCode::from_slice(&[0u8; 12])?.get_length(), 12);
+/// # Ok::<(), orion::errors::UnknownCryptoError>(())
+/// ```
+/// [`SecureRandom`]: https://docs.rs/orion/latest/orion/util/trait.SecureRandom.html
+macro_rules! secret_newtype {
+    ($(#[$meta:meta])* $vis:vis struct $name:ident($size:expr);) => (
+        $crate::secret_newtype! {
+            $(#[$meta])*
+            $vis struct $name($size, $size);
+        }
+    );
+    ($(#[$meta:meta])* $vis:vis struct $name:ident($lower_bound:expr, $upper_bound:expr);) => (
+        #[must_use]
+        $(#[$meta])*
+        $vis struct $name {
+            value: [u8; $upper_bound],
+            original_length: usize,
+        }
+
+        impl core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                $crate::__private::fmt_secret_debug(
+                    f,
+                    stringify!($name),
+                    self.unprotected_as_bytes(),
+                )
+            }
+        }
+
+        impl Drop for $name {
+            fn drop(&mut self) {
+                use $crate::__private::zeroize::Zeroize;
+                self.value.zeroize();
+            }
+        }
+
+        impl core::convert::TryFrom<&[u8]> for $name {
+            type Error = $crate::errors::UnknownCryptoError;
+
+            /// Make an object from a given byte slice.
+            fn try_from(slice: &[u8]) -> Result<$name, $crate::errors::UnknownCryptoError> {
+                $name::from_slice(slice)
+            }
+        }
+
+        impl core::cmp::PartialEq<$name> for $name {
+            fn eq(&self, other: &$name) -> bool {
+                use $crate::__private::subtle::ConstantTimeEq;
+
+                (self.unprotected_as_bytes()
+                    .ct_eq(other.unprotected_as_bytes())).into()
+            }
+        }
+
+        impl core::cmp::PartialEq<&[u8]> for $name {
+            fn eq(&self, other: &&[u8]) -> bool {
+                use $crate::__private::subtle::ConstantTimeEq;
+
+                (self.unprotected_as_bytes()
+                    .ct_eq(*other)).into()
+            }
+        }
+
+        impl $name {
+            #[must_use]
+            #[allow(clippy::double_comparisons)]
+            /// Make an object from a given byte slice.
+            pub fn from_slice(slice: &[u8]) -> Result<$name, $crate::errors::UnknownCryptoError> {
+                let slice_len = slice.len();
+
+                if slice_len < $lower_bound || slice_len > $upper_bound {
+                    return Err($crate::errors::UnknownCryptoError::InvalidLength);
+                }
+
+                let mut value = [0u8; $upper_bound];
+                value[..slice_len].copy_from_slice(slice);
+
+                Ok($name { value: value, original_length: slice_len })
+            }
+
+            #[inline]
+            #[must_use]
+            /// Return the object as byte slice. __**Warning**__: Should not be used unless strictly
+            /// needed. This __**breaks protections**__ that the type implements.
+            pub fn unprotected_as_bytes(&self) -> &[u8] {
+                self.value[..self.original_length].as_ref()
+            }
+
+            /// Return the length of the object.
+            pub fn get_length(&self) -> usize {
+                self.original_length
+            }
+
+            #[must_use]
+            /// Randomly generate using `rng`. An error is returned if `rng` fails.
+            pub fn generate_with_rng<R: $crate::util::SecureRandom + ?Sized>(
+                rng: &mut R,
+            ) -> Result<$name, $crate::errors::UnknownCryptoError> {
+                let mut value = [0u8; $upper_bound];
+                rng.fill_bytes(&mut value)?;
+
+                Ok($name { value: value, original_length: $upper_bound })
+            }
+
+            $crate::__secret_newtype_generate!($name, $upper_bound);
+        }
+    );
+}
+
+#[doc(hidden)]
+/// Format a secret value of type `name` for `Debug`. Only the length of
+/// `value` is shown, unless orion is built with the `unprotected_debug`
+/// feature, which is meant for local debugging only.
+pub fn fmt_secret_debug(
+	f: &mut core::fmt::Formatter,
+	name: &str,
+	value: &[u8],
+) -> core::fmt::Result {
+	if cfg!(feature = "unprotected_debug") {
+		write!(
+			f,
+			"{} {{ value: {:?}, length: {} }}",
+			name,
+			value,
+			value.len()
+		)
+	} else {
+		write!(
+			f,
+			"{} {{ value: ***OMITTED***, length: {} }}",
+			name,
+			value.len()
+		)
+	}
+}
+
+#[cfg(feature = "safe_api")]
+#[doc(hidden)]
+#[macro_export]
+/// Implement `generate()` for `secret_newtype!`. This is a separate macro,
+/// because whether orion has a CSPRNG available is decided by orion's features,
+/// not by those of the crate invoking `secret_newtype!`.
+macro_rules! __secret_newtype_generate (($name:ident, $upper_bound:expr) => (
+    #[must_use]
+    /// Randomly generate using a CSPRNG.
+    pub fn generate() -> $name {
+        let mut value = [0u8; $upper_bound];
+        $crate::util::secure_rand_bytes(&mut value).unwrap();
+
+        $name { value: value, original_length: $upper_bound }
+    }
+));
+
+#[cfg(not(feature = "safe_api"))]
+#[doc(hidden)]
+#[macro_export]
+/// `generate()` is not available without `safe_api`.
+macro_rules! __secret_newtype_generate (($name:ident, $upper_bound:expr) => ());
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	crate::secret_newtype! {
+		/// A fixed-size test secret.
+		struct FixedSecret(32);
+	}
+
+	crate::secret_newtype! {
+		/// A variable-size test secret.
+		pub(crate) struct RangeSecret(16, 64);
+	}
+
+	mod test_from_slice {
+		use super::*;
+
+		#[test]
+		fn test_fixed_size() {
+			assert!(FixedSecret::from_slice(&[0u8; 31]).is_err());
+			assert!(FixedSecret::from_slice(&[0u8; 33]).is_err());
+			assert!(FixedSecret::from_slice(&[]).is_err());
+			let secret = FixedSecret::from_slice(&[1u8; 32]).unwrap();
+			assert_eq!(secret.get_length(), 32);
+			assert_eq!(secret.unprotected_as_bytes(), &[1u8; 32][..]);
+		}
+
+		#[test]
+		fn test_try_from_slice() {
+			use core::convert::TryFrom;
+
+			assert!(FixedSecret::try_from(&[1u8; 32][..]).unwrap() == &[1u8; 32][..]);
+			assert!(FixedSecret::try_from(&[1u8; 31][..]).is_err());
+			assert!(RangeSecret::try_from(&[1u8; 20][..]).unwrap() == &[1u8; 20][..]);
+			assert!(RangeSecret::try_from(&[1u8; 65][..]).is_err());
+		}
+
+		#[test]
+		fn test_range_size() {
+			assert!(RangeSecret::from_slice(&[0u8; 15]).is_err());
+			assert!(RangeSecret::from_slice(&[0u8; 65]).is_err());
+			for len in 16..=64 {
+				let secret = RangeSecret::from_slice(&[1u8; 64][..len]).unwrap();
+				assert_eq!(secret.get_length(), len);
+				assert_eq!(secret.unprotected_as_bytes(), &[1u8; 64][..len]);
+			}
+		}
+	}
+
+	mod test_traits {
+		use super::*;
+
+		#[test]
+		#[cfg(feature = "safe_api")]
+		#[cfg(not(feature = "unprotected_debug"))]
+		// format! is only available with std
+		fn test_omitted_debug() {
+			let secret = FixedSecret::from_slice(&[0xabu8; 32]).unwrap();
+			assert_eq!(
+				format!("{:?}", secret),
+				"FixedSecret { value: ***OMITTED***, length: 32 }"
+			);
+			let secret = RangeSecret::from_slice(&[0xabu8; 20]).unwrap();
+			assert_eq!(
+				format!("{:?}", secret),
+				"RangeSecret { value: ***OMITTED***, length: 20 }"
+			);
+		}
+
+		#[test]
+		#[cfg(feature = "safe_api")]
+		#[cfg(feature = "unprotected_debug")]
+		// format! is only available with std
+		fn test_unprotected_debug() {
+			let secret = RangeSecret::from_slice(&[0xabu8; 16]).unwrap();
+			assert_eq!(
+				format!("{:?}", secret),
+				format!(
+					"RangeSecret {{ value: {:?}, length: 16 }}",
+					&[0xabu8; 16][..]
+				)
+			);
+		}
+
+		#[test]
+		fn test_partial_eq() {
+			let secret = RangeSecret::from_slice(&[0u8; 16]).unwrap();
+			assert!(secret == RangeSecret::from_slice(&[0u8; 16]).unwrap());
+			assert!(secret != RangeSecret::from_slice(&[1u8; 16]).unwrap());
+			// Same prefix, different length.
+			assert!(secret != RangeSecret::from_slice(&[0u8; 17]).unwrap());
+			assert!(secret == &[0u8; 16][..]);
+			assert!(secret != &[0u8; 17][..]);
+		}
+	}
+
+	mod test_generate {
+		use super::*;
+
+		#[test]
+		fn test_generate_with_rng() {
+			use crate::util::HmacDrbg;
+
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			let first = RangeSecret::generate_with_rng(&mut rng).unwrap();
+			assert_eq!(first.get_length(), 64);
+			assert!(first != RangeSecret::generate_with_rng(&mut rng).unwrap());
+
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			assert!(first == RangeSecret::generate_with_rng(&mut rng).unwrap());
+			assert_eq!(
+				FixedSecret::generate_with_rng(&mut rng)
+					.unwrap()
+					.get_length(),
+				32
+			);
+		}
+
+		#[test]
+		#[cfg(feature = "safe_api")]
+		fn test_generate() {
+			let secret = FixedSecret::generate();
+			assert_eq!(secret.get_length(), 32);
+			assert!(secret != &[0u8; 32][..]);
+			assert!(secret != FixedSecret::generate());
+			assert_eq!(RangeSecret::generate().get_length(), 64);
+		}
+	}
+}
@@ -0,0 +1,404 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Layered (onion) encryption.
+//!
+//! # Use case:
+//! `orion::onion` can be used to encrypt a payload in several layers, one
+//! for each hop of a route, such that each hop can only remove its own layer
+//! and learns only whether to forward the rest or that it is the last hop.
+//!
+//! An example of this could be experiments with mix networks or onion
+//! routing, where the sender shares a secret key with each hop.
+//!
+//! # About:
+//! - [`wrap()`] takes the secret keys of the hops in the order the onion
+//!   travels. The first key is used for the outermost layer.
+//! - Each layer is sealed with XChaCha20Poly1305 under the key of its hop,
+//!   with a random nonce that is prepended to it. A fixed context string is
+//!   authenticated as additional data, so a layer cannot be mistaken for a
+//!   message sealed with [`orion::aead`] under the same key.
+//! - The plaintext of each layer starts with a byte saying what it holds:
+//!   another layer to forward, or the payload. This byte is encrypted and
+//!   authenticated with the layer, so only the hop learns it.
+//! - The payload is padded to a multiple of `block_size` with
+//!   [`util::pad()`] before it is wrapped. An onion is [`LAYER_OVERHEAD`]
+//!   bytes longer per hop than the padded payload.
+//! - [`peel()`] removes one layer and returns a [`Peeled::Forward`] with the
+//!   onion for the next hop, or a [`Peeled::Payload`] with the payload.
+//!
+//! # Parameters:
+//! - `hop_keys`: The secret keys of the hops, the first hop first.
+//! - `payload`: The data to be delivered to the last hop.
+//! - `block_size`: The payload is padded to a multiple of this. It must be
+//!   the same for [`wrap()`] and [`peel()`].
+//! - `secret_key`: The secret key of the hop peeling the onion.
+//! - `onion`: An onion from [`wrap()`], or from [`Peeled::Forward`].
+//! - `rng`: The random generator used to generate the nonces in
+//!   [`wrap_with_rng()`]. [`wrap()`] uses [`OsRng`].
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `hop_keys` is empty or has more than [`MAX_HOPS`] keys.
+//! - A secret key is not 32 bytes.
+//! - `block_size` is 0.
+//! - `onion` is not longer than [`LAYER_OVERHEAD`] bytes.
+//! - `onion` was not wrapped with a layer for `secret_key` on the outside.
+//! - `onion` has been tampered with.
+//! - The payload of `onion` was not padded with `block_size`.
+//!
+//! # Panics:
+//! A panic will occur if:
+//! - Failure to generate random bytes securely.
+//!
+//! # Security:
+//! - Each peeled layer makes the onion [`LAYER_OVERHEAD`] bytes shorter, so
+//!   the length of an onion reveals how many layers are left. Padding the
+//!   payload only hides its length. To make all onions of a route the same
+//!   length, use a `block_size` larger than every payload.
+//! - Layers do not protect against a hop replaying or dropping onions, and
+//!   do not hide the timing of forwarded onions.
+//! - Anyone knowing the key of a hop can wrap a layer for it. The payload is
+//!   authenticated to the last hop only by its key.
+//!
+//! # Example:
+//! ```rust
+//! use orion::onion::{self, Peeled, SecretKey};
+//!
+//! let hop_keys = vec![SecretKey::default(), SecretKey::default(), SecretKey::default()];
+//! let wrapped = onion::wrap(&hop_keys, b"Secret message", 64)?;
+//!
+//! let mut current = wrapped;
+//! for (idx, key) in hop_keys.iter().enumerate() {
+//! 	match onion::peel(key, &current, 64)? {
+//! 		Peeled::Forward(next) => current = next,
+//! 		Peeled::Payload(payload) => {
+//! 			assert_eq!(idx, 2);
+//! 			assert_eq!(payload, b"Secret message");
+//! 			break;
+//! 		}
+//! 	}
+//! }
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`wrap()`]: https://docs.rs/orion/latest/orion/onion/fn.wrap.html
+//! [`wrap_with_rng()`]: https://docs.rs/orion/latest/orion/onion/fn.wrap_with_rng.html
+//! [`peel()`]: https://docs.rs/orion/latest/orion/onion/fn.peel.html
+//! [`Peeled::Forward`]: https://docs.rs/orion/latest/orion/onion/enum.Peeled.html#variant.Forward
+//! [`Peeled::Payload`]: https://docs.rs/orion/latest/orion/onion/enum.Peeled.html#variant.Payload
+//! [`LAYER_OVERHEAD`]: https://docs.rs/orion/latest/orion/onion/constant.LAYER_OVERHEAD.html
+//! [`MAX_HOPS`]: https://docs.rs/orion/latest/orion/onion/constant.MAX_HOPS.html
+//! [`util::pad()`]: https://docs.rs/orion/latest/orion/util/fn.pad.html
+//! [`orion::aead`]: https://docs.rs/orion/latest/orion/aead/index.html
+//! [`OsRng`]: https://docs.rs/orion/latest/orion/util/struct.OsRng.html
+
+pub use crate::hltypes::SecretKey;
+use crate::{
+	errors::UnknownCryptoError,
+	hazardous::{
+		aead::xchacha20poly1305,
+		mac::poly1305::POLY1305_OUTSIZE,
+		stream::{
+			chacha20,
+			xchacha20::{Nonce, XCHACHA_NONCESIZE},
+		},
+	},
+	util::{self, OsRng, SecureRandom},
+};
+use zeroize::Zeroize;
+
+/// The largest number of hops, and so of layers, of an onion.
+pub const MAX_HOPS: usize = 32;
+/// The amount of bytes each layer adds to an onion: the nonce, the kind of
+/// the layer's content and the tag.
+pub const LAYER_OVERHEAD: usize = XCHACHA_NONCESIZE + 1 + POLY1305_OUTSIZE;
+
+/// The kind of a layer that holds another layer.
+const KIND_FORWARD: u8 = 1;
+/// The kind of a layer that holds the padded payload.
+const KIND_PAYLOAD: u8 = 2;
+/// The additional data of every layer.
+const CONTEXT: &[u8] = b"orion onion layer";
+
+#[derive(Debug, PartialEq)]
+/// The result of peeling one layer of an onion.
+pub enum Peeled {
+	/// The onion to forward to the next hop.
+	Forward(Vec<u8>),
+	/// The payload, for the last hop.
+	Payload(Vec<u8>),
+}
+
+/// Seal the content `plaintext`, of which the first byte is its kind, as a
+/// layer for `secret_key`.
+fn seal_layer<R: SecureRandom + ?Sized>(
+	secret_key: &SecretKey,
+	plaintext: &[u8],
+	rng: &mut R,
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	let key = chacha20::SecretKey::from_slice(secret_key.unprotected_as_bytes())?;
+	let mut nonce = [0u8; XCHACHA_NONCESIZE];
+	rng.fill_bytes(&mut nonce)?;
+
+	let mut layer = vec![0u8; XCHACHA_NONCESIZE + plaintext.len() + POLY1305_OUTSIZE];
+	layer[..XCHACHA_NONCESIZE].copy_from_slice(&nonce);
+	xchacha20poly1305::seal(
+		&key,
+		&Nonce::from(nonce),
+		plaintext,
+		Some(CONTEXT),
+		&mut layer[XCHACHA_NONCESIZE..],
+	)?;
+
+	Ok(layer)
+}
+
+#[must_use]
+/// Wrap `payload` in a layer for each of `hop_keys`, the first key on the
+/// outside.
+pub fn wrap(
+	hop_keys: &[SecretKey],
+	payload: &[u8],
+	block_size: usize,
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	wrap_with_rng(hop_keys, payload, block_size, &mut OsRng)
+}
+
+#[must_use]
+/// Wrap `payload` in a layer for each of `hop_keys`, the first key on the
+/// outside, with the nonces generated by `rng`.
+pub fn wrap_with_rng<R: SecureRandom + ?Sized>(
+	hop_keys: &[SecretKey],
+	payload: &[u8],
+	block_size: usize,
+	rng: &mut R,
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	if hop_keys.is_empty() || hop_keys.len() > MAX_HOPS {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+	if block_size == 0 {
+		return Err(UnknownCryptoError::ParameterOutOfRange);
+	}
+
+	// At least one byte of padding is always added.
+	let plaintext_length = (payload.len() / block_size)
+		.checked_add(1)
+		.and_then(|blocks| blocks.checked_mul(block_size))
+		.and_then(|length| length.checked_add(1))
+		.ok_or(UnknownCryptoError::InvalidLength)?;
+	let mut plaintext = vec![0u8; plaintext_length];
+	plaintext[0] = KIND_PAYLOAD;
+	plaintext[1..1 + payload.len()].copy_from_slice(payload);
+	util::pad(&mut plaintext[1..], payload.len(), block_size)?;
+
+	// Wrap from the inside out, starting with the last hop.
+	for secret_key in hop_keys[1..].iter().rev() {
+		let layer = seal_layer(secret_key, &plaintext, rng);
+		plaintext.zeroize();
+		let layer = layer?;
+
+		plaintext = Vec::with_capacity(1 + layer.len());
+		plaintext.push(KIND_FORWARD);
+		plaintext.extend_from_slice(&layer);
+	}
+	let onion = seal_layer(&hop_keys[0], &plaintext, rng);
+	plaintext.zeroize();
+
+	onion
+}
+
+#[must_use]
+/// Remove the outer layer of `onion` with `secret_key`.
+pub fn peel(
+	secret_key: &SecretKey,
+	onion: &[u8],
+	block_size: usize,
+) -> Result<Peeled, UnknownCryptoError> {
+	if block_size == 0 {
+		return Err(UnknownCryptoError::ParameterOutOfRange);
+	}
+	if onion.len() <= LAYER_OVERHEAD {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let key = chacha20::SecretKey::from_slice(secret_key.unprotected_as_bytes())?;
+	let (nonce, sealed) = onion.split_at(XCHACHA_NONCESIZE);
+	let mut plaintext = vec![0u8; sealed.len() - POLY1305_OUTSIZE];
+	if let Err(err) = xchacha20poly1305::open(
+		&key,
+		&Nonce::from_slice(nonce)?,
+		sealed,
+		Some(CONTEXT),
+		&mut plaintext,
+	) {
+		plaintext.zeroize();
+		return Err(err);
+	}
+
+	let peeled = match plaintext[0] {
+		KIND_FORWARD => Ok(Peeled::Forward(plaintext[1..].to_vec())),
+		KIND_PAYLOAD => util::unpad(&plaintext[1..], block_size)
+			.map(|length| Peeled::Payload(plaintext[1..1 + length].to_vec())),
+		_ => Err(UnknownCryptoError::InvalidEncoding),
+	};
+	plaintext.zeroize();
+
+	peeled
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	fn keys(amount: usize) -> Vec<SecretKey> {
+		(0..amount).map(|_| SecretKey::default()).collect()
+	}
+
+	/// Peel `onion` with each of `hop_keys` in turn, returning the payload.
+	fn peel_all(hop_keys: &[SecretKey], onion: &[u8], block_size: usize) -> Vec<u8> {
+		let mut current = onion.to_vec();
+		for (idx, key) in hop_keys.iter().enumerate() {
+			match peel(key, &current, block_size).unwrap() {
+				Peeled::Forward(next) => {
+					assert!(idx < hop_keys.len() - 1);
+					assert_eq!(next.len(), current.len() - LAYER_OVERHEAD);
+					current = next;
+				}
+				Peeled::Payload(payload) => {
+					assert_eq!(idx, hop_keys.len() - 1);
+					return payload;
+				}
+			}
+		}
+
+		panic!("no payload after the last hop");
+	}
+
+	mod test_wrap_peel {
+		use super::*;
+
+		#[test]
+		fn test_roundtrip() {
+			for hops in 1..5 {
+				let hop_keys = keys(hops);
+				for payload in [&b""[..], b"a", b"Secret message", &[0x80; 64]].iter() {
+					let onion = wrap(&hop_keys, payload, 16).unwrap();
+					assert_eq!(peel_all(&hop_keys, &onion, 16), *payload);
+				}
+			}
+		}
+
+		#[test]
+		fn test_length() {
+			let hop_keys = keys(3);
+			// Payloads padded to the same length give onions of the same length.
+			let short = wrap(&hop_keys, b"a", 64).unwrap();
+			let long = wrap(&hop_keys, &[0u8; 63], 64).unwrap();
+			assert_eq!(short.len(), 3 * LAYER_OVERHEAD + 64);
+			assert_eq!(short.len(), long.len());
+			assert_eq!(
+				wrap(&hop_keys, &[0u8; 64], 64).unwrap().len(),
+				3 * LAYER_OVERHEAD + 128
+			);
+		}
+
+		#[test]
+		fn test_wrap_with_rng_deterministic() {
+			use crate::util::HmacDrbg;
+
+			let hop_keys = keys(2);
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			let first = wrap_with_rng(&hop_keys, b"Secret message", 16, &mut rng).unwrap();
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			let second = wrap_with_rng(&hop_keys, b"Secret message", 16, &mut rng).unwrap();
+			assert_eq!(first, second);
+			assert_ne!(first, wrap(&hop_keys, b"Secret message", 16).unwrap());
+		}
+
+		#[test]
+		fn test_invalid_parameters_err() {
+			assert!(wrap(&[], b"Secret message", 16).is_err());
+			assert!(wrap(&keys(MAX_HOPS + 1), b"Secret message", 16).is_err());
+			assert!(wrap(&keys(MAX_HOPS), b"Secret message", 16).is_ok());
+			assert!(wrap(&keys(1), b"Secret message", 0).is_err());
+			assert!(wrap(&[SecretKey::generate(64).unwrap()], b"Secret message", 16).is_err());
+			assert!(wrap(&keys(1), b"Secret message", usize::max_value()).is_err());
+
+			let key = SecretKey::default();
+			let onion = wrap(core::slice::from_ref(&key), b"a", 16).unwrap();
+			assert!(peel(&key, &onion, 0).is_err());
+			assert!(peel(&key, &onion[..LAYER_OVERHEAD], 16).is_err());
+		}
+	}
+
+	mod test_peel {
+		use super::*;
+
+		#[test]
+		fn test_wrong_key_err() {
+			let hop_keys = keys(2);
+			let onion = wrap(&hop_keys, b"Secret message", 16).unwrap();
+			assert!(peel(&SecretKey::default(), &onion, 16).is_err());
+			// The hops in the wrong order.
+			assert!(peel(&hop_keys[1], &onion, 16).is_err());
+		}
+
+		#[test]
+		fn test_modified_err() {
+			let hop_keys = keys(2);
+			let onion = wrap(&hop_keys, b"Secret message", 16).unwrap();
+			for idx in 0..onion.len() {
+				let mut modified = onion.clone();
+				modified[idx] ^= 1;
+				assert!(peel(&hop_keys[0], &modified, 16).is_err());
+			}
+		}
+
+		#[test]
+		fn test_modified_inner_layer_err() {
+			let hop_keys = keys(2);
+			let onion = wrap(&hop_keys, b"Secret message", 16).unwrap();
+			let mut inner = match peel(&hop_keys[0], &onion, 16).unwrap() {
+				Peeled::Forward(inner) => inner,
+				Peeled::Payload(_) => panic!("expected another layer"),
+			};
+			inner[XCHACHA_NONCESIZE] ^= 1;
+			assert!(peel(&hop_keys[1], &inner, 16).is_err());
+		}
+
+		#[test]
+		fn test_wrong_block_size_err() {
+			let hop_keys = keys(1);
+			let onion = wrap(&hop_keys, b"Secret message", 16).unwrap();
+			assert!(peel(&hop_keys[0], &onion, 32).is_err());
+		}
+
+		#[test]
+		fn test_aead_message_err() {
+			// A message sealed with `aead` under a hop's key is not a layer.
+			let key = SecretKey::default();
+			let sealed = crate::aead::seal(&key, &[KIND_FORWARD; 64]).unwrap();
+			assert!(peel(&key, &sealed, 16).is_err());
+		}
+	}
+}
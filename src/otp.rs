@@ -0,0 +1,469 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! One-time passwords.
+//!
+//! # Use case:
+//! `orion::otp` can be used to generate and verify one-time passwords for
+//! two-factor authentication, such as the codes shown by authenticator apps.
+//!
+//! # About:
+//! - HOTP as specified in the [RFC 4226].
+//! - TOTP as specified in the [RFC 6238], with `T0` being the Unix epoch.
+//! - Uses HMAC-SHA1, HMAC-SHA256 or HMAC-SHA512. HMAC-SHA1 is the algorithm
+//!   specified in the RFC 4226 and the only one most authenticator apps accept.
+//!
+//! # Parameters:
+//! - `secret_key`: The secret key shared with the user.
+//! - `hash`: The HMAC hash function used.
+//! - `digits`: The amount of digits in a code.
+//! - `time_step`: The amount of seconds a TOTP code is valid for.
+//! - `counter`: The HOTP counter.
+//! - `unix_time`: Seconds since the Unix epoch.
+//! - `code`: The code to verify.
+//! - `look_ahead`: The amount of HOTP counters after `counter` to accept.
+//! - `window`: The amount of TOTP time steps before and after the current to
+//!   accept.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `digits` is less than 6 or greater than 8.
+//! - `time_step` is 0.
+//! - `counter` + `look_ahead` overflows.
+//! - The system time is before the Unix epoch.
+//! - `code` does not match any of the codes in the accepted range.
+//!
+//! # Security:
+//! - The secret key should always be generated using a CSPRNG.
+//!   [`SecretKey::default()`] can be used for this. The recommended length
+//!   is at least 20 bytes, which is also the minimum the RFC specifies.
+//! - Prefer HMAC-SHA256 or HMAC-SHA512 where the other party supports it.
+//!   HMAC-SHA1 remains secure for OTP, as HMAC does not rely on the collision
+//!   resistance of SHA-1, but it is only supported for interoperability.
+//! - Codes are short, so verification must be rate-limited by the caller.
+//! - Verification returns the counter that matched. Store it and reject codes
+//!   with a counter not greater than the stored one, to prevent replays.
+//! - Keep `look_ahead` and `window` small. Each accepted counter adds to the
+//!   chance of guessing a valid code.
+//! - All codes in the accepted range are computed and compared, so that
+//!   verification runs in constant time with regards to which code matched.
+//!
+//! # Example:
+//! ```rust
+//! use orion::otp::{HashFunction, SecretKey, Totp};
+//!
+//! let key = SecretKey::default();
+//! let totp = Totp::new(&key, HashFunction::Sha256, 6, 30)?;
+//!
+//! let code = totp.generate()?;
+//! // Codes may have leading zeros, which must be kept when displayed.
+//! let displayed = format!("{:06}", code);
+//!
+//! let counter = totp.verify(displayed.parse().unwrap(), 1)?;
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [RFC 4226]: https://tools.ietf.org/html/rfc4226
+//! [RFC 6238]: https://tools.ietf.org/html/rfc6238
+//! [`SecretKey::default()`]: https://docs.rs/orion/latest/orion/otp/struct.SecretKey.html
+
+pub use crate::hltypes::SecretKey;
+use crate::{
+	errors::UnknownCryptoError,
+	hazardous::{
+		hash::{sha1::hmac_sha1, sha256::hmac_sha256},
+		mac::hmac,
+	},
+};
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+use zeroize::Zeroize;
+
+/// The minimum amount of digits in a code.
+const MIN_DIGITS: u32 = 6;
+/// The maximum amount of digits in a code.
+const MAX_DIGITS: u32 = 8;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// The hash function used with HMAC.
+pub enum HashFunction {
+	/// HMAC-SHA1. Only use this for interoperability with authenticator apps
+	/// and other implementations that support nothing else.
+	Sha1,
+	/// HMAC-SHA256.
+	Sha256,
+	/// HMAC-SHA512.
+	Sha512,
+}
+
+#[derive(Debug)]
+/// HOTP (HMAC-based one-time passwords).
+pub struct Hotp {
+	secret_key: SecretKey,
+	hash: HashFunction,
+	digits: u32,
+}
+
+impl Hotp {
+	#[must_use]
+	/// Initialize HOTP with a secret key, hash function and digit count.
+	pub fn new(
+		secret_key: &SecretKey,
+		hash: HashFunction,
+		digits: u32,
+	) -> Result<Self, UnknownCryptoError> {
+		if digits < MIN_DIGITS || digits > MAX_DIGITS {
+			return Err(UnknownCryptoError::ParameterOutOfRange);
+		}
+
+		Ok(Self {
+			secret_key: SecretKey::from_slice(secret_key.unprotected_as_bytes())?,
+			hash,
+			digits,
+		})
+	}
+
+	#[must_use]
+	/// Generate the code for `counter`.
+	pub fn generate(&self, counter: u64) -> Result<u32, UnknownCryptoError> {
+		let key = self.secret_key.unprotected_as_bytes();
+		let counter = counter.to_be_bytes();
+
+		let code = match self.hash {
+			HashFunction::Sha1 => {
+				let mut tag = hmac_sha1(key, &[&counter]);
+				let code = truncate(&tag);
+				tag.zeroize();
+				code
+			}
+			HashFunction::Sha256 => {
+				let mut tag = hmac_sha256(key, &[&counter])?;
+				let code = truncate(&tag);
+				tag.zeroize();
+				code
+			}
+			HashFunction::Sha512 => {
+				let mut state = hmac::init(&hmac::SecretKey::from_slice(key)?);
+				state.update(&counter)?;
+				truncate(state.finalize()?.unprotected_as_bytes())
+			}
+		};
+
+		Ok(code % 10u32.pow(self.digits))
+	}
+
+	#[must_use]
+	/// Verify `code` against the counters `counter..=counter + look_ahead`,
+	/// returning the counter that matched.
+	pub fn verify(
+		&self,
+		code: u32,
+		counter: u64,
+		look_ahead: u64,
+	) -> Result<u64, UnknownCryptoError> {
+		let last = counter
+			.checked_add(look_ahead)
+			.ok_or(UnknownCryptoError::ParameterOutOfRange)?;
+		self.verify_range(code, counter, last)
+	}
+
+	/// Verify `code` against the counters `first..=last` in constant time.
+	fn verify_range(&self, code: u32, first: u64, last: u64) -> Result<u64, UnknownCryptoError> {
+		let mut found = subtle::Choice::from(0u8);
+		let mut matched = 0u64;
+		for counter in first..=last {
+			let is_match = self.generate(counter)?.ct_eq(&code);
+			matched.conditional_assign(&counter, is_match);
+			found |= is_match;
+		}
+
+		if found.unwrap_u8() == 1 {
+			Ok(matched)
+		} else {
+			Err(UnknownCryptoError::InvalidTag)
+		}
+	}
+}
+
+/// Dynamic truncation of an HMAC tag (RFC 4226, Section 5.3).
+fn truncate(tag: &[u8]) -> u32 {
+	let offset = (tag[tag.len() - 1] & 0x0f) as usize;
+	let mut code = [0u8; 4];
+	code.copy_from_slice(&tag[offset..offset + 4]);
+
+	u32::from_be_bytes(code) & 0x7fff_ffff
+}
+
+#[derive(Debug)]
+/// TOTP (time-based one-time passwords).
+pub struct Totp {
+	hotp: Hotp,
+	time_step: u64,
+}
+
+impl Totp {
+	#[must_use]
+	/// Initialize TOTP with a secret key, hash function, digit count and time
+	/// step in seconds.
+	pub fn new(
+		secret_key: &SecretKey,
+		hash: HashFunction,
+		digits: u32,
+		time_step: u64,
+	) -> Result<Self, UnknownCryptoError> {
+		if time_step == 0 {
+			return Err(UnknownCryptoError::ParameterOutOfRange);
+		}
+
+		Ok(Self {
+			hotp: Hotp::new(secret_key, hash, digits)?,
+			time_step,
+		})
+	}
+
+	#[must_use]
+	/// Generate the code for the time `unix_time`.
+	pub fn generate_at(&self, unix_time: u64) -> Result<u32, UnknownCryptoError> {
+		self.hotp.generate(unix_time / self.time_step)
+	}
+
+	#[must_use]
+	/// Generate the code for the current system time.
+	pub fn generate(&self) -> Result<u32, UnknownCryptoError> {
+		self.generate_at(now()?)
+	}
+
+	#[must_use]
+	/// Verify `code` at the time `unix_time`, accepting `window` time steps
+	/// before and after. Returns the time step counter that matched.
+	pub fn verify_at(
+		&self,
+		code: u32,
+		unix_time: u64,
+		window: u64,
+	) -> Result<u64, UnknownCryptoError> {
+		let counter = unix_time / self.time_step;
+		self.hotp.verify_range(
+			code,
+			counter.saturating_sub(window),
+			counter
+				.checked_add(window)
+				.ok_or(UnknownCryptoError::ParameterOutOfRange)?,
+		)
+	}
+
+	#[must_use]
+	/// Verify `code` at the current system time, accepting `window` time steps
+	/// before and after. Returns the time step counter that matched.
+	pub fn verify(&self, code: u32, window: u64) -> Result<u64, UnknownCryptoError> {
+		self.verify_at(code, now()?, window)
+	}
+}
+
+/// Seconds since the Unix epoch.
+fn now() -> Result<u64, UnknownCryptoError> {
+	std::time::SystemTime::now()
+		.duration_since(std::time::UNIX_EPOCH)
+		.map(|duration| duration.as_secs())
+		.map_err(|_| UnknownCryptoError::ParameterOutOfRange)
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	mod test_hotp {
+		use super::*;
+
+		#[test]
+		fn test_rfc4226() {
+			// Test vectors from RFC 4226, Appendix D.
+			let key = SecretKey::from_slice(b"12345678901234567890").unwrap();
+			let hotp = Hotp::new(&key, HashFunction::Sha1, 6).unwrap();
+			let expected = [
+				755_224, 287_082, 359_152, 969_429, 338_314, 254_676, 287_922, 162_583, 399_871,
+				520_489,
+			];
+
+			for (counter, code) in expected.iter().enumerate() {
+				assert_eq!(hotp.generate(counter as u64).unwrap(), *code);
+				assert_eq!(
+					hotp.verify(*code, counter as u64, 0).unwrap(),
+					counter as u64
+				);
+			}
+		}
+
+		#[test]
+		fn test_digits() {
+			let key = SecretKey::default();
+			assert!(Hotp::new(&key, HashFunction::Sha256, 5).is_err());
+			assert!(Hotp::new(&key, HashFunction::Sha256, 9).is_err());
+
+			for digits in MIN_DIGITS..=MAX_DIGITS {
+				let hotp = Hotp::new(&key, HashFunction::Sha512, digits).unwrap();
+				for counter in 0..32 {
+					assert!(hotp.generate(counter).unwrap() < 10u32.pow(digits));
+				}
+			}
+		}
+
+		#[test]
+		fn test_verify_look_ahead() {
+			let hotp = Hotp::new(&SecretKey::default(), HashFunction::Sha256, 8).unwrap();
+			let code = hotp.generate(12).unwrap();
+
+			assert_eq!(hotp.verify(code, 12, 0).unwrap(), 12);
+			assert_eq!(hotp.verify(code, 10, 2).unwrap(), 12);
+			assert!(hotp.verify(code, 10, 1).is_err());
+			assert!(hotp.verify(code, 13, 5).is_err());
+		}
+
+		#[test]
+		fn test_verify_overflow() {
+			let hotp = Hotp::new(&SecretKey::default(), HashFunction::Sha256, 6).unwrap();
+			let code = hotp.generate(u64::max_value()).unwrap();
+
+			assert_eq!(
+				hotp.verify(code, u64::max_value(), 0).unwrap(),
+				u64::max_value()
+			);
+			assert!(hotp.verify(code, u64::max_value(), 1).is_err());
+		}
+
+		#[test]
+		fn test_hash_functions_differ() {
+			let key = SecretKey::default();
+			let sha256 = Hotp::new(&key, HashFunction::Sha256, 8).unwrap();
+			let sha512 = Hotp::new(&key, HashFunction::Sha512, 8).unwrap();
+
+			assert!((0..4).any(|c| sha256.generate(c).unwrap() != sha512.generate(c).unwrap()));
+		}
+	}
+
+	mod test_totp {
+		use super::*;
+
+		// Test vectors from RFC 6238, Appendix B.
+		const RFC_TIMES: [u64; 6] = [
+			59,
+			1_111_111_109,
+			1_111_111_111,
+			1_234_567_890,
+			2_000_000_000,
+			20_000_000_000,
+		];
+
+		#[test]
+		fn test_rfc6238_sha1() {
+			let key = SecretKey::from_slice(b"12345678901234567890").unwrap();
+			let totp = Totp::new(&key, HashFunction::Sha1, 8, 30).unwrap();
+			let expected = [
+				94_287_082, 7_081_804, 14_050_471, 89_005_924, 69_279_037, 65_353_130,
+			];
+
+			for (time, code) in RFC_TIMES.iter().zip(expected.iter()) {
+				assert_eq!(totp.generate_at(*time).unwrap(), *code);
+				assert_eq!(totp.verify_at(*code, *time, 0).unwrap(), *time / 30);
+			}
+		}
+
+		#[test]
+		fn test_rfc6238_sha256() {
+			let key = SecretKey::from_slice(b"12345678901234567890123456789012").unwrap();
+			let totp = Totp::new(&key, HashFunction::Sha256, 8, 30).unwrap();
+			let expected = [
+				46_119_246, 68_084_774, 67_062_674, 91_819_424, 90_698_825, 77_737_706,
+			];
+
+			for (time, code) in RFC_TIMES.iter().zip(expected.iter()) {
+				assert_eq!(totp.generate_at(*time).unwrap(), *code);
+				assert_eq!(totp.verify_at(*code, *time, 0).unwrap(), *time / 30);
+			}
+		}
+
+		#[test]
+		fn test_rfc6238_sha512() {
+			let key = SecretKey::from_slice(
+				b"1234567890123456789012345678901234567890123456789012345678901234",
+			)
+			.unwrap();
+			let totp = Totp::new(&key, HashFunction::Sha512, 8, 30).unwrap();
+			let expected = [
+				90_693_936, 25_091_201, 99_943_326, 93_441_116, 38_618_901, 47_863_826,
+			];
+
+			for (time, code) in RFC_TIMES.iter().zip(expected.iter()) {
+				assert_eq!(totp.generate_at(*time).unwrap(), *code);
+				assert_eq!(totp.verify_at(*code, *time, 0).unwrap(), *time / 30);
+			}
+		}
+
+		#[test]
+		fn test_zero_time_step_err() {
+			assert!(Totp::new(&SecretKey::default(), HashFunction::Sha256, 6, 0).is_err());
+		}
+
+		#[test]
+		fn test_verify_window() {
+			let totp = Totp::new(&SecretKey::default(), HashFunction::Sha512, 6, 30).unwrap();
+			let code = totp.generate_at(300).unwrap();
+
+			assert_eq!(totp.verify_at(code, 329, 0).unwrap(), 10);
+			assert!(totp.verify_at(code, 330, 0).is_err());
+			assert_eq!(totp.verify_at(code, 330, 1).unwrap(), 10);
+			assert_eq!(totp.verify_at(code, 270, 1).unwrap(), 10);
+			assert!(totp.verify_at(code, 360, 1).is_err());
+		}
+
+		#[test]
+		fn test_verify_window_saturates_at_epoch() {
+			let totp = Totp::new(&SecretKey::default(), HashFunction::Sha256, 6, 30).unwrap();
+			let code = totp.generate_at(0).unwrap();
+
+			assert_eq!(totp.verify_at(code, 0, 2).unwrap(), 0);
+		}
+
+		#[test]
+		fn test_generate_and_verify_now() {
+			let totp = Totp::new(&SecretKey::default(), HashFunction::Sha256, 6, 30).unwrap();
+			let code = totp.generate().unwrap();
+
+			assert!(totp.verify(code, 1).is_ok());
+		}
+	}
+
+	// Proptests. Only exectued when NOT testing no_std.
+	#[cfg(feature = "safe_api")]
+	mod proptest {
+		use super::*;
+
+		quickcheck! {
+			/// A generated code should always verify at the same time.
+			fn prop_totp_verify(unix_time: u64) -> bool {
+				let totp = Totp::new(&SecretKey::default(), HashFunction::Sha512, 8, 30).unwrap();
+				let code = totp.generate_at(unix_time).unwrap();
+
+				totp.verify_at(code, unix_time, 0).unwrap() == unix_time / 30
+			}
+		}
+	}
+}
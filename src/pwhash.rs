@@ -41,19 +41,35 @@
 //! [`pwhash::hash_password_verify`], then the separation of the salt and the
 //! password hash is automatically handled.
 //!
+//! [`pwhash::verify_batch`] verifies many password/hash pairs at once, for
+//! example when checking a set of leaked credentials against stored hashes or
+//! re-verifying hashes in a migration job. The work is spread over a fixed
+//! number of worker threads. Each worker verifies one pair at a time, so memory
+//! use depends on the number of workers and not on the size of the batch.
+//!
 //! # Parameters:
 //! - `password`: The password to be hashed.
 //! - `expected_with_salt`: The expected password hash with the corresponding
 //!   salt prepended.
 //! - `iterations`: The number of iterations performed by PBKDF2, i.e. the cost
 //!   parameter.
+//! - `rng`: The random generator used to generate the salt in
+//!   [`pwhash::hash_password_with_rng`]. [`pwhash::hash_password`] uses
+//!   [`OsRng`].
+//! - `candidates`: The pairs of expected password hash and password to be
+//!   verified by [`pwhash::verify_batch`].
+//! - `max_workers`: The largest number of threads [`pwhash::verify_batch`]
+//!   uses. Fewer are used if the CPU has fewer cores, or the batch fewer pairs.
 //!
 //! # Errors:
 //! An error will be returned if:
 //! - `iterations` is 0.
+//! - `max_workers` is 0.
 //! - The `expected_with_salt` is not constructed exactly as in
 //!   [`pwhash::hash_password`].
-//! - The password hash does not match `expected_with_salt`.
+//! - The password hash does not match `expected_with_salt`. For
+//!   [`pwhash::verify_batch`], a pair that does not match is reported as
+//!   `false` instead.
 //!
 //! # Panics:
 //! A panic will occur if:
@@ -71,14 +87,29 @@
 //!
 //! let hash = pwhash::hash_password(&password, 100000)?;
 //! assert!(pwhash::hash_password_verify(&hash, &password, 100000)?);
+//!
+//! let wrong_password = pwhash::Password::from_slice(b"Wrong password")?;
+//! let results = pwhash::verify_batch(
+//! 	&[(&hash, &password), (&hash, &wrong_password)],
+//! 	100000,
+//! 	4,
+//! )?;
+//! assert_eq!(results, vec![true, false]);
 //! # Ok::<(), orion::errors::UnknownCryptoError>(())
 //! ```
 //! [`PasswordHash`]: https://docs.rs/orion/latest/orion/pwhash/struct.PasswordHash.html
 //! [`pwhash::hash_password`]: https://docs.rs/orion/latest/orion/pwhash/fn.hash_password.html
 //! [`pwhash::hash_password_verify`]: https://docs.rs/orion/latest/orion/pwhash/fn.hash_password_verify.html
+//! [`pwhash::hash_password_with_rng`]: https://docs.rs/orion/latest/orion/pwhash/fn.hash_password_with_rng.html
+//! [`OsRng`]: https://docs.rs/orion/latest/orion/util/struct.OsRng.html
+//! [`pwhash::verify_batch`]: https://docs.rs/orion/latest/orion/pwhash/fn.verify_batch.html
 
 pub use crate::hltypes::{Password, PasswordHash, Salt};
-use crate::{errors::UnknownCryptoError, hazardous::kdf::pbkdf2};
+use crate::{
+	errors::UnknownCryptoError,
+	hazardous::kdf::pbkdf2,
+	util::{self, OsRng, SecureRandom},
+};
 use zeroize::Zeroize;
 
 #[must_use]
@@ -86,17 +117,26 @@ use zeroize::Zeroize;
 pub fn hash_password(
 	password: &Password,
 	iterations: usize,
+) -> Result<PasswordHash, UnknownCryptoError> {
+	hash_password_with_rng(password, iterations, &mut OsRng)
+}
+
+#[must_use]
+/// Hash a password using PBKDF2-HMAC-SHA512, with the salt generated by `rng`.
+pub fn hash_password_with_rng<R: SecureRandom + ?Sized>(
+	password: &Password,
+	iterations: usize,
+	rng: &mut R,
 ) -> Result<PasswordHash, UnknownCryptoError> {
 	let mut buffer = [0u8; 128];
-	// Cannot panic as this is a valid size.
-	let salt = Salt::generate(64).unwrap();
+	let (salt, dk) = buffer.split_at_mut(64);
+	rng.fill_bytes(salt)?;
 
-	buffer[..64].copy_from_slice(salt.as_ref());
 	pbkdf2::derive_key(
 		&pbkdf2::Password::from_slice(password.unprotected_as_bytes())?,
-		salt.as_ref(),
+		salt,
 		iterations,
-		&mut buffer[64..],
+		dk,
 	)?;
 
 	let dk = PasswordHash::from_slice(&buffer)?;
@@ -127,6 +167,28 @@ pub fn hash_password_verify(
 	Ok(is_good)
 }
 
+#[must_use]
+/// Verify many pairs of password hash and password using PBKDF2-HMAC-SHA512,
+/// with at most `max_workers` threads. The results are returned in the same
+/// order as `candidates`.
+pub fn verify_batch(
+	candidates: &[(&PasswordHash, &Password)],
+	iterations: usize,
+	max_workers: usize,
+) -> Result<Vec<bool>, UnknownCryptoError> {
+	if iterations < 1 || max_workers < 1 {
+		return Err(UnknownCryptoError::ParameterOutOfRange);
+	}
+
+	Ok(util::verify_with_workers(
+		candidates,
+		max_workers,
+		|(expected_with_salt, password)| {
+			hash_password_verify(expected_with_salt, password, iterations).is_ok()
+		},
+	))
+}
+
 // Testing public functions in the module.
 #[cfg(test)]
 mod public {
@@ -135,6 +197,20 @@ mod public {
 	mod test_pwhash_and_verify {
 		use super::*;
 
+		#[test]
+		fn test_hash_password_with_rng_deterministic() {
+			use crate::util::HmacDrbg;
+
+			let password = Password::from_slice(b"Secret password").unwrap();
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			let first = hash_password_with_rng(&password, 100, &mut rng).unwrap();
+
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			let second = hash_password_with_rng(&password, 100, &mut rng).unwrap();
+			assert_eq!(first, second);
+			assert!(hash_password_verify(&first, &password, 100).unwrap());
+		}
+
 		#[test]
 		fn test_pbkdf2_verify() {
 			let password = Password::from_slice(&[0u8; 64]).unwrap();
@@ -191,6 +267,54 @@ mod public {
 		}
 	}
 
+	mod test_verify_batch {
+		use super::*;
+
+		#[test]
+		fn test_verify_batch_same_as_verify() {
+			let passwords: Vec<Password> = (0..9u8)
+				.map(|idx| Password::from_slice(&[idx; 16]).unwrap())
+				.collect();
+			let hashes: Vec<PasswordHash> = passwords
+				.iter()
+				.map(|password| hash_password(password, 100).unwrap())
+				.collect();
+
+			// Every third pair has the wrong password.
+			let candidates: Vec<(&PasswordHash, &Password)> = hashes
+				.iter()
+				.enumerate()
+				.map(|(idx, hash)| (hash, &passwords[(idx + (idx % 3 == 0) as usize) % 9]))
+				.collect();
+
+			for max_workers in [1, 2, 4, 16].iter() {
+				let results = verify_batch(&candidates, 100, *max_workers).unwrap();
+				assert_eq!(results.len(), candidates.len());
+				for (idx, is_good) in results.iter().enumerate() {
+					assert_eq!(*is_good, idx % 3 != 0);
+				}
+			}
+		}
+
+		#[test]
+		fn test_verify_batch_empty() {
+			assert!(verify_batch(&[], 100, 4).unwrap().is_empty());
+		}
+
+		#[test]
+		fn test_verify_batch_bad_params() {
+			let password = Password::from_slice(&[0u8; 64]).unwrap();
+			let hash = hash_password(&password, 100).unwrap();
+
+			assert!(verify_batch(&[(&hash, &password)], 0, 4).is_err());
+			assert!(verify_batch(&[(&hash, &password)], 100, 0).is_err());
+			assert_eq!(
+				verify_batch(&[(&hash, &password)], 100, 1).unwrap(),
+				vec![true]
+			);
+		}
+	}
+
 	// Proptests. Only exectued when NOT testing no_std.
 	#[cfg(feature = "safe_api")]
 	mod proptest {
@@ -0,0 +1,774 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! End-to-end encrypted sessions using the Double Ratchet.
+//!
+//! # Use case:
+//! `orion::ratchet` can be used to encrypt the messages of a long-lived
+//! conversation between two parties, such that compromise of the current
+//! keys does not reveal past messages, and the session recovers once new
+//! key agreements happen.
+//!
+//! An example of this could be an end-to-end encrypted messaging
+//! application, after the two parties have agreed on a shared key.
+//!
+//! # About:
+//! - This implements the [Double Ratchet] algorithm with X448, without header
+//!   encryption.
+//! - Both parties must first agree on a shared secret key and the initial
+//!   public key of the responding party, for example with [`orion::kex`] or
+//!   an X3DH-like handshake. The initiating party creates its session with
+//!   [`Session::initiate()`], the responding party with
+//!   [`Session::respond()`]. The responding party can only encrypt once it
+//!   has decrypted a message.
+//! - The root key is updated with HKDF-HMAC-SHA512 from each X448 key
+//!   agreement. Sending and receiving chain keys are advanced with
+//!   HMAC-SHA512. Each message key is used once, with XChaCha20Poly1305 and
+//!   an all-zero nonce.
+//! - Messages may arrive out of order. The keys of skipped messages are kept
+//!   until those messages arrive, at most [`MAX_SKIP`] per chain and
+//!   [`MAX_SKIPPED_KEYS`] in total. When the total is reached, the oldest
+//!   skipped keys are dropped and those messages can no longer be decrypted.
+//! - A failed decryption leaves the session unchanged.
+//! - A message is laid out as follows, and is [`MESSAGE_OVERHEAD`] bytes
+//!   longer than its plaintext:
+//!
+//! | Field                   | Size                    |
+//! |-------------------------|-------------------------|
+//! | Ratchet public key      | 56 bytes                |
+//! | Previous chain length   | 4 bytes, big-endian     |
+//! | Message number          | 4 bytes, big-endian     |
+//! | Ciphertext              | Length of the plaintext |
+//! | Tag                     | 16 bytes                |
+//!
+//! # Parameters:
+//! - `shared_key`: The secret key both parties agreed on.
+//! - `remote_public_key`: The initial public key of the responding party.
+//! - `private_key`: The initial private key of the responding party.
+//! - `plaintext`: The data to be encrypted.
+//! - `message`: A message encrypted by the other party's session.
+//! - `ad`: Additional data to authenticate, such as the identities of the
+//!   parties. It must be the same for encryption and decryption.
+//! - `rng`: The random generator used to generate ratchet private keys.
+//!   Functions without `_with_rng` use [`OsRng`].
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `plaintext` is empty.
+//! - `message` is shorter than [`MESSAGE_OVERHEAD`] + 1 bytes.
+//! - A public key is a low-order point.
+//! - The session is responding and has not yet decrypted a message, when
+//!   encrypting.
+//! - 2^32 - 1 messages have been encrypted in one sending chain.
+//! - `message` would skip more than [`MAX_SKIP`] message keys in a chain.
+//! - `message` was already decrypted, or its skipped key was dropped.
+//! - `message` or `ad` has been tampered with.
+//!
+//! # Panics:
+//! A panic will occur if:
+//! - Failure to generate random bytes securely.
+//!
+//! # Security:
+//! - The headers of messages are not encrypted. Anyone observing the session
+//!   can see the ratchet public keys and message numbers, and so how many
+//!   messages each party has sent.
+//! - A `Session` must never be copied or restored from an earlier state.
+//!   Doing so reuses message keys.
+//! - The security of the session depends on the shared key being secret and
+//!   authenticated. This module does not authenticate the parties.
+//!
+//! # Example:
+//! ```rust
+//! use orion::ratchet::{PrivateKey, PublicKey, SecretKey, Session};
+//! use core::convert::TryFrom;
+//!
+//! // Agreed on beforehand.
+//! let shared_key = SecretKey::default();
+//! let bob_private_key = PrivateKey::generate();
+//! let bob_public_key = PublicKey::try_from(&bob_private_key)?;
+//!
+//! let mut alice = Session::initiate(&shared_key, &bob_public_key)?;
+//! let mut bob = Session::respond(&shared_key, &bob_private_key)?;
+//!
+//! let message = alice.encrypt(b"Hello Bob", b"alice,bob")?;
+//! assert_eq!(bob.decrypt(&message, b"alice,bob")?, b"Hello Bob");
+//! let reply = bob.encrypt(b"Hello Alice", b"alice,bob")?;
+//! assert_eq!(alice.decrypt(&reply, b"alice,bob")?, b"Hello Alice");
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [Double Ratchet]: https://signal.org/docs/specifications/doubleratchet/
+//! [`orion::kex`]: https://docs.rs/orion/latest/orion/kex/index.html
+//! [`Session::initiate()`]: https://docs.rs/orion/latest/orion/ratchet/struct.Session.html#method.initiate
+//! [`Session::respond()`]: https://docs.rs/orion/latest/orion/ratchet/struct.Session.html#method.respond
+//! [`MAX_SKIP`]: https://docs.rs/orion/latest/orion/ratchet/constant.MAX_SKIP.html
+//! [`MAX_SKIPPED_KEYS`]: https://docs.rs/orion/latest/orion/ratchet/constant.MAX_SKIPPED_KEYS.html
+//! [`MESSAGE_OVERHEAD`]: https://docs.rs/orion/latest/orion/ratchet/constant.MESSAGE_OVERHEAD.html
+//! [`OsRng`]: https://docs.rs/orion/latest/orion/util/struct.OsRng.html
+
+pub use crate::hazardous::ecc::x448::{PrivateKey, PublicKey};
+pub use crate::hltypes::SecretKey;
+use crate::{
+	errors::UnknownCryptoError,
+	hazardous::{
+		aead::xchacha20poly1305,
+		ecc::x448::{self, PRIVATE_KEY_SIZE, PUBLIC_KEY_SIZE},
+		kdf::hkdf,
+		mac::{hmac, poly1305::POLY1305_OUTSIZE},
+		stream::{
+			chacha20,
+			xchacha20::{Nonce, XCHACHA_NONCESIZE},
+		},
+	},
+	util::{OsRng, SecureRandom},
+};
+use core::convert::TryFrom;
+use zeroize::Zeroize;
+
+/// The largest number of message keys skipped in a single chain.
+pub const MAX_SKIP: u32 = 1000;
+/// The largest number of skipped message keys a session keeps.
+pub const MAX_SKIPPED_KEYS: usize = 2000;
+/// The size of the header of a message.
+pub const HEADER_SIZE: usize = PUBLIC_KEY_SIZE + 8;
+/// The amount of bytes a message is longer than its plaintext.
+pub const MESSAGE_OVERHEAD: usize = HEADER_SIZE + POLY1305_OUTSIZE;
+
+/// The size of root, chain and message keys.
+const KEY_SIZE: usize = 32;
+/// The HKDF salt used to derive the first root key from the shared key.
+const INITIAL_CONTEXT: &[u8] = b"orion ratchet initial";
+/// The HKDF info used to advance the root key.
+const ROOT_CONTEXT: &[u8] = b"orion ratchet root";
+/// The HMAC input that derives a message key from a chain key.
+const MESSAGE_KEY_INPUT: u8 = 0x01;
+/// The HMAC input that derives the next chain key from a chain key.
+const CHAIN_KEY_INPUT: u8 = 0x02;
+
+/// The header of a message.
+struct Header {
+	public_key: PublicKey,
+	previous_chain_length: u32,
+	number: u32,
+}
+
+impl Header {
+	fn to_bytes(&self) -> [u8; HEADER_SIZE] {
+		let mut header = [0u8; HEADER_SIZE];
+		header[..PUBLIC_KEY_SIZE].copy_from_slice(self.public_key.as_ref());
+		header[PUBLIC_KEY_SIZE..PUBLIC_KEY_SIZE + 4]
+			.copy_from_slice(&self.previous_chain_length.to_be_bytes());
+		header[PUBLIC_KEY_SIZE + 4..].copy_from_slice(&self.number.to_be_bytes());
+
+		header
+	}
+
+	fn from_bytes(header: &[u8]) -> Result<Self, UnknownCryptoError> {
+		if header.len() != HEADER_SIZE {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+
+		let mut previous_chain_length = [0u8; 4];
+		previous_chain_length.copy_from_slice(&header[PUBLIC_KEY_SIZE..PUBLIC_KEY_SIZE + 4]);
+		let mut number = [0u8; 4];
+		number.copy_from_slice(&header[PUBLIC_KEY_SIZE + 4..]);
+
+		Ok(Self {
+			public_key: PublicKey::from_slice(&header[..PUBLIC_KEY_SIZE])?,
+			previous_chain_length: u32::from_be_bytes(previous_chain_length),
+			number: u32::from_be_bytes(number),
+		})
+	}
+}
+
+/// Advance `root_key` with the key agreement of `private_key` and
+/// `public_key`, returning the new root key and a new chain key.
+fn kdf_root(
+	root_key: &[u8; KEY_SIZE],
+	private_key: &[u8; PRIVATE_KEY_SIZE],
+	public_key: &PublicKey,
+) -> Result<([u8; KEY_SIZE], [u8; KEY_SIZE]), UnknownCryptoError> {
+	let private_key = PrivateKey::from(*private_key);
+	let shared_key = x448::key_agreement(&private_key, public_key)?;
+
+	let mut okm = [0u8; KEY_SIZE * 2];
+	hkdf::derive_key(
+		root_key,
+		shared_key.unprotected_as_bytes(),
+		Some(ROOT_CONTEXT),
+		&mut okm,
+	)?;
+	let mut next_root_key = [0u8; KEY_SIZE];
+	let mut chain_key = [0u8; KEY_SIZE];
+	next_root_key.copy_from_slice(&okm[..KEY_SIZE]);
+	chain_key.copy_from_slice(&okm[KEY_SIZE..]);
+	okm.zeroize();
+
+	Ok((next_root_key, chain_key))
+}
+
+/// Advance `chain_key`, returning the next chain key and a message key.
+fn kdf_chain(
+	chain_key: &[u8; KEY_SIZE],
+) -> Result<([u8; KEY_SIZE], [u8; KEY_SIZE]), UnknownCryptoError> {
+	let key = hmac::SecretKey::from_slice(chain_key)?;
+	let mut next_chain_key = [0u8; KEY_SIZE];
+	let mut message_key = [0u8; KEY_SIZE];
+	next_chain_key
+		.copy_from_slice(&hmac::hmac(&key, &[CHAIN_KEY_INPUT])?.unprotected_as_bytes()[..KEY_SIZE]);
+	message_key.copy_from_slice(
+		&hmac::hmac(&key, &[MESSAGE_KEY_INPUT])?.unprotected_as_bytes()[..KEY_SIZE],
+	);
+
+	Ok((next_chain_key, message_key))
+}
+
+/// Generate a new ratchet private key with `rng`.
+fn generate_private_key<R: SecureRandom + ?Sized>(
+	rng: &mut R,
+) -> Result<([u8; PRIVATE_KEY_SIZE], PublicKey), UnknownCryptoError> {
+	let mut private_key = [0u8; PRIVATE_KEY_SIZE];
+	rng.fill_bytes(&mut private_key)?;
+	let public_key = PublicKey::try_from(&PrivateKey::from(private_key));
+	if public_key.is_err() {
+		private_key.zeroize();
+	}
+
+	Ok((private_key, public_key?))
+}
+
+/// Encrypt `plaintext` with the single-use `message_key`, authenticating
+/// `header` and `ad`.
+fn seal_message(
+	message_key: &[u8; KEY_SIZE],
+	header: &[u8; HEADER_SIZE],
+	plaintext: &[u8],
+	ad: &[u8],
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	let mut message = vec![0u8; plaintext.len() + MESSAGE_OVERHEAD];
+	message[..HEADER_SIZE].copy_from_slice(header);
+	let mut aad = Vec::with_capacity(HEADER_SIZE + ad.len());
+	aad.extend_from_slice(header);
+	aad.extend_from_slice(ad);
+
+	xchacha20poly1305::seal(
+		&chacha20::SecretKey::from_slice(message_key)?,
+		&Nonce::from([0u8; XCHACHA_NONCESIZE]),
+		plaintext,
+		Some(&aad),
+		&mut message[HEADER_SIZE..],
+	)?;
+
+	Ok(message)
+}
+
+/// Decrypt `message` with the single-use `message_key`.
+fn open_message(
+	message_key: &[u8; KEY_SIZE],
+	message: &[u8],
+	ad: &[u8],
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	let (header, ciphertext) = message.split_at(HEADER_SIZE);
+	let mut aad = Vec::with_capacity(HEADER_SIZE + ad.len());
+	aad.extend_from_slice(header);
+	aad.extend_from_slice(ad);
+
+	let mut plaintext = vec![0u8; ciphertext.len() - POLY1305_OUTSIZE];
+	xchacha20poly1305::open(
+		&chacha20::SecretKey::from_slice(message_key)?,
+		&Nonce::from([0u8; XCHACHA_NONCESIZE]),
+		ciphertext,
+		Some(&aad),
+		&mut plaintext,
+	)?;
+
+	Ok(plaintext)
+}
+
+#[derive(Clone)]
+/// The key of a message that was skipped in a receiving chain.
+struct SkippedKey {
+	public_key: PublicKey,
+	number: u32,
+	message_key: [u8; KEY_SIZE],
+}
+
+impl Drop for SkippedKey {
+	fn drop(&mut self) {
+		self.message_key.zeroize();
+	}
+}
+
+#[derive(Clone)]
+/// The state of a session. It is cloned only to be able to discard the
+/// changes of a failed decryption.
+struct State {
+	private_key: [u8; PRIVATE_KEY_SIZE],
+	public_key: PublicKey,
+	remote_public_key: Option<PublicKey>,
+	root_key: [u8; KEY_SIZE],
+	sending_chain_key: Option<[u8; KEY_SIZE]>,
+	receiving_chain_key: Option<[u8; KEY_SIZE]>,
+	sent: u32,
+	received: u32,
+	previous_chain_length: u32,
+	skipped: Vec<SkippedKey>,
+}
+
+impl Drop for State {
+	fn drop(&mut self) {
+		self.private_key.zeroize();
+		self.root_key.zeroize();
+		if let Some(key) = self.sending_chain_key.as_mut() {
+			key.zeroize();
+		}
+		if let Some(key) = self.receiving_chain_key.as_mut() {
+			key.zeroize();
+		}
+	}
+}
+
+impl State {
+	/// Store the keys of the messages in the receiving chain up to `until`.
+	fn skip_message_keys(&mut self, until: u32) -> Result<(), UnknownCryptoError> {
+		let (chain_key, remote_public_key) =
+			match (self.receiving_chain_key.as_mut(), self.remote_public_key) {
+				(Some(chain_key), Some(remote_public_key)) => (chain_key, remote_public_key),
+				_ => return Ok(()),
+			};
+		if until.saturating_sub(self.received) > MAX_SKIP {
+			return Err(UnknownCryptoError::SizeLimitExceeded);
+		}
+
+		while self.received < until {
+			let (next_chain_key, message_key) = kdf_chain(chain_key)?;
+			chain_key.copy_from_slice(&next_chain_key);
+			self.skipped.push(SkippedKey {
+				public_key: remote_public_key,
+				number: self.received,
+				message_key,
+			});
+			self.received += 1;
+		}
+		if self.skipped.len() > MAX_SKIPPED_KEYS {
+			let excess = self.skipped.len() - MAX_SKIPPED_KEYS;
+			self.skipped = self.skipped.split_off(excess);
+		}
+
+		Ok(())
+	}
+
+	/// Perform a DH ratchet step with the new `remote_public_key`.
+	fn dh_ratchet<R: SecureRandom + ?Sized>(
+		&mut self,
+		remote_public_key: PublicKey,
+		rng: &mut R,
+	) -> Result<(), UnknownCryptoError> {
+		self.previous_chain_length = self.sent;
+		self.sent = 0;
+		self.received = 0;
+		self.remote_public_key = Some(remote_public_key);
+
+		let (root_key, receiving_chain_key) =
+			kdf_root(&self.root_key, &self.private_key, &remote_public_key)?;
+		self.root_key = root_key;
+		self.receiving_chain_key = Some(receiving_chain_key);
+
+		let (private_key, public_key) = generate_private_key(rng)?;
+		self.private_key = private_key;
+		self.public_key = public_key;
+		let (root_key, sending_chain_key) =
+			kdf_root(&self.root_key, &self.private_key, &remote_public_key)?;
+		self.root_key = root_key;
+		self.sending_chain_key = Some(sending_chain_key);
+
+		Ok(())
+	}
+
+	fn decrypt<R: SecureRandom + ?Sized>(
+		&mut self,
+		message: &[u8],
+		ad: &[u8],
+		rng: &mut R,
+	) -> Result<Vec<u8>, UnknownCryptoError> {
+		let header = Header::from_bytes(&message[..HEADER_SIZE])?;
+
+		if let Some(idx) = self
+			.skipped
+			.iter()
+			.position(|key| key.public_key == header.public_key && key.number == header.number)
+		{
+			let skipped = self.skipped.remove(idx);
+			return open_message(&skipped.message_key, message, ad);
+		}
+
+		if self.remote_public_key != Some(header.public_key) {
+			self.skip_message_keys(header.previous_chain_length)?;
+			self.dh_ratchet(header.public_key, rng)?;
+		}
+		if header.number < self.received {
+			return Err(UnknownCryptoError::InvalidTag);
+		}
+		self.skip_message_keys(header.number)?;
+
+		let chain_key = self
+			.receiving_chain_key
+			.as_mut()
+			.ok_or(UnknownCryptoError::FinalizedState)?;
+		let (next_chain_key, mut message_key) = kdf_chain(chain_key)?;
+		chain_key.copy_from_slice(&next_chain_key);
+		self.received += 1;
+
+		let plaintext = open_message(&message_key, message, ad);
+		message_key.zeroize();
+
+		plaintext
+	}
+}
+
+/// A Double Ratchet session with another party.
+pub struct Session {
+	state: State,
+}
+
+impl core::fmt::Debug for Session {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(
+			f,
+			"Session {{ public_key: {:?}, sent: {:?}, received: {:?}, ***OMITTED*** }}",
+			self.state.public_key, self.state.sent, self.state.received
+		)
+	}
+}
+
+impl Session {
+	/// Derive the first root key from `shared_key`.
+	fn initial_root_key(shared_key: &SecretKey) -> Result<[u8; KEY_SIZE], UnknownCryptoError> {
+		let mut root_key = [0u8; KEY_SIZE];
+		hkdf::derive_key(
+			INITIAL_CONTEXT,
+			shared_key.unprotected_as_bytes(),
+			None,
+			&mut root_key,
+		)?;
+
+		Ok(root_key)
+	}
+
+	#[must_use]
+	/// Start a session as the initiating party.
+	pub fn initiate(
+		shared_key: &SecretKey,
+		remote_public_key: &PublicKey,
+	) -> Result<Self, UnknownCryptoError> {
+		Self::initiate_with_rng(shared_key, remote_public_key, &mut OsRng)
+	}
+
+	#[must_use]
+	/// Start a session as the initiating party, with the ratchet private keys
+	/// generated by `rng`.
+	pub fn initiate_with_rng<R: SecureRandom + ?Sized>(
+		shared_key: &SecretKey,
+		remote_public_key: &PublicKey,
+		rng: &mut R,
+	) -> Result<Self, UnknownCryptoError> {
+		let (private_key, public_key) = generate_private_key(rng)?;
+		let mut state = State {
+			private_key,
+			public_key,
+			remote_public_key: Some(*remote_public_key),
+			root_key: Self::initial_root_key(shared_key)?,
+			sending_chain_key: None,
+			receiving_chain_key: None,
+			sent: 0,
+			received: 0,
+			previous_chain_length: 0,
+			skipped: Vec::new(),
+		};
+		let (root_key, sending_chain_key) =
+			kdf_root(&state.root_key, &state.private_key, remote_public_key)?;
+		state.root_key = root_key;
+		state.sending_chain_key = Some(sending_chain_key);
+
+		Ok(Self { state })
+	}
+
+	#[must_use]
+	/// Start a session as the responding party, whose initial key pair is
+	/// that of `private_key`.
+	pub fn respond(
+		shared_key: &SecretKey,
+		private_key: &PrivateKey,
+	) -> Result<Self, UnknownCryptoError> {
+		let mut private_key_bytes = [0u8; PRIVATE_KEY_SIZE];
+		private_key_bytes.copy_from_slice(private_key.unprotected_as_bytes());
+
+		Ok(Self {
+			state: State {
+				private_key: private_key_bytes,
+				public_key: PublicKey::try_from(private_key)?,
+				remote_public_key: None,
+				root_key: Self::initial_root_key(shared_key)?,
+				sending_chain_key: None,
+				receiving_chain_key: None,
+				sent: 0,
+				received: 0,
+				previous_chain_length: 0,
+				skipped: Vec::new(),
+			},
+		})
+	}
+
+	/// The current ratchet public key of this session.
+	pub fn public_key(&self) -> &PublicKey {
+		&self.state.public_key
+	}
+
+	#[must_use]
+	/// Encrypt `plaintext` for the other party, authenticating `ad`.
+	pub fn encrypt(&mut self, plaintext: &[u8], ad: &[u8]) -> Result<Vec<u8>, UnknownCryptoError> {
+		if plaintext.is_empty() {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+		if self.state.sent == u32::MAX {
+			return Err(UnknownCryptoError::NonceCounterOverflow);
+		}
+		let chain_key = self
+			.state
+			.sending_chain_key
+			.as_mut()
+			.ok_or(UnknownCryptoError::FinalizedState)?;
+
+		let (next_chain_key, mut message_key) = kdf_chain(chain_key)?;
+		let header = Header {
+			public_key: self.state.public_key,
+			previous_chain_length: self.state.previous_chain_length,
+			number: self.state.sent,
+		};
+		let message = seal_message(&message_key, &header.to_bytes(), plaintext, ad);
+		message_key.zeroize();
+
+		let message = message?;
+		chain_key.copy_from_slice(&next_chain_key);
+		self.state.sent += 1;
+
+		Ok(message)
+	}
+
+	#[must_use]
+	/// Decrypt `message` from the other party, authenticating `ad`.
+	pub fn decrypt(&mut self, message: &[u8], ad: &[u8]) -> Result<Vec<u8>, UnknownCryptoError> {
+		self.decrypt_with_rng(message, ad, &mut OsRng)
+	}
+
+	#[must_use]
+	/// Decrypt `message` from the other party, authenticating `ad`, with a
+	/// new ratchet private key generated by `rng` if the other party's
+	/// ratchet public key changed.
+	pub fn decrypt_with_rng<R: SecureRandom + ?Sized>(
+		&mut self,
+		message: &[u8],
+		ad: &[u8],
+		rng: &mut R,
+	) -> Result<Vec<u8>, UnknownCryptoError> {
+		if message.len() <= MESSAGE_OVERHEAD {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+
+		// Changes are made to a copy, which replaces the state only if the
+		// message is authentic.
+		let mut state = self.state.clone();
+		let plaintext = state.decrypt(message, ad, rng)?;
+		self.state = state;
+
+		Ok(plaintext)
+	}
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	const AD: &[u8] = b"alice,bob";
+
+	fn sessions() -> (Session, Session) {
+		let shared_key = SecretKey::default();
+		let private_key = PrivateKey::generate();
+		let public_key = PublicKey::try_from(&private_key).unwrap();
+
+		(
+			Session::initiate(&shared_key, &public_key).unwrap(),
+			Session::respond(&shared_key, &private_key).unwrap(),
+		)
+	}
+
+	mod test_session {
+		use super::*;
+
+		#[test]
+		fn test_conversation() {
+			let (mut alice, mut bob) = sessions();
+			for round in 0..5u8 {
+				for idx in 0..3u8 {
+					let message = alice.encrypt(&[round, idx], AD).unwrap();
+					assert_eq!(message.len(), 2 + MESSAGE_OVERHEAD);
+					assert_eq!(bob.decrypt(&message, AD).unwrap(), [round, idx]);
+				}
+				let reply = bob.encrypt(&[round], AD).unwrap();
+				assert_eq!(alice.decrypt(&reply, AD).unwrap(), [round]);
+			}
+		}
+
+		#[test]
+		fn test_public_key_ratchets() {
+			let (mut alice, mut bob) = sessions();
+			let first = *alice.public_key();
+			bob.decrypt(&alice.encrypt(b"1", AD).unwrap(), AD).unwrap();
+			alice.decrypt(&bob.encrypt(b"2", AD).unwrap(), AD).unwrap();
+			assert_ne!(first, *alice.public_key());
+		}
+
+		#[test]
+		fn test_respond_encrypt_first_err() {
+			let (_, mut bob) = sessions();
+			assert_eq!(
+				bob.encrypt(b"Hello", AD).unwrap_err(),
+				UnknownCryptoError::FinalizedState
+			);
+		}
+
+		#[test]
+		fn test_different_shared_key_err() {
+			let private_key = PrivateKey::generate();
+			let public_key = PublicKey::try_from(&private_key).unwrap();
+			let mut alice = Session::initiate(&SecretKey::default(), &public_key).unwrap();
+			let mut bob = Session::respond(&SecretKey::default(), &private_key).unwrap();
+			assert!(bob
+				.decrypt(&alice.encrypt(b"Hello", AD).unwrap(), AD)
+				.is_err());
+		}
+
+		#[test]
+		fn test_invalid_length_err() {
+			let (mut alice, mut bob) = sessions();
+			assert!(alice.encrypt(b"", AD).is_err());
+			let message = alice.encrypt(b"Hello", AD).unwrap();
+			assert!(bob.decrypt(&message[..MESSAGE_OVERHEAD], AD).is_err());
+			assert!(bob.decrypt(&[], AD).is_err());
+		}
+
+		#[test]
+		fn test_initiate_with_rng_deterministic() {
+			use crate::util::HmacDrbg;
+
+			let shared_key = SecretKey::default();
+			let public_key = PublicKey::try_from(&PrivateKey::generate()).unwrap();
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			let mut first = Session::initiate_with_rng(&shared_key, &public_key, &mut rng).unwrap();
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			let mut second =
+				Session::initiate_with_rng(&shared_key, &public_key, &mut rng).unwrap();
+			assert_eq!(
+				first.encrypt(b"Hello", AD).unwrap(),
+				second.encrypt(b"Hello", AD).unwrap()
+			);
+		}
+
+		#[test]
+		fn test_debug_omits_keys() {
+			let (alice, _) = sessions();
+			assert!(format!("{:?}", alice).contains("***OMITTED***"));
+		}
+	}
+
+	mod test_out_of_order {
+		use super::*;
+
+		#[test]
+		fn test_reordered_in_chain() {
+			let (mut alice, mut bob) = sessions();
+			let messages: Vec<Vec<u8>> = (0..5u8)
+				.map(|idx| alice.encrypt(&[idx], AD).unwrap())
+				.collect();
+			for idx in [3usize, 0, 4, 2, 1].iter() {
+				assert_eq!(bob.decrypt(&messages[*idx], AD).unwrap(), [*idx as u8]);
+			}
+		}
+
+		#[test]
+		fn test_reordered_across_ratchet() {
+			let (mut alice, mut bob) = sessions();
+			bob.decrypt(&alice.encrypt(b"0", AD).unwrap(), AD).unwrap();
+			let delayed = alice.encrypt(b"delayed", AD).unwrap();
+			alice.decrypt(&bob.encrypt(b"1", AD).unwrap(), AD).unwrap();
+
+			// Alice has ratcheted, so this starts a new chain for Bob.
+			let current = alice.encrypt(b"current", AD).unwrap();
+			assert_eq!(bob.decrypt(&current, AD).unwrap(), b"current");
+			assert_eq!(bob.decrypt(&delayed, AD).unwrap(), b"delayed");
+		}
+
+		#[test]
+		fn test_replay_err() {
+			let (mut alice, mut bob) = sessions();
+			let first = alice.encrypt(b"first", AD).unwrap();
+			let second = alice.encrypt(b"second", AD).unwrap();
+			bob.decrypt(&second, AD).unwrap();
+			bob.decrypt(&first, AD).unwrap();
+			assert!(bob.decrypt(&first, AD).is_err());
+			assert!(bob.decrypt(&second, AD).is_err());
+		}
+
+		#[test]
+		fn test_max_skip() {
+			let (mut alice, mut bob) = sessions();
+			for _ in 0..MAX_SKIP {
+				let _ = alice.encrypt(b"skipped", AD).unwrap();
+			}
+			let message = alice.encrypt(b"last", AD).unwrap();
+			let too_far = alice.encrypt(b"too far", AD).unwrap();
+			assert_eq!(
+				bob.decrypt(&too_far, AD).unwrap_err(),
+				UnknownCryptoError::SizeLimitExceeded
+			);
+			assert_eq!(bob.decrypt(&message, AD).unwrap(), b"last");
+		}
+	}
+
+	mod test_tampering {
+		use super::*;
+
+		#[test]
+		fn test_modified_err() {
+			let (mut alice, mut bob) = sessions();
+			let message = alice.encrypt(b"Hello Bob", AD).unwrap();
+			for idx in 0..message.len() {
+				let mut modified = message.clone();
+				modified[idx] ^= 1;
+				assert!(bob.decrypt(&modified, AD).is_err());
+			}
+			assert!(bob.decrypt(&message, b"alice,eve").is_err());
+			// Failed decryptions leave the session unchanged.
+			assert_eq!(bob.decrypt(&message, AD).unwrap(), b"Hello Bob");
+		}
+	}
+}
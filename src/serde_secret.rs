@@ -0,0 +1,246 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Opt-in serialization of secret types.
+//!
+//! # About:
+//! - When the `serde` feature is enabled, all non-secret newtypes such as
+//!   `Digest`, `Tag`, `PublicKey`, `Signature` and `Salt` implement `Serialize`
+//!   and `Deserialize` directly.
+//! - Secret types such as `SecretKey` and `Password` do not. To serialize one,
+//!   it must explicitly be wrapped in [`ExposeSecret`], which makes every place
+//!   a secret leaves the process easy to find.
+//! - All types are serialized as bytes and are validated with `from_slice()`
+//!   when deserialized.
+//!
+//! # Errors:
+//! Deserialization fails if:
+//! - The input is not a byte array.
+//! - The length of the input is not valid for the type being deserialized.
+//!
+//! # Security:
+//! - Serializing an [`ExposeSecret`] writes the raw secret to the output. The
+//!   caller is responsible for protecting wherever that output ends up.
+//! - Buffers used while deserializing a secret are zeroed before they are
+//!   dropped, but the serialization format itself may keep copies that orion
+//!   has no control over.
+//!
+//! # Example:
+//! ```rust
+//! use orion::aead::SecretKey;
+//! use orion::hash::{digest, Digest};
+//! use orion::serde_secret::ExposeSecret;
+//!
+//! let digest = digest(b"Some data")?;
+//! let json = serde_json::to_string(&digest).unwrap();
+//! assert_eq!(serde_json::from_str::<Digest>(&json).unwrap(), digest);
+//!
+//! let key = ExposeSecret::new(SecretKey::default());
+//! let json = serde_json::to_string(&key).unwrap();
+//! let restored: ExposeSecret<SecretKey> = serde_json::from_str(&json).unwrap();
+//! assert!(restored.expose_secret() == key.expose_secret());
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`ExposeSecret`]: struct.ExposeSecret.html
+
+use crate::errors::UnknownCryptoError;
+use zeroize::Zeroize;
+
+/// The largest input a byte sequence can be deserialized from, when no
+/// allocator is available.
+#[cfg(not(feature = "safe_api"))]
+const SEQ_BUFFER_SIZE: usize = 512;
+
+/// A wrapper that opts a secret type in to `Serialize` and `Deserialize`.
+pub struct ExposeSecret<T>(T);
+
+impl<T> ExposeSecret<T> {
+	/// Wrap `secret` so that it can be serialized.
+	pub fn new(secret: T) -> Self {
+		ExposeSecret(secret)
+	}
+
+	/// Get a reference to the wrapped secret.
+	pub fn expose_secret(&self) -> &T {
+		&self.0
+	}
+
+	/// Unwrap the secret.
+	pub fn into_inner(self) -> T {
+		self.0
+	}
+}
+
+impl<T> core::fmt::Debug for ExposeSecret<T> {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "ExposeSecret {{***OMITTED***}}")
+	}
+}
+
+/// A `Visitor` that builds a newtype from either a byte slice or a sequence of
+/// bytes, using the newtype's `from_slice()`.
+pub(crate) struct BytesVisitor<T> {
+	name: &'static str,
+	max_length: usize,
+	from_slice: fn(&[u8]) -> Result<T, UnknownCryptoError>,
+}
+
+impl<T> BytesVisitor<T> {
+	pub(crate) fn new(
+		name: &'static str,
+		max_length: usize,
+		from_slice: fn(&[u8]) -> Result<T, UnknownCryptoError>,
+	) -> Self {
+		Self {
+			name,
+			max_length,
+			from_slice,
+		}
+	}
+}
+
+impl<'de, T> serde::de::Visitor<'de> for BytesVisitor<T> {
+	type Value = T;
+
+	fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "a byte array of valid length for {}", self.name)
+	}
+
+	fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<T, E> {
+		(self.from_slice)(v).map_err(|_| E::invalid_length(v.len(), &self))
+	}
+
+	#[cfg(feature = "safe_api")]
+	fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+		let capacity = seq.size_hint().unwrap_or(0).min(self.max_length);
+		let mut buffer: Vec<u8> = Vec::with_capacity(capacity);
+
+		let result = loop {
+			match seq.next_element::<u8>() {
+				Ok(Some(_)) if buffer.len() == self.max_length => {
+					break Err(serde::de::Error::invalid_length(buffer.len() + 1, &self));
+				}
+				Ok(Some(byte)) => buffer.push(byte),
+				Ok(None) => {
+					break (self.from_slice)(&buffer)
+						.map_err(|_| serde::de::Error::invalid_length(buffer.len(), &self));
+				}
+				Err(err) => break Err(err),
+			}
+		};
+		buffer.zeroize();
+
+		result
+	}
+
+	#[cfg(not(feature = "safe_api"))]
+	fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+		let max_length = self.max_length.min(SEQ_BUFFER_SIZE);
+		let mut buffer = [0u8; SEQ_BUFFER_SIZE];
+		let mut length = 0;
+
+		let result = loop {
+			match seq.next_element::<u8>() {
+				Ok(Some(_)) if length == max_length => {
+					break Err(serde::de::Error::invalid_length(length + 1, &self));
+				}
+				Ok(Some(byte)) => {
+					buffer[length] = byte;
+					length += 1;
+				}
+				Ok(None) => {
+					break (self.from_slice)(&buffer[..length])
+						.map_err(|_| serde::de::Error::invalid_length(length, &self));
+				}
+				Err(err) => break Err(err),
+			}
+		};
+		buffer.zeroize();
+
+		result
+	}
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+#[cfg(feature = "safe_api")]
+mod public {
+	use super::*;
+	use crate::hazardous::hash::sha512::Digest;
+	use crate::hazardous::mac::poly1305::Tag;
+	use crate::hazardous::stream::chacha20::SecretKey;
+	use crate::hltypes::{Password, Salt};
+
+	#[test]
+	fn test_public_roundtrip() {
+		let digest = Digest::from_slice(&[1u8; 64]).unwrap();
+		let json = serde_json::to_string(&digest).unwrap();
+		assert_eq!(serde_json::from_str::<Digest>(&json).unwrap(), digest);
+
+		let tag = Tag::from_slice(&[2u8; 16]).unwrap();
+		let json = serde_json::to_string(&tag).unwrap();
+		assert!(serde_json::from_str::<Tag>(&json).unwrap() == tag);
+
+		let salt = Salt::from_slice(&[3u8; 100]).unwrap();
+		let json = serde_json::to_string(&salt).unwrap();
+		assert_eq!(serde_json::from_str::<Salt>(&json).unwrap(), salt);
+	}
+
+	#[test]
+	fn test_secret_roundtrip() {
+		let key = ExposeSecret::new(SecretKey::generate());
+		let json = serde_json::to_string(&key).unwrap();
+		let restored: ExposeSecret<SecretKey> = serde_json::from_str(&json).unwrap();
+		assert!(restored.expose_secret() == key.expose_secret());
+
+		let password = ExposeSecret::new(Password::from_slice(b"Secret password").unwrap());
+		let json = serde_json::to_string(&password).unwrap();
+		let restored: ExposeSecret<Password> = serde_json::from_str(&json).unwrap();
+		assert!(restored.into_inner() == &b"Secret password"[..]);
+	}
+
+	#[test]
+	fn test_invalid_length_err() {
+		assert!(serde_json::from_str::<Digest>("[1,2,3]").is_err());
+		assert!(serde_json::from_str::<Tag>(&format!("{:?}", [0u8; 17])).is_err());
+		assert!(serde_json::from_str::<Salt>("[]").is_err());
+		assert!(
+			serde_json::from_str::<ExposeSecret<SecretKey>>(&format!("{:?}", [0u8; 31])).is_err()
+		);
+		assert!(
+			serde_json::from_str::<ExposeSecret<SecretKey>>(&format!("{:?}", [0u8; 33])).is_err()
+		);
+	}
+
+	#[test]
+	fn test_not_bytes_err() {
+		assert!(serde_json::from_str::<Digest>("\"abc\"").is_err());
+		assert!(serde_json::from_str::<Digest>("[256]").is_err());
+		assert!(serde_json::from_str::<ExposeSecret<SecretKey>>("{}").is_err());
+	}
+
+	#[test]
+	fn test_omitted_debug() {
+		let key = ExposeSecret::new(SecretKey::from_slice(&[1u8; 32]).unwrap());
+		assert_eq!(format!("{:?}", key), "ExposeSecret {***OMITTED***}");
+	}
+}
@@ -0,0 +1,517 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Signcryption: signing and encrypting to a recipient in one step.
+//!
+//! # Use case:
+//! `orion::signcryption` can be used to send a message that only the
+//! recipient can read, and that the recipient knows, and can prove, came from
+//! the sender.
+//!
+//! An example of this could be signed and encrypted email, or commands sent
+//! to a device that must both be kept secret and come from a known party.
+//!
+//! # About:
+//! - The sender signs with an ECDSA P-256 [`SigningKey`]. The message is
+//!   encrypted to the recipient's X448 [`PublicKey`].
+//! - A new ephemeral X448 private key is generated for each message. The
+//!   encryption key is derived with HKDF-HMAC-SHA512 from the key agreement
+//!   between it and the recipient's public key. The ephemeral and recipient
+//!   public keys are used as salt, and the sender's verifying key as info.
+//! - The signature covers the sender's verifying key, the recipient's public
+//!   key, the ephemeral public key and the plaintext. A sealed message can
+//!   therefore not be passed off as coming from another sender, or be
+//!   re-encrypted to another recipient without that recipient noticing.
+//! - The signature and plaintext are encrypted with XChaCha20Poly1305. The
+//!   key is only used once, so the nonce is all zeroes.
+//! - A sealed message is laid out as follows, and is [`SEAL_OVERHEAD`] bytes
+//!   longer than its plaintext:
+//!
+//! | Field                | Size                    |
+//! |----------------------|-------------------------|
+//! | Ephemeral public key | 56 bytes                |
+//! | Encrypted signature  | 64 bytes                |
+//! | Ciphertext           | Length of the plaintext |
+//! | Tag                  | 16 bytes                |
+//!
+//! # Parameters:
+//! - `signing_key`: The sender's key that signs the message.
+//! - `verifying_key`: The sender's key that the signature is verified with.
+//! - `public_key`: The recipient's public key.
+//! - `private_key`: The recipient's private key.
+//! - `plaintext`: The data to be signed and encrypted.
+//! - `sealed`: A message sealed with [`seal()`].
+//! - `signature`: The signature returned by [`open_with_signature()`].
+//! - `rng`: The random generator used to generate the ephemeral private key
+//!   in [`seal_with_rng()`]. [`seal()`] uses [`OsRng`].
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `plaintext` is empty.
+//! - `signing_key` is not a valid ECDSA P-256 secret key.
+//! - `public_key` is a low-order point.
+//! - `sealed` is shorter than [`SEAL_OVERHEAD`] + 1 bytes.
+//! - `sealed` was not sealed for `private_key`, or by the sender of
+//!   `verifying_key`.
+//! - `sealed` has been tampered with.
+//! - `signature` is not a signature of `plaintext` sealed in `sealed` from
+//!   `verifying_key` to `public_key`.
+//!
+//! # Panics:
+//! A panic will occur if:
+//! - Failure to generate random bytes securely.
+//!
+//! # Security:
+//! - Unlike [`orion::kex`] followed by [`orion::aead`], the recipient can
+//!   prove to anyone that the sender signed the plaintext, by revealing it
+//!   along with the signature, which [`open_with_signature()`] returns. Use
+//!   this module only when that is wanted.
+//! - Who sent a sealed message is hidden from everyone but the recipient. The
+//!   length of the plaintext is not hidden.
+//! - A sealed message can be replayed. Include a counter or timestamp in the
+//!   plaintext if this matters.
+//!
+//! # Example:
+//! ```rust
+//! use orion::signcryption::{self, PrivateKey, PublicKey, SigningKey, VerifyingKey};
+//! use core::convert::TryFrom;
+//!
+//! // The sender's key pair.
+//! let signing_key = SigningKey::generate();
+//! let verifying_key = VerifyingKey::try_from(&signing_key)?;
+//! // The recipient's key pair.
+//! let private_key = PrivateKey::generate();
+//! let public_key = PublicKey::try_from(&private_key)?;
+//!
+//! let sealed = signcryption::seal(&signing_key, &public_key, b"Secret message")?;
+//! assert_eq!(
+//! 	signcryption::open(&private_key, &verifying_key, &sealed)?,
+//! 	b"Secret message"
+//! );
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`SigningKey`]: https://docs.rs/orion/latest/orion/signcryption/struct.SigningKey.html
+//! [`PublicKey`]: https://docs.rs/orion/latest/orion/signcryption/struct.PublicKey.html
+//! [`SEAL_OVERHEAD`]: https://docs.rs/orion/latest/orion/signcryption/constant.SEAL_OVERHEAD.html
+//! [`seal()`]: https://docs.rs/orion/latest/orion/signcryption/fn.seal.html
+//! [`seal_with_rng()`]: https://docs.rs/orion/latest/orion/signcryption/fn.seal_with_rng.html
+//! [`open_with_signature()`]: https://docs.rs/orion/latest/orion/signcryption/fn.open_with_signature.html
+//! [`orion::kex`]: https://docs.rs/orion/latest/orion/kex/index.html
+//! [`orion::aead`]: https://docs.rs/orion/latest/orion/aead/index.html
+//! [`OsRng`]: https://docs.rs/orion/latest/orion/util/struct.OsRng.html
+
+pub use crate::hazardous::ecc::ecdsa_p256::{
+	PublicKey as VerifyingKey, SecretKey as SigningKey, Signature,
+};
+pub use crate::hazardous::ecc::x448::{PrivateKey, PublicKey};
+use crate::{
+	errors::UnknownCryptoError,
+	hazardous::{
+		aead::xchacha20poly1305,
+		ecc::{
+			ecdsa_p256::{self, PUBLIC_KEY_SIZE as VERIFYING_KEY_SIZE, SIGNATURE_SIZE},
+			x448::{self, PRIVATE_KEY_SIZE, PUBLIC_KEY_SIZE},
+		},
+		kdf::hkdf,
+		mac::poly1305::POLY1305_OUTSIZE,
+		stream::{
+			chacha20,
+			xchacha20::{Nonce, XCHACHA_NONCESIZE},
+		},
+	},
+	util::{OsRng, SecureRandom},
+};
+use core::convert::TryFrom;
+use zeroize::Zeroize;
+
+/// The amount of bytes a sealed message is longer than its plaintext.
+pub const SEAL_OVERHEAD: usize = PUBLIC_KEY_SIZE + SIGNATURE_SIZE + POLY1305_OUTSIZE;
+
+/// The size of the derived encryption key.
+const KEY_SIZE: usize = 32;
+/// The context that signatures and derived keys are bound to.
+const CONTEXT: &[u8] = b"orion signcryption";
+
+/// The message that is signed: the context, the sender's verifying key, the
+/// recipient's and ephemeral public keys, and `plaintext`.
+fn signed_message(
+	verifying_key: &VerifyingKey,
+	public_key: &PublicKey,
+	ephemeral_public_key: &PublicKey,
+	plaintext: &[u8],
+) -> Vec<u8> {
+	let mut message = Vec::with_capacity(
+		CONTEXT.len() + VERIFYING_KEY_SIZE + PUBLIC_KEY_SIZE * 2 + plaintext.len(),
+	);
+	message.extend_from_slice(CONTEXT);
+	message.extend_from_slice(verifying_key.as_ref());
+	message.extend_from_slice(public_key.as_ref());
+	message.extend_from_slice(ephemeral_public_key.as_ref());
+	message.extend_from_slice(plaintext);
+
+	message
+}
+
+/// Derive the encryption key from the key agreement of `private_key` and
+/// `other_public_key`.
+fn encryption_key(
+	private_key: &PrivateKey,
+	other_public_key: &PublicKey,
+	ephemeral_public_key: &PublicKey,
+	public_key: &PublicKey,
+	verifying_key: &VerifyingKey,
+) -> Result<chacha20::SecretKey, UnknownCryptoError> {
+	let shared_key = x448::key_agreement(private_key, other_public_key)?;
+
+	let mut salt = [0u8; PUBLIC_KEY_SIZE * 2];
+	salt[..PUBLIC_KEY_SIZE].copy_from_slice(ephemeral_public_key.as_ref());
+	salt[PUBLIC_KEY_SIZE..].copy_from_slice(public_key.as_ref());
+	let mut info = [0u8; CONTEXT.len() + VERIFYING_KEY_SIZE];
+	info[..CONTEXT.len()].copy_from_slice(CONTEXT);
+	info[CONTEXT.len()..].copy_from_slice(verifying_key.as_ref());
+
+	let mut key = [0u8; KEY_SIZE];
+	hkdf::derive_key(
+		&salt,
+		shared_key.unprotected_as_bytes(),
+		Some(&info),
+		&mut key,
+	)?;
+	let encryption_key = chacha20::SecretKey::from_slice(&key);
+	key.zeroize();
+
+	encryption_key
+}
+
+#[must_use]
+/// Sign `plaintext` with `signing_key` and encrypt it to `public_key`.
+pub fn seal(
+	signing_key: &SigningKey,
+	public_key: &PublicKey,
+	plaintext: &[u8],
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	seal_with_rng(signing_key, public_key, plaintext, &mut OsRng)
+}
+
+#[must_use]
+/// Sign `plaintext` with `signing_key` and encrypt it to `public_key`, with
+/// the ephemeral private key generated by `rng`.
+pub fn seal_with_rng<R: SecureRandom + ?Sized>(
+	signing_key: &SigningKey,
+	public_key: &PublicKey,
+	plaintext: &[u8],
+	rng: &mut R,
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	if plaintext.is_empty() {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let verifying_key = VerifyingKey::try_from(signing_key)?;
+	let mut ephemeral = [0u8; PRIVATE_KEY_SIZE];
+	rng.fill_bytes(&mut ephemeral)?;
+	let ephemeral_private_key = PrivateKey::from(ephemeral);
+	ephemeral.zeroize();
+	let ephemeral_public_key = PublicKey::try_from(&ephemeral_private_key)?;
+
+	let key = encryption_key(
+		&ephemeral_private_key,
+		public_key,
+		&ephemeral_public_key,
+		public_key,
+		&verifying_key,
+	)?;
+	let mut message = signed_message(&verifying_key, public_key, &ephemeral_public_key, plaintext);
+	let signature = ecdsa_p256::sign(signing_key, &message);
+	message.zeroize();
+
+	let mut signed = Vec::with_capacity(SIGNATURE_SIZE + plaintext.len());
+	signed.extend_from_slice(signature?.as_ref());
+	signed.extend_from_slice(plaintext);
+
+	let mut sealed = vec![0u8; plaintext.len() + SEAL_OVERHEAD];
+	sealed[..PUBLIC_KEY_SIZE].copy_from_slice(ephemeral_public_key.as_ref());
+	let (header, payload) = sealed.split_at_mut(PUBLIC_KEY_SIZE);
+	let result = xchacha20poly1305::seal(
+		&key,
+		&Nonce::from([0u8; XCHACHA_NONCESIZE]),
+		&signed,
+		Some(header),
+		payload,
+	);
+	signed.zeroize();
+	result?;
+
+	Ok(sealed)
+}
+
+#[must_use]
+/// Decrypt `sealed` with `private_key` and verify that it was signed by
+/// `verifying_key`, returning the plaintext and the signature.
+pub fn open_with_signature(
+	private_key: &PrivateKey,
+	verifying_key: &VerifyingKey,
+	sealed: &[u8],
+) -> Result<(Vec<u8>, Signature), UnknownCryptoError> {
+	if sealed.len() <= SEAL_OVERHEAD {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let (header, payload) = sealed.split_at(PUBLIC_KEY_SIZE);
+	let ephemeral_public_key = PublicKey::from_slice(header)?;
+	let public_key = PublicKey::try_from(private_key)?;
+	let key = encryption_key(
+		private_key,
+		&ephemeral_public_key,
+		&ephemeral_public_key,
+		&public_key,
+		verifying_key,
+	)?;
+
+	let mut signed = vec![0u8; payload.len() - POLY1305_OUTSIZE];
+	if let Err(err) = xchacha20poly1305::open(
+		&key,
+		&Nonce::from([0u8; XCHACHA_NONCESIZE]),
+		payload,
+		Some(header),
+		&mut signed,
+	) {
+		signed.zeroize();
+		return Err(err);
+	}
+
+	let signature = Signature::from_slice(&signed[..SIGNATURE_SIZE])?;
+	let plaintext = signed[SIGNATURE_SIZE..].to_vec();
+	signed.zeroize();
+
+	let mut message = signed_message(
+		verifying_key,
+		&public_key,
+		&ephemeral_public_key,
+		&plaintext,
+	);
+	let verified = ecdsa_p256::verify(&signature, verifying_key, &message);
+	message.zeroize();
+	verified?;
+
+	Ok((plaintext, signature))
+}
+
+#[must_use]
+/// Decrypt `sealed` with `private_key` and verify that it was signed by
+/// `verifying_key`.
+pub fn open(
+	private_key: &PrivateKey,
+	verifying_key: &VerifyingKey,
+	sealed: &[u8],
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	Ok(open_with_signature(private_key, verifying_key, sealed)?.0)
+}
+
+#[must_use]
+/// Verify a `signature` returned by [`open_with_signature()`], as a third
+/// party the recipient has shown the plaintext and keys to.
+///
+/// [`open_with_signature()`]: fn.open_with_signature.html
+pub fn verify_signature(
+	signature: &Signature,
+	verifying_key: &VerifyingKey,
+	public_key: &PublicKey,
+	sealed: &[u8],
+	plaintext: &[u8],
+) -> Result<bool, UnknownCryptoError> {
+	if sealed.len() <= SEAL_OVERHEAD {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let ephemeral_public_key = PublicKey::from_slice(&sealed[..PUBLIC_KEY_SIZE])?;
+	let message = signed_message(verifying_key, public_key, &ephemeral_public_key, plaintext);
+
+	ecdsa_p256::verify(signature, verifying_key, &message)
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	struct Party {
+		signing_key: SigningKey,
+		verifying_key: VerifyingKey,
+		private_key: PrivateKey,
+		public_key: PublicKey,
+	}
+
+	impl Party {
+		fn new() -> Self {
+			let signing_key = SigningKey::generate();
+			let private_key = PrivateKey::generate();
+			Self {
+				verifying_key: VerifyingKey::try_from(&signing_key).unwrap(),
+				public_key: PublicKey::try_from(&private_key).unwrap(),
+				signing_key,
+				private_key,
+			}
+		}
+	}
+
+	mod test_seal_open {
+		use super::*;
+
+		#[test]
+		fn test_roundtrip() {
+			let (alice, bob) = (Party::new(), Party::new());
+			for len in [1, 64, 1024].iter() {
+				let plaintext = vec![0xa5u8; *len];
+				let sealed = seal(&alice.signing_key, &bob.public_key, &plaintext).unwrap();
+				assert_eq!(sealed.len(), plaintext.len() + SEAL_OVERHEAD);
+				assert_eq!(
+					open(&bob.private_key, &alice.verifying_key, &sealed).unwrap(),
+					plaintext
+				);
+			}
+		}
+
+		#[test]
+		fn test_seal_with_rng_deterministic() {
+			use crate::util::HmacDrbg;
+
+			let (alice, bob) = (Party::new(), Party::new());
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			let first = seal_with_rng(
+				&alice.signing_key,
+				&bob.public_key,
+				b"Secret message",
+				&mut rng,
+			)
+			.unwrap();
+			let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+			let second = seal_with_rng(
+				&alice.signing_key,
+				&bob.public_key,
+				b"Secret message",
+				&mut rng,
+			)
+			.unwrap();
+			assert_eq!(first, second);
+			assert_ne!(
+				first,
+				seal(&alice.signing_key, &bob.public_key, b"Secret message").unwrap()
+			);
+		}
+
+		#[test]
+		fn test_wrong_recipient_err() {
+			let (alice, bob, eve) = (Party::new(), Party::new(), Party::new());
+			let sealed = seal(&alice.signing_key, &bob.public_key, b"Secret message").unwrap();
+			assert!(open(&eve.private_key, &alice.verifying_key, &sealed).is_err());
+		}
+
+		#[test]
+		fn test_wrong_sender_err() {
+			let (alice, bob, eve) = (Party::new(), Party::new(), Party::new());
+			let sealed = seal(&alice.signing_key, &bob.public_key, b"Secret message").unwrap();
+			assert!(open(&bob.private_key, &eve.verifying_key, &sealed).is_err());
+			// Eve cannot claim to be Alice by sealing with her own signing key.
+			let forged = seal(&eve.signing_key, &bob.public_key, b"Secret message").unwrap();
+			assert!(open(&bob.private_key, &alice.verifying_key, &forged).is_err());
+		}
+
+		#[test]
+		fn test_invalid_length_err() {
+			let (alice, bob) = (Party::new(), Party::new());
+			assert!(seal(&alice.signing_key, &bob.public_key, b"").is_err());
+			let sealed = seal(&alice.signing_key, &bob.public_key, b"Secret message").unwrap();
+			assert!(open(
+				&bob.private_key,
+				&alice.verifying_key,
+				&sealed[..SEAL_OVERHEAD]
+			)
+			.is_err());
+			assert!(open(&bob.private_key, &alice.verifying_key, &[]).is_err());
+		}
+
+		#[test]
+		fn test_modified_err() {
+			let (alice, bob) = (Party::new(), Party::new());
+			let sealed = seal(&alice.signing_key, &bob.public_key, b"Secret message").unwrap();
+			for idx in 0..sealed.len() {
+				let mut modified = sealed.clone();
+				modified[idx] ^= 1;
+				assert!(open(&bob.private_key, &alice.verifying_key, &modified).is_err());
+			}
+		}
+	}
+
+	mod test_verify_signature {
+		use super::*;
+
+		#[test]
+		fn test_third_party_verifies() {
+			let (alice, bob) = (Party::new(), Party::new());
+			let sealed = seal(&alice.signing_key, &bob.public_key, b"Secret message").unwrap();
+			let (plaintext, signature) =
+				open_with_signature(&bob.private_key, &alice.verifying_key, &sealed).unwrap();
+
+			assert!(verify_signature(
+				&signature,
+				&alice.verifying_key,
+				&bob.public_key,
+				&sealed,
+				&plaintext
+			)
+			.unwrap());
+		}
+
+		#[test]
+		fn test_other_context_err() {
+			let (alice, bob, eve) = (Party::new(), Party::new(), Party::new());
+			let sealed = seal(&alice.signing_key, &bob.public_key, b"Secret message").unwrap();
+			let (plaintext, signature) =
+				open_with_signature(&bob.private_key, &alice.verifying_key, &sealed).unwrap();
+
+			assert!(verify_signature(
+				&signature,
+				&alice.verifying_key,
+				&eve.public_key,
+				&sealed,
+				&plaintext
+			)
+			.is_err());
+			assert!(verify_signature(
+				&signature,
+				&eve.verifying_key,
+				&bob.public_key,
+				&sealed,
+				&plaintext
+			)
+			.is_err());
+			assert!(verify_signature(
+				&signature,
+				&alice.verifying_key,
+				&bob.public_key,
+				&sealed,
+				b"Other message"
+			)
+			.is_err());
+		}
+	}
+}
@@ -0,0 +1,834 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! [__**Caution**__] libsodium-compatible functions.
+//!
+//! # Use case:
+//! `orion::sodium_compat` offers functions named after, and producing the same
+//! output as, those of [libsodium]. It is meant for porting code that uses
+//! libsodium to orion, and for checking that the two interoperate. New code
+//! should use orion's own API instead.
+//!
+//! # About:
+//! - This module requires the `sodium_compat` feature.
+//! - The arguments are in the order of the C functions, with slices in place
+//!   of pointer and length pairs. Fixed-size arguments are arrays. Instead of
+//!   returning `-1`, functions return an error.
+//! - Unlike [`orion::hazardous::aead`], the AEAD functions accept empty
+//!   messages, as libsodium does. They return the number of bytes written.
+//! - The libsodium functions that are not here are built on primitives orion
+//!   does not implement: `crypto_secretbox` and `crypto_box` need XSalsa20 and
+//!   X25519, `crypto_sign` needs Ed25519, and `crypto_pwhash` needs Argon2.
+//!   `crypto_kdf` needs the salt and personalization parameters of BLAKE2b,
+//!   which orion does not expose.
+//!
+//! # Parameters:
+//! - `c`: The buffer for the ciphertext, or the ciphertext to decrypt.
+//! - `m`: The buffer for the message, or the message to encrypt.
+//! - `ad`: Additional data to authenticate.
+//! - `npub`, `n`: The nonce.
+//! - `k`, `key`: The secret key.
+//! - `ic`: The initial block counter.
+//! - `out`: The buffer for the hash or authentication tag.
+//! - `h`: The authentication tag to verify.
+//! - `in_`: The data to hash or authenticate.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `c` is too short for the ciphertext and tag, when encrypting.
+//! - `c` is shorter than the tag, or `m` too short for the message, when
+//!   decrypting.
+//! - `m` and `c` have different lengths, when using a stream cipher.
+//! - `out` is empty or longer than 64 bytes, or `key` is longer than 64
+//!   bytes, in [`crypto_generichash()`].
+//! - A tag does not match when decrypting or verifying.
+//! - The block counter would overflow.
+//!
+//! # Security:
+//! - The same security considerations apply as to the underlying primitives
+//!   in [`orion::hazardous`]. In particular, a nonce must never be reused
+//!   with the same key.
+//! - If decryption fails, `m` is overwritten with zeroes.
+//!
+//! # Example:
+//! ```rust
+//! use orion::sodium_compat::*;
+//!
+//! let key = [0x42u8; CRYPTO_AEAD_XCHACHA20POLY1305_IETF_KEYBYTES];
+//! let nonce = [0x01u8; CRYPTO_AEAD_XCHACHA20POLY1305_IETF_NPUBBYTES];
+//! let mut c = [0u8; 5 + CRYPTO_AEAD_XCHACHA20POLY1305_IETF_ABYTES];
+//! let clen = crypto_aead_xchacha20poly1305_ietf_encrypt(&mut c, b"hello", b"", &nonce, &key)?;
+//!
+//! let mut m = [0u8; 5];
+//! let mlen = crypto_aead_xchacha20poly1305_ietf_decrypt(&mut m, &c[..clen], b"", &nonce, &key)?;
+//! assert_eq!(&m[..mlen], b"hello");
+//!
+//! let mut hash = [0u8; CRYPTO_GENERICHASH_BYTES];
+//! crypto_generichash(&mut hash, b"hello", b"")?;
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [libsodium]: https://libsodium.gitbook.io/doc/
+//! [`orion::hazardous::aead`]: https://docs.rs/orion/latest/orion/hazardous/aead/index.html
+//! [`orion::hazardous`]: https://docs.rs/orion/latest/orion/hazardous/index.html
+//! [`crypto_generichash()`]: https://docs.rs/orion/latest/orion/sodium_compat/fn.crypto_generichash.html
+
+use crate::{
+	errors::UnknownCryptoError,
+	hazardous::{
+		hash::{blake2b, sha256, sha512},
+		mac::{hmac, poly1305},
+		stream::{
+			chacha20::{self, IETF_CHACHA_NONCESIZE},
+			xchacha20::XCHACHA_NONCESIZE,
+		},
+	},
+	util::secure_cmp,
+};
+use zeroize::Zeroize;
+
+/// The size of the key of ChaCha20-Poly1305 (IETF).
+pub const CRYPTO_AEAD_CHACHA20POLY1305_IETF_KEYBYTES: usize = 32;
+/// The size of the nonce of ChaCha20-Poly1305 (IETF).
+pub const CRYPTO_AEAD_CHACHA20POLY1305_IETF_NPUBBYTES: usize = IETF_CHACHA_NONCESIZE;
+/// The size of the tag of ChaCha20-Poly1305 (IETF).
+pub const CRYPTO_AEAD_CHACHA20POLY1305_IETF_ABYTES: usize = 16;
+/// The size of the key of XChaCha20-Poly1305 (IETF).
+pub const CRYPTO_AEAD_XCHACHA20POLY1305_IETF_KEYBYTES: usize = 32;
+/// The size of the nonce of XChaCha20-Poly1305 (IETF).
+pub const CRYPTO_AEAD_XCHACHA20POLY1305_IETF_NPUBBYTES: usize = XCHACHA_NONCESIZE;
+/// The size of the tag of XChaCha20-Poly1305 (IETF).
+pub const CRYPTO_AEAD_XCHACHA20POLY1305_IETF_ABYTES: usize = 16;
+/// The default output size of `crypto_generichash()`.
+pub const CRYPTO_GENERICHASH_BYTES: usize = 32;
+/// The recommended minimum output size of `crypto_generichash()`.
+pub const CRYPTO_GENERICHASH_BYTES_MIN: usize = 16;
+/// The maximum output size of `crypto_generichash()`.
+pub const CRYPTO_GENERICHASH_BYTES_MAX: usize = 64;
+/// The default key size of `crypto_generichash()`.
+pub const CRYPTO_GENERICHASH_KEYBYTES: usize = 32;
+/// The maximum key size of `crypto_generichash()`.
+pub const CRYPTO_GENERICHASH_KEYBYTES_MAX: usize = 64;
+/// The output size of `crypto_hash_sha256()`.
+pub const CRYPTO_HASH_SHA256_BYTES: usize = 32;
+/// The output size of `crypto_hash_sha512()` and `crypto_hash()`.
+pub const CRYPTO_HASH_SHA512_BYTES: usize = 64;
+/// The key size of the `crypto_auth` functions.
+pub const CRYPTO_AUTH_KEYBYTES: usize = 32;
+/// The tag size of `crypto_auth_hmacsha256()`.
+pub const CRYPTO_AUTH_HMACSHA256_BYTES: usize = 32;
+/// The tag size of `crypto_auth_hmacsha512()`.
+pub const CRYPTO_AUTH_HMACSHA512_BYTES: usize = 64;
+/// The tag size of `crypto_auth_hmacsha512256()` and `crypto_auth()`.
+pub const CRYPTO_AUTH_BYTES: usize = 32;
+/// The key size of `crypto_onetimeauth_poly1305()`.
+pub const CRYPTO_ONETIMEAUTH_POLY1305_KEYBYTES: usize = 32;
+/// The tag size of `crypto_onetimeauth_poly1305()`.
+pub const CRYPTO_ONETIMEAUTH_POLY1305_BYTES: usize = 16;
+/// The key size of the `crypto_stream` functions.
+pub const CRYPTO_STREAM_KEYBYTES: usize = 32;
+
+/// Padding for the Poly1305 input of ChaCha20-Poly1305.
+const ZEROES: [u8; 16] = [0u8; 16];
+
+/// The ChaCha20 key and nonce that XChaCha20 uses for `key` and `nonce`.
+fn xchacha20_subkey(
+	key: &[u8; 32],
+	nonce: &[u8; XCHACHA_NONCESIZE],
+) -> Result<(chacha20::SecretKey, chacha20::Nonce), UnknownCryptoError> {
+	let subkey = chacha20::hchacha20(&chacha20::SecretKey::from(*key), &nonce[..16])?;
+	let mut ietf_nonce = [0u8; IETF_CHACHA_NONCESIZE];
+	ietf_nonce[4..].copy_from_slice(&nonce[16..]);
+
+	Ok((
+		chacha20::SecretKey::from(subkey),
+		chacha20::Nonce::from(ietf_nonce),
+	))
+}
+
+/// XOR the ChaCha20 keystream, starting at block `ic`, with `m` into `c`.
+/// Unlike `chacha20::encrypt()`, this accepts empty input.
+fn chacha20_xor(
+	key: &chacha20::SecretKey,
+	nonce: &chacha20::Nonce,
+	ic: u32,
+	m: &[u8],
+	c: &mut [u8],
+) -> Result<(), UnknownCryptoError> {
+	if m.len() != c.len() {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+	if m.is_empty() {
+		return Ok(());
+	}
+
+	chacha20::encrypt(key, nonce, ic, m, c)
+}
+
+/// The Poly1305 tag of ChaCha20-Poly1305 (RFC 8439) over `ad` and `c`.
+fn aead_tag(
+	key: &chacha20::SecretKey,
+	nonce: &chacha20::Nonce,
+	ad: &[u8],
+	c: &[u8],
+) -> Result<poly1305::Tag, UnknownCryptoError> {
+	let mut block = chacha20::keystream_block(key, nonce, 0)?;
+	let one_time_key = poly1305::OneTimeKey::from_slice(&block[..32]);
+	block.zeroize();
+
+	let mut state = poly1305::init(&one_time_key?);
+	state.update(ad)?;
+	state.update(&ZEROES[..(16 - ad.len() % 16) % 16])?;
+	state.update(c)?;
+	state.update(&ZEROES[..(16 - c.len() % 16) % 16])?;
+	state.update(&(ad.len() as u64).to_le_bytes())?;
+	state.update(&(c.len() as u64).to_le_bytes())?;
+
+	state.finalize()
+}
+
+fn aead_encrypt(
+	key: &chacha20::SecretKey,
+	nonce: &chacha20::Nonce,
+	c: &mut [u8],
+	m: &[u8],
+	ad: &[u8],
+) -> Result<usize, UnknownCryptoError> {
+	let clen = m.len() + CRYPTO_AEAD_CHACHA20POLY1305_IETF_ABYTES;
+	if c.len() < clen {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let (ciphertext, tag) = c[..clen].split_at_mut(m.len());
+	chacha20_xor(key, nonce, 1, m, ciphertext)?;
+	tag.copy_from_slice(aead_tag(key, nonce, ad, ciphertext)?.unprotected_as_bytes());
+
+	Ok(clen)
+}
+
+fn aead_decrypt(
+	key: &chacha20::SecretKey,
+	nonce: &chacha20::Nonce,
+	m: &mut [u8],
+	c: &[u8],
+	ad: &[u8],
+) -> Result<usize, UnknownCryptoError> {
+	if c.len() < CRYPTO_AEAD_CHACHA20POLY1305_IETF_ABYTES {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+	let mlen = c.len() - CRYPTO_AEAD_CHACHA20POLY1305_IETF_ABYTES;
+	if m.len() < mlen {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let (ciphertext, tag) = c.split_at(mlen);
+	if aead_tag(key, nonce, ad, ciphertext)? != poly1305::Tag::from_slice(tag)? {
+		m.zeroize();
+		return Err(UnknownCryptoError::InvalidTag);
+	}
+	chacha20_xor(key, nonce, 1, ciphertext, &mut m[..mlen])?;
+
+	Ok(mlen)
+}
+
+#[must_use]
+/// `crypto_aead_chacha20poly1305_ietf_encrypt()`: encrypt `m` into `c`,
+/// returning the length of the ciphertext and tag.
+pub fn crypto_aead_chacha20poly1305_ietf_encrypt(
+	c: &mut [u8],
+	m: &[u8],
+	ad: &[u8],
+	npub: &[u8; CRYPTO_AEAD_CHACHA20POLY1305_IETF_NPUBBYTES],
+	k: &[u8; CRYPTO_AEAD_CHACHA20POLY1305_IETF_KEYBYTES],
+) -> Result<usize, UnknownCryptoError> {
+	aead_encrypt(
+		&chacha20::SecretKey::from(*k),
+		&chacha20::Nonce::from(*npub),
+		c,
+		m,
+		ad,
+	)
+}
+
+#[must_use]
+/// `crypto_aead_chacha20poly1305_ietf_decrypt()`: verify and decrypt `c` into
+/// `m`, returning the length of the message.
+pub fn crypto_aead_chacha20poly1305_ietf_decrypt(
+	m: &mut [u8],
+	c: &[u8],
+	ad: &[u8],
+	npub: &[u8; CRYPTO_AEAD_CHACHA20POLY1305_IETF_NPUBBYTES],
+	k: &[u8; CRYPTO_AEAD_CHACHA20POLY1305_IETF_KEYBYTES],
+) -> Result<usize, UnknownCryptoError> {
+	aead_decrypt(
+		&chacha20::SecretKey::from(*k),
+		&chacha20::Nonce::from(*npub),
+		m,
+		c,
+		ad,
+	)
+}
+
+#[must_use]
+/// `crypto_aead_xchacha20poly1305_ietf_encrypt()`: encrypt `m` into `c`,
+/// returning the length of the ciphertext and tag.
+pub fn crypto_aead_xchacha20poly1305_ietf_encrypt(
+	c: &mut [u8],
+	m: &[u8],
+	ad: &[u8],
+	npub: &[u8; CRYPTO_AEAD_XCHACHA20POLY1305_IETF_NPUBBYTES],
+	k: &[u8; CRYPTO_AEAD_XCHACHA20POLY1305_IETF_KEYBYTES],
+) -> Result<usize, UnknownCryptoError> {
+	let (subkey, nonce) = xchacha20_subkey(k, npub)?;
+	aead_encrypt(&subkey, &nonce, c, m, ad)
+}
+
+#[must_use]
+/// `crypto_aead_xchacha20poly1305_ietf_decrypt()`: verify and decrypt `c`
+/// into `m`, returning the length of the message.
+pub fn crypto_aead_xchacha20poly1305_ietf_decrypt(
+	m: &mut [u8],
+	c: &[u8],
+	ad: &[u8],
+	npub: &[u8; CRYPTO_AEAD_XCHACHA20POLY1305_IETF_NPUBBYTES],
+	k: &[u8; CRYPTO_AEAD_XCHACHA20POLY1305_IETF_KEYBYTES],
+) -> Result<usize, UnknownCryptoError> {
+	let (subkey, nonce) = xchacha20_subkey(k, npub)?;
+	aead_decrypt(&subkey, &nonce, m, c, ad)
+}
+
+#[must_use]
+/// `crypto_stream_chacha20_ietf_xor()`: encrypt or decrypt `m` into `c` with
+/// ChaCha20 (IETF).
+pub fn crypto_stream_chacha20_ietf_xor(
+	c: &mut [u8],
+	m: &[u8],
+	n: &[u8; IETF_CHACHA_NONCESIZE],
+	k: &[u8; CRYPTO_STREAM_KEYBYTES],
+) -> Result<(), UnknownCryptoError> {
+	crypto_stream_chacha20_ietf_xor_ic(c, m, n, 0, k)
+}
+
+#[must_use]
+/// `crypto_stream_chacha20_ietf_xor_ic()`: encrypt or decrypt `m` into `c`
+/// with ChaCha20 (IETF), starting at block `ic`.
+pub fn crypto_stream_chacha20_ietf_xor_ic(
+	c: &mut [u8],
+	m: &[u8],
+	n: &[u8; IETF_CHACHA_NONCESIZE],
+	ic: u32,
+	k: &[u8; CRYPTO_STREAM_KEYBYTES],
+) -> Result<(), UnknownCryptoError> {
+	chacha20_xor(
+		&chacha20::SecretKey::from(*k),
+		&chacha20::Nonce::from(*n),
+		ic,
+		m,
+		c,
+	)
+}
+
+#[must_use]
+/// `crypto_stream_xchacha20_xor()`: encrypt or decrypt `m` into `c` with
+/// XChaCha20.
+pub fn crypto_stream_xchacha20_xor(
+	c: &mut [u8],
+	m: &[u8],
+	n: &[u8; XCHACHA_NONCESIZE],
+	k: &[u8; CRYPTO_STREAM_KEYBYTES],
+) -> Result<(), UnknownCryptoError> {
+	// libsodium's XChaCha20 has a 64-bit block counter. Its upper half is
+	// zero for the first 2^32 blocks, where this is the same.
+	let (subkey, nonce) = xchacha20_subkey(k, n)?;
+	chacha20_xor(&subkey, &nonce, 0, m, c)
+}
+
+#[must_use]
+/// `crypto_generichash()`: BLAKE2b with an output of `out.len()` bytes,
+/// keyed if `key` is not empty.
+pub fn crypto_generichash(
+	out: &mut [u8],
+	in_: &[u8],
+	key: &[u8],
+) -> Result<(), UnknownCryptoError> {
+	let mut state = if key.is_empty() {
+		blake2b::init(None, out.len())?
+	} else {
+		blake2b::init(Some(&blake2b::SecretKey::from_slice(key)?), out.len())?
+	};
+	state.update(in_)?;
+	out.copy_from_slice(state.finalize()?.as_ref());
+
+	Ok(())
+}
+
+#[must_use]
+/// `crypto_hash_sha256()`: SHA256.
+pub fn crypto_hash_sha256(
+	out: &mut [u8; CRYPTO_HASH_SHA256_BYTES],
+	in_: &[u8],
+) -> Result<(), UnknownCryptoError> {
+	out.copy_from_slice(sha256::digest(in_)?.as_ref());
+
+	Ok(())
+}
+
+#[must_use]
+/// `crypto_hash_sha512()`: SHA512.
+pub fn crypto_hash_sha512(
+	out: &mut [u8; CRYPTO_HASH_SHA512_BYTES],
+	in_: &[u8],
+) -> Result<(), UnknownCryptoError> {
+	out.copy_from_slice(sha512::digest(in_)?.as_ref());
+
+	Ok(())
+}
+
+#[must_use]
+/// `crypto_hash()`: SHA512.
+pub fn crypto_hash(
+	out: &mut [u8; CRYPTO_HASH_SHA512_BYTES],
+	in_: &[u8],
+) -> Result<(), UnknownCryptoError> {
+	crypto_hash_sha512(out, in_)
+}
+
+#[must_use]
+/// `crypto_auth_hmacsha256()`: HMAC-SHA256.
+pub fn crypto_auth_hmacsha256(
+	out: &mut [u8; CRYPTO_AUTH_HMACSHA256_BYTES],
+	in_: &[u8],
+	k: &[u8; CRYPTO_AUTH_KEYBYTES],
+) -> Result<(), UnknownCryptoError> {
+	out.copy_from_slice(&sha256::hmac_sha256(k, &[in_])?);
+
+	Ok(())
+}
+
+#[must_use]
+/// `crypto_auth_hmacsha256_verify()`: verify an HMAC-SHA256 tag in constant
+/// time.
+pub fn crypto_auth_hmacsha256_verify(
+	h: &[u8; CRYPTO_AUTH_HMACSHA256_BYTES],
+	in_: &[u8],
+	k: &[u8; CRYPTO_AUTH_KEYBYTES],
+) -> Result<bool, UnknownCryptoError> {
+	secure_cmp(&sha256::hmac_sha256(k, &[in_])?, h)
+}
+
+#[must_use]
+/// `crypto_auth_hmacsha512()`: HMAC-SHA512.
+pub fn crypto_auth_hmacsha512(
+	out: &mut [u8; CRYPTO_AUTH_HMACSHA512_BYTES],
+	in_: &[u8],
+	k: &[u8; CRYPTO_AUTH_KEYBYTES],
+) -> Result<(), UnknownCryptoError> {
+	let tag = hmac::hmac(&hmac::SecretKey::from_slice(k)?, in_)?;
+	out.copy_from_slice(tag.unprotected_as_bytes());
+
+	Ok(())
+}
+
+#[must_use]
+/// `crypto_auth_hmacsha512_verify()`: verify an HMAC-SHA512 tag in constant
+/// time.
+pub fn crypto_auth_hmacsha512_verify(
+	h: &[u8; CRYPTO_AUTH_HMACSHA512_BYTES],
+	in_: &[u8],
+	k: &[u8; CRYPTO_AUTH_KEYBYTES],
+) -> Result<bool, UnknownCryptoError> {
+	hmac::verify(&hmac::Tag::from(*h), &hmac::SecretKey::from_slice(k)?, in_)
+}
+
+#[must_use]
+/// `crypto_auth_hmacsha512256()`: HMAC-SHA512 truncated to 32 bytes.
+pub fn crypto_auth_hmacsha512256(
+	out: &mut [u8; CRYPTO_AUTH_BYTES],
+	in_: &[u8],
+	k: &[u8; CRYPTO_AUTH_KEYBYTES],
+) -> Result<(), UnknownCryptoError> {
+	let tag = hmac::hmac(&hmac::SecretKey::from_slice(k)?, in_)?;
+	out.copy_from_slice(&tag.unprotected_as_bytes()[..CRYPTO_AUTH_BYTES]);
+
+	Ok(())
+}
+
+#[must_use]
+/// `crypto_auth_hmacsha512256_verify()`: verify a truncated HMAC-SHA512 tag
+/// in constant time.
+pub fn crypto_auth_hmacsha512256_verify(
+	h: &[u8; CRYPTO_AUTH_BYTES],
+	in_: &[u8],
+	k: &[u8; CRYPTO_AUTH_KEYBYTES],
+) -> Result<bool, UnknownCryptoError> {
+	let tag = hmac::hmac(&hmac::SecretKey::from_slice(k)?, in_)?;
+	secure_cmp(&tag.unprotected_as_bytes()[..CRYPTO_AUTH_BYTES], h)
+}
+
+#[must_use]
+/// `crypto_auth()`: HMAC-SHA512 truncated to 32 bytes.
+pub fn crypto_auth(
+	out: &mut [u8; CRYPTO_AUTH_BYTES],
+	in_: &[u8],
+	k: &[u8; CRYPTO_AUTH_KEYBYTES],
+) -> Result<(), UnknownCryptoError> {
+	crypto_auth_hmacsha512256(out, in_, k)
+}
+
+#[must_use]
+/// `crypto_auth_verify()`: verify a tag from `crypto_auth()` in constant time.
+pub fn crypto_auth_verify(
+	h: &[u8; CRYPTO_AUTH_BYTES],
+	in_: &[u8],
+	k: &[u8; CRYPTO_AUTH_KEYBYTES],
+) -> Result<bool, UnknownCryptoError> {
+	crypto_auth_hmacsha512256_verify(h, in_, k)
+}
+
+#[must_use]
+/// `crypto_onetimeauth_poly1305()`: Poly1305.
+pub fn crypto_onetimeauth_poly1305(
+	out: &mut [u8; CRYPTO_ONETIMEAUTH_POLY1305_BYTES],
+	in_: &[u8],
+	k: &[u8; CRYPTO_ONETIMEAUTH_POLY1305_KEYBYTES],
+) -> Result<(), UnknownCryptoError> {
+	let tag = poly1305::poly1305(&poly1305::OneTimeKey::from(*k), in_)?;
+	out.copy_from_slice(tag.unprotected_as_bytes());
+
+	Ok(())
+}
+
+#[must_use]
+/// `crypto_onetimeauth_poly1305_verify()`: verify a Poly1305 tag in constant
+/// time.
+pub fn crypto_onetimeauth_poly1305_verify(
+	h: &[u8; CRYPTO_ONETIMEAUTH_POLY1305_BYTES],
+	in_: &[u8],
+	k: &[u8; CRYPTO_ONETIMEAUTH_POLY1305_KEYBYTES],
+) -> Result<bool, UnknownCryptoError> {
+	poly1305::verify(
+		&poly1305::Tag::from(*h),
+		&poly1305::OneTimeKey::from(*k),
+		in_,
+	)
+}
+
+#[must_use]
+/// `sodium_memcmp()`: compare `b1` and `b2` in constant time.
+pub fn sodium_memcmp(b1: &[u8], b2: &[u8]) -> Result<bool, UnknownCryptoError> {
+	secure_cmp(b1, b2)
+}
+
+#[must_use]
+/// `randombytes_buf()`: fill `buf` with random bytes from the OS.
+pub fn randombytes_buf(buf: &mut [u8]) -> Result<(), UnknownCryptoError> {
+	crate::util::secure_rand_bytes(buf)
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	// The test vectors are the output of libsodium 1.0.18, with the key
+	// 00 01 .. 1f, the nonce 00 01 .. and the message below.
+	const KEY: [u8; 32] = [
+		0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+		25, 26, 27, 28, 29, 30, 31,
+	];
+	const MESSAGE: &[u8] = b"Ladies and Gentlemen of the class of '99";
+	const AD: &[u8] = b"additional data";
+
+	fn nonce<T: Default + AsMut<[u8]>>() -> T {
+		let mut nonce = T::default();
+		for (idx, byte) in nonce.as_mut().iter_mut().enumerate() {
+			*byte = idx as u8;
+		}
+		nonce
+	}
+
+	fn decode(hex: &str) -> Vec<u8> {
+		crate::util::hex::decode(hex).unwrap()
+	}
+
+	mod test_aead {
+		use super::*;
+
+		#[test]
+		fn test_chacha20poly1305_ietf() {
+			let expected = decode(
+				"c59a6c694c648521d9e71fb4fd737a0fac1dd789711bcb9992ff4ae516acc74f\
+				 9fa04395c63988a1051760db9e54bfac1fb9b61a18f82e99",
+			);
+			let mut c = vec![0u8; expected.len()];
+			let clen =
+				crypto_aead_chacha20poly1305_ietf_encrypt(&mut c, MESSAGE, AD, &nonce(), &KEY)
+					.unwrap();
+			assert_eq!(clen, expected.len());
+			assert_eq!(c, expected);
+
+			let mut m = vec![0u8; MESSAGE.len()];
+			let mlen =
+				crypto_aead_chacha20poly1305_ietf_decrypt(&mut m, &c, AD, &nonce(), &KEY).unwrap();
+			assert_eq!(&m[..mlen], MESSAGE);
+		}
+
+		#[test]
+		fn test_xchacha20poly1305_ietf() {
+			let expected = decode(
+				"d2a36b16f5a1adcf5d200689ae3cdc842e2a4dcbddb97bcb0b725a965ec1942a\
+				 2d26d9826140305dbbfd69f09d340e15f78943f3f7d8e1b1",
+			);
+			let mut c = vec![0u8; expected.len()];
+			crypto_aead_xchacha20poly1305_ietf_encrypt(&mut c, MESSAGE, AD, &nonce(), &KEY)
+				.unwrap();
+			assert_eq!(c, expected);
+
+			let mut m = vec![0u8; MESSAGE.len()];
+			crypto_aead_xchacha20poly1305_ietf_decrypt(&mut m, &c, AD, &nonce(), &KEY).unwrap();
+			assert_eq!(m, MESSAGE);
+		}
+
+		#[test]
+		fn test_empty_message() {
+			let mut c = [0u8; 16];
+			let clen =
+				crypto_aead_chacha20poly1305_ietf_encrypt(&mut c, b"", AD, &nonce(), &KEY).unwrap();
+			assert_eq!(clen, 16);
+			assert_eq!(c[..], decode("621365c597e682d135b608c158abbed2")[..]);
+			let mlen =
+				crypto_aead_chacha20poly1305_ietf_decrypt(&mut [], &c, AD, &nonce(), &KEY).unwrap();
+			assert_eq!(mlen, 0);
+
+			crypto_aead_xchacha20poly1305_ietf_encrypt(&mut c, b"", AD, &nonce(), &KEY).unwrap();
+			assert_eq!(c[..], decode("edf8de5e5e4a07ea08b1800640883a73")[..]);
+			assert!(
+				crypto_aead_xchacha20poly1305_ietf_decrypt(&mut [], &c, AD, &nonce(), &KEY).is_ok()
+			);
+		}
+
+		#[test]
+		fn test_modified_err() {
+			let mut c = vec![0u8; MESSAGE.len() + 16];
+			crypto_aead_chacha20poly1305_ietf_encrypt(&mut c, MESSAGE, AD, &nonce(), &KEY).unwrap();
+
+			let mut m = vec![0u8; MESSAGE.len()];
+			for idx in 0..c.len() {
+				let mut modified = c.clone();
+				modified[idx] ^= 1;
+				assert!(crypto_aead_chacha20poly1305_ietf_decrypt(
+					&mut m,
+					&modified,
+					AD,
+					&nonce(),
+					&KEY
+				)
+				.is_err());
+				assert_eq!(m, vec![0u8; MESSAGE.len()]);
+			}
+			assert!(
+				crypto_aead_chacha20poly1305_ietf_decrypt(&mut m, &c, b"", &nonce(), &KEY).is_err()
+			);
+		}
+
+		#[test]
+		fn test_buffer_lengths_err() {
+			let mut c = vec![0u8; MESSAGE.len() + 15];
+			assert!(
+				crypto_aead_chacha20poly1305_ietf_encrypt(&mut c, MESSAGE, AD, &nonce(), &KEY)
+					.is_err()
+			);
+			let mut m = vec![0u8; 1];
+			assert!(crypto_aead_chacha20poly1305_ietf_decrypt(
+				&mut m,
+				&[0u8; 15],
+				AD,
+				&nonce(),
+				&KEY
+			)
+			.is_err());
+			assert!(crypto_aead_chacha20poly1305_ietf_decrypt(
+				&mut [],
+				&[0u8; 17],
+				AD,
+				&nonce(),
+				&KEY
+			)
+			.is_err());
+		}
+	}
+
+	mod test_stream {
+		use super::*;
+
+		#[test]
+		fn test_chacha20_ietf() {
+			let mut c = vec![0u8; MESSAGE.len()];
+			crypto_stream_chacha20_ietf_xor(&mut c, MESSAGE, &nonce(), &KEY).unwrap();
+			assert_eq!(
+				c,
+				decode("5c5b9578a4f874fc5740aff7180eb6f6f0bcbee6f8fd9194db18ff7fb716ff38a6dff503adf21549")
+			);
+
+			crypto_stream_chacha20_ietf_xor_ic(&mut c, MESSAGE, &nonce(), 7, &KEY).unwrap();
+			assert_eq!(
+				c,
+				decode("bb067fed8637140e6f3a675820d8780ddd598fbdb937dee9e73c88c1f537561af3f6bf09e7658a16")
+			);
+		}
+
+		#[test]
+		fn test_xchacha20() {
+			let mut c = vec![0u8; MESSAGE.len()];
+			crypto_stream_xchacha20_xor(&mut c, MESSAGE, &nonce(), &KEY).unwrap();
+			assert_eq!(
+				c,
+				decode("a95b05a79422c8756f625da45fb1b442f5c6453d41dbfd73ebb21a2e00dddd0e1bdb8130e9e53500")
+			);
+		}
+
+		#[test]
+		fn test_lengths() {
+			assert!(crypto_stream_xchacha20_xor(&mut [], b"", &nonce(), &KEY).is_ok());
+			assert!(crypto_stream_xchacha20_xor(&mut [0u8; 2], b"a", &nonce(), &KEY).is_err());
+			assert!(crypto_stream_chacha20_ietf_xor_ic(
+				&mut [0u8; 65],
+				&[0u8; 65],
+				&nonce(),
+				u32::max_value(),
+				&KEY
+			)
+			.is_err());
+		}
+	}
+
+	mod test_hash {
+		use super::*;
+
+		#[test]
+		fn test_generichash() {
+			let mut out = [0u8; 32];
+			crypto_generichash(&mut out, MESSAGE, b"").unwrap();
+			assert_eq!(
+				out[..],
+				decode("a2356fed6666554a01c0ac40cbb4f8675f4ca82e6f32fdcc7b0fe0239f538351")[..]
+			);
+
+			let mut out = [0u8; 16];
+			crypto_generichash(&mut out, MESSAGE, &KEY).unwrap();
+			assert_eq!(out[..], decode("f3bea98b8d8063adc65e9ff94cb55105")[..]);
+
+			let key: Vec<u8> = (0u8..64).collect();
+			let mut out = [0u8; 64];
+			crypto_generichash(&mut out, MESSAGE, &key).unwrap();
+			assert_eq!(
+				out[..],
+				decode(
+					"16ae33d3ad821e31deec6d9f0357ce18e413892ddbe42b84e49071a05acaa41c\
+					 2a530a61deb948099fe5429f3b744160cd8ba71f59d6cf83be0ccac65fa268c5"
+				)[..]
+			);
+		}
+
+		#[test]
+		fn test_generichash_lengths_err() {
+			assert!(crypto_generichash(&mut [], MESSAGE, b"").is_err());
+			assert!(crypto_generichash(&mut [0u8; 65], MESSAGE, b"").is_err());
+			assert!(crypto_generichash(&mut [0u8; 32], MESSAGE, &[0u8; 65]).is_err());
+			assert!(crypto_generichash(&mut [0u8; 1], MESSAGE, &[0u8; 1]).is_ok());
+		}
+
+		#[test]
+		fn test_sha2() {
+			let mut out = [0u8; 32];
+			crypto_hash_sha256(&mut out, MESSAGE).unwrap();
+			assert_eq!(
+				out[..],
+				decode("174914536f5164bfd1b860e4666746db21e37a368ddd94cff99c0e425f0e154f")[..]
+			);
+
+			let expected = decode(
+				"9b0593fa324b5501ac1b8a918d59f4ff0ee64b2af015be01d1ea26f9f366773e\
+				 18f730348d9f2bd37cdfe45b2cc1d134beff510f1420167fb07011b19dfd1b05",
+			);
+			let mut out = [0u8; 64];
+			crypto_hash_sha512(&mut out, MESSAGE).unwrap();
+			assert_eq!(out[..], expected[..]);
+			crypto_hash(&mut out, MESSAGE).unwrap();
+			assert_eq!(out[..], expected[..]);
+		}
+	}
+
+	mod test_auth {
+		use super::*;
+
+		#[test]
+		fn test_hmacsha256() {
+			let mut out = [0u8; 32];
+			crypto_auth_hmacsha256(&mut out, MESSAGE, &KEY).unwrap();
+			assert_eq!(
+				out[..],
+				decode("9c4ef8a587b2972f4292524b23e79c34214c5c6399542ceb34518756f377f0f5")[..]
+			);
+			assert!(crypto_auth_hmacsha256_verify(&out, MESSAGE, &KEY).unwrap());
+			out[0] ^= 1;
+			assert!(crypto_auth_hmacsha256_verify(&out, MESSAGE, &KEY).is_err());
+		}
+
+		#[test]
+		fn test_hmacsha512() {
+			let expected = decode(
+				"fdd0557cbc9a9b12dd286a603a1516cca732a9866c7de301d2e8ec1d27ed2774\
+				 7c22355019fd55fb1cc7421ac244b8e973486a3d8d1f372104496b7d1ac5a4fa",
+			);
+			let mut out = [0u8; 64];
+			crypto_auth_hmacsha512(&mut out, MESSAGE, &KEY).unwrap();
+			assert_eq!(out[..], expected[..]);
+			assert!(crypto_auth_hmacsha512_verify(&out, MESSAGE, &KEY).unwrap());
+			out[63] ^= 1;
+			assert!(crypto_auth_hmacsha512_verify(&out, MESSAGE, &KEY).is_err());
+
+			let mut out = [0u8; 32];
+			crypto_auth_hmacsha512256(&mut out, MESSAGE, &KEY).unwrap();
+			assert_eq!(out[..], expected[..32]);
+			crypto_auth(&mut out, MESSAGE, &KEY).unwrap();
+			assert_eq!(out[..], expected[..32]);
+			assert!(crypto_auth_verify(&out, MESSAGE, &KEY).unwrap());
+			assert!(crypto_auth_hmacsha512256_verify(&out, b"", &KEY).is_err());
+		}
+
+		#[test]
+		fn test_poly1305() {
+			let mut out = [0u8; 16];
+			crypto_onetimeauth_poly1305(&mut out, MESSAGE, &KEY).unwrap();
+			assert_eq!(out[..], decode("24d2c47aacdd38450a54c926d9f1f339")[..]);
+			assert!(crypto_onetimeauth_poly1305_verify(&out, MESSAGE, &KEY).unwrap());
+			assert!(crypto_onetimeauth_poly1305_verify(&out, b"", &KEY).is_err());
+		}
+	}
+
+	#[test]
+	fn test_memcmp() {
+		assert!(sodium_memcmp(b"abc", b"abc").unwrap());
+		assert!(sodium_memcmp(b"abc", b"abd").is_err());
+		assert!(sodium_memcmp(b"abc", b"ab").is_err());
+	}
+}
@@ -0,0 +1,361 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::errors::UnknownCryptoError;
+
+#[allow(non_snake_case)]
+/// Test runner for AEADs, generic over `sealer` and `opener`, which take
+/// `(key, nonce, input, ad, dst_out)` in the same order as the hazardous
+/// `seal()` and `open()` functions.
+///
+/// Checks, for `input` and `aad`:
+/// - That sealing and then opening returns `input`, and that the output of
+///   sealing equals `expected_ct_with_tag` if it is given.
+/// - That `None` and `Some(&[])` as additional data give the same result.
+/// - The accepted lengths of `dst_out` when sealing and opening.
+/// - That a modified ciphertext, tag or additional data, a truncated
+///   ciphertext and the wrong key are all rejected with
+///   [`UnknownCryptoError::InvalidTag`] or
+///   [`UnknownCryptoError::InvalidLength`].
+///
+/// If `input` is empty, it is instead checked that sealing it, and opening a
+/// ciphertext that only holds a tag, both fail.
+///
+/// [`UnknownCryptoError::InvalidTag`]: ../../errors/enum.UnknownCryptoError.html#variant.InvalidTag
+/// [`UnknownCryptoError::InvalidLength`]: ../../errors/enum.UnknownCryptoError.html#variant.InvalidLength
+pub fn AeadTestRunner<Sealer, Opener, Key, Nonce>(
+	sealer: Sealer,
+	opener: Opener,
+	key: &Key,
+	wrong_key: &Key,
+	nonce: &Nonce,
+	input: &[u8],
+	expected_ct_with_tag: Option<&[u8]>,
+	tag_size: usize,
+	aad: &[u8],
+) where
+	Sealer: Fn(&Key, &Nonce, &[u8], Option<&[u8]>, &mut [u8]) -> Result<(), UnknownCryptoError>,
+	Opener: Fn(&Key, &Nonce, &[u8], Option<&[u8]>, &mut [u8]) -> Result<(), UnknownCryptoError>,
+{
+	if input.is_empty() {
+		empty_input_err(&sealer, &opener, key, nonce, tag_size, aad);
+		return;
+	}
+
+	seal_dst_out_length(&sealer, key, nonce, input, tag_size, aad);
+	let ct_with_tag = seal_open_same_input(
+		&sealer,
+		&opener,
+		key,
+		nonce,
+		input,
+		expected_ct_with_tag,
+		tag_size,
+		aad,
+	);
+	open_dst_out_length(&opener, key, nonce, &ct_with_tag, tag_size, aad);
+	open_modified_tag_err(&opener, key, nonce, &ct_with_tag, tag_size, aad);
+	open_modified_ciphertext_err(&opener, key, nonce, &ct_with_tag, tag_size, aad);
+	open_modified_aad_err(&opener, key, nonce, &ct_with_tag, tag_size, aad);
+	open_truncated_err(&opener, key, nonce, &ct_with_tag, tag_size, aad);
+	open_wrong_key_err(&opener, wrong_key, nonce, &ct_with_tag, tag_size, aad);
+}
+
+/// Seal `input`, check the result against `expected_ct_with_tag` and that
+/// opening it returns `input`. Returns the ciphertext with the tag appended.
+fn seal_open_same_input<Sealer, Opener, Key, Nonce>(
+	sealer: &Sealer,
+	opener: &Opener,
+	key: &Key,
+	nonce: &Nonce,
+	input: &[u8],
+	expected_ct_with_tag: Option<&[u8]>,
+	tag_size: usize,
+	aad: &[u8],
+) -> Vec<u8>
+where
+	Sealer: Fn(&Key, &Nonce, &[u8], Option<&[u8]>, &mut [u8]) -> Result<(), UnknownCryptoError>,
+	Opener: Fn(&Key, &Nonce, &[u8], Option<&[u8]>, &mut [u8]) -> Result<(), UnknownCryptoError>,
+{
+	let mut ct_with_tag = vec![0u8; input.len() + tag_size];
+	sealer(key, nonce, input, Some(aad), &mut ct_with_tag).unwrap();
+	if let Some(expected) = expected_ct_with_tag {
+		assert_eq!(&ct_with_tag[..], expected);
+	}
+
+	let mut pt = vec![0u8; input.len()];
+	opener(key, nonce, &ct_with_tag, Some(aad), &mut pt).unwrap();
+	assert_eq!(&pt[..], input);
+
+	if aad.is_empty() {
+		let mut ct_with_tag_none = vec![0u8; input.len() + tag_size];
+		sealer(key, nonce, input, None, &mut ct_with_tag_none).unwrap();
+		assert_eq!(ct_with_tag_none, ct_with_tag);
+
+		let mut pt_none = vec![0u8; input.len()];
+		opener(key, nonce, &ct_with_tag, None, &mut pt_none).unwrap();
+		assert_eq!(&pt_none[..], input);
+	}
+
+	ct_with_tag
+}
+
+/// Sealing requires `dst_out` to hold at least the input and the tag.
+fn seal_dst_out_length<Sealer, Key, Nonce>(
+	sealer: &Sealer,
+	key: &Key,
+	nonce: &Nonce,
+	input: &[u8],
+	tag_size: usize,
+	aad: &[u8],
+) where
+	Sealer: Fn(&Key, &Nonce, &[u8], Option<&[u8]>, &mut [u8]) -> Result<(), UnknownCryptoError>,
+{
+	let mut dst_out = vec![0u8; input.len() + tag_size + 1];
+	let exact = input.len() + tag_size;
+
+	assert!(sealer(key, nonce, input, Some(aad), &mut dst_out[..exact]).is_ok());
+	assert!(sealer(key, nonce, input, Some(aad), &mut dst_out).is_ok());
+	assert_eq!(
+		sealer(key, nonce, input, Some(aad), &mut dst_out[..exact - 1]),
+		Err(UnknownCryptoError::InvalidLength)
+	);
+	assert_eq!(
+		sealer(key, nonce, input, Some(aad), &mut [0u8; 0]),
+		Err(UnknownCryptoError::InvalidLength)
+	);
+}
+
+/// Opening requires `dst_out` to hold at least the ciphertext without the tag.
+fn open_dst_out_length<Opener, Key, Nonce>(
+	opener: &Opener,
+	key: &Key,
+	nonce: &Nonce,
+	ct_with_tag: &[u8],
+	tag_size: usize,
+	aad: &[u8],
+) where
+	Opener: Fn(&Key, &Nonce, &[u8], Option<&[u8]>, &mut [u8]) -> Result<(), UnknownCryptoError>,
+{
+	let mut dst_out = vec![0u8; ct_with_tag.len() - tag_size + 1];
+	let exact = ct_with_tag.len() - tag_size;
+
+	assert!(opener(key, nonce, ct_with_tag, Some(aad), &mut dst_out[..exact]).is_ok());
+	assert!(opener(key, nonce, ct_with_tag, Some(aad), &mut dst_out).is_ok());
+	assert_eq!(
+		opener(
+			key,
+			nonce,
+			ct_with_tag,
+			Some(aad),
+			&mut dst_out[..exact - 1]
+		),
+		Err(UnknownCryptoError::InvalidLength)
+	);
+	assert_eq!(
+		opener(key, nonce, ct_with_tag, Some(aad), &mut [0u8; 0]),
+		Err(UnknownCryptoError::InvalidLength)
+	);
+}
+
+/// Flipping a bit in any byte of the tag must fail verification.
+fn open_modified_tag_err<Opener, Key, Nonce>(
+	opener: &Opener,
+	key: &Key,
+	nonce: &Nonce,
+	ct_with_tag: &[u8],
+	tag_size: usize,
+	aad: &[u8],
+) where
+	Opener: Fn(&Key, &Nonce, &[u8], Option<&[u8]>, &mut [u8]) -> Result<(), UnknownCryptoError>,
+{
+	let mut modified = ct_with_tag.to_vec();
+	let mut dst_out = vec![0u8; ct_with_tag.len() - tag_size];
+
+	for idx in (ct_with_tag.len() - tag_size)..ct_with_tag.len() {
+		modified[idx] ^= 1;
+		assert_eq!(
+			opener(key, nonce, &modified, Some(aad), &mut dst_out),
+			Err(UnknownCryptoError::InvalidTag)
+		);
+		modified[idx] ^= 1;
+	}
+}
+
+/// Flipping a bit in the first or the last byte of the ciphertext must fail
+/// verification.
+fn open_modified_ciphertext_err<Opener, Key, Nonce>(
+	opener: &Opener,
+	key: &Key,
+	nonce: &Nonce,
+	ct_with_tag: &[u8],
+	tag_size: usize,
+	aad: &[u8],
+) where
+	Opener: Fn(&Key, &Nonce, &[u8], Option<&[u8]>, &mut [u8]) -> Result<(), UnknownCryptoError>,
+{
+	let ct_len = ct_with_tag.len() - tag_size;
+	let mut dst_out = vec![0u8; ct_len];
+
+	for idx in &[0, ct_len - 1] {
+		let mut modified = ct_with_tag.to_vec();
+		modified[*idx] ^= 1;
+		assert_eq!(
+			opener(key, nonce, &modified, Some(aad), &mut dst_out),
+			Err(UnknownCryptoError::InvalidTag)
+		);
+	}
+}
+
+/// Opening with different additional data than was used for sealing must fail
+/// verification.
+fn open_modified_aad_err<Opener, Key, Nonce>(
+	opener: &Opener,
+	key: &Key,
+	nonce: &Nonce,
+	ct_with_tag: &[u8],
+	tag_size: usize,
+	aad: &[u8],
+) where
+	Opener: Fn(&Key, &Nonce, &[u8], Option<&[u8]>, &mut [u8]) -> Result<(), UnknownCryptoError>,
+{
+	let mut dst_out = vec![0u8; ct_with_tag.len() - tag_size];
+
+	let mut appended = aad.to_vec();
+	appended.push(0);
+	assert_eq!(
+		opener(key, nonce, ct_with_tag, Some(&appended), &mut dst_out),
+		Err(UnknownCryptoError::InvalidTag)
+	);
+
+	if !aad.is_empty() {
+		let mut modified = aad.to_vec();
+		modified[0] ^= 1;
+		assert_eq!(
+			opener(key, nonce, ct_with_tag, Some(&modified), &mut dst_out),
+			Err(UnknownCryptoError::InvalidTag)
+		);
+		assert_eq!(
+			opener(key, nonce, ct_with_tag, None, &mut dst_out),
+			Err(UnknownCryptoError::InvalidTag)
+		);
+		assert_eq!(
+			opener(key, nonce, ct_with_tag, Some(&aad[1..]), &mut dst_out),
+			Err(UnknownCryptoError::InvalidTag)
+		);
+	}
+}
+
+/// Dropping bytes from the end of the ciphertext must be rejected, either as
+/// too short or as failing verification.
+fn open_truncated_err<Opener, Key, Nonce>(
+	opener: &Opener,
+	key: &Key,
+	nonce: &Nonce,
+	ct_with_tag: &[u8],
+	tag_size: usize,
+	aad: &[u8],
+) where
+	Opener: Fn(&Key, &Nonce, &[u8], Option<&[u8]>, &mut [u8]) -> Result<(), UnknownCryptoError>,
+{
+	let mut dst_out = vec![0u8; ct_with_tag.len()];
+
+	assert_eq!(
+		opener(
+			key,
+			nonce,
+			&ct_with_tag[..tag_size],
+			Some(aad),
+			&mut dst_out
+		),
+		Err(UnknownCryptoError::InvalidLength)
+	);
+	assert_eq!(
+		opener(key, nonce, &[0u8; 0], Some(aad), &mut dst_out),
+		Err(UnknownCryptoError::InvalidLength)
+	);
+	if ct_with_tag.len() - 1 > tag_size {
+		assert_eq!(
+			opener(
+				key,
+				nonce,
+				&ct_with_tag[..ct_with_tag.len() - 1],
+				Some(aad),
+				&mut dst_out
+			),
+			Err(UnknownCryptoError::InvalidTag)
+		);
+	}
+}
+
+/// Opening with a different key than was used for sealing must fail
+/// verification.
+fn open_wrong_key_err<Opener, Key, Nonce>(
+	opener: &Opener,
+	wrong_key: &Key,
+	nonce: &Nonce,
+	ct_with_tag: &[u8],
+	tag_size: usize,
+	aad: &[u8],
+) where
+	Opener: Fn(&Key, &Nonce, &[u8], Option<&[u8]>, &mut [u8]) -> Result<(), UnknownCryptoError>,
+{
+	let mut dst_out = vec![0u8; ct_with_tag.len() - tag_size];
+
+	assert_eq!(
+		opener(wrong_key, nonce, ct_with_tag, Some(aad), &mut dst_out),
+		Err(UnknownCryptoError::InvalidTag)
+	);
+}
+
+/// Empty plaintexts cannot be sealed, and a ciphertext that only holds a tag
+/// cannot be opened.
+fn empty_input_err<Sealer, Opener, Key, Nonce>(
+	sealer: &Sealer,
+	opener: &Opener,
+	key: &Key,
+	nonce: &Nonce,
+	tag_size: usize,
+	aad: &[u8],
+) where
+	Sealer: Fn(&Key, &Nonce, &[u8], Option<&[u8]>, &mut [u8]) -> Result<(), UnknownCryptoError>,
+	Opener: Fn(&Key, &Nonce, &[u8], Option<&[u8]>, &mut [u8]) -> Result<(), UnknownCryptoError>,
+{
+	let mut dst_out = vec![0u8; tag_size];
+
+	assert_eq!(
+		sealer(key, nonce, &[0u8; 0], Some(aad), &mut dst_out),
+		Err(UnknownCryptoError::InvalidLength)
+	);
+	assert_eq!(
+		sealer(key, nonce, &[0u8; 0], None, &mut dst_out),
+		Err(UnknownCryptoError::InvalidLength)
+	);
+	assert_eq!(
+		opener(key, nonce, &vec![0u8; tag_size], Some(aad), &mut dst_out),
+		Err(UnknownCryptoError::InvalidLength)
+	);
+	assert_eq!(
+		opener(key, nonce, &[0u8; 0], None, &mut dst_out),
+		Err(UnknownCryptoError::InvalidLength)
+	);
+}
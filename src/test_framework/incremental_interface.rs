@@ -0,0 +1,205 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::errors::UnknownCryptoError;
+use core::{fmt::Debug, marker::PhantomData};
+
+/// A streaming state, such as that of a hash function or a MAC, that can be
+/// tested with [`StreamingContextConsistencyTester`].
+///
+/// [`StreamingContextConsistencyTester`]: struct.StreamingContextConsistencyTester.html
+pub trait TestableStreamingContext<T: PartialEq + Debug>: Clone {
+	/// Reset to the initial state.
+	fn reset(&mut self) -> Result<(), UnknownCryptoError>;
+	/// Update the state with `input`.
+	fn update(&mut self, input: &[u8]) -> Result<(), UnknownCryptoError>;
+	/// Return the result of the streaming state.
+	fn finalize(&mut self) -> Result<T, UnknownCryptoError>;
+	/// Assert that all fields of `state_1` and `state_2` are equal.
+	fn compare_states(state_1: &Self, state_2: &Self);
+}
+
+/// Test runner for streaming states, checking that different usage patterns
+/// of `reset()`, `update()` and `finalize()` give the same state and result,
+/// and that the result equals that of the one-shot function.
+pub struct StreamingContextConsistencyTester<R, T, F> {
+	_return_type: PhantomData<R>,
+	// The initial context to base all calls upon.
+	initial_context: T,
+	one_shot: F,
+	blocksize: usize,
+}
+
+impl<R, T, F> StreamingContextConsistencyTester<R, T, F>
+where
+	R: PartialEq + Debug,
+	T: TestableStreamingContext<R>,
+	F: Fn(&[u8]) -> Result<R, UnknownCryptoError>,
+{
+	/// The streaming state `initial_context` should be freshly initialized,
+	/// with the same parameters as those used by `one_shot`.
+	pub fn new(initial_context: T, one_shot: F, blocksize: usize) -> Self {
+		Self {
+			_return_type: PhantomData,
+			initial_context,
+			one_shot,
+			blocksize,
+		}
+	}
+
+	/// Run all tests with input of every length up to a little over two
+	/// blocks.
+	pub fn run_all_tests(&self) {
+		for len in 0..=(self.blocksize * 2 + 1) {
+			let data: Vec<u8> = (0..len).map(|i| i as u8).collect();
+			self.run_all_tests_property(&data);
+		}
+	}
+
+	/// Run all tests with `data` as input.
+	pub fn run_all_tests_property(&self, data: &[u8]) {
+		self.produces_same_result(data);
+		self.produces_same_state(data);
+		self.incremental_processing_same_as_one_shot(data);
+		self.finalized_state_err(data);
+	}
+
+	/// Related bug: https://github.com/brycx/orion/issues/46
+	/// Different usage combinations of `reset()`, `update()` and `finalize()`
+	/// produce the same result.
+	fn produces_same_result(&self, data: &[u8]) {
+		// update(), finalize()
+		let mut state_1 = self.initial_context.clone();
+		state_1.update(data).unwrap();
+		let res_1 = state_1.finalize().unwrap();
+
+		// reset(), update(), finalize()
+		let mut state_2 = self.initial_context.clone();
+		state_2.reset().unwrap();
+		state_2.update(data).unwrap();
+		let res_2 = state_2.finalize().unwrap();
+
+		// update(), reset(), update(), finalize()
+		let mut state_3 = self.initial_context.clone();
+		state_3.update(data).unwrap();
+		state_3.reset().unwrap();
+		state_3.update(data).unwrap();
+		let res_3 = state_3.finalize().unwrap();
+
+		// update(), finalize(), reset(), update(), finalize()
+		let mut state_4 = self.initial_context.clone();
+		state_4.update(data).unwrap();
+		let _ = state_4.finalize().unwrap();
+		state_4.reset().unwrap();
+		state_4.update(data).unwrap();
+		let res_4 = state_4.finalize().unwrap();
+
+		assert_eq!(res_1, res_2);
+		assert_eq!(res_2, res_3);
+		assert_eq!(res_3, res_4);
+
+		// Returning Ok() on an empty update() must be the same as not calling
+		// update() at all.
+		if data.is_empty() {
+			// finalize()
+			let mut state_5 = self.initial_context.clone();
+			let res_5 = state_5.finalize().unwrap();
+
+			// reset(), finalize()
+			let mut state_6 = self.initial_context.clone();
+			state_6.reset().unwrap();
+			let res_6 = state_6.finalize().unwrap();
+
+			// update(), reset(), finalize()
+			let mut state_7 = self.initial_context.clone();
+			state_7.update(b"Wrong data").unwrap();
+			state_7.reset().unwrap();
+			let res_7 = state_7.finalize().unwrap();
+
+			assert_eq!(res_4, res_5);
+			assert_eq!(res_5, res_6);
+			assert_eq!(res_6, res_7);
+		}
+	}
+
+	/// Related bug: https://github.com/brycx/orion/issues/46
+	/// Different usage combinations of `reset()`, `update()` and `finalize()`
+	/// produce the same state.
+	fn produces_same_state(&self, data: &[u8]) {
+		let state_1 = self.initial_context.clone();
+
+		// reset()
+		let mut state_2 = self.initial_context.clone();
+		state_2.reset().unwrap();
+
+		// update(), reset()
+		let mut state_3 = self.initial_context.clone();
+		state_3.update(data).unwrap();
+		state_3.reset().unwrap();
+
+		// update(), finalize(), reset()
+		let mut state_4 = self.initial_context.clone();
+		state_4.update(data).unwrap();
+		let _ = state_4.finalize().unwrap();
+		state_4.reset().unwrap();
+
+		T::compare_states(&state_1, &state_2);
+		T::compare_states(&state_2, &state_3);
+		T::compare_states(&state_3, &state_4);
+	}
+
+	/// Processing `data` in chunks around the blocksize, with empty updates in
+	/// between, gives the same result as the one-shot function.
+	fn incremental_processing_same_as_one_shot(&self, data: &[u8]) {
+		let expected = (self.one_shot)(data).unwrap();
+
+		for chunk_size in &[
+			1,
+			self.blocksize - 1,
+			self.blocksize,
+			self.blocksize + 1,
+			self.blocksize * 2,
+		] {
+			let mut state = self.initial_context.clone();
+			for chunk in data.chunks(*chunk_size) {
+				state.update(chunk).unwrap();
+				state.update(b"").unwrap();
+			}
+			assert_eq!(state.finalize().unwrap(), expected);
+		}
+	}
+
+	/// A finalized state cannot be updated or finalized again until it has
+	/// been reset.
+	fn finalized_state_err(&self, data: &[u8]) {
+		let mut state = self.initial_context.clone();
+		state.update(data).unwrap();
+		let _ = state.finalize().unwrap();
+
+		assert_eq!(state.finalize(), Err(UnknownCryptoError::FinalizedState));
+		assert_eq!(state.update(data), Err(UnknownCryptoError::FinalizedState));
+		assert_eq!(state.update(b""), Err(UnknownCryptoError::FinalizedState));
+
+		state.reset().unwrap();
+		assert_eq!(state.finalize().unwrap(), (self.one_shot)(b"").unwrap());
+	}
+}
@@ -0,0 +1,154 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::errors::UnknownCryptoError;
+
+#[allow(non_snake_case)]
+/// Test runner for KDFs, generic over `derive`, which takes
+/// `(ikm, salt, info, dst_out)`. For KDFs without an `info` parameter, such
+/// as PBKDF2, `info` should be `None` and `derive` is called with an empty
+/// `info`.
+///
+/// Checks:
+/// - That the length of `dst_out` is accepted within `min_len..=max_len` and
+///   rejected outside it. `max_len` is `None` if the limit is too large to
+///   test.
+/// - That the output is deterministic and overwrites all of `dst_out`.
+/// - That a different `ikm`, `salt` or `info` gives a different output.
+pub fn KdfTestRunner<Derive>(
+	derive: Derive,
+	ikm: &[u8],
+	salt: &[u8],
+	info: Option<&[u8]>,
+	min_len: usize,
+	max_len: Option<usize>,
+) where
+	Derive: Fn(&[u8], &[u8], &[u8], &mut [u8]) -> Result<(), UnknownCryptoError>,
+{
+	assert!(!ikm.is_empty() && !salt.is_empty());
+
+	output_length_bounds(&derive, ikm, salt, info, min_len, max_len);
+	for len in &[min_len, 16, 32, 64, 65, 128, 129] {
+		if *len >= min_len && max_len.map_or(true, |max| *len <= max) {
+			deterministic(&derive, ikm, salt, info, *len);
+			// Shorter outputs can collide by chance.
+			if *len >= 16 {
+				distinct_input_distinct_output(&derive, ikm, salt, info, *len);
+			}
+		}
+	}
+}
+
+/// Lengths of `dst_out` within `min_len..=max_len` are accepted, all others
+/// are rejected with [`UnknownCryptoError::InvalidLength`].
+///
+/// [`UnknownCryptoError::InvalidLength`]: ../../errors/enum.UnknownCryptoError.html#variant.InvalidLength
+fn output_length_bounds<Derive>(
+	derive: &Derive,
+	ikm: &[u8],
+	salt: &[u8],
+	info: Option<&[u8]>,
+	min_len: usize,
+	max_len: Option<usize>,
+) where
+	Derive: Fn(&[u8], &[u8], &[u8], &mut [u8]) -> Result<(), UnknownCryptoError>,
+{
+	let info = info.unwrap_or(&[]);
+
+	assert_eq!(
+		derive(ikm, salt, info, &mut [0u8; 0]),
+		Err(UnknownCryptoError::InvalidLength)
+	);
+	if min_len > 1 {
+		assert_eq!(
+			derive(ikm, salt, info, &mut vec![0u8; min_len - 1]),
+			Err(UnknownCryptoError::InvalidLength)
+		);
+	}
+	assert!(derive(ikm, salt, info, &mut vec![0u8; min_len]).is_ok());
+
+	if let Some(max) = max_len {
+		assert!(derive(ikm, salt, info, &mut vec![0u8; max]).is_ok());
+		assert_eq!(
+			derive(ikm, salt, info, &mut vec![0u8; max + 1]),
+			Err(UnknownCryptoError::InvalidLength)
+		);
+	}
+}
+
+/// The same parameters always give the same output, regardless of what
+/// `dst_out` held before.
+fn deterministic<Derive>(derive: &Derive, ikm: &[u8], salt: &[u8], info: Option<&[u8]>, len: usize)
+where
+	Derive: Fn(&[u8], &[u8], &[u8], &mut [u8]) -> Result<(), UnknownCryptoError>,
+{
+	let info = info.unwrap_or(&[]);
+	let mut dst_zeroes = vec![0u8; len];
+	let mut dst_ones = vec![0xffu8; len];
+
+	derive(ikm, salt, info, &mut dst_zeroes).unwrap();
+	derive(ikm, salt, info, &mut dst_ones).unwrap();
+	assert_eq!(dst_zeroes, dst_ones);
+}
+
+/// Modifying any of `ikm`, `salt` or `info` changes the output.
+fn distinct_input_distinct_output<Derive>(
+	derive: &Derive,
+	ikm: &[u8],
+	salt: &[u8],
+	info: Option<&[u8]>,
+	len: usize,
+) where
+	Derive: Fn(&[u8], &[u8], &[u8], &mut [u8]) -> Result<(), UnknownCryptoError>,
+{
+	let mut expected = vec![0u8; len];
+	derive(ikm, salt, info.unwrap_or(&[]), &mut expected).unwrap();
+
+	let mut dst_out = vec![0u8; len];
+
+	// Only the first byte is modified, since for example HMAC-based KDFs pad
+	// a short key with zeroes, so appending a zero to `ikm` would not change
+	// the output.
+	let mut modified_ikm = ikm.to_vec();
+	modified_ikm[0] ^= 1;
+	derive(&modified_ikm, salt, info.unwrap_or(&[]), &mut dst_out).unwrap();
+	assert_ne!(dst_out, expected);
+
+	let mut modified_salt = salt.to_vec();
+	modified_salt[0] ^= 1;
+	derive(ikm, &modified_salt, info.unwrap_or(&[]), &mut dst_out).unwrap();
+	assert_ne!(dst_out, expected);
+
+	if let Some(info) = info {
+		let mut modified_info = info.to_vec();
+		modified_info.push(0);
+		derive(ikm, salt, &modified_info, &mut dst_out).unwrap();
+		assert_ne!(dst_out, expected);
+
+		if !info.is_empty() {
+			modified_info.pop();
+			modified_info[0] ^= 1;
+			derive(ikm, salt, &modified_info, &mut dst_out).unwrap();
+			assert_ne!(dst_out, expected);
+		}
+	}
+}
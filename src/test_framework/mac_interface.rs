@@ -0,0 +1,184 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::errors::UnknownCryptoError;
+use crate::test_framework::incremental_interface::{
+	StreamingContextConsistencyTester, TestableStreamingContext,
+};
+use core::fmt::Debug;
+
+/// A MAC whose streaming state and one-shot functions can be tested with
+/// [`MacTester`].
+///
+/// [`MacTester`]: struct.MacTester.html
+pub trait TestableMac: TestableStreamingContext<<Self as TestableMac>::Tag> {
+	/// The secret key type.
+	type Key;
+	/// The tag type.
+	type Tag: PartialEq + Debug;
+
+	/// Initialize a streaming state with `key`.
+	fn init(key: &Self::Key) -> Self;
+	/// One-shot function for generating a tag of `input`.
+	fn mac(key: &Self::Key, input: &[u8]) -> Result<Self::Tag, UnknownCryptoError>;
+	/// Verify `expected` against the tag of `input`.
+	fn verify(
+		expected: &Self::Tag,
+		key: &Self::Key,
+		input: &[u8],
+	) -> Result<(), UnknownCryptoError>;
+	/// Construct a tag from bytes.
+	fn tag_from_slice(slice: &[u8]) -> Result<Self::Tag, UnknownCryptoError>;
+	/// The bytes of `tag`.
+	fn tag_as_bytes(tag: &Self::Tag) -> &[u8];
+}
+
+/// Test runner for MACs. Checks the streaming state with
+/// [`StreamingContextConsistencyTester`], and that verification accepts only
+/// the correct tag for the correct key and input, and that truncated tags are
+/// rejected.
+///
+/// [`StreamingContextConsistencyTester`]: ../incremental_interface/struct.StreamingContextConsistencyTester.html
+pub struct MacTester<M: TestableMac> {
+	key: M::Key,
+	wrong_key: M::Key,
+	blocksize: usize,
+}
+
+impl<M: TestableMac> MacTester<M> {
+	/// `key` and `wrong_key` must be different.
+	pub fn new(key: M::Key, wrong_key: M::Key, blocksize: usize) -> Self {
+		Self {
+			key,
+			wrong_key,
+			blocksize,
+		}
+	}
+
+	/// Run all tests with input of every length up to a little over two
+	/// blocks.
+	pub fn run_all_tests(&self) {
+		self.streaming_tester().run_all_tests();
+		for len in 0..=(self.blocksize * 2 + 1) {
+			let data: Vec<u8> = (0..len).map(|i| i as u8).collect();
+			self.run_verification_tests(&data);
+		}
+		self.truncated_tag_err();
+	}
+
+	/// Run all tests with `data` as input.
+	pub fn run_all_tests_property(&self, data: &[u8]) {
+		self.streaming_tester().run_all_tests_property(data);
+		self.run_verification_tests(data);
+		self.truncated_tag_err();
+	}
+
+	fn streaming_tester(
+		&self,
+	) -> StreamingContextConsistencyTester<
+		M::Tag,
+		M,
+		impl Fn(&[u8]) -> Result<M::Tag, UnknownCryptoError> + '_,
+	> {
+		StreamingContextConsistencyTester::new(
+			M::init(&self.key),
+			move |input| M::mac(&self.key, input),
+			self.blocksize,
+		)
+	}
+
+	fn run_verification_tests(&self, data: &[u8]) {
+		let tag = M::mac(&self.key, data).unwrap();
+
+		self.verify_same_params_ok(&tag, data);
+		self.verify_modified_tag_err(&tag, data);
+		self.verify_wrong_key_err(&tag, data);
+		self.verify_modified_input_err(&tag, data);
+	}
+
+	/// The tag from `mac()` verifies, and equals the tag from the streaming
+	/// state.
+	fn verify_same_params_ok(&self, tag: &M::Tag, data: &[u8]) {
+		assert!(M::verify(tag, &self.key, data).is_ok());
+
+		let mut state = M::init(&self.key);
+		state.update(data).unwrap();
+		assert_eq!(&state.finalize().unwrap(), tag);
+	}
+
+	/// Flipping a bit in any byte of the tag fails verification.
+	fn verify_modified_tag_err(&self, tag: &M::Tag, data: &[u8]) {
+		let mut modified = M::tag_as_bytes(tag).to_vec();
+		for idx in 0..modified.len() {
+			modified[idx] ^= 1;
+			let bad_tag = M::tag_from_slice(&modified).unwrap();
+			assert_eq!(
+				M::verify(&bad_tag, &self.key, data),
+				Err(UnknownCryptoError::InvalidTag)
+			);
+			modified[idx] ^= 1;
+		}
+	}
+
+	/// A tag does not verify under a different key.
+	fn verify_wrong_key_err(&self, tag: &M::Tag, data: &[u8]) {
+		assert_eq!(
+			M::verify(tag, &self.wrong_key, data),
+			Err(UnknownCryptoError::InvalidTag)
+		);
+	}
+
+	/// A tag does not verify for modified input.
+	fn verify_modified_input_err(&self, tag: &M::Tag, data: &[u8]) {
+		let mut modified = data.to_vec();
+		modified.push(0);
+		assert_eq!(
+			M::verify(tag, &self.key, &modified),
+			Err(UnknownCryptoError::InvalidTag)
+		);
+
+		if !data.is_empty() {
+			modified.pop();
+			modified[0] ^= 1;
+			assert_eq!(
+				M::verify(tag, &self.key, &modified),
+				Err(UnknownCryptoError::InvalidTag)
+			);
+			assert_eq!(
+				M::verify(tag, &self.key, &data[..data.len() - 1]),
+				Err(UnknownCryptoError::InvalidTag)
+			);
+		}
+	}
+
+	/// A truncated tag cannot be constructed, so it can never be verified.
+	fn truncated_tag_err(&self) {
+		let tag = M::mac(&self.key, b"").unwrap();
+		let bytes = M::tag_as_bytes(&tag);
+		for len in 0..bytes.len() {
+			assert_eq!(
+				M::tag_from_slice(&bytes[..len]).err(),
+				Some(UnknownCryptoError::InvalidLength)
+			);
+		}
+	}
+}
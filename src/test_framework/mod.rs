@@ -0,0 +1,98 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Test runners shared between the tests of primitives with the same
+//! interface.
+//!
+//! # About:
+//! - This module requires the `testing` feature. orion's own tests always have
+//!   it available.
+//! - Each runner checks a set of properties that any implementation of the
+//!   interface must have, such as a streaming state giving the same result as
+//!   the one-shot function, or a MAC rejecting modified tags. Crates that wrap
+//!   orion's primitives, or implement a primitive with the same interface, can
+//!   run them against their own types.
+//! - The runners are only meant to be used in tests. A failed check panics.
+//! - The runners, the `Testable*` traits and what they check follow orion's
+//!   semver guarantees like the rest of the public API. New checks may be added
+//!   in minor versions, but only for properties the interface already
+//!   documents.
+//!
+//! # Example:
+//! ```rust
+//! use orion::errors::UnknownCryptoError;
+//! use orion::hazardous::hash::sha512::{self, Digest, Sha512, SHA512_BLOCKSIZE};
+//! use orion::test_framework::incremental_interface::{
+//! 	StreamingContextConsistencyTester, TestableStreamingContext,
+//! };
+//!
+//! // A wrapper around a streaming SHA-512 state.
+//! #[derive(Clone)]
+//! struct Wrapper(Sha512);
+//!
+//! impl TestableStreamingContext<Digest> for Wrapper {
+//! 	fn reset(&mut self) -> Result<(), UnknownCryptoError> {
+//! 		self.0.reset();
+//! 		Ok(())
+//! 	}
+//!
+//! 	fn update(&mut self, input: &[u8]) -> Result<(), UnknownCryptoError> {
+//! 		self.0.update(input)
+//! 	}
+//!
+//! 	fn finalize(&mut self) -> Result<Digest, UnknownCryptoError> {
+//! 		self.0.finalize()
+//! 	}
+//!
+//! 	fn compare_states(state_1: &Wrapper, state_2: &Wrapper) {
+//! 		assert_eq!(
+//! 			state_1.clone().finalize().ok(),
+//! 			state_2.clone().finalize().ok()
+//! 		);
+//! 	}
+//! }
+//!
+//! let tester = StreamingContextConsistencyTester::<Digest, _, _>::new(
+//! 	Wrapper(sha512::init()),
+//! 	sha512::digest,
+//! 	SHA512_BLOCKSIZE,
+//! );
+//! tester.run_all_tests();
+//! ```
+
+/// Test runner for AEADs.
+pub mod aead_interface;
+
+/// Test runner for streaming states.
+pub mod incremental_interface;
+
+/// Test runner for MACs.
+pub mod mac_interface;
+
+/// Test runner for KDFs.
+pub mod kdf_interface;
+
+/// Test runner for XOFs.
+pub mod xof_interface;
+
+/// Test runner for chunked streaming AEADs.
+pub mod streaming_aead_interface;
@@ -0,0 +1,216 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::errors::UnknownCryptoError;
+
+/// The sealing side of a chunked streaming AEAD that can be tested with
+/// [`StreamingAeadTester`].
+///
+/// [`StreamingAeadTester`]: struct.StreamingAeadTester.html
+pub trait TestableStreamSealer {
+	/// Seal the next chunk of the stream. `last` marks the final chunk, after
+	/// which sealing must fail with `FinalizedState`.
+	fn seal_chunk(&mut self, plaintext: &[u8], last: bool) -> Result<Vec<u8>, UnknownCryptoError>;
+}
+
+/// The opening side of a chunked streaming AEAD that can be tested with
+/// [`StreamingAeadTester`].
+///
+/// [`StreamingAeadTester`]: struct.StreamingAeadTester.html
+pub trait TestableStreamOpener {
+	/// Open the next chunk of the stream, returning its plaintext and whether
+	/// it was the final chunk. After the final chunk, opening must fail with
+	/// `FinalizedState`.
+	fn open_chunk(&mut self, sealed: &[u8]) -> Result<(Vec<u8>, bool), UnknownCryptoError>;
+}
+
+/// Test runner for chunked streaming AEADs, checking that reordered, replayed,
+/// truncated and spliced streams are detected, that the recovered plaintext
+/// does not depend on where the chunk boundaries are, and that a stream
+/// cannot be continued after its final chunk.
+pub struct StreamingAeadTester<F> {
+	// Starts a new stream, with a different nonce or key on every call.
+	new_stream: F,
+	blocksize: usize,
+}
+
+impl<S, O, F> StreamingAeadTester<F>
+where
+	S: TestableStreamSealer,
+	O: TestableStreamOpener,
+	F: Fn() -> (S, O),
+{
+	/// `new_stream` returns a sealer and the opener for the stream it seals.
+	/// Two calls must never return sealers for the same stream.
+	pub fn new(new_stream: F, blocksize: usize) -> Self {
+		Self {
+			new_stream,
+			blocksize,
+		}
+	}
+
+	/// Run all tests with input of every length up to a little over two
+	/// blocks.
+	pub fn run_all_tests(&self) {
+		for len in 0..=(self.blocksize * 2 + 1) {
+			let data: Vec<u8> = (0..len).map(|i| i as u8).collect();
+			self.run_all_tests_property(&data);
+		}
+	}
+
+	/// Run all tests with `data` as the plaintext of the stream.
+	pub fn run_all_tests_property(&self, data: &[u8]) {
+		self.finalized_stream_err();
+		// Empty chunks are not sealed, so there is no stream to test for
+		// empty input.
+		if data.is_empty() {
+			return;
+		}
+
+		self.chunk_boundaries_independent(data);
+		self.reordered_chunks_err(data);
+		self.truncated_stream_detected(data);
+		self.modified_chunk_err(data);
+		self.spliced_streams_err(data);
+	}
+
+	fn chunk_sizes(&self) -> [usize; 5] {
+		[
+			1,
+			self.blocksize - 1,
+			self.blocksize,
+			self.blocksize + 1,
+			self.blocksize * 2,
+		]
+	}
+
+	/// Seal `data` in chunks of `chunk_size` with `sealer`, marking the last
+	/// one as final.
+	fn seal_stream(sealer: &mut S, data: &[u8], chunk_size: usize) -> Vec<Vec<u8>> {
+		let count = (data.len() + chunk_size - 1) / chunk_size;
+		data.chunks(chunk_size)
+			.enumerate()
+			.map(|(idx, chunk)| sealer.seal_chunk(chunk, idx == count - 1).unwrap())
+			.collect()
+	}
+
+	/// Whatever the chunk size, opening all chunks in order recovers `data`,
+	/// and only the last chunk is reported as final.
+	fn chunk_boundaries_independent(&self, data: &[u8]) {
+		for chunk_size in self.chunk_sizes().iter() {
+			let (mut sealer, mut opener) = (self.new_stream)();
+			let sealed = Self::seal_stream(&mut sealer, data, *chunk_size);
+
+			let mut opened = Vec::new();
+			for (idx, chunk) in sealed.iter().enumerate() {
+				let (plaintext, last) = opener.open_chunk(chunk).unwrap();
+				assert_eq!(last, idx == sealed.len() - 1);
+				opened.extend_from_slice(&plaintext);
+			}
+			assert_eq!(opened, data);
+		}
+	}
+
+	/// Chunks opened out of order, or a second time, are rejected.
+	fn reordered_chunks_err(&self, data: &[u8]) {
+		let (mut sealer, mut opener) = (self.new_stream)();
+		let sealed = Self::seal_stream(&mut sealer, data, 1);
+		if sealed.len() < 2 {
+			return;
+		}
+
+		assert!(opener.open_chunk(&sealed[1]).is_err());
+		assert!(opener.open_chunk(&sealed[sealed.len() - 1]).is_err());
+		opener.open_chunk(&sealed[0]).unwrap();
+		assert!(opener.open_chunk(&sealed[0]).is_err());
+	}
+
+	/// Dropping chunks at the end of a stream leaves no chunk reported as
+	/// final, so the truncation can be detected, and a chunk cut short is
+	/// rejected.
+	fn truncated_stream_detected(&self, data: &[u8]) {
+		let (mut sealer, mut opener) = (self.new_stream)();
+		let sealed = Self::seal_stream(&mut sealer, data, 1);
+
+		for chunk in sealed[..sealed.len() - 1].iter() {
+			let (_, last) = opener.open_chunk(chunk).unwrap();
+			assert!(!last);
+		}
+
+		let final_chunk = &sealed[sealed.len() - 1];
+		assert!(opener
+			.open_chunk(&final_chunk[..final_chunk.len() - 1])
+			.is_err());
+		assert!(opener.open_chunk(&[]).is_err());
+		let (_, last) = opener.open_chunk(final_chunk).unwrap();
+		assert!(last);
+	}
+
+	/// A chunk with any byte modified is rejected.
+	fn modified_chunk_err(&self, data: &[u8]) {
+		let (mut sealer, _) = (self.new_stream)();
+		let sealed = Self::seal_stream(&mut sealer, data, self.blocksize);
+
+		for pos in 0..sealed[0].len() {
+			let (_, mut opener) = (self.new_stream)();
+			let mut modified = sealed[0].clone();
+			modified[pos] ^= 1;
+			assert!(opener.open_chunk(&modified).is_err());
+		}
+	}
+
+	/// A chunk of one stream cannot be opened as part of another.
+	fn spliced_streams_err(&self, data: &[u8]) {
+		let (mut first_sealer, _) = (self.new_stream)();
+		let (mut second_sealer, mut second_opener) = (self.new_stream)();
+		let first = Self::seal_stream(&mut first_sealer, data, 1);
+		let second = Self::seal_stream(&mut second_sealer, data, 1);
+
+		assert!(second_opener.open_chunk(&first[0]).is_err());
+		second_opener.open_chunk(&second[0]).unwrap();
+		if first.len() > 1 {
+			assert!(second_opener.open_chunk(&first[1]).is_err());
+		}
+	}
+
+	/// Neither side of a stream can be used after the final chunk.
+	fn finalized_stream_err(&self) {
+		let (mut sealer, mut opener) = (self.new_stream)();
+		let final_chunk = sealer.seal_chunk(&[0u8], true).unwrap();
+		assert_eq!(
+			sealer.seal_chunk(&[0u8], false),
+			Err(UnknownCryptoError::FinalizedState)
+		);
+		assert_eq!(
+			sealer.seal_chunk(&[0u8], true),
+			Err(UnknownCryptoError::FinalizedState)
+		);
+
+		let (plaintext, last) = opener.open_chunk(&final_chunk).unwrap();
+		assert_eq!(plaintext, [0u8]);
+		assert!(last);
+		assert_eq!(
+			opener.open_chunk(&final_chunk),
+			Err(UnknownCryptoError::FinalizedState)
+		);
+	}
+}
@@ -0,0 +1,243 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::errors::UnknownCryptoError;
+
+/// An extendable-output function that can be tested with [`XofTester`].
+///
+/// [`XofTester`]: struct.XofTester.html
+pub trait TestableXof: Clone {
+	/// Absorb `input`. This can be called multiple times before the first
+	/// call to `squeeze()`.
+	fn absorb(&mut self, input: &[u8]) -> Result<(), UnknownCryptoError>;
+	/// Fill `dst_out` with the next bytes of output. This can be called
+	/// multiple times.
+	fn squeeze(&mut self, dst_out: &mut [u8]) -> Result<(), UnknownCryptoError>;
+}
+
+/// Test runner for XOFs, checking that the output does not depend on how the
+/// input is absorbed or how the output is squeezed, and that it equals that of
+/// the one-shot function.
+pub struct XofTester<X, F> {
+	// The initial state to base all calls upon.
+	initial_state: X,
+	one_shot: F,
+	blocksize: usize,
+}
+
+impl<X, F> XofTester<X, F>
+where
+	X: TestableXof,
+	F: Fn(&[u8], &mut [u8]) -> Result<(), UnknownCryptoError>,
+{
+	/// The state `initial_state` should be freshly initialized, with the same
+	/// parameters as those used by `one_shot`, which takes `(input, dst_out)`.
+	pub fn new(initial_state: X, one_shot: F, blocksize: usize) -> Self {
+		Self {
+			initial_state,
+			one_shot,
+			blocksize,
+		}
+	}
+
+	/// Run all tests with input of every length up to a little over two
+	/// blocks.
+	pub fn run_all_tests(&self) {
+		for len in 0..=(self.blocksize * 2 + 1) {
+			let data: Vec<u8> = (0..len).map(|i| i as u8).collect();
+			self.run_all_tests_property(&data);
+		}
+	}
+
+	/// Run all tests with `data` as input.
+	pub fn run_all_tests_property(&self, data: &[u8]) {
+		self.squeeze_chunked_same_as_one_shot(data);
+		self.absorb_chunked_same_as_one_shot(data);
+		self.shorter_output_is_prefix(data);
+		self.absorb_after_squeeze_err(data);
+	}
+
+	fn chunk_sizes(&self) -> [usize; 5] {
+		[
+			1,
+			self.blocksize - 1,
+			self.blocksize,
+			self.blocksize + 1,
+			self.blocksize * 2,
+		]
+	}
+
+	/// Squeezing the output in chunks around the blocksize, with empty
+	/// squeezes in between, gives the same output as the one-shot function.
+	fn squeeze_chunked_same_as_one_shot(&self, data: &[u8]) {
+		let out_len = self.blocksize * 3 + 1;
+		let mut expected = vec![0u8; out_len];
+		(self.one_shot)(data, &mut expected).unwrap();
+
+		for chunk_size in self.chunk_sizes().iter() {
+			let mut state = self.initial_state.clone();
+			state.absorb(data).unwrap();
+
+			let mut actual = vec![0u8; out_len];
+			for chunk in actual.chunks_mut(*chunk_size) {
+				state.squeeze(chunk).unwrap();
+				state.squeeze(&mut []).unwrap();
+			}
+			assert_eq!(actual, expected);
+		}
+
+		// Uneven chunk sizes.
+		let mut state = self.initial_state.clone();
+		state.absorb(data).unwrap();
+		let mut actual = vec![0u8; out_len];
+		let (first, rest) = actual.split_at_mut(3);
+		let (second, third) = rest.split_at_mut(self.blocksize);
+		state.squeeze(first).unwrap();
+		state.squeeze(second).unwrap();
+		state.squeeze(third).unwrap();
+		assert_eq!(actual, expected);
+	}
+
+	/// Absorbing the input in chunks, with empty absorbs in between, gives the
+	/// same output as absorbing it at once.
+	fn absorb_chunked_same_as_one_shot(&self, data: &[u8]) {
+		let mut expected = vec![0u8; self.blocksize];
+		(self.one_shot)(data, &mut expected).unwrap();
+
+		for chunk_size in self.chunk_sizes().iter() {
+			let mut state = self.initial_state.clone();
+			for chunk in data.chunks(*chunk_size) {
+				state.absorb(chunk).unwrap();
+				state.absorb(b"").unwrap();
+			}
+
+			let mut actual = vec![0u8; self.blocksize];
+			state.squeeze(&mut actual).unwrap();
+			assert_eq!(actual, expected);
+		}
+	}
+
+	/// The output of the one-shot function is a prefix of any longer output.
+	fn shorter_output_is_prefix(&self, data: &[u8]) {
+		let mut long = vec![0u8; self.blocksize * 2 + 1];
+		(self.one_shot)(data, &mut long).unwrap();
+
+		for len in &[1, self.blocksize - 1, self.blocksize, self.blocksize + 1] {
+			let mut short = vec![0u8; *len];
+			(self.one_shot)(data, &mut short).unwrap();
+			assert_eq!(&short[..], &long[..*len]);
+		}
+	}
+
+	/// Input cannot be absorbed once output has been squeezed.
+	fn absorb_after_squeeze_err(&self, data: &[u8]) {
+		let mut state = self.initial_state.clone();
+		state.absorb(data).unwrap();
+		state.squeeze(&mut [0u8; 1]).unwrap();
+
+		assert_eq!(state.absorb(data), Err(UnknownCryptoError::FinalizedState));
+		assert_eq!(state.absorb(b""), Err(UnknownCryptoError::FinalizedState));
+	}
+}
+
+// No XOF is implemented yet, so the tester is checked against a simple one
+// built from SHA-512 in counter mode.
+#[cfg(test)]
+mod test_xof_tester {
+	use super::*;
+	use crate::hazardous::hash::sha512::{self, Sha512, SHA512_OUTSIZE};
+
+	#[derive(Clone)]
+	struct CounterXof {
+		absorber: Sha512,
+		seed: Option<[u8; SHA512_OUTSIZE]>,
+		counter: u64,
+		buffer: [u8; SHA512_OUTSIZE],
+		leftover: usize,
+	}
+
+	impl CounterXof {
+		fn new() -> Self {
+			Self {
+				absorber: sha512::init(),
+				seed: None,
+				counter: 0,
+				buffer: [0u8; SHA512_OUTSIZE],
+				leftover: 0,
+			}
+		}
+	}
+
+	impl TestableXof for CounterXof {
+		fn absorb(&mut self, input: &[u8]) -> Result<(), UnknownCryptoError> {
+			if self.seed.is_some() {
+				return Err(UnknownCryptoError::FinalizedState);
+			}
+			self.absorber.update(input)
+		}
+
+		fn squeeze(&mut self, dst_out: &mut [u8]) -> Result<(), UnknownCryptoError> {
+			if self.seed.is_none() {
+				let mut seed = [0u8; SHA512_OUTSIZE];
+				seed.copy_from_slice(self.absorber.finalize()?.as_ref());
+				self.seed = Some(seed);
+			}
+
+			for byte in dst_out.iter_mut() {
+				if self.leftover == 0 {
+					let mut block = sha512::init();
+					block.update(&self.seed.unwrap())?;
+					block.update(&self.counter.to_le_bytes())?;
+					self.buffer.copy_from_slice(block.finalize()?.as_ref());
+					self.counter += 1;
+					self.leftover = SHA512_OUTSIZE;
+				}
+				*byte = self.buffer[SHA512_OUTSIZE - self.leftover];
+				self.leftover -= 1;
+			}
+
+			Ok(())
+		}
+	}
+
+	fn one_shot(input: &[u8], dst_out: &mut [u8]) -> Result<(), UnknownCryptoError> {
+		let mut state = CounterXof::new();
+		state.absorb(input)?;
+		state.squeeze(dst_out)
+	}
+
+	#[test]
+	fn test_xof_tester() {
+		XofTester::new(CounterXof::new(), one_shot, SHA512_OUTSIZE).run_all_tests();
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_xof_tester_catches_wrong_one_shot() {
+		let wrong_one_shot = |input: &[u8], dst_out: &mut [u8]| {
+			one_shot(input, dst_out)?;
+			dst_out[0] ^= 1;
+			Ok(())
+		};
+		XofTester::new(CounterXof::new(), wrong_one_shot, SHA512_OUTSIZE).run_all_tests();
+	}
+}
@@ -0,0 +1,359 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Expiring authenticated tokens.
+//!
+//! # Use case:
+//! `orion::token` can be used to issue tokens that carry a payload, which
+//! can later be verified as unmodified and not expired.
+//!
+//! An example of this could be a password reset link, or a signed session
+//! cookie.
+//!
+//! # About:
+//! - A token holds the payload, the Unix time it was issued at and the Unix
+//!   time it expires at. The payload is not encrypted.
+//! - Uses HMAC-SHA512, under a key derived from `secret_key` with
+//!   HKDF-HMAC-SHA512 and a context string as info. A tag therefore cannot be
+//!   mistaken for a tag computed by [`orion::auth`] with the same key.
+//! - Tokens are encoded as URL-safe Base64 without padding, so they can be
+//!   used in URLs and cookies as they are.
+//! - The decoded token is laid out as follows:
+//!
+//! | Field      | Size                  |
+//! |------------|-----------------------|
+//! | Version    | 1 byte: `1`           |
+//! | Issued at  | 8 bytes, big-endian   |
+//! | Expires at | 8 bytes, big-endian   |
+//! | Payload    | Length of the payload |
+//! | Tag        | 64 bytes              |
+//!
+//! # Parameters:
+//! - `secret_key`: The secret key used to authenticate the token.
+//! - `payload`: The data the token carries, such as a user id.
+//! - `ttl`: The amount of seconds the token is valid for.
+//! - `unix_time`: Seconds since the Unix epoch. [`mint()`] and [`verify()`]
+//!   use the system time.
+//! - `token`: A token minted with [`mint()`] or [`mint_at()`].
+//! - `leeway`: The amount of seconds the clocks of the minting and verifying
+//!   systems may differ by. A token is accepted until `leeway` seconds after
+//!   it expires, and from `leeway` seconds before it was issued.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `ttl` is 0, or the expiry time overflows.
+//! - The system time is before the Unix epoch.
+//! - `token` is not valid Base64, is too short or has an unknown version.
+//! - The tag of `token` does not match the calculated tag.
+//! - `token` has expired, or was issued in the future.
+//!
+//! # Security:
+//! - The secret key should always be generated using a CSPRNG.
+//!   [`SecretKey::default()`] can be used for this.
+//! - The payload is only authenticated, not encrypted. Anyone holding a
+//!   token can read it.
+//! - A token can be used any number of times until it expires. Tokens that
+//!   must only be used once, such as password reset tokens, must be tracked
+//!   by the caller, for example by including a nonce in the payload.
+//! - The tag is verified in constant time, before the expiry is checked.
+//!
+//! # Example:
+//! ```rust
+//! use orion::token::{self, SecretKey};
+//!
+//! let key = SecretKey::default();
+//! let minted = token::mint(&key, b"user-42", 3600)?;
+//!
+//! assert_eq!(token::verify(&key, &minted, 60)?, b"user-42");
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`orion::auth`]: https://docs.rs/orion/latest/orion/auth/index.html
+//! [`mint()`]: https://docs.rs/orion/latest/orion/token/fn.mint.html
+//! [`mint_at()`]: https://docs.rs/orion/latest/orion/token/fn.mint_at.html
+//! [`verify()`]: https://docs.rs/orion/latest/orion/token/fn.verify.html
+//! [`SecretKey::default()`]: https://docs.rs/orion/latest/orion/token/struct.SecretKey.html
+
+pub use crate::hltypes::SecretKey;
+use crate::{
+	errors::UnknownCryptoError,
+	hazardous::{
+		kdf::hkdf,
+		mac::hmac::{self, Tag},
+	},
+	util::base64::{self, Variant},
+};
+use zeroize::Zeroize;
+
+/// The context string used as HKDF info to derive the key of the tags.
+const CONTEXT: &[u8] = b"orion::token";
+/// The current token format version.
+const VERSION: u8 = 1;
+/// The size of the fields before the payload.
+const HEADER_SIZE: usize = 1 + 8 + 8;
+/// The size of the HMAC-SHA512 tag.
+const TAG_SIZE: usize = 64;
+
+/// Derive the HMAC key of the tags from `secret_key`.
+fn tag_key(secret_key: &SecretKey) -> Result<hmac::SecretKey, UnknownCryptoError> {
+	let mut key = [0u8; TAG_SIZE];
+	hkdf::derive_key(
+		b"",
+		secret_key.unprotected_as_bytes(),
+		Some(CONTEXT),
+		&mut key,
+	)?;
+	let tag_key = hmac::SecretKey::from_slice(&key);
+	key.zeroize();
+
+	tag_key
+}
+
+/// The tag of `body`, the token without its tag.
+fn tag(secret_key: &SecretKey, body: &[u8]) -> Result<Tag, UnknownCryptoError> {
+	hmac::hmac(&tag_key(secret_key)?, body)
+}
+
+/// Seconds since the Unix epoch.
+fn now() -> Result<u64, UnknownCryptoError> {
+	std::time::SystemTime::now()
+		.duration_since(std::time::UNIX_EPOCH)
+		.map(|duration| duration.as_secs())
+		.map_err(|_| UnknownCryptoError::ParameterOutOfRange)
+}
+
+#[must_use]
+/// Mint a token carrying `payload`, valid for `ttl` seconds from the current
+/// system time.
+pub fn mint(
+	secret_key: &SecretKey,
+	payload: &[u8],
+	ttl: u64,
+) -> Result<String, UnknownCryptoError> {
+	mint_at(secret_key, payload, now()?, ttl)
+}
+
+#[must_use]
+/// Mint a token carrying `payload`, valid for `ttl` seconds from `unix_time`.
+pub fn mint_at(
+	secret_key: &SecretKey,
+	payload: &[u8],
+	unix_time: u64,
+	ttl: u64,
+) -> Result<String, UnknownCryptoError> {
+	if ttl == 0 {
+		return Err(UnknownCryptoError::ParameterOutOfRange);
+	}
+	let expires_at = unix_time
+		.checked_add(ttl)
+		.ok_or(UnknownCryptoError::ParameterOutOfRange)?;
+
+	let mut token = Vec::with_capacity(HEADER_SIZE + payload.len() + TAG_SIZE);
+	token.push(VERSION);
+	token.extend_from_slice(&unix_time.to_be_bytes());
+	token.extend_from_slice(&expires_at.to_be_bytes());
+	token.extend_from_slice(payload);
+	let tag = tag(secret_key, &token)?;
+	token.extend_from_slice(tag.unprotected_as_bytes());
+
+	Ok(base64::encode(&token, Variant::UrlSafeNoPadding))
+}
+
+#[must_use]
+/// Verify `token` at the current system time, and return its payload.
+pub fn verify(
+	secret_key: &SecretKey,
+	token: &str,
+	leeway: u64,
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	verify_at(secret_key, token, now()?, leeway)
+}
+
+#[must_use]
+/// Verify `token` at `unix_time`, and return its payload.
+pub fn verify_at(
+	secret_key: &SecretKey,
+	token: &str,
+	unix_time: u64,
+	leeway: u64,
+) -> Result<Vec<u8>, UnknownCryptoError> {
+	let decoded = base64::decode(token, Variant::UrlSafeNoPadding)?;
+	if decoded.len() < HEADER_SIZE + TAG_SIZE || decoded[0] != VERSION {
+		return Err(UnknownCryptoError::InvalidEncoding);
+	}
+	let (body, expected) = decoded.split_at(decoded.len() - TAG_SIZE);
+	if Tag::from_slice(expected)? != tag(secret_key, body)? {
+		return Err(UnknownCryptoError::InvalidTag);
+	}
+
+	let mut issued_at = [0u8; 8];
+	issued_at.copy_from_slice(&body[1..9]);
+	let mut expires_at = [0u8; 8];
+	expires_at.copy_from_slice(&body[9..HEADER_SIZE]);
+	if u64::from_be_bytes(issued_at) > unix_time.saturating_add(leeway)
+		|| unix_time > u64::from_be_bytes(expires_at).saturating_add(leeway)
+	{
+		return Err(UnknownCryptoError::ParameterOutOfRange);
+	}
+
+	Ok(body[HEADER_SIZE..].to_vec())
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	const NOW: u64 = 1_500_000_000;
+
+	fn key() -> SecretKey {
+		SecretKey::from_slice(&[0x42u8; 32]).unwrap()
+	}
+
+	mod test_mint {
+		use super::*;
+
+		#[test]
+		fn test_layout() {
+			let minted = mint_at(&key(), b"payload", NOW, 60).unwrap();
+			let decoded = base64::decode(&minted, Variant::UrlSafeNoPadding).unwrap();
+
+			assert_eq!(decoded.len(), HEADER_SIZE + 7 + TAG_SIZE);
+			assert_eq!(decoded[0], VERSION);
+			assert_eq!(&decoded[1..9], &NOW.to_be_bytes());
+			assert_eq!(&decoded[9..17], &(NOW + 60).to_be_bytes());
+			assert_eq!(&decoded[17..24], b"payload");
+			// The tag is computed under a key derived with the context string.
+			let mut derived = [0u8; TAG_SIZE];
+			hkdf::derive_key(b"", &[0x42u8; 32], Some(b"orion::token"), &mut derived).unwrap();
+			let tag_key = hmac::SecretKey::from_slice(&derived).unwrap();
+			assert_eq!(
+				&decoded[24..],
+				hmac::hmac(&tag_key, &decoded[..24])
+					.unwrap()
+					.unprotected_as_bytes()
+			);
+		}
+
+		#[test]
+		fn test_invalid_params_err() {
+			assert!(mint_at(&key(), b"payload", NOW, 0).is_err());
+			assert!(mint_at(&key(), b"payload", u64::max_value(), 1).is_err());
+			assert!(mint_at(&key(), b"payload", u64::max_value() - 1, 1).is_ok());
+		}
+
+		#[test]
+		fn test_system_time() {
+			let minted = mint(&key(), b"payload", 60).unwrap();
+			assert_eq!(verify(&key(), &minted, 0).unwrap(), b"payload");
+		}
+	}
+
+	mod test_verify {
+		use super::*;
+
+		#[test]
+		fn test_roundtrip() {
+			let minted = mint_at(&key(), b"payload", NOW, 60).unwrap();
+			assert_eq!(verify_at(&key(), &minted, NOW, 0).unwrap(), b"payload");
+
+			let minted = mint_at(&key(), b"", NOW, 60).unwrap();
+			assert!(verify_at(&key(), &minted, NOW, 0).unwrap().is_empty());
+		}
+
+		#[test]
+		fn test_expiry() {
+			let minted = mint_at(&key(), b"payload", NOW, 60).unwrap();
+
+			assert!(verify_at(&key(), &minted, NOW + 60, 0).is_ok());
+			assert!(verify_at(&key(), &minted, NOW + 61, 0).is_err());
+			assert!(verify_at(&key(), &minted, NOW + 65, 5).is_ok());
+			assert!(verify_at(&key(), &minted, NOW + 66, 5).is_err());
+			assert!(verify_at(&key(), &minted, u64::max_value(), u64::max_value()).is_ok());
+		}
+
+		#[test]
+		fn test_issued_in_future() {
+			let minted = mint_at(&key(), b"payload", NOW, 60).unwrap();
+
+			assert!(verify_at(&key(), &minted, NOW - 1, 0).is_err());
+			assert!(verify_at(&key(), &minted, NOW - 5, 5).is_ok());
+			assert!(verify_at(&key(), &minted, NOW - 6, 5).is_err());
+		}
+
+		#[test]
+		fn test_wrong_key_err() {
+			let minted = mint_at(&key(), b"payload", NOW, 60).unwrap();
+			let other = SecretKey::from_slice(&[0x43u8; 32]).unwrap();
+
+			assert_eq!(
+				verify_at(&other, &minted, NOW, 0).unwrap_err(),
+				UnknownCryptoError::InvalidTag
+			);
+		}
+
+		#[test]
+		fn test_modified_err() {
+			let minted = mint_at(&key(), b"payload", NOW, 60).unwrap();
+			let decoded = base64::decode(&minted, Variant::UrlSafeNoPadding).unwrap();
+
+			for index in 0..decoded.len() {
+				let mut modified = decoded.clone();
+				modified[index] ^= 1;
+				let modified = base64::encode(&modified, Variant::UrlSafeNoPadding);
+				assert!(verify_at(&key(), &modified, NOW, 0).is_err());
+			}
+			for len in 0..decoded.len() {
+				let truncated = base64::encode(&decoded[..len], Variant::UrlSafeNoPadding);
+				assert!(verify_at(&key(), &truncated, NOW, 0).is_err());
+			}
+		}
+
+		#[test]
+		fn test_not_auth_tag() {
+			use crate::auth;
+
+			let minted = mint_at(&key(), b"payload", NOW, 60).unwrap();
+			let mut decoded = base64::decode(&minted, Variant::UrlSafeNoPadding).unwrap();
+			let body_len = decoded.len() - TAG_SIZE;
+			let auth_tag = auth::authenticate(&key(), &decoded[..body_len]).unwrap();
+			decoded[body_len..].copy_from_slice(auth_tag.unprotected_as_bytes());
+			let forged = base64::encode(&decoded, Variant::UrlSafeNoPadding);
+			assert!(verify_at(&key(), &forged, NOW, 0).is_err());
+
+			// Prefixing the context string to the message does not help either.
+			let mut prefixed = CONTEXT.to_vec();
+			prefixed.extend_from_slice(&decoded[..body_len]);
+			let auth_tag = auth::authenticate(&key(), &prefixed).unwrap();
+			decoded[body_len..].copy_from_slice(auth_tag.unprotected_as_bytes());
+			let forged = base64::encode(&decoded, Variant::UrlSafeNoPadding);
+			assert!(verify_at(&key(), &forged, NOW, 0).is_err());
+		}
+
+		#[test]
+		fn test_malformed_err() {
+			assert!(verify_at(&key(), "", NOW, 0).is_err());
+			assert!(verify_at(&key(), "not base64!", NOW, 0).is_err());
+			let minted = mint_at(&key(), b"payload", NOW, 60).unwrap();
+			assert!(verify_at(&key(), &format!("{}=", minted), NOW, 0).is_err());
+		}
+	}
+}
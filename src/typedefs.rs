@@ -23,18 +23,18 @@
 ///
 /// Trait implementation macros
 
-#[cfg(feature = "safe_api")]
+#[cfg(feature = "alloc")]
 /// Macro that implements the `Default` trait, which will make a type, that
 /// needs secure default methods like CSPRNG generation, return itself with a
 /// default and secure length of random bytes.
 macro_rules! impl_default_trait (($name:ident, $size:expr) => (
+    #[cfg(feature = "safe_api")]
     impl core::default::Default for $name {
         #[must_use]
-        #[cfg(feature = "safe_api")]
         /// Randomly generate using a CSPRNG with recommended size. Not available in `no_std` context.
         fn default() -> $name {
             use crate::util;
-            let mut value = vec![0u8; $size];
+            let mut value = alloc::vec![0u8; $size];
             util::secure_rand_bytes(&mut value).unwrap();
 
             $name { value: value, original_length: $size }
@@ -65,13 +65,68 @@ macro_rules! impl_ct_partialeq_trait (($name:ident, $bytes_function:ident) => (
     }
 ));
 
+/// Macro that implements the `LowerHex` and `UpperHex` traits, and a `to_hex()`
+/// function, on a object called `$name` that provides a given $bytes_function
+/// to return a slice. This should only be implemented on types whose value is
+/// not secret. The alternate flag (`{:#x}`) adds a `0x` prefix.
+macro_rules! impl_hex_traits (($name:ident, $bytes_function:ident) => (
+    impl core::fmt::LowerHex for $name {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            if f.alternate() {
+                f.write_str("0x")?;
+            }
+            for byte in self.$bytes_function().iter() {
+                write!(f, "{:02x}", byte)?;
+            }
+
+            Ok(())
+        }
+    }
+
+    impl core::fmt::UpperHex for $name {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            if f.alternate() {
+                f.write_str("0x")?;
+            }
+            for byte in self.$bytes_function().iter() {
+                write!(f, "{:02X}", byte)?;
+            }
+
+            Ok(())
+        }
+    }
+
+    impl $name {
+        #[must_use]
+        #[cfg(feature = "safe_api")]
+        /// Encode the object as lowercase hexadecimal. Not available in `no_std` context.
+        pub fn to_hex(&self) -> String {
+            crate::util::hex::encode(self.$bytes_function())
+        }
+    }
+));
+
+/// Macro that implements the `TryFrom<&[u8]>` trait on a object called `$name`
+/// which provides a `from_slice()` function, so that it can be used with
+/// generic conversion code.
+macro_rules! impl_try_from_trait (($name:ident) => (
+    impl core::convert::TryFrom<&[u8]> for $name {
+        type Error = UnknownCryptoError;
+
+        /// Make an object from a given byte slice.
+        fn try_from(slice: &[u8]) -> Result<$name, UnknownCryptoError> {
+            $name::from_slice(slice)
+        }
+    }
+));
+
 /// Macro that implements the `Debug` trait on a object called `$name`.
-/// This `Debug` will omit any fields of object `$name` to avoid them being
-/// written to logs.
+/// This `Debug` will omit the value of object `$name` to avoid it being
+/// written to logs, and only show its length. See `fmt_secret_debug()`.
 macro_rules! impl_omitted_debug_trait (($name:ident) => (
     impl core::fmt::Debug for $name {
         fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-            write!(f, "{} {{***OMITTED***}}", stringify!($name))
+            crate::newtype::fmt_secret_debug(f, stringify!($name), self.unprotected_as_bytes())
         }
     }
 ));
@@ -117,11 +172,24 @@ macro_rules! impl_asref_trait (($name:ident) => (
 /// Macro that implements the `From<[T]>` trait on a object called `$name`
 /// which has fields `value` and `original_length`. It implements From
 /// based on `$size` and this macro should, in most cases, only be used for
-/// types which have a fixed-length.
+/// types which have a fixed-length. A `const` counterpart, `from_bytes()`, and
+/// the associated constant `SIZE` are implemented as well.
 macro_rules! impl_from_trait (($name:ident, $size:expr) => (
     impl core::convert::From<[u8; $size]> for $name {
         /// Make an object from a byte array.
         fn from(bytes: [u8; $size]) -> $name {
+            $name::from_bytes(bytes)
+        }
+    }
+
+    impl $name {
+        /// The length of the object in bytes.
+        pub const SIZE: usize = $size;
+
+        #[must_use]
+        /// Make an object from a byte array. Unlike `from()`, this can be used
+        /// in `const` context.
+        pub const fn from_bytes(bytes: [u8; $size]) -> $name {
             $name {
                 value: bytes,
                 original_length: $size
@@ -130,6 +198,92 @@ macro_rules! impl_from_trait (($name:ident, $size:expr) => (
     }
 ));
 
+#[cfg(feature = "serde")]
+/// Macro that implements `Serialize` and `Deserialize` on a object called
+/// `$name`, which provides a given $bytes_function to return a slice and a
+/// `from_slice()` function. Input longer than `$max_length` is rejected
+/// before `from_slice()` is called. This should only be implemented on public
+/// types.
+macro_rules! impl_serde_traits (($name:ident, $bytes_function:ident, $max_length:expr) => (
+    impl serde::Serialize for $name {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_bytes(self.$bytes_function())
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for $name {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<$name, D::Error> {
+            use crate::serde_secret::BytesVisitor;
+
+            deserializer.deserialize_bytes(
+                BytesVisitor::new(stringify!($name), $max_length, $name::from_slice))
+        }
+    }
+));
+
+#[cfg(feature = "serde")]
+/// Macro that implements `Serialize` and `Deserialize` on
+/// `ExposeSecret<$name>`, where `$name` is a secret type. Secret types must
+/// be explicitly wrapped in `ExposeSecret` before they can be serialized.
+macro_rules! impl_serde_secret_traits (($name:ident, $max_length:expr) => (
+    impl serde::Serialize for crate::serde_secret::ExposeSecret<$name> {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_bytes(self.expose_secret().unprotected_as_bytes())
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for crate::serde_secret::ExposeSecret<$name> {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            use crate::serde_secret::{BytesVisitor, ExposeSecret};
+
+            deserializer.deserialize_bytes(
+                BytesVisitor::new(stringify!($name), $max_length, $name::from_slice))
+                .map(ExposeSecret::new)
+        }
+    }
+));
+
+#[cfg(feature = "fuzz")]
+/// Macro that implements `arbitrary::Arbitrary` on a object called `$name`,
+/// which provides a `from_slice()` function. The length is drawn from the
+/// inclusive range `$lower_bound..=$upper_bound`, capped by the amount of data
+/// left to the fuzzer.
+macro_rules! impl_arbitrary_trait (($name:ident, $lower_bound:expr, $upper_bound:expr) => (
+    impl<'a> arbitrary::Arbitrary<'a> for $name {
+        fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+            let len = core::cmp::min(u.arbitrary_len::<u8>()?, $upper_bound);
+            let bytes = u.bytes(core::cmp::max(len, $lower_bound))?;
+
+            $name::from_slice(bytes).map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+));
+
+#[cfg(feature = "proptest")]
+/// Macro that implements `proptest::arbitrary::Arbitrary` on a object called
+/// `$name`, which provides a `from_slice()` function, so that
+/// `proptest::prelude::any::<$name>()` generates values of every length in
+/// the inclusive range `$lower_bound..=$upper_bound`.
+macro_rules! impl_proptest_trait (($name:ident, $lower_bound:expr, $upper_bound:expr) => (
+    impl proptest::arbitrary::Arbitrary for $name {
+        type Parameters = ();
+        type Strategy = proptest::strategy::BoxedStrategy<$name>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            use proptest::strategy::Strategy;
+
+            proptest::collection::vec(proptest::num::u8::ANY, $lower_bound..=$upper_bound)
+                .prop_map(|bytes| $name::from_slice(&bytes).unwrap())
+                .boxed()
+        }
+    }
+));
+
+/// The largest length generated by `impl_proptest_trait!` for types that have
+/// no upper bound on their length.
+#[cfg(feature = "proptest")]
+pub(crate) const PROPTEST_MAX_VARIABLE_SIZE: usize = 1024;
+
 ///
 /// Function implementation macros
 
@@ -148,7 +302,7 @@ macro_rules! func_from_slice (($name:ident, $lower_bound:expr, $upper_bound:expr
         let slice_len = slice.len();
 
         if slice_len < $lower_bound || slice_len > $upper_bound {
-            return Err(UnknownCryptoError);
+            return Err(UnknownCryptoError::InvalidLength);
         }
 
         let mut value = [0u8; $upper_bound];
@@ -158,19 +312,18 @@ macro_rules! func_from_slice (($name:ident, $lower_bound:expr, $upper_bound:expr
     }
 ));
 
-#[cfg(feature = "safe_api")]
+#[cfg(feature = "alloc")]
 /// Macro to implement a `from_slice()` function. Returns `UnknownCryptoError`
 /// if the slice is not of length `$size`.
 macro_rules! func_from_slice_variable_size (($name:ident) => (
     #[must_use]
-    #[cfg(feature = "safe_api")]
     /// Make an object from a given byte slice.
     pub fn from_slice(slice: &[u8]) -> Result<$name, UnknownCryptoError> {
         if slice.is_empty() {
-            return Err(UnknownCryptoError);
+            return Err(UnknownCryptoError::InvalidLength);
         }
 
-        Ok($name { value: Vec::from(slice), original_length: slice.len() })
+        Ok($name { value: alloc::vec::Vec::from(slice), original_length: slice.len() })
     }
 ));
 
@@ -196,8 +349,9 @@ macro_rules! func_get_length (() => (
     }
 ));
 
-/// Macro to implement a `generate()` function for objects that benefit from
-/// having a CSPRNG available to generate data of a fixed length $size.
+/// Macro to implement `generate()` and `generate_with_rng()` functions for
+/// objects that benefit from having a CSPRNG available to generate data of a
+/// fixed length $size.
 macro_rules! func_generate (($name:ident, $upper_bound:expr, $gen_length:expr) => (
     #[must_use]
     #[cfg(feature = "safe_api")]
@@ -211,9 +365,20 @@ macro_rules! func_generate (($name:ident, $upper_bound:expr, $gen_length:expr) =
 
         $name { value: value, original_length: $gen_length }
     }
+
+    #[must_use]
+    /// Randomly generate using `rng`. An error is returned if `rng` fails.
+    pub fn generate_with_rng<R: crate::util::SecureRandom + ?Sized>(
+        rng: &mut R,
+    ) -> Result<$name, UnknownCryptoError> {
+        let mut value = [0u8; $upper_bound];
+        rng.fill_bytes(&mut value[..$gen_length])?;
+
+        Ok($name { value: value, original_length: $gen_length })
+    }
 ));
 
-#[cfg(feature = "safe_api")]
+#[cfg(feature = "alloc")]
 /// Macro to implement a `generate()` function for objects that benefit from
 /// having a CSPRNG available to generate data of a variable length.
 macro_rules! func_generate_variable_size (($name:ident) => (
@@ -223,15 +388,65 @@ macro_rules! func_generate_variable_size (($name:ident) => (
     pub fn generate(length: usize) -> Result<$name, UnknownCryptoError> {
         use crate::util;
         if length < 1 || length >= (u32::max_value() as usize) {
-            return Err(UnknownCryptoError);
+            return Err(UnknownCryptoError::InvalidLength);
         }
 
-        let mut value = vec![0u8; length];
+        let mut value = alloc::vec![0u8; length];
         // This cannot panic on size input due to above length checks.
         util::secure_rand_bytes(&mut value).unwrap();
 
         Ok($name { value: value, original_length: length })
     }
+
+    #[must_use]
+    /// Randomly generate `length` bytes using `rng`.
+    pub fn generate_with_rng<R: crate::util::SecureRandom + ?Sized>(
+        length: usize,
+        rng: &mut R,
+    ) -> Result<$name, UnknownCryptoError> {
+        if length < 1 || length >= (u32::max_value() as usize) {
+            return Err(UnknownCryptoError::InvalidLength);
+        }
+
+        let mut value = alloc::vec![0u8; length];
+        rng.fill_bytes(&mut value)?;
+
+        Ok($name { value: value, original_length: length })
+    }
+));
+
+#[cfg(feature = "alloc")]
+/// Macro to implement a `generate_len()` function for variable-length secret
+/// keys, where generation below `$min_length` bytes should be refused.
+macro_rules! func_generate_len (($name:ident, $min_length:expr) => (
+    /// The minimum length accepted by `generate_len()` and `generate_len_with_rng()`.
+    pub const MIN_GENERATE_LENGTH: usize = $min_length;
+
+    #[must_use]
+    #[cfg(feature = "safe_api")]
+    /// Randomly generate `length` bytes using a CSPRNG, where `length` must be
+    /// at least `MIN_GENERATE_LENGTH`. Not available in `no_std` context.
+    pub fn generate_len(length: usize) -> Result<$name, UnknownCryptoError> {
+        if length < Self::MIN_GENERATE_LENGTH {
+            return Err(UnknownCryptoError::InvalidLength);
+        }
+
+        $name::generate(length)
+    }
+
+    #[must_use]
+    /// Randomly generate `length` bytes using `rng`, where `length` must be
+    /// at least `MIN_GENERATE_LENGTH`.
+    pub fn generate_len_with_rng<R: crate::util::SecureRandom + ?Sized>(
+        length: usize,
+        rng: &mut R,
+    ) -> Result<$name, UnknownCryptoError> {
+        if length < Self::MIN_GENERATE_LENGTH {
+            return Err(UnknownCryptoError::InvalidLength);
+        }
+
+        $name::generate_with_rng(length, rng)
+    }
 ));
 
 ///
@@ -279,6 +494,37 @@ macro_rules! test_from_slice (($name:ident, $lower_bound:expr, $upper_bound:expr
             assert!($name::from_slice(&[0u8; $lower_bound + 1]).is_ok());
         }
     }
+
+    #[test]
+    fn test_try_from_slice() {
+        use core::convert::TryFrom;
+
+        assert!($name::try_from(&[0u8; $upper_bound][..]).unwrap() == $name::from_slice(&[0u8; $upper_bound]).unwrap());
+        assert!($name::try_from(&[0u8; $lower_bound][..]).unwrap() == $name::from_slice(&[0u8; $lower_bound]).unwrap());
+        assert!($name::try_from(&[0u8; $upper_bound + 1][..]).is_err());
+        assert!($name::try_from(&[0u8; $lower_bound - 1][..]).is_err());
+    }
+));
+
+#[cfg(test)]
+macro_rules! test_hex_traits (($name:ident, $upper_bound:expr) => (
+    #[test]
+    #[cfg(feature = "safe_api")]
+    // format! is only available with std
+    fn test_hex_traits() {
+        let mut bytes = [0u8; $upper_bound];
+        for (idx, byte) in bytes.iter_mut().enumerate() {
+            *byte = (idx as u8).wrapping_mul(0x3b);
+        }
+        let value = $name::from_slice(&bytes).unwrap();
+        let expected = crate::util::hex::encode(&bytes);
+
+        assert_eq!(format!("{:x}", value), expected);
+        assert_eq!(format!("{:#x}", value), format!("0x{}", expected));
+        assert_eq!(format!("{:X}", value), expected.to_uppercase());
+        assert_eq!(format!("{:#X}", value), format!("0x{}", expected.to_uppercase()));
+        assert_eq!(value.to_hex(), expected);
+    }
 ));
 
 #[cfg(test)]
@@ -323,16 +569,39 @@ macro_rules! test_generate (($name:ident, $gen_length:expr) => (
     }
 ));
 
+#[cfg(test)]
+macro_rules! test_generate_with_rng (($name:ident, $gen_length:expr) => (
+    #[test]
+    fn test_generate_with_rng() {
+        use crate::util::HmacDrbg;
+
+        let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+        let first = $name::generate_with_rng(&mut rng).unwrap();
+        let second = $name::generate_with_rng(&mut rng).unwrap();
+        assert!(first != second);
+        assert!(first.get_length() == $gen_length);
+
+        // The same seed should always produce the same value.
+        let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+        assert!($name::generate_with_rng(&mut rng).unwrap() == first);
+    }
+));
+
 #[cfg(test)]
 #[cfg(feature = "safe_api")]
 macro_rules! test_omitted_debug (($name:ident, $upper_bound:expr) => (
     #[test]
     #[cfg(feature = "safe_api")]
+    #[cfg(not(feature = "unprotected_debug"))]
     // format! is only available with std
     fn test_omitted_debug() {
         let secret = format!("{:?}", [0u8; $upper_bound].as_ref());
         let test_debug_contents = format!("{:?}", $name::from_slice(&[0u8; $upper_bound]).unwrap());
         assert_eq!(test_debug_contents.contains(&secret), false);
+        assert_eq!(
+            test_debug_contents,
+            format!("{} {{ value: ***OMITTED***, length: {} }}", stringify!($name), $upper_bound)
+        );
     }
 ));
 
@@ -346,6 +615,10 @@ macro_rules! test_from_slice_variable (($name:ident) => (
         assert!($name::from_slice(&[0u8; 256]).is_ok());
         assert!($name::from_slice(&[0u8; 1]).is_ok());
         assert!($name::from_slice(&[0u8; 0]).is_err());
+
+        use core::convert::TryFrom;
+        assert!($name::try_from(&[1u8; 256][..]).unwrap() == $name::from_slice(&[1u8; 256]).unwrap());
+        assert!($name::try_from(&[0u8; 0][..]).is_err());
     }
 ));
 
@@ -366,6 +639,57 @@ macro_rules! test_generate_variable (($name:ident) => (
         assert!(test_zero != test_rand);
         assert!(test_rand.get_length() == 128);
     }
+
+    #[test]
+    #[cfg(feature = "safe_api")]
+    fn test_generate_variable_with_rng() {
+        use crate::util::HmacDrbg;
+
+        let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+        assert!($name::generate_with_rng(0, &mut rng).is_err());
+        assert!($name::generate_with_rng(usize::max_value(), &mut rng).is_err());
+
+        let first = $name::generate_with_rng(64, &mut rng).unwrap();
+        assert!(first.get_length() == 64);
+
+        // The same seed should always produce the same value.
+        let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+        assert!($name::generate_with_rng(1, &mut rng).unwrap() != first);
+        let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+        assert!($name::generate_with_rng(64, &mut rng).unwrap() == first);
+    }
+));
+
+#[cfg(test)]
+macro_rules! test_generate_len (($name:ident, $min_length:expr) => (
+    #[test]
+    fn test_generate_len() {
+        assert!($name::MIN_GENERATE_LENGTH == $min_length);
+        assert!($name::generate_len(0).is_err());
+        assert!($name::generate_len($min_length - 1).is_err());
+        assert!($name::generate_len(usize::max_value()).is_err());
+        assert!($name::generate_len($min_length).unwrap().get_length() == $min_length);
+        assert!($name::generate_len(128).unwrap().get_length() == 128);
+        // A random one should never be all 0's.
+        assert!($name::generate_len(128).unwrap() != $name::from_slice(&[0u8; 128]).unwrap());
+    }
+
+    #[test]
+    fn test_generate_len_with_rng() {
+        use crate::util::HmacDrbg;
+
+        let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+        assert!($name::generate_len_with_rng(0, &mut rng).is_err());
+        assert!($name::generate_len_with_rng($min_length - 1, &mut rng).is_err());
+        assert!($name::generate_len_with_rng(usize::max_value(), &mut rng).is_err());
+
+        let first = $name::generate_len_with_rng(64, &mut rng).unwrap();
+        assert!(first.get_length() == 64);
+
+        // Same output as generate_with_rng() once the length is accepted.
+        let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+        assert!($name::generate_with_rng(64, &mut rng).unwrap() == first);
+    }
 ));
 
 ///
@@ -386,9 +710,24 @@ macro_rules! test_generate_variable (($name:ident) => (
 ///
 /// - $gen_length: The amount of data to be randomly generated when using
 ///   generate().
+///
+/// If followed by `, custom_generate`, `generate()` and `generate_with_rng()`
+/// are not implemented and must be provided by the caller. This is for types
+/// where not all random values of `$gen_length` bytes are valid.
 macro_rules! construct_secret_key {
     ($(#[$meta:meta])*
     ($name:ident, $test_module_name:ident, $lower_bound:expr, $upper_bound:expr, $gen_length:expr)) => (
+        construct_secret_key! {
+            $(#[$meta])*
+            ($name, $test_module_name, $lower_bound, $upper_bound, $gen_length), custom_generate
+        }
+
+        impl $name {
+            func_generate!($name, $upper_bound, $gen_length);
+        }
+    );
+    ($(#[$meta:meta])*
+    ($name:ident, $test_module_name:ident, $lower_bound:expr, $upper_bound:expr, $gen_length:expr), custom_generate) => (
         #[must_use]
         $(#[$meta])*
         ///
@@ -419,13 +758,19 @@ macro_rules! construct_secret_key {
         }
 
         impl_omitted_debug_trait!($name);
+        impl_try_from_trait!($name);
         impl_drop_trait!($name);
         impl_ct_partialeq_trait!($name, unprotected_as_bytes);
+        #[cfg(feature = "serde")]
+        impl_serde_secret_traits!($name, $upper_bound);
+        #[cfg(feature = "fuzz")]
+        impl_arbitrary_trait!($name, $lower_bound, $upper_bound);
+        #[cfg(feature = "proptest")]
+        impl_proptest_trait!($name, $lower_bound, $upper_bound);
 
         impl $name {
             func_from_slice!($name, $lower_bound, $upper_bound);
             func_unprotected_as_bytes!();
-            func_generate!($name, $upper_bound, $gen_length);
             func_get_length!();
         }
 
@@ -437,6 +782,7 @@ macro_rules! construct_secret_key {
             test_from_slice!($name, $lower_bound, $upper_bound);
             test_as_bytes_and_get_length!($name, $lower_bound, $upper_bound, unprotected_as_bytes);
             test_partial_eq!($name, $upper_bound);
+            test_generate_with_rng!($name, $gen_length);
 
             #[cfg(test)]
             #[cfg(feature = "safe_api")]
@@ -481,7 +827,15 @@ macro_rules! construct_public {
 
         impl_ct_partialeq_trait!($name, as_ref);
         impl_normal_debug_trait!($name);
+        impl_try_from_trait!($name);
         impl_asref_trait!($name);
+        impl_hex_traits!($name, as_ref);
+        #[cfg(feature = "serde")]
+        impl_serde_traits!($name, as_ref, $upper_bound);
+        #[cfg(feature = "fuzz")]
+        impl_arbitrary_trait!($name, $lower_bound, $upper_bound);
+        #[cfg(feature = "proptest")]
+        impl_proptest_trait!($name, $lower_bound, $upper_bound);
 
         impl $name {
             func_from_slice!($name, $lower_bound, $upper_bound);
@@ -497,6 +851,7 @@ macro_rules! construct_public {
             test_from_slice!($name, $lower_bound, $upper_bound);
             test_as_bytes_and_get_length!($name, $lower_bound, $upper_bound, as_ref);
             test_partial_eq!($name, $upper_bound);
+            test_hex_traits!($name, $upper_bound);
         }
     );
 
@@ -513,7 +868,15 @@ macro_rules! construct_public {
 
         impl_ct_partialeq_trait!($name, as_ref);
         impl_normal_debug_trait!($name);
+        impl_try_from_trait!($name);
         impl_asref_trait!($name);
+        impl_hex_traits!($name, as_ref);
+        #[cfg(feature = "serde")]
+        impl_serde_traits!($name, as_ref, $upper_bound);
+        #[cfg(feature = "fuzz")]
+        impl_arbitrary_trait!($name, $lower_bound, $upper_bound);
+        #[cfg(feature = "proptest")]
+        impl_proptest_trait!($name, $lower_bound, $upper_bound);
 
         impl $name {
             func_from_slice!($name, $lower_bound, $upper_bound);
@@ -528,6 +891,8 @@ macro_rules! construct_public {
             test_from_slice!($name, $lower_bound, $upper_bound);
             test_as_bytes_and_get_length!($name, $lower_bound, $upper_bound, as_ref);
             test_partial_eq!($name, $upper_bound);
+            test_hex_traits!($name, $upper_bound);
+            test_generate_with_rng!($name, $gen_length);
 
             #[cfg(test)]
             #[cfg(feature = "safe_api")]
@@ -579,7 +944,15 @@ macro_rules! construct_tag {
         }
 
         impl_omitted_debug_trait!($name);
+        impl_try_from_trait!($name);
+        impl_hex_traits!($name, unprotected_as_bytes);
         impl_ct_partialeq_trait!($name, unprotected_as_bytes);
+        #[cfg(feature = "serde")]
+        impl_serde_traits!($name, unprotected_as_bytes, $upper_bound);
+        #[cfg(feature = "fuzz")]
+        impl_arbitrary_trait!($name, $lower_bound, $upper_bound);
+        #[cfg(feature = "proptest")]
+        impl_proptest_trait!($name, $lower_bound, $upper_bound);
 
         impl $name {
             func_from_slice!($name, $lower_bound, $upper_bound);
@@ -596,9 +969,11 @@ macro_rules! construct_tag {
             test_from_slice!($name, $lower_bound, $upper_bound);
             test_as_bytes_and_get_length!($name, $lower_bound, $upper_bound, unprotected_as_bytes);
             test_partial_eq!($name, $upper_bound);
+            test_hex_traits!($name, $upper_bound);
 
             #[cfg(test)]
             #[cfg(feature = "safe_api")]
+            #[cfg(not(feature = "unprotected_debug"))]
             mod tests_with_std {
                 use super::*;
 
@@ -643,8 +1018,15 @@ macro_rules! construct_hmac_key {
         }
 
         impl_omitted_debug_trait!($name);
+        impl_try_from_trait!($name);
         impl_drop_trait!($name);
         impl_ct_partialeq_trait!($name, unprotected_as_bytes);
+        #[cfg(feature = "serde")]
+        impl_serde_secret_traits!($name, usize::max_value());
+        #[cfg(feature = "fuzz")]
+        impl_arbitrary_trait!($name, 0, $size * 2);
+        #[cfg(feature = "proptest")]
+        impl_proptest_trait!($name, 0, $size * 2);
 
         impl $name {
             #[must_use]
@@ -700,6 +1082,19 @@ macro_rules! construct_hmac_key {
             assert!($size == test.get_length());
         }
 
+        #[test]
+        fn test_generate_with_rng_hmac() {
+            use crate::util::HmacDrbg;
+
+            let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+            let first = $name::generate_with_rng(&mut rng).unwrap();
+            assert!(first != $name::generate_with_rng(&mut rng).unwrap());
+            assert!(first.get_length() == $size);
+
+            let mut rng = HmacDrbg::new(&[0u8; 32], b"", b"").unwrap();
+            assert!($name::generate_with_rng(&mut rng).unwrap() == first);
+        }
+
         #[test]
         #[cfg(feature = "safe_api")]
         fn test_generate_hmac() {
@@ -713,6 +1108,7 @@ macro_rules! construct_hmac_key {
 
         #[test]
         #[cfg(feature = "safe_api")]
+        #[cfg(not(feature = "unprotected_debug"))]
         // format! is only available with std
         fn test_omitted_debug_hmac_key() {
             let secret = format!("{:?}", [0u8; $size].as_ref());
@@ -722,14 +1118,13 @@ macro_rules! construct_hmac_key {
     );
 }
 
-#[cfg(feature = "safe_api")]
+#[cfg(feature = "alloc")]
 /// Macro to construct a type containing sensitive data which is stored on the
 /// heap.
 macro_rules! construct_secret_key_variable_size {
     ($(#[$meta:meta])*
     ($name:ident, $test_module_name:ident, $default_size:expr)) => (
         #[must_use]
-        #[cfg(feature = "safe_api")]
         $(#[$meta])*
         ///
         /// # Security:
@@ -759,14 +1154,21 @@ macro_rules! construct_secret_key_variable_size {
         /// # }
         /// ```
         pub struct $name {
-            value: Vec<u8>,
+            value: alloc::vec::Vec<u8>,
             original_length: usize,
         }
 
         impl_omitted_debug_trait!($name);
+        impl_try_from_trait!($name);
         impl_drop_trait!($name);
         impl_ct_partialeq_trait!($name, unprotected_as_bytes);
         impl_default_trait!($name, $default_size);
+        #[cfg(feature = "serde")]
+        impl_serde_secret_traits!($name, usize::max_value());
+        #[cfg(feature = "fuzz")]
+        impl_arbitrary_trait!($name, 1, usize::max_value());
+        #[cfg(feature = "proptest")]
+        impl_proptest_trait!($name, 1, crate::typedefs::PROPTEST_MAX_VARIABLE_SIZE);
 
         impl $name {
             func_from_slice_variable_size!($name);
@@ -776,6 +1178,7 @@ macro_rules! construct_secret_key_variable_size {
         }
 
         #[cfg(test)]
+        #[cfg(feature = "safe_api")]
         mod $test_module_name {
             use super::*;
 
@@ -788,25 +1191,32 @@ macro_rules! construct_secret_key_variable_size {
     );
 }
 
-#[cfg(feature = "safe_api")]
+#[cfg(feature = "alloc")]
 /// Macro to construct a type containing non-sensitive which is stored on the
 /// heap.
 macro_rules! construct_salt_variable_size {
     ($(#[$meta:meta])*
     ($name:ident, $test_module_name:ident, $default_size:expr)) => (
         #[must_use]
-        #[cfg(feature = "safe_api")]
         $(#[$meta])*
         ///
         pub struct $name {
-            value: Vec<u8>,
+            value: alloc::vec::Vec<u8>,
             original_length: usize,
         }
 
         impl_normal_debug_trait!($name);
+        impl_try_from_trait!($name);
         impl_default_trait!($name, $default_size);
         impl_ct_partialeq_trait!($name, as_ref);
         impl_asref_trait!($name);
+        impl_hex_traits!($name, as_ref);
+        #[cfg(feature = "serde")]
+        impl_serde_traits!($name, as_ref, usize::max_value());
+        #[cfg(feature = "fuzz")]
+        impl_arbitrary_trait!($name, 1, usize::max_value());
+        #[cfg(feature = "proptest")]
+        impl_proptest_trait!($name, 1, crate::typedefs::PROPTEST_MAX_VARIABLE_SIZE);
 
         impl $name {
             func_from_slice_variable_size!($name);
@@ -815,6 +1225,7 @@ macro_rules! construct_salt_variable_size {
         }
 
         #[cfg(test)]
+        #[cfg(feature = "safe_api")]
         mod $test_module_name {
             use super::*;
 
@@ -822,6 +1233,7 @@ macro_rules! construct_salt_variable_size {
             test_as_bytes_and_get_length!($name, 1, $default_size + 1, as_ref);
             test_generate_variable!($name);
             test_partial_eq!($name, $default_size);
+            test_hex_traits!($name, $default_size);
         }
     );
 }
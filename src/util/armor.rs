@@ -0,0 +1,300 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! ASCII armor for keys, signatures and ciphertexts.
+//!
+//! # Use case:
+//! `orion::util::armor` can be used to store the output of orion's
+//! high-level modules as text, such as in configuration files or emails.
+//!
+//! # About:
+//! - The format is that of OpenPGP's ASCII armor ([RFC 4880], section 6),
+//!   without headers: a header line such as `-----BEGIN ORION MESSAGE-----`,
+//!   lines of 64 Base64 characters, a line with `=` and the Base64 encoded
+//!   CRC-24 checksum of the data, and a footer line. Lines end in `\n`.
+//! - The checksum catches data that was truncated or damaged in transit. It
+//!   is not a MAC. Data that must not be modified should be authenticated,
+//!   as the output of [`orion::aead`] is.
+//! - The Base64 encoding and decoding, and the checksum, are computed in
+//!   constant time.
+//! - Decoding is strict: the header and footer must match the [`Label`]
+//!   exactly, and there must be no text around them. Lines may end in `\n`
+//!   or `\r\n`. All lines of Base64 but the last must be 64 characters.
+//!
+//! # Parameters:
+//! - `src`: The data to armor, or the armored string to decode.
+//! - `label`: The type of data that is armored.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `src` is empty, when encoding.
+//! - The header and footer of `src` do not match `label`, or `src` is not
+//!   otherwise strictly formatted, when decoding.
+//! - The Base64 in `src` is not valid.
+//! - The checksum does not match the data.
+//!
+//! # Security:
+//! - The length of the data is not hidden, and neither is whether decoding
+//!   failed.
+//!
+//! # Example:
+//! ```rust
+//! use orion::aead;
+//! use orion::util::armor::{self, Label};
+//!
+//! let secret_key = aead::SecretKey::default();
+//! let ciphertext = aead::seal(&secret_key, b"Secret message")?;
+//!
+//! let armored = armor::encode(&ciphertext, Label::Message)?;
+//! assert!(armored.starts_with("-----BEGIN ORION MESSAGE-----\n"));
+//!
+//! let decoded = armor::decode(&armored, Label::Message)?;
+//! assert_eq!(aead::open(&secret_key, &decoded)?, b"Secret message");
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [RFC 4880]: https://tools.ietf.org/html/rfc4880#section-6
+//! [`orion::aead`]: ../../aead/index.html
+//! [`Label`]: enum.Label.html
+
+use super::base64::{self, Variant};
+use crate::errors::UnknownCryptoError;
+use subtle::ConstantTimeEq;
+
+/// The amount of Base64 characters in a full line.
+const LINE_LENGTH: usize = 64;
+/// The amount of bytes encoded in a full line.
+const LINE_BYTES: usize = 48;
+/// The initial value of the CRC-24 of RFC 4880.
+const CRC24_INIT: u32 = 0x00B7_04CE;
+/// The generator of the CRC-24 of RFC 4880.
+const CRC24_POLY: u32 = 0x0186_4CFB;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The label of the header and footer, which names the type of data.
+pub enum Label {
+	/// A secret key, `ORION SECRET KEY`.
+	SecretKey,
+	/// A public key, `ORION PUBLIC KEY`.
+	PublicKey,
+	/// A signature or authentication tag, `ORION SIGNATURE`.
+	Signature,
+	/// A ciphertext, `ORION MESSAGE`.
+	Message,
+}
+
+impl Label {
+	fn as_str(self) -> &'static str {
+		match self {
+			Label::SecretKey => "ORION SECRET KEY",
+			Label::PublicKey => "ORION PUBLIC KEY",
+			Label::Signature => "ORION SIGNATURE",
+			Label::Message => "ORION MESSAGE",
+		}
+	}
+}
+
+/// The CRC-24 of `data`, as specified in RFC 4880, section 6.1. This does not
+/// branch on or index by the data.
+fn crc24(data: &[u8]) -> [u8; 3] {
+	let mut crc = CRC24_INIT;
+	for byte in data.iter() {
+		crc ^= u32::from(*byte) << 16;
+		for _ in 0..8 {
+			crc <<= 1;
+			crc ^= CRC24_POLY & 0u32.wrapping_sub((crc >> 24) & 1);
+		}
+	}
+
+	[(crc >> 16) as u8, (crc >> 8) as u8, crc as u8]
+}
+
+/// The line `-----<kind> <label>-----`.
+fn boundary(kind: &str, label: Label) -> String {
+	format!("-----{} {}-----", kind, label.as_str())
+}
+
+#[must_use]
+/// Armor `src` with `label`.
+pub fn encode(src: &[u8], label: Label) -> Result<String, UnknownCryptoError> {
+	if src.is_empty() {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let mut armored = boundary("BEGIN", label);
+	armored.push('\n');
+	for chunk in src.chunks(LINE_BYTES) {
+		armored.push_str(&base64::encode(chunk, Variant::Standard));
+		armored.push('\n');
+	}
+	armored.push('=');
+	armored.push_str(&base64::encode(&crc24(src), Variant::Standard));
+	armored.push('\n');
+	armored.push_str(&boundary("END", label));
+	armored.push('\n');
+
+	Ok(armored)
+}
+
+#[must_use]
+/// Decode the armored string `src` with `label`.
+pub fn decode(src: &str, label: Label) -> Result<Vec<u8>, UnknownCryptoError> {
+	let mut lines = src.lines();
+	if lines.next() != Some(boundary("BEGIN", label).as_str())
+		|| lines.next_back() != Some(boundary("END", label).as_str())
+	{
+		return Err(UnknownCryptoError::InvalidEncoding);
+	}
+	let checksum = match lines.next_back() {
+		Some(line) if line.len() == 5 && line.starts_with('=') => {
+			base64::decode(&line[1..], Variant::Standard)?
+		}
+		_ => return Err(UnknownCryptoError::InvalidEncoding),
+	};
+
+	let mut body = lines.peekable();
+	if body.peek().is_none() {
+		return Err(UnknownCryptoError::InvalidEncoding);
+	}
+	let mut decoded = Vec::new();
+	while let Some(line) = body.next() {
+		// Only the last line may be short, or padded.
+		let is_valid = if body.peek().is_none() {
+			!line.is_empty() && line.len() <= LINE_LENGTH
+		} else {
+			line.len() == LINE_LENGTH && !line.ends_with('=')
+		};
+		if !is_valid {
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+		decoded.extend_from_slice(&base64::decode(line, Variant::Standard)?);
+	}
+
+	if !bool::from(crc24(&decoded)[..].ct_eq(&checksum)) {
+		return Err(UnknownCryptoError::InvalidEncoding);
+	}
+
+	Ok(decoded)
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	const LABELS: [Label; 4] = [
+		Label::SecretKey,
+		Label::PublicKey,
+		Label::Signature,
+		Label::Message,
+	];
+
+	fn data() -> Vec<u8> {
+		(0u8..100).collect()
+	}
+
+	// Base64 and CRC-24 computed with Python.
+	const ARMORED: &str = "-----BEGIN ORION MESSAGE-----
+AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8gISIjJCUmJygpKissLS4v
+MDEyMzQ1Njc4OTo7PD0+P0BBQkNERUZHSElKS0xNTk9QUVJTVFVWV1hZWltcXV5f
+YGFiYw==
+=ojIo
+-----END ORION MESSAGE-----
+";
+
+	mod test_encode {
+		use super::*;
+
+		#[test]
+		fn test_known() {
+			assert_eq!(encode(&data(), Label::Message).unwrap(), ARMORED);
+			assert_eq!(
+				encode(b"orion", Label::Signature).unwrap(),
+				"-----BEGIN ORION SIGNATURE-----\nb3Jpb24=\n=Bs3u\n-----END ORION SIGNATURE-----\n"
+			);
+		}
+
+		#[test]
+		fn test_empty_err() {
+			assert!(encode(b"", Label::Message).is_err());
+		}
+
+		#[test]
+		fn test_roundtrip() {
+			for label in LABELS.iter() {
+				for len in 1..150 {
+					let data = vec![0xa5u8; len];
+					let armored = encode(&data, *label).unwrap();
+					assert_eq!(decode(&armored, *label).unwrap(), data);
+				}
+			}
+		}
+	}
+
+	mod test_decode {
+		use super::*;
+
+		#[test]
+		fn test_known() {
+			assert_eq!(decode(ARMORED, Label::Message).unwrap(), data());
+			assert_eq!(
+				decode(&ARMORED.replace('\n', "\r\n"), Label::Message).unwrap(),
+				data()
+			);
+		}
+
+		#[test]
+		fn test_wrong_label_err() {
+			for label in LABELS.iter().filter(|label| **label != Label::Message) {
+				assert!(decode(ARMORED, *label).is_err());
+			}
+		}
+
+		#[test]
+		fn test_checksum_err() {
+			assert!(decode(&ARMORED.replace("=ojIo", "=ojIp"), Label::Message).is_err());
+			assert!(decode(&ARMORED.replace("=ojIo\n", ""), Label::Message).is_err());
+			assert!(decode(&ARMORED.replace("=ojIo", "ojIo"), Label::Message).is_err());
+			assert!(decode(&ARMORED.replace("=ojIo", "=ojI"), Label::Message).is_err());
+			// Modified data.
+			assert!(decode(&ARMORED.replace("AAEC", "AAED"), Label::Message).is_err());
+			assert!(decode(&ARMORED.replace("YGFiYw==", "YGFi"), Label::Message).is_err());
+		}
+
+		#[test]
+		fn test_format_err() {
+			let malformed: [&str; 9] = [
+				"",
+				&ARMORED[1..],
+				&ARMORED.replace("\nYGFiYw==", ""),
+				&ARMORED.replace("\nMDEy", "MDEy"),
+				&ARMORED.replace("\nYGFi", "\n\nYGFi"),
+				&ARMORED.replace("-----\n", "----- \n"),
+				&format!("Armored:\n{}", ARMORED),
+				&format!("{}\n", ARMORED),
+				"-----BEGIN ORION MESSAGE-----\n=twTO\n-----END ORION MESSAGE-----\n",
+			];
+			for src in malformed.iter() {
+				assert!(decode(src, Label::Message).is_err());
+			}
+		}
+	}
+}
@@ -0,0 +1,438 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Constant-time Base32 encoding and decoding.
+//!
+//! # About:
+//! - The alphabet from [RFC 4648] is supported, both with and without
+//!   padding.
+//! - Encoding produces uppercase. Decoding accepts both uppercase and
+//!   lowercase, since secrets for one-time passwords are often written in
+//!   lowercase.
+//! - Neither direction uses lookup tables or branches that depend on the data,
+//!   so they can be used on secret keys.
+//! - Decoding is strict: padding must match the [`Variant`], and the unused
+//!   bits of the last character must be zero.
+//! - [`decode_into()`] decodes directly into a newtype, such as a `SecretKey`,
+//!   without leaving an unwiped copy of the decoded bytes behind.
+//!
+//! # Parameters:
+//! - `src`: The data to encode, or the Base32 string to decode.
+//! - `dst`: The buffer to write the encoded or decoded data to.
+//! - `variant`: Whether padding is used.
+//! - `from_slice`: The function that constructs a newtype from the decoded
+//!   bytes, such as `SecretKey::from_slice`.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - The length of `src` is not a valid length for Base32 of the given
+//!   `variant`, when decoding.
+//! - `src` contains a character that is not in the Base32 alphabet, or
+//!   misplaced padding.
+//! - The unused bits of the last character in `src` are not zero.
+//! - The length of `dst` does not match the encoded or decoded length of
+//!   `src`.
+//! - `from_slice` returns an error.
+//! - The decoded data is longer than 256 bytes in [`decode_into()`] when
+//!   used in `no_std`.
+//!
+//! # Security:
+//! - The length of the input is not hidden, and neither is whether decoding
+//!   failed.
+//! - If decoding fails, `dst` is overwritten with zeroes.
+//!
+//! # Example:
+//! ```rust
+//! use orion::otp::{HashFunction, SecretKey, Totp};
+//! use orion::util::base32::{self, Variant};
+//!
+//! let encoded = base32::encode(b"foobar", Variant::Standard);
+//! assert_eq!(encoded, "MZXW6YTBOI======");
+//! assert_eq!(base32::decode(&encoded, Variant::Standard)?, b"foobar");
+//!
+//! let key = base32::decode_into(
+//! 	"gezdgnbvgy3tqojqgezdgnbvgy3tqojq",
+//! 	Variant::StandardNoPadding,
+//! 	SecretKey::from_slice,
+//! )?;
+//! let totp = Totp::new(&key, HashFunction::Sha256, 6, 30)?;
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [RFC 4648]: https://tools.ietf.org/html/rfc4648
+//! [`Variant`]: enum.Variant.html
+//! [`decode_into()`]: fn.decode_into.html
+
+use crate::errors::UnknownCryptoError;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The padding used for Base32.
+pub enum Variant {
+	/// The standard alphabet, with padding.
+	Standard,
+	/// The standard alphabet, without padding.
+	StandardNoPadding,
+}
+
+impl Variant {
+	fn is_padded(self) -> bool {
+		self == Variant::Standard
+	}
+}
+
+#[inline]
+/// Encode the lower five bits of `quintet` as a Base32 character.
+fn encode_quintet(quintet: u8) -> u8 {
+	let v = i16::from(quintet & 0x1f);
+	// (25 - v) >> 8 is all ones if v > 25, which moves `c` to the digits.
+	(v + 0x41 - (((25 - v) >> 8) & 41)) as u8
+}
+
+#[inline]
+/// Decode a Base32 character. Returns the decoded value and a mask that is all
+/// ones if `c` was a valid character and zero otherwise.
+fn decode_quintet(c: u8) -> (u8, u8) {
+	let c = i16::from(c);
+
+	// Each mask is all ones if `c` lies within the given range.
+	let upper = ((0x40 - c) & (c - 0x5b)) >> 8;
+	let lower = ((0x60 - c) & (c - 0x7b)) >> 8;
+	let digit = ((0x31 - c) & (c - 0x38)) >> 8;
+
+	let value = (upper & (c - 0x41)) | (lower & (c - 0x61)) | (digit & (c - 0x18));
+
+	(value as u8, (upper | lower | digit) as u8)
+}
+
+/// Remove the padding from `src` and return the remaining characters.
+fn strip_padding(src: &[u8], variant: Variant) -> Result<&[u8], UnknownCryptoError> {
+	let body = if variant.is_padded() {
+		if src.len() % 8 != 0 {
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+		// The amount of padding only depends on the length of the decoded data.
+		let padding = src.iter().rev().take(6).take_while(|c| **c == b'=').count();
+		&src[..src.len() - padding]
+	} else {
+		src
+	};
+
+	match body.len() % 8 {
+		1 | 3 | 6 => Err(UnknownCryptoError::InvalidEncoding),
+		_ => Ok(body),
+	}
+}
+
+#[must_use]
+/// Return the length of `length` bytes when encoded with `variant`.
+pub fn encoded_length(length: usize, variant: Variant) -> usize {
+	if variant.is_padded() {
+		((length + 4) / 5) * 8
+	} else {
+		(length * 8 + 4) / 5
+	}
+}
+
+#[must_use]
+/// Return the length of the data that `src` decodes to with `variant`.
+pub fn decoded_length(src: &str, variant: Variant) -> Result<usize, UnknownCryptoError> {
+	let body = strip_padding(src.as_bytes(), variant)?;
+
+	Ok((body.len() * 5) / 8)
+}
+
+#[must_use]
+/// Encode `src` as Base32 into `dst`.
+pub fn encode_to_slice(
+	src: &[u8],
+	dst: &mut [u8],
+	variant: Variant,
+) -> Result<(), UnknownCryptoError> {
+	if dst.len() != encoded_length(src.len(), variant) {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let mut written = 0;
+	for block in src.chunks(5) {
+		let mut bytes = [0u8; 8];
+		bytes[3..3 + block.len()].copy_from_slice(block);
+		let bits = u64::from_be_bytes(bytes);
+		// A block of n bytes needs ceil(8n / 5) characters.
+		for i in 0..(block.len() * 8 + 4) / 5 {
+			dst[written] = encode_quintet((bits >> (35 - 5 * i)) as u8);
+			written += 1;
+		}
+	}
+
+	for c in dst[written..].iter_mut() {
+		*c = b'=';
+	}
+
+	Ok(())
+}
+
+#[must_use]
+/// Decode the Base32 string `src` into `dst`.
+pub fn decode_to_slice(
+	src: &str,
+	dst: &mut [u8],
+	variant: Variant,
+) -> Result<(), UnknownCryptoError> {
+	let body = strip_padding(src.as_bytes(), variant)?;
+	if dst.len() != (body.len() * 5) / 8 {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let mut valid = 0xffu8;
+	let mut written = 0;
+	for block in body.chunks(8) {
+		let mut bits = 0u64;
+		for (i, c) in block.iter().enumerate() {
+			let (value, is_valid) = decode_quintet(*c);
+			bits |= u64::from(value) << (35 - 5 * i);
+			valid &= is_valid;
+		}
+		let bytes = bits.to_be_bytes();
+		let length = (block.len() * 5) / 8;
+		dst[written..written + length].copy_from_slice(&bytes[3..3 + length]);
+		written += length;
+
+		// The bits of a partial block that did not fit in the output must be zero.
+		if length < 5 {
+			let unused = i16::from(bytes[3 + length]);
+			// (unused - 1) >> 8 is all ones only if `unused` is zero.
+			valid &= ((unused - 1) >> 8) as u8;
+		}
+	}
+
+	if valid == 0xff {
+		Ok(())
+	} else {
+		use zeroize::Zeroize;
+		dst.zeroize();
+
+		Err(UnknownCryptoError::InvalidEncoding)
+	}
+}
+
+#[must_use]
+#[cfg(feature = "safe_api")]
+/// Encode `src` as Base32.
+pub fn encode(src: &[u8], variant: Variant) -> String {
+	let mut dst = vec![0u8; encoded_length(src.len(), variant)];
+	encode_to_slice(src, &mut dst, variant).unwrap();
+
+	// The output only contains ASCII characters.
+	String::from_utf8(dst).unwrap()
+}
+
+#[must_use]
+#[cfg(feature = "safe_api")]
+/// Decode the Base32 string `src`.
+pub fn decode(src: &str, variant: Variant) -> Result<Vec<u8>, UnknownCryptoError> {
+	let mut dst = vec![0u8; decoded_length(src, variant)?];
+	decode_to_slice(src, &mut dst, variant)?;
+
+	Ok(dst)
+}
+
+#[must_use]
+/// Decode the Base32 string `src` and construct a newtype from it using
+/// `from_slice`.
+pub fn decode_into<T, F>(
+	src: &str,
+	variant: Variant,
+	from_slice: F,
+) -> Result<T, UnknownCryptoError>
+where
+	F: FnOnce(&[u8]) -> Result<T, UnknownCryptoError>,
+{
+	super::decode_with_buffer(
+		decoded_length(src, variant)?,
+		|buffer| decode_to_slice(src, buffer, variant),
+		from_slice,
+	)
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	// Test vectors from RFC 4648, Section 10.
+	const RFC_VECTORS: [(&[u8], &str); 7] = [
+		(b"", ""),
+		(b"f", "MY======"),
+		(b"fo", "MZXQ===="),
+		(b"foo", "MZXW6==="),
+		(b"foob", "MZXW6YQ="),
+		(b"fooba", "MZXW6YTB"),
+		(b"foobar", "MZXW6YTBOI======"),
+	];
+
+	fn encode_array(src: &[u8], variant: Variant) -> ([u8; 16], usize) {
+		let mut dst = [0u8; 16];
+		let length = encoded_length(src.len(), variant);
+		encode_to_slice(src, &mut dst[..length], variant).unwrap();
+
+		(dst, length)
+	}
+
+	#[test]
+	fn test_rfc_vectors() {
+		for (data, encoded) in RFC_VECTORS.iter() {
+			let (dst, length) = encode_array(data, Variant::Standard);
+			assert_eq!(&dst[..length], encoded.as_bytes());
+
+			let unpadded = encoded.trim_end_matches('=');
+			let (dst, length) = encode_array(data, Variant::StandardNoPadding);
+			assert_eq!(&dst[..length], unpadded.as_bytes());
+
+			let mut decoded = [0u8; 6];
+			decode_to_slice(encoded, &mut decoded[..data.len()], Variant::Standard).unwrap();
+			assert_eq!(&decoded[..data.len()], *data);
+			decode_to_slice(
+				unpadded,
+				&mut decoded[..data.len()],
+				Variant::StandardNoPadding,
+			)
+			.unwrap();
+			assert_eq!(&decoded[..data.len()], *data);
+			let lowercase = unpadded.to_ascii_lowercase();
+			decode_to_slice(
+				&lowercase,
+				&mut decoded[..data.len()],
+				Variant::StandardNoPadding,
+			)
+			.unwrap();
+			assert_eq!(&decoded[..data.len()], *data);
+		}
+	}
+
+	#[test]
+	fn test_encode_all_quintets() {
+		let expected = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+		for (quintet, c) in expected.iter().enumerate() {
+			assert_eq!(encode_quintet(quintet as u8), *c);
+			assert_eq!(decode_quintet(*c), (quintet as u8, 0xff));
+			assert_eq!(
+				decode_quintet(c.to_ascii_lowercase()),
+				(quintet as u8, 0xff)
+			);
+		}
+
+		for c in 0u8..=255 {
+			let valid = expected.contains(&c.to_ascii_uppercase());
+			assert_eq!(decode_quintet(c).1 == 0xff, valid);
+		}
+	}
+
+	#[test]
+	fn test_padding_err() {
+		let mut dst = [0u8; 1];
+		assert!(decode_to_slice("MY", &mut dst, Variant::Standard).is_err());
+		assert!(decode_to_slice("MY=====", &mut dst, Variant::Standard).is_err());
+		assert!(decode_to_slice("MY======", &mut dst, Variant::StandardNoPadding).is_err());
+		assert!(decode_to_slice("M=======", &mut dst, Variant::Standard).is_err());
+		assert!(decode_to_slice("M=Y=====", &mut dst, Variant::Standard).is_err());
+		assert!(decode_to_slice("========", &mut [], Variant::Standard).is_err());
+		for length in [1, 3, 6].iter() {
+			assert!(decoded_length(&"A".repeat(*length), Variant::StandardNoPadding).is_err());
+		}
+	}
+
+	#[test]
+	fn test_non_canonical_err() {
+		let mut dst = [0u8; 1];
+		assert!(decode_to_slice("MY", &mut dst, Variant::StandardNoPadding).is_ok());
+		assert!(decode_to_slice("MZ", &mut dst, Variant::StandardNoPadding).is_err());
+		assert_eq!(dst, [0u8; 1]);
+
+		let mut dst = [0u8; 4];
+		assert!(decode_to_slice("MZXW6YQ", &mut dst, Variant::StandardNoPadding).is_ok());
+		assert!(decode_to_slice("MZXW6YR", &mut dst, Variant::StandardNoPadding).is_err());
+		assert_eq!(dst, [0u8; 4]);
+	}
+
+	#[test]
+	fn test_length_err() {
+		assert!(encode_to_slice(b"foo", &mut [0u8; 7], Variant::Standard).is_err());
+		assert!(encode_to_slice(b"foo", &mut [0u8; 9], Variant::Standard).is_err());
+		assert!(encode_to_slice(b"foo", &mut [0u8; 8], Variant::StandardNoPadding).is_err());
+		assert!(decode_to_slice("MZXW6===", &mut [0u8; 2], Variant::Standard).is_err());
+		assert!(decode_to_slice("MZXW6===", &mut [0u8; 4], Variant::Standard).is_err());
+
+		assert_eq!(decoded_length("", Variant::Standard).unwrap(), 0);
+		assert_eq!(decoded_length("MZXW6YQ=", Variant::Standard).unwrap(), 4);
+		assert_eq!(
+			decoded_length("MZXW6YQ", Variant::StandardNoPadding).unwrap(),
+			4
+		);
+		assert!(decoded_length("MZXW6YQ", Variant::Standard).is_err());
+	}
+
+	#[test]
+	fn test_decode_into() {
+		use crate::hazardous::stream::chacha20::SecretKey;
+
+		let key = decode_into(
+			"AAAQEAYEAUDAOCAJBIFQYDIOB4IBCEQTCQKRMFYYDENBWHA5DYPQ",
+			Variant::StandardNoPadding,
+			SecretKey::from_slice,
+		)
+		.unwrap();
+		let mut expected = [0u8; 32];
+		for (i, byte) in expected.iter_mut().enumerate() {
+			*byte = i as u8;
+		}
+		assert!(key == &expected[..]);
+
+		assert!(decode_into("AAAQ", Variant::StandardNoPadding, SecretKey::from_slice).is_err());
+		assert!(decode_into("AA!=====", Variant::Standard, |_| Ok(())).is_err());
+	}
+
+	// Proptests. Only exectued when NOT testing no_std.
+	#[cfg(feature = "safe_api")]
+	mod proptest {
+		use super::*;
+
+		quickcheck! {
+			/// Decoding encoded data should always return the data.
+			fn prop_encode_decode(data: Vec<u8>) -> bool {
+				[Variant::Standard, Variant::StandardNoPadding].iter().all(|variant| {
+					let encoded = encode(&data, *variant);
+					encoded.len() == encoded_length(data.len(), *variant)
+						&& decode(&encoded, *variant).unwrap() == data
+				})
+			}
+		}
+
+		quickcheck! {
+			/// Decoding arbitrary input should never panic.
+			fn prop_decode_no_panic(input: String) -> bool {
+				let _ = decode(&input, Variant::Standard);
+				let _ = decode(&input, Variant::StandardNoPadding);
+
+				true
+			}
+		}
+	}
+}
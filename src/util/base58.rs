@@ -0,0 +1,235 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Base58 and Base58Check encoding and decoding.
+//!
+//! # About:
+//! - The Bitcoin alphabet is used.
+//! - Base58Check appends the first four bytes of `SHA256(SHA256(payload))` to
+//!   the payload before encoding it, and verifies them when decoding.
+//! - Each leading zero byte is encoded as a leading `1`.
+//!
+//! # Parameters:
+//! - `src`: The data to encode, or the Base58 string to decode.
+//! - `payload`: The data to encode with a checksum.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `src` contains a character that is not in the Base58 alphabet.
+//! - `src` decodes to less than four bytes in [`decode_check()`].
+//! - The checksum does not match in [`decode_check()`].
+//!
+//! # Security:
+//! - Base58 is not constant-time and should only be used for public data, such
+//!   as public keys, fingerprints and addresses.
+//!
+//! # Example:
+//! ```rust
+//! use orion::util::base58;
+//!
+//! let encoded = base58::encode_check(b"Public data")?;
+//! assert_eq!(base58::decode_check(&encoded)?, b"Public data");
+//! assert!(base58::decode_check(&encoded[1..]).is_err());
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`decode_check()`]: fn.decode_check.html
+
+use crate::errors::UnknownCryptoError;
+use crate::hazardous::hash::sha256;
+
+/// The Bitcoin Base58 alphabet.
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// The size of the checksum that Base58Check appends.
+const CHECKSUM_SIZE: usize = 4;
+
+/// Return the first four bytes of `SHA256(SHA256(payload))`.
+fn checksum(payload: &[u8]) -> Result<[u8; CHECKSUM_SIZE], UnknownCryptoError> {
+	let digest = sha256::digest(sha256::digest(payload)?.as_ref())?;
+	let mut checksum = [0u8; CHECKSUM_SIZE];
+	checksum.copy_from_slice(&digest.as_ref()[..CHECKSUM_SIZE]);
+
+	Ok(checksum)
+}
+
+#[must_use]
+/// Encode `src` as Base58.
+pub fn encode(src: &[u8]) -> String {
+	let zeroes = src.iter().take_while(|b| **b == 0).count();
+	// Base58 digits of the remaining data, least significant first.
+	let mut digits: Vec<u8> = Vec::with_capacity(src.len() * 138 / 100 + 1);
+
+	for byte in src[zeroes..].iter() {
+		let mut carry = u32::from(*byte);
+		for digit in digits.iter_mut() {
+			carry += u32::from(*digit) << 8;
+			*digit = (carry % 58) as u8;
+			carry /= 58;
+		}
+		while carry > 0 {
+			digits.push((carry % 58) as u8);
+			carry /= 58;
+		}
+	}
+
+	let mut encoded = String::with_capacity(zeroes + digits.len());
+	encoded.extend(core::iter::repeat('1').take(zeroes));
+	encoded.extend(digits.iter().rev().map(|d| ALPHABET[*d as usize] as char));
+
+	encoded
+}
+
+#[must_use]
+/// Decode the Base58 string `src`.
+pub fn decode(src: &str) -> Result<Vec<u8>, UnknownCryptoError> {
+	let src = src.as_bytes();
+	let zeroes = src.iter().take_while(|c| **c == b'1').count();
+	// Bytes of the remaining data, least significant first.
+	let mut bytes: Vec<u8> = Vec::with_capacity(src.len() * 733 / 1000 + 1);
+
+	for c in src[zeroes..].iter() {
+		let mut carry = match ALPHABET.iter().position(|a| a == c) {
+			Some(value) => value as u32,
+			None => return Err(UnknownCryptoError::InvalidEncoding),
+		};
+		for byte in bytes.iter_mut() {
+			carry += u32::from(*byte) * 58;
+			*byte = carry as u8;
+			carry >>= 8;
+		}
+		while carry > 0 {
+			bytes.push(carry as u8);
+			carry >>= 8;
+		}
+	}
+
+	let mut decoded = vec![0u8; zeroes];
+	decoded.extend(bytes.iter().rev());
+
+	Ok(decoded)
+}
+
+#[must_use]
+/// Encode `payload` as Base58Check.
+pub fn encode_check(payload: &[u8]) -> Result<String, UnknownCryptoError> {
+	let mut data = Vec::with_capacity(payload.len() + CHECKSUM_SIZE);
+	data.extend_from_slice(payload);
+	data.extend_from_slice(&checksum(payload)?);
+
+	Ok(encode(&data))
+}
+
+#[must_use]
+/// Decode the Base58Check string `src` and return the payload.
+pub fn decode_check(src: &str) -> Result<Vec<u8>, UnknownCryptoError> {
+	let mut data = decode(src)?;
+	if data.len() < CHECKSUM_SIZE {
+		return Err(UnknownCryptoError::InvalidEncoding);
+	}
+
+	let payload_length = data.len() - CHECKSUM_SIZE;
+	if checksum(&data[..payload_length])? != data[payload_length..] {
+		return Err(UnknownCryptoError::InvalidEncoding);
+	}
+	data.truncate(payload_length);
+
+	Ok(data)
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	// Test vectors from Bitcoin Core's base58_encode_decode.json.
+	const VECTORS: [(&str, &str); 12] = [
+		("", ""),
+		("61", "2g"),
+		("626262", "a3gV"),
+		("636363", "aPEr"),
+		(
+			"73696d706c792061206c6f6e6720737472696e67",
+			"2cFupjhnEsSn59qHXstmK2ffpLv2",
+		),
+		(
+			"00eb15231dfceb60925886b67d065299925915aeb172c06647",
+			"1NS17iag9jJgTHD1VXjvLCEnZuQ3rJDE9L",
+		),
+		("516b6fcd0f", "ABnLTmg"),
+		("bf4f89001e670274dd", "3SEo3LWLoPntC"),
+		("572e4794", "3EFU7m"),
+		("ecac89cad93923c02321", "EJDM8drfXA6uyA"),
+		("10c8511e", "Rt5zm"),
+		("00000000000000000000", "1111111111"),
+	];
+
+	#[test]
+	fn test_vectors() {
+		for (data, encoded) in VECTORS.iter() {
+			let data = ::hex::decode(data).unwrap();
+			assert_eq!(encode(&data), *encoded);
+			assert_eq!(decode(encoded).unwrap(), data);
+		}
+	}
+
+	#[test]
+	fn test_invalid_characters_err() {
+		for c in ["0", "O", "I", "l", "+", " ", "é"].iter() {
+			assert!(decode(c).is_err());
+			assert!(decode(&format!("2g{}", c)).is_err());
+		}
+	}
+
+	#[test]
+	fn test_check() {
+		// The address example from the Bitcoin wiki.
+		let payload = ::hex::decode("00010966776006953d5567439e5e39f86a0d273bee").unwrap();
+		assert_eq!(
+			encode_check(&payload).unwrap(),
+			"16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvM"
+		);
+		assert_eq!(
+			decode_check("16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvM").unwrap(),
+			payload
+		);
+
+		assert!(decode_check("16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvN").is_err());
+		assert!(decode_check("16UwLL9Risc3QfPqBUvKofHmBQ7wMtjv").is_err());
+		assert!(decode_check("").is_err());
+		assert!(decode_check("111").is_err());
+		assert_eq!(decode_check(&encode_check(b"").unwrap()).unwrap(), b"");
+	}
+
+	// Proptests. Only exectued when NOT testing no_std.
+	#[cfg(feature = "safe_api")]
+	mod proptest {
+		use super::*;
+
+		quickcheck! {
+			/// Decoding encoded data should always return the data.
+			fn prop_encode_decode(data: Vec<u8>) -> bool {
+				decode(&encode(&data)).unwrap() == data
+					&& decode_check(&encode_check(&data).unwrap()).unwrap() == data
+			}
+		}
+	}
+}
@@ -0,0 +1,496 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Constant-time Base64 encoding and decoding.
+//!
+//! # About:
+//! - The standard and URL-safe alphabets from [RFC 4648] are supported, both
+//!   with and without padding.
+//! - Neither direction uses lookup tables or branches that depend on the data,
+//!   so they can be used on secret keys.
+//! - Decoding is strict: padding must match the [`Variant`], and the unused
+//!   bits of the last character must be zero.
+//! - [`decode_into()`] decodes directly into a newtype, such as a `SecretKey`,
+//!   without leaving an unwiped copy of the decoded bytes behind.
+//!
+//! # Parameters:
+//! - `src`: The data to encode, or the Base64 string to decode.
+//! - `dst`: The buffer to write the encoded or decoded data to.
+//! - `variant`: The alphabet and padding to use.
+//! - `from_slice`: The function that constructs a newtype from the decoded
+//!   bytes, such as `SecretKey::from_slice`.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - The length of `src` is not a valid length for Base64 of the given
+//!   `variant`, when decoding.
+//! - `src` contains a character that is not in the alphabet of `variant`, or
+//!   misplaced padding.
+//! - The unused bits of the last character in `src` are not zero.
+//! - The length of `dst` does not match the encoded or decoded length of
+//!   `src`.
+//! - `from_slice` returns an error.
+//! - The decoded data is longer than 256 bytes in [`decode_into()`] when
+//!   used in `no_std`.
+//!
+//! # Security:
+//! - The length of the input is not hidden, and neither is whether decoding
+//!   failed.
+//! - If decoding fails, `dst` is overwritten with zeroes.
+//!
+//! # Example:
+//! ```rust
+//! use orion::hazardous::stream::chacha20::SecretKey;
+//! use orion::util::base64::{self, Variant};
+//!
+//! let encoded = base64::encode(b"foobar", Variant::Standard);
+//! assert_eq!(encoded, "Zm9vYmFy");
+//! assert_eq!(base64::decode(&encoded, Variant::Standard)?, b"foobar");
+//!
+//! let secret_key = base64::decode_into(
+//! 	"AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8",
+//! 	Variant::UrlSafeNoPadding,
+//! 	SecretKey::from_slice,
+//! )?;
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [RFC 4648]: https://tools.ietf.org/html/rfc4648
+//! [`Variant`]: enum.Variant.html
+//! [`decode_into()`]: fn.decode_into.html
+
+use crate::errors::UnknownCryptoError;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The alphabet and padding used for Base64.
+pub enum Variant {
+	/// The standard alphabet, with padding.
+	Standard,
+	/// The standard alphabet, without padding.
+	StandardNoPadding,
+	/// The URL and filename safe alphabet, with padding.
+	UrlSafe,
+	/// The URL and filename safe alphabet, without padding.
+	UrlSafeNoPadding,
+}
+
+impl Variant {
+	fn is_url_safe(self) -> bool {
+		self == Variant::UrlSafe || self == Variant::UrlSafeNoPadding
+	}
+
+	fn is_padded(self) -> bool {
+		self == Variant::Standard || self == Variant::UrlSafe
+	}
+}
+
+#[inline]
+/// Returns all ones if `a == b` and zero otherwise.
+fn ct_eq_mask(a: i16, b: i16) -> i16 {
+	((a ^ b) - 1) >> 8
+}
+
+#[inline]
+/// Encode the lower six bits of `sextet` as a Base64 character.
+fn encode_sextet(sextet: u8, url_safe: bool) -> u8 {
+	// Offsets from the character before 62 and 63 respectively.
+	let (offset_62, offset_63) = if url_safe { (13, 49) } else { (15, 3) };
+	let v = i16::from(sextet & 0x3f);
+
+	// Each (x - v) >> 8 is all ones if v > x, which moves `c` to the next range.
+	let mut c = v + 0x41;
+	c += ((25 - v) >> 8) & 6;
+	c -= ((51 - v) >> 8) & 75;
+	c -= ((61 - v) >> 8) & offset_62;
+	c += ((62 - v) >> 8) & offset_63;
+
+	c as u8
+}
+
+#[inline]
+/// Decode a Base64 character. Returns the decoded value and a mask that is all
+/// ones if `c` was a valid character and zero otherwise.
+fn decode_sextet(c: u8, url_safe: bool) -> (u8, u8) {
+	let (char_62, char_63) = if url_safe { (0x2d, 0x5f) } else { (0x2b, 0x2f) };
+	let c = i16::from(c);
+
+	// Each mask is all ones if `c` lies within the given range.
+	let upper = ((0x40 - c) & (c - 0x5b)) >> 8;
+	let lower = ((0x60 - c) & (c - 0x7b)) >> 8;
+	let digit = ((0x2f - c) & (c - 0x3a)) >> 8;
+	let is_62 = ct_eq_mask(c, char_62);
+	let is_63 = ct_eq_mask(c, char_63);
+
+	let value = (upper & (c - 0x41))
+		| (lower & (c - 0x47))
+		| (digit & (c + 0x04))
+		| (is_62 & 62)
+		| (is_63 & 63);
+
+	(value as u8, (upper | lower | digit | is_62 | is_63) as u8)
+}
+
+/// Remove the padding from `src` and return the remaining characters.
+fn strip_padding(src: &[u8], variant: Variant) -> Result<&[u8], UnknownCryptoError> {
+	let body = if variant.is_padded() {
+		if src.len() % 4 != 0 {
+			return Err(UnknownCryptoError::InvalidEncoding);
+		}
+		// The amount of padding only depends on the length of the decoded data.
+		let padding = src.iter().rev().take(2).take_while(|c| **c == b'=').count();
+		&src[..src.len() - padding]
+	} else {
+		src
+	};
+
+	if body.len() % 4 == 1 {
+		return Err(UnknownCryptoError::InvalidEncoding);
+	}
+
+	Ok(body)
+}
+
+#[must_use]
+/// Return the length of `length` bytes when encoded with `variant`.
+pub fn encoded_length(length: usize, variant: Variant) -> usize {
+	if variant.is_padded() {
+		((length + 2) / 3) * 4
+	} else {
+		(length * 4 + 2) / 3
+	}
+}
+
+#[must_use]
+/// Return the length of the data that `src` decodes to with `variant`.
+pub fn decoded_length(src: &str, variant: Variant) -> Result<usize, UnknownCryptoError> {
+	let body = strip_padding(src.as_bytes(), variant)?;
+
+	Ok((body.len() * 3) / 4)
+}
+
+#[must_use]
+/// Encode `src` as Base64 into `dst`.
+pub fn encode_to_slice(
+	src: &[u8],
+	dst: &mut [u8],
+	variant: Variant,
+) -> Result<(), UnknownCryptoError> {
+	if dst.len() != encoded_length(src.len(), variant) {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let url_safe = variant.is_url_safe();
+	let mut written = 0;
+	for block in src.chunks(3) {
+		let mut bytes = [0u8; 3];
+		bytes[..block.len()].copy_from_slice(block);
+		let sextets = [
+			bytes[0] >> 2,
+			(bytes[0] << 4) | (bytes[1] >> 4),
+			(bytes[1] << 2) | (bytes[2] >> 6),
+			bytes[2],
+		];
+		// A block of n bytes needs n + 1 characters.
+		for sextet in sextets.iter().take(block.len() + 1) {
+			dst[written] = encode_sextet(*sextet, url_safe);
+			written += 1;
+		}
+	}
+
+	for c in dst[written..].iter_mut() {
+		*c = b'=';
+	}
+
+	Ok(())
+}
+
+#[must_use]
+/// Decode the Base64 string `src` into `dst`.
+pub fn decode_to_slice(
+	src: &str,
+	dst: &mut [u8],
+	variant: Variant,
+) -> Result<(), UnknownCryptoError> {
+	let body = strip_padding(src.as_bytes(), variant)?;
+	if dst.len() != (body.len() * 3) / 4 {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let url_safe = variant.is_url_safe();
+	let mut valid = 0xffu8;
+	let mut written = 0;
+	for block in body.chunks(4) {
+		let mut sextets = [0u8; 4];
+		for (sextet, c) in sextets.iter_mut().zip(block.iter()) {
+			let (value, is_valid) = decode_sextet(*c, url_safe);
+			*sextet = value;
+			valid &= is_valid;
+		}
+		let bytes = [
+			(sextets[0] << 2) | (sextets[1] >> 4),
+			(sextets[1] << 4) | (sextets[2] >> 2),
+			(sextets[2] << 6) | sextets[3],
+		];
+		let length = block.len() - 1;
+		dst[written..written + length].copy_from_slice(&bytes[..length]);
+		written += length;
+
+		// The bits of a partial block that did not fit in the output must be zero.
+		if length < 3 {
+			let unused = i16::from(bytes[length]);
+			valid &= ct_eq_mask(unused, 0) as u8;
+		}
+	}
+
+	if valid == 0xff {
+		Ok(())
+	} else {
+		use zeroize::Zeroize;
+		dst.zeroize();
+
+		Err(UnknownCryptoError::InvalidEncoding)
+	}
+}
+
+#[must_use]
+#[cfg(feature = "safe_api")]
+/// Encode `src` as Base64.
+pub fn encode(src: &[u8], variant: Variant) -> String {
+	let mut dst = vec![0u8; encoded_length(src.len(), variant)];
+	encode_to_slice(src, &mut dst, variant).unwrap();
+
+	// The output only contains ASCII characters.
+	String::from_utf8(dst).unwrap()
+}
+
+#[must_use]
+#[cfg(feature = "safe_api")]
+/// Decode the Base64 string `src`.
+pub fn decode(src: &str, variant: Variant) -> Result<Vec<u8>, UnknownCryptoError> {
+	let mut dst = vec![0u8; decoded_length(src, variant)?];
+	decode_to_slice(src, &mut dst, variant)?;
+
+	Ok(dst)
+}
+
+#[must_use]
+/// Decode the Base64 string `src` and construct a newtype from it using
+/// `from_slice`.
+pub fn decode_into<T, F>(
+	src: &str,
+	variant: Variant,
+	from_slice: F,
+) -> Result<T, UnknownCryptoError>
+where
+	F: FnOnce(&[u8]) -> Result<T, UnknownCryptoError>,
+{
+	super::decode_with_buffer(
+		decoded_length(src, variant)?,
+		|buffer| decode_to_slice(src, buffer, variant),
+		from_slice,
+	)
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	const ALL_VARIANTS: [Variant; 4] = [
+		Variant::Standard,
+		Variant::StandardNoPadding,
+		Variant::UrlSafe,
+		Variant::UrlSafeNoPadding,
+	];
+
+	// Test vectors from RFC 4648, Section 10.
+	const RFC_VECTORS: [(&[u8], &str); 7] = [
+		(b"", ""),
+		(b"f", "Zg=="),
+		(b"fo", "Zm8="),
+		(b"foo", "Zm9v"),
+		(b"foob", "Zm9vYg=="),
+		(b"fooba", "Zm9vYmE="),
+		(b"foobar", "Zm9vYmFy"),
+	];
+
+	fn encode_array(src: &[u8], variant: Variant) -> ([u8; 16], usize) {
+		let mut dst = [0u8; 16];
+		let length = encoded_length(src.len(), variant);
+		encode_to_slice(src, &mut dst[..length], variant).unwrap();
+
+		(dst, length)
+	}
+
+	#[test]
+	fn test_rfc_vectors() {
+		for (data, encoded) in RFC_VECTORS.iter() {
+			let (dst, length) = encode_array(data, Variant::Standard);
+			assert_eq!(&dst[..length], encoded.as_bytes());
+
+			let unpadded = encoded.trim_end_matches('=');
+			let (dst, length) = encode_array(data, Variant::UrlSafeNoPadding);
+			assert_eq!(&dst[..length], unpadded.as_bytes());
+
+			let mut decoded = [0u8; 6];
+			decode_to_slice(encoded, &mut decoded[..data.len()], Variant::UrlSafe).unwrap();
+			assert_eq!(&decoded[..data.len()], *data);
+			decode_to_slice(
+				unpadded,
+				&mut decoded[..data.len()],
+				Variant::StandardNoPadding,
+			)
+			.unwrap();
+			assert_eq!(&decoded[..data.len()], *data);
+		}
+	}
+
+	#[test]
+	fn test_alphabets() {
+		let data = [0xfb, 0xff, 0xbf];
+		assert_eq!(&encode_array(&data, Variant::Standard).0[..4], b"+/+/");
+		assert_eq!(&encode_array(&data, Variant::UrlSafe).0[..4], b"-_-_");
+
+		let mut dst = [0u8; 3];
+		decode_to_slice("+/+/", &mut dst, Variant::Standard).unwrap();
+		assert_eq!(dst, data);
+		decode_to_slice("-_-_", &mut dst, Variant::UrlSafe).unwrap();
+		assert_eq!(dst, data);
+		assert!(decode_to_slice("-_-_", &mut dst, Variant::Standard).is_err());
+		assert!(decode_to_slice("+/+/", &mut dst, Variant::UrlSafe).is_err());
+	}
+
+	#[test]
+	fn test_encode_all_sextets() {
+		let expected = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+		for (sextet, c) in expected.iter().enumerate() {
+			for url_safe in [true, false].iter() {
+				assert_eq!(encode_sextet(sextet as u8, *url_safe), *c);
+				assert_eq!(decode_sextet(*c, *url_safe), (sextet as u8, 0xff));
+			}
+		}
+
+		for c in 0u8..=255 {
+			let in_alphabet = expected.contains(&c);
+			assert_eq!(
+				decode_sextet(c, false).1 == 0xff,
+				in_alphabet || c == b'+' || c == b'/'
+			);
+			assert_eq!(
+				decode_sextet(c, true).1 == 0xff,
+				in_alphabet || c == b'-' || c == b'_'
+			);
+		}
+	}
+
+	#[test]
+	fn test_padding_err() {
+		let mut dst = [0u8; 1];
+		assert!(decode_to_slice("Zg", &mut dst, Variant::Standard).is_err());
+		assert!(decode_to_slice("Zg=", &mut dst, Variant::Standard).is_err());
+		assert!(decode_to_slice("Zg==", &mut dst, Variant::StandardNoPadding).is_err());
+		assert!(decode_to_slice("Z===", &mut dst, Variant::Standard).is_err());
+		assert!(decode_to_slice("Z=g=", &mut dst, Variant::Standard).is_err());
+		assert!(decode_to_slice("====", &mut [], Variant::Standard).is_err());
+		assert!(decode_to_slice("Z", &mut [], Variant::StandardNoPadding).is_err());
+	}
+
+	#[test]
+	fn test_non_canonical_err() {
+		let mut dst = [0u8; 1];
+		assert!(decode_to_slice("Zg==", &mut dst, Variant::Standard).is_ok());
+		assert!(decode_to_slice("Zh==", &mut dst, Variant::Standard).is_err());
+		assert_eq!(dst, [0u8; 1]);
+
+		let mut dst = [0u8; 2];
+		assert!(decode_to_slice("Zm8", &mut dst, Variant::UrlSafeNoPadding).is_ok());
+		assert!(decode_to_slice("Zm9", &mut dst, Variant::UrlSafeNoPadding).is_err());
+		assert_eq!(dst, [0u8; 2]);
+	}
+
+	#[test]
+	fn test_length_err() {
+		assert!(encode_to_slice(b"foo", &mut [0u8; 3], Variant::Standard).is_err());
+		assert!(encode_to_slice(b"foo", &mut [0u8; 5], Variant::Standard).is_err());
+		assert!(encode_to_slice(b"f", &mut [0u8; 4], Variant::StandardNoPadding).is_err());
+		assert!(decode_to_slice("Zm9v", &mut [0u8; 2], Variant::Standard).is_err());
+		assert!(decode_to_slice("Zm9v", &mut [0u8; 4], Variant::Standard).is_err());
+
+		for variant in ALL_VARIANTS.iter() {
+			assert_eq!(decoded_length("", *variant).unwrap(), 0);
+		}
+		assert_eq!(decoded_length("Zm9vYg==", Variant::Standard).unwrap(), 4);
+		assert_eq!(
+			decoded_length("Zm9vYg", Variant::StandardNoPadding).unwrap(),
+			4
+		);
+		assert!(decoded_length("Zm9vY", Variant::StandardNoPadding).is_err());
+		assert!(decoded_length("Zm9vYg", Variant::Standard).is_err());
+	}
+
+	#[test]
+	fn test_decode_into() {
+		use crate::hazardous::stream::chacha20::SecretKey;
+
+		let key = decode_into(
+			"AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=",
+			Variant::Standard,
+			SecretKey::from_slice,
+		)
+		.unwrap();
+		let mut expected = [0u8; 32];
+		for (i, byte) in expected.iter_mut().enumerate() {
+			*byte = i as u8;
+		}
+		assert!(key == &expected[..]);
+
+		assert!(decode_into("AAE", Variant::UrlSafeNoPadding, SecretKey::from_slice).is_err());
+		assert!(decode_into("AA!=", Variant::Standard, |_| Ok(())).is_err());
+	}
+
+	// Proptests. Only exectued when NOT testing no_std.
+	#[cfg(feature = "safe_api")]
+	mod proptest {
+		use super::*;
+
+		quickcheck! {
+			/// Decoding encoded data should always return the data.
+			fn prop_encode_decode(data: Vec<u8>) -> bool {
+				ALL_VARIANTS.iter().all(|variant| {
+					let encoded = encode(&data, *variant);
+					encoded.len() == encoded_length(data.len(), *variant)
+						&& decode(&encoded, *variant).unwrap() == data
+				})
+			}
+		}
+
+		quickcheck! {
+			/// Decoding arbitrary input should never panic.
+			fn prop_decode_no_panic(input: String) -> bool {
+				for variant in ALL_VARIANTS.iter() {
+					let _ = decode(&input, *variant);
+				}
+
+				true
+			}
+		}
+	}
+}
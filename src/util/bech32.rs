@@ -0,0 +1,402 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Bech32 and Bech32m encoding and decoding.
+//!
+//! # About:
+//! - Bech32 is specified in [BIP 173] and Bech32m in [BIP 350]. They only
+//!   differ in the constant used for the checksum.
+//! - A string consists of a human-readable part, the separator `1`, the data
+//!   and a six character checksum.
+//! - The data is converted from bytes to 5-bit groups, padded with zero bits.
+//! - Encoding always produces lowercase. Decoding accepts either lowercase or
+//!   uppercase, but not a mix of both.
+//! - Unlike BIP 173, strings longer than 90 characters are allowed, so that
+//!   larger public keys can be encoded.
+//!
+//! # Parameters:
+//! - `hrp`: The human-readable part, such as `"age"` or `"bc"`.
+//! - `data`: The data to encode.
+//! - `src`: The Bech32 string to decode.
+//! - `variant`: Whether to use Bech32 or Bech32m.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - `hrp` is empty, longer than 83 characters, or contains characters outside
+//!   the printable ASCII range.
+//! - `src` has no separator, mixes lowercase and uppercase, or contains
+//!   characters outside the Bech32 alphabet.
+//! - The checksum of `src` is not valid for `variant`.
+//! - The padding bits of the data in `src` are not zero.
+//!
+//! # Security:
+//! - Bech32 is not constant-time and should only be used for public data, such
+//!   as public keys, fingerprints and addresses.
+//! - The checksum is guaranteed to detect up to four errors only for strings
+//!   of up to 89 characters.
+//!
+//! # Example:
+//! ```rust
+//! use orion::util::bech32::{self, Variant};
+//!
+//! let encoded = bech32::encode("orion", b"Public data", Variant::Bech32m)?;
+//! assert!(encoded.starts_with("orion1"));
+//!
+//! let (hrp, data) = bech32::decode(&encoded, Variant::Bech32m)?;
+//! assert_eq!(hrp, "orion");
+//! assert_eq!(data, b"Public data");
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [BIP 173]: https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki
+//! [BIP 350]: https://github.com/bitcoin/bips/blob/master/bip-0350.mediawiki
+
+use crate::errors::UnknownCryptoError;
+
+/// The Bech32 alphabet.
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// The generator of the BCH code used for the checksum.
+const GENERATOR: [u32; 5] = [
+	0x3b6a_57b2,
+	0x2650_8e6d,
+	0x1ea1_19fa,
+	0x3d42_33dd,
+	0x2a14_62b3,
+];
+
+/// The number of characters in the checksum.
+const CHECKSUM_LENGTH: usize = 6;
+
+/// The maximum length of the human-readable part.
+const MAX_HRP_LENGTH: usize = 83;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The checksum variant.
+pub enum Variant {
+	/// Bech32, as specified in BIP 173.
+	Bech32,
+	/// Bech32m, as specified in BIP 350.
+	Bech32m,
+}
+
+impl Variant {
+	fn constant(self) -> u32 {
+		match self {
+			Variant::Bech32 => 1,
+			Variant::Bech32m => 0x2bc8_30a3,
+		}
+	}
+}
+
+/// Compute the checksum polynomial over `values`.
+fn polymod<'a, I: Iterator<Item = &'a u8>>(values: I) -> u32 {
+	let mut checksum = 1u32;
+	for value in values {
+		let top = checksum >> 25;
+		checksum = ((checksum & 0x01ff_ffff) << 5) ^ u32::from(*value);
+		for (i, generator) in GENERATOR.iter().enumerate() {
+			if (top >> i) & 1 == 1 {
+				checksum ^= generator;
+			}
+		}
+	}
+
+	checksum
+}
+
+/// Expand the human-readable part for use in the checksum.
+fn expand_hrp(hrp: &[u8]) -> Vec<u8> {
+	let mut expanded = Vec::with_capacity(hrp.len() * 2 + 1);
+	expanded.extend(hrp.iter().map(|c| c >> 5));
+	expanded.push(0);
+	expanded.extend(hrp.iter().map(|c| c & 31));
+
+	expanded
+}
+
+/// Validate the human-readable part, which must already be lowercase.
+fn validate_hrp(hrp: &[u8]) -> Result<(), UnknownCryptoError> {
+	if hrp.is_empty() || hrp.len() > MAX_HRP_LENGTH || hrp.iter().any(|c| *c < 33 || *c > 126) {
+		return Err(UnknownCryptoError::InvalidEncoding);
+	}
+
+	Ok(())
+}
+
+/// Regroup `data` from groups of `from` bits into groups of `to` bits. When
+/// `pad` is false, leftover bits must be zero and fewer than `from`.
+fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Result<Vec<u8>, UnknownCryptoError> {
+	let mut accumulator = 0u32;
+	let mut bits = 0u32;
+	let max_value = (1u32 << to) - 1;
+	let mut converted = Vec::with_capacity((data.len() * from as usize) / to as usize + 1);
+
+	for value in data.iter() {
+		accumulator = (accumulator << from) | u32::from(*value);
+		bits += from;
+		while bits >= to {
+			bits -= to;
+			converted.push(((accumulator >> bits) & max_value) as u8);
+		}
+	}
+
+	if pad {
+		if bits > 0 {
+			converted.push(((accumulator << (to - bits)) & max_value) as u8);
+		}
+	} else if bits >= from || ((accumulator << (to - bits)) & max_value) != 0 {
+		return Err(UnknownCryptoError::InvalidEncoding);
+	}
+
+	Ok(converted)
+}
+
+/// Encode 5-bit groups in `data` with the human-readable part `hrp`.
+fn encode_groups(hrp: &str, data: &[u8], variant: Variant) -> Result<String, UnknownCryptoError> {
+	let hrp = hrp.to_ascii_lowercase();
+	validate_hrp(hrp.as_bytes())?;
+
+	let mut values = expand_hrp(hrp.as_bytes());
+	values.extend_from_slice(data);
+	values.extend_from_slice(&[0u8; CHECKSUM_LENGTH]);
+	let checksum = polymod(values.iter()) ^ variant.constant();
+
+	let mut encoded = String::with_capacity(hrp.len() + 1 + data.len() + CHECKSUM_LENGTH);
+	encoded.push_str(&hrp);
+	encoded.push('1');
+	encoded.extend(data.iter().map(|v| CHARSET[*v as usize] as char));
+	encoded.extend(
+		(0..CHECKSUM_LENGTH).map(|i| CHARSET[((checksum >> (5 * (5 - i))) & 31) as usize] as char),
+	);
+
+	Ok(encoded)
+}
+
+/// Decode `src` into its human-readable part and 5-bit groups.
+fn decode_groups(src: &str, variant: Variant) -> Result<(String, Vec<u8>), UnknownCryptoError> {
+	let has_lower = src.bytes().any(|c| c.is_ascii_lowercase());
+	let has_upper = src.bytes().any(|c| c.is_ascii_uppercase());
+	if has_lower && has_upper {
+		return Err(UnknownCryptoError::InvalidEncoding);
+	}
+
+	let src = src.to_ascii_lowercase();
+	let separator = match src.rfind('1') {
+		Some(index) => index,
+		None => return Err(UnknownCryptoError::InvalidEncoding),
+	};
+	let (hrp, rest) = (
+		&src.as_bytes()[..separator],
+		&src.as_bytes()[separator + 1..],
+	);
+	validate_hrp(hrp)?;
+	if rest.len() < CHECKSUM_LENGTH {
+		return Err(UnknownCryptoError::InvalidEncoding);
+	}
+
+	let mut data = Vec::with_capacity(rest.len());
+	for c in rest.iter() {
+		match CHARSET.iter().position(|a| a == c) {
+			Some(value) => data.push(value as u8),
+			None => return Err(UnknownCryptoError::InvalidEncoding),
+		}
+	}
+
+	if polymod(expand_hrp(hrp).iter().chain(data.iter())) != variant.constant() {
+		return Err(UnknownCryptoError::InvalidEncoding);
+	}
+	data.truncate(data.len() - CHECKSUM_LENGTH);
+
+	// The human-readable part only contains ASCII characters.
+	Ok((String::from_utf8(hrp.to_vec()).unwrap(), data))
+}
+
+#[must_use]
+/// Encode `data` as Bech32 with the human-readable part `hrp`.
+pub fn encode(hrp: &str, data: &[u8], variant: Variant) -> Result<String, UnknownCryptoError> {
+	encode_groups(hrp, &convert_bits(data, 8, 5, true)?, variant)
+}
+
+#[must_use]
+/// Decode the Bech32 string `src` and return its human-readable part and data.
+pub fn decode(src: &str, variant: Variant) -> Result<(String, Vec<u8>), UnknownCryptoError> {
+	let (hrp, groups) = decode_groups(src, variant)?;
+
+	Ok((hrp, convert_bits(&groups, 5, 8, false)?))
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	#[test]
+	fn test_encode_decode() {
+		let encoded = encode("orion", b"Public data", Variant::Bech32).unwrap();
+		let (hrp, data) = decode(&encoded, Variant::Bech32).unwrap();
+		assert_eq!(hrp, "orion");
+		assert_eq!(data, b"Public data");
+		assert!(decode(&encoded, Variant::Bech32m).is_err());
+
+		let (hrp, data) = decode(&encoded.to_ascii_uppercase(), Variant::Bech32).unwrap();
+		assert_eq!(hrp, "orion");
+		assert_eq!(data, b"Public data");
+
+		assert_eq!(
+			encode("ORION", b"", Variant::Bech32m).unwrap(),
+			encode("orion", b"", Variant::Bech32m).unwrap()
+		);
+	}
+
+	#[test]
+	fn test_invalid_hrp_err() {
+		assert!(encode("", b"data", Variant::Bech32).is_err());
+		assert!(encode("a b", b"data", Variant::Bech32).is_err());
+		assert!(encode("é", b"data", Variant::Bech32).is_err());
+		assert!(encode(&"a".repeat(84), b"data", Variant::Bech32).is_err());
+		assert!(encode(&"a".repeat(83), b"data", Variant::Bech32).is_ok());
+	}
+
+	#[test]
+	fn test_non_zero_padding_err() {
+		// 1 byte needs two 5-bit groups, leaving two padding bits.
+		let encoded = encode_groups("a", &[0, 1], Variant::Bech32).unwrap();
+		assert!(decode(&encoded, Variant::Bech32).is_err());
+		let encoded = encode_groups("a", &[0, 0], Variant::Bech32).unwrap();
+		assert_eq!(decode(&encoded, Variant::Bech32).unwrap().1, [0u8]);
+		// A full group of padding is not allowed.
+		let encoded = encode_groups("a", &[0, 0, 0], Variant::Bech32).unwrap();
+		assert!(decode(&encoded, Variant::Bech32).is_err());
+	}
+
+	// Proptests. Only exectued when NOT testing no_std.
+	#[cfg(feature = "safe_api")]
+	mod proptest {
+		use super::*;
+
+		quickcheck! {
+			/// Decoding encoded data should always return the data.
+			fn prop_encode_decode(data: Vec<u8>) -> bool {
+				let encoded = encode("orion", &data, Variant::Bech32m).unwrap();
+
+				decode(&encoded, Variant::Bech32m).unwrap() == ("orion".to_string(), data)
+			}
+		}
+
+		quickcheck! {
+			/// Decoding arbitrary input should never panic.
+			fn prop_decode_no_panic(input: String) -> bool {
+				let _ = decode(&input, Variant::Bech32);
+
+				true
+			}
+		}
+	}
+}
+
+// Testing private functions in the module.
+#[cfg(test)]
+mod private {
+	use super::*;
+
+	// Valid test vectors from BIP 173 and BIP 350.
+	const VALID_BECH32: [&str; 6] = [
+		"A12UEL5L",
+		"a12uel5l",
+		"an83characterlonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio1tt5tgs",
+		"abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw",
+		"11qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqc8247j",
+		"split1checkupstagehandshakeupstreamerranterredcaperred2y9e3w",
+	];
+
+	const VALID_BECH32M: [&str; 6] = [
+		"A1LQFN3A",
+		"a1lqfn3a",
+		"an83characterlonghumanreadablepartthatcontainsthetheexcludedcharactersbioandnumber11sg7hg6",
+		"abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx",
+		"11llllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllludsr8",
+		"split1checkupstagehandshakeupstreamerranterredcaperredlc445v",
+	];
+
+	// Invalid test vectors from BIP 173 and BIP 350.
+	const INVALID: [&str; 8] = [
+		// No separator.
+		"pzry9x0s0muk",
+		// Empty human-readable part.
+		"1pzry9x0s0muk",
+		// Invalid data character.
+		"x1b4n0q5v",
+		// Too short checksum.
+		"li1dgmt3",
+		// Checksum calculated with uppercase form of human-readable part.
+		"A1G7SGD8",
+		// Empty human-readable part.
+		"10a06t8",
+		// Empty human-readable part.
+		"1qzzfhee",
+		// Mixed case.
+		"A12uEL5L",
+	];
+
+	#[test]
+	fn test_valid_vectors() {
+		for src in VALID_BECH32.iter() {
+			assert!(decode_groups(src, Variant::Bech32).is_ok(), "{}", src);
+			assert!(decode_groups(src, Variant::Bech32m).is_err(), "{}", src);
+		}
+		for src in VALID_BECH32M.iter() {
+			assert!(decode_groups(src, Variant::Bech32m).is_ok(), "{}", src);
+			assert!(decode_groups(src, Variant::Bech32).is_err(), "{}", src);
+		}
+	}
+
+	#[test]
+	fn test_valid_vectors_encode() {
+		for (src, variant) in VALID_BECH32
+			.iter()
+			.map(|s| (s, Variant::Bech32))
+			.chain(VALID_BECH32M.iter().map(|s| (s, Variant::Bech32m)))
+		{
+			let (hrp, data) = decode_groups(src, variant).unwrap();
+			assert_eq!(
+				encode_groups(&hrp, &data, variant).unwrap(),
+				src.to_ascii_lowercase()
+			);
+		}
+	}
+
+	#[test]
+	fn test_invalid_vectors() {
+		for src in INVALID.iter() {
+			assert!(decode_groups(src, Variant::Bech32).is_err(), "{}", src);
+			assert!(decode_groups(src, Variant::Bech32m).is_err(), "{}", src);
+		}
+	}
+
+	#[test]
+	fn test_convert_bits() {
+		assert_eq!(convert_bits(&[0xff], 8, 5, true).unwrap(), [31, 28]);
+		assert_eq!(convert_bits(&[31, 28], 5, 8, false).unwrap(), [0xff]);
+		assert!(convert_bits(&[31, 29], 5, 8, false).is_err());
+		assert_eq!(convert_bits(&[], 8, 5, true).unwrap(), Vec::<u8>::new());
+	}
+}
@@ -0,0 +1,316 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Constant-time hexadecimal encoding and decoding.
+//!
+//! # About:
+//! - Encoding produces lowercase hexadecimal. Decoding accepts both lowercase
+//!   and uppercase.
+//! - Neither direction uses lookup tables or branches that depend on the data,
+//!   so they can be used on secret keys.
+//! - [`decode_into()`] decodes directly into a newtype, such as a `SecretKey`,
+//!   without leaving an unwiped copy of the decoded bytes behind.
+//! - Non-secret newtypes, such as a `Digest`, `Tag`, `PublicKey` or
+//!   `Signature`, implement `LowerHex` and `UpperHex` and provide `to_hex()`.
+//!   These do not run in constant time.
+//!
+//! # Parameters:
+//! - `src`: The data to encode, or the hexadecimal string to decode.
+//! - `dst`: The buffer to write the encoded or decoded data to.
+//! - `from_slice`: The function that constructs a newtype from the decoded
+//!   bytes, such as `SecretKey::from_slice`.
+//!
+//! # Errors:
+//! An error will be returned if:
+//! - The length of `src` is not a multiple of two when decoding.
+//! - `src` contains a character that is not hexadecimal.
+//! - The length of `dst` does not match the encoded or decoded length of
+//!   `src`.
+//! - `from_slice` returns an error.
+//! - The decoded data is longer than 256 bytes in [`decode_into()`] when
+//!   used in `no_std`.
+//!
+//! # Security:
+//! - The length of the input is not hidden, and neither is whether decoding
+//!   failed.
+//! - If decoding fails, `dst` is overwritten with zeroes.
+//!
+//! # Example:
+//! ```rust
+//! use orion::hazardous::stream::chacha20::SecretKey;
+//! use orion::util::hex;
+//!
+//! let encoded = hex::encode(&[0xde, 0xad, 0xbe, 0xef]);
+//! assert_eq!(encoded, "deadbeef");
+//! assert_eq!(hex::decode(&encoded)?, [0xde, 0xad, 0xbe, 0xef]);
+//!
+//! let digest = orion::hazardous::hash::sha256::digest(b"abc")?;
+//! assert_eq!(
+//! 	format!("{:x}", digest),
+//! 	"ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+//! );
+//! assert_eq!(digest.to_hex(), format!("{:x}", digest));
+//!
+//! let secret_key = hex::decode_into(
+//! 	"000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+//! 	SecretKey::from_slice,
+//! )?;
+//! # Ok::<(), orion::errors::UnknownCryptoError>(())
+//! ```
+//! [`decode_into()`]: fn.decode_into.html
+
+use crate::errors::UnknownCryptoError;
+
+#[inline]
+/// Encode the lower four bits of `nibble` as a lowercase hexadecimal
+/// character.
+fn encode_nibble(nibble: u8) -> u8 {
+	let n = i16::from(nibble & 0x0f);
+	// (9 - n) >> 8 is all ones if n > 9, which adds the offset from ':' to 'a'.
+	(n + 0x30 + (((9 - n) >> 8) & 0x27)) as u8
+}
+
+#[inline]
+/// Decode a hexadecimal character. Returns the decoded value and a mask that
+/// is all ones if `c` was a valid character and zero otherwise.
+fn decode_nibble(c: u8) -> (u8, u8) {
+	let c = i16::from(c);
+	// Each mask is all ones if `c` lies within the given range.
+	let digit = ((0x2f - c) & (c - 0x3a)) >> 8;
+	let lower = ((0x60 - c) & (c - 0x67)) >> 8;
+	let upper = ((0x40 - c) & (c - 0x47)) >> 8;
+
+	let value = (digit & (c - 0x30)) | (lower & (c - 0x57)) | (upper & (c - 0x37));
+
+	(value as u8, (digit | lower | upper) as u8)
+}
+
+#[must_use]
+/// Encode `src` as lowercase hexadecimal into `dst`.
+pub fn encode_to_slice(src: &[u8], dst: &mut [u8]) -> Result<(), UnknownCryptoError> {
+	if dst.len() != src.len() * 2 {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	for (byte, out) in src.iter().zip(dst.chunks_exact_mut(2)) {
+		out[0] = encode_nibble(byte >> 4);
+		out[1] = encode_nibble(*byte);
+	}
+
+	Ok(())
+}
+
+#[must_use]
+/// Decode the hexadecimal string `src` into `dst`.
+pub fn decode_to_slice(src: &str, dst: &mut [u8]) -> Result<(), UnknownCryptoError> {
+	let src = src.as_bytes();
+	if src.len() % 2 != 0 {
+		return Err(UnknownCryptoError::InvalidEncoding);
+	}
+	if dst.len() != src.len() / 2 {
+		return Err(UnknownCryptoError::InvalidLength);
+	}
+
+	let mut valid = 0xffu8;
+	for (pair, out) in src.chunks_exact(2).zip(dst.iter_mut()) {
+		let (high, high_valid) = decode_nibble(pair[0]);
+		let (low, low_valid) = decode_nibble(pair[1]);
+		*out = (high << 4) | low;
+		valid &= high_valid & low_valid;
+	}
+
+	if valid == 0xff {
+		Ok(())
+	} else {
+		use zeroize::Zeroize;
+		dst.zeroize();
+
+		Err(UnknownCryptoError::InvalidEncoding)
+	}
+}
+
+#[must_use]
+#[cfg(feature = "safe_api")]
+/// Encode `src` as lowercase hexadecimal.
+pub fn encode(src: &[u8]) -> String {
+	let mut dst = vec![0u8; src.len() * 2];
+	encode_to_slice(src, &mut dst).unwrap();
+
+	// The output only contains ASCII characters.
+	String::from_utf8(dst).unwrap()
+}
+
+#[must_use]
+#[cfg(feature = "safe_api")]
+/// Decode the hexadecimal string `src`.
+pub fn decode(src: &str) -> Result<Vec<u8>, UnknownCryptoError> {
+	let mut dst = vec![0u8; src.len() / 2];
+	decode_to_slice(src, &mut dst)?;
+
+	Ok(dst)
+}
+
+#[must_use]
+/// Decode the hexadecimal string `src` and construct a newtype from it using
+/// `from_slice`.
+pub fn decode_into<T, F>(src: &str, from_slice: F) -> Result<T, UnknownCryptoError>
+where
+	F: FnOnce(&[u8]) -> Result<T, UnknownCryptoError>,
+{
+	super::decode_with_buffer(
+		src.len() / 2,
+		|buffer| decode_to_slice(src, buffer),
+		from_slice,
+	)
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	#[test]
+	fn test_encode_to_slice() {
+		let mut dst = [0u8; 32];
+		encode_to_slice(
+			&[
+				0x00, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0x10, 0x32, 0x54, 0x76, 0x98,
+				0xba, 0xdc,
+			],
+			&mut dst,
+		)
+		.unwrap();
+		assert_eq!(&dst, b"000123456789abcdef1032547698badc");
+
+		assert!(encode_to_slice(&[0u8; 16], &mut [0u8; 31]).is_err());
+		assert!(encode_to_slice(&[0u8; 16], &mut [0u8; 33]).is_err());
+		assert!(encode_to_slice(&[], &mut []).is_ok());
+	}
+
+	#[test]
+	fn test_decode_to_slice() {
+		let mut dst = [0u8; 8];
+		decode_to_slice("0123456789abcdef", &mut dst).unwrap();
+		assert_eq!(dst, [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef]);
+		decode_to_slice("0123456789ABCDEF", &mut dst).unwrap();
+		assert_eq!(dst, [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef]);
+
+		assert!(decode_to_slice("", &mut []).is_ok());
+		assert_eq!(
+			decode_to_slice("0123456789abcdef", &mut [0u8; 7]),
+			Err(UnknownCryptoError::InvalidLength)
+		);
+		assert_eq!(
+			decode_to_slice("0123456789abcdef", &mut [0u8; 9]),
+			Err(UnknownCryptoError::InvalidLength)
+		);
+		assert_eq!(
+			decode_to_slice("0123456789abcde", &mut [0u8; 7]),
+			Err(UnknownCryptoError::InvalidEncoding)
+		);
+	}
+
+	#[test]
+	fn test_decode_invalid_characters() {
+		let mut dst = [0u8; 1];
+		for c in 0u8..=255 {
+			let valid = (c as char).is_ascii_hexdigit();
+			let src = [c, b'0'];
+			if let Ok(src) = core::str::from_utf8(&src) {
+				assert_eq!(decode_to_slice(src, &mut dst).is_ok(), valid);
+			}
+		}
+
+		assert_eq!(
+			decode_to_slice("0g", &mut dst),
+			Err(UnknownCryptoError::InvalidEncoding)
+		);
+		assert!(decode_to_slice("G0", &mut dst).is_err());
+		assert!(decode_to_slice("é", &mut dst).is_err());
+	}
+
+	#[test]
+	fn test_decode_err_zeroes_dst() {
+		let mut dst = [0u8; 4];
+		assert!(decode_to_slice("ffffffzz", &mut dst).is_err());
+		assert_eq!(dst, [0u8; 4]);
+	}
+
+	#[test]
+	fn test_decode_into() {
+		use crate::hazardous::stream::chacha20::SecretKey;
+
+		let key = decode_into(
+			"000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+			SecretKey::from_slice,
+		)
+		.unwrap();
+		let mut expected = [0u8; 32];
+		for (i, byte) in expected.iter_mut().enumerate() {
+			*byte = i as u8;
+		}
+		assert!(key == &expected[..]);
+
+		assert!(decode_into("0001", SecretKey::from_slice).is_err());
+		assert!(decode_into("zz", |_| Ok(())).is_err());
+	}
+
+	#[test]
+	#[cfg(feature = "safe_api")]
+	fn test_encode_decode() {
+		assert_eq!(encode(b""), "");
+		assert_eq!(encode(b"foobar"), "666f6f626172");
+		assert_eq!(decode("666F6F626172").unwrap(), b"foobar");
+		assert!(decode("666f6f62617").is_err());
+	}
+
+	// Proptests. Only exectued when NOT testing no_std.
+	#[cfg(feature = "safe_api")]
+	mod proptest {
+		use super::*;
+
+		quickcheck! {
+			/// Encoding should always match the hex crate.
+			fn prop_encode_same_as_hex_crate(data: Vec<u8>) -> bool {
+				encode(&data) == ::hex::encode(&data)
+			}
+		}
+
+		quickcheck! {
+			/// Decoding encoded data should always return the data.
+			fn prop_encode_decode(data: Vec<u8>) -> bool {
+				decode(&encode(&data)).unwrap() == data
+					&& decode(&encode(&data).to_uppercase()).unwrap() == data
+			}
+		}
+
+		quickcheck! {
+			/// Decoding should succeed exactly when the hex crate succeeds.
+			fn prop_decode_same_as_hex_crate(input: String) -> bool {
+				match ::hex::decode(&input) {
+					Ok(expected) => decode(&input).unwrap() == expected,
+					Err(_) => decode(&input).is_err(),
+				}
+			}
+		}
+	}
+}
@@ -23,6 +23,45 @@
 use crate::errors;
 use subtle::ConstantTimeEq;
 
+#[cfg(feature = "safe_api")]
+/// ASCII armor with a checksum.
+pub mod armor;
+
+/// Constant-time Base32 encoding and decoding.
+pub mod base32;
+
+#[cfg(feature = "safe_api")]
+/// Base58 and Base58Check encoding and decoding.
+pub mod base58;
+
+/// Constant-time Base64 encoding and decoding.
+pub mod base64;
+
+#[cfg(feature = "safe_api")]
+/// Bech32 and Bech32m encoding and decoding.
+pub mod bech32;
+
+/// HMAC-DRBG as specified in NIST SP 800-90A.
+mod hmac_drbg;
+pub use self::hmac_drbg::HmacDrbg;
+
+#[cfg(feature = "safe_api")]
+/// A userspace CSPRNG based on ChaCha20.
+mod rng;
+#[cfg(feature = "safe_api")]
+pub use self::rng::Rng;
+
+/// Constant-time hexadecimal encoding and decoding.
+pub mod hex;
+
+pub mod pem;
+
+#[cfg(feature = "alloc")]
+/// A growable buffer for sensitive data.
+mod secret_vec;
+#[cfg(feature = "alloc")]
+pub use self::secret_vec::SecretVec;
+
 #[must_use]
 #[cfg(feature = "safe_api")]
 /// Generate random bytes using a CSPRNG. Not available in `no_std` context.
@@ -59,7 +98,7 @@ use subtle::ConstantTimeEq;
 /// ```
 pub fn secure_rand_bytes(dst: &mut [u8]) -> Result<(), errors::UnknownCryptoError> {
 	if dst.is_empty() {
-		return Err(errors::UnknownCryptoError);
+		return Err(errors::UnknownCryptoError::InvalidLength);
 	}
 
 	getrandom::getrandom(dst)?;
@@ -67,6 +106,261 @@ pub fn secure_rand_bytes(dst: &mut [u8]) -> Result<(), errors::UnknownCryptoErro
 	Ok(())
 }
 
+#[must_use]
+#[cfg(feature = "safe_api")]
+/// Generate a random `u32` using a CSPRNG. Not available in `no_std` context.
+///
+/// # Panics:
+/// A panic will occur if:
+/// - Failure to generate random bytes securely.
+///
+/// # Example:
+/// ```rust
+/// use orion::util;
+///
+/// let id = util::secure_rand_u32()?;
+/// # Ok::<(), orion::errors::UnknownCryptoError>(())
+/// ```
+pub fn secure_rand_u32() -> Result<u32, errors::UnknownCryptoError> {
+	let mut bytes = [0u8; 4];
+	secure_rand_bytes(&mut bytes)?;
+
+	Ok(u32::from_le_bytes(bytes))
+}
+
+#[must_use]
+#[cfg(feature = "safe_api")]
+/// Generate a random `u64` using a CSPRNG. Not available in `no_std` context.
+///
+/// # Panics:
+/// A panic will occur if:
+/// - Failure to generate random bytes securely.
+///
+/// # Example:
+/// ```rust
+/// use orion::util;
+///
+/// let id = util::secure_rand_u64()?;
+/// # Ok::<(), orion::errors::UnknownCryptoError>(())
+/// ```
+pub fn secure_rand_u64() -> Result<u64, errors::UnknownCryptoError> {
+	let mut bytes = [0u8; 8];
+	secure_rand_bytes(&mut bytes)?;
+
+	Ok(u64::from_le_bytes(bytes))
+}
+
+#[must_use]
+#[cfg(feature = "safe_api")]
+/// Generate a uniformly distributed random integer in `range` using a CSPRNG.
+/// Not available in `no_std` context.
+///
+/// # About:
+/// Reducing a random value with `%` makes some results more likely than
+/// others, unless the size of the range is a power of two. This function
+/// instead uses rejection sampling, so every value in `range` is equally
+/// likely.
+///
+/// # Parameters:
+/// - `range`: The half-open range to pick a value from.
+///
+/// # Errors:
+/// An error will be returned if:
+/// - `range` is empty.
+///
+/// # Panics:
+/// A panic will occur if:
+/// - Failure to generate random bytes securely.
+///
+/// # Example:
+/// ```rust
+/// use orion::util;
+///
+/// let die = util::secure_rand_range(1..7)?;
+/// assert!(die >= 1 && die < 7);
+/// # Ok::<(), orion::errors::UnknownCryptoError>(())
+/// ```
+pub fn secure_rand_range(range: core::ops::Range<u64>) -> Result<u64, errors::UnknownCryptoError> {
+	if range.start >= range.end {
+		return Err(errors::UnknownCryptoError::ParameterOutOfRange);
+	}
+
+	let span = range.end - range.start;
+	// Values below `threshold` would make the lowest `2^64 % span` results more
+	// likely, so they are rejected.
+	let threshold = span.wrapping_neg() % span;
+	loop {
+		let value = secure_rand_u64()?;
+		if value >= threshold {
+			return Ok(range.start + value % span);
+		}
+	}
+}
+
+/// The characters `a-z`, `A-Z` and `0-9`.
+pub const PASSWORD_CHARSET_ALPHANUMERIC: &str =
+	"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+#[must_use]
+#[cfg(feature = "safe_api")]
+/// Generate a random password of `length` characters from `charset` using a
+/// CSPRNG. Not available in `no_std` context.
+///
+/// # About:
+/// Each character is picked uniformly at random from `charset` using
+/// [`secure_rand_range`], so a password has `length * log2(charset size)`
+/// bits of entropy.
+///
+/// # Parameters:
+/// - `length`: The number of characters in the password.
+/// - `charset`: The characters to pick from, such as
+///   [`PASSWORD_CHARSET_ALPHANUMERIC`].
+///
+/// # Errors:
+/// An error will be returned if:
+/// - `length` is `0`.
+/// - `charset` has less than two characters.
+/// - `charset` contains the same character more than once.
+///
+/// # Panics:
+/// A panic will occur if:
+/// - Failure to generate random bytes securely.
+///
+/// # Security:
+/// - The returned `String` is not zeroed when dropped.
+///
+/// # Example:
+/// ```rust
+/// use orion::util;
+///
+/// let password = util::generate_password(20, util::PASSWORD_CHARSET_ALPHANUMERIC)?;
+/// assert_eq!(password.len(), 20);
+/// # Ok::<(), orion::errors::UnknownCryptoError>(())
+/// ```
+/// [`secure_rand_range`]: fn.secure_rand_range.html
+/// [`PASSWORD_CHARSET_ALPHANUMERIC`]: constant.PASSWORD_CHARSET_ALPHANUMERIC.html
+pub fn generate_password(
+	length: usize,
+	charset: &str,
+) -> Result<String, errors::UnknownCryptoError> {
+	let mut chars: Vec<char> = charset.chars().collect();
+	let count = chars.len();
+	chars.sort_unstable();
+	chars.dedup();
+	if length == 0 || count < 2 || chars.len() != count {
+		return Err(errors::UnknownCryptoError::ParameterOutOfRange);
+	}
+
+	let mut password = String::with_capacity(length);
+	for _ in 0..length {
+		password.push(chars[secure_rand_range(0..count as u64)? as usize]);
+	}
+
+	Ok(password)
+}
+
+#[must_use]
+#[cfg(feature = "safe_api")]
+/// Generate a random diceware-style passphrase of `words` words from
+/// `wordlist` using a CSPRNG. Not available in `no_std` context.
+///
+/// # About:
+/// Each word is picked uniformly at random from `wordlist` using
+/// [`secure_rand_range`], and the words are joined with `separator`. orion does
+/// not bundle a wordlist. A list designed for this purpose, such as the [EFF
+/// large wordlist] of 7776 words (about 12.9 bits per word), should be used.
+///
+/// # Parameters:
+/// - `words`: The number of words in the passphrase.
+/// - `wordlist`: The words to pick from.
+/// - `separator`: The string placed between words.
+///
+/// # Errors:
+/// An error will be returned if:
+/// - `words` is `0`.
+/// - `wordlist` has less than two words.
+/// - `wordlist` contains the same word more than once.
+///
+/// # Panics:
+/// A panic will occur if:
+/// - Failure to generate random bytes securely.
+///
+/// # Security:
+/// - The returned `String` is not zeroed when dropped.
+///
+/// # Example:
+/// ```rust
+/// use orion::util;
+///
+/// let wordlist = ["correct", "horse", "battery", "staple"];
+/// let passphrase = util::generate_passphrase(6, &wordlist, "-")?;
+/// assert_eq!(passphrase.split('-').count(), 6);
+/// # Ok::<(), orion::errors::UnknownCryptoError>(())
+/// ```
+/// [`secure_rand_range`]: fn.secure_rand_range.html
+/// [EFF large wordlist]: https://www.eff.org/dice
+pub fn generate_passphrase(
+	words: usize,
+	wordlist: &[&str],
+	separator: &str,
+) -> Result<String, errors::UnknownCryptoError> {
+	let mut sorted = wordlist.to_vec();
+	sorted.sort_unstable();
+	sorted.dedup();
+	if words == 0 || wordlist.len() < 2 || sorted.len() != wordlist.len() {
+		return Err(errors::UnknownCryptoError::ParameterOutOfRange);
+	}
+
+	let mut passphrase = String::new();
+	for i in 0..words {
+		if i > 0 {
+			passphrase.push_str(separator);
+		}
+		passphrase.push_str(wordlist[secure_rand_range(0..wordlist.len() as u64)? as usize]);
+	}
+
+	Ok(passphrase)
+}
+
+/// A source of cryptographically secure random bytes.
+///
+/// # About:
+/// The high-level functions that generate keys, nonces or salts, such as
+/// `aead::seal()` and `pwhash::hash_password()`, use [`OsRng`]. Each of them
+/// has a `_with_rng` variant that accepts any `SecureRandom` instead. This
+/// makes it possible to use a faster generator such as [`Rng`], or a
+/// deterministic one such as [`HmacDrbg`] in tests.
+///
+/// # Security:
+/// - Implementations must return an error rather than produce predictable
+///   output.
+/// - A deterministic generator with a fixed seed must never be used outside of
+///   tests.
+///
+/// [`OsRng`]: struct.OsRng.html
+/// [`Rng`]: struct.Rng.html
+/// [`HmacDrbg`]: struct.HmacDrbg.html
+pub trait SecureRandom {
+	#[must_use]
+	/// Fill `dst` with random bytes. An error is returned if `dst` is empty.
+	fn fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), errors::UnknownCryptoError>;
+}
+
+#[cfg(feature = "safe_api")]
+#[derive(Debug, Clone, Copy, Default)]
+/// The random generator of the OS, as used by [`secure_rand_bytes()`]. Not
+/// available in `no_std` context.
+///
+/// [`secure_rand_bytes()`]: fn.secure_rand_bytes.html
+pub struct OsRng;
+
+#[cfg(feature = "safe_api")]
+impl SecureRandom for OsRng {
+	fn fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), errors::UnknownCryptoError> {
+		secure_rand_bytes(dst)
+	}
+}
+
 #[must_use]
 /// Compare two equal length slices in constant time.
 ///
@@ -98,8 +392,248 @@ pub fn secure_cmp(a: &[u8], b: &[u8]) -> Result<bool, errors::UnknownCryptoError
 	if a.ct_eq(b).into() {
 		Ok(true)
 	} else {
-		Err(errors::UnknownCryptoError)
+		Err(errors::UnknownCryptoError::InvalidTag)
+	}
+}
+
+#[must_use]
+/// Pad the first `unpadded_length` bytes of `buf` to a multiple of
+/// `block_size`, returning the padded length.
+///
+/// # About:
+/// The padding is that of ISO/IEC 7816-4: a `0x80` byte followed by as many
+/// zero bytes as are needed. At least one byte is always added. This is the
+/// padding of libsodium's `sodium_pad()`, and the output is the same.
+///
+/// Padding messages before encrypting them hides their exact length, only
+/// revealing it rounded up to `block_size`. The amount of padding is not
+/// revealed by the time taken.
+///
+/// # Parameters:
+/// - `buf`: The buffer holding the data, with room for the padding.
+/// - `unpadded_length`: The length of the data at the start of `buf`.
+/// - `block_size`: The padded length is a multiple of this.
+///
+/// # Errors:
+/// An error will be returned if:
+/// - `block_size` is 0.
+/// - `buf` is too short for the data and its padding.
+///
+/// # Example:
+/// ```rust
+/// use orion::util;
+///
+/// let mut buf = [0u8; 32];
+/// buf[..5].copy_from_slice(b"hello");
+/// let padded_length = util::pad(&mut buf, 5, 16)?;
+/// assert_eq!(padded_length, 16);
+///
+/// assert_eq!(util::unpad(&buf[..padded_length], 16)?, 5);
+/// # Ok::<(), orion::errors::UnknownCryptoError>(())
+/// ```
+pub fn pad(
+	buf: &mut [u8],
+	unpadded_length: usize,
+	block_size: usize,
+) -> Result<usize, errors::UnknownCryptoError> {
+	if block_size == 0 {
+		return Err(errors::UnknownCryptoError::ParameterOutOfRange);
+	}
+
+	// The amount of zero bytes, and the position of the last padding byte.
+	let zeroes = block_size - 1 - (unpadded_length % block_size);
+	let last = match unpadded_length.checked_add(zeroes) {
+		Some(last) if last < buf.len() => last,
+		_ => return Err(errors::UnknownCryptoError::InvalidLength),
+	};
+
+	// Write the last block_size bytes, up to and including the padding, so
+	// that the memory accessed does not depend on the amount of padding.
+	let mut mask = 0u8;
+	for idx in 0..block_size {
+		let is_marker = ((idx ^ zeroes).wrapping_sub(1) >> (usize::BITS - 8)) as u8;
+		buf[last - idx] = (buf[last - idx] & mask) | (0x80 & is_marker);
+		mask |= is_marker;
+	}
+
+	Ok(last + 1)
+}
+
+#[must_use]
+/// Return the length of `buf` without its padding, as added by [`pad()`].
+///
+/// # About:
+/// This is the same as libsodium's `sodium_unpad()`. The last `block_size`
+/// bytes of `buf` are checked in constant time.
+///
+/// # Parameters:
+/// - `buf`: The padded data.
+/// - `block_size`: The block size that `buf` was padded with.
+///
+/// # Errors:
+/// An error will be returned if:
+/// - `block_size` is 0.
+/// - `buf` is shorter than `block_size`.
+/// - The last `block_size` bytes of `buf` do not hold valid padding.
+///
+/// # Example:
+/// ```rust
+/// use orion::util;
+///
+/// assert_eq!(util::unpad(b"hello\x80\x00\x00", 8)?, 5);
+/// assert!(util::unpad(b"hello\x00\x00\x00", 8).is_err());
+/// # Ok::<(), orion::errors::UnknownCryptoError>(())
+/// ```
+/// [`pad()`]: fn.pad.html
+pub fn unpad(buf: &[u8], block_size: usize) -> Result<usize, errors::UnknownCryptoError> {
+	if block_size == 0 {
+		return Err(errors::UnknownCryptoError::ParameterOutOfRange);
+	}
+	if buf.len() < block_size {
+		return Err(errors::UnknownCryptoError::InvalidLength);
+	}
+
+	// The accumulated bytes seen, from the end, and the amount of zero bytes
+	// before the marker. A byte is the marker if it is 0x80 and only zero
+	// bytes follow it.
+	let mut seen = 0usize;
+	let mut zeroes = 0usize;
+	let mut is_valid = 0usize;
+	for (idx, byte) in buf.iter().rev().take(block_size).enumerate() {
+		let byte = usize::from(*byte);
+		let is_marker =
+			((seen.wrapping_sub(1) & zeroes.wrapping_sub(1) & (byte ^ 0x80).wrapping_sub(1)) >> 8)
+				& 1;
+		seen |= byte;
+		zeroes |= idx & is_marker.wrapping_neg();
+		is_valid |= is_marker;
+	}
+
+	if is_valid == 1 {
+		Ok(buf.len() - 1 - zeroes)
+	} else {
+		Err(errors::UnknownCryptoError::InvalidEncoding)
+	}
+}
+
+/// Force all primitives to use their portable implementation.
+///
+/// # About:
+/// Some primitives have accelerated implementations that are selected at
+/// first use, based on the features of the CPU. Calling this function with
+/// `true` makes every primitive use its portable implementation instead, for
+/// example to rule out a faulty accelerated implementation or to compare the
+/// two in tests. Calling it with `false` allows accelerated implementations to
+/// be used again.
+///
+/// The setting is global and affects all threads. Outputs are the same
+/// regardless of which implementation is used.
+///
+/// # Parameters:
+/// - `force`: Whether to force the portable implementations.
+///
+/// # Example:
+/// ```rust
+/// use orion::util;
+///
+/// util::force_portable_backends(true);
+/// let digest = orion::hash::digest(b"Some data")?;
+/// util::force_portable_backends(false);
+/// assert_eq!(orion::hash::digest(b"Some data")?, digest);
+/// # Ok::<(), orion::errors::UnknownCryptoError>(())
+/// ```
+pub fn force_portable_backends(force: bool) {
+	crate::cpu::force_portable(force);
+}
+
+#[cfg(feature = "safe_api")]
+/// Run `verify` on each of `items`, using at most `max_workers` threads. Each
+/// worker takes the next unprocessed item when it is done with its current one,
+/// so no more than `max_workers` verifications are in progress at any time. The
+/// results are returned in the same order as `items`.
+pub(crate) fn verify_with_workers<T, F>(items: &[T], max_workers: usize, verify: F) -> Vec<bool>
+where
+	T: Sync,
+	F: Fn(&T) -> bool + Sync,
+{
+	use core::sync::atomic::{AtomicUsize, Ordering};
+
+	let workers = std::thread::available_parallelism()
+		.map(|n| n.get())
+		.unwrap_or(1)
+		.min(max_workers)
+		.min(items.len());
+	if workers <= 1 {
+		return items.iter().map(|item| verify(item)).collect();
 	}
+
+	let next_item = AtomicUsize::new(0);
+	let (next_item, verify) = (&next_item, &verify);
+	let mut results = vec![false; items.len()];
+	std::thread::scope(|scope| {
+		let workers: Vec<_> = (0..workers)
+			.map(|_| {
+				scope.spawn(move || {
+					let mut done = Vec::new();
+					loop {
+						let idx = next_item.fetch_add(1, Ordering::Relaxed);
+						if idx >= items.len() {
+							return done;
+						}
+						done.push((idx, verify(&items[idx])));
+					}
+				})
+			})
+			.collect();
+
+		for worker in workers {
+			match worker.join() {
+				Ok(done) => {
+					for (idx, is_good) in done {
+						results[idx] = is_good;
+					}
+				}
+				Err(panic) => std::panic::resume_unwind(panic),
+			}
+		}
+	});
+
+	results
+}
+
+/// The largest amount of data that can be decoded into a newtype when no
+/// allocator is available.
+#[cfg(not(feature = "safe_api"))]
+const DECODE_BUFFER_SIZE: usize = 256;
+
+/// Decode `length` bytes into a temporary buffer with `decode`, construct a
+/// newtype from it with `from_slice` and wipe the buffer.
+fn decode_with_buffer<T, D, F>(
+	length: usize,
+	decode: D,
+	from_slice: F,
+) -> Result<T, errors::UnknownCryptoError>
+where
+	D: FnOnce(&mut [u8]) -> Result<(), errors::UnknownCryptoError>,
+	F: FnOnce(&[u8]) -> Result<T, errors::UnknownCryptoError>,
+{
+	use zeroize::Zeroize;
+
+	#[cfg(feature = "safe_api")]
+	let mut buffer = vec![0u8; length];
+	#[cfg(not(feature = "safe_api"))]
+	let mut buffer = [0u8; DECODE_BUFFER_SIZE];
+	#[cfg(not(feature = "safe_api"))]
+	{
+		if length > DECODE_BUFFER_SIZE {
+			return Err(errors::UnknownCryptoError::InvalidLength);
+		}
+	}
+
+	let result = decode(&mut buffer[..length]).and_then(|_| from_slice(&buffer[..length]));
+	buffer.zeroize();
+
+	result
 }
 
 #[cfg(feature = "safe_api")]
@@ -117,7 +651,115 @@ fn rand_key_len_error() {
 
 	let mut dst = [0u8; 0];
 	let err = secure_rand_bytes(&mut dst).unwrap_err();
-	assert_eq!(err, errors::UnknownCryptoError);
+	assert_eq!(err, errors::UnknownCryptoError::InvalidLength);
+}
+
+#[cfg(feature = "safe_api")]
+#[test]
+fn test_rand_range() {
+	for _ in 0..100 {
+		let value = secure_rand_range(10..13).unwrap();
+		assert!(value >= 10 && value < 13);
+	}
+	assert_eq!(secure_rand_range(5..6).unwrap(), 5);
+	assert!(secure_rand_range(0..u64::max_value()).is_ok());
+}
+
+#[cfg(feature = "safe_api")]
+#[test]
+fn test_rand_range_all_values() {
+	let mut seen = [false; 7];
+	for _ in 0..1000 {
+		seen[secure_rand_range(0..7).unwrap() as usize] = true;
+	}
+	assert!(seen.iter().all(|s| *s));
+}
+
+#[cfg(feature = "safe_api")]
+#[test]
+fn test_rand_range_empty_err() {
+	assert!(secure_rand_range(0..0).is_err());
+	assert!(secure_rand_range(5..5).is_err());
+	#[allow(clippy::reversed_empty_ranges)]
+	let reversed = 6..5;
+	assert!(secure_rand_range(reversed).is_err());
+}
+
+#[cfg(feature = "safe_api")]
+#[test]
+fn test_generate_password() {
+	let password = generate_password(32, PASSWORD_CHARSET_ALPHANUMERIC).unwrap();
+	assert_eq!(password.len(), 32);
+	assert!(password.chars().all(|c| c.is_ascii_alphanumeric()));
+	assert_ne!(
+		password,
+		generate_password(32, PASSWORD_CHARSET_ALPHANUMERIC).unwrap()
+	);
+
+	// Non-ASCII characters are picked as whole characters.
+	let password = generate_password(10, "\u{e6}\u{f8}\u{e5}").unwrap();
+	assert_eq!(password.chars().count(), 10);
+
+	let mut seen = [false; 3];
+	for c in generate_password(1000, "abc").unwrap().chars() {
+		seen[(c as u8 - b'a') as usize] = true;
+	}
+	assert!(seen.iter().all(|s| *s));
+}
+
+#[cfg(feature = "safe_api")]
+#[test]
+fn test_generate_password_err() {
+	assert!(generate_password(0, PASSWORD_CHARSET_ALPHANUMERIC).is_err());
+	assert!(generate_password(10, "").is_err());
+	assert!(generate_password(10, "a").is_err());
+	assert!(generate_password(10, "abca").is_err());
+	assert!(generate_password(10, "ab").is_ok());
+}
+
+#[cfg(feature = "safe_api")]
+#[test]
+fn test_generate_passphrase() {
+	let wordlist = ["correct", "horse", "battery", "staple"];
+	let passphrase = generate_passphrase(5, &wordlist, " ").unwrap();
+	let words: Vec<&str> = passphrase.split(' ').collect();
+	assert_eq!(words.len(), 5);
+	assert!(words.iter().all(|w| wordlist.contains(w)));
+
+	assert_eq!(
+		generate_passphrase(1, &wordlist, "-")
+			.unwrap()
+			.matches('-')
+			.count(),
+		0
+	);
+}
+
+#[cfg(feature = "safe_api")]
+#[test]
+fn test_generate_passphrase_err() {
+	let wordlist = ["correct", "horse", "battery", "staple"];
+	assert!(generate_passphrase(0, &wordlist, " ").is_err());
+	assert!(generate_passphrase(5, &[], " ").is_err());
+	assert!(generate_passphrase(5, &["correct"], " ").is_err());
+	assert!(generate_passphrase(5, &["correct", "horse", "correct"], " ").is_err());
+}
+
+#[cfg(feature = "safe_api")]
+#[test]
+fn test_rand_integers() {
+	// The chance of these being equal is negligible.
+	assert_ne!(secure_rand_u64().unwrap(), secure_rand_u64().unwrap());
+	assert!(secure_rand_u32().is_ok());
+}
+
+#[cfg(feature = "safe_api")]
+#[test]
+fn test_os_rng() {
+	let mut dst = [0u8; 32];
+	OsRng.fill_bytes(&mut dst).unwrap();
+	assert_ne!(dst, [0u8; 32]);
+	assert!(OsRng.fill_bytes(&mut []).is_err());
 }
 
 #[cfg(feature = "safe_api")]
@@ -153,3 +795,63 @@ fn test_ct_ne_reg() {
 	assert!(secure_cmp(&[0], &[0, 1]).is_err());
 	assert!(secure_cmp(&[0, 1], &[0]).is_err());
 }
+
+#[cfg(feature = "safe_api")]
+#[test]
+fn test_pad() {
+	// Output of libsodium's sodium_pad().
+	let cases: [(usize, usize, &str); 6] = [
+		(0, 16, "80000000000000000000000000000000"),
+		(5, 16, "aaaaaaaaaa8000000000000000000000"),
+		(15, 16, "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaa80"),
+		(
+			16,
+			16,
+			"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa80000000000000000000000000000000",
+		),
+		(3, 1, "aaaaaa80"),
+		(20, 7, "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa80"),
+	];
+	for (unpadded_length, block_size, expected) in cases.iter() {
+		let mut buf = [0xffu8; 64];
+		for byte in buf[..*unpadded_length].iter_mut() {
+			*byte = 0xaa;
+		}
+		let padded_length = pad(&mut buf, *unpadded_length, *block_size).unwrap();
+		assert_eq!(hex::encode(&buf[..padded_length]), *expected);
+		assert_eq!(
+			unpad(&buf[..padded_length], *block_size).unwrap(),
+			*unpadded_length
+		);
+	}
+}
+
+#[test]
+fn test_pad_err() {
+	assert!(pad(&mut [0u8; 16], 5, 0).is_err());
+	assert!(pad(&mut [0u8; 16], 16, 16).is_err());
+	assert!(pad(&mut [0u8; 15], 5, 16).is_err());
+	assert!(pad(&mut [0u8; 16], 15, 16).is_ok());
+	assert!(pad(&mut [0u8; 16], usize::max_value(), 16).is_err());
+}
+
+#[test]
+fn test_unpad() {
+	// Output of libsodium's sodium_unpad().
+	assert_eq!(unpad(&[0x80], 1).unwrap(), 0);
+	assert_eq!(unpad(&[0x00, 0x80], 2).unwrap(), 1);
+	assert_eq!(unpad(&[0x80, 0x00], 2).unwrap(), 0);
+	assert_eq!(unpad(b"ab\x80\x00", 4).unwrap(), 2);
+	// Only the last block_size bytes are checked.
+	assert_eq!(unpad(b"\x00\x80\x80\x00", 2).unwrap(), 2);
+}
+
+#[test]
+fn test_unpad_err() {
+	assert!(unpad(&[0x00; 4], 4).is_err());
+	assert!(unpad(b"ab\x80\x01", 4).is_err());
+	assert!(unpad(b"ab\x81\x00", 4).is_err());
+	assert!(unpad(&[0x80], 0).is_err());
+	assert!(unpad(&[0x80], 2).is_err());
+	assert!(unpad(&[], 1).is_err());
+}
@@ -0,0 +1,267 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use super::SecureRandom;
+use crate::errors::UnknownCryptoError;
+use crate::hazardous::stream::chacha20::{self, Nonce, SecretKey, CHACHA_KEYSIZE};
+use zeroize::Zeroize;
+
+/// The amount of keystream generated each time the buffer is refilled.
+const BUFFER_SIZE: usize = 512;
+
+/// The amount of output after which the `Rng` is reseeded from the OS.
+const RESEED_INTERVAL: u64 = 1024 * 1024;
+
+/// A fast, userspace CSPRNG based on ChaCha20 and seeded from the OS. Not
+/// available in `no_std` context.
+///
+/// # About:
+/// - The `Rng` is seeded with 32 bytes from the OS random generator.
+/// - Output is generated as ChaCha20 keystream, in batches of 512 bytes. The
+///   first 32 bytes of each batch replace the key, so previous output cannot
+///   be recovered if the `Rng` is later compromised ("fast key erasure").
+/// - Output is wiped from the internal buffer as soon as it has been handed
+///   out.
+/// - The `Rng` reseeds itself from the OS after every 1 MiB of output, and
+///   before producing output in a process that was forked from the one that
+///   last seeded it.
+/// - `Rng` does not implement `Clone`, since two copies would produce the same
+///   output.
+///
+/// # Errors:
+/// An error will be returned if:
+/// - `dst` is empty.
+///
+/// # Panics:
+/// A panic will occur if:
+/// - Failure to generate random bytes securely.
+///
+/// # Example:
+/// ```rust
+/// use orion::util::Rng;
+///
+/// let mut rng = Rng::new()?;
+///
+/// let mut nonce = [0u8; 24];
+/// rng.fill_bytes(&mut nonce)?;
+/// let id = rng.next_u64()?;
+/// # Ok::<(), orion::errors::UnknownCryptoError>(())
+/// ```
+pub struct Rng {
+	key: SecretKey,
+	buffer: [u8; BUFFER_SIZE],
+	position: usize,
+	output_since_reseed: u64,
+	process_id: u32,
+}
+
+impl core::fmt::Debug for Rng {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "Rng {{ key: ***OMITTED***, buffer: ***OMITTED*** }}")
+	}
+}
+
+impl Drop for Rng {
+	fn drop(&mut self) {
+		self.buffer.zeroize();
+	}
+}
+
+impl Rng {
+	#[must_use]
+	/// Create a new `Rng` seeded from the OS.
+	pub fn new() -> Result<Self, UnknownCryptoError> {
+		Ok(Self {
+			key: SecretKey::generate(),
+			buffer: [0u8; BUFFER_SIZE],
+			// An empty buffer, so the first request generates fresh keystream.
+			position: BUFFER_SIZE,
+			output_since_reseed: 0,
+			process_id: std::process::id(),
+		})
+	}
+
+	#[must_use]
+	/// Replace the key with one from the OS and discard buffered output.
+	pub fn reseed(&mut self) -> Result<(), UnknownCryptoError> {
+		self.key = SecretKey::generate();
+		self.buffer.zeroize();
+		self.position = BUFFER_SIZE;
+		self.output_since_reseed = 0;
+		self.process_id = std::process::id();
+
+		Ok(())
+	}
+
+	/// Generate the next batch of keystream and ratchet the key forward.
+	fn refill(&mut self) -> Result<(), UnknownCryptoError> {
+		chacha20::encrypt(
+			&self.key,
+			&Nonce::from([0u8; 12]),
+			0,
+			&[0u8; BUFFER_SIZE],
+			&mut self.buffer,
+		)?;
+		self.key = SecretKey::from_slice(&self.buffer[..CHACHA_KEYSIZE])?;
+		self.buffer[..CHACHA_KEYSIZE].zeroize();
+		self.position = CHACHA_KEYSIZE;
+
+		Ok(())
+	}
+
+	#[must_use]
+	/// Fill `dst` with random bytes.
+	pub fn fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), UnknownCryptoError> {
+		if dst.is_empty() {
+			return Err(UnknownCryptoError::InvalidLength);
+		}
+		if self.output_since_reseed >= RESEED_INTERVAL || self.process_id != std::process::id() {
+			self.reseed()?;
+		}
+
+		let mut written = 0;
+		while written < dst.len() {
+			if self.position == BUFFER_SIZE {
+				self.refill()?;
+			}
+			let amount = core::cmp::min(dst.len() - written, BUFFER_SIZE - self.position);
+			let served = &mut self.buffer[self.position..self.position + amount];
+			dst[written..written + amount].copy_from_slice(served);
+			served.zeroize();
+
+			self.position += amount;
+			written += amount;
+		}
+		self.output_since_reseed += dst.len() as u64;
+
+		Ok(())
+	}
+
+	#[must_use]
+	/// Generate a random `u32`.
+	pub fn next_u32(&mut self) -> Result<u32, UnknownCryptoError> {
+		let mut bytes = [0u8; 4];
+		self.fill_bytes(&mut bytes)?;
+
+		Ok(u32::from_le_bytes(bytes))
+	}
+
+	#[must_use]
+	/// Generate a random `u64`.
+	pub fn next_u64(&mut self) -> Result<u64, UnknownCryptoError> {
+		let mut bytes = [0u8; 8];
+		self.fill_bytes(&mut bytes)?;
+
+		Ok(u64::from_le_bytes(bytes))
+	}
+}
+
+impl SecureRandom for Rng {
+	fn fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), UnknownCryptoError> {
+		Rng::fill_bytes(self, dst)
+	}
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	#[test]
+	fn test_fill_bytes() {
+		let mut rng = Rng::new().unwrap();
+		let mut first = [0u8; 64];
+		let mut second = [0u8; 64];
+		rng.fill_bytes(&mut first).unwrap();
+		rng.fill_bytes(&mut second).unwrap();
+
+		assert_ne!(first[..], [0u8; 64][..]);
+		assert_ne!(first[..], second[..]);
+		assert!(rng.fill_bytes(&mut []).is_err());
+	}
+
+	#[test]
+	fn test_different_instances_differ() {
+		let mut a = Rng::new().unwrap();
+		let mut b = Rng::new().unwrap();
+
+		assert_ne!(a.next_u64().unwrap(), b.next_u64().unwrap());
+	}
+
+	#[test]
+	fn test_large_requests() {
+		let mut rng = Rng::new().unwrap();
+		let mut dst = vec![0u8; BUFFER_SIZE * 3 + 7];
+		rng.fill_bytes(&mut dst).unwrap();
+
+		// No 64-byte block of output should repeat.
+		for (i, a) in dst.chunks(64).enumerate() {
+			for b in dst.chunks(64).skip(i + 1) {
+				assert_ne!(a, b);
+			}
+		}
+	}
+
+	#[test]
+	fn test_reseed_interval() {
+		let mut rng = Rng::new().unwrap();
+		let mut dst = vec![0u8; 4096];
+		for _ in 0..(RESEED_INTERVAL / 4096) {
+			rng.fill_bytes(&mut dst).unwrap();
+		}
+		assert_eq!(rng.output_since_reseed, RESEED_INTERVAL);
+
+		rng.fill_bytes(&mut dst[..1]).unwrap();
+		assert_eq!(rng.output_since_reseed, 1);
+	}
+
+	#[test]
+	fn test_reseed_on_new_process() {
+		let mut rng = Rng::new().unwrap();
+		rng.next_u32().unwrap();
+		let key_before = SecretKey::from_slice(rng.key.unprotected_as_bytes()).unwrap();
+
+		// Simulate being in a forked child.
+		rng.process_id = rng.process_id.wrapping_add(1);
+		rng.next_u32().unwrap();
+		assert_eq!(rng.process_id, std::process::id());
+		assert_eq!(rng.output_since_reseed, 4);
+		assert!(rng.key != key_before);
+	}
+
+	#[test]
+	fn test_output_is_wiped() {
+		let mut rng = Rng::new().unwrap();
+		let mut dst = [0u8; 100];
+		rng.fill_bytes(&mut dst).unwrap();
+
+		assert_eq!(rng.buffer[..rng.position], [0u8; 132][..]);
+	}
+
+	#[test]
+	fn test_omitted_debug() {
+		let rng = Rng::new().unwrap();
+		let debug = format!("{:?}", rng);
+		assert!(debug.contains("OMITTED"));
+		assert!(!debug.contains(&format!("{:?}", &rng.buffer[..])));
+	}
+}
@@ -0,0 +1,353 @@
+// MIT License
+
+// Copyright (c) 2018-2019 The orion Developers
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use alloc::vec::Vec;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+#[derive(Default)]
+/// A growable, heap-allocated buffer for sensitive data of variable length,
+/// such as decrypted payloads or passwords read from stdin.
+///
+/// # About:
+/// - The buffer is zeroed out when it is dropped.
+/// - When the buffer needs to grow, a new allocation is made, the data is
+///   copied over and the old allocation is zeroed out before it is freed.
+/// - Bytes removed with `truncate()` or `clear()` are zeroed out immediately.
+/// - `Debug` only shows the length, like orion's other secret types.
+/// - `PartialEq` compares in constant time, both with another `SecretVec` and
+///   with `&[u8]`. The lengths are not hidden.
+/// - With `safe_api`, `std::io::Write` is implemented, so that data can be
+///   read directly into a `SecretVec` with `std::io::copy()`.
+///
+/// # Security:
+/// - __**Avoid using**__ `unprotected_as_bytes()` and
+///   `unprotected_as_bytes_mut()` whenever possible, as copies made from them
+///   are not zeroed out.
+/// - Reserving the needed capacity up-front with `with_capacity()` avoids
+///   copying the data when the buffer grows.
+///
+/// # Example:
+/// ```rust
+/// use orion::util::SecretVec;
+///
+/// let mut password = SecretVec::new();
+/// password.extend_from_slice(b"correct horse");
+/// password.extend_from_slice(b" battery staple");
+///
+/// assert_eq!(password.len(), 28);
+/// assert!(password == &b"correct horse battery staple"[..]);
+///
+/// password.truncate(13);
+/// assert!(password == &b"correct horse"[..]);
+/// ```
+pub struct SecretVec {
+	value: Vec<u8>,
+}
+
+impl SecretVec {
+	#[must_use]
+	/// Create an empty `SecretVec`.
+	pub fn new() -> Self {
+		Self { value: Vec::new() }
+	}
+
+	#[must_use]
+	/// Create an empty `SecretVec` that can hold `capacity` bytes without
+	/// reallocating.
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self {
+			value: Vec::with_capacity(capacity),
+		}
+	}
+
+	#[must_use]
+	/// Create a `SecretVec` containing a copy of `slice`.
+	pub fn from_slice(slice: &[u8]) -> Self {
+		let mut secret = Self::with_capacity(slice.len());
+		secret.value.extend_from_slice(slice);
+
+		secret
+	}
+
+	/// Return the number of bytes in the buffer.
+	pub fn len(&self) -> usize {
+		self.value.len()
+	}
+
+	/// Return `true` if the buffer contains no bytes.
+	pub fn is_empty(&self) -> bool {
+		self.value.is_empty()
+	}
+
+	/// Return the number of bytes the buffer can hold without reallocating.
+	pub fn capacity(&self) -> usize {
+		self.value.capacity()
+	}
+
+	/// Make sure that at least `additional` more bytes can be added without
+	/// reallocating.
+	pub fn reserve(&mut self, additional: usize) {
+		let required = self
+			.value
+			.len()
+			.checked_add(additional)
+			.expect("SecretVec capacity overflow");
+		if required <= self.value.capacity() {
+			return;
+		}
+
+		// Grow at least geometrically, as Vec does, so that repeated calls
+		// to push() are not quadratic.
+		let new_capacity = core::cmp::max(required, self.value.capacity().saturating_mul(2));
+		let mut new_value = Vec::with_capacity(new_capacity);
+		new_value.extend_from_slice(&self.value);
+		// Zeroes the entire old allocation before it is freed.
+		self.value.zeroize();
+		self.value = new_value;
+	}
+
+	/// Append `byte` to the buffer.
+	pub fn push(&mut self, byte: u8) {
+		self.reserve(1);
+		self.value.push(byte);
+	}
+
+	/// Append a copy of `slice` to the buffer.
+	pub fn extend_from_slice(&mut self, slice: &[u8]) {
+		self.reserve(slice.len());
+		self.value.extend_from_slice(slice);
+	}
+
+	/// Resize the buffer to `new_len` bytes. New bytes are set to `value`.
+	/// Removed bytes are zeroed out.
+	pub fn resize(&mut self, new_len: usize, value: u8) {
+		if new_len > self.value.len() {
+			self.reserve(new_len - self.value.len());
+			self.value.resize(new_len, value);
+		} else {
+			self.truncate(new_len);
+		}
+	}
+
+	/// Shorten the buffer to `len` bytes, zeroing out the removed bytes. Has
+	/// no effect if `len` is not less than the current length.
+	pub fn truncate(&mut self, len: usize) {
+		if len < self.value.len() {
+			self.value[len..].zeroize();
+			self.value.truncate(len);
+		}
+	}
+
+	/// Remove all bytes from the buffer, zeroing them out. The capacity is
+	/// kept.
+	pub fn clear(&mut self) {
+		self.truncate(0);
+	}
+
+	#[inline]
+	#[must_use]
+	/// Return the buffer as byte slice. __**Warning**__: Should not be used
+	/// unless strictly needed. This __**breaks protections**__ that the type
+	/// implements.
+	pub fn unprotected_as_bytes(&self) -> &[u8] {
+		&self.value
+	}
+
+	#[inline]
+	#[must_use]
+	/// Return the buffer as a mutable byte slice. __**Warning**__: Should not
+	/// be used unless strictly needed. This __**breaks protections**__ that
+	/// the type implements.
+	pub fn unprotected_as_bytes_mut(&mut self) -> &mut [u8] {
+		&mut self.value
+	}
+}
+
+impl From<Vec<u8>> for SecretVec {
+	/// Take ownership of `value` without copying it. The allocation of
+	/// `value` is zeroed out when the `SecretVec` is dropped.
+	fn from(value: Vec<u8>) -> Self {
+		Self { value }
+	}
+}
+
+impl Drop for SecretVec {
+	fn drop(&mut self) {
+		self.value.zeroize();
+	}
+}
+
+impl core::fmt::Debug for SecretVec {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		crate::newtype::fmt_secret_debug(f, "SecretVec", &self.value)
+	}
+}
+
+impl PartialEq<SecretVec> for SecretVec {
+	fn eq(&self, other: &SecretVec) -> bool {
+		self.value.ct_eq(&other.value).into()
+	}
+}
+
+impl PartialEq<&[u8]> for SecretVec {
+	fn eq(&self, other: &&[u8]) -> bool {
+		self.value.as_slice().ct_eq(*other).into()
+	}
+}
+
+#[cfg(feature = "safe_api")]
+impl std::io::Write for SecretVec {
+	fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+		self.extend_from_slice(buf);
+		Ok(buf.len())
+	}
+
+	fn flush(&mut self) -> std::io::Result<()> {
+		Ok(())
+	}
+}
+
+// Testing public functions in the module.
+#[cfg(test)]
+mod public {
+	use super::*;
+
+	mod test_extend {
+		use super::*;
+
+		#[test]
+		fn test_push_and_extend() {
+			let mut secret = SecretVec::new();
+			assert!(secret.is_empty());
+			for byte in 0..=255u8 {
+				secret.push(byte);
+			}
+			secret.extend_from_slice(&[1u8; 1000]);
+
+			assert_eq!(secret.len(), 1256);
+			assert!(secret.capacity() >= 1256);
+			for (idx, byte) in secret.unprotected_as_bytes()[..256].iter().enumerate() {
+				assert_eq!(*byte, idx as u8);
+			}
+			assert!(secret.unprotected_as_bytes()[256..].iter().all(|b| *b == 1));
+		}
+
+		#[test]
+		fn test_with_capacity_no_realloc() {
+			let mut secret = SecretVec::with_capacity(64);
+			let capacity = secret.capacity();
+			secret.extend_from_slice(&[0u8; 64]);
+			assert_eq!(secret.capacity(), capacity);
+		}
+
+		#[test]
+		fn test_reserve_grows() {
+			let mut secret = SecretVec::from_slice(&[0u8; 10]);
+			secret.reserve(100);
+			assert!(secret.capacity() >= 110);
+			assert_eq!(secret.len(), 10);
+		}
+
+		#[test]
+		fn test_empty_extend() {
+			let mut secret = SecretVec::new();
+			secret.extend_from_slice(&[]);
+			assert!(secret.is_empty());
+		}
+	}
+
+	mod test_shrink {
+		use super::*;
+
+		#[test]
+		fn test_truncate() {
+			let mut secret = SecretVec::from_slice(&[1u8; 32]);
+			secret.truncate(64);
+			assert_eq!(secret.len(), 32);
+			secret.truncate(16);
+			assert!(secret == &[1u8; 16][..]);
+			secret.truncate(0);
+			assert!(secret.is_empty());
+		}
+
+		#[test]
+		fn test_resize() {
+			let mut secret = SecretVec::new();
+			secret.resize(8, 0xff);
+			assert!(secret == &[0xffu8; 8][..]);
+			secret.resize(4, 0x00);
+			assert!(secret == &[0xffu8; 4][..]);
+		}
+
+		#[test]
+		fn test_clear() {
+			let mut secret = SecretVec::from_slice(&[1u8; 32]);
+			let capacity = secret.capacity();
+			secret.clear();
+			assert!(secret.is_empty());
+			assert_eq!(secret.capacity(), capacity);
+		}
+	}
+
+	mod test_traits {
+		use super::*;
+
+		#[test]
+		fn test_partial_eq() {
+			let secret = SecretVec::from_slice(&[1u8; 32]);
+			assert!(secret == SecretVec::from_slice(&[1u8; 32]));
+			assert!(secret != SecretVec::from_slice(&[1u8; 31]));
+			assert!(secret != SecretVec::from_slice(&[0u8; 32]));
+			assert!(secret == &[1u8; 32][..]);
+			assert!(secret != &[1u8; 33][..]);
+			assert!(SecretVec::new() == &[][..]);
+		}
+
+		#[test]
+		fn test_from_vec() {
+			let secret = SecretVec::from(alloc::vec![3u8; 12]);
+			assert!(secret == &[3u8; 12][..]);
+		}
+
+		#[test]
+		#[cfg(feature = "safe_api")]
+		#[cfg(not(feature = "unprotected_debug"))]
+		// format! is only available with std
+		fn test_omitted_debug() {
+			let secret = SecretVec::from_slice(&[0xabu8; 7]);
+			assert_eq!(
+				format!("{:?}", secret),
+				"SecretVec { value: ***OMITTED***, length: 7 }"
+			);
+		}
+
+		#[test]
+		#[cfg(feature = "safe_api")]
+		fn test_io_write() {
+			let mut secret = SecretVec::new();
+			let mut reader = &[7u8; 5000][..];
+			std::io::copy(&mut reader, &mut secret).unwrap();
+			assert!(secret == &[7u8; 5000][..]);
+		}
+	}
+}
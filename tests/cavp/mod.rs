@@ -0,0 +1,285 @@
+// Parser for NIST CAVP response files (.rsp) and ACVP JSON vector files.
+//
+// Every .rsp and ACVP .json file in tests/test_data/original with a driver
+// registered below is run by `test_cavp_files`. The vendored files are
+// SHA512ShortMsg.rsp, SHA512LongMsg.rsp and HMAC.rsp. A new primitive gains
+// coverage from the official vectors by registering a driver and dropping
+// its files into that directory.
+//
+// .rsp files consist of "#" comments, "[Name = Value]" section headers and
+// records of "Name = Value" lines separated by blank lines. The parameters
+// of a section header apply to all records that follow it.
+//
+// ACVP files must contain both the prompts and the expected results, as in
+// the "internalProjection.json" files of the ACVP-Server repository. Only
+// groups with "testType": "AFT" are run; Monte Carlo and large data tests are
+// left out. No ACVP files are vendored yet; the SHA2-512 and HMAC-SHA2-512
+// drivers are checked against the vectors in `test_run_acvp`.
+
+extern crate hex;
+extern crate serde_json;
+
+use self::serde_json::Value;
+use crate::{hash::sha512_test_runner, mac::hmac_test_runner};
+use std::{collections::HashMap, fs, path::Path};
+
+/// A single record of a .rsp file, with the parameters of its section.
+pub struct Record {
+	pub section: HashMap<String, String>,
+	fields: HashMap<String, String>,
+}
+
+impl Record {
+	pub fn get(&self, name: &str) -> &str {
+		self.fields
+			.get(name)
+			.unwrap_or_else(|| panic!("Missing field {}", name))
+	}
+
+	/// Decode the hexadecimal field `name`.
+	pub fn bytes(&self, name: &str) -> Vec<u8> {
+		hex::decode(self.get(name)).unwrap()
+	}
+
+	/// The decimal field `name`.
+	pub fn usize(&self, name: &str) -> usize {
+		self.get(name).parse().unwrap()
+	}
+}
+
+pub fn parse_rsp(rsp: &str) -> Vec<Record> {
+	let mut records = Vec::new();
+	let mut section = HashMap::new();
+	let mut fields = HashMap::new();
+	// Consecutive headers belong to the same section.
+	let mut in_headers = false;
+
+	// Chain an empty line, so that the last record is finished as well.
+	for line in rsp.lines().chain(std::iter::once("")) {
+		let line = line.trim();
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let is_header = line.starts_with('[') && line.ends_with(']');
+		if (line.is_empty() || is_header) && !fields.is_empty() {
+			records.push(Record {
+				section: section.clone(),
+				fields: std::mem::replace(&mut fields, HashMap::new()),
+			});
+		}
+
+		if is_header {
+			if !in_headers {
+				section.clear();
+				in_headers = true;
+			}
+			let (name, value) = split_assignment(&line[1..line.len() - 1]);
+			section.insert(name, value);
+		} else if !line.is_empty() {
+			in_headers = false;
+			let (name, value) = split_assignment(line);
+			fields.insert(name, value);
+		}
+	}
+
+	records
+}
+
+fn split_assignment(line: &str) -> (String, String) {
+	let mut parts = line.splitn(2, '=');
+	let name = parts.next().unwrap().trim().to_string();
+	let value = parts.next().unwrap_or("").trim().to_string();
+
+	(name, value)
+}
+
+/// Run the driver registered for the .rsp file `name`, or return false if
+/// there is none.
+pub fn run_rsp(name: &str, rsp: &str) -> bool {
+	let records = parse_rsp(rsp);
+	assert!(!records.is_empty());
+
+	if name.starts_with("SHA512ShortMsg") || name.starts_with("SHA512LongMsg") {
+		hash_rsp(&records, sha512_test_runner);
+	} else if name.starts_with("HMAC") {
+		hmac_rsp(&records);
+	} else {
+		return false;
+	}
+
+	true
+}
+
+fn hash_rsp(records: &[Record], runner: fn(&[u8], &[u8])) {
+	for record in records {
+		// "Len" is in bits, and a message of length 0 is written as "00".
+		let len = record.usize("Len") / 8;
+		let msg = record.bytes("Msg");
+		runner(&msg[..len], &record.bytes("MD"));
+	}
+}
+
+fn hmac_rsp(records: &[Record]) {
+	let mut ran = 0;
+	for record in records {
+		// "L" is the size of the hash function's output in bytes. orion only
+		// implements HMAC-SHA512.
+		if record.section.get("L").map(String::as_str) != Some("64") {
+			continue;
+		}
+
+		let key = record.bytes("Key");
+		assert_eq!(key.len(), record.usize("Klen"));
+		hmac_test_runner(
+			&key,
+			&record.bytes("Msg"),
+			&record.bytes("Mac"),
+			Some(record.usize("Tlen")),
+		)
+		.unwrap();
+		ran += 1;
+	}
+
+	assert!(ran > 0);
+}
+
+/// Run the driver registered for the algorithm of the ACVP file `json`, or
+/// return false if there is none.
+pub fn run_acvp(json: &str) -> bool {
+	let file: Value = serde_json::from_str(json).unwrap();
+	let algorithm = match file.get("algorithm").and_then(Value::as_str) {
+		Some(algorithm) => algorithm,
+		None => return false,
+	};
+
+	let runner: fn(&Value) = match algorithm {
+		"SHA2-512" => |test| acvp_hash(test, sha512_test_runner),
+		"HMAC-SHA2-512" => acvp_hmac,
+		_ => return false,
+	};
+
+	let mut ran = 0;
+	for group in file["testGroups"].as_array().unwrap() {
+		if group["testType"].as_str() != Some("AFT") {
+			continue;
+		}
+		for test in group["tests"].as_array().unwrap() {
+			runner(test);
+			ran += 1;
+		}
+	}
+	assert!(ran > 0);
+
+	true
+}
+
+fn acvp_bytes(test: &Value, name: &str) -> Vec<u8> {
+	hex::decode(test[name].as_str().unwrap()).unwrap()
+}
+
+fn acvp_hash(test: &Value, runner: fn(&[u8], &[u8])) {
+	// "len" is in bits.
+	let len = test["len"].as_u64().unwrap() as usize / 8;
+	runner(&acvp_bytes(test, "msg")[..len], &acvp_bytes(test, "md"));
+}
+
+fn acvp_hmac(test: &Value) {
+	// "macLen" is in bits.
+	let mac_len = test["macLen"].as_u64().unwrap() as usize / 8;
+	hmac_test_runner(
+		&acvp_bytes(test, "key"),
+		&acvp_bytes(test, "msg"),
+		&acvp_bytes(test, "mac"),
+		Some(mac_len),
+	)
+	.unwrap();
+}
+
+#[test]
+fn test_cavp_files() {
+	let mut ran = Vec::new();
+	for entry in fs::read_dir("./tests/test_data/original").unwrap() {
+		let path = entry.unwrap().path();
+		let name = path.file_name().unwrap().to_str().unwrap().to_string();
+		let contents = || fs::read_to_string(Path::new(&path)).unwrap();
+
+		let was_run = if name.ends_with(".rsp") {
+			run_rsp(&name, &contents())
+		} else if name.ends_with(".json") && !name.starts_with("Wycheproof_") {
+			run_acvp(&contents())
+		} else {
+			false
+		};
+
+		if was_run {
+			ran.push(name);
+		}
+	}
+
+	ran.sort();
+	assert_eq!(ran, ["HMAC.rsp", "SHA512LongMsg.rsp", "SHA512ShortMsg.rsp"]);
+}
+
+#[test]
+fn test_parse_rsp() {
+	let rsp = "#  CAVS 11.0\n\
+	           #  Comment\n\
+	           \n\
+	           [L=64]\n\
+	           [Extra = 1]\n\
+	           \n\
+	           Count = 0\n\
+	           Key = 00\n\
+	           \n\
+	           Count = 1\n\
+	           Key = 01\n\
+	           [L = 32]\n\
+	           \n\
+	           Count = 2\n\
+	           Key =\n";
+	let records = parse_rsp(rsp);
+
+	assert_eq!(records.len(), 3);
+	assert_eq!(records[0].usize("Count"), 0);
+	assert_eq!(records[0].bytes("Key"), [0x00]);
+	assert_eq!(records[1].bytes("Key"), [0x01]);
+	assert_eq!(records[1].section["L"], "64");
+	assert_eq!(records[1].section["Extra"], "1");
+	assert_eq!(records[2].section["L"], "32");
+	assert!(records[2].section.get("Extra").is_none());
+	assert!(records[2].bytes("Key").is_empty());
+}
+
+#[test]
+fn test_run_acvp() {
+	// SHA2-512 and HMAC-SHA2-512 vectors from SHA512ShortMsg.rsp and
+	// HMAC.rsp, written in the ACVP format.
+	let sha512 = r#"{
+		"algorithm": "SHA2-512",
+		"testGroups": [
+			{ "tgId": 1, "testType": "AFT", "tests": [
+				{ "tcId": 1, "len": 0, "msg": "00", "md": "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e" },
+				{ "tcId": 2, "len": 8, "msg": "21", "md": "3831a6a6155e509dee59a7f451eb35324d8f8f2df6e3708894740f98fdee23889f4de5adb0c5010dfb555cda77c8ab5dc902094c52de3278f35a75ebc25f093a" }
+			]},
+			{ "tgId": 2, "testType": "MCT", "tests": [
+				{ "tcId": 3, "len": 8, "msg": "21", "md": "00" }
+			]}
+		]
+	}"#;
+	assert!(run_acvp(sha512));
+
+	let hmac = r#"{
+		"algorithm": "HMAC-SHA2-512",
+		"testGroups": [
+			{ "tgId": 1, "testType": "AFT", "tests": [
+				{ "tcId": 1, "macLen": 256, "key": "726374c4b8df517510db9159b730f93431e0cd468d4f3821eab0edb93abd0fba46ab4f1ef35d54fec3d85fa89ef72ff3d35f22cf5ab69e205c10afcdf4aaf11338dbb12073474fddb556e60b8ee52f91163ba314303ee0c910e64e87fbf302214edbe3f2", "msg": "ac939659dc5f668c9969c0530422e3417a462c8b665e8db25a883a625f7aa59b89c5ad0ece5712ca17442d1798c6dea25d82c5db260cb59c75ae650be56569c1bd2d612cc57e71315917f116bbfa65a0aeb8af7840ee83d3e7101c52cf652d2773531b7a6bdd690b846a741816c860819270522a5b0cdfa1d736c501c583d916", "mac": "bd3d2df6f9d284b421a43e5f9cb94bc4ff88a88243f1f0133bad0fb1791f6569" }
+			]}
+		]
+	}"#;
+	assert!(run_acvp(hmac));
+
+	assert!(!run_acvp(
+		r#"{ "algorithm": "SHA3-512", "testGroups": [] }"#
+	));
+}
@@ -0,0 +1,381 @@
+// Differential tests that compare orion's outputs with those of other
+// implementations, for randomly generated inputs.
+//
+// The reference implementations are Python's hashlib and the OpenSSL
+// bindings of the `cryptography` package, run by reference.py in a separate
+// process. The tests are ignored by default, because they need python3 with
+// `cryptography` installed, and are run with `cargo test -- --ignored`. When
+// they run, they fail if the reference implementations cannot be started.
+// Every failure message includes the request sent to the reference, so that
+// the inputs can be reproduced.
+
+extern crate orion;
+extern crate serde_json;
+
+use self::orion::{
+	hazardous::{
+		aead::chacha20poly1305,
+		ecc::{ecdsa_p256, x448},
+		hash::{blake2b, sha256, sha512},
+		kdf::{hkdf, pbkdf2},
+		mac::{hmac, poly1305},
+		stream::chacha20,
+	},
+	util::{HmacDrbg, OsRng, SecureRandom},
+};
+use self::serde_json::{json, Value};
+use core::convert::TryFrom;
+use std::{
+	io::{BufRead, BufReader, Write},
+	process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+};
+
+/// The number of random inputs tested for each primitive.
+const ROUNDS: usize = 64;
+
+/// A running instance of reference.py.
+struct Reference {
+	child: Child,
+	stdin: ChildStdin,
+	stdout: BufReader<ChildStdout>,
+}
+
+impl Reference {
+	/// Start reference.py.
+	///
+	/// # Panics:
+	/// If python3 or `cryptography` is not available.
+	fn start() -> Self {
+		let child = Command::new("python3")
+			.arg("./tests/differential/reference.py")
+			.stdin(Stdio::piped())
+			.stdout(Stdio::piped())
+			.stderr(Stdio::null())
+			.spawn();
+		let mut child = match child {
+			Ok(child) => child,
+			Err(_) => panic!("python3 is required for the differential tests"),
+		};
+
+		let stdin = child.stdin.take().unwrap();
+		let mut stdout = BufReader::new(child.stdout.take().unwrap());
+		let mut ready = String::new();
+		stdout.read_line(&mut ready).unwrap();
+		if ready.trim() != "ready" {
+			let _ = child.wait();
+			panic!("The `cryptography` package is required for the differential tests");
+		}
+
+		Self {
+			child,
+			stdin,
+			stdout,
+		}
+	}
+
+	/// Send `request` to the reference and return its response.
+	fn query(&mut self, request: &Value) -> Value {
+		writeln!(self.stdin, "{}", request).unwrap();
+		self.stdin.flush().unwrap();
+
+		let mut response = String::new();
+		self.stdout.read_line(&mut response).unwrap();
+		serde_json::from_str(&response)
+			.unwrap_or_else(|_| panic!("No response from the reference to {}", request))
+	}
+}
+
+impl Drop for Reference {
+	fn drop(&mut self) {
+		let _ = self.child.kill();
+		let _ = self.child.wait();
+	}
+}
+
+/// Generates the random inputs. It is seeded from the OS, so every run tests
+/// different inputs.
+struct Inputs(HmacDrbg);
+
+impl Inputs {
+	fn new() -> Self {
+		let mut seed = [0u8; 32];
+		OsRng.fill_bytes(&mut seed).unwrap();
+		Inputs(HmacDrbg::new(&seed, b"", b"orion differential").unwrap())
+	}
+
+	/// A random value in `min..=max`.
+	fn len(&mut self, min: usize, max: usize) -> usize {
+		let mut buf = [0u8; 8];
+		self.0.fill_bytes(&mut buf).unwrap();
+		min + (u64::from_le_bytes(buf) % (max - min + 1) as u64) as usize
+	}
+
+	fn bytes(&mut self, len: usize) -> Vec<u8> {
+		let mut buf = vec![0u8; len];
+		// The DRBG does not fill empty buffers.
+		if len > 0 {
+			self.0.fill_bytes(&mut buf).unwrap();
+		}
+
+		buf
+	}
+
+	/// Random bytes of a random length in `min..=max`.
+	fn bytes_between(&mut self, min: usize, max: usize) -> Vec<u8> {
+		let len = self.len(min, max);
+		self.bytes(len)
+	}
+}
+
+/// Decode the hexadecimal field `name` of `response`.
+fn field(response: &Value, name: &str) -> Vec<u8> {
+	hex::decode(response[name].as_str().unwrap()).unwrap()
+}
+
+/// Run `test` for `ROUNDS` random inputs.
+fn differential<F: FnMut(&mut Reference, &mut Inputs)>(mut test: F) {
+	let mut reference = Reference::start();
+	let mut inputs = Inputs::new();
+	for _ in 0..ROUNDS {
+		test(&mut reference, &mut inputs);
+	}
+}
+
+#[test]
+#[ignore]
+fn test_differential_hashes() {
+	differential(|reference, inputs| {
+		let msg = inputs.bytes_between(0, 600);
+		let request = json!({ "op": "sha256", "msg": hex::encode(&msg) });
+		let expected = field(&reference.query(&request), "digest");
+		assert_eq!(
+			sha256::digest(&msg).unwrap().as_ref(),
+			&expected[..],
+			"{}",
+			request
+		);
+
+		let request = json!({ "op": "sha512", "msg": hex::encode(&msg) });
+		let expected = field(&reference.query(&request), "digest");
+		assert_eq!(
+			sha512::digest(&msg).unwrap().as_ref(),
+			&expected[..],
+			"{}",
+			request
+		);
+
+		let key = inputs.bytes_between(0, 64);
+		let size = inputs.len(1, 64);
+		let request = json!({
+			"op": "blake2b",
+			"msg": hex::encode(&msg),
+			"key": hex::encode(&key),
+			"size": size,
+		});
+		let expected = field(&reference.query(&request), "digest");
+		let secret_key = if key.is_empty() {
+			None
+		} else {
+			Some(blake2b::SecretKey::from_slice(&key).unwrap())
+		};
+		let mut state = blake2b::init(secret_key.as_ref(), size).unwrap();
+		state.update(&msg).unwrap();
+		assert_eq!(
+			state.finalize().unwrap().as_ref(),
+			&expected[..],
+			"{}",
+			request
+		);
+	});
+}
+
+#[test]
+#[ignore]
+fn test_differential_macs() {
+	differential(|reference, inputs| {
+		let msg = inputs.bytes_between(0, 600);
+
+		let key = inputs.bytes_between(0, 200);
+		let request = json!({
+			"op": "hmac_sha512",
+			"key": hex::encode(&key),
+			"msg": hex::encode(&msg),
+		});
+		let expected = field(&reference.query(&request), "tag");
+		let tag = hmac::hmac(&hmac::SecretKey::from_slice(&key).unwrap(), &msg).unwrap();
+		assert_eq!(tag.unprotected_as_bytes(), &expected[..], "{}", request);
+
+		let key = inputs.bytes(32);
+		let request = json!({
+			"op": "poly1305",
+			"key": hex::encode(&key),
+			"msg": hex::encode(&msg),
+		});
+		let expected = field(&reference.query(&request), "tag");
+		let tag =
+			poly1305::poly1305(&poly1305::OneTimeKey::from_slice(&key).unwrap(), &msg).unwrap();
+		assert_eq!(tag.unprotected_as_bytes(), &expected[..], "{}", request);
+	});
+}
+
+#[test]
+#[ignore]
+fn test_differential_kdfs() {
+	differential(|reference, inputs| {
+		let ikm = inputs.bytes_between(0, 100);
+		let salt = inputs.bytes_between(0, 100);
+		let info = inputs.bytes_between(0, 100);
+		let size = inputs.len(1, 1000);
+		let request = json!({
+			"op": "hkdf_sha512",
+			"ikm": hex::encode(&ikm),
+			"salt": hex::encode(&salt),
+			"info": hex::encode(&info),
+			"size": size,
+		});
+		let expected = field(&reference.query(&request), "okm");
+		let mut okm = vec![0u8; size];
+		hkdf::derive_key(&salt, &ikm, Some(&info), &mut okm).unwrap();
+		assert_eq!(okm, expected, "{}", request);
+
+		let password = inputs.bytes_between(0, 200);
+		let iterations = inputs.len(1, 3);
+		let size = inputs.len(1, 200);
+		let request = json!({
+			"op": "pbkdf2_sha512",
+			"password": hex::encode(&password),
+			"salt": hex::encode(&salt),
+			"iterations": iterations,
+			"size": size,
+		});
+		let expected = field(&reference.query(&request), "dk");
+		let mut dk = vec![0u8; size];
+		pbkdf2::derive_key(
+			&pbkdf2::Password::from_slice(&password).unwrap(),
+			&salt,
+			iterations,
+			&mut dk,
+		)
+		.unwrap();
+		assert_eq!(dk, expected, "{}", request);
+	});
+}
+
+#[test]
+#[ignore]
+fn test_differential_chacha20() {
+	differential(|reference, inputs| {
+		let key = inputs.bytes(32);
+		let nonce = inputs.bytes(12);
+		let msg = inputs.bytes_between(1, 1000);
+		let secret_key = chacha20::SecretKey::from_slice(&key).unwrap();
+		let chacha_nonce = chacha20::Nonce::from_slice(&nonce).unwrap();
+
+		// Leave room for the blocks of `msg`, so that the counter does not
+		// overflow.
+		let counter = inputs.len(0, (u32::max_value() - 64) as usize) as u32;
+		let request = json!({
+			"op": "chacha20",
+			"key": hex::encode(&key),
+			"nonce": hex::encode(&nonce),
+			"counter": counter,
+			"msg": hex::encode(&msg),
+		});
+		let expected = field(&reference.query(&request), "ct");
+		let mut ct = vec![0u8; msg.len()];
+		chacha20::encrypt(&secret_key, &chacha_nonce, counter, &msg, &mut ct).unwrap();
+		assert_eq!(ct, expected, "{}", request);
+
+		let aad = inputs.bytes_between(0, 100);
+		let request = json!({
+			"op": "chacha20_poly1305",
+			"key": hex::encode(&key),
+			"nonce": hex::encode(&nonce),
+			"aad": hex::encode(&aad),
+			"msg": hex::encode(&msg),
+		});
+		let expected = field(&reference.query(&request), "ct");
+		let mut ct = vec![0u8; msg.len() + 16];
+		chacha20poly1305::seal(&secret_key, &chacha_nonce, &msg, Some(&aad), &mut ct).unwrap();
+		assert_eq!(ct, expected, "{}", request);
+	});
+}
+
+#[test]
+#[ignore]
+fn test_differential_x448() {
+	differential(|reference, inputs| {
+		let private_key = inputs.bytes(56);
+		let peer_private_key = x448::PrivateKey::from_slice(&inputs.bytes(56)).unwrap();
+		let peer_public_key = x448::PublicKey::try_from(&peer_private_key).unwrap();
+		let request = json!({
+			"op": "x448",
+			"private_key": hex::encode(&private_key),
+			"peer_public_key": hex::encode(peer_public_key.as_ref()),
+		});
+		let response = reference.query(&request);
+
+		let private_key = x448::PrivateKey::from_slice(&private_key).unwrap();
+		let public_key = x448::PublicKey::try_from(&private_key).unwrap();
+		assert_eq!(
+			public_key.as_ref(),
+			&field(&response, "public_key")[..],
+			"{}",
+			request
+		);
+		let shared_key = x448::key_agreement(&private_key, &peer_public_key).unwrap();
+		assert_eq!(
+			shared_key.unprotected_as_bytes(),
+			&field(&response, "shared_key")[..],
+			"{}",
+			request
+		);
+	});
+}
+
+#[test]
+#[ignore]
+fn test_differential_ecdsa_p256() {
+	differential(|reference, inputs| {
+		let secret_key = inputs.bytes(32);
+		let msg = inputs.bytes_between(0, 200);
+		// A secret key of zero or greater than the order of the curve is
+		// rejected by both, and too unlikely to test here.
+		let sk = match ecdsa_p256::SecretKey::from_slice(&secret_key) {
+			Ok(sk) => sk,
+			Err(_) => return,
+		};
+		let signature = ecdsa_p256::sign(&sk, &msg).unwrap();
+		let request = json!({
+			"op": "ecdsa_p256",
+			"secret_key": hex::encode(&secret_key),
+			"msg": hex::encode(&msg),
+			"signature": hex::encode(signature.as_ref()),
+		});
+		let response = reference.query(&request);
+
+		let pk = ecdsa_p256::PublicKey::try_from(&sk).unwrap();
+		assert_eq!(
+			pk.as_ref(),
+			&field(&response, "public_key")[..],
+			"{}",
+			request
+		);
+		assert_eq!(response["verified"], true, "{}", request);
+		// Both use RFC 6979 nonces, so the signatures are equal.
+		assert_eq!(
+			signature.as_ref(),
+			&field(&response, "deterministic")[..],
+			"{}",
+			request
+		);
+
+		let randomized =
+			ecdsa_p256::Signature::from_slice(&field(&response, "randomized")).unwrap();
+		assert!(
+			ecdsa_p256::verify(&randomized, &pk, &msg).unwrap(),
+			"{}",
+			request
+		);
+	});
+}
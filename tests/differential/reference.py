@@ -0,0 +1,153 @@
+# Reference implementations for the differential tests in mod.rs, backed by
+# Python's hashlib and the OpenSSL bindings of the `cryptography` package.
+#
+# Each line on stdin is a JSON request with an "op" and hex-encoded inputs.
+# Each response is a JSON line on stdout, with hex-encoded outputs. A first
+# line of "ready" is written once all imports have succeeded.
+
+import hashlib
+import hmac
+import json
+import sys
+
+from cryptography.hazmat.primitives import hashes
+from cryptography.hazmat.primitives.asymmetric import ec, x448
+from cryptography.hazmat.primitives.asymmetric.utils import (
+    decode_dss_signature,
+    encode_dss_signature,
+)
+from cryptography.hazmat.primitives.ciphers import Cipher, algorithms
+from cryptography.hazmat.primitives.ciphers.aead import ChaCha20Poly1305
+from cryptography.hazmat.primitives.kdf.hkdf import HKDF
+from cryptography.hazmat.primitives.poly1305 import Poly1305
+from cryptography.hazmat.primitives.serialization import Encoding, PublicFormat
+
+
+def b(request, name):
+    return bytes.fromhex(request[name])
+
+
+def sha256(request):
+    return {"digest": hashlib.sha256(b(request, "msg")).hexdigest()}
+
+
+def sha512(request):
+    return {"digest": hashlib.sha512(b(request, "msg")).hexdigest()}
+
+
+def blake2b(request):
+    h = hashlib.blake2b(
+        b(request, "msg"), key=b(request, "key"), digest_size=request["size"]
+    )
+    return {"digest": h.hexdigest()}
+
+
+def hmac_sha512(request):
+    tag = hmac.new(b(request, "key"), b(request, "msg"), hashlib.sha512)
+    return {"tag": tag.hexdigest()}
+
+
+def hkdf_sha512(request):
+    okm = HKDF(
+        algorithm=hashes.SHA512(),
+        length=request["size"],
+        salt=b(request, "salt"),
+        info=b(request, "info"),
+    ).derive(b(request, "ikm"))
+    return {"okm": okm.hex()}
+
+
+def pbkdf2_sha512(request):
+    dk = hashlib.pbkdf2_hmac(
+        "sha512",
+        b(request, "password"),
+        b(request, "salt"),
+        request["iterations"],
+        request["size"],
+    )
+    return {"dk": dk.hex()}
+
+
+def poly1305(request):
+    return {"tag": Poly1305.generate_tag(b(request, "key"), b(request, "msg")).hex()}
+
+
+def chacha20(request):
+    # OpenSSL takes the initial block counter as the first four bytes of a
+    # 16-byte nonce, in little-endian.
+    nonce = request["counter"].to_bytes(4, "little") + b(request, "nonce")
+    encryptor = Cipher(algorithms.ChaCha20(b(request, "key"), nonce), None).encryptor()
+    return {"ct": (encryptor.update(b(request, "msg")) + encryptor.finalize()).hex()}
+
+
+def chacha20_poly1305(request):
+    sealed = ChaCha20Poly1305(b(request, "key")).encrypt(
+        b(request, "nonce"), b(request, "msg"), b(request, "aad")
+    )
+    return {"ct": sealed.hex()}
+
+
+def x448_op(request):
+    private_key = x448.X448PrivateKey.from_private_bytes(b(request, "private_key"))
+    public_key = x448.X448PublicKey.from_public_bytes(b(request, "peer_public_key"))
+    return {
+        "public_key": private_key.public_key().public_bytes_raw().hex(),
+        "shared_key": private_key.exchange(public_key).hex(),
+    }
+
+
+def ecdsa_p256(request):
+    secret_key = ec.derive_private_key(
+        int.from_bytes(b(request, "secret_key"), "big"), ec.SECP256R1()
+    )
+    msg = b(request, "msg")
+    public_key = secret_key.public_key()
+
+    # Verify orion's signature.
+    signature = b(request, "signature")
+    r = int.from_bytes(signature[:32], "big")
+    s = int.from_bytes(signature[32:], "big")
+    try:
+        public_key.verify(encode_dss_signature(r, s), msg, ec.ECDSA(hashes.SHA256()))
+        verified = True
+    except Exception:
+        verified = False
+
+    # A deterministic (RFC 6979) and a randomized signature for orion to verify.
+    def raw(der):
+        r, s = decode_dss_signature(der)
+        return (r.to_bytes(32, "big") + s.to_bytes(32, "big")).hex()
+
+    deterministic = secret_key.sign(
+        msg, ec.ECDSA(hashes.SHA256(), deterministic_signing=True)
+    )
+    randomized = secret_key.sign(msg, ec.ECDSA(hashes.SHA256()))
+
+    return {
+        "public_key": public_key.public_bytes(
+            Encoding.X962, PublicFormat.UncompressedPoint
+        ).hex(),
+        "verified": verified,
+        "deterministic": raw(deterministic),
+        "randomized": raw(randomized),
+    }
+
+
+OPS = {
+    "sha256": sha256,
+    "sha512": sha512,
+    "blake2b": blake2b,
+    "hmac_sha512": hmac_sha512,
+    "hkdf_sha512": hkdf_sha512,
+    "pbkdf2_sha512": pbkdf2_sha512,
+    "poly1305": poly1305,
+    "chacha20": chacha20,
+    "chacha20_poly1305": chacha20_poly1305,
+    "x448": x448_op,
+    "ecdsa_p256": ecdsa_p256,
+}
+
+print("ready", flush=True)
+for line in sys.stdin:
+    request = json.loads(line)
+    print(json.dumps(OPS[request["op"]](request)), flush=True)
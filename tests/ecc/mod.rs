@@ -0,0 +1,29 @@
+pub mod rfc_ecdsa_p256;
+pub mod rfc_x448;
+
+extern crate orion;
+use self::orion::hazardous::ecc::{ecdsa_p256, x448};
+use core::convert::TryFrom;
+
+pub fn x448_test_runner(k: &[u8], u: &[u8], expected: &[u8]) {
+	let private_key = x448::PrivateKey::from_slice(k).unwrap();
+	let public_key = x448::PublicKey::from_slice(u).unwrap();
+	let shared_key = x448::key_agreement(&private_key, &public_key).unwrap();
+
+	assert!(shared_key == expected);
+}
+
+pub fn ecdsa_p256_test_runner(
+	secret_key: &[u8],
+	public_key: &[u8],
+	message: &[u8],
+	expected_signature: &[u8],
+) {
+	let sk = ecdsa_p256::SecretKey::from_slice(secret_key).unwrap();
+	let pk = ecdsa_p256::PublicKey::from_slice(public_key).unwrap();
+	assert!(ecdsa_p256::PublicKey::try_from(&sk).unwrap() == pk);
+
+	let signature = ecdsa_p256::sign(&sk, message).unwrap();
+	assert!(signature == expected_signature);
+	assert!(ecdsa_p256::verify(&signature, &pk, message).unwrap());
+}
@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod rfc6979_ecdsa_p256 {
+
+	extern crate hex;
+
+	use self::hex::decode;
+	use crate::ecc::ecdsa_p256_test_runner;
+
+	// Test vectors from RFC 6979, Appendix A.2.5 (P-256 with SHA-256).
+	const SECRET_KEY: &str = "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721";
+	const PUBLIC_KEY: &str = "0460fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6\
+	                          7903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299";
+
+	#[test]
+	fn rfc6979_p256_sha256_sample() {
+		let expected = decode(
+			"efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716\
+			 f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8",
+		)
+		.unwrap();
+
+		ecdsa_p256_test_runner(
+			&decode(SECRET_KEY).unwrap(),
+			&decode(PUBLIC_KEY).unwrap(),
+			b"sample",
+			&expected,
+		);
+	}
+
+	#[test]
+	fn rfc6979_p256_sha256_test() {
+		let expected = decode(
+			"f1abb023518351cd71d881567b1ea663ed3efcf6c5132b354f28d3b0b7d38367\
+			 019f4113742a2b14bd25926b49c649155f267e60d3814b4c0cc84250e46f0083",
+		)
+		.unwrap();
+
+		ecdsa_p256_test_runner(
+			&decode(SECRET_KEY).unwrap(),
+			&decode(PUBLIC_KEY).unwrap(),
+			b"test",
+			&expected,
+		);
+	}
+}
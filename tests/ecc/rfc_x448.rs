@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod rfc7748_x448 {
+
+	extern crate hex;
+	extern crate orion;
+
+	use self::hex::decode;
+	use self::orion::hazardous::ecc::x448::{self, PrivateKey, PublicKey};
+	use crate::ecc::x448_test_runner;
+	use core::convert::TryFrom;
+
+	#[test]
+	fn rfc7748_x448_test_vector_1() {
+		let k = decode(
+			"3d262fddf9ec8e88495266fea19a34d28882acef045104d0d1aae121700a779c\
+			 984c24f8cdd78fbff44943eba368f54b29259a4f1c600ad3",
+		)
+		.unwrap();
+		let u = decode(
+			"06fce640fa3487bfda5f6cf2d5263f8aad88334cbd07437f020f08f9814dc031\
+			 ddbdc38c19c6da2583fa5429db94ada18aa7a7fb4ef8a086",
+		)
+		.unwrap();
+		let expected = decode(
+			"ce3e4ff95a60dc6697da1db1d85e6afbdf79b50a2412d7546d5f239fe14fbaad\
+			 eb445fc66a01b0779d98223961111e21766282f73dd96b6f",
+		)
+		.unwrap();
+
+		x448_test_runner(&k, &u, &expected);
+	}
+
+	#[test]
+	fn rfc7748_x448_test_vector_2() {
+		let k = decode(
+			"203d494428b8399352665ddca42f9de8fef600908e0d461cb021f8c538345dd7\
+			 7c3e4806e25f46d3315c44e0a5b4371282dd2c8d5be3095f",
+		)
+		.unwrap();
+		let u = decode(
+			"0fbcc2f993cd56d3305b0b7d9e55d4c1a8fb5dbb52f8e9a1e9b6201b165d0158\
+			 94e56c4d3570bee52fe205e28a78b91cdfbde71ce8d157db",
+		)
+		.unwrap();
+		let expected = decode(
+			"884a02576239ff7a2f2f63b2db6a9ff37047ac13568e1e30fe63c4a7ad1b3ee3\
+			 a5700df34321d62077e63633c575c1c954514e99da7c179d",
+		)
+		.unwrap();
+
+		x448_test_runner(&k, &u, &expected);
+	}
+
+	/// Run the iterated test from section 5.2 `iterations` times.
+	fn iterated(iterations: usize) -> Vec<u8> {
+		let mut k = vec![0u8; 56];
+		k[0] = 5;
+		let mut u = k.clone();
+
+		for _ in 0..iterations {
+			let result = x448::key_agreement(
+				&PrivateKey::from_slice(&k).unwrap(),
+				&PublicKey::from_slice(&u).unwrap(),
+			)
+			.unwrap();
+			u = k;
+			k = result.unprotected_as_bytes().to_vec();
+		}
+
+		k
+	}
+
+	#[test]
+	fn rfc7748_x448_iterated_1() {
+		let expected = decode(
+			"3f482c8a9f19b01e6c46ee9711d9dc14fd4bf67af30765c2ae2b846a4d23a8cd\
+			 0db897086239492caf350b51f833868b9bc2b3bca9cf4113",
+		)
+		.unwrap();
+
+		assert_eq!(iterated(1), expected);
+	}
+
+	#[test]
+	fn rfc7748_x448_iterated_1000() {
+		let expected = decode(
+			"aa3b4749d55b9daf1e5b00288826c467274ce3ebbdd5c17b975e09d4af6c67cf\
+			 10d087202db88286e2b79fceea3ec353ef54faa26e219f38",
+		)
+		.unwrap();
+
+		assert_eq!(iterated(1000), expected);
+	}
+
+	#[test]
+	fn rfc7748_x448_diffie_hellman() {
+		let alice_sk = PrivateKey::from_slice(
+			&decode(
+				"9a8f4925d1519f5775cf46b04b5800d4ee9ee8bae8bc5565d498c28dd9c9baf5\
+				 74a9419744897391006382a6f127ab1d9ac2d8c0a598726b",
+			)
+			.unwrap(),
+		)
+		.unwrap();
+		let alice_pk = decode(
+			"9b08f7cc31b7e3e67d22d5aea121074a273bd2b83de09c63faa73d2c22c5d9bb\
+			 c836647241d953d40c5b12da88120d53177f80e532c41fa0",
+		)
+		.unwrap();
+		let bob_sk = PrivateKey::from_slice(
+			&decode(
+				"1c306a7ac2a0e2e0990b294470cba339e6453772b075811d8fad0d1d6927c120\
+				 bb5ee8972b0d3e21374c9c921b09d1b0366f10b65173992d",
+			)
+			.unwrap(),
+		)
+		.unwrap();
+		let bob_pk = decode(
+			"3eb7a829b0cd20f5bcfc0b599b6feccf6da4627107bdb0d4f345b43027d8b972\
+			 fc3e34fb4232a13ca706dcb57aec3dae07bdc1c67bf33609",
+		)
+		.unwrap();
+		let shared = decode(
+			"07fff4181ac6cc95ec1c16a94a0f74d12da232ce40a77552281d282bb60c0b56\
+			 fd2464c335543936521c24403085d59a449a5037514a879d",
+		)
+		.unwrap();
+
+		assert!(PublicKey::try_from(&alice_sk).unwrap() == &alice_pk[..]);
+		assert!(PublicKey::try_from(&bob_sk).unwrap() == &bob_pk[..]);
+
+		let alice_shared =
+			x448::key_agreement(&alice_sk, &PublicKey::from_slice(&bob_pk).unwrap()).unwrap();
+		let bob_shared =
+			x448::key_agreement(&bob_sk, &PublicKey::from_slice(&alice_pk).unwrap()).unwrap();
+
+		assert!(alice_shared == &shared[..]);
+		assert!(bob_shared == &shared[..]);
+	}
+}
@@ -1,9 +1,10 @@
 pub mod blake2b_kat;
 pub mod other_blake2b;
-pub mod sha512_nist_cavp;
+pub mod other_sha256;
+pub mod other_sha512;
 
 extern crate orion;
-use self::orion::hazardous::hash::{blake2b, sha512};
+use self::orion::hazardous::hash::{blake2b, blake2xb, sha256, sha512};
 
 fn blake2b_test_runner(input: &[u8], key: &[u8], output: &[u8]) {
 	// Only make SecretKey if test case key value is not empty, otherwise it will be
@@ -22,7 +23,27 @@ fn blake2b_test_runner(input: &[u8], key: &[u8], output: &[u8]) {
 	assert!(digest.as_ref() == &output[..]);
 }
 
-fn sha512_test_runner(data: &[u8], output: &[u8]) {
+fn blake2xb_test_runner(input: &[u8], key: &[u8], output: &[u8]) {
+	let secret_key = if key.is_empty() {
+		None
+	} else {
+		Some(blake2b::SecretKey::from_slice(key).unwrap())
+	};
+
+	let mut state = blake2xb::init(secret_key.as_ref(), output.len()).unwrap();
+	state.update(input).unwrap();
+	let mut digest = vec![0u8; output.len()];
+	state.finalize(&mut digest).unwrap();
+	assert!(digest == output);
+
+	if secret_key.is_none() {
+		let mut digest_one_shot = vec![0u8; output.len()];
+		blake2xb::digest(input, &mut digest_one_shot).unwrap();
+		assert!(digest_one_shot == output);
+	}
+}
+
+pub fn sha512_test_runner(data: &[u8], output: &[u8]) {
 	// Test streaming
 	let mut state = sha512::init();
 	state.update(data).unwrap();
@@ -33,3 +54,15 @@ fn sha512_test_runner(data: &[u8], output: &[u8]) {
 	assert!(digest.as_ref() == digest_one_shot.as_ref());
 	assert!(digest.as_ref() == output);
 }
+
+pub fn sha256_test_runner(data: &[u8], output: &[u8]) {
+	// Test streaming
+	let mut state = sha256::init();
+	state.update(data).unwrap();
+	let digest = state.finalize().unwrap();
+	// Test one-shot function
+	let digest_one_shot = sha256::digest(data).unwrap();
+
+	assert!(digest.as_ref() == digest_one_shot.as_ref());
+	assert!(digest.as_ref() == output);
+}
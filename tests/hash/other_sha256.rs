@@ -0,0 +1,61 @@
+// Testing against the SHA256 examples from FIPS 180-2 and the NIST
+// "Examples with Intermediate Values".
+// https://csrc.nist.gov/projects/cryptographic-standards-and-guidelines/example-values
+
+use super::*;
+
+#[cfg(test)]
+mod fips_examples {
+
+	extern crate hex;
+
+	use self::hex::decode;
+	use super::*;
+
+	#[test]
+	fn sha256_one_block_message() {
+		let input = "abc".as_bytes();
+		let expected_output =
+			decode("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad").unwrap();
+
+		sha256_test_runner(&input, &expected_output);
+	}
+
+	#[test]
+	fn sha256_empty_message() {
+		let input = "".as_bytes();
+		let expected_output =
+			decode("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855").unwrap();
+
+		sha256_test_runner(&input, &expected_output);
+	}
+
+	#[test]
+	fn sha256_two_block_message() {
+		let input = "abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq".as_bytes();
+		let expected_output =
+			decode("248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1").unwrap();
+
+		sha256_test_runner(&input, &expected_output);
+	}
+
+	#[test]
+	fn sha256_896_bit_message() {
+		let input = "abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnop\
+		             jklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu"
+			.as_bytes();
+		let expected_output =
+			decode("cf5b16a778af8380036ce59e7b0492370b249b11e8f07a51afac45037afee9d1").unwrap();
+
+		sha256_test_runner(&input, &expected_output);
+	}
+
+	#[test]
+	fn sha256_long_message() {
+		let input = vec![b'a'; 1_000_000];
+		let expected_output =
+			decode("cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0").unwrap();
+
+		sha256_test_runner(&input, &expected_output);
+	}
+}
@@ -1,9 +1,20 @@
 pub mod custom_hkdf;
 pub mod custom_pbkdf2;
+pub mod other_bcrypt_pbkdf;
 pub mod other_hkdf;
+pub mod other_slip10;
 
+extern crate hex;
 extern crate orion;
-use self::orion::hazardous::{kdf::hkdf::*, mac::hmac};
+use self::hex::decode;
+use self::orion::hazardous::{
+	kdf::{
+		bcrypt_pbkdf,
+		hkdf::*,
+		slip10::{Curve, DerivationPath, ExtendedPrivateKey},
+	},
+	mac::hmac,
+};
 
 pub fn hkdf_test_runner(
 	excp_prk: Option<&[u8]>,
@@ -26,3 +37,33 @@ pub fn hkdf_test_runner(
 
 	((okm_out == excp_okm) == (okm_one_shot_dst == excp_okm))
 }
+
+pub fn slip10_test_runner(
+	curve: Curve,
+	seed: &[u8],
+	path: &str,
+	expected_chain_code: &[u8],
+	expected_private_key: &[u8],
+) {
+	let master = ExtendedPrivateKey::from_seed(curve, seed).unwrap();
+	let key = master
+		.derive_path(&DerivationPath::parse(path).unwrap())
+		.unwrap();
+
+	assert!(*key.chain_code() == expected_chain_code);
+	assert!(*key.private_key() == expected_private_key);
+}
+
+pub fn bcrypt_pbkdf_test_runner(password: &str, salt: &str, rounds: u32, expected_dk: &str) {
+	let expected_dk = decode(expected_dk).unwrap();
+	let mut dk_out = vec![0u8; expected_dk.len()];
+	bcrypt_pbkdf::derive_key(
+		&decode(password).unwrap(),
+		&decode(salt).unwrap(),
+		rounds,
+		&mut dk_out,
+	)
+	.unwrap();
+
+	assert_eq!(dk_out, expected_dk);
+}
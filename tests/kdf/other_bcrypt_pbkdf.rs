@@ -0,0 +1,54 @@
+// Testing against custom test vectors.
+// These test vectors have been generated with a Python port of OpenBSD's
+// bcrypt_pbkdf, which was checked by decrypting private keys written by
+// ssh-keygen.
+
+#[cfg(test)]
+mod other_bcrypt_pbkdf {
+	use crate::kdf::bcrypt_pbkdf_test_runner;
+
+	#[test]
+	fn test_case_1() {
+		bcrypt_pbkdf_test_runner(
+			"70617373776f7264",
+			"73616c74",
+			4,
+			"5bbf0cc293587f1c3635555c27796598d47e579071bf427e9d8fbe842aba34d9",
+		);
+	}
+
+	#[test]
+	fn test_case_2() {
+		bcrypt_pbkdf_test_runner(
+			"70617373776f7264",
+			"73616c74",
+			4,
+			"5ba4bfc60c7ac272931458407f4c1c4936ea356c55125c5a279b791d65bf9842d49d7e1b572a9052715ebfa9421e7e949d8f8f19be3284732af1ba28341dd9bf",
+		);
+	}
+
+	#[test]
+	fn test_case_3() {
+		bcrypt_pbkdf_test_runner("70617373776f7264", "73616c74", 1, "7a");
+	}
+
+	#[test]
+	fn test_case_4() {
+		bcrypt_pbkdf_test_runner(
+			"70617373776f726400",
+			"73616c7400",
+			8,
+			"31d6b168321abfc2a36a2277560e487d036c16abf85b513ad04bbf0719656aa31416cc3653e626f6",
+		);
+	}
+
+	#[test]
+	fn test_case_5() {
+		bcrypt_pbkdf_test_runner(
+			"000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f606162636465666768696a6b6c6d6e6f707172737475767778797a7b7c7d7e7f808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9fa0a1a2a3a4a5a6a7a8a9aaabacadaeafb0b1b2b3b4b5b6b7b8b9babbbcbdbebfc0c1c2c3c4c5c6c7",
+			"fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0efeeedecebeae9e8e7e6e5e4e3e2e1e0",
+			2,
+			"3d00a24a45ee30caa584d7b99a2cd43d750e9b47bddeeb18d0f90a555d8b09e040d2e22877f96db402cf45bdea2f6ebb2aac49ef965c9dbac1021bb225e6df0bdcc6fcbc039c376a4b82421866428543325705bbc9fe2c9c",
+		);
+	}
+}
@@ -1,770 +0,0 @@
-extern crate hex;
-use self::hex::decode;
-use crate::mac::hmac_test_runner;
-
-#[test]
-fn test_nist_cavp() {
-	// Test vectors are structured as: [key, data, tag, tag_len]
-
-	let test_vectors: [[&str; 4]; 375] = [
-["726374c4b8df517510db9159b730f93431e0cd468d4f3821eab0edb93abd0fba46ab4f1ef35d54fec3d85fa89ef72ff3d35f22cf5ab69e205c10afcdf4aaf11338dbb12073474fddb556e60b8ee52f91163ba314303ee0c910e64e87fbf302214edbe3f2", "ac939659dc5f668c9969c0530422e3417a462c8b665e8db25a883a625f7aa59b89c5ad0ece5712ca17442d1798c6dea25d82c5db260cb59c75ae650be56569c1bd2d612cc57e71315917f116bbfa65a0aeb8af7840ee83d3e7101c52cf652d2773531b7a6bdd690b846a741816c860819270522a5b0cdfa1d736c501c583d916", "bd3d2df6f9d284b421a43e5f9cb94bc4ff88a88243f1f0133bad0fb1791f6569", "32"],
-
-["e245be9a9c8101263830ad3515c1c6cbf285a7e4b362ebc062cb8e7e75ef50ec4f315a9e09d9243d7109962253f26e23f847e1adedf2851405076e26a1f697062f048438f1fc26f80021ffd09068876975e4cda2e78261df82f672a390f534628ba58490", "425315dd8eccd17a84c1aa00ff72763f99ddcbc2c381b8b21567b2f8e263d1a210983d88263ae32fa7986ced9f596f4e7b05e5b71dc8de4930737308b9c4fc3defe783194d3c789ae55ba5b3f75665a7c23e11b69ae8bcfb3bf020955dffd705894acfd72a5bf885e7143f9830f1c010178d37066268b890dee7a1e5f69cccc1", "c5801d80a14391720e77eb7ffb1a0b21a16323641c9a312b05fdc34e90383c85", "32"],
-
-["7eb72f117b397cefcfb4e75ace3b081776e46b13521e93559d453e32ab74ebc0859b9a8dd4d1d39000ebe95f984d80a3f5004dc91a051dfbdfe9194f4f9a483e4e7955577fb0933464c63eaec771044d59abc3029a079519f8460a693b25b4ce207ae9d9", "4e6611a6d76395f2b0e23dcafcc672c090e7c00a5fb5e23d351f1f59a9b3da064d06641d2756146a656e9ab14cca90ee72fc52e12d4e10ca4ba9217203b59a6a2c422097ade7faef35e86a7d2c5d3eabe991a2c025a938a8af866bf462ec002155e3ed1d515d6f07881a573683c9a47e5d7057ae2f0d835dcb7ea4e7ad9e31b2", "531bb20800ceef736d52d323a01f91fed1b80457947b1f865d1f6b3b1102552f", "32"],
-
-["12720cff0ca649ff2e1c9355cba8020fc96dbe05af9fb4bc008d8d8ac8dee0fb741aa674e66def16c9a8d4e52289ad2a283c84165621c9a7bed046d0a05b56d43bd352f3e30d4d4854a501f246440872421f5054b3c0c91240096035597631bd10a2f8c3", "def8c9acee9d9f191419982551804a9a9411c7d1240b22243dc32fa5fef28b118353dcff4e6e5a5debae8f1dd3ba5a2c92023aa42598c942a642871a2bcdd989eba4526ca2a1308f37222a699c606906f7fcd444ffdb9042a834c71602e7a9be5e11b159b4dd2a98419df16f74197c100b261b197a7b7e0c8cc178014ce90297", "dc72f7049c66effcb820848059516c44f934ccb2ffca6f9f8f884e0e1c67eece", "32"],
-
-["83488a05032905754cc8fc569d37cae05f0c370db6acaafc56ca9a93982a4669ccaba6e3d184a19de4ce800bb643a360c14572aedb22974f0c966b859d91ad5d713b7ad99935794d2222570a3167733a532eda0b0eb17510bcb581e4995440101a00ee2e", "bd1170af91fdb2160db3522e1bc3b1a349d6e50479920ac5d9bedd8a16a787a3cdc2b6d24392f25555cc2f20b2ba9e6b47ddc96cfbd6df669d874ce21a758d3cf4704362ef7786d90ed67b01bd91299950058885accddbcf44e340ed4807864218653ee7ff7215aa1e176157a611f9374b984ad7f53ea70ab81be131062718ba", "6563097db04630821c814912828410d2cb056219f886786d6bf57a5b5b0083ea", "32"],
-
-["9c054e983cf5488714bc48ccbe9a5bc95e34646a84e61d13545a59d6e45a8ceae5e28c58867a99f895c29c9ce74fac8bfbb5d631dae51e1441bac10a02cebdaaa89293005a9e4c850245ba4dae6d0083369d55131f5804a6bfaab7d3a200f6932d3debf6", "7da3134a85e28a852e16a552aa34d3767d444a583c15f05b942f7c6a8d09d8d5107cca5ba878d48885f65941c62d009df8095ef6d9d9cf406248a49a058e842334e70c0f2244b9facd41087b3c58dbfd05541fca1308ac69a6406c2a9bf8c0ffeecc1f8d7db001830fe1fb2b941c2b3faf0dd6fd710a507d68011c43d8af5511", "adaa76f70a17b99fb65cb7625b4e19de5a539a21982fd5b5fce26772a0dbf488", "32"],
-
-["9963406c515852a8892ba6879e1a91d7b4058711f0d0eadee80920db57cae89916e1eee9472f9d56d34e34f218771c56dba279e484eeff4f8a44065e2c37e1ec622f6c3413c7e54b8873fdee21662e7e46b34e49a1b2650b8aa5bcb5c39b366c6695e3d5", "274376f90a39f49c0dad642cfa4f810afdae7157050847646d60cc6adcd27f7c6a24dab9049dd7c6111ab37c555ef2dd16aaa34d7e8de5ff41feaaad80a8bb8cec85fd7f2eaef28a8772828ab3a5fc24143a58fc0c15bf27ab1a4de28a8a1584f68f65b151154cd1b6dc5ac0dccba7c73d8816cc7745dbc5f2dd3b73c31f934c", "0ab30447b762dacaa6d2ca8a79265c80defdcc510cad72636b0f3f97cff05a3d", "32"],
-
-["7f87d81389a6062e8ed501ea964c2fe35b2d3de9fd676c04f7da2bb552bdeb7f183d2fa60c67e2379848ee4807530a81f403d3dc02c11fa8ce078ed422c6626a6c05905a6ecb1679364090c9510f06fb3c0e09321b21fe0aad5cb9d980674e35612723b6", "381dfe5c3405f0c67216a34475d453af05f8ae8fd47b92d561f119cd1d18d34ecdb152342f8eec0fe0edbc1d7d04ea7608dd2c878e648dc107bf6e927eddca957252be067b624878315b4fca4929e3570124c76dbf2c2c01f4f8c0a047abe7a9f0a848ba61a88c682c0a6233216ac7b8b6748429adfaf8fbf04517dec0a9b3f2", "69dfb148b9527e61b3bcc893cf098e21183fb989736591e80074baa270896136", "32"],
-
-["424f288fb5a07524c162c7adead1f4f4e99b87abb79f714fe8f4fb523a2dff786edb58825654970a30b3f7ae322094706652d75db851356d4e8119ab17dc1e95a77f82c64268d8d2367efc726ed14ce14524158598a94cd21f0b7f6cfdb10a8b95428ea4", "dc54c265659b8b724812ffc57f20ff8f05227ec58b54d7246df56473e4a64c087d149c8dcad69a0457995609df7a8157c0b3828ead7ac734a32659f91e94b43f8cc9d5d902d894132e0634f38d2d39bfdd5e79bfd6ca764415e3ebea59bea519f1bc94d2756922a8b5a4c30cef01cece456b8be16d0e8d5fab7442ccfcedc89f", "3c571546dcdf6491797129d298b478a3c672a976989bd89d9256fc64b3f715bd", "32"],
-
-["340446d9caf0c0f70f7d43325606ce5daeac0f3f5af9794e81c498ba2754a1ad5cc84ed2b3b5e814f612a5a81851354d6bcf98d2136e32d9bb8021dc87e8ce6d497bc2c2f7adbc705d6900770f40f17fa2c6e2611b392ae9a86476db8ec3643363e84db1", "7ce5cbe79d08104f03435fa575cb66f1b1fd6090685039d08b89f2bac52e482f493c9037cd1ce695d6d4869f377b7a4cd4ef768facca00e31791d3274b2f86ad25a2698e27f5b040bd6ed36ac40834f64c2303689d7b5e6f7957bdbaa1038e0d9b7f1c94b179b5773d790cae245cdc17a103cec6444c9d9c3a41781bce90a878", "c039cc8f9026d1c90e766a4bd723a1fc6ed313b99dc42bfd09e6c3f39ccc5c0e", "32"],
-
-["b636c84eba7188fa32cd65d87b8ba7df3ff77b58314a47e5fdcf3bc17e452949328596bcec64113fc9dbc3246565486b4fa9bd89ba591a69755ac2b11d255946916dd612a602d9051cf53e59d1fdf21b351905e7fe7701f31d957b4ef0a6e76ab937b1d9", "fa0f033f3293389e5a9312c6046757eee20fda34e4c8b4d8a1ddd2a8f8c0b0bbf7596362fb9edd5c19646d49bd74856191e2c066a19a23d7bc3ca4671d2ae8ae4bdf511d3d823837c2ba77fe20c52bacfbc4b8a479590da8a474294c106addabbd48d4583e66d2981b3f39ce757fd136e85ec8d6b62df8b2212e694018a9171b", "2e22dd9af3f9135dd49b88b2391dd2924f0b5b1d8154498d15681a329ff02813", "32"],
-
-["b80c19c671cd50f1c39dd0e0e89bcb50b41fcbf326fae8ce3dff3edf0cc29e04119f8418c3fe495f6ad69a24337029a0846ea21e8d0be15d20a46236c278f97e02acc32e09925cc1732a52de5de9da37bfbac2b1fd43a7c5b8696826ea16daa43239be50", "490aed3e0c539cd2af8cc9052a204338e9150354c537bd0a3473b560bc81d469aec6fb53107897a8db0376cd67abd9cf2373ca5e893eaf1a5135200ee568f67a16b8a4d60df4cb138442082436c111bd936c005bb942ee84a0a4c45094fe4e28e9a991ceea105f9d121228137fa8138ca3c84d503d89a1d087874a2db18905fa", "62461e063c08dc191e4bd04d5f553dd38d7679af84a1d0b140be7499b34fa43f", "32"],
-
-["94753191a7f8b9095102c5a7691e847623c068a735d972cd39a9af4d53d4030f13b9257a9dffeba00770c8b5020b93c6bd5489a706ef962eb36dd09e40664466b9c8f60cda6e236476f5c90515cb6afde1880cc4c382846fc99bf426cb5e93d84e4bac2d", "0fa4952307a137b5250aeef287dae233b4c8f79ad2b3a09a1a43f8b98ace0f94d9788124b09f4e411776e5642eef82b11ddfba354d5d556cd96a5b063fd871ea5c64667c97260a1b5c2b3feecc6052e1b2b18beab9730291ddffb5af20a0d8767eb06cb122fd134dda722319c9f3f9ca5c8890427fbe5212104a2d3d93f0ea3f", "a08df9f841a2e227c4d9ba9c87e5d14a421d025ff4bf5d533a03ea449cd89ea4", "32"],
-
-["986b190803ae085510e96c2bc65c956b14881040089478a542ef8e28a7378ced5a5ab7f5e8fb34a0c2171c71f1e8d312050995984edc51074f29bb1970079d3bf3006dd9297025dbe62e4f70326e5f0a41e646aaa630445dc370fe068436d05f4da6643b", "de48fc7bda19796ffdf455ce423c79b0f55db17e67ae03fae69b3e7fec03d58a97dfee7a5925b794f1ecf94a9ad0efbd829858807d16cb9ffb4f4be038dc1b48b290d3e1e05bb4bb216e3931f3a0ee6d6511852fcf0d27c9c033086f38d63ead17a3d339d68419eb75c53d0ab4761fc986106111c35bbf811d8e9eb455b8e7c2", "376f040e59dfbcd2936028edd6ff6011d2e2c8ddd98acc3e946e1880d103d343", "32"],
-
-["c35b2d9d8a383db4b0c6d46a59a6dd8e6843ebfba0627bc809fa79be9645050f04177644056f9f789e4b52c4cec123fa704c6e9662649cf1e751a1b84a40c99dc597b54ca086a0a19ddee8189427c9855bed6b05e85a1f34ba0ae5a0751a337ecc82f19c", "76e84e9a2f3063d740eca3fb6be583327dc9ceaf2d7ca49f48b0c25c0bb504e4d0922789ee54d4eadaa6da2192a2bc3e3be76bf508ec54935f949d363702ac3ec5aeeaff57b8c6b8807dfcc975691720b70c34f5f26f0ab725a8274604ac7c629b651c643304412b7999905adde09ec1395eb218b3adcdf3e415e7f23a5c7c38", "2c4b775af82a21615fe47e4c29322f9b91359337208378b036b77f809ad83d54", "32"],
-
-["6aa11466a2c96e20544c5b34c91f90d17f9799a57c73ca00e21d7736c42d6845382f87b7ada6dcca7f51bbcfc9ac3cd07bbaec75ac02c22831d755786edd4bbb6cc915f82c68da043be3b0ea87411290712d8f9823fabf8524e47b1f29994d182ff826ef", "2771cdfdd77aabbcc95740bbd94075876ed3024f0b5a9949214d60522818a5d5ed1dceed2efd8ece64135f61e0422c2e19f14d7f4553b9c226ec7730275156aca72fdeee958e86e03d5728486114b1a88cf81d62a31fa320bd162c73349e0abbfdade74634b6fbbcfb2a83ba853f6ebd6ef059424e45bd2b8dd46765798829c8", "2476dfac043e55518ddba312e63699b22a587fbdccccf3981207ef5cfc2e27eeb6a4062bda6b19bf", "40"],
-
-["3beb75373a0a02107b2748a7b9a3738b1a9d75fab167a6f8473aad9e5b28a4b567537783655d8de5921fab9e729e15ef143af668972901fded24595cdf60ae7e51c0a97cc8498ba529d611cdc40d9ae790918bd7b879f38157026b26808041f0829bd5cb", "0405555d89a8117794c64285cd30047d642a1d635f6eeb6b33739b5e92ee9c3e859a532dba34118b1fca425fb036e7f88ce646d44fdedfc09cffa341f9896133e0df81b0128af4332f816bfd84a7c7b82496d15b3a9b2c4d9d0188cda59317913e5c45ad3ab1a4b7ec722529f28c8e0652c228c1c9aeb85a5f02b1ca3f14a8a1", "fa58f0aad7a3116caec2193a3dffb0d1c0b3172c3b59a9a62ebbf31d21c766818f1cefb26052af72", "40"],
-
-["6bdaf94d6a351f76604658c7b9949f9e6d0dbafb8c4a6ce21b15ed011e289793f638e284ed3292d610c52f433d20b6247cfd8e9192debdcad4965cd53a0c4869b43c1b0bc17e5b773d0bf1417abddcbc9316261bfd8417d65fe3aa50a4443db64eed8c2a", "2bcb4303bc51438dbfa7630511c50d3a13184a25ae77e37a9458c67dc844aa176088f6298a2edf20cd2bd1ca91da1168e2a9a00471136204dbce7f002e450ad1aa48e1eac402d4ec712c0974a0819e3708729152251adddb7d74e6bc28cc17c18f73caef74b768183312d1ac47a2fed20fd9e5b7d2769c2602b4c25abdc11b10", "edbfe1cb8575c0b6ee7f31f156a6f5fa5d2ec3e433eb5f2a64a0c8e575cad47cd022a11f94ccce1f", "40"],
-
-["521f28ee6c3019f21a3cf988fbf10fe739666504c6522963e57c532ded728884497e3e13c4d622e90413b68753e43f37a604dea0d769fd9836bfec394014cc37aed5ad70b2f9f3c57d7cfe29040f0af7040e538943b27d061a88347b3021102e880bbb2f", "c444d8cf048b327b1459096f0a403d5fdb2e8e56713755a0fe9472e78fec859ce4d361997f91bd04fb242d769f88515c33354c9e12ea787f81a959c53f02cf195e958fb050d3d32b450451bcb58e6a8b3874a5589e1d5b613c57b486849bd2abd9934b5ea1d9ddb99b025d8a72dbf1b293f27fcb3c58650ed6a66b73853ef519", "bcac2df34ef75ed3737537d84c4c02cf2520a7b04dd47b90734a65c2fb4d237d138973feb8c7c1c9", "40"],
-
-["4241ad5de14a2198acb4c357c4ad5759bc09b335a038d9ee367563c78341245991100ce270424ce07b54bbdfdc58788f53a2d11933c564b3adbe8473506edf60d14e5dcf085e4bed3c5eb898a0411f731ddf473952ea6851da238fa3cdcd43ab896269cb", "20e0b7dba419fe76c427c77bd3e31d1b4f9f99dab8acd638a22024910ad58142e6b5c50dff351cbf0320b90fd696c9ca2a54a62d4f364e924cf913502e4694dca0eceb34bc6e287d21bdffea4646bde10e2f80599cdea88768bc89b992386282770e931783c90af94686ffd4c9fce1a722b9902646aecc5d1feeee95deb3d57b", "0741bd432e785b3e1e62b2e5064167b84e15415a7b21d168f91b96bf89efb2f0c3e4a12ae089e1ae", "40"],
-
-["1b0bb110ef8d7139773117d7308dac5d11ce7c756f071df11ec8ac05d9f35ab4e3cb2789ef4eee873ec5a2620799d7f01b6884dac9580797ffab699bb394e4cb8149a235965ac8fecc41b39b163a81441f55d4a62ec9d2d94d8b7191aa1ff1983dafaa7c", "10cd0c9fe541169120a005d9486737eb54bc0847134751f0cb432f2021d5063a389cc5001d896b0663f1ca660ae08ab6a6b17237bcdbe34f700ea53a5d7fe7fcc580809aea537b89bb40d367b3e57be9ffcea115df4534b14e6ba4fbe588e99afda0c6d514474f0372b5a9420b1d116ca83b1f47422d4cfcae83b3cb809914fc", "e59e91f69d6cb65db4781a1dff144dbf9dd61c7ac6425d2612d8535d2e404bbda2cabb349d7528ff", "40"],
-
-["e4b77ba343cde0bf4cd888b0ff6effe0ef5f24c38deb8bb0a9331fef36ee0c134e5ddc897681df72228bd1ae7c94b739618e0abbac7b175ab8dda6d3206ddac9c90f585d2306abfeab64e882b5c58d76683dafb30a8f55327ea01f220a2b2b375eb53844", "180bfcbdf15eadf2b90bd239fa475e104924aeaa1a110494d9a03798368da9e2c0fb7861b500f970186462b605a6c64a4240471b162c87905ea75f6bdd35d4f3d4e54652d2d6eb3ccefa7cd1731a2ec5bb1be4bebf7fa3fdce2f4641bc7773e87c52cd58f1e86b9d02558a53edac7ec7dd3bef59be11412550be54e27a5a6e09", "9481e91ef436ad8a6975300201997106dc545b261e7183533931d51aa19e1844bdc9ef5e5a860b8f", "40"],
-
-["180deff707d32ae0b6c0b58f030a7b8a6a14e9ddd3ed5ebfae19dd724295066b421544b3a290478da8feb55946611cb8a7022cf108fd2394c9ff885dc44f1c5add0b0e50dab56e5c76eee4d6b080a27a69a297ca2e78e5ae53da965e41568a811078b497", "09d575baeef7e337621e239c03035890b68a2730e8f61bef1a5948a09c4f2fd198ece25b53f36937b0496393f6a9f025d298cfc0a09cc425e6a93d1b21dc34c2c56c57247fdd670dbd76de1d70f6d41b1083238ce1e3ca8476ee8fbd7343e9e6973b1c3df91ba660918eda60fb52cb1878316bbd3633f4b6d11287ad19ee13e6", "0e1328e618ce2d3668a3af236c695409eae4ae963a380ab4ef458b68d0d313a74019c2a79d65bb02", "40"],
-
-["b522b2664b0a3669d6ef3796ffa1d5dd0a288c1c6605628b82bb6557e28e548d718b9d3a19c887d191edb1cc76f3bb969e2c43a9a98413f3d806987f23a29751852d1b929ba9b2ed6dc7d8388caab4575dee025db4e951f5a4306e01965d2080991015b5", "b3db592d3736d262fb074b7391258e13b4f2c7d08a8ce5e82648435f515c884e8adbcc7e4be7fc358e227f5090651992d5b5103c6c728ede74d2476cdf8e5b9476db7e4f1d9c56b759d624180f64a7498fec3e3dd0114cd5fdeae0f831b396e680c3b8c936bea98e630c84f2978a00e0ea7f97fa3fd1e776ca79582bd034ffeb", "a46f4c53e76cf37f354d2101df409db5a1f717c498bde11410187880c6a35249755cdff928c05023", "40"],
-
-["703df657c2480fa26225874f5b7fee03cad062afef274a6f832947b34c55679cf08d9ca907d26d927350ec74f49ae82ff736630caf5c46a467fb54fd17fd5df535ccb4e4cd15e37dd4cc9cf5ba70f3683e90cb78868e8e376fa322472223183fe8022651", "32c3d3082cacd50e386a307cad0c89f7ee78fabd8e035bdbb73fc0a0cc1442514d13031efb066c553dccb9d6c364b8c76947cd885efb3ae985e59d9b1b5fbae59b0a7b98de653545879f2dad9eaa5b797b916e2ce60b10557c506a09552beb90e351455f1df1b946dd8c95eb8707fb77ecffe38f6e5c638664292fd1c0a35cbb", "2c9550074d5acd1dcb9ca0e09ddf9402e09388b7ac96393d4747750b7eeae484bcc0f4aaf6f74142", "40"],
-
-["29fbd8f37c84f5b434abd16b6a36ec2c34f2ab55c94aa0c0e5170c3d6fbaa23f5a698ed5c9c6b6dc76b64074547054b073d4239224ff28365f1cad4d617c92cd99ea4ae7c538263a591ed64620452d23259e2472d4f822955dd27a1b596d357eb0261311", "5df0b8cc7a1fd4b92ed482809f6a46e98a7cd81792ef1dafd733553dcbc596d341f979f3c7f613ae3602270e870099b2e83626b80f5453e0cfb2a3ede9da0d4f2446ad00a06bbaac67f6b8144555efb4da791ba3edda65bc03bc92fb30b17b292e60bdbcac84b1f9e8389f5b80712e3886cc1fa2d28a3b8c9cd88a2d4a54c6aa", "4f689f159b02f42863d455875a4050b15ac7626ffc9128e3dd6208e97a1bbcb1593ea9b6a3dcd4d3", "40"],
-
-["eec8c90aa52c43e76ef3caff62a0648de4dda52a32bfda383b4a8781b138141c5ef6c391bbf0e53cbf0e4ef7f57e06f3d1b541e744b0916d8e805ba85405882bf2fa7c1612314c827e202b20329d261cb6e61248aae4c276a2b17d253557ae6151dbdee4", "bd1df1cca29d293a5ab75588360ae96ee616b0953df3b5ee344264483265ed228aebe9bd454e407623e14a53dc0434dcab9aa62c0dbda247c7b0b0ae261438318a5f2f05268bf516010b7e9e4003b1f7a8b80dae5f4692d8f291dfa99c7d9842c6620acbcaba12c2fc1d3a92eb702668e4592f42b8f54d49bfce3a601d07d4fd", "00f5519dbec8caf1213ca51f38bfd47291bdddf530cb56f47f0d2331924ed2711aeb94edf603b8f6", "40"],
-
-["9f8586d89146bc0bb7dd1cbc756d4dc1dd0e198d26717ff759c3033d4ed34948d776e2313b2b6605e6fbc3c55ea3d4b179f536c6ce7c5786695fcff7c387f4eef104a88d1bad760b3007deb3d869ec30aa5d1007a0482965c74ee31b3b7043e753f780f5", "e998038c09f9e420e7d23baebd0aa868ca700dba9af2ef1f890f1f38838f991f0d71acf989255bcab5fb048e76dc3f85d43809ce453f22372e54b354a7be34ce7fa18dfe7f2d7bbaf316366f67445170afcbe18e2a1de1e9477fd50647b9ae214512eec4747b0c98a90b1a7c19aba5d46313dd23d1f5f5ddda77cd76ece7cfbf", "ec3dddb720380ab8300173797377e65136d139dc7c85886bb5dfb407b68decca1880fb7332356dbd", "40"],
-
-["39b877b8e82ecbd9740325828faf6721c129046eb0136144a03182b13620e2498145a2bf3b03e6b64b317dd48fcbc018d9e7bc6e37eb938178fe1fd1ebbcd9056a2ef98297f9df3c66d5b2ccdc4147c41676443f8c9985bc9734be2c31e76249fc5bc42a", "0a331ce20089b29e94b2c5f518c8dbead40417a2a8d50018f32f8512b3263d54edbbf3134ff661ac14353c9628c371958cacaf31fdd02567d0378d9e21a469dd2c6d8c3afb89dd9642eb5887870e559685d20dabd3865ac5c146beee8387a76f91f0f1404d6cadc2e67d21b07dd30f53871d3bf6731f279a8c0421eb20f67f72", "97168f5513c2e9bc4bc525ce2703740bce1a06ecfe99a570ac66c83ede9667cc07edf664617ce53c", "40"],
-
-["9427feb606205df574911dc02c5d1dcf1798b85d90013cd076cd9e9c80a76c65d1f40264cd5b010b2a85fdec4a2176d7c82c0a42516fe8eeb54988c83dae9a0e40703cb2f1bd6f037beacc8a506e7332bd3245adc3085312227895cbb6c3e0ae0622126a", "8beb01cc36cb03235d113c97dc3ef8dcedb1ea5f9179b8c43268bb938668002eb6129207c07dfb0f1a540872982ccd818f14e9ef3b6d0b415efcb5802a3d403161fa2187bb1d6ad688c3944b96917bdb554baa7939a2d98c604eb4a0755dde5bd4cb36b3ee52595fef83688b9f160bd0867fb56354c22a8d80873f5b2e71e196", "a8c6c8f81e845c251428f4a87cabeaf88c9b45c1fd613a072d58864ac7343fa4f401b43ef1892afa", "40"],
-
-["79867559c9919f394cde76b31fe22f793b88987001764e111591300f70c31339b1fcb8a3b476fb00663e4c53d8f063c792c130da29c311c114654808bb39ac7ad1fb9e40e4ce3f4d32c8e1f9bfb1bbeba408e03daa4772db1769122cc8a89cf5808b117a", "d1998d3b3ff5bc1c8f724aab792de8a35c60fba4eb41f2342227d60380ef2273709ed6570847c7fbf5303966b630afdb095b02a6a3e2b115243478c44a69241d65e1a5200b2865600c1dd84ea85455ba00b82497cb75d8ff4a15de777ee8577398aa231db1603e52bb8350edb8607f492abf8801cac30efa7c1132a48334637c", "b6b18c4c5364b47fd78c3d321dadf60de187fa68f5d7e55ca7fbf71f42c742411d379c8c55180d3f54cb9747b51aef7f", "48"],
-
-["e9168c659c63b6f40523c90532bae743f24feb2e94814b6df2554365af73300abc933af5213235b8fa89c96ebfbfd196c95e02211204cdc93d5b86a5d64ac5fada6d0d557ac3abd61ca7e1cfa302448ef0be6376a87ea955388c85f11712c7e44b8e4eaa", "42657c6316fbc1bcd69a87c7df3ab06dcc1e471e9764c10a00db83943314a503a5bcf67b3f28402dbe32cdca5a44293da5b22a99b74d2e508835a4a79deece66e7b6c4d4e816bde217b88aa78678630cc2bde6c9fa0a5d9064d04087dd87fbfcd0fcf05558edc045a9d3c646acffe543e9e88494aac4d6305d555f1e3b06b42d", "0e9b3afab50782f1751c80b46ced531c07c9ae4af50c77a058a2db31dabb013b719c4c22f5e5adeafab72154cf1fbd21", "48"],
-
-["18e18c27755ab04f7d00c63788da883fc36f314ad6dc3c72c3256efa11c916832b99948303c918f4fa48ac49044d75a607333550858615637b5ea2c389eb15545252d76e26b7448bdf9bb0430869a77b454b79d9ee42f67bfe7677e82ccb1e4657b45ece", "3b0b520933f465a422231fe07270cb6c5f31317da0525f7c49a8a9fbf082efee0e811e18a9aaf9c330ba1ab4a7b9335e03d29a56d8620507314f92a7bf9b0a6a373966f5f51f9215e59c72b1a3ec23fde10a83f10ac5f3fa24165beffbb16f467cda31885f7ab70209722fbeb02f20b1c07ef3367da2b1bbeaa50ebaeaeba7ec", "6e29ba429cf494661280e7a8702913bfd0054cd83c613bd6e837f1230e65cedd2498f639966383c87312c1f30bfc3245", "48"],
-
-["9ac2e1ad014d3fd6fc343ffb64bf964ab83fe435b44fe7d4255aee84fb5e91d5a0efa0a3887e59820adbde606ef9e66547f2b21e034a43dbc078ca5561a26094e1a9f710e86590179c32af6ebaab0c3be5b58c223ec06faf3449cbf49ea3397307f8ce3e", "e2b3d90746200181461d55df5b894b91cde375afdeae5682441a7446dde0ae2e9f6ed0a9ce4465e4719ecd9006538fe575fdf0e7435ac4e808f2844e23faa196ac791899aaa9470e86a0fe51c02fda63e10bce31b12a0319a970e2aaab8b6fabf11cd85f44174d086e6c13aaf1e7d411f9543a1590e8bc1d11dcb7e2df5bc076", "a7d14d03b1e40581db5ba0f54612809c3f03cee5c722f36d3d03608e2804b4f41fc5eb51e1406e1cc8dc046d29c92a64", "48"],
-
-["92f2061c17e008f62cf70480508a914cd964db1f8c0de0516b49e077cee20e40b5a78c9d5db5962511f5feb743e6d861db9d7680cf0a9729f47cdbc814677a07406636f2677c8e58289f3574e577c2cc1963a95aebbd6aea035f08c39c65bd89da35ec62", "a01d062181b8bab5f1209dfd836afc630bee6688af02612e41095b54ccade3a26c681908634963a36af562e045ec25e39a0d9c2a035d4f6d62044fc975986d1eaa9eabb6344ef0275e97007a1f63aa8cef1c44b5da6f54a8d350909a4a3e09a701c8a3711b50c80a760f5d143a59f526b22245725bc2d9a53bf5050f74261d04", "27bf46dd28ca5eaabb2fc9824095e7fdb8efefb167409b7f7f785897a05a3a8997ffadfcd5c6ed47ce218e72304bb79c", "48"],
-
-["6d81ede1cd618f73f0acd379a0956bf4ac3e471e31e3fe4618566fdae0aba8c69d3407fc41b09e541bbfdc979d996ad662005401707369b5baa5e454e92d4c5b5b7f98f06c1b97bbf5048ff7137425c92ace7cd029d4a1fd0f1a27a5aeba0ed4ae4aeae5", "d07e91f235ac32cda78a381adec42f86e8fdbe6cc114d93e23a637269fd407eded46df1b816ff5605e1391322f07c2b8943951e7baf0f3eb2e54c2c8f330ee41d2f7b0f9f10580d8a72888b08f5f5820dbe1dbb9eb618a601f91dff3e9e50d302699eed2aef53dfc29d005db2553598866ff462b7df106669d6576f1a97acb81", "246f366361163cae164ba273d2b52844511462eb094d77bc3b7684b7995a6ae015d722eea129d7918c53acbbb5908acf", "48"],
-
-["c10a45807b39df778490c743d9a77b7496bd9651b964ac28297b76e2f75d2fdae3583df0226114d95e0558b9ffa341f3a9d9443f053cf0fec54c16cbe2a72c53287cf366f543584e3eab985fd2d4a8cfe3bfd4c8c203a6bceac109f940d9ae7d995eaf0b", "899c13c9c430c4ad724f268c0d1f16da00ec0168ae1c02f0b5654aef743dc5b2cb92fbb421bc3427acb0dfd1ec76341979dd1962ff6d4297879d9d07f846ed74dd58b46668cc57c5b7d97da7576bb86707d167f7beb5db77ef52b8060be19ed848540988a14776b01d34c8b855e6e9eb3030b4d0f2de13b775ec6492b5f62206", "0595246c12a0ceb9b18ed49d2edd2251782d7e3fe0afbd163834b25a31654a25409976513e12c800f5a4a2e05df1ae0b", "48"],
-
-["ae9644bbc297c7dba36f11ce9f447a157bd3a06c5dbf68b4ea283f31ab80a6f5b59aed9c89043e3a3997de92cc4cb089e452fbda9e5d6c0aba405f9ffcbe5a8dd19ddaf136fd5301d6e87243722aa5fe8663ddef2a5a933c369409aea926d21a4cc79420", "74e8994d0bade0f0fd90547cdca2512e0a8e63b38a21b6d5100ace5a5ade7829c35f01af16fda581a09d4a43899a6fc864095f7a0442aafd919cd50fdc7751b3f080aa8eea3001910a96bcd809577cd2adf34fa39c4928c66e5e8cf7055a8d5021d3fcb22f4edb666203110405e1619410d29df4f940177accd859d97e8e1649", "f1c7440f008ed213f33f246469a3618a49120e841a211378ea297c7540c2f2365531e2b1e932e4661f55033310780a71", "48"],
-
-["222037722ce86b719e85f8db721b4507eee37328ccc69b80972b56d1580d83157250446ecb554a602ccc533eebc9f0e2c71a8ca113d9aa7f173d6e9eb3b55ffd45386b5fcdd62c884f9469af7976e03186dd42836213806a0f25456e47f1c38c637fb32b", "785ff8f78955df2592bd56e810355bd98054f275c482b586c90d8bdf6779b84c881716c98dd9a1f2ed6439bb1f67a461bb2bd3a1dc6c6627c8687249d33581f58057dae8ea92032162fd85965a58ef25829f1dcd30dfba1e054904eece85cf4ea849cdbd68290c9b39b1c0e087b1430d1cd85a638984ba53c8d9cb371f11ab3f", "c56c263ef9c7054fe982d4ae76ac38c6accd30dc29a19ccb52a212d77496d9e1648da47c260b2427d81f0b65f17e096f", "48"],
-
-["3d017f59bee7d18fd4ba573f3e4f61076f5b9f6a3487e47d98c729e205e7d5faab673c57258865705aa71099a2caf7322b79551e9acfa577f5d0ac7fc0fdb9da66262cfbdb8035dd80f7def347c54c611744416c7200e62f5511c8905fa8207336e7572e", "93fb9be674a8e2643bd12f68b02d2b8470f4ffca93f11a838bd92e244fdb7c7dba7852342d95d2727ccef4dc080ecfc5285c14a1cef8dbbd67a7a22e024b9208b5b605d03cc04633e49797f7a857793399db27bb83d264dfcd157655a13873734d5ebba1e952c60f3ad420a3d731b1368a1742fff9a63d4f86cab47f73c9a527", "2482e9bc78cd1e5ae912489ae9b51f1f21721c5856187da29a41a7d10c5dc1c34f8d47b2994aefae3cd5dcc7c8764f64", "48"],
-
-["e1d89c4790461f936cb9fca580dac01efde745cb8db798c9e5f83bed99bc8d623534869489baa74d4415311ec5f996012dce2d3cb9b229894fc1cc735694d803cb07479c91403b2409ed3864e3cc91b2347120cdc8443a08b9c9e2ab40243d96ccdc4727", "6ff1b7b637e6317d8269c1be2781a4d7de97ed99022b6e9e96c6dbdc87302d7b30e245e423bed21b27fe744e7d6b22ae68cfc97decac3568d52e3518fb5ffa04c59ac189ec42b23c81b3077f32797052c9c1b66cf50c9f8b4d2b130892677cae340073cc18d998899971c178cd15586c0954fe8ea7b474ac06a3bcd4d3b07b22", "7c7c7245d6916052fded129f08de09671350d26fe704461e446816ca790d53e1d9eda76e8e71f3018f442f86f3cee737", "48"],
-
-["6af240550657a89df868051711bd5c481b1152d37451ec35fcf39ab23189748f9996c38261a0c3674386a734ad14de2f1fd5955fd4287d40a696cebb2c638c54256309a6190fab0c3dd8efa298bcae6c64169fd4b7ba1e5a62412b8719a2b622d5031aa7", "381d043cbcf4919fb7d6b70a1df2b74dc9c9df0e1f405076f0f3052a9dcd117d80d628e4874b61aa22f43ae460b2dda85558a27abebbd407e80db816cd66cbbb80a44ccdcc75fa9b18b21fdb8eefcde37d50681cfb9e64b3ce9950918d90148e023077e1d39ae4db61b86dd6f1d010680a5939dc1d0e89e26c42d6852473735e", "f3119898e26deb4a0f1177de7d8914ba33713d9226ff2d3de986798a6a7584b04cd3c7889a3d9e3803ab73363340362f", "48"],
-
-["fbdbc0f366d4678654544804b8d6fd6f171668f2832e4623cdff0785f7d2de51e83f1476634fa1de3addfdf3bf4234627c31391e24df7ca9c967be8f4e6e243320028bcd21c81cb4e55720d921df1594600e01a4f83406713da53793f45faa980becce02", "660ad30aa44a6b7dd2636dca34a1cca5006c1ce10862542a98aad2f6a2849fb91824f5589ac0fee151e8df791b16f596e8865e6e6447bcafd6933570475cbf67e8279a5dae1d1de03f5fb638119b2d9cd0dd28a501a7f402e643826ea158b0df9bbcca00278951e26373fbee5f58fa1f8219816e29a9858d9063c1d7f6a1fc88", "e6c788b11374ca821dfaa8a26d64364694e7be419104dd83368dfee6b0af84b66d85fabe37909f4706df9776a289c81a", "48"],
-
-["6656198bb79bea7cf7df472111bc7a70b076c2763f23ed9c140f292ae85e18098bf322cdd5910ff3e040d56674f3ae48c3676ef0574e142f48ad9fd8b283237f66ab1b80e00301447ace1cbfdf579a3dc1d8de5fcf991b60e15c229d76f978556146acad", "c3593383ecd41eec6f51dd4ea8c65d7683827f499cc163fca57ee68709886956ced8d542c022dbc1ccaa8159aa59da5bbf1014cd413cb9a89cab2e44149e8010ac1f5f8647946b5e0e95af0211fb6b433139174a3df0a4d15bbd0593aa56ee0025d5dc36cb53552dfdb9713127d39996a2a68fa7d904aedfab5d745c11d83d90", "20cc7dc22b3ea35062dee1ed9d271600b3b19e8b15c15de5e4c3ac9d9725f620664eb8b239b3a6a167c24bbadc4c4595", "48"],
-
-["da71f6b6ff3b2e902ac99a4946e8233a0dfaf7e7cea374476ec5475faeafd516ab91da44ab334f0c16e7926cc3bdc11275f56671a6ba5a15830de33337ff20856c23a7540f436cd75452e5bbfba26b03d157e412791c9f066c147f483f135c61250fa610", "14c7da8a6ce161b9d8f26f09b72b82d58763264757ba0b1e7f7c5aaf8e48492a8471ee1f42a450413ecbc4697b3277deb9e847d3e969d731e379e2975b53cf9f11038664491183417b5f28db4b545861bb3a4112a0e5790053964d14a81f565324474d5f6a6f1f41f77644650fdb4de6f721bd0d65781a143599442f5df205cd", "959fafc3f154f612a76938386ded563fc09cf76c5952f76988b927415ec31fdd32ebc009dc69734881adc6eea6dd4d75", "48"],
-
-["2ca67ae3ea3c84a2544ca8794457340e1e424a8ab3aae292657712798bb48eb4179e6b8e76fa281db7acee74f086171add5eeebbcb63b51eb4b1ed57ac22d13e7b67241f8c582cb30689ff4f381efd5c3ae09e07d1906e39947b55ca4d4e1cf2a22c2d00", "84371c9ff40b745ab5300bc914512c1468f98b339704e204db54dd9ac6c534d88462163f61d9ce058a2ca16718aaf5404a1eb9d912460003d486ef636adeda0e6b1aaa56ad4882e3086fa2055d7e8b48d7836349711d9cc9b934d100d3e7f033c0c69e89e142d71bb661d54aecb734559791532bbc73fd7d12c5491809c9be72", "eaa9b697c83bc571a029a6df1f440e8c646ac763333d5f7a57a6ffe7cfda7f904750ee4c3ae887e1c29429012d0aa736a8326ba5c974fce2", "56"],
-
-["a8cefefa109facc85cd6f65b91b42a988af51fceedfbc5f75d28003bf18cb7b6e5c0287b90117ed996e1a5dd5f4b5d17c8068da1188cda5e357b7980183b414d7ee10522e05320b8a6ad51a698e4ca2795aa831e8791d457967243109c109bb8d9f93ef6", "6b8bc0971c1c64e8b8df91397ab8f0a1f2823c004d48a4d8d6b8705fbdd4e8d217eb710b27c8fa56dc2996819a736a323ea3ca7d5c889fb6ca300c8463a0513705c7ef5cdcb50d8ee3091a8fa7a8b4974fb5c8ebd9bdfbb2a6314904391aec453c0880b1c34b6437d566638b29c194772d9e7e724c4e807371a57107c7ab83a2", "9cd00b99f73ca0e8cabba5513b575698fcf7a27a396dc33afbab9872589efa826b2cb2eb661ad8c36f7b99d623f440a04f0767c2500b0598", "56"],
-
-["469e10b87afa551fe02ebe13b7d58da068fea2f100d19416e2760004be3221ddbdd3a172fe4faf0746a85256f312fdd63258633c727c8c42199e29a8751e2579f5057c9099a1505aa7703adea040defa7293c2efef4860207e787916109fadc856fc4919", "25bf516bdcbe24c2a72dade92fda0ec8cd91781c589b9e1d3b856a5eca23eff4d6d3a0da7e06bf9449fd181372d1eed983b6365d258003b1376742621d07a7a05babe79e62ca283ca9d2b5e40025f7b12261a1d789e52a3f8691807190ccfbb7ddcc8f7f2f124702f133ee2382de0f9ad11b21512ad1fb0e4f8b8e53cb7bd283", "6517653cd34e77aa1c7cdfa793198aceb26b7e211eb81d8fde20e89f4fd13f1016de122524968087386e6f6f9ada63cfda9ee3e276d7ab8c", "56"],
-
-["b37cb20cff52455a433e9e3a22622601ece6392d7a482a535af388582d14a0e44ca94314b2daca6f168d561c57355d521dea620fbebf030d5e5039f25b9adcc6e81065abf7ebf8c85dc1a2873059df0074be5fb5f792097f7aa541c811c666fbcdd99f39", "6fd8b486cb00c5f5dcf7a6493df96cd250f51b13dd9f6d74fbc1cf3734d1adade4c9efaae233c43662839e851aaa7e2aa4e8718724e25a882af4f391dac80fb44a2822282b1002f6ac08eff69bb0d2facd9dc249c163cc4f80f3f492c72877e8689c53e8eec0099e802ca2830833f0ab94bb1641fc4b4b31c566da2445186db1", "0777df7e26b053d025a49599d9df51dc826221e47879bf916dbe1a6b79342d757de7af26e36706d5d81f9244c2630fa4d71f3a24e03106eb", "56"],
-
-["b2d6b897153734836e180ec29443f60de8e37b8a8beadb4fceb01922eadc5182f28849ec05a04857c88b2b01130eb56deb6d27acb1d310dd98f367f05df0d7af327a5afefb3b0961bc7133c052dcac6762da675190d147a6f1cd3382c26a69f85af51457", "59b911d1a4a61e6bdb3519c5287522924abb87f89699e62e3c0e9249ccfb3853f9d6c39e9ca27c57fc1f765e4c3e295575d75df8169e4aacc954fd5aa93afd7d11cb2f7aa6aaf73197daa0d1232dfef05cff51db2b236d2a951e18983c93b41229ff1e0ec8a7d51e34ce5fc5b52ae88988992d49de1198d126d5acac8fefa439", "5fd16e1abcfe6fb1b815f680ae1dd7d6cb833cfda5f75e1ec41e3dfa48aabc198b05b6cd2b8765d628ec91a380cdeac267f5bdf34f5c45d8", "56"],
-
-["10b1163b3d936d7af844fc2dfb1efeb5fb995f5ce579212f6846fe0cb3fd4ffb80052cc15164d5b3f93350c95f4f54f37f5266b0b1c242dc2e887cc9d0a36792191d36ac773e61bec6014bb317c4210db043057c54c76cafc49bc46a85c05bd9e4e17c24", "e88b8e34e2e4ef0e9d1956ff980154240c4107f51eb5eacb5812f1a05ac9d616cd2eadbb99d9bf8c584c95b80ad753a60ab948384188c25299d32796b4054172df8cda5d413b895d58edcbcda76c555da891a95a08ace8301325e72d6e64848704ef3ba142d2f48a87441fc6d6870352cf1053e2dde8036db05259ebeeae475e", "0247e758bda41cc1b50221d2f64a689ea8dabf663296ba0a6533f84e464b532f0c2f6d93182c1e6358eaa32816dae8982e366e7572697712", "56"],
-
-["c4b4ed564b8d509d63bba83602e8433d9b4cc9d49f24d72e445feae7859a7f976a4e78a68948c262cd5a354e7651bbbd6126b5a08a799a9dcf3ae161f7aa2236f28278d8627129845bcc895367821bc6ce4efaa2143077fec21d049a6af65172e5b7b493", "7dffc73782ffdbfe64f56655b6363a567bcf009743187c59978c120297d789cee23110c1b6eb7ee05947dea0b78d2b9f4c5b5892443d937ee17dcb2c1e3d70d792c27a279613ac63aac31ae2150be9c2cc1c56dd95b2f9f96ced4b3f265d67ef545b0d516aa0ecef2110210119d7fb29c3b701607ea0363000829b25f85ff67e", "f201e33cd8221cd4efc0fca3e5af2838f9a2abf0c62a7660782ca0c9750efeebcb3e0a0af9fd51a6f2d7daab503a3d874f4848f85b9f6aa6", "56"],
-
-["d0a9fba14f144d37250a3b38021286705373a87f0ae9b92c9d9d93fc51543504b8e765fb78f39a934fd1180954c95dfb8082bc7b6cbd48615c43f3e57d1cf76d6b0275a3f6165ccb26d18d547d3c3dac7b649a596808b1464d968f5a4456a64ff1e473c2", "43fa0f5278e7e15159e73a788d92a6339ddf8a65b3df07b6a1ab212f249b21046ab837b0d3746447f5999708e2801732ad83201a2ab4d179f6d0460ab76e0155a57c8ec54aec660185ab1579d5d3983fd421091902a97ed4c4c82edbad5bd508fb2e5ab965b8af271405faf3eff3f2021ba18b8bc225d56e147d21c88cc867c4", "1fefe5f8ab295f2249dbd467097d5f4bb19a6e82fae3f94b098c9689276bac58534e8eb0d4b2238d81e079fbd03cdaf08ed35ddc367d4485", "56"],
-
-["6eb9d2ce6f7a80287670c52b360c9509f022cb43f255cbbb5ecf66112c35fe2c72d9ea17e3321b0f968a00da0af7a85a668c276105d03ac7316f2eba2f30fd567d27bb75572c30c97f8cc7283efea726a6d81fe91b824739122be4d717bc8d10150a0c91", "ed106130abf2a2f6023549fa4a9b96cce9c739ad24b8c33c35915b529d9bb22abb8c39c59e0a918c186b423813bfb1ef28104caf6ee808f090140d8523a8afc43643a1e62a9465b1c41d075480370b922857a422a68f27561758befc208e618285bc025df79a08aa2ed4ff16380d8efc1124a9ce73b607b6efe4d77d3d56afab", "7e5bb39abc1c723cc0cc7db4c45867d235ee56d19234533838edf1e1e55c7c43211b2d96d5f40bb1c80e0900fedc4d9621106db1ee57eea8", "56"],
-
-["b54e1636dd82b1fa0faf26ee7bd2c828b1cc44b0641685d86db0d4d0125a447589074d6ab88fb76a3fa4b9dcb80763a83dfb394a2bfb212526a87f195e059e5710e652744fa6dbbd04190dcb34c1ae578145204ff41e3f28f9948d77abe98dbb52d46c7d", "93ac6811ae7ec7afd82b7ca0196fe026ba3ab24e9eb40f1ad67e9ede6f3b0ceb8e50b86bc0e7af8c8b3adae1490e7605367dc581c7f903ef3d8e9c607b679cfc149467e8a90baa6d72ec91561a093b99d0a56b3a9b7c806ded04a7fb7cf34fe64d01dd5011efa199b5c809c83567152cb1390b43c2446cda0e6826761ae50bf3", "4ed9c419ce128ca28427622a62b8887028142c334bf9ad64e1a2a1e63b99814bb9e7894d92e5e6db5baa8750d7016178435e27f7e3b84f6c", "56"],
-
-["30608b4bf71a69c879e650729b646c9603f0be05cf816b1786d15af04ebffd0260f16fd3babdc001134a8d8314f2d331c5eb048746275ba6bf786d9b99f802e4efa822303f57736766c6c2eacb09df1cda30ae21b4277be624db8e61d69eff1ac3cdccea", "363b32accfa593e454cc3ec83b9d775a0dd027b017ca2ff863c1fcb9e6215b5cfb2e8fea10eba2179f3bf88061472a31be1f116a87a420a3e7a5f7cd974836db52ac8f8189cc203b57a7ff92b79e169bf51d31c0627d55c24c2954ff2d009ef123fb5af8a010b55963567a86a769e4a09a10b03a101d7560799538d710113ba7", "359bd1e49bc1c69e3662ef329b245f659142f3ed348cc552c01bc10ec72497893574156cab5af2f4d216cb4409e62e03bb53deb53a4f22d7", "56"],
-
-["22943268c52744116c87652ed6476a919a73c03fbbd0a6d17c66991a753c100ae7636ad24d35254933df508f4b1d43f0be458d0bc0872e3367730715bdbb8e9b143bc61199366f872ce379766f28bfa61da5f24e9ef4d37ed82fa6ba47b3e3e750242e6c", "2d912035dca0d990741fc9ba4dd33040cd5cbc6639bafecaca0f1f4504a6cf1243357f32975fd00ed17d4b5fde9e524b54cc56d04b924b666ebb59e0525bd59406146fbbeb92ad1bc55898e313bfb5d69c3b36ab4be172b43c2693bf17ea8e3a9db5599a804058fd7837180d933d3f28cd31c3bfe0af5bfc5dda33a8fc59e44b", "b226c29ce8b20348c898769e3f370bc89d9f1a00156f114e40cddca52dcec7f1275591c02d9b3e1a49bc6bb056f950a71d4c0f344ccf44b7", "56"],
-
-["de48fc7bda19796ffdf455ce423c79b0f55db17e67ae03fae69b3e7fec03d58a97dfee7a5925b794f1ecf94a9ad0efbd829858807d16cb9ffb4f4be038dc1b48b290d3e1e05bb4bb216e3931f3a0ee6d6511852fcf0d27c9c033086f38d63ead17a3d339", "77914129b7af927556872a8eabe5343f668cf904217b0b6f386fc3cadba9eefe61f1c4b4dfdb1ab9fa49d8a7f931acc21fa1002037002a0d7096e3ad6b4ffbe393bb4b72ad5aea62af17a4a6d612a7fbf3a28013069b46f30dcc159a1eccbee3b4f7bdac47b36c381ff876d2690f8db38b996026b5d4c7eab81a41d81caaa1c5", "4c24ebefc034892b90e86904bf4670e94710595fd6368f643b5e011b7de022fdaf112a21c9fa382822c9195b1fef7e2788d77de0fe00d073", "56"],
-
-["7cd4636116d91f0db16a82de94032cc37ebce435956d2f789f15211e747d74ceb318d743b52e8897bf3925c27c90c05eaa4231ad1345b0dcbfd0a3896805b4842ed327d28c1b6af88b11ee7635e636914da42e553d59bddb50779dc8217b6b1654cdfa17", "8b6b7edfdfaa53e67816ad445bb053303680030744e46f3e7127859abf55f3a3e84eeb56451a231aee7c21f523cab500eafbc718fa0252d7d85bb1c240e31ac4c6ec68dcbd911d125847a7af0fb5e2ad44c6df6d27fce4a8d707b3a8ca960fb86e6d7dd910b748b6fe9ec7dae81ae2218c06d36c93792a99b55314b32416dc65", "7d5fd0ed7cc5102436c6cf22dc172ed00372b111a25731a7290bae7b87806f4ecaf213726f28cd6cee2c143abf89aea94cb44e77946a6f5b", "56"],
-
-["782bba866ad86d885874acccc5c049b122be4fe4fe13dade77b02caef4b1fef91685143feb50145f658d985e0bf88264efab9007e08730cce0d595ebd92d0a538b9786eec9f18126e59107a8692b0b6807f4fde5d3079faeb008142508083983c1a9391c", "7dd26a4d522342a5e9c081e18925c6f2ef6adb5141674240481b1052d94fff2d9476be8fd2d88b8fd8ef042651113aedfb500828a09fa3044836711dad371f43ef91ee7e89244d4f8427ad39eac791807e11e431aa129062b93d4cbb460db536f4eba1226051b06e543024243e8ff234e0751873480a32e303f948358e18eb8c", "e23703c386fd6f868ac392c75620d5bf3d0e3fa247c84564859367de317deaeb796177446c32b23072ee27939da4ce89a7da3a51baa6cede", "56"],
-
-["57c2eb677b5093b9e829ea4babb50bde55d0ad59fec34a618973802b2ad9b78e26b2045dda784df3ff90ae0f2cc51ce39cf54867320ac6f3ba2c6f0d72360480c96614ae66581f266c35fb79fd28774afd113fa5187eff9206d7cbe90dd8bf67c844e202", "2423dff48b312be864cb3490641f793d2b9fb68a7763b8e298c86f42245e4540eb01ae4d2d4500370b1886f23ca2cf9701704cad5bd21ba87b811daf7a854ea24a56565ced425b35e40e1acbebe03603e35dcf4a100e57218408a1d8dbcc3b99296cfea931efe3ebd8f719a6d9a15487b9ad67eafedf15559ca42445b0f9b42e", "33c511e9bc2307c62758df61125a980ee64cefebd90931cb91c13742d4714c06de4003faf3c41c06aefc638ad47b21906e6b104816b72de6269e045a1f4429d4", "64"],
-
-["7c98912c74421362e112a2f98fed9babe0057fc778b4453239aaf5ac724b725553539770a5bc8666b8e13d0e9ce36b2b934c8137c7f20b5f391f41cefaeed92e9df8206cec3049bcda0c05deb9e6549fada19aa2618ff560f892ce6e4782aeff41cf53a9", "74e8936d83bf3f16b8d03fb73384ed8f46bd32343f5df8358107e2fdda293afa103a2bffbd4030e75d96cc7ca6ec7c97188fea88d4eb63b7b14e8b8c8dee4f8de12e1cc6981d4e6e223fecc7c491924632c7aef45fd8ef1494bcfb06c074616b0f4cce8abd5d83f32d550661357b18e5bcede841882c869251db9a331ac456dd", "4cc28818486bb9b1b52e333dde71f73acc227488453fd907c6b51d349d67af1df29a9f225532ce04f50395fed565e98d78978626df93462d3f012f7373347298", "64"],
-
-["662ca8f53b97edd9bbd43b1f9e4ea49f2ac14417faee257aff93608bc49a85abf6913def235a2e76c2241ffa749a5da489595d25c6a8a2026563e12f5e3964e0e518ac9c34e45a938a6f503174a613f34b08737afe5d6fde11a45344e64d23b33ca83c23", "0c057a2b56cb7e651c6339e4c91a1a72d51af2a646de9dfd77e9e42c18b8a2b576f526b9fcedd90dfa442090a6e784bb614311793bb5fb39b8418842d586294746f1ea3c02320d6801ecf2ba44b13b60172d2d9693a158bc66947aacd7c5a14a0463905d6e80649db8c4770cac5e858a7f400da4568cfaae08498311265b50e5", "c0d6e13c5746369d49bef107cfc9a465627691320b8203233359e6a49659025ac96a6db6c4d460224f6aa1cb7a6b8df311e066f6109bd466cd9aee3058dbc5f0", "64"],
-
-["0cc5bca2025bd6030fe0818e0a61ecc730b2e5526da942c0d7897fa97bc1a8fb5dd77991ba9fc50890b014ce6118907b334f2265db6ad86e7b918a214ab3bdfe9378c711017834ca19aa6908081f87779ff0921c9c75d32e2bb77a28ac28881cb792ec4a", "c532714f570982993d4b22c7d07a1e79ff5a75c94eee75dc1fa222b630cad753664b30f3c99826b5cfe17c67dd875b9d0bd2390028e6ffe9fef36a2fd6adb13d3ffc69670cf4a67e9c0764a15e7925579315dbdb561f07b7da892394f4693e51d9abe65228034a1b2b26a01d5a3ac5cf208b2301e27fd86e3ecc159090e8c3b8", "c34bf0931b2dd2e41956dc86996e1427379d0c89739b1c33fa3be5b0770673a20c5335c6d22c766826009938fe1f4d478b882b59a3b19fdf25bf18f043fbb3f7", "64"],
-
-["7d407fda74d3a127b2ed14c727d0e81a04f6789d20eeef629b670abdc18b1f41318e5eea3e86579c957dbccc20c4687d2b8ba16fc6af9a936ad33cc1dfb226ad5cb3f318f1bfbb43224fdca9d5c9faed6e0c44123849f9ea07162bd11bbdc49b48dac6ca", "eabd8db90e6d67a41f096e4369f77cd6ba23da4fcfa459120d9c9ef9725fbe9bcad80bce26292d6a8a927450e6946cab4756b2764f47073fe305a32a237ecb389f55a6c9c7874d60a44e21a7c64561b37ecdfc884db0a3e09b052328ac54f2ccd1fa07b4dbceef0fd5041e4ff3528374c5525f8eb028567d9f64c7fcf62a59de", "771bf59b658cb17576761d078cf6b1474db746a2201d30ddf289fa708366a27d6a53959bb7eb2b963622b326edaae3dce086dc364c93c874e50089b69c5cf52d", "64"],
-
-["c367aeb5c02b727883ffe2a4ceebf911b01454beb328fb5d57fc7f11bf744576aba421e2a63426ea8109bd28ff21f53cd2bf1a11c6c989623d6ec27cdb0bbf458250857d819ff84408b4f3dce08b98b1587ee59683af8852a0a5f55bda3ab5e132b4010e", "1a7331c8ff1b748e3cee96952190fdbbe4ee2f79e5753bbb368255ee5b19c05a4ed9f1b2c72ff1e9b9cb0348205087befa501e7793770faf0606e9c901836a9bc8afa00d7db94ee29eb191d5cf3fc3e8da95a0f9f4a2a7964289c3129b512bd890de8700a9205420f28a8965b6c67be28ba7fe278e5fcd16f0f22cf2b2eacbb9", "4459066109cb11e6870fa9c6bfd251adfa304c0a2928ca915049704972edc560cc7c0bc38249e9101aae2f7d4da62eaff83fb07134efc277de72b9e4ab360425", "64"],
-
-["52d3e26c59df9bf3f5c01e311fd6611b895dbf6e8e918ff16916fbfaa6981033d7af119e880511d775bac09afa078684ca22ce1ee462a517c3a483d1d5ed68202f512b4e7f130f62420d98a137529d5613139dcf76bf57a81e6e944c5b8048b8c281d982", "2485736977ef55a55abeba3b8e857ee2fa5beb144324e46f9e12625be26b25ede28ca30bf92e45d1e6e8d234daf52be5d0383a781d7d25c64802c7901b366065fac08bc574c3718618603d778a7dd044d6c5b59903f0578aec4571334b5dc79b172914df1037438c9830e14cc4a6d3c5b30c44be1e06e28331e44a8b9968c059", "b1c34ea9d837b4e0b0771792384fb5f5b9bb5af7226d461b5ca81ce8079c6472c5c44624a640f01960c8a94f6aaca5324c0da2cbfdbcb077cbdca7f6c6a38e75", "64"],
-
-["ce1e3b693ce203166bf045472fdd1457c8f6591a0ad41912bb30f6e63df8f90f6ca18ed5cac0d07adad407b5c9666f6253553c77e56bda3aff3379b1dd0fc95a5685021d04da287fa5e28d18c11697478ee7c3241052eab684b5c467ffe1aab45370a029", "e2542c06864dd3a0499493e144a97fe04006b68c83a5dd4ceac3cde238e48895ae17728fdc7bbe84b6022694ea75df7371b8ccc76450f2d112222c504f7d1fa20f5b712d33e436fda234abae9c5e278d4bb14efa9b3a88114c89b28946b813db2caa91a73391245435b38cf8016d3f77f678a6eed06b8852c181c754c49d4a88", "d5e6ef77772459874a73fc4f7665cd3ade20468bea1ecdac41142ff32350b8cb15828612050046299f08ccc486acef0d0c04e0f8feb29045ef7e3a3db093d512", "64"],
-
-["3699d9cd078a20ec0c96eb01aa60df6cd5dcb554260eea8e2e15b7c00b6943c638611854aaf8d3dd18d020b49a77e67275eabf973557ab74fd2705481c3cb6a9e077a825af7e7e2a53bc822396a9dee40f4b10483bfd9818d06ff32f4deecddcd6e57388", "9186eaa3b8e785dd2a747297bdbdd4f5532a47b7008c21686ff7f8d881d464cd383205f6d45dc8203bb267ac9eb12f415a5406be1c9fac73497941909dba08dd12856aac03d83e0d916147404694fe70f8fa929ef0cc2edb4cc07abaa2236405e62820af8e806d0af32a1b3afb8dcaeaf5c4f43dc4392e074075aa3ed93601ab", "d8bf5ff4392938534a7962c64985f163ce7c95e6c05f93cf704106f9bda7c9ae963f5ea87f73626f67ed3146e8611ca62ef2eeb4f9a13847dc6e7ffbe3d851a4", "64"],
-
-["b0c0a896096bc42bd0c5ea646779a4f1ce541f9cbc04df29ef20b180c069e10efa50ae68ecb8fb31bdfc473f0034dff988b452037ed6261eb0fac9492ccaca2c0ec349b796f1ad077ef995898a5d106160fc100d9ad81c451a1c46269d5e5d90932163cd", "debcf190ea6ab2358636af5cfe4b3a9bdc1bce160bf350aa3cd3956b897e255158cd3e2e83481ce3b6f778d418764f992d48e4f7fb6d080e6b3799d3f35949c17241a0cc5ba84597166779e6a38ce45681ad944cce7c432baf9cd8caf2b33125f2c12052bbb0b3b76f2cb97be9b4813a9ff1e5fdcd478769d0ab5b36cfb466e3", "2180018c7e9c3287c3d2928fdf36dda80be4fa21d3a879c0f617eb0e43c58836b0cd714a8081652f8dca9a01925a4f3ec5dbe07b5160be7b1ac58ea623952293", "64"],
-
-["1ce7e20abbdcd1154d4b536714ff534a01b8e88c78da34d653638c39291fd80ad01f3df02067fa3bfae7907789ad2641c8582b5a45d03dfa24344a676614f5c56ce13b30b6a15608f1e7e18c31033eab7b76351686a9dd9ac2dec0ad9a663a47f61422f3", "46ae8403ebb4c8723652b9848fdaaa537a50e3191bd94442f9702bc602db98b5cfdd8f142aaebee7cda8608f6d436156f743c3491a30404605073155722fae3be3aef74d2b9d128331d9b6cdf1fc68aac38ce4f6e072be0322ad49ec0b47b82609888358f86b0d6de94e83e722ed077666910ec9768506a4d7ca3d33d60bfe9e", "d032bfb5a538197385eb70673cf8f93e31fdf9c22c0e90008a454ba4d69bdc2232475a41723c8a5e3b29c6de929a7a1e87b64beecda29683d0d925f00ce23b35", "64"],
-
-["d7148e81b94a9a902b5980e751a5a59ef4a2397ad4df251240443e30e598bf7ae445f65227603dfaf4e42cfcc23e0dc94c0f90a0e52dc1b10beb36833e9a8d93134f163e84e7cce08a498a3eeeeb7b215b98d344b970bc70b63093e6a5b355fd8cb9540c", "251cff72773e93021e816407edbdf5c1b0dd9a0d633f41e7a25e932d61ae3ca5ddc78642d2c62da3eff06fdd8799627a89458ac2b20cab390143dc686c58dde0d1feaa7d2f8a50e8169d005f5c0462b912dc2ba4b6faf232aa8a4094e5f5e625e90993aaf554a5d77bd04016d4c69d8533eca53dd8d0bfda867ae638364dfe7a", "9f783389d7905291a0446004816233918acfbbc1d4443f4dfdff55f3c151a7a1ad20d0a18d0aafc4bde38e3bbd9c7f672f8b1d14649e8f41c47a0fe1ca051d8e", "64"],
-
-["c821be1cce09579ea899899d24f8329994c2c839cf0084e27857c688837fb5c4f4f72527eaf7bfcfdda75b37248eb153ba4d31dd418d2fea473643c0c9e1f0ebf591838e349d3ef868f1b67772777a71f8cff5b0654696fe31062ef2628a99095355a0f8", "98b0c5e030490c0de1cb08d49ab64560693160acedab1a450ec2ab52eb6459d114344823fa2f94cba48f9d73a3efa22f47b19206139d1eaf6fca13989dc2e72deee1915636fe9e417d4e8263f7842cb9373ddd549f9c39141b319fb40f20b6068d7f4880ccff54f8d5cf5eac80bd0a859f9fe99d79f193fe7abff6ad28c6ede7", "8af5f3e56ba1a151975f4fc6a784aa050572f7b163a93f24f016395ab4688f39172bf20f1bc246d73b971d022b3d49d1b31f40b0a121b9c3a66e09096d4815f4", "64"],
-
-["8ad2ff9cc9e5979ab79e2122f2b6c0d75f0f19da6bedb79a9762aeee330a7f6169f93ccc7ef2ed2b55d931a9356b29fdcb2d91f973a23060b3c173f908a655e1e6888125faebbb90b2177cc2ffc8dc825a27b55605f906509317952aaa1ed996086716cf", "18aa61677eb2e25b7f5738c929c4f4ccb69749a1038b1a6644545722f7cb8a06164badf3915b3f3cfb8d97ac83a677cb27fee45d242a352cb1d96b07ecfcc00b152a8321fa4222c8b25289158eb7aaf74d865dc08f2b6be18d50e5f50601bb027d0d89fba1afb4890d6db60a3141b6db90f75cd22dd6e30f3f8270f52c21a273", "3aed2fb463393706efbbb98fd426075af837a8eab622b95da9aeb0393188665336f0d46bd20773fbea36aa289bd702d6ed4d6080449b680c92b0355fcbf13ead", "64"],
-
-["bceaf34d50c1f202539233630b16dd048ea23f093c9f713b8d3a385b0d5c2bb6a4dd14f91bf59947dcf31c89f931df0570476c33ae7f34cb51897623327062b8a3cd7f0af53b4aae3e0a209e58385dd32d9cc6163265241332c332af4de4b99b4022fa29", "f3bfa5c1f1055281a35b48f86fa3ae454c03eac56ff064cf268b8da20431219b3e4da9ac55714309f5a6a5241e0060dc817562f12deebfbc6a9fb11de594ddb40e8dd754bf0ce9b41eac1068c4b448101fec09d014b01200e94265246365931e2b2739a276fecbebe51690acaaeaee4aed12e8486e5be5036b1db39fc4c9cb41", "bb714844b6be93ccec0acb8780996b2a4778c42a8a76a49eff87ced3a258815d76685dce4c8ae37c244229f17e0503de089a043368cd300d13f842f28c111fbb", "64"],
-
-["fde15ba900e6648c9d8471f00d9b32d71e5383b9370c931c96944565dd9dd6eecd6ec15851d8df23be6cd37b59eba5551afeadbbaf27b364c4f854888283a7f255112157f317b6e69a65412098a126ea11f436e1fd03d3ed702154191ec2aba21e33444dee8931feb8d88587d3a5fdbe8d9de6d228873adf22770dbfba", "cf65959c760826becc0d3c4cf75740d8c8bebb9835b26b210a219773db9b9f363db5d74336ab9566f1498962b60b4d361a833d9f73fb89de8f5c89bba64e50ca8ca183ed2bec6c1a31734a5f064eeb2301c87daedb06a2ec64e9c26d7434a5b125f241a33f12d063f4552648ce6a4226e55723cc551addd1ef90eb912f97c772", "b1a9135311d32f5bd6b73abf54088b65fc1d23e4bed1d33879a707c5c93e8fba", "32"],
-
-["18519ace346e2e9987a25038d7642b7fbebd3a49b904005f7bedfa9c87c2d24ffdefd5e1b4c4a7b88a77355af868ecd6eaa92960cdbef03be9a1e0dcf10a664cd8ec32cee743e7c20d0d17c976966e2a5ef92d7428e4da65aa9b360bcc407029515198dcf472567eb76ddccb78771942c5b530cef1fe28a667e3e5723f", "7210549b3edd9a0a700b061f65ce10437477d9e5dc95bbc1fa612562c059a6e5622a470152d31e446f08209f7ad43725c98395103fbf47057bf90d99500b6913751bf6737ec2fbb40b6d404f4004200075cae0cc2e853f434dde4e03860a827d14aa08fcaaf058e3ad040d35a0a6f45be0146322912ecc04d8d891a84aa01aac", "2b2649c399f3716bf33f7937579df12deaa8ed00f656f8240468a3b022d411fb", "32"],
-
-["739e4ce0bc133f599fbf7b220a650933ead7c602a6670a9beb9f88f3af3275797031edefbd829278f88f76f25a3b3b85371a787c84e15f54961b9d132c1e82dcb03e62bfba403bd5d612978d87b9b4418c8ae30d619d2ee0097f3dd30b277bbabb2b74f9ba34819ac0b0a89c99c6be8d6e8fce5b4683bf67cc3130db53", "8deeaf2e1c03647268168d1276608fb0ef3e5e4088da94c6c108c061d26e0089e2e8d2957b2b27803ba8b9ecccb39f6716e41eeeb2bdc3afa28eae13409631fc756b9a4c2b9c4ca6059415784dc1bb392ef6d8ef230ee8f5afd3a1fb4f1e834ead3faf5a169efc6d2ea348bde2d40cad9f3d334cc1152d1e4f9e3f20e54d1d06", "077139064096116bc4f0bca8f2237196d1d9a244e6ead5cd6660c9ad77a28d82", "32"],
-
-["a5ae601409a8433b6e34b1f95266be2cf4163382139178d4f98db7b6b8633e4cb478255f61fc31af4ed8f98b63e147a06752c10fc479c9c28fb0a1ffc38fd33dcd60d0374ed3d7401cf418d68a591a79e9a05abe4e19359f96bf0715a86d95e9fa4ea8c65796fb94df6f57014f36fd6082e844b738d7831317a2311fef", "d2c5ea2b497f97d48372243e4c4076503e3383b99d8d56e07f19591e3006d32cd4f5b0d74e5181fb61dfc3ae159664bc5db08354f6017800928814da364429fee1484d3b6e12dc0ebb193cd00ba5652ea9e3a0dacf398e80c5d76dfc4d8f97ca3fe64bbd716bb0f4d947170bf616818e6bf561b3eedca348602fecea3d8f2478", "2d16656eceaa47a2cdc4c4100a0c5e343299cdda14be213d1253fe0afa17cbf9", "32"],
-
-["a39a510d232f92835616987d7f9a50c8841874c59411511b0c7ccda99c61a8bfa6a3028de61e1edb58f165fb561daefc0150bc21e3fb0891d3aac1501895126ed172a37f948e624b551c83c689cdc5428ca1f8e340fcbc53f8fc8bb8940afae3edaf3bca73a9e739143ffac460d12000ebb32ff3f33cf4c8431932c757", "76aa892b7824da3d74dee1c05d0dd6a0be232e0e58e8612e0cccef10fc7ba5e2eeb31c64f152849c42b49fca703b109ebf2fd6a9cac914126d8a138ae2d189812aad1c9aaff0225d2785ab2dbb03c1796981ee36a7adff8c491808cfa4d4a8b2f4418eb7fb243ceaebf90bcf868b709984c17d9c35bbab05671dd6c98aab89db", "0cf6ccf2f7810a4a020d0ea97e9ee72cd36f41e13a3ee3ca41030ef0f45ba03d", "32"],
-
-["b65b916e8c8746e59d281f6448f8800b34cc7cf32b1747cd2cb0eec5bbd545ad7c6f21570097c842df5aa67907b942b953f470d26009c74f9c7755599f4792d9bf4d93dddeac803d8798487e3a6093f603abf93151017194068e0c40cb23e33aa67e4dc8cecb561ebe6b6d3988f35d0c6c787fdaa90a2de3937e7942e6", "037d44818301e6466ae7a7f0ed1b0ef184cce011a61ffbc0d32482aad8093a8d4f8d344765b8ad34e0c932b660710815e43eca8a915a5496af75e098f5006f9f069c6b7d3bd28a1da304f51648a3aa7fb4a31e06b42c273c5c931a334b88304b31912f498eb746a9bc7e38362f1bbdd7fce225ac98fe3e59e26478a2640a4739", "a78c0ebf5aae01aa7a33cb0a5ebd33dea4d010ec10b31849bf8478fc33ab5b15", "32"],
-
-["9cb615e2eedd8df96d56803e7fbfaccce3a9e66f45e454090ffedc348306dc2807951ce0bf100178612703da45a16099bc5c121da38fff01385371b92c62c578f91e675075d5fe9ca743e39bda58d85df13cf7e3fa91e88206f8578837d6a5221a58de7d37b4ec2ce7b20aca666c7be029517b087270765321f478e9cd", "e3d0c3abdef069e6e4fa35015797bd8a9d64bc9b75f20b028b12cca04a4fe80ff1bbbd88e9ef1003564d499fec88df4503671188eec5d7d089dd18b812c41db43a3746f77b97fb94ab32e6e3d2c45f26393e286cc3c55bb680fea0598089a3a3e5cbc6c13cf113e00e317493b153267564716b0f9cfb7ffc98fdce4a3ba56424", "62cc1fc3b61fef9c4e9089b86229bd43f651d7346baad2fb145cde64f3f56d92", "32"],
-
-["7b4b528be9e0353c0156dc685bf0517ef4cc0ab18cb96a614c4889d6ac26383494a840abc1a8ebef6b90c6e825b4a4aa04e5e6a70342fa23a65222e9de50773d2dc62d110a5e187c87f46f6731efd18a38d28597d00e06b4d61bbf2fb7c6136d8ecda0248ca9c5ca9dab614e484ade05d7bc6fe7b9c395fb24cae810ff", "97f669cb219bec2d7a57a47979ef5a254d2cbafc26c9a91e3290d948cbbf9a55efb0f305f0b9f1fe6c523f108075f7bd394bb8f94b04f92e06069c83854bc65e2904ef27468335501b57f69208b99d79fb27bc222c4e5c440a2d916d7478518e2d3a85ba8abf612b6eb1f1867881f55f7b08ef002f6f7a66264cdd8d010de0ee", "f8839b532c1ad2f0dd2671dd607253ac20c5bd6a515022451667cc636e20f837", "32"],
-
-["3860b019c43dd5b6028348d2974e828b74e09248b5f21daac948a3bfd4880379514c425ffc768883efc46bb40ba470f49949cf2d31fe771fdbba529d75f5caf955bb8cbd2fff8c9b383149a78b1352c4ccc8095c0c2da755ed6d804007d089d38ad41799247fe9e825f36914e1432fe25585c73f0e29b4324789b41052", "76baf79762efacb78442c86c8cf48fb50ba43db7282a52ba94c42e75c070dd8b4d983455c4f41de287ad2f3c07be82ed38a0c1c2422680cf7aa6d0b39059111e09040890f0d83263c997570bc7f9619d3f4e6f8e5b6426bd13ab47b45997872b94469bce50639241344a6760346dd7cb08f670a2dcaeeb8d491dbb0084f3e6bd", "694c01f7f952a909842fff93a565389aa687f3d91df092ed0eecfcc18f07b88a", "32"],
-
-["787ce775ce65a604fb8f55af0e5f1428be380812f6f1fd1391864f2b620d0a4477ec500a9e4afbfc29c384c3ef6024c393c638f109adeae455940e2e96b34aec44327a9cb698522fe786455f79a5a643fadd0e2167c8fd83ee66c48df812a28d67b42dd41c39f3c5bd40622bb516e5d07ce5504b18287e19e46238ef90", "2b7a171a33714b709557af416f295d0cce0cf4c56634ead88704246c4c76c4e416be43ac382abf32f44d9632a75c333740d8285ff66d7d5e3b1b48c5eb937e85cae409ae2d561b7df796c196c714bb8e70aa8bacaa7eccf10729c55528193e54303392a979bd065a867c59f439199d1846ca4536e82e7e99d378c3a469cfab5b", "9387b868d66203470b5762cc10aa92b99168077a17497c0ecd244ec71ce72185", "32"],
-
-["1fc4c858845af638e18bd3952eb74883ba4d216fd5a36317cd25e0da1db20832e043027f8ccefc06dc6abf390eb386394f7010997473f465eac13562f0f3169803dc2f0e9a6cc1d514d44747efeb4d913ad1856f4dd21837b78d3a19785501a571d7778d18fdeead701393483929469563b8a97ac98877971cd018861d", "bc1463e3ccc4efa58f9d3f657e84b3d51357bd6981d43a5da2b602f93120bd14393ff9d7cd2c7ac3fa0fd1f5fa8a31a9aa557dfe3f25a5f934d333cb86ba21572461eeefa15af15e2d69ec2cc3e9e41da46c30fb03a2d195027c0108dd900e3d65c069b3b116fa7f10f3b79141697538e7e5fee93711633cf4f894379d6c2dcf", "2f77cf7f96eae7ebc838c9431522ebcac360b4406cdb3ef25d9cca93350bb671", "32"],
-
-["5f1149eb978bbb35182ca938e77ad0cf414b00972435c4fe1147459c11f66cc7f65a4761e39baef50ea62602c5240597afacd72055370180e0d5a460249fc4c6deedaae93ac9864499a359e990461218340af710954b4725881b1e13aa349892a130392aa5fd8d49408b0b9351c73f7957cd290dec59e014907f7a5da8", "5d9d92c853dbbdd5c20fd8a14bed15a25eb01604460b0d42666322f4c23b15e8c1f0247331a62f098b821a0c781a73e29529544a73888bf0e359f93f708538762c6a81feb0cdccfb5e163821e9bcaec818fec143c6d4d96e2e259187653a5d3f28737010d838ddd0c333fc87a5bc0f7e6f95259846d83fe5cf2db275aa40cc6a", "d4b5684f15c6431b25c4f5a8689147ed904e3b2b5009f080e3bd3069aabade42", "32"],
-
-["84a72b46b31ff2a3bfd295ca63dcce53634794327a2ca7f1931b693d3cbdefa457b8589d12d35dc91a5b2a780cc56c6f2d94ace95a7edbdb42ca2e7cd2fc2c05f1b405b56ed4251936e1098acbac51f7f13117e5abbf80b365599023735d4316ba63d997ad68711b7c2cd90787d8a57319e47e2e5338bd026691393906", "efd2510e18699c437982d63ecc061b3e9c5c0408f27e6d9531537c083f39dfe3e5e05479795f6fa7ba8198bffb6053c58436c4de356f72056c034b002902e892707113f3ef54867de10cf6a832e35038e4e643b5b1f3fe1cf2123bd4bf087ce3582a9462c3706a2eb7cafae1b9b79c0185138977af309b428a29546c4973223d", "aca1080fdf13b259f71db18ab0a8add74059af9d50f7310b1ef0bb0cff76fb72", "32"],
-
-["63ef2853fe6dfe1fede12081b2134522dfa0a52c859f0b0bf0852c75914637a73cbdadf2944e7377c3ca262f6e92ae2ee7001628a5029a34f20802d0e4212f258e60f48d778527534cd9f7bad15a7f401d189c2307dffc25dc16788c53e7a46ec2a1a42b915a413befd83c2ac5b2e1e0e8b2e026937e5966147736b2c5", "cfc04f6fd5c15870c1770633517926a0e08805233914df54a9029ad358adb476d82b7ba7ffd17c07f2f93d5883022f99bd99de72bc376bd3c3eb3cd9885af2f1d2049b3f46c929417eab24af5cd59ba69edd6006b2467519cb49c17534d40723e5d4190054b049648d15210285f9540fabdaf3a7c147e46ceaa291700424dd55", "fd71e14e6d8d13dc91807d290f3cfca47b8c7f8a23aa18238200f5dad7dbdb3f", "32"],
-
-["6bea67b37a53b090f6de495442793d6b641f67d5abf234db958baafaef8ea9243e8171285ede656e69660fe7dbb96344f32448665cba54160ca6d7f76dbf1cb8ba5deb8eac6b52e257bea6474ea5c4ae0ec29cdd9a18f11d62645a3369b18d92a727dccfad240055878e165ae667e7127fa16c22ae3492575f6f3a982e", "11c81f57e7c3fc99d60c16a6d9c8d1e9fae927fdb8ff3520ab51f9e56b2d5a29ad7c6847af99e690d76406f5979273079347942206e610e461288de4366bbea1554d9cb9357af35c95c221ff626f1a55dcd5b07bf52a1273f99892d37491abbe516342143f0c2ce6594ff12d0507cfbb1cbc02714c7443b3bbcb8f19ed462967", "35c4eb549bbd8afc1b033ad66e4d02315a62fca1741a26e85e2533c5f5e816b8", "32"],
-
-["04e866da69ea0939b02a4e9e29052fe6cfd7a5f87d65794a5e7856a7a6cb242f7f27919f46cdf0d2f8144788e753a367b201af3f731b85923ac6c454bb36e3ef43cec58af1898d8b2298b35a2d4d58685137d671eb8f9cfeecd2392d8bb0b6b437252924d0e6876b16feba9d62b9f3f494c142154c8764945de4dcbb7e", "2f77d8331b2b92c856c811889bab8edf75c6875c024da90bf6b2f3ffe2d4192eb774268286e8662c8913833c6794ee6eb43e8047b7c8626171c62a04dad846f56e229e93e8fc751f4eea905c2dce9b58265cc889a9cfb91b01daa08991e2a56b5d6a888fcccf874aac35821076c15d43d309a64960c877e1aed79eb78e58fc36", "bf29051be936e6a324f149da168236a5af7584ad0b8ab1e7c27ce03c02768885e27d065f26321667", "40"],
-
-["e5cf7ede640ece05e6e08e6435fa6e752adebbe515ade1005e3c2e6b6d69d811c8b0425f7bf97bb4bdb40713d028e31c2908c33ad1489e1d0b2e6c6b37ac2fb2f6ed30a28f2e8b7992cfedbebbaa9d3218a3b9046e80c344dafc5c9ab4164e38b8afd00d6854063bac59c8ccbc27a4a03fd626aab5ff565d12cb8360ab", "0c36ca43e7c113ed9fb71670b3ea73bfd6928c839f36db1a82d08ae0ff2c3dae199133a10aa38d1d3588ed115c4a437c137ce4307421ddd615c9863237fd5aa840dd05ff6c08bf66bfbcd9b43e3f95f45e7d3b21bdf2692e10caab495c474b616a646be675b850d0259c01e2c1901130a0dbb9dfe0722a2c5b1b20afd7d2bbe1", "719dd79984d1a74bcea46ccbba7ae09fe246a47709d993d31555a20d57dbd5b1be9f8fe55473ddbf", "40"],
-
-["4b07b5387cfc8303b4bd4012fec0d8aa55a0c76aac1539de32247050d39367e102a0b5d9b6f3b33cb2d0e19e0a918637e354973a7af1da63682f4bcf2437bf11c948c78219add7d8c2eca8fbe141da14c54adee6f504136174f62f7443ace1242867b5dbae1337026d795bd18bb61c6683ec641525372c00dfe3e4e73a", "27d367524b267653030bda2babc4dfca0260685822e08377b69940c867d9c4b097cd995c52a2480281eb2a6a61437bae3433b03e37560a26d3ba6c94178a6a9466eaa21f6d234f5458001b0546783575fa8ca460d373db3cfef58dbfea15c2a1fcf22ba385efb2d0f112560341aa36a9bb35ebc8df801b419e104b52c7c1f55e", "29f20b053e817ced2a92c32da8cf9b281c4d3b209e086e1512513016321e7f8d60c6bb42cb86fe15", "40"],
-
-["180df5d78fc1cbbafb80c955ac28df292ee51b5c1eb21c603d1ccad0c349449af63a1a63fa4dbd27eb063320290e0feaf37ca1e438546ad9a94dd2e013d81e80ec5d9c182cc8207e5e44b4e7f6468c07e5ce38796bea1d602c7b264f873bb76623ffe3beca3ade087bc3b570bad83cc82b6248e25f7a1f3dee2d2de4c6", "1928e560892d145758e7b25af656d00819a5f596b255b9141f988ff2a2fbe3c21248653fd0aba48ffd13dec5eadb16aad0aa68bdc2989a78df92f3d5ccff501162a374b0a3c4a0c1f38c636385afb488134e3ef9d21afb9ba5bc04f09075a8e07001bbbaeac23076a21aeb3dad19d02b48582053ca240503d7df6b4ad7a412b8", "2ec31bd36f54bcf875421e668e9f149b92190bdfaf75308a3d345f08043f087de8229e9f0abe25d8", "40"],
-
-["caa3f071e610a88a12645f8ae6a98961a3ef8640c491d3fed5b982bb8826e7c3062d95b483fe89e41529a4fdc6615feae87fbaf7a8ca7533e67583194469bc707d45d270f9a386269e9e1b1ae8910b2e3c20fac9797de835d1e4aa46d7689b3dd8f28feb6f87081a54c9ad2875c1f7f548db2e005f3294cc3d0ee08968", "eca53fd1eb5c3ea44227ad2d69af5a8e484d11f89cd81b1e9dbb05eade4aa36f745653205afe863f14c97d9893f2516e590336b6dda5008331b7e7c2e29d872720db2b671ee24aa8bc2952356480a57731a2e121570965aabf061100f1104c7b375d4c0fffaf92c78271b36ea3025e8f1a519ba1d1aa517b8ecf0b0ed34bfb52", "14fb39fd8aeafa7c3f1367bc918e2595ca5c9a7e663334f38b774978cd3a2fef78cbc22480ff791e", "40"],
-
-["ada011918a63fc7727e2b9c8d1499b24fd4664cf2e5f4fa61988545807f5a7d27c2d82fb166024a2b7fa1ab4e778bdb3737afc635a1a5bc7ea7d3c4b27a75d436c94a394558416b1db962ccac1984a40301e885b9ed1b53ff40a9b1cefa083a6c6fefcc50e78d98ddbdf797029524bb27a58901ec02c63f87c58f73228", "3419a1928ce7edc8e3400c30998f09bdac6f63ff351eb23d362e8dc5927eac805d694ac9563dcd7fb2efa9591c0d827af9f39146f0424873aa8e3963d65734b1713baf0a44696b3eae595596a3bbab69f5ad5fead230c0448fdd57e9a3d40f26f7f69afc5d540f2b5de6d88f3881cbaf27039794e4d162d76b83b0fe02dd8709", "a4e125d37da73c03071878f8ba0fe6f852cd23208efca650d560baec8b718425ebc80270b5c9dda6", "40"],
-
-["9cc58f0fe886a026073a8f75ffe12d1fb9cd5816abb1c2d6b4fbf4534763922fccc4e031432b85f32b2e8fdaa280b84bcdc2e71c7a9bfa49040ac3e977fb5060f51970d559e472e71903309b71db4d99be60ba99282b7f1883ceb84f7764dd65df6c47e470d7f06a9b5f7ba1b41b74705350d873fb8bd8a9f9ecda6d8f", "f87e5d99ce24330020d94c49c5aec8296b22783724958bfb21ff6a48bce1e0ccbc7c7ee6d5423eb76f8a667ca987af1b9c7ba7fbe42854195d871a592abdde1afc4b6c0c908516467a459e9322e0570d0c5986ee5cd50ea2994f4d21b18d417b3d53efb407d605d3c973be3638a106284125dfb6938503be5dbeca4859394f19", "2f41f4b3331273cecae809af52bee4afb56d89d9db9989cd6933b97aca92c092442c6aa426418421", "40"],
-
-["dce6add843eb2bf17fe66cdfe175dd68ef95e179accd2b021aa96a07fc6a2cf5e0fcfc325d28cef79a0b9e620fc753c79078e948876acfa581898e55ec18acd51ea6d0409a3e26208fdf0583a22bd052ed631adf48930dbdc70a0cd4840d97b49ecd6f2a8196fb116848eb6b210b1bb1103fba6777b2abf2f5162bae4c", "2c99f9d18ef7903dfbde905e761533d42ddf4496517e8c9124f68e925413746587ad483f372b6759c04633deb71009077e347384157f9e1f339f0e9c962b90e397e47061013bedab2313ea6424c7e662976f2e0c4f5f510675f1d511d785282c83c5d9ff952e087a4dcc1cba6544b42ca118dff4a7278f9011734bb8c1272589", "057c5754ac6b3377b5eff6877cc4cb2bca774fba71e7029d148939ee822e1f0149d8389b54c969d6", "40"],
-
-["d736694dd886bdc5588a6201636fe137e2c8932f1230b4033530b9238863e39b74d7159908b7e329a727eb44fe706809395d044a77e5ee4a7b092dc5a40034311f92b6445bc2bbb6bdaff44896bc4b0552efbfc8f2976ffebc32f4ad308df4b63f2df457b8ee6ef303ea2416119cb3b493466bb16a8f5ea65f71b7e315", "fbbdd756a8b2408130425e0262caa7bf9c0384d49d84bc36948930967cf2ca89ac8fb0e5aa9abd6e418531b2d3f7a731f01148c126f53f311b896c2dda785d616b7a7ae2a6d67fc9482530214758480363fcb2fffebdfbbe2d2908e60fb7043e5e089d13436c70da6fa8c11667f7707c1f4e2855cbe4da25f2f26a8ac4b7a866", "36f27970deb7fa57271d345a13f71708a9ef95dd9e149f9ed35ad7d4b95d3c6bd3c3b284b33bb440", "40"],
-
-["05af3c25279cbe03a617aa6e16f3d2046edc82ec0c48ac66f9ab42a66feae4e29813bbaa994ba578cf08928858802ee9d661c0d56fc2513e195912a914eff83fb712a921700a9bfd070e7adf22b7cb490eb4d085bcc0ab3a0ad1c53e449271abeb14cd35b5c0e9bad4912c1b7b80f34b9f3f7aa5fb290083567a260c08", "64d4ed1727833969d2d586f9f7288746661a35ee96575c2146af5a54636c233a2de289f09647bb78eac50b68b5cef4e3e2b05bc92069152b8abcaa0286845820ed495b666d116c382c78882eae03f20cf9b27bf5f7d401db1b1f363a4700a206ee30ece276885592e01685b3aa708aa9f4481edae341281a4301866570880ad4", "c3845a354bbd890438d78c1be026eab77862f3a6b251a48aea3adb1a91f42c1554f3f710c88a197c", "40"],
-
-["6ece2d8258c46d99d068990ad493b5209876bc351ddfda1705b8dab1a6d0e2e75ddf5ec124c43acc671054bd14c83158f267ef9033237af04c71033904cbae4dcbb18b4b4eeec8fc481c256e9b6aa7de9f9d91dbba5590a9319236e43d76a965624d9d6afcca7ff01b93f3649001817b48208b5fd9fe830b5b225bf879", "1c72ecfb8439d9ae3ed4eff8fff3aea771692ec3852f11b90aacb6b87f33af5c25ce768a593a5b9b2132c1bc05f18a9420f2d02876fad6fc88583e7b266b7c9985668ab79150ddc7844f99b0b82501f4b9fb31909f5e0f249b877f53cfdfd66d63c2924bd583487b90b1dd9ec199f90d660cb9c3a763a4776abfe1082296a71e", "9f28bb5a7db53b7d2b3d4e9a5e50c42f68e5f05e1e3c7f8f6f6a9d1fe6093ab0eba1858c3090923e", "40"],
-
-["fa90796c79d6a728b50d788e35b9345b109e5f8f9bd3821d44182fe6be693fe85fd45eb3c687ca1dc02d57376d7bb7809e05f85882574eb78241131e69720ccec848ee3999e720b62289c3781c15f0c115f24053131d92287a7908622f675385fe9731e4391e3359a2c8c4398baf67873c0c4068954ed6d7f569179a5a", "3744c090cad18c19a41b5fcc4d2091ab8920b70e224e80f5e7ccdd9df5d66551353ef7832618a11bddb6c00f4ad76934bc1b0854a7ba4780a67632a7bcce9868df75b425b7020c59a3e99a2bfdaa2a439f4803dca5fdc91a752ca29efd9798593cd23f9d04f8977afe9c3347ee029b7f388ff0cfa47fce6c5ed676aa41992dcb", "7fc9f8e88cfb59f850bcaf25345aaaa669997f1f2dcc13e6864a44e67b9978e05a0fe33128c51a9a", "40"],
-
-["df0251af61d9f87520a8a464bbe6004c4c9026afe3cbb58134cb242c8cf356d70a3e6ee80bdde4c7e622d9e5da9ca7fe4484bd9b1557aada84c5777100ca3af1691be8487fc36b8a9b0ef4f19da6932225fc05b106e9cde8f79686c48c997741656068d7a6d3b445a635a08dec4beb4057c1a6fb9cc94632f605f82c18", "48b9c72f2fb4032f503134e7899fdb60126c7ba4181e5876a8a07f40cfd9064d00839538b53b26a559d4082e66f12aa1cbd39668906f3c48bcc4f14f776bb7076c703ff07160ac2d6aa39a7e6a0c5f6e1caf90ce62f3c8613be8a4d9eada12025526cc3eab4c1f314946f1bddf180231cea972bdd5d1842aedb3a1c7714da0a7", "3d5cdd58801ed86d5c97930b7388b22d1f49619026049461c01db6e73a3cfc14f8ed25049c21eae0", "40"],
-
-["bf229362c49dc718e7a7933a33ec8174b224fcda3b96c0888cda8e8599e424ee233e6c508b95f455e9779ea3595b990195e28fbbc95c2048a28e902ba8b3bc17cfc0535648ec426c9d7070f7fd8bacd9d5cae499a0869707ffae8c1f0cc7c530eb30af3ea3ab9ba42c1971fdca85a3459964613c681b0c0bcac2ec2a6b", "04e38071f35b901bab55743063832c8339d5f52d6de21a1bcd890dc970d8b64ca72c0b96319ae1f6ae8e01de56b27fe9eb12471cec7df96d0c2ef781e3acbcbae2ee6f718cc3b780e371d204219ce161a96a86863de5b17271a1b7f6609ea5bd9d6fd38b6d9cbcc233460c2a2b09fbf15b27daf1c7e2eb32e52976b05077195e", "710481e7e1b77226cba0a0ce4630ce7fe637a615cda814ced3a30c0b02e3940ac024b56fd16f7043", "40"],
-
-["557c91298dcfed52937609089637a8cca82c853f71b0a22b207fddc7a718c4372866aa53aaa0fba9116eccc54d4db6b0d134e5b647786c6c82eddbf2b44e1476e1de5af99350ab56d0e4508049d4b20bd4ee6fee1c82ad87977eafdfb9d8a630682cc9a4afa7a760b623bcc1f29343e599a24c6e59970e82497c7e1620", "fb0e03450c407afdd45d07e5ab697336387d3d039562df6bae5f5313ea4ed6d40ef5ecb05e94a40b05bb1d17b65e4991942bf1ab4f1bf9499048e66f98fe9dfa24146d8f937d50d24a1a6ed858d2e3de56e5c23b917d5a936c87b84effc06d48041391caf42207ba6d23030ed7edca864752b99ba3b089b308c3d19668bdcc25", "31e3eb1cdf76f10b80fb7ed8e614534ac15f6ca3624d1640f50bc8206429c5ffd944a1875cce4601", "40"],
-
-["a6198953522d47667302628cdc705e0959618cb7e636de921f66f97af8688c35aed4e0b4fec5b19794813df3c65c9a5282d94cfb85131774ce5b12465323fd00f21bd47eaa99a46b0b3e9e05ebd76a205b81be6eda112efdc8b246011dd0d6d45a358d3bc072c9ebc081ae4cb4a8767ccca007974bf7cb36f3b3bc35bd", "2909532550db4940485ad5c1905a88c7608480cae0b038219796a4c726a67e5e3634db74afd801062a157c42aa386f91868329d5aa8bf8ef00df428528ead1026f1b6fdeff43b31f533e1a20eed559914de3f2bf1ab70615a2ba6ae38951fd5fbc0538eaa8e20694aa1cd6e1c6f9efce9bea040f96fb099b676e456ab1a3a77d", "9b31714df38d74da1d31c20101823a7a51129595e96885fe4a3cfb31d5e32c632b2f0e8318c23392c4e1f83f180aa9f0", "48"],
-
-["91f9e69e2bd3a0dc7240d509c7ec14c85427f79ca030574d60b4bc8d919217dbc3e1b4a8b8346ab82d1c15ccf8ef467e53c8386c78cf06986ebb1c0f2295ebc9b9bee2d1253399a5f6104fe073539616eae34d0044d1cfd9dcdd6a07923c13fe1b9857629b59956b75236b8e619f6e5ac07f1ea02dbc19d655228ebd08", "8e9b8a2af5bf4d8efd51e33223e35e69c3729c2d3cf6845950388c19c9e47e9e62df7d16e4da43db9028aceabdcc7898c2d74c8016f1fbc0b6350465c7425c237d8e6d4a3bfe5ef5fcb49584f1297a4d6b7b7e8ffc085da7d93b9f8783a66563a75162ad42522844089ea5e9071fedf288073a423663307882f36667453923c7", "b771f39b76ea764e7e3b488cff14ea8e337cdb173be0d8600d2f565fb04cee85011a26b5b9224b30162dc3ba48c9b121", "48"],
-
-["71c60834c9ef3ad38c0422e43a94e390a92e9f02a8763a1ae3776066073bc94e2699bb8e5c4fd1eab351fc1c4460c7129fdddf566de0d2ce548517a51b864f890f0fca17a5cd4d93604912ddf57411a0ae827ecd0cfc9348b0118fe17b2eff85f5ce3cb5eb9235385d946e0d97f949a49dc5854eccf304f1bfc6d10070", "e9a877d87ab357733cf63620940fc61cdcc24de1b5f99f4b9f094afac73710f71f7b9a9ab4846aab3dfb12c7ef0ca305992369daecf3d53a1abdbb00a4cfbe906c4b08bb12293ee37d7fb25fb800db2000c303951b4740cdebb3a5dc4262b97ff89e28033bacb962270a80b096f455b53a2c85dce9fbc74c377371544af8eb0c", "dd608e1c1464bd8bbd42c9ed528ef3a8036f66b38892da4d4aec611233eb4af89f7b2d9fed2e9aaf177175d674dbd516", "48"],
-
-["3ea973e74567002dea56ded27e4f1214581ecabe28864b4d88862b50d4dbc0153cc6d881170faa407a4a0d1f0a59e8b50e32ea49c9e37bf006d3f2f6f44a087f34338c6e31215986b09186cfa86fdc3c53db8d8a852c226b3d35b6e479c862f44f79f8763aa4f448b2b2afadff97d6f04e9f1b4b056b2a065436dfe30c", "2308e98a09d74271c2a336c572d424be7c0eaad198fa5d8d274abdc3c4f768e91ccd8dc0ff2aa435b7035c859827d5a5d8c810e1a137a4c365efb23672d51aebc6beeffc243229d067709a3e17b124f53ab1281b6c6c4134322533468cad1e3ac9e2f8869f7639097bf2d83616fa777549d2dbc622219ea837f528393a08db9e", "91cbfa1b604f0c2e6b85ed3a4838f7df6fe50353c94251cec2feffbf84ecf98deed44bd62c5406e86297dff5b7fc94fd", "48"],
-
-["f234a0b1af97eac1f791c02c2dcdb786a223d50ead60458cfe454f802fd288789cc446c2755c57431162f3a7856aeebb765632162b1c6dffe6c9078b75c65706045a169435711418739c295583586f545b93ae05386c1828eb3b376e73d123dcd101f871185f8708c4474c804c21a119fbe43021fbd9aed1b3a2c77253", "c48597527e4d997da811cca724f74fc12a2738dc9ad28549907356ff3954593ee65485725fe90b5a7a618969ec6d816d5edf0b6ec4b3c6d9ea4c14de4f01871637d89669fc91952fa5e7e495b91266425690343b7d259304b0212409bfe15b3cdf9832fefc501f43502b43f135efe48a37c11d0b6e31d4140b77ed4830fcbdf7", "f459c728cf1350639d2778ca5dbe68e3a5916588cc55a8739b461ed508bb358005bede01adb15875f03761d07484c4bc", "48"],
-
-["bedd2d63c53fe1ff0874896cce3357257ddd72c218f99c1006e0136715b9b438bea866e6ab43b6d77646bfcab4874d6b322c42247e1b3837a9b2670a4cb9c9c1c9947d12d3bd6a55317224dbbeab1381b54fd2852ba7d598dd134f342cfc522f66093891479643a3c0628048f687f1908eb0ea8b2886bc5b296b918dbe", "40b107b77ddc1cfa73fafdda84f4c895451c5138df05af12b697fb684862546a573326664ae5ac3cd17ab412c86ba3485e3bb8ae765295f54d09fb645d02d5cc82ba78be5c6129fbf4e9df2ca25d4bd42e74168235666a4bcefeb3776ef0a77b096e84287af69821256e0792db72c91ffeedf21be5e636fb819dac0a41d7d25e", "4fc42aab05538f132034dea79ada4b8c3764211d85191e3bab99a04c776fb25c940889a1906ff9309bc8ad998e5d80a0", "48"],
-
-["beccc5dd3e0ff0bdb69caca5ed6b1df74a9cf766441593b91a6d42cbc409e1d72984adf2925459d95fab5eedf99c937c077ffd42e596e83051f1366c688ef898d2c53b5739e3240dff1640e73089151cb5cffd72cb853403b4332f41ac742f381f57171319047ae2fea3f99a7b79cc5dbc549efce02a9ebbaafdd35479", "5a7079ab9de0bf5c46f49aca4079f05246d0f275a5a0fd2b43c8be79e0989a459c404d6ab988d108a3c2811d2dfd7e5a1e7c522fec67d329c7bf4a7dd997d5e5cd820aaa8e48dd0251ddc49dd593197be98ebf003078084a2177d6c2515c01f1f51ef996a286adf5582bab71bfa1885db0ee8210f73d643758d7aa70d04ee614", "56323521c9acbcef43b446cdc709701ad07c2f655e630cd4de7033096fb58c47842e07c78f460b13a8bb690f9579e141", "48"],
-
-["78eeef30512f01b5cddbe1ac68c81c3849d81af52c1fae39a048ec9a72343bb069e5ab1c339b88c8a03c2f710ef92063252f5584c48bd2943a727eb3baa03124090f0e507a1a130e1ce2ee2132f11202e31d0be133af0ee632c5d5bc73f5aa50c033c1d60cf5ca0ea995c7c5bcf61b9eed6ae41263d38bb6ef099abc87", "655fec47e10300503adefa0091e11a664c2819270c22bdc3dff3ac19b8167e2e1c47152a1da031a38c023f1e6bb672d3a15415b1f1e420a22d440910a7cadca8e9298e5ea6998fb1f0329e30aa99e13d41df351197a6c3004086d4fef1afe66335cffeeb62364101cc38be3d38d28f2b9491204ee5b0ce648080b4ec7d0dc1e6", "c683976dd4aa6e5a359e3f6359ff2bd9d44a54fd005e0b90d02b547b3aed369793aed0465cc8ebcdcc36175ec6c28fda", "48"],
-
-["1977183650572f0cbb8d65228d141b67cf5f4212a692c47dd5f21f37829a53553d71ff083e58c775eea8f8c0aadb6f6ac389b7dec997714609abf9a354e2461d02c61297377a3b8765386a0512fef222dc83fb7aba70df0d84cdd44ab6b9cc6d715f990bc22555eb272601184fe36c342235560f0187d6794f41f95430", "99d4482daecfeeb8d44226a39f85b42f9513fdc2d798c698044c3eb55a803f1e1e76d1483e76f0d1361e8f6e30fadc256f55c6bced4ebc71432eb8ebcaf87d7100421d5a2d44bdc4462f9c8911c0526f8a14569f86bec35996175ce52ed5cdcd06df3449c160dffbcd1a57dc8afe9e77aef9b655e81062b8c3af318cce3eb79a", "20055e634496d6386db2dd25f2f24f3336c9f3ea8a28c4226ff8b34e91d854a0886a0584c858665859a54534e1b1281b", "48"],
-
-["da02e70f3f27b04720271048f65d81fb12aceee8eb7baf59b7fdbbbc38b6194ad93b98e6f939c04887b8085acfa14ae2937e73625e827f3c15c9a51acfe0ce8de6884cf54fb7c9a026e07d368d74a554b7a93077407abb1cc0efbf259f9e097f2b726b02e7abb2982626d7a2d9df7a97a6b48bee3a6c4b3fdff773a551", "3b0d45434969e3931fd801367b32e7e680206cb1582eb9a71f214fd6fc8a4c78919be96a963a48e0c0546d58e8748acc69947f2cca33a10b9da7ba99a6b552d1405f2df3fdfd15358d8fdab5e15296b38f9135fd79be35aaaac1ab0b809087df100018c6c6dbd3f94c325037adf49503bbc0970caef8feed0d172916d244720a", "48dcaea229724fdc6a01a19b9742c1ed7fd57a86ecc2164c9f808f62a0c8cd6da6c57990db306499cabcf344156f4a12", "48"],
-
-["939cfaa5c4b2f535c8c796f4e98726116cb9a3a5cf711d8e4e87607aac47d3422f6aa07aed46154646414b6eb28b05b5bd0dda674f9c97323173df36cde0145f353d7bf367229a67d0d9e5770e129a76fad8eae2e6c4593b23716715c72f706dacfcd139bcb04c426c77ae368784aef92d04193d1b512c522d69413f20", "51f9ab865146187fe650e49d45421fff28c45c3bd8c465458b762d93f199067e0afd14ec3a42022c9fe2f321a272bca3cc245022dc917b8c16b5d471dd3bed6684fbcca762c29f002451abed67a8860087848683b126795f4056963c46a8b4ea68febafff04e57c21da5f348bd6ce5ddfeebe6a6820bc584b1605d3a5366c5d3", "ee3629c6388cf97e6f6f36cd0eddfada5a44c4417b02e68bd46318eee178495aa18bc701f940e29d9198510d494e3636", "48"],
-
-["d7d1e2a4b1015678e0acef1fd748da61498b86413e2d58de67efa19baedccd7e12cfddd6b23517212714fb2c174d7a7c071e63ed71ea1e99173d9cfb960b8e553e38d54e215d3ae06aca6071f1c7f6400c203103a7e07acf975c64372b73e0b80415b4552702297e51d404368b40b875fd99dacb0795d5478e54df9794", "9561d6d7e4018c397d3ffca92595481626fd14e65ab439de853eb942e7aaf83d12172982fa7706344b93c404ff5046992f309134291b8094c460b817f0f7df23910909c48eb17396240574e68150b0148ea28f3b0c8bee14e2231418b54de7e5ba3d5fe3c8383c27b29bf498d31ff050ea5bf745298beb28888fb38d5f37784d", "93934bc65a3163ead3180028e8104fc305f2ae407a27b14975fa79f0aec260e963736d9aa1d0069db5fff91f87e77512", "48"],
-
-["95bd8ac4e11edcb1b21ebcac3197b0f72388ca2561b7c4dc889b50daf178a936e4cc6ee808eab08c1ee54a63efaf709b83828bd0947f44d24296dfa909a5cbd6efe477dc028067ff0186864c5a03bed9546ac64e8e353ba4a8cff32d40b28ed520cc4da7b5282893c4ca1a6e58dbc03aff0622a83521d1db2110326f7f", "4f83fa4a7033fc574fbf1cebf8d4e4b9606807f6f6f996be7c1677b3152114e79f5350fc4200bf89491d16c031ffe04e00657383d9b923b3cc93b5dcf27f1788ba004ef57ed5af745a7a520f3df2c46e40c4fcfc37449045861329a237e935194992db7f4a3734df28dfb8204435f6e1c88c89a83b20473c529fbb4f09e8d660", "2e13e091d6062a8855b6372ce7e16ecacf91e4535c4f590824e95575a85154d333c96574402f9a30935bf30edc61c030", "48"],
-
-["8c679291067ff88fb4b7c9f2470fd1e6863ebfef380228da44b4cef4171f21794692064f7434d8f47798acb6b691da22d548b9be958ba9fbf56857659efb3c1d7d846789b2bc7d4aa96afe6e8631c9e7c7ace33c24bd747fba21127f6948423e7b643ca686c1ef77935445847f96a81fa293c5d2af3d9050db0788aa42", "1763082e69de7e54a36f4ca6ba48ad036061845527129dc4e8e1362a6699bec72f9cdc37c379b3a0f8c165309d17d5c75e87b2f0e719b1164da519f5a9e8854e06d8e5df7d35c7929e61d5f512719eea7f8499a0ac5366c1e60a58eda257316863c02c0fde3941915cf6a4db617e8fd48b01414bbceb472d7cb9fafc980b497c", "6327d024f6ffea558be652633d527bd9b023968032053a3a2ce183affae4721bfd81b965bf247b38498b1209e2955ec3", "48"],
-
-["be7b98eeda81b741128103c2a5cd5180ae213e384c37708400971f4f56add48cc81d7127df5b723c9b7672881534f11d63645459bc702d18dc32bd29aa08b8ff192b47a94c3c5305fd2001301f0e7940c2e6ec3638c5d52e76d67fd9bc6bc72d794c235b694ee3020028b7a20b83725395500db7075eef2d942c6b47d4", "82af0ac2ed6449298b517034353c3687889b4de0ddb5f3597b05c4e70cdfd2274e56f75a0b5c6aad4b2b91bfc8e4165da2763d9054c275d9e7fd2dbb6655e87a91d79423d59017cc7cf22c1d227a6d0f6890b0f4ecaf97b878c5636679dd09edb3fb88253447790d866ee8572946622640b14f168bc3837d95270ff02fbe5d09", "156277ce3ddaf28e60541fa2c95f58e045bc5d68d630d5fb94188de015491dd6bea7bfffa185cef131b6ae514e7b2422", "48"],
-
-["480be758a9b7ba9af001bf21db00c451cfd66f06c9d8d5d698ef47974a3d6f21e4049d5556c45b5fada447378b13226ed4af2427ab6692649ddb93831b0b40082e30fa9c66e60056148c403ab8ed6effbd1f541664ac69e7fff0a45e5fc292a68f57a734c362d2088b80532f4cd4d18df1eea7d9def280e925f62330fd", "b10af503590665c12e96c3be38b6ecedef0bbc4da1be8b63138bf7e76b15e4be4d7dc22845320f3b650b1c902f93133cb5a43901fe3771c6ac7a0245dc2399044e049286c6e073c2b17eb9d40cfbd8f382647da3d485162b7efb944a98916f26fac9eb85a3b89b3f5c1723f4989841fa9f760f99eb50827bd237375e66066f0c", "6a2930bca4229aa1f1ea1d5a26d02d3c350550f2e0817f52671dc8638dde0fed40de2cfa5483de8ce941d94e3501f6ca92131abc64724422", "56"],
-
-["2d0cb6d45a952e7696ba75babac052a0d44c5fa77a6d01ed5edc9d97238309736f3d41dc42dd5cdf8671be9ce7c88eeee4316b4e26865af4116903409ace1e5958ba2814dd495a1746f6db3e1117353d2fa706875a48f86fa988f28d626490640f0bd141d4e41066ffd13668e1b62dd6dd35981bbebcc1a64dc2d24824", "7415f6373b8a794877c639f009b3c49197f388f98bd3f0ee5f7ad00a196703ca111a53e1fa1098d220f524bdef165f79b515afe4a9e09b772be32f1e4d7da24693b13637f71f60a430322980349ad414fcfdc14f87e9915d210e8b7be5aa3e09814468e0399d17e72fe40ee1e1296a89f31486e12fd71bc7ca61acc9e8d4213a", "76912ff0f180d62a86a2bbf8e1f8d4438ed5ded0cfd3bbbb430384a60f18d9e9cdeb7e49ec43a61367686b34633601a5aedf8e3d669be282", "56"],
-
-["6e1d5f581eee884d330e4cd26b51007f4e3009b0111ce581bb126bf6867053798807f88a92715db259a46eb8d3c88918e4463db13a2252be768a09078cafa4596cd32933e2a364c2bac82b0f29b9e67868d34e5da2ab741b10a3053e63bbcdf262aa7bbbd42ea466a0a00a4033cb1d8522fec21b10605480d384e802d2", "0b8cdd9bc2dec68748a799c1c10a4199070ef57ba7975d7f2d95f63ba27bb7dc52f3f575d3cf849642431f21d05209597d87f53c24e52413b820cd47cd0ec1765a584fc6bd756579ffd9f9c25a69a7d7f8339c48fe5cb28fc959dce87762c563aa24b43788b7b35907cff04d6736633d007ab1483de3511ce9a7edc7a9610bca", "293fabbcfdb855289377f512efbc370152cb87197d12f661b6cae18b8d242cbc767a6f663377085525f0f27b390057dfb99b57b73f52aa2d", "56"],
-
-["030d2ff93bffc8241b850829f5720812c4ada593783279328d590aaae25b4092c9121d3a5a00a8d60e932ade89a79db4b64d628819cb327961e457fc3d591c8271af8255293334c38d3cbd7f95b0f9441fc9c9bb3f190b9fd6ccca1f662eb184246dc6134f9f5535c10629d5c493020a26aa44f69025edee139bc9da04", "942405a1436a808dade61cc4207fb8ff8846af793dfb4f981fd38dd0e578f6c5b252e9afe9050403433bdc501fa3f166193ae1beddc9db163eae575c82e004cd002e025eeec7525b74ff8b4e0450ce22ee9ffb882fc1259a8a3e44bedb552acea740b976f7b952dd67bc90877a8f377a3000679fdbee166c2e0abdc097df500e", "86abebfc1cc5ebda3fef0a08ae515176378e189b577b51b8d3cbf6181bc1cd070a086d881ebf31ed79da1f034e5decd30a9b69256cf5ad34", "56"],
-
-["c169eca1b31e6d7f3c59747c486ae4865ec824f5c8a967bef5cf31e91ba51745208ac65abb6d469fd79a80ed38d3fe3460a3f36db4ef7bcfdbce2346679bac21b7480c0a1bf4b63c3ac01bf0b1df7118fdedb3509b82e9126d06a92960c7de684c48c89b1cdd44be2e744e6dfdb6c49cfd4f6c88c68939ed7520ca7a4f", "4fc3b9d3c4a2a4b95c41d441b88e126304cf134ad02ff2fa1b76dd36b1180ea1b871fc23854fd38eb4b1228277ced48e2e5962973804477e0943b3fd7d5283d26c7971207f8f881242bddcc17a847758b80196762e7016d3c2f0292761854d4796637f915b6aab8b2fed68c768b7e20acb5017ac5a7c47426d05a57b04ba5348", "ef19689ca901092506af1eb87b16c24c898ae68ecdd2577ed6fdbae5b0e2f93e9f0c1a839df6e509bca954bd881ec03edce3186a9e529490", "56"],
-
-["9d10d596d5845f84a6805feb81fef632c044617a930c86dc3de1ccf180cc3098553096f3e1f35cb3a5de3596f547352dabed28e31c23283b4b316f595df769c44dd30d6ae1c56ee5ddd18dfc76a5726d097c7bea0d42f36147f9e6803c5b3479ecc1e502bd43bca0b6210f26ed62d67843ba01954eb10fd59702dc6f06", "1fd2428a58b14ac4f539d7eb55dce51ee7def6e4ec0afc787fa47864d39ee451bd0e4f1e72e0472f91c8fb08647ba11a631f16dcf900c41e8b84c99bb76156cdd90d5405d4774eba0ff48b21bd7c7aff621654bc9291fcb8fc752ae2a839c8867db69c6606dc280d76a4ce60e9453876ecabf7a940256aeddb5ee261308e0324", "55a1be4a6c9374fba4d41ca5b23f436c82c041cfe3fce242f404444844dbda9c49b2b4e52ebccbe0c95cb6f04a1e4fca51b26b09e05f774c", "56"],
-
-["a1b77e7d35e194c8f30d1f3b9d4510d5c71fbc688c9a309d7f4ac438e4b5f39d21fc69936fbe5e49d539239757b2fa856b172b11ace4542dd2430108e2777c8ea27ae73250e587eb9311637cb27c0ef9046ec187a8dcac63758c8e26347a84470d7b1ee14020b8b616dd0875166af3387b6aa551e64be046516d941a44", "6210041f1da920551a12029bc4dd81bbcdc5fee0c3f09afce571a66734115cc9cd4fcc3975a518a6760de476aac4096ed54e89d237bac8cd6227f9124a87d6c49ba394944f6016297f4061c4792b5024147088a74007afb3e119d954dec1432674154476d16080a4deb25fcb5c9bbea43233e4a8055db7750a4d2f7318480cb7", "232df75b5b6d63a2f4c32b739bb13a5d456684de17ee1f78d351feef5d082d2efab21d6143c405c0f015808675ccecb2c398e93874c7a15f", "56"],
-
-["8966e3236aec1581b61c8e3960e90380d8d48572df6bf8b19b10c64fe6fcd5eeec84b625f59a1f267f969b9e903780dd4ae42137c03846aa08bed9c192f9e2b7b7a8b6e095476a451bd98e8e60e5182639bf9f990a08ca41dc780721b0db8011135a4b1cf3fce026b16dd1d177fdb5a2cceb656066e46cbd3fc7b839eb", "f5722154ecc1d97bc585dbca18091604ed4474f922af5ffc0d5384966126d67c5697d6bf0468124310bcec728b719d3117af7a134cc175cd5a24e6ebefde8cc15b11cea3f2d04759b13182cd25a402078983865b2fea8be121c811260736a2214b0891697ca280a5f00e2443ae4b4d6d8ae7203892d0a71fa9678db172d62286", "e4e76d5f58ed574c96c42b565d53e6676d2c3075217f4c1a0afa79c3b55009617e73496c1af9db02a70ecf08b2c95dcf1be5d94b7760637b", "56"],
-
-["6bfadbffeb0d23e34c0243c51a7e5a1e7672a7fdc6fae407ab85d5bc343720fc518b5fc98a3d744ef0671c2054eac8c5b80fa873036e0819f90fdfa469d0a57ba806664b3f67150ae1282236a0624e5e60babfe48b414f0106992a09645a98f15ab3170e7355ad1f058c7c8e64af97f355bb14dac63b63c6bc6bde768c", "afa6ac462474f97c6f12e3b4260ed08c04837f95e23d9c9fe21d9138eebfa06f615b2ede03e962e73b89ef3d80212ea611487000e6639282e60dd2455abde003a2a2391e26d2b0e490a44d11395a5b95e004bd98a2a181bc2686b8a2190484afda45721076770afbd62ca53837017fe1db7e0d66fe3e91d30c50eb5611dcb2fc", "599d768f42ed0c6a81898955e38f34e17b0619891278f4d8e05ff4ee49ea30b063d6e40f44247d36de11a2f346a16395a218e94c7b04510f", "56"],
-
-["9703e403d09f821808d19eee46151209d02cf23feecb6c12f190f23d74399c8de218c7f238c203d60c082cadcf28d6ce4f44ee817f129f597bd673c4c78dbbe0d76b3090d588dad09ed731450e5783187d8d950589318daf16280f3794b2a70c81e567052e5ad1adc526f7de8b0be09163f02ef4948ffebdcb6e10be64", "a3acb88dd0b141652e248025ba606fad61ec8d52b75a30dfeb6e1dd9fb835608242b33329d16b42a8435160a191ea4bc2ab3d0bfcefe82275660b0e681eb2f4bef0cc9c8d86d8ff8fe1970e0e0e20b763100c25f88fa10fd59ca1ca9243c8e3dec97a1d6eb7f79d8841ab83279724ce35e1a8abd4e158168dcf388ab4c3d1ae7", "7100161e04dc2a98b6ac04b2fdcd16e6b64774e020c2a94b7bf0714b1e88b7c6fa55b6d1a25d3eca9ba04ccb5a8fc7e0407d01d98c4bbd3e", "56"],
-
-["bec9d2afddf5f48ead7cb48d31ac370ed58981cfed6e8eb55ef79ec89b1dd07ed4a3f23c6ab2981aa5c06be7a9b6c46da6517af550c8f80e8fb0928b771d19cb7a0bac3ddcfcd22268e2c695623e12c9bc8a5f938cd58c28beadfd27064136da19c7a8b91d1cca2f5eaa6ca1428f49ad7ffcdbaf8bda738a55b488a532", "6a15163bc2f7e899660edd4dd56819faeff7173bb64293f1555ba7dc62113469606683cb88442c4dc96b37e70dae820fbe5f62d188088d22615b3872c76c7c80491efe7249dbcf25008991c2c3b9dc928421e0139b4c4b4bc7906d5c6f4199592e24cf588f2699c8154e33057ff1ee2a4925d627767793138de18dc3e2f6549b", "091f47da39c7040d6d76064ee8017ea5e9a85ee82e5632e66901304d515ddf5cc4371c3cd3068e3b8a8a6ca5127f376b5d902c0fda382e3f", "56"],
-
-["44c3dfb2f9507384167f9844865eae22cb36713d95c9280a93d81efa89e85657a3467b6f094916f5b3d704a47f2a262580d45a339901aafd4bfdc4ccccf6aae55902abb1cc64921b710b1d3a165bb13061304b526720ba8979010cf741ab46de0fff58be6d294fbef921cd4d7478b60916512de024aebf75c5edddc512", "0487cc84f63e3fa27576b32e5781c5a23c4a3fef65df8e1dca2c773166090553d678c6302227379edb3d7c7e665354e797bf4d818219342e8df8d0f0cafdd0078413015918f4c41706f0c09e48ead49be3a34f73bb3b64e2abf4d8e2d53afece37e0e5c780add0572117e654a64e2b07477caf8eba4b067f4ca9f7ce30c878f1", "5c248c052efc46ab0baaa9af71df991103b7b83773bf7129d29121655c28d90ee5fda29c90ffb6ca6c5a072e95cef8ec2b706a9fbe4d2f7e", "56"],
-
-["b4351406709d4572693c0a7992499023232a732a3e8983aed2aac82ac0fcc4a73ceef7307603a9857a29e3145547bf247a15e154643046447c6e3d923c6eb2089574b2138e742c772f6df5429df90ffe86212b2bbf756ced2c5d6e01e1aa87f586704d99001858b14eb149ecb0870a1a0ff7589999817f10ac1f6560f5", "e109e67f994e1442aa4dc46bc18cb6cc3a8eae289b4a194661dcf443007bea2c25abbccd3eb3e6c33c5280d997ae6291938d8b00c3c7c4dfcbfc80e06982073eb7a1d398aa033affdf664e4e979402b900958ea9b62314c8895e835781c1bcc81f55f389e624581e1af1f772c826dd6f060e7dcc7c445226236cc5534d2050f3", "4a5e35d85e06a57fe6b6f90a4ec3b90d9c4d8896648f0496c5c453cd1a2c25c4f627c688f3f2bcaf88d7e7b33792b40c4a4aa6737b423e1d", "56"],
-
-["67f57e3643ef20e5bd2cadb13f38102c1de95c6e289d036f80c34ab4a99df92ee143575fb1ec55bec3690a5df3f25fc9a8250c31654cc3cb9bdfaa74c24a0f97a7ef584f086f38d05e252903faf41670534357619f45e344a07ec152c183c06e610168506f0030e23769f2ea7bc69beac64a2744fd11cda237a87695ce", "7f87d81389a6062e8ed501ea964c2fe35b2d3de9fd676c04f7da2bb552bdeb7f183d2fa60c67e2379848ee4807530a81f403d3dc02c11fa8ce078ed422c6626a6c05905a6ecb1679364090c9510f06fb3c0e09321b21fe0aad5cb9d980674e35612723b6179a4afc51030ef4dc48cc5819980575af50b0317d20d54cd36a57e6", "dcaffd5f88c2ce438bde469813643f87e841e94ecb283270564ae0b07db0b8c006426bfa7ba91fdabd5ef9b1b8b9cdbf860ab0ba459e2c9c", "56"],
-
-["31671f1a2f8f8495038b707aa10a2216f0ee457e8600cfdcb3e22a08c24692587b5ebeb8ed93c2fd6a7a3476d2048dc415f5d3ecf39775ab06af770a03b0ce313d2f0e806a9742dcaa1e1b47c5f74eb4b4c937196461c2c5b6a48bf4cac744a17dc2589b3001cddbcc5d6f15024c2b8dd854b92c61c67ed3cba8b0efa0", "77e16e181b3dcbf1faa2d371f48400b60207b23219592ea45245e6474905fb49c258007e93d50156be7fc30ddd994e21c3bca9096026e524e5555cf029d53b7c4b563e209a01bb6bb10ce25de68c43d6e68b914f995ba5c84c29c5010ed527ed5a02b164f76903181d72b7cad905e7beb29df06c8d9f7678ceeba9e4c0b0c685", "f421ee42006f668e1de1a54b0cf7778accf1fd8859bbd46fc98c10170985a3ab690826862818c97b7696762898f25417caa5ac9f21a0a3da", "56"],
-
-["13fb1ed6389f32d1de3139cb04bcdd53525c9889b85379d3535a25d290351c95938a3d0cdaf38dbf1d5234bf7965c8ddce9ace1b66247e60d74ec7702a0f931a3cdf4cb465ca9fc458c380004a3a6e7957f1f813210b8038ba663fcdc42a8965d6a252b5224bf249552b2575bf64568db4091d58323006c3c34994d3a5", "88ad812fd34e55c809e817199604b4a7f7feae42cdc4c9e930db08e845a3d74313db8a57926706bf0551be758a0fe239f004d237c849d9e4bfac18292bf9c0c3e37985ea54b94f30d18c32ad2b53a059827cddb95a49b4bef1d369ead14eeeb4a18e592e40ca96e515a15908a05a57cd5570b611ab4ec23f7057e1725f29c9de", "a481e713cdc81ca5afa0efcb16e35cd20d01aa449958fd2eaede2e25a5ba540beafba2fab4adfef2e146b4c1b2a1832e93dd373d63fa90bb61490f6568191f65", "64"],
-
-["fd5070362296c40d65b105d5ab4653fe34e0200516933f3eeae03ed0c5d9f6016a8560b4bd86ab2f7bf98b22299ed3e54a394602d538aaf3e6951f2db4feaf5dc33426f15bb124da388d709083a28f5701ef96c28b3a3c75bef9332ef373b90771236af5e25d589504345d28a19ab0dbc1c9b74d1ee21c4bd8d423de6a", "8d2e68d7e9846cfa30d931a38efb59bcced53a14164b3163d2653888eeb0bb1448e1a80c65bcc6eb633447e72ed4a075f75d980fe2b19f35ffef62b27ce09c2019922faedb427321057fce19448d85962a08d1baddc936d1110e108e33d46f97e7882445b5df1ca4ff03edc237efaf264f1c0d9e705d9b3eee076ba57c56db82", "b6cad1ca5ba505498a8f66a9422bf539426a8a55334fab9c6b9e08e3a5179d157d1efa0f91d5c5e26ffa43f5c1cb7ca5f906ce4f0efcf4e871820b8353e890e4", "64"],
-
-["0f67caceedf8cce39a7223d32ae1b6badae2c2ab01bf75f543dbb8a408514c4b2cd81801f9eac1aa52257c7830f120b1536380b23161f734330744ce204a98bbd9dbeede484e9b03937c26689526597c8edb98e6191b72c95fbc76468b8d48437e3af46bc36f8176c540caab3fc989a3f511b54fa2350e3d31c2f6162f", "3173a712ed715b321a849279be6ba237fc90dec0e48b0e1290e81cb86c2a10eed50f3e05e616be098e3f1da2e6125238c2e2f45a8753aa613c1ae250e304c6ff093a6b799cc34ca2fd4af81d5622076c2e8752593a27649576e12ba075ccf3e8f57b9635b77fec448e2e89b2b5a3a81d65066285a70e24f868c35f6ecb8981f2", "e913fccc25f84a1390ef0b0f86050fe9e61146c1b4ef0b4b60d187baa36b61458bbda925b96e99cf8a4330291690417fe194cb9bc2b6232d43e046d13f038ed3", "64"],
-
-["5c8589b3845970145e12b34713455eb6b5ceb132242024e42fd9a886fc9a30d3aa15703b3467e4dc99a915ae3ac118fd837e571dcde5945983c52a2c849296b4c96f3763488d52f818b459add51f6db2468d21db3d958196bf3a531f65bf9cf702bd66928672c14f235c08aeae0665be472397d43cd9f3822b5fa9c767", "7f2544a478961dd012ac705e12e74587e2df6b2ee1406a0bfe13b908853f31eb384fc236a275654ba8cf51d461ea726228851754ef97ebfbd69326fcfcea04f594d177633c4618c38b4d64f7e98025eca4c62e7a40634b8b0e317880002c51d0bb34caf2ee0f207ee2ae108f53b1466b7f2d18667cac7403ce75175d390e00c5", "be73d57ab3a7c5bb305451922fa9b11d0ae938bec886fac5645a8b72de93770d96465291da2bdd5d11692f2cbfae69adb36ae714f1e5cf43b9d2841ddeba4ca6", "64"],
-
-["7198b12a22014154578f5236b5a0d4cbe29aab3bf289bea2220a4a13c9677ddd8b03400cd0f954337400a069c192c9929a4d04e0f89928999b8b081af909ff1c7b2fcc36e8f2a0a32103764610f6a3ebdba4aed05dce6164d603204ee1c37ab1a7e4feae5335502bc6627cd38b17895e0d6852130771918b3d393db76a", "9b2e0306e73662c94377e5a99fa9b63f034ba91c8b95645eb0afc381c8207b77d089fdfa6a939d212226f331f5dc81b614b597d3e50c74d74bc9ae1027d9d4e41fdb511d9e1c93851bc66db39f54ba248149448e7422e470c589eaeeddcbbedc0d8cc8198bba8e1eb7bf1cd6a3fba9b1d37177f441c0fb53a4563ec1f2e08387", "165e1cc47a7433270f1673e1cee581001708195a3471c9ec71333ce87299e72abc5dd490d43676d10c1375125d4e7fc673adb15342ddb7e72eb24ff36f54f82c", "64"],
-
-["b5ffe36117589646ac097327e4147dc9fb13f7346fd97a6fb756448b32bbd3e25edfb3a14ae194844e109fe1f9070ae84b1cea2924fc4957fd8f794bd622a74b6fc4b38dbbf040f7926d0729a67370bdb80f63cd0cc85bb61a83bd1c86a4692f52768e2c53cdc226e1ea5206d39ed6d1abb309290d87d81776fab9072a", "1e6b0799e857a1efb3cb0aaadf74c78c31d5e1c72547dd1d863eed463bcf6892646f78cfa6fe136dc2042ce06d3a2a465c4c994a9edd1f482ecbb2b2c9b509b2fdbb501083852057ce87ae33e483431e6d4fec3b09d87282e7678c1e9423541310d8f82427f6b2f4feddfa6bed57fa5b8c6642641141bd15d999e353442031ff", "5702e9898e96cf220c6778cc1d07469f13ef0c2ec0e335ddd1791ff265ce865d200e4e6238b52eaa9be880adfc9076202625bf83bc07d861401a17d4a51d84a8", "64"],
-
-["b8100034c20a0b6e423c9f6c541fe9fca08fde8ce7177191db6f0929344332fe0036926e3a2720ea9fb3909cbdfb114d4da73b85c22b43f42248721015d6d5003cc235b9c35349673ab12b0ea0a70971c1a81d33c310df3cfbad795e057e7b3813bad05b8514f1acba3e580a26ac3830a59a6bdc0f50ab310da506592b", "29aaac0418f6bb3890902888219720b59878f226d06c7e8bcb42e9c0015e96f4f802520a15cccf3fb280540e7108b251cfb97aa8fcd86d1eea5d340aa3f65234e14f5639d89155315729978e0fca914732b513374138c3c01f74cab36964cd740a1b1f59094d3554a6115ad2a6e5a3e2ebf3269a479367b692101383faaff1fc", "9c87ca3b8ee3849224833b477333d4fb1bea3d8167fda445dc677d0d70b9ba85d0c4fd79909703481fbfe99caba7299da8c514a7a0799d59ab6f9e3f1f63a372", "64"],
-
-["d62dd5837abfe25749b0371803cc47ff4b386e840b4c7ad115a06a76066a765e70074f0238d7a7dc3c4ef41f394871a5ed9d662978f6aab608df665fb51d1b31aa41e766867d04db02b791c5d3dc959fd27741675a827509f17b861c2bbb3fcccc0425172d59ff3de1129671972fbad542ed85a57897e4b2189f521330", "ed3dc5fa63bedb28c12a423320ca6dce3afe8f72712ebbf61304495fdcd87bf9bc6c61a7dbdaf977dbca9b795de894d2c696120c43dd40b00aaab117e337027de7c7bb57889677e6d210450df1414d60f28db77dcea1c89fa4a94e7046c33f1ff7b49db373db9c9f30630796e4bce0a0474b476e1a609cea6869e8130c667908", "1e5b3b965cf268d5f9529d0a256e6512b41bf2726d762e9447e40af239cf3673fd91257edc6739cfcf9e6893bd9955e4166e4fe2eff2265c1534abaf5b11544d", "64"],
-
-["ad9e1d5774ee7e882b2dd772c7867eaadc56299b7583f67b430fae7efbed4a49f913b6a929d0d6852760c711a5be67450eb9178e684abfc37f25135a408e15dc636edb964da6939234a3c4c58432d78a8196d54f2ed9728e6e5f4b006ac16c0d32d81586a717be96981e58f62b8dd6617f16ec488fd716c65ae6ddd641", "e76411d3a1a72ffae3f9363389548084510d61fae1c251400c8e4d70517e7a29a4490ce2723b667edf738220524e94403323eafff33117b74dd550d7cc0116ee8ee9160547ff4d3288226f6a42128a978628e6ea8e4431730106d8ef7341b8e12bacf6e42adb2b3f696ec5ba6189aab0d0841e66bbe3e69baf88ad604d27a82a", "313a41518da2a8194451b01a4365b1d99d9c46498c24160b51a4b494f669dd2d0bf0922dbfecc703255f7ab74fff2d7bee9ff2a4823d26874f7532594b96847d", "64"],
-
-["054221b2a3507d491e5f0da4292931f63d105152316217100adbdb72146b5f88323abd5072ea9d22e41cab4bad3549f9576280576bd93df84262ba7918163a626267f94e6161634cf8308484bbe1c01e486dc45fdaf3bc151b45c6363aa6a7c43ed2bef39a3f368e01a37f977bb6fe2eb93903b3a537fc2f3e8be9f0a3", "56958d7871c6b901f42910b8d7a17c3172fd25454b77e5b52d81fd0f7ac206d6c15b2add936e93f493e124c6cd5f670249a0c7b9dfeb00084cd9d3f6865bfe9f4dfe6b93036ea879bd3997f89ad667d754e6c26214b948108b143d643e327e112108ba6168ceded79abad114e70f62f7bb567f0893eba71710bca120494e6f72", "6d3c6a9a076d3a3506d7beed3845521f50b2df2c5f3bce0707d66e20bcf09445ff13e0e445190e7f2c43fc73ae2bf68858a721a67feb8083dfaf498c8b86e192", "64"],
-
-["42d6852473735ecc21b107e3e0a5d3c5ad2cbccf7e2d9dc31d1ea26e35cafc88ad51d9db367093a82002274672c7ea0c3965777120acec72411e56343baf69eb85c975fa0293955cd5732a4276d287fd051fee1fdf16bcdd7042e0b848c0915d704741c9fe65ccb0e70bf620154c1462b9620921e3f274cd5091284282", "2679ac7911bf024295d61b25aaa0ddba9328bbfe6ee3e5b10a36bbfcf8398857e5bb6daa27869bb295e3c5a79c61d61c8fd3df263f9853c0cc1f766ebaa9132147516d5bfc4a799383694adbb8e3cdc00257b672e40fba25cf83ffb63b1ed192f64596d4bb9604ad066d910927ad2b6f95bd2a2986d083a6bc6a3e1ca2202a71", "cbc45d2f971d684010caef1c2d6124f56be8dfdcea7050c417b0a26f6058f6401542f8cd4cc9f81a28f4a4eecdb8376d8fd5cb15ca6b2bcafe79e1e209f55c16", "64"],
-
-["0748bc683d617a4e668f36907eee655e454d6c19e43f6309d3701952fa36582417686a335f73db6768aa81d446debaecf52ff5f3f72174b43df350734b2cbd2298768fd833affd7eac3ea5cc32a3d174f952f99cdfc289924ec1e3bb640aa8cd5119343e013e67fdfcf1ab5f8a65325c1a6080fbe4492a0bce83dd148c", "2b0b018d11c1ebeecc2a1b5bd17e3be5b87f577987ac3eb733b82b00643aa4d179a6a350cc7cf42537bfde8bd7a905a70f9d8f18d05d86cf187d7d21b918771a5cc10415c5bcad89596f9226dd13522180af2bcc1c476369b950e640536830efdd446d0285eadfc33a2081536b24eafa129d73bcf2d29c2d1c1aac86b89398f7", "ee313f4f6c8ad4764f81873d4baa3e9f9b7f6481904a01fb1c827eff0314c1305cdfb0595a4cb8a6f979d9b7ab337e38eabe823742b89c2a1ce88c5260896ac7", "64"],
-
-["ad8db0f165d0ed6e9495e6e53ea1e0dee4437cc156db2e83c999f084298738b8b1ca2706b82503edcebee76d0725b92597925db99f6e2876471598390ddb428c5d4d0b1361b99b271af00fc0b04aee9806eb5f8d924599476cde9a1ca4531c30d99ec5e107949e62a1b1c6a5e018687f5ad6ea07d667673f5f7b60f697", "b6d8212d828dfaf27a03b79f0cc73ecd76ebeeb521058c4f317a80bb5afd41dff5520e525358df7851469bef2c358b2a97df0f5c0ba68f985fd8b5369831d97802a1bd6e80507b1620e0bbc8f2f229d11beb275f25f8be9d994fdb1ed0b8b87f064c9848b07db7140f5781f20606020a29979e84160302a508695a3ba99bc43c", "9210b074bb645c3a36ce46f9921de1d6cd8c9f37829c251d1d999c193dc30467d44c830084914cf19f4ba761a0e774c103f092b51ea25beb1b8277e99fde26a3", "64"],
-
-["484babc2861503d442448c3c43d7569a380eadd9eed2eeb4c651997867e9a80bb0471f2df0d0e5fe5a3fd2c0b39009df4e1e882e3a08fd74bad8db27f567ae77effbac8fb8b38c17e4259bfd3a450c75b1898f3e984a2627146e34d3cf44d7b9efaf786e4587a127c73c0e7a41bc06644797ec906e6111d6bc59cc035c", "b04bb381f91b8b64478391d6ab6ae306e796020418bf917899e85a9423ef924739625eb4c39496e2f9f0c8b5828e801ddd04c68d017fe9af40127b56714d9db44e127dcd1ba41c2b890155d3d9721b79446002f09b6900e42bb375cbe3806a19b90316b34973a7a7bf5d3f1af83e3c82e451bc152fa66080207451ddc1b081df", "0d73f53c10c029069a2d1d5733bc6be8a1e94de821573daa290fbee3f897f44297cfbd515cc5ddb0df7bf44ef58c446ec085c32c8d6ef779a5518e63328de957", "64"],
-
-["c4e631adcca6a4735c905c369c7cd44d132200b362f29a0a3240b7e06ba49d32a6a1d82ee6848b3d828f05416657941b7c6b9e716436edae6b274b4abb0f4f4bcfca760c2a21753d49efce2bd683a2411e46c6cfc59d190bb811145664aea2c026a832cd02450b7a97f6e07b52c7a5c7e3faa156edefc3290248398cce", "e8ab6a06dbcd52a245152d3851ceac0551d5c3780da0a44f5a7fedfeef222050755c5550dd262b6af6e704ce3ff37215fa51ec2781c1c65cddfd60e195409d5ec40596d56e1190ce14b32cb1864bde73f909a07459f7e8a8e13ad7f6e895b3ddfe2e975d37956915c7020da9cfded866778548293d4bf27c8dbdbb0391294b17", "8d9725605aea94c3ef125b307f7acd5a906d10b4908fcd21f678937cc4605b0800af50480ef6ff1439cca60cd1986f04a1ddea0abed6c8dcb9b485a923288c03", "64"],
-
-["39cade3895b07abb4c100d2ac97586069efd1cfbd35c41097e23d1e194439092ffccd364f1fb7d0477742de251ccb6d8014ad00c22ef0d17fbb30e675412f6e188c71fcbdcd80cea0fd29fe733568cf8bbd40ebcd497d966f9024f1dc119f6a3fc432a35ff4d0fb33cb3ca01161bf1adf3233fd2925c8d3c9f96fdcc845f791e", "ffb6b2071ade0ebbecdfe6dcc8ccd52faebb66a1281b1c397248b64cdd5db0f0754a0db2e226548c8cc6d911038e3deb9d87388761b2960f239c8099f133fd9eaed8b9adfdb5046061511d90a261b1c572e29ff05064fe5dada961e34d6e595ef1893c5f33342521b1a93cbf6def38a574f32244beda5b0b5696e092bab7cd18", "d4080ab37e7598c78fb4c5e43e95483078c1e15e2b015edb30a5dfe3c739e5a9", "32"],
-
-["12dd35387a7d0ccbb91f4fc9afb087a3d849b46bd2aaefe719e44ee4165508cc58d9d97b213fa84f24fa687e8d193cad427adebe683a41e92a6f750eb51acc4987573bc8ead702d9bb908f4770b3a0e75adfa96e26947585aaea0f20df83e30b29be21c5df2d6292074a8ecc6165fd7aa8e1dc2414a0d3e523bce4c199789739", "fd64d1d06d7b92b77e33e39997ecf3fcf674a5453d7d36ce2d4e2138b1b83f031e4c335bb9cb05357ade0d9b0f07134424d8b468e26307d2b5f1813753f716c6fa45a100f88186ca71d496c15de333cdd001b4f97ea20b827e13a42d4e6991de18b8e56733855d9eed7d46b288679abf82576d9f1a2487040f1d53c3abf8f873", "30871176e17a7e99a6fe06ed5d679f1f07049ad445ea9122f736e4f67151aa6a", "32"],
-
-["ebfd4b4363dd0f062c8c269929b0a9815f8ae7a3a9d9b44eacfb047c0e56b855e3f6b7f56877416ff42680122b6360eb754aa18aaf922b0920b4a14bae50388251cdcaf4c94126fe8c90ab5903d7159cf50b960244dd296ed7e123acd7f00afa1ecbabc7ec0067badc12cef617d46f9dc816ed92d0835684bacab128f1fcd6c5", "4abf62e4cac5013498615dd3de6310c71aaa966bccfca56f7135bc20b5a4ffeae5b2421ba3c464e9dd63deb7009b9b9468a00b051cb87304b8548748aa803ac7a17ed57a43ae46601fb2bbc5e2ce70c4cd95c50b66c294de6e64c15f143e130c686d4289124b51a044941d86edc7375f30cfef0514e3b1057dab95eb5ba431c0", "6d537fee2a5a8c97fae78299bb72e2b86793eb3a6937a2708378ca01d6c47e29", "32"],
-
-["856c0e585b5d297fb90a0f44480854f8e15c36f2503480e258d5af62f8d552ceed8ab75678c2c35161b422d43c37a184eb32d7a864540b83b50552e0459cf8ee5cd2214ee576b06b7363680a83b0512201265eed0df74c3ffeaccd17a760e673da2c96692be2b874f6fe2a0ca4d92f79292012e8d1850dbf3db4682ea889e506", "5f419909012f911c154b7be2774c44659831fc422d282dae29af6cbbe9f92dd8af93dc97524729b43441a35273708887642fdb1b3d25b88d1169e55702eaa973b20fe11efa9a50dcdfd237bcc1e125ddd67b1131d7c0a681f964deb21f0dd2464efcd12661f0af9efe475fa9c867c46fd65cc50c65c2a093c9e68052aa285158", "613c4ee61a140bdf293e8c1136ebf7b350a6532ed0238bb21776828dc82fea0d", "32"],
-
-["00bd4db7da41ca61ba441aa9778eef4e608a1e9a2b5f3e9f2e5c72c1d299837444db32476db9cc2225fdfecba34debaf380b7ce03e79f75a4b21d48aaa74cc110bb132c5790886f88d0048fee1160ae9c8a6c9973c0f7def1813ef67887ccbc9bd05f638e577216e5a139f47193073837725aec70f69f8e6fff96c5f54faa927", "913aa422a59507de05f89840e0a3262aa220a121c8cb87f6f392a0a53bfaeb7dc562d881988cb70fac5971bf44d4768e7e72f63c299954f2d65ed8283293a4c69301e989d0f25aa956f3e1b978d791ce07ffdde323d9077c50a7db8acd829c8f219e3f02f6cfd7f52a4c51f524b46132644ebce05e9588dc89f41093b3cebd20", "b0920234c63ae297b519fb548fe5e47e221a5cea67fddebc557f18575b869e51", "32"],
-
-["d282396db948b2e4f3057fb12ac64774cf4b9e00bc16cc0c17c2cf2c7996a3004f5ac0ab8169e5875208141888f6dd3ecc45e0ae53678ea2be8c2fd03f2c2999fedf7f84bb7598eab44342f3c9bbf6c1de405eea8138142ccc62ad5c8d69f6a0a5ba7ddeae935374e15d112a363c1964cce41e9d8caee7100aeb4fb8ab78917b", "2fb3b04e1f5e7fade5abfb52efe19edd2ebc80181a657b85f7a18d3957497fede1fac453500da4a6bfca9a8523d8fa0119f8d6f5e2f42396abd1184a124cd7bee7854f322ff561186fa541de27a220089cac0881da2e0733fa738fd5a1161d04c9ba1996c4fcfd2b7da6ba04022558193f3edc650cfc6e856bedbb810a8e99ea", "48915cfa32566c5047433c0a69ddf522bddc205dcf336fb2863a2292fe0f9c00", "32"],
-
-["671367d3011146ebe23d97b3944466dd97fc408c0e478d5cb93921a3237d0df61df4bbef6aa03a2ebcc1d69ee03decf93545fc8f6236004348bdfcd6363fd7685c78693b8362d5bbfb5819bbd47be3025155a70699bceef7b0f9f1ffa62a66c90ee59a40b21ec099854b8893d9c5f4fdd27268791ba10081d73525a7db066321", "dcf7145eca1b1c32d1be48a7c23d179622d65cd480b0345a051c3ba34d1b00567520b62576172cf5b1a5ff3fcbb20eb6ba252e2a20ee1facd84abff3cf427efe167512fb35186c68c13bea78314eceb8ede3eff2a8b6ae51f0ea2cc70395baace57f91a8af27c4d22a0772199c1940e4e29a06f5bf0ec9937484eae8911b9ca8", "46a100f52104b12053e29d99b0a2f504d48e37641d590556b8308c4872f96576", "32"],
-
-["10c08a87af4ee0cd4d67f79fd3231b690deba0840081d15b7e4029cdf7a6c6d3920f1a637cc3e49480335e02d980b874443dc6bc5976f6df1af18476cdb3f15407c6b1690a3a24e6506ac7a26881acbd013c278345f1cb9368d0944080696637976eb662feba870de5939948292cdd8c6d6e2ba16cf837be8d65c2f3b9cd0dc4", "485a200ef7d07627986786ae1bdc734e4a61ed0109da9ee0dc4bc43aab911fe3c2510dce1c2ff4dee140e0fa2dc16d601309e83be2bcb177a642e3d4f2773739dda4264c4baf4e3f6e8e17ec8c0196250281b2458973850bb8a205a9caece317a23cb3ad0cb84ef1463b74aebf05b6461eb193488668e245185570e0ba58e925", "ce00915bb0874fc3affc15c66d336116edda115e2b8667aa7bdc7a4125888854", "32"],
-
-["41ecd9f2f9206805bd1ed2de2f0c74f53a411974ad58c3eb2883a9453c227cfbce95ee87bc4e751cc42a4c94a101bcaa767c996970260fe4a7c69f2846ed646feccb9b96524662d12d4ec09e566ae6f72897b4cc29537fb0edf512d3383ff2306336e7070131b78ec23135e497707a46163d738521069cb7c2b70a2d5a43afa6", "b20315814413cddce84df335c154697174489fd08d9b15681e201bd2a865dc233f439a1deab33ba749e3057b7a8aa61f3c78771655710157323bc1fecef62842d5ace6a708296874e30d9f687d1f04a9b1e4d9fd7b71b13ca47d3206a2ba1ab1ba668085671c5d2cfff14a6494bc8bf718b54dedc5422c00bc0df850ed5ab958", "38c958f3f6f43943c8c1843814cfdf6b58ac8e4b55b8e4f348c58271058aa3c5", "32"],
-
-["d465051d1fa80fc5114ee42d64fe23c8c77325de78c72fe915f2ec40b08e1af221dd83f5a00c2f8093b9d8b5a4b49e831702fccb5a28fbc1dea6a59626c1bd541e85ad09ad0bc0278df8912b5b46c47dea47f397dde6eb6a753119fda7143f5872676ba06f2c2a83aa11f9d7b03dfc7de948f38c288b69ab819b2ebf5b1008c7", "600e55b75bc37ea69641bc0184b9ce9e76ca27311cf49566484f202df67d35558add045d580126876963832d7c5373584c34238f11e83bed4989bd77b85a30acaefdff5d88e61cbe790fe8be8bed5794e0a44f9a3e77525fcb825df714ab109654d60e5ef458a7744eaeb559b670c378bb8075fbaa8724e354a2c85811581b3d", "2e2b59d7843be5a3e1d815fb12e806864b4ce9513f51b96ed2423fa3a1004815", "32"],
-
-["6ad551d1e6989f5f14cb11316d9187b186bfda6891487a1427f955fb4566fb80942400f4ed38a2ae1cd0ac5ee4319b9e44f1fecaf9662312445eefcded100e1088534d80f70e436430f037ff41613e1c91b8a4ea68ae8d0ab112dce7ffbf5446cfccfd1e0d02caa9d99378bb8a08b953f37c926fe6080225f77ee4f0825a1648", "0e2bf89372b47fd8504f4ecf7fc90a5ae6bcfa6f346c601bd1ef0ac7149124bbb67650e08681f9ca5f75e3f5575a074f3a239ff7378d7d45d84aa00a385f091970cbcb065269622d5a1745f6e118bf5894b7fa9c252d5d6440c517ebc13efc8e2471918c3c34620a287d9c5a84cbd77cdcc134e56614a880f0a897f317f6a523", "3966ea95938b8bb1a2dcefa33315bf9bd3de12370818ec4703fdbbf52bfc6b6f", "32"],
-
-["d136f9cc2ee6d4be1349b91ff6c9fd47e31329e9cf1af03a2a5f6456b37169df90af69e79e55491006b79cf111c717bc3ddd451e90b87c672cc010eb248dc6a88ead8c29d9a267aa612bac7c41c573ab687049dff0b62580abea9e1dfeae5a0ff2b7ca5988d514d34d2d51882fc9f95c8f549bf2971ed2ccce6774b4e9d90c5b", "6bca8be181694dfc4fe2721b8aace6891f8baa52bd077b56931dae9d5b345fea9753ca931a90f98fcbcca0d1a69d45d4038ca3781b81510cc87b9fac8c84c1cdd5e52f167f964b729bf844636fc63b99bd49a5c349ccf1a595506a6aef815e3cade88013b8618bca47d02878ed1012fdd62c78db4ed2a3488204d8818b118060", "45cc9db51d4a60e98c24451ee30531fc41fc47b118d3bdb8750659d8ec05344d", "32"],
-
-["475678927334f6104ced3f18cfda5b6063130d6e0ed8ab12e92f2c34d1cf83cf603d2c3e1b3504b7ed4b5d15d5d252dadbfd5874484416bf160977cca67cfde1b1bc58c0813f372c61735eaa52a5f885ff232f14f39dfb3f0ad137402568faa7221a24a10c1937798ead695fae1bf769b8ccbbd777ab019babcf9719b85a232b", "3a606b4770adf9778a9eac15cfaf182eabec2ec3a412c91490f1831f0514cd6312a43fe92c8a2c856bd47a5af75ab09cbda1519a66ff69fb890065471f0927aa383b5af5ed9a03e6966f59a93debd79e520c8f5d9fc5c1f87f59ac804b08a4ca16ed65729a27a7217137f30c89a9b4bdd2f7911639aafa93dac201cf71586c4a", "41d9552bd694baaf36aac029d04fd84501d7231f60f28a1e7dda9f418928fc28", "32"],
-
-["9904994b1973c4a0d161f84d2f5729c5ecf9fa6de18b2f491cb465d43382002fd8af667eae236b905bb90bbedf465f1524d8cab7d53b747e74c2f9e3a743621ed364548fec15875d8c5e7a5d425b65352c8570c1c83222b507601c84a3ae0781b3abcb447d5301508bd7acf3019d236d2d88525460792236fc163681cfcad8a0", "8ca55de0457a2202c584a0f2fd05e31609b6900f585b29ec0e9a8d9da60dbeaadc96e725caf39edd519266d4baa678f39d7ac3fd020531c2c7c02c9542b461ff26d7c3b5ab98d3c8e9b91f1c855d091b9062caaaacf0b75c7152c2e60f0543d4340ff97a30924b2c57a1d4b2a6521ff6aa15af009e444324d4fb587a5629ee8f", "07ded632065d67a08e9c046786308095dc1002f748ca05e52e25a309d12bb546", "32"],
-
-["0a7e6849757612d578b9a9304303099d9f4d5285dcf582bf0a194a0e6c431aeb294c7f85457aae07d0ec61195d38e1a9b65264ebceb09a9ef7ab8392552983679379b897d47e8bb7ed1085f9d19f65f64c196faaf50e06c05e137d108d642b67b0cdbd860ccad06d37edc0d98780ba0bca6c19cb3759efada517e193fb3c3973", "8cb6c466cca91344175d7cbadecd1d443cf80c6b2c3f3344fa0e5e39f7be920b71baa118e2e1fbda682cc7b1759132e2c82707df9c8a81c91f815d3325de32291ea60570457218101b150410147f078190e29fbe0c1358f19a32e2d98e8d66cb7bc21af75e2414ed18605c60226144297c70fc8ee6a061c5536916b49b93d6fb", "e5ae5b5c9d5978cb74a8eaad15329f68d86b55fda33c202f21d3df9de6b17157", "32"],
-
-["d86f48541803cd411fc34aceabc78c660c6c8306d8429944df25429161a9d4bb7fbc9ab6f086239bf594e0f18f36ef2cef2531732e69a2a63e7aeaa021a62fb958b22e8f9fc48a07d781638e44722ba5f8acb59c8fb08f9d1d9092ed6553b76f4c9b2d6a8d85a88badd1fb2e3243e9d91048380964dd083264ad6301967915e4", "3c283badcbc4cc09c100ff016d7e3c652fb2786a379614fc8f9f01555cf029cf61cf0af6c455a4e2156996c48cef84be923cbbf883cd18f0b3392611af658688c5f79453c60d479a0a2e5943b581a8c1393cdd2c1c604b97fca41a9ed0aea43e70891fea58547ddaa83790a7709c72152b9b242f89b5759a72c6252347354b9a", "c01a4e9e331774e549a56d23ce4959249d6a0db06d8c42a0415a99697c96c23798f8c1d978fdceaa", "40"],
-
-["0417b7fdaf3b2080271415685fd8bfc47c2c057137f20c8e8362fe31705c0b5850fcae2318703240090674a68f89ea8669cee47e0a8a12113f66475413567ce9f026183106566fbef027880795f623604dc9fa2946284845252e88cef0df7f062bbc7c914195dfe2269fd8f43bc6713a7244cfd8b273b4137ddf4906d0a01b97", "bdb0cf79966248a68d3fa4f9a122e4c1f1784e7c164762f7b67011b756312a9870ed15b4c630c386f5eee5504d0b5cb9e020f1bbd97d47bc106a0dfbdbb3782e2663f16ac6cf0b420ad6b7339368bb40664560d940076b012a634fb04caca1760698cc623d470622f381836b1b40e89cab6e029ef2efb80e2ce23e9e54248cb8", "4f8823e76cf5777c21dc05ebe40717fe559fbf9cfa8274cc34f742962f6583c7a92b268e5f6d76f3", "40"],
-
-["34d7efcf2202c778d9087283d113be98e6181b78a30dfdd5c0568267e980553cc231d250275f6b5db8c14af4958337cd663ebd5c9f2788a402871250656b7f00b59cc5e13bd10f801e2464e029c383cacfe812646c0bf805ce560848f459df5f15ff0faae5c07d881319076e16396a3629fe63b9941f159f2eabab7620b23e02", "4d5e7ddd7a9bba85a00812e19c23d888f9589b5f58d1a80c158fd0ad27ba6cc948359e865776d91dfa95a38c87a2998b253cb4c02e5fcc36d87e5a0c15d2826ab7f151b9d8a2431479f008f9697d87456919245f8d933eb3e5cffc625d194a37e2b91edec6c800506642c9920a5cab9f0c1771a33d15086984ab3f17c839cb19", "829ef13ab3c509c4e0c833bb14d174f166579a19ed7618f32c8be69a19797bd0da86b6234d8f1ea5", "40"],
-
-["e32c7692015f78ffa00e00e4bd98082144800f0bcc5b6c6d81ede1cd618f73f0acd379a0956bf4ac3e471e31e3fe4618566fdae0aba8c69d3407fc41b09e541bbfdc979d996ad662005401707369b5baa5e454e92d4c5b5b7f98f06c1b97bbf5048ff7137425c92ace7cd029d4a1fd0f1a27a5aeba0ed4ae4aeae5d60b404f87", "01a6f9ee2e42008898a245cdcf2a696729e324c3adce420b2f78ec55bdcf291a3a34a5e949d1b05c6d2d894c6efddd41fe3e8b2c9c80aa59b985cd9f190a85da7de5289b7f50d11dbd85a87b398b6421004f0e800af6070762724c4e8653f99411e00c6b97676cb2d1d12ac4fa7906ef783f3528bfd861fc7368f2cacc528c30", "203659322758826f2ac7e2dff082635017cecfc1035f254aab25d3a3f05c22a1eea02231dfb0ea64", "40"],
-
-["668b5017737b7be1739ee8580d110cb1f3cdd2ba0a33e010cbe71b92e9e26d1533c3c65abd66603d3ab8482eadca1ae541240b52745b2dd37be6efaae22de3ae74e3c5405a96b8818784d2c353b1db3d65365202d7dcb13495e9f374063a529eef9255b394320a1720aa90fd46eed91f29acaeb279ddf499df9b2dd99c32424d", "7ce7c4013b6c2e1644b855c027ace60996becc436cf1509cef0585f252eae3e87f7b32391369aa4ca97558095be3c3ec862bd057cef1e32d6237479c81d2d03a3e1abe5480e6e5824cb80b7f4734923876d7572349e5d9d2d8a39ec86cc7d28b412d32fb8acb28e1b6e737cbf2879a18ef2a284fe5b57bb5d8b0511e1ab23203", "3eb6f1996b9bb3cd45e97adbe15c7c53b882aef08a0776c6cb0a3ec2676ed4d36ae81ddc1cec1d04", "40"],
-
-["2e78d82d417e5169dcfd1c69f5a972961f57248e51b37f77e9317294f046f315a0d79e3423f29f7d9ebcd36d6eaa2a3fb2f4500309478cf09dbbe8fb600492a995ec822193015d85c58a9982f6c0e326cb3bbdf291ab6c3139c81188a18c16cd169fcb1c161351285a51b8d0145364f37514b5847245e7e1c84236603d44462b", "706d477b089a26e9d90f4f1991527de17724c128a9b4ddb4a812ee3d17e64fc6c062b92dd875af00844219929cd02f4eae03984c46cbd3226c9bd7fd6f1df6f0e124078be3e2c4b0a35b71a22219bf5f3b21ef30e1273a7ea8fe71e78652e191db9979beb0472fa13c419f71ad95f5bb25680efc0230da6f62230524e6eed652", "9c1df6b8ca24cff2b505fed7efd8aa1a027893e286d8cc4d598478c2e9924ddcf96a0737e3053954", "40"],
-
-["4e9d424c31a7441f6d16d41caafc861ab8fe3c002d66ec7d5073a3f936f3cacb2b14a9ac5478296b9287dee3a809442ba1f4490c6c820cb8ca87e5b86a02a52751f21f1e806be76fd1e8599623e3ddecb6a36a1ed85649dec25f1cd8bdf11ee889ddac8afea80592f4e14e1d61b1d9c7c1b4a61ebb072a5701a3504078e9be76", "9a6fc18a62f8c9cbd62fa8e0b960c9a1514453a2b4309507428da79973884e19d30a21d44aebea77a1634f2542c1b2c73f5f55a396d5c231b46d6a2937d78ce1f81e58d649db55887ba9008ca4d8b6dc9e66d371532a1c1438535a711811486d1392afb9e630d0a76de9023d660f9c897740153373996a59d927c0969d9e4b64", "32d1db1f537abef5c170b9d204eea2da1bed58c990f4b720cbf3212474222a99c1c518ddb8db1c82", "40"],
-
-["860880cface9225afc6c889d13c3e983834ef984316c671dfb92aa7d9e9afd82d75da8e3c98382af9f9ab4f67266bfc56d78c1582f02f48c70ea7793e81d51eb6c26b3915bff7d723827042298f412d03f2b2f7a86ce1f5d0035acb05c60a6918d8c274029041b8b0f3a00894d16eb8b418ecc50fe77f278f09bd23bc40b045b", "142875dfe08d2b5013a60a7989afec181af08c506f40f54c13c83a25cd0968062e21b98e66490a755ca38f73fd33c65dd32aa992da6b28959048d6fb4c8ce388d0bf4a9521a54bb497081c9730b7dc2e29cfc810d039b11c9474ae605e950e3a248c90bcbba8d755ec5e18aecb4616d344665093f7b3782946d5281cab2bd163", "d5d40a0d99b0f1e52a9836896b27ec719ad1eda82ed80f6d2300d885b9b9813fef778c04714f1fc9", "40"],
-
-["a31bc73a6a73000db291ff45b0ed2d36b2a7f91f4b1c1f60b0b82ae7620b37ec0d1efddeb0070a9dffbb4fe90624df1b0e73f412c4b8337198af310864dd358f4edc409aeff44ac9b5ebcf3f851d8a56a5cf8191dac041d3cd016bceb3076fbd2dff98566d6149fa9bc886307701b5513f61a685d35cd2ed1754e02299af6edf", "f776c8cf705d589211ff2260640b2ef1d492548c1db151ca006f1b9c3be8728fb8acb84f0562026a04b16f57e460b9b74fbb09dae5391085ea5d53f33bdac61987fa9d2fbb405f6bbe4d3c105f00a0a78ce1088cc4c015adce99de8facbb8b92cb2920757c41961ca89b3091be340c4af1ccf9aaa18c88c916ec667874d4e880", "517a096bca0ab90ec251347661ce9bf7a2d001520bec592439bc53e32cce7d36ecb2cd4fa9ccb416", "40"],
-
-["02257ce15f10f124f867c93ff6ba295a36836eeaabfeb725fd3aa3b976705cbd1f95d4e8262b4e01835c2196db49b8f1a5a0de488b6c3834133213647e396193f129fdb7d2d8377c75608cf0f875b5806bf5e42334c14ad5b2ccdc47217157d5be0a81b82bbeb27d149a94b513e327efd1ab08e36fa3f2f0243085c927c1466b", "25117774deaf7c068cbd4ce82a595a584ecc9dfd541ad81eb9d71f12c53b97f76d797da7774d6ae8dfd4d5e37aa1d9d8d90d380f70cea112f7cc2e19113031c62cbd3012a8632e883b112cb194ccf0cf3efe05cdc8100377233a11e74b9aa73f2984999eb91a514c681c5b38c00128a546460b20a30688b503cbce62bba482cc", "942bd05f304ba8681912892f43e59f9d0ef11b03fea2e97135a9c495e945966b02022f2409c4a926", "40"],
-
-["82d0e0b45e5f0ce5ee8c33a745eb29ca085ed8e581dbee3cef70d3e72084de31425fc83ff7ed61f2a7be810d1a52429b46946c8b65a4319e4bd91b83f707424068fdc2d3b2526c195062bcf0bc930b983ddd066000895f7e6b38c33eae280b5f9bb3b6b9b7189724917ecb965236ee8b96418eaa7a12e0a5f694b2616e6fa157", "c21f5b610ef4f0a966a3ff66ae8e312e741d23bfd30e66ee970dcbe1a185dd7e8eb5ae239306d798ab9cba42f52b0a99570c8edc689021038d94fef562553afb5b869274aba067cb647a07a7e0192e6161c9cdbbf46c09cf9b138745aacbe7c972ba8ebc1d6aa92ad9646efd3901a570d77d68c63f4d273b08b2ef3b448febd2", "e3e1930b791eb77a200ab551a591b972e31d1133856759f7a607fe358c46cb9174af099472e11d06", "40"],
-
-["b7e2a930f5ca534e8692c4dd752df57f2e6a4d93e1c51fac27837541e8fe0ca67c3d9f3a10c768e34cb9e5b73317d8fc4a609153fc656f303d5aa58d6e2e3ae08018544a5d73b51183cb8650f23853ed49ea58cc2c06fb42e8b199cd5a6985020d9c4ec6a27341c175ef2f107d38713a743228663850c9cb72adda677559f6f4", "71695a2754c18a349be576a7d7afe04e70741a42ec376cbd6e1db62b7d21568407094cd8a6999c2c2106f48436ae8f41970559ad32d354d5b59d3a97526064fbc086eac782120c4d6b1ed1886287ed6763e7e869c6c37d6ac40641bd37ae857aa1c67c197094a140801353dae2a4c269dc000f9d3071af25a14211c5b47380a9", "395dcdfb1daa4d0ae503cc0e218e6300fdbf85128cfb8f5d79c70c575887faa3237d88f30682aa5f", "40"],
-
-["1c96b62131fcd9f823fa9fa37263a31bbcad912c30973c34460eea28ad52f2d785558a73cf64da4d220855e09f2f48112787b3b5cb2b59548381132c236269798c7c63f41ff59d9a78519aae58965a01e2ae2f710b0cccb04c939f4799b361da97d5ebad0af94b678c9d171685e1017457eaac69d984bed0070b377ef88b0180", "94090f38c01096160bbf1ff92e346380d8b4a3c05e93f443ae54db508612196de31fc823bbeaa701ee26b3e47b28f775ef91a4f2242e6432271cece37a30d0177c959cff25b720f5f6600bc03021bc1445f454598c1f10ad385cd62c8b360d6f47b6b252663fa8fb0ab75018b7e75a67c025db7cff309fbfc95bc0b421ee777f", "85052994aff398ecddc38e1da6eb3cd326462290f007ee3c77273337905a6e47bc6d6766acf7d1b9", "40"],
-
-["9c18af3861895790a70a1baf5042161076364c785c6a3e36a449bca9c2c7fb04b045e74ee3b5baad7ffecee3c7a2af19d79e75dc95925165e521651d1c9bd960b8d92b814e69c60fc3ecd757bfbb5c2a5fd17de12ae5209d47d6b8d4ed4c5d091a9d22da88eda340dd9add1321d743cc76960e8f03acd469322f5cd61b23b186", "305d9717bc532c39f7fc633b38a46b64386fe5a0c7403c80bdda35813445c791f4742ccf2f2438936f07eaaecc2241f3627350f602d89513282c87b22db36ecabeb877a48d7408e2463251bfc5b1249886ca06ac5d5286206742cd464015771e7672a11d3e0b432e66c5c1f78b19bc5d8cba2e4a3e78b6ad626430c3d4358996", "ade6d0de71a801126e8b9d665d4f47dfab6a6f9ac1ef8cb258cf03e94f2697aef912140a76ead496", "40"],
-
-["d9361e9afb0840ac9be5b17ff2b3133c5503fe2cb68ba2cd6dd6ad25393a6f56810b3b73e00e1192e1307b220778b57f113b10c47b8f98e14801f5edff8b8fbdf4c0f6b5c84a80de3396343901cee6535b08a41a48bd51ef747c90fc2fdfe4c8b8bf1508bca4d5b91a7fa36be99cf0e0bcd280d524fd142077ae46fbd020b4a0", "0266358551e3b84d465f78b16753d81ec0aae7f088d4292e01169b74a024f1306ef12211aec626bdc092fec34581f6d084cf27250ddf3f489b8785fa3fae176abb1a76a82c83455cedec4eb8b733c6ab0de6d258d7584ac64df4bcd916d2b481b1883a3115439d9394166f7a0591d1182296f1712f68d00fdd0549f76db64fdd", "9b8b2d10a2faa0208ee3043d2a0ba3132bec4deecf8a5e201bde20ad9de823b2bce29d48e0aa83e6", "40"],
-
-["3d8e7d7ec30c16b7472ee0078b04be96a98cbe06491ef0f8170779d17575e3be6c93b7f5e9f44e3e2635e4b266deeec3e58aad7d0f48040cf040877dec9ad4c9b09b260f6811272132f20642d340ca2eaa2fe65be28a0d7feb5347522aaa4595efeff153a82b4a6755ac6f3e3cf0f9dedec58e2729adb459ef87e2d7976cdd2b", "1d00e440026f7efd6d8864fba48aa697e6c91ce04e015f93fbea194a6e0c7ff033d51b78c4e9cfceaab9170ee5578ef58e89b495bb1cee4ca37acef6037f9562c089f603cdce1de84ff3f0eeffda9535d0bb34d0d376fec157276e2454fbceaa0a43fe49e5c77132a45d7804babac33ff48724fb5db897da5f19803b2a2933b0", "3c270df99f8b2ab166fd29d5fb347c7ae5daef697fc20b408dd6f01b15c713f1c626442dbe5dafe3287f2e2c8651c332", "48"],
-
-["ed8fe523ddc1939262facd6d722b56bff66783d422e83756025311b6563ce4cda0ed68c47b632f312e9ada82810b8c9fd0729fcb5dd496cbeac1534b88d78b7daa8776037d1ccb0f7cf8ebc93f215cf193fd7ac5d05a692567b14ef5bbacbfc332e578a98bf14f75729d6a085177affc6a917c8a238198aaa0ac6a7b97c569e6", "c0ffbe82e2aac87bf2cbaf241643e00b34ac9941aa3f435f40f402c75aea8a2c730a3455c6e8511d4ee9bebff1abb950f9a1f28dc3fee5d7bbd5687c887e8038833b79fc6e1b36ed631fc5b00a9c36e50fe0aef1d318b7016272da4ca67e7098dadab5ff400e1ef317b5ed80c8de02d160b1f4f6425660e41e1281bd1db30152", "b94cd94b82f7cbc2a992b413499e941c0301ed8714c5e07b3badec2be179756f996e338025fb35dc729f96717c5ef8b3", "48"],
-
-["732957d1867047f2904817b4f559649059870d38b2bce77ea2e8b27205464ccbc6e02589f655f3d81fdaa736d57f9fd88fb41d4ab50bf857fa3f9128ec7609b0c9c3b14795efc29469794fb10edb778acb0fdf867a52606ae128fc7a40a017e127d9e3528ac51df0172df76bc7f5556cb4a23b342a19868000a9dbaf294cde26", "aa609dba1271d02ed605b2f3824d3b75e6a3ec7eb1ce069f4282fbd2ba27225d56dbaefafcbae134135d7d1498a07cfd6ea900d5f6268d65e0d57603414a956ee5ed3ff9e64277ec7d6d622719f5fc08aec8088f507f4dc000e4ff49c6b17c65009b3900f98ffb1cbd3c4a732cd1225e4e08edf5e663ae784ab57fee71e53d0b", "21c616f2df44961b2e5ea64d706516f8283732986ec9b6a5f43e28f0344454f218eb29500aea12500e86d8f5c78fd962", "48"],
-
-["107fcc04358c79cabaaf3467ba6c0907bff2f6ffd8e6a0b2eb6245b54d3ef231908ef6899c6b963d01f7020f8c9b8864199308eca7ada885b24dc85dbdb539572e83a9b15e7b62e3f18735ca63fcb005e41f98a066e893eff40624bd4522dca79168cfc2a34446843069b3e16718eab4c99f8d613b20c8b859933f1d0e0e45e6", "8f330972e6324b383793647431e00d0bf151e929c6886590377ee43047be19f8f7d1b3ed0df9407914224b05a415c8cae3efb3e34cd1f3a7afda84377952d55336286f72549fd8112a44b74b392338aab13ab23d372e88eae53d4d0692742b2354228529448cdba981e2ee6d78722554621c305d0d06eb01e09185c61a8be111", "3f94388f059a158574ca542ecd2586493fafe193e9e33f25502fbce92f7ca3ecf8df7ec2ce74aa96399d9300c5ae93cd", "48"],
-
-["08eacf6fd640cfa8bc15f77ca18978a65ddf182213802a4ac143bd4015eb148b83f25605948587f481cfb777007a79245e7397efceee044d01ab129af677e5b814f528fa769efe4086073c63cd62d5d30948967e80b613f49555e1c999fe053be37dad1523951ed4f345ee07af4a73fc87e57b98d2de3d87a5c99096a5885b5c", "88dcd82dc6e1cb894bc7fd88cff38ddb13b6dc6d227cf03ad6461b649f5ad7838038f53d5ad1f7186df4ba8b1c09585bf0014c25e08dd736d08ea3a357cda797c5ed4ae7659ecbecff13e091a1b59dab9f199889d92f4bcc3cbdb9798c0c554d60e21f516eaf10ae080bb8c1e760267228e408c294cb13a424baa50ec47ea94f", "c62015a3f4f85cb142f2ba59daf9f1d3b49cc14c87a30a1583974002ad8235aaa15a96e7aada71a3f7f22eae6a4ad336", "48"],
-
-["8fa70b480660c4b14a55e58c3e29953461b167be00572f82aabc13d6e5a2e51d1f08b001e79690fc5d3cc71de7170e092694294029d7bea1e05e6731d3e10618137d59ec7112ce32efea9822749b02abc42e03b7ae8013b088e93e43d81c7764544e2e920d9b255ce7a57f244c7e1a2321c81eadf91ad6b689a6bf965d19df09", "ac098b6812025ef3a8e024c21a2f88e40b95e6545908b0cfa33f10e30966ce49331675749584b16af1f9fcd31d82794f06e8200df5e21c9a118a71f6260ee2eda4a812a4ebd0e973c5b039f234d8a2576f33b3ff2739514a0e675dac9aec910106a6ab219b4cceb52ded2549e899c9a24d5ee55177761888a3be1a2def6aa32d", "dffac17c47937c172b6c514b303cbd7a4003e98658c46538285f3e43f3bdaf370535633977f8118300bebf44e474d5a6", "48"],
-
-["ae88c3a96652d3764a00f8b2d0027adc8a709b77fb9311b3a9a7ba4973df8ca725aacb4d4a357e77423123fc94d6a276852880a3e979550b392f80e3e6c71932ed1a2732acdf13f9f3725d585fd48e04e3f26ef3a82a7609170b88f4345cde552d60412032597b45884b0e90d90226cdcf56cd31e1ae5ef56cc33c4405a7c357", "230dd0f85a13fcef40420552de57426a687ebd6a5918e650c5ba880ceb79fbe40b659c1777537ac0ebe052fe21b2be52a101a948d756065a6793c111c534f66d00d46287def317752ef6736e5a6f522e3c9f839c323a79ab7569437ea615bfcfaa630a91b87b3ad4b08e50eaaf1768c8e06133ae9549a70b9645f59bb8a5bcd2", "3d7a25ec84d04e21dde859fefbf185d63ca270acd9ad55a1fca184045dc2bb6e72c926c2ae3a84a35ce346cf02e7aebf", "48"],
-
-["132c680e41a727801bc50191466ed2018f0301e7c6e7fa50cf3ede29f6f4785f830174448eeccb35158e827a4c19946d34592dde6f3918b753887bdc3dfdc2552879d9af7e8d9e9f2d3c96e9fe02de80231565ef0eea59f78afbdf7a04c80798291bff17b79187088c56a4a70430998481bf13e04b03a45a9b1e72cbf25b316e", "0ca8cd24bc4a0f25a50f82c60c373279f2cfd0b53b3afa330f11bb5d812d56046e013f51a82d6bfe5451c9c5911f11195bb5bc15ae2953b2ee65ed3c8635f407d09e275e3572e5bc9521a83fe8d2d05926b2a2c9658c90c9419bba89819cb6ddf8268c32a83e05c72e27261a5a77f7a01d7089b8a88c36e2d25a2b2cba8924ee", "8447823ab1787fd753de41477da2a8e306dfef910e1476bcc88fde6716e9b724562c7d09c80712e3a49aac5b33f5a790", "48"],
-
-["c05e649d3fe1142330ca683ede042dd4a64ac41609e9d461ffc73c628da44c7e99a03471fefdbc35dc27362531f1b162ae227076c3309b37fd4b33ee919d4bb939d1762e3ed27c41d6d55168b19947fdcfed82e39474ac6c1ea5eb7ad61510e545f9121d2ae6ba11c9dc9f4f8583556cb1b4e2a6a43b71c80bdc4b4e849402ca", "f1567c7dced30cf724c2a2463f98f32090492ec2ebb0d47df331ca72d29dad9a2d55650956b3fd73c4a5b1efc19b49fb63a4a6c0bdd39b9856e8fecedb2cfea5109db69b0c4b03eea60293602b293f3752ea9b897194cbb6f03d9836feabc395847598bb5765c771b2217021e30a7ddd446534a36db90ce0a877c032a6e0a372", "d056eacbba508214ba434d286aa0a1a56406a2e23d92d1eb9899b6896d4773dc255c58f4831a98e42adae1ccf8355d8e", "48"],
-
-["f4c540778b164f86ec8a71e4c468e3ac5440058c22ceb1c8ef20cb82eafb1938237c558e42fb814e79347badb7a9d1d01f42d68eb837f678662f461619aa5f74449c6ddd915a83e7d3ba32b03b765966d0d23e0d197fde7c1cbe82a98dc993273f6eafeddefdfc59e064bd75b99923784e386590ad6e13defb15a7c2ad205d5a", "c32bd69f97a5c6f36ee7eefa21b7055a4d8cbdd14baa5376638f65423c8e05ce97ba0b5e3d05af1f36a957ad3cc7add8704ef5a84b1f9e9fd66f163d1e6ce3cae223f04f90a124e6705839331ec5cc333d50b0346a7488256f1f9510b95ccce50d2f2c7976c04ece82bc3e95adf989f11e59977e91f03b92956989c3f7a5e8a9", "cbbda582978d51b1d033acac746dcb5c03ccf826738a127ac4bd049a67a26a900a5674e3131e91f15906426626a275f8", "48"],
-
-["ef4360affdcfd92d09596b1f758fa236413baa39240c07efdc99863fc2a37496a19a0d89c86337bc5e597102f69206c5636386a9ce26766c063a4711164c853d1dd551e15ae41119871e665468035fb6a373ecbad270951cd7256bf66f273f290bfc13fedbdace0d2bb0345ef26b14cfa872c3b55972cac6f861dc37f5bd0b21", "931e6aeca959828765b7f3d8cbba8397800994b2891d039f563e4ff0ffaefe28cd243ccdfc7bc6bebfac06c785fa7f737b6c6c215280a45a421ec1624fcb810dcb1f6dd41c79b828bff169078d8ebdcc3dbac64b2bf2591d57c22739b1f12ac494f9432b90b9216e6c6e64bf6be0024bcfd9ea589cdbe8909e12c99cad88bff5", "72de2ff4cf3f8e199a9dcd25b54688c0e5067ac6c985f1d18b4825d1a7332ac4547a6d7a87b877d943595ba1e1cad5ed", "48"],
-
-["72ce9cfd27b714419bde4dcd9b377dc840bdc3adaf5a734c0307af128834378b2a6a81252d2f0d371e2af3410987be76ec9d7c776cce1662c7afde0b0a696789846099f57a12046e1c417560b854c7062efbfc2ea4128d3d7ac8fd728000b46f67f70bf57627a4ef7e6cfcb210664383ed1e6b59beec7fe5069836d054bfc844", "84b1b92aae4aebd81021b6cd7560e9a4df97248f85b1f8ee18274a214a73f89a91c8c03628005dcd58d6f208f555ac722b90a3d27b9402b9009b6857f7828e927e7d295a9c0a12368223a42310b8659f9b5ce901ee3ff40b8e5cdfc243f33d7dd33abe3d1150533c167292c9cf715d52b863bec3e699160f4bd2770b36ae4e83", "8b2b909349d65a6b5a9b47c716a3696099c6806d6afdbbf905c87b18b3733e57dcbfd2a081b183716ac05c4ccfb8f585", "48"],
-
-["e89bfca0ccafc188ae7d3cabc8e90e3959c2169eeef8dc57e00930041ebd0ebf2c13c5ad6c7b58d29d45252aa15ac4f5832a3252b8e52f0fa5eee4c0628dc90ebee4c65283249963fb0077abb262f6817e5d2ab3bd640e61deb9261223276301a4f447b8981249e0d0957b58846a6a84524546e884db43f592523e22e7f5a43f", "bc97d32ab16b7cf72c8d9d4960b7105b27a7ae753c801f6eaa6df16f8487e8f9d7f9952add612a3c737a8d4ecdf9ab6769b40fb84109b321457e16f708a58e05ba43e0d056b00e201c8170bb42650674e74d0d6448d0d07836cdc12cc852c8954dbcdba58420cfab10ea6e1926c4c98a89bad003b89b1f7965a21b942a553eb3", "d111d592e3ed96655f6b9d9802198f46467954bf63885a4961e4af40ab9816cf476e8604cf39e527756ead2e4f4d69ce", "48"],
-
-["1a63bd7c8d60023be7cb9b24498bdf07d29cef51ca6d8b8e20872a2c177049823ccf03119bd0e20932caf50fa620223d3565be6aecc35bac1a9de11c0473e1d9da062550eabd8cba144815e57e833938899d7ef2037949b29e54f33ca0a9207add514cc1e2efa756748b2331397dcb00b48d566f7758fca793f6881454d9af6c", "67c52838515ca9247b4cc741064937f802322e44d3e5dc1ed958363850e6f9ed74e6ec78e98265655bc70e4662f8e9a7a82892444d07c5798afd1ff9957529c3c57c9252f78abc14309f7b1e8492191e36a69d91205f4c36559471c206c45ad5a2aea32d411bb71a43ff933134c0cd45f3d6d59314947c3d8245b2fa697b940b", "cefde7818c038cbf0bc0fc908625f4fa5b54c2becdc8d20921e4500d6d4f427db0c3fa007d40102e6b31b6e3d1b8d2be", "48"],
-
-["89745d553e42690dcaca938f860ea01b05665ae7f6d290124c8f5fd99de8b00dc84f02c4dbac6a5034f5d76de6ff9bbe5e94277b46c51664a9816ef9586722ec8dd9dbd7cf52f72b15f9d2682e76459460046de650c7aec22161f7eae0c047f2203970f4e1db1b86b7fd0ce9281aa78355dfb38fdd815f14fe548213fd9a2705", "f05e56198029c2a4ac8169eb68daf7b4f1ccb6f2037462444d1839bf220951ed71ce2e15f77e94bdb36e9a6e9a0c00b78d0bc27488c1ed511ccdb2891ffcb9f4a26eb84023f4437e04bde6e13c3271c34d1e93e97612aa082908da88bf465c876b8519216c5378ef87b674cdb512f1e1194c50d9343041735c10d8436aaee4b1", "5238b04c6a3322a2df3b6211b56b2427095fc957bfad3a783527851fcf88a89ef4cc5eb53a394fa589b2a64701346662", "48"],
-
-["fc5850b25cdb1b94312fe03f7ca6bb315935dab4791c8ba5d2c9997a95fad98461230070b60fb714acc9f269e5bd7dd3c01bd7a9cf7c44f18cc81e6b47576cd26356b65ec26d178d7e9e9323da71ab6f784e2fd1cc2a5479d0660671f9f37fde6abb1983693c60860f766c7743086e8ee9968834566de2c5076afa9593183b31", "85d09be10ad030a576a896eb7c4cbf6d3f6a74606ffb9aa7b519b3475210a81b03b7c5c9270105f423cf90b30608824269eceef89aa52379f91f2d2da11a9ff14b7e900c70eee464ae9fcdf26d6b9073c2218d6094207a21fd24ef7ef25f275627cc05932dc0555dddc2558efa9c736612b25981a59327058aceff208d07c7b2", "7a90a3f4a47dd262f302d257d6acb916ff8192b8d2d4539119fd72c2b7812194cfeef34a8c3cbd32057a7477085caf7291ff7f83acdc1d7b", "56"],
-
-["b1a85aaee55a9b2bf4e2284e663282a95c7e56448938a1357f3f17712c54c6e2a4cdf7ba218d550647335186ad92c4bc9a62d6430c342062ffac0de1ebea861b8f49ce55ed4caa4e96d5c6172798542514d0f6a5a430de0f3d1d33fee21ab73ffa84a828537fe42b663050abdd2d790d52317c135a152465c2fb537118cda375", "7fd12b616030479e3066ee0a0fa6dc7d40b50b1a2ce6d6dfaa485e7a7d1e2d5e1e8f1917bd9f6bda825e4161185f31f66c6c2fcdb278b196aad5115321a83267ba9966f0eecf8f57521b85261873b819d9a31777923f30a2ecdc98edc07f8dafda56da96fda6fb3d2805820018c9b90f0ec089c1d2229a2bf8c3bcf2628d865d", "df5c228bebabdd4d48cda20a869f12e6d44f1c881a28832ed4d5e404d91817e3969a36137f6c062e4c97502fd87f48944808a66be3be3923", "56"],
-
-["a96b6a8aff8fc36ae0ad65cf0e4f403c11a84d13d1d4c3fe64618e8e250dfd2206d1bb0b271f00eda7690868bae2c5f8fb88d11a0462a6b13c0611a7f15fe4d7a7adec2bafcd2201fbbb25d43055e818fd62a865acd222d8f64702b71cd59e533306e433bf22916e84ada55999301362be3dbc3cefad9e3d3a2c4904cdf8935e", "9728ad23a2b44003732c59791383eb35c502f2dae9986cfa2071a541b1c9b8f02b03538d4cf5bad922870f03707cd01b538fa1c925c24c3fa2f3066c49b0f473054ced5b0d328208097a3b6a76ba24f44b1f8850ddbcfd510021a0296f30a2dc6e2c04f4ad139e77702e6616ede6efa7f5d930d5d72d87168224fedee9f1cc13", "60eb5296e3ed5803b13446c35995e25bda93b88b8819060e52aaeba31d53a43f9a768cafe2fc74eede8653b71c555449286a0b399bd0613e", "56"],
-
-["29c6a168c0a4644fb8ed30346ee159ebf19b9b11fe3aae4c2f3f1ad46f364d33b199eb49e2f165921b3ecdac1c99e913f618863007f20e922be35d7b31d2d19e8cb2a0274ba66ffc4c682042acebeb24813fb0cfccb68bc1a945aa44c92c5b3522d9690ae50aa914b133d000061f87a4fcd9c2f935cd4d63102ba7391f8c1481", "5c99ea4dce3f3ebe7794910bffebfdb4786236714b091a7472478aeee87979d45edb42e330ac3fe2978d9727202b575c5d1dc583da10f13852218c36238afd1d0081b273e65db4e851fb7fdeaf601028159f3f1231fd4fa28054c41ec0b574cea951e103d89ac6590ca5f03894d34fa108b04d27381b5e3b62a8d9ada8420670", "68639aadb98735b55c7a0ea4c02f681737448700dbdf350e8c2fd9cb02a94c9510a108849a282bbbb04d9d2ef04407108a68b728dde1f138", "56"],
-
-["e619cb4bb4a96f9c44b267be0637b7704b955897f9678d3b83a774d21816dbc11bdd5620d4748ebd65c3dc64ff87175e55f8aa3851a9e9c606afa566e705fd89362f7870bf1e5134c55412093d4864c33a0c269aa92dbc2a3edbaabeae4961cd1f5758c5dc6f5f084eac3134284248a8e11af54467bcaf6f1272ac5fd6aaae95", "6483bb473b065f14eaf9c951066c573106cf4b4152b47c4b9dd74b7a755bd9c0185677758a905e41e488a13befb85f52f11a3dedc7eb13ce34afe63c79751761265cd9f12f56e80c422c796658c36ca58b9dc90a27a26a540321f5e112120e17359eca0253e1663eacee39186a13e7f0795fd8e29962e8a5b23460b267b926a9", "1f782910a5aded6af9b64f81adc1ae2f924c12ef7ea10aeab47e837e2dd3fb7454d92d90676f78d65c537ab0695ef5fdcf45cfaae2621417", "56"],
-
-["3e5b392c1da00340c8252126987148cf8725e9f538ea58af37a82d4381f9eb3b47dddfbbefaf4b115c57c48e6fc2d994cd8ff5f1e30b876b28605f9ca2095b01ac89444df65df303cd2fb8439d4cfce4b45824c44841b95cbc71c6981f4882e5f6c92f8b3b93423da513c4ee2af9f8e7c3e83e176f7237aa7a432addff9ca710", "59a7b02ba466cd03ce1d50c3f0ca4c02dc4b3d1c0e7b9a77df9eae0bfcffa32117d7e05adc7195f4278c93497401629897a58d08ad7141ea52e0163f14992d7a284e7b875ce4640b4dd48ceedad1ea17d8ab1e760773044845e0899602f1bdfff4d42ab80c0765d1a8bde2ba0a830c050923956d06c80b182264ad19ae4f7c39", "805d0f62925be637b1939592753902b256d855a45e696d7ba75053c790c7a15be2e6f4995f8f7442196c1b84550edb69fbddfee6226af31c", "56"],
-
-["4c74d1ab0049c5be3c75dddceb8a79b8866df80487fcce3321f4d7842e12ec3c6979b332dd54bb919dbcc8dab6dc6b286253d6b87ed9efa83e26a3ff20107dee1ef3775628551089d1ca9e554916e63f8b92ea0cd7d1028663822e7c4e98ab0bd5a9c41cc994eb2e406708568c80118369f2db077a2a3053f7d22102a0f59d1a", "6cc698b3204f51cc47c37a03cded00a0aa7935bb65607652d7a70b9932494e9142720fdcf2ed38d011e0adca56b776c5a25e202b86b360ea053bfacb9b13e1007e2aa0fb23aef88704c4cb11a17c3946c40408e40a1c49c99fc93851494e65dea85811b8bc79db18a7d79c7fb4268f4c99d3b58ef98277172dbb8ed244e02056", "21062149d0eb65362aca8032306973e4dc673d61952d1bd25c9791b9f4542af7e061235b23e4cd369fdf77ad21db66f804ad2858ad049157", "56"],
-
-["dec973a8215ce8f9596fbc96738f561e23ec36d81ebad03f8ad69bbeb2ad65b8ff5ae9cb240ea35698b5e33849d621db8fbb90725ed3f57ec154ba9783243e692ffa70fd3cc19c3a813d85abeff0b3827e3f0edd68d1ac499638e88457d3b48858f230c4d13c7badfc4ec875cba5b51adabed9a3a37b9ca7f1c94c0e97806d1b", "c7e5ede152c50a935e76b59979e08638a09cfffd01ac7008056a18ab8ebf8d347e955e06788ff6efeaf7c3b54bf898f2a10d502247b9b5fbed79a0fde0f761498c8060b63fb805222f8cd606bf69df4dfeab576770598099120fcf97983b4d54f98c3d192da8ffd5351e34b45d9b23f2be605cf206f39a8a7c15eea13ef51afd", "85ea4506f6d077324b20d06afb02cc633e119f4e151200130a96b642985f95ec5bf48f29462c8902b297854428a19b5f5a2911c844b57162", "56"],
-
-["56cacae4b595ea3fb4a8c5b57c14731179256608614c95c9725ddad5fbfa99111d4fa319d3015ad830601556e8e4c6d012d7da0e2c4f60f1605f6e4c058ec0f46988a31bce1411d9b2461e0c54f693627371d8b4596d179618439e2c1458853fc1dc918faeb8596bc3c1a780c73221ef245929a2ef9001df236f331d2d403017", "5fa0402a3a131e24cd15291be50fac77603d8ae53d6de844f50327c20e411542edc56402ec535b7aacd5cb87ada045c81bf98a48ebd8435e27ecf2d286de294140dafac3204caecadbe7b94ea0ecee27f0a9f0bab040e9d77da97ee23ffa023101e6e9b6008480de0152dcb8e87a105a8754a481b42b42779e44b13a611c08da", "e46e5c8450f6a06ea679347b98811bfee1910dd52820ba6c01fb8090d58e5943466e660efff61a7ac1b441ae954b0067165694777d4d2071", "56"],
-
-["b6265ae2d0a6b68022697e2c4ba3eecaf1756caf6107555975d7fc5dbb51f0a0d39f7eca19c277f885e234b2cfe2d61cd638d27042fcaeab683e05876f9dd1aab115ea1d6419c9ff7ae2927bc50652f75f4c848e49bfdbc152013b693be208f01831a4c1f9d333fdca11a2707836e7c3565a8af3be796402fbc8a7ec66481edc", "3250d12c31d11a36acdeb944fbc0e58527aa1959a29a675536800f8e3ca250140e3f7ebcbf725523095a8def8678ae58235826e8d374997e8055789947fbd5d3e47f76ac6bf728893a9a55fd2b25d9460e7d121b569536a51292eacb1f2d10b29c59e0849f263a7126e63af4518e0e58ce5f047a94c5e7d0d47f8caa2c00a8b8", "7b74a7ee09d02964f3fc34d8310742f7c2e68bb02a5f32e58804f9608dc11620c225fb0d66cbc9f3a0d2c4cae1d48853364ec2a34f5a6a22", "56"],
-
-["36aa473316a8b206a22edc8e33457d39ccee612e45b7b186a98b74b9dcce555681aaa7f81aa3a6757172005838109492ec11796cff3342c0353780694fef89f8e79978a89b6b75956d6f37286a91c6d68af7860ad890715fd2f0a413135b1db92f1fc32ddf27a6cd5ece89e612f19e6d6f4890f019f6c6cb485ee79f71399900", "7cc5ef15a41facba5b47876cbdfe0e0f6c6aa30d7a657f4c891bf75d30d4fdf6a10ee9a289cf7ab738391788025f5bdde557d1a06c91fcd9d2669bdd6bf42ac1402aac15f91fa8cf01a87286e429abe1fcab0b4e4c2f5ef7ac42cdf227d25fb7a140c0d8bcb640ecfdbb1ecc2b050703f88eda7fe4eaae8d5dd716042b16a4bf", "651a25ec186b3615f57d925e735c11b9ecc8718cf6dc85c31d991f1e65380ebf75441cbe33111f93684d529354b919a43025420859f629bc", "56"],
-
-["ff333ce688f621ef84fa6a52e47d30522c08a7e4d345a31be8105be4fcd06df33b0823fddc29f528bcab4fc0455b942b95f7621fdcf8181206af95f29b934317e3da55764d464c171142c0c9152d5d140ef62c6fe7e913b366c8a0d00d1e02be3ed058b7a3f52fa4cde745d51b9a93f4de2d8b3cbabf9fe6508402e41918b2d2", "6cc98be75b168e5bc9ad673a30ae01d275fa0d216da2a5eaa09f2f084dc38503aeef53e17c87c03eb623509f9f87080406d6d4bcc902277d9c87e1b40759751aae1a4b88d591630c23583c3ca6d4f4ca186ab05961f03e8f5c62f96cded7eddaf5b33c856d1dc1ec99b4f2b9507d0b4d352069a03b553348699940d7db25356a", "77294f4cc025128e50ce07ab71cdb6017dcf8247759e1b59632aad789600430c970cfbff31d9f635ff58b149f38da5115ae339a0aed6db4d", "56"],
-
-["bcb8aff6d0a89f2c5abe7f6372824bf416cd072a7ad0ae5f9f596c6127520c1b688ab471bfb9d06b26be4c96092a06a756028362d4c1e1bab59d0c3a6e0b35a3c68580d72d8bfdf550e8dac6ea36d758dda4f9e881f783053ccf22f3532c684eff307befc4270f14fec491babdada54ed23bd3852a7a7d895b7815557dbc8d62", "ef9486bfd96e72d068b5003d15100a0e19e432e8d2256c83676cbd5eaf4a42b24fdd73a423a0a9bee087dea0f74cb4f3bc03b99fc7f5ea3e9aab76d08549c4b0c71eb6c7f67830ffa1bdd4fa33b710051a305b4729bfbb3e3be18b6d1a4b7b4e9f7fd88e1a9e0e79f37d6bb492fa252a7d919f48c02ae86d9235849c35f97075", "4af995ab01e4d303884ffdb72e2ded3b568c6690dffb7eca47c021a567469303f7c3979e7e3af24b9920ee377af4b8072a8a64c56650e0a0", "56"],
-
-["d6d6c80c2890d096e581c98d9ccaddc4de8d360b4eb0b70df8deea79e4cf3a60eaca63dfc76826fd719a41d6a8ed9eface0dcb10f888aabf45d56ace6f95b5899f182b67862310eba9f141c8fc776d48b3ed81ee5589645b128ee56e5685f2af9eca0aee8b4bf427348db2a9c1f0b177363342ef8c3a4ea9060f44a2c228579a", "781f396e34e537c4853b9e91507c2d2fdbede621eb4d5a4929ad265bca82be926ff969a24a8a8ded83d02df9da98b0228d38e48c7c22425c8bb87428486214abcedeb7bee9d82a847abbfbe41dc63b3c0a62835339b33590ca6d046aa3e9b7205b7829aa57f795ef32a31038622f57e51914cb5f6a29ba4dad73e00b243e2e57", "786a521200443143a15a7bc18abaf01dd079d67f11b9b4562aca9949cb8fee812341e745f98bb3a2718c3e58bd4af7957e38b782f21a4680", "56"],
-
-["e58fbf55109e26a4d95dd60d754140cb8577af913b7786c4bfdcc8e7d173de57af92953839ab67af5320fb518b59efdcfc15d42571fd7a52ce7f6e49acd9c26944740c778e74b1dbaa8d640c7e18e949a1661f8a77543db69e1f5c16897a360a6bde2dfa57228f90a54b182497a5b8783d1397a695a35756f7659934b07ec2a2", "629667993c18ea3639ec4cb509d1df73ac96c75286ddafa4b520bb4ca2f1bd1fd686ab911074518d94bb43a4c503166af625cf1d7eed9c880a1fc22738b7d78c61753101b5999e6f8eec557ffeff2dfeaab70d86dc09a45bd3035a935e0c867aa96ce134560f3ba4cf856c8ddb4e8ef7705569d5c9ba2fe4b7d46ad63c7732b0", "be4d9e32041a8af4f851c6fbea171a41e4e0967da0b27dd2c34e23bd3b94262682f15262e4031579698a1ef5c5542ce6407d59905e27b590", "56"],
-
-["e9e4480d1c4a621e0c4e1505992556347a7ab34fd2b289910474766cc969116f8040d96dc5f66cdc4454fa7bcfb9f838af19195038467ab8a16e1cbc12e598e6fd250e21b2145f1e2e859cf73400be12a0c69749f7100847429875351d5a76970b9ccf700c2ca3ad72e9e4c0f0840e8cf488158136989b0891f867211350134a", "b82eefb2081bd14dab0e9e345248a34ade73f3291886b91ea3e8cc742fd884f6ee0ccdaf4c9879f4db12dba58cf491af2541a1d5ef6cc8b1af750ef5d8559ef7ff9cd56d8f599974be3aecd8c0f4c08f3ae50d86f9f822a1e4ca39fd2f0b4d78d22630733a24d8d63ecdf9555411daf205a761c39ef46ff6292e74129bc13a7f", "90093bdcc45da7338bd2efe92e30933b14f75582739c747f7572b3270b104f33af0c939e3c8ae53b2066fc8c97ccf38785cd2ec3d79e6946499d36121e44a3e7", "64"],
-
-["d3fbd6fe4e356ac1c8c120d432d7204d9d579b2a5a5d0c8b6016bd1eefd38dda735cf2f0ab873afe0a0916865e8b58a0af01fceb6a3765c9bfaceacc47a4916bea791afa003240d9b6563bebb3038949fc3aee38157dba596a9c4a20edccd187fff95904945d04b8925298e97b643ab24cab7af9a55890a2298de5022872d697", "b967c7d9c0a941f02e87723cf282eada4347b28193d3e0bfbeda6985886a37e646cc7b1cdbab45cce677528b3a0c24a08f8f580b779935c79398814d067298592a6bbff08248b5a2f0b48b0d28e4b6a2657763ac5ba00a8d6c86464b1eebe44ccd0c395e9dc9b9fbb306c6caa551c6682ec57869272e889ab26e6189b91f4248", "bc9a83d782e50ba5a801146f8da39095d92387d759eb4ad52bbd9e99d9f68f4a0f6f6470c653c45979c2e19543804ced592ee9c53eb68a5b1b7746ed403ebe67", "64"],
-
-["19d4cb1d72c73e2577a23006f31466ff777b9582fdfb25e8cbcd34649adade35f889bc20ebd5aa1ed7a2ce52a151d63d1592803585796013b3d5de2df2bd7e84876b643e554e1756ba5a8592b4a347b5482a27f624f6dfb28367245e51c8e3bf8f23cb5dfa590b35e7715dae723143ced7eb90ae209a2b2b012e10df00239750", "fd13a5e109ee583bda183ab64e4d27855bfaec17449f14991378febc435c33b8bde5f79106d11e98b6a821362c9f71e580bd0b7fb93c4dbb403208f49571d62d41abae530cdab5c16fde570a4c6897f2dd18a3bdebe2acad40b6f4c65e6029d471adf1af83cfc6beef0204ba187040b45a52dc5a4159d876f94cebb706f2d3b4", "b201720661830a671c94421958f73c8b666fd8a323629548a29afa163cee2ec24a01201d901ccf5b0adb1d20fcf0c5ea1c7483fa95ffa0a9590b970385d5779e", "64"],
-
-["69d9440047b29b8e1dea08482a506d9afad24fffe9ef7f18e36ff9ff6d510cd9e905bbaa15db646ed6bc9f65341869aea51f82178e341334079e94aedf81eddedf0ddb9a53f6964fc724b1500fab416e8afd41c9a75f35e6a7990e01a5f24ea4d45b6c7809eb7a5c798b05b4c3f33d0331d555bd3a572d564cc72f9fc98a7752", "18ec13250ee9c74c0fc4dd564b3d24a825802d5ae402a53bacace115ae3bbb329be79d1e5e42dbaf0a6446431145fe49b86a8703c7c41f8985d54f12e314c16ff89351d8addf66ebba2783f2d1a11965182aa0b0dd2de53586c5a695c6265c2b173958da648611090557bdebf11a1e042f089fe98e049f4796c60d26be38356f", "921264559658c2a0f948d13620312047326ba3ab84d1795c9e438fa76daa37ea5f16024306be804aedf8f91b586987254bc0ca8d64a79325c46b2f0b7371e3dc", "64"],
-
-["9ecc24e4faa8fd520aa9a49cab88fee7fd39425e13ca502eef8d45d5ad794c9dfeceb763d8f84e9d6cb6e69c597b360e1f15e7c6d68ceac0204d0e5f5c87d2fa1cd67797d91f5af6e6bb81d2a3d77463f31a4e27f08913e2dda844e45be2b18ae02b8f0766e4ca6460ff9dc6f2635ff06192a008c989749e0ee80fea14529255", "b85c46b5d55b896d67b87ac3313a97c7509984211ed80b0357d4615c7a1eaa4f7206c0e376f830fc2e0c868a17d8cb0028894b08b6329c749563db7880fe3917ba46b6dcf6392dca752091956e647613b2a3d3ed9003069af6c6188eda1f43aed844b1081dc587c1831c224efd85a0e73610a33975f4515cc426a004512ad0fb", "9088a7ca211f69835b3786789afd93f3704de4a34116ec5cd5ed0a43a3bd611ca08619718d9bf287502bfe07b6d79b3b2ba982f99442752aad29ec23856bfa47", "64"],
-
-["aa42b41c544fa928b2f3c7f12c41e5c56c910860ca257cb3080c24e440470e951a2b4a694206fdc41a05b1d3ac55efcde2891078f93c50ee33f724a1cc55ce9d30642e0d6b4fdb01e13a726e3f6e2e76b1b6b9ea5608420ef168d09ce10ad60b53b70710b6716b666f5ab3cbced2ca4b41e0acc0c8d37b9aa929d0dc65af4f67", "2b1f5c46d4b819bfa1ede55a14077644b642aa3963d177a6e823200bd065afa47a489f486f04d991f39de23dda6452d49dc2888bad319c69078b95a80987dc5e8480f15d12795d57aa5fe846718d0b0ad396a854d33ef9c49fc9c74e6879dce27052ba4c65208d59edbb5f3b828a8b2e8046745c7c0076fed8661dc594429578", "16d83f28f335f8d876b2fc85512159147f4cdcbb5c3ace09367d8f1b557bc977cc6cd31db4f93b144302f2712a05fd964f21f5fff11d28b703b9de3a01f87764", "64"],
-
-["b04cada1712ceb8b03c37c11034d7f6723c5d185cdfad3d80ae56e37a33a5418863d88046ad72048b6e94aee9fe08deb918a519ad128a26960c431d322c49b8e3fc0ee05ca916a08a1aa84c294ac31ecc93460415ef7c8325112e5da9d9b3d34a67ce88cd7814f338aaf347728d8f3d2916c0762be92cf99a57792365ce6c274", "1bfa498a685e81f725583a0f4fc5722fde8c01199b23139a3255db6a884286534ea76e95d75f918a262a41864cae1d01f1bb3ff830d3b790a8ea38fdcf6a12a28a7a6079fb8083b69ae4cfa7881883df20d5ff93cea7314424ab519e2a97cea1f6fc88fe7dc83fc4a90f4b3bac0b8e109361a8b31ea569fecf218c1741d2a297", "175e9a5606934304d65f5a2357d074b3511b597afe0167704aa457447a7015a02700f9c00aad116217b27daa9898c6c1e134e7624a7488c3515694b98a2f6ec4", "64"],
-
-["95b203c6488fc5b5215aa58c6e34148dc277cd1552925b139f14dcbe55060488737e654dad71edd10fc9b069e2b7e7f8d34b391d52423aab391f325aae7d1fbc4aa3fd727b59449f26fab39d91cb4cef818ba0779f4b4ce92080c480a5574ff06048539b79eed307b016369043164a5a1260888a01569ab69e601a99043c9d0c", "2ce3453903e4f074dfe57499a1506187f8287e79849e0e373cdf538e0d3151fdc33ed4b12dafb4b47cfd5861ed84791ba8da283ee75e13565a14048fcbb0aa6dfef09cbddb2f9bccce3817d66f58f5c15eb7900b71e7fe0212be9433e261ac24a3a2a546548c2c259d3bbae26ef25ee3e467bdc96c6157a22a850c609c6dde8b", "2302ffdac9cf3b7e284d80fc470ed42cac01d218fa1b851a4dc3edc80c2f3c8f239280da93ebcc249886bfc08c7f0bf22defb7e447dc8bdbb94093cb357cc5ea", "64"],
-
-["f84d1361c51c3e50e245848383fcd37b2f7b0fd916010a7fa41bb1f256228302cd0548ae2148ff42774d18c2d6d3e38b36bc4938da13bac3e04d66ec17cfb0df10b1df1178c2176ba4cc89aa6e19e606403519116ef635e3c9baac7471f0c349eada42537290701492493e03f4d6c332746fe82e79b2652686e9ec500c8ca389", "2bac5a6bd9dc5ee714606e2262bbd3d3ef73c9d578688321676370fa40f2bd673b741be63370c25fbe2bb5579e79486658d3e0eb22aafbfe02fb70a63524f74ccef6eb709f0b4f9b5c591095fe0889d766814c4343c82013350b4610337b01042a5a5571e6550e83361504444b119e0f62a69547a369fa4848bc7b9e019fe276", "5f3422f586e6dbae45ddc87f8d04b0c88152f90fabfc6dfbb0ccd7edae37de528460a7bf16c4c0521355d5f28c88ffeb55986f8b919feb756693b076c1690d5b", "64"],
-
-["befb1ce10b50a8dd71468834cc5c1174bcc1885f4a67e49ece59d6b3104e0730ad7ea126bcb410e1b2a50ad28380cccd0ac6a775ab5cbcf437df04ef0f3793b88d6f1dc69fc3b963b5fbe5a5def8ca9f2d8dc2d8629018fdc6300fd25788256e257598a8fca52acd43f1219424ed9353eebde072b72a802045f5ff462f6a45b9", "1283748593d539417ff5701cac703c3dfcae39608382bd14ec005e26188fb45d093f6067ff5c4c14e04335c2dd74671953e9c8f8efa618ae1692776e848528fd33a294ae7ee792908602e5e6d56606d7419f256713e26aa669e98027d9fe54b457551a40599e921d39db8970da6fa2e18e785697375f3a63adae803b6021c1eb", "6d4aa62658419fc842553c70118d90da3cb2a37539dae4b086b4a7f0303dd9c5f5a82d1a7700fbc1d5309a7f668bda06ac53f6f77ae26878bb9225651b1523a2", "64"],
-
-["21251ddba377e48fa35ad148389c486a84e623f3dc49f9af281aa0af8d00f0f74a4bca77e087593d765198e87b6a15608d0af4c49a7736a445d53c718b408631a618c177319c01938ebb4b06852656392daa926e10bd6af68a57c6a47203d583fc3509c4dede63fab23ef08a9cf9945c2c6e06d786441c0dc04549328b706453", "41da7852d48d59fc2ad0fffb9d64b9ae213f3266d5d4a2c7d89445725eb50de1033294915f135467daee1a4dc1aeb81c9a93fabeb57adc94045ffa152c2048b8dc8303145e7be8466ca7a394441c8a50ff648bfe1ec663b966c811d2eef38bbb76d6af23fa4e7c63870482194e369712f7bbcc6e3741a0fea5da73f1c9b73db0", "41a4c83777750d4fea58645cea32ff4456c44953b466a5980d2b8da234603547a6259188f8693649dcef85e85110026c006ab968297567ce823965a1df6701fe", "64"],
-
-["5d307655cf8f7c1b3b573bd75e2374942ee3e56b6b2578ec7793bbc067bc908d5a17261a094427b4a09633d0cdcf8ef1162a15cc6f9f77aa0c62a10f74ad7a99d7bfd12aa125934a4f3842c681e7a29d51b6b61de407ea4a3e98927f5b4e93587b3160cebdb729a4ef454f03a5f31a618890aea7f1e63b92b73e755945274491", "f91bc92f97c28b011e7bb1dce84cf9154942094ab908b49635b87906e2f14c51f42a9ac3ce46877b6a687ad6fc08db2bd2471bb97f7ff5dd381ff4897eb636f1fe4d6f87b5fa302a57b26a9af25f2e30ce32b6cc993ba90ec0379bc920a9d3b4de2c526393071176ad0289111278788c06aee36b4e63579095a875af10f2ae03", "9f5c4fd863f070b85d29b933b1379e7023335b74aac37186315e959473bf2b3c0f1f893e1feace27dffe35be6c607a22b02d695e41948b3b6b2bbf58ae7ae84b", "64"],
-
-["ffe01cbd0ef36a85e32adf18931c4761709fb382228b27bbf9c1938d816c041f57871ce03ca0c06e68db10b720399c5e8b1ad460c201c1f72698f3bef6f4bb1621199ac958c1f8ee6859190dc74d4e836c856827e430722da3c0a04b9835821b049f7dc18bde7ccd8cade363aedfc599bbe75620b29ea3271741807c8eb9c2f4", "a32d2fba17f4b0f08a9ee7edeab34b1b8f7b12ad6e65fe248fa97a18c12de5358ca62e8467ffa1eb2bfe00f8c825d6da36d608b0afbbad071c651f9dcd33526b6c7665e334d277775ee8bc5adc31d08a4a2d4b0e22be957c2fcefcbf443803172de6fd61e637db990ba3439d90a1e234b2c8024e2d355f8eb02e36058e04a041", "c1437541647fb134322fe4809de516ff2a9982c16132077dac91e0e06f14cfa943fc8539a22c91faa3fa8fe623dafc954ebee5c17136281e7f8a3038f58bd80a", "64"],
-
-["d65a384d328a1c8908a53151d8fb1e029c6fc44958c2728bf314588445a73f2e71e777e475a710c7ffae4d61837255888a232c854debe27682750af176ac6eea5cc501d7e47f151110a9ce7e44e5d76d9cad53c1819317527fcd169051f01c6a3efcc06ea9999431e3a09ef143dd0c79791423451f4179e7912464a9fffdb274", "0f280564119a83a8482f57b7c20b247171a985d8dcc55b17157966c4eba613626095952a5ede370ba589f1ef08743940d9f41baaa2bf8c23150afc2946ee2a4b18103cebf5810f42c3e3cca513cebc069b725dbde67db5894a3fe6d11b0b03301ee12231404bb25788850f614be054cb9f68719811c57d4f9b5f4d44d0c64518", "9645b0d953f9f91de98ca15845b7edc24434d3a247c1eccc99b71e9a3c3ffc79e94ac59ac7bb6ebfd10cc7645dd9c8449ce36bd1b4d1eae96de857cb04a76c0e", "64"],
-
-["69a9f4e2dc0ec5a720cb369e9a7ef804a4eb5254dffc1567ca06d2e0944e4ac72fcc2674a62fe9afb021221585cbe6bef09c7d1ca6465c26d60a53b6013608300ceca4659424ccb781f4d37dde102ea9e88d28a864ead78936504e62301914ef2890d57d4df75806bbcfb19e4c53b80db146b9bec2ee6dd8136129aac8ff564c", "b9c8680ebb44ac60adfb20716c23b7bd9ba54908f51e888de129355847e094f1a3a01d3a580d749a46569b5b9ebb6751f54c30bd98f3cd7020b4bf344634ad67f87811e9acf03039f4b44fda520d24fc4e378b58c7657a5c870637881a47c818dfd9ace35ff4c883de9ca4b63023d704ceefc0a2297d77973ea6031d6b21ce4a", "73eefeacc31d31fa658517504322a759664bc1a94c3f31ffcf333f678d236c743066f05f92c99b30141a13dd65d0fc9881145af6acc9bbc446e0194d68b64977", "64"],
-
-["9ce66be0e16f03baae3567aeb7ae8400fe601499999c7b5ab668efb0dcbddc6974f387c68779f1d1c9c9fef0d79bd6bbbd598c0bbbd4fe534935fc345836ac4bdb922c4e86b97a57d5c9917f51bad5af0fd8b1b379777f9050e2a818f2940cbbd9aba4a0659965f5db1d6883ad724985fcc6cdba5bedc7b9d6573c85333fc561772635e5ac807c52e2adcb", "6456643e93196695b484f8a38179486c3e3b577a9cc800d2dc69362837878d4f7ec0fbf3fe3ae08aa63745886cea61d2ec8a627652a46a997bb5d7b157f8c7f4927ddb0f737b3c1c04e7dcce7345ffefb8bff90d787439702912864f78a78943e7b48cfbbb84813215bb46de5f3227aae902a5a7d4df753e30a8cc6a613bda24", "27973b3457c6bd4136a33ac61d41d5dd4395dbd0487da65a0e4eb369d9482d2b", "32"],
-
-["8647453889476b944eaf55b97b9a7bccec873657556780fa29f4fb5ebb45366ba49f2bbb648f0c4e4c353f7f9be3a736e7e72560bea45e9c8ee8bf37c279bf5b2ef16483adcc093208c05ee51a4db04632946ba2b96cdd9d15b33c25cce2eba4ede4f97aac29ebaa4cf6bbd342ff2063973710078ee7836687fd7b0e23a741aea921f187a8cc381dde7f5d", "8d4f4a896a5d6f681c951da1eee6143cd83a271750fba88762d5214203be447da334255101c6a76343d634c4469db2163370b2f15341ea852468e580aca4f9320d5c7aee5a2b2db4a99cff0e6932f738f6ac6a836b866efbb8c39048f4eacad2f44faf291c93e9a3756ee54700accde94a76b79741d31c34466f8b63839a9ea7", "54a6fe3515e84298c9e3b1f2eb6f238fad2cf61f4e2e9686464e087ece4567c3", "32"],
-
-["6e573e5984bd566a807c3f800da68f65216697b926939f2f588572f96e281e807c4ef559184ad774bcbab4976ba44c0b97c53996432530a965ef84d98e80eb93ccbe850cd0ab1a784c3aa47c9f610de5b46f399a27a047cfaaee147ca82b010e284df75cf309a8dcf9a31717f1045a5a400d9eaf1c2b6d0bf12b2cc90350e85756a35230cb8b070a8b9d15", "35430b4881fc4991db8b046d64df7bef6d53afe02f8ebdbfbca76a06368d00af3fa759f7b0b739628e04e1ff3ab8e0ede7872d1e5191735bad8aac88db18f12ae37c90269f7560cbb5615113c9a64726f050292067fc341485f49651ee9b38e40a0cdb5eb5fe66d738eb7e32787158a36ef8cb6fd795aaf32d2e50e8d5df7ce5", "dfcd236af05c8204f1e68ea0a389499cf7601371ff190c66228752289e1b11ca", "32"],
-
-["4f48222e6c000f73c0db3321a59fd4baa7710d3bdee1c4447b27dd78a0acad2775cdb22b78db810f1466ff7776bbd4bb9cab1dd6ae8e617f9288c795bb0b86c0419d9c5637dcc37b39bfa18d441e3fbfca75bc0306e5432e8e7b3a5627b5bc7fdc424a77520abdff566e7f2bb8d1fb0c5c57528c8eec509896899e858cb239ac6e442258706d58e393fc03", "16a5c0cc8104031e789c05f885df407d931b9a8d534ee639df3bac7a9ebd2d22675ca212e91ec5ae4a9f928e0e644f763b6dca89ba09e2b901ef4882ef8987d8f793425e81e5bdd88d8da0e39a21f7e4c6547c3e36c619c8b821a97ea761ff76107329161202f5858b8eb55ff1be40ad7df739148f2d944c4ef955d48eec9be4", "4efd257f9e855323846b91da50c392de2bb9a29ed31970c3f01dec6dc37da66f", "32"],
-
-["f82e2ac9e2a859aa38fe8fa0d4f298130bd68e89e0f2aa2578265b6eced19553a8f16c6bca8be181694dfc4fe2721b8aace6891f8baa52bd077b56931dae9d5b345fea9753ca931a90f98fcbcca0d1a69d45d4038ca3781b81510cc87b9fac8c84c1cdd5e52f167f964b729bf844636fc63b99bd49a5c349ccf1a595506a6aef815e3cade88013b8618bca", "a59077774b861c354922c7f7fd5a687c7a034e642ce7eee7c017e0c73e832a8378c7c1cea8484d3f55802d4a7696bb6fda32d1b1c78f8c4ae2d24f9d4e9a0b6c18d8be4bd3e13a557fc1281db0d24ce8ec8e0b1954a22aec8643d867c3c5ef8e4d6a77d713786cdd908ec058f137a44d76c004821a4750357615820de32e5d51", "a8c446e15a39765fc932e0db9a715119622413522a6fb5c1ddacae1be8e8b9d0", "32"],
-
-["6d5f17f536140d17073b062536e893f982e91fe83e582df6b26ce145be94c2d4c0c7be66b8156fbfaa839c16441503c8c12c554a0d1283612ad43895d250a6e35e8e86b2e6545a08aaf4f6feb0693e71afa231fb2c1e2721e3b956ce68c69b010e5d78c208f6595ace371bc3c30bce5e14f4bae4e3a2a19fa5c444dac81e289699dc9a7e6626f11ddff413", "c35d20d1c500794332b0c1a1bc67dfc033c4c360a8a3aa5fd2f19d2db1bf3b807094b949900827e6438ef5991692b539d3c42227a6b362847e9d88a1b6855db7f58760d953690b26bd7258439a7f8409ae53137a3f2f14fa77a2a6bc0aa3bb7a19dd1c69554aae6c6703f3879057d3978c1a9d41bd3f492985aa0064f43fde2f", "5dd809c012393f579be168109ca60d1688df32bc5eea2024bddd91cd45ed43d1", "32"],
-
-["9b0e2154665d5e5b57bbfaab3fbffbbffae6bc1a51e5ed391f3edbc6b312f10e76367fb5cd89bba841840654de127016d8a27df2757b2a8726502b42f71577d32a1106dd1f33d9f278a93cbe7aaf4882b5a5abc5e765ac461ed369341db663a101c63d352405c11f42532451da3d8bedd717cdfa704a7b5c4dee1e03eb9cbf62417dad23a4a77a19e8aa47", "dd2efa4b6d340de657823a5959b441ab66bae0f9412201d04b9532da9aa555bd4ac699401899a9b02c71238237104b39beb1018ab64cf0be50150959164530f9371e34801f2905754bc321a85cd750156aed910b9a64548ef3fbe9f96d9739e84b40b454ab6c8a005c801e14ef94b808117679c418f29506db51bc03c720d951", "6070d709a0a26fb5d4e3a006f9913c4d5bbb1d02e6008685e006b1cf7a4783fb", "32"],
-
-["6d6017f05b66ccb88d7011068fe678b12c7bbc1a8dbd0362a5ea60dacc069cbba92a3618561fa810bf2cec484b2b3f99bfe225055f91adf056c8f68c577bf29685586a409058426e2195ff24795b863f5581266414fad9371ba7c7cd796f0c63c792bb50523a23387d545183609dd60db500ca1ff51f109fbe30dc83780a738b387f3230e4edd2d89910ea", "fc46e832af596bb15a22f89741e09d4c7c61c12756d3ace1f4d84a4a37d82b3ae29bcfcb001721c58086415a23bc861498b8c40be579ca5b08132fd5de014ff545bd90147d8eeadfd5a1a608835c653cd3ef6554842c00cf6e98758069c2eb846bd3b377f067cee79f9baf092a6741ab762f2c6afc9455256f90a3b29ec209cf", "1827aef6f794fda16cccbb3e1ac5e9b84c5f4a5949e313f7443b9d9ecb785d7f", "32"],
-
-["a6ec2b6448a36dae7f0288fa095dcf5c778e04a334ea79e6c374765f4851db7c4a7c0634b7bbf79828d2b2aa67f6e5712f84cb4d32f350896008762f6748f72076b32c6347af2dbe5c433a8389855aadb256412bd64c777c4cf0b77c4d2a967eb3f9659a00675749a2bde72f76f374378a6a2ee527de36e80f9601ef03ddfebe88b67881ebccbd6083a880", "89745d553e42690dcaca938f860ea01b05665ae7f6d290124c8f5fd99de8b00dc84f02c4dbac6a5034f5d76de6ff9bbe5e94277b46c51664a9816ef9586722ec8dd9dbd7cf52f72b15f9d2682e76459460046de650c7aec22161f7eae0c047f2203970f4e1db1b86b7fd0ce9281aa78355dfb38fdd815f14fe548213fd9a2705", "857877efb8dfa0d46601ddcdc52cdbf5fc1a34735d36773008d190dcd57c0b95", "32"],
-
-["f05e56198029c2a4ac8169eb68daf7b4f1ccb6f2037462444d1839bf220951ed71ce2e15f77e94bdb36e9a6e9a0c00b78d0bc27488c1ed511ccdb2891ffcb9f4a26eb84023f4437e04bde6e13c3271c34d1e93e97612aa082908da88bf465c876b8519216c5378ef87b674cdb512f1e1194c50d9343041735c10d8436aaee4b1b45d7c413b62da1725a06d", "b5df01a9bbd2517946829209dd27a6019b3fa763858ed8b61a64db652b1e2462ecefad0e4ce1eb5d82160235e85435180dc1a00305ea2996040ab4742c8a96eda2c44cdbe70c6ec966f1de5b49c045ea914ac06e66e7d6a1abbbae61d6387e56ffe447d6365dc0051a9a8f41a873bfbd50646f6b4e8ec72037fe31f3d232c5e6", "0485e3bd7dd2d8fff30f26c32c60a04343f22d7b4e90980a0819ffbfe66209fb", "32"],
-
-["13ade41b10d936e3bc007d1f1ede120e8163b7c0c8b78636017c27c53d5346bb9ea14445b1932d90fe5feb2fdecdb9bd8df7dac3804c8adbf9db3516d8cfe79cb43504922a0639417561b2743f188d21b76e8f4ce41f162939a14f8169aaa9567df28aca233a403d498d3ab6eac67884285240259c371366ea55dfcc98ec7633205f3fb543382bcda16beb", "721248d5545218f96cc0e8756e912dbd95661418957115ecd0dacb3ccc61036fe54413533f636d61ffa27343bb8f8d932e4579e63190e309e751368a3724b17a7f41bd7286a07ed4b361a8537d6a94b6235293d89d7e82f4d2899e1f0be99a16a8d0ef3a125174d9f0c42eaf9e6b69adda5607f9c1831935fb4aac0432f8e5ca", "3ca21e282bda0ea11c8b1dd7a8986af19ac75a3e6b1c0c02131ecbb786edafc5", "32"],
-
-["5bd7b597338c27d20e9772ec0fdff39ca56ca5c3bbc9f1f22facc82c86d8f0edde439b6a23743e1b8630a0772b5532ff22069e7d9908a28e506c1f5e4d6f6db3d09ef22d893ef3cc09eaa9b2777e982a84341ceaa00d45956f73e484761ce6c61b31e165ccb0edeb7e0fb5255922ce27b13e4790399f8110730740276ba8032fa544919c5493d583cce5eb", "66aa32cece01bfaed925c97614fee37a52a0228bf47d81c868d984b8f07f1c96a5b3cbecbb67086406b2e5dabd7defb57f805678936d4e94746ab4818dc5f50c41e32cf32e7a8aafb300fb91af6406108cfbf5627dbd374903b10230b6a63d7642249c0ab0a20d38e7f953c27219f03383fb497038d705901e96b6edf48abb7b", "6e2ba26b55bc023c53dabb4c8476d240b60a5febb38d6f34a8f81d7031949f81", "32"],
-
-["671fe8eb38047cafb577a5ed0989825fdecda94e210d0ef1063c27f54dac4d5dc381668c0b66c1a09e7e4102119dbe3b97b9a80ac69bedb39eda1d99c70acd0f1a31396c637a2d5d652e25801fdfb32e4ee7219833115715969500ba0b4ae92c7417a9b2c7aa878e126d988ebcb628de0d3f5b20f0d514bef854167fa2d26a59da1b0087c05e99dbab25e3", "ff44aa46bd6671aaa0c866bfc760b2d6ceadf1f04152408056059772dba75a6b6db42a53ebde01112af57e95ea0a7d1c09d4ebaff753d9ed6ab2ce0b5372621910c50887e3382ce7a3957ef4b1088dd42e80979a6b589f0fa137c1b2e335c3fd503fecea8dbc19b673700de504a10986a4799cb886acbd6d645b176e7337a4a0", "8def5273d5341f709b16467f48d3ae46ec35dd20f4449dda7657c21ac1a2f073", "32"],
-
-["f795ab87cce39d5f7786037e6bf704ba3466a45c07fbf3829e6184c2438521102a9bbde20363aa92d0aa0d03f4138e878d175400044b3046e3e118c9e672d87854fb04e18f253199eac964debcb3cb7bb3a6abec6aa19ac33d61ee4a5c27756a1ebb67cb98b1983d6febf257ea5f235d4b1a9367608599c055e8ee8dcc2c62af2fe5cdea4590494604189e", "331c94c1ed01e9f7ec5f0448564defa62082d8968dc5b741f1a431c8bb69bcd9b24a05807fe08f3f6cdb9c606bf3508a5a221493851039956dcfdd44c8c8137ec8f53e24a051be29458dcfe87105adbbeef2fdc911e1681f8564890349070984fc850856593cbfddfedf6488716b4e60645c5ae138208d08c1ccfbad490ee713", "9ea4cc85332df7f5f73415ddc94764227da7845adf1227c39fccbe0254970195", "32"],
-
-["a686aba184bae89ac5af1efdcb55476df8908b015c4706f590b8cba0e10e29a6b1352d6f1bed67d8d61df1285c6aae454b09d0280bd19d8eb19572f023b008b67de1f6a8b45a77a57a136e976164a7a642d360ace02b08e9c9ce46f97d4b3d02d2330f3297299f6978399c32216bbbf8a7974377237acf70a00a4fa1370751f478d37ff70c83688c993683", "8c562a0b7e50074e62deed2a664797b853fc4394641e1e835840fd58c66e4d18b16e4d5c5c457bf75ffe4bd9fba61f33ab9b74dcfb0759f49da726c7bad16af386b525ce17e33802770d197af80ecb82c72c0e29c49ad3ea9141f28ce9c11f4734b21b28582f27b7d195bed6048883ecd037794367e172af5c99ca71fd46545a", "ae4cd8fd5518706daab064f327553b23e221d8bd1b0b005ac634a14cceef2cba", "32"],
-
-["f3e81c3dcfa5305c9ff15edf303103fdedf7fc73af841525d78bc4099b3818419d71ec87c25c60ce41218a26faf168e10a4719f49c6d4da00143fb51043c52c677a9f172123a5a227ec1a4e1ac3a71186b0920fa3a82441f5aae546f284ca442aa1793c6684706a07d5a16d6ed1406ec39c5784d31cae0ed0a56382db073f6ff5d433b4a6b8c4d90677347", "155e66936d319f169db519d51763f9b43de5d54177d5689783c88b820d8b61e380acd1561b3c391347d4601228d6ad7372f5971c7ef85da49dbaf770ac764c1be841a51b04d862a2799cecc31edbead6f851b81d53ef14a811db1b7543b775dcf626cc2a4f8c828ddb16a33020fb18a678011e8c1f42f76a8a30dfdbfad742ee", "6251628d0e65b0aac304695a2059fb7cdd6e787b6d3787ea544a4a53e861bf5467d9d3faa8cca13e", "40"],
-
-["fbbbdd42e5fc631968985c0657ec42ef0db17af0497ecffe3d8e1fcae1c454e88dec9631c744c0665a3b1016a87822c1401e1f3db34f3259a4ef3bc11faac8212c38418da3df6221413aeee4fc3aa2f2946a68db6b77ce4696efb6da34494f5efe26441e20d63391481e603afc38abcc301cbf9118fe8d0c3b18f9dec9a9d2e62e3e08ace7301900946ecd", "7a03ff3737a8b26de4f9fa293b94899cb9d5d9b2ac9fd5f28c59d6a78e36d03d77baceedae7a9b9d9623c2011abdb9078a315a72a50992c4f7785d62659af2f306fc3a09345f8703e3b98332327d673a401c6dbb41cc8731d188511987584456ced22dd2f0e1de6874c52402aa5bf9fe849ffad7a76f1b01c29299141ff8302d", "0f19b00a7f9c96a0d88fba43cc55cbd04c0dce844a945320c041e36c3f8c5b5af5eb9f38ed7b071c", "40"],
-
-["05dca0efde68f835650ca4a7eb21a907be12d8b159bea420d71e31b3ae28adb199f3e38cd61b0d629267eac9a2a911cc73e6853144b789fe92efb63bd47e4af86996c392cf0cad331500b89893c00d17d8eae2e0e4d14c83982255b26ac84f485d0c688b31017eaed61f349b272e45efd5661845a1d495b7cfff38a2d7cc63aa5d9ff9fe63c328ddbe0c86", "c1993dbf1dc2436639784627d83c581cb5af21a0d29bd06f0d375708f4a7d856dac35a902b19c15efc35fd3de62081241526f7689cdc32dd0b62643e44e691ce8a694340dbc680d480f0f9c4e27b84de9f5ecc72ddf7476edf4f69c8d6a0181cf9145f97d7d06613ec31a283735549927cde09873ef2f96cae86d06f51dd5588", "42e45ef94ceeb15b5c5a1b6c6f26412e7e63a15dac6eb912bcbcfffac4ae7dae5f42fa7e6b1d7447", "40"],
-
-["cc17a0b3a2d1051123c11c5bd918dff0c0b93e5356f6cc19f29ab906562d00738773e1f2dd3692355645322c2fbdfe6eb687c7da5a22a47e04a3e4b8aafa469a21766d93328c8e4cf1d46cd14a5486f1dd018ee70c831e3a5139a7e1d57daaab3f292adeca59611e9b73b2915165df41dd2e7cc294a0a3148264eaca023e926c8c92011bcd287273fd2cec", "ebdf5cc51dcc4827c2ba27d22b86f03220a6d206776a6cc1e582403013c1655ef4217db55706e765b8c7360a5c6ae1899daf3b3a8251a64d92880466ead26f8b8ef32a40fd3ad7f00807ea8fe38a5d7e7dcda6af0df5e609edc5630eac6338bb5f5f4baf37f1809059822cabe96ecdda6809f8ab41e092be9e41436f80fd28b5", "30e125c44f1e06a333fab208db7b4f0eafbabaf3da5596daf7b17b17e215fe469ca829b8c8f31da3", "40"],
-
-["aedbe9ac8e96e95bb5c6a392247fab23b53c5d8a477721201a77ee927c6a0d92a6b320cce185621cedb130fea2bcc946d835521185451dfb25882a925f1bee0be5f754349c7d02aafd51dd4cbb6ccfefa56dc854cf300257eca28a68ffd4fc3f334e8dcc06a54f6dad50b164b4b74588e68dc73a6c605a9b396e14fda5bbbd975760d47c6926d3cec753b5", "12720cff0ca649ff2e1c9355cba8020fc96dbe05af9fb4bc008d8d8ac8dee0fb741aa674e66def16c9a8d4e52289ad2a283c84165621c9a7bed046d0a05b56d43bd352f3e30d4d4854a501f246440872421f5054b3c0c91240096035597631bd10a2f8c32b0dc95d771c02c25f617daebc1d0a08ef031cc32e3fc2021bb7d97f", "6c5f36354db5957016edcffbff8e52148c35de807c320ea58a622910e4472440087212f5d7025555", "40"],
-
-["89f6ce9c23e9f65f67b9f0736841fda218b64bf4b17f762354464e4ff04fdbf366089e18ebe21ac8ad6093c6b96d167c1cf3b93548a9248cba2d5024da528f9a23bbcdb883a915ec051157f8adf268eaa3e54a4f95f6aea456b2b70dcb81014a0736e2e6b5e5efb1b6f4c2710c75fbd5f7b385aa5d0b1b516ffe0a718a8438e95ba26509473eb1010a335e", "1784de20dbd9410f06ab0f19caf7a146e0240f59ca3899a85c4c452a7a3ecad223a5ad1baf0aaae55455557fef85010501a4e1ecf8fa6ddbb18207bc3a9adf14dcfe148f59f71ce072d065c7ac8a3c7354d1ef0bc12e2e88d97db7c5d7a233d94b785aa4610a5ea86706871a8a6e1a39e09180dfac3aa456dd279d6cd7bc8dca", "a4271a7cc9f6e4c0eb1b8386cbf3119b6f333a40da853f09e119cedcbdd384973c55291560d44db3", "40"],
-
-["cd8e4a0a21484000fbc7da29d8669b4e6dd5004a3c61b36c6676011dc0628ec3285b47e51ac4998f7eace0f8c49823623382fe427e21dfa1996b76c2ca59660503ea410b6f6ab31b4bf4fdc4f736a5c44d746c2931cf81b669c8f488b0006dfa05411ec549c2f66c09a718d799c88b3d62b333487cf40074809465b997df0c69d76ac85bd055cb4961ce19", "a4946aa5aca018655242bf0fb9194e65ee16c4fb848ef3eb7b8d83cb676e3bdccda87c84d1eb0eff0e6c2051c67d897f3b6054fe5c5d7c6ca412f47e400bea3a70924d662fc88d4016f5a26f7e1d4f3ae5d4a8f89352d2c77cca0a8169f8e8ec53fae5310f601c9031864d512a2227feb319c7cf6f977c66cb5ecdbc2a5ebd5c", "12b4f4334e5498393832f146c7e5c0afad6ac9ba02021363570090a9140a786225c48d45018ecf58", "40"],
-
-["d6ec1de088eb1b2efab889a79233993cc211f67e2e7607c911c573bbdcb7e0eb21aa01d8b03ccf20001916f3d01134c60d6e1d4cf784a3a28089f5caf4a7655adf506e752cd2f5fb8a2bcffd141e847430865232b7eb75185753a68a365ae220d8856c9e43d415276196bbada58110acf1029c18b8d2069460ca8fe4eaf8dfa5d4f2043b3e6ab80c4d03e2", "9638216939c172f74e845ecc4486eda080e6d0345305f29329e94f9703e409b02770b8e1155e020bbe306d6ef1c3706d1631d0911ef52ad1ba50cb11cbecc8e81ac04ca64a332c960b84c1809069f67242f6a0c0b6c10e4adc44e1fd7a821e9dd9ee82c1bf88161f9c49030908c1a6386dffe7c901b1c785f88c3965f7ee3355", "11304e9a457258ad7be884ede48b466b976b572bebe8a615bfcdb8b6e8758ecc2adc99bad372fd37", "40"],
-
-["65cd0e941290a0848c8bb67080c603caeb5719718133e62650447e3fa1bc1b9f1fa861e53bc8e7e4ce9ef45400c586c66a8499de77a3a5bc107d69bb7857e75e74f0b087b05961ea5e076ec220271ec22831d74f9dc09cec41a1d4f6522a4d91aecd80e23aed177f83efac23a695d4a76e875c2dbd33d29f2dd74758f6dc542013665b5ea0290f89877e37", "6eeb152015e6939ef579840d299f0bd01289a053048964043e2eb19f20b1f6af988fc252de781e3c616820fe0114bd887e87dfed300db767f950559b79c1761e80399d37c9e065e7e54f152675f175709cafe080b4cc9b5c400d6591221e4fc00f31ce593fe80fa769758a0dee00da8f5548cc93f78eb6e9fbaf0a0a85902a9d", "1685a4dbc1af73b97a2ede015075dd73d4a07b3c059850307966ddb9d6fa82f520b5db07f5e9fd82", "40"],
-
-["7668d18d60fe54739fb1286d927543268a6918d62bac18b9167279c1c5573688b5f35788cbae728ef1d5726c00ee97eee249be10f8d8e36eb940e4fc9c3087f738f2bf1caa402b173858e03e3453687f22b20a971d3a4c857602b73f9fcadaa3478e92b0c75b88f6bd62fa4bf64bbfc092cb85d462795ee795dd7bf194ca36732aee56aee8a88f14c81949", "338ca368ad156741f6018e48a6d891e61ccfe19570ed7b2fa96cbd6b306278a485a146dd31db1e03fc0a25715f0ec7ba3c9807a2cd48136ddb121c5d30e664764e90998461dd615f73ff366edf32746a08a0b0644a824c0a31894826e97c5b67f6224364256259dec3a968450c8cbf7ee6af85e4d0e54d8d1598eace456c8501", "602d5b37355707b54724bfd1416061ab895d3d7911d8fafd1da25e546aa07db0f2de49f324ab298e", "40"],
-
-["3ce78309d9f006e07308508ffde48da2a58aff635ac0a1f38bc40a9a323d935c1272bccd3cbacc26d833b89722b746dfb30c721b025b11db2ae4b47beeefcfb1fa061b626b6138ba92a2977bf5c39795974ceef87ac94ba6617b3a0cda4e47927c0b66716ecf92c429bcb5e37eeec8ecdab03ba2c9f0977bb9f162f40140432821e966a14bea226e23e8d9", "768aa679f74bd6cf8822f6c1fc221b20b4be400c1e38e9097c5c0756e76d1c7a93609f1f5448a2236112b2ed5972fffd831dbdc75a39c9c2491190bcb30e2d7036e3d15a310768ca83687938965e4ed79ddd566c19f0c761a2628715e4be4d77e87b04d3957070ec768e134abdcd52d58867d4a364452b0f0fb6b60b8367c5b7", "37a6e1502f0ac608701d68b4fad3b9e80121d4eb8af7573b6dcb5162121894f6cda020d44a74ae80", "40"],
-
-["9a54c64057af3f7bef4797501ef71084dee1166a8a037c11430c09bc936d339250b22a97c31318db0a46a7f2bb98c5a3ca3ca4e4ade30407bd8db42ee09e5604653464af2fb8700016b3b0ed8ae3b942798f8b937317ce750dcf5bee830dfe29a1817a6ee3c5ce52db35b72bd30176c7b481d35e26c862c4f97b05e3c4e4b269cb4277be2663bb392075c6", "0b6dfbe3665881bae120b8a3ba1d6bd0a73ba5abbd4cbbc68fa38c159ade085be103efe2d4cfdb6f764a8175bf9f34893f2bab1605d40352bf9c07702375b0188d58e814e991934cd35dd0f4fdb67c04d16ef9e8587b2d795b92cd2dc8f7db8a56936de16c47b13840802e883c0b4c29a52b178a7ee94025aaac64b25209919b", "fb4cf434e81cb3ea9ca88dc827b799d45f0adabdd3be3722f7b5299bc3ec3924bfbbbdbdf2c48c9b", "40"],
-
-["16d9fd38c5d4345c381bbbf52952375d9a43db506923964d24168a3f1ef6c15dc3501b4f28b08b92e71f831d29e9ad4504a4dcde086d42a31475e3d86ebd492d131d7ae64c8b2739829a26f75341236fe6de92907b7ae74d464f0a467d8705a4b047004459ec7019b4958bcbd0e1ffa4e6fae3adebd2ee1461ace7d172df5c893fd697b3765ccef922169a", "c9da201165a6165755cd01bdeedd817275989f68e1a7b3aae38c85ef24d9b26fce9f51f54d2ad73657eb386129b64ff7142e80dd397d9cb09148045830e112e0cabfb484762c5a0cb23e1359a708f23eaf11b7911506ab811cabcc2942172a66c52191e6671668826eadd9cef03c33ee4d2b8cabfda9aac68c9f248be2e2c4aa", "37d2820226c124868624270db08a64d1c218f34e907c8c7d8e0ff52e0ac641e8bc6b384d6c536e22", "40"],
-
-["f543ca501f80215b0adbff3b3a1822877c1fe3001c5d0d175967efaf16e0b023d29ee38bda085b3bbdc2418d018d63e11468cfe185fba10a743619684836f1a0a068ed348bf0b303fb4cdd0f77344d8311c5c0f598dfa6e80ecc32955c22adeb45ea0fd49901d0baabbffc4a04d78677597edec2bbd0c5b90e6f61bebc6d8c0aab6c72f26c035b68b564c5", "41b7a26c17ed19ffbb2a26c6d9b9fcfc4d596cfe93e3fb0b7f2c38482e2d4d073c61d348376feb8054098a9a6114143cfb092d4b019bccb1499a905e9117588cb8c90e482eafc32ea9f70174191f5c2adf5a4bbf393536c8017ad0fd9854a7841db5fa73e2f5ca9020bc335be921fd4e5f136f6324f863d71195a02d2b2e6377", "374afe237c3ac24e36368006609c1f8ef87255b0a25d7bb4020c0defc52de461f21495bc0f151845", "40"],
-
-["3d31cf76288ba777d0da29e9ce21d69dc6419c153e7a4d2eb02f5001dde9970c659fd08d9535e02f80428de851167a22dffc591982bc5c842664ec779d489e883a4863319b51ff75c627bcc678615f27b9b55b8eb475458cc65a882fd5815a28e3b3ee29e2e9eb91ca0f1e4bea096bf37bf40a3b7baef08eb9988af32c9ab1338868db3e13048ecbb5851b", "7efcd40347389e6fe10b791e53753825b04d27323bd74cc1ce94ed937d5e535c76b4cdbd2b52f771b4043bcb6ebd9960e9e3a40101eecdec4a31e442b2f7dcbc61d2cf9ae43041dd8b2fa9b60fb32e3e78b5ae673ede0433af548b2490d4def30701e285b9fb071a2f34cac87e7f7f28276a4d4f3cce7affc4a4e81f611ae069", "0829a099abd50327669d003b67678bb9cfe3559db9fea54b13647ba1db19d0a15b49acce0f4413cc", "40"],
-
-["77b60a4989c45160c1e2e41978530e6b5b62f99f1c480c57675076ce18390b61abdc504ff30fd1bd0fc8bbfd9b86a60f23ecb368ddafd7f397b37656ee713476c19c083de504929b1abaaeca3e7549d7c134735e5925b695f7c6a68bf94abab2a13e5a9cc66c3902c900e50acbe99dc91d826207f87250436fd12ff7a18c461e330a6ff2fe0f71fa04e189", "534c85dd76baf3aa0e3cd31ace049d931b39ec18789d8d10426ed6499d8a393caed619930bdfdbe86fc241d0b34af318f9595f4e2b89c383a41288502cefd2172a3c558b15e36a732c7762ab67601a6bad39cfcb47763487954ac200ffc850842f48e3cf7d0cc7d2ab23dfb3d38e39149da2e598b5ad08a37ddc2c62ff5cda0c", "1577561f5f3c0bf523cd8cc470e9c1b9507bc6991a53dfe2735f033bbf881c513b3153172502c6cf0194fc00980e1fdc", "48"],
-
-["a18a27748ef39b49be984e8d18520110008bc8a1d5aeb424bedcaee5a7e1a62c8666ee12e367e09297e8c7e3d4e4fd056587509b379daaf81949f27cc0fa2d210e9be951940adbfb55ccc7e5ccffa044318ff18af9ad7b7f9c7d1f939a0fff72c091e1daa7c3d4a97fab153b0a8933f2eb0d721621c86de0cfe100d13e09654824b09d54277912c79dec7a", "29c4ae3484dc27c0360343fd0b2058ba261ac3acf6f87fb56647f66554bc16c2451ba8445757dd2477fb2ad7d3c856d592a0d29ec3a348ff94977691c58d3d845cc1f59a99c304762cd4af17a69330a02ed9085a75e196fbecace92934a3d33ad57f3e3e3466c33fbf5679c76bc70ba3608c0ee7f2fb9132d44df5338848ff6c", "26a37c97b7c13097c5735bc31f8bdcdc27dfa96e991219e708c23d1b32c04d88a89a86a1c217724f4ce5bb580a1a71c0", "48"],
-
-["988deffa74a9cb1073252f9fd70b89ae954b9b44abba7389b55a9e28bbdc99f74e6388dad38b2ae51eaa0dc04356fe50a827c4935fbd0eccb305a9e101b9f601ca269c8905327a29ba9de043cc87fe9317339c15ba0a0c23b6e35ddeb981d2d0345d92d0b3a6a1256e870fee1ba870c107f78c9265a857d6e67a23e98a3d14adb591d47585f0892163ef04", "6e8c74df65f0a6708270eb9963f9075d0e4f442e4009670f01dde3f67c1d4d740e8210979d2962102bdb7691aa91f3791237a6b3b2b173dbc31c92a34356f87601c0125bf442b91bd09063a6dbac96b3fdada3a717be90b89669565248164db96ecee4f1124595db9b1818a09e53d752f736a3d11939a5eca24d97c933653487", "553d71c6e005589f195283b6a4b190ebc88a2cfd9e44c7c283ba13666cb032de35a04fb8391fdfa7f29b9629cae6d2f2", "48"],
-
-["2021755f1edb657857df8ab2ec9a307d9d984360e14706e135fe08a9b43d55e4e837e9e7e08d6a15825d1603237bfada55fe64fd00ff9cecb59dcf693e444cd97a30f3ec682ce98081a091c34bcab8982b4c103d7ae7e02adc86f93ccd7eec127d147ab8de404c82fbb0e53b8b10d47fc6892e6677873b6353c0706b89729d599aeb68916b4ffae39996f8", "d6a871085b50187629d6edfebed8e9476d68a512495e652844f25691988a140766d06fab9d086ff61ea196f11e0964a9cbc621dd32c3d6fb60e79d4bc7ec1a69e46f4af81f437b95f30b9c0a4e08b7c43b2fbc88be7f8061ff9105ddab71fc7898ac8859a8aa453cef3e89b0187cea8204cb0079b53b6c436b1818b00cbfc11e", "2f10f4d5303c5b237594ba5bdda13951be2fea63c02e0f2c1821c8798dec4dc7927b41bb7c41192f6cb1234a71de905d", "48"],
-
-["b7c3c673bf96cd22a641eea1c83f036e79289af445e053607c0f8ab0efd2360cf4b1115ab0d0fa0d9569973d29793efd09ba16e92ed279fe70ca9daf48c17434dae2d0263393cb49cfef66a95e7770e8c704aad66e012842b3d6fa10bba70c8c723f4bfed047097f5cbca30aefa061d6bbef9de38bd428d34c1de9fccdedc7bbc3b0ca0b10547c5645b796", "a550b9edf71774d43ca00fa13da13ad1d59c421053b193ab20b87b44a28c30c95fa915a766fa393aebec208831c7c730ad3ee29d6845687fa522517cc4c79f22665f9b21d22761b7977f9e6b734199fe7451709f5d75a45e4b67d8e39829d6600099d3c40245f432898011b1ccb6b82573fc757efbeb502f5baf98e1e85bdf69", "8d0af6a102ca32bdff8862a35f59726628818a3dd076158eef955e58e52a15178da59d8453882bd5af2dd84da7eb8153", "48"],
-
-["41d7851e98c51d6da7e612c43aa3b87d56be5a01cd9373300f9d2003433bd66f97b4508dd479ccf935ab777879dd26dd371b462d722b16016d12142cc0dbb49eac935888ac987dc14c1432693ec4cfccabfee388c7f26ef3a83051b7586a02bf297b845748a9978e95272cdfb2de1b115a6e185cf56fe4cd69fcc0ec3272279bb575bc41d1348228b8ee4b", "767c2c4723f582437374f26bd6fe7e85882c6c9707d151a9cadd6fcb3081405ab79453806e3657faadb3761e03aae41c706eb784d4acf908a84efb84eca0ed3b60b1ac63f7fddbe58948355ec8cd216975988720d431e3e05d7a984db4da8696db9bdefba791358c70fdd8330db060f4ff748674eda738b85129ec30707934f4", "466b3245ddc7a5beb00fd06ab35877ab60264571f16a1c5d7162442dad42a30c3b4a449c799846e44c96ba43c8c9b039", "48"],
-
-["1c458931cd6ea07c7fdff6bf29c5d7a42033d8fe38b919ff2c39c8ab40f6e68c24ee4ea81c6af3b05ed3697abbc7a7b1826c0bcca2049e3c0ec29aea66dc29e0d3e27046334ecc91765c5bf7471cbf26efc51e35774c6ddb0d35efdfaaaaf8cff1caf7f55943b3878e23d51e15eba7692fe51b2301b80f42f13acf725385dcc1454dec9cd77131fa70cfde", "47c39405f78038cad3a5dde2bba4fc6f93df3e07e9be4068d8ff93672c4e082ac6162a9743960b0cd8374451801d37cb5afc97f24c2d2b05ed01cefca255bbbe0766079490e76fdd70515404b97b04b0c56a3b3ad66aea64f95d36483054c48ecf5c34e2279beba5629b16e3aee5eb869ed232c9cc9e05efc4bed4341d73ac04", "800632a5afbe6e2d03bb2cf4e930b675864e93bf27b679b625f0724f17bb4a66e78cc06facc19945c46768832026e05b", "48"],
-
-["0eb602843c11877141d8ea6069bd65b7556c40a640c13dfe8838344769a5fb0d0b25887abf545a2a85c0153ad39e6ca291bc43c568b715473c6e941ff7b690ed89501a8d2d18e9eced7efd1a683a81a1d33cf6163ee28eed186ed691f6b798b5901f00e40cc4fd6080fbea29b2fa6e5c22d4a1be4aab7099242042c7ed0fdc0c297a81a9e7c25e2d461d26", "67818341abb04645ef2a3f5edb0ceca2725a0f573993f81e9b88066b8c6d6c386d3131fb2f0b7bd5bc560c0c929e0dbbb1fb0ac8b89d3f174ba912fdb744e0f8d9ecfc9d3c0ff5d181d6183c044236fbbc7ba89563658f31d7736006c1c0a112745ced0f18e33ea307556cd77721f0ca5a83eea7821073d40990fb6cf00b36f0", "0216330dac742a5b8023fb09812d8a08619047fd28cadc12f37c4443a9263262588d0c43661eb7d6b36a801dbdcee794", "48"],
-
-["2c5354e0c3f86ebee987fe9af1db03c7f376877867c6d325f3a7df30822a0cc99694150fdfaa43770c2ce172e1a0f04a8a501c4d2f96ee2ec85742a833cefc64838bf71d9cbb3e02fda97f5cdc85bc70786544a7ab89e2ecbee3545682d6fe079c3fe05421b2c6266306be9f0a13cf0166bae8cc032617277e52fb8198cb7c7889b8b9fa971742aae64988", "f784430144b3ac1e25ae26b68b30ca8c012429013417ea2ab2eeb426f2d44eaf957704eeeb744d94b90fac263928d498636b0f1e6bc6e03f8b20e72b0248064e5d2bc225f54bec51b96c80ea2d90cd15b326d6d90a7ff92e1481ce57dc9f5272d709454e6a1fd07cad2c6ba96f51fe2b7abf46d917297b20d57a305387c37a43", "993a5bd06cb1062b06a2956744f0d74b8ee6416a7e9ca44964953684d47250049dc7f603bd4b7e541770326cc0c8fe96", "48"],
-
-["8b80d993c7f44057d20e8123f8377347ddd4f4f96f6e7d991810fa3a38f1d85dd2535fff86ab42d7bf0274d92d9b3efe76d68f4728549300f35c8642157ecbbb157638b1b453b54521d805f56c22e3653d8b515fc6cf2d18f66d05b5ef502c0dbbaf0db13a32c02a5f56db819c128b059bbbe3482f25736b8a0b1d9bb0b024dc2cc7860501908c0ce3459f", "003ec4e4208538ec15be8171a8e1cbbc8e19a3f4e26a0ce4d297cfff984312a1ac562c9837a2b8e3cbc7e8b952531dce452eb4d11f36223910264341f5bcef31881bcfe0d46e6e77edce9377dd66cffee1d45246d6d3026eee17cf7da77940a4fe5452c3df2f832fe19c2ab3305f09d4cad32559ef8ad60bd2341aba95c22e80", "40e8c2efb1083622a677b59fab9c008ea81afbc58bf719674e6a71bb94eaac304631427abf41acb3c87dcc65dc485b62", "48"],
-
-["0fe553a781639eb8b8c2b12aa091f59502c1a01ffb4ed143ad22dcd13436e236dbfaa6fe90f79b9796b242587ad4b2c148897805fe26a2c5410e171f4fd62ae735a76e3442e4cb1fbb0dd1c7d0e44d99cfce1ace987b3a3451c925325be6610684b553527e7455d7909b09808b07eca200572e82b097c118441cb0123eaeb21c30edff5f2bed62477b248f", "1a84994d864a65d08ec6735d025837e91730abd5b958441222f5258aacca94bbe3fdaaa5df1e4ee7d1656425db3b41ac25fc62592dfc5eb75ec81a67e5ae3cc5c07fe2c81793932ad00e76c95e62c14ed915750c54c5492b6b69e3051bd94ed0d791a97ed7b11ce1b449111332d4a0155c024ab6d7bbebb67060ea2cf754b325", "1d94fc28212de0e3d3254b7edb00fe55d2a0fa823714329b5a2f7fd502d4c71955988f54e6a08929b687b9ea47448d5e", "48"],
-
-["c0fdca48cc354a8cf616cdce0d80c39113bd5f0ef163ea346a7b5ced4ee22da3f0f06903d9580300be4f6b1bb0f162e293e00eb7d12e49d3507d3f8ba16438d17a15b2fd663db3cebb2c5dabcc36c32f6159fbe564207550ddc68a2f219f58fe45b4cec0d9ad03fcd3f5cc06ca188d7f65704f1f120013b2fba06661365b36333b15e4d49c709a94f0f1df", "f3ec088ba8d08bb36350413144d285479d39408abc665c8904fe7caf436eea6b3f2dd08d9241acf4b805fe66672943bf1efa274e01e4f77ce43555aea6232f6dc011f2c51d0efaea2d9f4e2235be8adee9221c2a3cc1b3487794f598889a71bc9d1fd4f8f23e5fdfe7a5982c569840ee233986e815067a37e4a3c43593da969e", "2881746de7893d92c0a0421db7e7325dcdc4860d3b10df1270c508c531d0505100d17d48c2e55766200750dc03e1778d", "48"],
-
-["05d2120be33cff0014bd83a4da9e8eb642c248534e3a522252134fd09f72a4bc5fe47bf3c0ef0e5e55ed223ab91ad1c975eb8d4723a16220d41f7b60b0f8e86bdccd9feea1d6c015cabe1dff4db4f96f2ebf10c4a151d82b6f2007139a3155e1443583e5cd199c8d3cd97bd9e0958d0f37e6d8b00e723caaeabef9cfa74472865fc631c18de12a2f72e544", "e733c695bef2faec2b99794ea1a06f8dbef895ce2394a630822813aff831f7479e354d5c5b4c6159fcdf849886b00482ca1a5bf73b9ba7f0fd8f5a623e3e3366c9196d98d84136bc2a4962a8563d615e5fe36e7888400ac96a5ea941434dab1191688d10e0d9615de0607b46ba506e69a4da3719b48db704f11e6712b0654790", "8ef1c2ddcacb92e5a66d02e008d4e6830c66082a1188047826a3c08842c126c3d0550a41309fb69c5fd12d5f0eca5187", "48"],
-
-["1f2468935aa2a207dac977e94f5b31ae68191bc5e9883679fef52d18ceb78b5554b42ceb6a27f18327a0b8d86b4c8c19b876188f444e627f4ce9b652aa8adff3aa209e791a0f5406a03302ee5122731c7eb2b8b2d1bcd5f0991fba576307750eca6fa58490e43257ed8c3b752668508162d6e278ba5af7948f9d227020b6b36e5ba92b56f42df33fb5b34d", "ea2f8f950949227cab95e57a1c421f4880f1bcb3ba0d4d978d5b4f0a01d2b0d809ac8f3062bd449cbe04a0362280751a8d445917dacea8aae795c82cf7e1b14a55dfacb63bf55a1cf153f95839cbe3e6ebe25799dd277c9005c3a367e252cdaa17f16d8f5a986fe48cab784629cb7094e3f23ea38abfd2332f0e692289cc0ce0", "ae673ba704e7b8f7adba1ca451c96c9189456220c8b5fd8f3cd87737c88390b250170936bd9522dc021a1d1593034c40", "48"],
-
-["07d1cd3f695b642016a3dc06b70140a2cf3d479dedf42921263db19ac28d93be36d801cb53f9790fbc54e1ae9dbad5a09a8d40f90624296d5670d013d5e0fa6999838999b56d4ffab05b24da369a367b0e24a15b4c0d40b8c81dc254cebf007617198d31f87d8fe8da00ce12ea680b3b124c934b2776140b648caee517f04dec9ae4371c85f2e1e2228b07", "156fce19f4609057ee8df69cf72b33e27026509c4bb972ee6f3b5c1d5e3c344515d08a4a342b96105e6ec1efe3c168619ca2dcfe177cadabf47c280e8fa01e45e011e7d79fb2a7eaabf9d0b7acfee83cf7d18d30b311b63c3aba3b68c23f98d43dd9eea87e1e4a4d9cd52ade9b093dd9495c6b1b679e150b099e9e1540402a5a", "f3a6a3c11b618d5af52ec6f501a6eaf90c889297316a7ab6ae3e2c65da06e77338d92a4ac0fe44a96340c9ff18a25f6c", "48"],
-
-["a08e14c2671ef79f81f732df2df3835ab0cd0db9e190fb88eb4f668c4f1778562822d218b34d2cb80ecc3b23401dd8e47a3a5ef59d1354d4fb3b4cda62e20ac95703e9a49fde7bc304a970265583b90aafa9edbafbeceba8b863c1bcfb5dccecb399210d32ba2ce8e9f59791730d6df4d68180b10504082aa92d8cd9207006e8c64d42fb74c78751471879", "0fd5e53b9797cc3ea75fcb7e70a93b80b54091762b0bdfb7252a9e6d7042aa8d7c148e0dbd55025151b9b2e6e3524172cf188eeb87c9360835f35af5a24ea24b5650813f01fcbb1a19fb25e9c5edac75fa019975e3c314038736e64da623838de3b0473b29340080474aadefdd2585707c233b7c09a48190621ccbdb4467553a", "b07d4e89d7fbca2db335e807f9ec131dbc75b878c513f6cf6595d7545571fd1361d8229dfa6949cd97de564462937b35b92320e3ab30d9d9", "56"],
-
-["ec4698b68d26f2fc0428f413a0ef0dc4d6b0e6233e2e8b1cdee8cb4fab90e11e4dc0540323e91b27878c053153ac585ca383b8cddd744b23ef411b4fd87ebca66a452e344e2c04544874c67ebc83955f72940d2f96ae703f03d200d1c179ac2dcb3eeff116d7f6a9d049019fe55c0bf5c84dadf070c44097a105427d6c6afadbf9115fe184d2374ea6747c", "f1450e17beb57bdc8e2ab1b9b6b35553993681e8cc080d8578bb0d7900379a09e40cd665e6072adb6b04d24b23029cdbec7decebf4f8044c1ed982aca8792a550a7ce618215e0b838c4fde5b57415746d63f25c7d400f16feebce752393e73b92b3b4816b8e2a73dbbb4ded098960ffb1f243262b4495d58ad0c4352fcadfc9b", "664a4b280edef3004ca8032a424206083baf4ed3f055f1958d84d7deb8ec7eab7f9285a3b0dce0997c07b38eaee1853aa5c6de7989338d5a", "56"],
-
-["7ee879498441719e0d48af2010edfdc5b28bd4715fb214d21a29f7881133488a7512d0c588ca1206f82738196b014c335bbfab8d241ab7525b24cdbb628877783913e253977392103a3f54977b38745525cf31df87e76ed8c8dbaa167bc3320073f3953ad9c559857b99ece06719ddf3b9c24caeb1a0a02e095878e0856ce3e8cbbbd3c47547cf2c653be9", "378385ff81dd16b3702920075bf93f3c48c658aff7e3c46623b0b0e90410c353181d02a8231af16975a8fb666b3d14d438d39d9fe5dd8802977fbc5411febb25797682895fcbb1b4fbe1ebc7b61080eaedd2499bf1bfd44552bcc1155d6f6c09e3f6cd4a3cd89cbb8c3bb8d24558c9a7ed2d244a2271f05adbcd87d3f0491f28", "0c79d26868ba4802dc8af7d0ac89b608b6a1255f005a9cb65755ff98c4e6f45dba10dab91d0bdbe9f15b0ce693cba841c0bc12ecbeef8227", "56"],
-
-["97449ca6c272484156c84d60b7afc1cc59546a46cba1df1bf56beaf89eed31003175151cc05ddb92493d09da385f13ad2e73375e0184a66d042be45a880371b7a25ca9812f34e9b01663f30dcd1594441f7d843a2cc88da0b150efc9891304b87463207e18dfbbc345a1d2a27db98abab4da17815454dcdc8442d3edaa05302c2ef141ba824599f25f616d", "a0be64e0d1c2cd877e16048abdc5f7f007b30964eb83643803b78cab28193a67e25a674e22faa7763467b872ef695bd42fbd62ef85010be22cd08cd23f2def762a2520d95fb0455b4ad94df9861ecccf511a06db3d61490429ac815b703606a06065b808eead4e3da7b2eba7eaacf2ea55101cb07d6dc8a8e29392cf22fb26ee", "978c5b056ca1342170b158a73704c35885f5e8db7d76c2fe74466913103df9dde24e1100413bdee4347771ae355150256a8df0a56baedbb4", "56"],
-
-["c52103e948ebf17ea6b3303a479580ad0748c9ef2f45aee4a52c64bd5f64c5c21d02a04f58ed541d753f9dc33feead7d94ab7f2b5e0a689dc6e4ef0da4fadeed39da99e42007af138254af9c8a913d6518696419ba8b2e70d80872b12efcee757f7195ad88802ae634b90ecc0ec657edfb4a21c0290f29e6436198afe7b51ce0598f3db479a2ef20f23863", "6f8f23414c2907bf8c753b13fd1b9e320fdcb80f366a4211907de8c4c8234dcf3a57502658d68e804c2e270cdf18c65f9907dbb129012b4a08ae8b375aafe84115308b2064c19beb4e61e4d393dcd611d658d11d012aa021e6ee43ad22d6f1ac47425d973a9139e8d937f8d2e110d92f0ff521ba868ca77e7164968ee9416ff7", "d65b12dc067e2b1eebb59c6aa1b13a02bf7c2841422952b4379c61324897b2906832dfde7797a644f5ed9eff9ef0dd723da7fd2fdb7cc120", "56"],
-
-["ed8269eb2ebcfda7d381d4f8a5049edc74674c905ae675624453f8c1adc2e396ad8593d7335b1d588ee4ede53358ad8fba79694329c6f0a175f27d0a469496fbfb20359aee16268ff979b015398377511197a1e2727738b95ab7cf2b9b726944de3b965fe756dff31b2964b03fedc4cb82a93d96be611553fb8a384a35c222195fbeff4ca784112dfcda53", "23fe5ba70eab493c715416c16050096e00be19748e760c8fa23fdcdfb5425ef4e1be2528e9fea531798894f6af9896a2d9cc18f781217ce6a71e775c4d4fb85e39f5cb58f6fc3cb21c2dffa5d1a50f4d908656ff29eccddc0923e4a6bc1724d3b00b13e03c8e58e8e0ab1f75cf61bf96aa539a328fbf459b52b4ea660a80ea7b", "8749a3f31a8ba6373caf881459b4bbe7b57a217e399e61c8204557c6d10d45b9fb1bf4682054076fcd8b4dc5472a865b5c7e7bdfed9ef420", "56"],
-
-["3e0913221bafacfce39482279ef2fb3f06eac4153b6c1eaeb856788f86e04337af3702eef668a0bdc58007be67f1ee967738f23f99bde90b68fb73e5afe0d1dadb77fbd9b0c4ecb73cf0417609a602c797bcc0cd0916e0241fc73622647ad65da911266db18b38f32e2b57daecd6e0dc4dbe5448f53daa03c603eacd0f74f7c6004cac3162852b77857a72", "82e05400d01a7f3843b02d191cf0477e1ab1190c9be39e69056afea9642d55095569b6e0224c70d22ebf8f479483a61b0b7fcc980d58e5a13843232ff417cc0256a73d90444e2f42dacb39df0d4f96488ed52967f1b2be620f6d9314a9d017bd4e3ff1f87e4944ee0f7d31cad07f9e6d6d5b36629218b1145391563ecc80bea4", "4b80f01d188bed5cc7aed1e9f5bc16595df37fcbc8732d668d228d4cdfa60b66c4d9b5becacb2eafc28c31db904c41473b31a987ff46d3f6", "56"],
-
-["af28226e910a27a751c2545db05f91b1dda4a121e8ae3f5179d76d0bc2db0984b239a1e16bdc88f9f7fb295d363e9c961b2277ad189ee7f1d707fff42b314fd746d7b9c72c3c80c86813a2b1fd9c68789f113bb02340b950357908b649aa6aecd4c91229bb72379b0bd26663ecc31ce2d0389433f0dcdef8f9f6315a0290824bc2d8550e00c6d4fa4aff1e", "5ff266343b057668b2a5c81ae08ef906e178afc639eb22457355dc76095abd46846b41cfe49a06ce42ac8857b4702fc771508dfb3626e0bfe851df897a07b36811ec433766e4b4166c26301b3493e7440d4554b0ef6ac20f1a530e58fac8aeba4e9ff2d4898d8a28783b49cd269c2965fd7f8e4f2d60cf1e5284f2495145b723", "047fbd637fb3d82f6eadc40fd8ac221ef3e33cfddf6f4cbd8be6ce0e2278980c241a70962efa61b67ca13b8b15222b0a589df4f4a56d8deb", "56"],
-
-["8b5b16537dcd2245ac73db5dd4e6febaadd025ddbf663b52783233937c16f9293c8ea914ca35006dec8b104a6ff537e2bd0551f2b2fc2d5fac8d3e1ce1e4934ef6e709aecdbcc02f9e8bcbe249852c16ea25ffc3aeb5f529e61504b45ab85d23e0ef9fe92d6a4ad3eafb76ec0b9ec59e5cb796195c9a7bf94f50ee228f993fef232dd18d924786a1f96478", "ba33904d04c8b08bb2c9d104f62d579901f0a23dc8c3ee4b0802771d87e6e1da9efcd88ff4d5f4828cffdfc30235b90311d3bba9598d1b235f5f307ad6c7d72a1d33153008b5fe64e3fed509b48a74a3c118ee08525d74bab8f975748eaa09033fc09057c9d196dac0adfb019594502018a783823c9dbfb91ab78e3339f6410e", "bc6f6f9ad5bf623506f1242364e4d9c94b8624c86b08a9af59bcdefbeea09bf0d8c7b2cc84578b518945c67abae81f9389b17c5bc88589ff", "56"],
-
-["b39b9aa4e2c72a00ed85931dd26c6069108aba7cb0578aac86e8dedbb5658e9471bd1f570998e730248e728e4a962553a7c121280c286b133722494b54bbef4bc4625a251ce27d1971949b66d3d2f46017aea3c3dae4c747c20ee17407177db832f215b7258c32e25cffd44b720814b7e7bb08f52075e76faf136dffd561810445d516b42b7e4fb46d37e6", "5c3a432b965ab1b6bfcbe3d2902895a2af56a8ce3cc560dea6f2cfe7c431916a21a9ba7997a00d1b1ff96ff2e7fa9b1ba55316562c5c66673c9f7fbc9f967e4f71b699cec0ac56fa55e6fc4dd05547dffb71608a587e0c4cfc0e24eea0c845e3f667c8733906f519089a732974c003b558b96fb2e9b2bc578d49a7c6f14e50ee", "653cde316c2a653cac8b1f6bf9bd5c870a606def9c113804130153d76df6ddb51109a96fc022acbc020f8688029f13e0cb0ad67301a6c4a0", "56"],
-
-["e7e687fa0e3d5d25c5a8a8dd13a541a9292e8386e733f4f2a2472844259ae33ef5277ee5da2e8c909ae5bd40086b022f3dfdf1ac266c6c253fce7d869d7485c2321d96ae4d691d166091c40b0654967a443564030fe368070ff92903a3e79e1d253096fd6fd758de9e1386e5164a47b1c63da433ad8133c09c2ef3643edb787a57c94879495e8411dcd5cb", "3d0278735b4bf056152f4e5aef2fe220541b0b1c3828fc48fc43bc1a92a470b3e1a5dd596bd529272d48e103c63241d1dcb4afe6bcf688c040761ff4f6a003677e9e81c134716a1f10411709caf1a9a341f795271d6b6192b35da89e2a6c64f1dc946d94ff483ca685c1f5524095a5fe4a8bc7c5a4ae0e281da05e0076311bb7", "747d22793557864754598e570379f66cc46bc439e68638f505833ecf6c51b70403315ff216d6664c0c9b3065f8ee9a48077b23a7bbf68562", "56"],
-
-["1435f0178e91c13ba11f03ee55d6a4fc9df0c3943641b840d2c8541a7684693e21888d7bae521c4efb597c104be5a56ee923e01a0f79cec00517ad3c8dcfd70a7f268bfdb1abf0341987574b3656c1ceab35a1f32e1ce388a06f2c2a7dcf64b8773672075e4346d743d21d06ddd000042c4e8e875bd3fc80ebf75a4d0e2d183051e1c8007ec3251ec16255", "f18ab3088ab39d5ba081437d3bed8561d3a03e06b5c0c6f1a90abcdad22102a6d52508953e884ebd1f1d24b7f972b598e0604322dbe0b43c8a204ea00bd7bfd41dd5605b86deba2cb3b84a639f838137f6490f2c79d66189a4ca680d8eec9dde12c142c22c5eea91a57a3bebf3c7f3265b83298a5849d6c1402e93892509a4ac", "b219778681f759a0c9ae6b9a8f74d282d47c0278184c9c89629ac8be7cfc7747dc957ed94e5f1c5c6cd3081f98c0efb25f24e1c5d4984a71", "56"],
-
-["67a259321cab0901526602d1ebbcdfe5f21d9a5bdcb9f3020ef3166ba0127843613a02df1b9c711918e9fd5be40c38f955183ac4c97ffbdcaf8600c549f53abdaf132b4d18d14a1f5546a90f9e5bd58491c8fd01783f57f7cbe6c0def60ba3835b141ba49bb45cba11654f16a0127c2faf132d3b715fd0fc6afd9edd2fbc7a3d6d241c0b2cac6e93552e36", "8f18140963d761c2956c66a83f94b88c42c0dd70e7200b3bc60031b1e7573ca1289d9360dedee377bf0d2299009aced8ba46761892377cc3994719be71a2a19cb29009128b8fa6abada537bd64f4269c078f0b0d938c72fe935bde7443560ec4f4987e06b21331c725ddaefeb5cb4941f141a339324874547be188b9a49a0881", "8b534eff22fa08f408f8f7ec5b300a5320ca0df43d41b506a38315be34536295a1e1c7b5bb9052d2be32e03fe0a48f6071d870be71ddc7cd", "56"],
-
-["e6965a05cf02e24aa7c20ada24045aea3746678a3ebba7297bf0855cc5afb1db7d24baccc43ede45eb58210c2b199ca19798187e4ec4db9df504c23b651a90dca04d868f0ac73473d63c8e7abecc426df72b493064ffbc480a89ea9ac488a775375ae7d309658bdc4eb57aac43e6f4563f452329e632f332a0c924014ac19eb02b93042229df8b893c0c0d", "13bd4ca671b6688488a34f05a5a868840a1395ea4c35ba21fa7179cc7f9240354a6186862c78513d0151fcda92a237dabaed4fe9c852b05425a9a8bd37cd6d12843a62c01bdb9623301d8d41064844b9120f6ff65d6605a82a93fe75d9885ceb0b7068ba07883e205c308ba4c842960759a27d78d216c1075058055c2d6a13a9", "56aa0a589f3f732d3828f8ecee6b22ab6a56af8eaa07ea840e61336abfd5f71583ab2577a3865b552c0b0efca3ce988af9096b8478f0677f", "56"],
-
-["45b07738277c0501b83ca6bbf19bab8544d1bb7e231a73dac16750351c54cf263b5bd218b447465572eb0364b513f3955abb77da5d7f06d89c53183ede744256139fa2dade807d08506d27f8f754232e17f28c6c3746b3c9df460a07a1d0412256e3ae25aa0ff241db2ada20bd45837702dec2dbb858c2d513a8ef386d5013b28b91bfa222dced29998be3", "246690b11b6910d8f438d91d8799a8199a889a4359d942b60020f935dbce756c709c9e78fb2d9caa1aee3588ca42561592dcef53fe6b04054f7d02b9719d4f062dbf2413a0df9f58ece414a92dcaa570af00123a5e0fd2757d7d62779bf1ddc3c397cd88f4c6406bd0e11f86987bbcb158beaa005f0dd18df2e8c1774210472a", "6507b66e18d6927d0b525ad606d6dedaadcfc31e089c9038f5a364b48b97a33c1015b8441e9c8207a635a1cee6dbb4ffce58770625f7ecd7", "56"],
-
-["95af10920dc788269e70b8560b73135cf7f6f5b04a502c7bd61cb74f3b8ccd160701224922d865636a860d949ae755b970d3858c0ff37418a2d24b7142378ba11ab352e5c876da1a076642728b73916b2d24f8024876572363e7036510cec7f413ed28cec749ed33be3adf56a8bece597612d478bf84de85628367946df887f73dd92d6de7faa896d7276d", "61d91f317a902ea0944e11e92e6657a589e17abc027fcd869ff8b030e8870662f8a9e91ed3239cecfa42c0343d66cbebd1c2b771a25df7baea5cafad038424c97afb720e644e7d1bf5b829944ea2cec69766e68e4e580976de071c2274c0c5eb0e5421c9d51bba76ac39b3d009204680035771d9ad79eb02a3805d58e243cf0e", "6e989ec9cbf010ad6691a672ff4ca90a00275f9ba4c81cd147cc506e1dbc8bc93b1d96a375e493503c0ac697f7c45e4fadf138242df7e06e677de245afa97780", "64"],
-
-["27e6c9f270b9855c9658ad0e3d6c9a111a624f66fa64a49a0688a49b454733ca6230f451b0dd69b76b275cb241967e3c101b4fe8f2023d77772210a63157854b763239a061eec9df1aa6380f57c6911d23c0cd2edf00f63486218dbf35612a17ea5262878bd3edfb2b3f08ce8ae419dddab792e0c94517fabbede38e574d685546fa35ad37741d34275996", "df24279bf8277ad1091972b82594d84677e54fe5d65786d19ab5b2c1ae0a3cc9e7abb67f9477145d575e196633200f0ce557bb5278b8902e1496233117a7df69660bfa87068aa73de61e8eeaffb179799f275086029f47c323f6569bd18dea15054ddafa73e89c3a5f61b98cb2ce7e554d5df4cb9d95135a70de33470744c393", "e6f6061275a89345f5463cfa198d528e14047d478f69ad7a73432f18f88bc68a1b8aba2c3b025c93b25deb8f403763a55024408a97a903e95f0cb6178e7be389", "64"],
-
-["59aa9d7f583f6ab90b472935aa6dea95e2fbe402cbf70c6e1992e61c96c49b63d0304daf0e4da7c889c7b857d92301a6aea3cca7c069c03809deb3a53155bf6e4aec984bbdb31c6e84112c089a63bb0eb0e5243d22d6c15c29d7b9c1529519162fa7275d4cbaf33264eb2e50d5743f57528b94cdd8873662e345a178e1cad2e2e729a1eca3a7519c921e66", "b9a8865c3a6ba8f2c13f35730b39fc3c92405c06bb6e116851b84d9d32d20a88484d9ff5bb03922265b4ae7e87f155b0ba3917db28638321fb3b3c661670505603aec6a92d0776a550971ee52d68b15a8293f28f39a84231e050b6ce59316dddd31221fff940fb846830ac316765b940d29416a95807f7a0e73fe35f63dd0a25", "d78285ab97dffed5f16d00a7f277eee9e9bbbc5eb14fab10c189739965fb3dd1c196fbe3b01363260bf688955278884812286dcf81c25f1eb17cd2503ffc7acc", "64"],
-
-["51f9ab865146187fe650e49d45421fff28c45c3bd8c465458b762d93f199067e0afd14ec3a42022c9fe2f321a272bca3cc245022dc917b8c16b5d471dd3bed6684fbcca762c29f002451abed67a8860087848683b126795f4056963c46a8b4ea68febafff04e57c21da5f348bd6ce5ddfeebe6a6820bc584b1605d3a5366c5d35e0bc63e0c6e923c31e7b2", "b96ac1ed835f1e58f5327338fd604fabd399bb65e6d9cdf716d57a512398ac86656e94d0be0142d4cec27327658aaa103e818290ea40429f0a32b2b9c1402d9969adf845853ed24af79fcc4974025bb23409acd0eee6370603c19758fa59992c2cef9352c060f743da9127a6100f8b191ef7e22dda14d5b26a48d236b42d17c8", "d6aa880ea45e0ab267e47374598086784a7db2169043073614c4a1917e90e8dd2818f63bf8a899d0bc615e60abc57d45e20638dec691b4750a36079a330e5270", "64"],
-
-["9cb3288f3b04f0442f2ca8cdc41b0f39ddf93f898868e312d509a422e941e4e64e3daf8b8d33eea4ffebdecce07fc18ca55fd47f8f9780b38155d4530fa53383d7804a8c14054539700643811ac607651901f01ce02d2b6e2200275ee732490be084e2d8f7015ecb2b84be339b7a488ee0e97ca9a0b24b096013848a9a6f8f4610525db85fb09f22d76d36", "96a83010f9d4f0405826e8cc941190e07c33d933362db680e1b1db3a78ddc47cf9c7fa3fc75992cf4d5ff680e5dee89cf8a7c3a1662d04a928940a2a340a1adfa05aa6060bbecfcc39f52806fd96bd215ca4545cef62f2348969a1201af7717fd38abdcf8baeaf1f621306c7a4e21756f05112cc9976870a4c582986f34cd143", "cb700e68aec9448b67ab8e15d491aa070242b4430d6c70db6b736ad66756065f417c0b201395e203c57bd7809272ce34d4dfe1972b7c5277a28d71c7f52c32fd", "64"],
-
-["cf44b9d057cc04899fdc5a32e48c043fd99862e3f761dc3115351c8138d07a15ac23b8fc5454f0373e05ca1b7ad9f2f62d34caf5e1435c451f3d927940e8a92c805ee3e754117c45fe0de0545e7d1b3f0b71912aa2deef5e5ee661a6e95a06f8727ea158000c91fa067b03a7378066619bd61f4ae33b7ec2fabbf1d0dc3078c3ab0a5919004c159f7fd79a", "ff24c8943c8e6d3db40c7ac16776f756c44803ee07a3c95bc594afb7c599d0031261ad0e4ed41f98495391b8d3416b7bcec2d1ce87c28e9e463a4b3d23ae05081cfbbe47654f7254ff794c008c631a3262dcffd1de9b67e4fa8140f8221f68c24478610627084cb8fd0515603be4ebc3a81ff3bfb4363d770cf4f7b06bf3e07b", "2c537d0edfa86126672ca6f0081e8c13fd161510d56c0bb6ef015ec35cdcbadf4fe68594fe70820ebfd99cfb83a1fd18febfde2743bf408479a52c3334e39141", "64"],
-
-["0f55624e40771d01c2643c2bef1c97d5fd0eaa1ede76953064e96874a92e9e02ae50e75c42f12b5b26e1cb696ef02af12a006c14465e7d9eaf525538b7f47bdfbb42c89403706e55e97f394d3e111448e97cce69d11d1e1ffeefe555fb5bb4e97e528e604a9aefd855650c3d26285dc082aa5985475c819c98e89f333a0c500a3ea9c027e117b5cab0bccf", "0f565c68deda3bc803d93246a1fc18ede3cf16d1e217adfcc965faab37eb39bbe48f895e883eee12f8839cd492587390dc3cc6dd688560e7fbf8c9aced97c56cd3ba1e5a5c61a39879c97162c13d718a132f22247d8799825c3bc663c520f8672400a3c623ec6242ced3385af4541bc1d0d1b30ee8c55fb536577936862fdb94", "db88ffd6256ef15c09d67244d84d4ba61730ee6eaa565cfcc4fe587e6a950cbf69a7ca19ef489b68f8dec772550795198462e87ae414ad604591d765b6a2ad0a", "64"],
-
-["a50b14fc2b1852542a497dddb86709c49b3285f26af9d93fef69cce2d0a3c92c6e91e2770e79155937d2ce1d5a57ae73b95b8b66815cb88f39da868860690aa4520621dd6ca7b20e701633632bfe6f0d5546863ab89f354495595728437bac3b1912da188ec9b1dd9fbab3977184d4fa389e7e5657ebd8c6c98e48abbfff37588a5e140fbc089b2d8a6957", "f4d6aedd9a34e0a1822362714d4e81794b53b266417678c16a97887bbb612cc96bc5e532b3a654e5d3d65a5155427ff09569906381138cc49e3fc2384c5d33c34abd3d617c487b52ec6ee7b5105f41584b7eb5cfb512b8c31f3f338d5236e30398a8ff927e801c8ed7d14fc5040d915a737967d166ddc266f68023a357530431", "0d99228df5e7ec538be55d8852a0c4ad0ca61befb94f988d2d3cb68c006c0fed69b867e1a4f2a50348890fc1ec82c46ba72fb8d585376037f94c9fd18b67a839", "64"],
-
-["bd619ca4cf382df22b99f1310a6498633bbf0100220a578e011681727691b90645c57aeb5883a0a10459cb548e0b04f9ee41a39ad27e6feee651082d53cf82228540538232196f7c982bb19106197da69d0fa45858b1878f4a52805627677dea063bee1065e4e2ac6e756c9079754c92d190cbe1bb8de799b480b094fb41f2844f1c143efaca40590ea0c5", "acf723e38ad26db1560747fa39674eb6d8546af98625a677b7cc3f47b8b5fae79ef2bb817d96546cda5e9bf66297bf61dc3bdc2b5c5ecc93b9c8415842e410c4add9d0e950f6a42b945355fea6b5a4f16fb3deecc717b0b5e5873db91a656e0ac0f389a46dbb06f46c2e9e9b6d8ba46f7b0298c22f1afae823505cf3aa00bbc1", "3c13c0897926d1d45c67f68a4e1c1bdbee8f601affa0edf8ebc6b567d920962fb9f14a4fc92276ee1a266258ea7f71e09241225053edbeb7f31130b36022dc13", "64"],
-
-["107ed9ca1f16fbdcbdfa5211b1a9ec8d9e03bbcb1f0a468715e99fdb403695a80d742a37afdc4820715daf6b4be132f6b3d22316b5dc0c8146f59f6cd69bf0f0245de2b2569ddfc34994cdc526973d503698e594f7ef503f6f5bd4a1c043c50dfd42e8833ce32a8deca9926769537562ce5de98a0bca7b87372125c127b67ac83f2a24f28835904603a3e8", "204058177a61aa45bd666bd0f3870645fd6b9330cb91a89ad3f072cfbbda04655926ee79de8e123c4e56b3af342fcfaa935aaf231f10bcadad22943fe9dd5d3747305a9e3c11bbaead1cba91a87de36c5211b39c20c865404a4e09f77dead6ebd1a6c10efab04fa3c200a701b73beb9320d7c82436814c5991bbf87fe1ca1787", "1bc503398a25223fb9e93ea65228d2727b201f0a3fb544a300dc804340b6ab3676862b6320bce35adabef387ec628da38277f7de6929c44810c47f2a7acbeb03", "64"],
-
-["8aa3ae798494805441b45a59bce230f9b2bbb960b15f2456727807636879983799241f48ad4fabbd44e7048b8d35e2de15605cbadc34a3e2ae21f0f9b087bbe73a312e7ee41fd95ee488a715971f118b5d96919eaa605a095abc468a45ef104414d0c362538a72fdc79e2a3f012725c933624053436daf921e4ff05f4f39fd15d00cc63b1f1ade63953175", "20d04970ba4d2cfcb4c4e1cfa3fc6406011e8a31e869e4ac1b28e62d46e1489987d0ad1c2c938378bf272a9d4013587bb1e3bc14d8d8e1d540de562681be0c40c195ff3e7b8518777faa520c3a501dbd38630001e72681559e2849d4dd758408d70987002d8ab5a2fd36a3431b1a7a759e849d209fbc8cd53def095ee46c799a", "45560765e5e4644476206c7af9a2744de1768b44692a1e38567390eab59b951e6c311576b8c33b8e8769149c9bed6b5e1698ee209a8b464e032f4bdef057065c", "64"],
-
-["be0a902d7d0a1a31c76982a5a4612e27ce13c887656f94cae453017554f77e08bf888ec8e8131fc139a5180cfdb1314ecfb10781521070f723480b9b0c481c7b7b18978332bf7b8b3f6561952fb5554f7f85e4e053b976e06a64dfa8523130cdd802d3e7c3d6d797c2f088c8a2364334c4d7882acf30518fa2f1a2248d7b6206c08d697b0320daeba88fb3", "6e3662888bd3f3d6d980684854d93883dcd2da3637a8e6aa5618779bd9ced347d5204ec4d4fc6b961d1f458136882d9ca82d95a702ec2d9e20c44b8ead4590a8e745c994a2d5130890744398bf8284d063f74280d6544757ddda24b32dfad3b82e0e9aa0fe463251b9e52935f8ad85469797aa68bcc87efc14c1cab260d6e49e", "4a87dba3c394b7f9c0d55041043e9637bc5713a9664daf4077a37fc55ebfadc5fd04a28a9bd13caeda0a7db4ea7a01eeb1410d35a821b1cf4e8c66475549f43d", "64"],
-
-["6dfc8966adf3c592d0d2895f5ba20d3cce6d7eac6ef6e99d47a75bf672cb476f94fd27a5a7e0666efd117e69815a5eda41290eb5294095f911ad1b3ffbfd4b9395adfb8206e10f74e6589a3d8ea047ee9386f8f18dd1ac551d30a6662e70cc817af24d26505d864f959027000adb9120cc6e83872111f65871164591938c91e04bb664b910bc94a5129f6b", "97449ca6c272484156c84d60b7afc1cc59546a46cba1df1bf56beaf89eed31003175151cc05ddb92493d09da385f13ad2e73375e0184a66d042be45a880371b7a25ca9812f34e9b01663f30dcd1594441f7d843a2cc88da0b150efc9891304b87463207e18dfbbc345a1d2a27db98abab4da17815454dcdc8442d3edaa05302c", "2ffef9fe1a0a7ecf39d730564020b8f0ccadca49e4c1780e193e1901a19787db8aec9d7af3b9b7d5a96c2d948f81d89b0b0aa23e4fa350c3d8983556e1be2f4c", "64"],
-
-["1f2855e133cefd5e9e295a3c63fcb3f9185b5fdcf56b7ddc2b69e5bf76ed5e7ff67011f13b0c3da89f842d18d88467f7cd2a27e9a9c40b5b2edc10af4d72bd9af3889ecb25e4f4ce4f11ab2fd9a78ce2fcccb4e2d4fb4519b6045b80c3544287bd77e6b71adb8a9657d7bdcb8feb8a824c4af0129d9592dd69eaae78021d530dfd45cffd427b67c95fde4c", "b55e84a2c74e837420120c9babb9813dff4d545833587664f4a71334d64e7c1ee8ed655b8b3150b0849d494e4f8ad4f8b66c2463a3588de233c33c430b8d26f3ef3e3e69918febc2a62cb9a93fb84f7a5711a1a7a8d6665ac427cd5c60ac6b35f3d9789580703cf2af47c473234bd90d08c533ecebdce914cf5b2c487db5352a", "61a87e67cdb1cc9b3aedc912b28f086c0a23cb5be98a51d60cdf1ab3e0d288fb443881a10205778c6f599d79dfedd4af27f1417131d9263d995748df45342d2d", "64"],
-
-["f37bfaeac36a4ec9d379ac509b49fe50f85a995a89d8c22f59b87178bf0455b78373177e423de3df142d25236bf890be3fcd6583682df2154bfd599eb8da92c313acb3d7b27e6f4e878ddd75ff9e7b61299573251b441d499281c477d87bad76eb4e555ddbd4fb0074be5eaf1de4c82000da4ba96bc44bb2766b3d6be790adba5280867885e88edc23eb30", "a781be58853dcea37c1a30ca5db6e16b9ebdaab800ffea2670c695652b667d952615d0b7adfde2614a902db6e81c9796389ff31254dcc923a3ae5a9bfc9ddf5b0eaf12c7ff80ef775395bc2f10ee47121fda6f12f610e5ab74fa34d10c54ff2726f68a71c2ee7d4c5fd2e41d1b5579471b1726a12dc2c627ac522cefa5b4665f", "66dadec2dd4e47418e8116d5ce4c2a9e85fb82ea0a96257b661d5ee9f4b8568a0021602f698f20b5c3c77d2533e1c6760eca0abaff7f1cb0834cc5064b19b102", "64"],
-
-["1bcaf96dfdbaab1028396865bff3fd9a87a6046e9130aae91aedb54aaf3fc542956ed95f67b316cb018fc4369d619a113c383bfd48e600837756f805f21f2df4b75829443bafb91d54b5f1d4577c70197d64e6728c32d3e8b36291ffb5bf94ec4a30f103c2d51d6feb52f725356b091b14d20bb4a63ca97f96ba872240478191159d3784414ec9278c0fdf137e57", "9d1c57b3e625d6a7f38462459c442efd5d842c0b576c3104976654af9b8a97171259c9dee4afaad472f351c81f91cb9f0faa05a32f0aeb1d755ad225699613b9e4c2f90d17d9de22d007909af917facb612f0012c0bbaa69f5a7853c91d1467999beb1e7888cb9b89427e96132c002a9dc7be6001268b4139fb5359d1d227780", "ca7f6abed1c2252fb7f08706d119fedd83df9e09c12e481267ef733bc053f945", "32"],
-
-["f473204e1113981c4bbae6ab7207fabb97d6112a7114346800d2022b6f97b0643ffb4e71a2714c9425653ca49287d902156649cf9d409f5c8518a4e2740ad77482dcb2319cd52fbe29d28bf4ed964ad52e627bc516c53df759b6822518e01384043dd5f7c6e64a829f2a5a024bdd541c2cf23074a44d171fc3d2255a22c26d7c0e473d29e94da4c9ce9432387403", "61a9c83487fdb8df536e2a79f5777f716e2a4c92adc7a634f88d9db48ed006218b4c7e380bb9ab9392a0c51dac6c55c9d0c43d848a18f968da7a2432d7b03ef69787ac11536a0060c7327bafd840d07479c92bbecf727268931dcb92b9025e514f015ca6a73640adb8cf5517c21a78da31e21f27c4fc9a9dee1d99bc7defef9c", "9ef822b71d2b1f44c0c478d0a16e48ae105fc01e4c0cc52e9dbc6821a1ca1e3a", "32"],
-
-["bab50194e96f3edd4fb82ee8d860b4201440934a836fd35c10b96793db4c0cc03bb574e3040ba4fa056a9b1bd5a334c1d0324d645c71294c710b13a658b244d8b2150712bd0c1b25d3165999330696f904f9cb72978b749ded4b3598796cc35b26bd02499cdb6b06bfa4b18979f0a472ba7c559dbd277bf78c611590c6e051f2a094adb22ade5c44d4fdeb1330c9", "11d69a89bb195025ebba1bfc00ed6d84277bfc73cf66cd815dd0916f211935488948b5e795ac31b98bc342c4ec5a8ee8a6f69a658b73e09e02eb221dfdd9fdd62b7ec1b06e8480418fe265d931ff47c3031a7ec73ae9f6f33f80829ca475729f5d4267ec2dea66465ee02c44b524c19ca5af50a0c31136097a5959f40035bb2a", "6a3577b4161fd63d6b87301c41e4a62dbf1fade9fbd73150e03e1d1e1dec72e9", "32"],
-
-["232bb8903d88e295eb89fb358d617dd28c233eb98f2746a9f2afdd8f74cae9942f797759ce119881fae06e71151386532bd7686125bf6d604066d16abe5509f1dc27570103eb9bc542c96c4dfce9678ede90c7ab148c3773bdd3e42a9c67d58c107c7f0f46dd9469ad8c1d52aaa8c88b8e94831fc55649e94f3d3c5675c7f56cb4c1180bfeb02b732270a807881b", "807e05575f3ef05a4bd64e0b15278fedbf7ae63930679cde2bf7a9c68205855b87ea3c4ac459c186ec878491118f46f716162c1b682a1335e970249c2d7fa841e9871d7ca836890cc0dc0e5985cdbffbbc07b2805314566a4f32077805b2e0278d9ed177749ef1f2b11c15ebdaa7a10498959ef359ed0d8544847be27265d614", "ef070024ec611efa3315566a486300a0130447a3b25e25cc818bedaef568e4c2", "32"],
-
-["88fcb4049978707d6c8ecae02ec20005aeb8fbbec101d452e635982d264248d53e032420001529a5f7e6d9704a0e3afae2e32158cf47b0b2e8c51ef7b2ddb6cf849b23d1e86feaf74338384eb9a2def24bb29a3a429f9830d1e43844f6931241a1ec387f182eba5b9aad70e44225f3d72f4eedcee2b093b4e7b96f7cb80d6312de1deb9259fba2b9426d0899af40", "97115f35a4f5d6a41c7fa2446c3aafa4fcbec6b505310473a30e8bc72b2577acbee414921ed44effc166687c24f0f1af62f3f4c5059f70131c9907fc8efbfa15ad9d55870e9367b599f6751fdce8b086a4d26352cff071305c10ffffc4966eed8dc48cdd1c380b0dcbb632f53b28d272ece37c230a2cf1138afbda9976ffb97d", "35ec99a24129c23e90e4d299dbf1eaf4b76063d927ac7362550ef20c7b2de0cf", "32"],
-
-["303134472adab1a8be3c75e3bc73791991ca2d0dc92f1a32d2e5c5090379d7a9dcf77c9f905d09f9c050e19e8a0e99e1c2a45a557f6187e2da8aa018f24f67c434fe41c1d977de11afe01c2c1d9a29986173e8ba1d1d97ec12dfaed02b8a0ae3bdaf4a550d0f7a181353ef377dfd7169f79fa0e6aa4a9079024ab7d6b510092f01706102350d233572111f64b321", "6d7b0d59dec1b52e30970ed18231cd2fdb0dc8d36c255db71569125a2b6c7912795e57a4843a0412144015b4efa6398ae88b61d1182c24d46c5a6105602c264c09f1d09f596874d39f347eb24d8a984dfe2dce94b79cef236d89365fc88e29c2271cc273e4b4cd1ad95991f22345b22025db5643d0dd874c22dfd990edfb4c1b", "adfdf26fbb84fc8a43bb56affcdb0248180ee613cc52ecef61dfd18d3dd94fae", "32"],
-
-["86f7194925ba3118aa93abd532fbaf55500d7d295d895ec50f1ed9e24de929204239eff0b19525b188005319b77b05d742cc7c76957ea485192946f504f37fc2755771d6c025382a06ca1f2e0ad2146b3fab5a6626ac7ec8d6558cbddcd9829a46d905c2fb7353ca4e7b79dbd208c6586f337fc1b51bd4d8712d13027ea21cefde48157d09865fda859ac8e9b726", "057df9cea6836a2dad42f66e7d5e40f5f6074b0d497ae98a0bca76ad89e0c5475cc05b213d0fdb9c1ad2c2956979548bbc1cd7049504fe2c7c6da8c0508acb2adbcaaaeea85a4f4419eb3506b4b545cdbebc03580b520a2d14ddb6824ada205daa224ffb926cac6fd314b78b071bf903256e39d0526bf3d3faa106bb20d7ad28", "b038ea88226289a74e946cfd88d5ccaf290036160c27e7ae512f7d7f0d05932a", "32"],
-
-["3464e7149f386cc608bce78676aab7885c7909956dde4cafb194e5f8d95924d0d039773e920ede9141489f4776ea3b2dd5d5a0c8e449a0f41da9a2cb417e98c8f0bf312c77504fa1e9c8bb1e7177180ae6a815a4a023e7a28af6f7187975d79cbc807ba8f504a64027c4a2d40f5a3467b878cff14a74080222044aa92846cb945d03be78b434cbb423c5bc31f99a", "39b67d9e4acaac11338d2f66ddcfe708dc6998bcac724a793bb6ef79a86347904b0601bdf5b623ff798fab696b63767927ab62a615c4b0382c58ac5cb51a8f7852b6a4bc3e07aafad68b7ab75915e982ecc2f084e12e0a5938e8de1e66231ecb56aeafc3ae260aaa536ae2772f4f07ca32958a7f961a2283684ecee644e7bc32", "2e8d890e823b46ae168ec19c67e777809e8f94bdb0119222d53106e470e060e5", "32"],
-
-["98ef836985b9b46eef4ec02a5a6f730b0d986d00c7e17582ae2210d5885a528b2d52f54b3c7da8d00e23a934e8be966f75a5811b73e7427aac7a11b928955476a15ab58137b878df65900b153416dfa3ae7b77ce96c9bfa2560e9e3839bcce35da50ec93dd32cba4dcdc0e53a43ff6aea26278a1984d93049425e0d923986bcec27cc10a95b24a255c51d978a200", "d0c0498f4dd2b8967cb7f26da95cc2011cb7d4a4ed5021b31a00ae76d7f876472506a4f7a614066db0f2bf90a0553c68711f697f8de0248ee29df25539d1f0cbc3b20d77f5b5771e9654539e170fb11a22b1bf5775d43c66f1a73084eeefdb3ed24cebd9e2bef2f05867165fb5930b6058f53ef4503353856fa6d2c99f5b1de9", "35a6692e0928dee1d02af26f0b541019c0b517d387562e27d73abd6e6f66107b", "32"],
-
-["deddcd62e35315ca2d328695b618917d21af8a99fade092e3e4191c91ed76f8a00fbe5c3c79688c25492e7ea4c926d091ce089e53ba60b18af62d21ed394a4ad57b95d44887d4c07d22ddaca836b6c6cd1258b661fc843a37cb0c8936de4607bec007c0fb0722842afe9a65de20b564e4867da432daa7678ac15bd4d732e6a9cc6b68c49c7dce61bf4b095caf1da", "7bc9db647d4a08111fa66bbed1a090591cabe8258647c6afb68eae03d80acf1f43cacea859b5c5dcaf1e8cc86f9837bbfa0bdffad107a6b5b559f53a0ddc08698992ad6805f34898b0851bc76dadc4a779c5077e4e6ece20c8286e1b87202c319448000b47ad992b6806a02394884104e202e81ac72548d63723a20c0d7d445f", "2bf0422bff8e360dfcb02f8a80a8c3de837f4f0af5a3f48331be6f30bba000be", "32"],
-
-["a5f018b221cec80442616b4c1dfe51a727d048ba40ccc049f0e72d05f087ada6ec38eb3a87a143bda0b627e9d32fc14f4338d9a7976afa6543fad08d0930d5c7bf1b2db65284cd860b20e3c411097273306f3d5cd88be7a569d558a5ad7763f7a601a5cd8b5eb0870d076985de76ae3974de3c800b19d032e9e28df7e5449e5d2a521c28391932ea2dd37fd3435e", "581bab213da5a7a29b1ebe1754f6708d87da8a32fd85a6c8c4d9f154192e31168cea06be5e516fdd2e9f919ad454b4a68ed08f725a1fc59fa216afb61e9f5c19f751d6392844a70a2a239cb4d082c98c7072cd424353585b0eeaeae202b4dd5be179a5f963235711d8d168cd0e28b72575e9ce0b81c965e6507d28428d14bbc6", "26e309186f09e64b23db3bc2adb2ae4de485cbe1df97b96e304d4ef9767896d2", "32"],
-
-["14c4e7563119242a560883d2546b81a3bce24ee24f78dc87b684354074ce13b918f3aa1bb70bc9d3998821943361e49f55719342a51a119e4c2dfbaeef0e3c85e113dbd7972e18bb5cccb8af45fc9ddd68d4999d4910843a95fd8cf91396cd57d7ec2293b9cb3a6c059aee2c7483d1e55bd409fb23a55ee2f9bc9129c6adefb8624525560529566efebb6b12a73d", "fd43f5fe73922e22d7370c60de1f9b1ab2e0e9c24e927d4a2be025db1c5ef5c9761e8cc7757e9957db439e85daecf4d53440107aa12a04c871c648ec72bcbbdb14cde588cbab7f5ab50e35dd7d04aab87f88bddf2d570b09b1ffb465d43963881e7888aa3ec2e813adbf58d08f10c5aadae17859b9c298f433b2f1ac087df3ba", "6f8859b69cfee89dee650d16fd00749c9f660e6fc9b927caf02bb891c812def0", "32"],
-
-["63dfb4078cb38bcc97bd250fda0e286ecd4e64046a985bdfda8b01b34d9dc0cf2ab3bf5168ef64963bc918f5f4624aad2214b61bfe4ee731069ffa23e710a663a0b779d0e589642bc1707aca809ef590cc207a1c16b69e4138e2a8b6583f980f0f1469ecca29947171d9d5f6476931ff1298386e7b7788f7b77b92d567d1a1bbbdf1180ee176fa51932db0e1116c", "1cc6063e9ca0afe0201a714d023517b113938ed73a89a5e55e7711478654b4e93f8d1fa93f10550c47a3d12f4e7bcb01db0ab8b0b3181061b415532401ae4e275f9e9b2adfc76732be81778289326dc7841f48934d812b0cbddc5fe60f8c9a8e44a082a8c5ec6dbc4b5a6b2cfc81866ad779486751ed7e4f40713c73f9162187", "9a3819ae5774023c1d7deeca6f15a732f87671b5dcba791ffa5340f841b9015c", "32"],
-
-["321e8dd5b0b7c660b3090544612c5476b0d4e17ae0cba47a499f429556983279d2ec1e3462368c475325bdfc9671d5fa09143d17f9b6466de4690fb08396a8bf07377778a447dbd14c771024bd2353cbbd8446eda42795971c9eda0f2575be655c68614a7cd2fd252569c664dc291410548ec3a5eb06da2078a66c59441cbc9356e5a452f4c0386d6662a663fd6b", "cfb4c87716df82d2a97ee4333417a67fd5e66be2da89a11ecdfda823aec936e17f79fa5a064e7ffbce34542c6ff46a260fabbd04297b361d8d80d092a806fb2306f93e47159c1f7b2c7e47dacbabe2ee251f45c6db80d3480b0c21c989d058db6c1adb66cd322afbcb450764d2a2d7801e294a4e7aff3e474e87e1f1d88e16b1", "4c978f61005e9a04a733205f9e8f2f3a40b8266206558bcee20b5193792fdce3", "32"],
-
-["36dc8b8b97c105dc9c03908fccda0e4844c4990d08857462976c41b2c2d974f1bdfc078077df705127a7d04b27176df00655623dd9f195d1d123ea521354b60dc3e077dc621bc24d5b927c00d8934d51daef523789fe126cc9e4b4cf0d136f0670114167027ce4195f4bfe76875698b4e919d1c5c1b4d37ba43707f0591d8525eb16cfe9fc0abefd5c5abc75db69", "b5fdea4779f5787e6b947d0b3333fcb115528b816b3862a353dab253a5fe382a28a0119f0595d0cd7b6ece77d59fc14999e1906003d4660db1e906d6a07f5ebe574c5ec762ff0ab40223ce132347127b8e5c0f13605ee153bb37dd0fbbe024b1acc978c67a7b4fbc687c26e9057218d05d8cafa06c1efafed88be004df68b38a", "0c27ccc84bf1ab92f42edc8188a1a0b6b38ef732ffda929f767c0970b27ec9f8", "32"],
-
-["750d16ba014522c2ab475e8638535d5e729fce4a284aa48777917560484520471a7cb32402888cedef25be7733f6b68c3f331e3dae9c017986b130dfba24e5d1f38fa7ca8f636df1ea2006eeddccb8fc859e283f46fa79590fa2b838df2a783188acb953b3be0ff524a100923fbbadd7cb47b5f9f74c564c9acbd512bf3d2090613db3687381b822240e720c60ac", "6e9aef113947fafb961eefef891ea3f83ae018a45670ff6fb35b7b8033092893ea4d5c37833bad39df3ab360005a0f5a26e7abf7373e449564df26dc0ac437abd6f137512d4d4601cbb0a780aa3dc32125f271f35d7d7439dc51451a78fa149a7aad09c90024cf3aa0a953b74e70c9338029f10090d6984361da61a44553c54a", "90781faa0146879a3645319c5390bae2d0ad0612bf2e6e7dc741e7c74b2ab65c520403410f1eca27", "40"],
-
-["3f64c815f2793ed4933e374950f77e685ae3dc21f309d340c9358f92823d1a499888cfa167d111c484293deb513bdcc7ced41e1fb2a384babb3ddc7f426372db128885637a8ef8bba1d14ab61a66c8beb8a50177a4e47b9fac86a439de350bea566bd0a354375a80923e47f0d400a917c05c6f70f05621b74619d1067f384e3e0b399aa81c549edcfe781de2a68f", "fe7ae0cc9e99c1b0a2bb11d71db429cfba959e655bc80860ff5c086fe99d895fffe8459170bd8bac9916408f2af7258a844757cd66de1c20c9dfdcd508486efb44c796d507d0cb8b27ec3d1eee5416a177be00a75b77e8f88eb2028883200ea997c0d97facf906b414e60e6eb2f1867e5ba3a1db82baf54e157c826ea24cf0cf", "4bbba9ca72b720356a2ce80f32ed65f6988b8769721413ae44014a72cf34efc965cfaeb44b294445", "40"],
-
-["696b28929d149b340ffd609ee488e7d420748133bea31456012013370560ad9699c8459f517023c7d62769f781b748ec286a73de38ccd45f04b37f875d7de44e639948399a49763941a5b1d0dfe4ee751ed7403adcb694ac801f9efa8a5c135a6fe519a9a325fc51f781227025974137a9c3cf90d3e8ba031b6d5adab485a48364b4a037928f1dd9a1c3a3217c58", "c66a563cfa22744dc310137966f1488f433efaee105f4352651c18ed7d542dba3bf1130e6b1ba3704baca0aa49e498200f5f0b61bd43d0fc75beb3951db0633760a48cecf605408b268f9bd28903a7b4101f7df448cdadf3dd9be0c75e6bbc8c3f6b08ff244811495d9f4c18acb53cf44f6796d13a64a4e0a599e7abc6ed0061", "dc6891c9ba1d783cd0ce19c2e009ecb70d4fe5f99340db8dcdae1f51c741781e1bbd5bca92af6f5f", "40"],
-
-["c5dd58111a50eec6d5d19591733ad0b8b39f78834a2e0c43d85948931b38f9de62280f2245b9f4f394c71cc28de6de5f520482bd9a499bf6a642978e773f94fc3ef97566147835adeb347ca9c47b70b62312ce6fc01f39b98046d204a31308cd3f98b267ed575bfcc262dccf81c031c30e13852126788f964eaf217b097cfe594e4cedad391c0be8019765ba2a7e", "44c8c5204a8d2e01951e67d1f2e2cccdb7a784d556e6b1cd3148e943b06aea5291dd89c68072ec80e8b4f91d08b50ed2490ef39acf210d944ea1d4a61f55876793181e3c872580c13891f66a5919df6a631ea6d32deef2ffbc451c305c14e673ef806aca862e50892ebe44010d95a75cade085c897d5c8d8243cab26902f713e", "b83b4c548687d6f09ad25065657faf047989e52ab5d4147eb09a1ee13e69a1ba59d5e3291a2e9b98", "40"],
-
-["c8ce9ef354069f3408c9fe64d39649a7d758d3d71e4608818808fd31081b4cf010761e0a4a6e891589d71d2eff6cb986b071a31e2696d8ce671fa18c244267eb33d0c8e24018ebcfbf0910bb24966be0575f3268628df5786dfd2e6deda219661824c5029ccd6b6b90a60093abdd06bdb46aa74039f2048784eccb5dcb020767a7ba3df2c755b4f0e6f8143cfa09", "e83b5b65f66715b9b53e9856a845250eabf61c48da130af5b039e2c66cb88b9cb9a29b418d226355520f2b8b44c1be151a242a5ce80ac1f544c663d0a8f600b317a058e7038105326fa1bc05512bd0f53a7cf76f387a51a8fc27a6d43876f0984b5d19c1202b0536531cd32b962a609854270dea9409c3f81f853438e5df6333", "b9e52ed0c04ef11f6fd3d17a35281cf18647df94a069a2a59bc73f007cba1b683536bd447eb915e6", "40"],
-
-["6a53dbaa79037233321b9f88e341c68bae9eb8dc8bd7d662903f7a28714b926b43468ef185457d9c605e723e2e152daf3a17f71dc62bcea45365c21e1c9c9f3de41fccd7f1a473805981e25e7c1f3239d2ab26d2e70e5576a3208cd2cf186e09d5485d04c7079e0aa3eeb790d6471c52fec20ba2f46ab5000ad89eec91a646f89f2709210f55445fc80bb97b4375", "e107f34b5d86d7b32763d7345ecbb49b6f0dd5c39309eaae99e4cfd4e501b3bbb92d79c8b31a5e55970fdd044fddd0523015d76f7f78fd4d34af99f6fd3d732486b1280bf820d07ac0d2dd22913c4635ac2ee6345f4e1f02cbc5c23ceb3833cdd2fac8bc348073492815a1392cce616e6ac1c9ba3a7295f5e78e124db6dc3bde", "b0d64486b92f3cf41e8a696ef372143673904dda2301635f139952587f35dbcc209169c07ffe065b", "40"],
-
-["796692a748b89bcb60228ab4bff874f93123ab6dc7c6d7ec852148545bb58ad157a83a5e186c1988869c5f75c026522656af16f9b933c772efecc53a8b764f68dd30540066e585b98eacaf049a0c4849d86448741c8a3ab070b39272089341ba74c58796196c08e7e21b236733f27feb1f0ae6e6da14572f713c5604b854874e842e7b261b49ca99e9b062a87520", "dfa3aa8e29f56b6d68dff72a4c23983a8484990cd487faeb6877692568ab6e6dcc0ce67e72d1b2016d8db0b1a16ceabd8db90e6d67a41f096e4369f77cd6ba23da4fcfa459120d9c9ef9725fbe9bcad80bce26292d6a8a927450e6946cab4756b2764f47073fe305a32a237ecb389f55a6c9c7874d60a44e21a7c64561b37ecd", "baa1d4878b8aef2c59d782bb655e9f51b0c3210f39644adfdb8715a84cb9de57d3177f5a72ad5b6f", "40"],
-
-["c6173613801fbde3223f883f158f609e86d5058c5ea5c6f275cd509602f0c4e557700cf1cb916eb6f7dc61a125c2a05305827ea2a24d79e095a711607810db379ee4d1400180ec637ca6f5163b2398236faebaac46140c6d1a92e042e9823ce9053b2d080c325e721b65e9a63ff96d992c2161b53243a8216c5665433b8f1083f23cca52d3f0ed6b3be0e859198d", "a05cd06ae605a3c988227edf935ff24b38f7d5da1fc238acfe6e9992690c5abbeccf7290571163dc59976c305016ff8660f67524c25501c35f4fe2f19e7dddb47abbeb70d72f2f0a16872a4ee781328e5eb1208c4a7f4a9bc26fb10000a57b9e73a8a3d30f66ef9de8782201ffa873ad5bb03fdc5c45fb7a4d546c88388c1ac3", "f4247272014df37543abc7645722fd3ec0aeb89b6f49dfd36d11daebec40bfa3bfbb16f9d44b1c84", "40"],
-
-["3d6f9ac66786d1eae1a7ffea8417e7cd49b96e355fd44f3f7a150fab6dd8343dfba3b262eaf3a6175a3c4607552b18f3a72e6357f036e44bf7bcc1a74102c36ae39bbf5d942d55f30676dd74778d9f5a836a42fc3ca988697c8b38977a3e5bbd9c5eaafd8cbb48468e5dad7911c5e2ca8376f46a6ab4a363b18c5ac33f1464fccff45c8bfb25e5d899f99c5d4b5c", "2b28f00a57d2b2cd36fc1a23cfa8f533f6636c16b91b8644fb31ee4c5fcff7c9687cdd91deed4e2f731e2894a6a28509e838f568fd9e0a8ca6aa1a067d964011209ca4c991c2fc97ddf853f0fc60093ff9ed1c1f532fa5cea26da6de7df5022e9cb0cd193af9c6edbdaf229f99898cbd72aa69a3817660f238a31a3e29934607", "27f5aecf0d153a94598848d42272c7e36c0ba4aa658d2cd73c1ab775bd4812dfb2713dde11868ff6", "40"],
-
-["19a76e3342eaa5ccfc6d4b92c603f39beb16703cfb4aebcf7b5d33b3dc525395aace6aee06c2b7cd564af4d73bd5e58cc48730cce1db6f991a8d841e80b72cf0e43ad895485fb84393be18e9895fef74f920b54b3856761bcfb725da7178320630b25e2ef00acfb41b915fd196e93ad9f4aaa8c1ea0327de03a5bdef95dc8c5ef4ffa7c3e50b24aa568b73964cbb", "fdefd6dbd43cb817b132754633c0ce724be5572e4e732b7d4813ddef9489b20da9390df737ea2a4c73cb0f4aaeb3c0372dadb3ff35b4831e65bbe8c049f1e7272b42464e2c2143cc948586fa673153583939042d42c2b76eb50944e14ea772822a136a26dc686b5ebd68c09e83c1ef9f169034019f242d35e104d79acc33b9e6", "cf049f7c069e0954b9c76f22a517eca694e4e4abc5d41190b64943be8649da34dd9f0bb54cde4bfa", "40"],
-
-["d7e34449b142a6e1edc5b7210ff3b0f0b7fcd01dc74ca495c46085da7fd862b0f4cec01a7267fe84fca7de42f08a4139e65032dcdb18265b8fb082162bac5e5827b589426b24cd05b4d074553a230a1a90746568cb90e3b1e69af3396516d7cb3940a8abe4a29acbdde5814136e894f97c0f71a7dfde620092c6df2a753e5471f216243b2408ed3e3f910ef02cff", "19c9441fd9382728e5de19e630b0cb95156b9972eed5bc1fe246c148fe683987873bb3858f5ce763c786345894594d9b655a2f83433c56ecaa5e30de9794d269e22aead3ce26f4f6dfceb1e3eb6ad5cb744b0020350cf0f7fb18f5b1b66ca5dad09fd051bd1fb7a9d4bd78d05d6ccdeeeb45f690eccff4067e7ef80984791ba2", "024226ba95f0c73567a20f9077abc8a8ce1fa766dae177f3c8d39982654a0dee7cadf131f0c645c0", "40"],
-
-["b7c3c77899440e81200be3c06e411fb6cf96fdc3f67b7ef38a910b1be1615c250b4d9aa812c6c0bc80c0470c2263022c0c04472ddd534f6f44d6dfb7c3962881b318ed98898ee3e3574f5d829685281a2225b46c8625752d7ae98911aafe9b2ab90a077086033fa1aea06d4b520d5a261ae38ea87957b9d433b251531d017dc5240d3259918d9b9b8c9bbf8ede65", "87b0745d346851f812070f3053c39a906c1123d9246bceedeb51abeb6e8ee89c68bc450ebb0ac77ebf3db17bdaf5b049513256845d16c90582edbd0a1c6a9f903b331304b0a1a11b68c0abfff99ea3ab7bc27b9899fa5c99e75a9a037781e812a71ec8883cb34697b599e601d945db7e93c9795eff9ded6fc5a02c21a65a29e8", "8cab0aba5c0428a037b4a467c32d4d576ee3ba2de6d56c37e81d5c651435e556105caf703daed9d2", "40"],
-
-["f871dc8772df3e9bc76b342df66cc7ef7abf7342c16935fae07de5618541c67e2d8c5c9797727df47823abe67011a1083d512cc52f443c1adb763b9d14c69dbf17c8b94e0667d73e98cf8aa80e8a401369e817f3daa5f26f7ac6cd673bc44db61eec9bb4a70673ff8f35787cb81ecb98664cdbd4abd37f80233442c6e281fdebabc2acd262967ac2c41e1e685616", "64c673e5532ce4debe2682a3d104eaab398860797ff0c4c09430c1caad80481a50163d51af35704e3666ce996006d902ce0055859adc4471e9f915cabf1619eb1817366f3406df5232594354e073c59ed26dada8b1151d5ad6374672171cdb25e151c60988a91b32da854a9bfa5398c86c55d0aa6ca435c38474bc8b5d997811", "6427232699c7b9d67b7f302757a147f8d3e97895a4d6d547514cda97fa348c6b82afe0bb863afd04", "40"],
-
-["70c06d7e2fde3dce44d5f9f8e5cfaf28a2f1843bf598ed211ae222862071d0b1416b0eac6fc593a52fc22694aab6551e01106ab9e2979b1259f481ace871ccc9d900348dd37fb361ac3af1967eb2004afd9d4990072dc6cb7d95725b52cafe7ce186c66a849303ca62464245884a15763c9e08068de1d6152fcedddc8603d0910106ac8ba989eda7faed01eaf106", "2eba90d1335117922bf5cbdb66432159a97f9c952e89f180ed0c6a4e1af98888ef34ec5dab8d773adbf1b19ad8e1671e912499e08ab9930525105991217a487f60ddfb12edac6c663d165b4ab57ef4526d2cac2734426c22d4f1f11997ec7379091d849500983c3edacd939406010e1bc6ff9e12320ac5008117fa8f84394f9d", "170b060a7dcbbdb3ff6c2f6d99354748a0e9bf7ab801b91339252ba2cb842a19dda5cf637a6c389d", "40"],
-
-["01a6f9e95494bf281ed74fae28284e1ed375c1f5fc34187722f76beacb40030123971c9be7dbe9ae81d6d97d75131912fbf45ae8edffd4e8e4798b969f36d18da4af0c292955b9833425b1a2027dabefa62e03eaf2d2ba1c9a60bd4c97fd6af6ca1550f9e2ab36907fed5f83970c0d9f1a4a4463d155d0fc8cd38a747c3ed626def27075d98469bdaa566c8afe68", "11f77655d955725a18ebfd1612b7a91d15a055280db75c7252bc0c61c4e45a691ad9d32e7703d6e83e06010c288ce92994440e0f1e2b0e37720a185f633fa47537c161b6455f6f17c348cfe4d9d018a7f9716b4b7310a8814482e2632f4f115710b607376cf1a6af4cba4fe64c0d9d60ef30542335f19418702f53b5539b0ad9", "972ed368bbc123e2894aaa1ed0b3002f1f2f0f2ea9c3f55eb52418fa82c7878537fff0f39957d387", "40"],
-
-["f01aaa41528ebfecdb9fcd42948047bf9c2366861e5dc0a480451c95dddd8509b6f49de8e44a3493d1f6196de805309a4a64c506c30020a9fc2b5a0af2426f02a68c93c31766ca186d9db3ef771ca41457c7fc3f7ca6b551d14639db345c77e02aac35dcd129a804165e42d0270ccbb72c15b3393298a723675e620f8d75c62aa91a2b3afbddf7df33ff6e18e21f", "7bda43e90479f852717f4786b267ca874fed63805a2bd007a2027f9f29bba381d8ee879c72a322dac7b8eda52a83f1aa24b724dc6a8cf5bc0d1a26d8f106da928061ef239fcd8de0e1e2ffbbfbd0c20c7945dc92af916fb4f1088e0d07a74f28dbfb2288753f61ec29c528d10f976637b45d34a80f816677b792604653e62cca", "0201dbe5ef4095f4d4b3306d9b2d7552e0d68c591ea88368195f66280b80ba0f2f6ae436c423c5c9393aa9f4588b0107", "48"],
-
-["931b7d98f580e6d2278d1b671117e6047a59edcccda191a81c4917de65e905e614cbcf79ca9fb3ea5d70e2b920d7e066646f2d833e88250d8b2025fc320fef19a9815010bba900c688b4c9eccdfcebd5657fc084108f9c0a74cbf70f614dceae592546865006930db0401828a0eecff98671ecf8ca1dbd46de31d53e7b0d694c2d9ffa02111f3468ddfc17942216", "9f848cbef3e29e43766825a1c38dcca8f84eadda22d0672bf5477105c116ae8fa138412ff6dea24e1359f15b3a3b8b12c8b9dcfeac54743c1db95c838de5aa61d88c53c7c2bc41c8a0bea59e553d8fee80a9bc4df4deae026901020d71abff69ad4a6b8f40c4a23b845de972fcdaffc48ae6f5cfe2b640043fe90dae55b2d42f", "9575d542bc82ea5defa50698a7e77c4bc68f47fd332cb3eb52f009987a7bd289ad3837ee50360264c95467c76c0e8ac3", "48"],
-
-["f2af375607397f0793f7624c3fbcf530ff81da1d9fad72439f944f3d403de8f2df6891ba82cfaac3b1ad16b2877def8d080477d8b59152fe5e84f3f3380d55182f36eb5f7d24d9af7c99648ab8bea7ed4920887760f8ebce1faf263faea1b4a48b222d8b21be75949dc5341bfd08871769ddf642c769a57b8a1ece68ca826e5f9a323f879627e73abb396fae7ce1", "ebda765da63dcf09bde64e646505edcf8aa4213c67e80d925393f653decef87c1c26f7ac41d4d8896e41f05fcbc9bc0bd6bfd318fbb21a2764ef3794124748562d13d427987501e6c9e00839d8dae776d50056c45f4bfb7d7293e1d7815127c4c5a9f516d4a8b4e775ca7f38b3bfd4d8306e9088bc3b1a5c2685de645273fd8c", "0b7a197e76e58bb8b3ea8b28a25cfa35bd30c274d41ca25387d43d5ea7145cfa08ecca6e49e45bcf9a7ca1b280cc0295", "48"],
-
-["f6f938107a56a48534248a25a03c6f2f9274e2ca27c20d47fba589a79cc0775d3fc8cc55b2a1ec3492c47be2bee46f460bd16a503a9c483d27fcf31642f07f7a43a6739e1a9c64922505c9c6a66e0acc74a1da5ff1cac7a53bd80fb1a726b64c6b5bd5f59a8f352656af1cd94122674b34acd9003493b307a101857fb1aa435a1dae9b611ac186788b891e247547", "b4b884477b9691658c61733ecd7ad261e00b0169489b3f2ccde0363521d4c875c30b0b352443924f093def9e30cb75b3362b43c9b8937efbb90dddb543856b6951208883edd0319e962682f8e46779236fe1fdfeec7f1fe4999c9f23dc15b2b026a202191e66f7bafcc435343275661b52d739ef2c88aa4e3634856732ab25b2", "343a2499482bcf541bc68e55eff02875eabd96b4e2adba90390ccbe7b8b02c095f29f7b681d97f5435108ef27265e164", "48"],
-
-["409ea037d05a6c189bed16324a2ea9b0694838abbdbcd04dd7a5ab37e940a621d9abf5c094ed16eda5370303066aac8935d8a4118b4f1b5fca540ccef5acb7f0642152751782fc4e4becf2124b4805a089154c06ed6816a16d302286debf132109861e1c02200682b5934a25c2d29d3d8b8696518a0da95be9c356274c81a0fcf264c17efcd01cd30cb47305120f", "c482a31e43855cee2527df70c1364e8f5fa66077929056b2c87b31901fdb22cd7ad7426ed54f83ecd51ee694a39cef2efb6757dd8064004653002ef4e3bcf3fb07e8ebdc4aae2aa98553736ab4baaabe022a8cc626509e3ef28863ed2958d240923d07324aab396e5e4fecb8999768899b7469f92b5f18e2cf9a3a4399b8a8e2", "204cdc33a2b804bc8d1332a0f9b82e07c50d51c3f645898de4148a7005a13b9c7599e92a4540c2a23996b5547594e39f", "48"],
-
-["79d5a72e90bbbaeeaf84297bb7a07ad7f141e5ebf5b3597acbebc054516284ccdc24526b22231ee658d2674e97efbc8784a07ffb30c2e98f3d7e4ed7431da285711d3d287884db13a474e79a4c00f9856d3e9dbe929d564df779e286ededc08180e9f0cb5884fa5bdd9331c89f7a39b9f442c79ce3eca89b400505cb197be5a751aff3be8bcb82a84956ce3e5506", "94c75d7a30d8b1267b527a6c0bba34a469ccbe077d1c6a6f46fd515ecfa2e9425e83d9f7ee020b8edf4f034ded8970f65f26014d80947ad4532007d98318d0b2992ccd48711adcd2ef8b11e3ee374da7795012693e1e95593fe7158dcb116c45ad8ae473a3684b7b12cd4a37f70ef23e211498669e02e3877317238290d7e482", "68f507eb2c0ae009965302b8387196cc83860839d9e40f058642552fbe93cfd9765a1812b658d8f5056169fb608c2c31", "48"],
-
-["52b400b5fe5956268f393e1418ea8a5329896e9ea447ed38cc2492d49ffb60b366bf0b0c8a0469b0a9c123eee954b0b48cc684f8c5518456ca1fbefb2b5557c16250c49bab2e3d3416916fd3da9f7282ec4efa6fd0ded192b0a78590ab145ee06ff0922c0ae0bcfafd318b8b92d4b70370a73acb6df7bc34a3424f5dc6415c56b525dd854e22cd49872adddebafb", "b1e81f17c2b8e4654625f56ace0814f2c5b79317ae33ba13ada2695249fffecc70107991bb98dfa5f4cfdc0fd60fab2d13d03b4a9cd68a721ca95585c546f48b501480473f19360f666e20dba9f0f29b073932ced8cf9b50529ac473529cfd525adc7962d5a3b34aa3eb0af5d115aee5a8dac0caea84b553585efc447769e0c9", "29fb2bd6920873e7e73c06e48cf21b2569e36c7da879b802ad22dd376cb5c01c9b76dec775d87de289c82555ca3a9c9c", "48"],
-
-["e4e4d9924d57bb7741d86243d2818e8cd133acd74037ef5c0625a20ae605bdcbb2b5b609bc8b99718e3a5bf7dbfa6c1ab5e38b69ff9ade46263042ea0c47e0a94577017786f023ac5a604b0417179eea11692bf60d530cb4f962ada7dad16b8d05f161e592ce7eaf0e10db73701f4c844e8b368651aaa02ee7838c4937ea87005aed7f5ac86fd5021013b34bcd5c", "101c3e74ab6b30458346b7a1df2582490b7655c307845c59819dbf65017476cc64c45fc98b368eec5485e462c9e0e3769890c058c4daba1d9927ab08e562dd0865a21e817e09174f2decd9094133b982c8035e96c79b18232e7c73550acd0d27fdfda426ebaa7378f7c2bf1eaee8ad7681195604798f1d7126e541d4d97dae31", "2aa697d6b6bc98c1295d74280b8b8aeca85b970ae9afce5a53ad846e1af75221194242cf3f000696f1ba6d50d613105e", "48"],
-
-["95308d9fdcf997893fab34e3e4e35368e956ab894c21b861085a00eb81eb9b7322a0f24a457ec7be535776492c9b7a09c97132702006e53d472505abdd09b0e8a66f7cc875ef74bb4f8b5efdcd89400cc27e225f1eac1455128d736c75b069b367c4b38234f3a7687b7175658f54ad591da601bd0fb84d91b1bad4951289ccbcaec81ede493267ad1e2e42ea39a4", "88e1be9b171e8606c966cc153b77ab86fe26ef05e39979fd75b6e6adc6db6b7e6fd00394d2facda3a2d1af04a6944008b93be58c16ad3acdd233b37eaa2c8318ff03bc721f5fb891c699b9c1e573cce4a107f30b9ffd82437f01cab2a674f5e24162315c86b511793da82f75807e17c5bf7360aa07f31c07830a7370431c8a83", "703898c05011fc49007a182d0e4d2adc1de189013f3080e4767a1e4c32e3e4aad2fec73ce44af52d8283981de55c1a54", "48"],
-
-["8b78fff13bf0484ad184c56029f2546a27e309fba60fc53fb1a80a81f6f60df1773f0da583e9e4dde5e2dc8b5b40c3134a25424fafe66ad6596c907e56da5b1fab6f1b9a48af5c1a3abdbc9a230cf9a8e7364e68a92c00dbf69034613f5f037d8dd3d0d97088d98a38dd0d94689d1eb168dfd64e7a2aaed7efdd9d349bce2e948632d569e4bd83b3480a7882143c", "5af4e9eda7da0b4f6919028f7caa329003b281b3afb2b8a83efd2c8441709f13235d616f67c8bad131d68da8088235e8da92b3a540c90be0045feba1e1ae8e46892ee01aeda3e329b89551531c0183a0d24e91d35c82bb428eb607e44cc82cac954faf15c1d44cff063f97b9418fe35ff3ce63668a448f75e3ecc9760c2441ee", "5d843f0479edf1b6ab5483521cf5353706dcee0f8437b828fe80f60c44c33abef2955b612c376dfffcd67fea585c6d50", "48"],
-
-["7711be3686e1a1d9f6c2a51c8455754c58edafde234c97556aad0d662208bc5a7c9195b502817c15e31a405c8be5d111773fad0448139c3116e99de70620202a1f77755559d38504f73cc0301728569c88fcaa70dbd00424bd7285a084ae61a207cd485fb35c256ddb2f0be050a56cb4322c8d6b050836f60751aed9c13be78bdd9bf51b37a6c41937fb0bb45de4", "51dac8cff6c0d3b27fca03803e554836c8f10c6aee7ac38dee84c46deafc57fd980d0f593c415a05d3e9567591bee6d38ec806fcbd7f5bcc1d310e9b33a3db07b7fb65f48eddcdd2f8d5821f0bf55b2c60bfe3230c45f449d18e8781963cca12156c73a9d4de54a2565630839241cec9a49c4be8c312b48d7364d526fb0e4ba4", "c8909eccd81ac5c763cbe4c2ccb537298026781ea0f7cb7ac43c0aedf8d369bcf6f40d0ff315672e73294dcc4400e4c8", "48"],
-
-["205f40ae5dd58fec68ad60b3a29041ad2110148001a108f3ffe4c853432cf2157e3bcabc354c30fd5d57cf21279098ac1d306f3c6e7923b386f4a498e2fe70bb23824f18610840fec1efb3714af6f8ccf402f8ab021b230792b7a9a3733beda736bf378b8811a837bffd7469b2cc4b56ce0b1894668eee374bb43f3b9deb5022e077e9dd527051f07c0a7ad2081a", "fe0bcb445797a28e53597311250b23f0fa7763e72cdb86d2b9918f6ff3ef8f64796d2e846505874ec7e039b6532eba295656efcbef4ffa037b4d543951a11bdeb0daadadf5317531112405883b606566e2e4ab5de664cc5b07ebb2749ec58fe18b9c70496ee115401a6e000e4669ccb1866bc0257ae1a4e245b3d3a741249276", "e85d23f403ce6224efc217efc09d90f56a0fad52dc7471499199a126872d07834603ba26512116ecee901083e0ec7cd4", "48"],
-
-["75e3ac050d30c6f8b3fc66496e03eb2cb0bb826a2fda9a05f018981fa436cc18383fa4f7a80e200b141086d2154b5719519f81654d4cd69283b5bdbab5642858804dc6ad34577963e3180a71b8e01c3e8afa5e09b12e0588198a7acf95634f74759678f15a13b849499d59efffcb20e38453801e03870e30d9203528ec3b2bb43ea12389c24bc5056e26db139113", "404d7a28f4bbd3753c928114aaad41d7919fb0fc0fa36bb94bd27518fc99d9052b6990a539b0a4ea2309966505affe5bdd38711a9280a78d35e3dd0f86af797dbad8ed8f7beedd68314d846b809454511158dc789619ef7e0874030f339bd7fb7503598cc35cc616ecc613cf22428ce1b666bbbe23568bb44eefadc10c453ba8", "3c226c574944ed5caa2d511740d995ca468185c426f47547c2456f2e262808fe55b0a0da08a720dee83bd7dc6e898924", "48"],
-
-["004e324f0a500b652622671dd7c984d1dc9ab39db1adfe70598f0d635b374b4a0b3b44890a44512a10c0782ce9d68e1dbe9f9eeb96938ff71c41006ba34477ce6772bbd516567e7c8fe13f56e95c6f21b7a1d39e8a8b29a0b8ab0e625c3c1f1d9e595fda356ed9852aed8644de4270074e08185f967d1593b433fff18f098b62385af7416f74a96d694c139dd070", "f4572bea6966ef05235dddff26bec1853eb36840434604a79cd4d11702bff9258664b633d64db641bca7c306e7f6a33fee073d489226fee944927b72e72e580ad679684429c015f3349303405175f0f9e0d7df2c8b4875f5cfe6a394b71885c2eb7f8a46ea29d6c39408e4d4eba3705a1a0c9415b712317245b790a4fde549f6", "5c6c83482e60d6d787074d18ef5b1fdd05cc4844235e7b8d6f705bb07c9cb0d32c1b66491fd638fbc41956041bba7088", "48"],
-
-["61956d7ac4068b0f7eac617bb1ee43127067f9d1d1a26d454422a7a923a6fb4f8aab81416831c627d6c2c16ba8b1099c42a04510ae44e3ceeb6d0daa8740d8b270c7bfc907ad51927642269043c5178b26e3ae35187bdc0dada4ea84a3f1b25ebbf40f5cc700921805337730abb4d7a6c33a1f8739cec0cb33d1287da040f21c14375940d3b4f3802c9e9c940806", "97648538cb39ec8887444d24c0b7e0eb278a88c7501514e014debe1034b0f8983eed6911b52432a56ce6757e33d6187fb500c632a16c370208f2b2ab074fd0eea5798289fe305566a2d6f5133880f072674dbdb2f6f7fd7d31a700738f0e8bd893b1d989f084df35f0bbd5c916ddf12c031a762d96deedba57a6b2521613b3bc", "9b2cf9090a25b8528c159e96290fbf41e077bc42cae131b89e4a212d20f8e0680e255c3906d09cdd60f3de1fcb68c1d6", "48"],
-
-["c937c7387738e5be8761a41603a204cd93128fdaa18698d3bc62c1613aafc0f2226e62ad492ccfabbad711645bd0d067ce415f4f96b1fdf27bd654db2faa46fb31c1e99bf2d9d09537b38dc4be2173b92461a1af06a93cc16703fb5b515be9efbfd666e9bb666fe4c49c201e72bd77295d175be43a95ddaa4792bdc9eaeb30369458e07fecfc35002a3ad35ac0cb", "92cf3716e22f68d30211401034fc3839c94f473695e0f836a0e4bfab1e0f8d95a2fca2048ecea3d8ce18ecccc6fa27b3888d1d014b81cd8c1905bb94c7e78b012afdceed88c31c624d456365ff407e3374840aca439c75ab78b157df8cac0638949b65d9b856fcf8e7624ee3c9156641f5fbb49b6579171b26b03b9354a425c0", "490450772d57cde7f86f9d685d070c23794cedc113143c441dbf5b4d787fe6e619b6e6a286a3913e1d713f4b28af2477cf3453c41529c2ec", "56"],
-
-["49a75faf8232544d78234c3f4fd9bd7b35996217f5d579b2c9ae2f1070b7590481fc6493ff438f562d915720a4f033e1a27082caa56cc2b2390e02b90e159a2b4dd619a72c817f80c1a6ad6313f8193a7750c6511af574b7da1a14e85aa87ea8553e663232768c92d96b3450c678d64e5b4216f37bf8f68954d94be47cf76e45403b337e39aa1f5620e6d52ee2f8", "e4437faeaa8e071f7fd71b5d9b42db5643d0d7ad9ee17f5fc5f8dca619bf7e1dd94bb625f4a005881b78d35d1b40642c6a640952ffcc93a667a70d5c1667ab2afb0cfdaa57a66800ce15d7bcbf14ae02d17600e8ceae011fb595bed60a680bdb7110bd037ba71acdc0378e422ec5a01fa08e2336b290b112d44c55da9ecb8877", "a093df05d8df35bb12de09c9aedb23dcfcd517df6f0cdd1886d4c736c85068f9f19486051d223df48509a2a6e1b50ce22429efb8f0797823", "56"],
-
-["ab3ec2eef335aef9a9d3d0da7082ffdcf77e93ca737e77af4d9680e0175cfd3cc54a90b31a633c056019becc5c6f21bd815b8d2b646e60cc70b67fc0dbe4efcfd8aa9ff30f6788164a06f81c71232b24b8701c0b17589a32fbe29e823bd7dcad4d2798b6ba89bbb3cf6226d295e8469a0487e9737484506954e0852d1e7fef5c8a8923040311fd6557f4f0f4b204", "81159166393b6f178964edf61b612215cb6af701001d24e93dc45b03f3d7c22c9ac2a7cfffe77007e0e7d008b61d41bbd26caea8ab3b6f1977bd7b7cf876d90a99fb58a6b9f980084778c81a8f610aa2533e33221e423ffcd58965da8fa08e4da6480cb50f778960e6a03df64d3465147769f037003a7bdc80af7fe55f5ec93f", "866fd902b3bca0ae39abf8712fc35ce9bc0ae280b6bff7078b5d17711d7662eb547fb5326a9f739a5e24ab125386392ca74d24d10335acbf", "56"],
-
-["e5ce84beecfd6fdf2ba0a229dd18d214eeafec6460729fa1ed3f1ac8ee1e17407bd4180fd4b6f04effbc86dab3d6d64d7583382d019fe563eb7cb7ee863530d8053fbe69e0413d9c7a26becf007087be94cf5bb2abf2fbf6b39dec8bfafc7b1ccb37ff34da465eafe107d26f8c8ded5093bbdb5d2512e8448dc84107101afff514e361d9886c59717f864b211ed6", "db6e03879e21326121aaa331892a3d5199e7825077fc6f43b34fa35b85c4c4bd56b01f8d82918e4c5dfc9f6574d6f20215c7e32411ec69f3c0cc3e53468e41b71569183ab35b19ecbe1e6b692d5868b1d81246333b6bf72b768d4d2e99e162031f7f37b5b8e99be64cf84f663c166705ebaef3a57de1df93de7b18170625f049", "478afd001acd85badaacf824427098bd5c574340e837bab8c8e608b0654cd2de85e3e697252803cbeccb8770b1fe3b5ac1915b1b9b93864b", "56"],
-
-["79e902e852b2e7be8347f379fa29b636ef0439acd1d792698056b65ebc8d8f9edec54e63c5ab035214af6a009bab08b412c5776f0d96ff292c8c665e928c08c999dc2a70c4418be0f6643cbae17ab26ec0241a474994eca8ddf2c39503fe6c7414f63c1538baa7f1902e0fafb5e0daed62ebc73c6d1b826cfc51760bf529e228b6db2f5c075f810c4cd58ce80023", "7d1cb6b5c810a2dccdc7651cb460f4a5b9b315347586c4e65f05d54e926cdcc5d1074a01c85cd8ea94a729a88686aa0124f6bee2717e183b6faa6710e1bb39ce559d19db6219fb702f4cdbab3458a0e045594631cc9b3c5e560a0bde51e9392e13c76d691fafd319a4a0c24c473c9deb22c4f7480d44faf61c0b09dd695ea48d", "d1004dc9d78d4559d2592cc1e8d960fead0f94ecb45e8f538883d930f227ba26e865fd366ac706823729977cdc50eaf03bdb1727d2818cf9", "56"],
-
-["78d9947c69e6154472e40ccdcb41fc21a18329030195a0ddf85e77faf99856f57ee03772f209690bcfb6db8e0428976599548d559539926c2070a834e505802dba853d7a83587bdb535190dbd584114beb5899ee94ddc576135f83af4e3b8dfb74f130fee27b529a48ddb31e07fe73badeb6d537c62842e41a5291d4fbe28546f34b9765d819f632f481cdbe623d", "5c128db8116c5615164550d509171ee4b4d1014d847beeff3d1ce4d2eddc7cc51f66c28950904344a73e678fe08301c08a0a256f72b53a335f834fadffb7b3c82237bf3e2632f75fb205520ea860d50c54a46679ea90e0972f1d63d276a6eefdce4c2fdf7a9999eea164b85a47f4c14d7c686e72d35508b416b2c54838f155ed", "6ade4411b042e26847ffba81f9d77f1d6d452ae12aec7eb2926a6db35e1a339f2ad3c628a7ad61c7cffd301dd18403703059e6e218508f7e", "56"],
-
-["b6ee6f1a804c6552d1b06ed4b3117b5e3f2f19da056cf4d6aedd9a34e0a1822362714d4e81794b53b266417678c16a97887bbb612cc96bc5e532b3a654e5d3d65a5155427ff09569906381138cc49e3fc2384c5d33c34abd3d617c487b52ec6ee7b5105f41584b7eb5cfb512b8c31f3f338d5236e30398a8ff927e801c8ed7d14fc5040d915a737967d166ddc266", "1fa2f26ced0d74dcf81a0640c50a708990f8f939e31792c7673c5245fb9c224003d6bae80234cc706c2d7eb8b52f1e2aae9c11e211130d115af089a3d916711f06ec1dcf14a58422c9c5ad4e8ce06739c0a53a9a501c9d5b8b2c694333608ed1d0eb056b4ae906b8b7e77a4a72a6b3806d1c5baac8bcaa6fa2f7dc6c2f03e31e", "e145c5715b9442347f09405640f2af5bec8d8699f458d4c69a0f823fe9e2a6676759359c8a73ccbc34253f38c81c7ebacdd433ee1913d19c", "56"],
-
-["63c866198ef4216d41106fda34c4ebdd040a39de8731f3585d28e639c7f97f8cbc9480553acef7bcdba9716ea8d66b4131780917de2b0b048045fcb32b5cac054808e1fce6e94ad851ecb47fe6cb802225d3551e08ea122093d0078dada564212eacf1d6394e0007cc62a1d595ab14ca08a284bcae76f2f4c0526f90f4de8e26970819aadc04039274919e375e7f", "afc68ef8a54dfdea5bceceb387f88b0c0ee86c981ced13c4738e79f303ce29d8bbaeac22a0f4445f1c2bdd1a98eb40474d5a8ae256f3dcd20a8655138a803d14966d835ea5bb6a7734c17f065e00ce37ada85561305eb9e7cd3c385cd919dfeae60bfdf4dc2bd7fa4c989dbd00bf9da4d7d9b75fd0b411ca64b70c1419217620", "2c547e05592dc04ea530c9ae8949f3f9aa6f9563e69c1de166591e24a6bb4e6b1414a079734ff28597cab217161e147fb51a1434e9fd21e3", "56"],
-
-["7f432d3c5b0b0276bdf36541c2f180ee0684ec7e174fca642a070f8c0d901f9b5d639e79f933820db6d06d47554b3b64b25bd66d3dff5201ae653655f50b177970246cdd613d9d139f205dd68cf93a4c46b7728822b50953569a182cf57e688d214e3f707c717a12dfabdcf74f42a62e2c6068d6acb916e1886d5e18f530395fd6a8c13b30164291de3993b18670", "15ef510c252e80f44e537b0f1fbbf3d57dc69ac349e8e6083dfd128217dd67e8fa31b949118534b5759e593a510d431244042f1a06693b864f296ed3f4cff9fe5355bcf4f2390c4963d4b42c6be8399b637863d3dddcf80b8b14cb615d5df515ea0263f511759118e09e94cfb4735ed9a2c93ed61669a6feba6731139aafe494", "74787330ed257b3c1f545207d42470732c4415ff9f19b243128c9cd9724ebbc15cdd14b1d8d5b12a709e5709691c334207e940b6701661ae", "56"],
-
-["ce7509e8e7c29911223037bdce5c5d8cda10fcc050ba1b4e201cc1341f44160e4fbfc5e4610aa4477849979c303bb2b7df6e9dac1baf3de17cc59068a25fd98c7a4afeaabee72e43bbddc64e570b76d352c1a0b5cc2f00d0081d92436fddb00fd87e7be206437d2d72538d7c4bb2f207bc70cb846d21ae404c8d8f6e8da8e3bfefc406334205da2e2615b2ce151a", "f6152e7422034d9c5c8192d1ec85a965eb978bb555903d095fa2babb0a982e9cf85f64937ec68287fc82f47f6975a608323076d845b6e9b068821c87d0ccfee4ef09bd8c8db17e5d1a44517a438f64a8c32c77f87c488fa7c6a8ef4207ee440d841920a6764ca9e661d09308a15b4b0d4593755e21ce99b41f1c1935d68767e0", "4a8dfd230cd6f466a29c8f0737f5c8253f06694a43a6f1bd78cdee2fdc397eb68f70e242992d68f23904cfdcbf4080b4fb91da0cd58f7384", "56"],
-
-["c73132626516889283f28fef56fa340450a6a592396d0a7b81cf70ded5990584f0658b7bb46007007950b9a3a5d551a57cee60a53ac00d7b5f9309654b1ec5610a04e2d9719ee9856afb3c8e8c254cefefa60d213e4ba7f70d286fc76ddc15cabe171ec5c55995e9fffcfef834748caa4ed6944f6c9740b53396884c645d5e88b4a42987d81775f4df5ae92bc449", "b1e626fd2455c59e04b0ff4e1d2894d9dff62765aa92a2474926d71bbc4263133ad1d88f764c1bee222d0aa4b1a7628d6294e0827aafa729d003e2118fa3aafe389c2a81e62801ccd624e7c526c49459dd86de132c6421edf44ee36687d7b8d4b32f64fcfd5140e02a7ddfd804e9f0e45ab46641491bd73abaef22412c46553e", "2b5ec29766abddac4939a3610ebf2a043873cd15bc121d8ae6dea60af0fd03b348f835b32988998630590994217d053cee8fcc4017efed80", "56"],
-
-["e0ff5e7b2612bbe69ebeb72d5826ff1b820f4e4adb8a86e96bd4ffbd727a1f669ec02be480445c5941df361091cc15bfe10c0909d208e347ed7990889485a6d6382a3dbccde000057b7a84c5974ed89ed1955d3caf15d7223afdc5a9f710ee4518053d0bf42ece70b559495450f46f3fbb74f2ea5af78a01ba27452781621f3ba519d2d9dd76ef2455422ec3a605", "c213d3695012190b4ea9dd66912db20d687b897d758328b9bb08d831f7a15ee834042dd69a83da8c87d7d016e11d4525aa1e6d6ed4d3ed4d572992ae8201432d8e7b347c79745d05124d2bd150ee75a9e70bb17b1da668ffdad6e799ffc63882f900502396ec8ac11793545f86a928e04acbbf6df80581166c973fbc19f9242e", "1252ceb41afebe8dd9ca1d4a01d1c07b6e39a0462c497eee7a778757aea39d0e5f0a1239f452663ada69d55cf2ba14836b40e2457933e37c", "56"],
-
-["59b5247df0ce81b59d0b40546ed185ff440be012d91d753702195a3832364011407e564a6ac66a8399e35151e71b8a485eda1e5902a6ca5f125560f48c7e6b89ef2d12b644db3fce5f9d00b7639b2df884c44b1411c0b017b126e86eedf058fbee2c216988f447bd789f132eea39b5373f312eea0c526d8766f4b84d5d744e90c6e14b0c3ef8cb796b53526085dd", "7dd26a4d522342a5e9c081e18925c6f2ef6adb5141674240481b1052d94fff2d9476be8fd2d88b8fd8ef042651113aedfb500828a09fa3044836711dad371f43ef91ee7e89244d4f8427ad39eac791807e11e431aa129062b93d4cbb460db536f4eba1226051b06e543024243e8ff234e0751873480a32e303f948358e18eb8c", "0c9eeaa855d460d0cfc0babe4c57110f0737b79e80fe76422832e47657afaf00944a061bf31c897e0a96abee0614eb755e37f1a7a2699f5d", "56"],
-
-["57c2eb677b5093b9e829ea4babb50bde55d0ad59fec34a618973802b2ad9b78e26b2045dda784df3ff90ae0f2cc51ce39cf54867320ac6f3ba2c6f0d72360480c96614ae66581f266c35fb79fd28774afd113fa5187eff9206d7cbe90dd8bf67c844e20228d8507ab87e993125c4643cfd8c58bab7053c64f4da5c6604a92f6eacb6380226e12a166ad54fdeac83", "ec99b8d92c7b68072a2e8b2d4c4eda6d3cab78b63e4ddde9c1b5123a336624da1aa4d3d3617c8a33ef04946c7fe48cfbab15154849e9cccdfb4c7b7b0249905953d0cd6fedb640e269fd7660d356ecbeb8e4c6e08043c3dafec97154460b9d33bfa30e767931735dc0a099d1f1abe1008c9ff2488b62ab56f2b45b8fa8b535ae", "fbc3d7351550d40e77bac577fd9c180f2c9487d301a5d0e44f4b8dd207980c24973964ec21920ceac324fad4c88ca235d00e86ff9264d2d5", "56"],
-
-["3fd3dd9d3a7c5cf7e2a1d60f489f84471902179f21f656ce0fff089278ea14441e04e7af71891622565f44c428044728fcc686212a32a5d809ce651bf90bc5f8a7756e8b8c2d4d14b799824ccd5a79fa4e7e56c119f7973c334cf44dc48f8ab1628f39d8924e4bf48cb656513f8763e495944f26f82f722315e27fcde060ab97d8ba123f8cd656eb5e1e89788775", "05b2305a6264fb92280197a579b4d336395d5b51148adbfec2a3671589641b530490feae24e42ce6744a355da150c02839d87466b31118d0b0a6f89280358b5ae80254ae22ed068226a1eb0a280f86cd621b78fb1394a000c86a8659da1bfaa6386ff8016665cf8fc66d825417d76f4c3b8c2eb73dfcbcb49257d9119f00ae62", "9b4f475853a268a72f31364df38438d3fdad705c9c50c8dab3d64e6c193dc63c6bbf399035641cd222c05aead20ba55c0df360483eadd7a6", "56"],
-
-["01b95a887927ce31b1242391bbd00965eb77a903d4b8399b72e6cebda9ae721beefa779145160b626b110cc554671da0d8dcf993a9ab073888e02fa9b803ed43b3f6a3aa1d20340df6ccceac13cb0797cf612cb8fe5fd513228cbd4de249d16bb77587dde98f71bbba1a124ee046f0d239ccea7abb1accb5aab021b00dca491c623fcb3191a9ecf31fc680b4a41e", "632afa8e79b14b2a3604f5855d2bf182d3c56d6853f21fe46271da5286065f38b31f751306b63c57b679beb14729c78f0040f7e2a0d615224dc5a693cd0cbec8f87117656d6b6029853ed72b85681a63183c3a6dfccd128afb0dd7e81d36f0231c69070b189560a88c9b697b81b0930701026190cf9ebe23559194d6de4d9a51", "210ad45ca2fd1f105c0a18f993774f933ece57ace4da619689e1cb8b491a189cc6e4ee1954a32201072e70f934837c0fb6e239b4fdfbd26ebf11b9a919eafd09", "64"],
-
-["61096f4fe5340488916de293be38cc3ae0c877670c713637b760d74fc18ac773b2e27d5543cf16aa20dd3d83ecb34edb8545bb6c8a4aaec81bf1f0a4e0cf09774d1ca944242046b33be807677f3de18c39d700af90cd68d34f50dcc1e999fe9fbb20b9c4900fdccb6af607e680c0cb7583e60dd825e2ab81dce7634de3cff0148355757f90841f19366f06a9f623", "67e704046f98cb5aa97da95b19147391f05788f811366b0ece44b12af2b11e0e05780bbfcbd90a950e0acd8e9d2a44e7957606eedfbff212fa1c163cfbdcd062d2be3259ce65abea6406e4292c64e9022cfe89155986ffc45b96d289919ff98d552243778122f68231d9b6d3cbaaa9093d57d9158674da4c781bacbabce2e2ba", "9a2d147e50827157f3866e868c1cca9f081579c92f25da8cebc9ed249928c82bead39d480ecbb5b5d0e0755029aebf3e0206984f3ea83f4d6372f4453390e070", "64"],
-
-["c5c06993d43f27e86bff96ca7511176974bb63e618bfc4b610e0854820a3a6e77453d5e134479ae95868d2babeed5efd79691c6d6d0816391915faa9b3c0cb057a1fd5b34872e69f66abbbe0a52eb998aad5de1b8a37f654972a12657986368e802c5250384773d23ed23b83535b8f01af068f3a97d4cbd13225b3c3997c504a2d8332012d4faa4988e439eceffc", "b53127b89772ea1ca6dd27277da80ed972e82f1232a73d4ba537118418c5f17d9a311329a61e5d6003456fd4e90ee3466561d3fafeb99c68997be2349a87d5604c0cb2c183a08caf80904c011474f73909072ffbbd36fdc41077cdd8805cba7c93680c667621ff72e366c7964703d01825834afeb546e5c7d2d3d958136e2a39", "e3a4d32f262c6cb0e99195c7439ad2731185c58811f40ecd32af214a21c20869aef5297cd951fec2a145e15f982266c46f7a60c9fb0bd0c6b16f5ee40fb44708", "64"],
-
-["5860501208a4c922ad7550dbd931a19ac1434750e63d5f34f528a0b5eb1798b37c0338eeb6d293bfe2b9e306abc4cd6382b3e6a94008758f0d5e7ba981fcf0970aaa507d8ec456b3518c07bd18c4f37d8f7db8a7e82ac776c5f86b9d58620781c8ff9fa5d79f9965c397c5e869599c50b048c53325cade4fe39e7879b67063d780aa2d4fdb8ee53fff82246fe7aa", "c35b26bd02499cdb6b06bfa4b18979f0a472ba7c559dbd277bf78c611590c6e051f2a094adb22ade5c44d4fdeb1330c924d1f9a3330c55ac07035735fbb7c877b64527844f72ee7eb58817074f61dff8dfc1ca56ace9e782e06855af2f350699b9fbc37532b47023407992ab24980ee79de8337d0959fb11cecf8eb8f83108af", "8c385547a8eba518e777c3593c5b7ce0bd7c859af6d67b6238d20a58b8d0d74d80d18ab358ef1c1218b928a026ae8c4e3b73bb5bc0914de905d499c75e6f3d9f", "64"],
-
-["fe4c83e8496a69b7a251228396a5d2b4849edcee0ab1f8dabd6d872a1da324d7c8c97cadeda05f0a041517e3bc65f807358538a870c1011704a3c5cf1216d2b57acb269e4fdc841289b0c750fa1e779184d59a9188fbcc4ca11492059326ec8d7e1a29c25866ff5699e9dbd2381676dad755a9b23ba68201fe8897d588199ae83b7e2e22ee85f95e9d89fb715e97", "c24fab7f7998c69063c2d1103e60a6c4cb03206add01d09faf75f1007a879e9047ee435a02b35257d1373791a4778d890c8f92d6507dd810be283eec3fa11fa82ea8c9aa6a723164aaa9e57a11b54127033ae6dd36e1682b0c5c47e420a4217e1e8525b8d95dcb7f9721c213afa02a66570c04c5b7b6e7b94219f430451a0cd8", "d725750a042de65607af5ef523e3c86d08be52427b7036ad514b9596c901e96d76b5e58f68907044282e695b3b875c09ad49ecd9950fe312a59dad691471c572", "64"],
-
-["41f4749cded6e44c11b8118c38cd71cb95a26f9eff01bbbdd716e44e3ed02867858a8bcee5eb2603710ac28048d6a53f0fb6ac7d9f6c9abefa3fb01184597e95706ef83c789ecccfd19df3325e1186ea243bd4dcfedab157914c115583f7d5fee8e7e46efdb87eb819b7cd2be044bdd4ba7b0e438413a89285852ea4a371d5abd63e77edde02e3c731a178f23838", "5c74212dfc2a80d30c39d680327d2488838c35d6503c1a8c4366d7eaabe95c7115f1b7481c7987de820eb4d17fd65d0f58d2123b346044522c04f98ea167c48ad2a0f5a8adb30db0e65775b947fd6f4d470d4cc8dd73e001965a332ce63779ffbb0a441458e1f98f619d800032f8408b75c74b46f47dd5e2abf1eb8e22616218", "7e983cd601ff5837e7d170f3092e914e076c21b31761eb7b9ec211e3506758d8d1395ea914c0350afdd6827c0283ea4af188cf30c1fdf075e41363fbdbb29eed", "64"],
-
-["fe27bbc87755aacc37f667f8ca37f8888fc9dc530fe4f8f38e8cd426e01307747edff012d96da707ee96338d1b11feba313a865fca115431dd8632268ff499224ceb69d31732dcd91e0cbd2b92bbd5b6b543a74735705daab81a0114b8a8f0be91d38cd3d8ad328cefe16c99d63c67c4446ca7d1f708f9a848d1a9b60238f6907420c3d9c5e48f67889ca7a1909c", "99841c3e4a41b53c30267dc056e7e9b8f9994494dfbba363ea761c38ec2433d3bd10957d8b7c093472e9a3084c923ac5cb3a1dd2c5270259ce6f3fa80c723dd847a829ac409decbb44395ed20045b694972b4663f2fd658458b9ac7d3ecc65c260d4409110aa481bcea016e41a07446c86f5250f0f45b32aaddec97f293993db", "562b5ef3d5cec882a2f54f8169612dba2b033325ce5ed924024e7806c745de9e7612dbcfcbb95ea3fdb93de9c6460a866bd412b45eaba5139939fe43d20f9315", "64"],
-
-["29f8eb9fc8ab58fef681f9faaf934e992d42046f0ccd2fc9ab23d42bd5f5aafda110218196eaa408137a1b66ee4db5a35cd7e4f31107a9e8a81e11e744c000d9784b2d2264696ed721e1362b60b35b2b4d631dacba95658179da4af109cad9687653166c7a503ed3e85d4f334aeaca9bc98fb8804e9febfae70086316c3ac01162cde4461fc89c642f977065f71d", "3ac2ffbc5b6b2334809232c0f8151ed379a8634d70d3f5a1963a7637c421ad0f082f34a8f872702046a4c69c95ad0cea8b683e6528aa731956810f28c1b9396de8a5905e751c1937c9c17c55dc8771df447575ac93a7c161e6967cdabb9930cc03ab7ba8796e07c23170bbb274ad33facb566eea5ad1c7c16f0127155bc77875", "38ca18d60f180fd2a40e342272190d9b84ba37bbccf59b29bcbdb08762a90e1f8b28349ca634a6f955cc08c96835ee70a2267444fde88b45b8e313b0daf6e12f", "64"],
-
-["345479ae901adbac7223f5f9edc419bb64665cba4e3684b7371e28ff07f3124087f0e89a21630cf9e8a6c0a3d8518e0d5eaee7f31b6d0aa7e59927aa0ecbc479e99e61a98b625736cf1506199d8f2f186bfc9fe2038f0e5b87754635b30888c063462b035581860b2f571083c4e5c6859338cdb09004597b2899cdc87f1224bdfcd08fcf07275f1f1156260ad5bd", "e4b38e556aa285688979a55eeacd7d953f1ee0ab8109444c7cc068488eb83ae9aca1f783a59b944caba75d6e0f5bdc5b4cdbfc6147046e7ed5ea4c757e85fc2181a7580a17310b36fc873e422c4175b1ea24b3830750e50961ba7df9aadd5ebe6badf81148cdb4cd850192ffc9e6103d22e14f3a4a557197291945fb9a292665", "24d2dd3d082e6556dbe27381640837a23e5d4a4d6822066cd09217a677068e5b8901c1eca7da77a9595be271abfa76f9d40656cfbae050ff6d8ddedb0f4c82ed", "64"],
-
-["2aa1d94ec83ce7c3c75c6bc847759b085234fd44b407d8f80ddfe93c243556e87e4be8fb30b4743ef1169a24732fb2f5f416042b10c3371dd9d20dda29844d58370700ce69f7df5e69240df77b96027a0ecec71b904f690b875da854de05ef047c5d898d1c0d116c580e2a0906b271dec8e5b0dcdfb2550a40092270eabf253376d6eb01f0fff1afe55d5b21bd8c", "acf624e86580af11d0d23c19df6969fe2ec2cdc737bfd00bc54dc0b2ab4421ffb58f44cfdf8c1b1bc5b54bc45b818390de850c6f0adfa2048ed48360bdb8c511860eec5ba6f1bcc51cb34cd8ddc35c23cad4e882df3bfea0ad99ccbb0abbfda707be461622773b16bd1268dbcff89dbfdaf789871d9d8ae80ae4c44afa1571cb", "b6e82d35182ec417bb33d9230a55690f8720d32191cb5cd46bfd591421911727a0f8ff64ba6e16f25aa10669a85bf2ba74d84a754ed947335b7a17af0297accc", "64"],
-
-["cea946542b91ca50e2afecba73cf546ce1383d82668ecb6265f79ffaa07daa49abb43e21a19c6b2b15c8882b4bc01085a8a5b00168139dcb8f4b2bbe22929ce196d43532898d98a3b0ea4d63112ba25e724bb50711e3cf55954cf30b4503b73d785253104c2df8c19b5b63e92bd6b1ff2573751ec9c508085f3f206c719aa4643776bf425344348cbf63f1450389", "f3ac4422cc724378100d7515ddfbf3fe340002b7976c43acd69c2acf26c3b18173eb4eb6f73622540c6a73dd3eac5c4ea58cc34772428c6bc7370c0accc8c1feff4640d2cb416e2a5d06f35eb366ec69f5b9e0020923f6086216652318182ba93ec702be701a90c0abe9dee261b00b16cd9042318596e9494e401b62333d594a", "d336f2002c558eb518c773608387bd500704156043b76104eca2309afa67d69ad9b00e6b83417e088d3f93435922d4e8242e9631f962cd9fc258f3505305d636", "64"],
-
-["ef71b7b3ca0f904dc50447ae548096b2b3603b312a5e59d490851b270ee99aef259401bdf2c3efc3b1531ce78176401666aa30db94ec4a30eb281494bef5205dd87f3350c1c4a56f3d040b12167214391b30b121697a7915e9224b871a3c355f111a9493be7b7df870ff5c589bdedbc4dada062b3072ac2c93590829ab26a09dd74d6eaf714e3e07532c57e09921", "50ec304fa342839457d7eb28791b671ba5c425f711c3a351cc76149d481f0547179540fff239f054ff2c078454bfdd92b72b199aa783d562a1e6fd319cf9f8e4d6948b3ed2bcfa80a1d270396209a060051eada0544347f3335c1872266d5e6c1553d9b54cb3e740c631eef0abe2faac1703a7b21deb422d0c3e2b09f0647d06", "39d94c4e1c8456bed8637e592e4231854df3a6ffce98463e4a85c477d9fd34d27035cdfccfcfd385d91e4e38e8c75d9ff941de80742e985baa9c94dbec5a6837", "64"],
-
-["e5606f31ca4d0f5d62730f443f6db0edd8224f1881eaf27f9af3215d06e2f72ddfbd78b467082541422ece34e323a8bd45489fe6db8fedd4c9dfec4954ba286e971db9d078a7d0a8dbfe8f5f166f1e51a4d4fbd21dbb916e65c40d75244b6db87747d98de672371995abfacebe983a325e8f0ae22fb706d7d76a2be95fdeec91e60581f397b1831cd8fcb688c4e7", "082e7b4604dbd3608df7932475e4279bb288688ef998cceb8e16d9695a18e06f3ecce733a7b9e71f62473878b2824941a01b945d93afd1f5204c6a19233230aa0fd64c77822d78a61d266f569279a182fe9f2c287a2108abec16817724e7ebe32456915bbebfeebe659d20053d4f9926741d1837d576d7d79a7b06ca82c279e3", "d675982ccc457324e24a8ac6db3710b38e5f18c5057730cb7ea2a37b4ba44c41dde0874e43836cc95e97ff0b3ac10410497f9664177b0e576be8c508ab1c7857", "64"],
-
-["8a0349d4d1ed8c4af533e9e83468b5859bb68237798038171346684499c9dc2b5970730533eb2ca04d1680630820f58d32ecf0bd7db7cab72ffc27651c94831cd1220e2113aeba6c889092abb3904d8a264b2332f2d9df0f63ac36d7eabb57c85be0c331587f5f330d69c7c91f00e606de9bc49ec22c9ea815203ca2ed867fb65d743a3beca6427f4669c9c432b7", "035f55033df01f670015a828eff154a245e8ca7474b0b3330cabbe5fdd74e89560b8fa075347532aa46ae7ae907888b30ca4653a6419d0d9224944b43181a6a842c1cbc96fcc3b0f1e7b344c2956f2613c652eb27e44e5d773765a9521fb5e0c7125cf31d9a75f7f38ef96ea01b61b159cd52fc4095a7a94c7db0aeaf40a9929", "3780ef695742f09a160c8dd7d35e2758b08284e8150934d222db31df2767d40d7c815c526ecee5f787030c8dc5f050c419ec6ea7563650dcce1480892d3088e6", "64"],
-
-["f78343071f61ee7d9f791bd53132e6d557928bcfe4b214bebf6f3592e46374c7ab148c3c4d6a1443a4675cf4321298c865b440631947b6b05f2c2a337d1cbb9b3661de974b4604eb41cc77c3659e85470e47e16f22a34619db935d59cbf5e1101ed401c020db069eff1035e9d1bff77bd8b3379e05ac0c20bc0e98aad7d7304dedd3bc5ed4136184649b5e0f7e5b", "d63b50b54e1536e35d5f3c6e29f1e49a78ca43fa22b31232c71f0300bd56517e4cd29ba11ee9f206f1ad31ee8f118c87004d6c6dfe837b70a9a2fa987c8b5b6680720c5dbf8791c1fcd6d59fa16cc20df9bc0fb39f41598a376476e45b9f06add8e34af01b373a9ce6a3d189484cacb6cbe0d3d5ef34d709d72c1dee43dc79da", "086f674d778db491e73b6fbc5126233c6b6e1f066963356d49ea386d9c0868ad25bf6edad0371cde87cea94a18c6dba47535dfce2e40d2246ab17980495d656c", "64"],
-
-];
-	for test_case in test_vectors.iter() {
-		hmac_test_runner(
-			&decode(test_case[0]).unwrap(),
-			&decode(test_case[1]).unwrap(),
-			&decode(test_case[2]).unwrap(),
-			Some(test_case[3].parse::<usize>().unwrap()),
-		)
-		.unwrap();
-	}
-}
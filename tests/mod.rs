@@ -1,10 +1,34 @@
+#[cfg(test)]
+#[cfg(feature = "safe_api")]
+#[macro_use]
+extern crate quickcheck;
+
 #[cfg(test)]
 pub mod aead;
 #[cfg(test)]
+pub mod cavp;
+#[cfg(test)]
+#[cfg(feature = "safe_api")]
+pub mod differential;
+#[cfg(test)]
+pub mod ecc;
+#[cfg(test)]
 pub mod hash;
 #[cfg(test)]
 pub mod kdf;
 #[cfg(test)]
 pub mod mac;
 #[cfg(test)]
+pub mod noise;
+#[cfg(test)]
+pub mod pake;
+#[cfg(test)]
 pub mod stream;
+#[cfg(test)]
+#[cfg(feature = "timing_tests")]
+pub mod timing;
+#[cfg(test)]
+pub mod wycheproof;
+#[cfg(test)]
+#[cfg(feature = "safe_api")]
+pub mod no_panic;
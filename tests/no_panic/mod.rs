@@ -0,0 +1,283 @@
+// Property tests that feed arbitrary and corrupted input to every public
+// function that parses, decodes, opens or verifies data from an untrusted
+// source. The properties only check that no panic occurs; a panic fails the
+// test. Valid messages are also truncated and bit-flipped, since random bytes
+// alone rarely get past the first length check.
+
+extern crate orion;
+use self::orion::{
+	aead, file,
+	hazardous::{
+		aead::{chacha20poly1305, xchacha20poly1305},
+		ecc::{ecdsa_p256, x448},
+		kdf::slip10::{Curve, DerivationPath, ExtendedPrivateKey},
+		noise::x448_chachapoly_blake2b::{HandshakePattern, HandshakeState},
+		pake::{spake2_p256, srp6a},
+		stream::{chacha20, xchacha20},
+	},
+	keyfile, pwhash,
+	util::{base32, base58, base64, bech32, hex},
+};
+
+/// Call `f` with `valid` truncated to every length, and with every bit
+/// flipped in turn.
+fn for_each_corruption<F: FnMut(&[u8])>(valid: &[u8], mut f: F) {
+	for len in 0..valid.len() {
+		f(&valid[..len]);
+	}
+	let mut corrupted = valid.to_vec();
+	for idx in 0..corrupted.len() {
+		for bit in 0..8 {
+			corrupted[idx] ^= 1 << bit;
+			f(&corrupted);
+			corrupted[idx] ^= 1 << bit;
+		}
+	}
+}
+
+quickcheck! {
+	fn prop_newtypes_from_slice(input: Vec<u8>) -> bool {
+		let _ = aead::SecretKey::from_slice(&input);
+		let _ = pwhash::PasswordHash::from_slice(&input);
+		let _ = chacha20::SecretKey::from_slice(&input);
+		let _ = chacha20::Nonce::from_slice(&input);
+		let _ = xchacha20::Nonce::from_slice(&input);
+		let _ = x448::PublicKey::from_slice(&input);
+		let _ = ecdsa_p256::PublicKey::from_slice(&input);
+		let _ = ecdsa_p256::Signature::from_slice(&input);
+		let _ = spake2_p256::PublicShare::from_slice(&input);
+		let _ = srp6a::PublicValue::from_slice(&input);
+		let _ = srp6a::Verifier::from_slice(&input);
+
+		true
+	}
+
+	fn prop_decoders(input: String, bytes: Vec<u8>) -> bool {
+		let _ = hex::decode(&input);
+		let _ = base58::decode(&input);
+		let _ = base58::decode_check(&input);
+		for variant in &[base32::Variant::Standard, base32::Variant::StandardNoPadding] {
+			let _ = base32::decode(&input, *variant);
+		}
+		for variant in &[
+			base64::Variant::Standard,
+			base64::Variant::StandardNoPadding,
+			base64::Variant::UrlSafe,
+			base64::Variant::UrlSafeNoPadding,
+		] {
+			let _ = base64::decode(&input, *variant);
+		}
+		for variant in &[bech32::Variant::Bech32, bech32::Variant::Bech32m] {
+			let _ = bech32::decode(&input, *variant);
+		}
+
+		// The same decoders, but with input that is more likely to be mostly
+		// in the alphabet.
+		let encoded = hex::encode(&bytes);
+		let _ = hex::decode(&encoded[..encoded.len() / 2 * 2 / 3]);
+		let encoded = base64::encode(&bytes, base64::Variant::Standard);
+		let _ = base64::decode(&encoded[..encoded.len() / 2], base64::Variant::Standard);
+
+		true
+	}
+
+	fn prop_aead_open(input: Vec<u8>) -> bool {
+		let sk = chacha20::SecretKey::from_slice(&[0u8; 32]).unwrap();
+		let mut dst = vec![0u8; input.len()];
+		let _ = chacha20poly1305::open(
+			&sk,
+			&chacha20::Nonce::from_slice(&[0u8; 12]).unwrap(),
+			&input,
+			Some(&input),
+			&mut dst,
+		);
+		let _ = xchacha20poly1305::open(
+			&sk,
+			&xchacha20::Nonce::from_slice(&[0u8; 24]).unwrap(),
+			&input,
+			None,
+			&mut dst,
+		);
+
+		let sk = aead::SecretKey::from_slice(&[0u8; 32]).unwrap();
+		let _ = aead::open(&sk, &input);
+		if let Ok(mut session) = aead::OpeningSession::new(&sk, &input) {
+			let _ = session.open(&input);
+		}
+
+		true
+	}
+
+	fn prop_stream_counter(input: Vec<u8>, counter: u32) -> bool {
+		let sk = chacha20::SecretKey::from_slice(&[0u8; 32]).unwrap();
+		let mut dst = vec![0u8; input.len()];
+		let _ = chacha20::encrypt(
+			&sk,
+			&chacha20::Nonce::from_slice(&[0u8; 12]).unwrap(),
+			counter,
+			&input,
+			&mut dst,
+		);
+
+		true
+	}
+
+	fn prop_slip10_path(input: String) -> bool {
+		let key = ExtendedPrivateKey::from_seed(Curve::NistP256, &[1u8; 32]).unwrap();
+		for path in &[input.clone(), format!("m/{}", input)] {
+			if let Ok(path) = DerivationPath::parse(path) {
+				let _ = path.indices().count();
+				let _ = key.derive_path(&path);
+			}
+		}
+
+		true
+	}
+
+	fn prop_ecc_verify(public_key: Vec<u8>, signature: Vec<u8>, message: Vec<u8>) -> bool {
+		let mut pk = [0u8; ecdsa_p256::PUBLIC_KEY_SIZE];
+		let mut sig = [0u8; ecdsa_p256::SIGNATURE_SIZE];
+		for (dst, src) in pk.iter_mut().zip(public_key.iter()) {
+			*dst = *src;
+		}
+		for (dst, src) in sig.iter_mut().zip(signature.iter()) {
+			*dst = *src;
+		}
+		// Valid SEC1 prefix, so that the point decoding is reached.
+		pk[0] = 0x04;
+		let _ = ecdsa_p256::verify(
+			&ecdsa_p256::Signature::from_slice(&sig).unwrap(),
+			&ecdsa_p256::PublicKey::from_slice(&pk).unwrap(),
+			&message,
+		);
+
+		let mut u = [0u8; x448::PUBLIC_KEY_SIZE];
+		for (dst, src) in u.iter_mut().zip(public_key.iter()) {
+			*dst = *src;
+		}
+		let _ = x448::key_agreement(
+			&x448::PrivateKey::from_slice(&[1u8; x448::PRIVATE_KEY_SIZE]).unwrap(),
+			&x448::PublicKey::from_slice(&u).unwrap(),
+		);
+
+		true
+	}
+
+	fn prop_pake_messages(input: Vec<u8>) -> bool {
+		let mut share = [0u8; spake2_p256::PUBLIC_SHARE_SIZE];
+		for (dst, src) in share.iter_mut().zip(input.iter()) {
+			*dst = *src;
+		}
+		share[0] = 0x04;
+		let spake = spake2_p256::Spake2::new(
+			spake2_p256::Role::A,
+			&spake2_p256::HashedPassword::from_slice(&[1u8; 32]).unwrap(),
+			b"a",
+			b"b",
+			b"",
+			spake2_p256::EphemeralKey::from_slice(&[2u8; 32]).unwrap(),
+		)
+		.unwrap();
+		let _ = spake.finish(&spake2_p256::PublicShare::from_slice(&share).unwrap());
+
+		if let Ok(server_public) = srp6a::PublicValue::from_slice(&input) {
+			let client = srp6a::SrpClient::new(
+				srp6a::Group::Group3072,
+				srp6a::EphemeralKey::from_slice(&[3u8; 32]).unwrap(),
+			)
+			.unwrap();
+			let _ = client.process_challenge(b"alice", b"password", b"salt", &server_public);
+		}
+
+		true
+	}
+
+	fn prop_noise_read_message(input: Vec<u8>) -> bool {
+		let mut responder = HandshakeState::new(
+			HandshakePattern::NN,
+			false,
+			b"",
+			None,
+			x448::PrivateKey::from_slice(&[4u8; x448::PRIVATE_KEY_SIZE]).unwrap(),
+			None,
+		)
+		.unwrap();
+		let mut dst = vec![0u8; input.len()];
+		let _ = responder.read_message(&input, &mut dst);
+
+		true
+	}
+
+	fn prop_keyfile_and_file(input: Vec<u8>) -> bool {
+		let password = keyfile::Password::from_slice(b"password").unwrap();
+		let _ = keyfile::label(&input);
+		let _ = keyfile::import(&password, "label", &input, 1);
+
+		let sk = file::SecretKey::from_slice(&[0u8; 32]).unwrap();
+		let _ = file::decrypt(&sk, &input[..], std::io::sink(), None);
+
+		true
+	}
+}
+
+#[test]
+fn test_corrupted_aead() {
+	let sk = aead::SecretKey::from_slice(&[0u8; 32]).unwrap();
+	let sealed = aead::seal(&sk, b"Some message").unwrap();
+	for_each_corruption(&sealed, |input| {
+		let _ = aead::open(&sk, input);
+	});
+}
+
+#[test]
+fn test_corrupted_keyfile() {
+	let password = keyfile::Password::from_slice(b"password").unwrap();
+	let exported = keyfile::export(&password, "label", &[1u8; 32], 1).unwrap();
+	for_each_corruption(&exported, |input| {
+		let _ = keyfile::label(input);
+		let _ = keyfile::import(&password, "label", input, 1);
+	});
+}
+
+#[test]
+fn test_corrupted_file() {
+	let sk = file::SecretKey::from_slice(&[0u8; 32]).unwrap();
+	let mut encrypted = Vec::new();
+	file::encrypt(&sk, &[1u8; 100][..], &mut encrypted, None).unwrap();
+	for_each_corruption(&encrypted, |input| {
+		let _ = file::decrypt(&sk, input, std::io::sink(), None);
+	});
+}
+
+#[test]
+fn test_corrupted_noise_message() {
+	let new_state = |is_initiator: bool, ephemeral: u8| {
+		HandshakeState::new(
+			HandshakePattern::NN,
+			is_initiator,
+			b"",
+			None,
+			x448::PrivateKey::from_slice(&[ephemeral; x448::PRIVATE_KEY_SIZE]).unwrap(),
+			None,
+		)
+		.unwrap()
+	};
+
+	let mut message = [0u8; 128];
+	let len = new_state(true, 1)
+		.write_message(b"payload", &mut message)
+		.unwrap();
+	for_each_corruption(&message[..len], |input| {
+		let mut dst = [0u8; 128];
+		let _ = new_state(false, 2).read_message(input, &mut dst);
+	});
+}
+
+#[test]
+fn test_message_length_limits() {
+	// Reaching the size limits is reported as an error, not a panic.
+	let sk = chacha20::SecretKey::from_slice(&[0u8; 32]).unwrap();
+	let nonce = chacha20::Nonce::from_slice(&[0u8; 12]).unwrap();
+	let mut dst = [0u8; 128];
+	assert!(chacha20::encrypt(&sk, &nonce, u32::max_value(), &[0u8; 128], &mut dst).is_err());
+}
@@ -0,0 +1,73 @@
+// Testing against the cacophony test vectors for the 448_ChaChaPoly_BLAKE2b
+// cipher suite, as distributed with the snow crate (tests/vectors/cacophony.txt).
+extern crate hex;
+extern crate serde_json;
+
+use self::hex::decode;
+
+use self::serde_json::{Deserializer, Value};
+use crate::noise::{noise_test_runner, NoiseTestCase};
+use orion::hazardous::noise::x448_chachapoly_blake2b::HandshakePattern;
+use std::{fs::File, io::BufReader};
+
+fn get_hex(test_case: &Value, field: &str) -> Option<Vec<u8>> {
+	test_case
+		.get(field)
+		.map(|value| decode(value.as_str().unwrap()).unwrap())
+}
+
+#[test]
+fn test_cacophony() {
+	let file =
+		File::open("./tests/test_data/original/cacophony_448_ChaChaPoly_BLAKE2b.json").unwrap();
+	let reader = BufReader::new(file);
+	let stream = Deserializer::from_reader(reader).into_iter::<Value>();
+	let mut tested = 0;
+
+	for test_file in stream {
+		for test_case in test_file
+			.unwrap()
+			.get("vectors")
+			.unwrap()
+			.as_array()
+			.unwrap()
+		{
+			let pattern = match test_case.get("protocol_name").unwrap().as_str().unwrap() {
+				"Noise_NN_448_ChaChaPoly_BLAKE2b" => HandshakePattern::NN,
+				"Noise_NK_448_ChaChaPoly_BLAKE2b" => HandshakePattern::NK,
+				"Noise_XX_448_ChaChaPoly_BLAKE2b" => HandshakePattern::XX,
+				_ => panic!("Unrecognized protocol name detected!"),
+			};
+
+			let messages = test_case
+				.get("messages")
+				.unwrap()
+				.as_array()
+				.unwrap()
+				.iter()
+				.map(|msg| {
+					(
+						get_hex(msg, "payload").unwrap(),
+						get_hex(msg, "ciphertext").unwrap(),
+					)
+				})
+				.collect();
+
+			noise_test_runner(&NoiseTestCase {
+				pattern,
+				init_prologue: get_hex(test_case, "init_prologue").unwrap(),
+				init_static: get_hex(test_case, "init_static"),
+				init_ephemeral: get_hex(test_case, "init_ephemeral").unwrap(),
+				init_remote_static: get_hex(test_case, "init_remote_static"),
+				resp_prologue: get_hex(test_case, "resp_prologue").unwrap(),
+				resp_static: get_hex(test_case, "resp_static"),
+				resp_ephemeral: get_hex(test_case, "resp_ephemeral").unwrap(),
+				handshake_hash: get_hex(test_case, "handshake_hash").unwrap(),
+				messages,
+			});
+			tested += 1;
+		}
+	}
+
+	assert_eq!(tested, 3);
+}
@@ -0,0 +1,110 @@
+pub mod cacophony_noise;
+
+extern crate orion;
+use self::orion::hazardous::{
+	ecc::x448::{PrivateKey, PublicKey},
+	noise::x448_chachapoly_blake2b::{HandshakePattern, HandshakeState},
+};
+
+pub struct NoiseTestCase {
+	pub pattern: HandshakePattern,
+	pub init_prologue: Vec<u8>,
+	pub init_static: Option<Vec<u8>>,
+	pub init_ephemeral: Vec<u8>,
+	pub init_remote_static: Option<Vec<u8>>,
+	pub resp_prologue: Vec<u8>,
+	pub resp_static: Option<Vec<u8>>,
+	pub resp_ephemeral: Vec<u8>,
+	pub handshake_hash: Vec<u8>,
+	/// Pairs of (payload, ciphertext). Even indices are sent by the initiator.
+	pub messages: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+fn new_state(
+	pattern: HandshakePattern,
+	is_initiator: bool,
+	prologue: &[u8],
+	s: &Option<Vec<u8>>,
+	e: &[u8],
+	rs: &Option<Vec<u8>>,
+) -> HandshakeState {
+	let s = s.as_ref().map(|s| PrivateKey::from_slice(s).unwrap());
+	let rs = rs.as_ref().map(|rs| PublicKey::from_slice(rs).unwrap());
+
+	HandshakeState::new(
+		pattern,
+		is_initiator,
+		prologue,
+		s.as_ref(),
+		PrivateKey::from_slice(e).unwrap(),
+		rs.as_ref(),
+	)
+	.unwrap()
+}
+
+pub fn noise_test_runner(tc: &NoiseTestCase) {
+	let mut initiator = new_state(
+		tc.pattern,
+		true,
+		&tc.init_prologue,
+		&tc.init_static,
+		&tc.init_ephemeral,
+		&tc.init_remote_static,
+	);
+	let mut responder = new_state(
+		tc.pattern,
+		false,
+		&tc.resp_prologue,
+		&tc.resp_static,
+		&tc.resp_ephemeral,
+		&None,
+	);
+
+	let mut message = vec![0u8; 65535];
+	let mut payload = vec![0u8; 65535];
+	let mut messages = tc.messages.iter().enumerate();
+
+	// Handshake messages
+	while !initiator.is_handshake_finished() {
+		let (idx, (expected_payload, expected_ciphertext)) = messages.next().unwrap();
+		let (sender, receiver) = if idx % 2 == 0 {
+			(&mut initiator, &mut responder)
+		} else {
+			(&mut responder, &mut initiator)
+		};
+
+		let len = sender
+			.write_message(expected_payload, &mut message)
+			.unwrap();
+		assert_eq!(&message[..len], &expected_ciphertext[..]);
+		let len = receiver
+			.read_message(expected_ciphertext, &mut payload)
+			.unwrap();
+		assert_eq!(&payload[..len], &expected_payload[..]);
+	}
+
+	assert!(responder.is_handshake_finished());
+	assert_eq!(initiator.get_handshake_hash(), &tc.handshake_hash[..]);
+	assert_eq!(responder.get_handshake_hash(), &tc.handshake_hash[..]);
+
+	// Transport messages
+	let (mut init_send, mut init_recv) = initiator.into_transport_mode().unwrap();
+	let (mut resp_send, mut resp_recv) = responder.into_transport_mode().unwrap();
+
+	for (idx, (expected_payload, expected_ciphertext)) in messages {
+		let (sender, receiver) = if idx % 2 == 0 {
+			(&mut init_send, &mut resp_recv)
+		} else {
+			(&mut resp_send, &mut init_recv)
+		};
+
+		let len = sender
+			.encrypt_with_ad(&[], expected_payload, &mut message)
+			.unwrap();
+		assert_eq!(&message[..len], &expected_ciphertext[..]);
+		let len = receiver
+			.decrypt_with_ad(&[], expected_ciphertext, &mut payload)
+			.unwrap();
+		assert_eq!(&payload[..len], &expected_payload[..]);
+	}
+}
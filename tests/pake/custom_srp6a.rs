@@ -0,0 +1,50 @@
+// Testing against vectors generated by an independent implementation of
+// SRP-6a with SHA512 over the RFC 5054 groups, following the encodings
+// documented in orion::hazardous::pake::srp6a.
+extern crate hex;
+extern crate serde_json;
+
+use self::hex::decode;
+
+use self::serde_json::Value;
+use crate::pake::{srp6a_test_runner, Srp6aTestCase};
+use orion::hazardous::pake::srp6a::Group;
+use std::{fs::File, io::BufReader};
+
+fn get_hex(test_case: &Value, field: &str) -> Vec<u8> {
+	decode(test_case.get(field).unwrap().as_str().unwrap()).unwrap()
+}
+
+#[test]
+fn test_custom_srp6a() {
+	let file = File::open("./tests/test_data/original/custom_srp6a_sha512.json").unwrap();
+	let test_cases: Value = serde_json::from_reader(BufReader::new(file)).unwrap();
+	let mut tested = 0;
+
+	for test_case in test_cases.as_array().unwrap() {
+		let group = match test_case.get("group").unwrap().as_str().unwrap() {
+			"Group3072" => Group::Group3072,
+			"Group4096" => Group::Group4096,
+			_ => panic!("Unrecognized group detected!"),
+		};
+
+		let tc = Srp6aTestCase {
+			group,
+			identity: get_hex(test_case, "identity"),
+			password: get_hex(test_case, "password"),
+			salt: get_hex(test_case, "salt"),
+			client_ephemeral: get_hex(test_case, "client_ephemeral"),
+			server_ephemeral: get_hex(test_case, "server_ephemeral"),
+			verifier: get_hex(test_case, "verifier"),
+			client_public: get_hex(test_case, "client_public"),
+			server_public: get_hex(test_case, "server_public"),
+			client_proof: get_hex(test_case, "client_proof"),
+			server_proof: get_hex(test_case, "server_proof"),
+			session_key: get_hex(test_case, "session_key"),
+		};
+		srp6a_test_runner(&tc);
+		tested += 1;
+	}
+
+	assert_eq!(tested, 2);
+}
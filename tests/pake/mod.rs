@@ -0,0 +1,121 @@
+pub mod custom_srp6a;
+pub mod rfc_spake2_p256;
+
+extern crate orion;
+use self::orion::hazardous::pake::{
+	spake2_p256::{Confirmation, EphemeralKey, HashedPassword, Role, Spake2},
+	srp6a::{self, Group, Proof, PublicValue, SrpClient, SrpServer},
+};
+
+pub struct Spake2TestCase<'a> {
+	pub identity_a: &'a [u8],
+	pub identity_b: &'a [u8],
+	pub w: Vec<u8>,
+	pub x: Vec<u8>,
+	pub y: Vec<u8>,
+	pub share_a: Vec<u8>,
+	pub share_b: Vec<u8>,
+	pub ke: Vec<u8>,
+	pub confirmation_a: Vec<u8>,
+	pub confirmation_b: Vec<u8>,
+}
+
+pub fn spake2_p256_test_runner(tc: &Spake2TestCase) {
+	let w = HashedPassword::from_slice(&tc.w).unwrap();
+	let a = Spake2::new(
+		Role::A,
+		&w,
+		tc.identity_a,
+		tc.identity_b,
+		b"",
+		EphemeralKey::from_slice(&tc.x).unwrap(),
+	)
+	.unwrap();
+	let b = Spake2::new(
+		Role::B,
+		&w,
+		tc.identity_a,
+		tc.identity_b,
+		b"",
+		EphemeralKey::from_slice(&tc.y).unwrap(),
+	)
+	.unwrap();
+	assert_eq!(a.public_share().as_ref(), &tc.share_a[..]);
+	assert_eq!(b.public_share().as_ref(), &tc.share_b[..]);
+
+	let share_a = *a.public_share();
+	let share_b = *b.public_share();
+	let a = a.finish(&share_b).unwrap();
+	let b = b.finish(&share_a).unwrap();
+	assert!(*a.confirmation() == &tc.confirmation_a[..]);
+	assert!(*b.confirmation() == &tc.confirmation_b[..]);
+
+	let ke_a = a
+		.verify(&Confirmation::from_slice(&tc.confirmation_b).unwrap())
+		.unwrap();
+	let ke_b = b
+		.verify(&Confirmation::from_slice(&tc.confirmation_a).unwrap())
+		.unwrap();
+	assert!(ke_a == &tc.ke[..]);
+	assert!(ke_b == &tc.ke[..]);
+}
+
+pub struct Srp6aTestCase {
+	pub group: Group,
+	pub identity: Vec<u8>,
+	pub password: Vec<u8>,
+	pub salt: Vec<u8>,
+	pub client_ephemeral: Vec<u8>,
+	pub server_ephemeral: Vec<u8>,
+	pub verifier: Vec<u8>,
+	pub client_public: Vec<u8>,
+	pub server_public: Vec<u8>,
+	pub client_proof: Vec<u8>,
+	pub server_proof: Vec<u8>,
+	pub session_key: Vec<u8>,
+}
+
+pub fn srp6a_test_runner(tc: &Srp6aTestCase) {
+	let verifier = srp6a::create_verifier(tc.group, &tc.identity, &tc.password, &tc.salt).unwrap();
+	assert_eq!(verifier.as_ref(), &tc.verifier[..]);
+
+	let client = SrpClient::new(
+		tc.group,
+		srp6a::EphemeralKey::from_slice(&tc.client_ephemeral).unwrap(),
+	)
+	.unwrap();
+	let server = SrpServer::new(
+		tc.group,
+		&verifier,
+		srp6a::EphemeralKey::from_slice(&tc.server_ephemeral).unwrap(),
+	)
+	.unwrap();
+	assert_eq!(client.public_value().as_ref(), &tc.client_public[..]);
+	assert_eq!(server.public_value().as_ref(), &tc.server_public[..]);
+
+	let client = client
+		.process_challenge(
+			&tc.identity,
+			&tc.password,
+			&tc.salt,
+			&PublicValue::from_slice(&tc.server_public).unwrap(),
+		)
+		.unwrap();
+	assert!(*client.proof() == &tc.client_proof[..]);
+
+	let (server_proof, server_key) = server
+		.verify_client(
+			&tc.identity,
+			&tc.salt,
+			&PublicValue::from_slice(&tc.client_public).unwrap(),
+			&Proof::from_slice(&tc.client_proof).unwrap(),
+		)
+		.unwrap();
+	assert!(server_proof == &tc.server_proof[..]);
+
+	let client_key = client
+		.verify_server(&Proof::from_slice(&tc.server_proof).unwrap())
+		.unwrap();
+	assert!(client_key == &tc.session_key[..]);
+	assert!(server_key == &tc.session_key[..]);
+}
@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod rfc9382_spake2_p256 {
+
+	extern crate hex;
+
+	use self::hex::decode;
+	use crate::pake::{spake2_p256_test_runner, Spake2TestCase};
+
+	#[test]
+	fn rfc9382_spake2_p256_test_vector_1() {
+		spake2_p256_test_runner(&Spake2TestCase {
+			identity_a: b"server",
+			identity_b: b"client",
+			w: decode("2ee57912099d31560b3a44b1184b9b4866e904c49d12ac5042c97dca461b1a5f").unwrap(),
+			x: decode("43dd0fd7215bdcb482879fca3220c6a968e66d70b1356cac18bb26c84a78d729").unwrap(),
+			y: decode("dcb60106f276b02606d8ef0a328c02e4b629f84f89786af5befb0bc75b6e66be").unwrap(),
+			share_a: decode(
+				"04a56fa807caaa53a4d28dbb9853b9815c61a411118a6fe516a8798434751470f9\
+				 010153ac33d0d5f2047ffdb1a3e42c9b4e6be662766e1eeb4116988ede5f912c",
+			)
+			.unwrap(),
+			share_b: decode(
+				"0406557e482bd03097ad0cbaa5df82115460d951e3451962f1eaf4367a420676d0\
+				 9857ccbc522686c83d1852abfa8ed6e4a1155cf8f1543ceca528afb591a1e0b7",
+			)
+			.unwrap(),
+			ke: decode("0e0672dc86f8e45565d338b0540abe69").unwrap(),
+			confirmation_a: decode(
+				"58ad4aa88e0b60d5061eb6b5dd93e80d9c4f00d127c65b3b35b1b5281fee38f0",
+			)
+			.unwrap(),
+			confirmation_b: decode(
+				"d3e2e547f1ae04f2dbdbf0fc4b79f8ecff2dff314b5d32fe9fcef2fb26dc459b",
+			)
+			.unwrap(),
+		});
+	}
+}
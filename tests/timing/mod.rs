@@ -0,0 +1,239 @@
+// Statistical timing tests for the constant-time paths, following dudect
+// (Reparaz, Balasch and Verbauwhede: "Dude, is my code constant time?",
+// https://eprint.iacr.org/2016/1123).
+//
+// Each test times an operation on inputs of two classes, a fixed input and
+// random inputs, interleaved in random order. Welch's t-test is then used to
+// check whether the two timing distributions differ. As in dudect, the test
+// is repeated on measurements cropped at several percentiles, to remove the
+// long tail caused by interrupts and scheduling, and the largest t-value is
+// used. A t-value above `T_THRESHOLD` means the operation leaks which class
+// its input belongs to.
+//
+// These tests need a quiet machine and an optimized build, so they only run
+// with the `timing_tests` feature:
+//
+// cargo test --release --features timing_tests --test mod timing
+//
+// X25519 is not implemented in orion, so X448 is tested instead.
+
+extern crate orion;
+
+use self::orion::{
+	hazardous::{
+		ecc::x448,
+		mac::{hmac, poly1305},
+		stream::chacha20,
+	},
+	util::secure_rand_bytes,
+};
+use core::convert::TryFrom;
+use std::{hint::black_box, time::Instant};
+
+/// dudect considers a t-value above 10 to mean that the timing definitely
+/// depends on the input class.
+const T_THRESHOLD: f64 = 10.0;
+
+/// The percentiles at which the measurements are cropped, in addition to
+/// testing all of them.
+const CROP_PERCENTILES: [f64; 4] = [0.5, 0.75, 0.9, 0.95];
+
+/// Welch's t-test, updated online.
+#[derive(Default)]
+struct WelchTTest {
+	n: [f64; 2],
+	mean: [f64; 2],
+	m2: [f64; 2],
+}
+
+impl WelchTTest {
+	fn push(&mut self, class: usize, x: f64) {
+		self.n[class] += 1.0;
+		let delta = x - self.mean[class];
+		self.mean[class] += delta / self.n[class];
+		self.m2[class] += delta * (x - self.mean[class]);
+	}
+
+	fn t(&self) -> f64 {
+		let var_0 = self.m2[0] / (self.n[0] - 1.0);
+		let var_1 = self.m2[1] / (self.n[1] - 1.0);
+		let denominator = (var_0 / self.n[0] + var_1 / self.n[1]).sqrt();
+		if denominator == 0.0 {
+			return 0.0;
+		}
+
+		(self.mean[0] - self.mean[1]) / denominator
+	}
+}
+
+/// The largest absolute t-value over all measurements and the cropped sets.
+/// `measurements` holds pairs of input class and time.
+fn max_t(measurements: &[(usize, f64)]) -> f64 {
+	let mut sorted: Vec<f64> = measurements.iter().map(|(_, time)| *time).collect();
+	sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+	let mut thresholds = vec![core::f64::INFINITY];
+	for percentile in CROP_PERCENTILES.iter() {
+		thresholds.push(sorted[(sorted.len() as f64 * percentile) as usize]);
+	}
+
+	thresholds
+		.iter()
+		.map(|threshold| {
+			let mut test = WelchTTest::default();
+			for (class, time) in measurements.iter().filter(|(_, time)| time < threshold) {
+				test.push(*class, *time);
+			}
+			test.t().abs()
+		})
+		.fold(0.0, f64::max)
+}
+
+fn random_bytes(len: usize) -> Vec<u8> {
+	let mut buf = vec![0u8; len];
+	secure_rand_bytes(&mut buf).unwrap();
+	buf
+}
+
+/// Time `op` on `samples` inputs, each either `fixed` (class 0) or random
+/// bytes of the same length (class 1), and return the largest absolute
+/// t-value. Each sample times `batch` calls, so that short operations are
+/// measured above the resolution of the timer.
+fn leakage<F: FnMut(&[u8])>(samples: usize, batch: usize, fixed: &[u8], mut op: F) -> f64 {
+	let classes = random_bytes(samples);
+	let inputs: Vec<(usize, Vec<u8>)> = classes
+		.iter()
+		.map(|byte| {
+			let class = (byte & 1) as usize;
+			let input = if class == 0 {
+				fixed.to_vec()
+			} else {
+				random_bytes(fixed.len())
+			};
+			(class, input)
+		})
+		.collect();
+
+	// Warm up caches and branch predictors.
+	for (_, input) in inputs.iter().take(samples / 10) {
+		op(input);
+	}
+
+	let mut measurements = Vec::with_capacity(samples);
+	for (class, input) in inputs.iter() {
+		let start = Instant::now();
+		for _ in 0..batch {
+			op(black_box(&input[..]));
+		}
+		measurements.push((*class, start.elapsed().as_nanos() as f64));
+	}
+
+	max_t(&measurements)
+}
+
+fn assert_constant_time(name: &str, t: f64) {
+	assert!(
+		t < T_THRESHOLD,
+		"{} leaks timing information: t = {:.2}",
+		name,
+		t
+	);
+}
+
+#[test]
+fn test_welch_t_test() {
+	let mut test = WelchTTest::default();
+	for x in &[1.0, 2.0, 3.0, 4.0] {
+		test.push(0, *x);
+	}
+	for x in &[2.0, 4.0, 6.0, 8.0] {
+		test.push(1, *x);
+	}
+	// Means 2.5 and 5, variances 5/3 and 20/3, four samples each.
+	let expected = -2.5 / (5.0f64 / 12.0 + 20.0 / 12.0).sqrt();
+	assert!((test.t() - expected).abs() < 1e-9);
+}
+
+#[test]
+fn test_harness_detects_early_exit_comparison() {
+	// A comparison that returns at the first differing byte. The fixed input
+	// differs from the reference only in its last byte, the random ones
+	// almost always in their first.
+	let reference = vec![0u8; 4096];
+	let mut fixed = reference.clone();
+	fixed[4095] = 1;
+
+	let t = leakage(10_000, 4, &fixed, |input| {
+		black_box(input == &reference[..]);
+	});
+	assert!(t > T_THRESHOLD, "t = {:.2}", t);
+}
+
+#[test]
+fn test_newtype_comparison_constant_time() {
+	let reference = chacha20::SecretKey::from_slice(&[0u8; 32]).unwrap();
+	let mut fixed = [0u8; 32];
+	fixed[31] = 1;
+
+	let t = leakage(100_000, 64, &fixed, |input| {
+		black_box(reference == input);
+	});
+	assert_constant_time("SecretKey comparison", t);
+}
+
+#[test]
+fn test_hmac_verify_constant_time() {
+	let key = hmac::SecretKey::from_slice(&[0x42u8; 32]).unwrap();
+	let data = [0u8; 64];
+	let mut fixed = hmac::hmac(&key, &data)
+		.unwrap()
+		.unprotected_as_bytes()
+		.to_vec();
+	fixed[63] ^= 1;
+
+	let t = leakage(50_000, 4, &fixed, |tag| {
+		let tag = hmac::Tag::from_slice(tag).unwrap();
+		black_box(hmac::verify(&tag, &key, &data).is_err());
+	});
+	assert_constant_time("hmac::verify", t);
+}
+
+#[test]
+fn test_poly1305_verify_constant_time() {
+	let key = poly1305::OneTimeKey::from_slice(&[0x42u8; 32]).unwrap();
+	let data = [0u8; 64];
+	let mut fixed = poly1305::poly1305(&key, &data)
+		.unwrap()
+		.unprotected_as_bytes()
+		.to_vec();
+	fixed[15] ^= 1;
+
+	let t = leakage(100_000, 16, &fixed, |tag| {
+		let tag = poly1305::Tag::from_slice(tag).unwrap();
+		black_box(poly1305::verify(&tag, &key, &data).is_err());
+	});
+	assert_constant_time("poly1305::verify", t);
+}
+
+#[test]
+fn test_poly1305_key_constant_time() {
+	let data = random_bytes(256);
+
+	let t = leakage(50_000, 4, &[0u8; 32], |key| {
+		let key = poly1305::OneTimeKey::from_slice(key).unwrap();
+		let _ = black_box(poly1305::poly1305(&key, &data).unwrap());
+	});
+	assert_constant_time("poly1305::poly1305", t);
+}
+
+#[test]
+fn test_x448_key_agreement_constant_time() {
+	let peer_private_key = x448::PrivateKey::from_slice(&[0x42u8; 56]).unwrap();
+	let public_key = x448::PublicKey::try_from(&peer_private_key).unwrap();
+
+	let t = leakage(5_000, 1, &[0x01u8; 56], |private_key| {
+		let private_key = x448::PrivateKey::from_slice(private_key).unwrap();
+		let _ = black_box(x448::key_agreement(&private_key, &public_key).unwrap());
+	});
+	assert_constant_time("x448::key_agreement", t);
+}
@@ -0,0 +1,183 @@
+// Drivers that run the test cases of a Wycheproof file against orion. There
+// are no drivers for X25519 or Ed25519, since orion does not implement them.
+
+extern crate orion;
+
+use self::orion::{
+	errors::UnknownCryptoError,
+	hazardous::{
+		aead::{chacha20poly1305, xchacha20poly1305},
+		kdf::hkdf,
+		mac::hmac,
+		stream::{chacha20, xchacha20},
+	},
+};
+use super::{Expected, TestFile};
+
+const POLY1305_OUTSIZE: usize = 16;
+
+fn aead<Sealer, Opener>(file: &TestFile, sealer: Sealer, opener: Opener)
+where
+	Sealer: Fn(&[u8], &[u8], &[u8], &[u8], &mut [u8]) -> Result<(), UnknownCryptoError>,
+	Opener: Fn(&[u8], &[u8], &[u8], &[u8], &mut [u8]) -> Result<(), UnknownCryptoError>,
+{
+	for (_, test) in file.test_cases() {
+		let key = test.bytes("key");
+		let iv = test.bytes("iv");
+		let aad = test.bytes("aad");
+		let msg = test.bytes("msg");
+		let mut ct_with_tag = test.bytes("ct");
+		ct_with_tag.extend_from_slice(&test.bytes("tag"));
+
+		// orion does not accept empty plaintexts, so test cases that are
+		// otherwise valid but have an empty message are left out.
+		if msg.is_empty() && test.result != Expected::Invalid {
+			continue;
+		}
+
+		let mut pt = vec![0u8; msg.len()];
+		let accepted = opener(&key, &iv, &ct_with_tag, &aad, &mut pt).is_ok() && pt == msg;
+		test.check(accepted);
+
+		if test.result == Expected::Valid {
+			let mut sealed = vec![0u8; msg.len() + POLY1305_OUTSIZE];
+			sealer(&key, &iv, &msg, &aad, &mut sealed).unwrap();
+			assert_eq!(sealed, ct_with_tag, "tcId {}", test.tc_id);
+		}
+	}
+}
+
+pub fn chacha20_poly1305(file: &TestFile) {
+	aead(
+		file,
+		|key, nonce, pt, aad, dst_out| {
+			chacha20poly1305::seal(
+				&chacha20::SecretKey::from_slice(key)?,
+				&chacha20::Nonce::from_slice(nonce)?,
+				pt,
+				Some(aad),
+				dst_out,
+			)
+		},
+		|key, nonce, ct, aad, dst_out| {
+			chacha20poly1305::open(
+				&chacha20::SecretKey::from_slice(key)?,
+				&chacha20::Nonce::from_slice(nonce)?,
+				ct,
+				Some(aad),
+				dst_out,
+			)
+		},
+	);
+}
+
+pub fn xchacha20_poly1305(file: &TestFile) {
+	aead(
+		file,
+		|key, nonce, pt, aad, dst_out| {
+			xchacha20poly1305::seal(
+				&chacha20::SecretKey::from_slice(key)?,
+				&xchacha20::Nonce::from_slice(nonce)?,
+				pt,
+				Some(aad),
+				dst_out,
+			)
+		},
+		|key, nonce, ct, aad, dst_out| {
+			xchacha20poly1305::open(
+				&chacha20::SecretKey::from_slice(key)?,
+				&xchacha20::Nonce::from_slice(nonce)?,
+				ct,
+				Some(aad),
+				dst_out,
+			)
+		},
+	);
+}
+
+pub fn hmac_sha512(file: &TestFile) {
+	for (group, test) in file.test_cases() {
+		let tag_size = group.usize("tagSize") / 8;
+		let key = hmac::SecretKey::from_slice(&test.bytes("key")).unwrap();
+		let msg = test.bytes("msg");
+		let tag = test.bytes("tag");
+
+		// orion only verifies full-length tags, so truncated tags are compared
+		// with a prefix of the computed tag.
+		let accepted = match hmac::Tag::from_slice(&tag) {
+			Ok(full_tag) => hmac::verify(&full_tag, &key, &msg).is_ok(),
+			Err(_) => {
+				let computed = hmac::hmac(&key, &msg).unwrap();
+				tag.len() == tag_size && computed.unprotected_as_bytes()[..tag_size] == tag[..]
+			}
+		};
+		test.check(accepted);
+	}
+}
+
+pub fn hkdf_sha512(file: &TestFile) {
+	for (_, test) in file.test_cases() {
+		let expected = test.bytes("okm");
+		let mut okm = vec![0u8; test.usize("size")];
+
+		let accepted = hkdf::derive_key(
+			&test.bytes("salt"),
+			&test.bytes("ikm"),
+			Some(&test.bytes("info")),
+			&mut okm,
+		)
+		.is_ok() && okm == expected;
+		test.check(accepted);
+	}
+}
+
+// orion does not include the Wycheproof files for HMAC-SHA512 and
+// HKDF-SHA512, so their drivers are checked here with the RFC 4231 and
+// kullo.net vectors, written in the Wycheproof format, along with invalid
+// cases made by modifying them.
+
+#[test]
+fn test_hmac_sha512_driver() {
+	let json = r#"{
+		"algorithm": "HMACSHA512",
+		"numberOfTests": 4,
+		"testGroups": [{
+			"keySize": 32,
+			"tagSize": 512,
+			"type": "MacTest",
+			"tests": [
+				{ "tcId": 1, "comment": "RFC 4231 test case 2", "key": "4a656665", "msg": "7768617420646f2079612077616e7420666f72206e6f7468696e673f", "tag": "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea2505549758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737", "result": "valid", "flags": [] },
+				{ "tcId": 2, "comment": "modified tag", "key": "4a656665", "msg": "7768617420646f2079612077616e7420666f72206e6f7468696e673f", "tag": "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea2505549758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce736", "result": "invalid", "flags": [] }
+			]
+		}, {
+			"keySize": 32,
+			"tagSize": 128,
+			"type": "MacTest",
+			"tests": [
+				{ "tcId": 3, "comment": "truncated tag", "key": "4a656665", "msg": "7768617420646f2079612077616e7420666f72206e6f7468696e673f", "tag": "164b7a7bfcf819e2e395fbe73b56e0a3", "result": "valid", "flags": [] },
+				{ "tcId": 4, "comment": "truncated tag of the wrong length", "key": "4a656665", "msg": "7768617420646f2079612077616e7420666f72206e6f7468696e673f", "tag": "164b7a7bfcf819e2e395fbe73b56e0", "result": "invalid", "flags": [] }
+			]
+		}]
+	}"#;
+
+	TestFile::parse(json).run();
+}
+
+#[test]
+fn test_hkdf_sha512_driver() {
+	let json = r#"{
+		"algorithm": "HKDF-SHA-512",
+		"numberOfTests": 3,
+		"testGroups": [{
+			"keySize": 176,
+			"type": "HkdfTest",
+			"tests": [
+				{ "tcId": 1, "comment": "kullo.net test case 1", "ikm": "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b", "salt": "000102030405060708090a0b0c", "info": "f0f1f2f3f4f5f6f7f8f9", "size": 42, "okm": "832390086cda71fb47625bb5ceb168e4c8e26a1a16ed34d9fc7fe92c1481579338da362cb8d9f925d7cb", "result": "valid", "flags": [] },
+				{ "tcId": 2, "comment": "modified info", "ikm": "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b", "salt": "000102030405060708090a0b0c", "info": "f0f1f2f3f4f5f6f7f8f8", "size": 42, "okm": "832390086cda71fb47625bb5ceb168e4c8e26a1a16ed34d9fc7fe92c1481579338da362cb8d9f925d7cb", "result": "invalid", "flags": [] },
+				{ "tcId": 3, "comment": "output size too large", "ikm": "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b", "salt": "000102030405060708090a0b0c", "info": "f0f1f2f3f4f5f6f7f8f9", "size": 16321, "okm": "", "result": "invalid", "flags": ["SizeTooLarge"] }
+			]
+		}]
+	}"#;
+
+	TestFile::parse(json).run();
+}
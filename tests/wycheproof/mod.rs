@@ -0,0 +1,222 @@
+// Loader for Google Wycheproof JSON test vector files.
+// See https://github.com/google/wycheproof/blob/master/doc/files.md for the
+// file format.
+//
+// Each test case has a result of "valid", "acceptable" or "invalid":
+// - "valid": orion must accept the input and produce the expected output.
+// - "invalid": orion must reject the input, or produce an output that differs
+//   from the expected one.
+// - "acceptable": the input is technically valid, but uses a weak or legacy
+//   parameter, so both accepting and rejecting it is allowed. If orion does
+//   accept it, the output must be the expected one.
+//
+// Every file in tests/test_data/original whose name starts with "Wycheproof_"
+// is run by `test_wycheproof_files`, using the driver registered for the
+// file's "algorithm" field below. New vector files for a registered
+// algorithm are picked up by dropping them into that directory.
+
+extern crate hex;
+extern crate serde_json;
+
+pub mod drivers;
+
+use self::serde_json::Value;
+use std::{fs, path::Path};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Expected {
+	Valid,
+	Acceptable,
+	Invalid,
+}
+
+impl Expected {
+	fn parse(result: &str) -> Self {
+		match result {
+			"valid" => Expected::Valid,
+			"acceptable" => Expected::Acceptable,
+			"invalid" => Expected::Invalid,
+			_ => panic!("Unrecognized result detected: {}", result),
+		}
+	}
+}
+
+pub struct TestCase {
+	pub tc_id: u64,
+	pub comment: String,
+	pub result: Expected,
+	pub flags: Vec<String>,
+	fields: Value,
+}
+
+impl TestCase {
+	/// Decode the hexadecimal field `name`.
+	pub fn bytes(&self, name: &str) -> Vec<u8> {
+		let field = self.fields.get(name).and_then(Value::as_str);
+		match field {
+			Some(encoded) => hex::decode(encoded).unwrap(),
+			None => panic!("tcId {}: missing field {}", self.tc_id, name),
+		}
+	}
+
+	/// The numeric field `name`.
+	pub fn usize(&self, name: &str) -> usize {
+		let field = self.fields.get(name).and_then(Value::as_u64);
+		match field {
+			Some(value) => value as usize,
+			None => panic!("tcId {}: missing field {}", self.tc_id, name),
+		}
+	}
+
+	/// Assert that whether orion accepted the test case matches its expected
+	/// result. `accepted` must only be true if orion both accepted the input
+	/// and produced the expected output.
+	pub fn check(&self, accepted: bool) {
+		match self.result {
+			Expected::Valid => assert!(
+				accepted,
+				"tcId {} ({}) is valid, but was rejected",
+				self.tc_id, self.comment
+			),
+			Expected::Invalid => assert!(
+				!accepted,
+				"tcId {} ({}) is invalid, but was accepted",
+				self.tc_id, self.comment
+			),
+			Expected::Acceptable => (),
+		}
+	}
+}
+
+pub struct TestGroup {
+	params: Value,
+	pub tests: Vec<TestCase>,
+}
+
+impl TestGroup {
+	/// The numeric group parameter `name`, such as "keySize" or "tagSize".
+	pub fn usize(&self, name: &str) -> usize {
+		self.params
+			.get(name)
+			.and_then(Value::as_u64)
+			.unwrap_or_else(|| panic!("Missing group parameter {}", name)) as usize
+	}
+}
+
+pub struct TestFile {
+	pub algorithm: String,
+	pub number_of_tests: usize,
+	pub groups: Vec<TestGroup>,
+}
+
+impl TestFile {
+	pub fn parse(json: &str) -> Self {
+		let file: Value = serde_json::from_str(json).unwrap();
+		let algorithm = file["algorithm"].as_str().unwrap().to_string();
+		let number_of_tests = file["numberOfTests"].as_u64().unwrap() as usize;
+
+		let mut groups = Vec::new();
+		for group in file["testGroups"].as_array().unwrap() {
+			let mut tests = Vec::new();
+			for test in group["tests"].as_array().unwrap() {
+				tests.push(TestCase {
+					tc_id: test["tcId"].as_u64().unwrap(),
+					comment: test["comment"].as_str().unwrap_or("").to_string(),
+					result: Expected::parse(test["result"].as_str().unwrap()),
+					flags: test["flags"]
+						.as_array()
+						.map(|flags| {
+							flags
+								.iter()
+								.map(|flag| flag.as_str().unwrap().to_string())
+								.collect()
+						})
+						.unwrap_or_default(),
+					fields: test.clone(),
+				});
+			}
+
+			let mut params = group.clone();
+			params.as_object_mut().unwrap().remove("tests");
+			groups.push(TestGroup { params, tests });
+		}
+
+		let parsed = TestFile {
+			algorithm,
+			number_of_tests,
+			groups,
+		};
+		assert_eq!(parsed.test_cases().count(), parsed.number_of_tests);
+
+		parsed
+	}
+
+	pub fn load<P: AsRef<Path>>(path: P) -> Self {
+		Self::parse(&fs::read_to_string(path).unwrap())
+	}
+
+	/// All test cases, with the group each belongs to.
+	pub fn test_cases(&self) -> impl Iterator<Item = (&TestGroup, &TestCase)> {
+		self.groups
+			.iter()
+			.flat_map(|group| group.tests.iter().map(move |test| (group, test)))
+	}
+
+	/// Run the driver registered for this file's algorithm.
+	pub fn run(&self) {
+		match self.algorithm.as_str() {
+			"CHACHA20-POLY1305" => drivers::chacha20_poly1305(self),
+			"XCHACHA20-POLY1305" => drivers::xchacha20_poly1305(self),
+			"HMACSHA512" => drivers::hmac_sha512(self),
+			"HKDF-SHA-512" => drivers::hkdf_sha512(self),
+			other => panic!("No Wycheproof driver registered for {}", other),
+		}
+	}
+}
+
+#[test]
+fn test_wycheproof_files() {
+	let mut ran = 0;
+	for entry in fs::read_dir("./tests/test_data/original").unwrap() {
+		let path = entry.unwrap().path();
+		let name = path.file_name().unwrap().to_str().unwrap();
+		if name.starts_with("Wycheproof_") && name.ends_with(".json") {
+			TestFile::load(&path).run();
+			ran += 1;
+		}
+	}
+
+	assert!(ran > 0);
+}
+
+#[test]
+fn test_result_semantics() {
+	let json = r#"{
+		"algorithm": "HMACSHA512",
+		"numberOfTests": 3,
+		"testGroups": [{
+			"keySize": 0,
+			"tagSize": 512,
+			"type": "MacTest",
+			"tests": [
+				{ "tcId": 1, "comment": "", "key": "", "msg": "", "tag": "", "result": "valid", "flags": [] },
+				{ "tcId": 2, "comment": "", "key": "", "msg": "", "tag": "", "result": "acceptable", "flags": ["Weak"] },
+				{ "tcId": 3, "comment": "", "key": "", "msg": "", "tag": "", "result": "invalid" }
+			]
+		}]
+	}"#;
+	let file = TestFile::parse(json);
+	let cases: Vec<&TestCase> = file.test_cases().map(|(_, test)| test).collect();
+
+	assert_eq!(cases[0].result, Expected::Valid);
+	assert_eq!(cases[1].flags, vec!["Weak".to_string()]);
+	assert!(cases[2].flags.is_empty());
+
+	cases[0].check(true);
+	cases[1].check(true);
+	cases[1].check(false);
+	cases[2].check(false);
+
+	assert!(std::panic::catch_unwind(|| cases[0].check(false)).is_err());
+	assert!(std::panic::catch_unwind(|| cases[2].check(true)).is_err());
+}